@@ -14,16 +14,22 @@ public sealed record AudioBuffer(ReadOnlyMemory<byte> Data, long PositionMs)
     /// Null for non-pooled buffers (e.g. decoder output).
     /// </summary>
     private byte[]? _rentedArray;
+    private readonly ArrayPool<byte>? _pool;
 
     /// <summary>
     /// Creates a pooled audio buffer backed by an ArrayPool rental.
     /// The <paramref name="rentedArray"/> may be larger than <paramref name="dataLength"/>;
     /// only the first <paramref name="dataLength"/> bytes are exposed via <see cref="Data"/>.
     /// </summary>
-    public AudioBuffer(byte[] rentedArray, int dataLength, long positionMs)
+    /// <param name="rentedArray">The rented array.</param>
+    /// <param name="dataLength">Number of valid bytes.</param>
+    /// <param name="positionMs">Track position of the buffer.</param>
+    /// <param name="pool">Pool the array was rented from; <see cref="ArrayPool{T}.Shared"/> when null.</param>
+    public AudioBuffer(byte[] rentedArray, int dataLength, long positionMs, ArrayPool<byte>? pool = null)
         : this(new ReadOnlyMemory<byte>(rentedArray, 0, dataLength), positionMs)
     {
         _rentedArray = rentedArray;
+        _pool = pool;
     }
 
     /// <summary>
@@ -55,7 +61,7 @@ public sealed record AudioBuffer(ReadOnlyMemory<byte> Data, long PositionMs)
         if (arr != null)
         {
             _rentedArray = null;
-            ArrayPool<byte>.Shared.Return(arr);
+            (_pool ?? ArrayPool<byte>.Shared).Return(arr);
         }
     }
 }
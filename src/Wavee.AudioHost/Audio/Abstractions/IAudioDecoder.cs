@@ -26,6 +26,15 @@ public interface IAudioDecoder
     /// <returns>Output PCM format.</returns>
     Task<AudioFormat> GetFormatAsync(Stream stream, CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Gets the length of the audio in the stream, when the decoder can tell without decoding it.
+    /// </summary>
+    /// <param name="stream">Audio stream (position is restored).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Duration in milliseconds, or null when unknown.</returns>
+    Task<long?> GetDurationMsAsync(Stream stream, CancellationToken cancellationToken = default)
+        => Task.FromResult<long?>(null);
+
     /// <summary>
     /// Decodes the audio stream into PCM audio buffers.
     /// </summary>
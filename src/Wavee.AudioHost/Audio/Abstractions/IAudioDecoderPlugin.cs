@@ -0,0 +1,38 @@
+using System.Diagnostics.CodeAnalysis;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio.Decoders;
+
+namespace Wavee.AudioHost.Audio.Abstractions;
+
+/// <summary>
+/// Entry point for an out-of-tree decoder package (hardware decoder, alternative
+/// codec library). Registers one or more <see cref="IAudioDecoder"/>s, usually
+/// keyed by codec name or MIME type so they take precedence over the built-ins.
+/// </summary>
+/// <remarks>
+/// Plug-in assemblies are discovered by <see cref="DecoderPluginLoader"/> and must
+/// declare their entry point with <see cref="AudioDecoderPluginAttribute"/>.
+/// </remarks>
+public interface IAudioDecoderPlugin
+{
+    /// <summary>Display name used in logs.</summary>
+    string Name { get; }
+
+    /// <summary>
+    /// Registers the plug-in's decoders. Called once at startup, after the built-in decoders.
+    /// </summary>
+    void Register(AudioDecoderRegistry registry, ILogger? logger);
+}
+
+/// <summary>
+/// Declares the <see cref="IAudioDecoderPlugin"/> implementation in a plug-in assembly.
+/// The type needs a public parameterless constructor.
+/// </summary>
+[AttributeUsage(AttributeTargets.Assembly, AllowMultiple = true)]
+public sealed class AudioDecoderPluginAttribute(
+    [DynamicallyAccessedMembers(DynamicallyAccessedMemberTypes.PublicParameterlessConstructor)] Type pluginType)
+    : Attribute
+{
+    [DynamicallyAccessedMembers(DynamicallyAccessedMemberTypes.PublicParameterlessConstructor)]
+    public Type PluginType { get; } = pluginType;
+}
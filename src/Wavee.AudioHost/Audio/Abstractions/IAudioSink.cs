@@ -10,6 +10,11 @@ public interface IAudioSink : IAsyncDisposable
     /// </summary>
     string SinkName { get; }
 
+    /// <summary>
+    /// PCM format the sink was last opened with, or null before <see cref="InitializeAsync"/>.
+    /// </summary>
+    AudioFormat? Format { get; }
+
     /// <summary>
     /// Initializes the audio sink with the given format.
     /// </summary>
@@ -67,6 +72,19 @@ public interface IAudioSink : IAsyncDisposable
     /// </summary>
     /// <param name="cancellationToken">Cancellation token.</param>
     Task DrainAsync(CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Total number of output underruns seen since the sink was created.
+    /// Sinks that can't detect underruns report 0.
+    /// </summary>
+    long UnderrunCount => 0;
+
+    /// <summary>
+    /// Closes the output stream and frees its buffer so the device can idle.
+    /// The next <see cref="InitializeAsync"/> opens it again.
+    /// Sinks with nothing to release do nothing.
+    /// </summary>
+    Task ReleaseAsync() => Task.CompletedTask;
 }
 
 /// <summary>
@@ -0,0 +1,16 @@
+namespace Wavee.AudioHost.Audio.Abstractions;
+
+/// <summary>
+/// Optional capability for <see cref="IAudioSink"/> implementations that can
+/// guarantee untouched output: no realtime volume, no transport ramps, and a
+/// device that runs at the stream's native format.
+/// </summary>
+public interface IBitPerfectSink
+{
+    /// <summary>
+    /// When true, <see cref="IAudioSink.InitializeAsync"/> (and device switches)
+    /// throw <see cref="NotSupportedException"/> instead of opening a device whose
+    /// native format differs from the stream, and PCM is written unmodified.
+    /// </summary>
+    bool BitPerfect { get; set; }
+}
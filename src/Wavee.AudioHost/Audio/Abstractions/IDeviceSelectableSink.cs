@@ -11,6 +11,13 @@ public interface IDeviceSelectableSink
     /// <summary>Friendly name of the device the sink is currently writing to.</summary>
     string? CurrentDeviceName { get; }
 
+    /// <summary>
+    /// Raised after the stream has been reopened on a different device, whether by
+    /// request, by following the system default, or because the device disappeared.
+    /// Buffered audio carries over, so playback resumes at the sample it left off.
+    /// </summary>
+    event Action<AudioDeviceChange>? DeviceChanged;
+
     /// <summary>
     /// Enumerate all local output devices the sink could switch to.
     /// </summary>
@@ -37,3 +44,16 @@ public interface IDeviceSelectableSink
     /// </summary>
     Task SwitchToDefaultDeviceAsync(CancellationToken ct = default);
 }
+
+/// <summary>
+/// The sink moved playback to a different output device.
+/// </summary>
+/// <param name="PreviousDeviceName">Device playback was on before the change.</param>
+/// <param name="DeviceName">Device playback is on now.</param>
+/// <param name="Reason">What triggered the change.</param>
+/// <param name="PositionMs">Playback position the new device resumed from.</param>
+public sealed record AudioDeviceChange(
+    string? PreviousDeviceName,
+    string? DeviceName,
+    AudioDeviceChangeReason Reason,
+    long PositionMs);
@@ -0,0 +1,19 @@
+namespace Wavee.AudioHost.Audio.Abstractions;
+
+/// <summary>
+/// Optional capability for <see cref="IAudioSink"/> implementations that ramp
+/// volume on start, resume, pause and stop instead of cutting the signal.
+/// </summary>
+/// <remarks>
+/// <see cref="IAudioSink.PauseAsync"/> completes only after the fade-out has
+/// played, so <see cref="IAudioSink.PlaybackPositionMs"/> read afterwards
+/// includes the faded audio and nothing beyond it.
+/// </remarks>
+public interface ITransportFadeSink
+{
+    /// <summary>Fade-in length in milliseconds for start, resume and post-seek unmute; 0 disables.</summary>
+    int FadeInMs { get; set; }
+
+    /// <summary>Fade-out length in milliseconds for pause and stop; 0 disables.</summary>
+    int FadeOutMs { get; set; }
+}
@@ -100,6 +100,11 @@ public sealed record TrackMetadata
     /// </summary>
     public double? ReplayGainTrackPeak { get; init; }
 
+    /// <summary>
+    /// ReplayGain album peak value (0.0 - 1.0+).
+    /// </summary>
+    public double? ReplayGainAlbumPeak { get; init; }
+
     /// <summary>
     /// Additional metadata key-value pairs.
     /// </summary>
@@ -1,4 +1,5 @@
 using System.Diagnostics;
+using System.Reactive.Linq;
 using System.Reactive.Subjects;
 using System.Threading.Channels;
 using Microsoft.Extensions.Logging;
@@ -42,9 +43,38 @@ public sealed class AudioEngine : IAsyncDisposable
     private EngineState _currentState = EngineState.Empty;
     private readonly object _stateLock = new();
 
+    // Per-track counters for the track currently loaded. Replaced at the start of
+    // every playback loop and folded into each published EngineState.
+    private TrackStatsCounters _trackStats = new();
+
+    // Evaluated at the start of every track; null keeps the sink on whatever it opened.
+    private volatile AudioRoutingPolicy? _routingPolicy;
+
+    // Channel layouts keyed by output device name; the "" entry applies to every other device.
+    private readonly Dictionary<string, (ChannelLayoutMode Mode, int[]? Map)> _channelLayouts = new(StringComparer.Ordinal);
+    private readonly ChannelMapProcessor? _channelMap;
+    private readonly PcmTapProcessor? _pcmTap;
+    private readonly OutputAnalysisProcessor? _outputAnalysis;
+
+    // A-B loop of the loaded track; see SetLoopRegion.
+    private readonly LoopRegion _loopRegion = new();
+
+    // Describes the chain of the track currently loaded; null when idle. See GetPipelineInfoAsync.
+    private volatile ActivePipeline? _activePipeline;
+
     // Playback control
     private CancellationTokenSource? _playbackCts;
     private Task? _playbackTask;
+    // Re-runs the current track's playback loop from a given position; used by the stall watchdog.
+    private volatile Func<long, CancellationToken, Task>? _restartLoop;
+
+    // Position to restart the loaded track from after ReleaseOutputAsync; null while
+    // the output is open. Guarded by _stateLock.
+    private long? _releasedAtMs;
+
+    // Serializes load/stop so two overlapping PlayAsync calls can't both pass
+    // StopInternalAsync and leave an orphaned playback loop writing to the sink.
+    private readonly SemaphoreSlim _loadLock = new(1, 1);
     private long? _pendingSeekMs;
     private readonly object _seekLock = new();
     private bool _disposed;
@@ -68,9 +98,39 @@ public sealed class AudioEngine : IAsyncDisposable
     // 2 s with no UX impact.
     private const long PositionPublishIntervalMs = 5000;
 
+    // Nominal length of Spotify's public preview clips, shown until the decoder reports the real one.
+    private const long PreviewClipDurationMs = 30_000;
+
+    // Clips are ~500 KB; anything far larger isn't a preview and isn't buffered.
+    private const int MaxPreviewClipBytes = 4 * 1024 * 1024;
+
     // Per-seek sequence counter for [seek-trace] correlation across phases and components.
     private static int _seekTraceSeq;
 
+    // Stall watchdog — samples the sink position once a second while playing. A
+    // position frozen for longer than the timeout means the decode loop or the
+    // device callback is wedged; the loop is torn down and restarted from that
+    // position. Seeks and device switches move or keep advancing the position,
+    // so they never look like stalls. Restarts are capped per track so a
+    // permanently broken source ends in an error instead of a restart storm.
+    public const int DefaultStallTimeoutMs = 8000;
+    private const int StallCheckIntervalMs = 1000;
+    private const int MaxStallRestartsPerTrack = 3;
+    // Playback must get this far past a restart before the attempt counter resets.
+    private const long StallRecoveryMs = 30_000;
+
+    private readonly Subject<PipelineStall> _stallSubject = new();
+    private readonly Subject<AudioDeviceChange> _deviceChangedSubject = new();
+    private readonly Timer _stallWatchdog;
+    private int _stallTimeoutMs = DefaultStallTimeoutMs;
+    private AudioFetchParams _fetchParams = AudioFetchParams.Default;
+    private int _stallCheckRunning;
+    private string? _stallTrackUri;
+    private long _stallLastPositionMs = -1;
+    private long _stallLastProgressTs;
+    private long _stallRecoveredAtMs;
+    private int _stallRestarts;
+
     public AudioEngine(
         IAudioSink audioSink,
         AudioDecoderRegistry decoderRegistry,
@@ -90,12 +150,36 @@ public sealed class AudioEngine : IAsyncDisposable
         _audioCacheMaxBytes = audioCacheMaxBytes;
 
         if (audioCacheDirectory != null)
+        {
             Wavee.Playback.Contracts.AudioFileCache.EnsureDirectoryExists(audioCacheDirectory);
 
+            // Upgrading an old cache can take a while; untouched files are still
+            // converted on access in the meantime.
+            _ = Task.Run(() =>
+            {
+                try
+                {
+                    Wavee.Playback.Contracts.AudioCacheMigrations.Migrate(audioCacheDirectory, _logger);
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogWarning(ex, "Audio cache migration failed; will retry on next start");
+                }
+            });
+        }
+
         _stateSubject = new BehaviorSubject<EngineState>(_currentState);
 
         _volumeProcessor = volumeProcessor ?? _processingChain.Processors.OfType<VolumeProcessor>().FirstOrDefault();
         _userEq = _processingChain.Processors.OfType<EqualizerProcessor>().FirstOrDefault();
+        _channelMap = _processingChain.Processors.OfType<ChannelMapProcessor>().FirstOrDefault();
+        _pcmTap = _processingChain.Processors.OfType<PcmTapProcessor>().FirstOrDefault();
+        _outputAnalysis = _processingChain.Processors.OfType<OutputAnalysisProcessor>().FirstOrDefault();
+
+        _stallWatchdog = new Timer(_ => _ = CheckForStallAsync(), null, StallCheckIntervalMs, StallCheckIntervalMs);
+
+        if (_audioSink is IDeviceSelectableSink selectable)
+            selectable.DeviceChanged += OnOutputDeviceChanged;
     }
 
     /// <summary>Observable stream of engine state changes.</summary>
@@ -107,6 +191,20 @@ public sealed class AudioEngine : IAsyncDisposable
     /// <summary>Fires when a track plays to natural completion (NOT on cancellation/skip).</summary>
     public IObservable<string> TrackCompleted => _trackCompletedSubject;
 
+    /// <summary>Fires when the stall watchdog restarts, or gives up restarting, a frozen pipeline.</summary>
+    public IObservable<PipelineStall> PipelineStalls => _stallSubject;
+
+    /// <summary>Fires when the sink moves playback to another output device mid-track.</summary>
+    public IObservable<AudioDeviceChange> DeviceChanges => _deviceChangedSubject;
+
+    /// <summary>Visualization frames from the PCM tap; see <see cref="ConfigurePcmTap"/>.</summary>
+    public IObservable<PcmTapFrame> PcmTapFrames => _pcmTap?.Frames ?? Observable.Empty<PcmTapFrame>();
+
+    /// <summary>Sustained silence or clipping in the output; see <see cref="SetOutputDiagnostics"/>.</summary>
+    public IObservable<OutputDiagnostic> OutputDiagnostics => _outputAnalysis == null
+        ? Observable.Empty<OutputDiagnostic>()
+        : _outputAnalysis.Diagnostics.Select(d => d with { TrackUri = CurrentState.TrackUri });
+
     /// <summary>Current state snapshot.</summary>
     public EngineState CurrentState
     {
@@ -117,106 +215,181 @@ public sealed class AudioEngine : IAsyncDisposable
     /// <summary>Play with deferred CDN resolution — instant start from head data.</summary>
     public async Task PlayAsync(PlayTrackCommand cmd, Task<DeferredResult> deferredTask, CancellationToken ct = default)
     {
-        await StopInternalAsync();
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            await StopInternalAsync();
 
-        _logger?.LogInformation("Playing (deferred): {Title} by {Artist} [{Codec}]",
-            cmd.Metadata?.Title, cmd.Metadata?.Artist, cmd.Codec);
+            _logger?.LogInformation("Playing (deferred): {Title} by {Artist} [{Codec}]",
+                cmd.Metadata?.Title, cmd.Metadata?.Artist, cmd.Codec);
 
-        _playbackCts = new CancellationTokenSource();
-        var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _playbackCts = new CancellationTokenSource();
+            var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _restartLoop = (positionMs, token) => PlaybackLoopDeferredAsync(cmd, deferredTask, positionMs, token);
 
-        _playbackTask = Task.Run(async () =>
-        {
-            try
-            {
-                await PlaybackLoopDeferredAsync(cmd, deferredTask, linkedCts.Token);
-            }
-            catch (OperationCanceledException)
-            {
-                _logger?.LogDebug("[AudioEngine] Playback cancelled: {Title} ({Uri})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
-            }
-            catch (Exception ex)
+            _playbackTask = Task.Run(async () =>
             {
-                _logger?.LogError(ex, "[AudioEngine] Playback error for {Title} ({Uri})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
-                _errorSubject.OnNext(new EngineError(ex.Message, ex));
-            }
-            finally
-            {
-                linkedCts.Dispose();
-            }
-        }, CancellationToken.None);
+                try
+                {
+                    await PlaybackLoopDeferredAsync(cmd, deferredTask, cmd.PositionMs, linkedCts.Token);
+                }
+                catch (OperationCanceledException)
+                {
+                    _logger?.LogDebug("[AudioEngine] Playback cancelled: {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogError(ex, "[AudioEngine] Playback error for {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
+                    _errorSubject.OnNext(new EngineError(ex.Message, ex));
+                }
+                finally
+                {
+                    linkedCts.Dispose();
+                    _activePipeline = null;
+                }
+            }, CancellationToken.None);
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
     }
 
     /// <summary>Play a local audio file from disk. No CDN, no audio key, no Spotify metadata.</summary>
     public async Task PlayAsync(PlayLocalFileCommand cmd, CancellationToken ct = default)
     {
-        await StopInternalAsync();
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            await StopInternalAsync();
 
-        _logger?.LogInformation("Playing local file: {Title} ({Path})",
-            cmd.Metadata?.Title, cmd.FilePath);
+            _logger?.LogInformation("Playing local file: {Title} ({Path})",
+                cmd.Metadata?.Title, cmd.FilePath);
 
-        _playbackCts = new CancellationTokenSource();
-        var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _playbackCts = new CancellationTokenSource();
+            var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _restartLoop = (positionMs, token) => PlaybackLoopLocalAsync(cmd, positionMs, token);
 
-        _playbackTask = Task.Run(async () =>
-        {
-            try
+            _playbackTask = Task.Run(async () =>
             {
-                await PlaybackLoopLocalAsync(cmd, linkedCts.Token);
-            }
-            catch (OperationCanceledException)
-            {
-                _logger?.LogDebug("[AudioEngine] Local playback cancelled: {Title} ({Uri})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri);
-            }
-            catch (Exception ex)
-            {
-                _logger?.LogError(ex, "[AudioEngine] Local playback error for {Title} ({Path})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.FilePath);
-                _errorSubject.OnNext(new EngineError(ex.Message, ex));
-            }
-            finally
+                try
+                {
+                    await PlaybackLoopLocalAsync(cmd, cmd.StartPositionMs, linkedCts.Token);
+                }
+                catch (OperationCanceledException)
+                {
+                    _logger?.LogDebug("[AudioEngine] Local playback cancelled: {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri);
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogError(ex, "[AudioEngine] Local playback error for {Title} ({Path})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.FilePath);
+                    _errorSubject.OnNext(new EngineError(ex.Message, ex));
+                }
+                finally
+                {
+                    linkedCts.Dispose();
+                    _activePipeline = null;
+                }
+            }, CancellationToken.None);
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
+    }
+
+    /// <summary>Play a public 30-second preview clip over HTTP. No session, audio key or CDN resolution.</summary>
+    public async Task PlayAsync(PlayPreviewCommand cmd, CancellationToken ct = default)
+    {
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            await StopInternalAsync();
+
+            _logger?.LogInformation("Playing preview: {Title} ({Uri})",
+                cmd.Metadata?.Title, cmd.TrackUri);
+
+            _playbackCts = new CancellationTokenSource();
+            var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _restartLoop = (positionMs, token) => PlaybackLoopPreviewAsync(cmd, positionMs, token);
+
+            _playbackTask = Task.Run(async () =>
             {
-                linkedCts.Dispose();
-            }
-        }, CancellationToken.None);
+                try
+                {
+                    await PlaybackLoopPreviewAsync(cmd, cmd.StartPositionMs, linkedCts.Token);
+                }
+                catch (OperationCanceledException)
+                {
+                    _logger?.LogDebug("[AudioEngine] Preview playback cancelled: {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri);
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogError(ex, "[AudioEngine] Preview playback error for {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri);
+                    _errorSubject.OnNext(new EngineError(ex.Message, ex));
+                }
+                finally
+                {
+                    linkedCts.Dispose();
+                    _activePipeline = null;
+                }
+            }, CancellationToken.None);
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
     }
 
     public async Task PlayAsync(PlayResolvedTrackCommand cmd, CancellationToken ct = default)
     {
-        // Stop any current playback
-        await StopInternalAsync();
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            // Stop any current playback
+            await StopInternalAsync();
 
-        _logger?.LogInformation("Playing: {Title} by {Artist} [{Codec}]",
-            cmd.Metadata?.Title, cmd.Metadata?.Artist, cmd.Codec);
+            _logger?.LogInformation("Playing: {Title} by {Artist} [{Codec}]",
+                cmd.Metadata?.Title, cmd.Metadata?.Artist, cmd.Codec);
 
-        _playbackCts = new CancellationTokenSource();
-        var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _playbackCts = new CancellationTokenSource();
+            var linkedCts = CancellationTokenSource.CreateLinkedTokenSource(_playbackCts.Token, ct);
+            _restartLoop = (positionMs, token) => PlaybackLoopAsync(cmd, positionMs, token);
 
-        _playbackTask = Task.Run(async () =>
-        {
-            try
-            {
-                await PlaybackLoopAsync(cmd, linkedCts.Token);
-            }
-            catch (OperationCanceledException)
-            {
-                _logger?.LogDebug("[AudioEngine] Playback cancelled: {Title} ({Uri})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
-            }
-            catch (Exception ex)
-            {
-                _logger?.LogError(ex, "[AudioEngine] Playback error for {Title} ({Uri})",
-                    cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
-                _errorSubject.OnNext(new EngineError(ex.Message, ex));
-            }
-            finally
+            _playbackTask = Task.Run(async () =>
             {
-                linkedCts.Dispose();
-            }
-        }, CancellationToken.None);
+                try
+                {
+                    await PlaybackLoopAsync(cmd, cmd.PositionMs, linkedCts.Token);
+                }
+                catch (OperationCanceledException)
+                {
+                    _logger?.LogDebug("[AudioEngine] Playback cancelled: {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogError(ex, "[AudioEngine] Playback error for {Title} ({Uri})",
+                        cmd.Metadata?.Title ?? "<unknown>", cmd.TrackUri ?? "<none>");
+                    _errorSubject.OnNext(new EngineError(ex.Message, ex));
+                }
+                finally
+                {
+                    linkedCts.Dispose();
+                    _activePipeline = null;
+                }
+            }, CancellationToken.None);
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
     }
 
     public async Task PauseAsync(CancellationToken ct = default)
@@ -228,7 +401,7 @@ public sealed class AudioEngine : IAsyncDisposable
             {
                 IsPlaying = false,
                 IsPaused  = true,
-                PositionMs = _audioSink.PlaybackPositionMs,
+                PositionMs = HeardPositionMs,
                 Timestamp  = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
             };
         }
@@ -237,6 +410,17 @@ public sealed class AudioEngine : IAsyncDisposable
 
     public async Task ResumeAsync(CancellationToken ct = default)
     {
+        long? releasedAtMs;
+        lock (_stateLock)
+        {
+            releasedAtMs = _releasedAtMs;
+            _releasedAtMs = null;
+        }
+
+        // Output was released while paused: rebuild the pipeline, which reopens the sink.
+        if (releasedAtMs is { } resumeAtMs && await RestartPipelineAsync(CurrentState.TrackUri, resumeAtMs))
+            return;
+
         await _audioSink.ResumeAsync();
         lock (_stateLock)
         {
@@ -244,7 +428,7 @@ public sealed class AudioEngine : IAsyncDisposable
             {
                 IsPlaying  = true,
                 IsPaused   = false,
-                PositionMs = _audioSink.PlaybackPositionMs,
+                PositionMs = HeardPositionMs,
                 Timestamp  = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
             };
         }
@@ -253,6 +437,18 @@ public sealed class AudioEngine : IAsyncDisposable
 
     public Task SeekAsync(long positionMs, CancellationToken ct = default)
     {
+        // Clamp to the track: a target past the end would make the decoder's
+        // granule bisection run off the last page and stall instead of ending.
+        var durationMs = CurrentState.DurationMs;
+        positionMs = Math.Max(0, durationMs > 0 ? Math.Min(positionMs, durationMs) : positionMs);
+
+        lock (_stateLock)
+        {
+            // No loop to consume the seek while released; move the restart point instead.
+            if (_releasedAtMs != null)
+                _releasedAtMs = positionMs;
+        }
+
         lock (_seekLock)
             _pendingSeekMs = positionMs;
         // Visibility log so we can correlate "Seek confirmation timed out" UI errors with
@@ -264,7 +460,21 @@ public sealed class AudioEngine : IAsyncDisposable
 
     public async Task StopAsync(CancellationToken ct = default)
     {
-        await StopInternalAsync();
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            // Ramp audible output down before the buffer is flushed.
+            var state = CurrentState;
+            if (state.IsPlaying && !state.IsPaused && _audioSink is ITransportFadeSink)
+                await _audioSink.PauseAsync();
+            await StopInternalAsync();
+            _restartLoop = null;
+            _loopRegion.Reset();
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
         lock (_stateLock)
         {
             _currentState = EngineState.Empty;
@@ -272,6 +482,41 @@ public sealed class AudioEngine : IAsyncDisposable
         PublishState();
     }
 
+    /// <summary>
+    /// Tears down the playback loop and closes the output stream so the device and its
+    /// buffers are freed while idle. A paused track stays loaded in the published state;
+    /// <see cref="ResumeAsync"/> restarts its pipeline at the paused position. Ignored
+    /// while audio is playing.
+    /// </summary>
+    public async Task ReleaseOutputAsync(CancellationToken ct = default)
+    {
+        await _loadLock.WaitAsync(ct);
+        try
+        {
+            var state = CurrentState;
+            if (state.IsPlaying && !state.IsPaused)
+            {
+                _logger?.LogDebug("[AudioEngine] Release ignored: playback is active");
+                return;
+            }
+
+            long? resumeAtMs = _restartLoop != null && state.TrackUri != null
+                ? HeardPositionMs
+                : null;
+
+            await StopInternalAsync();
+            await _audioSink.ReleaseAsync();
+
+            lock (_stateLock) _releasedAtMs = resumeAtMs;
+            _logger?.LogInformation("[AudioEngine] Output released (resume at {Position}ms)",
+                resumeAtMs?.ToString() ?? "<none>");
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
+    }
+
     public Task SetVolumeAsync(float volume, CancellationToken ct = default)
     {
         if (_volumeProcessor != null) _volumeProcessor.Volume = volume;
@@ -289,6 +534,315 @@ public sealed class AudioEngine : IAsyncDisposable
             p.IsEnabled = enabled;
     }
 
+    public void ConfigureNormalization(NormalizationSettings settings)
+    {
+        foreach (var p in _processingChain.Processors.OfType<NormalizationProcessor>())
+            p.Configure(settings);
+    }
+
+    /// <summary>
+    /// Sets the policy that picks an output device at the start of each track,
+    /// or null to always play on the device the sink opens (the system default).
+    /// Takes effect from the next track. Ignored when the sink can't switch devices.
+    /// </summary>
+    public void SetRoutingPolicy(AudioRoutingPolicy? policy) => _routingPolicy = policy;
+
+    /// <summary>
+    /// Describes the active chain — fetcher, decryptor, decoder, DSP stages and
+    /// sink — with the format each stage emits and buffer levels, for diagnosing
+    /// format negotiation and buffering problems. Returns an empty chain when idle.
+    /// </summary>
+    /// <remarks>
+    /// The fetcher and decryptor carry encoded bytes and report no format. The decryptor
+    /// is listed only once one is actually decrypting.
+    /// </remarks>
+    public async Task<PipelineInfo> GetPipelineInfoAsync()
+    {
+        var pipeline = _activePipeline;
+        if (pipeline == null)
+            return new PipelineInfo { BufferPools = GetBufferPoolInfo() };
+
+        var chainFormat = _processingChain.Format is { } initialized ? ToFormatInfo(initialized) : null;
+        var sinkFormat = _audioSink.Format;
+        var stages = new List<PipelineStageInfo>
+        {
+            new()
+            {
+                Kind = "fetcher",
+                Name = pipeline.FetcherName,
+                Detail = pipeline.FetcherDetail(),
+                BufferedBytes = pipeline.FetchedBytes?.Invoke()
+            }
+        };
+
+        if (pipeline.Decryption?.Invoke() is { } decryption)
+            stages.Add(new PipelineStageInfo { Kind = "decryptor", Name = "AudioDecryptStream", Detail = decryption });
+
+        stages.Add(new PipelineStageInfo
+        {
+            Kind = "decoder",
+            Name = pipeline.DecoderName,
+            Format = ToFormatInfo(pipeline.Format),
+            Detail = pipeline.Codec
+        });
+
+        foreach (var processor in _processingChain.Processors)
+        {
+            stages.Add(new PipelineStageInfo
+            {
+                Kind = "processor",
+                Name = processor.ProcessorName,
+                Enabled = processor.IsEnabled && !_processingChain.Bypass,
+                Format = chainFormat
+            });
+        }
+
+        var sinkStatus = await _audioSink.GetStatusAsync();
+        stages.Add(new PipelineStageInfo
+        {
+            Kind = "sink",
+            Name = _audioSink.SinkName,
+            Format = sinkFormat is null ? null : ToFormatInfo(sinkFormat),
+            BufferedMs = sinkStatus.BufferedMs,
+            BufferedBytes = sinkFormat is null ? null : sinkStatus.BufferedMs * (long)sinkFormat.BytesPerSecond / 1000,
+            Detail = (_audioSink as IDeviceSelectableSink)?.CurrentDeviceName
+        });
+
+        return new PipelineInfo { TrackUri = pipeline.TrackUri, Stages = stages.ToArray(), BufferPools = GetBufferPoolInfo() };
+    }
+
+    private static BufferPoolInfo[] GetBufferPoolInfo() =>
+    [
+        ToBufferPoolInfo(SlabBufferPool.Packets.GetStatistics()),
+        ToBufferPoolInfo(SlabBufferPool.Chunks.GetStatistics())
+    ];
+
+    private static BufferPoolInfo ToBufferPoolInfo(BufferPoolStatistics stats) => new()
+    {
+        Name = stats.Name,
+        Rents = stats.Rents,
+        Hits = stats.Hits,
+        Misses = stats.Misses,
+        Oversize = stats.Oversize,
+        HitRate = stats.HitRate,
+        RetainedBytes = stats.RetainedBytes
+    };
+
+    private static string? DescribeDecryption(AesBackend? backend) =>
+        backend is { } aes ? $"AES-128-CTR, {aes}" : null;
+
+    private static PcmFormatInfo ToFormatInfo(AudioFormat format) => new()
+    {
+        SampleRate = format.SampleRate,
+        Channels = format.Channels,
+        BitsPerSample = format.BitsPerSample
+    };
+
+    /// <summary>
+    /// Turns on the analysis that reports sustained digital silence and clipping on
+    /// <see cref="OutputDiagnostics"/>. Nothing is analysed in bit-perfect mode.
+    /// </summary>
+    /// <exception cref="NotSupportedException">The chain was built without the analysis stage.</exception>
+    public void SetOutputDiagnostics(bool enabled)
+    {
+        if (_outputAnalysis == null)
+            throw new NotSupportedException("The processing chain has no output analysis");
+
+        _outputAnalysis.IsEnabled = enabled;
+        _logger?.LogInformation("[AudioEngine] Output diagnostics {State}", enabled ? "on" : "off");
+    }
+
+    /// <summary>
+    /// Enables bit-perfect playback: the processing chain (normalization, EQ,
+    /// channel layout, dynamics) and sink-side volume and ramps are bypassed, and
+    /// the sink refuses devices that don't run at the track's native format.
+    /// Processing changes apply immediately; the format check from the next track.
+    /// </summary>
+    /// <exception cref="NotSupportedException">The sink can't guarantee bit-perfect output.</exception>
+    public void SetBitPerfect(bool enabled)
+    {
+        if (_audioSink is not IBitPerfectSink bitPerfectSink)
+        {
+            if (enabled)
+                throw new NotSupportedException($"{_audioSink.SinkName} sink does not support bit-perfect output");
+            return;
+        }
+
+        bitPerfectSink.BitPerfect = enabled;
+        _processingChain.Bypass = enabled;
+        _logger?.LogInformation("[AudioEngine] Bit-perfect output {State}", enabled ? "enabled" : "disabled");
+    }
+
+    /// <summary>
+    /// Sets the channel layout for an output device (null for every device without
+    /// its own layout) and applies it if that's the device playing now.
+    /// </summary>
+    public void SetChannelLayout(string? deviceName, ChannelLayoutMode mode, int[]? channelMap)
+    {
+        if (mode == ChannelLayoutMode.Custom && (channelMap == null || channelMap.Length == 0))
+            throw new ArgumentException("A custom layout needs a channel map", nameof(channelMap));
+
+        lock (_channelLayouts)
+        {
+            var key = deviceName ?? string.Empty;
+            if (mode == ChannelLayoutMode.Default && key.Length > 0)
+                _channelLayouts.Remove(key);
+            else
+                _channelLayouts[key] = (mode, channelMap);
+        }
+        RefreshChannelLayout();
+    }
+
+    /// <summary>
+    /// Re-applies the channel layout for the sink's current device. Call after
+    /// the output device changes.
+    /// </summary>
+    public void RefreshChannelLayout()
+    {
+        if (_channelMap == null)
+            return;
+
+        var deviceName = (_audioSink as IDeviceSelectableSink)?.CurrentDeviceName;
+        lock (_channelLayouts)
+        {
+            if ((deviceName != null && _channelLayouts.TryGetValue(deviceName, out var layout))
+                || _channelLayouts.TryGetValue(string.Empty, out layout))
+                _channelMap.Configure(layout.Mode, layout.Map);
+            else
+                _channelMap.Configure(ChannelLayoutMode.Default);
+        }
+    }
+
+    /// <summary>
+    /// Sets an A-B loop on the loaded track (<paramref name="trackUri"/>): playback
+    /// returns from <paramref name="endMs"/> to <paramref name="startMs"/>, splicing at the
+    /// exact frames without flushing the output. Cleared when another track loads.
+    /// </summary>
+    /// <returns>False when <paramref name="trackUri"/> isn't playing, the bounds are invalid,
+    /// or the track plays through a path without loop support.</returns>
+    public bool SetLoopRegion(string trackUri, long startMs, long endMs)
+    {
+        var heardMs = HeardPositionMs;
+        var spliced = _loopRegion.HasSpliced;
+        if (!_loopRegion.Set(trackUri, startMs, endMs))
+            return false;
+        if (spliced)
+            _audioSink.SetBasePosition(heardMs);
+
+        // The decoder runs up to the sink's buffer ahead of what is heard, so an end
+        // set at (or just after) the current position may already be decoded. Re-decode
+        // from the heard position so the loop still closes at the end.
+        if (_loopRegion.IsDecodedPast(endMs))
+            _ = SeekAsync(heardMs < endMs ? heardMs : startMs);
+
+        _logger?.LogInformation("[AudioEngine] Loop {Start}ms-{End}ms on {Uri}", startMs, endMs, trackUri);
+        return true;
+    }
+
+    /// <summary>
+    /// Removes the A-B loop; playback continues past its end.
+    /// </summary>
+    public void ClearLoopRegion()
+    {
+        var heardMs = HeardPositionMs;
+        var spliced = _loopRegion.HasSpliced;
+        _loopRegion.Clear();
+        if (spliced)
+            _audioSink.SetBasePosition(heardMs);
+    }
+
+    /// <summary>
+    /// Position being heard: the sink's position, wrapped back into the A-B loop once
+    /// the written audio has gone round it.
+    /// </summary>
+    private long HeardPositionMs => _loopRegion.MapPosition(_audioSink.PlaybackPositionMs);
+
+    // Every jump of the decode position goes through here so the loop region counts
+    // frames from the same place as the sink.
+    private void SetBasePosition(long positionMs)
+    {
+        _audioSink.SetBasePosition(positionMs);
+        _loopRegion.Rebase(positionMs);
+        RebaseAnalysis(positionMs);
+    }
+
+    // The PCM tap and output analysis stamp what they see with track positions.
+    private void RebaseAnalysis(long positionMs)
+    {
+        _pcmTap?.Rebase(positionMs);
+        _outputAnalysis?.Rebase(positionMs);
+    }
+
+    /// <summary>
+    /// Refreshes the playing position after a write, publishing on the usual cadence
+    /// and straight away when the position wraps back to the loop start.
+    /// </summary>
+    private void UpdatePlayingPosition(ref long lastPublishMs, ref long lastHeardMs)
+    {
+        var sinkPositionMs = _audioSink.PlaybackPositionMs;
+        var positionMs = _loopRegion.MapPosition(sinkPositionMs);
+        lock (_stateLock)
+        {
+            _currentState = _currentState with
+            {
+                PositionMs = positionMs,
+                IsPlaying = true,
+                IsPaused = false,
+                Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
+            };
+        }
+
+        var wrapped = positionMs < lastHeardMs;
+        lastHeardMs = positionMs;
+        if (wrapped || sinkPositionMs - lastPublishMs >= PositionPublishIntervalMs)
+        {
+            lastPublishMs = sinkPositionMs;
+            PublishState();
+        }
+    }
+
+    /// <summary>
+    /// Configures the visualization tap at the end of the processing chain.
+    /// </summary>
+    /// <exception cref="NotSupportedException">The chain was built without a tap.</exception>
+    public void ConfigurePcmTap(PcmTapMode mode, int rateHz, int size)
+    {
+        if (_pcmTap == null)
+            throw new NotSupportedException("The processing chain has no PCM tap");
+
+        _pcmTap.Configure(mode, rateHz, size);
+        _logger?.LogInformation("[AudioEngine] PCM tap: {Mode} at {Rate} Hz, {Size} values",
+            mode, _pcmTap.RateHz, _pcmTap.Size);
+    }
+
+    /// <summary>
+    /// Sets the transport volume ramps. Ignored when the sink can't fade.
+    /// </summary>
+    public void SetTransportFade(int fadeInMs, int fadeOutMs)
+    {
+        if (_audioSink is not ITransportFadeSink fading)
+            return;
+        fading.FadeInMs = fadeInMs;
+        fading.FadeOutMs = fadeOutMs;
+    }
+
+    /// <summary>
+    /// Sets how long position may stay frozen while playing before the pipeline is
+    /// restarted. 0 disables the watchdog. Range: 0ms, or 2000ms to 60000ms
+    /// </summary>
+    public void SetStallTimeout(int timeoutMs)
+        => Volatile.Write(ref _stallTimeoutMs, timeoutMs <= 0 ? 0 : Math.Clamp(timeoutMs, 2000, 60_000));
+
+    /// <summary>
+    /// Sets the timeout for a single CDN range request. Applies from the next track.
+    /// Range: 1000ms to 120000ms
+    /// </summary>
+    public void SetCdnRequestTimeout(int timeoutMs)
+        => Volatile.Write(ref _fetchParams, AudioFetchParams.Default with
+        {
+            RequestTimeout = TimeSpan.FromMilliseconds(Math.Clamp(timeoutMs, 1000, 120_000))
+        });
+
     public async Task<EqualizerApplyResult?> SetEqualizerEnabledAsync(
         bool enabled,
         double[]? bandGains = null,
@@ -335,24 +889,89 @@ public sealed class AudioEngine : IAsyncDisposable
 
     // ── Deferred playback loop (instant start from head data) ──
 
-    private IAudioDecoder? FindDecoderForCodec(string? codec, Stream stream, out Stream decodingStream)
+    /// <summary>
+    /// Runs the routing policy for a track that is about to start. Called after
+    /// <see cref="IAudioSink.InitializeAsync"/>, which reopens on the system default,
+    /// and before any PCM is written so the switch doesn't drop audio.
+    /// </summary>
+    private async Task ApplyRoutingAsync(string trackUri, CancellationToken ct)
+    {
+        var policy = _routingPolicy;
+        if (policy == null || _audioSink is not IDeviceSelectableSink selectable)
+            return;
+
+        try
+        {
+            var request = new AudioRouteRequest(
+                trackUri,
+                AudioRouting.Classify(trackUri),
+                selectable.CurrentDeviceName,
+                selectable.EnumerateOutputDevices());
+            var device = policy(request);
+            if (device == null || string.Equals(device.Name, request.CurrentDeviceName, StringComparison.Ordinal))
+                return;
+
+            _logger?.LogInformation("Routing {ContentKind} track {TrackUri} to {Device}",
+                request.ContentKind, trackUri, device.Name);
+            await selectable.SwitchToDeviceAsync(device.DeviceIndex, ct);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            // A bad route shouldn't stop playback — stay on the current device.
+            _logger?.LogWarning(ex, "Audio routing failed for {TrackUri}; keeping current device", trackUri);
+        }
+    }
+
+    /// <summary>
+    /// Parses a track's decoder headers from its head data and decodes the first packet,
+    /// so the decoder is loaded and JIT-compiled before the real play. Runs beside current
+    /// playback without touching it or the sink. Failures are logged, not thrown: the
+    /// play that follows reports them properly.
+    /// </summary>
+    public async Task PrewarmAsync(PrewarmTrackCommand cmd, CancellationToken ct = default)
     {
-        if (string.Equals(codec, "vorbis", StringComparison.OrdinalIgnoreCase))
+        try
         {
-            var decoder = _decoderRegistry.FindDecoderByFormatName("Vorbis");
-            if (decoder != null)
+            var startTs = Stopwatch.GetTimestamp();
+            using var headStream = new MemoryStream(Convert.FromBase64String(cmd.HeadData), writable: false);
+            var decoder = FindDecoderForCodec(cmd.Codec, headStream, out var decodingStream);
+            if (decoder == null)
+            {
+                _logger?.LogDebug("[AudioEngine] Prewarm: no decoder for codec {Codec} ({Uri})", cmd.Codec, cmd.TrackUri);
+                return;
+            }
+
+            var audioFormat = await decoder.GetFormatAsync(decodingStream, ct);
+            await foreach (var buffer in decoder.DecodeAsync(decodingStream, 0, null, ct))
             {
-                decodingStream = stream;
-                return decoder;
+                buffer.Return();
+                break;
             }
+
+            _logger?.LogDebug("[AudioEngine] Prewarmed {Decoder} for {Uri}: {SampleRate}Hz {Channels}ch in {Elapsed:F1}ms",
+                decoder.FormatName, cmd.TrackUri, audioFormat.SampleRate, audioFormat.Channels,
+                Stopwatch.GetElapsedTime(startTs).TotalMilliseconds);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogDebug(ex, "[AudioEngine] Prewarm failed for {Uri} (non-fatal)", cmd.TrackUri);
+        }
+    }
+
+    private IAudioDecoder? FindDecoderForCodec(string? codec, Stream stream, out Stream decodingStream)
+    {
+        var decoder = _decoderRegistry.FindDecoderForFormat(codec);
+        if (decoder != null)
+        {
+            decodingStream = stream;
+            return decoder;
         }
 
         return _decoderRegistry.FindDecoder(stream, out decodingStream);
     }
 
-    private async Task PlaybackLoopDeferredAsync(PlayTrackCommand cmd, Task<DeferredResult> deferredTask, CancellationToken ct)
+    private async Task PlaybackLoopDeferredAsync(PlayTrackCommand cmd, Task<DeferredResult> deferredTask, long startPositionMs, CancellationToken ct)
     {
-        var startPositionMs = cmd.PositionMs;
 
         // Update state to buffering
         lock (_stateLock)
@@ -392,7 +1011,10 @@ public sealed class AudioEngine : IAsyncDisposable
                 System.Text.Encoding.UTF8.GetBytes(cmd.TrackUri ?? "")));
         var fileId = FileId.FromBase16(trackHash.ToLowerInvariant());
 
-        var lazyStream = new LazyProgressiveDownloader(
+        // Owned by this loop: disposing on exit (normal end, error or preemption)
+        // cancels in-flight CDN range fetches and deletes the temp file instead of
+        // leaving them to the finalizer.
+        await using var lazyStream = new LazyProgressiveDownloader(
             headData ?? Array.Empty<byte>(),
             deferredTask,
             _httpClient,
@@ -400,7 +1022,9 @@ public sealed class AudioEngine : IAsyncDisposable
             _logger,
             _audioCacheDirectory,
             audioCacheMaxBytes: _audioCacheMaxBytes,
+            fetchParams: Volatile.Read(ref _fetchParams),
             playbackToken: ct);
+        _trackStats = new TrackStatsCounters(_audioSink.UnderrunCount, lazyStream);
 
         // Read normalization from head data if available
         if (cmd.NormalizationGain.HasValue)
@@ -411,7 +1035,9 @@ public sealed class AudioEngine : IAsyncDisposable
                 {
                     Uri = cmd.TrackUri,
                     ReplayGainTrackGain = cmd.NormalizationGain.Value,
-                    ReplayGainTrackPeak = cmd.NormalizationPeak ?? 1.0f
+                    ReplayGainTrackPeak = cmd.NormalizationPeak ?? 1.0f,
+                    ReplayGainAlbumGain = cmd.NormalizationAlbumGain,
+                    ReplayGainAlbumPeak = cmd.NormalizationAlbumPeak
                 };
                 proc.SetTrackGain(meta);
             }
@@ -427,9 +1053,18 @@ public sealed class AudioEngine : IAsyncDisposable
         var audioFormat = await decoder.GetFormatAsync(decodingStream, ct);
         _logger?.LogDebug("Audio format: {SampleRate}Hz {Channels}ch {Bits}bit",
             audioFormat.SampleRate, audioFormat.Channels, audioFormat.BitsPerSample);
+        _activePipeline = new ActivePipeline(
+            cmd.TrackUri, "LazyProgressiveDownloader",
+            () => lazyStream.IsFromCache ? "audio cache" : "head data + CDN range requests",
+            () => lazyStream.BytesDownloaded,
+            () => DescribeDecryption(lazyStream.DecryptionBackend),
+            decoder.FormatName, cmd.Codec, audioFormat);
 
         await _audioSink.InitializeAsync(audioFormat, bufferSizeMs: 2000, ct);
-        _audioSink.SetBasePosition(startPositionMs);
+        _loopRegion.Start(cmd.TrackUri, audioFormat, startPositionMs);
+        SetBasePosition(startPositionMs);
+        await ApplyRoutingAsync(cmd.TrackUri, ct);
+        RefreshChannelLayout();
         await _processingChain.InitializeAsync(audioFormat, ct);
 
         // Now playing
@@ -460,6 +1095,7 @@ public sealed class AudioEngine : IAsyncDisposable
         bool seekTelemetryPending = false;
         long pendingSeekTargetMs = 0;
 
+        long lastHeardMs = startPositionMs;
         long iterCount = 0;
         long lastIterLogTs = Stopwatch.GetTimestamp();
 
@@ -476,10 +1112,10 @@ public sealed class AudioEngine : IAsyncDisposable
         while (true)
         {
             needsRecreate = false;
-        await foreach (var buffer in decoder.DecodeAsync(
+        await foreach (var buffer in TimeDecodeAsync(decoder.DecodeAsync(
             decodingStream, currentStartPos,
             title => { lock (_stateLock) _currentState = _currentState with { Title = title }; PublishState(); },
-            ct))
+            ct), _trackStats))
         {
             iterCount++;
             // Heartbeat: log iteration count once per ~3 s so we can tell from logs whether
@@ -542,7 +1178,7 @@ public sealed class AudioEngine : IAsyncDisposable
 
                     buffer.Return();
                     await _audioSink.FlushAsync();
-                    _audioSink.SetBasePosition(seekTarget.Value);
+                    SetBasePosition(seekTarget.Value);
                     lastPublishMs = seekTarget.Value;
                     currentStartPos = seekTarget.Value;
                     lastForwardSeekTargetMs = -1; // fresh decoder gets dense _pageOffsets
@@ -577,7 +1213,7 @@ public sealed class AudioEngine : IAsyncDisposable
                 seekFlushDoneTs = Stopwatch.GetTimestamp();
                 _logger?.LogDebug("[seek-trace] seq={Seq} flush done elapsed={Ms:F1}ms",
                     seq, TicksToMs(seekStartTs, seekFlushDoneTs));
-                _audioSink.SetBasePosition(seekTarget.Value);
+                SetBasePosition(seekTarget.Value);
                 lastPublishMs = seekTarget.Value;
 
                 // Predictive prefetch for the bisection's convergence region. Runs for
@@ -644,7 +1280,8 @@ public sealed class AudioEngine : IAsyncDisposable
                     TicksToMs(seekDecoderDoneTs, firstDecodeTs));
             }
             var writeStartTs = Stopwatch.GetTimestamp();
-            await _audioSink.WriteAsync(processed.Data, ct);
+            var writeLength = _loopRegion.Clip(processed.Data.Length, out var loopStartMs);
+            await _audioSink.WriteAsync(processed.Data[..writeLength], ct);
             // If WriteAsync is suspiciously slow (sink ring buffer full + device
             // truly stalled, not just normal back-pressure), log it. Normal writes
             // at 1× playback against a 2-s ring buffer block ~150 ms each when the
@@ -674,23 +1311,23 @@ public sealed class AudioEngine : IAsyncDisposable
             processed.Return();
             if (!ReferenceEquals(processed, buffer)) buffer.Return();
 
-            var positionMs = _audioSink.PlaybackPositionMs;
-            lock (_stateLock)
+            if (loopStartMs is { } loopStart)
             {
-                _currentState = _currentState with
+                // Same hazard as a backward seek across a materialized page: take the
+                // seek path (flush + decoder recreate) once; later passes are seamless.
+                if (lastForwardSeekTargetMs > 0 && loopStart < lastForwardSeekTargetMs)
                 {
-                    PositionMs = positionMs,
-                    IsPlaying = true,
-                    IsPaused = false,
-                    Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
-                };
+                    lock (_seekLock) _pendingSeekMs ??= loopStart;
+                }
+                else
+                {
+                    decoder.SeekTo(loopStart);
+                    lazyStream.NotifySeek();
+                    RebaseAnalysis(loopStart);
+                }
             }
 
-            if (positionMs - lastPublishMs >= PositionPublishIntervalMs)
-            {
-                lastPublishMs = positionMs;
-                PublishState();
-            }
+            UpdatePlayingPosition(ref lastPublishMs, ref lastHeardMs);
         }
 
             if (!needsRecreate) break; // normal completion — exit the recreate while loop
@@ -708,7 +1345,7 @@ public sealed class AudioEngine : IAsyncDisposable
 
         await _audioSink.DrainAsync(ct);
 
-        var finalPosition = _audioSink.PlaybackPositionMs;
+        var finalPosition = HeardPositionMs;
         lock (_stateLock)
         {
             _currentState = _currentState with
@@ -732,15 +1369,32 @@ public sealed class AudioEngine : IAsyncDisposable
         return (endTs - startTs) * 1000d / Stopwatch.Frequency;
     }
 
+    /// <summary>
+    /// Passes decoder output through unchanged while accumulating the time spent
+    /// producing each buffer. That includes any wait on the underlying stream, so
+    /// a download stall shows up as decode time.
+    /// </summary>
+    private static async IAsyncEnumerable<AudioBuffer> TimeDecodeAsync(
+        IAsyncEnumerable<AudioBuffer> source, TrackStatsCounters stats)
+    {
+        await using var enumerator = source.GetAsyncEnumerator();
+        while (true)
+        {
+            var startTs = Stopwatch.GetTimestamp();
+            if (!await enumerator.MoveNextAsync())
+                yield break;
+            stats.AddDecodeTicks(Stopwatch.GetTimestamp() - startTs);
+            yield return enumerator.Current;
+        }
+    }
+
     // ── Local-file playback loop ──
 
-    private async Task PlaybackLoopLocalAsync(PlayLocalFileCommand cmd, CancellationToken ct)
+    private async Task PlaybackLoopLocalAsync(PlayLocalFileCommand cmd, long startPositionMs, CancellationToken ct)
     {
         if (!File.Exists(cmd.FilePath))
             throw new FileNotFoundException("Local audio file no longer exists.", cmd.FilePath);
 
-        var startPositionMs = cmd.StartPositionMs;
-
         lock (_stateLock)
         {
             _currentState = new EngineState
@@ -774,8 +1428,12 @@ public sealed class AudioEngine : IAsyncDisposable
             FileMode.Open, FileAccess.Read, FileShare.Read,
             bufferSize: 4096, useAsync: true);
         await using var localStream = new LocalFilePathStream(fileStream, cmd.FilePath);
+        _trackStats = new TrackStatsCounters(_audioSink.UnderrunCount);
 
-        var decoder = _decoderRegistry.FindDecoder(localStream, out var decodingStream);
+        // A decoder registered for the extension (e.g. a FLAC plug-in) wins over sniffing.
+        Stream decodingStream = localStream;
+        var decoder = _decoderRegistry.FindDecoderForFormat(Path.GetExtension(cmd.FilePath).TrimStart('.'))
+                      ?? _decoderRegistry.FindDecoder(localStream, out decodingStream);
         if (decoder == null)
             throw new NotSupportedException(
                 $"No decoder accepted local file {Path.GetExtension(cmd.FilePath)}: {cmd.FilePath}");
@@ -785,20 +1443,118 @@ public sealed class AudioEngine : IAsyncDisposable
         var audioFormat = await decoder.GetFormatAsync(decodingStream, ct);
         _logger?.LogDebug("Local audio format: {SampleRate}Hz {Channels}ch {Bits}bit",
             audioFormat.SampleRate, audioFormat.Channels, audioFormat.BitsPerSample);
+        _activePipeline = new ActivePipeline(
+            cmd.TrackUri, "LocalFile", () => cmd.FilePath, null, null,
+            decoder.FormatName, Path.GetExtension(cmd.FilePath).TrimStart('.'), audioFormat);
+
+        await PlayDecodedAsync(cmd.TrackUri, decoder, decodingStream, audioFormat, cmd.Normalization, startPositionMs, ct);
+    }
+
+    private async Task PlaybackLoopPreviewAsync(PlayPreviewCommand cmd, long startPositionMs, CancellationToken ct)
+    {
+        lock (_stateLock)
+        {
+            _currentState = new EngineState
+            {
+                TrackUri = cmd.TrackUri,
+                Title = cmd.Metadata?.Title,
+                Artist = cmd.Metadata?.Artist,
+                Album = cmd.Metadata?.Album,
+                AlbumUri = cmd.Metadata?.AlbumUri,
+                ArtistUri = cmd.Metadata?.ArtistUri,
+                ImageUrl = cmd.Metadata?.ImageUrl,
+                ImageLargeUrl = cmd.Metadata?.ImageLargeUrl,
+                DurationMs = PreviewClipDurationMs,
+                PositionMs = startPositionMs,
+                IsPlaying = false,
+                IsPaused = false,
+                IsBuffering = true,
+                Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
+            };
+        }
+        PublishState();
+
+        // Clips are small, so fetch the whole file: seeking then never waits on the network.
+        var bytes = await DownloadPreviewClipAsync(cmd.PreviewUrl, ct);
+        await using var previewStream = new MemoryStream(bytes, writable: false);
+        _trackStats = new TrackStatsCounters(_audioSink.UnderrunCount);
+
+        Stream decodingStream = previewStream;
+        var decoder = _decoderRegistry.FindDecoderForFormat("mp3")
+                      ?? _decoderRegistry.FindDecoder(previewStream, out decodingStream);
+        if (decoder == null)
+            throw new NotSupportedException($"No decoder accepted preview clip: {cmd.PreviewUrl}");
+
+        var audioFormat = await decoder.GetFormatAsync(decodingStream, ct);
+        if (await decoder.GetDurationMsAsync(decodingStream, ct) is { } durationMs)
+        {
+            lock (_stateLock)
+                _currentState = _currentState with { DurationMs = durationMs };
+        }
+
+        _activePipeline = new ActivePipeline(
+            cmd.TrackUri, "PreviewClip", () => "p.scdn.co", () => bytes.Length, null,
+            decoder.FormatName, "mp3", audioFormat);
+
+        // Previews carry no loudness data, so no track gain is applied.
+        await PlayDecodedAsync(cmd.TrackUri, decoder, decodingStream, audioFormat, null, startPositionMs, ct);
+    }
 
+    private async Task<byte[]> DownloadPreviewClipAsync(string url, CancellationToken ct)
+    {
+        using var response = await _httpClient.GetAsync(url, HttpCompletionOption.ResponseHeadersRead, ct);
+        response.EnsureSuccessStatusCode();
+        if (response.Content.Headers.ContentLength > MaxPreviewClipBytes)
+            throw new InvalidDataException(
+                $"Preview clip is {response.Content.Headers.ContentLength} bytes, over the {MaxPreviewClipBytes}-byte limit: {url}");
+
+        await using var body = await response.Content.ReadAsStreamAsync(ct);
+        using var buffer = new MemoryStream();
+        var chunk = new byte[81920];
+        int read;
+        while ((read = await body.ReadAsync(chunk, ct)) > 0)
+        {
+            // Content-Length can be missing or wrong, so the limit holds on the bytes read too.
+            if (buffer.Length + read > MaxPreviewClipBytes)
+                throw new InvalidDataException($"Preview clip exceeds the {MaxPreviewClipBytes}-byte limit: {url}");
+            buffer.Write(chunk, 0, read);
+        }
+
+        return buffer.ToArray();
+    }
+
+    /// <summary>
+    /// Shared tail of the local-file and preview loops: initialises the sink and
+    /// processing chain for <paramref name="audioFormat"/>, then decodes to the sink
+    /// until the stream ends, honouring seeks.
+    /// </summary>
+    private async Task PlayDecodedAsync(
+        string trackUri,
+        IAudioDecoder decoder,
+        Stream decodingStream,
+        AudioFormat audioFormat,
+        NormalizationDataDto? normalization,
+        long startPositionMs,
+        CancellationToken ct)
+    {
         await _audioSink.InitializeAsync(audioFormat, bufferSizeMs: 2000, ct);
-        _audioSink.SetBasePosition(startPositionMs);
+        _loopRegion.Start(trackUri, audioFormat, startPositionMs);
+        SetBasePosition(startPositionMs);
+        await ApplyRoutingAsync(trackUri, ct);
+        RefreshChannelLayout();
         await _processingChain.InitializeAsync(audioFormat, ct);
 
-        if (cmd.Normalization is { } norm && norm.TrackGainDb.HasValue)
+        if (normalization is { } norm && norm.TrackGainDb.HasValue)
         {
             foreach (var proc in _processingChain.Processors.OfType<NormalizationProcessor>())
             {
                 var meta = new TrackMetadata
                 {
-                    Uri = cmd.TrackUri,
+                    Uri = trackUri,
                     ReplayGainTrackGain = norm.TrackGainDb.Value,
-                    ReplayGainTrackPeak = norm.TrackPeak ?? 1.0f
+                    ReplayGainTrackPeak = norm.TrackPeak ?? 1.0f,
+                    ReplayGainAlbumGain = norm.AlbumGainDb,
+                    ReplayGainAlbumPeak = norm.AlbumPeak
                 };
                 proc.SetTrackGain(meta);
             }
@@ -819,8 +1575,10 @@ public sealed class AudioEngine : IAsyncDisposable
         lock (_seekLock) _pendingSeekMs = null;
 
         long lastPublishMs = startPositionMs;
+        long lastHeardMs = startPositionMs;
 
-        await foreach (var buffer in decoder.DecodeAsync(decodingStream, startPositionMs, null, ct))
+        await foreach (var buffer in TimeDecodeAsync(
+            decoder.DecodeAsync(decodingStream, startPositionMs, null, ct), _trackStats))
         {
             long? seekTarget;
             lock (_seekLock)
@@ -833,7 +1591,7 @@ public sealed class AudioEngine : IAsyncDisposable
             {
                 buffer.Return();
                 await _audioSink.FlushAsync();
-                _audioSink.SetBasePosition(seekTarget.Value);
+                SetBasePosition(seekTarget.Value);
                 lastPublishMs = seekTarget.Value;
                 decoder.SeekTo(seekTarget.Value);
 
@@ -853,32 +1611,23 @@ public sealed class AudioEngine : IAsyncDisposable
             }
 
             var processed = _processingChain.Process(buffer);
-            await _audioSink.WriteAsync(processed.Data, ct);
+            var writeLength = _loopRegion.Clip(processed.Data.Length, out var loopStartMs);
+            await _audioSink.WriteAsync(processed.Data[..writeLength], ct);
             processed.Return();
             if (!ReferenceEquals(processed, buffer)) buffer.Return();
 
-            var positionMs = _audioSink.PlaybackPositionMs;
-            lock (_stateLock)
+            if (loopStartMs is { } loopStart)
             {
-                _currentState = _currentState with
-                {
-                    PositionMs = positionMs,
-                    IsPlaying = true,
-                    IsPaused = false,
-                    Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
-                };
+                decoder.SeekTo(loopStart);
+                RebaseAnalysis(loopStart);
             }
 
-            if (positionMs - lastPublishMs >= PositionPublishIntervalMs)
-            {
-                lastPublishMs = positionMs;
-                PublishState();
-            }
+            UpdatePlayingPosition(ref lastPublishMs, ref lastHeardMs);
         }
 
         await _audioSink.DrainAsync(ct);
 
-        var finalPosition = _audioSink.PlaybackPositionMs;
+        var finalPosition = HeardPositionMs;
         lock (_stateLock)
         {
             _currentState = _currentState with
@@ -890,15 +1639,14 @@ public sealed class AudioEngine : IAsyncDisposable
             };
         }
         PublishState();
-        _logger?.LogInformation("Local track finished: {TrackUri}", cmd.TrackUri);
-        _trackCompletedSubject.OnNext(cmd.TrackUri);
+        _logger?.LogInformation("Track finished: {TrackUri}", trackUri);
+        _trackCompletedSubject.OnNext(trackUri);
     }
 
     // ── Legacy playback loop (full resolution upfront) ──
 
-    private async Task PlaybackLoopAsync(PlayResolvedTrackCommand cmd, CancellationToken ct)
+    private async Task PlaybackLoopAsync(PlayResolvedTrackCommand cmd, long startPositionMs, CancellationToken ct)
     {
-        var startPositionMs = cmd.PositionMs;
 
         // Update state to buffering
         lock (_stateLock)
@@ -928,12 +1676,14 @@ public sealed class AudioEngine : IAsyncDisposable
 
         // Download audio
         _logger?.LogDebug("Downloading audio from CDN...");
-        var response = await _httpClient.GetAsync(cmd.CdnUrl, HttpCompletionOption.ResponseHeadersRead, ct);
+        // Disposed with the loop so a preempted load releases its CDN socket immediately.
+        using var response = await _httpClient.GetAsync(cmd.CdnUrl, HttpCompletionOption.ResponseHeadersRead, ct);
         response.EnsureSuccessStatusCode();
         var httpStream = await response.Content.ReadAsStreamAsync(ct);
 
         // Wrap in buffered stream for progressive reading
         var bufferedStream = new BufferedHttpStream(httpStream);
+        _trackStats = new TrackStatsCounters(_audioSink.UnderrunCount);
 
         // Decrypt
         byte[]? audioKey = null;
@@ -952,10 +1702,18 @@ public sealed class AudioEngine : IAsyncDisposable
         var audioFormat = await decoder.GetFormatAsync(decodingStream, ct);
         _logger?.LogDebug("Audio format: {SampleRate}Hz {Channels}ch {Bits}bit",
             audioFormat.SampleRate, audioFormat.Channels, audioFormat.BitsPerSample);
+        _activePipeline = new ActivePipeline(
+            cmd.TrackUri, "BufferedHttpStream", () => "CDN", null,
+            () => DescribeDecryption(decryptStream.Backend),
+            decoder.FormatName, cmd.Codec, audioFormat);
 
         // Initialize sink and processing chain
         await _audioSink.InitializeAsync(audioFormat, bufferSizeMs: (int)2000, ct);
-        _audioSink.SetBasePosition(startPositionMs);
+        // No A-B loop on this path; a null track drops any loop and refuses new ones.
+        _loopRegion.Start(null, audioFormat, startPositionMs);
+        SetBasePosition(startPositionMs);
+        await ApplyRoutingAsync(cmd.TrackUri, ct);
+        RefreshChannelLayout();
         await _processingChain.InitializeAsync(audioFormat, ct);
 
         // Set normalization gain if provided
@@ -967,7 +1725,9 @@ public sealed class AudioEngine : IAsyncDisposable
                 {
                     Uri = cmd.TrackUri,
                     ReplayGainTrackGain = cmd.NormalizationGain.Value,
-                    ReplayGainTrackPeak = cmd.NormalizationPeak ?? 1.0f
+                    ReplayGainTrackPeak = cmd.NormalizationPeak ?? 1.0f,
+                    ReplayGainAlbumGain = cmd.NormalizationAlbumGain,
+                    ReplayGainAlbumPeak = cmd.NormalizationAlbumPeak
                 };
                 proc.SetTrackGain(meta);
             }
@@ -1001,7 +1761,7 @@ public sealed class AudioEngine : IAsyncDisposable
         {
             bool seekRequested = false;
 
-            await foreach (var buffer in decoder.DecodeAsync(
+            await foreach (var buffer in TimeDecodeAsync(decoder.DecodeAsync(
                 decodingStream,
                 decodeStartPosition,
                 title =>
@@ -1011,7 +1771,7 @@ public sealed class AudioEngine : IAsyncDisposable
                         _currentState = _currentState with { Title = title };
                     PublishState();
                 },
-                ct))
+                ct), _trackStats))
             {
                 // Check for seek
                 lock (_seekLock)
@@ -1028,7 +1788,7 @@ public sealed class AudioEngine : IAsyncDisposable
                 {
                     if (decodingStream.CanSeek)
                         decodingStream.Position = 0;
-                    _audioSink.SetBasePosition(decodeStartPosition);
+                    SetBasePosition(decodeStartPosition);
                     lastPublishMs = decodeStartPosition;
 
                     // Emit seek result immediately so state consumers don't wait for cadence tick.
@@ -1105,18 +1865,164 @@ public sealed class AudioEngine : IAsyncDisposable
         _trackCompletedSubject.OnNext(cmd.TrackUri);
     }
 
+    private void OnOutputDeviceChanged(AudioDeviceChange change)
+    {
+        _logger?.LogInformation("[AudioEngine] Output device changed ({Reason}): {Previous} → {Current} at {Position}ms",
+            change.Reason, change.PreviousDeviceName ?? "<none>", change.DeviceName ?? "<none>", change.PositionMs);
+
+        // Layouts are per device; the stream kept its format, so the chain stays initialized.
+        RefreshChannelLayout();
+        if (!_disposed)
+            _deviceChangedSubject.OnNext(change);
+    }
+
+    // ── Stall watchdog ──
+
+    private async Task CheckForStallAsync()
+    {
+        if (_disposed || Interlocked.Exchange(ref _stallCheckRunning, 1) == 1)
+            return;
+
+        try
+        {
+            var timeoutMs = Volatile.Read(ref _stallTimeoutMs);
+            var state = CurrentState;
+            if (timeoutMs <= 0 || _restartLoop == null
+                || !state.IsPlaying || state.IsPaused || state.IsBuffering)
+            {
+                _stallLastPositionMs = -1;
+                return;
+            }
+
+            if (!string.Equals(state.TrackUri, _stallTrackUri, StringComparison.Ordinal))
+            {
+                _stallTrackUri = state.TrackUri;
+                _stallRestarts = 0;
+                _stallLastPositionMs = -1;
+            }
+
+            var nowTs = Stopwatch.GetTimestamp();
+            var positionMs = _audioSink.PlaybackPositionMs;
+            if (positionMs != _stallLastPositionMs)
+            {
+                _stallLastPositionMs = positionMs;
+                _stallLastProgressTs = nowTs;
+                if (_stallRestarts > 0 && positionMs >= _stallRecoveredAtMs)
+                    _stallRestarts = 0;
+                return;
+            }
+
+            var stalledMs = (long)Stopwatch.GetElapsedTime(_stallLastProgressTs, nowTs).TotalMilliseconds;
+            if (stalledMs < timeoutMs || _stallRestarts > MaxStallRestartsPerTrack)
+                return;
+
+            // Audio sitting in the sink means the device stopped pulling; an empty
+            // sink means nothing upstream (fetch, decrypt, decode) is producing.
+            var sinkStatus = await _audioSink.GetStatusAsync();
+            var stage = sinkStatus.BufferedMs > 0 ? "sink" : "decode";
+            var attempt = ++_stallRestarts;
+            var restart = attempt <= MaxStallRestartsPerTrack;
+            var stall = new PipelineStall(state.TrackUri, positionMs, stalledMs, stage, attempt, restart);
+            _stallSubject.OnNext(stall);
+
+            if (!restart)
+            {
+                _logger?.LogError("[AudioEngine] Playback stalled in {Stage} at {Position}ms for {Uri}; giving up after {Max} restarts",
+                    stage, positionMs, state.TrackUri ?? "<none>", MaxStallRestartsPerTrack);
+                _errorSubject.OnNext(new EngineError(
+                    $"Playback stalled at {TimeSpan.FromMilliseconds(positionMs):m\\:ss} and could not be restarted"));
+                return;
+            }
+
+            _logger?.LogWarning("[AudioEngine] Playback stalled in {Stage} for {Stalled}ms at {Position}ms ({Uri}); restarting pipeline (attempt {Attempt}/{Max})",
+                stage, stalledMs, positionMs, state.TrackUri ?? "<none>", attempt, MaxStallRestartsPerTrack);
+            // The sink counts through loop splices; restart where the track is heard.
+            var restartAtMs = _loopRegion.MapPosition(positionMs);
+            if (await RestartPipelineAsync(state.TrackUri, restartAtMs))
+            {
+                _stallRecoveredAtMs = restartAtMs + StallRecoveryMs;
+                _stallLastPositionMs = -1;
+            }
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "[AudioEngine] Stall watchdog check failed");
+        }
+        finally
+        {
+            Volatile.Write(ref _stallCheckRunning, 0);
+        }
+    }
+
+    /// <summary>
+    /// Tears down the playback loop and starts it again at <paramref name="positionMs"/>,
+    /// unless a different track was loaded in the meantime.
+    /// </summary>
+    private async Task<bool> RestartPipelineAsync(string? trackUri, long positionMs)
+    {
+        await _loadLock.WaitAsync();
+        try
+        {
+            var restartLoop = _restartLoop;
+            if (restartLoop == null || !string.Equals(CurrentState.TrackUri, trackUri, StringComparison.Ordinal))
+                return false;
+
+            await StopInternalAsync();
+
+            _playbackCts = new CancellationTokenSource();
+            var token = _playbackCts.Token;
+            _playbackTask = Task.Run(async () =>
+            {
+                try
+                {
+                    await restartLoop(positionMs, token);
+                }
+                catch (OperationCanceledException)
+                {
+                    _logger?.LogDebug("[AudioEngine] Restarted playback cancelled: {Uri}", trackUri ?? "<none>");
+                }
+                catch (Exception ex)
+                {
+                    _logger?.LogError(ex, "[AudioEngine] Restarted playback error for {Uri}", trackUri ?? "<none>");
+                    _errorSubject.OnNext(new EngineError(ex.Message, ex));
+                }
+                finally
+                {
+                    _activePipeline = null;
+                }
+            }, CancellationToken.None);
+            return true;
+        }
+        finally
+        {
+            _loadLock.Release();
+        }
+    }
+
     // ── Helpers ──
 
     private void PublishState()
     {
         EngineState snapshot;
+        var stats = _trackStats;
         lock (_stateLock)
-            snapshot = _currentState;
+        {
+            snapshot = _currentState with
+            {
+                TrackBytesDownloaded = stats.BytesDownloaded,
+                TrackFromCache = stats.FromCache,
+                TrackDecodeMs = stats.DecodeMs,
+                TrackUnderruns = Math.Max(0, _audioSink.UnderrunCount - stats.UnderrunBaseline),
+                UnderrunCount = _audioSink.UnderrunCount
+            };
+        }
         _stateSubject.OnNext(snapshot);
     }
 
     private async Task StopInternalAsync()
     {
+        lock (_stateLock) _releasedAtMs = null;
+
         if (_playbackCts != null)
         {
             await _playbackCts.CancelAsync();
@@ -1150,9 +2056,14 @@ public sealed class AudioEngine : IAsyncDisposable
         if (_disposed) return;
         _disposed = true;
 
+        await _stallWatchdog.DisposeAsync();
+        if (_audioSink is IDeviceSelectableSink selectable)
+            selectable.DeviceChanged -= OnOutputDeviceChanged;
         await StopInternalAsync();
         _stateSubject.Dispose();
         _errorSubject.Dispose();
+        _stallSubject.Dispose();
+        _deviceChangedSubject.Dispose();
     }
 }
 
@@ -1178,6 +2089,16 @@ public sealed record EngineState
     public long Timestamp { get; init; }
     /// <summary>Current volume level (0.0 = silence, 1.0 = 100%). 0 when not yet set.</summary>
     public float Volume { get; init; }
+    /// <summary>Bytes fetched for the current track so far (head data + CDN).</summary>
+    public long TrackBytesDownloaded { get; init; }
+    /// <summary>True when the current track is served from the persistent audio cache.</summary>
+    public bool TrackFromCache { get; init; }
+    /// <summary>Time spent in the decoder for the current track, including stream waits.</summary>
+    public long TrackDecodeMs { get; init; }
+    /// <summary>Sink underruns since the current track started.</summary>
+    public long TrackUnderruns { get; init; }
+    /// <summary>Sink underruns since the engine started.</summary>
+    public long UnderrunCount { get; init; }
 
     public static EngineState Empty => new()
     {
@@ -1187,3 +2108,60 @@ public sealed record EngineState
 
 /// <summary>Playback error info.</summary>
 public sealed record EngineError(string Message, Exception? Exception = null);
+
+/// <summary>
+/// Playback position stopped advancing while playing.
+/// </summary>
+/// <param name="TrackUri">Track that stalled.</param>
+/// <param name="PositionMs">Position playback was frozen at.</param>
+/// <param name="StalledMs">How long the position had been frozen.</param>
+/// <param name="Stage">"sink" when audio was buffered but not played, otherwise "decode".</param>
+/// <param name="Attempt">Restart attempt for this track, starting at 1.</param>
+/// <param name="Restarted">False once the per-track restart limit is reached.</param>
+public sealed record PipelineStall(
+    string? TrackUri,
+    long PositionMs,
+    long StalledMs,
+    string Stage,
+    int Attempt,
+    bool Restarted);
+
+/// <summary>
+/// What <see cref="AudioEngine"/> set up for the track currently loaded.
+/// Delegates read live values from the loop-owned streams.
+/// </summary>
+internal sealed record ActivePipeline(
+    string? TrackUri,
+    string FetcherName,
+    Func<string?> FetcherDetail,
+    Func<long>? FetchedBytes,
+    Func<string?>? Decryption,
+    string DecoderName,
+    string? Codec,
+    AudioFormat Format);
+
+/// <summary>
+/// Mutable per-track counters owned by the active playback loop.
+/// </summary>
+internal sealed class TrackStatsCounters
+{
+    private readonly LazyProgressiveDownloader? _downloader;
+    private long _decodeTicks;
+
+    public TrackStatsCounters(long underrunBaseline = 0, LazyProgressiveDownloader? downloader = null)
+    {
+        UnderrunBaseline = underrunBaseline;
+        _downloader = downloader;
+    }
+
+    /// <summary>Sink underrun total when the track started.</summary>
+    public long UnderrunBaseline { get; }
+
+    public long BytesDownloaded => _downloader?.BytesDownloaded ?? 0;
+
+    public bool FromCache => _downloader?.IsFromCache ?? false;
+
+    public long DecodeMs => (long)Stopwatch.GetElapsedTime(0, Interlocked.Read(ref _decodeTicks)).TotalMilliseconds;
+
+    public void AddDecodeTicks(long ticks) => Interlocked.Add(ref _decodeTicks, ticks);
+}
@@ -0,0 +1,69 @@
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Audio;
+
+/// <summary>
+/// What kind of content a track is, for output routing.
+/// </summary>
+public enum AudioContentKind
+{
+    /// <summary>Songs and local files.</summary>
+    Music,
+
+    /// <summary>Podcast episodes and audiobook chapters.</summary>
+    SpokenWord
+}
+
+/// <summary>
+/// Input to an <see cref="AudioRoutingPolicy"/>.
+/// </summary>
+/// <param name="TrackUri">URI of the track being started.</param>
+/// <param name="ContentKind">Classification of <paramref name="TrackUri"/>.</param>
+/// <param name="CurrentDeviceName">Device the sink opened on (the system default), if known.</param>
+/// <param name="Devices">Output devices available right now.</param>
+public sealed record AudioRouteRequest(
+    string TrackUri,
+    AudioContentKind ContentKind,
+    string? CurrentDeviceName,
+    IReadOnlyList<AudioOutputDeviceDto> Devices);
+
+/// <summary>
+/// Picks the output device for a track. Evaluated by <see cref="AudioEngine"/>
+/// once per track, after the sink is opened and before the first sample is written.
+/// </summary>
+/// <returns>One of <see cref="AudioRouteRequest.Devices"/>, or null to stay on the current device.</returns>
+public delegate AudioOutputDeviceDto? AudioRoutingPolicy(AudioRouteRequest request);
+
+/// <summary>
+/// Helpers for building <see cref="AudioRoutingPolicy"/> instances.
+/// </summary>
+public static class AudioRouting
+{
+    /// <summary>
+    /// Classifies a track URI. Episodes and chapters are spoken word; everything
+    /// else (tracks, local files) is music.
+    /// </summary>
+    public static AudioContentKind Classify(string? trackUri) =>
+        trackUri != null && (trackUri.StartsWith("spotify:episode:", StringComparison.Ordinal)
+                             || trackUri.StartsWith("spotify:chapter:", StringComparison.Ordinal))
+            ? AudioContentKind.SpokenWord
+            : AudioContentKind.Music;
+
+    /// <summary>
+    /// Routes each content kind to a device by name. Kinds without an entry,
+    /// and devices that aren't currently present, fall back to the current device.
+    /// </summary>
+    /// <returns>The policy, or null when <paramref name="deviceNames"/> has no usable entries.</returns>
+    public static AudioRoutingPolicy? ByDeviceName(IReadOnlyDictionary<AudioContentKind, string> deviceNames)
+    {
+        var routes = deviceNames
+            .Where(static kv => !string.IsNullOrWhiteSpace(kv.Value))
+            .ToDictionary(static kv => kv.Key, static kv => kv.Value);
+        if (routes.Count == 0)
+            return null;
+
+        return request => routes.TryGetValue(request.ContentKind, out var name)
+            ? request.Devices.FirstOrDefault(d => string.Equals(d.Name, name, StringComparison.Ordinal))
+            : null;
+    }
+}
@@ -5,10 +5,14 @@ namespace Wavee.AudioHost.Audio.Decoders;
 
 /// <summary>
 /// Registry for audio decoders with automatic format detection.
+/// Decoders can also be registered for codec names (<c>"vorbis"</c>, <c>"mp3"</c>,
+/// <c>"aac"</c>, <c>"flac"</c>) and MIME types (<c>"audio/mpeg"</c>); those are
+/// used without header sniffing when the format is known up front.
 /// </summary>
 public sealed class AudioDecoderRegistry
 {
     private readonly List<IAudioDecoder> _decoders = new();
+    private readonly Dictionary<string, IAudioDecoder> _decodersByFormat = new(StringComparer.OrdinalIgnoreCase);
 
     /// <summary>
     /// Size of header buffer for format detection on non-seekable streams.
@@ -21,7 +25,42 @@ public sealed class AudioDecoderRegistry
     /// </summary>
     public void Register(IAudioDecoder decoder)
     {
-        _decoders.Add(decoder);
+        if (!_decoders.Contains(decoder))
+            _decoders.Add(decoder);
+    }
+
+    /// <summary>
+    /// Registers an audio decoder for the given codec names and/or MIME types.
+    /// The latest registration for a format wins, so plug-ins can replace built-ins.
+    /// The decoder also takes part in header sniffing like <see cref="Register(IAudioDecoder)"/>.
+    /// </summary>
+    public void Register(IAudioDecoder decoder, params string[] formats)
+    {
+        Register(decoder);
+        foreach (var format in formats)
+        {
+            var key = NormalizeFormat(format);
+            if (key.Length > 0)
+                _decodersByFormat[key] = decoder;
+        }
+    }
+
+    /// <summary>
+    /// Finds the decoder registered for a codec name or MIME type
+    /// (parameters such as <c>; codecs=...</c> are ignored).
+    /// </summary>
+    public IAudioDecoder? FindDecoderForFormat(string? format)
+    {
+        if (string.IsNullOrWhiteSpace(format))
+            return null;
+
+        return _decodersByFormat.TryGetValue(NormalizeFormat(format), out var decoder) ? decoder : null;
+    }
+
+    private static string NormalizeFormat(string format)
+    {
+        var separator = format.IndexOf(';');
+        return (separator >= 0 ? format[..separator] : format).Trim();
     }
 
     /// <summary>
@@ -6,12 +6,14 @@ using Microsoft.Extensions.Logging;
 using Wavee.AudioHost.Audio.Abstractions;
 using Wavee.Local.Playback;
 using Wavee.AudioHost.Audio.Streaming;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Decoders;
 
 /// <summary>
 /// Audio decoder using ManagedBass (BASS library).
-/// Handles MP3, FLAC, WAV, and other formats supported by BASS.
+/// Handles MP3, FLAC, WAV, and other formats supported by BASS; with the unified
+/// decoder backend it also takes Spotify's Ogg Vorbis and AAC streams.
 /// </summary>
 public sealed class BassDecoder : IAudioDecoder
 {
@@ -39,10 +41,16 @@ public sealed class BassDecoder : IAudioDecoder
     private const int DefaultStreamingChannels = 2;
 
     private readonly ILogger? _logger;
+    private long _pendingSeekMs = -1;
 
     /// <inheritdoc/>
     public string FormatName => "Bass";
-    public void SeekTo(long positionMs) { /* TODO: BASS seek support */ }
+
+    /// <summary>
+    /// Requests a seek, applied before the next buffer of the running decode. Ignored
+    /// for non-seekable (radio) sources.
+    /// </summary>
+    public void SeekTo(long positionMs) => Interlocked.Exchange(ref _pendingSeekMs, Math.Max(0, positionMs));
 
     /// <summary>
     /// Creates a new BassDecoder.
@@ -140,11 +148,9 @@ public sealed class BassDecoder : IAudioDecoder
             return new AudioFormat(DefaultStreamingSampleRate, DefaultStreamingChannels, 16);
         }
 
-        // Copy stream to memory for BASS
-        var data = await ReadStreamToMemoryAsync(stream, cancellationToken);
-
-        // Create BASS stream from memory
-        var handle = Bass.CreateStream(data, 0, data.Length, BassFlags.Decode | BassFlags.Float);
+        var startPosition = stream.Position;
+        var source = new BassStreamSource(stream);
+        var handle = source.CreateStream(BassFlags.Decode | BassFlags.Float);
 
         if (handle == 0)
         {
@@ -160,6 +166,38 @@ public sealed class BassDecoder : IAudioDecoder
         finally
         {
             Bass.StreamFree(handle);
+            GC.KeepAlive(source);
+            stream.Position = startPosition;
+        }
+    }
+
+    /// <inheritdoc/>
+    /// <remarks>Seekable, in-process streams only; URL and radio streams report null.</remarks>
+    public Task<long?> GetDurationMsAsync(Stream stream, CancellationToken cancellationToken = default)
+    {
+        EnsureBassInitialized();
+        if (!stream.CanSeek || FindUrlAwareStream(stream) != null)
+            return Task.FromResult<long?>(null);
+
+        var startPosition = stream.Position;
+        var source = new BassStreamSource(stream);
+        var handle = source.CreateStream(BassFlags.Decode | BassFlags.Float);
+        if (handle == 0)
+        {
+            stream.Position = startPosition;
+            return Task.FromResult<long?>(null);
+        }
+
+        try
+        {
+            var length = Bass.ChannelGetLength(handle);
+            return Task.FromResult<long?>(length < 0 ? null : (long)(Bass.ChannelBytes2Seconds(handle, length) * 1000));
+        }
+        finally
+        {
+            Bass.StreamFree(handle);
+            GC.KeepAlive(source);
+            stream.Position = startPosition;
         }
     }
 
@@ -171,9 +209,11 @@ public sealed class BassDecoder : IAudioDecoder
         [EnumeratorCancellation] CancellationToken cancellationToken = default)
     {
         EnsureBassInitialized();
+        Interlocked.Exchange(ref _pendingSeekMs, -1);
 
         int handle;
         byte[]? memoryData = null;
+        BassStreamSource? streamSource = null;
         int syncHandle = 0;
         string? streamUrl = null; // Track URL for potential reconnection
         UrlAwareStream? urlStream = null;
@@ -195,7 +235,9 @@ public sealed class BassDecoder : IAudioDecoder
                   urlStream.Url.StartsWith("https://", StringComparison.OrdinalIgnoreCase)))
         {
             streamUrl = urlStream.Url; // Store for reconnection
-            _logger?.LogDebug("Using BASS native URL streaming for: {Url}", streamUrl);
+            // CDN URLs carry a signed token in the query — log host + path only.
+            _logger?.LogDebug("Using BASS native URL streaming for: {Url}",
+                Uri.TryCreate(streamUrl, UriKind.Absolute, out var logUri) ? logUri.GetLeftPart(UriPartial.Path) : "<invalid url>");
             handle = Bass.CreateStream(streamUrl, 0, BassFlags.Decode | BassFlags.Float, null);
 
             // Set up ICY metadata sync if callback provided
@@ -237,9 +279,15 @@ public sealed class BassDecoder : IAudioDecoder
                 }
             }
         }
+        else if (stream.CanSeek)
+        {
+            // Read through the stream as BASS asks, so a progressive download starts
+            // decoding before it completes.
+            streamSource = new BassStreamSource(stream);
+            handle = streamSource.CreateStream(BassFlags.Decode | BassFlags.Float);
+        }
         else
         {
-            // Load into memory for seekable streams
             memoryData = await ReadStreamToMemoryAsync(stream, cancellationToken);
             handle = Bass.CreateStream(memoryData, 0, memoryData.Length, BassFlags.Decode | BassFlags.Float);
         }
@@ -287,6 +335,13 @@ public sealed class BassDecoder : IAudioDecoder
 
                 while (!cancellationToken.IsCancellationRequested)
                 {
+                    var seekMs = Interlocked.Exchange(ref _pendingSeekMs, -1);
+                    if (seekMs >= 0 && !isStreaming
+                        && !Bass.ChannelSetPosition(handle, Bass.ChannelSeconds2Bytes(handle, seekMs / 1000.0)))
+                    {
+                        _logger?.LogWarning("Failed to seek to {PositionMs}ms: {Error}", seekMs, Bass.LastError);
+                    }
+
                     // Read float samples from BASS
                     // BASS_DATA_FLOAT = read as 32-bit floating point
                     var bytesToRead = floatBuffer.Length * sizeof(float);
@@ -362,10 +417,10 @@ public sealed class BassDecoder : IAudioDecoder
 
                     // Rent a pooled buffer to avoid per-chunk heap allocations
                     // (the caller returns it via AudioBuffer.Return after consuming)
-                    var pooled = ArrayPool<byte>.Shared.Rent(pcmBytes);
+                    var pooled = SlabBufferPool.Packets.Rent(pcmBytes);
                     pcmBuffer.AsSpan(0, pcmBytes).CopyTo(pooled);
 
-                    yield return new AudioBuffer(pooled, pcmBytes, posMs);
+                    yield return new AudioBuffer(pooled, pcmBytes, posMs, SlabBufferPool.Packets);
                 }
 
                 _logger?.LogDebug("BASS decode complete");
@@ -379,6 +434,7 @@ public sealed class BassDecoder : IAudioDecoder
         finally
         {
             Bass.StreamFree(handle);
+            GC.KeepAlive(streamSource);
         }
     }
 
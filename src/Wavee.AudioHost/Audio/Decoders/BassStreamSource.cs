@@ -0,0 +1,90 @@
+using ManagedBass;
+
+namespace Wavee.AudioHost.Audio.Decoders;
+
+/// <summary>
+/// Feeds a seekable stream to BASS through user file callbacks, so progressive CDN
+/// downloads decode as bytes arrive instead of being copied to memory first.
+/// </summary>
+/// <remarks>
+/// Spotify's Ogg files carry a 0xa7-byte header before the first <c>OggS</c> page;
+/// when present it is hidden from BASS, which would otherwise reject the file.
+/// Keep the instance alive until the BASS handle is freed: BASS holds the callbacks.
+/// </remarks>
+internal sealed class BassStreamSource
+{
+    private readonly Stream _stream;
+    private readonly long _origin;
+    private readonly FileProcedures _procedures;
+
+    public BassStreamSource(Stream stream)
+    {
+        ArgumentNullException.ThrowIfNull(stream);
+        if (!stream.CanSeek)
+            throw new ArgumentException("Stream must be seekable", nameof(stream));
+
+        _stream = stream;
+        _origin = FindOrigin(stream);
+        _stream.Position = _origin;
+        _procedures = new FileProcedures
+        {
+            Close = _ => { },
+            Length = _ => Math.Max(0, _stream.Length - _origin),
+            Read = Read,
+            Seek = Seek
+        };
+    }
+
+    /// <summary>
+    /// Opens a BASS stream over the source.
+    /// </summary>
+    /// <returns>The handle, or 0 with <see cref="Bass.LastError"/> set.</returns>
+    public int CreateStream(BassFlags flags) => Bass.CreateStream(StreamSystem.NoBuffer, flags, _procedures);
+
+    private unsafe int Read(IntPtr buffer, int length, IntPtr user)
+    {
+        try
+        {
+            return _stream.Read(new Span<byte>((void*)buffer, length));
+        }
+        catch (Exception ex) when (ex is IOException or ObjectDisposedException)
+        {
+            // BASS treats a short read as end of file.
+            return 0;
+        }
+    }
+
+    private bool Seek(long offset, IntPtr user)
+    {
+        try
+        {
+            _stream.Position = _origin + offset;
+            return true;
+        }
+        catch (Exception ex) when (ex is IOException or ObjectDisposedException or ArgumentOutOfRangeException)
+        {
+            return false;
+        }
+    }
+
+    private static long FindOrigin(Stream stream)
+    {
+        Span<byte> magic = stackalloc byte[4];
+        if (ReadAt(stream, 0, magic) && !magic.SequenceEqual("OggS"u8)
+            && ReadAt(stream, VorbisDecoder.SpotifyHeaderSize, magic) && magic.SequenceEqual("OggS"u8))
+        {
+            return VorbisDecoder.SpotifyHeaderSize;
+        }
+
+        return 0;
+    }
+
+    private static bool ReadAt(Stream stream, long position, Span<byte> buffer)
+    {
+        if (stream.Length < position + buffer.Length)
+            return false;
+
+        stream.Position = position;
+        return stream.ReadAtLeast(buffer, buffer.Length, throwOnEndOfStream: false) == buffer.Length;
+    }
+}
@@ -0,0 +1,74 @@
+using System.Diagnostics.CodeAnalysis;
+using System.Reflection;
+using System.Runtime.CompilerServices;
+using System.Runtime.Loader;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Audio.Decoders;
+
+/// <summary>
+/// Best-effort loader for managed decoder plug-ins.
+///
+/// <para>
+/// At startup we look for a <c>decoder-plugins/</c> folder next to the running
+/// AudioHost executable, load every <c>*.dll</c> in it, and instantiate each
+/// <see cref="AudioDecoderPluginAttribute"/> entry point. A plug-in that fails
+/// to load or register is logged and skipped — the built-in decoders always work.
+/// </para>
+/// <para>
+/// Loading assemblies at runtime needs the JIT, so plug-ins are ignored in a
+/// Native AOT build.
+/// </para>
+/// </summary>
+internal static class DecoderPluginLoader
+{
+    public const string PluginFolderName = "decoder-plugins";
+
+    [UnconditionalSuppressMessage("Trimming", "IL2026",
+        Justification = "Plug-in assemblies are loaded from disk and are not part of the trimmed app; their entry point is rooted via AudioDecoderPluginAttribute.")]
+    public static void LoadAll(AudioDecoderRegistry registry, ILogger? logger)
+    {
+        var pluginDir = Path.Combine(AppContext.BaseDirectory, PluginFolderName);
+        if (!Directory.Exists(pluginDir))
+            return;
+
+        if (!RuntimeFeature.IsDynamicCodeSupported)
+        {
+            logger?.LogWarning("Decoder plug-ins in {PluginDir} ignored: not supported in a Native AOT build", pluginDir);
+            return;
+        }
+
+        foreach (var dll in Directory.EnumerateFiles(pluginDir, "*.dll", SearchOption.TopDirectoryOnly))
+        {
+            try
+            {
+                var assembly = AssemblyLoadContext.Default.LoadFromAssemblyPath(dll);
+                var entryPoints = assembly.GetCustomAttributes<AudioDecoderPluginAttribute>().ToList();
+                if (entryPoints.Count == 0)
+                {
+                    logger?.LogDebug("Skipping {Plugin}: no [AudioDecoderPlugin] entry point", Path.GetFileName(dll));
+                    continue;
+                }
+
+                foreach (var entryPoint in entryPoints)
+                {
+                    if (Activator.CreateInstance(entryPoint.PluginType) is not IAudioDecoderPlugin plugin)
+                    {
+                        logger?.LogWarning("Decoder plug-in type {Type} does not implement IAudioDecoderPlugin",
+                            entryPoint.PluginType.FullName);
+                        continue;
+                    }
+
+                    plugin.Register(registry, logger);
+                    logger?.LogInformation("Decoder plug-in loaded: {Plugin} ({Assembly})",
+                        plugin.Name, Path.GetFileName(dll));
+                }
+            }
+            catch (Exception ex)
+            {
+                logger?.LogWarning(ex, "Decoder plug-in failed to load: {Plugin}", Path.GetFileName(dll));
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ using System.Runtime.CompilerServices;
 using Microsoft.Extensions.Logging;
 using NVorbis;
 using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Decoders;
 
@@ -152,10 +153,10 @@ public sealed class VorbisDecoder : IAudioDecoder
                     var positionMs = (long)(reader.TimePosition.TotalMilliseconds);
 
                     // Rent from pool — caller returns via AudioBuffer.Return() after consuming
-                    var pooled = ArrayPool<byte>.Shared.Rent(pcmBytes);
+                    var pooled = SlabBufferPool.Packets.Rent(pcmBytes);
                     pcmBuffer.AsSpan(0, pcmBytes).CopyTo(pooled);
 
-                    yield return new AudioBuffer(pooled, pcmBytes, positionMs);
+                    yield return new AudioBuffer(pooled, pcmBytes, positionMs, SlabBufferPool.Packets);
                 }
 
                 _logger?.LogDebug("Vorbis decode complete");
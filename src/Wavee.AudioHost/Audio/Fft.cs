@@ -0,0 +1,82 @@
+namespace Wavee.AudioHost.Audio;
+
+/// <summary>
+/// In-place radix-2 FFT and window helpers shared by the visualization paths.
+/// </summary>
+public static class Fft
+{
+    /// <summary>
+    /// Creates a Hann window of <paramref name="length"/> samples.
+    /// </summary>
+    public static float[] CreateHannWindow(int length)
+    {
+        var window = new float[length];
+        for (int i = 0; i < window.Length; i++)
+            window[i] = 0.5f - (0.5f * MathF.Cos(2f * MathF.PI * i / (window.Length - 1)));
+
+        return window;
+    }
+
+    /// <summary>
+    /// Forward transform of (<paramref name="real"/>, <paramref name="imaginary"/>) in place.
+    /// The length must be a power of two.
+    /// </summary>
+    public static void Transform(Span<float> real, Span<float> imaginary)
+    {
+        var n = real.Length;
+        for (int i = 1, j = 0; i < n; i++)
+        {
+            var bit = n >> 1;
+            for (; (j & bit) != 0; bit >>= 1)
+                j ^= bit;
+
+            j ^= bit;
+            if (i >= j)
+                continue;
+
+            (real[i], real[j]) = (real[j], real[i]);
+            (imaginary[i], imaginary[j]) = (imaginary[j], imaginary[i]);
+        }
+
+        for (int length = 2; length <= n; length <<= 1)
+        {
+            var angle = -2f * MathF.PI / length;
+            var wLengthReal = MathF.Cos(angle);
+            var wLengthImaginary = MathF.Sin(angle);
+
+            for (int i = 0; i < n; i += length)
+            {
+                var wReal = 1f;
+                var wImaginary = 0f;
+                var halfLength = length >> 1;
+
+                for (int j = 0; j < halfLength; j++)
+                {
+                    var evenIndex = i + j;
+                    var oddIndex = evenIndex + halfLength;
+                    var oddReal = (real[oddIndex] * wReal) - (imaginary[oddIndex] * wImaginary);
+                    var oddImaginary = (real[oddIndex] * wImaginary) + (imaginary[oddIndex] * wReal);
+
+                    real[oddIndex] = real[evenIndex] - oddReal;
+                    imaginary[oddIndex] = imaginary[evenIndex] - oddImaginary;
+                    real[evenIndex] += oddReal;
+                    imaginary[evenIndex] += oddImaginary;
+
+                    var nextWReal = (wReal * wLengthReal) - (wImaginary * wLengthImaginary);
+                    wImaginary = (wReal * wLengthImaginary) + (wImaginary * wLengthReal);
+                    wReal = nextWReal;
+                }
+            }
+        }
+    }
+
+    /// <summary>
+    /// Log-interpolates between <paramref name="start"/> and <paramref name="end"/> (Hz).
+    /// </summary>
+    public static float LogLerp(float start, float end, float amount)
+    {
+        start = MathF.Max(start, 1f);
+        end = MathF.Max(end, start + 1f);
+        return start * MathF.Pow(end / start, amount);
+    }
+}
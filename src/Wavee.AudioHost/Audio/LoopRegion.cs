@@ -0,0 +1,200 @@
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Audio;
+
+/// <summary>
+/// A-B loop of the loaded track. Counts the frames the playback loop sends to the
+/// sink so the buffer that crosses B can be cut at B's exact frame, after which the
+/// decoder is sent back to A without flushing — the sink plays B's last frame and
+/// A's first one back to back.
+/// </summary>
+/// <remarks>
+/// Set and cleared from the IPC thread; everything else runs on the playback loop.
+/// Bounds are in milliseconds and become frames at the track's sample rate
+/// (<c>ms * rate / 1000</c>, the same rounding the decoders use to seek).
+/// </remarks>
+public sealed class LoopRegion
+{
+    private readonly object _lock = new();
+
+    private string? _trackUri;
+    private int _sampleRate;
+    private int _bytesPerFrame;
+
+    private bool _active;
+    private long _startMs;
+    private long _endMs;
+
+    // Frame of the next decoded sample.
+    private long _decodeFrame;
+
+    // Bounds of the loop the sink's audio has been spliced with since the last
+    // rebase, or null while it plays straight through. See MapPosition.
+    private (long StartMs, long EndMs)? _splicedWith;
+
+    /// <summary>
+    /// The loop as (start, end) in milliseconds, or null when none is set.
+    /// </summary>
+    public (long StartMs, long EndMs)? Current
+    {
+        get
+        {
+            lock (_lock)
+                return _active ? (_startMs, _endMs) : null;
+        }
+    }
+
+    /// <summary>
+    /// True when audio written since the last rebase went round the loop, so the
+    /// sink's own position runs ahead of what is heard.
+    /// </summary>
+    public bool HasSpliced
+    {
+        get
+        {
+            lock (_lock)
+                return _splicedWith != null;
+        }
+    }
+
+    /// <summary>
+    /// Called when a playback loop opens the sink. Keeps the loop when the same track
+    /// is restarted (stall recovery, output release) and drops it for any other track.
+    /// </summary>
+    public void Start(string? trackUri, AudioFormat format, long positionMs)
+    {
+        ArgumentNullException.ThrowIfNull(format);
+
+        lock (_lock)
+        {
+            if (!string.Equals(trackUri, _trackUri, StringComparison.Ordinal))
+                _active = false;
+
+            _trackUri = trackUri;
+            _sampleRate = format.SampleRate;
+            _bytesPerFrame = format.BytesPerFrame;
+            RebaseLocked(positionMs);
+        }
+    }
+
+    /// <summary>
+    /// Sets the loop. Returns false when <paramref name="trackUri"/> isn't the loaded
+    /// track or the bounds are empty or negative.
+    /// </summary>
+    public bool Set(string? trackUri, long startMs, long endMs)
+    {
+        if (startMs < 0 || endMs <= startMs)
+            return false;
+
+        lock (_lock)
+        {
+            if (!string.Equals(trackUri, _trackUri, StringComparison.Ordinal))
+                return false;
+
+            _active = true;
+            _startMs = startMs;
+            _endMs = endMs;
+            _splicedWith = null;
+            return true;
+        }
+    }
+
+    /// <summary>
+    /// Removes the loop; playback continues past its end.
+    /// </summary>
+    public void Clear()
+    {
+        lock (_lock)
+        {
+            _active = false;
+            _splicedWith = null;
+        }
+    }
+
+    /// <summary>
+    /// Forgets the loop and the track it belongs to (stop).
+    /// </summary>
+    public void Reset()
+    {
+        lock (_lock)
+        {
+            _active = false;
+            _splicedWith = null;
+            _trackUri = null;
+        }
+    }
+
+    /// <summary>
+    /// The decoder and the sink were moved to <paramref name="positionMs"/> (seek, flush).
+    /// </summary>
+    public void Rebase(long positionMs)
+    {
+        lock (_lock)
+            RebaseLocked(positionMs);
+    }
+
+    /// <summary>
+    /// True when the decoder has already produced audio past <paramref name="positionMs"/>.
+    /// </summary>
+    public bool IsDecodedPast(long positionMs)
+    {
+        lock (_lock)
+            return _sampleRate > 0 && _decodeFrame > ToFrame(positionMs);
+    }
+
+    /// <summary>
+    /// Accounts for a decoded buffer of <paramref name="byteCount"/> bytes about to be written.
+    /// </summary>
+    /// <param name="byteCount">Size of the buffer.</param>
+    /// <param name="loopStartMs">
+    /// Set when the buffer reaches the loop end: the caller writes only the returned
+    /// prefix, then seeks the decoder to this position without flushing the sink.
+    /// </param>
+    /// <returns>Number of bytes to write.</returns>
+    public int Clip(int byteCount, out long? loopStartMs)
+    {
+        loopStartMs = null;
+        lock (_lock)
+        {
+            if (_bytesPerFrame <= 0)
+                return byteCount;
+
+            var frames = byteCount / _bytesPerFrame;
+            var endFrame = _active ? ToFrame(_endMs) : long.MaxValue;
+            if (_decodeFrame >= endFrame || _decodeFrame + frames < endFrame)
+            {
+                _decodeFrame += frames;
+                return byteCount;
+            }
+
+            var keep = (int)(endFrame - _decodeFrame);
+            _decodeFrame = ToFrame(_startMs);
+            _splicedWith ??= (_startMs, _endMs);
+            loopStartMs = _startMs;
+            return keep * _bytesPerFrame;
+        }
+    }
+
+    /// <summary>
+    /// Converts the sink's position, which counts every frame played since the last
+    /// rebase, into the track position heard: past the loop end it wraps back into the loop.
+    /// </summary>
+    public long MapPosition(long sinkPositionMs)
+    {
+        lock (_lock)
+        {
+            if (_splicedWith is not { } loop || sinkPositionMs < loop.EndMs)
+                return sinkPositionMs;
+
+            return loop.StartMs + (sinkPositionMs - loop.EndMs) % (loop.EndMs - loop.StartMs);
+        }
+    }
+
+    private void RebaseLocked(long positionMs)
+    {
+        _decodeFrame = ToFrame(positionMs);
+        _splicedWith = null;
+    }
+
+    private long ToFrame(long positionMs) => positionMs * _sampleRate / 1000;
+}
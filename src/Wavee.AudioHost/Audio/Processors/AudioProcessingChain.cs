@@ -13,6 +13,17 @@ public sealed class AudioProcessingChain
     private AudioFormat? _format;
     private CrossfadeProcessor? _crossfadeProcessor;
 
+    /// <summary>
+    /// When true, <see cref="Process"/> returns decoder output untouched (bit-perfect playback).
+    /// </summary>
+    public bool Bypass { get; set; }
+
+    /// <summary>
+    /// Format the processors were last initialized with; they work in place, so it is
+    /// also the format each of them emits. Null before <see cref="InitializeAsync"/>.
+    /// </summary>
+    public AudioFormat? Format => _format;
+
     /// <summary>
     /// Adds a processor to the chain.
     /// </summary>
@@ -55,7 +66,7 @@ public sealed class AudioProcessingChain
     /// </remarks>
     public AudioBuffer Process(AudioBuffer input)
     {
-        if (input.IsEmpty)
+        if (input.IsEmpty || Bypass)
             return input;
 
         // Check if any processor is enabled
@@ -0,0 +1,159 @@
+using System.Buffers;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Audio.Processors;
+
+/// <summary>
+/// Rearranges decoded channels before any other processing: mono downmix,
+/// left/right swap, or an arbitrary map for multi-channel output.
+/// The channel count is never changed — the sink is opened with the decoder's layout.
+/// </summary>
+public sealed class ChannelMapProcessor : IAudioProcessor
+{
+    private sealed record Layout(ChannelLayoutMode Mode, int[]? Map);
+
+    private static readonly Layout DefaultLayout = new(ChannelLayoutMode.Default, null);
+
+    private AudioFormat? _format;
+    private volatile Layout _layout = DefaultLayout;
+
+    public string ProcessorName => "ChannelMap";
+    public bool IsEnabled { get; set; } = true;
+
+    /// <summary>
+    /// Gets the current layout mode.
+    /// </summary>
+    public ChannelLayoutMode Mode => _layout.Mode;
+
+    /// <summary>
+    /// Gets the current custom map (output channel i plays input channel map[i]; -1 is silence).
+    /// </summary>
+    public IReadOnlyList<int>? ChannelMap => _layout.Map;
+
+    /// <summary>
+    /// Sets the layout. Takes effect from the next buffer.
+    /// </summary>
+    /// <param name="mode">Layout mode.</param>
+    /// <param name="channelMap">
+    /// Required for <see cref="ChannelLayoutMode.Custom"/>: entry i is the input channel
+    /// routed to output channel i, or -1 for silence. Output channels past the end of the
+    /// map, and entries naming a channel the stream doesn't have, pass through unchanged.
+    /// </param>
+    public void Configure(ChannelLayoutMode mode, IReadOnlyList<int>? channelMap = null)
+    {
+        if (mode == ChannelLayoutMode.Custom && (channelMap == null || channelMap.Count == 0))
+            throw new ArgumentException("A custom layout needs a channel map", nameof(channelMap));
+
+        _layout = new Layout(mode, mode == ChannelLayoutMode.Custom ? channelMap!.ToArray() : null);
+    }
+
+    public Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
+    {
+        _format = format;
+        return Task.CompletedTask;
+    }
+
+    public AudioBuffer Process(AudioBuffer input)
+    {
+        if (_format == null)
+            throw new InvalidOperationException("Processor not initialized");
+
+        if (input.IsEmpty || _layout.Mode == ChannelLayoutMode.Default)
+            return input; // No change needed
+
+        var inputSpan = input.Data.Span;
+        var output = ArrayPool<byte>.Shared.Rent(inputSpan.Length);
+        var outputSpan = output.AsSpan(0, inputSpan.Length);
+        inputSpan.CopyTo(outputSpan);
+
+        ProcessInPlace(outputSpan);
+
+        return new AudioBuffer(output, inputSpan.Length, input.PositionMs);
+    }
+
+    public void ProcessInPlace(Span<byte> data)
+    {
+        var layout = _layout;
+        if (_format == null || layout.Mode == ChannelLayoutMode.Default || data.Length == 0)
+            return;
+
+        var channels = _format.Channels;
+        var bytesPerSample = _format.BitsPerSample / 8;
+        if (channels < 2 || bytesPerSample is not (2 or 3 or 4))
+            return;
+
+        Span<int> frame = stackalloc int[channels];
+        Span<int> mapped = stackalloc int[channels];
+        var frameBytes = channels * bytesPerSample;
+        var frameCount = data.Length / frameBytes;
+
+        for (var f = 0; f < frameCount; f++)
+        {
+            var frameSpan = data.Slice(f * frameBytes, frameBytes);
+            for (var ch = 0; ch < channels; ch++)
+                frame[ch] = ReadSample(frameSpan, ch * bytesPerSample, bytesPerSample);
+
+            switch (layout.Mode)
+            {
+                case ChannelLayoutMode.Mono:
+                    long sum = 0;
+                    for (var ch = 0; ch < channels; ch++)
+                        sum += frame[ch];
+                    mapped.Fill((int)(sum / channels));
+                    break;
+
+                case ChannelLayoutMode.SwapStereo:
+                    frame.CopyTo(mapped);
+                    mapped[0] = frame[1];
+                    mapped[1] = frame[0];
+                    break;
+
+                case ChannelLayoutMode.Custom:
+                    var map = layout.Map!;
+                    for (var ch = 0; ch < channels; ch++)
+                    {
+                        var source = ch < map.Length ? map[ch] : ch;
+                        mapped[ch] = source < 0
+                            ? 0
+                            : source < channels ? frame[source] : frame[ch];
+                    }
+                    break;
+
+                default:
+                    return;
+            }
+
+            for (var ch = 0; ch < channels; ch++)
+                WriteSample(frameSpan, ch * bytesPerSample, bytesPerSample, mapped[ch]);
+        }
+    }
+
+    public void Reset()
+    {
+        // Channel mapping has no state to reset
+    }
+
+    private static int ReadSample(ReadOnlySpan<byte> frame, int offset, int bytesPerSample)
+    {
+        switch (bytesPerSample)
+        {
+            case 2:
+                return (short)(frame[offset] | (frame[offset + 1] << 8));
+            case 3:
+                var sample = frame[offset] | (frame[offset + 1] << 8) | (frame[offset + 2] << 16);
+                if ((sample & 0x800000) != 0)
+                    sample |= unchecked((int)0xFF000000);
+                return sample;
+            default:
+                return frame[offset] | (frame[offset + 1] << 8) |
+                       (frame[offset + 2] << 16) | (frame[offset + 3] << 24);
+        }
+    }
+
+    private static void WriteSample(Span<byte> frame, int offset, int bytesPerSample, int value)
+    {
+        for (var i = 0; i < bytesPerSample; i++)
+            frame[offset + i] = (byte)(value >> (8 * i));
+    }
+}
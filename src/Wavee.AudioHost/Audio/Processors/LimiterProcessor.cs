@@ -10,18 +10,60 @@ namespace Wavee.AudioHost.Audio.Processors;
 /// </summary>
 public sealed class LimiterProcessor : IAudioProcessor
 {
-    // Limiter preset values
-    private const float CeilingDb = -0.5f;       // Maximum output level (leaves headroom for inter-sample peaks)
-    private const float ReleaseMs = 50f;         // Fast release for minimal pumping
-
     private AudioFormat? _format;
     private double[] _gainReductionDb = Array.Empty<double>();  // Per-channel gain reduction
+    private double _attackCoeff;
     private double _releaseCoeff;
-    private float _ceilingLinear;
+    private float _ceilingDb = -0.5f;       // Leaves headroom for inter-sample peaks
+    private float _attackMs;                // Instant attack by default
+    private float _releaseMs = 50f;         // Fast release for minimal pumping
+    private float _ceilingLinear = DbToLinear(-0.5f);
 
     public string ProcessorName => "Limiter";
     public bool IsEnabled { get; set; }
 
+    /// <summary>
+    /// Gets or sets the maximum output level in dBFS.
+    /// Range: -24dB to 0dB
+    /// </summary>
+    public float CeilingDb
+    {
+        get => _ceilingDb;
+        set
+        {
+            _ceilingDb = Math.Clamp(value, -24.0f, 0.0f);
+            _ceilingLinear = DbToLinear(_ceilingDb);
+        }
+    }
+
+    /// <summary>
+    /// Gets or sets how quickly gain reduction engages, in milliseconds.
+    /// 0 is an instant (brick-wall) attack. Range: 0ms to 100ms
+    /// </summary>
+    public float AttackMs
+    {
+        get => _attackMs;
+        set
+        {
+            _attackMs = Math.Clamp(value, 0.0f, 100.0f);
+            UpdateCoefficients();
+        }
+    }
+
+    /// <summary>
+    /// Gets or sets how quickly gain reduction recovers, in milliseconds.
+    /// Range: 1ms to 2000ms
+    /// </summary>
+    public float ReleaseMs
+    {
+        get => _releaseMs;
+        set
+        {
+            _releaseMs = Math.Clamp(value, 1.0f, 2000.0f);
+            UpdateCoefficients();
+        }
+    }
+
     public Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
     {
         _format = format;
@@ -29,15 +71,22 @@ public sealed class LimiterProcessor : IAudioProcessor
         // Initialize per-channel gain reduction state
         _gainReductionDb = new double[format.Channels];
 
-        // Calculate release coefficient
-        _releaseCoeff = Math.Exp(-1.0 / (format.SampleRate * ReleaseMs / 1000.0));
-
-        // Pre-calculate ceiling in linear
-        _ceilingLinear = DbToLinear(CeilingDb);
+        UpdateCoefficients();
 
         return Task.CompletedTask;
     }
 
+    private void UpdateCoefficients()
+    {
+        if (_format == null)
+            return;
+
+        _attackCoeff = _attackMs <= 0f
+            ? 0.0
+            : Math.Exp(-1.0 / (_format.SampleRate * _attackMs / 1000.0));
+        _releaseCoeff = Math.Exp(-1.0 / (_format.SampleRate * _releaseMs / 1000.0));
+    }
+
     public AudioBuffer Process(AudioBuffer input)
     {
         if (_format == null)
@@ -175,18 +224,24 @@ public sealed class LimiterProcessor : IAudioProcessor
     }
 
     /// <summary>
-    /// Core limiting algorithm - instant attack, smooth release.
+    /// Core limiting algorithm - smoothed (or instant) attack, smooth release.
+    /// Operates on a normalized (-1 to 1) sample; used directly by
+    /// <see cref="NormalizationProcessor"/> for dynamic normalization.
     /// </summary>
-    private double LimitSample(double sample, int channel)
+    internal double LimitSample(double sample, int channel)
     {
         var absLevel = Math.Abs(sample);
 
         // Check if we need to limit
         if (absLevel > _ceilingLinear)
         {
-            // Calculate required gain reduction (instant attack)
             var requiredReduction = 20.0 * Math.Log10(absLevel / _ceilingLinear);
-            _gainReductionDb[channel] = Math.Max(_gainReductionDb[channel], requiredReduction);
+            var current = _gainReductionDb[channel];
+            if (requiredReduction > current)
+            {
+                // Attack - move towards the required reduction (instant when coeff is 0)
+                _gainReductionDb[channel] = requiredReduction + _attackCoeff * (current - requiredReduction);
+            }
         }
         else
         {
@@ -1,5 +1,6 @@
 using System.Buffers;
 using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Processors;
 
@@ -14,6 +15,9 @@ public sealed class NormalizationProcessor : IAudioProcessor
     private NormalizationMode _mode = NormalizationMode.Track;
     private float _preAmpDb = 0.0f;
     private bool _preventClipping = true;
+    private double _targetLufs = NormalizationSettings.ReferenceLufs;
+    private NormalizationMethod _method = NormalizationMethod.Basic;
+    private readonly LimiterProcessor _limiter = new() { IsEnabled = true };
 
     public string ProcessorName => "Normalization";
     public bool IsEnabled { get; set; } = true;
@@ -46,15 +50,61 @@ public sealed class NormalizationProcessor : IAudioProcessor
         set => _preventClipping = value;
     }
 
+    /// <summary>
+    /// Gets or sets the target loudness in LUFS. Spotify's gain values are computed
+    /// against <see cref="NormalizationSettings.ReferenceLufs"/>; the difference is
+    /// added to every track's gain. Range: -30 LUFS to -5 LUFS
+    /// </summary>
+    public double TargetLufs
+    {
+        get => _targetLufs;
+        set => _targetLufs = Math.Clamp(value, -30.0, -5.0);
+    }
+
+    /// <summary>
+    /// Gets or sets how positive gain is kept from clipping.
+    /// <see cref="NormalizationMethod.Basic"/> caps the gain by the track's peak
+    /// (when <see cref="PreventClipping"/> is set); <see cref="NormalizationMethod.Dynamic"/>
+    /// applies the full gain and runs the result through <see cref="Limiter"/>.
+    /// </summary>
+    public NormalizationMethod Method
+    {
+        get => _method;
+        set => _method = value;
+    }
+
+    /// <summary>
+    /// Limiter used by <see cref="NormalizationMethod.Dynamic"/>. Configure its
+    /// ceiling, attack and release directly.
+    /// </summary>
+    public LimiterProcessor Limiter => _limiter;
+
     /// <summary>
     /// Gets the current gain being applied (in linear scale).
     /// </summary>
     public float CurrentGain => _currentGainLinear;
 
-    public Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
+    public async Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
     {
         _format = format;
-        return Task.CompletedTask;
+        await _limiter.InitializeAsync(format, cancellationToken);
+    }
+
+    /// <summary>
+    /// Applies a full normalization configuration, including the dynamic limiter.
+    /// Gain changes take effect from the next track.
+    /// </summary>
+    public void Configure(NormalizationSettings settings)
+    {
+        ArgumentNullException.ThrowIfNull(settings);
+
+        IsEnabled = settings.Enabled;
+        Mode = settings.GainMode == NormalizationGainMode.Album ? NormalizationMode.Album : NormalizationMode.Track;
+        TargetLufs = settings.TargetLufs;
+        Method = settings.Method;
+        _limiter.CeilingDb = (float)settings.LimiterThresholdDb;
+        _limiter.AttackMs = (float)settings.LimiterAttackMs;
+        _limiter.ReleaseMs = (float)settings.LimiterReleaseMs;
     }
 
     /// <summary>
@@ -62,17 +112,18 @@ public sealed class NormalizationProcessor : IAudioProcessor
     /// </summary>
     public void SetTrackGain(TrackMetadata metadata)
     {
-        double? gainDb = _mode switch
-        {
-            NormalizationMode.Track => metadata.ReplayGainTrackGain,
-            NormalizationMode.Album => metadata.ReplayGainAlbumGain ?? metadata.ReplayGainTrackGain,
-            _ => null
-        };
+        var useAlbum = _mode == NormalizationMode.Album && metadata.ReplayGainAlbumGain.HasValue;
+        var gainDb = useAlbum ? metadata.ReplayGainAlbumGain : metadata.ReplayGainTrackGain;
+        var peak = useAlbum ? metadata.ReplayGainAlbumPeak : metadata.ReplayGainTrackPeak;
 
         if (gainDb.HasValue)
         {
-            var totalGainDb = (float)gainDb.Value + _preAmpDb;
+            var totalGainDb = (float)(gainDb.Value + (_targetLufs - NormalizationSettings.ReferenceLufs)) + _preAmpDb;
             _currentGainLinear = DbToLinear(totalGainDb);
+
+            // Basic: never push the file's peak past full scale
+            if (_method == NormalizationMethod.Basic && _preventClipping && peak is > 0)
+                _currentGainLinear = Math.Min(_currentGainLinear, (float)(1.0 / peak.Value));
         }
         else
         {
@@ -81,13 +132,22 @@ public sealed class NormalizationProcessor : IAudioProcessor
         }
     }
 
+    private LimiterProcessor? ActiveLimiter => _method == NormalizationMethod.Dynamic ? _limiter : null;
+
+    // Unity gain is applied as exactly 1 so the sample loops can skip the multiply.
+    private float EffectiveGain => Math.Abs(_currentGainLinear - 1.0f) < 0.0001f ? 1.0f : _currentGainLinear;
+
     public AudioBuffer Process(AudioBuffer input)
     {
         if (_format == null)
             throw new InvalidOperationException("Processor not initialized");
 
-        if (input.IsEmpty || Math.Abs(_currentGainLinear - 1.0f) < 0.0001f)
-            return input; // No change needed
+        // Without a limiter unity gain changes nothing; with one, input that already
+        // clips still has to be limited.
+        var gain = EffectiveGain;
+        var limiter = ActiveLimiter;
+        if (input.IsEmpty || (gain == 1.0f && limiter == null))
+            return input;
 
         var inputSpan = input.Data.Span;
         var output = ArrayPool<byte>.Shared.Rent(inputSpan.Length);
@@ -95,15 +155,15 @@ public sealed class NormalizationProcessor : IAudioProcessor
 
         if (_format.BitsPerSample == 16)
         {
-            ProcessInt16(inputSpan, outputSpan, _currentGainLinear, _preventClipping);
+            ProcessInt16(inputSpan, outputSpan, gain, _preventClipping, limiter, _format.Channels);
         }
         else if (_format.BitsPerSample == 24)
         {
-            ProcessInt24(inputSpan, outputSpan, _currentGainLinear, _preventClipping);
+            ProcessInt24(inputSpan, outputSpan, gain, _preventClipping, limiter, _format.Channels);
         }
         else if (_format.BitsPerSample == 32)
         {
-            ProcessInt32(inputSpan, outputSpan, _currentGainLinear, _preventClipping);
+            ProcessInt32(inputSpan, outputSpan, gain, _preventClipping, limiter, _format.Channels);
         }
         else
         {
@@ -115,30 +175,37 @@ public sealed class NormalizationProcessor : IAudioProcessor
 
     public void ProcessInPlace(Span<byte> data)
     {
-        if (_format == null || Math.Abs(_currentGainLinear - 1.0f) < 0.0001f || data.Length == 0)
+        var gain = EffectiveGain;
+        var limiter = ActiveLimiter;
+        if (_format == null || (gain == 1.0f && limiter == null) || data.Length == 0)
             return;
 
         if (_format.BitsPerSample == 16)
-            ProcessInt16(data, data, _currentGainLinear, _preventClipping);
+            ProcessInt16(data, data, gain, _preventClipping, limiter, _format.Channels);
         else if (_format.BitsPerSample == 24)
-            ProcessInt24(data, data, _currentGainLinear, _preventClipping);
+            ProcessInt24(data, data, gain, _preventClipping, limiter, _format.Channels);
         else if (_format.BitsPerSample == 32)
-            ProcessInt32(data, data, _currentGainLinear, _preventClipping);
+            ProcessInt32(data, data, gain, _preventClipping, limiter, _format.Channels);
     }
 
     public void Reset()
     {
         _currentGainLinear = 1.0f;
+        _limiter.Reset();
     }
 
-    private static void ProcessInt16(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping)
+    private static void ProcessInt16(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping,
+        LimiterProcessor? limiter, int channels)
     {
         var sampleCount = input.Length / 2;
         for (var i = 0; i < sampleCount; i++)
         {
             var sampleIndex = i * 2;
             var sample = (short)(input[sampleIndex] | (input[sampleIndex + 1] << 8));
-            var processed = sample * gain;
+            var processed = gain == 1.0f ? sample : sample * gain;
+
+            if (limiter != null)
+                processed = (float)(limiter.LimitSample(processed / 32768.0, i % channels) * 32768.0);
 
             // Apply soft clipping if enabled
             if (preventClipping && Math.Abs(processed) > short.MaxValue)
@@ -156,7 +223,8 @@ public sealed class NormalizationProcessor : IAudioProcessor
         }
     }
 
-    private static void ProcessInt24(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping)
+    private static void ProcessInt24(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping,
+        LimiterProcessor? limiter, int channels)
     {
         const int maxValue = 8388607;
         const int minValue = -8388608;
@@ -171,7 +239,10 @@ public sealed class NormalizationProcessor : IAudioProcessor
             if ((sample & 0x800000) != 0)
                 sample |= unchecked((int)0xFF000000);
 
-            var processed = sample * gain;
+            var processed = gain == 1.0f ? sample : sample * gain;
+
+            if (limiter != null)
+                processed = (float)(limiter.LimitSample(processed / (double)maxValue, i % channels) * maxValue);
 
             if (preventClipping && Math.Abs(processed) > maxValue)
             {
@@ -189,7 +260,8 @@ public sealed class NormalizationProcessor : IAudioProcessor
         }
     }
 
-    private static void ProcessInt32(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping)
+    private static void ProcessInt32(ReadOnlySpan<byte> input, Span<byte> output, float gain, bool preventClipping,
+        LimiterProcessor? limiter, int channels)
     {
         var sampleCount = input.Length / 4;
         for (var i = 0; i < sampleCount; i++)
@@ -198,7 +270,10 @@ public sealed class NormalizationProcessor : IAudioProcessor
             var sample = input[sampleIndex] | (input[sampleIndex + 1] << 8) |
                         (input[sampleIndex + 2] << 16) | (input[sampleIndex + 3] << 24);
 
-            var processed = (double)sample * gain;
+            var processed = gain == 1.0f ? sample : (double)sample * gain;
+
+            if (limiter != null)
+                processed = limiter.LimitSample(processed / 2147483648.0, i % channels) * 2147483648.0;
 
             if (preventClipping && Math.Abs(processed) > int.MaxValue)
             {
@@ -0,0 +1,217 @@
+using System.Buffers.Binary;
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Audio.Processors;
+
+/// <summary>
+/// Read-only analysis at the end of the chain that reports sustained digital silence
+/// and clipping in the processed audio. Silence that shows up here came from the
+/// source or decoder; a network problem shows up as a stall instead, and a device
+/// problem leaves this audio intact. Off (and skipped by the chain) by default.
+/// </summary>
+public sealed class OutputAnalysisProcessor : IAudioProcessor
+{
+    /// <summary>
+    /// Default for <see cref="SilenceThresholdMs"/>.
+    /// </summary>
+    public const int DefaultSilenceThresholdMs = 3000;
+
+    // Within one 16-bit step of zero, so dither and rounding noise still count as silence.
+    private const float SilenceLevel = 2f / 32768f;
+
+    // A sample at full scale, on any bit depth.
+    private const float ClipLevel = 32767f / 32768f;
+
+    // Single full-scale peaks are normal in loud masters; three samples pinned in a
+    // row on one channel is a clipped waveform.
+    private const int ClipRunSamples = 3;
+
+    // Clipped runs per one-second window for the window to count as clipping.
+    private const int ClipRunsPerWindow = 5;
+
+    private readonly Subject<OutputDiagnostic> _diagnostics = new();
+
+    private AudioFormat? _format;
+    private int[] _clipRunLength = [];
+    private long _basePositionMs;
+    private long _positionFrames;
+
+    private long _silentFrames;
+    private bool _silenceReported;
+
+    private long _windowFrames;
+    private int _windowRuns;
+    private long? _clippingStartFrame;
+    private int _clippingRuns;
+
+    public string ProcessorName => "OutputAnalysis";
+    public bool IsEnabled { get; set; }
+
+    /// <summary>
+    /// How long the output must stay silent before it is reported, in milliseconds.
+    /// </summary>
+    public int SilenceThresholdMs { get; set; } = DefaultSilenceThresholdMs;
+
+    /// <summary>
+    /// Silence and clipping reports, on the playback thread. Subscribers must not block.
+    /// </summary>
+    public IObservable<OutputDiagnostic> Diagnostics => _diagnostics.AsObservable();
+
+    public Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
+    {
+        Reset();
+        _format = format;
+        _clipRunLength = new int[format.Channels];
+        return Task.CompletedTask;
+    }
+
+    /// <summary>
+    /// Playback moved to <paramref name="positionMs"/> (track start, seek, loop).
+    /// Ends any open report.
+    /// </summary>
+    public void Rebase(long positionMs)
+    {
+        Reset();
+        _basePositionMs = positionMs;
+        _positionFrames = 0;
+    }
+
+    public AudioBuffer Process(AudioBuffer input)
+    {
+        if (_format == null)
+            throw new InvalidOperationException("Processor not initialized");
+
+        Analyze(input.Data.Span);
+        return input;
+    }
+
+    public void ProcessInPlace(Span<byte> data) => Analyze(data);
+
+    public void Reset()
+    {
+        if (_silenceReported)
+            Publish(OutputDiagnosticKind.Silence, _positionFrames - _silentFrames, _silentFrames, ended: true, 0);
+        if (_clippingStartFrame is { } start)
+            Publish(OutputDiagnosticKind.Clipping, start, _positionFrames - start, ended: true, _clippingRuns);
+
+        _silentFrames = 0;
+        _silenceReported = false;
+        _windowFrames = 0;
+        _windowRuns = 0;
+        _clippingStartFrame = null;
+        _clippingRuns = 0;
+        Array.Clear(_clipRunLength);
+    }
+
+    private void Analyze(ReadOnlySpan<byte> data)
+    {
+        if (_format is not { } format || data.Length == 0)
+            return;
+
+        var bytesPerSample = format.BitsPerSample / 8;
+        var bytesPerFrame = format.BytesPerFrame;
+        var frameCount = data.Length / bytesPerFrame;
+        var silenceFrames = (long)SilenceThresholdMs * format.SampleRate / 1000;
+
+        for (var frame = 0; frame < frameCount; frame++)
+        {
+            var offset = frame * bytesPerFrame;
+            var silent = true;
+            for (var ch = 0; ch < format.Channels; ch++)
+            {
+                var level = Math.Abs(ReadSample(data.Slice(offset + ch * bytesPerSample, bytesPerSample), format.BitsPerSample));
+                if (level > SilenceLevel)
+                    silent = false;
+
+                if (level < ClipLevel)
+                    _clipRunLength[ch] = 0;
+                else if (++_clipRunLength[ch] == ClipRunSamples)
+                    _windowRuns++;
+            }
+
+            _positionFrames++;
+
+            if (silent)
+            {
+                if (++_silentFrames >= silenceFrames && !_silenceReported)
+                {
+                    _silenceReported = true;
+                    Publish(OutputDiagnosticKind.Silence, _positionFrames - _silentFrames, _silentFrames, ended: false, 0);
+                }
+            }
+            else if (_silentFrames > 0)
+            {
+                if (_silenceReported)
+                    Publish(OutputDiagnosticKind.Silence, _positionFrames - 1 - _silentFrames, _silentFrames, ended: true, 0);
+                _silentFrames = 0;
+                _silenceReported = false;
+            }
+
+            if (++_windowFrames >= format.SampleRate)
+                CloseClipWindow();
+        }
+    }
+
+    private void CloseClipWindow()
+    {
+        var windowStart = _positionFrames - _windowFrames;
+        if (_windowRuns >= ClipRunsPerWindow)
+        {
+            var started = _clippingStartFrame == null;
+            _clippingStartFrame ??= windowStart;
+            _clippingRuns += _windowRuns;
+            if (started)
+                Publish(OutputDiagnosticKind.Clipping, windowStart, _windowFrames, ended: false, _clippingRuns);
+        }
+        else if (_clippingStartFrame is { } start)
+        {
+            Publish(OutputDiagnosticKind.Clipping, start, windowStart - start, ended: true, _clippingRuns);
+            _clippingStartFrame = null;
+            _clippingRuns = 0;
+        }
+
+        _windowFrames = 0;
+        _windowRuns = 0;
+    }
+
+    private void Publish(OutputDiagnosticKind kind, long startFrame, long frames, bool ended, int clippedRuns)
+    {
+        if (_format is not { } format)
+            return;
+
+        _diagnostics.OnNext(new OutputDiagnostic(
+            kind,
+            _basePositionMs + startFrame * 1000 / format.SampleRate,
+            frames * 1000 / format.SampleRate,
+            ended,
+            clippedRuns));
+    }
+
+    private static float ReadSample(ReadOnlySpan<byte> sample, int bitsPerSample) => bitsPerSample switch
+    {
+        16 => BinaryPrimitives.ReadInt16LittleEndian(sample) / 32768f,
+        24 => ((sample[0] | (sample[1] << 8) | (sample[2] << 16)) << 8 >> 8) / 8388608f,
+        32 => BinaryPrimitives.ReadInt32LittleEndian(sample) / 2147483648f,
+        _ => 0f
+    };
+}
+
+/// <summary>
+/// Sustained silence or clipping seen by <see cref="OutputAnalysisProcessor"/>.
+/// </summary>
+/// <param name="Kind">What was seen.</param>
+/// <param name="PositionMs">Track position where it started.</param>
+/// <param name="DurationMs">How long it has lasted so far, or in total when <paramref name="Ended"/>.</param>
+/// <param name="Ended">False when first detected, true once it has cleared.</param>
+/// <param name="ClippedRuns">Runs of clipped samples counted; 0 for silence.</param>
+/// <param name="TrackUri">Track playing, filled in by <see cref="AudioEngine"/>.</param>
+public sealed record OutputDiagnostic(
+    OutputDiagnosticKind Kind,
+    long PositionMs,
+    long DurationMs,
+    bool Ended,
+    int ClippedRuns,
+    string? TrackUri = null);
@@ -0,0 +1,231 @@
+using System.Buffers.Binary;
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Audio.Processors;
+
+/// <summary>
+/// Read-only tap at the end of the chain that turns the processed audio into
+/// visualization frames — peak waveform points or log-spaced FFT magnitudes — at a
+/// fixed rate. Never modifies the audio; off (and skipped by the chain) by default.
+/// </summary>
+/// <remarks>
+/// Frames are built on the playback thread as the audio is written, so they lead the
+/// speaker by the sink's buffer; each carries the track position it ends at.
+/// </remarks>
+public sealed class PcmTapProcessor : IAudioProcessor
+{
+    /// <summary>
+    /// FFT length for <see cref="PcmTapMode.Spectrum"/>.
+    /// </summary>
+    public const int FftLength = 2048;
+
+    private const int MaxRateHz = 60;
+    private const int MaxSize = 1024;
+    private const float MinFrequencyHz = 20f;
+    private const float MaxFrequencyHz = 20000f;
+    private const float FloorDb = -90f;
+
+    private static readonly float[] HannWindow = Fft.CreateHannWindow(FftLength);
+
+    // Hann's coherent gain is 0.5, so a full-scale sine peaks at N/4 in the transform.
+    private const float SpectrumScale = 4f / FftLength;
+
+    private sealed record Settings(PcmTapMode Mode, int RateHz, int Size);
+
+    private readonly Subject<PcmTapFrame> _frames = new();
+    private readonly float[] _real = new float[FftLength];
+    private readonly float[] _imaginary = new float[FftLength];
+
+    private volatile Settings _settings = new(PcmTapMode.Off, 30, 64);
+    private AudioFormat? _format;
+
+    // Mono mix of the most recent audio; at least one second and one FFT window long.
+    private float[] _history = [];
+    private int _historyWrite;
+    private int _historyCount;
+
+    private long _framesSinceEmit;
+    private long _basePositionMs;
+    private long _positionFrames;
+    private long _sequence;
+
+    public string ProcessorName => "PcmTap";
+    public bool IsEnabled { get; set; }
+
+    /// <summary>
+    /// Current mode.
+    /// </summary>
+    public PcmTapMode Mode => _settings.Mode;
+
+    /// <summary>
+    /// Frames per second.
+    /// </summary>
+    public int RateHz => _settings.RateHz;
+
+    /// <summary>
+    /// Values per frame.
+    /// </summary>
+    public int Size => _settings.Size;
+
+    /// <summary>
+    /// Published frames, on the playback thread. Subscribers must not block.
+    /// </summary>
+    public IObservable<PcmTapFrame> Frames => _frames.AsObservable();
+
+    /// <summary>
+    /// Sets what the tap publishes. Takes effect from the next buffer.
+    /// </summary>
+    /// <param name="mode">Frame content, or <see cref="PcmTapMode.Off"/>.</param>
+    /// <param name="rateHz">Frames per second, clamped to 1-60.</param>
+    /// <param name="size">Values per frame, clamped to 1-1024.</param>
+    public void Configure(PcmTapMode mode, int rateHz, int size)
+    {
+        _settings = new Settings(mode, Math.Clamp(rateHz, 1, MaxRateHz), Math.Clamp(size, 1, MaxSize));
+        IsEnabled = mode != PcmTapMode.Off;
+    }
+
+    public Task InitializeAsync(AudioFormat format, CancellationToken cancellationToken = default)
+    {
+        _format = format;
+        _history = new float[Math.Max(FftLength, format.SampleRate)];
+        Reset();
+        return Task.CompletedTask;
+    }
+
+    /// <summary>
+    /// Playback moved to <paramref name="positionMs"/> (track start, seek, loop).
+    /// </summary>
+    public void Rebase(long positionMs)
+    {
+        _basePositionMs = positionMs;
+        _positionFrames = 0;
+    }
+
+    public AudioBuffer Process(AudioBuffer input)
+    {
+        if (_format == null)
+            throw new InvalidOperationException("Processor not initialized");
+
+        Analyze(input.Data.Span);
+        return input;
+    }
+
+    public void ProcessInPlace(Span<byte> data) => Analyze(data);
+
+    public void Reset()
+    {
+        Array.Clear(_history);
+        _historyWrite = 0;
+        _historyCount = 0;
+        _framesSinceEmit = 0;
+    }
+
+    private void Analyze(ReadOnlySpan<byte> data)
+    {
+        var settings = _settings;
+        if (_format is not { } format || settings.Mode == PcmTapMode.Off || data.Length == 0)
+            return;
+
+        var bytesPerSample = format.BitsPerSample / 8;
+        var bytesPerFrame = format.BytesPerFrame;
+        var frameCount = data.Length / bytesPerFrame;
+        var interval = Math.Max(1, format.SampleRate / settings.RateHz);
+
+        for (var frame = 0; frame < frameCount; frame++)
+        {
+            var offset = frame * bytesPerFrame;
+            var mixed = 0f;
+            for (var ch = 0; ch < format.Channels; ch++)
+                mixed += ReadSample(data.Slice(offset + ch * bytesPerSample, bytesPerSample));
+
+            _history[_historyWrite] = mixed / format.Channels;
+            _historyWrite = (_historyWrite + 1) % _history.Length;
+            _historyCount = Math.Min(_historyCount + 1, _history.Length);
+            _positionFrames++;
+
+            if (++_framesSinceEmit < interval)
+                continue;
+
+            _framesSinceEmit = 0;
+            var values = settings.Mode == PcmTapMode.Spectrum
+                ? BuildSpectrum(format, settings.Size)
+                : BuildWaveform(interval, settings.Size);
+            _frames.OnNext(new PcmTapFrame
+            {
+                Mode = settings.Mode,
+                Sequence = ++_sequence,
+                PositionMs = _basePositionMs + _positionFrames * 1000 / format.SampleRate,
+                Values = values
+            });
+        }
+    }
+
+    // Sample i of the last `count` mono samples, oldest first.
+    private float History(int count, int i)
+        => _history[(_historyWrite - count + i + _history.Length) % _history.Length];
+
+    private float[] BuildWaveform(int interval, int size)
+    {
+        var count = Math.Min(interval, _historyCount);
+        var values = new float[size];
+        for (var i = 0; i < size; i++)
+        {
+            var start = (int)((long)i * count / size);
+            var end = Math.Max(start + 1, (int)((long)(i + 1) * count / size));
+            var peak = 0f;
+            for (var s = start; s < end && s < count; s++)
+            {
+                var sample = History(count, s);
+                if (Math.Abs(sample) > Math.Abs(peak))
+                    peak = sample;
+            }
+
+            values[i] = peak;
+        }
+
+        return values;
+    }
+
+    private float[] BuildSpectrum(AudioFormat format, int size)
+    {
+        var count = Math.Min(FftLength, _historyCount);
+        var pad = FftLength - count;
+        Array.Clear(_real, 0, pad);
+        for (var i = 0; i < count; i++)
+            _real[pad + i] = History(count, i) * HannWindow[pad + i];
+        Array.Clear(_imaginary);
+
+        Fft.Transform(_real, _imaginary);
+
+        var sampleRate = format.SampleRate;
+        var maxFrequency = Math.Min(MaxFrequencyHz, sampleRate / 2f);
+        var values = new float[size];
+        for (var band = 0; band < size; band++)
+        {
+            var low = Fft.LogLerp(MinFrequencyHz, maxFrequency, band / (float)size);
+            var high = Fft.LogLerp(MinFrequencyHz, maxFrequency, (band + 1) / (float)size);
+            var startBin = Math.Clamp((int)MathF.Floor(low * FftLength / sampleRate), 1, (FftLength / 2) - 1);
+            var endBin = Math.Clamp((int)MathF.Ceiling(high * FftLength / sampleRate), startBin + 1, FftLength / 2);
+
+            var peak = 0f;
+            for (var bin = startBin; bin < endBin; bin++)
+                peak = MathF.Max(peak, MathF.Sqrt((_real[bin] * _real[bin]) + (_imaginary[bin] * _imaginary[bin])));
+
+            var db = 20f * MathF.Log10(MathF.Max(peak * SpectrumScale, 1e-9f));
+            values[band] = Math.Clamp((db - FloorDb) / -FloorDb, 0f, 1f);
+        }
+
+        return values;
+    }
+
+    private float ReadSample(ReadOnlySpan<byte> sample) => _format!.BitsPerSample switch
+    {
+        16 => BinaryPrimitives.ReadInt16LittleEndian(sample) / 32768f,
+        24 => ((sample[0] | (sample[1] << 8) | (sample[2] << 16)) << 8 >> 8) / 8388608f,
+        32 => BinaryPrimitives.ReadInt32LittleEndian(sample) / 2147483648f,
+        _ => 0f
+    };
+}
@@ -0,0 +1,396 @@
+using System.Runtime.InteropServices;
+using System.Runtime.Versioning;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Audio.Sinks;
+
+/// <summary>
+/// Android output through AAudio (the NDK's <c>libaaudio.so</c>, API 26+), the
+/// backend Oboe wraps on current devices.
+/// </summary>
+/// <remarks>
+/// Uses AAudio's own buffer with non-blocking writes instead of a callback, so the
+/// decode loop gets backpressure the same way it does from <see cref="PortAudioSink"/>.
+/// The stream starts once that buffer is full, or on <see cref="DrainAsync"/> for
+/// tracks shorter than it. Interruptions (calls, lost audio focus) are the host app's
+/// to report through the orchestrator; the OS doesn't pause the stream for us.
+/// </remarks>
+[SupportedOSPlatform("android26.0")]
+public sealed class AAudioSink : IAudioSink
+{
+    private const int FullBufferPollMs = 5;
+    private const int DrainTimeoutMs = 10_000;
+
+    private readonly ILogger? _logger;
+    private readonly object _lock = new();
+    private IntPtr _stream;
+    private AudioFormat? _format;
+    private long _baseFrames;
+    private long _basePositionMs;
+    private bool _started;
+    private bool _paused;
+    private long _underrunsBeforeStream;
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a new AAudioSink.
+    /// </summary>
+    /// <param name="logger">Optional logger.</param>
+    public AAudioSink(ILogger? logger = null)
+    {
+        _logger = logger;
+    }
+
+    /// <inheritdoc />
+    public string SinkName => "AAudio";
+
+    public AudioFormat? Format => _format;
+
+    /// <inheritdoc />
+    public long PlaybackPositionMs
+    {
+        get
+        {
+            lock (_lock)
+            {
+                if (_stream == IntPtr.Zero || _format == null)
+                    return _basePositionMs;
+
+                var played = Math.Max(0, Native.AAudioStream_getFramesRead(_stream) - _baseFrames);
+                return _basePositionMs + played * 1000 / _format.SampleRate;
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public long UnderrunCount
+    {
+        get
+        {
+            lock (_lock)
+            {
+                return _underrunsBeforeStream
+                       + (_stream == IntPtr.Zero ? 0 : Math.Max(0, Native.AAudioStream_getXRunCount(_stream)));
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public Task InitializeAsync(AudioFormat format, int bufferSizeMs = 100, CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+        if (format.BitsPerSample != 16)
+            throw new NotSupportedException($"AAudio sink takes 16-bit PCM, got {format.BitsPerSample}-bit");
+
+        lock (_lock)
+        {
+            CloseStream();
+
+            Check(Native.AAudio_createStreamBuilder(out var builder), "create stream builder");
+            try
+            {
+                Native.AAudioStreamBuilder_setFormat(builder, Native.FormatPcmI16);
+                Native.AAudioStreamBuilder_setChannelCount(builder, format.Channels);
+                Native.AAudioStreamBuilder_setSampleRate(builder, format.SampleRate);
+                Native.AAudioStreamBuilder_setPerformanceMode(builder, Native.PerformanceModePowerSaving);
+                // Same 2x margin PortAudioSink gives its ring buffer.
+                Native.AAudioStreamBuilder_setBufferCapacityInFrames(
+                    builder, (int)((long)format.SampleRate * bufferSizeMs * 2 / 1000));
+                Check(Native.AAudioStreamBuilder_openStream(builder, out _stream), "open stream");
+            }
+            finally
+            {
+                Native.AAudioStreamBuilder_delete(builder);
+            }
+
+            _format = format;
+            _started = false;
+            _baseFrames = 0;
+            _basePositionMs = 0;
+        }
+
+        _logger?.LogDebug("AAudio stream opened: {SampleRate}Hz {Channels}ch", format.SampleRate, format.Channels);
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public async Task WriteAsync(ReadOnlyMemory<byte> audioData, CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+        var format = _format ?? throw new InvalidOperationException("Sink not initialized");
+
+        var frames = audioData.Length / format.BytesPerFrame;
+        var written = 0;
+        while (written < frames)
+        {
+            cancellationToken.ThrowIfCancellationRequested();
+            var count = WriteFrames(audioData[(written * format.BytesPerFrame)..], frames - written);
+            written += count;
+            if (written < frames)
+            {
+                // Buffer full: that's the cue to start, unless the user paused.
+                StartIfReady();
+                await Task.Delay(FullBufferPollMs, cancellationToken);
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public Task<AudioSinkStatus> GetStatusAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            if (_stream == IntPtr.Zero || _format == null)
+                return Task.FromResult(new AudioSinkStatus(0, 0, false));
+
+            var bufferedFrames = Native.AAudioStream_getFramesWritten(_stream) - Native.AAudioStream_getFramesRead(_stream);
+            var bufferedMs = (int)(Math.Max(0, bufferedFrames) * 1000 / _format.SampleRate);
+            return Task.FromResult(new AudioSinkStatus(PlaybackPositionMs, bufferedMs, _started && !_paused));
+        }
+    }
+
+    /// <inheritdoc />
+    public Task PauseAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            _paused = true;
+            if (_stream != IntPtr.Zero && _started)
+                LogIfFailed(Native.AAudioStream_requestPause(_stream), "pause");
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public Task<bool> ResumeAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            _paused = false;
+            if (_stream == IntPtr.Zero)
+                return Task.FromResult(false);
+
+            var result = Native.AAudioStream_requestStart(_stream);
+            _started = result == Native.Ok;
+            LogIfFailed(result, "start");
+            return Task.FromResult(_started);
+        }
+    }
+
+    /// <inheritdoc />
+    public Task FlushAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            if (_stream == IntPtr.Zero)
+                return Task.CompletedTask;
+
+            // AAudio only flushes a paused stream; the next full buffer restarts it.
+            if (_started)
+                LogIfFailed(Native.AAudioStream_requestPause(_stream), "pause");
+            LogIfFailed(Native.AAudioStream_requestFlush(_stream), "flush");
+            _started = false;
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public void SetBasePosition(long positionMs)
+    {
+        lock (_lock)
+        {
+            _basePositionMs = positionMs;
+            // The next frame written plays at positionMs.
+            _baseFrames = _stream == IntPtr.Zero ? 0 : Native.AAudioStream_getFramesWritten(_stream);
+        }
+    }
+
+    /// <inheritdoc />
+    public async Task DrainAsync(CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        StartIfReady(force: true);
+        var deadline = Environment.TickCount64 + DrainTimeoutMs;
+        while (Environment.TickCount64 < deadline)
+        {
+            lock (_lock)
+            {
+                if (_stream == IntPtr.Zero
+                    || Native.AAudioStream_getFramesRead(_stream) >= Native.AAudioStream_getFramesWritten(_stream))
+                {
+                    return;
+                }
+            }
+
+            await Task.Delay(FullBufferPollMs * 2, cancellationToken);
+        }
+
+        _logger?.LogWarning("AAudio drain timed out after {Timeout}ms", DrainTimeoutMs);
+    }
+
+    /// <inheritdoc />
+    public Task ReleaseAsync()
+    {
+        if (_disposed)
+            return Task.CompletedTask;
+
+        lock (_lock)
+        {
+            CloseStream();
+        }
+
+        _logger?.LogDebug("AAudio stream released");
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public ValueTask DisposeAsync()
+    {
+        if (_disposed)
+            return ValueTask.CompletedTask;
+
+        lock (_lock)
+        {
+            CloseStream();
+            _disposed = true;
+        }
+
+        return ValueTask.CompletedTask;
+    }
+
+    private unsafe int WriteFrames(ReadOnlyMemory<byte> data, int frames)
+    {
+        lock (_lock)
+        {
+            if (_stream == IntPtr.Zero)
+                throw new InvalidOperationException("Sink not initialized");
+
+            using var pin = data.Pin();
+            var result = Native.AAudioStream_write(_stream, (IntPtr)pin.Pointer, frames, 0);
+            if (result < 0)
+                throw new InvalidOperationException($"AAudio write failed: {Native.ResultText(result)}");
+            return result;
+        }
+    }
+
+    private void StartIfReady(bool force = false)
+    {
+        lock (_lock)
+        {
+            if (_stream == IntPtr.Zero || _started || _paused)
+                return;
+
+            var result = Native.AAudioStream_requestStart(_stream);
+            _started = result == Native.Ok;
+            if (_started)
+                _logger?.LogDebug("AAudio playback started{Reason}", force ? " (drain)" : string.Empty);
+            else
+                LogIfFailed(result, "start");
+        }
+    }
+
+    private void CloseStream()
+    {
+        if (_stream == IntPtr.Zero)
+            return;
+
+        _underrunsBeforeStream += Math.Max(0, Native.AAudioStream_getXRunCount(_stream));
+        Native.AAudioStream_requestStop(_stream);
+        Native.AAudioStream_close(_stream);
+        _stream = IntPtr.Zero;
+        _started = false;
+    }
+
+    private static void Check(int result, string operation)
+    {
+        if (result != Native.Ok)
+            throw new InvalidOperationException($"AAudio failed to {operation}: {Native.ResultText(result)}");
+    }
+
+    private void LogIfFailed(int result, string operation)
+    {
+        if (result != Native.Ok)
+            _logger?.LogWarning("AAudio failed to {Operation}: {Error}", operation, Native.ResultText(result));
+    }
+
+    /// <summary>
+    /// The slice of the AAudio C API the sink needs (<c>aaudio/AAudio.h</c>).
+    /// </summary>
+    private static class Native
+    {
+        private const string Library = "aaudio";
+
+        public const int Ok = 0;
+        public const int FormatPcmI16 = 1;
+        public const int PerformanceModePowerSaving = 11;
+
+        [DllImport(Library)]
+        public static extern int AAudio_createStreamBuilder(out IntPtr builder);
+
+        [DllImport(Library)]
+        public static extern void AAudioStreamBuilder_setFormat(IntPtr builder, int format);
+
+        [DllImport(Library)]
+        public static extern void AAudioStreamBuilder_setChannelCount(IntPtr builder, int channelCount);
+
+        [DllImport(Library)]
+        public static extern void AAudioStreamBuilder_setSampleRate(IntPtr builder, int sampleRate);
+
+        [DllImport(Library)]
+        public static extern void AAudioStreamBuilder_setPerformanceMode(IntPtr builder, int mode);
+
+        [DllImport(Library)]
+        public static extern void AAudioStreamBuilder_setBufferCapacityInFrames(IntPtr builder, int numFrames);
+
+        [DllImport(Library)]
+        public static extern int AAudioStreamBuilder_openStream(IntPtr builder, out IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStreamBuilder_delete(IntPtr builder);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_requestStart(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_requestPause(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_requestFlush(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_requestStop(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_close(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_write(IntPtr stream, IntPtr buffer, int numFrames, long timeoutNanoseconds);
+
+        [DllImport(Library)]
+        public static extern long AAudioStream_getFramesWritten(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern long AAudioStream_getFramesRead(IntPtr stream);
+
+        [DllImport(Library)]
+        public static extern int AAudioStream_getXRunCount(IntPtr stream);
+
+        [DllImport(Library)]
+        private static extern IntPtr AAudio_convertResultToText(int result);
+
+        public static string ResultText(int result)
+            => Marshal.PtrToStringUTF8(AAudio_convertResultToText(result)) ?? result.ToString();
+    }
+}
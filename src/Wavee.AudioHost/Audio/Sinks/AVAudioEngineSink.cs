@@ -0,0 +1,475 @@
+using System.Runtime.InteropServices;
+using System.Runtime.Versioning;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Audio.Sinks;
+
+/// <summary>
+/// iOS output through an <c>AVAudioEngine</c> with one <c>AVAudioPlayerNode</c> feeding
+/// the main mixer, driven through the Objective-C runtime so no platform bindings are needed.
+/// </summary>
+/// <remarks>
+/// Each write becomes a scheduled <c>AVAudioPCMBuffer</c>; writes wait while more than
+/// the requested buffer size is queued, and played frames come from the node's player
+/// time. The sink sets the <c>AVAudioSession</c> category to playback so audio continues
+/// in the background (the app also needs the <c>audio</c> background mode). Interruptions
+/// such as an incoming call are observed by the app and reported through
+/// PlaybackOrchestrator.BeginAudioSessionInterruptionAsync / EndAudioSessionInterruptionAsync.
+/// </remarks>
+[SupportedOSPlatform("ios")]
+public sealed class AVAudioEngineSink : IAudioSink
+{
+    private const int QueuePollMs = 5;
+    private const int DrainTimeoutMs = 10_000;
+
+    private readonly ILogger? _logger;
+    private readonly object _lock = new();
+    private IntPtr _engine;
+    private IntPtr _player;
+    private IntPtr _nodeFormat;
+    private AudioFormat? _format;
+    private long _capacityFrames;
+    private long _framesScheduled;
+    private long _framesPlayed;
+    private long _baseFrames;
+    private long _basePositionMs;
+    private bool _playing;
+    private bool _paused;
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a new AVAudioEngineSink.
+    /// </summary>
+    /// <param name="logger">Optional logger.</param>
+    public AVAudioEngineSink(ILogger? logger = null)
+    {
+        _logger = logger;
+    }
+
+    /// <inheritdoc />
+    public string SinkName => "AVAudioEngine";
+
+    public AudioFormat? Format => _format;
+
+    /// <inheritdoc />
+    public long PlaybackPositionMs
+    {
+        get
+        {
+            lock (_lock)
+            {
+                if (_format == null)
+                    return _basePositionMs;
+
+                var played = Math.Max(0, FramesPlayed() - _baseFrames);
+                return _basePositionMs + played * 1000 / _format.SampleRate;
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public Task InitializeAsync(AudioFormat format, int bufferSizeMs = 100, CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+        if (format.BitsPerSample != 16)
+            throw new NotSupportedException($"AVAudioEngine sink takes 16-bit PCM, got {format.BitsPerSample}-bit");
+
+        lock (_lock)
+        {
+            Close();
+            ObjC.ActivatePlaybackSession(_logger);
+
+            _engine = ObjC.New("AVAudioEngine");
+            _player = ObjC.New("AVAudioPlayerNode");
+            ObjC.Send(_engine, "attachNode:", _player);
+
+            // The mixer takes the standard format: Float32, one buffer per channel.
+            _nodeFormat = ObjC.SendInitFormat(
+                ObjC.Send(ObjC.Class("AVAudioFormat"), "alloc"),
+                ObjC.PcmFormatFloat32,
+                format.SampleRate,
+                (uint)format.Channels,
+                interleaved: false);
+            ObjC.Send(_engine, "connect:to:format:", _player, ObjC.Send(_engine, "mainMixerNode"), _nodeFormat);
+
+            if (!ObjC.SendStart(_engine, out var error))
+            {
+                var message = ObjC.Describe(error);
+                Close();
+                throw new InvalidOperationException($"AVAudioEngine failed to start: {message}");
+            }
+
+            _format = format;
+            // Same 2x margin PortAudioSink gives its ring buffer.
+            _capacityFrames = (long)format.SampleRate * bufferSizeMs * 2 / 1000;
+            _framesScheduled = 0;
+            _framesPlayed = 0;
+            _baseFrames = 0;
+            _basePositionMs = 0;
+        }
+
+        _logger?.LogDebug("AVAudioEngine started: {SampleRate}Hz {Channels}ch", format.SampleRate, format.Channels);
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public async Task WriteAsync(ReadOnlyMemory<byte> audioData, CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+        var format = _format ?? throw new InvalidOperationException("Sink not initialized");
+
+        // Backpressure: wait for the node to play down to the target queue depth.
+        while (QueuedFrames() >= _capacityFrames)
+        {
+            // A full queue is the cue to start, unless the user paused.
+            StartIfReady();
+            await Task.Delay(QueuePollMs, cancellationToken);
+        }
+
+        var frames = audioData.Length / format.BytesPerFrame;
+        if (frames > 0)
+            Schedule(audioData.Span[..(frames * format.BytesPerFrame)], frames, format.Channels);
+    }
+
+    /// <inheritdoc />
+    public Task<AudioSinkStatus> GetStatusAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            if (_format == null)
+                return Task.FromResult(new AudioSinkStatus(0, 0, false));
+
+            var bufferedMs = (int)(QueuedFrames() * 1000 / _format.SampleRate);
+            return Task.FromResult(new AudioSinkStatus(PlaybackPositionMs, bufferedMs, _playing && !_paused));
+        }
+    }
+
+    /// <inheritdoc />
+    public Task PauseAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            _paused = true;
+            if (_player != IntPtr.Zero && _playing)
+                ObjC.Send(_player, "pause");
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public Task<bool> ResumeAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            _paused = false;
+            if (_player == IntPtr.Zero)
+                return Task.FromResult(false);
+
+            // The engine stops on its own when the session is interrupted or the route changes.
+            if (!ObjC.SendBool(_engine, "isRunning") && !ObjC.SendStart(_engine, out var error))
+            {
+                _logger?.LogWarning("AVAudioEngine failed to restart: {Error}", ObjC.Describe(error));
+                return Task.FromResult(false);
+            }
+
+            ObjC.Send(_player, "play");
+            _playing = true;
+            return Task.FromResult(true);
+        }
+    }
+
+    /// <inheritdoc />
+    public Task FlushAsync()
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        lock (_lock)
+        {
+            if (_player == IntPtr.Zero)
+                return Task.CompletedTask;
+
+            // stop drops every scheduled buffer and resets the player time; the next full
+            // queue starts it again.
+            ObjC.Send(_player, "stop");
+            _playing = false;
+            _framesScheduled = 0;
+            _framesPlayed = 0;
+            _baseFrames = 0;
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public void SetBasePosition(long positionMs)
+    {
+        lock (_lock)
+        {
+            _basePositionMs = positionMs;
+            // The next frame scheduled plays at positionMs.
+            _baseFrames = _framesScheduled;
+        }
+    }
+
+    /// <inheritdoc />
+    public async Task DrainAsync(CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        StartIfReady();
+        var deadline = Environment.TickCount64 + DrainTimeoutMs;
+        while (Environment.TickCount64 < deadline)
+        {
+            if (QueuedFrames() <= 0)
+                return;
+
+            await Task.Delay(QueuePollMs * 2, cancellationToken);
+        }
+
+        _logger?.LogWarning("AVAudioEngine drain timed out after {Timeout}ms", DrainTimeoutMs);
+    }
+
+    /// <inheritdoc />
+    public Task ReleaseAsync()
+    {
+        if (_disposed)
+            return Task.CompletedTask;
+
+        lock (_lock)
+        {
+            Close();
+        }
+
+        _logger?.LogDebug("AVAudioEngine released");
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc />
+    public ValueTask DisposeAsync()
+    {
+        if (_disposed)
+            return ValueTask.CompletedTask;
+
+        lock (_lock)
+        {
+            Close();
+            _disposed = true;
+        }
+
+        return ValueTask.CompletedTask;
+    }
+
+    private unsafe void Schedule(ReadOnlySpan<byte> pcm, int frames, int channels)
+    {
+        lock (_lock)
+        {
+            if (_player == IntPtr.Zero)
+                throw new InvalidOperationException("Sink not initialized");
+
+            var buffer = ObjC.SendInitBuffer(ObjC.Send(ObjC.Class("AVAudioPCMBuffer"), "alloc"), _nodeFormat, (uint)frames);
+            try
+            {
+                ObjC.SendUInt(buffer, "setFrameLength:", (uint)frames);
+
+                // Interleaved Int16 in, one Float32 plane per channel out.
+                var planes = (float**)ObjC.Send(buffer, "floatChannelData");
+                var samples = MemoryMarshal.Cast<byte, short>(pcm);
+                for (var channel = 0; channel < channels; channel++)
+                {
+                    var plane = planes[channel];
+                    for (var frame = 0; frame < frames; frame++)
+                        plane[frame] = samples[frame * channels + channel] / 32768f;
+                }
+
+                // No completion handler: progress comes from the player time instead.
+                ObjC.Send(_player, "scheduleBuffer:completionHandler:", buffer, IntPtr.Zero);
+                _framesScheduled += frames;
+            }
+            finally
+            {
+                // The node retains scheduled buffers.
+                ObjC.Send(buffer, "release");
+            }
+        }
+    }
+
+    private long QueuedFrames()
+    {
+        lock (_lock)
+        {
+            return _player == IntPtr.Zero ? 0 : Math.Max(0, _framesScheduled - FramesPlayed());
+        }
+    }
+
+    private long FramesPlayed()
+    {
+        // The player time is nil while paused or stopped; the last reading holds then.
+        if (_player == IntPtr.Zero || !_playing || _paused)
+            return _framesPlayed;
+
+        var nodeTime = ObjC.Send(_player, "lastRenderTime");
+        var playerTime = nodeTime == IntPtr.Zero ? IntPtr.Zero : ObjC.Send(_player, "playerTimeForNodeTime:", nodeTime);
+        if (playerTime != IntPtr.Zero && ObjC.SendBool(playerTime, "isSampleTimeValid"))
+            _framesPlayed = Math.Min(ObjC.SendLong(playerTime, "sampleTime"), _framesScheduled);
+        return _framesPlayed;
+    }
+
+    private void StartIfReady()
+    {
+        lock (_lock)
+        {
+            if (_player == IntPtr.Zero || _playing || _paused)
+                return;
+
+            ObjC.Send(_player, "play");
+            _playing = true;
+            _logger?.LogDebug("AVAudioEngine playback started");
+        }
+    }
+
+    private void Close()
+    {
+        if (_engine != IntPtr.Zero)
+        {
+            ObjC.Send(_player, "stop");
+            ObjC.Send(_engine, "stop");
+            ObjC.Send(_player, "release");
+            ObjC.Send(_nodeFormat, "release");
+            ObjC.Send(_engine, "release");
+        }
+
+        _engine = IntPtr.Zero;
+        _player = IntPtr.Zero;
+        _nodeFormat = IntPtr.Zero;
+        _format = null;
+        _playing = false;
+    }
+
+    /// <summary>
+    /// Minimal Objective-C runtime bridge for the AVFoundation calls above.
+    /// </summary>
+    private static class ObjC
+    {
+        private const string Runtime = "/usr/lib/libobjc.dylib";
+        private const string AVFoundation = "/System/Library/Frameworks/AVFoundation.framework/AVFoundation";
+
+        public const nuint PcmFormatFloat32 = 1;
+
+        private static readonly IntPtr AVFoundationHandle = NativeLibrary.Load(AVFoundation);
+
+        public static IntPtr Class(string name)
+        {
+            _ = AVFoundationHandle;
+            var cls = objc_getClass(name);
+            return cls != IntPtr.Zero ? cls : throw new PlatformNotSupportedException($"{name} is not available");
+        }
+
+        public static IntPtr New(string className) => Send(Send(Class(className), "alloc"), "init");
+
+        public static IntPtr Send(IntPtr receiver, string selector) => Msg(receiver, sel_registerName(selector));
+
+        public static IntPtr Send(IntPtr receiver, string selector, IntPtr arg) => Msg(receiver, sel_registerName(selector), arg);
+
+        public static IntPtr Send(IntPtr receiver, string selector, IntPtr arg1, IntPtr arg2)
+            => Msg(receiver, sel_registerName(selector), arg1, arg2);
+
+        public static IntPtr Send(IntPtr receiver, string selector, IntPtr arg1, IntPtr arg2, IntPtr arg3)
+            => Msg(receiver, sel_registerName(selector), arg1, arg2, arg3);
+
+        public static bool SendBool(IntPtr receiver, string selector) => MsgBool(receiver, sel_registerName(selector));
+
+        public static long SendLong(IntPtr receiver, string selector) => MsgLong(receiver, sel_registerName(selector));
+
+        public static void SendUInt(IntPtr receiver, string selector, uint value) => MsgUInt(receiver, sel_registerName(selector), value);
+
+        public static IntPtr SendInitFormat(IntPtr allocated, nuint commonFormat, double sampleRate, uint channels, bool interleaved)
+            => MsgInitFormat(allocated, sel_registerName("initWithCommonFormat:sampleRate:channels:interleaved:"),
+                commonFormat, sampleRate, channels, interleaved);
+
+        public static IntPtr SendInitBuffer(IntPtr allocated, IntPtr format, uint frameCapacity)
+            => MsgInitBuffer(allocated, sel_registerName("initWithPCMFormat:frameCapacity:"), format, frameCapacity);
+
+        public static bool SendStart(IntPtr engine, out IntPtr error)
+            => MsgError(engine, sel_registerName("startAndReturnError:"), out error);
+
+        public static string Describe(IntPtr nsError)
+        {
+            if (nsError == IntPtr.Zero)
+                return "unknown error";
+
+            var description = Send(nsError, "localizedDescription");
+            return Marshal.PtrToStringUTF8(Send(description, "UTF8String")) ?? "unknown error";
+        }
+
+        /// <summary>
+        /// Sets the shared AVAudioSession to the playback category and activates it, so
+        /// output ignores the silent switch and keeps going in the background.
+        /// </summary>
+        public static void ActivatePlaybackSession(ILogger? logger)
+        {
+            var session = Send(Class("AVAudioSession"), "sharedInstance");
+            var category = Marshal.ReadIntPtr(NativeLibrary.GetExport(AVFoundationHandle, "AVAudioSessionCategoryPlayback"));
+            if (!MsgArgError(session, sel_registerName("setCategory:error:"), category, out var error))
+                logger?.LogWarning("AVAudioSession category not set: {Error}", Describe(error));
+            if (!MsgBoolArgError(session, sel_registerName("setActive:error:"), true, out error))
+                logger?.LogWarning("AVAudioSession not activated: {Error}", Describe(error));
+        }
+
+        [DllImport(Runtime)]
+        private static extern IntPtr objc_getClass(string name);
+
+        [DllImport(Runtime)]
+        private static extern IntPtr sel_registerName(string name);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr Msg(IntPtr receiver, IntPtr selector);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr Msg(IntPtr receiver, IntPtr selector, IntPtr arg);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr Msg(IntPtr receiver, IntPtr selector, IntPtr arg1, IntPtr arg2);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr Msg(IntPtr receiver, IntPtr selector, IntPtr arg1, IntPtr arg2, IntPtr arg3);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        [return: MarshalAs(UnmanagedType.I1)]
+        private static extern bool MsgBool(IntPtr receiver, IntPtr selector);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern long MsgLong(IntPtr receiver, IntPtr selector);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern void MsgUInt(IntPtr receiver, IntPtr selector, uint value);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr MsgInitFormat(
+            IntPtr receiver, IntPtr selector, nuint commonFormat, double sampleRate, uint channels,
+            [MarshalAs(UnmanagedType.I1)] bool interleaved);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        private static extern IntPtr MsgInitBuffer(IntPtr receiver, IntPtr selector, IntPtr format, uint frameCapacity);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        [return: MarshalAs(UnmanagedType.I1)]
+        private static extern bool MsgError(IntPtr receiver, IntPtr selector, out IntPtr error);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        [return: MarshalAs(UnmanagedType.I1)]
+        private static extern bool MsgArgError(IntPtr receiver, IntPtr selector, IntPtr arg, out IntPtr error);
+
+        [DllImport(Runtime, EntryPoint = "objc_msgSend")]
+        [return: MarshalAs(UnmanagedType.I1)]
+        private static extern bool MsgBoolArgError(
+            IntPtr receiver, IntPtr selector, [MarshalAs(UnmanagedType.I1)] bool arg, out IntPtr error);
+    }
+}
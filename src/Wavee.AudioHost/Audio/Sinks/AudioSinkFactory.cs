@@ -9,13 +9,18 @@ namespace Wavee.AudioHost.Audio.Sinks;
 public static class AudioSinkFactory
 {
     /// <summary>
-    /// Creates the default audio sink for the current platform.
-    /// Uses miniaudio which supports Windows, macOS, and Linux natively.
+    /// Creates the default audio sink for the current platform: AAudio on Android,
+    /// AVAudioEngine on iOS, PortAudio elsewhere.
     /// </summary>
     /// <param name="logger">Optional logger.</param>
     /// <returns>Platform-appropriate audio sink.</returns>
     public static IAudioSink CreateDefault(ILogger? logger = null)
     {
+        if (OperatingSystem.IsAndroidVersionAtLeast(26))
+            return new AAudioSink(logger);
+        if (OperatingSystem.IsIOS())
+            return new AVAudioEngineSink(logger);
+
         // PortAudio is cross-platform and AOT-compatible
         // It automatically uses the best backend:
         // - Windows: WASAPI/DirectSound
@@ -36,6 +41,10 @@ public static class AudioSinkFactory
         {
             AudioSinkType.PortAudio => new PortAudioSink(logger),
             AudioSinkType.Stub => new StubAudioSink(logger),
+            AudioSinkType.AAudio when OperatingSystem.IsAndroidVersionAtLeast(26) => new AAudioSink(logger),
+            AudioSinkType.AAudio => throw new PlatformNotSupportedException("AAudio needs Android 8.0 (API 26) or later"),
+            AudioSinkType.AVAudioEngine when OperatingSystem.IsIOS() => new AVAudioEngineSink(logger),
+            AudioSinkType.AVAudioEngine => throw new PlatformNotSupportedException("AVAudioEngine is only available on iOS"),
             _ => new PortAudioSink(logger) // Default to PortAudio
         };
     }
@@ -51,6 +60,16 @@ public enum AudioSinkType
     /// </summary>
     PortAudio,
 
+    /// <summary>
+    /// Android AAudio backend (API 26+).
+    /// </summary>
+    AAudio,
+
+    /// <summary>
+    /// iOS AVAudioEngine backend.
+    /// </summary>
+    AVAudioEngine,
+
     /// <summary>
     /// Stub sink that discards audio (for testing).
     /// </summary>
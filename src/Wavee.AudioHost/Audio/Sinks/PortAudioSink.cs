@@ -12,7 +12,7 @@ namespace Wavee.AudioHost.Audio.Sinks;
 /// Cross-platform audio output using PortAudio.
 /// Supports WASAPI (Windows), CoreAudio (macOS), ALSA (Linux).
 /// </summary>
-public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
+public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink, ITransportFadeSink, IBitPerfectSink
 {
     private static bool _initialized;
     private static readonly object _initLock = new();
@@ -42,6 +42,12 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     // so user volume changes are not delayed by already-buffered PCM.
     private VolumeProcessor? _realtimeVolumeProcessor;
 
+    // Transport ramps, applied in the callback after the realtime volume.
+    private readonly TransportFader _fader = new();
+
+    // Bypasses realtime volume and ramps; volatile: read by native PortAudio callback thread.
+    private volatile bool _bitPerfect;
+
     // Underrun detection (logged from callback thread)
     private long _underrunCount;
 
@@ -80,6 +86,11 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
 
     public string SinkName => "PortAudio";
 
+    public AudioFormat? Format => _format;
+
+    /// <inheritdoc />
+    public event Action<AudioDeviceChange>? DeviceChanged;
+
     /// <inheritdoc />
     public long PlaybackPositionMs
     {
@@ -105,6 +116,32 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         EnsurePortAudioInitialized();
     }
 
+    /// <inheritdoc />
+    /// <remarks>
+    /// Checked against the device's default sample rate and channel count. In
+    /// WASAPI shared mode that default is the mixer format, so a match means the
+    /// OS mixer doesn't resample.
+    /// </remarks>
+    public bool BitPerfect
+    {
+        get => _bitPerfect;
+        set => _bitPerfect = value;
+    }
+
+    /// <inheritdoc />
+    public int FadeInMs
+    {
+        get => _fader.FadeInMs;
+        set => _fader.FadeInMs = value;
+    }
+
+    /// <inheritdoc />
+    public int FadeOutMs
+    {
+        get => _fader.FadeOutMs;
+        set => _fader.FadeOutMs = value;
+    }
+
     public void SetRealtimeVolumeProcessor(VolumeProcessor? processor)
     {
         AudioFormat? formatToInit;
@@ -148,6 +185,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             _format = format;
             _bytesPerMs = format.BytesPerSecond / 1000.0;
             processorToInit = _realtimeVolumeProcessor;
+            _fader.Initialize(format);
 
             // Calculate buffer size in bytes (2x requested for safety margin)
             var bufferBytes = format.BytesPerSecond * bufferSizeMs * 2 / 1000;
@@ -162,6 +200,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
 
             _currentDeviceIndex = deviceIndex;
             var deviceInfo = PortAudioSharp.PortAudio.GetDeviceInfo(deviceIndex);
+            EnsureBitPerfectSupported(deviceInfo, format);
 
             // Configure output parameters
             var outputParams = new StreamParameters
@@ -242,7 +281,8 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         var buffer = _buffer;
         var format = _format;
 
-        if (_seekMute || buffer == null || format == null || !_isPlaying)
+        var bitPerfect = _bitPerfect;
+        if (_seekMute || buffer == null || format == null || !_isPlaying || (!bitPerfect && _fader.IsSilent))
         {
             // Output silence. Use format if available, otherwise fall back to a safe estimate.
             var bytesPerFrame = format?.BytesPerFrame ?? 4; // fallback: 2ch * 16bit = 4
@@ -255,26 +295,34 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         }
 
         var bytesNeeded = (int)(frameCount * format.BytesPerFrame);
+        // A fade-out only consumes audio up to the end of its ramp.
+        var bytesToRead = bitPerfect ? bytesNeeded : _fader.LimitReadBytes(bytesNeeded, format.BytesPerFrame);
 
         // Read from circular buffer into unmanaged memory
         unsafe
         {
             var span = new Span<byte>((void*)output, bytesNeeded);
-            var bytesRead = buffer.Read(span);
+            var bytesRead = buffer.Read(span[..bytesToRead]);
 
             // If we didn't get enough data, pad with silence
             if (bytesRead < bytesNeeded)
             {
                 span.Slice(bytesRead).Clear();
-                _bufferUnderrunFlag = true;
-                _lastUnderrunBytesRead = bytesRead;
-                _lastUnderrunBytesNeeded = bytesNeeded;
+                if (bytesRead < bytesToRead)
+                {
+                    _bufferUnderrunFlag = true;
+                    _lastUnderrunBytesRead = bytesRead;
+                    _lastUnderrunBytesNeeded = bytesNeeded;
+                }
             }
 
             var volumeProcessor = _realtimeVolumeProcessor;
-            if (bytesRead > 0 && volumeProcessor is { IsEnabled: true })
+            if (bytesRead > 0 && !bitPerfect && volumeProcessor is { IsEnabled: true })
                 volumeProcessor.ProcessInPlace(span[..bytesRead]);
 
+            if (bytesRead > 0 && !bitPerfect)
+                _fader.Apply(span[..bytesRead], format);
+
             // Track playback position from bytes actually sent to the speaker
             if (bytesRead > 0)
             {
@@ -338,6 +386,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         // at least one callback comfortably, avoiding immediate partial callback fills.
         if (_seekMute && _buffer.Available >= _seekUnmuteThresholdBytes)
         {
+            _fader.BeginFadeIn();
             _seekMute = false;
             Volatile.Write(ref _lastUnmuteAtTicks, Stopwatch.GetTimestamp());
         }
@@ -366,6 +415,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             // circular buffer instead of outputting silence.  Without this, the
             // first real callback after Start() returns sees an empty PortAudio
             // internal buffer and reports OutputUnderflow.
+            _fader.BeginFadeIn();
             _isPlaying = true;
             _stream.Start();
             _logger?.LogDebug("PortAudio playback started");
@@ -399,15 +449,25 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     }
 
     /// <inheritdoc />
-    public Task PauseAsync()
+    public async Task PauseAsync()
     {
         ObjectDisposedException.ThrowIf(_disposed, this);
 
-        // Pure flag flip — callback outputs silence on next invocation (~5ms).
+        // Let the callback ramp the buffered audio down first. Bounded in case the
+        // stream stalls (device unplugged) and the ramp never completes.
+        if (_isPlaying && !_seekMute && !_bitPerfect && _fader.FadeOutMs > 0)
+        {
+            var fadeOut = _fader.BeginFadeOutAsync();
+            var completed = await Task.WhenAny(fadeOut, Task.Delay(_fader.FadeOutMs + CallbackPeriodMs * 3));
+            if (completed != fadeOut)
+                _logger?.LogDebug("PortAudio fade-out did not complete in time");
+        }
+        _fader.Silence();
+
+        // Flag flip — callback outputs silence on next invocation (~5ms).
         // Stream stays running so resume is instant (no device restart).
         _isPlaying = false;
         _logger?.LogDebug("PortAudio paused (flag flip)");
-        return Task.CompletedTask;
     }
 
     /// <inheritdoc />
@@ -419,6 +479,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         // Stream never stopped, so there's zero startup latency.
         if (_stream != null)
         {
+            _fader.BeginFadeIn();
             _isPlaying = true;
             _logger?.LogDebug("PortAudio resumed (flag flip)");
             return Task.FromResult(true);
@@ -489,19 +550,40 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     // DEVICE CHANGE DETECTION & SELECTION
     // ================================================================
 
+    private void EnsureBitPerfectSupported(DeviceInfo deviceInfo, AudioFormat format)
+    {
+        if (!_bitPerfect)
+            return;
+
+        if ((int)Math.Round(deviceInfo.defaultSampleRate) != format.SampleRate)
+        {
+            throw new NotSupportedException(
+                $"Bit-perfect output: '{deviceInfo.name}' runs at {deviceInfo.defaultSampleRate:0} Hz but the stream is {format.SampleRate} Hz");
+        }
+
+        if (deviceInfo.maxOutputChannels < format.Channels)
+        {
+            throw new NotSupportedException(
+                $"Bit-perfect output: '{deviceInfo.name}' has {deviceInfo.maxOutputChannels} output channels but the stream has {format.Channels}");
+        }
+    }
+
     /// <summary>
     /// Core device-switch routine used by explicit user-initiated <see cref="SwitchToDeviceAsync"/> calls.
     /// Must be called outside the _lock (this method acquires it).
     /// </summary>
-    private void SwitchToDeviceInternal(int newDeviceIndex)
+    /// <returns>The change to raise once the lock is released, or null when the device didn't change.</returns>
+    private AudioDeviceChange? SwitchToDeviceInternal(int newDeviceIndex)
     {
         lock (_lock)
         {
             if (_disposed || _format == null)
-                return;
+                return null;
 
             var wasPlaying = _isPlaying;
             var format = _format;
+            // Refuse before touching the current stream so playback continues on failure.
+            EnsureBitPerfectSupported(PortAudioSharp.PortAudio.GetDeviceInfo(newDeviceIndex), format);
             string? oldName = null, newName = null;
             try { oldName = PortAudioSharp.PortAudio.GetDeviceInfo(_currentDeviceIndex).name; } catch { }
 
@@ -509,6 +591,10 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
                 "[PortAudio] Switching output device: {OldName} (idx={OldIdx}) → idx={NewIdx}",
                 oldName, _currentDeviceIndex, newDeviceIndex);
 
+            // Stop current stream
+            _logger?.LogDebug("[PortAudio] Stopping existing stream on {OldName}", oldName);
+            CloseStreamForSwap(deviceLost: false);
+
             // Save remaining buffer data so playback resumes seamlessly
             byte[]? savedData = null;
             int savedLength = 0;
@@ -519,16 +605,6 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
                 _logger?.LogDebug("[PortAudio] Saved {Bytes} bytes of buffered PCM for device switch", savedLength);
             }
 
-            // Stop current stream
-            _isPlaying = false;
-            if (_stream != null)
-            {
-                _logger?.LogDebug("[PortAudio] Aborting existing stream on {OldName}", oldName);
-                try { _stream.Abort(); } catch { }
-                _stream.Dispose();
-                _stream = null;
-            }
-
             // Create new stream on the new device
             _currentDeviceIndex = newDeviceIndex;
             var deviceInfo = PortAudioSharp.PortAudio.GetDeviceInfo(newDeviceIndex);
@@ -580,6 +656,87 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             _logger?.LogInformation(
                 "[PortAudio] Device switch complete: {NewName} (idx={NewIdx}), wasPlaying={WasPlaying}, restoredBytes={RestoredBytes}",
                 newName, newDeviceIndex, wasPlaying, savedLength);
+            return DeviceChange(oldName, newName, AudioDeviceChangeReason.Requested);
+        }
+    }
+
+    /// <summary>
+    /// Ramps the live stream down ahead of a device swap so the old device ends on
+    /// silence instead of a cut. Skipped when nothing is audible or ramps are off.
+    /// Must be called outside the _lock: the ramp advances in the stream callback.
+    /// </summary>
+    private void FadeOutForDeviceSwap()
+    {
+        if (!_isPlaying || _seekMute || _stream == null || _bitPerfect || _fader.FadeOutMs <= 0)
+            return;
+
+        // Bounded: a removed device stops calling back and the ramp never completes.
+        if (!_fader.BeginFadeOutAsync().Wait(_fader.FadeOutMs + CallbackPeriodMs * 3))
+            _logger?.LogDebug("[PortAudio] Fade-out before device swap did not complete in time");
+    }
+
+    /// <summary>
+    /// Undoes <see cref="FadeOutForDeviceSwap"/> once the swap is over: fades in whatever
+    /// stream is playing now, the reopened one or, when the swap was refused or had
+    /// nothing to switch, the previous one.
+    /// </summary>
+    private void FadeInAfterDeviceSwap()
+    {
+        if (_isPlaying && !_bitPerfect && _fader.IsSilent)
+            _fader.BeginFadeIn();
+    }
+
+    /// <summary>
+    /// Closes the stream for a device swap. Everything the callback has read is
+    /// already counted as played, so a live device is stopped (not aborted) to let
+    /// it play those host buffers out; the next buffered sample is then exactly
+    /// where the new device picks up. A device that is gone can't play anything.
+    /// </summary>
+    private void CloseStreamForSwap(bool deviceLost)
+    {
+        _isPlaying = false;
+        if (_stream == null)
+            return;
+
+        try
+        {
+            if (deviceLost)
+                _stream.Abort();
+            else
+                _stream.Stop();
+        }
+        catch
+        {
+            try { _stream.Abort(); } catch { }
+        }
+        try { _stream.Dispose(); } catch { }
+        _stream = null;
+    }
+
+    /// <summary>
+    /// Captures a device change under the _lock; null when the device name didn't change.
+    /// </summary>
+    private AudioDeviceChange? DeviceChange(string? previousName, string? newName, AudioDeviceChangeReason reason) =>
+        string.Equals(previousName, newName, StringComparison.Ordinal)
+            ? null
+            : new AudioDeviceChange(previousName, newName, reason, PlaybackPositionMs);
+
+    /// <summary>
+    /// Raises <see cref="DeviceChanged"/>. Must be called outside the _lock so a subscriber
+    /// can call back into the sink.
+    /// </summary>
+    private void RaiseDeviceChanged(AudioDeviceChange? change)
+    {
+        if (change == null)
+            return;
+
+        try
+        {
+            DeviceChanged?.Invoke(change);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogError(ex, "[PortAudio] DeviceChanged subscriber threw");
         }
     }
 
@@ -679,12 +836,12 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     /// change across a re-init), and re-opens the stream on the matching device. The
     /// buffered PCM is restored so there's only a brief gap (~50-100ms) in playback.
     /// </summary>
-    private void RefreshPortAudioDeviceList()
+    private AudioDeviceChange? RefreshPortAudioDeviceList()
     {
         lock (_lock)
         {
             if (_disposed || !_initialized)
-                return;
+                return null;
 
             // Snapshot stream state (if any) before tearing down.
             var hadStream = _stream != null;
@@ -700,17 +857,13 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
 
             if (hadStream)
             {
+                CloseStreamForSwap(deviceLost: false);
                 if (_buffer != null && _buffer.Available > 0)
                 {
                     savedData = new byte[_buffer.Available];
                     savedLen = _buffer.Read(savedData);
                     _logger?.LogDebug("[PortAudio] Saved {Bytes} bytes of buffered PCM before Pa_Terminate", savedLen);
                 }
-
-                _isPlaying = false;
-                try { _stream!.Abort(); } catch { }
-                try { _stream!.Dispose(); } catch { }
-                _stream = null;
             }
 
             // Cycle PortAudio so it re-scans the system audio devices.
@@ -727,22 +880,22 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             {
                 _logger?.LogError(ex, "[PortAudio] Pa_Initialize failed");
                 _initialized = false;
-                return;
+                return null;
             }
 
             if (!hadStream || savedFormat == null)
             {
                 _logger?.LogDebug("[PortAudio] No stream to reopen after refresh (hadStream={HadStream})", hadStream);
-                return;
+                return null;
             }
 
             // Find the new index of the device we were playing on (indexes change on re-init).
-            var newIndex = FindDeviceIndexByName(savedDeviceName)
-                           ?? PortAudioSharp.PortAudio.DefaultOutputDevice;
+            var previousIndex = FindDeviceIndexByName(savedDeviceName);
+            var newIndex = previousIndex ?? PortAudioSharp.PortAudio.DefaultOutputDevice;
             if (newIndex == PortAudioSharp.PortAudio.NoDevice)
             {
                 _logger?.LogWarning("[PortAudio] No output device available after Pa re-init; cannot reopen stream");
-                return;
+                return null;
             }
 
             string? reopenName = null;
@@ -755,10 +908,14 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             {
                 ReopenStreamOnDevice(newIndex, savedFormat, savedData, savedLen, wasPlaying);
                 _logger?.LogInformation("[PortAudio] Stream reopened successfully on {DeviceName}", reopenName);
+                return previousIndex == null
+                    ? DeviceChange(savedDeviceName, reopenName, AudioDeviceChangeReason.DeviceLost)
+                    : null;
             }
             catch (Exception ex)
             {
                 _logger?.LogError(ex, "[PortAudio] Failed to reopen stream after device refresh");
+                return null;
             }
         }
     }
@@ -924,7 +1081,17 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     /// <inheritdoc />
     public void RefreshDeviceList()
     {
-        RefreshPortAudioDeviceList();
+        FadeOutForDeviceSwap();
+        AudioDeviceChange? change;
+        try
+        {
+            change = RefreshPortAudioDeviceList();
+        }
+        finally
+        {
+            FadeInAfterDeviceSwap();
+        }
+        RaiseDeviceChanged(change);
     }
 
     /// <inheritdoc />
@@ -932,17 +1099,24 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     {
         return Task.Run(() =>
         {
+            AudioDeviceChange? change;
             try
             {
                 // Re-init PortAudio so it discovers newly-plugged devices (Bluetooth, USB DAC).
                 // Then reopen the stream on whatever Pa_GetDefaultOutputDevice() returns now.
-                RefreshPortAudioDeviceListAndFollowDefault();
+                FadeOutForDeviceSwap();
+                change = RefreshPortAudioDeviceListAndFollowDefault();
             }
             catch (Exception ex)
             {
                 _logger?.LogError(ex, "SwitchToDefaultDeviceAsync failed");
                 throw;
             }
+            finally
+            {
+                FadeInAfterDeviceSwap();
+            }
+            RaiseDeviceChanged(change);
         }, ct);
     }
 
@@ -951,12 +1125,12 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
     /// on the system default device rather than trying to reattach to the old device by name.
     /// Used when Windows signals that the system default output changed.
     /// </summary>
-    private void RefreshPortAudioDeviceListAndFollowDefault()
+    private AudioDeviceChange? RefreshPortAudioDeviceListAndFollowDefault()
     {
         lock (_lock)
         {
             if (_disposed || !_initialized)
-                return;
+                return null;
 
             var hadStream = _stream != null;
             var savedFormat = _format;
@@ -971,18 +1145,14 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
 
             if (hadStream)
             {
+                _logger?.LogDebug("[PortAudio] Stopping stream on {OldDevice} before Pa_Terminate", savedDeviceName);
+                CloseStreamForSwap(deviceLost: false);
                 if (_buffer != null && _buffer.Available > 0)
                 {
                     savedData = new byte[_buffer.Available];
                     savedLen = _buffer.Read(savedData);
                     _logger?.LogDebug("[PortAudio] Saved {Bytes} bytes of buffered PCM before Pa_Terminate", savedLen);
                 }
-
-                _isPlaying = false;
-                _logger?.LogDebug("[PortAudio] Aborting stream on {OldDevice} before Pa_Terminate", savedDeviceName);
-                try { _stream!.Abort(); } catch { }
-                try { _stream!.Dispose(); } catch { }
-                _stream = null;
             }
 
             // Cycle PortAudio so it re-scans the system audio devices.
@@ -999,13 +1169,13 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             {
                 _logger?.LogError(ex, "[PortAudio] Pa_Initialize failed — cannot follow new default device");
                 _initialized = false;
-                return;
+                return null;
             }
 
             if (!hadStream || savedFormat == null)
             {
                 _logger?.LogDebug("[PortAudio] No stream to reopen after FollowDefault (hadStream={HadStream})", hadStream);
-                return;
+                return null;
             }
 
             // Follow the new system default (not the old device).
@@ -1013,7 +1183,7 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             if (newIndex == PortAudioSharp.PortAudio.NoDevice)
             {
                 _logger?.LogWarning("[PortAudio] Pa_GetDefaultOutputDevice returned NoDevice after re-init; cannot follow new default");
-                return;
+                return null;
             }
 
             string? newName = null;
@@ -1026,10 +1196,12 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
             {
                 ReopenStreamOnDevice(newIndex, savedFormat, savedData, savedLen, wasPlaying);
                 _logger?.LogInformation("[PortAudio] Stream successfully reopened on new default device: {NewName}", newName);
+                return DeviceChange(savedDeviceName, newName, AudioDeviceChangeReason.DefaultChanged);
             }
             catch (Exception ex)
             {
                 _logger?.LogError(ex, "[PortAudio] Failed to reopen stream on new default device {NewName}", newName);
+                return null;
             }
         }
     }
@@ -1047,18 +1219,40 @@ public sealed class PortAudioSink : IAudioSink, IDeviceSelectableSink
         // don't block the caller (IPC command thread).
         return Task.Run(() =>
         {
+            FadeOutForDeviceSwap();
+            AudioDeviceChange? change;
             try
             {
-                SwitchToDeviceInternal(deviceIndex);
+                change = SwitchToDeviceInternal(deviceIndex);
             }
             catch (Exception ex)
             {
                 _logger?.LogError(ex, "Failed to switch to device index {DeviceIndex}", deviceIndex);
                 throw;
             }
+            finally
+            {
+                FadeInAfterDeviceSwap();
+            }
+            RaiseDeviceChanged(change);
         }, ct);
     }
 
+    /// <inheritdoc />
+    public Task ReleaseAsync()
+    {
+        if (_disposed)
+            return Task.CompletedTask;
+
+        lock (_lock)
+        {
+            CleanupStream();
+        }
+
+        _logger?.LogDebug("PortAudio stream released");
+        return Task.CompletedTask;
+    }
+
     private void CleanupStream()
     {
         if (_stream != null)
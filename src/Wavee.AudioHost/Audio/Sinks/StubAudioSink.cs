@@ -15,6 +15,8 @@ public sealed class StubAudioSink : IAudioSink
 
     public string SinkName => "Stub";
 
+    public AudioFormat? Format => _format;
+
     /// <summary>
     /// Creates a new StubAudioSink.
     /// </summary>
@@ -0,0 +1,213 @@
+using System.Buffers.Binary;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Audio.Sinks;
+
+/// <summary>
+/// Linear gain ramp applied to PCM in a sink's output callback on transport
+/// transitions (start, resume, seek unmute, pause, stop) to avoid clicks.
+/// </summary>
+/// <remarks>
+/// The fade-out ramp is applied to real buffered audio, so the sink's played-bytes
+/// counter (and therefore its reported position) advances by exactly the audio
+/// that was heard. Once the ramp reaches zero the sink stops pulling from its
+/// buffer, so nothing after the ramp is consumed or counted.
+/// </remarks>
+internal sealed class TransportFader
+{
+    public const int DefaultFadeInMs = 15;
+    public const int DefaultFadeOutMs = 30;
+
+    private enum RampState { Idle, FadingIn, FadingOut, Silent }
+
+    private readonly object _lock = new();
+    private RampState _state = RampState.Idle;
+    private float _gain = 1.0f;
+    private float _step;
+    private int _sampleRate;
+    private int _fadeInMs = DefaultFadeInMs;
+    private int _fadeOutMs = DefaultFadeOutMs;
+    private TaskCompletionSource? _fadeOutDone;
+
+    /// <summary>Fade-in length in milliseconds; 0 disables the ramp. Range: 0ms to 1000ms</summary>
+    public int FadeInMs
+    {
+        get => Volatile.Read(ref _fadeInMs);
+        set => Volatile.Write(ref _fadeInMs, Math.Clamp(value, 0, 1000));
+    }
+
+    /// <summary>Fade-out length in milliseconds; 0 disables the ramp. Range: 0ms to 1000ms</summary>
+    public int FadeOutMs
+    {
+        get => Volatile.Read(ref _fadeOutMs);
+        set => Volatile.Write(ref _fadeOutMs, Math.Clamp(value, 0, 1000));
+    }
+
+    /// <summary>True once a fade-out has reached zero; the callback should output silence.</summary>
+    public bool IsSilent
+    {
+        get { lock (_lock) return _state == RampState.Silent; }
+    }
+
+    public void Initialize(AudioFormat format)
+    {
+        lock (_lock)
+        {
+            _sampleRate = format.SampleRate;
+            CompleteFadeOut();
+            _state = RampState.Idle;
+            _gain = 1.0f;
+        }
+    }
+
+    /// <summary>
+    /// Ramps from silence up to unity. An interrupted fade-out reverses from its current gain.
+    /// </summary>
+    public void BeginFadeIn()
+    {
+        lock (_lock)
+        {
+            CompleteFadeOut();
+            var frames = _sampleRate * FadeInMs / 1000;
+            if (frames <= 0)
+            {
+                _state = RampState.Idle;
+                _gain = 1.0f;
+                return;
+            }
+
+            if (_state != RampState.FadingOut)
+                _gain = 0.0f;
+            _step = 1.0f / frames;
+            _state = RampState.FadingIn;
+        }
+    }
+
+    /// <summary>
+    /// Ramps from the current gain down to zero.
+    /// </summary>
+    /// <returns>Completes when the ramp reaches zero (immediately when fade-out is disabled).</returns>
+    public Task BeginFadeOutAsync()
+    {
+        lock (_lock)
+        {
+            var frames = _sampleRate * FadeOutMs / 1000;
+            if (frames <= 0 || _state == RampState.Silent)
+            {
+                _state = RampState.Silent;
+                _gain = 0.0f;
+                return Task.CompletedTask;
+            }
+
+            _step = 1.0f / frames;
+            _state = RampState.FadingOut;
+            // Completed from the audio callback; never run continuations on that thread.
+            _fadeOutDone ??= new TaskCompletionSource(TaskCreationOptions.RunContinuationsAsynchronously);
+            return _fadeOutDone.Task;
+        }
+    }
+
+    /// <summary>
+    /// Jumps straight to silence, e.g. when a fade-out didn't finish in time.
+    /// </summary>
+    public void Silence()
+    {
+        lock (_lock)
+        {
+            _state = RampState.Silent;
+            _gain = 0.0f;
+            CompleteFadeOut();
+        }
+    }
+
+    /// <summary>
+    /// Limits a callback read so a fade-out doesn't consume audio past the end of its ramp.
+    /// </summary>
+    public int LimitReadBytes(int bytesNeeded, int bytesPerFrame)
+    {
+        lock (_lock)
+        {
+            if (_state != RampState.FadingOut)
+                return bytesNeeded;
+
+            var remainingFrames = (int)MathF.Ceiling(_gain / _step);
+            return Math.Min(bytesNeeded, remainingFrames * bytesPerFrame);
+        }
+    }
+
+    /// <summary>
+    /// Applies the ramp to interleaved PCM in place, advancing it frame by frame.
+    /// </summary>
+    public void Apply(Span<byte> pcm, AudioFormat format)
+    {
+        lock (_lock)
+        {
+            if (_state is RampState.Idle or RampState.Silent)
+                return;
+
+            var bytesPerSample = format.BitsPerSample / 8;
+            var frameCount = pcm.Length / format.BytesPerFrame;
+            for (var frame = 0; frame < frameCount; frame++)
+            {
+                if (_state == RampState.FadingIn)
+                {
+                    _gain = Math.Min(1.0f, _gain + _step);
+                    if (_gain >= 1.0f)
+                        _state = RampState.Idle;
+                }
+                else if (_state == RampState.FadingOut)
+                {
+                    _gain = Math.Max(0.0f, _gain - _step);
+                    if (_gain <= 0.0f)
+                    {
+                        _state = RampState.Silent;
+                        CompleteFadeOut();
+                    }
+                }
+
+                var frameSpan = pcm.Slice(frame * format.BytesPerFrame, format.BytesPerFrame);
+                for (var offset = 0; offset < frameSpan.Length; offset += bytesPerSample)
+                    ScaleSample(frameSpan.Slice(offset, bytesPerSample), _gain);
+
+                if (_state == RampState.Idle)
+                    return;
+                if (_state == RampState.Silent)
+                {
+                    pcm[((frame + 1) * format.BytesPerFrame)..].Clear();
+                    return;
+                }
+            }
+        }
+    }
+
+    private void CompleteFadeOut()
+    {
+        _fadeOutDone?.TrySetResult();
+        _fadeOutDone = null;
+    }
+
+    // Sample layouts match the PortAudio stream formats: Int16, packed Int24, Float32.
+    private static void ScaleSample(Span<byte> sample, float gain)
+    {
+        switch (sample.Length)
+        {
+            case 2:
+                BinaryPrimitives.WriteInt16LittleEndian(sample,
+                    (short)(BinaryPrimitives.ReadInt16LittleEndian(sample) * gain));
+                break;
+            case 3:
+                var value = sample[0] | (sample[1] << 8) | (sample[2] << 16);
+                if ((value & 0x800000) != 0)
+                    value |= unchecked((int)0xFF000000);
+                value = (int)(value * gain);
+                sample[0] = (byte)value;
+                sample[1] = (byte)(value >> 8);
+                sample[2] = (byte)(value >> 16);
+                break;
+            case 4:
+                BinaryPrimitives.WriteSingleLittleEndian(sample,
+                    BinaryPrimitives.ReadSingleLittleEndian(sample) * gain);
+                break;
+        }
+    }
+}
@@ -1,7 +1,6 @@
-﻿using System.Runtime.CompilerServices;
-using System.Runtime.InteropServices;
-using System.Security.Cryptography;
+﻿using System.Security.Cryptography;
 using Microsoft.Extensions.Logging;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Streaming;
 
@@ -9,23 +8,14 @@ namespace Wavee.AudioHost.Audio.Streaming;
 /// AES-128-CTR decryption stream for Spotify audio files.
 /// Wraps an encrypted audio source and provides transparent decryption with seeking support.
 /// Pass null key for unencrypted pass-through mode.
+/// The keystream comes from <see cref="AesCtrKeystream"/>, which uses AES-NI / ARMv8 crypto when present.
 /// </summary>
 public sealed class AudioDecryptStream : Stream
 {
-    private static readonly byte[] AUDIO_AESIV =
-    [
-        0x72, 0xe0, 0x67, 0xfb, 0xdd, 0xcb, 0xcf, 0x77,
-        0xeb, 0xe8, 0xbc, 0x64, 0x3f, 0x63, 0x0d, 0x93
-    ];
-
-    private const int AES_BLOCK_SIZE = 16;
-
     private readonly Stream _baseStream;
-    private readonly Aes? _aes;
+    private readonly AesCtrKeystream? _keystream;
     private readonly long _decryptionStartOffset;
     private long _position;
-    private readonly byte[] _keystreamBlock = new byte[AES_BLOCK_SIZE];
-    private long _keystreamBlockPosition = -1;
     private bool _disposed;
 
     public AudioDecryptStream(byte[]? key, Stream baseStream, long decryptionStartOffset = 0, ILogger? logger = null)
@@ -44,22 +34,22 @@ public sealed class AudioDecryptStream : Stream
 
         if (key is null)
         {
-            _aes = null;
+            _keystream = null;
             logger?.LogDebug("AudioDecryptStream constructed in pass-through mode (null key, decryptOffset={Offset})", decryptionStartOffset);
             return;
         }
 
-        if (key.Length != 16)
-            throw new ArgumentException("AES-128 key must be exactly 16 bytes", nameof(key));
-
-        _aes = Aes.Create();
-        _aes.KeySize = 128;
-        _aes.Key = key;
-        _aes.Padding = PaddingMode.None;
+        _keystream = new AesCtrKeystream(key);
 
-        logger?.LogDebug("AudioDecryptStream constructed (keyFp={KeyFp}, decryptOffset={Offset})", KeyFingerprint(key), decryptionStartOffset);
+        logger?.LogDebug("AudioDecryptStream constructed (keyFp={KeyFp}, decryptOffset={Offset}, aes={Backend})",
+            KeyFingerprint(key), decryptionStartOffset, _keystream.Backend);
     }
 
+    /// <summary>
+    /// AES implementation in use, or null in pass-through mode.
+    /// </summary>
+    public AesBackend? Backend => _keystream?.Backend;
+
     private static string KeyFingerprint(ReadOnlySpan<byte> key)
     {
         Span<byte> digest = stackalloc byte[32];
@@ -87,7 +77,7 @@ public sealed class AudioDecryptStream : Stream
         if (buffer.Length == 0)
             return 0;
 
-        if (_aes is null)
+        if (_keystream is null)
         {
             int bytesRead = _baseStream.Read(buffer);
             _position += bytesRead;
@@ -108,7 +98,7 @@ public sealed class AudioDecryptStream : Stream
             long decryptStart = Math.Max(_position, _decryptionStartOffset);
             int skipBytes = (int)(decryptStart - _position);
             int decryptLen = totalRead - skipBytes;
-            DecryptCtr(buffer.Slice(skipBytes, decryptLen), decryptStart);
+            _keystream.Apply(buffer.Slice(skipBytes, decryptLen), decryptStart);
         }
 
         _position += totalRead;
@@ -141,19 +131,6 @@ public sealed class AudioDecryptStream : Stream
     public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
     public override void Flush() { }
 
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private void DecryptCtr(Span<byte> buffer, long streamPosition)
-    {
-        if (_aes is null) return;
-
-        ApplySpotifyCtr(
-            _aes,
-            buffer,
-            streamPosition,
-            _keystreamBlock,
-            ref _keystreamBlockPosition);
-    }
-
     /// <summary>
     /// Applies the Spotify audio AES-CTR transform in-place. CTR is symmetric,
     /// so callers can use this for both decrypting CDN bytes and re-encrypting
@@ -161,90 +138,8 @@ public sealed class AudioDecryptStream : Stream
     /// </summary>
     internal static void ApplySpotifyCtr(byte[] key, Span<byte> buffer, long streamPosition)
     {
-        if (key.Length != AES_BLOCK_SIZE)
-            throw new ArgumentException("AES-128 key must be exactly 16 bytes", nameof(key));
-
-        using var aes = Aes.Create();
-        aes.KeySize = 128;
-        aes.Key = key;
-        aes.Padding = PaddingMode.None;
-
-        Span<byte> keyStreamBlock = stackalloc byte[AES_BLOCK_SIZE];
-        var keyStreamBlockPosition = -1L;
-        ApplySpotifyCtr(aes, buffer, streamPosition, keyStreamBlock, ref keyStreamBlockPosition);
-    }
-
-    private static void ApplySpotifyCtr(
-        Aes aes,
-        Span<byte> buffer,
-        long streamPosition,
-        Span<byte> keyStreamBlock,
-        ref long keyStreamBlockPosition)
-    {
-        long currentPosition = streamPosition;
-        int bufferOffset = 0;
-
-        while (bufferOffset < buffer.Length)
-        {
-            long blockIndex = currentPosition / AES_BLOCK_SIZE;
-            int offsetInBlock = (int)(currentPosition % AES_BLOCK_SIZE);
-
-            if (keyStreamBlockPosition != blockIndex)
-            {
-                GenerateKeystreamBlock(aes, blockIndex, keyStreamBlock);
-                keyStreamBlockPosition = blockIndex;
-            }
-
-            int bytesToProcess = Math.Min(buffer.Length - bufferOffset, AES_BLOCK_SIZE - offsetInBlock);
-
-            XorBytes(
-                buffer.Slice(bufferOffset, bytesToProcess),
-                MemoryMarshal.CreateReadOnlySpan(ref keyStreamBlock[offsetInBlock], bytesToProcess));
-
-            bufferOffset += bytesToProcess;
-            currentPosition += bytesToProcess;
-        }
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private static void XorBytes(Span<byte> dst, ReadOnlySpan<byte> key)
-    {
-        // Fast path: aligned 8-byte chunks, then tail bytes. A full keystream
-        // block is 16 bytes, so we usually take the ulong path twice + zero tail.
-        int i = 0;
-        int end = dst.Length - sizeof(ulong);
-        ref byte dRef = ref MemoryMarshal.GetReference(dst);
-        ref byte kRef = ref MemoryMarshal.GetReference(key);
-        while (i <= end)
-        {
-            ulong d = Unsafe.ReadUnaligned<ulong>(ref Unsafe.Add(ref dRef, i));
-            ulong k = Unsafe.ReadUnaligned<ulong>(ref Unsafe.Add(ref kRef, i));
-            Unsafe.WriteUnaligned(ref Unsafe.Add(ref dRef, i), d ^ k);
-            i += sizeof(ulong);
-        }
-        for (; i < dst.Length; i++)
-            Unsafe.Add(ref dRef, i) ^= Unsafe.Add(ref kRef, i);
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private static void GenerateKeystreamBlock(Aes aes, long blockIndex, Span<byte> output)
-    {
-        Span<byte> counterBlock = stackalloc byte[AES_BLOCK_SIZE];
-        AUDIO_AESIV.CopyTo(counterBlock);
-        AddBigEndianSpan(counterBlock, blockIndex);
-        aes.EncryptEcb(counterBlock, output, PaddingMode.None);
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private static void AddBigEndianSpan(Span<byte> buffer, long value)
-    {
-        ulong carry = (ulong)value;
-        for (int i = AES_BLOCK_SIZE - 1; i >= 0 && carry > 0; i--)
-        {
-            ulong sum = buffer[i] + carry;
-            buffer[i] = (byte)sum;
-            carry = sum >> 8;
-        }
+        using var keystream = new AesCtrKeystream(key);
+        keystream.Apply(buffer, streamPosition);
     }
 
     protected override void Dispose(bool disposing)
@@ -252,7 +147,7 @@ public sealed class AudioDecryptStream : Stream
         if (_disposed) return;
         if (disposing)
         {
-            _aes?.Dispose();
+            _keystream?.Dispose();
             _baseStream?.Dispose();
         }
         _disposed = true;
@@ -262,7 +157,7 @@ public sealed class AudioDecryptStream : Stream
     public override async ValueTask DisposeAsync()
     {
         if (_disposed) return;
-        _aes?.Dispose();
+        _keystream?.Dispose();
         if (_baseStream is not null)
             await _baseStream.DisposeAsync().ConfigureAwait(false);
         _disposed = true;
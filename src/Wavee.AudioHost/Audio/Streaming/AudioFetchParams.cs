@@ -20,6 +20,29 @@ public sealed record AudioFetchParams
     /// </summary>
     public int MaximumChunkSize { get; init; } = 256 * 1024;
 
+    /// <summary>
+    /// Size of the pooled buffer each HTTP response body is streamed through on its
+    /// way to the temp file. Bounds per-request memory regardless of range size.
+    /// </summary>
+    public int StreamSliceSize { get; init; } = 32 * 1024;
+
+    /// <summary>
+    /// High-water mark for bytes buffered ahead of the read position. The background
+    /// loop never plans a read-ahead horizon beyond this, whatever
+    /// <see cref="ReadAheadDuration"/> works out to, so a stalled sink cannot keep the
+    /// downloader fetching.
+    /// Default: 4 MB (~100 s at 320 kbps)
+    /// </summary>
+    public long MaxBufferedAheadBytes { get; init; } = 4 * 1024 * 1024;
+
+    /// <summary>
+    /// Low-water mark: once the high-water mark is hit, read-ahead resumes only after
+    /// the buffered-ahead amount drops below this. Hysteresis avoids one-chunk
+    /// stop/start churn at the boundary.
+    /// Default: 2 MB
+    /// </summary>
+    public long ResumeBufferedAheadBytes { get; init; } = 2 * 1024 * 1024;
+
     /// <summary>
     /// Bytes to prefetch before playback starts (for instant start).
     /// Default: 256KB (enough for ~2 seconds at 320kbps)
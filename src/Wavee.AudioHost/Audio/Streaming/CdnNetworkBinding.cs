@@ -0,0 +1,105 @@
+using System.Net;
+using System.Net.Sockets;
+
+namespace Wavee.AudioHost.Audio.Streaming;
+
+/// <summary>
+/// Binds CDN connections to the local address and IP family the parent process was
+/// configured with (<see cref="Wavee.Playback.Contracts.AudioHostConfig.LocalAddress"/>,
+/// <see cref="Wavee.Playback.Contracts.AudioHostConfig.AddressFamily"/>).
+/// </summary>
+/// <remarks>
+/// Host-side counterpart of Wavee.Core.Connection.NetworkBinding, which AudioHost can't
+/// reference. Same candidate ordering, so audio leaves through the same interface as
+/// the session traffic.
+/// </remarks>
+internal static class CdnNetworkBinding
+{
+    /// <summary>
+    /// Installs a connect callback on <paramref name="handler"/>. Leaves it untouched when
+    /// neither setting is given.
+    /// </summary>
+    /// <param name="addressFamily">A NetworkConfig.AddressFamily name, e.g. <c>IPv4Only</c>.</param>
+    /// <returns>The same handler, for chaining.</returns>
+    /// <exception cref="FormatException"><paramref name="localAddress"/> isn't an IP address.</exception>
+    public static SocketsHttpHandler Apply(SocketsHttpHandler handler, string? localAddress, string? addressFamily)
+    {
+        ArgumentNullException.ThrowIfNull(handler);
+        var local = string.IsNullOrEmpty(localAddress) ? null : IPAddress.Parse(localAddress);
+        var family = addressFamily ?? "Any";
+        if (local is null && family == "Any")
+            return handler;
+
+        handler.ConnectCallback = async (context, ct) =>
+        {
+            var socket = await ConnectAsync(context.DnsEndPoint.Host, context.DnsEndPoint.Port, local, family, ct);
+            return new NetworkStream(socket, ownsSocket: true);
+        };
+        return handler;
+    }
+
+    private static async Task<Socket> ConnectAsync(
+        string host,
+        int port,
+        IPAddress? local,
+        string family,
+        CancellationToken cancellationToken)
+    {
+        var addresses = IPAddress.TryParse(host, out var literal)
+            ? [literal]
+            : await Dns.GetHostAddressesAsync(host, cancellationToken);
+
+        var candidates = OrderCandidates(addresses, local, family);
+        if (candidates.Count == 0)
+        {
+            throw new SocketException(
+                (int)SocketError.AddressFamilyNotSupported,
+                $"{host} has no address usable with family preference {family}" +
+                (local is null ? string.Empty : $" and local address {local}"));
+        }
+
+        SocketException? lastError = null;
+        foreach (var address in candidates)
+        {
+            var socket = new Socket(address.AddressFamily, SocketType.Stream, ProtocolType.Tcp) { NoDelay = true };
+            try
+            {
+                if (local is not null)
+                    socket.Bind(new IPEndPoint(local, 0));
+
+                await socket.ConnectAsync(new IPEndPoint(address, port), cancellationToken);
+                return socket;
+            }
+            catch (SocketException ex)
+            {
+                socket.Dispose();
+                lastError = ex;
+            }
+            catch
+            {
+                socket.Dispose();
+                throw;
+            }
+        }
+
+        throw lastError!;
+    }
+
+    private static List<IPAddress> OrderCandidates(IEnumerable<IPAddress> addresses, IPAddress? local, string family)
+    {
+        var usable = addresses
+            .Select(a => a.IsIPv4MappedToIPv6 ? a.MapToIPv4() : a)
+            .Where(a => a.AddressFamily is AddressFamily.InterNetwork or AddressFamily.InterNetworkV6)
+            .Where(a => local is null || a.AddressFamily == local.AddressFamily)
+            .Distinct();
+
+        return family switch
+        {
+            "IPv4Only" => usable.Where(a => a.AddressFamily == AddressFamily.InterNetwork).ToList(),
+            "IPv6Only" => usable.Where(a => a.AddressFamily == AddressFamily.InterNetworkV6).ToList(),
+            "PreferIPv4" => usable.OrderBy(a => a.AddressFamily == AddressFamily.InterNetwork ? 0 : 1).ToList(),
+            "PreferIPv6" => usable.OrderBy(a => a.AddressFamily == AddressFamily.InterNetworkV6 ? 0 : 1).ToList(),
+            _ => usable.ToList()
+        };
+    }
+}
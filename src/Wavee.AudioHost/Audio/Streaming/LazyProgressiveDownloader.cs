@@ -1,4 +1,5 @@
 using Microsoft.Extensions.Logging;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Streaming;
 
@@ -27,6 +28,7 @@ public sealed class LazyProgressiveDownloader : Stream
     // we open the cached file from here instead of downloading from CDN.
     private readonly string? _audioCacheDirectory;
     private readonly long? _audioCacheMaxBytes;
+    private readonly AudioFetchParams? _fetchParams;
 
     private long _position;
     private long _fileSize;
@@ -48,6 +50,9 @@ public sealed class LazyProgressiveDownloader : Stream
     // _deferredTask timeout (which is minutes long).
     private readonly CancellationTokenSource _initWaitCts;
 
+    // Latest PrefetchRangeAsync; replaced (and cancelled) by each new prefetch.
+    private CancellationTokenSource? _prefetchCts;
+
     /// <summary>
     /// Raised when buffer state changes (forwarded from ProgressiveDownloader).
     /// </summary>
@@ -58,6 +63,22 @@ public sealed class LazyProgressiveDownloader : Stream
     /// </summary>
     public event Action<DownloadError>? DownloadError;
 
+    /// <summary>
+    /// Gets whether the audio is being read from the persistent audio cache instead of the CDN.
+    /// </summary>
+    public bool IsFromCache => _cachedFileStream != null;
+
+    /// <summary>
+    /// Gets the number of bytes fetched from the CDN so far (0 when served from cache).
+    /// </summary>
+    public long BytesDownloaded => _cdnDownloader?.BytesDownloaded ?? 0;
+
+    /// <summary>
+    /// AES implementation decrypting the CDN or cached bytes; null while only the
+    /// (already decrypted) head data has been read, or when the file isn't encrypted.
+    /// </summary>
+    public AesBackend? DecryptionBackend => _decryptStream?.Backend;
+
     /// <summary>
     /// Creates a lazy progressive downloader for instant playback start.
     /// </summary>
@@ -71,6 +92,7 @@ public sealed class LazyProgressiveDownloader : Stream
     /// <c>LocalCacheFileId</c>, the file is opened from here instead of CDN.
     /// </param>
     /// <param name="audioCacheMaxBytes">Maximum persistent cache size before LRU pruning.</param>
+    /// <param name="fetchParams">CDN fetch parameters; null uses <see cref="AudioFetchParams.Default"/>.</param>
     public LazyProgressiveDownloader(
         byte[] headData,
         Task<DeferredResult> deferredTask,
@@ -79,6 +101,7 @@ public sealed class LazyProgressiveDownloader : Stream
         ILogger? logger = null,
         string? audioCacheDirectory = null,
         long? audioCacheMaxBytes = null,
+        AudioFetchParams? fetchParams = null,
         CancellationToken playbackToken = default)
     {
         ArgumentNullException.ThrowIfNull(headData);
@@ -92,6 +115,7 @@ public sealed class LazyProgressiveDownloader : Stream
         _logger = logger;
         _audioCacheDirectory = audioCacheDirectory;
         _audioCacheMaxBytes = audioCacheMaxBytes;
+        _fetchParams = fetchParams;
 
         _initWaitCts = playbackToken.CanBeCanceled
             ? CancellationTokenSource.CreateLinkedTokenSource(_disposeCts.Token, playbackToken)
@@ -303,9 +327,6 @@ public sealed class LazyProgressiveDownloader : Stream
         var useLocalCache = !string.IsNullOrEmpty(deferred.LocalCacheFileId)
                              && _audioCacheDirectory != null
                              && audioKey is { Length: 16 };
-        string? localCachePath = useLocalCache
-            ? Path.Combine(_audioCacheDirectory!, "audio", deferred.LocalCacheFileId + ".enc")
-            : null;
 
         if (useLocalCache)
         {
@@ -313,8 +334,10 @@ public sealed class LazyProgressiveDownloader : Stream
             // The file is fully on disk — no CDN download needed.
             _logger?.LogInformation("Using local cache for {FileId} ({Bytes} bytes)", deferred.LocalCacheFileId, _fileSize);
 
-            _cachedFileStream = new FileStream(localCachePath!, FileMode.Open, FileAccess.Read,
-                FileShare.Read, 4096, FileOptions.SequentialScan);
+            // Chunks are checksummed as they're read. A corrupt one throws
+            // AudioCacheCorruptException after dropping the file from the cache, so the
+            // retry resolves from CDN instead of decoding garbage.
+            _cachedFileStream = AudioFileCache.OpenRead(_audioCacheDirectory!, deferred.LocalCacheFileId!);
 
             _decryptStream = new AudioDecryptStream(audioKey, _cachedFileStream, decryptionStartOffset: 0, logger: _logger);
 
@@ -338,8 +361,7 @@ public sealed class LazyProgressiveDownloader : Stream
                 _decryptStream = null;
                 await _cachedFileStream.DisposeAsync();
                 _cachedFileStream = null;
-                try { File.Delete(localCachePath!); }
-                catch (Exception ex) { _logger?.LogDebug(ex, "Failed to delete corrupt cache file {Path}", localCachePath); }
+                AudioFileCache.Delete(_audioCacheDirectory!, deferred.LocalCacheFileId!);
 
                 // The deferred result we received chose the local-cache shortcut and
                 // its CdnUrl is empty — we cannot rebuild the CDN downloader from
@@ -360,10 +382,6 @@ public sealed class LazyProgressiveDownloader : Stream
             var cdnUrl = deferred.CdnUrl;
             _logger?.LogDebug("CDN resolved: URL ready, file size = {FileSize}", _fileSize);
 
-            // Compute persistent cache path if we know the Spotify file ID
-            string? persistCachePath = null;
-            if (!string.IsNullOrEmpty(deferred.SpotifyFileId) && _audioCacheDirectory != null)
-                persistCachePath = Path.Combine(_audioCacheDirectory, "audio", deferred.SpotifyFileId + ".enc");
 
             _cdnDownloader = new ProgressiveDownloader(
                 _httpClient,
@@ -371,8 +389,10 @@ public sealed class LazyProgressiveDownloader : Stream
                 _fileSize,
                 _fileId,
                 _headData,
+                _fetchParams,
                 logger: _logger,
-                persistCachePath: persistCachePath,
+                persistCacheDirectory: _audioCacheDirectory,
+                persistCacheFileId: deferred.SpotifyFileId,
                 maxCacheBytes: _audioCacheMaxBytes,
                 persistentCacheAudioKey: audioKey);
 
@@ -490,16 +510,41 @@ public sealed class LazyProgressiveDownloader : Stream
     /// <param name="bytePosition">Start byte position to prefetch from.</param>
     /// <param name="length">Number of bytes to prefetch.</param>
     /// <param name="cancellationToken">Cancellation token.</param>
+    /// <remarks>
+    /// Only the most recent prefetch is kept alive: issuing a new one cancels the
+    /// previous, so rapid seek scrubbing can't queue an unbounded number of fetches.
+    /// </remarks>
     public async Task PrefetchRangeAsync(long bytePosition, int length, CancellationToken cancellationToken = default)
     {
-        // Ensure CDN is initialized first
-        await EnsureCdnInitializedAsync(cancellationToken);
+        ObjectDisposedException.ThrowIf(_disposed, this);
 
-        if (_cdnDownloader != null)
+        var cts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken, _disposeCts.Token);
+        var previous = Interlocked.Exchange(ref _prefetchCts, cts);
+        if (previous != null)
+        {
+            try { previous.Cancel(); } catch (ObjectDisposedException) { }
+        }
+
+        try
+        {
+            // Ensure CDN is initialized first
+            await EnsureCdnInitializedAsync(cts.Token);
+
+            if (_cdnDownloader != null)
+            {
+                // Fetch the range so it's ready when NVorbis seeks
+                await _cdnDownloader.FetchRangeAsync(bytePosition, bytePosition + length, cts.Token);
+                _logger?.LogDebug("Prefetched {Length} bytes at position {Position}", length, bytePosition);
+            }
+        }
+        catch (OperationCanceledException) when (!cancellationToken.IsCancellationRequested)
+        {
+            _logger?.LogDebug("Prefetch at {Position} superseded", bytePosition);
+        }
+        finally
         {
-            // Fetch the range so it's ready when NVorbis seeks
-            await _cdnDownloader.FetchRangeAsync(bytePosition, bytePosition + length, cancellationToken);
-            _logger?.LogDebug("Prefetched {Length} bytes at position {Position}", length, bytePosition);
+            Interlocked.CompareExchange(ref _prefetchCts, null, cts);
+            cts.Dispose();
         }
     }
 
@@ -2,6 +2,7 @@ using System.Buffers;
 using System.Net;
 using System.Net.Http.Headers;
 using Microsoft.Extensions.Logging;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioHost.Audio.Streaming;
 
@@ -27,9 +28,10 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     private readonly AudioFetchParams _params;
     private readonly ILogger? _logger;
 
-    // Optional persistent cache: when set, the fully downloaded file is copied here
-    // so future plays can bypass CDN resolution entirely.
-    private readonly string? _persistCachePath;
+    // Optional persistent cache: when set, the fully downloaded file is copied into
+    // this audio cache so future plays can bypass CDN resolution entirely.
+    private readonly string? _persistCacheDirectory;
+    private readonly string? _persistCacheFileId;
     private readonly long? _maxCacheBytes;
     private readonly byte[]? _persistentCacheAudioKey;
     private readonly int _clearHeadBytes;
@@ -45,7 +47,7 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     // thread-safe for concurrent SendAsync; temp-file writes target distinct
     // ranges; RangeSet is internally guarded.
     private readonly SemaphoreSlim _fetchLock = new(2, 2);
-    private readonly ArrayPool<byte> _bufferPool = ArrayPool<byte>.Shared;
+    private readonly ArrayPool<byte> _bufferPool = SlabBufferPool.Chunks;
     private readonly CancellationTokenSource _disposeCts = new();
 
     private long _position;
@@ -84,6 +86,10 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     // Mirrors librespot's mode switch: random-access fetches near the seek,
     // then back to streaming-mode prefetch once playback is stable.
     private long _postSeekRecoveryUntilTick;
+
+    // Set when buffered-ahead reaches MaxBufferedAheadBytes; cleared once it drains
+    // below ResumeBufferedAheadBytes. Only touched by the background loop.
+    private bool _aboveHighWater;
     private const int PostSeekRecoveryMs = 2000;
 
     /// <summary>
@@ -106,9 +112,10 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     /// <param name="headData">Optional head file data for instant start.</param>
     /// <param name="params">Fetch parameters.</param>
     /// <param name="logger">Optional logger.</param>
-    /// <param name="persistCachePath">
-    /// When set, the fully downloaded file is written here so future plays can skip CDN resolution.
+    /// <param name="persistCacheDirectory">
+    /// When set, the fully downloaded file is written to this audio cache so future plays can skip CDN resolution.
     /// </param>
+    /// <param name="persistCacheFileId">Spotify file id (40-char hex) the file is cached under.</param>
     /// <param name="maxCacheBytes">Maximum persistent cache size before LRU pruning.</param>
     /// <param name="persistentCacheAudioKey">Audio key used to re-encrypt clear head data before cache commit.</param>
     public ProgressiveDownloader(
@@ -119,7 +126,8 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
         byte[]? headData = null,
         AudioFetchParams? @params = null,
         ILogger? logger = null,
-        string? persistCachePath = null,
+        string? persistCacheDirectory = null,
+        string? persistCacheFileId = null,
         long? maxCacheBytes = null,
         byte[]? persistentCacheAudioKey = null)
     {
@@ -134,7 +142,8 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
         _fileId = fileId;
         _params = @params ?? AudioFetchParams.Default;
         _logger = logger;
-        _persistCachePath = persistCachePath;
+        _persistCacheDirectory = string.IsNullOrEmpty(persistCacheFileId) ? null : persistCacheDirectory;
+        _persistCacheFileId = persistCacheFileId;
         _maxCacheBytes = maxCacheBytes is > 0 ? maxCacheBytes : null;
         _persistentCacheAudioKey = persistentCacheAudioKey is { Length: 16 }
             ? persistentCacheAudioKey.ToArray()
@@ -212,6 +221,11 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     /// </summary>
     public bool IsFullyDownloaded => _downloadedRanges.TotalBytes >= _fileSize;
 
+    /// <summary>
+    /// Gets the total number of bytes fetched so far, including head data.
+    /// </summary>
+    public long BytesDownloaded => Interlocked.Read(ref _bytesDownloadedTotal);
+
 
     #endregion
 
@@ -370,11 +384,18 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                     Math.Max(gap.End, gap.Start + _params.MinimumChunkSize),
                     _fileSize);
 
-                // Skip if already downloaded (could have been fetched by another task)
-                if (_downloadedRanges.ContainsRange(fetchStart, gap.End))
-                    continue;
+                // Split into MaximumChunkSize requests so one large prefetch can't hold
+                // the fetch slot (and a proportionally large cancellation waste) for long.
+                for (var chunkStart = fetchStart; chunkStart < fetchEnd; chunkStart += _params.MaximumChunkSize)
+                {
+                    var chunkEnd = Math.Min(chunkStart + _params.MaximumChunkSize, fetchEnd);
+
+                    // Skip if already downloaded (could have been fetched by another task)
+                    if (_downloadedRanges.ContainsRange(chunkStart, Math.Min(chunkEnd, gap.End)))
+                        continue;
 
-                await FetchChunkWithRetryAsync(fetchStart, fetchEnd, cancellationToken);
+                    await FetchChunkWithRetryAsync(chunkStart, chunkEnd, cancellationToken);
+                }
             }
         }
         finally
@@ -445,30 +466,41 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                 $"CDN returned {response.StatusCode} for range [{start}-{end}]");
         }
 
-        // Read response into buffer
-        var buffer = _bufferPool.Rent((int)(end - start));
+        // Stream the body through a fixed-size slice straight into the temp file.
+        // Memory per request stays at StreamSliceSize no matter how large the range,
+        // and readers blocked on the head of the range wake as soon as it lands.
+        var expected = (int)(end - start);
+        var buffer = _bufferPool.Rent(Math.Min(_params.StreamSliceSize, expected));
         try
         {
             await using var stream = await response.Content.ReadAsStreamAsync(cts.Token);
             var totalRead = 0;
-            int bytesRead;
 
-            while ((bytesRead = await stream.ReadAsync(
-                       buffer.AsMemory(totalRead, (int)(end - start) - totalRead),
-                       cts.Token)) > 0)
+            while (totalRead < expected)
             {
-                totalRead += bytesRead;
-            }
+                var slice = buffer.AsMemory(0, Math.Min(buffer.Length, expected - totalRead));
+                var sliceRead = 0;
+                int bytesRead;
+                while (sliceRead < slice.Length &&
+                       (bytesRead = await stream.ReadAsync(slice[sliceRead..], cts.Token)) > 0)
+                {
+                    sliceRead += bytesRead;
+                }
 
-            // Write to temp file
-            WriteToTempFile(start, buffer.AsSpan(0, totalRead));
+                if (sliceRead == 0)
+                    break;
+
+                WriteToTempFile(start + totalRead, buffer.AsSpan(0, sliceRead));
+                _downloadedRanges.AddRange(start + totalRead, start + totalRead + sliceRead);
+                Interlocked.Add(ref _bytesDownloadedTotal, sliceRead);
+                totalRead += sliceRead;
 
-            // Update tracking
-            _downloadedRanges.AddRange(start, start + totalRead);
-            Interlocked.Add(ref _bytesDownloadedTotal, totalRead);
+                // Wake any reader blocked in EnsureDataAvailable
+                _newDataAvailable.Set();
 
-            // Wake any reader blocked in EnsureDataAvailable
-            _newDataAvailable.Set();
+                if (sliceRead < slice.Length)
+                    break; // body ended early
+            }
 
             // Update throughput
             stopwatch.Stop();
@@ -626,7 +658,15 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                     readAheadBytes = _params.MinimumChunkSize;
                 }
 
-                if (bufferedAhead >= readAheadBytes)
+                // High-water backpressure: never plan past MaxBufferedAheadBytes, and
+                // once there, hold off until the reader drains below the low-water mark.
+                readAheadBytes = Math.Min(readAheadBytes, _params.MaxBufferedAheadBytes);
+                if (bufferedAhead >= _params.MaxBufferedAheadBytes)
+                    _aboveHighWater = true;
+                else if (bufferedAhead < _params.ResumeBufferedAheadBytes || inRandomAccess)
+                    _aboveHighWater = false;
+
+                if (bufferedAhead >= readAheadBytes || _aboveHighWater)
                 {
                     // Enough data buffered -- wait before checking again. Wakes
                     // up early on seek (NotifySeek releases _seekWakeSignal),
@@ -717,16 +757,16 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     /// </summary>
     private Task PersistToCacheIfCompleteAsync(CancellationToken ct)
     {
-        if (_persistCachePath == null || !IsFullyDownloaded)
+        if (_persistCacheDirectory == null || !IsFullyDownloaded)
             return Task.CompletedTask;
 
         if (Interlocked.Exchange(ref _persistStarted, 1) != 0)
             return Task.CompletedTask;
 
-        return PersistToCacheAsync(_persistCachePath, ct);
+        return PersistToCacheAsync(_persistCacheDirectory, _persistCacheFileId!, ct);
     }
 
-    private async Task PersistToCacheAsync(string cachePath, CancellationToken ct)
+    private async Task PersistToCacheAsync(string cacheDirectory, string cacheFileId, CancellationToken ct)
     {
         try
         {
@@ -742,25 +782,18 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                 return;
             }
 
-            var dir = Path.GetDirectoryName(cachePath);
-            if (dir != null && !Directory.Exists(dir))
-                Directory.CreateDirectory(dir);
-
-            // Read temp file from start and write to cache path atomically via a temp swap
-            var swapPath = cachePath + ".tmp";
-            await using (var dest = new FileStream(swapPath, FileMode.Create, FileAccess.Write,
-                FileShare.None, 4096, useAsync: true))
+            // Chunks are hashed as they're written; the manifest only appears on commit
+            using (var writer = AudioFileCache.BeginWrite(cacheDirectory, cacheFileId))
             {
-                await CopyTempFileForPersistentCacheAsync(dest, ct);
+                await Task.Run(() => CopyTempFileForPersistentCache(writer, ct), ct);
+                writer.Commit();
             }
-            File.Move(swapPath, cachePath, overwrite: true);
-            File.SetLastAccessTimeUtc(cachePath, DateTime.UtcNow);
 
             _logger?.LogInformation("Audio file {FileId} persisted to cache ({Bytes} bytes)",
                 _fileId.ToBase16(), _fileSize);
 
-            if (_maxCacheBytes is > 0 && dir != null)
-                PruneCacheDirectory(dir, cachePath, _maxCacheBytes.Value);
+            if (_maxCacheBytes is > 0)
+                PruneCache(cacheDirectory, cacheFileId, _maxCacheBytes.Value);
         }
         catch (Exception ex)
         {
@@ -768,7 +801,7 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
         }
     }
 
-    private async Task CopyTempFileForPersistentCacheAsync(FileStream destination, CancellationToken ct)
+    private void CopyTempFileForPersistentCache(AudioCacheWriter destination, CancellationToken ct)
     {
         // Snapshot the clear head bytes under lock so we can re-encrypt them
         // without racing with concurrent BASS reads on _tempFile.
@@ -798,7 +831,7 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
             // Re-encrypt clear head in place so cache is uniformly encrypted from byte 0.
             // _persistentCacheAudioKey is non-null here (PersistToCacheAsync guards above).
             AudioDecryptStream.ApplySpotifyCtr(_persistentCacheAudioKey!, headSnapshot.AsSpan(), 0);
-            await destination.WriteAsync(headSnapshot, ct);
+            destination.Write(headSnapshot);
         }
 
         // Copy the remainder (encrypted CDN body) chunk-by-chunk, taking the
@@ -818,8 +851,9 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                 }
                 if (read <= 0)
                     break;
-                await destination.WriteAsync(buffer.AsMemory(0, read), ct);
+                destination.Write(buffer.AsSpan(0, read));
                 position += read;
+                ct.ThrowIfCancellationRequested();
             }
         }
         finally
@@ -828,39 +862,12 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
         }
     }
 
-    private void PruneCacheDirectory(string cacheDirectory, string protectedPath, long maxBytes)
+    private void PruneCache(string cacheDirectory, string protectedFileId, long maxBytes)
     {
         try
         {
-            var protectedFullPath = Path.GetFullPath(protectedPath);
-            var files = new DirectoryInfo(cacheDirectory)
-                .EnumerateFiles("*.enc", SearchOption.TopDirectoryOnly)
-                .OrderBy(f => string.Equals(Path.GetFullPath(f.FullName), protectedFullPath, StringComparison.OrdinalIgnoreCase)
-                    ? DateTime.MaxValue
-                    : EffectiveLastAccessUtc(f))
-                .ToList();
-
-            var totalBytes = files.Sum(f => f.Length);
-            foreach (var file in files)
-            {
-                if (totalBytes <= maxBytes)
-                    break;
-
-                if (string.Equals(Path.GetFullPath(file.FullName), protectedFullPath, StringComparison.OrdinalIgnoreCase))
-                    continue;
-
-                var length = file.Length;
-                try
-                {
-                    file.Delete();
-                    totalBytes -= length;
-                    _logger?.LogInformation("Pruned cached audio file {File} ({Bytes} bytes)", file.Name, length);
-                }
-                catch (Exception ex)
-                {
-                    _logger?.LogDebug(ex, "Failed to prune cached audio file {File}", file.FullName);
-                }
-            }
+            foreach (var fileId in AudioFileCache.Prune(cacheDirectory, maxBytes, protectedFileId))
+                _logger?.LogInformation("Pruned cached audio file {FileId}", fileId);
         }
         catch (Exception ex)
         {
@@ -868,11 +875,6 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
         }
     }
 
-    private static DateTime EffectiveLastAccessUtc(FileInfo file)
-        => file.LastAccessTimeUtc > DateTime.UnixEpoch
-            ? file.LastAccessTimeUtc
-            : file.LastWriteTimeUtc;
-
     #endregion
 
     #region Unsupported Operations
@@ -143,10 +143,17 @@ internal sealed class AudioHostService : IAsyncDisposable
 
         var sink = AudioSinkFactory.CreateDefault(_logger);
         _sink = sink;
-        var decoderRegistry = CreateDecoderRegistry();
+        var decoderRegistry = CreateDecoderRegistry(config);
         var volumeProcessor = new VolumeProcessor();
         if (sink is PortAudioSink portAudioSink)
             portAudioSink.SetRealtimeVolumeProcessor(volumeProcessor);
+        if (sink is ITransportFadeSink fadingSink)
+        {
+            if (config?.FadeInMs is { } fadeInMs)
+                fadingSink.FadeInMs = fadeInMs;
+            if (config?.FadeOutMs is { } fadeOutMs)
+                fadingSink.FadeOutMs = fadeOutMs;
+        }
 
         var processingChain = CreateProcessingChain(
             config,
@@ -156,13 +163,17 @@ internal sealed class AudioHostService : IAsyncDisposable
         // gaps. ProgressiveDownloader no longer cancels the in-flight fetch
         // on seek (NotifySeek), and the on-demand fetch for the new seek
         // target reuses a pooled connection — no fresh TCP+TLS handshake.
-        var httpClient = new HttpClient(new SocketsHttpHandler
-        {
-            PooledConnectionLifetime = TimeSpan.FromMinutes(5),
-            PooledConnectionIdleTimeout = TimeSpan.FromMinutes(2),
-            MaxConnectionsPerServer = 4,
-            EnableMultipleHttp2Connections = true,
-        });
+        // Bound like the parent's session traffic (network.localAddress/addressFamily).
+        var httpClient = new HttpClient(CdnNetworkBinding.Apply(
+            new SocketsHttpHandler
+            {
+                PooledConnectionLifetime = TimeSpan.FromMinutes(5),
+                PooledConnectionIdleTimeout = TimeSpan.FromMinutes(2),
+                MaxConnectionsPerServer = 4,
+                EnableMultipleHttp2Connections = true,
+            },
+            config?.LocalAddress,
+            config?.AddressFamily));
 
         _engine = new AudioEngine(sink, decoderRegistry, processingChain, httpClient, volumeProcessor, _logger,
             audioCacheDirectory: config?.AudioCacheDirectory,
@@ -181,6 +192,24 @@ internal sealed class AudioHostService : IAsyncDisposable
         // follow it — refresh PortAudio AND reopen the stream on the new default device.
         _deviceWatcher.DefaultOutputDeviceChanged += OnWindowsDefaultOutputDeviceChanged;
 
+        if (config?.StallTimeoutMs is { } stallTimeoutMs)
+            _engine.SetStallTimeout(stallTimeoutMs);
+
+        if (config?.CdnRequestTimeoutMs is { } cdnRequestTimeoutMs)
+            _engine.SetCdnRequestTimeout(cdnRequestTimeoutMs);
+
+        if (config?.BitPerfect == true)
+        {
+            try
+            {
+                _engine.SetBitPerfect(true);
+            }
+            catch (NotSupportedException ex)
+            {
+                _logger.LogError(ex, "Bit-perfect output requested but unavailable");
+            }
+        }
+
         // Pre-seed volume so the first state snapshot carries a real value
         if (config?.InitialVolumePercent is > 0 and <= 100)
         {
@@ -234,12 +263,29 @@ internal sealed class AudioHostService : IAsyncDisposable
         _cts.Cancel();
     }
 
-    private AudioDecoderRegistry CreateDecoderRegistry()
+    private AudioDecoderRegistry CreateDecoderRegistry(AudioHostConfig? config)
     {
         var registry = new AudioDecoderRegistry();
-        registry.Register(new VorbisDecoder(_logger));
         _bassDecoder = new BassDecoder(_logger);
-        registry.Register(_bassDecoder);
+        if (string.Equals(config?.DecoderBackend, "bass", StringComparison.OrdinalIgnoreCase))
+        {
+            // One backend for every codec and local-file extension. Preview analysis keeps
+            // its own instance so it can't take the player's pending seek.
+            registry.Register(new BassDecoder(_logger),
+                "vorbis", "audio/vorbis", "ogg",
+                "mp3", "audio/mpeg",
+                "aac", "audio/aac", "audio/mp4", "m4a",
+                "flac", "audio/flac",
+                "wav", "aiff");
+            _logger.LogInformation("Decoding with the BASS backend");
+        }
+        else
+        {
+            registry.Register(new VorbisDecoder(_logger), "vorbis", "audio/vorbis");
+            registry.Register(_bassDecoder);
+        }
+
+        DecoderPluginLoader.LoadAll(registry, _logger);
         return registry;
     }
 
@@ -250,12 +296,17 @@ internal sealed class AudioHostService : IAsyncDisposable
     {
         var chain = new AudioProcessingChain();
 
+        // Channel layout runs first so everything downstream sees the output layout.
+        chain.AddProcessor(new ChannelMapProcessor());
+
         var audioPreset = NormalizeAudioPreset(config?.AudioPreset);
         var radioProcessingEnabled = string.Equals(audioPreset, "Radio", StringComparison.OrdinalIgnoreCase);
 
         // Normalization
         var normalization = new NormalizationProcessor();
         normalization.IsEnabled = config?.NormalizationEnabled ?? true;
+        if (config?.Normalization is { } normalizationSettings)
+            normalization.Configure(normalizationSettings);
         chain.AddProcessor(normalization);
 
         // Equalizer
@@ -276,6 +327,11 @@ internal sealed class AudioHostService : IAsyncDisposable
         chain.AddProcessor(compressor);
         chain.AddProcessor(limiter);
 
+        // Visualization tap: after the DSP, before user volume so the display
+        // doesn't shrink with the volume. Off until a frontend configures it.
+        chain.AddProcessor(new PcmTapProcessor());
+        chain.AddProcessor(new OutputAnalysisProcessor { IsEnabled = config?.OutputDiagnostics ?? false });
+
         _logger.LogInformation(
             "Audio processing chain configured: preset={Preset}, normalization={Normalization}, eq={Eq}, compressor={Compressor}, limiter={Limiter}",
             audioPreset,
@@ -331,6 +387,67 @@ internal sealed class AudioHostService : IAsyncDisposable
             _ = _transport!.SendAsync(IpcMessageTypes.TrackFinished,
                 IpcPayloadHelper.SerializeToUtf8(finished), ct: CancellationToken.None);
         });
+
+        _engine.DeviceChanges.Subscribe(change =>
+        {
+            if (ct.IsCancellationRequested) return;
+            // Force the next state snapshot to re-send the device list
+            _lastSentAudioDeviceName = null;
+            var msg = new AudioDeviceChangedMessage
+            {
+                PreviousDeviceName = change.PreviousDeviceName,
+                DeviceName = change.DeviceName,
+                Reason = change.Reason,
+                PositionMs = change.PositionMs
+            };
+            _ = _transport!.SendAsync(IpcMessageTypes.DeviceChanged,
+                IpcPayloadHelper.SerializeToUtf8(msg), ct: CancellationToken.None);
+        });
+
+        _engine.PipelineStalls.Subscribe(stall =>
+        {
+            if (ct.IsCancellationRequested) return;
+            var msg = new PipelineStallMessage
+            {
+                TrackUri = stall.TrackUri,
+                PositionMs = stall.PositionMs,
+                StalledMs = stall.StalledMs,
+                Stage = stall.Stage,
+                Attempt = stall.Attempt,
+                Restarted = stall.Restarted
+            };
+            _ = _transport!.SendAsync(IpcMessageTypes.PipelineStalled,
+                IpcPayloadHelper.SerializeToUtf8(msg), ct: CancellationToken.None);
+        });
+
+        _engine.OutputDiagnostics.Subscribe(diagnostic =>
+        {
+            if (ct.IsCancellationRequested) return;
+            if (diagnostic.Ended)
+                _logger.LogInformation("Output {Kind} ended after {Duration}ms at {Position}ms ({Uri})",
+                    diagnostic.Kind, diagnostic.DurationMs, diagnostic.PositionMs, diagnostic.TrackUri ?? "<none>");
+            else
+                _logger.LogWarning("Output {Kind} detected at {Position}ms ({Uri})",
+                    diagnostic.Kind, diagnostic.PositionMs, diagnostic.TrackUri ?? "<none>");
+            var msg = new OutputDiagnosticMessage
+            {
+                Kind = diagnostic.Kind,
+                TrackUri = diagnostic.TrackUri,
+                PositionMs = diagnostic.PositionMs,
+                DurationMs = diagnostic.DurationMs,
+                Ended = diagnostic.Ended,
+                ClippedRuns = diagnostic.ClippedRuns
+            };
+            _ = _transport!.SendAsync(IpcMessageTypes.OutputDiagnostic,
+                IpcPayloadHelper.SerializeToUtf8(msg), ct: CancellationToken.None);
+        });
+
+        _engine.PcmTapFrames.Subscribe(frame =>
+        {
+            if (ct.IsCancellationRequested) return;
+            _ = _transport!.SendAsync(IpcMessageTypes.PcmTapFrame,
+                IpcPayloadHelper.SerializeToUtf8(frame), ct: CancellationToken.None);
+        });
     }
 
     private async Task ProcessCommandsAsync(CancellationToken ct)
@@ -384,7 +501,12 @@ internal sealed class AudioHostService : IAsyncDisposable
             {
                 var cmd = IpcPayloadHelper.Deserialize<PlayResolvedTrackCommand>(msg);
                 if (cmd != null)
+                {
+                    // A new load preempts the previous one: release anything still
+                    // waiting on its deferred key/CDN so it unwinds immediately.
+                    _deferredRegistry.CancelAll();
                     await _engine.PlayAsync(cmd, ct);
+                }
                 await SendOk(msg.Id, ct);
                 break;
             }
@@ -393,6 +515,7 @@ internal sealed class AudioHostService : IAsyncDisposable
                 var cmd = IpcPayloadHelper.Deserialize<PlayTrackCommand>(msg);
                 if (cmd != null)
                 {
+                    _deferredRegistry.CancelAll();
                     var deferredTask = _deferredRegistry.CreateDeferred(cmd.DeferredId);
                     await _engine.PlayAsync(cmd, deferredTask, ct);
                 }
@@ -403,7 +526,29 @@ internal sealed class AudioHostService : IAsyncDisposable
             {
                 var cmd = IpcPayloadHelper.Deserialize<PlayLocalFileCommand>(msg);
                 if (cmd != null)
+                {
+                    _deferredRegistry.CancelAll();
                     await _engine.PlayAsync(cmd, ct);
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.PlayPreview:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<PlayPreviewCommand>(msg);
+                if (cmd != null)
+                {
+                    _deferredRegistry.CancelAll();
+                    await _engine.PlayAsync(cmd, ct);
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.Prewarm:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<PrewarmTrackCommand>(msg);
+                if (cmd != null)
+                    await _engine.PrewarmAsync(cmd, ct);
                 await SendOk(msg.Id, ct);
                 break;
             }
@@ -438,9 +583,14 @@ internal sealed class AudioHostService : IAsyncDisposable
                 await SendOk(msg.Id, ct);
                 break;
             case IpcMessageTypes.Stop:
+                _deferredRegistry.CancelAll();
                 await _engine.StopAsync(ct);
                 await SendOk(msg.Id, ct);
                 break;
+            case IpcMessageTypes.ReleaseOutput:
+                await _engine.ReleaseOutputAsync(ct);
+                await SendOk(msg.Id, ct);
+                break;
             case IpcMessageTypes.Seek:
             {
                 var cmd = IpcPayloadHelper.Deserialize<SeekCommand>(msg);
@@ -460,7 +610,9 @@ internal sealed class AudioHostService : IAsyncDisposable
             case IpcMessageTypes.SetNormalization:
             {
                 var cmd = IpcPayloadHelper.Deserialize<SetNormalizationCommand>(msg);
-                if (cmd != null)
+                if (cmd?.Settings != null)
+                    _engine.ConfigureNormalization(cmd.Settings);
+                else if (cmd != null)
                     _engine.SetNormalizationEnabled(cmd.Enabled);
                 await SendOk(msg.Id, ct);
                 break;
@@ -489,6 +641,20 @@ internal sealed class AudioHostService : IAsyncDisposable
                     }), ct: ct);
                 break;
             }
+            case IpcMessageTypes.GetPipelineInfo:
+            {
+                var info = await _engine.GetPipelineInfoAsync();
+                var infoJson = IpcPayloadHelper.SerializeToUtf8(info);
+                using var infoDoc = System.Text.Json.JsonDocument.Parse(infoJson);
+                await _transport!.SendAsync(IpcMessageTypes.CommandResult,
+                    IpcPayloadHelper.SerializeToUtf8(new CommandResultMessage
+                    {
+                        RequestId = msg.Id,
+                        Success = true,
+                        Result = infoDoc.RootElement.Clone()
+                    }), ct: ct);
+                break;
+            }
             case IpcMessageTypes.SwitchAudioOutput:
             {
                 var cmd = IpcPayloadHelper.Deserialize<SwitchAudioOutputCommand>(msg);
@@ -514,6 +680,124 @@ internal sealed class AudioHostService : IAsyncDisposable
                 }
                 break;
             }
+            case IpcMessageTypes.SetAudioRouting:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetAudioRoutingCommand>(msg);
+                if (cmd != null)
+                {
+                    var deviceNames = new Dictionary<AudioContentKind, string>();
+                    if (cmd.MusicDeviceName != null)
+                        deviceNames[AudioContentKind.Music] = cmd.MusicDeviceName;
+                    if (cmd.SpokenWordDeviceName != null)
+                        deviceNames[AudioContentKind.SpokenWord] = cmd.SpokenWordDeviceName;
+                    _engine.SetRoutingPolicy(AudioRouting.ByDeviceName(deviceNames));
+                    _logger.LogInformation("Audio routing set: music={Music}, spokenWord={SpokenWord}",
+                        cmd.MusicDeviceName ?? "(default)", cmd.SpokenWordDeviceName ?? "(default)");
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.SetBitPerfect:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetBitPerfectCommand>(msg);
+                if (cmd != null)
+                {
+                    try
+                    {
+                        _engine.SetBitPerfect(cmd.Enabled);
+                    }
+                    catch (NotSupportedException ex)
+                    {
+                        await SendCommandResult(msg.Id, success: false, errorMessage: ex.Message, ct);
+                        break;
+                    }
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.SetChannelLayout:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetChannelLayoutCommand>(msg);
+                if (cmd != null)
+                {
+                    try
+                    {
+                        _engine.SetChannelLayout(cmd.DeviceName, cmd.Mode, cmd.ChannelMap);
+                        _logger.LogInformation("Channel layout set: device={Device}, mode={Mode}",
+                            cmd.DeviceName ?? "(all)", cmd.Mode);
+                    }
+                    catch (ArgumentException ex)
+                    {
+                        await SendCommandResult(msg.Id, success: false, errorMessage: ex.Message, ct);
+                        break;
+                    }
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.SetLoopRegion:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetLoopRegionCommand>(msg);
+                if (cmd?.StartMs is { } startMs && cmd.EndMs is { } endMs)
+                {
+                    if (cmd.TrackUri == null || !_engine.SetLoopRegion(cmd.TrackUri, startMs, endMs))
+                    {
+                        await SendCommandResult(msg.Id, success: false,
+                            errorMessage: "Loop region must lie on the loaded track with start < end", ct);
+                        break;
+                    }
+                }
+                else
+                {
+                    _engine.ClearLoopRegion();
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.SetOutputDiagnostics:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetOutputDiagnosticsCommand>(msg);
+                if (cmd != null)
+                {
+                    try
+                    {
+                        _engine.SetOutputDiagnostics(cmd.Enabled);
+                    }
+                    catch (NotSupportedException ex)
+                    {
+                        await SendCommandResult(msg.Id, success: false, errorMessage: ex.Message, ct);
+                        break;
+                    }
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.ConfigurePcmTap:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<ConfigurePcmTapCommand>(msg);
+                if (cmd != null)
+                {
+                    try
+                    {
+                        _engine.ConfigurePcmTap(cmd.Mode, cmd.RateHz, cmd.Size);
+                    }
+                    catch (NotSupportedException ex)
+                    {
+                        await SendCommandResult(msg.Id, success: false, errorMessage: ex.Message, ct);
+                        break;
+                    }
+                }
+                await SendOk(msg.Id, ct);
+                break;
+            }
+            case IpcMessageTypes.SetTransportFade:
+            {
+                var cmd = IpcPayloadHelper.Deserialize<SetTransportFadeCommand>(msg);
+                if (cmd != null)
+                    _engine.SetTransportFade(cmd.FadeInMs, cmd.FadeOutMs);
+                await SendOk(msg.Id, ct);
+                break;
+            }
             case IpcMessageTypes.RefreshAudioDevices:
             {
                 if (_sink is IDeviceSelectableSink dss)
@@ -818,6 +1102,11 @@ internal sealed class AudioHostService : IAsyncDisposable
             // Sending 0-100 here causes PlaybackStateService (state.Volume / 655.35) to
             // collapse the slider to 0 when the local engine echoes back.
             Volume = state.Volume > 0f ? (uint)Math.Round(state.Volume * 65535d) : 0,
+            UnderrunCount = state.UnderrunCount,
+            TrackBytesDownloaded = state.TrackBytesDownloaded,
+            TrackFromCache = state.TrackFromCache,
+            TrackDecodeMs = state.TrackDecodeMs,
+            TrackUnderruns = state.TrackUnderruns,
             ActiveAudioDeviceName = currentDeviceName,
             AvailableAudioDevices = availableDevices,
         };
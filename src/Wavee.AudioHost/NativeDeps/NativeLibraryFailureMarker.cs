@@ -1,5 +1,6 @@
 using System.Text.Json;
 using System.Text.Json.Serialization;
+using Wavee.Core.Storage;
 
 namespace Wavee.AudioHost.NativeDeps;
 
@@ -27,7 +28,7 @@ internal partial class NativeLibraryFailureJsonContext : JsonSerializerContext
 }
 
 /// <summary>
-/// Static helper that writes a small JSON failure marker to %LOCALAPPDATA%\Wavee\NativeDeps\
+/// Static helper that writes a small JSON failure marker to <see cref="WaveePaths.NativeDepsDirectory"/>
 /// when <see cref="NativeLibraryProvisioner"/> fails. The UI-side AudioProcessManager detects
 /// this marker on child-process exit code 3 and surfaces a specific error toast instead of
 /// running the default exponential-backoff restart loop.
@@ -42,10 +43,7 @@ internal static class NativeLibraryFailureMarker
     /// </summary>
     public static string GetMarkerPath(NativeLibraryDescriptor descriptor)
     {
-        var root = Path.Combine(
-            Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-            "Wavee", "NativeDeps");
-        return Path.Combine(root, descriptor.FailureMarkerName);
+        return Path.Combine(WaveePaths.Default.NativeDepsDirectory, descriptor.FailureMarkerName);
     }
 
     /// <summary>
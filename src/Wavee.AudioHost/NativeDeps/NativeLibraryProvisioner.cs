@@ -3,6 +3,7 @@ using System.Reflection;
 using System.Runtime.InteropServices;
 using System.Security.Cryptography;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage;
 
 namespace Wavee.AudioHost.NativeDeps;
 
@@ -13,7 +14,7 @@ namespace Wavee.AudioHost.NativeDeps;
 ///
 /// Flow (see Wavee.AudioHost/NativeDeps plan):
 ///   1. Platform gate via <see cref="NativeLibraryDescriptor.AppliesTo"/>.
-///   2. Cache probe under %LOCALAPPDATA%\Wavee\{CacheSubfolder}\{CacheFileName} + SHA-256 verify.
+///   2. Cache probe under {WaveePaths.CacheDirectory}\{CacheSubfolder}\{CacheFileName} + SHA-256 verify.
 ///   3. Otherwise download .nupkg, extract the embedded DLL, verify SHA-256, atomic rename.
 ///   4. Register a <see cref="NativeLibrary.SetDllImportResolver(Assembly,DllImportResolver)"/>
 ///      on the managed wrapper assembly so subsequent P/Invokes find the cached binary.
@@ -142,9 +143,7 @@ internal sealed class NativeLibraryProvisioner
 
     private string ResolveCachePath()
     {
-        var baseDir = Path.Combine(
-            Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-            "Wavee", _descriptor.CacheSubfolder);
+        var baseDir = Path.Combine(WaveePaths.Default.CacheDirectory, _descriptor.CacheSubfolder);
         return Path.Combine(baseDir, _descriptor.CacheFileName);
     }
 
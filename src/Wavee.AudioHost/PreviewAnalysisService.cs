@@ -1,6 +1,7 @@
 using System.Buffers.Binary;
 using System.Diagnostics;
 using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio;
 using Wavee.AudioHost.Audio.Abstractions;
 using Wavee.AudioHost.Audio.Decoders;
 using Wavee.AudioHost.Audio.Streaming;
@@ -16,7 +17,7 @@ internal sealed class PreviewAnalysisService : IAsyncDisposable
     private const float MinFrequencyHz = 45f;
     private const float MaxFrequencyHz = 16000f;
 
-    private static readonly float[] HannWindow = CreateHannWindow();
+    private static readonly float[] HannWindow = Fft.CreateHannWindow(FftLength);
 
     private readonly BassDecoder _decoder;
     private readonly Func<PreviewVisualizationFrame, CancellationToken, Task> _sendFrameAsync;
@@ -272,7 +273,7 @@ internal sealed class PreviewAnalysisService : IAsyncDisposable
             real[fftOffset + frameIndex] = mixed * HannWindow[fftOffset + frameIndex];
         }
 
-        Transform(real, imaginary);
+        Fft.Transform(real, imaginary);
 
         amplitudes = new float[BucketCount];
         var rawBands = new float[BucketCount];
@@ -286,8 +287,8 @@ internal sealed class PreviewAnalysisService : IAsyncDisposable
         {
             var bandStart = i / (float)BucketCount;
             var bandEnd = (i + 1) / (float)BucketCount;
-            var lowFrequency = LogLerp(minFrequency, maxFrequency, bandStart);
-            var highFrequency = LogLerp(minFrequency, maxFrequency, bandEnd);
+            var lowFrequency = Fft.LogLerp(minFrequency, maxFrequency, bandStart);
+            var highFrequency = Fft.LogLerp(minFrequency, maxFrequency, bandEnd);
             var startBin = Math.Clamp((int)MathF.Floor(lowFrequency * FftLength / sampleRate), 1, (FftLength / 2) - 1);
             var endBin = Math.Clamp((int)MathF.Ceiling(highFrequency * FftLength / sampleRate), startBin + 1, FftLength / 2);
 
@@ -324,71 +325,6 @@ internal sealed class PreviewAnalysisService : IAsyncDisposable
         return true;
     }
 
-    private static float[] CreateHannWindow()
-    {
-        var window = new float[FftLength];
-        for (int i = 0; i < window.Length; i++)
-            window[i] = 0.5f - (0.5f * MathF.Cos(2f * MathF.PI * i / (window.Length - 1)));
-
-        return window;
-    }
-
-    private static float LogLerp(float start, float end, float amount)
-    {
-        start = MathF.Max(start, 1f);
-        end = MathF.Max(end, start + 1f);
-        return start * MathF.Pow(end / start, amount);
-    }
-
-    private static void Transform(float[] real, float[] imaginary)
-    {
-        var n = real.Length;
-        for (int i = 1, j = 0; i < n; i++)
-        {
-            var bit = n >> 1;
-            for (; (j & bit) != 0; bit >>= 1)
-                j ^= bit;
-
-            j ^= bit;
-            if (i >= j)
-                continue;
-
-            (real[i], real[j]) = (real[j], real[i]);
-            (imaginary[i], imaginary[j]) = (imaginary[j], imaginary[i]);
-        }
-
-        for (int length = 2; length <= n; length <<= 1)
-        {
-            var angle = -2f * MathF.PI / length;
-            var wLengthReal = MathF.Cos(angle);
-            var wLengthImaginary = MathF.Sin(angle);
-
-            for (int i = 0; i < n; i += length)
-            {
-                var wReal = 1f;
-                var wImaginary = 0f;
-                var halfLength = length >> 1;
-
-                for (int j = 0; j < halfLength; j++)
-                {
-                    var evenIndex = i + j;
-                    var oddIndex = evenIndex + halfLength;
-                    var oddReal = (real[oddIndex] * wReal) - (imaginary[oddIndex] * wImaginary);
-                    var oddImaginary = (real[oddIndex] * wImaginary) + (imaginary[oddIndex] * wReal);
-
-                    real[oddIndex] = real[evenIndex] - oddReal;
-                    imaginary[oddIndex] = imaginary[evenIndex] - oddImaginary;
-                    real[evenIndex] += oddReal;
-                    imaginary[evenIndex] += oddImaginary;
-
-                    var nextWReal = (wReal * wLengthReal) - (wImaginary * wLengthImaginary);
-                    wImaginary = (wReal * wLengthImaginary) + (wImaginary * wLengthReal);
-                    wReal = nextWReal;
-                }
-            }
-        }
-    }
-
     public async ValueTask DisposeAsync()
     {
         Task? task;
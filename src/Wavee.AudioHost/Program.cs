@@ -4,6 +4,7 @@ using Microsoft.Extensions.Logging;
 using Serilog;
 using Wavee.AudioHost;
 using Wavee.AudioHost.NativeDeps;
+using Wavee.Core.Storage;
 using ILogger = Microsoft.Extensions.Logging.ILogger;
 
 // Parse arguments: --pipe <name> [--verbose]
@@ -31,9 +32,7 @@ for (int i = 0; i < args.Length; i++)
 }
 
 // Configure Serilog for the audio process
-var logPath = Path.Combine(
-    Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-    "Wavee", "Logs", "audiohost-.log");
+var logPath = Path.Combine(WaveePaths.Default.LogDirectory, "audiohost-.log");
 
 var loggerCfg = new LoggerConfiguration();
 if (verbose)
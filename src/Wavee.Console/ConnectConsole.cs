@@ -6,15 +6,18 @@ using Spectre.Console.Rendering;
 using Wavee.Connect;
 using Wavee.Connect.Commands;
 using Wavee.Connect.Connection;
+using Wavee.Connect.Metadata;
 using Wavee.Audio;
 using Wavee.AudioHost.Audio;
 using Wavee.Connect.Protocol;
+using Wavee.Core.Configuration;
 using Wavee.Core.DependencyInjection;
 using Wavee.Core.Http;
 using Wavee.Core.Library;
 using Wavee.Core.Library.Spotify;
 using Wavee.Core.Session;
 using Wavee.Core.Audio;
+using Wavee.Core.Authentication;
 using Wavee.Core.Http.Lyrics;
 using Wavee.Core.Storage;
 using Wavee.Core.Storage.Abstractions;
@@ -30,12 +33,23 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
     private readonly HttpClient _httpClient;
     private readonly SpectreUI _ui;
     private readonly ILogger? _logger;
+    private readonly ConfigReloader? _config;
+    private readonly ICredentialsCache? _credentialsCache;
     private readonly List<IDisposable> _subscriptions = new();
-    // AudioPipeline removed — audio now handled by AudioHost process
-    // TODO: Wire console to out-of-process AudioHost if local playback needed
-    private IPlaybackEngine? _audioPipeline;
+    private ConsolePlayback? _playback;
     private ServiceProvider? _serviceProvider;
     private SpotifyLibraryService? _libraryService;
+    private StatusHttpServer? _statusServer;
+    private UnixSocketControlServer? _controlSocket;
+#if WAVEE_MQTT
+    private MqttBridge? _mqttBridge;
+#endif
+#if WAVEE_GRPC
+    private GrpcControlServer? _grpcServer;
+#endif
+#if WAVEE_WASM_PLUGINS
+    private WasmPluginHost? _pluginHost;
+#endif
     private bool _disposed;
 
     // Lyrics state
@@ -45,12 +59,57 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
     // Reactive audio settings
     private AudioSettings? _audioSettings;
 
-    public ConnectConsole(Session session, HttpClient httpClient, SpectreUI ui, ILogger? logger = null)
+    public ConnectConsole(
+        Session session,
+        HttpClient httpClient,
+        SpectreUI ui,
+        ILogger? logger = null,
+        ConfigReloader? config = null,
+        ICredentialsCache? credentialsCache = null)
     {
         _session = session ?? throw new ArgumentNullException(nameof(session));
         _httpClient = httpClient ?? throw new ArgumentNullException(nameof(httpClient));
         _ui = ui ?? throw new ArgumentNullException(nameof(ui));
         _logger = logger;
+        _config = config;
+        _credentialsCache = credentialsCache;
+    }
+
+    // Local engine from the AudioHost; null in Connect-only mode.
+    private IPlaybackEngine? LocalEngine => _playback?.Engine;
+
+    /// <summary>
+    /// Cache services (metadata database, cache service, extended metadata client, metadata
+    /// provider chain) shared by the library and playback layers. Resolving <see cref="IMetadataDatabase"/>
+    /// takes the cache directory lock and may throw <see cref="CacheLockedException"/>.
+    /// </summary>
+    internal static ServiceProvider CreateCacheServices(
+        Session session,
+        HttpClient httpClient,
+        TimeSpan cacheLockWait,
+        ILogger? logger)
+    {
+        var services = new ServiceCollection();
+        services.AddWaveeCache(options =>
+        {
+            options.CacheLockTimeout = cacheLockWait;
+        });
+
+        // Register ExtendedMetadataClient - we have session context here
+        services.AddSingleton<IExtendedMetadataClient>(sp =>
+        {
+            var metadataDb = sp.GetRequiredService<IMetadataDatabase>();
+            return new ExtendedMetadataClient(
+                session,
+                httpClient,
+                metadataDb,
+                logger);
+        });
+
+        // Extra track metadata for the event stream, from whichever IMetadataProviders are registered.
+        services.AddSingleton(sp => new MetadataProviderChain(sp.GetServices<IMetadataProvider>(), logger: logger));
+
+        return services.BuildServiceProvider();
     }
 
     /// <summary>
@@ -70,31 +129,22 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         // Initialize DI container with cache services
         try
         {
-            var dbPath = Path.Combine(
-                Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData),
-                "Wavee",
-                "metadata.db");
-
-            var services = new ServiceCollection();
-            services.AddWaveeCache(options =>
-            {
-                options.DatabasePath = dbPath;
-            });
-
-            // Register ExtendedMetadataClient - we have session context here
-            services.AddSingleton<IExtendedMetadataClient>(sp =>
-            {
-                var metadataDb = sp.GetRequiredService<IMetadataDatabase>();
-                return new ExtendedMetadataClient(
-                    _session,
-                    _httpClient,
-                    metadataDb,
-                    _logger);
-            });
-
-            _serviceProvider = services.BuildServiceProvider();
+            _serviceProvider = CreateCacheServices(
+                _session,
+                _httpClient,
+                _config?.Current.Cache.LockWaitTimeout ?? TimeSpan.Zero,
+                _logger);
+            _ = _serviceProvider.GetRequiredService<IMetadataDatabase>(); // takes the cache directory lock
             _ui.AddLog("INF", $"Cache services initialized");
         }
+        catch (CacheLockedException ex)
+        {
+            _logger?.LogError("{Reason} Continuing without the metadata cache (holder pid {ProcessId})",
+                ex.Message, ex.HolderProcessId);
+            _ui.AddLog("ERR", $"{ex.Message} Close it or set cache.lockWait to wait for it; continuing without the metadata cache.");
+            _serviceProvider?.Dispose();
+            _serviceProvider = null;
+        }
         catch (Exception ex)
         {
             _ui.AddLog("WRN", $"Cache services initialization failed: {ex.Message}");
@@ -130,6 +180,8 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
                     metadataDatabase,
                     outboxHandlers,
                     null);
+                outboxProcessor.ReplayWhenOnline(_session.ConnectionState);
+                _subscriptions.Add(outboxProcessor);
 
                 // Create SpotifyLibraryService using unified MetadataDatabase
                 _libraryService = new SpotifyLibraryService(
@@ -152,29 +204,37 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             _ui.AddLog("WRN", $"Library services initialization failed: {ex.Message}");
         }
 
-        // Initialize audio pipeline for local playback
-        try
+        // Local playback runs in the AudioHost process; without one this is a Connect remote.
+        if (!ConsolePlayback.TryFindAudioHost(out var audioHostPath))
         {
-            var metadataDatabase = _serviceProvider?.GetService<IMetadataDatabase>();
-            var cacheService = _serviceProvider?.GetService<ICacheService>();
-
-            // TODO: Audio now handled by AudioHost process — wire up out-of-process audio here if needed
-            _ui.AddLog("INF", "Audio pipeline removed — Connect-only mode (no local playback)");
-
-            // Enable bidirectional mode for state tracking (no local engine)
-            if (_session.PlaybackState != null)
+            _ui.AddLog("INF", $"No AudioHost — Connect-only mode (set {ConsolePlayback.AudioHostEnvironmentVariable} for local playback)");
+        }
+        else if (_serviceProvider == null || _credentialsCache == null)
+        {
+            _ui.AddLog("WRN", "Local playback needs the metadata cache and stored credentials — Connect-only mode");
+        }
+        else
+        {
+            try
             {
-                _ui.AddLog("INF", "PlaybackStateManager initialized — cluster state tracking enabled");
+                _playback = await ConsolePlayback.StartAsync(
+                    _session,
+                    _httpClient,
+                    _serviceProvider,
+                    _credentialsCache,
+                    audioHostPath,
+                    _config?.Current.Player,
+                    _logger,
+                    cancellationToken);
+                if (_config != null)
+                    _playback.Follow(_config);
+                _ui.AddLog("INF", "Audio pipeline initialized - playback ready!");
             }
-            else
+            catch (Exception ex) when (ex is not OperationCanceledException)
             {
-                _ui.AddLog("INF", "Audio pipeline initialized - playback ready!");
+                _ui.AddLog("WRN", $"Audio pipeline initialization failed: {ex.Message} — Connect-only mode");
             }
         }
-        catch (Exception ex)
-        {
-            _ui.AddLog("WRN", $"Audio pipeline initialization failed: {ex.Message}");
-        }
 
         _ui.AddLog("INF", "Type 'help' for available commands");
 
@@ -184,6 +244,18 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         SubscribeToConnectionEvents();
         SubscribeToVolumeChanges();
 
+        StartStatusServer();
+        StartControlSocket();
+#if WAVEE_MQTT
+        await StartMqttBridgeAsync(cancellationToken);
+#endif
+#if WAVEE_GRPC
+        await StartGrpcServerAsync(cancellationToken);
+#endif
+#if WAVEE_WASM_PLUGINS
+        StartPluginHost();
+#endif
+
         // Update initial device state
         _ui.UpdateDevice(
             _session.Config.DeviceName,
@@ -229,9 +301,9 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
                 return false;
 
             case "pause":
-                if (_audioPipeline != null)
+                if (LocalEngine != null)
                 {
-                    await _audioPipeline.PauseAsync(cancellationToken);
+                    await LocalEngine.PauseAsync(cancellationToken);
                     _ui.AddLog("INF", "Paused");
                 }
                 else
@@ -241,9 +313,9 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
                 return false;
 
             case "resume":
-                if (_audioPipeline != null)
+                if (LocalEngine != null)
                 {
-                    await _audioPipeline.ResumeAsync(cancellationToken);
+                    await LocalEngine.ResumeAsync(cancellationToken);
                     _ui.AddLog("INF", "Resumed");
                 }
                 else
@@ -257,17 +329,17 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
                 return false;
 
             case "next":
-                if (_audioPipeline != null)
+                if (LocalEngine != null)
                 {
-                    await _audioPipeline.SkipNextAsync(cancellationToken);
+                    await LocalEngine.SkipNextAsync(cancellationToken);
                     _ui.AddLog("INF", "Skipped to next track");
                 }
                 return false;
 
             case "prev":
-                if (_audioPipeline != null)
+                if (LocalEngine != null)
                 {
-                    await _audioPipeline.SkipPreviousAsync(cancellationToken);
+                    await LocalEngine.SkipPreviousAsync(cancellationToken);
                     _ui.AddLog("INF", "Skipped to previous track");
                 }
                 return false;
@@ -276,6 +348,10 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
                 await HandleSyncCommandAsync(parts, cancellationToken);
                 return false;
 
+            case "offline":
+                await HandleOfflineCommandAsync(parts, cancellationToken);
+                return false;
+
             case "library":
             case "lib":
                 await HandleLibraryCommandAsync(parts, cancellationToken);
@@ -315,7 +391,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
 
     private async Task HandlePlayCommandAsync(string[] parts, CancellationToken cancellationToken)
     {
-        if (_audioPipeline == null)
+        if (LocalEngine == null)
         {
             _ui.AddLog("ERR", "Audio pipeline not available");
             return;
@@ -323,7 +399,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
 
         if (parts.Length < 2)
         {
-            _ui.AddLog("WRN", "Usage: play <spotify:track:xxx> or <file_path.mp3>");
+            _ui.AddLog("WRN", "Usage: play <spotify:uri | open.spotify.com url> or <file_path.mp3>");
             return;
         }
 
@@ -333,30 +409,21 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
 
         try
         {
-            // Check if it's a local file (file:// URI or file path)
-            var isLocalFile = uri.StartsWith("file://", StringComparison.OrdinalIgnoreCase) ||
-                              Path.IsPathRooted(uri) ||
-                              File.Exists(uri);
-
-            // Check if it's a Spotify context (playlist, album, artist, show)
-            var isContext = !isLocalFile && (
-                            uri.Contains(":playlist:") || uri.Contains("/playlist/") ||
-                            uri.Contains(":album:") || uri.Contains("/album/") ||
-                            uri.Contains(":artist:") || uri.Contains("/artist/") ||
-                            uri.Contains(":show:") || uri.Contains("/show/"));
-
-            var command = new PlayCommand
-            {
-                Endpoint = "play",
-                MessageIdent = "local",
-                MessageId = 0,
-                SenderDeviceId = _session.Config.DeviceId,
-                Key = "local/0",
-                TrackUri = isContext ? null : uri,
-                ContextUri = isContext ? uri : null
-            };
+            // Spotify URIs / open.spotify.com URLs resolve to a context; anything
+            // else (file:// URI or file path) is handed through as a local track.
+            var command = PlayableContext.TryResolve(uri, out var context)
+                ? context.ToPlayCommand(_session.Config.DeviceId)
+                : new PlayCommand
+                {
+                    Endpoint = "play",
+                    MessageIdent = "local",
+                    MessageId = 0,
+                    SenderDeviceId = _session.Config.DeviceId,
+                    Key = "local/0",
+                    TrackUri = uri
+                };
 
-            await _audioPipeline.PlayAsync(command, cancellationToken);
+            await LocalEngine.PlayAsync(command, cancellationToken);
         }
         catch (Exception ex)
         {
@@ -366,7 +433,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
 
     private async Task HandleSeekCommandAsync(string[] parts, CancellationToken cancellationToken)
     {
-        if (_audioPipeline == null)
+        if (LocalEngine == null)
         {
             _ui.AddLog("ERR", "Audio pipeline not available");
             return;
@@ -379,7 +446,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         }
 
         var positionMs = seconds * 1000L;
-        await _audioPipeline.SeekAsync(positionMs, cancellationToken);
+        await LocalEngine.SeekAsync(positionMs, cancellationToken);
         _ui.AddLog("INF", $"Seeked to {FormatTimeSpan(positionMs)}");
     }
 
@@ -596,7 +663,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
 
     private async Task PlayPlaylistAsync(string playlistUri, CancellationToken ct)
     {
-        if (_audioPipeline == null)
+        if (LocalEngine == null)
         {
             _ui.AddLog("ERR", "Audio pipeline not available");
             return;
@@ -614,7 +681,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             ContextUri = playlistUri
         };
 
-        await _audioPipeline.PlayAsync(command, ct);
+        await LocalEngine.PlayAsync(command, ct);
     }
 
     /// <summary>
@@ -778,7 +845,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
     /// </summary>
     private async Task PlaySearchResultAsync(string uri, Wavee.Core.Http.Pathfinder.SearchResultType type, CancellationToken ct)
     {
-        if (_audioPipeline == null)
+        if (LocalEngine == null)
         {
             _ui.AddLog("ERR", "Audio pipeline not available");
             return;
@@ -809,7 +876,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             ContextUri = isTrack ? null : uri
         };
 
-        await _audioPipeline.PlayAsync(command, ct);
+        await LocalEngine.PlayAsync(command, ct);
     }
 
     /// <summary>
@@ -1004,7 +1071,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
     /// </summary>
     private async Task PlayTrackInContextAsync(string contextUri, string trackUri, int trackIndex, CancellationToken ct)
     {
-        if (_audioPipeline == null)
+        if (LocalEngine == null)
         {
             _ui.AddLog("ERR", "Audio pipeline not available");
             return;
@@ -1024,7 +1091,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             SkipToIndex = trackIndex
         };
 
-        await _audioPipeline.PlayAsync(command, ct);
+        await LocalEngine.PlayAsync(command, ct);
     }
 
     /// <summary>
@@ -1391,6 +1458,7 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         _ui.AddLog("INF", "vol [0-100] - Set or show volume");
         _ui.AddLog("INF", "preset [name|next] - Audio preset (none, radio)");
         _ui.AddLog("INF", "device on|off - Toggle device active");
+        _ui.AddLog("INF", "offline [on|off] - Toggle offline mode (cached tracks only)");
         _ui.AddLog("INF", "sync [type] - Sync library (all|tracks|albums|artists|shows|playlists|...)");
         _ui.AddLog("INF", "library     - Show library sync state");
         _ui.AddLog("INF", "playlists   - Show synced playlists");
@@ -1439,6 +1507,32 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         }
     }
 
+    private async Task HandleOfflineCommandAsync(string[] parts, CancellationToken cancellationToken)
+    {
+        if (parts.Length < 2)
+        {
+            _ui.AddLog("INF", $"Offline mode is {(_session.IsOfflineMode ? "on" : "off")}");
+            return;
+        }
+
+        var action = parts[1].ToLower();
+        if (action is not ("on" or "off"))
+        {
+            _ui.AddLog("WRN", $"Unknown offline action: {action}. Use 'on' or 'off'.");
+            return;
+        }
+
+        try
+        {
+            await _session.SetOfflineModeAsync(action == "on", cancellationToken);
+            _ui.AddLog("INF", $"Offline mode is now {action}");
+        }
+        catch (Exception ex)
+        {
+            _ui.AddLog("ERR", $"Failed to switch offline mode: {ex.Message}");
+        }
+    }
+
     private async Task HandleVolumeCommandAsync(string[] parts, CancellationToken cancellationToken)
     {
         if (parts.Length == 1)
@@ -1607,6 +1701,154 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
         }));
     }
 
+    // Without a local engine the integrations don't offer playback commands at all.
+    private DaemonController CreateController() =>
+        new(_session, _playback is { } playback ? () => playback.Engine : null, _config,
+            _serviceProvider?.GetService<MetadataProviderChain>());
+
+    /// <summary>
+    /// Starts the HTTP status/control server when <c>WAVEE_HTTP_PORT</c> is set.
+    /// </summary>
+    private void StartStatusServer()
+    {
+        if (!StatusHttpServer.TryGetPrefixFromEnvironment(out var prefix))
+            return;
+
+        var (token, allowedOrigins) = StatusHttpServer.GetAccessFromEnvironment();
+        try
+        {
+            _statusServer = new StatusHttpServer(
+                _session,
+                CreateController(),
+                _serviceProvider?.GetService<ICacheService>(),
+                prefix,
+                token,
+                allowedOrigins,
+                _logger);
+            _statusServer.Start();
+            _ui.AddLog("INF", $"Status server listening on {prefix}");
+        }
+        catch (Exception ex)
+        {
+            _ui.AddLog("WRN", $"Status server failed to start on {prefix}: {ex.Message}");
+            _statusServer = null;
+        }
+    }
+
+    /// <summary>
+    /// Starts the Unix domain socket control channel when <c>WAVEE_CONTROL_SOCKET</c> is set.
+    /// </summary>
+    private void StartControlSocket()
+    {
+        if (!UnixSocketControlServer.TryGetPathFromEnvironment(out var path))
+            return;
+
+        var server = new UnixSocketControlServer(
+            _session,
+            CreateController(),
+            _serviceProvider?.GetService<ICacheService>(),
+            path,
+            _logger);
+        try
+        {
+            server.Start();
+            _controlSocket = server;
+            _ui.AddLog("INF", $"Control socket listening on {server.Path}");
+        }
+        catch (Exception ex)
+        {
+            server.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _ui.AddLog("WRN", $"Control socket failed to start on {path}: {ex.Message}");
+        }
+    }
+
+#if WAVEE_MQTT
+    /// <summary>
+    /// Starts the MQTT bridge when <c>WAVEE_MQTT_HOST</c> is set.
+    /// </summary>
+    private async Task StartMqttBridgeAsync(CancellationToken cancellationToken)
+    {
+        if (!MqttBridgeOptions.TryFromEnvironment(_session.Config.DeviceId, out var options))
+            return;
+
+        var bridge = new MqttBridge(
+            _session,
+            CreateController(),
+            _serviceProvider?.GetService<ICacheService>(),
+            options,
+            _logger);
+        try
+        {
+            await bridge.StartAsync(cancellationToken);
+            _mqttBridge = bridge;
+            _ui.AddLog("INF", $"MQTT bridge connected to {options.Host}:{options.Port} ({options.TopicPrefix})");
+        }
+        catch (Exception ex)
+        {
+            await bridge.DisposeAsync();
+            _ui.AddLog("WRN", $"MQTT bridge failed to connect to {options.Host}:{options.Port}: {ex.Message}");
+        }
+    }
+#endif
+
+#if WAVEE_GRPC
+    /// <summary>
+    /// Starts the gRPC control server when <c>WAVEE_GRPC_PORT</c> is set.
+    /// </summary>
+    private async Task StartGrpcServerAsync(CancellationToken cancellationToken)
+    {
+        if (!GrpcControlServer.TryGetEndpointFromEnvironment(out var endpoint))
+            return;
+
+        var service = new WaveeControlService(
+            _session,
+            CreateController(),
+            _serviceProvider?.GetService<ICacheService>(),
+            _serviceProvider?.GetService<IExtendedMetadataClient>(),
+            _logger);
+        var server = new GrpcControlServer(service, endpoint, _logger);
+        try
+        {
+            await server.StartAsync(cancellationToken);
+            _grpcServer = server;
+            _ui.AddLog("INF", $"gRPC control server listening on {endpoint}");
+        }
+        catch (Exception ex)
+        {
+            await server.DisposeAsync();
+            _ui.AddLog("WRN", $"gRPC control server failed to start on {endpoint}: {ex.Message}");
+        }
+    }
+#endif
+
+#if WAVEE_WASM_PLUGINS
+    /// <summary>
+    /// Loads WebAssembly plugins when <c>WAVEE_PLUGIN_DIR</c> is set.
+    /// </summary>
+    private void StartPluginHost()
+    {
+        if (WasmPluginHost.GetDirectoryFromEnvironment() is not { } directory)
+            return;
+
+        var host = new WasmPluginHost(
+            _session,
+            CreateController(),
+            _serviceProvider?.GetService<ICacheService>(),
+            _logger);
+        try
+        {
+            host.Start(directory);
+            _pluginHost = host;
+            _ui.AddLog("INF", $"Plugins loaded from {directory}: {string.Join(", ", host.PluginNames)}");
+        }
+        catch (Exception ex)
+        {
+            host.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _ui.AddLog("WRN", $"Plugins failed to load from {directory}: {ex.Message}");
+        }
+    }
+#endif
+
     private void SubscribeToVolumeChanges()
     {
         if (_session.DeviceState?.Volume == null)
@@ -1645,11 +1887,48 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             subscription?.Dispose();
         _subscriptions.Clear();
 
-        if (_audioPipeline != null)
+        if (_playback != null)
         {
-            _audioPipeline = null;
+            _playback.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _playback = null;
         }
 
+        if (_statusServer != null)
+        {
+            _statusServer.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _statusServer = null;
+        }
+
+        if (_controlSocket != null)
+        {
+            _controlSocket.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _controlSocket = null;
+        }
+
+#if WAVEE_MQTT
+        if (_mqttBridge != null)
+        {
+            _mqttBridge.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _mqttBridge = null;
+        }
+#endif
+
+#if WAVEE_GRPC
+        if (_grpcServer != null)
+        {
+            _grpcServer.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _grpcServer = null;
+        }
+#endif
+
+#if WAVEE_WASM_PLUGINS
+        if (_pluginHost != null)
+        {
+            _pluginHost.DisposeAsync().AsTask().GetAwaiter().GetResult();
+            _pluginHost = null;
+        }
+#endif
+
         if (_libraryService != null)
         {
             _libraryService.DisposeAsync().AsTask().GetAwaiter().GetResult();
@@ -1674,10 +1953,47 @@ internal sealed class ConnectConsole : IDisposable, IAsyncDisposable
             subscription?.Dispose();
         _subscriptions.Clear();
 
-        if (_audioPipeline != null)
+        if (_playback != null)
+        {
+            await _playback.DisposeAsync();
+            _playback = null;
+        }
+
+        if (_statusServer != null)
+        {
+            await _statusServer.DisposeAsync();
+            _statusServer = null;
+        }
+
+        if (_controlSocket != null)
+        {
+            await _controlSocket.DisposeAsync();
+            _controlSocket = null;
+        }
+
+#if WAVEE_MQTT
+        if (_mqttBridge != null)
+        {
+            await _mqttBridge.DisposeAsync();
+            _mqttBridge = null;
+        }
+#endif
+
+#if WAVEE_GRPC
+        if (_grpcServer != null)
+        {
+            await _grpcServer.DisposeAsync();
+            _grpcServer = null;
+        }
+#endif
+
+#if WAVEE_WASM_PLUGINS
+        if (_pluginHost != null)
         {
-            _audioPipeline = null;
+            await _pluginHost.DisposeAsync();
+            _pluginHost = null;
         }
+#endif
 
         if (_libraryService != null)
         {
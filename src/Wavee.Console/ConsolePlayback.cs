@@ -0,0 +1,247 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.DependencyInjection;
+using Microsoft.Extensions.Logging;
+using Wavee.Audio;
+using Wavee.AudioIpc;
+using Wavee.Core.Audio;
+using Wavee.Core.Authentication;
+using Wavee.Core.Configuration;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Wavee.Core.Storage.Abstractions;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Local playback for the console: runs Wavee.AudioHost as a child process and drives it
+/// with a <see cref="PlaybackOrchestrator"/>, rebuilt whenever the AudioHost restarts.
+/// </summary>
+/// <remarks>
+/// The AudioHost is Windows x64 only. It is taken from <see cref="AudioHostEnvironmentVariable"/>
+/// (<c>off</c> disables local playback), else from next to the console executable. Without one
+/// the console stays a Connect remote and its integrations don't offer playback commands
+/// (see <see cref="DaemonController"/>).
+/// Audio keys come from the access point only; the PlayPlay runtime isn't provisioned here.
+/// </remarks>
+internal sealed class ConsolePlayback : IAsyncDisposable
+{
+    public const string AudioHostEnvironmentVariable = "WAVEE_AUDIO_HOST";
+    public const string DisabledValue = "off";
+
+    private static readonly string[] SkipRuleKeys =
+        ["player.blockedTracks", "player.blockedArtists", "player.maxTrackDuration", "player.skipLiveVersions"];
+
+    private readonly Session _session;
+    private readonly SpClient _spClient;
+    private readonly AudioProcessManager _processManager;
+    private readonly PlayerBuilder _builder;
+    private readonly ILogger? _logger;
+    private readonly List<IDisposable> _subscriptions = new();
+    private PlaybackOrchestrator? _engine;
+    private AudioPipelineProxy? _proxy;
+    private PlayerConfig? _player;
+
+    private ConsolePlayback(
+        Session session,
+        SpClient spClient,
+        AudioProcessManager processManager,
+        PlayerBuilder builder,
+        PlayerConfig? player,
+        ILogger? logger)
+    {
+        _session = session;
+        _spClient = spClient;
+        _processManager = processManager;
+        _builder = builder;
+        _player = player;
+        _logger = logger;
+    }
+
+    /// <summary>Current orchestrator; replaced after an AudioHost restart.</summary>
+    public PlaybackOrchestrator? Engine => Volatile.Read(ref _engine);
+
+    /// <summary>
+    /// Finds the AudioHost executable.
+    /// </summary>
+    /// <returns>False when none is configured or installed (no local playback).</returns>
+    public static bool TryFindAudioHost(out string path)
+    {
+        path = Environment.GetEnvironmentVariable(AudioHostEnvironmentVariable)?.Trim() ?? string.Empty;
+        if (path.Equals(DisabledValue, StringComparison.OrdinalIgnoreCase))
+            return false;
+        if (path.Length > 0)
+            return true;
+
+        path = Path.Combine(AppContext.BaseDirectory, "Wavee.AudioHost.exe");
+        return OperatingSystem.IsWindows() && File.Exists(path);
+    }
+
+    /// <summary>
+    /// Launches the AudioHost and attaches an orchestrator to <paramref name="session"/>.
+    /// </summary>
+    /// <param name="services">Cache services from <see cref="ConnectConsole.CreateCacheServices"/>.</param>
+    /// <exception cref="InvalidOperationException">No stored credentials for the session's user.</exception>
+    public static async Task<ConsolePlayback> StartAsync(
+        Session session,
+        HttpClient httpClient,
+        IServiceProvider services,
+        ICredentialsCache credentialsCache,
+        string audioHostPath,
+        PlayerConfig? player = null,
+        ILogger? logger = null,
+        CancellationToken ct = default)
+    {
+        // The AudioHost restarts with these; it never receives them itself.
+        var username = session.GetUserData()?.Username;
+        var credentials = username == null ? null : await credentialsCache.LoadCredentialsAsync(username, ct);
+        if (credentials == null || credentials.AuthData.Length == 0)
+            throw new InvalidOperationException($"No stored credentials for '{username}'.");
+
+        var metadataDatabase = services.GetRequiredService<IMetadataDatabase>();
+        var cacheService = services.GetRequiredService<ICacheService>();
+        var metadataClient = services.GetRequiredService<IExtendedMetadataClient>();
+        session.SetCacheService(cacheService);
+
+        var spClient = (SpClient)session.SpClient;
+        var headFileClient = new HeadFileClient(
+            httpClient,
+            logger,
+            urlTemplateResolver: () => session.UserData?.HeadFilesUrl);
+        var trackResolver = new TrackResolver(
+            session, spClient, headFileClient, httpClient,
+            preferredQuality: player?.Quality ?? AudioQuality.VeryHigh,
+            extendedMetadataClient: metadataClient,
+            cacheService: cacheService,
+            logger: logger,
+            metadataDatabase: metadataDatabase);
+        var contextResolver = new ContextResolver(
+            spClient, metadataClient, cacheService, new HotCache<ContextCacheEntry>(256), logger);
+        var builder = new PlayerBuilder(session)
+            .WithTrackResolver(trackResolver)
+            .WithContextResolver(contextResolver)
+            .WithLogger(logger)
+            .WithListeningStats(metadataDatabase)
+            .WithCacheCleanup(services.GetService<CacheCleanupService>())
+            .WithPrefetch(player?.PrefetchEnabled ?? true)
+            .WithTrackFilter(player is null ? TrackFilterRules.None : TrackFilterRules.FromConfig(player));
+
+        var processManager = new AudioProcessManager(audioHostPath, logger);
+        var playback = new ConsolePlayback(session, spClient, processManager, builder, player, logger);
+        try
+        {
+            var proxy = await processManager.StartAsync(
+                username!,
+                credentials.AuthData,
+                session.Config.DeviceId,
+                initialVolumePercent: session.GetVolumePercentage() ?? player?.InitialVolumePercent ?? 50,
+                cdnRequestTimeout: session.Config.Network.CdnRequestTimeout,
+                network: session.Config.Network,
+                decoderBackend: player?.DecoderBackend ?? AudioDecoderBackend.Native,
+                normalizationEnabled: player?.NormalizationEnabled ?? true,
+                ct: ct);
+            playback.Attach(proxy);
+        }
+        catch
+        {
+            await playback.DisposeAsync();
+            throw;
+        }
+
+        processManager.ProxyRestarted += playback.Attach;
+        return playback;
+    }
+
+    /// <summary>
+    /// Applies the player settings of every reload of <paramref name="config"/> to the running
+    /// engine and AudioHost, until this playback is disposed.
+    /// </summary>
+    public void Follow(ConfigReloader config)
+    {
+        var subscription = Observable.FromEvent<EventHandler<ConfigChangedEventArgs>, ConfigChangedEventArgs>(
+                static handler => (_, e) => handler(e),
+                h => config.ConfigChanged += h,
+                h => config.ConfigChanged -= h)
+            .Subscribe(e => _ = ApplyAsync(e));
+        lock (_subscriptions)
+            _subscriptions.Add(subscription);
+    }
+
+    private async Task ApplyAsync(ConfigChangedEventArgs e)
+    {
+        var player = e.Current.Player;
+        Volatile.Write(ref _player, player);
+        if (Engine is not { } engine || Volatile.Read(ref _proxy) is not { } proxy)
+            return;
+
+        try
+        {
+            if (e.ChangedKeys.Contains("player.prefetch"))
+                engine.PrefetchEnabled = player.PrefetchEnabled;
+            if (e.ChangedKeys.Any(SkipRuleKeys.Contains))
+                engine.TrackFilter = TrackFilterRules.FromConfig(player);
+            if (e.ChangedKeys.Contains("player.normalization"))
+                await proxy.SetNormalizationEnabledAsync(player.NormalizationEnabled);
+            if (e.ChangedKeys.Contains("player.quality"))
+                await engine.SwitchQualityAsync(player.Quality);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Failed to apply reloaded player settings");
+        }
+    }
+
+    private void Attach(AudioPipelineProxy proxy)
+    {
+        var engine = _builder.Build(proxy);
+        // The builder and a restarted AudioHost still carry the startup settings.
+        if (Volatile.Read(ref _player) is { } player)
+        {
+            engine.PrefetchEnabled = player.PrefetchEnabled;
+            engine.TrackFilter = TrackFilterRules.FromConfig(player);
+            _ = SetNormalizationAsync(proxy, player.NormalizationEnabled);
+        }
+        _session.PlaybackState?.EnableBidirectionalMode(engine, _spClient, _session);
+        lock (_subscriptions)
+        {
+            _subscriptions.Add(proxy.Errors.Subscribe(OnError));
+            _subscriptions.Add(engine.Errors.Subscribe(OnError));
+        }
+
+        Volatile.Write(ref _proxy, proxy);
+        var previous = Interlocked.Exchange(ref _engine, engine);
+        if (previous != null)
+            _ = previous.DisposeAsync().AsTask();
+    }
+
+    private async Task SetNormalizationAsync(AudioPipelineProxy proxy, bool enabled)
+    {
+        try
+        {
+            await proxy.SetNormalizationEnabledAsync(enabled);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Failed to restore normalization after AudioHost restart");
+        }
+    }
+
+    private void OnError(Wavee.Connect.PlaybackError error) =>
+        _logger?.LogWarning("Playback error ({Type}): {Message}", error.ErrorType, error.Message);
+
+    public async ValueTask DisposeAsync()
+    {
+        _processManager.ProxyRestarted -= Attach;
+        lock (_subscriptions)
+        {
+            foreach (var subscription in _subscriptions)
+                subscription.Dispose();
+            _subscriptions.Clear();
+        }
+
+        var engine = Interlocked.Exchange(ref _engine, null);
+        if (engine != null)
+            await engine.DisposeAsync();
+        await _processManager.DisposeAsync();
+    }
+}
@@ -0,0 +1,226 @@
+using Wavee.Audio;
+using Wavee.Connect;
+using Wavee.Connect.Metadata;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Result of <see cref="DaemonController.ExecuteAsync"/>.
+/// </summary>
+internal enum CommandOutcome
+{
+    /// <summary>The command was carried out.</summary>
+    Ok,
+
+    /// <summary>No such command.</summary>
+    UnknownCommand,
+
+    /// <summary>Missing or out-of-range argument.</summary>
+    InvalidArgument,
+
+    /// <summary>The local engine isn't up yet, or the device rejected the change.</summary>
+    Unavailable
+}
+
+/// <summary>
+/// Executes the command set shared by the daemon's integrations (HTTP,
+/// MQTT, WebSocket): <c>play</c>, <c>pause</c>, <c>next</c>, <c>previous</c>,
+/// <c>seek</c> (argument: position in ms), <c>volume</c> (argument: 0-100),
+/// <c>reload</c> (re-read the configuration; see <see cref="ConfigReloader"/>),
+/// the hot cues <c>cue_set</c>, <c>cue</c> and <c>cue_clear</c> (argument: slot)
+/// and the A-B loop <c>loop_in</c>, <c>loop_out</c> (argument: optional position
+/// in ms, default the current position) and <c>loop_clear</c>.
+/// </summary>
+/// <remarks>
+/// A host without local playback passes no engine; every command but <c>volume</c> and
+/// <c>reload</c> is then reported as <see cref="CommandOutcome.UnknownCommand"/>, so the
+/// integrations don't offer what can never work.
+/// </remarks>
+internal sealed class DaemonController
+{
+    private static readonly HashSet<string> PlaybackCommands = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "play", "pause", "next", "previous", "seek",
+        "cue_set", "cue", "cue_clear", "loop_in", "loop_out", "loop_clear"
+    };
+
+    private readonly Session _session;
+    private readonly Func<IPlaybackEngine?>? _engine;
+
+    /// <param name="session">Session the commands act on.</param>
+    /// <param name="engine">Current local engine; null when the host has no local playback.</param>
+    /// <param name="config">Live configuration, for <c>reload</c>.</param>
+    /// <param name="metadata">Providers enriching the <c>track.metadata</c> event; null when the host has none.</param>
+    public DaemonController(
+        Session session,
+        Func<IPlaybackEngine?>? engine,
+        ConfigReloader? config = null,
+        MetadataProviderChain? metadata = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _engine = engine;
+        Config = config;
+        Metadata = metadata;
+    }
+
+    /// <summary>Live configuration, or null when the host doesn't support reloading.</summary>
+    public ConfigReloader? Config { get; }
+
+    /// <summary>Track metadata providers for the event stream, or null when the host has none.</summary>
+    public MetadataProviderChain? Metadata { get; }
+
+    /// <summary>True when the host has a local engine, i.e. transport commands, <c>load</c> and <c>prewarm</c> exist.</summary>
+    public bool HasPlayback => _engine != null;
+
+    /// <summary>
+    /// Runs <paramref name="command"/>. Exceptions from the engine, and
+    /// <see cref="ConfigException"/> from <c>reload</c>, propagate.
+    /// </summary>
+    /// <param name="command">Command name (case-insensitive).</param>
+    /// <param name="argument">
+    /// Position for <c>seek</c>, <c>loop_in</c> and <c>loop_out</c>, percentage for
+    /// <c>volume</c>, slot for the cue commands; ignored otherwise.
+    /// </param>
+    /// <param name="ct">Cancellation token.</param>
+    public async Task<CommandOutcome> ExecuteAsync(string command, long? argument, CancellationToken ct = default)
+    {
+        if (!HasPlayback && PlaybackCommands.Contains(command))
+            return CommandOutcome.UnknownCommand;
+
+        switch (command.ToLowerInvariant())
+        {
+            case "volume":
+                if (argument is not (>= 0 and <= 100))
+                    return CommandOutcome.InvalidArgument;
+                return await _session.SetVolumePercentageAsync((int)argument.Value, ct)
+                    ? CommandOutcome.Ok
+                    : CommandOutcome.Unavailable;
+
+            case "seek":
+                if (argument is not >= 0)
+                    return CommandOutcome.InvalidArgument;
+                return await RunAsync((e, c) => e.SeekAsync(argument.Value, c), ct);
+
+            case "play":
+                return await RunAsync(static (e, c) => e.ResumeAsync(c), ct);
+            case "pause":
+                return await RunAsync(static (e, c) => e.PauseAsync(c), ct);
+            case "next":
+                return await RunAsync(static (e, c) => e.SkipNextAsync(c), ct);
+            case "previous":
+                return await RunAsync(static (e, c) => e.SkipPreviousAsync(c), ct);
+
+            case "cue_set":
+                if (argument is not (>= 0 and < PlaybackOrchestrator.CueSlotCount))
+                    return CommandOutcome.InvalidArgument;
+                return await RunCueAsync(
+                    (o, _) => Task.FromResult(o.SetCuePoint((int)argument.Value) != null), ct);
+            case "cue":
+                if (argument is not (>= 0 and < PlaybackOrchestrator.CueSlotCount))
+                    return CommandOutcome.InvalidArgument;
+                return await RunCueAsync((o, c) => o.JumpToCuePointAsync((int)argument.Value, c), ct);
+            case "cue_clear":
+                if (argument is not (>= 0 and < PlaybackOrchestrator.CueSlotCount))
+                    return CommandOutcome.InvalidArgument;
+                return await RunCueAsync((o, _) =>
+                {
+                    o.ClearCuePoint((int)argument.Value);
+                    return Task.FromResult(true);
+                }, ct);
+
+            case "loop_in":
+                if (argument is < 0)
+                    return CommandOutcome.InvalidArgument;
+                return await RunCueAsync((o, c) => o.SetLoopInAsync(argument, c), ct);
+            case "loop_out":
+                if (argument is < 0)
+                    return CommandOutcome.InvalidArgument;
+                return await RunCueAsync((o, c) => o.SetLoopOutAsync(argument, c), ct);
+            case "loop_clear":
+                return await RunCueAsync(async (o, c) =>
+                {
+                    await o.ClearLoopAsync(c);
+                    return true;
+                }, ct);
+
+            case "reload":
+                if (Config == null)
+                    return CommandOutcome.Unavailable;
+                Config.Reload();
+                return CommandOutcome.Ok;
+
+            default:
+                return CommandOutcome.UnknownCommand;
+        }
+    }
+
+    /// <summary>
+    /// Starts playback of a Spotify URI or open.spotify.com URL (album,
+    /// playlist, artist, show, track or episode) on the local engine.
+    /// </summary>
+    public async Task<CommandOutcome> LoadAsync(string? uriOrUrl, CancellationToken ct = default)
+    {
+        if (!HasPlayback)
+            return CommandOutcome.UnknownCommand;
+        if (!PlayableContext.TryResolve(uriOrUrl, out var context))
+            return CommandOutcome.InvalidArgument;
+
+        var deviceId = _session.Config.DeviceId;
+        return await RunAsync((e, c) => e.PlayAsync(context.ToPlayCommand(deviceId), c), ct);
+    }
+
+    /// <summary>
+    /// Resolves a Spotify track or episode (URI or open.spotify.com URL) and readies
+    /// AudioHost's decoder, so a following <see cref="LoadAsync"/> of it skips resolving
+    /// the audio. For voice assistants that know the track before the request
+    /// finishes. Resolve failures propagate.
+    /// </summary>
+    public async Task<CommandOutcome> PrewarmAsync(string? uriOrUrl, CancellationToken ct = default)
+    {
+        if (!HasPlayback)
+            return CommandOutcome.UnknownCommand;
+        if (!PlayableContext.TryResolve(uriOrUrl, out var context) || context.TrackUri is not { } trackUri)
+            return CommandOutcome.InvalidArgument;
+        if (_engine?.Invoke() is not PlaybackOrchestrator orchestrator)
+            return CommandOutcome.Unavailable;
+
+        await orchestrator.PrewarmAsync(trackUri, ct);
+        return CommandOutcome.Ok;
+    }
+
+    /// <summary>
+    /// Short human-readable reason for a non-<see cref="CommandOutcome.Ok"/> outcome.
+    /// </summary>
+    public static string? Describe(CommandOutcome outcome) => outcome switch
+    {
+        CommandOutcome.Ok => null,
+        CommandOutcome.UnknownCommand => "unknown command",
+        CommandOutcome.InvalidArgument => "missing or invalid argument",
+        CommandOutcome.Unavailable => "playback unavailable",
+        _ => outcome.ToString()
+    };
+
+    // Cues and loops are local-engine features; false from the action means the
+    // slot was empty or the loop point wasn't usable.
+    private async Task<CommandOutcome> RunCueAsync(
+        Func<PlaybackOrchestrator, CancellationToken, Task<bool>> action,
+        CancellationToken ct)
+    {
+        if (_engine?.Invoke() is not PlaybackOrchestrator orchestrator)
+            return CommandOutcome.Unavailable;
+
+        return await action(orchestrator, ct) ? CommandOutcome.Ok : CommandOutcome.InvalidArgument;
+    }
+
+    private async Task<CommandOutcome> RunAsync(Func<IPlaybackEngine, CancellationToken, Task> action, CancellationToken ct)
+    {
+        var engine = _engine?.Invoke();
+        if (engine == null)
+            return CommandOutcome.Unavailable;
+
+        await action(engine, ct);
+        return CommandOutcome.Ok;
+    }
+}
@@ -0,0 +1,103 @@
+using System.Reactive.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization.Metadata;
+using Wavee.Connect.Commands;
+using Wavee.Connect.Metadata;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// One event pushed to a daemon integration.
+/// </summary>
+/// <param name="Type">Event name: <c>player.state</c>, <c>connect.command</c>, <c>session.connection</c>, <c>config.changed</c> or <c>track.metadata</c>.</param>
+/// <param name="Payload">Typed payload: <see cref="DaemonStatus"/>, <see cref="ConnectCommandEvent"/>, <see cref="ConnectionEvent"/>, <see cref="ConfigChangedEvent"/> or <see cref="TrackMetadataEvent"/>.</param>
+/// <param name="Data"><paramref name="Payload"/> serialized as JSON.</param>
+internal sealed record DaemonEvent(string Type, object Payload, byte[] Data);
+
+/// <summary>
+/// The session's player, Connect, connection, config reload and track metadata events as a single stream of
+/// pre-serialized <see cref="DaemonEvent"/>s, shared by the push-style
+/// integrations (WebSocket, JSON-RPC, gRPC).
+/// </summary>
+internal static class DaemonEvents
+{
+    public const string PlayerState = "player.state";
+    public const string ConnectCommand = "connect.command";
+    public const string SessionConnection = "session.connection";
+    public const string ConfigChanged = "config.changed";
+    public const string TrackMetadata = "track.metadata";
+
+    /// <summary>
+    /// Hot stream of events for <paramref name="session"/>; subscribe to start receiving.
+    /// <c>player.state</c> is sampled per <see cref="SamplingConfig.StateEventInterval"/>;
+    /// <c>track.metadata</c> is only raised when <paramref name="metadata"/> has providers.
+    /// </summary>
+    public static IObservable<DaemonEvent> Observe(
+        Session session,
+        ICacheService? cache,
+        ConfigReloader? config = null,
+        MetadataProviderChain? metadata = null)
+    {
+        var sources = new List<IObservable<DaemonEvent>>
+        {
+            DaemonStatus.Changes(session, session.Config.Sampling.StateEventInterval).Select(_ => CurrentState(session, cache))
+        };
+
+        if (session.CommandHandler is { } commands)
+        {
+            sources.Add(Observable.Merge<ConnectCommand>(
+                    commands.PlayCommands,
+                    commands.PauseCommands,
+                    commands.ResumeCommands,
+                    commands.SeekCommands,
+                    commands.SkipNextCommands,
+                    commands.SkipPrevCommands,
+                    commands.ShuffleCommands,
+                    commands.TransferCommands)
+                .Select(static c => Create(ConnectCommand,
+                    new ConnectCommandEvent(c.Endpoint, c.SenderDeviceId),
+                    DaemonJsonContext.Default.ConnectCommandEvent)));
+        }
+
+        if (session.Dealer is { } dealer)
+        {
+            sources.Add(dealer.ConnectionState.Select(static state => Create(SessionConnection,
+                new ConnectionEvent(state),
+                DaemonJsonContext.Default.ConnectionEvent)));
+        }
+
+        if (config != null)
+        {
+            sources.Add(Observable.FromEvent<EventHandler<ConfigChangedEventArgs>, ConfigChangedEventArgs>(
+                    static handler => (_, e) => handler(e),
+                    h => config.ConfigChanged += h,
+                    h => config.ConfigChanged -= h)
+                .Select(static e => Create(ConfigChanged,
+                    new ConfigChangedEvent(e.ChangedKeys, e.RestartRequiredKeys),
+                    DaemonJsonContext.Default.ConfigChangedEvent)));
+        }
+
+        if (metadata is { Providers.Count: > 0 } && session.PlaybackState is { } playback)
+        {
+            sources.Add(metadata.EnrichTrackChanges(playback.TrackChanged)
+                .Select(static track => Create(TrackMetadata,
+                    new TrackMetadataEvent(track.Uri, track.Metadata),
+                    DaemonJsonContext.Default.TrackMetadataEvent)));
+        }
+
+        return sources.Merge();
+    }
+
+    /// <summary>
+    /// A <c>player.state</c> event for right now — sent to new subscribers so
+    /// they don't wait for the next change.
+    /// </summary>
+    public static DaemonEvent CurrentState(Session session, ICacheService? cache) =>
+        Create(PlayerState, DaemonStatus.Capture(session, cache), DaemonJsonContext.Default.DaemonStatus);
+
+    private static DaemonEvent Create<T>(string type, T data, JsonTypeInfo<T> typeInfo) =>
+        new(type, data!, JsonSerializer.SerializeToUtf8Bytes(data, typeInfo));
+}
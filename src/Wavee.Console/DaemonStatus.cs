@@ -0,0 +1,168 @@
+using System.Reactive;
+using System.Reactive.Linq;
+using System.Text.Json.Serialization;
+using Wavee.Connect;
+using Wavee.Connect.Commands;
+using Wavee.Connect.Connection;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Point-in-time player status shared by the daemon's integrations
+/// (<see cref="StatusHttpServer"/>, <see cref="EventSocketHub"/> and the MQTT bridge).
+/// </summary>
+internal sealed record DaemonStatus(
+    DeviceStatus Device,
+    TrackStatus? Track,
+    PlaybackStatus Status,
+    long PositionMs,
+    long DurationMs,
+    CacheStatistics? Cache,
+    BandwidthStatistics Bandwidth)
+{
+    /// <summary>
+    /// Captures the current status from the session's cluster state.
+    /// </summary>
+    public static DaemonStatus Capture(Session session, ICacheService? cache)
+    {
+        var state = session.PlaybackState?.CurrentState;
+        var positionMs = state?.PositionMs ?? 0;
+        if (state is { Status: PlaybackStatus.Playing, Timestamp: > 0 })
+        {
+            // Extrapolate from the last cluster update so pollers see a moving position.
+            var elapsed = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds() - state.Timestamp;
+            positionMs = Math.Clamp(positionMs + Math.Max(0, elapsed), 0, Math.Max(state.DurationMs, positionMs));
+        }
+
+        return new DaemonStatus(
+            new DeviceStatus(
+                session.Config.DeviceId,
+                session.Config.DeviceName,
+                session.DeviceState?.IsActive ?? false,
+                session.GetVolumePercentage(),
+                state?.ActiveDeviceName),
+            state?.Track is { } track
+                ? new TrackStatus(track.Uri, track.Title, track.Artist, track.Album, state.ContextUri)
+                : null,
+            state?.Status ?? PlaybackStatus.Stopped,
+            positionMs,
+            state?.DurationMs ?? 0,
+            cache?.GetStatistics(),
+            session.Bandwidth.GetStatistics());
+    }
+
+    /// <summary>
+    /// Fires whenever a field of <see cref="DaemonStatus"/> may have changed
+    /// (track, status, position, active device, volume), sampled to at most
+    /// one notification per <paramref name="interval"/>. Bursts inside a window
+    /// coalesce into one notification at its end, so steady position updates
+    /// still come through; zero forwards every change.
+    /// </summary>
+    public static IObservable<Unit> Changes(Session session, TimeSpan interval)
+    {
+        var sources = new List<IObservable<Unit>>();
+        if (session.PlaybackState is { } playback)
+        {
+            sources.Add(playback.TrackChanged.Select(static _ => Unit.Default));
+            sources.Add(playback.PlaybackStatusChanged.Select(static _ => Unit.Default));
+            sources.Add(playback.PositionChanged.Select(static _ => Unit.Default));
+            sources.Add(playback.ActiveDeviceChanged.Select(static _ => Unit.Default));
+        }
+        if (session.DeviceState?.Volume is { } volume)
+            sources.Add(volume.DistinctUntilChanged().Select(static _ => Unit.Default));
+
+        var changes = sources.Merge();
+        return interval > TimeSpan.Zero ? changes.Sample(interval) : changes;
+    }
+}
+
+/// <summary>
+/// This device, plus whichever Connect device is currently playing.
+/// </summary>
+internal sealed record DeviceStatus(
+    string Id,
+    string Name,
+    bool IsActive,
+    int? VolumePercent,
+    string? ActiveDeviceName);
+
+/// <summary>
+/// Currently loaded track.
+/// </summary>
+internal sealed record TrackStatus(
+    string Uri,
+    string? Title,
+    string? Artist,
+    string? Album,
+    string? ContextUri);
+
+/// <summary>
+/// A Connect command received from another device.
+/// </summary>
+internal sealed record ConnectCommandEvent(
+    string Endpoint,
+    string SenderDeviceId);
+
+/// <summary>
+/// Dealer connection state change.
+/// </summary>
+internal sealed record ConnectionEvent(ConnectionState State);
+
+/// <summary>
+/// Settings applied by a configuration reload.
+/// </summary>
+/// <param name="Changed">Reloadable keys whose value changed.</param>
+/// <param name="RestartRequired">Keys or sections that differ but need a restart.</param>
+internal sealed record ConfigChangedEvent(
+    IReadOnlyList<string> Changed,
+    IReadOnlyList<string> RestartRequired);
+
+/// <summary>
+/// Track metadata merged from the configured metadata providers.
+/// </summary>
+/// <param name="Uri">Track the metadata belongs to.</param>
+/// <param name="Metadata">Spotify's metadata plus every key the providers added (e.g. <c>bpm</c>, <c>genre</c>).</param>
+internal sealed record TrackMetadataEvent(
+    string Uri,
+    IReadOnlyDictionary<string, string> Metadata);
+
+/// <summary>
+/// Reply to a command sent by an integration.
+/// </summary>
+/// <param name="Id">Caller-supplied correlation id, echoed back.</param>
+/// <param name="Command">Command name.</param>
+/// <param name="Ok">Whether the command was carried out.</param>
+/// <param name="Error">Failure reason when <paramref name="Ok"/> is false.</param>
+internal sealed record CommandReply(
+    string? Id,
+    string Command,
+    bool Ok,
+    string? Error);
+
+/// <summary>
+/// One search result returned to an integration.
+/// </summary>
+/// <param name="Type">Result kind (track, album, artist, playlist, ...).</param>
+/// <param name="Uri">Spotify URI, playable via <c>load</c>.</param>
+/// <param name="Name">Display name.</param>
+/// <param name="Subtitle">Artists, owner or publisher, when known.</param>
+internal sealed record SearchHit(
+    string Type,
+    string Uri,
+    string Name,
+    string? Subtitle);
+
+[JsonSerializable(typeof(DaemonStatus))]
+[JsonSerializable(typeof(SearchHit[]))]
+[JsonSerializable(typeof(ConnectCommandEvent))]
+[JsonSerializable(typeof(ConnectionEvent))]
+[JsonSerializable(typeof(ConfigChangedEvent))]
+[JsonSerializable(typeof(TrackMetadataEvent))]
+[JsonSerializable(typeof(CommandReply))]
+[JsonSerializable(typeof(RemoteCommandEntry[]))]
+[JsonSourceGenerationOptions(
+    PropertyNamingPolicy = JsonKnownNamingPolicy.CamelCase,
+    UseStringEnumConverter = true)]
+internal sealed partial class DaemonJsonContext : JsonSerializerContext;
@@ -0,0 +1,212 @@
+using System.Buffers;
+using System.Collections.Concurrent;
+using System.Net;
+using System.Net.WebSockets;
+using System.Text.Json;
+using System.Threading.Channels;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// WebSocket push channel for local frontends (Electron, web), served by
+/// <see cref="StatusHttpServer"/> at <c>/events</c>.
+/// </summary>
+/// <remarks>
+/// Every server → client frame is a JSON envelope <c>{"type": ..., "data": ...}</c>:
+/// <list type="bullet">
+///   <item><c>player.state</c> — <see cref="DaemonStatus"/>; sent on connect and on every change.</item>
+///   <item><c>connect.command</c> — <see cref="ConnectCommandEvent"/> received from another device.</item>
+///   <item><c>session.connection</c> — <see cref="ConnectionEvent"/> for the dealer connection.</item>
+///   <item><c>track.metadata</c> — <see cref="TrackMetadataEvent"/> once the metadata providers have answered for a new track.</item>
+///   <item><c>command.result</c> — <see cref="CommandReply"/> for a client command.</item>
+/// </list>
+/// Clients send <c>{"id": "1", "command": "volume", "argument": 40}</c>; see
+/// <see cref="DaemonController"/> for the command set. Slow clients drop their
+/// oldest queued frames rather than stalling the others. Client messages over
+/// <see cref="MaxMessageBytes"/> close the connection.
+/// </remarks>
+internal sealed class EventSocketHub : IDisposable
+{
+    private const int ClientQueueCapacity = 64;
+    private const int ReceiveChunkBytes = 1024;
+
+    /// <summary>Largest client message accepted; commands are a few dozen bytes.</summary>
+    public const int MaxMessageBytes = 16 * 1024;
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly ILogger? _logger;
+    private readonly ConcurrentDictionary<Guid, Channel<byte[]>> _clients = new();
+    private readonly IDisposable _subscription;
+
+    public EventSocketHub(Session session, DaemonController controller, ICacheService? cache, ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _logger = logger;
+
+        _subscription = DaemonEvents.Observe(session, cache, controller.Config, controller.Metadata).Subscribe(e => Broadcast(Encode(e)));
+    }
+
+    /// <summary>Number of connected clients.</summary>
+    public int ClientCount => _clients.Count;
+
+    /// <summary>
+    /// Upgrades <paramref name="context"/> to a WebSocket and serves it until
+    /// either side closes or <paramref name="ct"/> fires.
+    /// </summary>
+    public async Task AcceptAsync(HttpListenerContext context, CancellationToken ct)
+    {
+        WebSocket socket;
+        try
+        {
+            socket = (await context.AcceptWebSocketAsync(subProtocol: null)).WebSocket;
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogDebug(ex, "WebSocket upgrade failed");
+            context.Response.StatusCode = (int)HttpStatusCode.BadRequest;
+            context.Response.Close();
+            return;
+        }
+
+        var id = Guid.NewGuid();
+        var queue = Channel.CreateBounded<byte[]>(new BoundedChannelOptions(ClientQueueCapacity)
+        {
+            FullMode = BoundedChannelFullMode.DropOldest,
+            SingleReader = true
+        });
+        _clients[id] = queue;
+        queue.Writer.TryWrite(Encode(DaemonEvents.CurrentState(_session, _cache)));
+        _logger?.LogDebug("Event client {Id} connected ({Count} total)", id, _clients.Count);
+
+        using var linked = CancellationTokenSource.CreateLinkedTokenSource(ct);
+        var sendLoop = SendLoopAsync(socket, queue.Reader, linked.Token);
+        try
+        {
+            await ReceiveLoopAsync(socket, queue.Writer, linked.Token);
+        }
+        catch (Exception ex) when (ex is OperationCanceledException or WebSocketException)
+        {
+        }
+        finally
+        {
+            _clients.TryRemove(id, out _);
+            queue.Writer.TryComplete();
+            await linked.CancelAsync();
+            try { await sendLoop; }
+            catch (Exception ex) when (ex is OperationCanceledException or WebSocketException) { }
+
+            if (socket.State is WebSocketState.Open or WebSocketState.CloseReceived)
+            {
+                try { await socket.CloseOutputAsync(WebSocketCloseStatus.NormalClosure, null, CancellationToken.None); }
+                catch (WebSocketException) { }
+            }
+            socket.Dispose();
+            _logger?.LogDebug("Event client {Id} disconnected", id);
+        }
+    }
+
+    private static async Task SendLoopAsync(WebSocket socket, ChannelReader<byte[]> reader, CancellationToken ct)
+    {
+        await foreach (var frame in reader.ReadAllAsync(ct))
+            await socket.SendAsync(frame, WebSocketMessageType.Text, endOfMessage: true, ct);
+    }
+
+    private async Task ReceiveLoopAsync(WebSocket socket, ChannelWriter<byte[]> replies, CancellationToken ct)
+    {
+        var buffer = new ArrayBufferWriter<byte>(ReceiveChunkBytes);
+        while (socket.State == WebSocketState.Open)
+        {
+            var result = await socket.ReceiveAsync(buffer.GetMemory(ReceiveChunkBytes)[..ReceiveChunkBytes], ct);
+            if (result.MessageType == WebSocketMessageType.Close)
+                return;
+
+            buffer.Advance(result.Count);
+            if (buffer.WrittenCount > MaxMessageBytes)
+            {
+                _logger?.LogDebug("Event client message over {Max} bytes; closing", MaxMessageBytes);
+                await socket.CloseOutputAsync(WebSocketCloseStatus.MessageTooBig, null, ct);
+                return;
+            }
+            if (!result.EndOfMessage)
+                continue;
+
+            if (result.MessageType == WebSocketMessageType.Text)
+                replies.TryWrite(await HandleCommandAsync(buffer.WrittenMemory, ct));
+            buffer.ResetWrittenCount();
+        }
+    }
+
+    private async Task<byte[]> HandleCommandAsync(ReadOnlyMemory<byte> json, CancellationToken ct)
+    {
+        string? id = null;
+        var command = string.Empty;
+        CommandOutcome outcome;
+        string? error;
+        try
+        {
+            using var doc = JsonDocument.Parse(json);
+            var root = doc.RootElement;
+            if (root.TryGetProperty("id", out var idElement))
+                id = idElement.ValueKind == JsonValueKind.String ? idElement.GetString() : idElement.GetRawText();
+            command = root.TryGetProperty("command", out var c) && c.ValueKind == JsonValueKind.String
+                ? c.GetString() ?? string.Empty
+                : string.Empty;
+            long? argument = root.TryGetProperty("argument", out var a) && a.TryGetInt64(out var value)
+                ? value
+                : null;
+
+            outcome = await _controller.ExecuteAsync(command, argument, ct);
+            error = DaemonController.Describe(outcome);
+        }
+        catch (JsonException)
+        {
+            outcome = CommandOutcome.InvalidArgument;
+            error = "malformed JSON";
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Event client command '{Command}' failed", command);
+            outcome = CommandOutcome.Unavailable;
+            error = ex.Message;
+        }
+
+        var reply = new CommandReply(id, command, outcome == CommandOutcome.Ok, error);
+        return Encode(new DaemonEvent("command.result", reply,
+            JsonSerializer.SerializeToUtf8Bytes(reply, DaemonJsonContext.Default.CommandReply)));
+    }
+
+    private void Broadcast(byte[] frame)
+    {
+        foreach (var queue in _clients.Values)
+            queue.Writer.TryWrite(frame);
+    }
+
+    private static byte[] Encode(DaemonEvent e)
+    {
+        var buffer = new ArrayBufferWriter<byte>(e.Data.Length + 32);
+        using (var writer = new Utf8JsonWriter(buffer))
+        {
+            writer.WriteStartObject();
+            writer.WriteString("type", e.Type);
+            writer.WritePropertyName("data");
+            writer.WriteRawValue(e.Data, skipInputValidation: true);
+            writer.WriteEndObject();
+        }
+        return buffer.WrittenSpan.ToArray();
+    }
+
+    public void Dispose()
+    {
+        _subscription.Dispose();
+
+        foreach (var queue in _clients.Values)
+            queue.Writer.TryComplete();
+    }
+}
@@ -0,0 +1,96 @@
+#if WAVEE_GRPC
+using System.Net;
+using Microsoft.AspNetCore.Builder;
+using Microsoft.AspNetCore.Server.Kestrel.Core;
+using Microsoft.Extensions.DependencyInjection;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Optional gRPC server exposing <see cref="WaveeControlService"/> for
+/// cross-language integrations. Disabled unless <see cref="PortEnvironmentVariable"/> is set.
+/// </summary>
+/// <remarks>
+/// Serves cleartext HTTP/2 (h2c) only and has no authentication — bind to
+/// localhost (the default) unless the network is trusted.
+/// </remarks>
+internal sealed class GrpcControlServer : IAsyncDisposable
+{
+    public const string PortEnvironmentVariable = "WAVEE_GRPC_PORT";
+    public const string HostEnvironmentVariable = "WAVEE_GRPC_HOST";
+
+    private readonly WebApplication _app;
+    private readonly ILogger? _logger;
+
+    public GrpcControlServer(WaveeControlService service, IPEndPoint endpoint, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(service);
+        ArgumentNullException.ThrowIfNull(endpoint);
+
+        Endpoint = endpoint;
+        _logger = logger;
+
+        var builder = WebApplication.CreateSlimBuilder();
+        // The console owns stdout (Spectre live view / JSON-RPC); keep ASP.NET Core quiet.
+        builder.Logging.ClearProviders();
+        builder.WebHost.ConfigureKestrel(options =>
+            options.Listen(endpoint, listen => listen.Protocols = HttpProtocols.Http2));
+        builder.Services.AddGrpc();
+        builder.Services.AddSingleton(service);
+
+        _app = builder.Build();
+        _app.MapGrpcService<WaveeControlService>();
+    }
+
+    /// <summary>Address the server listens on.</summary>
+    public IPEndPoint Endpoint { get; }
+
+    /// <summary>
+    /// Builds the listen endpoint from <see cref="PortEnvironmentVariable"/> and
+    /// <see cref="HostEnvironmentVariable"/> (an IP address; <c>*</c> for all interfaces).
+    /// </summary>
+    /// <returns>False when the port variable is unset or invalid (server disabled).</returns>
+    public static bool TryGetEndpointFromEnvironment(out IPEndPoint endpoint)
+    {
+        endpoint = null!;
+        var portValue = Environment.GetEnvironmentVariable(PortEnvironmentVariable);
+        if (!int.TryParse(portValue, out var port) || port is <= 0 or > 65535)
+            return false;
+
+        var host = Environment.GetEnvironmentVariable(HostEnvironmentVariable)?.Trim();
+        IPAddress address;
+        if (string.IsNullOrEmpty(host) || host == "localhost")
+            address = IPAddress.Loopback;
+        else if (host == "*")
+            address = IPAddress.Any;
+        else if (!IPAddress.TryParse(host, out address!))
+            return false;
+
+        endpoint = new IPEndPoint(address, port);
+        return true;
+    }
+
+    /// <summary>
+    /// Starts listening. Throws if the endpoint can't be bound.
+    /// </summary>
+    public async Task StartAsync(CancellationToken cancellationToken = default)
+    {
+        await _app.StartAsync(cancellationToken);
+        _logger?.LogInformation("gRPC control server listening on {Endpoint}", Endpoint);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        try
+        {
+            await _app.StopAsync(TimeSpan.FromSeconds(2));
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogDebug(ex, "gRPC control server stop failed");
+        }
+        await _app.DisposeAsync();
+    }
+}
+#endif
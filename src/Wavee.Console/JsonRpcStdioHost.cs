@@ -0,0 +1,277 @@
+using System.Buffers;
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// JSON-RPC 2.0 over stdin/stdout, one message per line, for embedding the
+/// daemon as a subprocess (<c>Wavee.Console --jsonrpc</c>). Also serves each
+/// <see cref="UnixSocketControlServer"/> connection.
+/// </summary>
+/// <remarks>
+/// Methods: <c>status</c> (returns <see cref="DaemonStatus"/>), <c>search</c>
+/// (<c>{"query": ..., "limit": ...}</c>, returns <see cref="SearchHit"/>s), <c>load</c>
+/// (<c>{"uri": ...}</c>), <c>prewarm</c> (<c>{"uri": ...}</c>, a track or episode to
+/// resolve ahead of its <c>load</c>), <c>play</c>, <c>pause</c>, <c>next</c>, <c>previous</c>,
+/// <c>seek</c> (<c>{"positionMs": ...}</c>), <c>volume</c> (<c>{"percent": ...}</c>) and
+/// <c>reload</c> (re-read the configuration).
+/// Control methods return <c>null</c> on success.
+/// <para/>
+/// Every <see cref="DaemonEvents"/> event is sent as a notification whose
+/// method is the event type (<c>player.state</c>, <c>connect.command</c>,
+/// <c>session.connection</c>, <c>config.changed</c>, <c>track.metadata</c>). Nothing else may write to stdout in this mode —
+/// logs go to stderr.
+/// </remarks>
+internal sealed class JsonRpcStdioHost
+{
+    // JSON-RPC 2.0 reserved error codes.
+    private const int ParseError = -32700;
+    private const int InvalidRequest = -32600;
+    private const int MethodNotFound = -32601;
+    private const int InvalidParams = -32602;
+    private const int ServerError = -32000;
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly TextReader _input;
+    private readonly Stream _output;
+    private readonly ILogger? _logger;
+    private readonly SemaphoreSlim _writeLock = new(1, 1);
+
+    public JsonRpcStdioHost(
+        Session session,
+        DaemonController controller,
+        ICacheService? cache,
+        TextReader input,
+        Stream output,
+        ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _input = input ?? throw new ArgumentNullException(nameof(input));
+        _output = output ?? throw new ArgumentNullException(nameof(output));
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Serves requests until stdin closes or <paramref name="ct"/> fires.
+    /// </summary>
+    public async Task RunAsync(CancellationToken ct = default)
+    {
+        using var events = DaemonEvents.Observe(_session, _cache, _controller.Config, _controller.Metadata)
+            .Subscribe(e => _ = WriteAsync(w => WriteNotification(w, e), CancellationToken.None));
+        await WriteAsync(w => WriteNotification(w, DaemonEvents.CurrentState(_session, _cache)), ct);
+
+        while (!ct.IsCancellationRequested)
+        {
+            var line = await _input.ReadLineAsync(ct);
+            if (line == null)
+                break;
+            if (string.IsNullOrWhiteSpace(line))
+                continue;
+
+            await HandleLineAsync(line, ct);
+        }
+    }
+
+    private async Task HandleLineAsync(string line, CancellationToken ct)
+    {
+        JsonDocument doc;
+        try
+        {
+            doc = JsonDocument.Parse(line);
+        }
+        catch (JsonException)
+        {
+            await WriteErrorAsync(null, ParseError, "Parse error", ct);
+            return;
+        }
+
+        using (doc)
+        {
+            var root = doc.RootElement;
+            string? id = root.ValueKind == JsonValueKind.Object && root.TryGetProperty("id", out var idElement)
+                ? idElement.GetRawText()
+                : null;
+
+            if (root.ValueKind != JsonValueKind.Object
+                || !root.TryGetProperty("method", out var methodElement)
+                || methodElement.ValueKind != JsonValueKind.String)
+            {
+                await WriteErrorAsync(id, InvalidRequest, "Invalid Request", ct);
+                return;
+            }
+
+            var method = methodElement.GetString()!;
+            root.TryGetProperty("params", out var parameters);
+
+            try
+            {
+                if (method == "status")
+                {
+                    // Notifications (no id) get no response, per spec.
+                    if (id == null) return;
+                    var status = DaemonStatus.Capture(_session, _cache);
+                    await WriteAsync(w =>
+                    {
+                        WriteHeader(w, id);
+                        w.WritePropertyName("result");
+                        JsonSerializer.Serialize(w, status, DaemonJsonContext.Default.DaemonStatus);
+                        w.WriteEndObject();
+                    }, ct);
+                    return;
+                }
+
+                if (method == "search")
+                {
+                    var query = GetString(parameters, "query");
+                    if (string.IsNullOrWhiteSpace(query))
+                    {
+                        if (id != null)
+                            await WriteErrorAsync(id, InvalidParams, "Invalid params", ct);
+                        return;
+                    }
+
+                    var limit = (int)Math.Clamp(GetInt64(parameters, "limit") ?? 10, 1, 50);
+                    var result = await _session.Pathfinder.SearchAsync(query, limit: limit, cancellationToken: ct);
+                    if (id == null) return;
+                    var hits = result.Items
+                        .Select(item => new SearchHit(
+                            item.Type.ToString().ToLowerInvariant(),
+                            item.Uri,
+                            item.Name,
+                            item.ArtistNames is { Count: > 0 } artists
+                                ? string.Join(", ", artists)
+                                : item.OwnerName ?? item.PublisherName))
+                        .ToArray();
+                    await WriteAsync(w =>
+                    {
+                        WriteHeader(w, id);
+                        w.WritePropertyName("result");
+                        JsonSerializer.Serialize(w, hits, DaemonJsonContext.Default.SearchHitArray);
+                        w.WriteEndObject();
+                    }, ct);
+                    return;
+                }
+
+                var outcome = method switch
+                {
+                    "load" => await _controller.LoadAsync(GetString(parameters, "uri"), ct),
+                    "prewarm" => await _controller.PrewarmAsync(GetString(parameters, "uri"), ct),
+                    "seek" => await _controller.ExecuteAsync(method, GetInt64(parameters, "positionMs"), ct),
+                    "volume" => await _controller.ExecuteAsync(method, GetInt64(parameters, "percent"), ct),
+                    "cue_set" or "cue" or "cue_clear"
+                        => await _controller.ExecuteAsync(method, GetInt64(parameters, "slot"), ct),
+                    "loop_in" or "loop_out"
+                        => await _controller.ExecuteAsync(method, GetInt64(parameters, "positionMs"), ct),
+                    _ => await _controller.ExecuteAsync(method, null, ct)
+                };
+
+                if (id == null)
+                    return;
+
+                switch (outcome)
+                {
+                    case CommandOutcome.Ok:
+                        await WriteAsync(w =>
+                        {
+                            WriteHeader(w, id);
+                            w.WriteNull("result");
+                            w.WriteEndObject();
+                        }, ct);
+                        break;
+                    case CommandOutcome.UnknownCommand:
+                        await WriteErrorAsync(id, MethodNotFound, "Method not found", ct);
+                        break;
+                    case CommandOutcome.InvalidArgument:
+                        await WriteErrorAsync(id, InvalidParams, "Invalid params", ct);
+                        break;
+                    default:
+                        await WriteErrorAsync(id, ServerError, DaemonController.Describe(outcome)!, ct);
+                        break;
+                }
+            }
+            catch (Exception ex) when (ex is not OperationCanceledException)
+            {
+                _logger?.LogWarning(ex, "JSON-RPC method '{Method}' failed", method);
+                if (id != null)
+                    await WriteErrorAsync(id, ServerError, ex.Message, ct);
+            }
+        }
+    }
+
+    private static string? GetString(JsonElement parameters, string name) =>
+        parameters.ValueKind == JsonValueKind.Object
+        && parameters.TryGetProperty(name, out var value)
+        && value.ValueKind == JsonValueKind.String
+            ? value.GetString()
+            : null;
+
+    private static long? GetInt64(JsonElement parameters, string name) =>
+        parameters.ValueKind == JsonValueKind.Object
+        && parameters.TryGetProperty(name, out var value)
+        && value.ValueKind == JsonValueKind.Number
+        && value.TryGetInt64(out var number)
+            ? number
+            : null;
+
+    private static void WriteHeader(Utf8JsonWriter writer, string? id)
+    {
+        writer.WriteStartObject();
+        writer.WriteString("jsonrpc", "2.0");
+        writer.WritePropertyName("id");
+        if (id == null)
+            writer.WriteNullValue();
+        else
+            writer.WriteRawValue(id, skipInputValidation: true);
+    }
+
+    private static void WriteNotification(Utf8JsonWriter writer, DaemonEvent e)
+    {
+        writer.WriteStartObject();
+        writer.WriteString("jsonrpc", "2.0");
+        writer.WriteString("method", e.Type);
+        writer.WritePropertyName("params");
+        writer.WriteRawValue(e.Data, skipInputValidation: true);
+        writer.WriteEndObject();
+    }
+
+    private Task WriteErrorAsync(string? id, int code, string message, CancellationToken ct) =>
+        WriteAsync(w =>
+        {
+            WriteHeader(w, id);
+            w.WriteStartObject("error");
+            w.WriteNumber("code", code);
+            w.WriteString("message", message);
+            w.WriteEndObject();
+            w.WriteEndObject();
+        }, ct);
+
+    private async Task WriteAsync(Action<Utf8JsonWriter> write, CancellationToken ct)
+    {
+        var buffer = new ArrayBufferWriter<byte>(256);
+        using (var writer = new Utf8JsonWriter(buffer))
+            write(writer);
+        buffer.Write("\n"u8);
+
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            await _output.WriteAsync(buffer.WrittenMemory, ct);
+            await _output.FlushAsync(ct);
+        }
+        catch (IOException ex)
+        {
+            _logger?.LogDebug(ex, "JSON-RPC output closed");
+        }
+        finally
+        {
+            _writeLock.Release();
+        }
+    }
+}
@@ -0,0 +1,247 @@
+#if WAVEE_MQTT
+using System.Text;
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using MQTTnet;
+using MQTTnet.Client;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Broker settings for <see cref="MqttBridge"/>, read from <c>WAVEE_MQTT_*</c> environment variables.
+/// </summary>
+/// <param name="Host">Broker host name.</param>
+/// <param name="Port">Broker port (default 1883).</param>
+/// <param name="Username">Optional broker username.</param>
+/// <param name="Password">Optional broker password.</param>
+/// <param name="TopicPrefix">Root of every topic (default <c>wavee/&lt;device id&gt;</c>).</param>
+internal sealed record MqttBridgeOptions(
+    string Host,
+    int Port,
+    string? Username,
+    string? Password,
+    string TopicPrefix)
+{
+    public const string HostEnvironmentVariable = "WAVEE_MQTT_HOST";
+    public const string PortEnvironmentVariable = "WAVEE_MQTT_PORT";
+    public const string UsernameEnvironmentVariable = "WAVEE_MQTT_USERNAME";
+    public const string PasswordEnvironmentVariable = "WAVEE_MQTT_PASSWORD";
+    public const string TopicPrefixEnvironmentVariable = "WAVEE_MQTT_TOPIC_PREFIX";
+
+    /// <returns>False when <see cref="HostEnvironmentVariable"/> is unset (bridge disabled).</returns>
+    public static bool TryFromEnvironment(string deviceId, out MqttBridgeOptions options)
+    {
+        options = null!;
+        var host = Environment.GetEnvironmentVariable(HostEnvironmentVariable);
+        if (string.IsNullOrWhiteSpace(host))
+            return false;
+
+        var port = int.TryParse(Environment.GetEnvironmentVariable(PortEnvironmentVariable), out var p) && p is > 0 and <= 65535
+            ? p
+            : 1883;
+        var prefix = Environment.GetEnvironmentVariable(TopicPrefixEnvironmentVariable);
+
+        options = new MqttBridgeOptions(
+            host.Trim(),
+            port,
+            Environment.GetEnvironmentVariable(UsernameEnvironmentVariable),
+            Environment.GetEnvironmentVariable(PasswordEnvironmentVariable),
+            string.IsNullOrWhiteSpace(prefix) ? $"wavee/{deviceId}" : prefix.Trim().TrimEnd('/'));
+        return true;
+    }
+}
+
+/// <summary>
+/// Publishes player state to an MQTT broker and accepts commands from it, for
+/// Home Assistant and similar setups running Wavee as a headless speaker.
+/// Compiled only with <c>-p:WaveeEnableMqtt=true</c>.
+/// </summary>
+/// <remarks>
+/// Topics, relative to <see cref="MqttBridgeOptions.TopicPrefix"/>:
+/// <list type="bullet">
+///   <item><c>availability</c> — retained <c>online</c>/<c>offline</c>; <c>offline</c> is also the last will.</item>
+///   <item><c>state</c> — retained <see cref="DaemonStatus"/> JSON, republished on track, status, position, device and volume changes.</item>
+///   <item><c>command/play|pause|next|previous</c> — transport control (payload ignored).</item>
+///   <item><c>command/seek</c> — payload is the position in ms.</item>
+///   <item><c>command/volume</c> — payload is a 0-100 percentage.</item>
+/// </list>
+/// The bridge reconnects on its own after a broker disconnect.
+/// </remarks>
+internal sealed class MqttBridge : IAsyncDisposable
+{
+    private static readonly TimeSpan ReconnectDelay = TimeSpan.FromSeconds(5);
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly MqttBridgeOptions _options;
+    private readonly ILogger? _logger;
+    private readonly IMqttClient _client;
+    private readonly MqttClientOptions _clientOptions;
+    private readonly CancellationTokenSource _cts = new();
+    private IDisposable? _stateSubscription;
+    private bool _disposed;
+
+    public MqttBridge(
+        Session session,
+        DaemonController controller,
+        ICacheService? cache,
+        MqttBridgeOptions options,
+        ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _options = options ?? throw new ArgumentNullException(nameof(options));
+        _logger = logger;
+
+        var builder = new MqttClientOptionsBuilder()
+            .WithTcpServer(options.Host, options.Port)
+            .WithClientId($"wavee-{session.Config.DeviceId}")
+            .WithCleanSession()
+            .WithWillTopic(Topic("availability"))
+            .WithWillPayload("offline")
+            .WithWillRetain();
+        if (!string.IsNullOrEmpty(options.Username))
+            builder = builder.WithCredentials(options.Username, options.Password);
+        _clientOptions = builder.Build();
+
+        _client = new MqttFactory().CreateMqttClient();
+        _client.ApplicationMessageReceivedAsync += OnMessageAsync;
+        _client.DisconnectedAsync += OnDisconnectedAsync;
+    }
+
+    /// <summary>
+    /// Connects to the broker, announces availability and starts publishing state.
+    /// </summary>
+    public async Task StartAsync(CancellationToken ct = default)
+    {
+        await ConnectAsync(ct);
+
+        _stateSubscription = DaemonStatus.Changes(_session, _session.Config.Sampling.StateEventInterval)
+            .Subscribe(_ => _ = PublishStateAsync());
+    }
+
+    private async Task ConnectAsync(CancellationToken ct)
+    {
+        await _client.ConnectAsync(_clientOptions, ct);
+        await _client.SubscribeAsync(
+            new MqttClientSubscribeOptionsBuilder()
+                .WithTopicFilter(f => f.WithTopic(Topic("command/#")))
+                .Build(),
+            ct);
+        await PublishAsync("availability", "online", ct);
+        await PublishStateAsync();
+
+        _logger?.LogInformation("MQTT bridge connected to {Host}:{Port} under {Prefix}",
+            _options.Host, _options.Port, _options.TopicPrefix);
+    }
+
+    private async Task PublishStateAsync()
+    {
+        if (!_client.IsConnected)
+            return;
+
+        try
+        {
+            var json = JsonSerializer.Serialize(
+                DaemonStatus.Capture(_session, _cache),
+                DaemonJsonContext.Default.DaemonStatus);
+            await PublishAsync("state", json, _cts.Token);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogDebug(ex, "MQTT state publish failed");
+        }
+    }
+
+    private Task PublishAsync(string subtopic, string payload, CancellationToken ct)
+    {
+        var message = new MqttApplicationMessageBuilder()
+            .WithTopic(Topic(subtopic))
+            .WithPayload(payload)
+            .WithRetainFlag()
+            .Build();
+        return _client.PublishAsync(message, ct);
+    }
+
+    private async Task OnMessageAsync(MqttApplicationMessageReceivedEventArgs e)
+    {
+        var commandPrefix = Topic("command/");
+        var topic = e.ApplicationMessage.Topic;
+        if (!topic.StartsWith(commandPrefix, StringComparison.Ordinal))
+            return;
+
+        var command = topic[commandPrefix.Length..];
+        var payload = Encoding.UTF8.GetString(e.ApplicationMessage.PayloadSegment).Trim();
+        try
+        {
+            var outcome = await _controller.ExecuteAsync(
+                command,
+                long.TryParse(payload, out var argument) ? argument : null,
+                _cts.Token);
+            if (outcome != CommandOutcome.Ok)
+            {
+                _logger?.LogWarning("MQTT command '{Command}' ignored: {Reason}",
+                    command, DaemonController.Describe(outcome));
+            }
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "MQTT command '{Command}' failed", command);
+        }
+    }
+
+    private async Task OnDisconnectedAsync(MqttClientDisconnectedEventArgs e)
+    {
+        if (_disposed || _cts.IsCancellationRequested)
+            return;
+
+        _logger?.LogWarning(e.Exception, "MQTT bridge disconnected ({Reason}); reconnecting", e.Reason);
+        while (!_cts.IsCancellationRequested)
+        {
+            try
+            {
+                await Task.Delay(ReconnectDelay, _cts.Token);
+                await ConnectAsync(_cts.Token);
+                return;
+            }
+            catch (OperationCanceledException) { return; }
+            catch (Exception ex)
+            {
+                _logger?.LogDebug(ex, "MQTT reconnect failed");
+            }
+        }
+    }
+
+    private string Topic(string subtopic) => $"{_options.TopicPrefix}/{subtopic}";
+
+    public async ValueTask DisposeAsync()
+    {
+        if (_disposed)
+            return;
+        _disposed = true;
+
+        _stateSubscription?.Dispose();
+        await _cts.CancelAsync();
+
+        if (_client.IsConnected)
+        {
+            try
+            {
+                await PublishAsync("availability", "offline", CancellationToken.None);
+                await _client.DisconnectAsync();
+            }
+            catch (Exception ex)
+            {
+                _logger?.LogDebug(ex, "MQTT bridge disconnect failed");
+            }
+        }
+
+        _client.Dispose();
+        _cts.Dispose();
+    }
+}
+#endif
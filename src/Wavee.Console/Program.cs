@@ -3,12 +3,21 @@ using System.Text;
 using Google.Protobuf;
 using Microsoft.Extensions.DependencyInjection;
 using Microsoft.Extensions.Logging;
+using System.Runtime.InteropServices;
 using Serilog;
+using Serilog.Core;
 using Serilog.Events;
 using Spectre.Console;
 using Wavee.Console;
+using Wavee.Connect.Diagnostics;
 using Wavee.Core.Authentication;
+using Wavee.Core.Configuration;
+using Wavee.Core.Connection;
+using Wavee.Core.DependencyInjection;
+using Wavee.Core.Diagnostics;
 using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Wavee.Core.Utilities;
 using Wavee.OAuth;
 using Wavee.Protocol.DescriptorExtension;
 using Wavee.Protocol.ExtendedMetadata;
@@ -18,6 +27,22 @@ using Wavee.Protocol.Metadata;
 Console.OutputEncoding = Encoding.UTF8;
 Console.InputEncoding = Encoding.UTF8;
 
+#if WAVEE_CLI
+// One-shot `wavee <command>` invocations talk to a daemon and exit.
+if (WaveeCli.IsCommand(args))
+{
+    Environment.ExitCode = await WaveeCli.RunAsync(args, GetOrCreateDeviceId);
+    return;
+}
+#endif
+
+// Headless JSON-RPC mode: stdout belongs to the protocol, so skip the interactive UI entirely.
+if (args.Contains("--jsonrpc"))
+{
+    Environment.ExitCode = await RunJsonRpcAsync(args);
+    return;
+}
+
 var request = new BatchedEntityRequest
 {
     Header = new BatchedEntityRequestHeader
@@ -71,9 +96,10 @@ var descriptorData = ExtensionDescriptorData.Parser.ParseFrom(item);
 // Create SpectreUI instance (will be configured with Serilog later)
 using var spectreUI = new SpectreUI();
 
-// Configure Serilog with SpectreUI sink
+// Configure Serilog with SpectreUI sink; the level follows logging.level and can be reloaded.
+var logLevelSwitch = new LoggingLevelSwitch(LogEventLevel.Debug);
 Log.Logger = new LoggerConfiguration()
-    .MinimumLevel.Debug()
+    .MinimumLevel.ControlledBy(logLevelSwitch)
     .MinimumLevel.Override("Microsoft", LogEventLevel.Information)
     .Enrich.FromLogContext()
     .WriteTo.SpectreUI(spectreUI, LogEventLevel.Debug)
@@ -83,18 +109,12 @@ Log.Logger = new LoggerConfiguration()
 using var loggerFactory = LoggerFactory.Create(builder =>
 {
     builder.AddSerilog(Log.Logger, dispose: false);
-    builder.SetMinimumLevel(LogLevel.Debug);
+    builder.SetMinimumLevel(LogLevel.Trace);
 });
 
 var logger = loggerFactory.CreateLogger<Program>();
 var sessionLogger = loggerFactory.CreateLogger("Wavee.Core.Session.Session");
 
-// Setup dependency injection with HttpClient
-var services = new ServiceCollection();
-services.AddHttpClient("Wavee", client => { client.Timeout = TimeSpan.FromSeconds(30); });
-var serviceProvider = services.BuildServiceProvider();
-var httpClientFactory = serviceProvider.GetRequiredService<IHttpClientFactory>();
-
 // Setup credentials cache
 var credentialsCache = new CredentialsCache(logger: loggerFactory.CreateLogger<CredentialsCache>());
 
@@ -109,12 +129,18 @@ try
     var deviceId = GetOrCreateDeviceId();
     AnsiConsole.MarkupLine($"[dim]Device ID:[/] {deviceId[..8]}...");
 
-    var config = new SessionConfig
-    {
-        DeviceId = deviceId,
-        DeviceName = "Wavee Console",
-        DeviceType = DeviceType.Computer
-    };
+    var waveeConfig = LoadConfig(args, deviceId, LogLevel.Debug);
+    var config = waveeConfig.Session with { Bandwidth = new BandwidthMeter() };
+
+    // Setup dependency injection with HttpClient
+    var services = new ServiceCollection();
+    AddWaveeHttpClient(services, config, loggerFactory);
+    var serviceProvider = services.BuildServiceProvider();
+    var httpClientFactory = serviceProvider.GetRequiredService<IHttpClientFactory>();
+    var reloader = new ConfigReloader(waveeConfig, () => LoadConfig(args, deviceId, LogLevel.Debug), logger);
+    logLevelSwitch.MinimumLevel = ToSerilogLevel(waveeConfig.LogLevel);
+    reloader.ConfigChanged += (_, e) => logLevelSwitch.MinimumLevel = ToSerilogLevel(e.Current.LogLevel);
+    using var reloadSignal = RegisterReloadSignal(reloader, logger);
 
     // 2. Try to load stored credentials
     var lastUsername = await credentialsCache.LoadLastUsernameAsync();
@@ -139,6 +165,8 @@ try
     // 3. Create and connect session
     AnsiConsole.MarkupLine("[dim]Creating session...[/]");
     await using var session = Session.Create(config, httpClientFactory, sessionLogger);
+    using var dealerRecorder = StartDealerRecording(session);
+    using var protocolTrace = StartProtocolTrace(session);
 
     // 4. Connect with status spinner
     await AnsiConsole.Status()
@@ -174,7 +202,7 @@ try
     // Initialize UI with device info
     spectreUI.UpdateDevice(config.DeviceName, config.DeviceId, false);
 
-    await using var connectConsole = new ConnectConsole(session, httpClient, spectreUI, audioPipelineLogger);
+    await using var connectConsole = new ConnectConsole(session, httpClient, spectreUI, audioPipelineLogger, reloader, credentialsCache);
     await connectConsole.RunAsync();
 
     // 6. Cleanup
@@ -195,6 +223,10 @@ catch (SessionException ex)
     AnsiConsole.MarkupLine($"[red]Session error:[/] {ex.Message}");
     AnsiConsole.MarkupLine($"[dim]Reason:[/] {ex.Reason}");
 }
+catch (ConfigException ex)
+{
+    AnsiConsole.MarkupLine($"[red]Invalid configuration:[/] {Markup.Escape(ex.Message)}");
+}
 catch (Exception ex)
 {
     AnsiConsole.Write(ex.ToString());
@@ -257,26 +289,237 @@ static async Task<string> GetAccessTokenAsync(string clientId, Microsoft.Extensi
     return newToken.AccessToken;
 }
 
+static async Task<int> RunJsonRpcAsync(string[] args)
+{
+    var logLevelSwitch = new LoggingLevelSwitch(LogEventLevel.Information);
+    Log.Logger = new LoggerConfiguration()
+        .MinimumLevel.ControlledBy(logLevelSwitch)
+        .WriteTo.Console(standardErrorFromLevel: LogEventLevel.Verbose)
+        .CreateLogger();
+
+    using var loggerFactory = LoggerFactory.Create(builder =>
+    {
+        builder.AddSerilog(Log.Logger, dispose: false);
+        builder.SetMinimumLevel(LogLevel.Trace);
+    });
+    var logger = loggerFactory.CreateLogger("Wavee.Console.JsonRpc");
+
+    // OAuth needs a browser or a prompt, neither of which fits a subprocess; log in interactively once first.
+    var credentialsCache = new CredentialsCache(logger: loggerFactory.CreateLogger<CredentialsCache>());
+    var lastUsername = await credentialsCache.LoadLastUsernameAsync();
+    var credentials = await credentialsCache.LoadCredentialsAsync(lastUsername);
+    if (credentials == null)
+    {
+        System.Console.Error.WriteLine("No stored credentials. Run Wavee.Console interactively once (or `wavee login`) to log in.");
+        return 1;
+    }
+
+    var deviceId = GetOrCreateDeviceId();
+    WaveeConfig waveeConfig;
+    try
+    {
+        waveeConfig = LoadConfig(args, deviceId, LogLevel.Information);
+    }
+    catch (ConfigException ex)
+    {
+        System.Console.Error.WriteLine($"Invalid configuration: {ex.Message}");
+        return 1;
+    }
+
+    var reloader = new ConfigReloader(waveeConfig, () => LoadConfig(args, deviceId, LogLevel.Information), logger);
+    logLevelSwitch.MinimumLevel = ToSerilogLevel(waveeConfig.LogLevel);
+    reloader.ConfigChanged += (_, e) => logLevelSwitch.MinimumLevel = ToSerilogLevel(e.Current.LogLevel);
+    using var reloadSignal = RegisterReloadSignal(reloader, logger);
+
+    var services = new ServiceCollection();
+    var sessionConfig = waveeConfig.Session with { Bandwidth = new BandwidthMeter() };
+    AddWaveeHttpClient(services, sessionConfig, loggerFactory);
+    await using var serviceProvider = services.BuildServiceProvider();
+
+    await using var session = Session.Create(
+        sessionConfig,
+        serviceProvider.GetRequiredService<IHttpClientFactory>(),
+        loggerFactory.CreateLogger("Wavee.Core.Session.Session"));
+    using var dealerRecorder = StartDealerRecording(session);
+    using var protocolTrace = StartProtocolTrace(session);
+    await session.ConnectAsync(credentials, credentialsCache);
+
+    // Same as the interactive console: local playback when an AudioHost is available, else
+    // the transport methods don't exist.
+    var (cacheServices, startedPlayback) = await StartPlaybackAsync(
+        session, serviceProvider, credentialsCache, waveeConfig, loggerFactory);
+    await using var playbackServices = cacheServices;
+    await using var playback = startedPlayback;
+    playback?.Follow(reloader);
+    var controller = new DaemonController(
+        session,
+        playback is { } engine ? () => engine.Engine : null,
+        reloader,
+        cacheServices?.GetService<Wavee.Connect.Metadata.MetadataProviderChain>());
+    var host = new JsonRpcStdioHost(
+        session,
+        controller,
+        cache: cacheServices?.GetService<ICacheService>(),
+        System.Console.In,
+        System.Console.OpenStandardOutput(),
+        logger);
+
+    using var cts = new CancellationTokenSource();
+    System.Console.CancelKeyPress += (_, e) =>
+    {
+        e.Cancel = true;
+        cts.Cancel();
+    };
+
+    try
+    {
+        await host.RunAsync(cts.Token);
+    }
+    catch (OperationCanceledException)
+    {
+    }
+
+    return 0;
+}
+
+// Nulls when there's no AudioHost, or playback failed to start (logged); the cache services
+// stay with the caller so they outlive the engine.
+static async Task<(ServiceProvider? CacheServices, ConsolePlayback? Playback)> StartPlaybackAsync(
+    Session session,
+    IServiceProvider serviceProvider,
+    CredentialsCache credentialsCache,
+    WaveeConfig config,
+    ILoggerFactory loggerFactory)
+{
+    if (!ConsolePlayback.TryFindAudioHost(out var audioHostPath))
+        return (null, null);
+
+    var logger = loggerFactory.CreateLogger("Wavee.Audio.Pipeline");
+    var httpClient = serviceProvider.GetRequiredService<IHttpClientFactory>().CreateClient("Wavee");
+    var cacheServices = ConnectConsole.CreateCacheServices(session, httpClient, config.Cache.LockWaitTimeout, logger);
+    try
+    {
+        var playback = await ConsolePlayback.StartAsync(
+            session, httpClient, cacheServices, credentialsCache, audioHostPath, config.Player, logger);
+        return (cacheServices, playback);
+    }
+    catch (CacheLockedException ex)
+    {
+        logger.LogError("{Reason} Continuing without the metadata cache and local playback (holder pid {ProcessId})",
+            ex.Message, ex.HolderProcessId);
+        await cacheServices.DisposeAsync();
+        return (null, null);
+    }
+    catch (Exception ex)
+    {
+        logger.LogWarning(ex, "Local playback unavailable");
+        await cacheServices.DisposeAsync();
+        return (null, null);
+    }
+}
+
+// Spotify hosts get the configured TLS pins; everything else keeps plain CA validation.
+// Traffic is metered onto the session's bandwidth meter.
+static void AddWaveeHttpClient(IServiceCollection services, SessionConfig config, ILoggerFactory loggerFactory)
+{
+    var builder = services.AddHttpClient("Wavee", client => { client.Timeout = TimeSpan.FromSeconds(30); })
+        .ConfigurePrimaryHttpMessageHandler(() => CertificatePinning.Apply(
+            NetworkBinding.Apply(new SocketsHttpHandler(), config.Network),
+            config.Network,
+            loggerFactory.CreateLogger("Wavee.TlsPinning")));
+    if (config.Bandwidth is { } bandwidth)
+        builder.AddBandwidthMetering(bandwidth);
+}
+
+// WAVEE_DEALER_RECORD=<path> appends redacted dealer traffic to a log for bug reports;
+// DealerReplayConnection plays it back in tests.
+static DealerTrafficRecorder? StartDealerRecording(Session session)
+{
+    var path = Environment.GetEnvironmentVariable("WAVEE_DEALER_RECORD")?.Trim();
+    if (string.IsNullOrEmpty(path))
+        return null;
+
+    session.DealerTrafficRecorder = DealerTrafficRecorder.CreateFile(path);
+    return session.DealerTrafficRecorder;
+}
+
+// WAVEE_TRACE=<path> writes a redacted .wavee-trace of AP, Mercury and dealer traffic;
+// `wavee trace <path>` prints it.
+static ProtocolTraceWriter? StartProtocolTrace(Session session)
+{
+    var path = Environment.GetEnvironmentVariable("WAVEE_TRACE")?.Trim();
+    if (string.IsNullOrEmpty(path))
+        return null;
+
+    session.ProtocolTraceWriter = ProtocolTraceWriter.CreateFile(path);
+    return session.ProtocolTraceWriter;
+}
+
+// Defaults, then the file from --config, WAVEE_CONFIG or the platform config directory, then WAVEE_* overrides.
+static WaveeConfig LoadConfig(string[] args, string deviceId, LogLevel defaultLogLevel)
+{
+    var index = Array.IndexOf(args, "--config");
+    var path = index >= 0 && index + 1 < args.Length ? args[index + 1] : null;
+
+    return WaveeConfigLoader.Load(new WaveeConfig
+    {
+        Session = new SessionConfig
+        {
+            DeviceId = deviceId,
+            DeviceName = "Wavee Console",
+            DeviceType = DeviceType.Computer
+        },
+        LogLevel = defaultLogLevel
+    }, path, defaultFilePath: WaveePaths.Default.ConfigFile);
+}
+
+// SIGHUP re-reads the configuration, as with most Unix daemons. Not available on Windows;
+// use the `reload` command there.
+static IDisposable? RegisterReloadSignal(ConfigReloader reloader, Microsoft.Extensions.Logging.ILogger logger)
+{
+    if (OperatingSystem.IsWindows())
+        return null;
+
+    return PosixSignalRegistration.Create(PosixSignal.SIGHUP, context =>
+    {
+        context.Cancel = true;
+        try
+        {
+            reloader.Reload();
+        }
+        catch (ConfigException ex)
+        {
+            logger.LogWarning("Configuration reload rejected: {Message}", ex.Message);
+        }
+    });
+}
+
+static LogEventLevel ToSerilogLevel(LogLevel level) => level switch
+{
+    LogLevel.Trace => LogEventLevel.Verbose,
+    LogLevel.Debug => LogEventLevel.Debug,
+    LogLevel.Information => LogEventLevel.Information,
+    LogLevel.Warning => LogEventLevel.Warning,
+    LogLevel.Error => LogEventLevel.Error,
+    _ => LogEventLevel.Fatal
+};
+
 static string GetOrCreateDeviceId()
 {
-    var deviceIdPath = Path.Combine(
-        Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData),
-        "Wavee",
-        "device_id.txt"
-    );
+    var deviceIdPath = WaveePaths.Default.DeviceIdFile;
 
     if (File.Exists(deviceIdPath))
     {
         return File.ReadAllText(deviceIdPath).Trim();
     }
 
-    var deviceId = Guid.NewGuid().ToString();
+    var deviceId = DeviceIdGenerator.Generate();
 
     var directory = Path.GetDirectoryName(deviceIdPath);
     if (directory != null)
     {
         Directory.CreateDirectory(directory);
-        File.WriteAllText(deviceIdPath, deviceId);
+        AtomicFile.WriteAllText(deviceIdPath, deviceId);
     }
 
     return deviceId;
@@ -0,0 +1,330 @@
+using System.Net;
+using System.Security.Cryptography;
+using System.Text;
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Optional embedded HTTP server for home-automation integrations that don't
+/// speak Spotify Connect. Disabled unless <see cref="PortEnvironmentVariable"/> is set.
+/// </summary>
+/// <remarks>
+/// Routes:
+/// <list type="bullet">
+///   <item><c>GET /status</c> — current track, position, device and cache stats as JSON.</item>
+///   <item><c>GET /commands</c> — recent remote Connect commands, oldest first, with the sending device.</item>
+///   <item><c>GET /cover</c> — cover art of the current track (JPEG), through <see cref="Session.Images"/>; 404 when there is none.</item>
+///   <item><c>POST /play</c>, <c>/pause</c>, <c>/next</c>, <c>/previous</c> — transport control.</item>
+///   <item><c>POST /seek?positionMs=N</c> — seek the current track.</item>
+///   <item><c>POST /volume?percent=N</c> — set device volume (0-100).</item>
+///   <item><c>POST /reload</c> — re-read the configuration; 422 when it is invalid.</item>
+///   <item><c>GET /events</c> (WebSocket) — live event stream and commands, see <see cref="EventSocketHub"/>.</item>
+/// </list>
+/// Control routes return 204 on success and 503 when no local playback engine is wired.
+/// <para>
+/// Requests carrying an <c>Origin</c> header are refused (403) unless the origin is listed in
+/// <see cref="OriginsEnvironmentVariable"/>, so a web page can't drive the daemon through the
+/// user's browser. When <see cref="TokenEnvironmentVariable"/> is set every route, the WebSocket
+/// included, needs <c>Authorization: Bearer &lt;token&gt;</c> (401 otherwise); browsers can't
+/// set headers on a WebSocket, so <c>/events</c> also accepts <c>?token=</c>.
+/// </para>
+/// </remarks>
+internal sealed class StatusHttpServer : IAsyncDisposable
+{
+    public const string PortEnvironmentVariable = "WAVEE_HTTP_PORT";
+    public const string HostEnvironmentVariable = "WAVEE_HTTP_HOST";
+    public const string TokenEnvironmentVariable = "WAVEE_HTTP_TOKEN";
+    public const string OriginsEnvironmentVariable = "WAVEE_HTTP_ORIGINS";
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly EventSocketHub _events;
+    private readonly ICacheService? _cache;
+    private readonly ILogger? _logger;
+    private readonly byte[]? _token;
+    private readonly HashSet<string> _allowedOrigins;
+    private readonly HttpListener _listener = new();
+    private readonly CancellationTokenSource _cts = new();
+    private Task? _loop;
+
+    public StatusHttpServer(
+        Session session,
+        DaemonController controller,
+        ICacheService? cache,
+        string prefix,
+        string? token = null,
+        IEnumerable<string>? allowedOrigins = null,
+        ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _logger = logger;
+        _token = string.IsNullOrEmpty(token) ? null : Encoding.UTF8.GetBytes(token);
+        _allowedOrigins = new HashSet<string>(
+            allowedOrigins?.Select(static o => o.Trim().TrimEnd('/')).Where(static o => o.Length > 0) ?? [],
+            StringComparer.OrdinalIgnoreCase);
+        _events = new EventSocketHub(session, controller, cache, logger);
+        _listener.Prefixes.Add(prefix);
+    }
+
+    /// <summary>
+    /// Builds the listener prefix from <see cref="PortEnvironmentVariable"/> and
+    /// <see cref="HostEnvironmentVariable"/>.
+    /// </summary>
+    /// <returns>False when the port variable is unset or invalid (server disabled).</returns>
+    public static bool TryGetPrefixFromEnvironment(out string prefix)
+    {
+        prefix = string.Empty;
+        var portValue = Environment.GetEnvironmentVariable(PortEnvironmentVariable);
+        if (!int.TryParse(portValue, out var port) || port is <= 0 or > 65535)
+            return false;
+
+        var host = Environment.GetEnvironmentVariable(HostEnvironmentVariable);
+        prefix = $"http://{(string.IsNullOrWhiteSpace(host) ? "localhost" : host.Trim())}:{port}/";
+        return true;
+    }
+
+    /// <summary>
+    /// Reads <see cref="TokenEnvironmentVariable"/> and the comma-separated
+    /// <see cref="OriginsEnvironmentVariable"/>.
+    /// </summary>
+    public static (string? Token, string[] AllowedOrigins) GetAccessFromEnvironment()
+    {
+        var token = Environment.GetEnvironmentVariable(TokenEnvironmentVariable)?.Trim();
+        var origins = Environment.GetEnvironmentVariable(OriginsEnvironmentVariable)?
+            .Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries) ?? [];
+        return (string.IsNullOrEmpty(token) ? null : token, origins);
+    }
+
+    /// <summary>
+    /// True when <paramref name="origin"/> is absent (not a browser) or allowlisted.
+    /// </summary>
+    internal static bool IsOriginAllowed(string? origin, IReadOnlySet<string> allowedOrigins)
+        => string.IsNullOrEmpty(origin) || allowedOrigins.Contains(origin.TrimEnd('/'));
+
+    /// <summary>
+    /// True when no token is configured, or the request presents it as a bearer token
+    /// (or, for WebSocket upgrades, as <paramref name="queryToken"/>).
+    /// </summary>
+    internal static bool IsAuthorized(byte[]? token, string? authorization, string? queryToken)
+    {
+        if (token is null)
+            return true;
+
+        const string bearer = "Bearer ";
+        var presented = authorization is not null && authorization.StartsWith(bearer, StringComparison.OrdinalIgnoreCase)
+            ? authorization[bearer.Length..].Trim()
+            : queryToken;
+        return presented is not null
+               && CryptographicOperations.FixedTimeEquals(Encoding.UTF8.GetBytes(presented), token);
+    }
+
+    /// <summary>
+    /// Starts listening. Throws <see cref="HttpListenerException"/> if the prefix can't be bound.
+    /// </summary>
+    public void Start()
+    {
+        _listener.Start();
+        _loop = Task.Run(() => AcceptLoopAsync(_cts.Token));
+        _logger?.LogInformation("Status server listening on {Prefixes}", string.Join(", ", _listener.Prefixes));
+    }
+
+    private async Task AcceptLoopAsync(CancellationToken ct)
+    {
+        while (!ct.IsCancellationRequested)
+        {
+            HttpListenerContext context;
+            try
+            {
+                context = await _listener.GetContextAsync().WaitAsync(ct);
+            }
+            catch (OperationCanceledException) { break; }
+            catch (Exception ex) when (ex is HttpListenerException or ObjectDisposedException)
+            {
+                break;
+            }
+
+            _ = Task.Run(() => HandleAsync(context, ct), ct);
+        }
+    }
+
+    private async Task HandleAsync(HttpListenerContext context, CancellationToken ct)
+    {
+        var request = context.Request;
+        var response = context.Response;
+        var path = request.Url?.AbsolutePath.TrimEnd('/').ToLowerInvariant() ?? string.Empty;
+
+        var origin = request.Headers["Origin"];
+        if (!IsOriginAllowed(origin, _allowedOrigins))
+        {
+            _logger?.LogWarning("Status server refused {Method} {Path} from origin {Origin}", request.HttpMethod, path, origin);
+            Reject(response, HttpStatusCode.Forbidden);
+            return;
+        }
+
+        var isEventSocket = path == "/events" && request.IsWebSocketRequest;
+        if (!IsAuthorized(_token, request.Headers["Authorization"], isEventSocket ? request.QueryString["token"] : null))
+        {
+            Reject(response, HttpStatusCode.Unauthorized);
+            return;
+        }
+
+        // The hub owns the connection from here on, including closing it.
+        if (isEventSocket)
+        {
+            await _events.AcceptAsync(context, ct);
+            return;
+        }
+
+        try
+        {
+            var method = request.HttpMethod;
+
+            if (path == "/status")
+            {
+                if (method != "GET")
+                {
+                    response.StatusCode = (int)HttpStatusCode.MethodNotAllowed;
+                    return;
+                }
+
+                response.ContentType = "application/json";
+                await JsonSerializer.SerializeAsync(
+                    response.OutputStream,
+                    DaemonStatus.Capture(_session, _cache),
+                    DaemonJsonContext.Default.DaemonStatus,
+                    ct);
+                return;
+            }
+
+            if (path == "/commands")
+            {
+                if (method != "GET")
+                {
+                    response.StatusCode = (int)HttpStatusCode.MethodNotAllowed;
+                    return;
+                }
+
+                response.ContentType = "application/json";
+                await JsonSerializer.SerializeAsync(
+                    response.OutputStream,
+                    _session.CommandLog.GetEntries().ToArray(),
+                    DaemonJsonContext.Default.RemoteCommandEntryArray,
+                    ct);
+                return;
+            }
+
+            if (path == "/cover")
+            {
+                if (method != "GET")
+                {
+                    response.StatusCode = (int)HttpStatusCode.MethodNotAllowed;
+                    return;
+                }
+
+                await WriteCoverAsync(response, ct);
+                return;
+            }
+
+            if (method != "POST")
+            {
+                response.StatusCode = path is "/play" or "/pause" or "/next" or "/previous" or "/seek" or "/volume"
+                    ? (int)HttpStatusCode.MethodNotAllowed
+                    : path == "/events"
+                        ? (int)HttpStatusCode.UpgradeRequired
+                        : (int)HttpStatusCode.NotFound;
+                return;
+            }
+
+            response.StatusCode = (int)await HandleControlAsync(path, request, ct);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Status server request {Method} {Url} failed", request.HttpMethod, request.Url);
+            response.StatusCode = (int)HttpStatusCode.InternalServerError;
+        }
+        finally
+        {
+            response.Close();
+        }
+    }
+
+    private async Task WriteCoverAsync(HttpListenerResponse response, CancellationToken ct)
+    {
+        var track = _session.PlaybackState?.CurrentState.Track;
+        var imageUri = track?.ImageLargeUrl ?? track?.ImageUrl ?? track?.ImageXLargeUrl ?? track?.ImageSmallUrl;
+        var image = string.IsNullOrEmpty(imageUri) ? null : await _session.Images.GetAsync(imageUri, ct);
+        if (image == null)
+        {
+            response.StatusCode = (int)HttpStatusCode.NotFound;
+            return;
+        }
+
+        response.ContentType = "image/jpeg";
+        response.ContentLength64 = image.Length;
+        await response.OutputStream.WriteAsync(image, ct);
+    }
+
+    private static void Reject(HttpListenerResponse response, HttpStatusCode status)
+    {
+        response.StatusCode = (int)status;
+        if (status == HttpStatusCode.Unauthorized)
+            response.AddHeader("WWW-Authenticate", "Bearer");
+        response.Close();
+    }
+
+    private async Task<HttpStatusCode> HandleControlAsync(string path, HttpListenerRequest request, CancellationToken ct)
+    {
+        var command = path.TrimStart('/');
+        var argument = command switch
+        {
+            "volume" => request.QueryString["percent"],
+            "seek" or "loop_in" or "loop_out" => request.QueryString["positionMs"],
+            "cue_set" or "cue" or "cue_clear" => request.QueryString["slot"],
+            _ => null
+        };
+
+        CommandOutcome outcome;
+        try
+        {
+            outcome = await _controller.ExecuteAsync(
+                command,
+                long.TryParse(argument, out var value) ? value : null,
+                ct);
+        }
+        catch (ConfigException ex)
+        {
+            _logger?.LogWarning("Configuration reload rejected: {Message}", ex.Message);
+            return HttpStatusCode.UnprocessableEntity;
+        }
+
+        return outcome switch
+        {
+            CommandOutcome.Ok => HttpStatusCode.NoContent,
+            CommandOutcome.UnknownCommand => HttpStatusCode.NotFound,
+            CommandOutcome.InvalidArgument => HttpStatusCode.BadRequest,
+            _ => HttpStatusCode.ServiceUnavailable
+        };
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _cts.CancelAsync();
+        _events.Dispose();
+        if (_listener.IsListening)
+            _listener.Stop();
+        if (_loop != null)
+        {
+            try { await _loop; }
+            catch (OperationCanceledException) { }
+        }
+        _listener.Close();
+        _cts.Dispose();
+    }
+}
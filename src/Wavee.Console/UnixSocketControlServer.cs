@@ -0,0 +1,146 @@
+using System.Net.Sockets;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Optional Unix domain socket speaking the same newline-delimited JSON-RPC 2.0
+/// as <c>--jsonrpc</c>, for lightweight local CLIs. Disabled unless
+/// <see cref="PathEnvironmentVariable"/> is set.
+/// </summary>
+/// <remarks>
+/// Each connection gets its own <see cref="JsonRpcStdioHost"/>: requests in, responses
+/// and event notifications out. Access control is the socket file's permissions — it is
+/// created owner-only (<c>0600</c>) before the server starts listening, so only the
+/// daemon's user can connect. On Windows the file mode is left to the directory's ACL.
+/// </remarks>
+internal sealed class UnixSocketControlServer : IAsyncDisposable
+{
+    public const string PathEnvironmentVariable = "WAVEE_CONTROL_SOCKET";
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly ILogger? _logger;
+    private readonly Socket _listener = new(AddressFamily.Unix, SocketType.Stream, ProtocolType.Unspecified);
+    private readonly CancellationTokenSource _cts = new();
+    private readonly List<Task> _connections = new();
+    private Task? _loop;
+    private bool _bound;
+
+    public UnixSocketControlServer(
+        Session session,
+        DaemonController controller,
+        ICacheService? cache,
+        string path,
+        ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        ArgumentException.ThrowIfNullOrWhiteSpace(path);
+        _cache = cache;
+        _logger = logger;
+        Path = System.IO.Path.GetFullPath(path);
+    }
+
+    /// <summary>Socket file the server listens on.</summary>
+    public string Path { get; }
+
+    /// <summary>
+    /// Reads the socket path from <see cref="PathEnvironmentVariable"/>.
+    /// </summary>
+    /// <returns>False when the variable is unset (server disabled).</returns>
+    public static bool TryGetPathFromEnvironment(out string path)
+    {
+        path = Environment.GetEnvironmentVariable(PathEnvironmentVariable)?.Trim() ?? string.Empty;
+        return path.Length > 0;
+    }
+
+    /// <summary>
+    /// Binds the socket and starts accepting. A stale socket file left by a crashed
+    /// daemon is replaced; throws <see cref="SocketException"/> if the path can't be bound.
+    /// </summary>
+    public void Start()
+    {
+        var directory = System.IO.Path.GetDirectoryName(Path);
+        if (!string.IsNullOrEmpty(directory))
+            Directory.CreateDirectory(directory);
+        if (File.Exists(Path))
+            File.Delete(Path);
+
+        _listener.Bind(new UnixDomainSocketEndPoint(Path));
+        _bound = true;
+        if (!OperatingSystem.IsWindows())
+            File.SetUnixFileMode(Path, UnixFileMode.UserRead | UnixFileMode.UserWrite);
+        _listener.Listen();
+
+        _loop = Task.Run(() => AcceptLoopAsync(_cts.Token));
+        _logger?.LogInformation("Control socket listening on {Path}", Path);
+    }
+
+    private async Task AcceptLoopAsync(CancellationToken ct)
+    {
+        while (!ct.IsCancellationRequested)
+        {
+            Socket client;
+            try
+            {
+                client = await _listener.AcceptAsync(ct);
+            }
+            catch (OperationCanceledException) { break; }
+            catch (Exception ex) when (ex is SocketException or ObjectDisposedException)
+            {
+                break;
+            }
+
+            lock (_connections)
+            {
+                _connections.RemoveAll(t => t.IsCompleted);
+                _connections.Add(ServeAsync(client, ct));
+            }
+        }
+    }
+
+    private async Task ServeAsync(Socket client, CancellationToken ct)
+    {
+        await using var stream = new NetworkStream(client, ownsSocket: true);
+        using var reader = new StreamReader(stream, leaveOpen: true);
+        var host = new JsonRpcStdioHost(_session, _controller, _cache, reader, stream, _logger);
+        try
+        {
+            await host.RunAsync(ct);
+        }
+        catch (Exception ex) when (ex is OperationCanceledException or IOException)
+        {
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Control socket client failed");
+        }
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _cts.CancelAsync();
+        _listener.Dispose();
+        if (_loop != null)
+        {
+            try { await _loop; }
+            catch (OperationCanceledException) { }
+        }
+
+        Task[] connections;
+        lock (_connections)
+            connections = _connections.ToArray();
+        await Task.WhenAll(connections);
+
+        if (_bound)
+        {
+            try { File.Delete(Path); }
+            catch (Exception ex) when (ex is IOException or UnauthorizedAccessException) { }
+        }
+        _cts.Dispose();
+    }
+}
@@ -0,0 +1,273 @@
+#if WAVEE_WASM_PLUGINS
+using System.Text;
+using System.Threading.Channels;
+using Microsoft.Extensions.Logging;
+using Wasmtime;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Runs WebAssembly plugins that react to player and session events and may issue a
+/// small set of playback commands. Compiled only with <c>-p:WaveeEnableWasmPlugins=true</c>.
+/// </summary>
+/// <remarks>
+/// Every <c>*.wasm</c> file in <see cref="DirectoryEnvironmentVariable"/> is loaded as one plugin.
+/// A plugin exports:
+/// <list type="bullet">
+///   <item><c>memory</c> — its linear memory.</item>
+///   <item><c>wavee_alloc(len: i32) -> i32</c> — returns a buffer for an incoming event.</item>
+///   <item><c>wavee_on_event(ptr: i32, len: i32)</c> — receives one event as UTF-8 JSON,
+///   <c>{"type":"player.state","payload":{...}}</c>, with the same types and payloads as the WebSocket stream.</item>
+/// </list>
+/// and may import from module <c>wavee</c>:
+/// <list type="bullet">
+///   <item><c>command(ptr: i32, len: i32)</c> — <c>play</c>, <c>pause</c>, <c>next</c> or <c>previous</c>.</item>
+///   <item><c>log(ptr: i32, len: i32)</c> — writes a line to the Wavee log.</item>
+/// </list>
+/// Plugins have no WASI, file or network access. Commands are run after the event
+/// handler returns, at most <see cref="MaxCommandsPerEvent"/> per event. A plugin that
+/// traps or exhausts its fuel budget for one event is unloaded.
+/// </remarks>
+internal sealed class WasmPluginHost : IAsyncDisposable
+{
+    public const string DirectoryEnvironmentVariable = "WAVEE_PLUGIN_DIR";
+
+    /// <summary>Commands a plugin may issue; see <see cref="DaemonController.ExecuteAsync"/>.</summary>
+    private static readonly HashSet<string> AllowedCommands = new(StringComparer.Ordinal) { "play", "pause", "next", "previous" };
+
+    private const int MaxCommandsPerEvent = 4;
+
+    /// <summary>Instructions a plugin may run per event before it is unloaded.</summary>
+    private const ulong FuelPerEvent = 50_000_000;
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly ILogger? _logger;
+    private readonly Engine _engine = new(new Config().WithFuelConsumption(true));
+    private readonly List<Plugin> _plugins = [];
+    private readonly Channel<DaemonEvent> _events = Channel.CreateBounded<DaemonEvent>(
+        new BoundedChannelOptions(256) { SingleReader = true, FullMode = BoundedChannelFullMode.DropOldest });
+    private readonly CancellationTokenSource _cts = new();
+    private IDisposable? _eventSubscription;
+    private Task? _dispatchLoop;
+    private bool _disposed;
+
+    public WasmPluginHost(Session session, DaemonController controller, ICacheService? cache, ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Names of the loaded plugins.
+    /// </summary>
+    public IReadOnlyList<string> PluginNames
+    {
+        get
+        {
+            lock (_plugins)
+                return _plugins.Select(p => p.Name).ToList();
+        }
+    }
+
+    /// <returns>The plugin directory, or null when <see cref="DirectoryEnvironmentVariable"/> is unset (plugins disabled).</returns>
+    public static string? GetDirectoryFromEnvironment()
+    {
+        var directory = Environment.GetEnvironmentVariable(DirectoryEnvironmentVariable);
+        return string.IsNullOrWhiteSpace(directory) ? null : directory.Trim();
+    }
+
+    /// <summary>
+    /// Loads every plugin in <paramref name="directory"/> and starts delivering events.
+    /// Plugins that fail to compile or link are skipped with a warning.
+    /// </summary>
+    public void Start(string directory) =>
+        Start(Directory.EnumerateFiles(directory, "*.wasm")
+            .Order(StringComparer.Ordinal)
+            .Select(path => (Path.GetFileNameWithoutExtension(path), (Func<Engine, Module>)(e => Module.FromFile(e, path)))));
+
+    /// <summary>
+    /// Loads one plugin per entry and starts delivering events.
+    /// </summary>
+    /// <param name="modules">Plugin name and a compiler for its module, run on the host's engine.</param>
+    internal void Start(IEnumerable<(string Name, Func<Engine, Module> Compile)> modules)
+    {
+        foreach (var (name, compile) in modules)
+        {
+            try
+            {
+                using var module = compile(_engine);
+                _plugins.Add(Load(name, module));
+                _logger?.LogInformation("Loaded plugin {Plugin}", name);
+            }
+            catch (WasmtimeException ex)
+            {
+                _logger?.LogWarning("Plugin {Plugin} failed to load: {Error}", name, ex.Message);
+            }
+        }
+
+        if (_plugins.Count == 0)
+            return;
+
+        _events.Writer.TryWrite(DaemonEvents.CurrentState(_session, _cache));
+        _eventSubscription = DaemonEvents.Observe(_session, _cache, _controller.Config, _controller.Metadata)
+            .Subscribe(e => _events.Writer.TryWrite(e));
+        _dispatchLoop = Task.Run(() => DispatchAsync(_cts.Token));
+    }
+
+    private Plugin Load(string name, Module module)
+    {
+        var store = new Store(_engine);
+        var plugin = new Plugin(name, store);
+
+        using var linker = new Linker(_engine);
+        linker.DefineFunction("wavee", "command", (Caller caller, int ptr, int len) =>
+        {
+            var command = ReadString(caller, ptr, len);
+            if (!AllowedCommands.Contains(command))
+                throw new WasmtimeException($"command '{command}' is not available to plugins");
+            if (plugin.PendingCommands.Count >= MaxCommandsPerEvent)
+                throw new WasmtimeException($"more than {MaxCommandsPerEvent} commands for one event");
+            plugin.PendingCommands.Add(command);
+        });
+        linker.DefineFunction("wavee", "log", (Caller caller, int ptr, int len) =>
+            _logger?.LogInformation("[plugin {Plugin}] {Message}", name, ReadString(caller, ptr, len)));
+
+        try
+        {
+            var instance = linker.Instantiate(store, module);
+            plugin.Memory = instance.GetMemory("memory")
+                ?? throw new WasmtimeException("missing export 'memory'");
+            plugin.Alloc = instance.GetFunction<int, int>("wavee_alloc")
+                ?? throw new WasmtimeException("missing export 'wavee_alloc(i32) -> i32'");
+            plugin.OnEvent = instance.GetAction<int, int>("wavee_on_event")
+                ?? throw new WasmtimeException("missing export 'wavee_on_event(i32, i32)'");
+            return plugin;
+        }
+        catch
+        {
+            store.Dispose();
+            throw;
+        }
+    }
+
+    private async Task DispatchAsync(CancellationToken ct)
+    {
+        try
+        {
+            await foreach (var e in _events.Reader.ReadAllAsync(ct))
+            {
+                var message = Encoding.UTF8.GetBytes($"{{\"type\":\"{e.Type}\",\"payload\":")
+                    .Concat(e.Data)
+                    .Append((byte)'}')
+                    .ToArray();
+
+                List<Plugin> plugins;
+                lock (_plugins)
+                    plugins = _plugins.ToList();
+
+                foreach (var plugin in plugins)
+                {
+                    if (!Deliver(plugin, message))
+                    {
+                        lock (_plugins)
+                            _plugins.Remove(plugin);
+                        plugin.Store.Dispose();
+                        continue;
+                    }
+
+                    foreach (var command in plugin.PendingCommands)
+                        await RunCommandAsync(plugin, command, ct);
+                    plugin.PendingCommands.Clear();
+                }
+            }
+        }
+        catch (OperationCanceledException)
+        {
+        }
+    }
+
+    /// <returns>False when the plugin misbehaved and must be unloaded.</returns>
+    private bool Deliver(Plugin plugin, byte[] message)
+    {
+        try
+        {
+            plugin.PendingCommands.Clear();
+            plugin.Store.Fuel = FuelPerEvent;
+
+            var ptr = plugin.Alloc!(message.Length);
+            if (ptr < 0 || (long)ptr + message.Length > plugin.Memory!.GetLength())
+                throw new WasmtimeException("wavee_alloc returned an out-of-bounds buffer");
+            message.CopyTo(plugin.Memory.GetSpan(ptr, message.Length));
+
+            plugin.OnEvent!(ptr, message.Length);
+            return true;
+        }
+        catch (WasmtimeException ex)
+        {
+            _logger?.LogWarning("Plugin {Plugin} unloaded: {Error}", plugin.Name, ex.Message);
+            plugin.PendingCommands.Clear();
+            return false;
+        }
+    }
+
+    private async Task RunCommandAsync(Plugin plugin, string command, CancellationToken ct)
+    {
+        try
+        {
+            var outcome = await _controller.ExecuteAsync(command, null, ct);
+            if (outcome != CommandOutcome.Ok)
+            {
+                _logger?.LogDebug("Plugin {Plugin} command '{Command}' ignored: {Reason}",
+                    plugin.Name, command, DaemonController.Describe(outcome));
+            }
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Plugin {Plugin} command '{Command}' failed", plugin.Name, command);
+        }
+    }
+
+    private static string ReadString(Caller caller, int ptr, int len)
+    {
+        var memory = caller.GetMemory("memory") ?? throw new WasmtimeException("missing export 'memory'");
+        if (ptr < 0 || len < 0 || len > 4096 || (long)ptr + len > memory.GetLength())
+            throw new WasmtimeException("string argument out of bounds");
+        return memory.ReadString(ptr, len);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        if (_disposed)
+            return;
+        _disposed = true;
+
+        _eventSubscription?.Dispose();
+        _events.Writer.TryComplete();
+        await _cts.CancelAsync();
+        if (_dispatchLoop != null)
+            await _dispatchLoop;
+
+        foreach (var plugin in _plugins)
+            plugin.Store.Dispose();
+        _plugins.Clear();
+        _engine.Dispose();
+        _cts.Dispose();
+    }
+
+    private sealed class Plugin(string name, Store store)
+    {
+        public string Name { get; } = name;
+        public Store Store { get; } = store;
+        public Memory? Memory { get; set; }
+        public Func<int, int>? Alloc { get; set; }
+        public Action<int, int>? OnEvent { get; set; }
+        public List<string> PendingCommands { get; } = [];
+    }
+}
+#endif
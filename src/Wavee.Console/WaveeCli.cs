@@ -0,0 +1,330 @@
+#if WAVEE_CLI
+using System.Diagnostics;
+using System.Net.Sockets;
+using System.Text;
+using System.Text.Json;
+using Microsoft.Extensions.DependencyInjection;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Sinks;
+using Wavee.Core.Authentication;
+using Wavee.Core.Diagnostics;
+using Wavee.Core.Session;
+using Wavee.OAuth;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// One-shot subcommands for the <c>wavee</c> binary (<c>-p:WaveeEnableCli=true</c>):
+/// <c>login</c>, <c>status</c>, <c>play [uri]</c>, <c>pause</c>, <c>next</c>,
+/// <c>previous</c>, <c>search &lt;query&gt;</c>, <c>selftest</c> and <c>trace &lt;file&gt;</c>.
+/// </summary>
+/// <remarks>
+/// Everything except <c>login</c>, <c>selftest</c> and <c>trace</c> is a JSON-RPC call (see <see cref="JsonRpcStdioHost"/>).
+/// When <see cref="UnixSocketControlServer.PathEnvironmentVariable"/> points at a running
+/// daemon the call goes over its control socket; otherwise the CLI starts itself with
+/// <c>--jsonrpc</c> for the duration of the command. An ad-hoc daemon runs without local
+/// playback (it would stop with the command), so transport commands only work against a running one.
+/// </remarks>
+internal static class WaveeCli
+{
+    private static readonly string[] Commands = ["login", "status", "play", "pause", "next", "previous", "search", "selftest", "trace"];
+
+    /// <summary>Whether <paramref name="args"/> starts with a CLI subcommand.</summary>
+    public static bool IsCommand(string[] args) =>
+        args.Length > 0 && Commands.Contains(args[0], StringComparer.OrdinalIgnoreCase);
+
+    /// <summary>
+    /// Runs the subcommand in <paramref name="args"/>[0].
+    /// </summary>
+    /// <param name="args">Command line, subcommand first.</param>
+    /// <param name="deviceId">Returns the persistent device id shared with the daemon.</param>
+    /// <returns>Process exit code: 0 on success, 1 on failure, 2 on bad usage.</returns>
+    public static async Task<int> RunAsync(string[] args, Func<string> deviceId)
+    {
+        var command = args[0].ToLowerInvariant();
+        var rest = args.Skip(1).ToArray();
+        try
+        {
+            return command switch
+            {
+                "login" => await LoginAsync(deviceId(), rest.Contains("--device-code")),
+                "status" => await StatusAsync(),
+                "play" => rest.Length > 0
+                    ? await ControlAsync("load", w => w.WriteString("uri", rest[0]))
+                    : await ControlAsync("play"),
+                "selftest" => await SelfTestAsync(),
+                "trace" => rest.Length > 0
+                    ? PrintTrace(rest[0], !rest.Contains("--summary"))
+                    : Usage("wavee trace <file.wavee-trace> [--summary]"),
+                "search" => rest.Length > 0
+                    ? await SearchAsync(string.Join(' ', rest))
+                    : Usage("wavee search <query>"),
+                _ => await ControlAsync(command)
+            };
+        }
+        catch (Exception ex) when (ex is IOException or SocketException or InvalidOperationException)
+        {
+            System.Console.Error.WriteLine($"wavee {command}: {ex.Message}");
+            return 1;
+        }
+    }
+
+    private static int Usage(string usage)
+    {
+        System.Console.Error.WriteLine($"usage: {usage}");
+        return 2;
+    }
+
+    private static async Task<int> LoginAsync(string deviceId, bool deviceCode)
+    {
+        using var loggerFactory = LoggerFactory.Create(builder => builder.AddConsole(o => o.LogToStandardErrorThreshold = LogLevel.Trace));
+        var services = new ServiceCollection();
+        services.AddHttpClient("Wavee", client => { client.Timeout = TimeSpan.FromSeconds(30); });
+        await using var serviceProvider = services.BuildServiceProvider();
+
+        var config = new SessionConfig { DeviceId = deviceId, DeviceName = "Wavee Console" };
+        var oauth = OAuthClient.CreateCustom(
+            config.GetClientId(),
+            ["streaming", "user-read-playback-state", "user-modify-playback-state"],
+            flow: deviceCode ? OAuthFlow.DeviceCode : OAuthFlow.AuthorizationCode,
+            openBrowser: !deviceCode,
+            logger: loggerFactory.CreateLogger("OAuth"));
+        var token = await oauth.GetAccessTokenAsync();
+
+        // Connecting once exchanges the OAuth token for reusable credentials and stores them.
+        var credentialsCache = new CredentialsCache(logger: loggerFactory.CreateLogger<CredentialsCache>());
+        await using var session = Session.Create(config, serviceProvider.GetRequiredService<IHttpClientFactory>());
+        await session.ConnectAsync(Credentials.WithAccessToken(token.AccessToken), credentialsCache);
+
+        System.Console.WriteLine($"Logged in as {session.GetUserData()?.Username}");
+        return 0;
+    }
+
+    private static int PrintTrace(string path, bool includeMessages)
+    {
+        ProtocolTrace trace;
+        try
+        {
+            trace = ProtocolTrace.ReadFile(path);
+        }
+        catch (FormatException ex)
+        {
+            System.Console.Error.WriteLine($"wavee trace: {path}: {ex.Message}");
+            return 1;
+        }
+
+        ProtocolTraceFormatter.Write(trace, System.Console.Out, includeMessages);
+        return 0;
+    }
+
+    private static async Task<int> SelfTestAsync()
+    {
+        var report = await SelfTest.RunAsync([new SelfTestCheck("audio-sink", ProbeAudioSinkAsync)]);
+
+        System.Console.WriteLine($"wavee selftest on {report.Platform}");
+        foreach (var result in report.Results)
+        {
+            System.Console.WriteLine(
+                $"  {(result.Passed ? "ok  " : "FAIL")}  {result.Name,-18} {result.Detail} ({result.Elapsed.TotalMilliseconds:F0} ms)");
+        }
+        return report.Passed ? 0 : 1;
+    }
+
+    /// <summary>
+    /// Opens the default sink with a CD-quality format and closes it again; nothing is played.
+    /// </summary>
+    private static async Task<string> ProbeAudioSinkAsync(CancellationToken cancellationToken)
+    {
+        await using var sink = AudioSinkFactory.CreateDefault();
+        await sink.InitializeAsync(new AudioFormat(44100, 2, 16), cancellationToken: cancellationToken);
+
+        if (sink is not IDeviceSelectableSink selectable)
+            return sink.SinkName;
+
+        var devices = selectable.EnumerateOutputDevices();
+        return $"{sink.SinkName} on '{selectable.CurrentDeviceName}', {devices.Count} output device(s)";
+    }
+
+    private static async Task<int> StatusAsync()
+    {
+        await using var client = await DaemonRpcClient.ConnectAsync();
+        using var result = await client.CallAsync("status", null);
+        var status = result.RootElement.Deserialize(DaemonJsonContext.Default.DaemonStatus)!;
+
+        if (status.Track is { } track)
+        {
+            System.Console.WriteLine($"{status.Status}: {track.Title} — {track.Artist}");
+            System.Console.WriteLine($"  {FormatTime(status.PositionMs)} / {FormatTime(status.DurationMs)}  {track.Uri}");
+        }
+        else
+        {
+            System.Console.WriteLine(status.Status.ToString());
+        }
+
+        var device = status.Device.ActiveDeviceName ?? status.Device.Name;
+        System.Console.WriteLine($"  on {device}{(status.Device.VolumePercent is { } v ? $" at {v}%" : string.Empty)}");
+        return 0;
+    }
+
+    private static async Task<int> SearchAsync(string query)
+    {
+        await using var client = await DaemonRpcClient.ConnectAsync();
+        using var result = await client.CallAsync("search", w => w.WriteString("query", query));
+        var hits = result.RootElement.Deserialize(DaemonJsonContext.Default.SearchHitArray) ?? [];
+
+        foreach (var hit in hits)
+        {
+            var subtitle = string.IsNullOrEmpty(hit.Subtitle) ? string.Empty : $" — {hit.Subtitle}";
+            System.Console.WriteLine($"{hit.Type,-9} {hit.Uri,-40} {hit.Name}{subtitle}");
+        }
+        return 0;
+    }
+
+    private static async Task<int> ControlAsync(string method, Action<Utf8JsonWriter>? parameters = null)
+    {
+        await using var client = await DaemonRpcClient.ConnectAsync();
+        using var _ = await client.CallAsync(method, parameters);
+        return 0;
+    }
+
+    private static string FormatTime(long milliseconds)
+    {
+        var ts = TimeSpan.FromMilliseconds(milliseconds);
+        return $"{(int)ts.TotalMinutes}:{ts.Seconds:D2}";
+    }
+
+    /// <summary>
+    /// Minimal JSON-RPC client over the control socket or an ad-hoc <c>--jsonrpc</c> child.
+    /// </summary>
+    private sealed class DaemonRpcClient : IAsyncDisposable
+    {
+        // JSON-RPC 2.0 parse error, used for replies the client can't read.
+        private const int ParseError = -32700;
+
+        private readonly Stream _output;
+        private readonly TextReader _input;
+        private readonly IAsyncDisposable? _socket;
+        private readonly Process? _child;
+        private int _nextId;
+
+        private DaemonRpcClient(Stream output, TextReader input, IAsyncDisposable? socket, Process? child)
+        {
+            _output = output;
+            _input = input;
+            _socket = socket;
+            _child = child;
+        }
+
+        public static async Task<DaemonRpcClient> ConnectAsync()
+        {
+            if (UnixSocketControlServer.TryGetPathFromEnvironment(out var path) && File.Exists(path))
+            {
+                var socket = new Socket(AddressFamily.Unix, SocketType.Stream, ProtocolType.Unspecified);
+                await socket.ConnectAsync(new UnixDomainSocketEndPoint(path));
+                var stream = new NetworkStream(socket, ownsSocket: true);
+                return new DaemonRpcClient(stream, new StreamReader(stream, leaveOpen: true), stream, null);
+            }
+
+            var start = new ProcessStartInfo(Environment.ProcessPath ?? throw new InvalidOperationException("Cannot locate own executable"))
+            {
+                RedirectStandardInput = true,
+                RedirectStandardOutput = true,
+                UseShellExecute = false,
+                StandardOutputEncoding = Encoding.UTF8
+            };
+            start.ArgumentList.Add("--jsonrpc");
+            start.Environment[ConsolePlayback.AudioHostEnvironmentVariable] = ConsolePlayback.DisabledValue;
+            var child = Process.Start(start) ?? throw new InvalidOperationException("Failed to start daemon");
+            return new DaemonRpcClient(child.StandardInput.BaseStream, child.StandardOutput, null, child);
+        }
+
+        /// <summary>
+        /// Sends one request and returns its <c>result</c>. Notifications in between are skipped.
+        /// </summary>
+        /// <exception cref="InvalidOperationException">
+        /// The daemon returned an error, sent a line that isn't JSON-RPC (reported as a -32700 parse error) or went away.
+        /// </exception>
+        public async Task<JsonDocument> CallAsync(string method, Action<Utf8JsonWriter>? parameters)
+        {
+            var id = ++_nextId;
+            using (var buffer = new MemoryStream())
+            {
+                using (var writer = new Utf8JsonWriter(buffer))
+                {
+                    writer.WriteStartObject();
+                    writer.WriteString("jsonrpc", "2.0");
+                    writer.WriteNumber("id", id);
+                    writer.WriteString("method", method);
+                    if (parameters != null)
+                    {
+                        writer.WriteStartObject("params");
+                        parameters(writer);
+                        writer.WriteEndObject();
+                    }
+                    writer.WriteEndObject();
+                }
+                buffer.WriteByte((byte)'\n');
+                await _output.WriteAsync(buffer.ToArray());
+                await _output.FlushAsync();
+            }
+
+            while (await _input.ReadLineAsync() is { } line)
+            {
+                JsonDocument message;
+                try
+                {
+                    message = JsonDocument.Parse(line);
+                }
+                catch (JsonException ex)
+                {
+                    throw new InvalidOperationException($"Parse error ({ParseError}): {ex.Message}", ex);
+                }
+
+                using (message)
+                {
+                    var root = message.RootElement;
+                    if (root.ValueKind != JsonValueKind.Object
+                        || !root.TryGetProperty("id", out var replyId)
+                        || replyId.ValueKind != JsonValueKind.Number
+                        || !replyId.TryGetInt32(out var number)
+                        || number != id)
+                        continue;
+
+                    if (root.TryGetProperty("error", out var error))
+                    {
+                        throw new InvalidOperationException(
+                            error.ValueKind == JsonValueKind.Object && error.TryGetProperty("message", out var text)
+                                && text.ValueKind == JsonValueKind.String
+                                ? text.GetString()
+                                : "Daemon returned an error");
+                    }
+
+                    if (!root.TryGetProperty("result", out var result))
+                        throw new InvalidOperationException($"Parse error ({ParseError}): reply {id} has no result");
+
+                    return JsonDocument.Parse(result.GetRawText());
+                }
+            }
+
+            throw new InvalidOperationException("Daemon closed the connection");
+        }
+
+        public async ValueTask DisposeAsync()
+        {
+            _input.Dispose();
+            if (_socket != null)
+                await _socket.DisposeAsync();
+
+            if (_child != null)
+            {
+                // Closing stdin ends the ad-hoc daemon's JSON-RPC loop and with it the process.
+                _child.StandardInput.Close();
+                if (!_child.WaitForExit(TimeSpan.FromSeconds(5)))
+                    _child.Kill(entireProcessTree: true);
+                _child.Dispose();
+            }
+        }
+    }
+}
+#endif
@@ -0,0 +1,226 @@
+#if WAVEE_GRPC
+using System.Threading.Channels;
+using Grpc.Core;
+using Microsoft.Extensions.Logging;
+using Wavee.Console.Grpc;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using DomainConnectionState = Wavee.Connect.Connection.ConnectionState;
+using DomainPlaybackStatus = Wavee.Connect.PlaybackStatus;
+
+namespace Wavee.Console;
+
+/// <summary>
+/// Implementation of the <c>WaveeControl</c> gRPC service (see
+/// <c>Protos/wavee_control.proto</c>), hosted by <see cref="GrpcControlServer"/>.
+/// </summary>
+/// <remarks>
+/// Playback RPCs go through <see cref="DaemonController"/> and events come from
+/// <see cref="DaemonEvents"/>, so gRPC clients see exactly what the HTTP,
+/// WebSocket and MQTT integrations see.
+/// </remarks>
+internal sealed class WaveeControlService : WaveeControl.WaveeControlBase
+{
+    private const int EventQueueCapacity = 64;
+
+    private static readonly CommandResponse Done = new();
+
+    private readonly Session _session;
+    private readonly DaemonController _controller;
+    private readonly ICacheService? _cache;
+    private readonly IExtendedMetadataClient? _metadata;
+    private readonly ILogger? _logger;
+
+    public WaveeControlService(
+        Session session,
+        DaemonController controller,
+        ICacheService? cache,
+        IExtendedMetadataClient? metadata,
+        ILogger? logger = null)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _controller = controller ?? throw new ArgumentNullException(nameof(controller));
+        _cache = cache;
+        _metadata = metadata;
+        _logger = logger;
+    }
+
+    public override Task<SessionInfo> GetSession(GetSessionRequest request, ServerCallContext context) =>
+        Task.FromResult(new SessionInfo
+        {
+            DeviceId = _session.Config.DeviceId,
+            DeviceName = _session.Config.DeviceName,
+            Username = _session.GetUserData()?.Username ?? string.Empty,
+            Connected = _session.IsConnected(),
+            DeviceActive = _session.DeviceState?.IsActive ?? false,
+            DealerState = Map(_session.Dealer?.CurrentState ?? DomainConnectionState.Disconnected)
+        });
+
+    public override async Task<CommandResponse> SetDeviceActive(SetDeviceActiveRequest request, ServerCallContext context)
+    {
+        if (!await _session.SetDeviceActiveAsync(request.Active, context.CancellationToken))
+            throw new RpcException(new Status(StatusCode.Unavailable, "Spotify Connect is not available"));
+        return Done;
+    }
+
+    public override Task<PlayerStatus> GetStatus(GetStatusRequest request, ServerCallContext context) =>
+        Task.FromResult(Map(DaemonStatus.Capture(_session, _cache)));
+
+    public override async Task<CommandResponse> Load(LoadRequest request, ServerCallContext context) =>
+        Complete(await _controller.LoadAsync(request.Uri, context.CancellationToken));
+
+    public override Task<CommandResponse> Play(PlaybackRequest request, ServerCallContext context) =>
+        ExecuteAsync("play", null, context);
+
+    public override Task<CommandResponse> Pause(PlaybackRequest request, ServerCallContext context) =>
+        ExecuteAsync("pause", null, context);
+
+    public override Task<CommandResponse> Next(PlaybackRequest request, ServerCallContext context) =>
+        ExecuteAsync("next", null, context);
+
+    public override Task<CommandResponse> Previous(PlaybackRequest request, ServerCallContext context) =>
+        ExecuteAsync("previous", null, context);
+
+    public override Task<CommandResponse> Seek(SeekRequest request, ServerCallContext context) =>
+        ExecuteAsync("seek", request.PositionMs, context);
+
+    public override Task<CommandResponse> SetVolume(SetVolumeRequest request, ServerCallContext context) =>
+        ExecuteAsync("volume", request.Percent, context);
+
+    public override async Task<TrackMetadata> GetTrack(GetTrackRequest request, ServerCallContext context)
+    {
+        if (_metadata == null)
+            throw new RpcException(new Status(StatusCode.Unavailable, "metadata client unavailable"));
+        if (!request.Uri.StartsWith("spotify:track:", StringComparison.Ordinal))
+            throw new RpcException(new Status(StatusCode.InvalidArgument, "expected a spotify:track: URI"));
+
+        var track = await _metadata.GetTrackAsync(request.Uri, context.CancellationToken)
+            ?? throw new RpcException(new Status(StatusCode.NotFound, $"{request.Uri} not found"));
+
+        var result = new TrackMetadata
+        {
+            Uri = request.Uri,
+            Name = track.Name,
+            Album = track.Album?.Name ?? string.Empty,
+            DurationMs = track.Duration,
+            Explicit = track.Explicit,
+            Popularity = track.Popularity,
+            TrackNumber = track.Number,
+            DiscNumber = track.DiscNumber
+        };
+        result.Artists.AddRange(track.Artist.Select(static a => a.Name));
+        return result;
+    }
+
+    public override async Task StreamEvents(
+        StreamEventsRequest request,
+        IServerStreamWriter<Event> responseStream,
+        ServerCallContext context)
+    {
+        // Slow clients drop their oldest events rather than back-pressuring the session.
+        var queue = Channel.CreateBounded<Event>(new BoundedChannelOptions(EventQueueCapacity)
+        {
+            FullMode = BoundedChannelFullMode.DropOldest,
+            SingleReader = true
+        });
+
+        using var subscription = DaemonEvents.Observe(_session, _cache, _controller.Config, _controller.Metadata)
+            .Subscribe(e =>
+            {
+                if (Map(e) is { } mapped)
+                    queue.Writer.TryWrite(mapped);
+            });
+        queue.Writer.TryWrite(new Event { PlayerState = Map(DaemonStatus.Capture(_session, _cache)) });
+
+        try
+        {
+            await foreach (var e in queue.Reader.ReadAllAsync(context.CancellationToken))
+                await responseStream.WriteAsync(e, context.CancellationToken);
+        }
+        catch (OperationCanceledException)
+        {
+            // Client went away.
+        }
+    }
+
+    private async Task<CommandResponse> ExecuteAsync(string command, long? argument, ServerCallContext context)
+    {
+        try
+        {
+            return Complete(await _controller.ExecuteAsync(command, argument, context.CancellationToken));
+        }
+        catch (Exception ex) when (ex is not (RpcException or OperationCanceledException))
+        {
+            _logger?.LogWarning(ex, "gRPC command '{Command}' failed", command);
+            throw new RpcException(new Status(StatusCode.Internal, ex.Message));
+        }
+    }
+
+    private static CommandResponse Complete(CommandOutcome outcome) => outcome switch
+    {
+        CommandOutcome.Ok => Done,
+        CommandOutcome.InvalidArgument => throw new RpcException(new Status(StatusCode.InvalidArgument, DaemonController.Describe(outcome)!)),
+        CommandOutcome.UnknownCommand => throw new RpcException(new Status(StatusCode.Unimplemented, DaemonController.Describe(outcome)!)),
+        _ => throw new RpcException(new Status(StatusCode.Unavailable, DaemonController.Describe(outcome)!))
+    };
+
+    private static Event? Map(DaemonEvent e) => e.Payload switch
+    {
+        DaemonStatus status => new Event { PlayerState = Map(status) },
+        ConnectCommandEvent command => new Event
+        {
+            ConnectCommand = new ConnectCommand { Endpoint = command.Endpoint, SenderDeviceId = command.SenderDeviceId }
+        },
+        ConnectionEvent connection => new Event
+        {
+            Connection = new ConnectionChanged { State = Map(connection.State) }
+        },
+        _ => null
+    };
+
+    private static PlayerStatus Map(DaemonStatus status)
+    {
+        var result = new PlayerStatus
+        {
+            DeviceId = status.Device.Id,
+            DeviceName = status.Device.Name,
+            DeviceActive = status.Device.IsActive,
+            ActiveDeviceName = status.Device.ActiveDeviceName ?? string.Empty,
+            Status = status.Status switch
+            {
+                DomainPlaybackStatus.Playing => PlaybackStatus.Playing,
+                DomainPlaybackStatus.Paused => PlaybackStatus.Paused,
+                DomainPlaybackStatus.Buffering => PlaybackStatus.Buffering,
+                _ => PlaybackStatus.Stopped
+            },
+            PositionMs = status.PositionMs,
+            DurationMs = status.DurationMs
+        };
+
+        if (status.Device.VolumePercent is { } volume)
+            result.VolumePercent = volume;
+
+        if (status.Track is { } track)
+        {
+            result.Track = new NowPlaying
+            {
+                Uri = track.Uri,
+                Title = track.Title ?? string.Empty,
+                Artist = track.Artist ?? string.Empty,
+                Album = track.Album ?? string.Empty,
+                ContextUri = track.ContextUri ?? string.Empty
+            };
+        }
+
+        return result;
+    }
+
+    private static ConnectionState Map(DomainConnectionState state) => state switch
+    {
+        DomainConnectionState.Connected => ConnectionState.Connected,
+        DomainConnectionState.Connecting => ConnectionState.Connecting,
+        _ => ConnectionState.Disconnected
+    };
+}
+#endif
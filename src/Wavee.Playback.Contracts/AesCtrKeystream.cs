@@ -0,0 +1,336 @@
+using System.Buffers;
+using System.Buffers.Binary;
+using System.Runtime.CompilerServices;
+using System.Runtime.Intrinsics;
+using System.Security.Cryptography;
+using ArmAes = System.Runtime.Intrinsics.Arm.Aes;
+using X86Aes = System.Runtime.Intrinsics.X86.Aes;
+
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Which AES implementation produces the audio keystream.
+/// </summary>
+public enum AesBackend
+{
+    /// <summary>
+    /// <see cref="System.Security.Cryptography.Aes"/> from the OS crypto library, one call per batch.
+    /// The only option on CPUs without AES instructions (e.g. ARMv6 Pi Zero).
+    /// </summary>
+    Platform,
+
+    /// <summary>x86/x64 AES-NI instructions.</summary>
+    AesNi,
+
+    /// <summary>ARMv8 Cryptography Extensions (AESE/AESMC).</summary>
+    ArmCrypto
+}
+
+/// <summary>
+/// AES-128-CTR keystream for Spotify audio files (fixed IV, 128-bit big-endian counter).
+/// Shared by the core and AudioHost decrypt streams so both pick the same backend.
+/// </summary>
+/// <remarks>
+/// With AES instructions available the blocks are encrypted in-process, four at a time so the
+/// rounds pipeline, instead of going through the OS library once per 16 bytes — the dominant
+/// CPU cost of playback on small ARM boards. Without them, counters are still batched into a
+/// single <see cref="System.Security.Cryptography.Aes.EncryptEcb(ReadOnlySpan{byte}, Span{byte}, PaddingMode)"/> call.
+/// All backends produce identical output; <see cref="DefaultBackend"/> is what gets used.
+/// </remarks>
+public sealed class AesCtrKeystream : IDisposable
+{
+    /// <summary>AES block size in bytes.</summary>
+    public const int BlockSize = 16;
+
+    private const int Rounds = 10;
+    private const int ScratchBlocks = 64;
+
+    private static readonly byte[] SpotifyAudioIv =
+    [
+        0x72, 0xe0, 0x67, 0xfb, 0xdd, 0xcb, 0xcf, 0x77,
+        0xeb, 0xe8, 0xbc, 0x64, 0x3f, 0x63, 0x0d, 0x93
+    ];
+
+    private static readonly ulong IvHigh = BinaryPrimitives.ReadUInt64BigEndian(SpotifyAudioIv);
+    private static readonly ulong IvLow = BinaryPrimitives.ReadUInt64BigEndian(SpotifyAudioIv.AsSpan(8));
+
+    private static ReadOnlySpan<byte> SBox =>
+    [
+        0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+        0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+        0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+        0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+        0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+        0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+        0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+        0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+        0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+        0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+        0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+        0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+        0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+        0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+        0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+        0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16
+    ];
+
+    private readonly Vector128<byte>[]? _roundKeys;
+    private readonly System.Security.Cryptography.Aes? _platform;
+    private bool _disposed;
+
+    /// <summary>
+    /// The fastest backend this CPU supports.
+    /// </summary>
+    public static AesBackend DefaultBackend { get; } =
+        X86Aes.IsSupported ? AesBackend.AesNi
+        : ArmAes.IsSupported ? AesBackend.ArmCrypto
+        : AesBackend.Platform;
+
+    /// <summary>
+    /// Creates a keystream for a 16-byte audio key.
+    /// </summary>
+    /// <param name="key">AES-128 key.</param>
+    /// <param name="backend">Force a backend (tests, diagnostics); null uses <see cref="DefaultBackend"/>.</param>
+    /// <exception cref="PlatformNotSupportedException">The requested backend isn't available on this CPU.</exception>
+    public AesCtrKeystream(ReadOnlySpan<byte> key, AesBackend? backend = null)
+    {
+        if (key.Length != BlockSize)
+            throw new ArgumentException("AES-128 key must be exactly 16 bytes", nameof(key));
+
+        Backend = backend ?? DefaultBackend;
+        if (!IsSupported(Backend))
+            throw new PlatformNotSupportedException($"AES backend {Backend} is not supported on this CPU");
+
+        if (Backend == AesBackend.Platform)
+        {
+            _platform = System.Security.Cryptography.Aes.Create();
+            _platform.Key = key.ToArray();
+            _platform.Padding = PaddingMode.None;
+        }
+        else
+        {
+            _roundKeys = ExpandKey(key);
+        }
+    }
+
+    /// <summary>
+    /// The backend this instance uses.
+    /// </summary>
+    public AesBackend Backend { get; }
+
+    /// <summary>
+    /// Whether <paramref name="backend"/> can run on this CPU.
+    /// </summary>
+    public static bool IsSupported(AesBackend backend) => backend switch
+    {
+        AesBackend.AesNi => X86Aes.IsSupported,
+        AesBackend.ArmCrypto => ArmAes.IsSupported,
+        _ => true
+    };
+
+    /// <summary>
+    /// XORs the keystream into <paramref name="buffer"/>, which starts at byte
+    /// <paramref name="streamPosition"/> of the file. CTR is symmetric, so this both
+    /// decrypts and encrypts.
+    /// </summary>
+    public void Apply(Span<byte> buffer, long streamPosition)
+    {
+        Span<byte> scratch = stackalloc byte[ScratchBlocks * BlockSize];
+        while (!buffer.IsEmpty)
+        {
+            var block = streamPosition / BlockSize;
+            var skip = (int)(streamPosition % BlockSize);
+            var blocks = (int)Math.Min(ScratchBlocks, (skip + (long)buffer.Length + BlockSize - 1) / BlockSize);
+
+            var keystream = scratch[..(blocks * BlockSize)];
+            Generate(block, keystream);
+
+            var count = Math.Min(buffer.Length, keystream.Length - skip);
+            Xor(buffer[..count], keystream.Slice(skip, count));
+            buffer = buffer[count..];
+            streamPosition += count;
+        }
+
+        CryptographicOperations.ZeroMemory(scratch);
+    }
+
+    /// <summary>
+    /// Writes the keystream for blocks <paramref name="firstBlock"/> onward into
+    /// <paramref name="output"/>, whose length must be a multiple of <see cref="BlockSize"/>.
+    /// </summary>
+    public void Generate(long firstBlock, Span<byte> output)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+        if (output.Length % BlockSize != 0)
+            throw new ArgumentException("Output must be a whole number of blocks", nameof(output));
+
+        WriteCounters(firstBlock, output);
+        switch (Backend)
+        {
+            case AesBackend.AesNi:
+                EncryptAesNi(output, _roundKeys!);
+                break;
+            case AesBackend.ArmCrypto:
+                EncryptArm(output, _roundKeys!);
+                break;
+            default:
+                EncryptPlatform(output);
+                break;
+        }
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        _disposed = true;
+        _platform?.Dispose();
+        if (_roundKeys != null)
+            Array.Clear(_roundKeys);
+    }
+
+    private static void WriteCounters(long firstBlock, Span<byte> output)
+    {
+        var low = IvLow + (ulong)firstBlock;
+        var high = IvHigh + (low < IvLow ? 1UL : 0UL);
+        for (var offset = 0; offset < output.Length; offset += BlockSize)
+        {
+            BinaryPrimitives.WriteUInt64BigEndian(output[offset..], high);
+            BinaryPrimitives.WriteUInt64BigEndian(output[(offset + 8)..], low);
+            if (++low == 0)
+                high++;
+        }
+    }
+
+    private void EncryptPlatform(Span<byte> blocks)
+    {
+        var counters = ArrayPool<byte>.Shared.Rent(blocks.Length);
+        try
+        {
+            blocks.CopyTo(counters);
+            _platform!.EncryptEcb(counters.AsSpan(0, blocks.Length), blocks, PaddingMode.None);
+        }
+        finally
+        {
+            ArrayPool<byte>.Shared.Return(counters);
+        }
+    }
+
+    private static void EncryptAesNi(Span<byte> blocks, Vector128<byte>[] rk)
+    {
+        var offset = 0;
+        for (; offset + 4 * BlockSize <= blocks.Length; offset += 4 * BlockSize)
+        {
+            var b0 = Vector128.Create<byte>(blocks.Slice(offset, BlockSize)) ^ rk[0];
+            var b1 = Vector128.Create<byte>(blocks.Slice(offset + 16, BlockSize)) ^ rk[0];
+            var b2 = Vector128.Create<byte>(blocks.Slice(offset + 32, BlockSize)) ^ rk[0];
+            var b3 = Vector128.Create<byte>(blocks.Slice(offset + 48, BlockSize)) ^ rk[0];
+            for (var round = 1; round < Rounds; round++)
+            {
+                b0 = X86Aes.Encrypt(b0, rk[round]);
+                b1 = X86Aes.Encrypt(b1, rk[round]);
+                b2 = X86Aes.Encrypt(b2, rk[round]);
+                b3 = X86Aes.Encrypt(b3, rk[round]);
+            }
+            X86Aes.EncryptLast(b0, rk[Rounds]).CopyTo(blocks.Slice(offset, BlockSize));
+            X86Aes.EncryptLast(b1, rk[Rounds]).CopyTo(blocks.Slice(offset + 16, BlockSize));
+            X86Aes.EncryptLast(b2, rk[Rounds]).CopyTo(blocks.Slice(offset + 32, BlockSize));
+            X86Aes.EncryptLast(b3, rk[Rounds]).CopyTo(blocks.Slice(offset + 48, BlockSize));
+        }
+
+        for (; offset < blocks.Length; offset += BlockSize)
+        {
+            var b = Vector128.Create<byte>(blocks.Slice(offset, BlockSize)) ^ rk[0];
+            for (var round = 1; round < Rounds; round++)
+                b = X86Aes.Encrypt(b, rk[round]);
+            X86Aes.EncryptLast(b, rk[Rounds]).CopyTo(blocks.Slice(offset, BlockSize));
+        }
+    }
+
+    private static void EncryptArm(Span<byte> blocks, Vector128<byte>[] rk)
+    {
+        // AESE = AddRoundKey + SubBytes + ShiftRows, AESMC = MixColumns; the final
+        // round has no MixColumns and the last key is a plain XOR.
+        var offset = 0;
+        for (; offset + 4 * BlockSize <= blocks.Length; offset += 4 * BlockSize)
+        {
+            var b0 = Vector128.Create<byte>(blocks.Slice(offset, BlockSize));
+            var b1 = Vector128.Create<byte>(blocks.Slice(offset + 16, BlockSize));
+            var b2 = Vector128.Create<byte>(blocks.Slice(offset + 32, BlockSize));
+            var b3 = Vector128.Create<byte>(blocks.Slice(offset + 48, BlockSize));
+            for (var round = 0; round < Rounds - 1; round++)
+            {
+                b0 = ArmAes.MixColumns(ArmAes.Encrypt(b0, rk[round]));
+                b1 = ArmAes.MixColumns(ArmAes.Encrypt(b1, rk[round]));
+                b2 = ArmAes.MixColumns(ArmAes.Encrypt(b2, rk[round]));
+                b3 = ArmAes.MixColumns(ArmAes.Encrypt(b3, rk[round]));
+            }
+            (ArmAes.Encrypt(b0, rk[Rounds - 1]) ^ rk[Rounds]).CopyTo(blocks.Slice(offset, BlockSize));
+            (ArmAes.Encrypt(b1, rk[Rounds - 1]) ^ rk[Rounds]).CopyTo(blocks.Slice(offset + 16, BlockSize));
+            (ArmAes.Encrypt(b2, rk[Rounds - 1]) ^ rk[Rounds]).CopyTo(blocks.Slice(offset + 32, BlockSize));
+            (ArmAes.Encrypt(b3, rk[Rounds - 1]) ^ rk[Rounds]).CopyTo(blocks.Slice(offset + 48, BlockSize));
+        }
+
+        for (; offset < blocks.Length; offset += BlockSize)
+        {
+            var b = Vector128.Create<byte>(blocks.Slice(offset, BlockSize));
+            for (var round = 0; round < Rounds - 1; round++)
+                b = ArmAes.MixColumns(ArmAes.Encrypt(b, rk[round]));
+            (ArmAes.Encrypt(b, rk[Rounds - 1]) ^ rk[Rounds]).CopyTo(blocks.Slice(offset, BlockSize));
+        }
+    }
+
+    /// <summary>
+    /// FIPS-197 AES-128 key expansion; round keys in the byte order both instruction sets expect.
+    /// </summary>
+    private static Vector128<byte>[] ExpandKey(ReadOnlySpan<byte> key)
+    {
+        Span<uint> w = stackalloc uint[4 * (Rounds + 1)];
+        for (var i = 0; i < 4; i++)
+            w[i] = BinaryPrimitives.ReadUInt32BigEndian(key[(i * 4)..]);
+
+        uint rcon = 0x01;
+        for (var i = 4; i < w.Length; i++)
+        {
+            var temp = w[i - 1];
+            if (i % 4 == 0)
+            {
+                temp = SubWord((temp << 8) | (temp >> 24)) ^ (rcon << 24);
+                rcon = (rcon << 1) ^ ((rcon & 0x80) != 0 ? 0x11bu : 0u);
+            }
+            w[i] = w[i - 4] ^ temp;
+        }
+
+        var roundKeys = new Vector128<byte>[Rounds + 1];
+        Span<byte> bytes = stackalloc byte[BlockSize];
+        for (var round = 0; round <= Rounds; round++)
+        {
+            for (var j = 0; j < 4; j++)
+                BinaryPrimitives.WriteUInt32BigEndian(bytes[(j * 4)..], w[round * 4 + j]);
+            roundKeys[round] = Vector128.Create<byte>(bytes);
+        }
+
+        CryptographicOperations.ZeroMemory(bytes);
+        CryptographicOperations.ZeroMemory(System.Runtime.InteropServices.MemoryMarshal.AsBytes(w));
+        return roundKeys;
+    }
+
+    private static uint SubWord(uint word) =>
+        (uint)SBox[(int)(word >> 24)] << 24
+        | (uint)SBox[(int)((word >> 16) & 0xff)] << 16
+        | (uint)SBox[(int)((word >> 8) & 0xff)] << 8
+        | SBox[(int)(word & 0xff)];
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    private static void Xor(Span<byte> destination, ReadOnlySpan<byte> keystream)
+    {
+        var i = 0;
+        for (; i + Vector128<byte>.Count <= destination.Length; i += Vector128<byte>.Count)
+        {
+            var value = Vector128.Create<byte>(destination[i..]) ^ Vector128.Create<byte>(keystream[i..]);
+            value.CopyTo(destination[i..]);
+        }
+
+        for (; i < destination.Length; i++)
+            destination[i] ^= keystream[i];
+    }
+}
@@ -0,0 +1,25 @@
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Thrown by <see cref="CachedAudioStream"/> when a chunk is missing or fails its checksum.
+/// The file has already been dropped from the cache, so resolving it again downloads a fresh copy.
+/// </summary>
+public sealed class AudioCacheCorruptException : IOException
+{
+    public AudioCacheCorruptException(string fileIdHex, int chunkIndex, string message)
+        : base(message)
+    {
+        FileIdHex = fileIdHex;
+        ChunkIndex = chunkIndex;
+    }
+
+    /// <summary>
+    /// Spotify file id (40-char hex) of the corrupt file.
+    /// </summary>
+    public string FileIdHex { get; }
+
+    /// <summary>
+    /// Index of the chunk that failed verification.
+    /// </summary>
+    public int ChunkIndex { get; }
+}
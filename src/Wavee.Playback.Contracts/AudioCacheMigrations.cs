@@ -0,0 +1,167 @@
+using System.Globalization;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Format version stamp and in-place upgrades for the on-disk audio cache.
+/// </summary>
+/// <remarks>
+/// The version lives in <c>$cacheDir/audio/format-version</c>. A cache without a stamp is
+/// either brand new (stamped at <see cref="CurrentVersion"/>) or predates stamping (treated as v1).
+/// Each step is resumable and the stamp is only advanced after a step finishes, so a crash
+/// mid-upgrade picks up where it stopped on the next start. A cache stamped by a newer build
+/// is left untouched.
+/// </remarks>
+public static class AudioCacheMigrations
+{
+    // v1: one {fileId}.enc file per track, no checksums.
+    // v2: content-addressed chunks + {fileId}.manifest (see AudioFileCache).
+    public const int CurrentVersion = 2;
+
+    private const string VersionFileName = "format-version";
+    private const string LockFileName = ".migration.lock";
+
+    /// <summary>
+    /// Additive list of upgrade steps. Each step returns the number of files it converted.
+    /// Never edit a shipped step; add a new one and bump <see cref="CurrentVersion"/>.
+    /// </summary>
+    private static readonly IReadOnlyList<AudioCacheMigration> Migrations =
+    [
+        new AudioCacheMigration(1, 2, (cacheDirectory, ct) =>
+        {
+            var converted = 0;
+            foreach (var fileId in AudioFileCache.EnumerateLegacyFileIds(cacheDirectory).ToList())
+            {
+                ct.ThrowIfCancellationRequested();
+                if (AudioFileCache.TryImportLegacyFile(cacheDirectory, fileId))
+                    converted++;
+            }
+            return converted;
+        }),
+    ];
+
+    private sealed record AudioCacheMigration(int FromVersion, int ToVersion, Func<string, CancellationToken, int> Apply);
+
+    /// <summary>
+    /// Returns the cache's format version, or 0 when the directory holds no cache yet.
+    /// </summary>
+    public static int GetVersion(string cacheDirectory)
+    {
+        var stampPath = GetVersionPath(cacheDirectory);
+        if (File.Exists(stampPath))
+        {
+            return int.TryParse(File.ReadAllText(stampPath).Trim(), NumberStyles.None, CultureInfo.InvariantCulture, out var version)
+                ? version
+                : 1;
+        }
+
+        return AudioFileCache.EnumerateLegacyFileIds(cacheDirectory).Any() ? 1 : 0;
+    }
+
+    /// <summary>
+    /// Brings the cache up to <see cref="CurrentVersion"/>. Safe to call from both processes:
+    /// whoever gets the lock migrates, the other returns immediately.
+    /// </summary>
+    /// <param name="cacheDirectory">Root cache directory (the one containing <c>audio/</c>).</param>
+    /// <param name="logger">Optional logger.</param>
+    /// <param name="ct">Cancellation token; a cancelled run resumes next time.</param>
+    /// <returns>What was done.</returns>
+    public static AudioCacheMigrationResult Migrate(string cacheDirectory, ILogger? logger = null, CancellationToken ct = default)
+    {
+        AudioFileCache.EnsureDirectoryExists(cacheDirectory);
+
+        FileStream lockFile;
+        try
+        {
+            lockFile = new FileStream(Path.Combine(cacheDirectory, "audio", LockFileName),
+                FileMode.OpenOrCreate, FileAccess.ReadWrite, FileShare.None, 1, FileOptions.DeleteOnClose);
+        }
+        catch (IOException)
+        {
+            logger?.LogDebug("Audio cache migration already running in another process");
+            return new AudioCacheMigrationResult(AudioCacheMigrationStatus.Busy, 0, 0, 0);
+        }
+
+        using (lockFile)
+        {
+            var version = GetVersion(cacheDirectory);
+            if (version == 0)
+            {
+                WriteVersion(cacheDirectory, CurrentVersion);
+                return new AudioCacheMigrationResult(AudioCacheMigrationStatus.Created, 0, CurrentVersion, 0);
+            }
+
+            if (version > CurrentVersion)
+            {
+                logger?.LogWarning(
+                    "Audio cache is at format v{Actual}, this build supports up to v{Supported}; leaving it as is",
+                    version, CurrentVersion);
+                return new AudioCacheMigrationResult(AudioCacheMigrationStatus.Downgrade, version, version, 0);
+            }
+
+            if (version == CurrentVersion)
+                return new AudioCacheMigrationResult(AudioCacheMigrationStatus.UpToDate, version, version, 0);
+
+            var from = version;
+            var files = 0;
+            foreach (var step in Migrations
+                         .Where(m => m.FromVersion >= version)
+                         .OrderBy(m => m.FromVersion))
+            {
+                var converted = step.Apply(cacheDirectory, ct);
+                WriteVersion(cacheDirectory, step.ToVersion);
+                version = step.ToVersion;
+                files += converted;
+                logger?.LogInformation(
+                    "Applied audio cache migration v{From} → v{To} ({Files} files)",
+                    step.FromVersion, step.ToVersion, converted);
+            }
+
+            return new AudioCacheMigrationResult(AudioCacheMigrationStatus.Migrated, from, version, files);
+        }
+    }
+
+    private static string GetVersionPath(string cacheDirectory)
+        => Path.Combine(cacheDirectory, "audio", VersionFileName);
+
+    private static void WriteVersion(string cacheDirectory, int version)
+    {
+        AudioFileCache.WriteDurably(GetVersionPath(cacheDirectory),
+            System.Text.Encoding.UTF8.GetBytes(version.ToString(CultureInfo.InvariantCulture)));
+    }
+}
+
+/// <summary>
+/// Outcome of <see cref="AudioCacheMigrations.Migrate"/>.
+/// </summary>
+public enum AudioCacheMigrationStatus
+{
+    /// <summary>Already at the current version.</summary>
+    UpToDate,
+
+    /// <summary>Empty cache, stamped at the current version.</summary>
+    Created,
+
+    /// <summary>One or more upgrade steps ran.</summary>
+    Migrated,
+
+    /// <summary>Another process holds the migration lock.</summary>
+    Busy,
+
+    /// <summary>Written by a newer build; left untouched.</summary>
+    Downgrade
+}
+
+/// <summary>
+/// Result of <see cref="AudioCacheMigrations.Migrate"/>.
+/// </summary>
+/// <param name="Status">What happened.</param>
+/// <param name="FromVersion">Version found on disk.</param>
+/// <param name="ToVersion">Version on disk afterwards.</param>
+/// <param name="FilesMigrated">Files converted by the upgrade steps.</param>
+public sealed record AudioCacheMigrationResult(
+    AudioCacheMigrationStatus Status,
+    int FromVersion,
+    int ToVersion,
+    int FilesMigrated);
@@ -0,0 +1,150 @@
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Writes a file into the audio cache as content-addressed chunks. Create with
+/// <see cref="AudioFileCache.BeginWrite"/>.
+/// </summary>
+/// <remarks>
+/// Chunks are written as soon as they fill; the manifest is written last by <see cref="Commit"/>,
+/// so a crash mid-write leaves no readable entry. Chunks orphaned that way are collected by
+/// <see cref="AudioFileCache.Prune"/>, which leaves alone the chunks of a writer that is still open.
+/// </remarks>
+public sealed class AudioCacheWriter : IDisposable
+{
+    private readonly string _cacheDirectory;
+    private readonly string _fileIdHex;
+    private readonly byte[] _buffer = SlabBufferPool.Chunks.Rent(AudioFileCache.ChunkSize);
+    private readonly List<string> _chunkHashes = [];
+    private int _buffered;
+    private long _length;
+    private bool _committed;
+    private bool _released;
+    private bool _disposed;
+
+    internal AudioCacheWriter(string cacheDirectory, string fileIdHex)
+    {
+        _cacheDirectory = cacheDirectory;
+        _fileIdHex = fileIdHex;
+    }
+
+    /// <summary>
+    /// Bytes written so far.
+    /// </summary>
+    public long Length => _length;
+
+    /// <summary>
+    /// Appends encrypted file bytes.
+    /// </summary>
+    public void Write(ReadOnlySpan<byte> data)
+    {
+        ObjectDisposedException.ThrowIf(_committed, this);
+
+        while (!data.IsEmpty)
+        {
+            var count = Math.Min(data.Length, AudioFileCache.ChunkSize - _buffered);
+            data[..count].CopyTo(_buffer.AsSpan(_buffered));
+            _buffered += count;
+            _length += count;
+            data = data[count..];
+
+            if (_buffered == AudioFileCache.ChunkSize)
+                FlushChunk();
+        }
+    }
+
+    /// <summary>
+    /// Writes the last partial chunk and the manifest, making the file visible to readers.
+    /// </summary>
+    /// <returns>Total file size.</returns>
+    public long Commit()
+    {
+        ObjectDisposedException.ThrowIf(_committed, this);
+        if (_length == 0)
+            throw new InvalidOperationException("Cannot commit an empty audio file");
+
+        if (_buffered > 0)
+            FlushChunk();
+
+        AudioFileCache.WriteManifest(_cacheDirectory, _fileIdHex,
+            new CacheManifest(_length, AudioFileCache.ChunkSize, _chunkHashes));
+        _committed = true;
+        ReleaseChunks();
+        return _length;
+    }
+
+    public void Dispose()
+    {
+        if (_disposed)
+            return;
+
+        // Uncommitted chunks stay on disk until pruning; they may already be shared.
+        _committed = true;
+        _disposed = true;
+        ReleaseChunks();
+        SlabBufferPool.Chunks.Return(_buffer);
+    }
+
+    private void ReleaseChunks()
+    {
+        if (_released)
+            return;
+
+        _released = true;
+        AudioFileCache.ReleaseChunks(_chunkHashes);
+    }
+
+    private void FlushChunk()
+    {
+        var chunk = _buffer.AsSpan(0, _buffered);
+        var hash = AudioFileCache.HashChunk(chunk);
+        var path = AudioFileCache.GetChunkPath(_cacheDirectory, hash);
+
+        // Held before the reuse check, so pruning can't delete it between the two.
+        AudioFileCache.HoldChunk(hash);
+        _chunkHashes.Add(hash);
+
+        // Same content, same name: an existing chunk is reused unless it has rotted. A reused
+        // chunk is touched so pruning in another process sees it as recently written.
+        if (IsIntact(path, hash))
+        {
+            TryTouch(path);
+        }
+        else
+        {
+            Directory.CreateDirectory(Path.GetDirectoryName(path)!);
+            var swapPath = path + "." + Guid.NewGuid().ToString("N") + ".tmp";
+            using (var stream = new FileStream(swapPath, FileMode.CreateNew, FileAccess.Write, FileShare.None))
+                stream.Write(chunk);
+            File.Move(swapPath, path, overwrite: true);
+        }
+
+        _buffered = 0;
+    }
+
+    private static void TryTouch(string path)
+    {
+        try
+        {
+            File.SetLastWriteTimeUtc(path, DateTime.UtcNow);
+        }
+        catch (IOException)
+        {
+        }
+        catch (UnauthorizedAccessException)
+        {
+        }
+    }
+
+    private static bool IsIntact(string path, string hash)
+    {
+        try
+        {
+            return File.Exists(path)
+                   && string.Equals(AudioFileCache.HashChunk(File.ReadAllBytes(path)), hash, StringComparison.OrdinalIgnoreCase);
+        }
+        catch (IOException)
+        {
+            return false;
+        }
+    }
+}
@@ -1,3 +1,6 @@
+using System.Globalization;
+using System.Security.Cryptography;
+
 namespace Wavee.Playback.Contracts;
 
 /// <summary>
@@ -5,52 +8,139 @@ namespace Wavee.Playback.Contracts;
 /// Both the UI process (to check before resolving CDN) and AudioHost (to write after download)
 /// use these paths so they agree on where cached files live.
 /// </summary>
+/// <remarks>
+/// Layout under <c>$cacheDir/audio</c>:
+/// <list type="bullet">
+///   <item><c>{fileId}.manifest</c> — file size, chunk size and the ordered chunk hashes.</item>
+///   <item><c>chunks/{aa}/{sha256}.chunk</c> — up to <see cref="ChunkSize"/> encrypted bytes, named by
+///   the SHA-256 of their content. Identical chunks are stored once.</item>
+/// </list>
+/// Every chunk is re-hashed when read (<see cref="CachedAudioStream"/>), so a bit flipped on disk
+/// surfaces as <see cref="AudioCacheCorruptException"/> and a cache miss instead of garbled audio.
+/// Older layouts are upgraded by <see cref="AudioCacheMigrations"/>; until that has run, a
+/// single-file <c>{fileId}.enc</c> is converted in the background when first looked up, or
+/// inline by <see cref="OpenRead"/>.
+/// </remarks>
 public static class AudioFileCache
 {
+    /// <summary>Size of each content-addressed chunk.</summary>
+    public const int ChunkSize = 256 * 1024;
+
+    /// <summary>
+    /// Files at least this large are read through memory-mapped chunks by default (see
+    /// <see cref="OpenRead"/>). Below it the per-chunk buffer is cheaper than the mapping.
+    /// </summary>
+    public const long MemoryMapThreshold = 32L * 1024 * 1024;
+
     private const string AudioSubDir = "audio";
-    private const string CacheExtension = ".enc";
+    private const string ChunkSubDir = "chunks";
+    private const string ManifestExtension = ".manifest";
+    private const string ChunkExtension = ".chunk";
+    private const string LegacyExtension = ".enc";
+    private const string ManifestHeader = "wavee-audio-manifest 1";
 
     /// <summary>
-    /// Returns the full path of the persistent cache file for a given Spotify audio file ID.
-    /// The file is stored encrypted (the same Spotify OGG encryption used on the CDN);
-    /// the audio key is still required to decrypt it during playback.
+    /// Chunks written or reused more recently than this are never collected: they may belong
+    /// to a writer in another process that hasn't committed its manifest yet.
     /// </summary>
-    public static string GetCachedFilePath(string cacheDirectory, string fileIdHex)
-        => Path.Combine(cacheDirectory, AudioSubDir, fileIdHex + CacheExtension);
+    internal static readonly TimeSpan UncommittedChunkGrace = TimeSpan.FromHours(1);
+
+    // Chunks held by writers in this process, with the number of writers holding each.
+    private static readonly Dictionary<string, int> PendingChunks = new(StringComparer.OrdinalIgnoreCase);
+    private static readonly HashSet<string> ScheduledImports = new(StringComparer.OrdinalIgnoreCase);
+    private static readonly object LegacyImportLock = new();
+
+    /// <summary>
+    /// Returns the path of the manifest describing the cached file for a given Spotify audio file ID.
+    /// The chunks are stored encrypted (the same Spotify OGG encryption used on the CDN);
+    /// the audio key is still required to decrypt them during playback.
+    /// </summary>
+    public static string GetManifestPath(string cacheDirectory, string fileIdHex)
+        => Path.Combine(cacheDirectory, AudioSubDir, fileIdHex + ManifestExtension);
 
     /// <summary>
     /// Returns true when the audio file for <paramref name="fileIdHex"/> is fully cached on disk.
+    /// Chunk contents are verified when read, not here.
     /// </summary>
     public static bool IsCached(string cacheDirectory, string fileIdHex)
+        => GetCachedFileSize(cacheDirectory, fileIdHex) > 0;
+
+    /// <summary>
+    /// Returns the size (bytes) of the cached file, or 0 if it is not cached.
+    /// </summary>
+    /// <remarks>
+    /// Callers are on the track resolution path, so a legacy <c>{fileId}.enc</c> is reported
+    /// as cached and converted in the background rather than here.
+    /// </remarks>
+    public static long GetCachedFileSize(string cacheDirectory, string fileIdHex)
     {
         if (string.IsNullOrEmpty(cacheDirectory) || string.IsNullOrEmpty(fileIdHex))
-            return false;
+            return 0;
 
-        var path = GetCachedFilePath(cacheDirectory, fileIdHex);
-        if (!File.Exists(path))
-            return false;
+        var manifestPath = GetManifestPath(cacheDirectory, fileIdHex);
+        if (!File.Exists(manifestPath))
+        {
+            var legacy = new FileInfo(GetLegacyFilePath(cacheDirectory, fileIdHex));
+            if (!legacy.Exists)
+                return 0;
 
-        var info = new FileInfo(path);
-        if (info.Length <= 0)
-            return false;
+            ScheduleLegacyImport(cacheDirectory, fileIdHex);
+            return legacy.Length;
+        }
+
+        var manifest = TryReadManifest(manifestPath);
+        if (manifest == null)
+        {
+            TryDeleteFile(manifestPath);
+            return 0;
+        }
 
-        TryTouch(info);
-        return true;
+        TryTouch(new FileInfo(manifestPath));
+        return manifest.FileSize;
     }
 
     /// <summary>
-    /// Returns the size (bytes) of the cached file, or 0 if it is not cached.
+    /// Opens the cached file for reading. Each chunk is verified against its hash as it is read.
     /// </summary>
-    public static long GetCachedFileSize(string cacheDirectory, string fileIdHex)
+    /// <param name="cacheDirectory">Root cache directory.</param>
+    /// <param name="fileIdHex">Spotify audio file ID.</param>
+    /// <param name="memoryMapped">
+    /// Read chunks through a read-only memory mapping instead of a private buffer. Null picks
+    /// mapping for files of at least <see cref="MemoryMapThreshold"/> in 64-bit processes.
+    /// </param>
+    /// <exception cref="FileNotFoundException">The file is not cached.</exception>
+    public static CachedAudioStream OpenRead(string cacheDirectory, string fileIdHex, bool? memoryMapped = null)
     {
-        if (string.IsNullOrEmpty(cacheDirectory) || string.IsNullOrEmpty(fileIdHex))
-            return 0;
+        TryImportLegacyFile(cacheDirectory, fileIdHex);
+
+        var manifestPath = GetManifestPath(cacheDirectory, fileIdHex);
+        var manifest = File.Exists(manifestPath) ? TryReadManifest(manifestPath) : null;
+        if (manifest == null)
+            throw new FileNotFoundException($"Audio file {fileIdHex} is not cached", manifestPath);
 
-        var path = GetCachedFilePath(cacheDirectory, fileIdHex);
-        if (!File.Exists(path)) return 0;
-        var info = new FileInfo(path);
-        TryTouch(info);
-        return info.Length;
+        TryTouch(new FileInfo(manifestPath));
+        var mapped = memoryMapped ?? (Environment.Is64BitProcess && manifest.FileSize >= MemoryMapThreshold);
+        return new CachedAudioStream(cacheDirectory, fileIdHex, manifest, mapped);
+    }
+
+    /// <summary>
+    /// Starts writing a file into the cache. Nothing is visible to readers until
+    /// <see cref="AudioCacheWriter.Commit"/> writes the manifest.
+    /// </summary>
+    public static AudioCacheWriter BeginWrite(string cacheDirectory, string fileIdHex)
+    {
+        EnsureDirectoryExists(cacheDirectory);
+        return new AudioCacheWriter(cacheDirectory, fileIdHex);
+    }
+
+    /// <summary>
+    /// Removes a file from the cache. Its chunks are left for <see cref="Prune"/> to collect,
+    /// since other files may share them.
+    /// </summary>
+    public static void Delete(string cacheDirectory, string fileIdHex)
+    {
+        TryDeleteFile(GetManifestPath(cacheDirectory, fileIdHex));
+        TryDeleteFile(GetLegacyFilePath(cacheDirectory, fileIdHex));
     }
 
     /// <summary>
@@ -58,11 +148,290 @@ public static class AudioFileCache
     /// </summary>
     public static void EnsureDirectoryExists(string cacheDirectory)
     {
-        var dir = Path.Combine(cacheDirectory, AudioSubDir);
+        var dir = Path.Combine(cacheDirectory, AudioSubDir, ChunkSubDir);
         if (!Directory.Exists(dir))
             Directory.CreateDirectory(dir);
     }
 
+    /// <summary>
+    /// Drops least recently used files until the cache fits <paramref name="maxBytes"/>, then deletes
+    /// chunks no remaining manifest references. Chunks an uncommitted <see cref="AudioCacheWriter"/>
+    /// may still need are kept (see <see cref="UncommittedChunkGrace"/>).
+    /// </summary>
+    /// <param name="cacheDirectory">Root cache directory.</param>
+    /// <param name="maxBytes">Size budget, counted as the sum of cached file sizes.</param>
+    /// <param name="protectedFileIdHex">File that must survive, typically the one just written.</param>
+    /// <returns>File IDs that were dropped.</returns>
+    public static IReadOnlyList<string> Prune(string cacheDirectory, long maxBytes, string? protectedFileIdHex = null)
+    {
+        var audioDir = Path.Combine(cacheDirectory, AudioSubDir);
+        if (!Directory.Exists(audioDir))
+            return [];
+
+        var manifests = new List<(FileInfo Info, string FileId, CacheManifest Manifest)>();
+        foreach (var info in new DirectoryInfo(audioDir).EnumerateFiles("*" + ManifestExtension, SearchOption.TopDirectoryOnly))
+        {
+            if (TryReadManifest(info.FullName) is { } manifest)
+                manifests.Add((info, Path.GetFileNameWithoutExtension(info.Name), manifest));
+        }
+
+        var totalBytes = manifests.Sum(m => m.Manifest.FileSize);
+        var pruned = new List<string>();
+        foreach (var (info, fileId, manifest) in manifests
+                     .Where(m => !string.Equals(m.FileId, protectedFileIdHex, StringComparison.OrdinalIgnoreCase))
+                     .OrderBy(m => EffectiveLastAccessUtc(m.Info)))
+        {
+            if (totalBytes <= maxBytes)
+                break;
+
+            if (!TryDeleteFile(info.FullName))
+                continue;
+
+            totalBytes -= manifest.FileSize;
+            pruned.Add(fileId);
+        }
+
+        if (pruned.Count > 0)
+            CollectUnreferencedChunks(cacheDirectory);
+
+        return pruned;
+    }
+
+    /// <summary>
+    /// Keeps <paramref name="chunkHash"/> from being collected until <see cref="ReleaseChunks"/>.
+    /// </summary>
+    internal static void HoldChunk(string chunkHash)
+    {
+        lock (PendingChunks)
+            PendingChunks[chunkHash] = PendingChunks.GetValueOrDefault(chunkHash) + 1;
+    }
+
+    internal static void ReleaseChunks(IEnumerable<string> chunkHashes)
+    {
+        lock (PendingChunks)
+        {
+            foreach (var hash in chunkHashes)
+            {
+                if (PendingChunks.TryGetValue(hash, out var holders) && holders > 1)
+                    PendingChunks[hash] = holders - 1;
+                else
+                    PendingChunks.Remove(hash);
+            }
+        }
+    }
+
+    internal static string GetChunkPath(string cacheDirectory, string chunkHash)
+        => Path.Combine(cacheDirectory, AudioSubDir, ChunkSubDir, chunkHash[..2], chunkHash + ChunkExtension);
+
+    internal static string HashChunk(ReadOnlySpan<byte> chunk)
+        => Convert.ToHexStringLower(SHA256.HashData(chunk));
+
+    internal static void WriteManifest(string cacheDirectory, string fileIdHex, CacheManifest manifest)
+    {
+        var path = GetManifestPath(cacheDirectory, fileIdHex);
+        var lines = new List<string>(manifest.ChunkHashes.Count + 3)
+        {
+            ManifestHeader,
+            "size " + manifest.FileSize.ToString(CultureInfo.InvariantCulture),
+            "chunk " + manifest.ChunkSize.ToString(CultureInfo.InvariantCulture)
+        };
+        lines.AddRange(manifest.ChunkHashes);
+
+        // Chunks aren't fsynced: one lost to a power cut fails its checksum on read.
+        // The manifest is, so it never points at a file that was only half committed.
+        WriteDurably(path, System.Text.Encoding.UTF8.GetBytes(string.Join('\n', lines) + "\n"));
+        File.SetLastAccessTimeUtc(path, DateTime.UtcNow);
+    }
+
+    /// <summary>
+    /// Temp file + fsync + rename, so <paramref name="path"/> is either the old or the new contents.
+    /// </summary>
+    internal static void WriteDurably(string path, ReadOnlySpan<byte> contents)
+    {
+        var swapPath = path + ".tmp";
+        using (var stream = new FileStream(swapPath, FileMode.Create, FileAccess.Write, FileShare.None))
+        {
+            stream.Write(contents);
+            stream.Flush(flushToDisk: true);
+        }
+        File.Move(swapPath, path, overwrite: true);
+    }
+
+    internal static CacheManifest? TryReadManifest(string manifestPath)
+    {
+        try
+        {
+            var lines = File.ReadAllLines(manifestPath);
+            if (lines.Length < 3
+                || lines[0] != ManifestHeader
+                || !lines[1].StartsWith("size ", StringComparison.Ordinal)
+                || !lines[2].StartsWith("chunk ", StringComparison.Ordinal)
+                || !long.TryParse(lines[1].AsSpan(5), NumberStyles.None, CultureInfo.InvariantCulture, out var size)
+                || !int.TryParse(lines[2].AsSpan(6), NumberStyles.None, CultureInfo.InvariantCulture, out var chunkSize)
+                || size <= 0
+                || chunkSize <= 0)
+            {
+                return null;
+            }
+
+            var hashes = lines[3..];
+            if (hashes.Length != (int)((size + chunkSize - 1) / chunkSize)
+                || hashes.Any(h => h.Length != 64))
+            {
+                return null;
+            }
+
+            return new CacheManifest(size, chunkSize, hashes);
+        }
+        catch (IOException)
+        {
+            return null;
+        }
+        catch (UnauthorizedAccessException)
+        {
+            return null;
+        }
+    }
+
+    internal static bool TryDeleteFile(string path)
+    {
+        try
+        {
+            if (File.Exists(path))
+                File.Delete(path);
+            return true;
+        }
+        catch (IOException)
+        {
+            return false;
+        }
+        catch (UnauthorizedAccessException)
+        {
+            return false;
+        }
+    }
+
+    private static string GetLegacyFilePath(string cacheDirectory, string fileIdHex)
+        => Path.Combine(cacheDirectory, AudioSubDir, fileIdHex + LegacyExtension);
+
+    internal static IEnumerable<string> EnumerateLegacyFileIds(string cacheDirectory)
+    {
+        var audioDir = Path.Combine(cacheDirectory, AudioSubDir);
+        return Directory.Exists(audioDir)
+            ? Directory.EnumerateFiles(audioDir, "*" + LegacyExtension, SearchOption.TopDirectoryOnly)
+                .Select(Path.GetFileNameWithoutExtension)
+                .OfType<string>()
+            : [];
+    }
+
+    /// <summary>
+    /// Converts a <c>{fileId}.enc</c> file from the single-file layout into chunks. The old
+    /// layout carries no checksums, so its bytes are taken as-is.
+    /// </summary>
+    /// <returns>True when the legacy file was converted (or was redundant) and removed.</returns>
+    internal static bool TryImportLegacyFile(string cacheDirectory, string fileIdHex)
+    {
+        var legacyPath = GetLegacyFilePath(cacheDirectory, fileIdHex);
+        if (!File.Exists(legacyPath))
+            return false;
+
+        // Serialised so a background import and OpenRead never write the same manifest at once.
+        lock (LegacyImportLock)
+            return ImportLegacyFile(cacheDirectory, fileIdHex, legacyPath);
+    }
+
+    private static void ScheduleLegacyImport(string cacheDirectory, string fileIdHex)
+    {
+        var key = Path.Combine(cacheDirectory, fileIdHex);
+        lock (ScheduledImports)
+        {
+            if (!ScheduledImports.Add(key))
+                return;
+        }
+
+        _ = Task.Run(() =>
+        {
+            try
+            {
+                TryImportLegacyFile(cacheDirectory, fileIdHex);
+            }
+            finally
+            {
+                lock (ScheduledImports)
+                    ScheduledImports.Remove(key);
+            }
+        });
+    }
+
+    private static bool ImportLegacyFile(string cacheDirectory, string fileIdHex, string legacyPath)
+    {
+        if (!File.Exists(legacyPath))
+            return false;
+
+        try
+        {
+            if (!File.Exists(GetManifestPath(cacheDirectory, fileIdHex)) && new FileInfo(legacyPath).Length > 0)
+            {
+                using var writer = BeginWrite(cacheDirectory, fileIdHex);
+                using (var source = new FileStream(legacyPath, FileMode.Open, FileAccess.Read, FileShare.Read,
+                           81920, FileOptions.SequentialScan))
+                {
+                    var buffer = new byte[81920];
+                    int read;
+                    while ((read = source.Read(buffer)) > 0)
+                        writer.Write(buffer.AsSpan(0, read));
+                }
+                writer.Commit();
+            }
+
+            File.Delete(legacyPath);
+            return true;
+        }
+        catch (IOException)
+        {
+            // Another process may be converting or reading it; try again next access.
+            return false;
+        }
+        catch (UnauthorizedAccessException)
+        {
+            return false;
+        }
+    }
+
+    private static void CollectUnreferencedChunks(string cacheDirectory)
+    {
+        var chunkDir = Path.Combine(cacheDirectory, AudioSubDir, ChunkSubDir);
+        if (!Directory.Exists(chunkDir))
+            return;
+
+        var referenced = new HashSet<string>(StringComparer.OrdinalIgnoreCase);
+        foreach (var manifestPath in Directory.EnumerateFiles(Path.Combine(cacheDirectory, AudioSubDir), "*" + ManifestExtension))
+        {
+            if (TryReadManifest(manifestPath) is { } manifest)
+                referenced.UnionWith(manifest.ChunkHashes);
+        }
+
+        var graceCutoff = DateTime.UtcNow - UncommittedChunkGrace;
+        foreach (var chunkPath in Directory.EnumerateFiles(chunkDir, "*" + ChunkExtension, SearchOption.AllDirectories))
+        {
+            var hash = Path.GetFileNameWithoutExtension(chunkPath);
+            if (referenced.Contains(hash) || File.GetLastWriteTimeUtc(chunkPath) > graceCutoff)
+                continue;
+
+            // Checked and deleted under the lock so a writer can't start reusing it in between.
+            lock (PendingChunks)
+            {
+                if (!PendingChunks.ContainsKey(hash))
+                    TryDeleteFile(chunkPath);
+            }
+        }
+    }
+
+    private static DateTime EffectiveLastAccessUtc(FileInfo file)
+        => file.LastAccessTimeUtc > DateTime.UnixEpoch
+            ? file.LastAccessTimeUtc
+            : file.LastWriteTimeUtc;
+
     private static void TryTouch(FileInfo info)
     {
         try
@@ -75,3 +444,8 @@ public static class AudioFileCache
         }
     }
 }
+
+/// <summary>
+/// Parsed <c>{fileId}.manifest</c>.
+/// </summary>
+internal sealed record CacheManifest(long FileSize, int ChunkSize, IReadOnlyList<string> ChunkHashes);
@@ -0,0 +1,206 @@
+using System.IO.MemoryMappedFiles;
+
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Read-only, seekable view over a file in the audio cache. Open with <see cref="AudioFileCache.OpenRead"/>.
+/// </summary>
+/// <remarks>
+/// One chunk is held in memory at a time. Each chunk is hashed when loaded and compared with its
+/// content address; on mismatch the chunk and the file's manifest are deleted and
+/// <see cref="AudioCacheCorruptException"/> is thrown, so corruption costs a re-download rather
+/// than feeding bad bytes to the decoder.
+/// <para>
+/// In memory-mapped mode the current chunk is mapped read-only instead of copied into a buffer:
+/// it is hashed straight from the page cache and <see cref="Read(Span{byte})"/> copies from the
+/// mapping into the caller's (decryptor's) buffer. That saves the intermediate copy and the read
+/// syscalls, and the pages stay reclaimable by the OS rather than pinned on the managed heap —
+/// which matters for multi-hour audiobooks on low-memory devices.
+/// </para>
+/// </remarks>
+public sealed unsafe class CachedAudioStream : Stream
+{
+    private readonly string _cacheDirectory;
+    private readonly string _fileIdHex;
+    private readonly CacheManifest _manifest;
+    private readonly byte[]? _chunk;
+    private MemoryMappedFile? _map;
+    private MemoryMappedViewAccessor? _view;
+    private byte* _mapped;
+    private int _chunkIndex = -1;
+    private int _chunkLength;
+    private long _position;
+    private bool _disposed;
+
+    internal CachedAudioStream(string cacheDirectory, string fileIdHex, CacheManifest manifest, bool memoryMapped = false)
+    {
+        _cacheDirectory = cacheDirectory;
+        _fileIdHex = fileIdHex;
+        _manifest = manifest;
+        if (!memoryMapped)
+            _chunk = SlabBufferPool.Chunks.Rent(manifest.ChunkSize);
+    }
+
+    /// <summary>
+    /// True when chunks are read through a memory mapping rather than a private buffer.
+    /// </summary>
+    public bool IsMemoryMapped => _chunk == null;
+
+    public override bool CanRead => !_disposed;
+    public override bool CanSeek => !_disposed;
+    public override bool CanWrite => false;
+    public override long Length => _manifest.FileSize;
+
+    public override long Position
+    {
+        get => _position;
+        set
+        {
+            ArgumentOutOfRangeException.ThrowIfNegative(value);
+            _position = value;
+        }
+    }
+
+    public override int Read(byte[] buffer, int offset, int count)
+        => Read(buffer.AsSpan(offset, count));
+
+    public override int Read(Span<byte> buffer)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        var total = 0;
+        while (!buffer.IsEmpty && _position < _manifest.FileSize)
+        {
+            var index = (int)(_position / _manifest.ChunkSize);
+            if (index != _chunkIndex)
+                LoadChunk(index);
+
+            var offsetInChunk = (int)(_position - (long)index * _manifest.ChunkSize);
+            var count = Math.Min(buffer.Length, _chunkLength - offsetInChunk);
+            CurrentChunk.Slice(offsetInChunk, count).CopyTo(buffer);
+
+            buffer = buffer[count..];
+            _position += count;
+            total += count;
+        }
+
+        return total;
+    }
+
+    public override long Seek(long offset, SeekOrigin origin)
+    {
+        Position = origin switch
+        {
+            SeekOrigin.Begin => offset,
+            SeekOrigin.Current => _position + offset,
+            SeekOrigin.End => _manifest.FileSize + offset,
+            _ => throw new ArgumentOutOfRangeException(nameof(origin))
+        };
+        return _position;
+    }
+
+    public override void Flush() { }
+    public override void SetLength(long value) => throw new NotSupportedException();
+    public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
+
+    protected override void Dispose(bool disposing)
+    {
+        if (!_disposed && _chunk != null)
+            SlabBufferPool.Chunks.Return(_chunk);
+
+        _disposed = true;
+        ReleaseMapping();
+        base.Dispose(disposing);
+    }
+
+    private ReadOnlySpan<byte> CurrentChunk => _chunk != null
+        ? _chunk.AsSpan(0, _chunkLength)
+        : new ReadOnlySpan<byte>(_mapped, _chunkLength);
+
+    private void LoadChunk(int index)
+    {
+        var expectedHash = _manifest.ChunkHashes[index];
+        var expectedLength = (int)Math.Min(_manifest.ChunkSize, _manifest.FileSize - (long)index * _manifest.ChunkSize);
+        var path = AudioFileCache.GetChunkPath(_cacheDirectory, expectedHash);
+
+        _chunkIndex = -1;
+        ReleaseMapping();
+
+        ReadOnlySpan<byte> chunk = default;
+        var wrongLength = false;
+        try
+        {
+            using var stream = new FileStream(path, FileMode.Open, FileAccess.Read, FileShare.Read, 1, FileOptions.SequentialScan);
+            if (stream.Length != expectedLength)
+            {
+                wrongLength = true;
+            }
+            else if (_chunk != null)
+            {
+                stream.ReadExactly(_chunk.AsSpan(0, expectedLength));
+                chunk = _chunk.AsSpan(0, expectedLength);
+            }
+            else
+            {
+                chunk = Map(stream, expectedLength);
+            }
+        }
+        catch (FileNotFoundException)
+        {
+            throw Corrupt(index, path, "is missing");
+        }
+        catch (DirectoryNotFoundException)
+        {
+            throw Corrupt(index, path, "is missing");
+        }
+        catch (EndOfStreamException)
+        {
+            wrongLength = true;
+        }
+
+        if (wrongLength)
+            throw Corrupt(index, path, "has the wrong length");
+
+        if (!string.Equals(AudioFileCache.HashChunk(chunk), expectedHash, StringComparison.OrdinalIgnoreCase))
+            throw Corrupt(index, path, "failed its checksum");
+
+        _chunkIndex = index;
+        _chunkLength = expectedLength;
+    }
+
+    private ReadOnlySpan<byte> Map(FileStream stream, int length)
+    {
+        _map = MemoryMappedFile.CreateFromFile(stream, null, 0, MemoryMappedFileAccess.Read, HandleInheritability.None, leaveOpen: true);
+        _view = _map.CreateViewAccessor(0, length, MemoryMappedFileAccess.Read);
+
+        byte* pointer = null;
+        _view.SafeMemoryMappedViewHandle.AcquirePointer(ref pointer);
+        _mapped = pointer + _view.PointerOffset;
+        return new ReadOnlySpan<byte>(_mapped, length);
+    }
+
+    private void ReleaseMapping()
+    {
+        if (_view != null)
+        {
+            _view.SafeMemoryMappedViewHandle.ReleasePointer();
+            _view.Dispose();
+            _view = null;
+        }
+
+        _mapped = null;
+        _map?.Dispose();
+        _map = null;
+    }
+
+    private AudioCacheCorruptException Corrupt(int index, string chunkPath, string reason)
+    {
+        // Unmap first: Windows refuses to delete a file with a live mapping.
+        _chunkIndex = -1;
+        ReleaseMapping();
+        AudioFileCache.TryDeleteFile(chunkPath);
+        AudioFileCache.TryDeleteFile(AudioFileCache.GetManifestPath(_cacheDirectory, _fileIdHex));
+        return new AudioCacheCorruptException(_fileIdHex, index,
+            $"Cached audio {_fileIdHex} chunk {index} {reason}; dropped from cache");
+    }
+}
@@ -49,6 +49,12 @@ public sealed class PlayResolvedTrackCommand
     [JsonPropertyName("normalizationPeak")]
     public float? NormalizationPeak { get; init; }
 
+    [JsonPropertyName("normalizationAlbumGain")]
+    public float? NormalizationAlbumGain { get; init; }
+
+    [JsonPropertyName("normalizationAlbumPeak")]
+    public float? NormalizationAlbumPeak { get; init; }
+
     [JsonPropertyName("trackUri")]
     public required string TrackUri { get; init; }
 
@@ -172,6 +178,73 @@ public sealed class SetNormalizationCommand
 {
     [JsonPropertyName("enabled")]
     public bool Enabled { get; init; }
+
+    /// <summary>
+    /// Full normalization configuration. When set it replaces the current one
+    /// and <see cref="Enabled"/> is ignored in favour of <see cref="NormalizationSettings.Enabled"/>.
+    /// </summary>
+    [JsonPropertyName("settings")]
+    public NormalizationSettings? Settings { get; init; }
+}
+
+/// <summary>
+/// Which ReplayGain-style value drives loudness normalization.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<NormalizationGainMode>))]
+public enum NormalizationGainMode
+{
+    /// <summary>Level every track independently.</summary>
+    Track,
+
+    /// <summary>Keep relative levels within an album; falls back to track gain when missing.</summary>
+    Album
+}
+
+/// <summary>
+/// How normalization keeps positive gain from clipping.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<NormalizationMethod>))]
+public enum NormalizationMethod
+{
+    /// <summary>Cap the gain so the file's peak stays at or below full scale.</summary>
+    Basic,
+
+    /// <summary>Apply the full gain and catch peaks with a limiter.</summary>
+    Dynamic
+}
+
+/// <summary>
+/// Loudness normalization configuration (librespot-equivalent feature set).
+/// </summary>
+public sealed class NormalizationSettings
+{
+    /// <summary>Loudness Spotify's embedded gain values are computed against.</summary>
+    public const double ReferenceLufs = -14.0;
+
+    [JsonPropertyName("enabled")]
+    public bool Enabled { get; init; } = true;
+
+    [JsonPropertyName("gainMode")]
+    public NormalizationGainMode GainMode { get; init; } = NormalizationGainMode.Track;
+
+    /// <summary>Target integrated loudness. Each dB below <see cref="ReferenceLufs"/> lowers all tracks by 1 dB.</summary>
+    [JsonPropertyName("targetLufs")]
+    public double TargetLufs { get; init; } = ReferenceLufs;
+
+    [JsonPropertyName("method")]
+    public NormalizationMethod Method { get; init; } = NormalizationMethod.Basic;
+
+    /// <summary>Level (dBFS) above which the <see cref="NormalizationMethod.Dynamic"/> limiter engages.</summary>
+    [JsonPropertyName("limiterThresholdDb")]
+    public double LimiterThresholdDb { get; init; } = -2.0;
+
+    /// <summary>How quickly the limiter clamps down on a peak, in milliseconds.</summary>
+    [JsonPropertyName("limiterAttackMs")]
+    public double LimiterAttackMs { get; init; } = 5.0;
+
+    /// <summary>How quickly the limiter lets go after a peak, in milliseconds.</summary>
+    [JsonPropertyName("limiterReleaseMs")]
+    public double LimiterReleaseMs { get; init; } = 100.0;
 }
 
 public sealed class SwitchQualityCommand
@@ -204,6 +277,98 @@ public sealed class EqualizerApplyResult
     public string? Message { get; init; }
 }
 
+/// <summary>
+/// PCM format at a pipeline stage boundary.
+/// </summary>
+public sealed class PcmFormatInfo
+{
+    [JsonPropertyName("sampleRate")]
+    public int SampleRate { get; init; }
+
+    [JsonPropertyName("channels")]
+    public int Channels { get; init; }
+
+    [JsonPropertyName("bitsPerSample")]
+    public int BitsPerSample { get; init; }
+}
+
+/// <summary>
+/// One stage of the active audio pipeline.
+/// </summary>
+public sealed class PipelineStageInfo
+{
+    /// <summary>fetcher, decryptor, decoder, processor or sink.</summary>
+    [JsonPropertyName("kind")]
+    public required string Kind { get; init; }
+
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    [JsonPropertyName("enabled")]
+    public bool Enabled { get; init; } = true;
+
+    /// <summary>PCM format the stage emits; null for stages that carry encoded bytes.</summary>
+    [JsonPropertyName("format")]
+    public PcmFormatInfo? Format { get; init; }
+
+    /// <summary>Bytes held or fetched by the stage, where it has a buffer.</summary>
+    [JsonPropertyName("bufferedBytes")]
+    public long? BufferedBytes { get; init; }
+
+    /// <summary>Audio held by the stage in milliseconds, where it has a PCM buffer.</summary>
+    [JsonPropertyName("bufferedMs")]
+    public int? BufferedMs { get; init; }
+
+    [JsonPropertyName("detail")]
+    public string? Detail { get; init; }
+}
+
+/// <summary>
+/// Snapshot of the active chain (fetcher → decryptor → decoder → DSP → sink),
+/// returned for <see cref="IpcMessageTypes.GetPipelineInfo"/>. Empty when idle.
+/// </summary>
+public sealed class PipelineInfo
+{
+    [JsonPropertyName("trackUri")]
+    public string? TrackUri { get; init; }
+
+    [JsonPropertyName("stages")]
+    public PipelineStageInfo[] Stages { get; init; } = [];
+
+    /// <summary>Usage of the packet and chunk buffer pools since AudioHost started; reported even when idle.</summary>
+    [JsonPropertyName("bufferPools")]
+    public BufferPoolInfo[] BufferPools { get; init; } = [];
+}
+
+/// <summary>
+/// Counters for one <see cref="SlabBufferPool"/>.
+/// </summary>
+public sealed class BufferPoolInfo
+{
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    [JsonPropertyName("rents")]
+    public long Rents { get; init; }
+
+    [JsonPropertyName("hits")]
+    public long Hits { get; init; }
+
+    [JsonPropertyName("misses")]
+    public long Misses { get; init; }
+
+    /// <summary>Rents larger than the biggest slab, served by the shared pool.</summary>
+    [JsonPropertyName("oversize")]
+    public long Oversize { get; init; }
+
+    /// <summary>Fraction of rents served without allocating.</summary>
+    [JsonPropertyName("hitRate")]
+    public double HitRate { get; init; }
+
+    [JsonPropertyName("retainedBytes")]
+    public long RetainedBytes { get; init; }
+}
+
 /// <summary>
 /// Switch the local PortAudio output device to the one at the given index.
 /// </summary>
@@ -213,6 +378,110 @@ public sealed class SwitchAudioOutputCommand
     public int DeviceIndex { get; init; }
 }
 
+/// <summary>
+/// Route content kinds to specific output devices by name, evaluated at the
+/// start of each track. Null (or a device that isn't present) plays on the
+/// system default. Both null clears routing.
+/// </summary>
+public sealed class SetAudioRoutingCommand
+{
+    [JsonPropertyName("musicDeviceName")]
+    public string? MusicDeviceName { get; init; }
+
+    [JsonPropertyName("spokenWordDeviceName")]
+    public string? SpokenWordDeviceName { get; init; }
+}
+
+/// <summary>
+/// Volume ramp lengths for transport transitions. Fade-in applies on track
+/// start, resume and after a seek; fade-out on pause and stop. 0 disables a ramp.
+/// </summary>
+public sealed class SetTransportFadeCommand
+{
+    [JsonPropertyName("fadeInMs")]
+    public int FadeInMs { get; init; }
+
+    [JsonPropertyName("fadeOutMs")]
+    public int FadeOutMs { get; init; }
+}
+
+/// <summary>
+/// Toggle bit-perfect output: all DSP and volume are bypassed and playback
+/// fails instead of running on a device whose native format differs from the track.
+/// </summary>
+public sealed class SetBitPerfectCommand
+{
+    [JsonPropertyName("enabled")]
+    public bool Enabled { get; init; }
+}
+
+/// <summary>
+/// Sets an A-B loop on the loaded track, or clears it when either bound is null.
+/// The loop end is sample-accurate; the loop is dropped when another track starts.
+/// </summary>
+public sealed class SetLoopRegionCommand
+{
+    /// <summary>Track the loop belongs to; rejected when it isn't the loaded track.</summary>
+    [JsonPropertyName("trackUri")]
+    public string? TrackUri { get; init; }
+
+    [JsonPropertyName("startMs")]
+    public long? StartMs { get; init; }
+
+    [JsonPropertyName("endMs")]
+    public long? EndMs { get; init; }
+}
+
+/// <summary>
+/// Toggle the output analysis that reports sustained digital silence and clipping
+/// (see <see cref="OutputDiagnosticMessage"/>). Off by default.
+/// </summary>
+public sealed class SetOutputDiagnosticsCommand
+{
+    [JsonPropertyName("enabled")]
+    public bool Enabled { get; init; }
+}
+
+/// <summary>
+/// How decoded channels are laid out on the output.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<ChannelLayoutMode>))]
+public enum ChannelLayoutMode
+{
+    /// <summary>Channels play as decoded.</summary>
+    Default,
+
+    /// <summary>All channels are averaged and the mix plays on every channel.</summary>
+    Mono,
+
+    /// <summary>The first two channels (left/right) are swapped.</summary>
+    SwapStereo,
+
+    /// <summary>Channels follow <see cref="SetChannelLayoutCommand.ChannelMap"/>.</summary>
+    Custom
+}
+
+/// <summary>
+/// Sets the output channel layout for one output device, or for every device
+/// without its own layout when <see cref="DeviceName"/> is null. Applies
+/// immediately when it affects the current device.
+/// </summary>
+public sealed class SetChannelLayoutCommand
+{
+    [JsonPropertyName("deviceName")]
+    public string? DeviceName { get; init; }
+
+    [JsonPropertyName("mode")]
+    public ChannelLayoutMode Mode { get; init; }
+
+    /// <summary>
+    /// For <see cref="ChannelLayoutMode.Custom"/>: entry i is the decoded channel
+    /// played on output channel i, or -1 for silence.
+    /// </summary>
+    [JsonPropertyName("channelMap")]
+    public int[]? ChannelMap { get; init; }
+}
+
 /// <summary>
 /// Describes a local Windows audio output device enumerated by PortAudio.
 /// </summary>
@@ -358,6 +627,22 @@ public sealed class PlaybackStateSnapshot
     [JsonPropertyName("underrunCount")]
     public long UnderrunCount { get; init; }
 
+    /// <summary>Bytes fetched for the current track so far (head data + CDN).</summary>
+    [JsonPropertyName("trackBytesDownloaded")]
+    public long TrackBytesDownloaded { get; init; }
+
+    /// <summary>True when the current track is served from the persistent audio cache.</summary>
+    [JsonPropertyName("trackFromCache")]
+    public bool TrackFromCache { get; init; }
+
+    /// <summary>Time spent decoding the current track, including waits on the stream.</summary>
+    [JsonPropertyName("trackDecodeMs")]
+    public long TrackDecodeMs { get; init; }
+
+    /// <summary>Sink underruns since the current track started.</summary>
+    [JsonPropertyName("trackUnderruns")]
+    public long TrackUnderruns { get; init; }
+
     /// <summary>
     /// Friendly name of the current local Windows audio output device (from PortAudio).
     /// Null if the sink is not an <c>IDeviceSelectableSink</c>.
@@ -411,6 +696,119 @@ public sealed class TrackFinishedMessage
     public required string Reason { get; init; }
 }
 
+/// <summary>
+/// Why AudioHost moved playback to another output device.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<AudioDeviceChangeReason>))]
+public enum AudioDeviceChangeReason
+{
+    /// <summary>The UI or the routing policy selected the device.</summary>
+    Requested,
+
+    /// <summary>The system default output changed and playback followed it.</summary>
+    DefaultChanged,
+
+    /// <summary>The device was removed (e.g. USB DAC unplugged); playback fell back to the default.</summary>
+    DeviceLost
+}
+
+/// <summary>
+/// AudioHost tells UI that playback moved to another output device mid-track
+/// without stopping.
+/// </summary>
+public sealed class AudioDeviceChangedMessage
+{
+    [JsonPropertyName("previousDeviceName")]
+    public string? PreviousDeviceName { get; init; }
+
+    [JsonPropertyName("deviceName")]
+    public string? DeviceName { get; init; }
+
+    [JsonPropertyName("reason")]
+    public AudioDeviceChangeReason Reason { get; init; }
+
+    /// <summary>Position the new device resumed from.</summary>
+    [JsonPropertyName("positionMs")]
+    public long PositionMs { get; init; }
+}
+
+/// <summary>
+/// AudioHost tells UI that playback position stopped advancing while playing and
+/// the watchdog restarted (or gave up restarting) the pipeline.
+/// </summary>
+public sealed class PipelineStallMessage
+{
+    [JsonPropertyName("trackUri")]
+    public string? TrackUri { get; init; }
+
+    /// <summary>Position the playback was frozen at; a restart resumes from here.</summary>
+    [JsonPropertyName("positionMs")]
+    public long PositionMs { get; init; }
+
+    [JsonPropertyName("stalledMs")]
+    public long StalledMs { get; init; }
+
+    /// <summary>"decode" when the sink ran dry, "sink" when it held audio but stopped playing it.</summary>
+    [JsonPropertyName("stage")]
+    public required string Stage { get; init; }
+
+    /// <summary>Restart attempt for this track, starting at 1.</summary>
+    [JsonPropertyName("attempt")]
+    public int Attempt { get; init; }
+
+    /// <summary>False when the per-track restart limit was reached and playback was left stalled.</summary>
+    [JsonPropertyName("restarted")]
+    public bool Restarted { get; init; }
+}
+
+/// <summary>
+/// What the output analysis found.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<OutputDiagnosticKind>))]
+public enum OutputDiagnosticKind
+{
+    /// <summary>
+    /// Digital silence: the decoded, processed audio itself is silent, so the gap is in
+    /// the source or decoder rather than the network (stalls) or the output device.
+    /// </summary>
+    Silence,
+
+    /// <summary>
+    /// Repeated runs of full-scale samples after DSP: too much gain from
+    /// normalization, EQ or the source itself.
+    /// </summary>
+    Clipping
+}
+
+/// <summary>
+/// AudioHost tells UI that the output analysis saw sustained silence or clipping.
+/// Sent once when the condition is detected and again with <see cref="Ended"/> set
+/// when it clears.
+/// </summary>
+public sealed class OutputDiagnosticMessage
+{
+    [JsonPropertyName("kind")]
+    public OutputDiagnosticKind Kind { get; init; }
+
+    [JsonPropertyName("trackUri")]
+    public string? TrackUri { get; init; }
+
+    /// <summary>Track position where the condition started.</summary>
+    [JsonPropertyName("positionMs")]
+    public long PositionMs { get; init; }
+
+    /// <summary>How long it has lasted so far, or in total once ended.</summary>
+    [JsonPropertyName("durationMs")]
+    public long DurationMs { get; init; }
+
+    [JsonPropertyName("ended")]
+    public bool Ended { get; init; }
+
+    /// <summary>For <see cref="OutputDiagnosticKind.Clipping"/>: runs of clipped samples counted.</summary>
+    [JsonPropertyName("clippedRuns")]
+    public int ClippedRuns { get; init; }
+}
+
 public sealed class PreviewVisualizationFrame
 {
     [JsonPropertyName("sessionId")]
@@ -426,6 +824,60 @@ public sealed class PreviewVisualizationFrame
     public bool Completed { get; init; }
 }
 
+/// <summary>
+/// What the PCM tap publishes.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<PcmTapMode>))]
+public enum PcmTapMode
+{
+    /// <summary>The tap is off; nothing is analysed or sent.</summary>
+    Off,
+
+    /// <summary>Peak samples of the audio since the previous frame, -1 to 1.</summary>
+    Waveform,
+
+    /// <summary>Log-spaced FFT magnitudes, 0 (-90 dBFS or below) to 1 (0 dBFS).</summary>
+    Spectrum
+}
+
+/// <summary>
+/// Configures the tap on decoded, post-DSP audio that feeds visualizations.
+/// </summary>
+public sealed class ConfigurePcmTapCommand
+{
+    [JsonPropertyName("mode")]
+    public PcmTapMode Mode { get; init; }
+
+    /// <summary>Frames per second, 1-60.</summary>
+    [JsonPropertyName("rateHz")]
+    public int RateHz { get; init; } = 30;
+
+    /// <summary>Values per frame: waveform points or spectrum bands.</summary>
+    [JsonPropertyName("size")]
+    public int Size { get; init; } = 64;
+}
+
+/// <summary>
+/// One frame from the PCM tap. Frames are taken as audio enters the sink, which
+/// buffers ahead of the speaker; compare <see cref="PositionMs"/> with the playback
+/// position to line them up with what is heard.
+/// </summary>
+public sealed class PcmTapFrame
+{
+    [JsonPropertyName("mode")]
+    public PcmTapMode Mode { get; init; }
+
+    [JsonPropertyName("sequence")]
+    public long Sequence { get; init; }
+
+    /// <summary>Track position at the end of the audio the frame covers.</summary>
+    [JsonPropertyName("positionMs")]
+    public long PositionMs { get; init; }
+
+    [JsonPropertyName("values")]
+    public float[] Values { get; init; } = [];
+}
+
 /// <summary>
 /// Play a track with head data for instant start. CDN URL + audio key arrive
 /// later via DeferredResolvedCommand, enabling gapless head→CDN transition.
@@ -456,6 +908,12 @@ public sealed class PlayTrackCommand
     [JsonPropertyName("normalizationPeak")]
     public float? NormalizationPeak { get; init; }
 
+    [JsonPropertyName("normalizationAlbumGain")]
+    public float? NormalizationAlbumGain { get; init; }
+
+    [JsonPropertyName("normalizationAlbumPeak")]
+    public float? NormalizationAlbumPeak { get; init; }
+
     [JsonPropertyName("headData")]
     public string? HeadData { get; init; }
 
@@ -508,6 +966,45 @@ public sealed class PlayLocalFileCommand
     public NormalizationDataDto? Normalization { get; init; }
 }
 
+/// <summary>
+/// Play a public 30-second preview clip (<c>https://p.scdn.co/mp3-preview/...</c>).
+/// The clip is an unencrypted MP3 fetched over plain HTTP, so no session, audio
+/// key or CDN resolution is involved; it goes through the same decoder, processing
+/// chain and sink as full tracks.
+/// </summary>
+public sealed class PlayPreviewCommand
+{
+    [JsonPropertyName("trackUri")]
+    public required string TrackUri { get; init; }
+
+    [JsonPropertyName("previewUrl")]
+    public required string PreviewUrl { get; init; }
+
+    [JsonPropertyName("startPositionMs")]
+    public long StartPositionMs { get; init; }
+
+    [JsonPropertyName("metadata")]
+    public TrackMetadataDto? Metadata { get; init; }
+}
+
+/// <summary>
+/// Warm AudioHost for a track that is about to be played: parse the decoder headers
+/// from its head data and decode the first packet, without touching current
+/// playback or the output device.
+/// </summary>
+public sealed class PrewarmTrackCommand
+{
+    [JsonPropertyName("trackUri")]
+    public required string TrackUri { get; init; }
+
+    [JsonPropertyName("codec")]
+    public required string Codec { get; init; }
+
+    /// <summary>Base64 head data, already decrypted.</summary>
+    [JsonPropertyName("headData")]
+    public required string HeadData { get; init; }
+}
+
 /// <summary>
 /// Completes a deferred CDN resolution. AudioHost's LazyProgressiveDownloader
 /// uses this to seamlessly continue from CDN after head data is exhausted.
@@ -580,6 +1077,10 @@ public sealed class AudioHostConfig
     [JsonPropertyName("normalizationEnabled")]
     public bool NormalizationEnabled { get; init; }
 
+    /// <summary>Full normalization configuration; overrides <see cref="NormalizationEnabled"/> when set.</summary>
+    [JsonPropertyName("normalization")]
+    public NormalizationSettings? Normalization { get; init; }
+
     [JsonPropertyName("audioPreset")]
     public string? AudioPreset { get; init; }
 
@@ -593,6 +1094,51 @@ public sealed class AudioHostConfig
     [JsonPropertyName("initialVolumePercent")]
     public int InitialVolumePercent { get; init; }
 
+    /// <summary>Fade-in on start/resume/seek in milliseconds. Null keeps the sink default.</summary>
+    [JsonPropertyName("fadeInMs")]
+    public int? FadeInMs { get; init; }
+
+    /// <summary>Fade-out on pause/stop in milliseconds. Null keeps the sink default.</summary>
+    [JsonPropertyName("fadeOutMs")]
+    public int? FadeOutMs { get; init; }
+
+    /// <summary>Start in bit-perfect mode (see <see cref="SetBitPerfectCommand"/>).</summary>
+    [JsonPropertyName("bitPerfect")]
+    public bool BitPerfect { get; init; }
+
+    /// <summary>Start with output diagnostics on (see <see cref="SetOutputDiagnosticsCommand"/>).</summary>
+    [JsonPropertyName("outputDiagnostics")]
+    public bool OutputDiagnostics { get; init; }
+
+    /// <summary>
+    /// How long position may stay frozen while playing before the pipeline is restarted.
+    /// Null keeps the engine default; 0 disables the watchdog.
+    /// </summary>
+    [JsonPropertyName("stallTimeoutMs")]
+    public int? StallTimeoutMs { get; init; }
+
+    /// <summary>Timeout for a single CDN range request. Null keeps the engine default.</summary>
+    [JsonPropertyName("cdnRequestTimeoutMs")]
+    public int? CdnRequestTimeoutMs { get; init; }
+
+    /// <summary>Local address CDN connections bind to. Null lets the OS pick.</summary>
+    [JsonPropertyName("localAddress")]
+    public string? LocalAddress { get; init; }
+
+    /// <summary>
+    /// IP family preference for CDN connections, as a NetworkConfig.AddressFamily name
+    /// (<c>PreferIPv4</c>, <c>IPv6Only</c>, ...). Null means resolver order.
+    /// </summary>
+    [JsonPropertyName("addressFamily")]
+    public string? AddressFamily { get; init; }
+
+    /// <summary>
+    /// <c>bass</c> decodes every format with BASS instead of a decoder per format.
+    /// Null keeps the per-format decoders.
+    /// </summary>
+    [JsonPropertyName("decoderBackend")]
+    public string? DecoderBackend { get; init; }
+
     /// <summary>
     /// Directory where persistent audio cache files are stored.
     /// AudioHost writes fully downloaded tracks here so future plays skip CDN resolution.
@@ -616,11 +1162,14 @@ public static class IpcMessageTypes
     public const string PlayResolved = "play_resolved";
     public const string PlayTrack = "play_track";
     public const string PlayLocalFile = "play_local_file";
+    public const string PlayPreview = "play_preview";
+    public const string Prewarm = "prewarm";
     public const string DeferredResolved = "deferred_resolved";
     public const string PrepareNext = "prepare_next";
     public const string Resume = "resume";
     public const string Pause = "pause";
     public const string Stop = "stop";
+    public const string ReleaseOutput = "release_output";
     public const string SkipNext = "skip_next";
     public const string SkipPrevious = "skip_previous";
     public const string Seek = "seek";
@@ -632,6 +1181,14 @@ public static class IpcMessageTypes
     public const string SwitchQuality = "switch_quality";
     public const string SetEqualizer = "set_equalizer";
     public const string SwitchAudioOutput = "switch_audio_output";
+    public const string SetAudioRouting = "set_audio_routing";
+    public const string SetTransportFade = "set_transport_fade";
+    public const string SetChannelLayout = "set_channel_layout";
+    public const string SetBitPerfect = "set_bit_perfect";
+    public const string SetLoopRegion = "set_loop_region";
+    public const string ConfigurePcmTap = "configure_pcm_tap";
+    public const string SetOutputDiagnostics = "set_output_diagnostics";
+    public const string GetPipelineInfo = "get_pipeline_info";
     public const string RefreshAudioDevices = "refresh_audio_devices";
     public const string StartPreviewAnalysis = "start_preview_analysis";
     public const string StopPreviewAnalysis = "stop_preview_analysis";
@@ -645,7 +1202,11 @@ public static class IpcMessageTypes
     public const string Error = "error";
     public const string CommandResult = "command_result";
     public const string TrackFinished = "track_finished";
+    public const string PipelineStalled = "pipeline_stalled";
+    public const string DeviceChanged = "device_changed";
     public const string PreviewVisualizationFrame = "preview_visualization_frame";
+    public const string PcmTapFrame = "pcm_tap_frame";
+    public const string OutputDiagnostic = "output_diagnostic";
     public const string Ready = "ready";
     public const string Pong = "pong";
 }
@@ -660,6 +1221,8 @@ public static class IpcMessageTypes
 [JsonSerializable(typeof(PlayResolvedTrackCommand))]
 [JsonSerializable(typeof(PlayTrackCommand))]
 [JsonSerializable(typeof(PlayLocalFileCommand))]
+[JsonSerializable(typeof(PlayPreviewCommand))]
+[JsonSerializable(typeof(PrewarmTrackCommand))]
 [JsonSerializable(typeof(NormalizationDataDto))]
 [JsonSerializable(typeof(DeferredResolvedCommand))]
 [JsonSerializable(typeof(PrepareNextTrackCommand))]
@@ -670,10 +1233,19 @@ public static class IpcMessageTypes
 [JsonSerializable(typeof(SetRepeatCommand))]
 [JsonSerializable(typeof(AddToQueueCommand))]
 [JsonSerializable(typeof(SetNormalizationCommand))]
+[JsonSerializable(typeof(NormalizationSettings))]
 [JsonSerializable(typeof(SwitchQualityCommand))]
 [JsonSerializable(typeof(SetEqualizerCommand))]
 [JsonSerializable(typeof(EqualizerApplyResult))]
+[JsonSerializable(typeof(PipelineInfo))]
 [JsonSerializable(typeof(SwitchAudioOutputCommand))]
+[JsonSerializable(typeof(SetAudioRoutingCommand))]
+[JsonSerializable(typeof(SetTransportFadeCommand))]
+[JsonSerializable(typeof(SetChannelLayoutCommand))]
+[JsonSerializable(typeof(SetBitPerfectCommand))]
+[JsonSerializable(typeof(SetLoopRegionCommand))]
+[JsonSerializable(typeof(ConfigurePcmTapCommand))]
+[JsonSerializable(typeof(SetOutputDiagnosticsCommand))]
 [JsonSerializable(typeof(AudioOutputDeviceDto))]
 [JsonSerializable(typeof(AudioOutputDeviceDto[]))]
 [JsonSerializable(typeof(StartPreviewAnalysisCommand))]
@@ -684,7 +1256,11 @@ public static class IpcMessageTypes
 [JsonSerializable(typeof(PlaybackErrorMessage))]
 [JsonSerializable(typeof(CommandResultMessage))]
 [JsonSerializable(typeof(TrackFinishedMessage))]
+[JsonSerializable(typeof(PipelineStallMessage))]
+[JsonSerializable(typeof(AudioDeviceChangedMessage))]
 [JsonSerializable(typeof(PreviewVisualizationFrame))]
+[JsonSerializable(typeof(PcmTapFrame))]
+[JsonSerializable(typeof(OutputDiagnosticMessage))]
 [JsonSerializable(typeof(AudioHostReady))]
 [JsonSerializable(typeof(AudioHostConfig))]
 public partial class IpcJsonContext : JsonSerializerContext;
@@ -0,0 +1,169 @@
+using System.Buffers;
+using System.Collections.Concurrent;
+using System.Numerics;
+
+namespace Wavee.Playback.Contracts;
+
+/// <summary>
+/// Byte-array pool with power-of-two slab sizes and hit/miss counters, for the buffers the
+/// audio path rents continuously: decoded PCM packets and fetch/cache chunks.
+/// </summary>
+/// <remarks>
+/// <see cref="ArrayPool{T}.Shared"/> keeps only a few arrays per size per core and says nothing
+/// about how often it actually reuses them; the 256 KiB cache chunks also exceed its per-thread
+/// cache and end up as fresh LOH allocations. Each pool here retains up to a fixed number of
+/// arrays per slab size across threads and counts every rent, so the hit rate shows up in the
+/// pipeline diagnostics. Requests above the largest slab fall through to the shared pool and are
+/// counted as <see cref="BufferPoolStatistics.Oversize"/>.
+/// </remarks>
+public sealed class SlabBufferPool : ArrayPool<byte>
+{
+    /// <summary>
+    /// Decoded PCM packets (4096 samples × channels × 2 bytes), returned by the sink after playback.
+    /// </summary>
+    public static SlabBufferPool Packets { get; } = new("packets", 4 * 1024, 64 * 1024, maxRetainedPerSlab: 64);
+
+    /// <summary>
+    /// CDN fetch slices and ranges (up to 512 KiB) and <see cref="AudioFileCache.ChunkSize"/> cache chunks.
+    /// </summary>
+    public static SlabBufferPool Chunks { get; } = new("chunks", 32 * 1024, 512 * 1024, maxRetainedPerSlab: 8);
+
+    private readonly int _minSlabShift;
+    private readonly int _maxSlabSize;
+    private readonly int _maxRetainedPerSlab;
+    private readonly ConcurrentQueue<byte[]>[] _slabs;
+    private readonly int[] _retained;
+
+    private long _rents;
+    private long _hits;
+    private long _misses;
+    private long _oversize;
+
+    /// <summary>
+    /// Creates a pool.
+    /// </summary>
+    /// <param name="name">Name reported in <see cref="GetStatistics"/>.</param>
+    /// <param name="minSlabSize">Smallest slab; a power of two.</param>
+    /// <param name="maxSlabSize">Largest slab; a power of two, at least <paramref name="minSlabSize"/>.</param>
+    /// <param name="maxRetainedPerSlab">Arrays kept per slab size; returns beyond this are dropped.</param>
+    public SlabBufferPool(string name, int minSlabSize, int maxSlabSize, int maxRetainedPerSlab)
+    {
+        if (!BitOperations.IsPow2(minSlabSize) || !BitOperations.IsPow2(maxSlabSize) || maxSlabSize < minSlabSize)
+            throw new ArgumentException("Slab sizes must be powers of two with min <= max");
+        ArgumentOutOfRangeException.ThrowIfNegative(maxRetainedPerSlab);
+
+        Name = name;
+        _minSlabShift = BitOperations.Log2((uint)minSlabSize);
+        _maxSlabSize = maxSlabSize;
+        _maxRetainedPerSlab = maxRetainedPerSlab;
+
+        var slabCount = BitOperations.Log2((uint)maxSlabSize) - _minSlabShift + 1;
+        _slabs = new ConcurrentQueue<byte[]>[slabCount];
+        _retained = new int[slabCount];
+        for (var i = 0; i < slabCount; i++)
+            _slabs[i] = new ConcurrentQueue<byte[]>();
+    }
+
+    /// <summary>
+    /// Name reported in <see cref="GetStatistics"/>.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Rents an array of at least <paramref name="minimumLength"/> bytes, rounded up to the next
+    /// slab size. The contents are not cleared.
+    /// </summary>
+    public override byte[] Rent(int minimumLength)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegative(minimumLength);
+        Interlocked.Increment(ref _rents);
+
+        if (minimumLength > _maxSlabSize)
+        {
+            Interlocked.Increment(ref _oversize);
+            return Shared.Rent(minimumLength);
+        }
+
+        var slab = SlabIndex(minimumLength);
+        if (_slabs[slab].TryDequeue(out var array))
+        {
+            Interlocked.Decrement(ref _retained[slab]);
+            Interlocked.Increment(ref _hits);
+            return array;
+        }
+
+        Interlocked.Increment(ref _misses);
+        return GC.AllocateUninitializedArray<byte>(1 << (slab + _minSlabShift));
+    }
+
+    /// <summary>
+    /// Returns an array from <see cref="Rent"/>. Arrays that aren't a slab size of this pool are
+    /// handed to the shared pool (oversize rents) or dropped.
+    /// </summary>
+    public override void Return(byte[] array, bool clearArray = false)
+    {
+        ArgumentNullException.ThrowIfNull(array);
+
+        if (array.Length > _maxSlabSize)
+        {
+            Shared.Return(array, clearArray);
+            return;
+        }
+
+        if (array.Length == 0 || !BitOperations.IsPow2(array.Length) || array.Length < 1 << _minSlabShift)
+            return;
+
+        var slab = SlabIndex(array.Length);
+        if (Interlocked.Increment(ref _retained[slab]) > _maxRetainedPerSlab)
+        {
+            Interlocked.Decrement(ref _retained[slab]);
+            return;
+        }
+
+        if (clearArray)
+            Array.Clear(array);
+        _slabs[slab].Enqueue(array);
+    }
+
+    /// <summary>
+    /// Counters since the pool was created.
+    /// </summary>
+    public BufferPoolStatistics GetStatistics()
+    {
+        long retainedBytes = 0;
+        for (var i = 0; i < _retained.Length; i++)
+            retainedBytes += (long)Volatile.Read(ref _retained[i]) << (i + _minSlabShift);
+
+        return new BufferPoolStatistics(
+            Name,
+            Interlocked.Read(ref _rents),
+            Interlocked.Read(ref _hits),
+            Interlocked.Read(ref _misses),
+            Interlocked.Read(ref _oversize),
+            retainedBytes);
+    }
+
+    private int SlabIndex(int length)
+        => Math.Max(0, BitOperations.Log2(BitOperations.RoundUpToPowerOf2((uint)Math.Max(length, 1))) - _minSlabShift);
+}
+
+/// <summary>
+/// Usage counters for a <see cref="SlabBufferPool"/>.
+/// </summary>
+/// <param name="Name">Pool name.</param>
+/// <param name="Rents">Total rents.</param>
+/// <param name="Hits">Rents served from a retained array.</param>
+/// <param name="Misses">Rents that allocated a new slab.</param>
+/// <param name="Oversize">Rents larger than the biggest slab, passed to the shared pool.</param>
+/// <param name="RetainedBytes">Bytes currently held for reuse.</param>
+public readonly record struct BufferPoolStatistics(
+    string Name,
+    long Rents,
+    long Hits,
+    long Misses,
+    long Oversize,
+    long RetainedBytes)
+{
+    /// <summary>Fraction of rents served without allocating (0 when nothing was rented).</summary>
+    public double HitRate => Rents == 0 ? 0 : (double)Hits / Rents;
+}
@@ -134,12 +134,20 @@ public partial class App : Application
             // wraps in a trivial initializer exception — unwrap to match.
             _ = Ioc.Default.GetRequiredService<Wavee.Core.Storage.Abstractions.IMetadataDatabase>();
         }
-        catch (Exception ex) when (UnwrapMigrationException(ex) is MetadataMigrationException migrationEx)
+        catch (Exception ex) when (UnwrapException<MetadataMigrationException>(ex) is MetadataMigrationException migrationEx)
         {
             LogUnhandledException("MetadataMigration", migrationEx);
             ShowMigrationErrorWindow(migrationEx);
             return;
         }
+        catch (Exception ex) when (UnwrapException<CacheLockedException>(ex) is CacheLockedException lockedEx)
+        {
+            // Another Wavee instance owns the cache directory. Opening the DB
+            // anyway would interleave writes to the index; quit instead.
+            LogUnhandledException("CacheLocked", lockedEx);
+            OnUserChoseQuit();
+            return;
+        }
 
         // Get AppModel instance
         AppModel = Ioc.Default.GetRequiredService<AppModel>();
@@ -252,11 +260,11 @@ public partial class App : Application
     /// <c>InvalidOperationException</c> (or similar). Walk the inner chain
     /// looking for our typed marker so we can present the right UI.
     /// </summary>
-    private static MetadataMigrationException? UnwrapMigrationException(Exception? ex)
+    private static T? UnwrapException<T>(Exception? ex) where T : Exception
     {
         while (ex is not null)
         {
-            if (ex is MetadataMigrationException mig) return mig;
+            if (ex is T match) return match;
             ex = ex.InnerException;
         }
         return null;
@@ -279,8 +279,9 @@ internal sealed partial class AuthStateService : ObservableObject, IAuthState, I
         }
 
         // 2. Tear down the playback engine before we disconnect the session — the engine
-        //    uses the session for AudioKey/CDN requests.
-        Wavee.UI.WinUI.Helpers.Application.AppLifecycleHelper.TeardownPlaybackEngine();
+        //    uses the session for AudioKey/CDN requests. The playing track ends as "logout".
+        Wavee.UI.WinUI.Helpers.Application.AppLifecycleHelper.TeardownPlaybackEngine(
+            Wavee.Connect.Events.TrackEndReason.Logout);
 
         // 3. Disconnect the live AP/dealer connection so the old user's session is
         //    actually terminated (not just "logged out" from the UI's POV).
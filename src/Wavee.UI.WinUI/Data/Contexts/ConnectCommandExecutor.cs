@@ -50,6 +50,13 @@ internal sealed class ConnectCommandExecutor : IPlaybackCommandExecutor, IAudioP
     /// <summary>
     /// Disables local playback routing (e.g. on logout).
     /// </summary>
+    /// <summary>
+    /// Stops the local engine, if any, recording <paramref name="reason"/> as
+    /// the end of the current track. Used by sign-out before teardown.
+    /// </summary>
+    internal Task StopLocalPlaybackAsync(Wavee.Connect.Events.TrackEndReason reason)
+        => _localEngine?.StopAsync(reason) ?? Task.CompletedTask;
+
     internal void DisableLocalPlayback()
     {
         _localEngine = null;
@@ -1069,7 +1076,7 @@ internal sealed class ConnectCommandExecutor : IPlaybackCommandExecutor, IAudioP
 
             try
             {
-                await stateManager.ResumeAsync(userInitiated: true).ConfigureAwait(false);
+                await stateManager.ResumeAsync(userInitiated: true, ct).ConfigureAwait(false);
                 return PlaybackResult.Success();
             }
             catch (Exception ex)
@@ -326,7 +326,7 @@ public sealed class LibraryDataService : ILibraryDataService
 
 
     // CreateFolderAsync moved to RootlistService (Phase 2).
-    // Rootlist ChangeInfo + Nonce helpers moved to Helpers/RootlistGraph.cs.
+    // Rootlist ChangeInfo + Nonce helpers moved to Wavee.Core.Playlists.RootlistGraph.
 
     public Task<PlaylistDetailDto> GetPlaylistAsync(string playlistId, CancellationToken ct = default)
         => GetPlaylistCoreAsync(playlistId, ct);
@@ -597,7 +597,7 @@ public sealed class LibraryDataService : ILibraryDataService
     // DTO then produced `spotify:user:spotify:user:id` in the UI header.
 
 
-    // Rootlist index / span helpers moved to Helpers/RootlistGraph.cs (Phase 2).
+    // Rootlist index / span helpers moved to Wavee.Core.Playlists.RootlistGraph (Phase 2).
 
     private static PlaylistBasePermission MapBasePermission(CachedPlaylistBasePermission value)
     {
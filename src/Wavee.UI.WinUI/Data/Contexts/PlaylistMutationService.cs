@@ -7,6 +7,7 @@ using System.Threading.Tasks;
 using Google.Protobuf;
 using Microsoft.Extensions.Logging;
 using Wavee.Core.DependencyInjection;
+using Wavee.Core.Library.Spotify;
 using Wavee.Core.Playlists;
 using Wavee.Core.Session;
 using Wavee.Core.Storage.Abstractions;
@@ -32,6 +33,7 @@ public sealed class PlaylistMutationService : IPlaylistMutationService
     private readonly ISession _session;
     private readonly IOutboxProcessor _outboxProcessor;
     private readonly IChangeBus _changeBus;
+    private readonly ISpotifyLibraryService _library;
     private readonly ILogger<PlaylistMutationService>? _logger;
     private readonly string _databasePath;
 
@@ -41,6 +43,7 @@ public sealed class PlaylistMutationService : IPlaylistMutationService
         ISession session,
         IOutboxProcessor outboxProcessor,
         IChangeBus changeBus,
+        ISpotifyLibraryService library,
         WaveeCacheOptions cacheOptions,
         ILogger<PlaylistMutationService>? logger = null)
     {
@@ -49,6 +52,7 @@ public sealed class PlaylistMutationService : IPlaylistMutationService
         _session = session;
         _outboxProcessor = outboxProcessor ?? throw new ArgumentNullException(nameof(outboxProcessor));
         _changeBus = changeBus;
+        _library = library ?? throw new ArgumentNullException(nameof(library));
         _databasePath = cacheOptions.DatabasePath;
         _logger = logger;
     }
@@ -476,85 +480,14 @@ public sealed class PlaylistMutationService : IPlaylistMutationService
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(playlistId);
 
-        var userData = _session.GetUserData()
-            ?? throw new InvalidOperationException("SetPlaylistFollowedAsync requires an authenticated session");
-        var username = userData.Username;
+        // Follow = add to the rootlist, unfollow = remove from it; the core library
+        // service owns that write and the rootlist refresh after it.
         var playlistUri = PlaylistUriHelpers.NormalizePlaylistUri(playlistId);
-        var nowMs = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds();
-
-        // Spotify's desktop client treats "follow playlist" as "add to my rootlist"
-        // and "unfollow" as "remove from my rootlist" — the same path
-        // DeletePlaylistAsync / CreatePlaylistAsync use.
-        var rootlist = await _playlistCache.GetRootlistAsync(ct: ct);
-        var existingIndex = RootlistGraph.FindRootlistPlaylistIndex(rootlist, playlistUri);
-
-        if (followed && existingIndex >= 0) return;
-        if (!followed && existingIndex < 0) return;
-
-        Wavee.Protocol.Playlist.Op op;
-        if (followed)
-        {
-            op = new Wavee.Protocol.Playlist.Op
-            {
-                Kind = Wavee.Protocol.Playlist.Op.Types.Kind.Add,
-                Add = new Wavee.Protocol.Playlist.Add
-                {
-                    FromIndex = 0,
-                    Items =
-                    {
-                        new Wavee.Protocol.Playlist.Item
-                        {
-                            Uri = playlistUri,
-                            Attributes = new Wavee.Protocol.Playlist.ItemAttributes
-                            {
-                                Timestamp = nowMs,
-                                Public = true,
-                            }
-                        }
-                    }
-                }
-            };
-        }
-        else
-        {
-            op = new Wavee.Protocol.Playlist.Op
-            {
-                Kind = Wavee.Protocol.Playlist.Op.Types.Kind.Rem,
-                Rem = new Wavee.Protocol.Playlist.Rem
-                {
-                    FromIndex = existingIndex,
-                    Length = 1,
-                }
-            };
-        }
-
-        var changes = new Wavee.Protocol.Playlist.ListChanges
-        {
-            BaseRevision = ByteString.CopyFrom(rootlist.Revision),
-            Deltas =
-            {
-                new Wavee.Protocol.Playlist.Delta
-                {
-                    Ops = { op },
-                    Info = RootlistGraph.BuildRootlistChangeInfo(username, nowMs),
-                }
-            },
-            WantResultingRevisions = true,
-            WantSyncResult = true,
-            Nonces = { RootlistGraph.NextRootlistNonce() },
-        };
-
-        await _session.SpClient.PostRootlistChangesAsync(username, changes, ct);
-        await _playlistCache.InvalidateAsync(PlaylistCacheUris.Rootlist, ct);
-
-        try
-        {
-            await _playlistCache.GetRootlistAsync(forceRefresh: true, ct);
-        }
-        catch (Exception ex) when (ex is not OperationCanceledException)
-        {
-            _logger?.LogDebug(ex, "SetPlaylistFollowedAsync: rootlist refresh failed after toggling {Uri}", playlistUri);
-        }
+        var ok = followed
+            ? await _library.FollowPlaylistAsync(playlistUri, ct)
+            : await _library.UnfollowPlaylistAsync(playlistUri, ct);
+        if (!ok)
+            throw new InvalidOperationException($"Failed to {(followed ? "follow" : "unfollow")} {playlistUri}");
 
         _changeBus.Publish(ChangeScope.Playlists);
         _changeBus.Publish(ChangeScope.Library);
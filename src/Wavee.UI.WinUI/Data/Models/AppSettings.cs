@@ -71,6 +71,26 @@ public sealed class AppSettings
     /// </summary>
     public bool AutoplayEnabled { get; set; } = true;
 
+    /// <summary>
+    /// Track URIs the player skips instead of playing. Applied when the player starts.
+    /// </summary>
+    public List<string> BlockedTracks { get; set; } = [];
+
+    /// <summary>
+    /// Artist URIs or names whose tracks the player skips. Applied when the player starts.
+    /// </summary>
+    public List<string> BlockedArtists { get; set; } = [];
+
+    /// <summary>
+    /// Tracks longer than this many minutes are skipped; 0 allows any length.
+    /// </summary>
+    public int MaxTrackDurationMinutes { get; set; }
+
+    /// <summary>
+    /// Whether the player skips live recordings.
+    /// </summary>
+    public bool SkipLiveVersions { get; set; }
+
     /// <summary>
     /// When true, popping the player out into a second window does not hide
     /// the currently selected docked player surface (bottom bar or sidebar).
@@ -116,6 +136,12 @@ public sealed class AppSettings
     /// </summary>
     public int ConnectionTimeoutSeconds { get; set; } = 30;
 
+    /// <summary>
+    /// Minutes without playback before the player enters power save (audio output
+    /// closed, caches trimmed, fewer dealer pings). 0 disables power save.
+    /// </summary>
+    public int PowerSaveIdleMinutes { get; set; }
+
     // ── UI zoom ──
 
     /// <summary>
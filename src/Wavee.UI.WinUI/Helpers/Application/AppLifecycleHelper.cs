@@ -52,6 +52,7 @@ public static class AppLifecycleHelper
 
     // Out-of-process audio mode
     private static Wavee.AudioIpc.AudioProcessManager? _audioProcessManager;
+    private static Wavee.Audio.Offline.OfflineDownloadService? _offlineDownloads;
 
     // Held so we can -= on teardown. Without these the lambdas would still be
     // collectible once _audioProcessManager is nulled, but explicit unsubscribe
@@ -85,6 +86,12 @@ public static class AppLifecycleHelper
     /// </summary>
     public static Wavee.AudioIpc.AudioProcessManager? AudioProcessManager => _audioProcessManager;
 
+    /// <summary>
+    /// Offline store for pinned playlists (null until the audio engine is up, or with the
+    /// audio cache disabled). Downloads land in the AudioHost cache directory.
+    /// </summary>
+    public static Wavee.Audio.Offline.OfflineDownloadService? OfflineDownloads => _offlineDownloads;
+
     /// <summary>
     /// Serilog level switch driving the file + in-memory sinks. Flipped at runtime by the
     /// "Verbose logging" toggle in the Diagnostics settings — no app restart required.
@@ -356,6 +363,11 @@ public static class AppLifecycleHelper
         var spotifyMetadataLocale = SpotifyMetadataLanguageSettings.ResolveEffectiveLocale(
             SettingsService.PeekSpotifyMetadataLanguage());
 
+        var powerSaveIdleMinutes = SettingsService.PeekPowerSaveIdleMinutes();
+
+        // Shared by the "Wavee" HTTP client's metering handler and the session.
+        var sessionBandwidth = new BandwidthMeter();
+
         return Host.CreateDefaultBuilder()
             .ConfigureLogging(logging => logging
                 .ClearProviders()
@@ -645,6 +657,10 @@ public static class AppLifecycleHelper
                         sp.GetRequiredService<Wavee.Core.Session.ISession>().Pathfinder,
                         sp.GetRequiredService<Wavee.Core.Storage.Abstractions.IMetadataDatabase>(),
                         sp.GetService<ILogger<Wavee.Core.Http.ExtractedColorService>>()))
+                .AddSingleton(sp =>
+                    new Wavee.Connect.Metadata.CoverPaletteService(
+                        sp.GetRequiredService<Wavee.Core.Http.IColorService>(),
+                        sp.GetService<ILogger<Wavee.Connect.Metadata.CoverPaletteService>>()))
                 // UI-oriented batched color-hint service for virtualized track rows.
                 // Wraps IColorService with request dedupe + debounce-window batching so
                 // scroll bursts across hundreds of tracks coalesce into a few backend calls.
@@ -656,14 +672,20 @@ public static class AppLifecycleHelper
                 // Spotify session infrastructure
                 .AddTransient<RetryHandler>()
                 .AddHttpClient("Wavee")
-                    .ConfigurePrimaryHttpMessageHandler(() => new System.Net.Http.SocketsHttpHandler
-                    {
-                        // Enables Accept-Encoding: gzip, deflate, br on all outgoing requests
-                        // and transparent decompression of responses.
-                        AutomaticDecompression = System.Net.DecompressionMethods.All,
-                        PooledConnectionLifetime = TimeSpan.FromMinutes(5),
-                    })
+                    .ConfigurePrimaryHttpMessageHandler(sp => Wavee.Core.Connection.CertificatePinning.Apply(
+                        Wavee.Core.Connection.NetworkBinding.Apply(
+                            new System.Net.Http.SocketsHttpHandler
+                            {
+                                // Enables Accept-Encoding: gzip, deflate, br on all outgoing requests
+                                // and transparent decompression of responses.
+                                AutomaticDecompression = System.Net.DecompressionMethods.All,
+                                PooledConnectionLifetime = TimeSpan.FromMinutes(5),
+                            },
+                            sp.GetRequiredService<SessionConfig>().Network),
+                        sp.GetRequiredService<SessionConfig>().Network,
+                        sp.GetService<ILoggerFactory>()?.CreateLogger("Wavee.TlsPinning")))
                     .AddHttpMessageHandler<RetryHandler>()
+                    .AddBandwidthMetering(sessionBandwidth)
                     .Services
                 .AddHttpClient("WaveeAudio")
                     .ConfigurePrimaryHttpMessageHandler(() => new System.Net.Http.SocketsHttpHandler
@@ -677,7 +699,9 @@ public static class AppLifecycleHelper
                 {
                     DeviceId = DeviceIdHelper.GetOrCreateDeviceId(),
                     PreferredLocale = spotifyMetadataLocale,
-                    LocalSpotifyPlaybackEnabled = Wavee.Core.Audio.SpotifyPlaybackCapabilities.DefaultLocalSpotifyPlaybackEnabled
+                    LocalSpotifyPlaybackEnabled = Wavee.Core.Audio.SpotifyPlaybackCapabilities.DefaultLocalSpotifyPlaybackEnabled,
+                    Bandwidth = sessionBandwidth,
+                    PowerSaveIdleTimeout = TimeSpan.FromMinutes(powerSaveIdleMinutes)
                 })
                 .AddSingleton(sp => Session.Create(
                     sp.GetRequiredService<SessionConfig>(),
@@ -718,10 +742,15 @@ public static class AppLifecycleHelper
                         sp.GetRequiredService<Wavee.Core.Playlists.IPlaylistCacheService>(),
                         sp.GetRequiredService<IMetadataDatabase>()))
                 .AddSingleton<Wavee.Core.Storage.Outbox.IOutboxProcessor>(sp =>
-                    new Wavee.Core.Storage.Outbox.OutboxProcessor(
+                {
+                    var processor = new Wavee.Core.Storage.Outbox.OutboxProcessor(
                         sp.GetRequiredService<IMetadataDatabase>(),
                         sp.GetServices<Wavee.Core.Storage.Outbox.IOutboxHandler>(),
-                        sp.GetService<ILogger<Wavee.Core.Storage.Outbox.OutboxProcessor>>()))
+                        sp.GetService<ILogger<Wavee.Core.Storage.Outbox.OutboxProcessor>>());
+                    // Hold writes while offline; replay the queue on reconnect.
+                    processor.ReplayWhenOnline(sp.GetRequiredService<ISession>().ConnectionState);
+                    return processor;
+                })
 
                 .AddSingleton<Wavee.Core.Library.Spotify.ISpotifyLibraryService>(sp =>
                 {
@@ -787,6 +816,7 @@ public static class AppLifecycleHelper
                         sp.GetRequiredService<ISession>(),
                         sp.GetRequiredService<Wavee.Core.Storage.Outbox.IOutboxProcessor>(),
                         sp.GetRequiredService<Wavee.UI.Services.Infra.IChangeBus>(),
+                        sp.GetRequiredService<Wavee.Core.Library.Spotify.ISpotifyLibraryService>(),
                         sp.GetRequiredService<Wavee.Core.DependencyInjection.WaveeCacheOptions>(),
                         sp.GetService<ILogger<Data.Contexts.PlaylistMutationService>>()))
                 .AddSingleton<ILibraryDataService>(sp =>
@@ -1363,7 +1393,9 @@ public static class AppLifecycleHelper
                 audioPreset: audioCacheSettings?.AudioPreset,
                 audioCacheDirectory: audioCacheDirectory,
                 audioCacheMaxBytes: audioCacheMaxBytes,
-                CancellationToken.None);
+                cdnRequestTimeout: session.Config.Network.CdnRequestTimeout,
+                network: session.Config.Network,
+                ct: CancellationToken.None);
             await ApplyAudioPipelineSettingsAsync(proxy, settingsForAudioPipeline, logger, CancellationToken.None);
 
             // Create PlaybackOrchestrator — owns queue, track resolution, remote commands
@@ -1435,7 +1467,14 @@ public static class AppLifecycleHelper
                 preferredAudioQuality,
                 extMetadataClient, cacheService, logger,
                 audioCacheDirectory: audioCacheDirectory,
-                videoManifestCache: Ioc.Default.GetService<Wavee.Core.Video.IVideoManifestCache>());
+                videoManifestCache: Ioc.Default.GetService<Wavee.Core.Video.IVideoManifestCache>(),
+                metadataDatabase: metadataDb);
+
+            // Offline downloads share the AudioHost cache, so they need it enabled.
+            _offlineDownloads = audioCacheDirectory != null && metadataDb != null
+                ? new Wavee.Audio.Offline.OfflineDownloadService(
+                    trackResolver, metadataDb, httpClient, audioCacheDirectory, logger: logger)
+                : null;
 
             Wavee.Audio.ContextResolver? contextResolver = null;
             if (metadataDb != null && extMetadataClient != null && cacheService != null)
@@ -1443,23 +1482,47 @@ public static class AppLifecycleHelper
                 var contextCache = new Wavee.Core.Storage.HotCache<Wavee.Core.Storage.ContextCacheEntry>(256);
                 contextResolver = new Wavee.Audio.ContextResolver(
                     spClient, extMetadataClient, cacheService, contextCache, logger);
-            }
 
-            var orchestrator = new Wavee.Audio.PlaybackOrchestrator(
-                proxy, trackResolver, contextResolver!, session.CommandHandler, logger,
-                events: session.Events,
-                localDeviceId: session.Config.DeviceId,
-                localLibrary: GetLocalLibraryService(),
-                localMediaPlayer: GetLocalMediaPlayer(),
-                spotifyVideoPlayback: Ioc.Default.GetService<Wavee.Audio.ISpotifyVideoPlayback>(),
-                localSpotifyPlaybackEnabled: session.Config.LocalSpotifyPlaybackEnabled);
+                // Playlist edits (ours or another device's) bump the revision;
+                // drop the cached track pages so the next play re-resolves.
+                if (Ioc.Default.GetService<Wavee.Core.Library.Spotify.ISpotifyLibraryService>()
+                    is Wavee.Core.Library.Spotify.SpotifyLibraryService libraryService)
+                {
+                    var resolver = contextResolver;
+                    _appSubscriptions.Add(libraryService.LibraryChanged
+                        .Where(e => !string.IsNullOrEmpty(e.PlaylistUri) && e.NewRevision is { Length: > 0 })
+                        .Subscribe(e => resolver.OnContextRevisionChanged(
+                            e.PlaylistUri!, Convert.ToBase64String(e.NewRevision!))));
+                }
+            }
 
             // Honor the user's autoplay preference. Read fresh on each check so
             // a toggle in the Settings page takes effect immediately — no event
             // plumbing / debounce needed.
             var settingsForAutoplay = Ioc.Default.GetService<ISettingsService>();
-            if (settingsForAutoplay is not null)
-                orchestrator.AutoplayEnabledProvider = () => settingsForAutoplay.Settings.AutoplayEnabled;
+
+            // Reused on AudioHost restart so the replacement orchestrator is wired identically.
+            var playerBuilder = new Wavee.Audio.PlayerBuilder(session)
+                .WithTrackResolver(trackResolver)
+                .WithContextResolver(contextResolver!)
+                .WithLogger(logger)
+                .WithLocalLibrary(GetLocalLibraryService())
+                .WithLocalMediaPlayer(GetLocalMediaPlayer())
+                .WithSpotifyVideoPlayback(Ioc.Default.GetService<Wavee.Audio.ISpotifyVideoPlayback>())
+                .WithAutoplay(settingsForAutoplay is null ? null : () => settingsForAutoplay.Settings.AutoplayEnabled)
+                .WithTrackFilter(settingsForAutoplay is null
+                    ? Wavee.Audio.TrackFilterRules.None
+                    : Wavee.Audio.TrackFilterRules.FromConfig(new Wavee.Core.Configuration.PlayerConfig
+                    {
+                        BlockedTracks = settingsForAutoplay.Settings.BlockedTracks,
+                        BlockedArtists = settingsForAutoplay.Settings.BlockedArtists,
+                        MaxTrackDuration = TimeSpan.FromMinutes(Math.Max(0, settingsForAutoplay.Settings.MaxTrackDurationMinutes)),
+                        SkipLiveVersions = settingsForAutoplay.Settings.SkipLiveVersions
+                    }))
+                .WithPlaylistRefreshes(Ioc.Default.GetService<Wavee.Core.Playlists.IPlaylistCacheService>()?.Refreshed)
+                .WithListeningStats(metadataDb)
+                .WithCacheCleanup(Ioc.Default.GetService<Wavee.Core.Storage.CacheCleanupService>());
+            var orchestrator = playerBuilder.Build(proxy);
 
             // Wire up orchestrator (not raw proxy) as the local engine
             var executor = Ioc.Default.GetService<IPlaybackCommandExecutor>() as ConnectCommandExecutor;
@@ -1618,16 +1681,7 @@ public static class AppLifecycleHelper
                 var notifDisp = _uiDispatcher;
                 notifDisp?.TryEnqueue(() =>
                 {
-                    var newOrch = new Wavee.Audio.PlaybackOrchestrator(
-                        newProxy, trackResolver, contextResolver!, session.CommandHandler, logger,
-                        events: session.Events,
-                        localDeviceId: session.Config.DeviceId,
-                        localLibrary: GetLocalLibraryService(),
-                        localMediaPlayer: GetLocalMediaPlayer(),
-                        spotifyVideoPlayback: Ioc.Default.GetService<Wavee.Audio.ISpotifyVideoPlayback>(),
-                        localSpotifyPlaybackEnabled: session.Config.LocalSpotifyPlaybackEnabled);
-                    if (settingsForAutoplay is not null)
-                        newOrch.AutoplayEnabledProvider = () => settingsForAutoplay.Settings.AutoplayEnabled;
+                    var newOrch = playerBuilder.Build(newProxy);
                     var exec = Ioc.Default.GetService<IPlaybackCommandExecutor>() as ConnectCommandExecutor;
                     exec?.EnableLocalPlayback(newOrch);
                     exec?.EnableAudioPipelineControl(newProxy);
@@ -1702,17 +1756,21 @@ public static class AppLifecycleHelper
     /// Tears down the playback engine and associated resources.
     /// Call on logout to avoid leaking AudioPipeline and subscriptions on re-login.
     /// </summary>
-    public static void TeardownPlaybackEngine()
-        => _ = TeardownPlaybackEngineAsync();
+    public static void TeardownPlaybackEngine(Wavee.Connect.Events.TrackEndReason? endReason = null)
+        => _ = TeardownPlaybackEngineCoreAsync(endReason);
 
     /// <summary>
     /// Tears down the playback engine and associated resources.
     /// Await this on app shutdown so background audio/process work finishes before XAML teardown.
     /// </summary>
     public static Task TeardownPlaybackEngineAsync()
-        => TeardownPlaybackEngineCoreAsync();
+        => TeardownPlaybackEngineCoreAsync(endReason: null);
 
-    private static async Task TeardownPlaybackEngineCoreAsync()
+    /// <param name="endReason">
+    /// When set, the local engine is stopped first so the playing track ends
+    /// with this reason (e.g. sign-out) instead of being cut off unreported.
+    /// </param>
+    private static async Task TeardownPlaybackEngineCoreAsync(Wavee.Connect.Events.TrackEndReason? endReason)
     {
         await _playbackTeardownGate.WaitAsync().ConfigureAwait(false);
         try
@@ -1723,6 +1781,11 @@ public static class AppLifecycleHelper
 
             // Clear engine from executor
             var executor = Ioc.Default.GetService<IPlaybackCommandExecutor>() as ConnectCommandExecutor;
+            if (endReason is { } reason && executor != null)
+            {
+                try { await executor.StopLocalPlaybackAsync(reason).ConfigureAwait(false); }
+                catch (Exception ex) { Log.Warning(ex, "Failed to stop playback ({Reason}) before teardown", reason); }
+            }
             executor?.DisableLocalPlayback();
 
             // Dispose the enricher (unregisters from messenger)
@@ -1745,6 +1808,7 @@ public static class AppLifecycleHelper
                 await _audioProcessManager.DisposeAsync().ConfigureAwait(false);
                 _audioProcessManager = null;
             }
+            _offlineDownloads = null;
         }
         finally
         {
@@ -16,8 +16,8 @@ internal static class DeviceIdHelper
             var existing = File.ReadAllText(path).Trim();
             if (!string.IsNullOrEmpty(existing)) return existing;
         }
-        var id = Guid.NewGuid().ToString("N");
-        File.WriteAllText(path, id);
+        var id = Wavee.Core.Session.DeviceIdGenerator.Generate();
+        Wavee.Core.Utilities.AtomicFile.WriteAllText(path, id);
         return id;
     }
 }
@@ -7,6 +7,7 @@ using System.Text;
 using System.Threading;
 using System.Threading.Tasks;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Utilities;
 using Wavee.Local.Enrichment;
 using Wavee.UI.WinUI.Helpers;
 using Wavee.UI.WinUI.Helpers.Application;
@@ -57,32 +58,26 @@ public sealed class DpapiTmdbTokenStore : ITmdbTokenStore
                 return null;
             }
 
-            byte[] encrypted;
-            try
-            {
-                encrypted = await File.ReadAllBytesAsync(_filePath, ct);
-            }
-            catch (Exception ex)
+            // A blob that fails to unprotect sends the read to the previous copy.
+            byte[]? plain = null;
+            await AtomicFile.ReadAllBytesAsync(_filePath, encrypted =>
             {
-                _logger?.LogWarning(ex, "Failed to read TMDB token blob — treating as absent");
-                return null;
-            }
+                plain = ProtectedData.Unprotect(encrypted, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
+                return true;
+            }, _logger, ct);
 
-            try
+            if (plain == null)
             {
-                var plain = ProtectedData.Unprotect(encrypted, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
-                return Encoding.UTF8.GetString(plain);
-            }
-            catch (CryptographicException ex)
-            {
-                // Blob is corrupt or was written under a different Windows
-                // user account (e.g. profile migration). Treat as no token
-                // and let the user re-paste — don't crash.
-                _logger?.LogWarning(ex, "TMDB token blob could not be unprotected — clearing");
-                try { File.Delete(_filePath); } catch { /* best effort */ }
+                // Blob (and its backup) are corrupt or were written under a
+                // different Windows user account (e.g. profile migration).
+                // Treat as no token and let the user re-paste — don't crash.
+                _logger?.LogWarning("TMDB token blob could not be read or unprotected — clearing");
+                try { AtomicFile.Delete(_filePath); } catch { /* best effort */ }
                 if (_hasToken) { _hasToken = false; _changes.OnNext(false); }
                 return null;
             }
+
+            return Encoding.UTF8.GetString(plain);
         }
         finally
         {
@@ -97,11 +92,8 @@ public sealed class DpapiTmdbTokenStore : ITmdbTokenStore
         {
             if (string.IsNullOrWhiteSpace(token))
             {
-                if (File.Exists(_filePath))
-                {
-                    try { File.Delete(_filePath); }
-                    catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete TMDB token blob"); }
-                }
+                try { AtomicFile.Delete(_filePath); }
+                catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete TMDB token blob"); }
                 if (_hasToken) { _hasToken = false; _changes.OnNext(false); }
                 return;
             }
@@ -118,11 +110,8 @@ public sealed class DpapiTmdbTokenStore : ITmdbTokenStore
                 throw;
             }
 
-            // Atomic write: write to a temp file then move into place so a
-            // crash mid-write never leaves a half-written blob.
-            var tmpPath = _filePath + ".tmp";
-            await File.WriteAllBytesAsync(tmpPath, encrypted, ct);
-            File.Move(tmpPath, _filePath, overwrite: true);
+            // Atomic write: a crash mid-write never leaves a half-written blob.
+            await AtomicFile.WriteAllBytesAsync(_filePath, encrypted, cancellationToken: ct);
 
             if (!_hasToken) { _hasToken = true; _changes.OnNext(true); }
         }
@@ -5,6 +5,7 @@ using System.Text.Json.Serialization;
 using System.Threading;
 using System.Threading.Tasks;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Utilities;
 using Wavee.UI.WinUI.Data.Contracts;
 using Wavee.UI.WinUI.Data.Models;
 
@@ -120,6 +121,34 @@ public sealed class SettingsService : ISettingsService, IDisposable
         return SpotifyMetadataLanguageSettings.MatchApp;
     }
 
+    /// <summary>
+    /// Reads ONLY the <c>PowerSaveIdleMinutes</c> field, for the session config built
+    /// before DI. Falls back to <c>0</c> (power save off) on any failure or negative value.
+    /// </summary>
+    public static int PeekPowerSaveIdleMinutes()
+    {
+        try
+        {
+            if (!File.Exists(SettingsPath)) return 0;
+
+            using var stream = File.OpenRead(SettingsPath);
+            using var doc = JsonDocument.Parse(stream);
+            if (doc.RootElement.TryGetProperty("PowerSaveIdleMinutes", out var prop)
+                && prop.ValueKind == JsonValueKind.Number
+                && prop.TryGetInt32(out var minutes)
+                && minutes > 0)
+            {
+                return minutes;
+            }
+        }
+        catch
+        {
+            // Any exception falls through to the default.
+        }
+
+        return 0;
+    }
+
     private AppSettings _settings = new();
     private readonly SemaphoreSlim _writeLock = new(1, 1);
     private CancellationTokenSource? _debounceCts;
@@ -137,10 +166,17 @@ public sealed class SettingsService : ISettingsService, IDisposable
     {
         try
         {
-            if (File.Exists(SettingsPath))
+            // A torn or unparsable settings.json falls back to the last good copy
+            AppSettings? loaded = null;
+            var json = await AtomicFile.ReadAllTextAsync(SettingsPath, text =>
+            {
+                loaded = JsonSerializer.Deserialize(text, AppSettingsJsonContext.Default.AppSettings);
+                return loaded != null;
+            }, _logger);
+
+            if (json != null)
             {
-                var json = await File.ReadAllTextAsync(SettingsPath);
-                _settings = JsonSerializer.Deserialize(json, AppSettingsJsonContext.Default.AppSettings) ?? new AppSettings();
+                _settings = loaded ?? new AppSettings();
                 NormalizeSettings(_settings);
                 _logger?.LogInformation("Settings loaded from {Path}", SettingsPath);
             }
@@ -188,7 +224,7 @@ public sealed class SettingsService : ISettingsService, IDisposable
             var dir = Path.GetDirectoryName(SettingsPath)!;
             Directory.CreateDirectory(dir);
             var json = JsonSerializer.Serialize(_settings, AppSettingsJsonContext.Default.AppSettings);
-            await File.WriteAllTextAsync(SettingsPath, json);
+            await AtomicFile.WriteAllTextAsync(SettingsPath, json);
             _logger?.LogDebug("Settings saved to {Path}", SettingsPath);
         }
         catch (Exception ex)
@@ -223,7 +259,7 @@ public sealed class SettingsService : ISettingsService, IDisposable
             var dir = Path.GetDirectoryName(SettingsPath)!;
             Directory.CreateDirectory(dir);
             var json = JsonSerializer.Serialize(_settings, AppSettingsJsonContext.Default.AppSettings);
-            File.WriteAllText(SettingsPath, json);
+            AtomicFile.WriteAllText(SettingsPath, json);
         }
         catch (Exception ex)
         {
@@ -5,6 +5,7 @@ using System.Threading.Tasks;
 using Microsoft.Extensions.Logging;
 using Wavee.Core.Playlists;
 using Wavee.Core.Storage.Abstractions;
+using Wavee.Core.Utilities;
 using Wavee.UI.WinUI.Data.Contracts;
 
 namespace Wavee.UI.WinUI.Services;
@@ -101,7 +102,7 @@ internal sealed class UserScopeGuard : IUserScopeGuard
     {
         try
         {
-            File.WriteAllText(path, userId);
+            AtomicFile.WriteAllText(path, userId);
         }
         catch (Exception ex)
         {
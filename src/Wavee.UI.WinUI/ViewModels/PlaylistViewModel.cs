@@ -2316,6 +2316,35 @@ public sealed partial class PlaylistViewModel : ObservableObject, ITrackListView
         }
     }
 
+    /// <summary>
+    /// Pins the playlist to the offline store: downloads the tracks that aren't stored
+    /// yet and keeps all of them out of quota eviction. Progress goes to the activity feed.
+    /// </summary>
+    [RelayCommand]
+    private async Task DownloadAsync()
+    {
+        var downloads = Helpers.Application.AppLifecycleHelper.OfflineDownloads;
+        if (downloads is null || string.IsNullOrEmpty(PlaylistId) || _allTracks.Count == 0) return;
+
+        const string prefix = "spotify:playlist:";
+        var contextUri = PlaylistId.StartsWith(prefix, StringComparison.Ordinal) ? PlaylistId : prefix + PlaylistId;
+        var trackUris = _allTracks.Select(static t => t.Uri).ToList();
+        var activities = CommunityToolkit.Mvvm.DependencyInjection.Ioc.Default.GetService<IActivityService>();
+        var activityId = activities?.Start("downloads", string.IsNullOrEmpty(PlaylistName) ? contextUri : PlaylistName, Styles.FluentGlyphs.Download);
+        try
+        {
+            var downloaded = await downloads.PinAsync(contextUri, trackUris).ConfigureAwait(true);
+            if (activityId is { } id)
+                activities!.Complete(id, $"{downloaded} new tracks downloaded");
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Download failed for {PlaylistId}", PlaylistId);
+            if (activityId is { } id)
+                activities!.Fail(id, ex.Message);
+        }
+    }
+
     [RelayCommand]
     private void PlayTrack(object? track)
     {
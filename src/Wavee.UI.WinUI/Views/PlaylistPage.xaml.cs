@@ -142,7 +142,8 @@ public sealed partial class PlaylistPage : Page, INavigationCacheMemoryParticipa
                 PlaylistName = ViewModel.PlaylistName ?? string.Empty,
                 IsOwner = ViewModel.IsOwner,
                 PlayCommand = ViewModel.PlayAllCommand,
-                ShuffleCommand = ViewModel.ShuffleCommand
+                ShuffleCommand = ViewModel.ShuffleCommand,
+                DownloadCommand = ViewModel.DownloadCommand
             });
             ContextMenuHost.Show(LeftColumnHost, items, e.GetPosition(LeftColumnHost));
             e.Handled = true;
@@ -0,0 +1,77 @@
+namespace Wavee.Audio;
+
+/// <summary>
+/// Remembers whether playback should come back after the OS takes the audio session
+/// away (incoming call, alarm, another app claiming exclusive output).
+/// </summary>
+/// <remarks>
+/// Only playback that was running when the interruption began is resumed, and only
+/// if the OS allows it when the interruption ends. If playback starts during the
+/// interruption the user has taken over, so nothing is resumed afterwards.
+/// </remarks>
+internal sealed class AudioSessionInterruption
+{
+    private readonly object _lock = new();
+    private bool _interrupted;
+    private bool _resumePending;
+
+    /// <summary>
+    /// True between <see cref="Begin"/> and <see cref="End"/>.
+    /// </summary>
+    public bool IsInterrupted
+    {
+        get
+        {
+            lock (_lock) return _interrupted;
+        }
+    }
+
+    /// <summary>
+    /// Records the start of an interruption.
+    /// </summary>
+    /// <param name="wasPlaying">Whether playback was running.</param>
+    /// <returns>True if the caller should pause; false if already interrupted or nothing was playing.</returns>
+    public bool Begin(bool wasPlaying)
+    {
+        lock (_lock)
+        {
+            if (_interrupted)
+                return false;
+
+            _interrupted = true;
+            _resumePending = wasPlaying;
+            return wasPlaying;
+        }
+    }
+
+    /// <summary>
+    /// Playback started while interrupted; the end of the interruption must not resume it again.
+    /// </summary>
+    public void PlaybackStarted()
+    {
+        lock (_lock)
+        {
+            if (_interrupted)
+                _resumePending = false;
+        }
+    }
+
+    /// <summary>
+    /// Records the end of an interruption.
+    /// </summary>
+    /// <param name="shouldResume">The OS's resume hint (e.g. AVAudioSession's shouldResume option).</param>
+    /// <returns>True if the caller should resume playback.</returns>
+    public bool End(bool shouldResume)
+    {
+        lock (_lock)
+        {
+            if (!_interrupted)
+                return false;
+
+            var resume = _resumePending && shouldResume;
+            _interrupted = false;
+            _resumePending = false;
+            return resume;
+        }
+    }
+}
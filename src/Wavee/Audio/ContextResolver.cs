@@ -2,6 +2,7 @@ using System.Collections.Concurrent;
 using Microsoft.Extensions.Logging;
 using Wavee.Audio.Queue;
 using Wavee.Connect.Commands;
+using Wavee.Core.Audio;
 using Wavee.Core.Http;
 using Wavee.Core.Storage;
 using Wavee.Core.Storage.Abstractions;
@@ -42,6 +43,10 @@ public sealed class ContextResolver
     private static readonly TimeSpan UnavailableCooldown = TimeSpan.FromSeconds(30);
     private readonly ConcurrentDictionary<string, DateTimeOffset> _unavailableContexts = new();
 
+    // Latest known revision per context URI, from playlist change pushes.
+    // Cache entries resolved at another revision are stale.
+    private readonly ConcurrentDictionary<string, string> _contextRevisions = new();
+
     // TTL values for different context types
     private static readonly TimeSpan PlaylistTtl = TimeSpan.FromMinutes(5);
     private static readonly TimeSpan AlbumTtl = TimeSpan.FromHours(24);
@@ -52,6 +57,12 @@ public sealed class ContextResolver
     private const int BatchSize = 500;
     private const int MaxPagesPerLoad = 10; // Safety limit for page loading
 
+    // Eager page loading stops once this many tracks are in hand (and the
+    // requested start track has been seen). Remaining pages are fetched on
+    // demand via LoadNextPageAsync when the queue runs low, so a 10k-track
+    // playlist doesn't block playback start or sit fully in memory.
+    internal const int EagerTrackTarget = 300;
+
     public ContextResolver(
         SpClient spClient,
         IExtendedMetadataClient metadataClient,
@@ -77,12 +88,23 @@ public sealed class ContextResolver
 
     /// <summary>
     /// Loads a context and returns a flat track list with metadata.
-    /// Merges all available pages into one list. Retries on transient failures.
+    /// Loads pages until <see cref="EagerTrackTarget"/> tracks are available and
+    /// the start track (if given) has been found; the rest is left to
+    /// <see cref="LoadNextPageAsync"/> via <see cref="ContextLoadResult.NextPageUrl"/>.
+    /// Retries on transient failures.
     /// </summary>
+    /// <param name="contextUri">Context to resolve.</param>
+    /// <param name="maxTracks">Hard cap on returned tracks.</param>
+    /// <param name="enrichMetadata">Fill titles/artists from extended metadata.</param>
+    /// <param name="startTrackUri">Track playback will start on; pages keep loading until it's found.</param>
+    /// <param name="startTrackUid">UID of the start track (preferred over URI when set).</param>
+    /// <param name="ct">Cancellation token.</param>
     public async Task<ContextLoadResult> LoadContextAsync(
         string contextUri,
         int? maxTracks = null,
         bool enrichMetadata = true,
+        string? startTrackUri = null,
+        string? startTrackUid = null,
         CancellationToken ct = default)
     {
         _logger?.LogDebug("Loading context: {ContextUri}, maxTracks={MaxTracks}, enrich={Enrich}",
@@ -97,9 +119,15 @@ public sealed class ContextResolver
             throw new ContextUnavailableException(contextUri, cooldownUntil);
         }
 
+        // Set by OnContextRevisionChanged; an entry resolved at an older revision is stale.
+        _contextRevisions.TryGetValue(contextUri, out var expectedRevision);
+
         // Check context cache
         var cached = _contextCache.Get(contextUri);
-        if (cached is { IsValid: true })
+        if (cached is { IsValid: true }
+            && (expectedRevision is null || cached.Revision == expectedRevision)
+            && (cached.NextPageUrl is null
+                || ContainsStartTrack(cached.Tracks, startTrackUri, startTrackUid)))
         {
             _logger?.LogDebug("Context cache hit: {ContextUri}, {TrackCount} tracks",
                 contextUri, cached.Tracks.Count);
@@ -122,9 +150,10 @@ public sealed class ContextResolver
             throw new ContextUnavailableException(contextUri, until, ex);
         }
 
-        // Extract all tracks from pages (eager load for bounded contexts)
-        var trackInfos = await LoadTracksFromPagesAsync(context, contextUri, maxTracks, ct);
-        var nextPageUrl = FindNextPageUrl(context);
+        // Extract tracks from the initial pages, eager-loading just enough
+        // follow-up pages to start playback.
+        var (trackInfos, nextPageUrl) = await LoadTracksFromPagesAsync(
+            context, contextUri, maxTracks, startTrackUri, startTrackUid, ct);
         var totalCount = GetTotalFromMetadata(context);
         var isInfinite = IsInfiniteContext(contextUri);
         var sortingCriteria = ExtractSortingCriteria(context);
@@ -136,7 +165,8 @@ public sealed class ContextResolver
 
         // Cache the raw result (including rich metadata so cache hits don't
         // lose the context-level decorations PlayerState needs).
-        CacheContext(contextUri, trackInfos, nextPageUrl, totalCount, isInfinite, contextMetadata, context.Pages.Count);
+        CacheContext(contextUri, trackInfos, nextPageUrl, totalCount, isInfinite, contextMetadata,
+            context.Pages.Count, expectedRevision);
 
         // Enrich with metadata
         var tracks = enrichMetadata && trackInfos.Count > 0
@@ -167,7 +197,7 @@ public sealed class ContextResolver
         var page = await _spClient.GetNextPageAsync(pageUrl, ct);
 
         var trackInfos = page.Tracks
-            .Where(t => !string.IsNullOrEmpty(t.Uri))
+            .Where(t => IsQueueableEntry(t.Uri))
             .Select(t => new CachedContextTrack(t.Uri, t.Uid, SnapshotTrackMetadata(t)))
             .ToList();
 
@@ -209,7 +239,7 @@ public sealed class ContextResolver
         {
             foreach (var track in page.Tracks)
             {
-                if (!string.IsNullOrEmpty(track.Uri))
+                if (IsQueueableEntry(track.Uri))
                     trackInfos.Add(new CachedContextTrack(track.Uri, track.Uid, SnapshotTrackMetadata(track)));
             }
         }
@@ -267,7 +297,7 @@ public sealed class ContextResolver
         var resp = await _spClient.GetRadioApolloAutoplayAsync(seedId, prevIds, cancellationToken: ct);
 
         var trackInfos = resp.Tracks
-            .Where(t => !string.IsNullOrEmpty(t.Uri))
+            .Where(t => IsQueueableEntry(t.Uri))
             .Select(t => new CachedContextTrack(
                 t.Uri,
                 t.Uid,
@@ -376,6 +406,27 @@ public sealed class ContextResolver
         _logger?.LogDebug("Context invalidated: {ContextUri}", contextUri);
     }
 
+    /// <summary>
+    /// Records a new revision for a context (e.g. a playlist change push).
+    /// A cached entry resolved at a different revision is dropped, so the next
+    /// <see cref="LoadContextAsync"/> re-resolves instead of serving stale pages.
+    /// </summary>
+    public void OnContextRevisionChanged(string contextUri, string revision)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(contextUri);
+        ArgumentException.ThrowIfNullOrEmpty(revision);
+
+        _contextRevisions[contextUri] = revision;
+
+        var cached = _contextCache.Get(contextUri);
+        if (cached is not null && cached.Revision != revision)
+        {
+            _contextCache.Remove(contextUri);
+            _logger?.LogDebug("Context {ContextUri} moved to revision {Revision}, cache entry dropped",
+                contextUri, revision);
+        }
+    }
+
     // ================================================================
     // METADATA ENRICHMENT
     // ================================================================
@@ -392,10 +443,10 @@ public sealed class ContextResolver
         CancellationToken ct)
     {
         var cachedTracks = await _cacheService.GetTracksAsync(
-            trackInfos.Select(t => t.Uri), ct);
+            trackInfos.Where(t => !HasNoTrackMetadata(t.Uri)).Select(t => t.Uri), ct);
 
         var uncachedUris = trackInfos
-            .Where(t => !cachedTracks.ContainsKey(t.Uri))
+            .Where(t => !HasNoTrackMetadata(t.Uri) && !cachedTracks.ContainsKey(t.Uri))
             .Select(t => t.Uri)
             .ToList();
 
@@ -435,7 +486,27 @@ public sealed class ContextResolver
 
         foreach (var info in trackInfos)
         {
-            if (cachedTracks.TryGetValue(info.Uri, out var cached))
+            if (SpotifyLocalUri.TryParse(info.Uri, out var local))
+            {
+                // Kept so the queue mirrors the context; the orchestrator skips it.
+                result.Add(new QueueTrack(
+                    Uri: info.Uri, Uid: info.Uid,
+                    Title: local.Title, Artist: local.Artist, Album: local.Album,
+                    DurationMs: (int?)local.Duration?.TotalMilliseconds,
+                    IsPlayable: false)
+                {
+                    Metadata = info.Metadata
+                });
+            }
+            else if (HasNoTrackMetadata(info.Uri))
+            {
+                // Ad / interruption slot: kept so its position in the context survives.
+                result.Add(new QueueTrack(info.Uri, info.Uid, IsPlayable: false)
+                {
+                    Metadata = info.Metadata
+                });
+            }
+            else if (cachedTracks.TryGetValue(info.Uri, out var cached))
             {
                 result.Add(new QueueTrack(
                     Uri: info.Uri, Uid: info.Uid,
@@ -462,6 +533,23 @@ public sealed class ContextResolver
         return result;
     }
 
+    /// <summary>
+    /// Context entries that belong in the queue. Delimiters and <c>spotify:meta:*</c>
+    /// page markers are dropped. Local files, ads and interruptions are kept; the
+    /// orchestrator skips them when reached (and reports ads / interruptions).
+    /// </summary>
+    private static bool IsQueueableEntry(string? uri)
+        => !string.IsNullOrEmpty(uri)
+           && (!SpotifyId.IsContextMarker(uri)
+               || SpotifyId.GetUriType(uri) is SpotifyIdType.Ad or SpotifyIdType.Interruption);
+
+    /// <summary>
+    /// Entries with no extended metadata to fetch: local files carry their tags in
+    /// the URI; ads and interruptions only have their context metadata.
+    /// </summary>
+    private static bool HasNoTrackMetadata(string uri)
+        => SpotifyId.GetUriType(uri) is SpotifyIdType.Local or SpotifyIdType.Ad or SpotifyIdType.Interruption;
+
     // ================================================================
     // INTERNAL: RETRY & PAGE LOADING
     // ================================================================
@@ -501,45 +589,49 @@ public sealed class ContextResolver
     }
 
     /// <summary>
-    /// Extracts all tracks from context pages. Loads additional pages eagerly
-    /// for bounded contexts, respects maxTracks limit. Captures the server's
-    /// per-track metadata dict so it can round-trip through the cache and into
-    /// <c>PlayerState.track.metadata</c> on publish.
+    /// Extracts tracks from context pages. For bounded contexts, loads further
+    /// pages until <see cref="EagerTrackTarget"/> is reached and the start track
+    /// has been seen, respecting maxTracks. Returns the URL of the first page
+    /// NOT loaded so pagination resumes where eager loading stopped. Captures
+    /// the server's per-track metadata dict so it can round-trip through the
+    /// cache and into <c>PlayerState.track.metadata</c> on publish.
     /// </summary>
-    private async Task<List<CachedContextTrack>> LoadTracksFromPagesAsync(
-        Context context, string contextUri, int? maxTracks, CancellationToken ct)
+    private async Task<(List<CachedContextTrack> Tracks, string? NextPageUrl)> LoadTracksFromPagesAsync(
+        Context context, string contextUri, int? maxTracks,
+        string? startTrackUri, string? startTrackUid, CancellationToken ct)
     {
         var trackInfos = new List<CachedContextTrack>();
         var isInfinite = IsInfiniteContext(contextUri);
+        var nextPageUrl = FindNextPageUrl(context);
 
         // Extract tracks from initial response pages
         foreach (var page in context.Pages)
         {
             foreach (var track in page.Tracks)
             {
-                if (string.IsNullOrEmpty(track.Uri)) continue;
+                if (!IsQueueableEntry(track.Uri)) continue;
                 trackInfos.Add(new CachedContextTrack(track.Uri, track.Uid, SnapshotTrackMetadata(track)));
                 if (maxTracks.HasValue && trackInfos.Count >= maxTracks.Value) break;
             }
             if (maxTracks.HasValue && trackInfos.Count >= maxTracks.Value) break;
         }
 
-        // For bounded contexts, eagerly load remaining pages if needed
+        // For bounded contexts, eagerly load further pages only while needed
         if (!isInfinite && (!maxTracks.HasValue || trackInfos.Count < maxTracks.Value))
         {
-            var nextPageUrl = FindNextPageUrl(context);
             var pagesLoaded = 0;
 
             while (nextPageUrl != null && pagesLoaded < MaxPagesPerLoad)
             {
                 if (maxTracks.HasValue && trackInfos.Count >= maxTracks.Value) break;
+                if (HasEnoughForStart(trackInfos, startTrackUri, startTrackUid)) break;
 
                 try
                 {
                     var page = await _spClient.GetNextPageAsync(nextPageUrl, ct);
                     foreach (var track in page.Tracks)
                     {
-                        if (string.IsNullOrEmpty(track.Uri)) continue;
+                        if (!IsQueueableEntry(track.Uri)) continue;
                         trackInfos.Add(new CachedContextTrack(track.Uri, track.Uid, SnapshotTrackMetadata(track)));
                         if (maxTracks.HasValue && trackInfos.Count >= maxTracks.Value) break;
                     }
@@ -551,16 +643,34 @@ public sealed class ContextResolver
                 {
                     _logger?.LogWarning(ex, "Failed to load page {PageNum} for {ContextUri}",
                         pagesLoaded + 1, contextUri);
-                    break; // Continue with partial data
+                    break; // Continue with partial data; nextPageUrl still points at the failed page
                 }
             }
 
             if (pagesLoaded > 0)
-                _logger?.LogDebug("Loaded {Pages} additional pages for {ContextUri}, total {Tracks} tracks",
-                    pagesLoaded, contextUri, trackInfos.Count);
+                _logger?.LogDebug("Loaded {Pages} additional pages for {ContextUri}, total {Tracks} tracks, more={HasMore}",
+                    pagesLoaded, contextUri, trackInfos.Count, nextPageUrl != null);
         }
 
-        return trackInfos;
+        return (trackInfos, nextPageUrl);
+    }
+
+    /// <summary>
+    /// True once eager loading can stop: at least <see cref="EagerTrackTarget"/>
+    /// tracks are loaded and the start track (if any) is among them.
+    /// </summary>
+    internal static bool HasEnoughForStart(
+        IReadOnlyList<CachedContextTrack> tracks, string? startTrackUri, string? startTrackUid)
+        => tracks.Count >= EagerTrackTarget && ContainsStartTrack(tracks, startTrackUri, startTrackUid);
+
+    private static bool ContainsStartTrack(
+        IReadOnlyList<CachedContextTrack> tracks, string? startTrackUri, string? startTrackUid)
+    {
+        if (!string.IsNullOrEmpty(startTrackUid))
+            return tracks.Any(t => t.Uid == startTrackUid);
+        if (!string.IsNullOrEmpty(startTrackUri))
+            return tracks.Any(t => t.Uri == startTrackUri);
+        return true;
     }
 
     /// <summary>
@@ -596,7 +706,7 @@ public sealed class ContextResolver
         string contextUri, List<CachedContextTrack> trackInfos,
         string? nextPageUrl, int? totalCount, bool isInfinite,
         IReadOnlyDictionary<string, string>? contextMetadata,
-        int pageCount)
+        int pageCount, string? revision)
     {
         var ttl = GetContextTtl(contextUri);
 
@@ -609,7 +719,8 @@ public sealed class ContextResolver
             NextPageUrl = nextPageUrl,
             TotalCount = totalCount,
             IsInfinite = isInfinite,
-            PageCount = pageCount
+            PageCount = pageCount,
+            Revision = revision
         });
 
         _logger?.LogDebug("Context cached: {ContextUri}, TTL={TtlMinutes}m", contextUri, ttl.TotalMinutes);
@@ -641,14 +752,19 @@ public sealed class ContextResolver
     // INTERNAL: METADATA EXTRACTION
     // ================================================================
 
-    private static string? FindNextPageUrl(Context context)
+    /// <summary>
+    /// First page still to fetch: a placeholder page that only carries a
+    /// <c>page_url</c> (large playlists resolve with every page after the first
+    /// left unloaded), or the <c>next_page_url</c> of a loaded page.
+    /// </summary>
+    internal static string? FindNextPageUrl(Context context)
     {
         foreach (var page in context.Pages)
         {
+            if (page.Tracks.Count == 0 && !string.IsNullOrEmpty(page.PageUrl))
+                return page.PageUrl;
             if (!string.IsNullOrEmpty(page.NextPageUrl))
                 return page.NextPageUrl;
-            if (!string.IsNullOrEmpty(page.PageUrl) && page.Tracks.Count > 0)
-                return page.PageUrl;
         }
         return null;
     }
@@ -0,0 +1,150 @@
+using System.Globalization;
+using Wavee.Core.Session;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Playback rules for the account's product, read from the ProductInfo attributes
+/// (<see cref="UserData.ProductAttributes"/>).
+/// </summary>
+/// <param name="HasAds">Spotify inserts ads and interruptions into contexts (<c>ads=1</c>).</param>
+/// <param name="OnDemand">The user may pick individual tracks (<c>on-demand</c>).</param>
+/// <param name="SkipsPerHour">Forward skips allowed per rolling hour; null when unlimited.</param>
+public sealed record ProductRestrictions(bool HasAds, bool OnDemand, int? SkipsPerHour)
+{
+    /// <summary>
+    /// Skips per hour for free accounts whose product state carries no
+    /// <c>skips-per-hour</c> value; the limit Spotify's free tier applies.
+    /// </summary>
+    public const int DefaultFreeSkipsPerHour = 6;
+
+    /// <summary>
+    /// No restrictions (premium, or product state not received yet).
+    /// </summary>
+    public static ProductRestrictions None { get; } = new(false, true, null);
+
+    /// <summary>
+    /// Reads the restrictions for <paramref name="userData"/>. Returns <see cref="None"/>
+    /// until ProductInfo has arrived, so a slow packet never limits a premium user.
+    /// </summary>
+    public static ProductRestrictions FromUserData(UserData? userData)
+    {
+        var attributes = userData?.ProductAttributes;
+        if (attributes is null)
+            return userData?.AccountType == AccountType.Free
+                ? new ProductRestrictions(true, true, DefaultFreeSkipsPerHour)
+                : None;
+
+        return FromAttributes(attributes, userData?.AccountType);
+    }
+
+    /// <summary>
+    /// Reads the restrictions from raw ProductInfo attributes.
+    /// </summary>
+    public static ProductRestrictions FromAttributes(
+        IReadOnlyDictionary<string, string> attributes,
+        AccountType? accountType = null)
+    {
+        ArgumentNullException.ThrowIfNull(attributes);
+
+        var isFree = accountType == AccountType.Free
+                     || (attributes.TryGetValue("type", out var type)
+                         && string.Equals(type, "free", StringComparison.OrdinalIgnoreCase));
+        var hasAds = attributes.TryGetValue("ads", out var ads) ? ads == "1" : isFree;
+        var onDemand = !attributes.TryGetValue("on-demand", out var demand) || demand != "0";
+
+        int? skipsPerHour = null;
+        if (attributes.TryGetValue("skips-per-hour", out var skipsRaw)
+            && int.TryParse(skipsRaw, NumberStyles.None, CultureInfo.InvariantCulture, out var skips))
+        {
+            skipsPerHour = skips;
+        }
+        else if (isFree)
+        {
+            skipsPerHour = DefaultFreeSkipsPerHour;
+        }
+
+        return new ProductRestrictions(hasAds, onDemand, skipsPerHour);
+    }
+}
+
+/// <summary>
+/// Counts forward skips in a rolling one-hour window.
+/// </summary>
+internal sealed class SkipLimiter
+{
+    internal static readonly TimeSpan Window = TimeSpan.FromHours(1);
+
+    private readonly Queue<DateTimeOffset> _skips = new();
+    private readonly object _lock = new();
+
+    /// <summary>
+    /// Records a skip if fewer than <paramref name="limit"/> happened in the last hour.
+    /// </summary>
+    /// <param name="limit">Skips allowed per hour.</param>
+    /// <param name="now">Current time.</param>
+    /// <param name="retryAfter">When refused, how long until the oldest skip leaves the window.</param>
+    /// <returns>True if the skip is allowed.</returns>
+    public bool TryConsume(int limit, DateTimeOffset now, out TimeSpan retryAfter)
+    {
+        lock (_lock)
+        {
+            while (_skips.Count > 0 && now - _skips.Peek() >= Window)
+                _skips.Dequeue();
+
+            if (_skips.Count >= limit)
+            {
+                retryAfter = _skips.Count > 0 ? _skips.Peek() + Window - now : Window;
+                return false;
+            }
+
+            _skips.Enqueue(now);
+            retryAfter = TimeSpan.Zero;
+            return true;
+        }
+    }
+}
+
+/// <summary>
+/// Kind of non-music item Spotify inserted into a context.
+/// </summary>
+public enum InterruptionKind
+{
+    /// <summary><c>spotify:ad:*</c> — an audio or video ad.</summary>
+    Ad,
+
+    /// <summary><c>spotify:interruption:*</c> — a jingle, announcement or upsell break.</summary>
+    Interruption
+}
+
+/// <summary>
+/// Published by <see cref="PlaybackOrchestrator.Interruptions"/> when playback reaches
+/// an ad or interruption entry. Wavee has no ad server session, so the entry is
+/// skipped; frontends can show an ad-break placeholder or upsell instead.
+/// </summary>
+/// <param name="Uri">The entry's URI.</param>
+/// <param name="Kind">Ad or interruption.</param>
+/// <param name="ContextUri">Context the entry came from.</param>
+/// <param name="Metadata">The entry's context metadata (ad ids, durations) as sent by Spotify.</param>
+public sealed record InterruptionEvent(
+    string Uri,
+    InterruptionKind Kind,
+    string? ContextUri,
+    IReadOnlyDictionary<string, string>? Metadata);
+
+/// <summary>
+/// Why an action was refused for the account's product.
+/// </summary>
+public enum UpsellReason
+{
+    /// <summary>The hourly forward-skip allowance is used up.</summary>
+    SkipLimitReached
+}
+
+/// <summary>
+/// Published by <see cref="PlaybackOrchestrator.Upsells"/> when a product restriction
+/// refuses an action. Frontends render the matching premium prompt.
+/// </summary>
+/// <param name="Reason">What was refused.</param>
+/// <param name="RetryAfter">When the action becomes available again, if it does.</param>
+public sealed record UpsellEvent(UpsellReason Reason, TimeSpan? RetryAfter);
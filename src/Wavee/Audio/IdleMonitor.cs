@@ -0,0 +1,120 @@
+namespace Wavee.Audio;
+
+/// <summary>
+/// Reports when playback has been inactive for a set time, and when it wakes again.
+/// </summary>
+/// <remarks>
+/// Arms a timer whenever activity stops; the timer firing enters idle. Activity, or
+/// <see cref="Wake"/> (any remote command), leaves idle. After a wake without
+/// playback the timer re-arms, so an ignored command drops back into idle later.
+/// </remarks>
+internal sealed class IdleMonitor : IDisposable
+{
+    private readonly TimeSpan _timeout;
+    private readonly Action<bool> _onIdleChanged;
+    private readonly ITimer _timer;
+    private readonly object _lock = new();
+
+    private bool _active;
+    private bool _idle;
+    private bool _disposed;
+
+    /// <param name="timeout">Inactivity before entering idle; must be positive.</param>
+    /// <param name="onIdleChanged">Called with true on entering idle, false on leaving it. Not called under a lock.</param>
+    /// <param name="timeProvider">Clock for the idle timer. Defaults to the system clock.</param>
+    public IdleMonitor(TimeSpan timeout, Action<bool> onIdleChanged, TimeProvider? timeProvider = null)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThanOrEqual(timeout, TimeSpan.Zero);
+        _timeout = timeout;
+        _onIdleChanged = onIdleChanged ?? throw new ArgumentNullException(nameof(onIdleChanged));
+        _timer = (timeProvider ?? TimeProvider.System)
+            .CreateTimer(_ => OnTimeout(), null, timeout, Timeout.InfiniteTimeSpan);
+    }
+
+    /// <summary>
+    /// True between the timeout firing and the next activity or wake.
+    /// </summary>
+    public bool IsIdle
+    {
+        get
+        {
+            lock (_lock) return _idle;
+        }
+    }
+
+    /// <summary>
+    /// Records whether playback is running. Active wakes immediately; inactive arms the timer.
+    /// </summary>
+    public void SetActive(bool active)
+    {
+        bool woke;
+        lock (_lock)
+        {
+            if (_disposed || _active == active)
+                return;
+
+            _active = active;
+            woke = active && _idle;
+            if (active)
+            {
+                _idle = false;
+                _timer.Change(Timeout.InfiniteTimeSpan, Timeout.InfiniteTimeSpan);
+            }
+            else if (!_idle)
+            {
+                _timer.Change(_timeout, Timeout.InfiniteTimeSpan);
+            }
+        }
+
+        if (woke)
+            _onIdleChanged(false);
+    }
+
+    /// <summary>
+    /// Leaves idle without playback starting (e.g. a remote command arrived).
+    /// Restarts the countdown when idle or not.
+    /// </summary>
+    public void Wake()
+    {
+        bool woke;
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+
+            woke = _idle;
+            _idle = false;
+            if (!_active)
+                _timer.Change(_timeout, Timeout.InfiniteTimeSpan);
+        }
+
+        if (woke)
+            _onIdleChanged(false);
+    }
+
+    private void OnTimeout()
+    {
+        lock (_lock)
+        {
+            if (_disposed || _active || _idle)
+                return;
+
+            _idle = true;
+        }
+
+        _onIdleChanged(true);
+    }
+
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+
+            _disposed = true;
+        }
+
+        _timer.Dispose();
+    }
+}
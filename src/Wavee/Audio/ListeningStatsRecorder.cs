@@ -0,0 +1,61 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage.Abstractions;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Folds finished tracks (<see cref="PlaybackOrchestrator.TrackStatsCompleted"/>) into the
+/// listening stats of an <see cref="IMetadataDatabase"/>. Wired by <see cref="PlayerBuilder.WithListeningStats"/>.
+/// </summary>
+internal static class ListeningStatsRecorder
+{
+    /// <summary>
+    /// Listened time from which a track counts as played, the same threshold Spotify
+    /// uses for a stream. Shorter listens still add their time.
+    /// </summary>
+    public static readonly TimeSpan MinCountedPlay = TimeSpan.FromSeconds(30);
+
+    /// <returns>The entry to record, or null when nothing was heard.</returns>
+    public static ListeningEntry? ToEntry(TrackPlaybackStats stats)
+    {
+        var msPlayed = stats.MsPlayed;
+        if (msPlayed <= 0)
+            return null;
+
+        return new ListeningEntry
+        {
+            TrackUri = stats.TrackUri,
+            Title = stats.Title,
+            // Multi-artist tracks carry a joined "A, B" display string; ArtistUri is the first.
+            ArtistName = stats.Artist?.Split(", ")[0].Trim() is { Length: > 0 } artist ? artist : null,
+            ArtistUri = stats.ArtistUri,
+            StartedAt = stats.StartedAt,
+            MsPlayed = msPlayed,
+            CountsAsPlay = stats.Played >= MinCountedPlay
+        };
+    }
+
+    public static IDisposable Attach(IObservable<TrackPlaybackStats> completed, IMetadataDatabase database, ILogger? logger)
+    {
+        ArgumentNullException.ThrowIfNull(completed);
+        ArgumentNullException.ThrowIfNull(database);
+
+        return completed
+            .Select(ToEntry)
+            .Where(entry => entry is not null)
+            .Subscribe(entry => _ = RecordAsync(database, entry!, logger));
+    }
+
+    private static async Task RecordAsync(IMetadataDatabase database, ListeningEntry entry, ILogger? logger)
+    {
+        try
+        {
+            await database.RecordListeningAsync(entry);
+        }
+        catch (Exception ex)
+        {
+            logger?.LogDebug(ex, "Failed to record listening stats for {Uri}", entry.TrackUri);
+        }
+    }
+}
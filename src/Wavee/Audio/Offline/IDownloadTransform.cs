@@ -0,0 +1,68 @@
+using Wavee.Core.Crypto;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.Audio.Offline;
+
+/// <summary>
+/// Post-download hook for the offline store, e.g. transcoding to Opus to save space.
+/// </summary>
+/// <remarks>
+/// The encrypted source file is kept either way — it is what playback reads. The
+/// transform writes an additional artifact whose size and SHA-256 are recorded on the
+/// <see cref="Core.Storage.Abstractions.OfflineTrackEntry"/> so it can be verified later
+/// with <see cref="OfflineDownloadService.VerifyArtifactAsync"/>.
+/// </remarks>
+public interface IDownloadTransform
+{
+    /// <summary>
+    /// Short, stable name recorded with the artifact (e.g. <c>"opus-96"</c>).
+    /// </summary>
+    string Name { get; }
+
+    /// <summary>
+    /// Produces the artifact for a freshly downloaded track.
+    /// </summary>
+    /// <param name="context">The downloaded file and where to write the artifact.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The artifact that was written.</returns>
+    Task<DownloadTransformResult> TransformAsync(DownloadTransformContext context, CancellationToken cancellationToken);
+}
+
+/// <summary>
+/// Input to <see cref="IDownloadTransform.TransformAsync"/>.
+/// </summary>
+public sealed class DownloadTransformContext
+{
+    public required string TrackUri { get; init; }
+
+    /// <summary>Spotify file id (40-char hex) of the downloaded file.</summary>
+    public required string FileId { get; init; }
+
+    public required string Codec { get; init; }
+    public int BitrateKbps { get; init; }
+
+    /// <summary>Audio cache directory holding the encrypted file.</summary>
+    public required string CacheDirectory { get; init; }
+
+    /// <summary>16-byte AES key for the cached file.</summary>
+    public required byte[] AudioKey { get; init; }
+
+    /// <summary>Directory the artifact should be written to. Already exists.</summary>
+    public required string OutputDirectory { get; init; }
+
+    /// <summary>
+    /// Opens the encrypted file as stored in the audio cache. Reads are checksum-verified.
+    /// </summary>
+    public Stream OpenEncrypted() => AudioFileCache.OpenRead(CacheDirectory, FileId);
+
+    /// <summary>
+    /// Opens the decrypted audio. Vorbis files still start with Spotify's 0xa7-byte header.
+    /// </summary>
+    public Stream OpenDecrypted() => new AudioDecryptStream(AudioKey, OpenEncrypted());
+}
+
+/// <summary>
+/// Output of <see cref="IDownloadTransform.TransformAsync"/>.
+/// </summary>
+/// <param name="Path">Full path of the artifact that was written.</param>
+public sealed record DownloadTransformResult(string Path);
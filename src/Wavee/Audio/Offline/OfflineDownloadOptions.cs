@@ -0,0 +1,33 @@
+using Wavee.Core.Audio;
+
+namespace Wavee.Audio.Offline;
+
+/// <summary>
+/// How tracks are stored in the offline store. Independent of the streaming quality
+/// passed to <see cref="TrackResolver"/>.
+/// </summary>
+public sealed record OfflineDownloadOptions
+{
+    /// <summary>
+    /// Default options: 320 kbps Vorbis (falling back to what the track offers), no transform.
+    /// </summary>
+    public static OfflineDownloadOptions Default { get; } = new();
+
+    /// <summary>
+    /// Quality of the stored audio file. Falls back the same way streaming does when a
+    /// track doesn't offer the preferred format.
+    /// </summary>
+    public AudioQuality Quality { get; init; } = AudioQuality.VeryHigh;
+
+    /// <summary>
+    /// Optional hook run after each download (e.g. an Opus transcode to save space).
+    /// </summary>
+    public IDownloadTransform? Transform { get; init; }
+
+    /// <summary>
+    /// Maximum bytes the offline store may use (audio plus artifacts), or null for no limit.
+    /// When exceeded, the least recently played tracks that aren't in a pinned playlist or
+    /// album are evicted.
+    /// </summary>
+    public long? QuotaBytes { get; init; }
+}
@@ -0,0 +1,422 @@
+using System.Security.Cryptography;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Audio;
+using Wavee.Core.Storage.Abstractions;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.Audio.Offline;
+
+/// <summary>
+/// Integrity of a transformed artifact, as reported by <see cref="OfflineDownloadService.VerifyArtifactAsync"/>.
+/// </summary>
+public enum OfflineArtifactStatus
+{
+    /// <summary>The track isn't downloaded or no transform ran for it.</summary>
+    None,
+
+    /// <summary>The artifact matches the recorded size and SHA-256.</summary>
+    Valid,
+
+    /// <summary>The artifact file is gone.</summary>
+    Missing,
+
+    /// <summary>The artifact no longer matches its recorded SHA-256.</summary>
+    Corrupt
+}
+
+/// <summary>
+/// Downloads tracks into the offline store at <see cref="OfflineDownloadOptions.Quality"/>
+/// and runs the optional post-download transform.
+/// </summary>
+/// <remarks>
+/// The encrypted audio goes into the same disk cache AudioHost streams into, so
+/// <see cref="TrackResolver"/> plays it without touching the CDN. Each download is
+/// recorded in the <c>offline_tracks</c> table; that record is how the resolver finds a
+/// download stored at a different quality than the current streaming one.
+/// </remarks>
+public sealed class OfflineDownloadService
+{
+    private const string ArtifactSubDir = "offline";
+
+    private readonly Func<string, AudioQuality, CancellationToken, Task<TrackResolution>> _resolve;
+    private readonly IMetadataDatabase _database;
+    private readonly HttpClient _httpClient;
+    private readonly string _audioCacheDirectory;
+    private readonly TimeProvider _timeProvider;
+    private readonly ILogger? _logger;
+
+    /// <summary>
+    /// Creates a download service resolving tracks through <paramref name="trackResolver"/>.
+    /// </summary>
+    /// <param name="trackResolver">Resolver used to pick the file and CDN URL at the download quality.</param>
+    /// <param name="database">Database holding the offline store index.</param>
+    /// <param name="httpClient">HTTP client for CDN downloads.</param>
+    /// <param name="audioCacheDirectory">AudioHost's persistent audio cache directory.</param>
+    /// <param name="options">Download format and transform; defaults to <see cref="OfflineDownloadOptions.Default"/>.</param>
+    /// <param name="timeProvider">Clock for download timestamps; defaults to <see cref="TimeProvider.System"/>.</param>
+    /// <param name="logger">Optional logger.</param>
+    public OfflineDownloadService(
+        TrackResolver trackResolver,
+        IMetadataDatabase database,
+        HttpClient httpClient,
+        string audioCacheDirectory,
+        OfflineDownloadOptions? options = null,
+        TimeProvider? timeProvider = null,
+        ILogger? logger = null)
+        : this(
+            (trackResolver ?? throw new ArgumentNullException(nameof(trackResolver))).ResolveForDownloadAsync,
+            database, httpClient, audioCacheDirectory, options, timeProvider, logger)
+    {
+    }
+
+    internal OfflineDownloadService(
+        Func<string, AudioQuality, CancellationToken, Task<TrackResolution>> resolve,
+        IMetadataDatabase database,
+        HttpClient httpClient,
+        string audioCacheDirectory,
+        OfflineDownloadOptions? options = null,
+        TimeProvider? timeProvider = null,
+        ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(resolve);
+        ArgumentNullException.ThrowIfNull(database);
+        ArgumentNullException.ThrowIfNull(httpClient);
+        ArgumentException.ThrowIfNullOrWhiteSpace(audioCacheDirectory);
+
+        _resolve = resolve;
+        _database = database;
+        _httpClient = httpClient;
+        _audioCacheDirectory = audioCacheDirectory;
+        Options = options ?? OfflineDownloadOptions.Default;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Format and transform used for subsequent downloads. Changing it doesn't touch
+    /// tracks already in the store.
+    /// </summary>
+    public OfflineDownloadOptions Options { get; set; }
+
+    /// <summary>
+    /// Gets the offline-store entry for a track, or null if it isn't downloaded.
+    /// </summary>
+    public Task<OfflineTrackEntry?> GetAsync(string trackUri, CancellationToken ct = default)
+        => _database.GetOfflineTrackAsync(trackUri, ct);
+
+    /// <summary>
+    /// Downloads a track into the offline store and runs the configured transform.
+    /// </summary>
+    /// <remarks>
+    /// A transform failure is logged and the track is stored without an artifact — the
+    /// encrypted file is still playable.
+    /// </remarks>
+    /// <param name="trackUri">Track or episode URI.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>The recorded entry.</returns>
+    public async Task<OfflineTrackEntry> DownloadAsync(string trackUri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(trackUri);
+
+        var entry = await DownloadCoreAsync(trackUri, ct);
+        await EnforceQuotaAsync(ct);
+        return entry;
+    }
+
+    private async Task<OfflineTrackEntry> DownloadCoreAsync(string trackUri, CancellationToken ct)
+    {
+        var options = Options;
+        var resolution = await _resolve(trackUri, options.Quality, ct);
+        var fileId = resolution.SpotifyFileId
+            ?? throw new InvalidOperationException($"No audio file resolved for {trackUri}");
+
+        if (resolution.LocalCacheFileId == null)
+            await DownloadFileAsync(await resolution.CdnUrlTask.WaitAsync(ct), fileId, ct);
+
+        // The key manager persists the key, so the download plays without the AP
+        var audioKey = await resolution.AudioKeyTask.WaitAsync(ct);
+
+        var entry = new OfflineTrackEntry
+        {
+            TrackUri = trackUri,
+            FileId = fileId,
+            Codec = resolution.Codec,
+            BitrateKbps = resolution.BitrateKbps,
+            SizeBytes = AudioFileCache.GetCachedFileSize(_audioCacheDirectory, fileId),
+            DownloadedAt = _timeProvider.GetUtcNow().ToUnixTimeSeconds()
+        };
+
+        if (options.Transform is { } transform)
+            entry = await RunTransformAsync(transform, entry, audioKey, ct);
+
+        var previous = await _database.GetOfflineTrackAsync(trackUri, ct);
+        entry = entry with { LastPlayedAt = previous?.LastPlayedAt };
+        await _database.UpsertOfflineTrackAsync(entry, ct);
+
+        if (previous?.ArtifactPath is { } oldArtifact && oldArtifact != entry.ArtifactPath)
+            TryDelete(oldArtifact);
+        if (previous != null && previous.FileId != entry.FileId)
+            AudioFileCache.Delete(_audioCacheDirectory, previous.FileId);
+
+        _logger?.LogInformation("Downloaded {Uri} ({Codec} {Bitrate} kbps, {Bytes} bytes{Transform})",
+            trackUri, entry.Codec, entry.BitrateKbps, entry.SizeBytes,
+            entry.Transform is null ? "" : $", {entry.Transform} artifact");
+        return entry;
+    }
+
+    /// <summary>
+    /// Checks a track's transformed artifact against the size and SHA-256 recorded when it
+    /// was written. A missing or corrupt artifact is dropped from the entry (and deleted)
+    /// so the next <see cref="DownloadAsync"/> recreates it.
+    /// </summary>
+    public async Task<OfflineArtifactStatus> VerifyArtifactAsync(string trackUri, CancellationToken ct = default)
+    {
+        var entry = await _database.GetOfflineTrackAsync(trackUri, ct);
+        if (entry?.ArtifactPath is not { } artifactPath || entry.ArtifactSha256 is null)
+            return OfflineArtifactStatus.None;
+
+        OfflineArtifactStatus status;
+        if (!File.Exists(artifactPath))
+        {
+            status = OfflineArtifactStatus.Missing;
+        }
+        else
+        {
+            var (size, sha256) = await HashFileAsync(artifactPath, ct);
+            status = size == entry.ArtifactSizeBytes
+                     && string.Equals(sha256, entry.ArtifactSha256, StringComparison.OrdinalIgnoreCase)
+                ? OfflineArtifactStatus.Valid
+                : OfflineArtifactStatus.Corrupt;
+        }
+
+        if (status != OfflineArtifactStatus.Valid)
+        {
+            _logger?.LogWarning("Offline artifact for {Uri} is {Status}; dropping it", trackUri, status);
+            TryDelete(artifactPath);
+            await _database.UpsertOfflineTrackAsync(entry with
+            {
+                Transform = null,
+                ArtifactPath = null,
+                ArtifactSizeBytes = null,
+                ArtifactSha256 = null
+            }, ct);
+        }
+
+        return status;
+    }
+
+    /// <summary>
+    /// Pins a playlist or album: downloads its tracks that aren't in the store yet and
+    /// protects all of them from quota eviction.
+    /// </summary>
+    /// <remarks>
+    /// Re-pinning replaces the recorded track list, so call it again after the playlist
+    /// changes. Tracks that fail to download are logged and skipped.
+    /// </remarks>
+    /// <param name="contextUri">Playlist or album URI.</param>
+    /// <param name="trackUris">The context's tracks.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>The number of tracks newly downloaded.</returns>
+    public async Task<int> PinAsync(string contextUri, IReadOnlyList<string> trackUris, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(contextUri);
+        ArgumentNullException.ThrowIfNull(trackUris);
+
+        await _database.SetOfflinePinAsync(contextUri, trackUris, ct);
+
+        var downloaded = 0;
+        foreach (var trackUri in trackUris.Distinct(StringComparer.Ordinal))
+        {
+            if (await _database.GetOfflineTrackAsync(trackUri, ct) != null)
+                continue;
+
+            try
+            {
+                await DownloadCoreAsync(trackUri, ct);
+                downloaded++;
+            }
+            catch (Exception ex) when (ex is not OperationCanceledException)
+            {
+                _logger?.LogWarning(ex, "Failed to download {Uri} for pinned {Context}", trackUri, contextUri);
+            }
+        }
+
+        await EnforceQuotaAsync(ct);
+        return downloaded;
+    }
+
+    /// <summary>
+    /// Unpins a playlist or album. Its tracks stay downloaded until the quota evicts them.
+    /// </summary>
+    public async Task UnpinAsync(string contextUri, CancellationToken ct = default)
+    {
+        await _database.DeleteOfflinePinAsync(contextUri, ct);
+        await EnforceQuotaAsync(ct);
+    }
+
+    /// <summary>
+    /// Evicts the least recently played unpinned tracks until the store fits
+    /// <see cref="OfflineDownloadOptions.QuotaBytes"/>. Pinned tracks are never evicted,
+    /// so the store can stay over quota when pins alone exceed it.
+    /// </summary>
+    /// <returns>URIs of the evicted tracks, in eviction order.</returns>
+    public async Task<IReadOnlyList<string>> EnforceQuotaAsync(CancellationToken ct = default)
+    {
+        if (Options.QuotaBytes is not { } quota)
+            return [];
+
+        var tracks = await _database.GetOfflineTracksAsync(ct);
+        var total = tracks.Sum(SizeOf);
+        if (total <= quota)
+            return [];
+
+        var pinned = await GetPinnedTrackUrisAsync(ct);
+        var evicted = new List<string>();
+        foreach (var entry in tracks
+                     .Where(t => !pinned.Contains(t.TrackUri))
+                     .OrderBy(t => t.LastPlayedAt ?? t.DownloadedAt))
+        {
+            if (total <= quota)
+                break;
+
+            await RemoveEntryAsync(entry, ct);
+            total -= SizeOf(entry);
+            evicted.Add(entry.TrackUri);
+        }
+
+        if (total > quota)
+            _logger?.LogWarning("Offline store uses {Total} bytes, over the {Quota}-byte quota, with only pinned tracks left", total, quota);
+        else
+            _logger?.LogInformation("Evicted {Count} offline tracks to stay under the {Quota}-byte quota", evicted.Count, quota);
+
+        return evicted;
+    }
+
+    /// <summary>
+    /// Reports how much space the offline store uses, per track and per pinned context.
+    /// </summary>
+    public async Task<OfflineStorageUsage> GetStorageUsageAsync(CancellationToken ct = default)
+    {
+        var tracks = await _database.GetOfflineTracksAsync(ct);
+        var pins = await _database.GetOfflinePinsAsync(ct);
+        var pinned = pins.SelectMany(p => p.TrackUris).ToHashSet(StringComparer.Ordinal);
+        var byUri = tracks.ToDictionary(t => t.TrackUri, StringComparer.Ordinal);
+
+        var trackUsage = tracks
+            .Select(t => new OfflineTrackUsage(t.TrackUri, SizeOf(t), pinned.Contains(t.TrackUri), t.LastPlayedAt ?? t.DownloadedAt))
+            .OrderByDescending(t => t.Bytes)
+            .ToList();
+
+        var pinUsage = pins
+            .Select(p =>
+            {
+                var downloaded = p.TrackUris.Where(byUri.ContainsKey).ToList();
+                return new OfflinePinUsage(p.ContextUri, p.TrackUris.Count, downloaded.Count, downloaded.Sum(u => SizeOf(byUri[u])));
+            })
+            .ToList();
+
+        return new OfflineStorageUsage(trackUsage.Sum(t => t.Bytes), Options.QuotaBytes, trackUsage, pinUsage);
+    }
+
+    /// <summary>
+    /// Removes a track from the offline store, deleting its audio file and artifact.
+    /// </summary>
+    public async Task RemoveAsync(string trackUri, CancellationToken ct = default)
+    {
+        var entry = await _database.GetOfflineTrackAsync(trackUri, ct);
+        if (entry != null)
+            await RemoveEntryAsync(entry, ct);
+    }
+
+    private async Task RemoveEntryAsync(OfflineTrackEntry entry, CancellationToken ct)
+    {
+        await _database.DeleteOfflineTrackAsync(entry.TrackUri, ct);
+        AudioFileCache.Delete(_audioCacheDirectory, entry.FileId);
+        if (entry.ArtifactPath is { } artifactPath)
+            TryDelete(artifactPath);
+    }
+
+    private async Task<HashSet<string>> GetPinnedTrackUrisAsync(CancellationToken ct)
+    {
+        var pins = await _database.GetOfflinePinsAsync(ct);
+        return pins.SelectMany(p => p.TrackUris).ToHashSet(StringComparer.Ordinal);
+    }
+
+    private static long SizeOf(OfflineTrackEntry entry) => entry.SizeBytes + (entry.ArtifactSizeBytes ?? 0);
+
+    private async Task DownloadFileAsync(string cdnUrl, string fileId, CancellationToken ct)
+    {
+        using var response = await _httpClient.GetAsync(cdnUrl, HttpCompletionOption.ResponseHeadersRead, ct);
+        response.EnsureSuccessStatusCode();
+
+        // Nothing is visible in the cache until the manifest is committed
+        using var writer = AudioFileCache.BeginWrite(_audioCacheDirectory, fileId);
+        await using (var source = await response.Content.ReadAsStreamAsync(ct))
+        {
+            var buffer = new byte[81920];
+            int read;
+            while ((read = await source.ReadAsync(buffer, ct)) > 0)
+                writer.Write(buffer.AsSpan(0, read));
+        }
+
+        writer.Commit();
+    }
+
+    private async Task<OfflineTrackEntry> RunTransformAsync(
+        IDownloadTransform transform, OfflineTrackEntry entry, byte[] audioKey, CancellationToken ct)
+    {
+        var outputDirectory = Path.Combine(_audioCacheDirectory, ArtifactSubDir);
+        Directory.CreateDirectory(outputDirectory);
+
+        try
+        {
+            var result = await transform.TransformAsync(new DownloadTransformContext
+            {
+                TrackUri = entry.TrackUri,
+                FileId = entry.FileId,
+                Codec = entry.Codec,
+                BitrateKbps = entry.BitrateKbps,
+                CacheDirectory = _audioCacheDirectory,
+                AudioKey = audioKey,
+                OutputDirectory = outputDirectory
+            }, ct);
+
+            if (!File.Exists(result.Path))
+                throw new FileNotFoundException("Transform reported an artifact that doesn't exist", result.Path);
+
+            var (size, sha256) = await HashFileAsync(result.Path, ct);
+            return entry with
+            {
+                Transform = transform.Name,
+                ArtifactPath = result.Path,
+                ArtifactSizeBytes = size,
+                ArtifactSha256 = sha256
+            };
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Transform {Transform} failed for {Uri}; keeping the plain download",
+                transform.Name, entry.TrackUri);
+            return entry;
+        }
+    }
+
+    private static async Task<(long Size, string Sha256)> HashFileAsync(string path, CancellationToken ct)
+    {
+        await using var stream = File.OpenRead(path);
+        var hash = await SHA256.HashDataAsync(stream, ct);
+        return (stream.Length, Convert.ToHexStringLower(hash));
+    }
+
+    private void TryDelete(string path)
+    {
+        try
+        {
+            File.Delete(path);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogDebug(ex, "Failed to delete {Path}", path);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+namespace Wavee.Audio.Offline;
+
+/// <summary>
+/// Space used by the offline store, as reported by <see cref="OfflineDownloadService.GetStorageUsageAsync"/>.
+/// </summary>
+/// <param name="TotalBytes">Audio plus artifacts across all downloaded tracks.</param>
+/// <param name="QuotaBytes">The configured quota, or null when unlimited.</param>
+/// <param name="Tracks">Per-track usage, largest first.</param>
+/// <param name="Pins">Per pinned playlist/album usage.</param>
+public sealed record OfflineStorageUsage(
+    long TotalBytes,
+    long? QuotaBytes,
+    IReadOnlyList<OfflineTrackUsage> Tracks,
+    IReadOnlyList<OfflinePinUsage> Pins);
+
+/// <summary>
+/// Space used by one downloaded track.
+/// </summary>
+/// <param name="TrackUri">The track URI.</param>
+/// <param name="Bytes">Audio plus artifact size.</param>
+/// <param name="IsPinned">Whether a pinned playlist/album protects it from eviction.</param>
+/// <param name="LastUsedAt">Unix seconds of the last play, or of the download if never played.</param>
+public sealed record OfflineTrackUsage(string TrackUri, long Bytes, bool IsPinned, long LastUsedAt);
+
+/// <summary>
+/// Space used by one pinned playlist or album. Tracks shared between pins count towards each.
+/// </summary>
+/// <param name="ContextUri">The playlist or album URI.</param>
+/// <param name="TrackCount">Tracks recorded when it was pinned.</param>
+/// <param name="DownloadedCount">Of those, how many are in the store.</param>
+/// <param name="Bytes">Size of the downloaded ones.</param>
+public sealed record OfflinePinUsage(string ContextUri, int TrackCount, int DownloadedCount, long Bytes);
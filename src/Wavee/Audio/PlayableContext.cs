@@ -0,0 +1,78 @@
+using System.Diagnostics.CodeAnalysis;
+using Wavee.Connect.Commands;
+using Wavee.Core;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// A context URI (plus optional start track) that <see cref="PlaybackOrchestrator.PlayAsync"/>
+/// can load, resolved from an arbitrary <c>spotify:</c> URI or <c>open.spotify.com</c> URL.
+/// </summary>
+/// <remarks>
+/// Collections (album, playlist, artist, show) play as themselves. Single items
+/// (track, episode) use their own URI as the context — context-resolve returns a
+/// one-item context for them, and the orchestrator's autoplay takes over at the end.
+/// </remarks>
+/// <param name="Kind">The kind of link that was resolved.</param>
+/// <param name="ContextUri">Canonical context URI to resolve and play.</param>
+/// <param name="TrackUri">Item to start on, for track/episode links; otherwise null.</param>
+public sealed record PlayableContext(
+    SpotifyLinkKind Kind,
+    string ContextUri,
+    string? TrackUri)
+{
+    /// <summary>
+    /// Resolves a Spotify URI or open.spotify.com URL to a playable context.
+    /// </summary>
+    /// <param name="uriOrUrl">e.g. <c>spotify:album:xxx</c> or <c>https://open.spotify.com/intl-de/track/xxx?si=...</c>.</param>
+    /// <param name="context">The resolved context.</param>
+    /// <returns>False if the input isn't a Spotify link or names something that can't be played (user, genre page).</returns>
+    public static bool TryResolve(string? uriOrUrl, [NotNullWhen(true)] out PlayableContext? context)
+    {
+        context = null;
+        if (!SpotifyLink.TryParse(uriOrUrl, out var link))
+            return false;
+
+        switch (link.Kind)
+        {
+            case SpotifyLinkKind.Album:
+            case SpotifyLinkKind.Playlist:
+            case SpotifyLinkKind.Artist:
+            case SpotifyLinkKind.Show:
+                context = new PlayableContext(link.Kind, link.CanonicalUri, null);
+                return true;
+            case SpotifyLinkKind.Track:
+            case SpotifyLinkKind.Episode:
+                context = new PlayableContext(link.Kind, link.CanonicalUri, link.CanonicalUri);
+                return true;
+            default:
+                return false;
+        }
+    }
+
+    /// <summary>
+    /// Resolves a Spotify URI or open.spotify.com URL to a playable context.
+    /// </summary>
+    /// <exception cref="ArgumentException">The input can't be resolved to a playable context.</exception>
+    public static PlayableContext Resolve(string uriOrUrl)
+    {
+        if (!TryResolve(uriOrUrl, out var context))
+            throw new ArgumentException($"Not a playable Spotify URI or URL: {uriOrUrl}", nameof(uriOrUrl));
+        return context;
+    }
+
+    /// <summary>
+    /// Builds a locally-initiated <see cref="PlayCommand"/> for this context.
+    /// </summary>
+    /// <param name="senderDeviceId">Local device id (empty marks the play as local UI).</param>
+    public PlayCommand ToPlayCommand(string senderDeviceId = "") => new()
+    {
+        Endpoint = "play",
+        MessageIdent = "local",
+        MessageId = 0,
+        SenderDeviceId = senderDeviceId,
+        Key = "local/0",
+        ContextUri = ContextUri,
+        TrackUri = TrackUri
+    };
+}
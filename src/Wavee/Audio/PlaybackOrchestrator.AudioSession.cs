@@ -0,0 +1,63 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// OS audio session interruptions: hosts that own the platform audio session (an
+/// iOS app observing AVAudioSession, an Android app losing audio focus) report them
+/// here, and playback pauses and resumes the way the platform expects.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    private readonly AudioSessionInterruption _audioSessionInterruption = new();
+    private IDisposable? _audioSessionPlaybackSubscription;
+
+    /// <summary>
+    /// True between <see cref="BeginAudioSessionInterruptionAsync"/> and
+    /// <see cref="EndAudioSessionInterruptionAsync"/>.
+    /// </summary>
+    public bool IsAudioSessionInterrupted => _audioSessionInterruption.IsInterrupted;
+
+    /// <summary>
+    /// The OS took the audio session away. Pauses if playing; repeated calls are ignored.
+    /// </summary>
+    public async Task BeginAudioSessionInterruptionAsync(CancellationToken ct = default)
+    {
+        if (!_audioSessionInterruption.Begin(IsActivePlayback(_stateSubject.Value)))
+            return;
+
+        _logger?.LogInformation("Orchestrator: audio session interrupted, pausing");
+
+        // Skip the current value: only playback starting after this point means the user took over.
+        _audioSessionPlaybackSubscription?.Dispose();
+        _audioSessionPlaybackSubscription = _stateSubject
+            .Select(IsActivePlayback)
+            .DistinctUntilChanged()
+            .Skip(1)
+            .Where(active => active)
+            .Subscribe(_ => _audioSessionInterruption.PlaybackStarted());
+
+        await PauseAsync(ct);
+    }
+
+    /// <summary>
+    /// The OS handed the audio session back.
+    /// </summary>
+    /// <param name="shouldResume">
+    /// The platform's hint that playback may continue, such as AVAudioSession's
+    /// <c>shouldResume</c> option. Without it playback stays paused.
+    /// </param>
+    /// <param name="ct">Cancellation token.</param>
+    public async Task EndAudioSessionInterruptionAsync(bool shouldResume, CancellationToken ct = default)
+    {
+        _audioSessionPlaybackSubscription?.Dispose();
+        _audioSessionPlaybackSubscription = null;
+
+        if (!_audioSessionInterruption.End(shouldResume))
+            return;
+
+        _logger?.LogInformation("Orchestrator: audio session interruption ended, resuming");
+        await ResumeAsync(ct);
+    }
+}
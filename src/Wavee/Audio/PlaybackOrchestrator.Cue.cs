@@ -0,0 +1,205 @@
+using System.Collections.Immutable;
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// DJ-style hot cues and A-B loop for the current track. Cue points live here;
+/// the loop itself runs in AudioHost, which cuts it at the loop end's exact sample.
+/// Both are cleared when the track changes.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    /// <summary>
+    /// Number of hot cue slots per track.
+    /// </summary>
+    public const int CueSlotCount = 8;
+
+    private readonly object _cueLock = new();
+    private readonly BehaviorSubject<CueState> _cueSubject = new(CueState.Empty);
+
+    /// <summary>
+    /// Cue points and loop of the current track.
+    /// </summary>
+    public CueState Cues => _cueSubject.Value;
+
+    /// <summary>
+    /// Emits whenever a cue point or the loop changes, and on track change.
+    /// Replays the current value to new subscribers.
+    /// </summary>
+    public IObservable<CueState> CuesChanged => _cueSubject.AsObservable();
+
+    /// <summary>
+    /// Stores a cue point in <paramref name="slot"/>, at <paramref name="positionMs"/>
+    /// or the current position when null.
+    /// </summary>
+    /// <returns>The stored position, or null when nothing is loaded.</returns>
+    public long? SetCuePoint(int slot, long? positionMs = null)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegative(slot);
+        ArgumentOutOfRangeException.ThrowIfGreaterThanOrEqual(slot, CueSlotCount);
+
+        if (!TryResolveCuePosition(positionMs, out var trackUri, out var cueMs))
+            return null;
+
+        UpdateCues(trackUri, cues => cues with { CuePoints = cues.CuePoints.SetItem(slot, cueMs) });
+        _logger?.LogDebug("Orchestrator: cue {Slot} set at {Pos}ms", slot, cueMs);
+        return cueMs;
+    }
+
+    /// <summary>
+    /// Empties <paramref name="slot"/>.
+    /// </summary>
+    public void ClearCuePoint(int slot)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegative(slot);
+        ArgumentOutOfRangeException.ThrowIfGreaterThanOrEqual(slot, CueSlotCount);
+
+        UpdateCues(null, cues => cues with { CuePoints = cues.CuePoints.SetItem(slot, null) });
+    }
+
+    /// <summary>
+    /// Seeks to the cue point in <paramref name="slot"/>.
+    /// </summary>
+    /// <returns>False when the slot is empty.</returns>
+    public async Task<bool> JumpToCuePointAsync(int slot, CancellationToken ct = default)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegative(slot);
+        ArgumentOutOfRangeException.ThrowIfGreaterThanOrEqual(slot, CueSlotCount);
+
+        if (Cues.CuePoints[slot] is not { } cueMs)
+            return false;
+
+        await SeekAsync(cueMs, ct);
+        return true;
+    }
+
+    /// <summary>
+    /// Loops the current track between <paramref name="startMs"/> and <paramref name="endMs"/>.
+    /// </summary>
+    /// <returns>False when nothing is loaded, video is playing or the bounds are empty.</returns>
+    public async Task<bool> SetLoopAsync(long startMs, long endMs, CancellationToken ct = default)
+    {
+        var state = _stateSubject.Value;
+        if (state.TrackUri is not { } trackUri || _videoEngineActive)
+            return false;
+
+        startMs = ClampSeekPosition(startMs, state.DurationMs);
+        endMs = ClampSeekPosition(endMs, state.DurationMs);
+        if (endMs <= startMs)
+            return false;
+
+        await _proxy.SetLoopRegionAsync(trackUri, startMs, endMs, ct);
+        UpdateCues(trackUri, cues => cues with { LoopStartMs = startMs, LoopEndMs = endMs });
+        _logger?.LogInformation("Orchestrator: loop {Start}ms-{End}ms on {Uri}", startMs, endMs, trackUri);
+        return true;
+    }
+
+    /// <summary>
+    /// Marks the loop start at <paramref name="positionMs"/> or the current position.
+    /// Looping starts with <see cref="SetLoopOutAsync"/>; an active loop keeps its end
+    /// and moves its start.
+    /// </summary>
+    /// <returns>False when nothing is loaded or the point is not before an active loop's end.</returns>
+    public async Task<bool> SetLoopInAsync(long? positionMs = null, CancellationToken ct = default)
+    {
+        if (!TryResolveCuePosition(positionMs, out var trackUri, out var startMs))
+            return false;
+
+        if (Cues.LoopEndMs is { } endMs)
+            return await SetLoopAsync(startMs, endMs, ct);
+
+        UpdateCues(trackUri, cues => cues with { LoopStartMs = startMs });
+        return true;
+    }
+
+    /// <summary>
+    /// Closes the loop at <paramref name="positionMs"/> or the current position and
+    /// starts looping back to the loop start.
+    /// </summary>
+    /// <returns>False when no loop start is marked or the point is not after it.</returns>
+    public async Task<bool> SetLoopOutAsync(long? positionMs = null, CancellationToken ct = default)
+    {
+        if (Cues.LoopStartMs is not { } startMs
+            || !TryResolveCuePosition(positionMs, out _, out var endMs))
+            return false;
+
+        return await SetLoopAsync(startMs, endMs, ct);
+    }
+
+    /// <summary>
+    /// Removes the loop and the loop-in mark; playback continues past the loop end.
+    /// </summary>
+    public async Task ClearLoopAsync(CancellationToken ct = default)
+    {
+        var hadLoop = Cues.LoopEndMs != null;
+        UpdateCues(null, cues => cues with { LoopStartMs = null, LoopEndMs = null });
+        if (hadLoop)
+            await _proxy.ClearLoopRegionAsync(ct);
+    }
+
+    private bool TryResolveCuePosition(long? positionMs, out string trackUri, out long cueMs)
+    {
+        var state = _stateSubject.Value;
+        trackUri = state.TrackUri ?? "";
+        cueMs = ClampSeekPosition(
+            positionMs ?? ExtrapolatePosition(state, DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()),
+            state.DurationMs);
+        return state.TrackUri != null;
+    }
+
+    // trackUri null edits whatever track the cues belong to; otherwise cues of
+    // another track are dropped first.
+    private void UpdateCues(string? trackUri, Func<CueState, CueState> update)
+    {
+        lock (_cueLock)
+        {
+            var cues = _cueSubject.Value;
+            if (trackUri != null && cues.TrackUri != trackUri)
+                cues = CueState.Empty with { TrackUri = trackUri };
+            var next = update(cues);
+            if (next != _cueSubject.Value)
+                _cueSubject.OnNext(next);
+        }
+    }
+
+    private void ResetCues(string? trackUri)
+    {
+        lock (_cueLock)
+        {
+            if (_cueSubject.Value.TrackUri is null || _cueSubject.Value.TrackUri == trackUri)
+                return;
+            _cueSubject.OnNext(CueState.Empty);
+        }
+    }
+}
+
+/// <summary>
+/// Hot cues and A-B loop of one track.
+/// </summary>
+/// <param name="TrackUri">Track the cues belong to, or null when none are set.</param>
+/// <param name="CuePoints">
+/// Positions in milliseconds, one per slot (<see cref="PlaybackOrchestrator.CueSlotCount"/>);
+/// null for an empty slot.
+/// </param>
+/// <param name="LoopStartMs">Loop start, set on its own after a loop-in before the loop is closed.</param>
+/// <param name="LoopEndMs">Loop end; set only while looping.</param>
+public sealed record CueState(
+    string? TrackUri,
+    ImmutableArray<long?> CuePoints,
+    long? LoopStartMs,
+    long? LoopEndMs)
+{
+    public static CueState Empty { get; } = new(
+        null,
+        ImmutableArray.Create(new long?[PlaybackOrchestrator.CueSlotCount]),
+        null,
+        null);
+
+    /// <summary>
+    /// True while playback loops between <see cref="LoopStartMs"/> and <see cref="LoopEndMs"/>.
+    /// </summary>
+    public bool IsLooping => LoopStartMs != null && LoopEndMs != null;
+}
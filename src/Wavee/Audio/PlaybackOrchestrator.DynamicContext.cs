@@ -0,0 +1,124 @@
+using Microsoft.Extensions.Logging;
+using Wavee.Audio.Queue;
+using Wavee.Core.Playlists;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Reloads the playing context when Spotify regenerates it in place (daylist,
+/// Discover Weekly, blends). Without this the queue keeps the old generation's
+/// tracks until the next play, and remote clients see a context whose revision no
+/// longer exists.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    /// <summary>
+    /// Reloads the playing context whenever <paramref name="refreshes"/> reports it was
+    /// regenerated. Set by <see cref="PlayerBuilder"/> from the playlist cache.
+    /// </summary>
+    public void ObservePlaylistRefreshes(IObservable<PlaylistRefreshedEvent> refreshes)
+    {
+        ArgumentNullException.ThrowIfNull(refreshes);
+        _subs.Add(refreshes.Subscribe(e => { _ = OnPlaylistRefreshedAsync(e); }));
+    }
+
+    /// <summary>
+    /// Re-resolves the playing context in place. The current track keeps playing;
+    /// the tracks after it come from the new generation, and the user queue is kept.
+    /// </summary>
+    /// <param name="contextUri">Context to reload; ignored unless it is the one playing.</param>
+    /// <param name="revision">New context revision (base64), if known.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>True if the queue was rebuilt.</returns>
+    public async Task<bool> ReloadContextAsync(string contextUri, string? revision = null, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(contextUri);
+
+        // Autoplay has already replaced the context; the old generation is history.
+        if (_autoplayTriggered || !string.Equals(_queue.ContextUri, contextUri, StringComparison.Ordinal))
+            return false;
+
+        if (!string.IsNullOrEmpty(revision))
+            _contextResolver.OnContextRevisionChanged(contextUri, revision);
+        else
+            _contextResolver.InvalidateContext(contextUri);
+
+        var current = _queue.Current;
+        var context = await _contextResolver.LoadContextAsync(
+            contextUri,
+            startTrackUri: current?.Uri,
+            startTrackUid: current?.Uid,
+            ct: ct).ConfigureAwait(false);
+
+        // Playback may have moved on to another context while the load ran.
+        if (context.Tracks.Count == 0
+            || _autoplayTriggered
+            || !string.Equals(_queue.ContextUri, contextUri, StringComparison.Ordinal))
+        {
+            return false;
+        }
+
+        var tracks = context.Tracks;
+        var startIndex = 0;
+        if (current is not null)
+        {
+            startIndex = FindLoadedTrackIndex(tracks, current);
+            if (startIndex < 0)
+            {
+                // A regeneration usually drops the playing track. Keep it as the
+                // current entry so the new generation starts after it.
+                tracks = [current, .. tracks];
+                startIndex = 0;
+            }
+        }
+
+        _queue.UpdateContext(contextUri, context.IsInfinite, context.TotalCount);
+        _queue.SetTracks(tracks, startIndex);
+
+        _currentNextPageUrl = context.NextPageUrl;
+        _currentContextPageCount = context.PageCount;
+        _currentContextTrackCount = context.TotalCount ?? context.Tracks.Count;
+        _currentContextFormatAttributes = context.ContextMetadata;
+
+        _logger?.LogInformation(
+            "Orchestrator: reloaded regenerated context {Context} ({Count} tracks)",
+            contextUri,
+            context.Tracks.Count);
+
+        ResetPrefetch();
+        PublishQueueState();
+        return true;
+    }
+
+    private async Task OnPlaylistRefreshedAsync(PlaylistRefreshedEvent e)
+    {
+        try
+        {
+            var revision = e.Revision.Length > 0 ? Convert.ToBase64String(e.Revision) : null;
+            await ReloadContextAsync(e.Uri, revision).ConfigureAwait(false);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Failed to reload regenerated context {Context}", e.Uri);
+        }
+    }
+
+    private static int FindLoadedTrackIndex(IReadOnlyList<QueueTrack> tracks, QueueTrack current)
+    {
+        // Unlike ContextResolver.FindTrackIndex this reports a miss instead of
+        // falling back to the first track.
+        for (var i = 0; i < tracks.Count; i++)
+        {
+            if (!string.IsNullOrEmpty(current.Uid) && tracks[i].Uid == current.Uid)
+                return i;
+        }
+
+        for (var i = 0; i < tracks.Count; i++)
+        {
+            if (tracks[i].Uri == current.Uri)
+                return i;
+        }
+
+        return -1;
+    }
+}
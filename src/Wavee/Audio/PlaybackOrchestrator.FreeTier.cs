@@ -0,0 +1,74 @@
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Microsoft.Extensions.Logging;
+using Wavee.Audio.Queue;
+using Wavee.Core.Audio;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Free-tier rules: the hourly skip allowance and the ad / interruption entries
+/// Spotify inserts into free contexts.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    private readonly Subject<InterruptionEvent> _interruptionSubject = new();
+    private readonly Subject<UpsellEvent> _upsellSubject = new();
+    private readonly SkipLimiter _skipLimiter = new();
+
+    /// <summary>
+    /// Supplies the account's product restrictions, read on every skip so a
+    /// mid-session upgrade lifts the limit. Set by <see cref="PlayerBuilder"/>
+    /// from the session's ProductInfo. Null = unrestricted.
+    /// </summary>
+    public Func<ProductRestrictions>? ProductRestrictionsProvider { get; set; }
+
+    /// <summary>
+    /// Fires when playback reaches an ad or interruption entry; the entry is then skipped.
+    /// </summary>
+    public IObservable<InterruptionEvent> Interruptions => _interruptionSubject.AsObservable();
+
+    /// <summary>
+    /// Fires when a product restriction refuses an action (e.g. skip limit reached).
+    /// </summary>
+    public IObservable<UpsellEvent> Upsells => _upsellSubject.AsObservable();
+
+    /// <summary>
+    /// Counts a forward skip against the hourly allowance. Publishes an
+    /// <see cref="UpsellEvent"/> and returns false when the allowance is used up.
+    /// </summary>
+    private bool TryConsumeSkip()
+    {
+        var restrictions = ProductRestrictionsProvider?.Invoke();
+        if (restrictions?.SkipsPerHour is not { } limit)
+            return true;
+
+        if (_skipLimiter.TryConsume(limit, DateTimeOffset.UtcNow, out var retryAfter))
+            return true;
+
+        _logger?.LogInformation("Orchestrator: skip refused, {Limit} skips/hour used; next in {RetryAfter}",
+            limit, retryAfter);
+        _upsellSubject.OnNext(new UpsellEvent(UpsellReason.SkipLimitReached, retryAfter));
+        return false;
+    }
+
+    private static InterruptionKind? GetInterruptionKind(string uri) => SpotifyId.GetUriType(uri) switch
+    {
+        SpotifyIdType.Ad => InterruptionKind.Ad,
+        SpotifyIdType.Interruption => InterruptionKind.Interruption,
+        _ => null
+    };
+
+    /// <summary>
+    /// Reports an ad / interruption entry and moves past it. Wavee has no ad
+    /// session to fetch the creative from, so nothing is played.
+    /// </summary>
+    private async Task SkipInterruptionAsync(QueueTrack current, InterruptionKind kind)
+    {
+        _logger?.LogInformation("Orchestrator: skipping {Kind} entry {Uri}", kind, current.Uri);
+        _interruptionSubject.OnNext(new InterruptionEvent(current.Uri, kind, _queue.ContextUri, current.Metadata));
+
+        if (!await TryAdvanceOrAutoplayAsync())
+            await EndOfContextAsync();
+    }
+}
@@ -0,0 +1,96 @@
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Microsoft.Extensions.Logging;
+using Wavee.Connect;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Idle power save: after <see cref="PowerSaveIdleTimeout"/> without playback the
+/// AudioHost output is released, and <see cref="PowerSaveChanged"/> tells the
+/// session to slow its keep-alives and the caches to shrink.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    private readonly BehaviorSubject<bool> _powerSaveSubject = new(false);
+    private IdleMonitor? _idleMonitor;
+    private IDisposable? _idleStateSubscription;
+    private TimeSpan _powerSaveIdleTimeout;
+
+    /// <summary>
+    /// Time without playback before entering power save. Zero disables it (default).
+    /// Set by <see cref="PlayerBuilder"/> from <c>SessionConfig.PowerSaveIdleTimeout</c>.
+    /// </summary>
+    public TimeSpan PowerSaveIdleTimeout
+    {
+        get => _powerSaveIdleTimeout;
+        set
+        {
+            ArgumentOutOfRangeException.ThrowIfLessThan(value, TimeSpan.Zero);
+            if (value == _powerSaveIdleTimeout)
+                return;
+
+            _powerSaveIdleTimeout = value;
+            _idleStateSubscription?.Dispose();
+            _idleMonitor?.Dispose();
+            _idleStateSubscription = null;
+            _idleMonitor = null;
+            if (_powerSaveSubject.Value)
+                _powerSaveSubject.OnNext(false);
+
+            if (value <= TimeSpan.Zero)
+                return;
+
+            var monitor = new IdleMonitor(value, OnIdleChanged, PowerSaveTimeProvider);
+            _idleMonitor = monitor;
+            _idleStateSubscription = _stateSubject
+                .Select(IsActivePlayback)
+                .DistinctUntilChanged()
+                .Subscribe(monitor.SetActive);
+        }
+    }
+
+    /// <summary>
+    /// Clock for the idle timer; tests substitute a virtual one. Set before
+    /// <see cref="PowerSaveIdleTimeout"/>.
+    /// </summary>
+    internal TimeProvider? PowerSaveTimeProvider { get; set; }
+
+    /// <summary>
+    /// True while in power save.
+    /// </summary>
+    public bool IsPowerSaving => _powerSaveSubject.Value;
+
+    /// <summary>
+    /// Emits true on entering power save and false on waking (playback started or a
+    /// Connect command arrived). Replays the current value to new subscribers.
+    /// </summary>
+    public IObservable<bool> PowerSaveChanged => _powerSaveSubject.DistinctUntilChanged();
+
+    private static bool IsActivePlayback(LocalPlaybackState state)
+        => state.IsBuffering || (state.IsPlaying && !state.IsPaused);
+
+    /// <summary>
+    /// Leaves power save ahead of handling a Connect command, so keep-alives are back
+    /// at full rate before the command's work starts.
+    /// </summary>
+    private void WakeFromPowerSave() => _idleMonitor?.Wake();
+
+    private void OnIdleChanged(bool idle)
+    {
+        if (_disposed)
+            return;
+
+        if (idle)
+        {
+            _logger?.LogInformation("Orchestrator: idle for {Timeout}, entering power save", _powerSaveIdleTimeout);
+            FireAndLog(_proxy.ReleaseOutputAsync(), "release output");
+        }
+        else
+        {
+            _logger?.LogInformation("Orchestrator: leaving power save");
+        }
+
+        _powerSaveSubject.OnNext(idle);
+    }
+}
@@ -0,0 +1,52 @@
+using Microsoft.Extensions.Logging;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Pre-warming: resolve a track and ready AudioHost's decoder before the play
+/// command arrives, so playback starts within tens of milliseconds.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    /// <summary>
+    /// Resolves <paramref name="trackUri"/> (metadata, audio key, CDN URL, head data) and
+    /// has AudioHost parse its decoder headers, without starting audio or disturbing the
+    /// current track. A following play of the same URI — local or from Connect — skips
+    /// all of that work. Meant for voice assistants and similar integrations that know
+    /// the track before the user's request finishes. Only the latest prewarm is kept.
+    /// </summary>
+    /// <param name="trackUri">Spotify track or episode URI.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <exception cref="ArgumentException"><paramref name="trackUri"/> is not a Spotify track or episode.</exception>
+    public async Task PrewarmAsync(string trackUri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(trackUri);
+        if (!IsSpotifyAudioUri(trackUri))
+            throw new ArgumentException($"'{trackUri}' is not a Spotify track or episode", nameof(trackUri));
+
+        if (RejectIfSpotifyAudioPlaybackDisabled(trackUri, nameof(PrewarmAsync)))
+            return;
+
+        var resolution = await _trackResolver.PrewarmAsync(trackUri, ct).ConfigureAwait(false);
+
+        // Fully cached files have no head data; their decoder headers come off disk.
+        if (resolution.HeadData is not { Length: > 0 } headData)
+            return;
+
+        try
+        {
+            await _proxy.PrewarmAsync(new PrewarmTrackCommand
+            {
+                TrackUri = trackUri,
+                Codec = resolution.Codec,
+                HeadData = Convert.ToBase64String(headData)
+            }, ct).ConfigureAwait(false);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            // The resolution is what saves most of the time; a missed decoder warm-up is not worth failing for.
+            _logger?.LogDebug(ex, "Orchestrator: AudioHost prewarm failed for {Uri}", trackUri);
+        }
+    }
+}
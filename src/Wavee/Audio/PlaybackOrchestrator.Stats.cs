@@ -0,0 +1,90 @@
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Wavee.Connect;
+using Wavee.Connect.Events;
+using Wavee.Core.Session;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Local per-track playback statistics for <see cref="PlaybackOrchestrator"/>:
+/// bytes downloaded, cache hits, decode time, underruns, seeks, time played and
+/// why the track ended. Unlike the event reporting in
+/// <c>PlaybackOrchestrator.Metrics.cs</c> nothing here leaves the process — it
+/// exists to help tune buffer and cache settings and to feed local consumers
+/// such as scrobblers.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    private readonly TrackStatsRecorder _trackStats = new();
+    private readonly Subject<TrackPlaybackStats> _trackStatsSubject = new();
+
+    // Last byte count the engine reported per track, so only the growth is metered.
+    private string? _meteredTrackUri;
+    private long _meteredTrackBytes;
+
+    // Latest error from any engine or the resolve pipeline; consumed by the next track end.
+    private PlaybackError? _lastPlaybackError;
+
+    /// <summary>
+    /// Meter the AudioHost's download progress is added to as
+    /// <see cref="TrafficCategory.Audio"/>. Set by <see cref="PlayerBuilder"/> to the
+    /// session's <see cref="Session.Bandwidth"/>; null disables it.
+    /// </summary>
+    public BandwidthMeter? Bandwidth { get; init; }
+
+    /// <summary>
+    /// Fires once per track when it ends, with <see cref="TrackPlaybackStats.EndReason"/>
+    /// and <see cref="TrackPlaybackStats.MsPlayed"/> for scrobblers and analytics.
+    /// </summary>
+    public IObservable<TrackPlaybackStats> TrackStatsCompleted => _trackStatsSubject.AsObservable();
+
+    /// <summary>
+    /// Stats for the most recently completed tracks (up to 50), oldest first.
+    /// </summary>
+    public IReadOnlyList<TrackPlaybackStats> GetTrackStatsHistory() => _trackStats.GetHistory();
+
+    private void ObserveTrackStats(LocalPlaybackState state)
+    {
+        MeterAudioBytes(state);
+
+        var completed = _trackStats.Observe(state, DateTimeOffset.UtcNow);
+        if (completed != null)
+            _trackStatsSubject.OnNext(completed);
+    }
+
+    private void MeterAudioBytes(LocalPlaybackState state)
+    {
+        if (Bandwidth is null || state.TrackCounters is not { } counters)
+            return;
+
+        var bytes = counters.BytesDownloaded;
+        // A new track, or a reload of the same one, starts its counter again from zero.
+        if (state.TrackUri != _meteredTrackUri || bytes < _meteredTrackBytes)
+        {
+            _meteredTrackUri = state.TrackUri;
+            _meteredTrackBytes = 0;
+        }
+
+        Bandwidth.RecordReceived(TrafficCategory.Audio, bytes - _meteredTrackBytes);
+        _meteredTrackBytes = bytes;
+    }
+
+    /// <summary>
+    /// Ends the current track: stamps the local stats with <paramref name="reason"/>
+    /// and reports the matching reason_end to Spotify.
+    /// </summary>
+    private void EndTrack(TrackEndReason reason, long endPositionMs)
+    {
+        var error = Interlocked.Exchange(ref _lastPlaybackError, null);
+        _trackStats.MarkEnded(reason, error?.ErrorType, endPositionMs, DateTimeOffset.UtcNow);
+        DispatchTrackTransition(reason.ToPlaybackReason(), endPositionMs);
+    }
+
+    private void CompleteTrackStats()
+    {
+        var completed = _trackStats.Complete(DateTimeOffset.UtcNow);
+        if (completed != null)
+            _trackStatsSubject.OnNext(completed);
+    }
+}
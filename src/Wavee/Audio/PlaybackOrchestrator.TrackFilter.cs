@@ -0,0 +1,78 @@
+using System.Reactive.Linq;
+using System.Reactive.Subjects;
+using Microsoft.Extensions.Logging;
+using Wavee.Audio.Queue;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// User skip rules (<see cref="TrackFilterRules"/>): checked on the queue's metadata
+/// before a track is resolved, and on the resolved metadata before any audio is sent.
+/// </summary>
+public sealed partial class PlaybackOrchestrator
+{
+    /// <summary>
+    /// Filtered tracks skipped in a row before giving up, so a context where every
+    /// track matches (with repeat on) ends instead of cycling forever.
+    /// </summary>
+    private const int MaxConsecutiveFilteredSkips = 100;
+
+    private readonly Subject<TrackSkippedEvent> _skippedSubject = new();
+    private TrackFilterRules _trackFilter = TrackFilterRules.None;
+    private int _consecutiveFilteredSkips;
+
+    /// <summary>
+    /// Rules for tracks to skip. Takes effect from the next track. Set by
+    /// <see cref="PlayerBuilder.WithTrackFilter"/>; hosts may replace it at any time.
+    /// </summary>
+    public TrackFilterRules TrackFilter
+    {
+        get => Volatile.Read(ref _trackFilter);
+        set => Volatile.Write(ref _trackFilter, value ?? throw new ArgumentNullException(nameof(value)));
+    }
+
+    /// <summary>
+    /// Fires when a track is skipped by a <see cref="TrackFilter"/> rule, naming the rule.
+    /// </summary>
+    public IObservable<TrackSkippedEvent> Skipped => _skippedSubject.AsObservable();
+
+    private static TrackFilterCandidate ToFilterCandidate(QueueTrack track) => new(
+        track.Uri, track.Title, track.Artist, track.ArtistUri, track.Album, track.DurationMs);
+
+    private static TrackFilterCandidate ToFilterCandidate(QueueTrack track, TrackResolution resolution) => new(
+        track.Uri,
+        resolution.Metadata.Title ?? track.Title,
+        resolution.Metadata.Artist ?? track.Artist,
+        resolution.Metadata.ArtistUri ?? track.ArtistUri,
+        resolution.Metadata.Album ?? track.Album,
+        resolution.DurationMs > 0 ? resolution.DurationMs : track.DurationMs);
+
+    /// <summary>
+    /// Skips <paramref name="current"/> when it breaks a <see cref="TrackFilter"/> rule.
+    /// </summary>
+    /// <returns>True when the track was skipped; the caller must not play it.</returns>
+    private async Task<bool> TrySkipFilteredAsync(QueueTrack current, TrackFilterCandidate candidate)
+    {
+        var rules = TrackFilter;
+        if (rules.IsEmpty || rules.Match(candidate) is not { } match)
+            return false;
+
+        _logger?.LogInformation("Orchestrator: skipping {Uri}, {Rule} rule matched ({Detail})",
+            current.Uri, match.Rule, match.Detail);
+        _skippedSubject.OnNext(new TrackSkippedEvent(current.Uri, match.Rule, match.Detail, _queue.ContextUri));
+
+        if (++_consecutiveFilteredSkips > MaxConsecutiveFilteredSkips)
+        {
+            _logger?.LogWarning("Orchestrator: {Count} tracks in a row matched skip rules; stopping",
+                MaxConsecutiveFilteredSkips);
+            _consecutiveFilteredSkips = 0;
+            await EndOfContextAsync();
+            return true;
+        }
+
+        // No ct: the advance starts a new load, which cancels this one.
+        if (!await TryAdvanceOrAutoplayAsync())
+            await EndOfContextAsync();
+        return true;
+    }
+}
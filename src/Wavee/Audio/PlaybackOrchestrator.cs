@@ -31,6 +31,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     private readonly BehaviorSubject<LocalPlaybackState> _stateSubject = new(LocalPlaybackState.Empty);
     private readonly Subject<PlaybackError> _errorSubject = new();
     private readonly Subject<EndOfContextEvent> _endOfContextSubject = new();
+    private readonly Subject<PlaybackSeekedEvent> _seekedSubject = new();
 
     private bool _repeatContext;
     private bool _repeatTrack;
@@ -84,6 +85,19 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     /// </summary>
     public Func<bool>? AutoplayEnabledProvider { get; set; }
 
+    /// <summary>
+    /// How far into a track "previous" restarts it instead of going back.
+    /// Applies to both local skip-previous and Connect <c>skip_prev</c>.
+    /// Zero disables the restart. Default 3 s.
+    /// </summary>
+    public TimeSpan PreviousRestartThreshold { get; set; } = TimeSpan.FromSeconds(3);
+
+    /// <summary>
+    /// Whether the next track's key, head and CDN URL are fetched while the current
+    /// one is still playing. Default true.
+    /// </summary>
+    public bool PrefetchEnabled { get; set; } = true;
+
     // Latch: set true for auto-advance / transfer-resume / autoplay rollover,
     // false for user-initiated play. Sticks until the next PlayAsync flips it.
     private bool _isSystemInitiated;
@@ -97,6 +111,11 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     private bool _lastPrefetchWasVideo;
     private CancellationTokenSource? _prefetchCts;
 
+    // Load preemption: each PlayCurrentTrackAsync swaps in a fresh CTS and cancels
+    // the previous one, so a superseded load stops its metadata/head/key/CDN work
+    // instead of finishing in the background and sending a stale deferred result.
+    private CancellationTokenSource? _loadCts;
+
     // Pre-warmed video session for the next queued music video. Disposed on
     // queue change or after commit. Capped at one in flight at a time.
     private IPreparedVideoSession? _preparedNextVideoSession;
@@ -159,6 +178,9 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         // Forward proxy errors
         _subs.Add(_proxy.Errors.Subscribe(err => _errorSubject.OnNext(err)));
 
+        // Remember the latest failure so an error track end can say what went wrong
+        _subs.Add(_errorSubject.Subscribe(err => Volatile.Write(ref _lastPlaybackError, err)));
+
         // Auto-advance on track finish
         _subs.Add(_proxy.TrackFinished.Subscribe(msg => { _ = OnTrackFinishedAsync(msg); }));
 
@@ -198,6 +220,12 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     /// the end" notification with whatever phrasing fits the event.
     /// </summary>
     public IObservable<EndOfContextEvent> EndOfContext => _endOfContextSubject.AsObservable();
+
+    /// <summary>
+    /// Fires after every accepted seek (local or Connect-driven) with the
+    /// position before the seek and the clamped target.
+    /// </summary>
+    public IObservable<PlaybackSeekedEvent> Seeked => _seekedSubject.AsObservable();
     public LocalPlaybackState CurrentState => _stateSubject.Value;
 
     private bool RejectIfSpotifyAudioPlaybackDisabled(string? uri, string operation)
@@ -266,6 +294,11 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
                     : null);
             ResetPrefetch();
 
+            // A sender that shares its shuffle seed gets the same order back —
+            // seed before SetTracks so the first shuffle already uses it.
+            if (command.Options is { ShufflingContext: true, ShuffleSeed: { } shuffleSeed })
+                _queue.SetShuffle(true, shuffleSeed);
+
             // Build queue from context and/or explicit track list.
             var hasRealContext = !string.IsNullOrEmpty(command.ContextUri)
                                  && command.ContextUri != "spotify:internal:queue";
@@ -289,13 +322,19 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
                 if (IsLocalPlaybackContext(command.ContextUri))
                     _ = EnrichLocalQueueTracksAsync(ct);
 
+                // Remote senders usually inline page 0 and leave the rest as
+                // page_url placeholders — page straight from there on demand.
+                if (!string.IsNullOrEmpty(command.NextPageUrl))
+                {
+                    _currentNextPageUrl = command.NextPageUrl;
+                }
                 // The UI supplies its own page-0 tracks (e.g. an extended top-tracks
                 // view) but doesn't know the next-page URL or total page count. Kick
                 // a background resolve so LoadMoreTracksAsync has somewhere to page
                 // to once the user plays through what the UI provided. Local contexts
                 // are already represented by PageTracks here; Spotify's resolver does
                 // not understand wavee:local:* URIs.
-                if (!IsLocalPlaybackContext(command.ContextUri))
+                else if (!IsLocalPlaybackContext(command.ContextUri))
                 {
                     _ = Task.Run(async () =>
                     {
@@ -341,7 +380,11 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             else if (hasRealContext)
             {
                 // Remote transfer / deep-link: resolve the full context from Spotify.
-                var context = await _contextResolver.LoadContextAsync(command.ContextUri!, ct: ct);
+                var context = await _contextResolver.LoadContextAsync(
+                    command.ContextUri!,
+                    startTrackUri: command.TrackUri,
+                    startTrackUid: command.TrackUid,
+                    ct: ct);
                 _queue.SetContext(command.ContextUri!, context.IsInfinite, context.TotalCount);
                 _queue.SetTracks(context.Tracks);
 
@@ -466,11 +509,15 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         await _proxy.ResumeAsync(ct);
     }
 
-    public async Task StopAsync(CancellationToken ct = default)
+    public Task StopAsync(CancellationToken ct = default)
+        => StopAsync(Wavee.Connect.Events.TrackEndReason.Stopped, ct);
+
+    public async Task StopAsync(Wavee.Connect.Events.TrackEndReason reason, CancellationToken ct = default)
     {
-        // Event reporting: Stop is endplay. Captures whatever the user was doing
-        // when they hit Stop, and the post-stop ReasonStart will be ClickRow.
-        DispatchTrackTransition(Wavee.Connect.Events.PlaybackReason.EndPlay, _stateSubject.Value.PositionMs);
+        // Event reporting: Stop is endplay (logout for sign-out). Captures whatever
+        // the user was doing when they hit Stop, and the post-stop ReasonStart will be ClickRow.
+        EndTrack(reason, _stateSubject.Value.PositionMs);
+        CompleteTrackStats();
 
         if (_videoEngineActive)
         {
@@ -495,6 +542,8 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             }
         }
 
+        CancelLoad();
+
         _logger?.LogInformation("Orchestrator: StopAsync → forwarding to proxy");
         if (_localMediaPlayer?.IsActive == true)
         {
@@ -505,23 +554,68 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         await _proxy.StopAsync(ct);
     }
 
-    public Task SeekAsync(long positionMs, CancellationToken ct = default)
+    public Task SeekToAsync(TimeSpan position, CancellationToken ct = default)
+        => SeekAsync((long)position.TotalMilliseconds, ct);
+
+    public async Task SeekAsync(long positionMs, CancellationToken ct = default)
     {
-        if (_videoEngineActive)
+        var before = _stateSubject.Value;
+        var fromMs = ExtrapolatePosition(before, DateTimeOffset.UtcNow.ToUnixTimeMilliseconds());
+        positionMs = ClampSeekPosition(positionMs, before.DurationMs);
+
+        if (_videoEngineActive && _isSpotifyVideoActive && _spotifyVideoPlayback is not null)
         {
-            if (_isSpotifyVideoActive && _spotifyVideoPlayback is not null)
-            {
-                _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → Spotify video engine", positionMs);
-                return _spotifyVideoPlayback.SeekAsync(positionMs, ct);
-            }
-            if (_localMediaPlayer is not null)
+            _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → Spotify video engine", positionMs);
+            await _spotifyVideoPlayback.SeekAsync(positionMs, ct);
+        }
+        else if (_videoEngineActive && _localMediaPlayer is not null)
+        {
+            _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → local video engine", positionMs);
+            await _localMediaPlayer.SeekAsync(positionMs, ct);
+        }
+        else
+        {
+            _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → forwarding to proxy", positionMs);
+            await _proxy.SeekAsync(positionMs, ct);
+        }
+
+        // Re-anchor position + timestamp now rather than waiting for the engine's
+        // next state push, so Connect's position_as_of_timestamp doesn't keep
+        // extrapolating from the pre-seek anchor on other devices.
+        var current = _stateSubject.Value;
+        if (current.TrackUri == before.TrackUri)
+        {
+            OnProxyStateChanged(current with
             {
-                _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → local video engine", positionMs);
-                return _localMediaPlayer.SeekAsync(positionMs, ct);
-            }
+                PositionMs = positionMs,
+                Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
+            });
         }
-        _logger?.LogInformation("Orchestrator: SeekAsync({Pos}ms) → forwarding to proxy", positionMs);
-        return _proxy.SeekAsync(positionMs, ct);
+
+        _trackStats.RecordSeek();
+        _seekedSubject.OnNext(new PlaybackSeekedEvent(
+            TimeSpan.FromMilliseconds(fromMs),
+            TimeSpan.FromMilliseconds(positionMs)));
+    }
+
+    /// <summary>
+    /// Clamps a seek target to <c>[0, duration]</c>. Unknown duration (0) only clamps the lower bound.
+    /// </summary>
+    internal static long ClampSeekPosition(long positionMs, long durationMs)
+    {
+        if (positionMs < 0) return 0;
+        return durationMs > 0 && positionMs > durationMs ? durationMs : positionMs;
+    }
+
+    /// <summary>
+    /// Current position extrapolated from the last state anchor while playing.
+    /// </summary>
+    internal static long ExtrapolatePosition(LocalPlaybackState state, long nowMs)
+    {
+        if (!state.IsPlaying || state.IsPaused || state.IsBuffering || state.Timestamp <= 0)
+            return state.PositionMs;
+        var elapsed = Math.Max(0, nowMs - state.Timestamp);
+        return ClampSeekPosition(state.PositionMs + (long)(elapsed * state.PlaybackSpeed), state.DurationMs);
     }
 
     public Task SetVolumeAsync(float volume, CancellationToken ct = default)
@@ -594,10 +688,14 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
 
     public async Task SkipNextAsync(CancellationToken ct = default)
     {
+        // Free accounts: refused skips leave the track playing and publish an upsell.
+        if (!TryConsumeSkip())
+            return;
+
         // Event reporting: forward-skip is fwdbtn, regardless of whether the
         // skip lands on a real track or end-of-context. Dispatch before advance
         // so wire ordering is transition-then-newPlaybackId.
-        DispatchTrackTransition(Wavee.Connect.Events.PlaybackReason.ForwardBtn, _stateSubject.Value.PositionMs);
+        EndTrack(Wavee.Connect.Events.TrackEndReason.SkippedForward, _stateSubject.Value.PositionMs);
 
         // Shared end-of-queue handling with OnTrackFinishedAsync. Without this
         // delegation, skip-next past the last track bypassed Phase E's autoplay
@@ -609,13 +707,26 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         }
     }
 
-    public async Task SkipPreviousAsync(CancellationToken ct = default)
+    public Task SkipPreviousAsync(CancellationToken ct = default)
+        => SkipPreviousAsync(allowRestart: true, ct);
+
+    /// <summary>
+    /// Previous-button semantics shared by the local API and Connect <c>skip_prev</c>:
+    /// past <see cref="PreviousRestartThreshold"/> the current track restarts,
+    /// otherwise playback moves to the previous context track.
+    /// </summary>
+    /// <param name="allowRestart">
+    /// False forces the move to the previous track (Connect <c>options.allow_seeking=false</c>).
+    /// </param>
+    /// <param name="ct">Cancellation token.</param>
+    public async Task SkipPreviousAsync(bool allowRestart, CancellationToken ct = default)
     {
-        // If more than 3 seconds in, restart current track — NOT a transition.
+        // Past the threshold, restart current track — NOT a transition.
         var state = _stateSubject.Value;
-        if (state.PositionMs > 3000)
+        var positionMs = ExtrapolatePosition(state, DateTimeOffset.UtcNow.ToUnixTimeMilliseconds());
+        if (allowRestart && ShouldRestartOnPrevious(positionMs, PreviousRestartThreshold))
         {
-            await _proxy.SeekAsync(0, ct);
+            await SeekAsync(0, ct);
             return;
         }
 
@@ -625,7 +736,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             // Event reporting: only the actual cross-track back-button case is
             // a transition. The position≤3 s + no-prev-track path below also
             // just re-seeks and isn't reported.
-            DispatchTrackTransition(Wavee.Connect.Events.PlaybackReason.BackBtn, state.PositionMs);
+            EndTrack(Wavee.Connect.Events.TrackEndReason.SkippedBack, positionMs);
 
             ResetPrefetch();
             _logger?.LogInformation("Orchestrator: skip prev → {Uri}", prev.Uri);
@@ -633,10 +744,17 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         }
         else
         {
-            await _proxy.SeekAsync(0, ct);
+            await SeekAsync(0, ct);
         }
     }
 
+    /// <summary>
+    /// True when a previous-press at <paramref name="positionMs"/> should restart the
+    /// current track rather than go back. A zero threshold always goes back.
+    /// </summary>
+    internal static bool ShouldRestartOnPrevious(long positionMs, TimeSpan threshold)
+        => threshold > TimeSpan.Zero && positionMs > (long)threshold.TotalMilliseconds;
+
     public Task SetShuffleAsync(bool enabled, CancellationToken ct = default)
     {
         _queue.SetShuffle(enabled);
@@ -705,7 +823,11 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             contextUri,
             activeTrackUri);
 
-        var context = await _contextResolver.LoadContextAsync(contextUri, ct: ct).ConfigureAwait(false);
+        var context = await _contextResolver.LoadContextAsync(
+            contextUri,
+            startTrackUri: activeTrackUri,
+            startTrackUid: state.TrackUid,
+            ct: ct).ConfigureAwait(false);
         if (context.Tracks.Count == 0)
             throw new InvalidOperationException("The resolved context did not contain any tracks.");
 
@@ -766,6 +888,56 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     }
 
     private async Task PlayCurrentTrackAsync(long positionMs, CancellationToken ct = default, bool pauseAfterStart = false)
+    {
+        var loadCts = BeginLoad(ct);
+        try
+        {
+            await LoadCurrentTrackAsync(positionMs, loadCts.Token, pauseAfterStart);
+        }
+        catch (OperationCanceledException) when (loadCts.IsCancellationRequested && !ct.IsCancellationRequested)
+        {
+            // Preempted by a newer load (or Stop). The newer load owns the engine now.
+            _logger?.LogDebug("Track load preempted: {Uri}", _queue.Current?.Uri);
+        }
+        finally
+        {
+            EndLoad(loadCts);
+        }
+    }
+
+    /// <summary>
+    /// Cancels the in-flight load (if any) and starts a new one linked to <paramref name="ct"/>.
+    /// </summary>
+    private CancellationTokenSource BeginLoad(CancellationToken ct)
+    {
+        var cts = CancellationTokenSource.CreateLinkedTokenSource(ct);
+        var previous = Interlocked.Exchange(ref _loadCts, cts);
+        if (previous != null)
+        {
+            try { previous.Cancel(); } catch (ObjectDisposedException) { /* already finished */ }
+        }
+        return cts;
+    }
+
+    private void EndLoad(CancellationTokenSource cts)
+    {
+        Interlocked.CompareExchange(ref _loadCts, null, cts);
+        cts.Dispose();
+    }
+
+    /// <summary>
+    /// Aborts the in-flight load without starting a new one (Stop / dispose).
+    /// </summary>
+    private void CancelLoad()
+    {
+        var cts = Volatile.Read(ref _loadCts);
+        if (cts != null)
+        {
+            try { cts.Cancel(); } catch (ObjectDisposedException) { /* already finished */ }
+        }
+    }
+
+    private async Task LoadCurrentTrackAsync(long positionMs, CancellationToken ct, bool pauseAfterStart)
     {
         var current = _queue.Current;
         if (current == null)
@@ -774,14 +946,35 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             return;
         }
 
+        if (await TrySkipFilteredAsync(current, ToFilterCandidate(current)))
+            return;
+
         // Local-file fast path: no Spotify resolution, no CDN/key, no head data.
         // Hand the file path straight to AudioHost which opens it via BASS.
         if (Wavee.Core.PlayableUri.IsLocalTrack(current.Uri))
         {
+            _consecutiveFilteredSkips = 0;
             await PlayLocalCurrentTrackAsync(current, positionMs, ct);
             return;
         }
 
+        // A local file added to a Spotify playlist from another device: there is
+        // nothing to stream, so move past it like librespot does. No ct here — the
+        // advance starts a new load, which cancels this one.
+        if (SpotifyId.GetUriType(current.Uri) == SpotifyIdType.Local)
+        {
+            _logger?.LogInformation("Skipping Spotify local file {Uri}: not available on this device", current.Uri);
+            if (!await TryAdvanceOrAutoplayAsync())
+                await EndOfContextAsync();
+            return;
+        }
+
+        if (GetInterruptionKind(current.Uri) is { } interruption)
+        {
+            await SkipInterruptionAsync(current, interruption);
+            return;
+        }
+
         // Spotify music-video path: track_player=video + media.manifest_id signals
         // that Spotify wants DASH+PlayReady instead of AudioHost+BASS.
         if (_spotifyVideoPlayback is not null
@@ -848,6 +1041,11 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         // 1. Resolve with head file (instant start) + deferred CDN/key
         var resolution = await _trackResolver.ResolveWithHeadAsync(current.Uri, ct);
 
+        // Queue entries often arrive without metadata; check the rules again now that it's known.
+        if (await TrySkipFilteredAsync(current, ToFilterCandidate(current, resolution)))
+            return;
+        _consecutiveFilteredSkips = 0;
+
         // Remember the music-video manifest_id (if any). This is what
         // SwitchToVideoAsync will hand to ISpotifyVideoPlayback when the user
         // clicks "Watch Video", and what flows out on LocalPlaybackState so the
@@ -875,6 +1073,8 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
             PositionMs = positionMs,
             NormalizationGain = resolution.Normalization.TrackGainDb,
             NormalizationPeak = resolution.Normalization.TrackPeak,
+            NormalizationAlbumGain = resolution.Normalization.AlbumGainDb,
+            NormalizationAlbumPeak = resolution.Normalization.AlbumPeak,
             HeadData = resolution.HeadData != null ? Convert.ToBase64String(resolution.HeadData) : null,
             Metadata = resolution.Metadata,
         }, ct);
@@ -884,8 +1084,10 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
 
         _logger?.LogInformation("Head data sent — audio starting instantly for {Title}", resolution.Metadata?.Title);
 
-        // 4. Wait for audio key (always needed); CDN URL only if not using local cache
-        await Task.WhenAll(resolution.AudioKeyTask, resolution.FileSizeTask);
+        // 4. Wait for audio key (always needed); CDN URL only if not using local cache.
+        // WaitAsync so a preempting load returns immediately even if a key request
+        // ignores cancellation mid-retry.
+        await Task.WhenAll(resolution.AudioKeyTask, resolution.FileSizeTask).WaitAsync(ct);
 
         // 5. Send deferred resolution → AudioHost seamlessly continues
         if (resolution.LocalCacheFileId != null)
@@ -903,7 +1105,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         {
             await _proxy.SendDeferredResolvedAsync(
                 deferredId,
-                await resolution.CdnUrlTask,
+                await resolution.CdnUrlTask.WaitAsync(ct),
                 await resolution.AudioKeyTask,
                 await resolution.FileSizeTask,
                 spotifyFileId: resolution.SpotifyFileId,
@@ -1563,10 +1765,10 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
                    ?? (_stateSubject.Value.DurationMs > 0
                        ? _stateSubject.Value.DurationMs
                        : _stateSubject.Value.PositionMs));
-            DispatchTrackTransition(
+            EndTrack(
                 string.Equals(msg.Reason, "error", StringComparison.OrdinalIgnoreCase)
-                    ? Wavee.Connect.Events.PlaybackReason.TrackError
-                    : Wavee.Connect.Events.PlaybackReason.TrackDone,
+                    ? Wavee.Connect.Events.TrackEndReason.Error
+                    : Wavee.Connect.Events.TrackEndReason.Finished,
                 endPos);
 
             if (_repeatTrack)
@@ -1888,6 +2090,9 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
 
         _logger?.LogInformation("Orchestrator: subscribing to remote commands from ConnectCommandHandler");
 
+        // Emitted ahead of the typed streams below, so power save ends first.
+        _subs.Add(handler.Received.Subscribe(_ => WakeFromPowerSave()));
+
         _subs.Add(handler.PlayCommands.Subscribe(cmd =>
         {
             RememberSender(cmd.SenderDeviceId);
@@ -1923,7 +2128,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         {
             RememberSender(cmd.SenderDeviceId);
             _logger?.LogInformation("Remote cmd: skip_prev (sender={Sender})", cmd.SenderDeviceId ?? "<none>");
-            FireAndLog(SkipPreviousAsync(), "skip_prev");
+            FireAndLog(SkipPreviousAsync(cmd.AllowSeeking), "skip_prev");
         }));
         _subs.Add(handler.ShuffleCommands.Subscribe(cmd =>
         {
@@ -1992,6 +2197,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         // boundaries so TrackTransitionEvent's ms_played accurately reflects what
         // the user actually heard (excluding paused time).
         OnIntervalEdge(prev, engineState);
+        ObserveTrackStats(engineState);
 
         // Log significant state transitions
         if (prev.TrackUri != engineState.TrackUri)
@@ -2026,6 +2232,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         {
             ContextUri = _queue.ContextUri ?? engineState.ContextUri,
             Shuffling = _queue.IsShuffled,
+            ShuffleSeed = _queue.ShuffleSeed,
             RepeatingContext = _repeatContext,
             RepeatingTrack = _repeatTrack,
             CurrentIndex = _queue.CurrentIndex,
@@ -2056,6 +2263,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         if (prev.TrackUri != engineState.TrackUri)
         {
             ResetPrefetch();
+            ResetCues(engineState.TrackUri);
         }
 
         MaybeTriggerPrefetch(enriched);
@@ -2216,6 +2424,7 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
     /// </summary>
     private void MaybeTriggerPrefetch(LocalPlaybackState state)
     {
+        if (!PrefetchEnabled) return;
         if (!state.IsPlaying) return;
         if (_repeatTrack) return;                             // next target = current
         if (state.DurationMs <= 0) return;                    // unknown duration, can't decide
@@ -2363,13 +2572,25 @@ public sealed partial class PlaybackOrchestrator : IPlaybackEngine, IAsyncDispos
         // Event reporting: flush any in-flight track as endplay before tearing
         // down the subscriptions. EventService's own DisposeAsync (driven by
         // Session) drains its async worker queue separately.
-        DispatchTrackTransition(Wavee.Connect.Events.PlaybackReason.EndPlay, _stateSubject.Value.PositionMs);
+        EndTrack(Wavee.Connect.Events.TrackEndReason.Stopped, _stateSubject.Value.PositionMs);
+        CompleteTrackStats();
 
+        CancelLoad();
         ResetPrefetch();
         _subs.Dispose();
+        _idleStateSubscription?.Dispose();
+        _idleMonitor?.Dispose();
+        _audioSessionPlaybackSubscription?.Dispose();
         _stateSubject.Dispose();
         _errorSubject.Dispose();
         _endOfContextSubject.Dispose();
+        _seekedSubject.Dispose();
+        _trackStatsSubject.Dispose();
+        _interruptionSubject.Dispose();
+        _skippedSubject.Dispose();
+        _cueSubject.Dispose();
+        _upsellSubject.Dispose();
+        _powerSaveSubject.Dispose();
     }
 }
 
@@ -2388,3 +2609,10 @@ public sealed record EndOfContextEvent(
     string? OriginalContextUri,
     bool AutoplayAttempted,
     bool ContextSupportsAutoplay);
+
+/// <summary>
+/// Published by <see cref="PlaybackOrchestrator.Seeked"/> after a seek is applied.
+/// </summary>
+/// <param name="From">Position immediately before the seek.</param>
+/// <param name="To">Clamped seek target.</param>
+public sealed record PlaybackSeekedEvent(TimeSpan From, TimeSpan To);
@@ -0,0 +1,204 @@
+using Microsoft.Extensions.Logging;
+using Wavee.AudioIpc;
+using Wavee.Core.Configuration;
+using Wavee.Core.Playlists;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Wavee.Core.Storage.Abstractions;
+using Wavee.Local;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Builds <see cref="PlaybackOrchestrator"/>s for a session, filling the session-derived
+/// arguments (command handler, event service, device id, playback capability) itself.
+/// </summary>
+/// <remarks>
+/// Configure once and call <see cref="Build"/> for each <see cref="AudioPipelineProxy"/> —
+/// again after an AudioHost restart — so every orchestrator is wired the same way.
+/// Validation failures throw <see cref="ConfigException"/> keyed by the missing option.
+/// </remarks>
+public sealed class PlayerBuilder
+{
+    private readonly Session _session;
+    private TrackResolver? _trackResolver;
+    private ContextResolver? _contextResolver;
+    private ILogger? _logger;
+    private ILocalLibraryService? _localLibrary;
+    private ILocalMediaPlayer? _localMediaPlayer;
+    private ISpotifyVideoPlayback? _spotifyVideoPlayback;
+    private Func<bool>? _autoplayEnabled;
+    private IObservable<PlaylistRefreshedEvent>? _playlistRefreshes;
+    private CacheCleanupService? _cacheCleanup;
+    private TrackFilterRules _trackFilter = TrackFilterRules.None;
+    private IMetadataDatabase? _listeningStats;
+    private bool _prefetchEnabled = true;
+
+    /// <summary>
+    /// Starts a builder for players attached to <paramref name="session"/>.
+    /// </summary>
+    public PlayerBuilder(Session session)
+    {
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+    }
+
+    /// <summary>Sets the track resolver. Required.</summary>
+    public PlayerBuilder WithTrackResolver(TrackResolver trackResolver)
+    {
+        _trackResolver = trackResolver;
+        return this;
+    }
+
+    /// <summary>Sets the context resolver. Required.</summary>
+    public PlayerBuilder WithContextResolver(ContextResolver contextResolver)
+    {
+        _contextResolver = contextResolver;
+        return this;
+    }
+
+    /// <summary>Sets the orchestrator logger.</summary>
+    public PlayerBuilder WithLogger(ILogger? logger)
+    {
+        _logger = logger;
+        return this;
+    }
+
+    /// <summary>Enables playback of local files from <paramref name="localLibrary"/>.</summary>
+    public PlayerBuilder WithLocalLibrary(ILocalLibraryService? localLibrary)
+    {
+        _localLibrary = localLibrary;
+        return this;
+    }
+
+    /// <summary>Sets the in-process engine for local video and audio files.</summary>
+    public PlayerBuilder WithLocalMediaPlayer(ILocalMediaPlayer? localMediaPlayer)
+    {
+        _localMediaPlayer = localMediaPlayer;
+        return this;
+    }
+
+    /// <summary>Sets the engine for Spotify music videos.</summary>
+    public PlayerBuilder WithSpotifyVideoPlayback(ISpotifyVideoPlayback? spotifyVideoPlayback)
+    {
+        _spotifyVideoPlayback = spotifyVideoPlayback;
+        return this;
+    }
+
+    /// <summary>
+    /// Sets <see cref="PlaybackOrchestrator.AutoplayEnabledProvider"/>; read on every
+    /// end-of-context check, so it can follow a live setting.
+    /// </summary>
+    public PlayerBuilder WithAutoplay(Func<bool>? enabled)
+    {
+        _autoplayEnabled = enabled;
+        return this;
+    }
+
+    /// <summary>
+    /// Reloads the playing context when a dynamic playlist it came from is regenerated.
+    /// Usually <see cref="IPlaylistCacheService.Refreshed"/>.
+    /// </summary>
+    public PlayerBuilder WithPlaylistRefreshes(IObservable<PlaylistRefreshedEvent>? refreshes)
+    {
+        _playlistRefreshes = refreshes;
+        return this;
+    }
+
+    /// <summary>
+    /// Caches to shrink (<see cref="CacheCleanupService.ShrinkAsync"/>) when the player
+    /// enters power save. Only used when <see cref="SessionConfig.PowerSaveIdleTimeout"/> is set.
+    /// </summary>
+    public PlayerBuilder WithCacheCleanup(CacheCleanupService? cacheCleanup)
+    {
+        _cacheCleanup = cacheCleanup;
+        return this;
+    }
+
+    /// <summary>
+    /// Sets <see cref="PlaybackOrchestrator.TrackFilter"/>, the rules for tracks to skip;
+    /// usually <see cref="TrackFilterRules.FromConfig"/>.
+    /// </summary>
+    public PlayerBuilder WithTrackFilter(TrackFilterRules trackFilter)
+    {
+        _trackFilter = trackFilter ?? throw new ArgumentNullException(nameof(trackFilter));
+        return this;
+    }
+
+    /// <summary>
+    /// Sets <see cref="PlaybackOrchestrator.PrefetchEnabled"/>. Default true.
+    /// </summary>
+    public PlayerBuilder WithPrefetch(bool enabled)
+    {
+        _prefetchEnabled = enabled;
+        return this;
+    }
+
+    /// <summary>
+    /// Records every finished track into the listening stats of <paramref name="database"/>,
+    /// queried with <see cref="IMetadataDatabase.GetListeningSummaryAsync"/> and friends.
+    /// Null (default) keeps no stats.
+    /// </summary>
+    public PlayerBuilder WithListeningStats(IMetadataDatabase? database)
+    {
+        _listeningStats = database;
+        return this;
+    }
+
+    /// <summary>
+    /// Creates an orchestrator driving <paramref name="proxy"/>.
+    /// </summary>
+    /// <exception cref="ConfigException">A required option is missing.</exception>
+    public PlaybackOrchestrator Build(AudioPipelineProxy proxy)
+    {
+        ArgumentNullException.ThrowIfNull(proxy);
+        if (_trackResolver is null)
+            throw new ConfigException("trackResolver", nameof(PlayerBuilder), "is required");
+        if (_contextResolver is null)
+            throw new ConfigException("contextResolver", nameof(PlayerBuilder), "is required");
+
+        var orchestrator = new PlaybackOrchestrator(
+            proxy,
+            _trackResolver,
+            _contextResolver,
+            _session.CommandHandler,
+            _logger,
+            events: _session.Events,
+            localDeviceId: _session.Config.DeviceId,
+            localLibrary: _localLibrary,
+            localMediaPlayer: _localMediaPlayer,
+            spotifyVideoPlayback: _spotifyVideoPlayback,
+            localSpotifyPlaybackEnabled: _session.Config.LocalSpotifyPlaybackEnabled)
+        {
+            AutoplayEnabledProvider = _autoplayEnabled,
+            ProductRestrictionsProvider = () => ProductRestrictions.FromUserData(_session.GetUserData()),
+            Bandwidth = _session.Bandwidth,
+            TrackFilter = _trackFilter,
+            PrefetchEnabled = _prefetchEnabled
+        };
+
+        if (_playlistRefreshes is not null)
+            orchestrator.ObservePlaylistRefreshes(_playlistRefreshes);
+
+        if (_listeningStats is not null)
+            ListeningStatsRecorder.Attach(orchestrator.TrackStatsCompleted, _listeningStats, _logger);
+
+        if (_session.Config.PowerSaveIdleTimeout > TimeSpan.Zero)
+        {
+            var cacheCleanup = _cacheCleanup;
+            var logger = _logger;
+            orchestrator.PowerSaveChanged.Subscribe(powerSave =>
+            {
+                _session.Dealer?.SetPowerSave(powerSave);
+                if (powerSave && cacheCleanup is not null)
+                {
+                    _ = cacheCleanup.ShrinkAsync().ContinueWith(
+                        t => logger?.LogWarning(t.Exception!.GetBaseException(), "Cache shrink on power save failed"),
+                        TaskContinuationOptions.OnlyOnFaulted | TaskContinuationOptions.ExecuteSynchronously);
+                }
+            });
+            orchestrator.PowerSaveIdleTimeout = _session.Config.PowerSaveIdleTimeout;
+        }
+
+        return orchestrator;
+    }
+}
@@ -0,0 +1,113 @@
+using Microsoft.Extensions.Logging;
+using Wavee.AudioIpc;
+using Wavee.Connect;
+using Wavee.Playback.Contracts;
+using Wavee.Protocol.Metadata;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Plays Spotify's public 30-second preview clips through AudioHost.
+/// </summary>
+/// <remarks>
+/// Preview clips are unencrypted MP3s on <c>p.scdn.co</c>, so this player needs
+/// no <see cref="Core.Session.ISession"/>, audio key or CDN resolution. Use it when
+/// the user is signed out, or when a full stream is restricted (free tier on
+/// demand, region locks). Clips go through the same decoder, processing chain and
+/// output device as full tracks, so volume, EQ and routing still apply.
+/// Do not run it alongside <see cref="PlaybackOrchestrator"/> on the same AudioHost:
+/// both drive the one engine.
+/// </remarks>
+public sealed class PreviewPlayer
+{
+    /// <summary>
+    /// Base URL of the public preview clips; the clip's file ID (hex) is appended.
+    /// </summary>
+    public const string PreviewBaseUrl = "https://p.scdn.co/mp3-preview/";
+
+    private readonly AudioPipelineProxy _proxy;
+    private readonly ILogger? _logger;
+
+    public PreviewPlayer(AudioPipelineProxy proxy, ILogger? logger = null)
+    {
+        _proxy = proxy ?? throw new ArgumentNullException(nameof(proxy));
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// URI of the clip last started, or null when stopped.
+    /// </summary>
+    public string? CurrentTrackUri { get; private set; }
+
+    /// <summary>
+    /// Playback state from AudioHost. Duration is the clip's, not the track's.
+    /// </summary>
+    public IObservable<LocalPlaybackState> StateChanges => _proxy.StateChanges;
+
+    /// <summary>
+    /// Emits when a clip plays to the end.
+    /// </summary>
+    public IObservable<TrackFinishedMessage> Finished => _proxy.TrackFinished;
+
+    /// <summary>
+    /// Starts a preview clip, replacing whatever AudioHost is playing.
+    /// </summary>
+    /// <param name="trackUri">Track the clip belongs to; echoed in state updates.</param>
+    /// <param name="previewUrl">Clip URL, e.g. from <see cref="GetPreviewUrl(Track)"/>.</param>
+    /// <param name="metadata">Optional display metadata for the media transport.</param>
+    /// <param name="ct">Cancellation token.</param>
+    public async Task PlayAsync(
+        string trackUri,
+        string previewUrl,
+        TrackMetadataDto? metadata = null,
+        CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(trackUri);
+        ArgumentException.ThrowIfNullOrWhiteSpace(previewUrl);
+
+        _logger?.LogInformation("Playing preview clip for {Uri}", trackUri);
+        CurrentTrackUri = trackUri;
+        await _proxy.PlayPreviewAsync(new PlayPreviewCommand
+        {
+            TrackUri = trackUri,
+            PreviewUrl = previewUrl,
+            Metadata = metadata
+        }, ct);
+    }
+
+    public Task PauseAsync(CancellationToken ct = default) => _proxy.PauseAsync(ct);
+
+    public Task ResumeAsync(CancellationToken ct = default) => _proxy.ResumeAsync(ct);
+
+    public Task SeekAsync(long positionMs, CancellationToken ct = default) => _proxy.SeekAsync(positionMs, ct);
+
+    public async Task StopAsync(CancellationToken ct = default)
+    {
+        CurrentTrackUri = null;
+        await _proxy.StopAsync(ct);
+    }
+
+    /// <summary>
+    /// Builds the clip URL for a preview file ID.
+    /// </summary>
+    public static string GetPreviewUrl(ReadOnlySpan<byte> fileId)
+    {
+        if (fileId.IsEmpty)
+            throw new ArgumentException("Preview file ID is empty", nameof(fileId));
+
+        return PreviewBaseUrl + Convert.ToHexString(fileId).ToLowerInvariant();
+    }
+
+    /// <summary>
+    /// Clip URL from track metadata (<c>Track.preview</c>), or null when the track has none.
+    /// </summary>
+    public static string? GetPreviewUrl(Track track)
+    {
+        ArgumentNullException.ThrowIfNull(track);
+
+        // Prefer MP3_96, the format the public clips are served in.
+        var file = track.Preview.FirstOrDefault(f => f.Format == AudioFile.Types.Format.Mp396 && !f.FileId.IsEmpty)
+                   ?? track.Preview.FirstOrDefault(f => !f.FileId.IsEmpty);
+        return file is null ? null : GetPreviewUrl(file.FileId.Span);
+    }
+}
@@ -0,0 +1,45 @@
+using Wavee.Core.Audio;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Holds the one resolution made by <see cref="TrackResolver.PrewarmAsync"/> until the
+/// play it was made for takes it. Only the latest prewarm is kept.
+/// </summary>
+internal sealed class PrewarmSlot
+{
+    /// <summary>
+    /// How long a prewarmed resolution stays usable. Well inside the CDN URL
+    /// lifetime, so a taken resolution never carries an expired URL.
+    /// </summary>
+    internal static readonly TimeSpan Lifetime = TimeSpan.FromMinutes(10);
+
+    private Entry? _entry;
+
+    public void Store(string uri, AudioQuality quality, TrackResolution resolution, DateTimeOffset now)
+        => Volatile.Write(ref _entry, new Entry(uri, quality, resolution, now + Lifetime));
+
+    /// <summary>
+    /// Returns and clears the resolution when it matches <paramref name="uri"/> and
+    /// <paramref name="quality"/>; null otherwise. A prewarm for another track is kept.
+    /// </summary>
+    public TrackResolution? Take(string uri, AudioQuality quality, DateTimeOffset now)
+    {
+        var entry = Volatile.Read(ref _entry);
+        if (entry is null)
+            return null;
+
+        if (now >= entry.ExpiresAt)
+        {
+            Interlocked.CompareExchange(ref _entry, null, entry);
+            return null;
+        }
+
+        if (entry.Quality != quality || !string.Equals(entry.Uri, uri, StringComparison.Ordinal))
+            return null;
+
+        return Interlocked.CompareExchange(ref _entry, null, entry) == entry ? entry.Resolution : null;
+    }
+
+    private sealed record Entry(string Uri, AudioQuality Quality, TrackResolution Resolution, DateTimeOffset ExpiresAt);
+}
@@ -26,6 +26,7 @@ public sealed class PlaybackQueue : IDisposable
     // Shuffle state
     private List<int>? _shuffledIndices;
     private bool _isShuffled;
+    private int? _shuffleSeed;
 
     // Position state
     private int _currentIndex = -1;
@@ -128,6 +129,16 @@ public sealed class PlaybackQueue : IDisposable
         get { lock (_lock) { return _isShuffled; } }
     }
 
+    /// <summary>
+    /// Seed driving the current shuffle order, or null when shuffle is off.
+    /// Published in Connect state so peers (and transfers back to Wavee) can
+    /// reproduce the exact upcoming order for the same track list.
+    /// </summary>
+    public int? ShuffleSeed
+    {
+        get { lock (_lock) { return _shuffleSeed; } }
+    }
+
     /// <summary>
     /// Gets the number of tracks in the user queue.
     /// </summary>
@@ -318,10 +329,12 @@ public sealed class PlaybackQueue : IDisposable
             _contextTracks.AddRange(newTracks.Select(StampContextUri));
             _needsMoreTracksRequested = false;
 
-            // If shuffled, add new tracks at random positions
+            // If shuffled, add new tracks at random positions. Seeded from the
+            // shuffle seed + page offset so every peer with the same seed places
+            // a lazily-loaded page identically.
             if (_isShuffled && _shuffledIndices != null)
             {
-                var random = new Random();
+                var random = new Random(unchecked((_shuffleSeed ?? 0) * 31 + oldCount));
                 for (int i = oldCount; i < _contextTracks.Count; i++)
                 {
                     // Insert at random position after current
@@ -752,17 +765,30 @@ public sealed class PlaybackQueue : IDisposable
     /// Enables or disables shuffle mode.
     /// </summary>
     /// <param name="enabled">Whether to enable shuffle.</param>
-    public void SetShuffle(bool enabled)
+    /// <param name="seed">
+    /// Seed for the shuffle order. Pass the seed received from a Connect peer to
+    /// reproduce its order; null picks a fresh random seed. Supplying a different
+    /// seed while already shuffled re-shuffles with that seed.
+    /// </param>
+    public void SetShuffle(bool enabled, int? seed = null)
     {
         lock (_lock)
         {
-            if (_isShuffled == enabled)
+            if (_isShuffled == enabled && (!enabled || seed is null || seed == _shuffleSeed))
                 return;
 
+            if (enabled && _isShuffled && _shuffledIndices != null
+                && _currentIndex >= 0 && _currentIndex < _shuffledIndices.Count)
+            {
+                // Re-seeding an active shuffle: regenerate around the actual track.
+                _currentIndex = _shuffledIndices[_currentIndex];
+            }
+
             _isShuffled = enabled;
 
             if (enabled)
             {
+                _shuffleSeed = seed ?? Random.Shared.Next();
                 GenerateShuffledOrderInternal();
             }
             else
@@ -773,16 +799,17 @@ public sealed class PlaybackQueue : IDisposable
                     _currentIndex = _shuffledIndices[_currentIndex];
                 }
                 _shuffledIndices = null;
+                _shuffleSeed = null;
             }
 
-            _logger?.LogDebug("Shuffle set: enabled={Enabled}", enabled);
+            _logger?.LogDebug("Shuffle set: enabled={Enabled}, seed={Seed}", enabled, _shuffleSeed);
         }
 
         NotifyStateChanged();
     }
 
     /// <summary>
-    /// Fisher-Yates shuffle to generate random order.
+    /// Fisher-Yates shuffle to generate random order from <see cref="_shuffleSeed"/>.
     /// Keeps current track at position 0.
     /// </summary>
     private void GenerateShuffledOrderInternal()
@@ -797,7 +824,7 @@ public sealed class PlaybackQueue : IDisposable
         for (int i = 0; i < _contextTracks.Count; i++)
             _shuffledIndices.Add(i);
 
-        var random = new Random();
+        var random = new Random(_shuffleSeed ??= Random.Shared.Next());
 
         // If we have a current track, put it first
         if (_currentIndex >= 0 && _currentIndex < _contextTracks.Count)
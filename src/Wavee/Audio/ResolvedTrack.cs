@@ -17,6 +17,8 @@ public sealed record ResolvedTrack
     public int BitrateKbps { get; init; }
     public float? NormalizationGain { get; init; }
     public float? NormalizationPeak { get; init; }
+    public float? NormalizationAlbumGain { get; init; }
+    public float? NormalizationAlbumPeak { get; init; }
     public long DurationMs { get; init; }
     public required TrackMetadataDto Metadata { get; init; }
 
@@ -32,6 +34,8 @@ public sealed record ResolvedTrack
         BitrateKbps = BitrateKbps,
         NormalizationGain = NormalizationGain,
         NormalizationPeak = NormalizationPeak,
+        NormalizationAlbumGain = NormalizationAlbumGain,
+        NormalizationAlbumPeak = NormalizationAlbumPeak,
         TrackUri = TrackUri,
         TrackUid = TrackUid,
         DurationMs = DurationMs,
@@ -0,0 +1,161 @@
+using System.Text.RegularExpressions;
+using Wavee.Core.Configuration;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// User rules for tracks the player skips on its own, checked before each track starts.
+/// </summary>
+/// <remarks>
+/// Rules are checked in the order of <see cref="TrackFilterRule"/>; the first match wins.
+/// A rule whose metadata isn't known yet (no duration, no artist) doesn't match, and is
+/// checked again once the track has been resolved.
+/// </remarks>
+public sealed partial record TrackFilterRules
+{
+    /// <summary>
+    /// No rules; every track plays.
+    /// </summary>
+    public static TrackFilterRules None { get; } = new();
+
+    /// <summary>
+    /// Track URIs (<c>spotify:track:…</c>) never to play.
+    /// </summary>
+    public IReadOnlyList<string> BlockedTracks { get; init; } = [];
+
+    /// <summary>
+    /// Artists never to play, as artist URIs (<c>spotify:artist:…</c>) or names
+    /// (case-insensitive). A track matches when any of its artists is listed.
+    /// </summary>
+    public IReadOnlyList<string> BlockedArtists { get; init; } = [];
+
+    /// <summary>
+    /// Tracks longer than this are skipped. Null (default) allows any length.
+    /// </summary>
+    public TimeSpan? MaxDuration { get; init; }
+
+    /// <summary>
+    /// Skip live recordings: titles such as "Song - Live" or "Song (Live at …)", and
+    /// albums such as "Live at …". Default is false.
+    /// </summary>
+    public bool SkipLiveVersions { get; init; }
+
+    /// <summary>
+    /// Rules from the <c>player.*</c> configuration keys.
+    /// </summary>
+    public static TrackFilterRules FromConfig(PlayerConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+        return new TrackFilterRules
+        {
+            BlockedTracks = config.BlockedTracks,
+            BlockedArtists = config.BlockedArtists,
+            MaxDuration = config.MaxTrackDuration > TimeSpan.Zero ? config.MaxTrackDuration : null,
+            SkipLiveVersions = config.SkipLiveVersions
+        };
+    }
+
+    /// <summary>
+    /// True when no rule is set.
+    /// </summary>
+    public bool IsEmpty => BlockedTracks.Count == 0 && BlockedArtists.Count == 0
+        && MaxDuration is null && !SkipLiveVersions;
+
+    /// <summary>
+    /// Finds the first rule <paramref name="track"/> breaks.
+    /// </summary>
+    /// <returns>The matching rule and what matched, or null when the track may play.</returns>
+    internal TrackFilterMatch? Match(TrackFilterCandidate track)
+    {
+        if (BlockedTracks.Contains(track.Uri, StringComparer.Ordinal))
+            return new TrackFilterMatch(TrackFilterRule.BlockedTrack, track.Uri);
+
+        if (FindBlockedArtist(track) is { } artist)
+            return new TrackFilterMatch(TrackFilterRule.BlockedArtist, artist);
+
+        if (MaxDuration is { } max && track.DurationMs is { } duration && duration > 0
+            && TimeSpan.FromMilliseconds(duration) > max)
+        {
+            return new TrackFilterMatch(TrackFilterRule.MaxDuration, $"{TimeSpan.FromMilliseconds(duration):g} > {max:g}");
+        }
+
+        if (SkipLiveVersions)
+        {
+            if (track.Title is { } title && LiveTitle().IsMatch(title))
+                return new TrackFilterMatch(TrackFilterRule.LiveVersion, title);
+            if (track.Album is { } album && LiveAlbum().IsMatch(album))
+                return new TrackFilterMatch(TrackFilterRule.LiveVersion, album);
+        }
+
+        return null;
+    }
+
+    private string? FindBlockedArtist(TrackFilterCandidate track)
+    {
+        if (BlockedArtists.Count == 0)
+            return null;
+
+        if (track.ArtistUri is { } uri && BlockedArtists.Contains(uri, StringComparer.Ordinal))
+            return uri;
+
+        if (track.Artist is not { } artist)
+            return null;
+
+        // Multi-artist tracks carry a joined "A, B" display string.
+        foreach (var name in artist.Split(", ").Prepend(artist))
+        {
+            if (BlockedArtists.Contains(name.Trim(), StringComparer.OrdinalIgnoreCase))
+                return name.Trim();
+        }
+        return null;
+    }
+
+    // "Song - Live", "Song (Live at Wembley)", "Song [Live]" — but not "Live Forever".
+    [GeneratedRegex(@"(?:[(\[]|\s[-–]\s)\s*live\b", RegexOptions.IgnoreCase | RegexOptions.CultureInvariant)]
+    private static partial Regex LiveTitle();
+
+    // "Live at Wembley", "Unplugged (Live)", "Tour 2019 - Live".
+    [GeneratedRegex(@"^live\s+(?:at|from|in|on)\b|[(\[]\s*live\b|\s[-–]\s*live\b", RegexOptions.IgnoreCase | RegexOptions.CultureInvariant)]
+    private static partial Regex LiveAlbum();
+}
+
+/// <summary>
+/// A <see cref="TrackFilterRules"/> rule.
+/// </summary>
+public enum TrackFilterRule
+{
+    /// <summary>The track is in <see cref="TrackFilterRules.BlockedTracks"/>.</summary>
+    BlockedTrack,
+
+    /// <summary>An artist of the track is in <see cref="TrackFilterRules.BlockedArtists"/>.</summary>
+    BlockedArtist,
+
+    /// <summary>The track is longer than <see cref="TrackFilterRules.MaxDuration"/>.</summary>
+    MaxDuration,
+
+    /// <summary>The track is a live recording and <see cref="TrackFilterRules.SkipLiveVersions"/> is set.</summary>
+    LiveVersion
+}
+
+/// <summary>
+/// Published by <see cref="PlaybackOrchestrator.Skipped"/> when a track is skipped by a
+/// <see cref="TrackFilterRules"/> rule instead of being played.
+/// </summary>
+/// <param name="TrackUri">The skipped track.</param>
+/// <param name="Rule">The rule that matched.</param>
+/// <param name="Detail">What matched: the artist, the title, or the duration against the limit.</param>
+/// <param name="ContextUri">Context the track came from.</param>
+public sealed record TrackSkippedEvent(string TrackUri, TrackFilterRule Rule, string Detail, string? ContextUri);
+
+/// <summary>
+/// What the filter knows about a track: queue metadata before resolution, resolved metadata after.
+/// </summary>
+internal sealed record TrackFilterCandidate(
+    string Uri,
+    string? Title = null,
+    string? Artist = null,
+    string? ArtistUri = null,
+    string? Album = null,
+    long? DurationMs = null);
+
+internal sealed record TrackFilterMatch(TrackFilterRule Rule, string Detail);
@@ -5,6 +5,7 @@ using Wavee.Core.Audio;
 using Wavee.Core.Http;
 using Wavee.Core.Session;
 using Wavee.Core.Storage;
+using Wavee.Core.Storage.Abstractions;
 using Wavee.Core.Video;
 using Wavee.Playback.Contracts;
 using Wavee.Protocol.ExtendedMetadata;
@@ -30,10 +31,12 @@ public sealed class TrackResolver
     private readonly HeadFileClient _headFileClient;
     private readonly IExtendedMetadataClient? _extendedMetadataClient;
     private readonly ICacheService? _cacheService;
+    private readonly IMetadataDatabase? _metadataDatabase;
     private readonly IVideoManifestCache? _videoManifestCache;
     private readonly HttpClient _httpClient;
     private AudioQuality _preferredQuality;
     private readonly ILogger? _logger;
+    private readonly PrewarmSlot _prewarmed = new();
 
     /// <summary>
     /// Directory where AudioHost persists fully downloaded tracks.
@@ -52,7 +55,8 @@ public sealed class TrackResolver
         ICacheService? cacheService = null,
         ILogger? logger = null,
         string? audioCacheDirectory = null,
-        IVideoManifestCache? videoManifestCache = null)
+        IVideoManifestCache? videoManifestCache = null,
+        IMetadataDatabase? metadataDatabase = null)
     {
         _session = session ?? throw new ArgumentNullException(nameof(session));
         _spClient = spClient ?? throw new ArgumentNullException(nameof(spClient));
@@ -64,6 +68,7 @@ public sealed class TrackResolver
         _videoManifestCache = videoManifestCache;
         _logger = logger;
         _audioCacheDirectory = audioCacheDirectory;
+        _metadataDatabase = metadataDatabase;
     }
 
     /// <summary>
@@ -151,6 +156,8 @@ public sealed class TrackResolver
             var fileId = FileId.FromBytes(selectedFile.FileId.Span);
             var fileIdHex = fileId.ToBase16();
 
+            await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
             // If the encrypted file is already on disk, skip CDN + head entirely
             // and only warm the AudioKey (decryption needs it at playback time).
             if (_audioCacheDirectory != null && AudioFileCache.IsCached(_audioCacheDirectory, fileIdHex))
@@ -194,6 +201,8 @@ public sealed class TrackResolver
             var fileId = FileId.FromBytes(selectedFile.FileId.Span);
             var fileIdHex = fileId.ToBase16();
 
+            await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
             if (_audioCacheDirectory != null && AudioFileCache.IsCached(_audioCacheDirectory, fileIdHex))
             {
                 await _session.AudioKeys.RequestAudioKeyAsync(episodeId, fileId, ct).ConfigureAwait(false);
@@ -399,19 +408,61 @@ public sealed class TrackResolver
 
     /// <summary>
     /// Resolves a track with deferred CDN — returns head data immediately,
-    /// CDN URL + audio key as background tasks.
+    /// CDN URL + audio key as background tasks. Returns the result of a matching
+    /// <see cref="PrewarmAsync"/> without any network work.
     /// </summary>
-    public async Task<TrackResolution> ResolveWithHeadAsync(string uri, CancellationToken ct = default)
+    public Task<TrackResolution> ResolveWithHeadAsync(string uri, CancellationToken ct = default)
+    {
+        if (_prewarmed.Take(uri, _preferredQuality, DateTimeOffset.UtcNow) is { } prewarmed)
+        {
+            _logger?.LogDebug("Using prewarmed resolution for {Uri}", uri);
+            return Task.FromResult(prewarmed);
+        }
+
+        return ResolveWithHeadAsync(uri, _preferredQuality, forDownload: false, ct);
+    }
+
+    /// <summary>
+    /// Fully resolves a track or episode ahead of playback — metadata, head data,
+    /// audio key, CDN URL and file size — and keeps the result for the next
+    /// <see cref="ResolveWithHeadAsync(string, CancellationToken)"/> of the same URI,
+    /// which then completes immediately. Only the latest prewarm is kept, for up to
+    /// ten minutes. Unlike <see cref="PrefetchAsync"/>, failures are thrown.
+    /// </summary>
+    public async Task<TrackResolution> PrewarmAsync(string uri, CancellationToken ct = default)
+    {
+        var quality = _preferredQuality;
+        var resolution = await ResolveWithHeadAsync(uri, quality, forDownload: false, ct).ConfigureAwait(false);
+
+        // File size waits on the CDN URL, so this covers all three background lookups.
+        await Task.WhenAll(resolution.AudioKeyTask, resolution.FileSizeTask).WaitAsync(ct).ConfigureAwait(false);
+
+        _prewarmed.Store(uri, quality, resolution, DateTimeOffset.UtcNow);
+        _logger?.LogInformation("Prewarmed {Uri} ({Codec}, head={HeadBytes} bytes)",
+            uri, resolution.Codec, resolution.HeadData?.Length ?? 0);
+        return resolution;
+    }
+
+    /// <summary>
+    /// Resolves a track or episode for the offline store at <paramref name="quality"/>,
+    /// independent of the streaming quality. Unlike <see cref="ResolveWithHeadAsync(string, CancellationToken)"/>
+    /// an existing download at another quality is not substituted.
+    /// </summary>
+    public Task<TrackResolution> ResolveForDownloadAsync(string uri, AudioQuality quality, CancellationToken ct = default)
+        => ResolveWithHeadAsync(uri, quality, forDownload: true, ct);
+
+    private async Task<TrackResolution> ResolveWithHeadAsync(
+        string uri, AudioQuality quality, bool forDownload, CancellationToken ct)
     {
         if (uri.StartsWith("spotify:episode:", StringComparison.OrdinalIgnoreCase))
-            return await ResolveEpisodeWithHeadAsync(uri, ct).ConfigureAwait(false);
+            return await ResolveEpisodeWithHeadAsync(uri, quality, ct).ConfigureAwait(false);
 
-        _logger?.LogInformation("Resolving track (with head) {Uri} at quality {Quality}", uri, _preferredQuality);
+        _logger?.LogInformation("Resolving track (with head) {Uri} at quality {Quality}", uri, quality);
 
         var trackId = SpotifyId.FromUri(uri);
         var track = await FetchTrackMetadataAsync(uri, trackId, ct);
 
-        var (selectedFile, effectiveTrack) = await SelectAudioFileAsync(track, _preferredQuality, ct);
+        var (selectedFile, effectiveTrack) = await SelectAudioFileAsync(track, quality, ct);
         if (selectedFile == null || effectiveTrack == null)
             throw new InvalidOperationException($"No suitable audio file found for track {uri}");
 
@@ -422,6 +473,8 @@ public sealed class TrackResolver
         var fileId = FileId.FromBytes(selectedFile.FileId.Span);
         var fileIdHex = fileId.ToBase16();
         var audioFormat = MapToAudioFileFormat(selectedFile.Format);
+        var codec = GetCodecName(audioFormat);
+        var bitrateKbps = audioFormat.GetBitrate();
 
         // Cheap manifest_id discovery for self-contained video tracks (i.e.,
         // tracks where Spotify has populated Track.original_video on the
@@ -431,14 +484,32 @@ public sealed class TrackResolver
         // discovering their manifest is a Phase-2 GraphQL/NPV follow-up.
         var videoManifestId = ExtractOriginalVideoGid(track) ?? ExtractOriginalVideoGid(effectiveTrack);
 
+        await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
         // ── Cache short-circuit ────────────────────────────────────────────────────
         // If the full encrypted audio file is already on disk, skip both the CDN
         // storage-resolve call AND the head-file fetch. We still need the audio key
         // (decryption happens at playback time regardless of source).
+        // A download stored at a different quality than we stream at still beats the network.
+        if (!forDownload
+            && _audioCacheDirectory != null
+            && !AudioFileCache.IsCached(_audioCacheDirectory, fileIdHex)
+            && await FindDownloadedAsync(uri, ct) is { } downloaded)
+        {
+            _logger?.LogInformation("Using {Bitrate} kbps download of {Uri} instead of streaming", downloaded.BitrateKbps, uri);
+            fileId = FileId.FromBase16(downloaded.FileId);
+            fileIdHex = downloaded.FileId;
+            codec = downloaded.Codec;
+            bitrateKbps = downloaded.BitrateKbps;
+        }
+
         if (_audioCacheDirectory != null && AudioFileCache.IsCached(_audioCacheDirectory, fileIdHex))
         {
             _logger?.LogInformation("Cache HIT for {FileId} — skipping CDN and head fetch", fileIdHex);
 
+            if (!forDownload)
+                TouchDownloaded(uri);
+
             var cachedFileSize = AudioFileCache.GetCachedFileSize(_audioCacheDirectory, fileIdHex);
             var keyTaskCached = _session.AudioKeys.RequestAudioKeyAsync(effectiveTrackId, fileId, ct);
             var metadata = BuildMetadataDto(uri, track, NormalizationData.Default);
@@ -446,8 +517,8 @@ public sealed class TrackResolver
             return new TrackResolution
             {
                 TrackUri = uri,
-                Codec = GetCodecName(audioFormat),
-                BitrateKbps = audioFormat.GetBitrate(),
+                Codec = codec,
+                BitrateKbps = bitrateKbps,
                 HeadData = null,
                 Normalization = NormalizationData.Default,
                 Metadata = metadata,
@@ -499,8 +570,8 @@ public sealed class TrackResolver
         return new TrackResolution
         {
             TrackUri = uri,
-            Codec = GetCodecName(audioFormat),
-            BitrateKbps = audioFormat.GetBitrate(),
+            Codec = codec,
+            BitrateKbps = bitrateKbps,
             HeadData = headData,
             Normalization = normalization,
             Metadata = trackMetadata,
@@ -513,9 +584,9 @@ public sealed class TrackResolver
         };
     }
 
-    private async Task<TrackResolution> ResolveEpisodeWithHeadAsync(string uri, CancellationToken ct)
+    private async Task<TrackResolution> ResolveEpisodeWithHeadAsync(string uri, AudioQuality quality, CancellationToken ct)
     {
-        _logger?.LogInformation("Resolving episode (with head) {Uri} at quality {Quality}", uri, _preferredQuality);
+        _logger?.LogInformation("Resolving episode (with head) {Uri} at quality {Quality}", uri, quality);
 
         var episodeId = SpotifyId.FromUri(uri);
         var metadataBytes = await _spClient.GetEpisodeMetadataAsync(uri, ct).ConfigureAwait(false);
@@ -524,7 +595,7 @@ public sealed class TrackResolver
         if (episode.Audio.Count == 0)
             throw new InvalidOperationException($"No audio files for episode {uri}");
 
-        var selectedFile = SelectAudioFileFromEpisode(episode, _preferredQuality);
+        var selectedFile = SelectAudioFileFromEpisode(episode, quality);
         if (selectedFile == null)
             throw new InvalidOperationException($"No suitable audio file found for episode {uri}");
 
@@ -533,6 +604,8 @@ public sealed class TrackResolver
         var audioFormat = MapToAudioFileFormat(selectedFile.Format);
         var metadata = BuildEpisodeMetadataDto(episode);
 
+        await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
         if (_audioCacheDirectory != null && AudioFileCache.IsCached(_audioCacheDirectory, fileIdHex))
         {
             _logger?.LogInformation("Cache HIT for episode {FileId} - skipping CDN and head fetch", fileIdHex);
@@ -703,6 +776,8 @@ public sealed class TrackResolver
         var fileId = FileId.FromBytes(selectedFile.FileId.Span);
         var audioFormat = MapToAudioFileFormat(selectedFile.Format);
 
+        await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
         // 4. Parallel fetches: head file + audio key + CDN URL
         var headTask = GetHeadDataAsync(fileId, ct);
         var keyTask = _session.AudioKeys.RequestAudioKeyAsync(effectiveTrackId, fileId, ct);
@@ -741,6 +816,8 @@ public sealed class TrackResolver
             BitrateKbps = audioFormat.GetBitrate(),
             NormalizationGain = normalization.TrackGainDb,
             NormalizationPeak = normalization.TrackPeak,
+            NormalizationAlbumGain = normalization.AlbumGainDb,
+            NormalizationAlbumPeak = normalization.AlbumPeak,
             DurationMs = track.Duration,
             Metadata = metadata
         };
@@ -765,6 +842,8 @@ public sealed class TrackResolver
         var fileId = FileId.FromBytes(selectedFile.FileId.Span);
         var audioFormat = MapToAudioFileFormat(selectedFile.Format);
 
+        await ThrowIfNotAvailableOfflineAsync(uri, ct).ConfigureAwait(false);
+
         // Parallel fetches
         var headTask = GetHeadDataAsync(fileId, ct);
         var keyTask = Task.Run(() => _session.AudioKeys.RequestAudioKeyAsync(episodeId, fileId, ct));
@@ -793,6 +872,8 @@ public sealed class TrackResolver
             BitrateKbps = audioFormat.GetBitrate(),
             NormalizationGain = normalization.TrackGainDb,
             NormalizationPeak = normalization.TrackPeak,
+            NormalizationAlbumGain = normalization.AlbumGainDb,
+            NormalizationAlbumPeak = normalization.AlbumPeak,
             DurationMs = episode.Duration,
             Metadata = new TrackMetadataDto
             {
@@ -990,6 +1071,60 @@ public sealed class TrackResolver
         return null;
     }
 
+    /// <summary>
+    /// Returns the offline-store entry for <paramref name="uri"/> when its audio file is on disk.
+    /// </summary>
+    private async Task<OfflineTrackEntry?> FindDownloadedAsync(string uri, CancellationToken ct)
+    {
+        if (_metadataDatabase == null || _audioCacheDirectory == null)
+            return null;
+
+        try
+        {
+            var entry = await _metadataDatabase.GetOfflineTrackAsync(uri, ct);
+            return entry != null && AudioFileCache.IsCached(_audioCacheDirectory, entry.FileId) ? entry : null;
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogDebug(ex, "Offline store lookup failed for {Uri}", uri);
+            return null;
+        }
+    }
+
+    /// <summary>
+    /// Stamps an offline-store track as played so quota eviction keeps it longer.
+    /// Best-effort and off the resolve path; a no-op for tracks that weren't downloaded.
+    /// </summary>
+    private void TouchDownloaded(string uri)
+    {
+        if (_metadataDatabase == null)
+            return;
+
+        var database = _metadataDatabase;
+        var playedAt = DateTimeOffset.UtcNow.ToUnixTimeSeconds();
+        _ = Task.Run(async () =>
+        {
+            try
+            {
+                await database.TouchOfflineTrackAsync(uri, playedAt);
+            }
+            catch (Exception ex)
+            {
+                _logger?.LogDebug(ex, "Failed to stamp offline track {Uri} as played", uri);
+            }
+        });
+    }
+
+    /// <summary>
+    /// In offline mode only tracks in the offline store play — a file that is merely in the
+    /// streaming cache doesn't count — so fail before touching the cache or the CDN.
+    /// </summary>
+    private async Task ThrowIfNotAvailableOfflineAsync(string uri, CancellationToken ct)
+    {
+        if (_session.IsOfflineMode && await FindDownloadedAsync(uri, ct).ConfigureAwait(false) == null)
+            throw new OfflineModeException($"{uri} is not available offline");
+    }
+
     // ── Format mapping ──
 
     private static string GetCodecName(AudioFileFormat format) => format switch
@@ -0,0 +1,248 @@
+using Wavee.Connect;
+using Wavee.Connect.Events;
+
+namespace Wavee.Audio;
+
+/// <summary>
+/// Playback statistics for one finished track, published by
+/// <see cref="PlaybackOrchestrator.TrackStatsCompleted"/>. Intended for tuning
+/// buffer and cache settings rather than for reporting to Spotify.
+/// </summary>
+/// <param name="TrackUri">Track that played.</param>
+/// <param name="StartedAt">When the engine first reported the track.</param>
+/// <param name="EndedAt">When the track was replaced, stopped, skipped or finished.</param>
+/// <param name="BytesDownloaded">Bytes fetched for the track (head data + CDN); 0 for cache hits and local files.</param>
+/// <param name="FromCache">True when the audio was served from the persistent audio cache.</param>
+/// <param name="DecodeTime">Time spent decoding, including waits on the download.</param>
+/// <param name="Underruns">Output underruns while the track was playing.</param>
+/// <param name="SeekCount">Seeks issued while the track was current.</param>
+/// <param name="Played">Time the track was audible: playing, not paused and not buffering.</param>
+/// <param name="Title">Track title, when the engine reported one.</param>
+/// <param name="Artist">Artist display string ("A, B" for several artists), when reported.</param>
+/// <param name="ArtistUri">URI of the first artist, when reported.</param>
+/// <param name="EndReason">Why the track ended.</param>
+/// <param name="ErrorKind">What failed, when <paramref name="EndReason"/> is <see cref="TrackEndReason.Error"/>.</param>
+public sealed record TrackPlaybackStats(
+    string TrackUri,
+    DateTimeOffset StartedAt,
+    DateTimeOffset EndedAt,
+    long BytesDownloaded,
+    bool FromCache,
+    TimeSpan DecodeTime,
+    long Underruns,
+    int SeekCount,
+    TimeSpan Played = default,
+    string? Title = null,
+    string? Artist = null,
+    string? ArtistUri = null,
+    TrackEndReason EndReason = TrackEndReason.Stopped,
+    PlaybackErrorType? ErrorKind = null)
+{
+    /// <summary><see cref="Played"/> in whole milliseconds, as scrobblers expect it.</summary>
+    public long MsPlayed => (long)Played.TotalMilliseconds;
+}
+
+/// <summary>
+/// Folds engine state pushes into per-track <see cref="TrackPlaybackStats"/>
+/// and keeps the most recent ones in a fixed-size ring buffer.
+/// </summary>
+/// <remarks>
+/// A track ends when the engine reports a different track URI (or none), or
+/// when <see cref="Complete"/> is called on stop. Engine counters arrive with
+/// the periodic state push, so the final figures may lag the true totals by
+/// up to one publish interval. Played time is measured between pushes, from
+/// whether the previous push was audible.
+/// <para>
+/// <see cref="MarkEnded"/> records why the current track is ending and stops
+/// its played time at that moment; the stats are still published when the
+/// engine moves off the track. Tracks that end without a mark are reported as
+/// <see cref="TrackEndReason.Replaced"/> (engine moved on) or
+/// <see cref="TrackEndReason.Stopped"/> (<see cref="Complete"/>).
+/// </para>
+/// </remarks>
+internal sealed class TrackStatsRecorder
+{
+    public const int DefaultCapacity = 50;
+
+    private readonly object _lock = new();
+    private readonly TrackPlaybackStats[] _history;
+    private int _historyNext;
+    private int _historyCount;
+
+    private string? _trackUri;
+    private DateTimeOffset _startedAt;
+    private EngineTrackCounters? _counters;
+    private int _seekCount;
+    private TimeSpan _played;
+    private DateTimeOffset _lastObservedAt;
+    private bool _audible;
+    private string? _title;
+    private string? _artist;
+    private string? _artistUri;
+    private TrackEndReason? _endReason;
+    private PlaybackErrorType? _errorKind;
+    private long _endPositionMs;
+    private DateTimeOffset _endedAt;
+
+    public TrackStatsRecorder(int capacity = DefaultCapacity)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThan(capacity, 1);
+        _history = new TrackPlaybackStats[capacity];
+    }
+
+    /// <summary>
+    /// Applies an engine state push.
+    /// </summary>
+    /// <returns>Stats for the previous track if this push moved off it; otherwise null.</returns>
+    public TrackPlaybackStats? Observe(LocalPlaybackState state, DateTimeOffset now)
+    {
+        lock (_lock)
+        {
+            // Pushes naming an ended track are stale until the engine restarts it
+            // (repeat-track), which shows up as the position going back.
+            var restarted = _endReason != null && state.PositionMs < _endPositionMs;
+            if (_trackUri != null && state.TrackUri == _trackUri && !restarted)
+            {
+                if (state.TrackCounters != null)
+                    _counters = state.TrackCounters;
+                if (_endReason != null)
+                    return null;
+                AccumulatePlayedLocked(now);
+                ObserveStateLocked(state);
+                return null;
+            }
+
+            var completed = CompleteLocked(now, TrackEndReason.Replaced);
+
+            // Only an active engine starts a track — the idle push that follows
+            // a stop still names the stopped track and must not reopen it.
+            if (!string.IsNullOrEmpty(state.TrackUri) && (state.IsPlaying || state.IsBuffering))
+            {
+                _trackUri = state.TrackUri;
+                _startedAt = now;
+                _counters = state.TrackCounters;
+                _lastObservedAt = now;
+                ObserveStateLocked(state);
+            }
+            return completed;
+        }
+    }
+
+    /// <summary>
+    /// Records why the current track is ending. Played time stops here; the
+    /// first mark wins, so a stop following a skip keeps the skip.
+    /// </summary>
+    /// <param name="reason">Why the track ended.</param>
+    /// <param name="errorKind">What failed, for <see cref="TrackEndReason.Error"/>.</param>
+    /// <param name="positionMs">Playback position the track ended at.</param>
+    /// <param name="now">Time of the end.</param>
+    public void MarkEnded(TrackEndReason reason, PlaybackErrorType? errorKind, long positionMs, DateTimeOffset now)
+    {
+        lock (_lock)
+        {
+            if (_trackUri == null || _endReason != null)
+                return;
+
+            AccumulatePlayedLocked(now);
+            _endReason = reason;
+            _errorKind = reason == TrackEndReason.Error ? errorKind ?? PlaybackErrorType.Unknown : null;
+            _endPositionMs = positionMs;
+            _endedAt = now;
+        }
+    }
+
+    /// <summary>Counts a seek against the current track.</summary>
+    public void RecordSeek()
+    {
+        lock (_lock)
+        {
+            if (_trackUri != null && _endReason == null)
+                _seekCount++;
+        }
+    }
+
+    /// <summary>
+    /// Ends the current track (stop / dispose).
+    /// </summary>
+    /// <returns>Stats for the track, or null if nothing was playing.</returns>
+    public TrackPlaybackStats? Complete(DateTimeOffset now)
+    {
+        lock (_lock)
+            return CompleteLocked(now, TrackEndReason.Stopped);
+    }
+
+    /// <summary>Completed tracks, oldest first.</summary>
+    public IReadOnlyList<TrackPlaybackStats> GetHistory()
+    {
+        lock (_lock)
+        {
+            var result = new TrackPlaybackStats[_historyCount];
+            var start = (_historyNext - _historyCount + _history.Length) % _history.Length;
+            for (var i = 0; i < _historyCount; i++)
+                result[i] = _history[(start + i) % _history.Length];
+            return result;
+        }
+    }
+
+    private static bool IsAudible(LocalPlaybackState state) =>
+        state.IsPlaying && !state.IsPaused && !state.IsBuffering;
+
+    private void AccumulatePlayedLocked(DateTimeOffset now)
+    {
+        if (_endReason != null)
+            return;
+        if (_audible && now > _lastObservedAt)
+            _played += now - _lastObservedAt;
+        _lastObservedAt = now;
+    }
+
+    private void ObserveStateLocked(LocalPlaybackState state)
+    {
+        _audible = IsAudible(state);
+        _title = state.TrackTitle ?? _title;
+        _artist = state.TrackArtist ?? _artist;
+        _artistUri = state.ArtistUri ?? _artistUri;
+    }
+
+    private TrackPlaybackStats? CompleteLocked(DateTimeOffset now, TrackEndReason unmarkedReason)
+    {
+        if (_trackUri == null)
+            return null;
+
+        AccumulatePlayedLocked(now);
+        var counters = _counters;
+        var stats = new TrackPlaybackStats(
+            _trackUri,
+            _startedAt,
+            _endReason != null ? _endedAt : now,
+            counters?.BytesDownloaded ?? 0,
+            counters?.FromCache ?? false,
+            TimeSpan.FromMilliseconds(counters?.DecodeMs ?? 0),
+            counters?.Underruns ?? 0,
+            _seekCount,
+            _played,
+            _title,
+            _artist,
+            _artistUri,
+            _endReason ?? unmarkedReason,
+            _errorKind);
+
+        _history[_historyNext] = stats;
+        _historyNext = (_historyNext + 1) % _history.Length;
+        if (_historyCount < _history.Length)
+            _historyCount++;
+
+        _trackUri = null;
+        _counters = null;
+        _seekCount = 0;
+        _played = TimeSpan.Zero;
+        _audible = false;
+        _title = null;
+        _artist = null;
+        _artistUri = null;
+        _endReason = null;
+        _errorKind = null;
+        _endPositionMs = 0;
+        return stats;
+    }
+}
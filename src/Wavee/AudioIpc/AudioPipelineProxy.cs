@@ -25,7 +25,11 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
     private readonly BehaviorSubject<LocalPlaybackState> _stateSubject = new(new LocalPlaybackState());
     private readonly Subject<PlaybackError> _errorSubject = new();
     private readonly Subject<TrackFinishedMessage> _trackFinishedSubject = new();
+    private readonly Subject<PipelineStallMessage> _pipelineStallSubject = new();
+    private readonly Subject<AudioDeviceChangedMessage> _deviceChangedSubject = new();
     private readonly Subject<PreviewVisualizationFrame> _previewVisualizationFrameSubject = new();
+    private readonly Subject<PcmTapFrame> _pcmTapFrameSubject = new();
+    private readonly Subject<OutputDiagnosticMessage> _outputDiagnosticSubject = new();
 
     private long _nextRequestId;
     private Task? _receiveLoop;
@@ -106,11 +110,34 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
     public IObservable<PlaybackError> Errors => _errorSubject.AsObservable();
     public IObservable<PreviewVisualizationFrame> PreviewVisualizationFrames => _previewVisualizationFrameSubject.AsObservable();
 
+    /// <summary>
+    /// Visualization frames of the playing audio, once enabled with <see cref="ConfigurePcmTapAsync"/>.
+    /// </summary>
+    public IObservable<PcmTapFrame> PcmTapFrames => _pcmTapFrameSubject.AsObservable();
+
     /// <summary>
     /// Fires when AudioHost reports a track has finished playing naturally.
     /// The layer above (PlaybackService) should resolve and send the next track.
     /// </summary>
     public IObservable<TrackFinishedMessage> TrackFinished => _trackFinishedSubject.AsObservable();
+
+    /// <summary>
+    /// Fires when AudioHost's watchdog finds playback frozen and restarts the pipeline
+    /// from the stalled position (or gives up after repeated restarts). Diagnostic only.
+    /// </summary>
+    public IObservable<PipelineStallMessage> PipelineStalls => _pipelineStallSubject.AsObservable();
+
+    /// <summary>
+    /// Fires when AudioHost's output analysis sees sustained digital silence or clipping,
+    /// and again when it clears. Only while enabled with <see cref="SetOutputDiagnosticsAsync"/>.
+    /// </summary>
+    public IObservable<OutputDiagnosticMessage> OutputDiagnostics => _outputDiagnosticSubject.AsObservable();
+
+    /// <summary>
+    /// Fires when AudioHost moves playback to another output device mid-track
+    /// (user selection, new system default, or the device was unplugged).
+    /// </summary>
+    public IObservable<AudioDeviceChangedMessage> DeviceChanged => _deviceChangedSubject.AsObservable();
     public LocalPlaybackState CurrentState => _stateSubject.Value;
 
     /// <summary>
@@ -140,6 +167,10 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
         int parentProcessId = 0,
         string? sessionId = null,
         string? launchToken = null,
+        int? cdnRequestTimeoutMs = null,
+        string? localAddress = null,
+        string? addressFamily = null,
+        string? decoderBackend = null,
         CancellationToken ct = default)
     {
         var config = new AudioHostConfig
@@ -153,6 +184,10 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
             InitialVolumePercent = initialVolumePercent,
             AudioCacheDirectory = audioCacheDirectory,
             AudioCacheMaxBytes = audioCacheMaxBytes,
+            CdnRequestTimeoutMs = cdnRequestTimeoutMs,
+            LocalAddress = localAddress,
+            AddressFamily = addressFamily,
+            DecoderBackend = decoderBackend,
         };
         var configJson = IpcPayloadHelper.SerializeToUtf8(config);
         await _transport.SendAsync(IpcMessageTypes.Configure, configJson, ct: ct);
@@ -196,6 +231,19 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
     public Task PlayLocalFileAsync(PlayLocalFileCommand cmd, CancellationToken ct = default)
         => SendCommandAsync(IpcMessageTypes.PlayLocalFile, cmd, ct);
 
+    /// <summary>
+    /// Tells AudioHost to play a public 30-second preview clip over HTTP. Needs no
+    /// session: the clip is unencrypted and fetched by AudioHost itself.
+    /// </summary>
+    public Task PlayPreviewAsync(PlayPreviewCommand cmd, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.PlayPreview, cmd, ct);
+
+    /// <summary>
+    /// Parses the track's decoder headers in AudioHost ahead of playback.
+    /// </summary>
+    public Task PrewarmAsync(PrewarmTrackCommand cmd, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.Prewarm, cmd, ct);
+
     /// <summary>
     /// Completes the deferred CDN resolution so AudioHost can continue from CDN after head data.
     /// Pass <paramref name="spotifyFileId"/> so AudioHost can persist the download to the audio cache.
@@ -233,6 +281,13 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
     public Task StopAsync(CancellationToken cancellationToken = default)
         => SendSimpleCommandAsync(IpcMessageTypes.Stop, cancellationToken);
 
+    /// <summary>
+    /// Closes AudioHost's output stream while nothing is playing. A paused track stays
+    /// loaded; resume or the next play command reopens the output.
+    /// </summary>
+    public Task ReleaseOutputAsync(CancellationToken cancellationToken = default)
+        => SendSimpleCommandAsync(IpcMessageTypes.ReleaseOutput, cancellationToken);
+
     public Task ResumeAsync(CancellationToken cancellationToken = default)
         => SendSimpleCommandAsync(IpcMessageTypes.Resume, cancellationToken);
 
@@ -266,6 +321,14 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
         => SendCommandAsync(IpcMessageTypes.SetNormalization,
             new SetNormalizationCommand { Enabled = enabled }, ct);
 
+    /// <summary>
+    /// Replaces AudioHost's normalization configuration (gain mode, target
+    /// loudness, method and limiter). Gain changes apply from the next track.
+    /// </summary>
+    public Task SetNormalizationAsync(NormalizationSettings settings, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetNormalization,
+            new SetNormalizationCommand { Enabled = settings.Enabled, Settings = settings }, ct);
+
     public async Task<EqualizerApplyResult> SetEqualizerAsync(bool enabled, double[]? bandGains, CancellationToken ct = default)
     {
         var result = await SendRequestAsync(IpcMessageTypes.SetEqualizer,
@@ -289,6 +352,19 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
         };
     }
 
+    /// <summary>
+    /// Describes AudioHost's active chain (fetcher → decryptor → decoder → DSP → sink)
+    /// with per-stage formats and buffer levels. Empty when nothing is loaded.
+    /// </summary>
+    public async Task<PipelineInfo> GetPipelineInfoAsync(CancellationToken ct = default)
+    {
+        var result = await SendRequestAsync(IpcMessageTypes.GetPipelineInfo, payloadBytes: null, ct).ConfigureAwait(false);
+        if (!result.Success)
+            throw new InvalidOperationException(result.ErrorMessage ?? "AudioHost did not return pipeline info");
+
+        return result.Result?.Deserialize(IpcJsonContext.Default.PipelineInfo) ?? new PipelineInfo();
+    }
+
     public Task SwitchQualityAsync(string quality, CancellationToken ct = default)
         => SendCommandAsync(IpcMessageTypes.SwitchQuality,
             new SwitchQualityCommand { Quality = quality }, ct);
@@ -300,6 +376,70 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
         => SendCommandAsync(IpcMessageTypes.SwitchAudioOutput,
             new SwitchAudioOutputCommand { DeviceIndex = deviceIndex }, ct);
 
+    /// <summary>
+    /// Route music and spoken-word content (episodes, chapters) to output devices
+    /// by name. The AudioHost applies the route at the start of each track; null
+    /// or an absent device plays on the system default.
+    /// </summary>
+    public Task SetAudioRoutingAsync(string? musicDeviceName, string? spokenWordDeviceName, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetAudioRouting,
+            new SetAudioRoutingCommand
+            {
+                MusicDeviceName = musicDeviceName,
+                SpokenWordDeviceName = spokenWordDeviceName
+            }, ct);
+
+    /// <summary>
+    /// Enables or disables bit-perfect output. Fails when AudioHost's sink can't
+    /// guarantee it; tracks then fail to start on devices that would resample.
+    /// </summary>
+    public async Task SetBitPerfectAsync(bool enabled, CancellationToken ct = default)
+    {
+        var result = await SendRequestAsync(IpcMessageTypes.SetBitPerfect,
+            new SetBitPerfectCommand { Enabled = enabled }, ct).ConfigureAwait(false);
+        if (!result.Success)
+            throw new InvalidOperationException(result.ErrorMessage ?? "AudioHost rejected bit-perfect mode");
+    }
+
+    /// <summary>
+    /// Sets the output channel layout for one device by name, or for every
+    /// device without its own layout when <paramref name="deviceName"/> is null.
+    /// </summary>
+    public Task SetChannelLayoutAsync(
+        string? deviceName,
+        ChannelLayoutMode mode,
+        int[]? channelMap = null,
+        CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetChannelLayout,
+            new SetChannelLayoutCommand { DeviceName = deviceName, Mode = mode, ChannelMap = channelMap }, ct);
+
+    /// <summary>
+    /// Loops playback between <paramref name="startMs"/> and <paramref name="endMs"/>
+    /// of <paramref name="trackUri"/>, which must be the loaded track. The loop is
+    /// dropped when another track starts.
+    /// </summary>
+    public async Task SetLoopRegionAsync(string trackUri, long startMs, long endMs, CancellationToken ct = default)
+    {
+        var result = await SendRequestAsync(IpcMessageTypes.SetLoopRegion,
+            new SetLoopRegionCommand { TrackUri = trackUri, StartMs = startMs, EndMs = endMs }, ct).ConfigureAwait(false);
+        if (!result.Success)
+            throw new InvalidOperationException(result.ErrorMessage ?? "AudioHost rejected the loop region");
+    }
+
+    /// <summary>
+    /// Removes the A-B loop; playback continues past its end.
+    /// </summary>
+    public Task ClearLoopRegionAsync(CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetLoopRegion, new SetLoopRegionCommand(), ct);
+
+    /// <summary>
+    /// Sets AudioHost's volume ramps for start/resume/seek (fade-in) and
+    /// pause/stop (fade-out). 0 disables a ramp.
+    /// </summary>
+    public Task SetTransportFadeAsync(int fadeInMs, int fadeOutMs, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetTransportFade,
+            new SetTransportFadeCommand { FadeInMs = fadeInMs, FadeOutMs = fadeOutMs }, ct);
+
     /// <summary>
     /// Music-video switch is owned by the orchestrator (PlayReady/DASH lives
     /// in the UI process). The bare AudioHost proxy has no path for it.
@@ -354,6 +494,23 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
             SessionId = sessionId
         }, ct);
 
+    /// <summary>
+    /// Turns AudioHost's silence and clipping analysis on or off; findings arrive on
+    /// <see cref="OutputDiagnostics"/>.
+    /// </summary>
+    public Task SetOutputDiagnosticsAsync(bool enabled, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.SetOutputDiagnostics,
+            new SetOutputDiagnosticsCommand { Enabled = enabled }, ct);
+
+    /// <summary>
+    /// Starts, changes or stops (<see cref="PcmTapMode.Off"/>) the visualization
+    /// frames on <see cref="PcmTapFrames"/>: <paramref name="size"/> waveform points
+    /// or spectrum bands, <paramref name="rateHz"/> times a second.
+    /// </summary>
+    public Task ConfigurePcmTapAsync(PcmTapMode mode, int rateHz = 30, int size = 64, CancellationToken ct = default)
+        => SendCommandAsync(IpcMessageTypes.ConfigurePcmTap,
+            new ConfigurePcmTapCommand { Mode = mode, RateHz = rateHz, Size = size }, ct);
+
     // PlayPlay is Spotify property. Forwards a derivation request to AudioHost.
     public async Task<byte[]> DerivePlayPlayKeyAsync(
         ReadOnlyMemory<byte> obfuscatedKey,
@@ -417,7 +574,10 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
     /// where the caller actually needs the result; everything else should
     /// remain fire-and-forget so a hung audio process doesn't stall the UI.
     /// </summary>
-    private async Task<CommandResultMessage> SendRequestAsync<T>(string type, T payload, CancellationToken ct)
+    private Task<CommandResultMessage> SendRequestAsync<T>(string type, T payload, CancellationToken ct)
+        => SendRequestAsync(type, IpcPayloadHelper.SerializeToUtf8(payload), ct);
+
+    private async Task<CommandResultMessage> SendRequestAsync(string type, byte[]? payloadBytes, CancellationToken ct)
     {
         var id = Interlocked.Increment(ref _nextRequestId);
         var tcs = new TaskCompletionSource<CommandResultMessage>(TaskCreationOptions.RunContinuationsAsynchronously);
@@ -435,9 +595,11 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
         try
         {
             Interlocked.Increment(ref _messagesSent);
-            var payloadBytes = IpcPayloadHelper.SerializeToUtf8(payload);
-            _logger?.LogTrace("IPC request: type={Type}, id={Id}, bytes={Bytes}", type, id, payloadBytes.Length);
-            await _transport.SendAsync(type, payloadBytes, id, ct).ConfigureAwait(false);
+            _logger?.LogTrace("IPC request: type={Type}, id={Id}, bytes={Bytes}", type, id, payloadBytes?.Length ?? 0);
+            if (payloadBytes != null)
+                await _transport.SendAsync(type, payloadBytes, id, ct).ConfigureAwait(false);
+            else
+                await _transport.SendAsync(type, id, ct).ConfigureAwait(false);
         }
         catch (Exception ex)
         {
@@ -625,6 +787,11 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
                     UpstreamChanges = (StateChanges)snapshot.Changes,
                     ActiveAudioDeviceName = _lastActiveAudioDeviceName,
                     AvailableAudioDevices = _lastAvailableAudioDevices,
+                    TrackCounters = new EngineTrackCounters(
+                        snapshot.TrackBytesDownloaded,
+                        snapshot.TrackFromCache,
+                        snapshot.TrackDecodeMs,
+                        snapshot.TrackUnderruns),
                 };
                 _stateSubject.OnNext(state);
                 break;
@@ -671,6 +838,29 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
                 }
                 break;
             }
+            case IpcMessageTypes.DeviceChanged:
+            {
+                var change = IpcPayloadHelper.Deserialize<AudioDeviceChangedMessage>(msg);
+                if (change != null)
+                {
+                    _logger?.LogInformation("Audio output moved ({Reason}): {Previous} -> {Device} at {Position}ms",
+                        change.Reason, change.PreviousDeviceName, change.DeviceName, change.PositionMs);
+                    _deviceChangedSubject.OnNext(change);
+                }
+                break;
+            }
+            case IpcMessageTypes.PipelineStalled:
+            {
+                var stall = IpcPayloadHelper.Deserialize<PipelineStallMessage>(msg);
+                if (stall != null)
+                {
+                    _logger?.LogWarning(
+                        "Audio pipeline stalled in {Stage} at {Position}ms for {Stalled}ms: {TrackUri} attempt={Attempt} restarted={Restarted}",
+                        stall.Stage, stall.PositionMs, stall.StalledMs, stall.TrackUri, stall.Attempt, stall.Restarted);
+                    _pipelineStallSubject.OnNext(stall);
+                }
+                break;
+            }
             case IpcMessageTypes.PreviewVisualizationFrame:
             {
                 var frame = IpcPayloadHelper.Deserialize<PreviewVisualizationFrame>(msg);
@@ -678,6 +868,26 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
                     _previewVisualizationFrameSubject.OnNext(frame);
                 break;
             }
+            case IpcMessageTypes.OutputDiagnostic:
+            {
+                var diagnostic = IpcPayloadHelper.Deserialize<OutputDiagnosticMessage>(msg);
+                if (diagnostic != null)
+                {
+                    _logger?.Log(diagnostic.Ended ? LogLevel.Information : LogLevel.Warning,
+                        "Audio output {Kind} at {Position}ms for {Duration}ms: {TrackUri} ended={Ended} clippedRuns={Runs}",
+                        diagnostic.Kind, diagnostic.PositionMs, diagnostic.DurationMs, diagnostic.TrackUri,
+                        diagnostic.Ended, diagnostic.ClippedRuns);
+                    _outputDiagnosticSubject.OnNext(diagnostic);
+                }
+                break;
+            }
+            case IpcMessageTypes.PcmTapFrame:
+            {
+                var frame = IpcPayloadHelper.Deserialize<PcmTapFrame>(msg);
+                if (frame != null)
+                    _pcmTapFrameSubject.OnNext(frame);
+                break;
+            }
             case IpcMessageTypes.Pong:
             {
                 // Compute RTT
@@ -755,7 +965,11 @@ public sealed class AudioPipelineProxy : IPlaybackEngine, IAsyncDisposable
 
         _stateSubject.Dispose();
         _errorSubject.Dispose();
+        _pipelineStallSubject.Dispose();
+        _deviceChangedSubject.Dispose();
         _previewVisualizationFrameSubject.Dispose();
+        _pcmTapFrameSubject.Dispose();
+        _outputDiagnosticSubject.Dispose();
         await _transport.DisposeAsync();
         _cts.Dispose();
     }
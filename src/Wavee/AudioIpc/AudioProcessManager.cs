@@ -4,6 +4,8 @@ using System.Runtime.InteropServices;
 using System.Security.Cryptography;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
 using Wavee.Playback.Contracts;
 
 namespace Wavee.AudioIpc;
@@ -20,6 +22,23 @@ public enum AudioProcessState
     Failed
 }
 
+/// <summary>
+/// Decoder set the AudioHost plays with.
+/// </summary>
+public enum AudioDecoderBackend
+{
+    /// <summary>
+    /// A decoder per format: NVorbis for Ogg Vorbis, BASS for the rest.
+    /// </summary>
+    Native,
+
+    /// <summary>
+    /// BASS for every format, Spotify's Ogg Vorbis streams included. FLAC, Opus and
+    /// AAC need the matching BASS plug-ins in <c>bass-plugins/</c>.
+    /// </summary>
+    Bass
+}
+
 /// <summary>
 /// Manages the lifecycle of the Wavee.AudioHost child process.
 /// Launches the process, establishes Named Pipe IPC, monitors health via
@@ -61,6 +80,10 @@ public sealed class AudioProcessManager : IAsyncDisposable
     private string? _audioPreset;
     private string? _audioCacheDirectory;
     private long? _audioCacheMaxBytes;
+    private TimeSpan? _cdnRequestTimeout;
+    private NetworkConfig? _network;
+    private AudioDecoderBackend _decoderBackend;
+    private bool _normalizationEnabled = true;
 
     // ── Resilience configuration ──
     private const int MaxRestartAttempts = 5;
@@ -175,6 +198,17 @@ public sealed class AudioProcessManager : IAsyncDisposable
         }
     }
 
+    /// <summary>
+    /// Launches the AudioHost executable at <paramref name="audioHostPath"/> instead of
+    /// searching the WinUI output layout. Used by hosts that ship it alongside themselves.
+    /// </summary>
+    public AudioProcessManager(string audioHostPath, ILogger? logger = null)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(audioHostPath);
+        _logger = logger;
+        _audioHostPath = Path.GetFullPath(audioHostPath);
+    }
+
     /// <summary>
     /// Parses the WinUI build output layout to recover the solution root, MSBuild Platform,
     /// and Configuration from <see cref="AppContext.BaseDirectory"/>.
@@ -237,6 +271,10 @@ public sealed class AudioProcessManager : IAsyncDisposable
         string? audioPreset = null,
         string? audioCacheDirectory = null,
         long? audioCacheMaxBytes = null,
+        TimeSpan? cdnRequestTimeout = null,
+        NetworkConfig? network = null,
+        AudioDecoderBackend decoderBackend = AudioDecoderBackend.Native,
+        bool normalizationEnabled = true,
         CancellationToken ct = default)
     {
         // Cache config for auto-restart (credentials no longer sent to AudioHost)
@@ -247,6 +285,10 @@ public sealed class AudioProcessManager : IAsyncDisposable
         _audioPreset = audioPreset;
         _audioCacheDirectory = audioCacheDirectory;
         _audioCacheMaxBytes = audioCacheMaxBytes;
+        _cdnRequestTimeout = cdnRequestTimeout;
+        _network = network;
+        _decoderBackend = decoderBackend;
+        _normalizationEnabled = normalizationEnabled;
         _restartCount = 0;
 
         return await LaunchAndConnectAsync(ct);
@@ -340,7 +382,7 @@ public sealed class AudioProcessManager : IAsyncDisposable
         _proxy.PongReceived += () => _lastPongTimestamp = Stopwatch.GetTimestamp();
 
         // Handshake
-        var success = await _proxy.ConfigureAsync(_deviceId!, normalizationEnabled: true,
+        var success = await _proxy.ConfigureAsync(_deviceId!, normalizationEnabled: _normalizationEnabled,
             audioPreset: _audioPreset,
             initialVolumePercent: _initialVolumePercent,
             audioCacheDirectory: _audioCacheDirectory,
@@ -348,6 +390,12 @@ public sealed class AudioProcessManager : IAsyncDisposable
             parentProcessId: _launchContext.ParentProcessId,
             sessionId: _launchContext.SessionId,
             launchToken: _launchContext.LaunchToken,
+            cdnRequestTimeoutMs: _cdnRequestTimeout is { } cdnTimeout ? (int)cdnTimeout.TotalMilliseconds : null,
+            localAddress: _network?.LocalAddress?.ToString(),
+            addressFamily: _network is { AddressFamily: not AddressFamilyPreference.Any } network
+                ? network.AddressFamily.ToString()
+                : null,
+            decoderBackend: _decoderBackend == AudioDecoderBackend.Bass ? "bass" : null,
             ct: ct);
         if (!success)
         {
@@ -451,7 +499,7 @@ public sealed class AudioProcessManager : IAsyncDisposable
     }
 
     /// <summary>
-    /// Scans %LOCALAPPDATA%\Wavee\NativeDeps\ for any *.failure.json marker files produced by
+    /// Scans <see cref="WaveePaths.NativeDepsDirectory"/> for any *.failure.json marker files produced by
     /// Wavee.AudioHost/NativeDeps/NativeLibraryFailureMarker and returns a user-facing message
     /// describing the failure. The marker file is deleted after reading so a subsequent
     /// successful retry does not re-trigger this path. Returns null if no marker is present.
@@ -460,9 +508,7 @@ public sealed class AudioProcessManager : IAsyncDisposable
     {
         try
         {
-            var markerDir = Path.Combine(
-                Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-                "Wavee", "NativeDeps");
+            var markerDir = WaveePaths.Default.NativeDepsDirectory;
             if (!Directory.Exists(markerDir)) return null;
 
             var markers = Directory.GetFiles(markerDir, "*.failure.json");
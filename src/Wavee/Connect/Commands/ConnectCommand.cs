@@ -103,14 +103,7 @@ public abstract record ConnectCommand
                 SenderDeviceId = request.SenderDeviceId,
                 Key = request.Key
             },
-            "skip_prev" => new SkipPrevCommand
-            {
-                Endpoint = endpoint,
-                MessageIdent = request.MessageIdent,
-                MessageId = request.MessageId,
-                SenderDeviceId = request.SenderDeviceId,
-                Key = request.Key
-            },
+            "skip_prev" => SkipPrevCommand.FromJson(request, command),
             "set_shuffling_context" => ShuffleCommand.FromJson(request, command),
             "set_repeating_context" => RepeatContextCommand.FromJson(request, command),
             "set_repeating_track" => RepeatTrackCommand.FromJson(request, command),
@@ -142,4 +135,33 @@ public sealed record SkipNextCommand : ConnectCommand;
 /// <summary>
 /// Command to skip to previous track.
 /// </summary>
-public sealed record SkipPrevCommand : ConnectCommand;
+public sealed record SkipPrevCommand : ConnectCommand
+{
+    /// <summary>
+    /// When false the sender wants the previous track even if the current one is past
+    /// the restart threshold (Spotify's <c>options.allow_seeking</c>). Default true.
+    /// </summary>
+    public bool AllowSeeking { get; init; } = true;
+
+    internal static SkipPrevCommand FromJson(DealerRequest request, JsonElement json)
+    {
+        var allowSeeking = true;
+        if (json.TryGetProperty("options", out var options) &&
+            options.ValueKind == JsonValueKind.Object &&
+            options.TryGetProperty("allow_seeking", out var allow) &&
+            allow.ValueKind is JsonValueKind.True or JsonValueKind.False)
+        {
+            allowSeeking = allow.GetBoolean();
+        }
+
+        return new SkipPrevCommand
+        {
+            Endpoint = "skip_prev",
+            MessageIdent = request.MessageIdent,
+            MessageId = request.MessageId,
+            SenderDeviceId = request.SenderDeviceId,
+            Key = request.Key,
+            AllowSeeking = allowSeeking
+        };
+    }
+}
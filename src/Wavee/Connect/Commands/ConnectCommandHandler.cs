@@ -29,6 +29,8 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     private readonly ICommandSource _commandSource;
     private readonly ILogger? _logger;
     private readonly IRemoteStateRecorder? _remoteStateRecorder;
+    private readonly RemoteCommandLog? _commandLog;
+    private readonly IRemoteCommandPolicy? _policy;
 
     // Command observables - use SafeSubject for exception isolation
     private readonly SafeSubject<PlayCommand> _playCommands = new();
@@ -45,6 +47,7 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     private readonly SafeSubject<TransferCommand> _transferCommands = new();
     private readonly SafeSubject<UpdateContextCommand> _updateContextCommands = new();
     private readonly SafeSubject<SetOptionsCommand> _setOptionsCommands = new();
+    private readonly SafeSubject<ConnectCommand> _received = new();
 
     // Command dispatch infrastructure
     private readonly AsyncWorker<ConnectCommand> _commandWorker;
@@ -63,11 +66,15 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     /// </summary>
     /// <param name="dealerClient">The dealer client to receive commands from</param>
     /// <param name="logger">Optional logger for diagnostics</param>
+    /// <param name="commandLog">Optional history that records every command and its reply</param>
+    /// <param name="policy">Optional guardrail that may reject commands before dispatch</param>
     public ConnectCommandHandler(
         DealerClient dealerClient,
         ILogger? logger = null,
-        IRemoteStateRecorder? remoteStateRecorder = null)
-        : this(new DealerClientCommandSource(dealerClient), logger, remoteStateRecorder)
+        IRemoteStateRecorder? remoteStateRecorder = null,
+        RemoteCommandLog? commandLog = null,
+        IRemoteCommandPolicy? policy = null)
+        : this(new DealerClientCommandSource(dealerClient), logger, remoteStateRecorder, commandLog, policy)
     {
     }
 
@@ -77,14 +84,20 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     /// </summary>
     /// <param name="commandSource">The command source to receive requests from and send replies to</param>
     /// <param name="logger">Optional logger for diagnostics</param>
+    /// <param name="commandLog">Optional history that records every command and its reply</param>
+    /// <param name="policy">Optional guardrail that may reject commands before dispatch</param>
     public ConnectCommandHandler(
         ICommandSource commandSource,
         ILogger? logger = null,
-        IRemoteStateRecorder? remoteStateRecorder = null)
+        IRemoteStateRecorder? remoteStateRecorder = null,
+        RemoteCommandLog? commandLog = null,
+        IRemoteCommandPolicy? policy = null)
     {
         _commandSource = commandSource ?? throw new ArgumentNullException(nameof(commandSource));
         _logger = logger;
         _remoteStateRecorder = remoteStateRecorder;
+        _commandLog = commandLog;
+        _policy = policy;
 
         // Create worker for async command processing (bounded capacity for backpressure)
         _commandWorker = new AsyncWorker<ConnectCommand>(
@@ -155,6 +168,9 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     /// <summary>Set options command stream - combined shuffle/repeat options</summary>
     public IObservable<SetOptionsCommand> SetOptionsCommands => _setOptionsCommands;
 
+    /// <summary>Every accepted command, emitted before its typed stream - e.g. to wake from idle</summary>
+    public IObservable<ConnectCommand> Received => _received;
+
     // ================================================================
     // COMMAND PROCESSING PIPELINE
     // ================================================================
@@ -182,6 +198,8 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
                     notes: "failed to parse dealer command");
 
                 // Send error reply
+                _commandLog?.Record(request.MessageIdent.Split('/')[^1], request.SenderDeviceId, null,
+                    RequestResult.DeviceDoesNotSupportCommand);
                 _ = SendReplyAsync(request.Key, RequestResult.DeviceDoesNotSupportCommand);
                 return;
             }
@@ -201,7 +219,7 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
             {
                 _logger?.LogWarning("Command queue FULL, DROPPING command: endpoint={Endpoint}, key={Key} — will reply UpstreamError",
                     command.Endpoint, command.Key);
-                _ = SendReplyAsync(request.Key, RequestResult.UpstreamError);
+                _ = ReplyAsync(command, RequestResult.UpstreamError);
             }
         }
         catch (Exception ex)
@@ -241,7 +259,16 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
     {
         try
         {
+            if (_policy?.Evaluate(command) is { IsAllowed: false } decision)
+            {
+                _logger?.LogInformation("Rejected {Endpoint} from {Sender}: {Reason}",
+                    command.Endpoint, command.SenderDeviceId, decision.Reason);
+                await ReplyAsync(command, decision.Result, decision.Reason);
+                return;
+            }
+
             _logger?.LogDebug("Dispatching command: {Endpoint}", command.Endpoint);
+            _received.OnNext(command);
 
             // Dispatch to appropriate observable stream based on command type
             switch (command)
@@ -290,7 +317,7 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
                     break;
                 default:
                     _logger?.LogWarning("Unknown command type: {Type}", command.GetType().Name);
-                    await SendReplyAsync(command.Key, RequestResult.DeviceDoesNotSupportCommand);
+                    await ReplyAsync(command, RequestResult.DeviceDoesNotSupportCommand);
                     return;
             }
 
@@ -301,15 +328,21 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
             // via OnNext; any failure during actual execution surfaces through
             // putstate (errors on the playback engine) rather than the dealer
             // reply channel.
-            await SendReplyAsync(command.Key, RequestResult.Success);
+            await ReplyAsync(command, RequestResult.Success);
         }
         catch (Exception ex)
         {
             _logger?.LogError(ex, "Error dispatching command: {Endpoint}", command.Endpoint);
-            await SendReplyAsync(command.Key, RequestResult.UpstreamError);
+            await ReplyAsync(command, RequestResult.UpstreamError);
         }
     }
 
+    private Task ReplyAsync(ConnectCommand command, RequestResult result, string? rejectionReason = null)
+    {
+        _commandLog?.Record(command, result, rejectionReason);
+        return SendReplyAsync(command.Key, result);
+    }
+
     // ================================================================
     // REPLY MANAGEMENT - Send success/error replies back to Spotify
     // ================================================================
@@ -413,6 +446,7 @@ public sealed class ConnectCommandHandler : IAsyncDisposable
         _transferCommands.OnCompleted();
         _updateContextCommands.OnCompleted();
         _setOptionsCommands.OnCompleted();
+        _received.OnCompleted();
 
         // Cancel all pending replies
         lock (_replyLock)
@@ -0,0 +1,88 @@
+using Wavee.Connect.Protocol;
+
+namespace Wavee.Connect.Commands;
+
+/// <summary>
+/// Host-supplied guardrail deciding which remote Connect commands this device carries out,
+/// e.g. capping volume or refusing transfers during quiet hours.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Set via <see cref="Core.Session.SessionConfig.CommandPolicy"/>. Both methods default to
+/// allowing everything, so a policy overrides only what it cares about.
+/// </para>
+/// <para>
+/// Evaluation is synchronous and runs on the command dispatch path, inside the dealer's
+/// 10-second reply window: keep it fast and side-effect free. A rejected command is never
+/// dispatched; the sender gets the decision's <see cref="RemoteCommandDecision.Result"/>
+/// and the entry in <see cref="RemoteCommandLog"/> carries the reason. A rejected volume
+/// change is answered by re-announcing the current volume, so remote sliders snap back.
+/// </para>
+/// </remarks>
+public interface IRemoteCommandPolicy
+{
+    /// <summary>
+    /// Decides whether to carry out a command from another device.
+    /// </summary>
+    /// <param name="command">The parsed command.</param>
+    RemoteCommandDecision Evaluate(ConnectCommand command) => RemoteCommandDecision.Allow;
+
+    /// <summary>
+    /// Decides whether to apply a remote volume change.
+    /// </summary>
+    /// <param name="volume">Requested volume (0-65535).</param>
+    /// <param name="currentVolume">Volume in effect now (0-65535).</param>
+    RemoteCommandDecision EvaluateVolume(int volume, int currentVolume) => RemoteCommandDecision.Allow;
+}
+
+/// <summary>
+/// Outcome of an <see cref="IRemoteCommandPolicy"/> check.
+/// </summary>
+public sealed record RemoteCommandDecision
+{
+    private RemoteCommandDecision(bool isAllowed, RequestResult result, string? reason)
+    {
+        IsAllowed = isAllowed;
+        Result = result;
+        Reason = reason;
+    }
+
+    /// <summary>
+    /// Carry the command out.
+    /// </summary>
+    public static RemoteCommandDecision Allow { get; } = new(true, RequestResult.Success, null);
+
+    /// <summary>
+    /// Refuse the command.
+    /// </summary>
+    /// <param name="reason">Why, for logs and the command history.</param>
+    /// <param name="result">
+    /// Reply sent to the sender. Defaults to <see cref="RequestResult.DeviceDoesNotSupportCommand"/>,
+    /// which Spotify clients show as the device refusing the action.
+    /// </param>
+    public static RemoteCommandDecision Reject(
+        string reason,
+        RequestResult result = RequestResult.DeviceDoesNotSupportCommand)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(reason);
+        if (result == RequestResult.Success)
+            throw new ArgumentException("A rejection cannot reply with Success", nameof(result));
+
+        return new RemoteCommandDecision(false, result, reason);
+    }
+
+    /// <summary>
+    /// Gets whether the command should be carried out.
+    /// </summary>
+    public bool IsAllowed { get; }
+
+    /// <summary>
+    /// Gets the reply sent to the sender.
+    /// </summary>
+    public RequestResult Result { get; }
+
+    /// <summary>
+    /// Gets the rejection reason; null when allowed.
+    /// </summary>
+    public string? Reason { get; }
+}
@@ -42,6 +42,13 @@ public sealed record PlayCommand : ConnectCommand
     /// </summary>
     public IReadOnlyList<PageTrack>? PageTracks { get; init; }
 
+    /// <summary>
+    /// First context page the sender did not inline: a page carrying only
+    /// <c>page_url</c>, or the last inlined page's <c>next_page_url</c>.
+    /// Lets the queue page on demand without re-resolving the whole context.
+    /// </summary>
+    public string? NextPageUrl { get; init; }
+
     /// <summary>
     /// Play origin information.
     /// </summary>
@@ -94,6 +101,9 @@ public sealed record PlayCommand : ConnectCommand
         // 1. Parse context (context.uri and context.pages)
         string? contextUri = null;
         List<PageTrack>? pageTracks = null;
+        string? placeholderPageUrl = null;
+        string? lastNextPageUrl = null;
+        int? shuffleSeed = null;
 
         if (json.TryGetProperty("context", out var ctx))
         {
@@ -101,36 +111,53 @@ public sealed record PlayCommand : ConnectCommand
             if (ctx.TryGetProperty("uri", out var ctxUri))
                 contextUri = ctxUri.GetString();
 
+            if (ctx.TryGetProperty("metadata", out var ctxMeta)
+                && ctxMeta.ValueKind == JsonValueKind.Object
+                && ctxMeta.TryGetProperty(PlaybackStateHelpers.ShuffleSeedMetadataKey, out var seedEl)
+                && seedEl.ValueKind == JsonValueKind.String)
+            {
+                shuffleSeed = PlaybackStateHelpers.ParseShuffleSeed(seedEl.GetString());
+            }
+
             // Extract tracks from pages (for pre-loading or when no URI)
             if (ctx.TryGetProperty("pages", out var pages) && pages.GetArrayLength() > 0)
             {
                 pageTracks = new List<PageTrack>();
                 foreach (var page in pages.EnumerateArray())
                 {
-                    if (page.TryGetProperty("tracks", out var tracks))
+                    var hasTracks = page.TryGetProperty("tracks", out var tracks)
+                                    && tracks.ValueKind == JsonValueKind.Array
+                                    && tracks.GetArrayLength() > 0;
+
+                    // Unloaded pages arrive as bare page_url placeholders
+                    if (!hasTracks)
+                    {
+                        placeholderPageUrl ??= GetNonEmptyString(page, "page_url");
+                        continue;
+                    }
+                    lastNextPageUrl = GetNonEmptyString(page, "next_page_url");
+
+                    foreach (var track in tracks.EnumerateArray())
                     {
-                        foreach (var track in tracks.EnumerateArray())
+                        var uri = track.TryGetProperty("uri", out var u) ? u.GetString() : null;
+                        var uid = track.TryGetProperty("uid", out var id) ? id.GetString() : null;
+                        if (!string.IsNullOrEmpty(uri))
                         {
-                            var uri = track.TryGetProperty("uri", out var u) ? u.GetString() : null;
-                            var uid = track.TryGetProperty("uid", out var id) ? id.GetString() : null;
-                            if (!string.IsNullOrEmpty(uri))
+                            Dictionary<string, string>? trackMeta = null;
+                            if (track.TryGetProperty("metadata", out var meta)
+                                && meta.ValueKind == JsonValueKind.Object)
                             {
-                                Dictionary<string, string>? trackMeta = null;
-                                if (track.TryGetProperty("metadata", out var meta)
-                                    && meta.ValueKind == JsonValueKind.Object)
+                                trackMeta = new Dictionary<string, string>();
+                                foreach (var kv in meta.EnumerateObject())
                                 {
-                                    trackMeta = new Dictionary<string, string>();
-                                    foreach (var kv in meta.EnumerateObject())
-                                    {
-                                        if (kv.Value.ValueKind == JsonValueKind.String)
-                                            trackMeta[kv.Name] = kv.Value.GetString()!;
-                                    }
+                                    if (kv.Value.ValueKind == JsonValueKind.String)
+                                        trackMeta[kv.Name] = kv.Value.GetString()!;
                                 }
-                                pageTracks.Add(new PageTrack(uri!, uid ?? string.Empty)
-                                {
-                                    Metadata = trackMeta
-                                });
                             }
+                            pageTracks.Add(new PageTrack(uri!, uid ?? string.Empty)
+                            {
+                                Metadata = trackMeta
+                            });
                         }
                     }
                 }
@@ -212,15 +239,24 @@ public sealed record PlayCommand : ConnectCommand
             SkipToIndex = skipToIndex,
             PositionMs = posMs,
             PageTracks = pageTracks,
+            NextPageUrl = placeholderPageUrl ?? lastNextPageUrl,
             PlayOrigin = playOrigin,
             Options = new PlayerOptions
             {
                 ShufflingContext = shuffle,
                 RepeatingContext = repeatContext,
-                RepeatingTrack = repeatTrack
+                RepeatingTrack = repeatTrack,
+                ShuffleSeed = shuffleSeed
             }
         };
     }
+
+    private static string? GetNonEmptyString(JsonElement element, string property)
+        => element.TryGetProperty(property, out var value)
+           && value.ValueKind == JsonValueKind.String
+           && value.GetString() is { Length: > 0 } text
+            ? text
+            : null;
 }
 
 /// <summary>
@@ -280,4 +316,10 @@ public sealed record PlayerOptions
     /// Whether single track repeat is enabled.
     /// </summary>
     public bool RepeatingTrack { get; init; }
+
+    /// <summary>
+    /// Shuffle seed carried in <c>context.metadata["shuffle.seed"]</c>, so the
+    /// queue reproduces the sender's shuffle order.
+    /// </summary>
+    public int? ShuffleSeed { get; init; }
 }
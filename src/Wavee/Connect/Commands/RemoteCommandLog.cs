@@ -0,0 +1,151 @@
+using Wavee.Connect.Protocol;
+
+namespace Wavee.Connect.Commands;
+
+/// <summary>
+/// One remote command as received by this device.
+/// </summary>
+/// <param name="ReceivedAt">When the command was handled.</param>
+/// <param name="Endpoint">Command endpoint (<c>pause</c>, <c>seek_to</c>, <c>set_volume</c>, ...).</param>
+/// <param name="SenderDeviceId">Device that sent it; empty when the dealer did not say (volume messages).</param>
+/// <param name="SenderDeviceName">Display name of the sender from the Connect cluster, when known.</param>
+/// <param name="Username">Account the command arrived on.</param>
+/// <param name="Detail">Short argument summary, e.g. the play context or seek position.</param>
+/// <param name="Result">Reply sent to the dealer; null for messages that take no reply.</param>
+/// <param name="RejectionReason">Why <see cref="IRemoteCommandPolicy"/> refused the command; null when carried out.</param>
+public sealed record RemoteCommandEntry(
+    DateTimeOffset ReceivedAt,
+    string Endpoint,
+    string SenderDeviceId,
+    string? SenderDeviceName,
+    string? Username,
+    string? Detail,
+    RequestResult? Result,
+    string? RejectionReason = null);
+
+/// <summary>
+/// Bounded history of the Connect commands this device received, answering
+/// "what paused my music?".
+/// </summary>
+/// <remarks>
+/// Owned by the session, so it survives dealer reconnects. Once <see cref="Capacity"/>
+/// entries are held, each new entry evicts the oldest. Thread-safe.
+/// </remarks>
+public sealed class RemoteCommandLog
+{
+    /// <summary>
+    /// Default number of entries kept.
+    /// </summary>
+    public const int DefaultCapacity = 100;
+
+    private readonly Queue<RemoteCommandEntry> _entries;
+    private readonly SafeSubject<RemoteCommandEntry> _recorded = new();
+    private readonly Func<string, string?>? _resolveDeviceName;
+    private readonly Func<string?>? _resolveUsername;
+    private readonly TimeProvider _timeProvider;
+
+    /// <summary>
+    /// Creates an empty log.
+    /// </summary>
+    /// <param name="capacity">Maximum entries kept. Must be positive.</param>
+    /// <param name="resolveDeviceName">Maps a sender device id to its display name.</param>
+    /// <param name="resolveUsername">Returns the account the session is logged in as.</param>
+    /// <param name="timeProvider">Clock for timestamps; defaults to <see cref="TimeProvider.System"/>.</param>
+    public RemoteCommandLog(
+        int capacity = DefaultCapacity,
+        Func<string, string?>? resolveDeviceName = null,
+        Func<string?>? resolveUsername = null,
+        TimeProvider? timeProvider = null)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(capacity);
+
+        Capacity = capacity;
+        _entries = new Queue<RemoteCommandEntry>(capacity);
+        _resolveDeviceName = resolveDeviceName;
+        _resolveUsername = resolveUsername;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+    }
+
+    /// <summary>
+    /// Gets the maximum number of entries kept.
+    /// </summary>
+    public int Capacity { get; }
+
+    /// <summary>
+    /// Emits every entry as it is recorded.
+    /// </summary>
+    public IObservable<RemoteCommandEntry> Recorded => _recorded;
+
+    /// <summary>
+    /// Gets a snapshot of the history, oldest first.
+    /// </summary>
+    public IReadOnlyList<RemoteCommandEntry> GetEntries()
+    {
+        lock (_entries)
+        {
+            return _entries.ToArray();
+        }
+    }
+
+    /// <summary>
+    /// Removes every entry.
+    /// </summary>
+    public void Clear()
+    {
+        lock (_entries)
+        {
+            _entries.Clear();
+        }
+    }
+
+    /// <summary>
+    /// Records a parsed command and the reply it got.
+    /// </summary>
+    internal RemoteCommandEntry Record(ConnectCommand command, RequestResult? result, string? rejectionReason = null) =>
+        Record(command.Endpoint, command.SenderDeviceId, Describe(command), result, rejectionReason);
+
+    /// <summary>
+    /// Records a command by endpoint, for messages that never become a <see cref="ConnectCommand"/>.
+    /// </summary>
+    internal RemoteCommandEntry Record(
+        string endpoint,
+        string? senderDeviceId,
+        string? detail,
+        RequestResult? result,
+        string? rejectionReason = null)
+    {
+        senderDeviceId ??= string.Empty;
+        var entry = new RemoteCommandEntry(
+            _timeProvider.GetUtcNow(),
+            endpoint,
+            senderDeviceId,
+            senderDeviceId.Length > 0 ? _resolveDeviceName?.Invoke(senderDeviceId) : null,
+            _resolveUsername?.Invoke(),
+            detail,
+            result,
+            rejectionReason);
+
+        lock (_entries)
+        {
+            if (_entries.Count == Capacity)
+                _entries.Dequeue();
+            _entries.Enqueue(entry);
+        }
+
+        _recorded.OnNext(entry);
+        return entry;
+    }
+
+    private static string? Describe(ConnectCommand command) => command switch
+    {
+        PlayCommand play => play.TrackUri is { } track
+            ? $"{play.ContextUri} @ {track}"
+            : play.ContextUri,
+        SeekCommand seek => $"{seek.PositionMs}ms",
+        ShuffleCommand shuffle => shuffle.Enabled ? "on" : "off",
+        RepeatContextCommand repeat => repeat.Enabled ? "on" : "off",
+        RepeatTrackCommand repeat => repeat.Enabled ? "on" : "off",
+        AddToQueueCommand add => add.TrackUri,
+        _ => null
+    };
+}
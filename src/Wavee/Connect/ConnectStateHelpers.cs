@@ -49,33 +49,35 @@ public static class ConnectStateHelpers
     /// Creates a DeviceInfo with full capabilities for Spotify Connect.
     /// </summary>
     /// <param name="config">Session configuration containing device details.</param>
-    /// <param name="volume">Initial volume (0-65535).</param>
-    /// <param name="volumeSteps">Number of volume steps supported (default 64).</param>
+    /// <param name="volume">Initial volume (0-65535). Ignored for fixed-volume devices, which report full volume.</param>
+    /// <param name="volumeSteps">Number of volume steps supported; null uses <see cref="ConnectDeviceConfig.VolumeSteps"/>.</param>
     /// <returns>Configured DeviceInfo instance.</returns>
     public static DeviceInfo CreateDeviceInfo(
         SessionConfig config,
         int volume = MaxVolume / 2,
-        int volumeSteps = DefaultVolumeSteps,
+        int? volumeSteps = null,
         string? audioOutputDeviceName = null,
         AudioOutputDeviceType audioOutputDeviceType = AudioOutputDeviceType.UnknownAudioOutputDeviceType)
     {
         var localSpotifyPlaybackEnabled = config.LocalSpotifyPlaybackEnabled;
+        var device = config.ConnectDevice;
         var info = new DeviceInfo
         {
             CanPlay = localSpotifyPlaybackEnabled,
-            Volume = (uint)Math.Clamp(volume, 0, MaxVolume),
+            Volume = device.SupportsVolume ? (uint)Math.Clamp(volume, 0, MaxVolume) : MaxVolume,
             Name = config.DeviceName,
             DeviceId = config.DeviceId,
             DeviceType = MapDeviceType(config.DeviceType),
             DeviceSoftwareVersion = SpotifyClientIdentity.DeviceSoftwareVersion,
             ClientId = config.ClientId ?? KeymasterClientId,
             SpircVersion = SpotifyClientIdentity.SpircVersion,
-            Capabilities = CreateDefaultCapabilities(volumeSteps, localSpotifyPlaybackEnabled),
-            // Brand/model: parity with desktop's own DeviceInfo. These show up in
+            Capabilities = CreateDefaultCapabilities(
+                volumeSteps ?? device.VolumeSteps, localSpotifyPlaybackEnabled, device),
+            // Brand/model: parity with desktop's own DeviceInfo by default. These show up in
             // remote "Now Playing" device lists and feed device-fingerprint logic
             // server-side. Pre-2026-04 captures show desktop sends "spotify"/"PC laptop".
-            Brand = "spotify",
-            Model = "PC laptop",
+            Brand = device.Brand,
+            Model = device.Model,
             // license=premium is what tells the play-history pipeline that this
             // device is eligible to register plays. Without it, plays from the
             // device may be excluded from Recently Played.
@@ -119,19 +121,26 @@ public static class ConnectStateHelpers
     /// playback device, not a thin controller.
     /// </summary>
     /// <param name="volumeSteps">Number of volume steps (default 64).</param>
+    /// <param name="localSpotifyPlaybackEnabled">Whether this device can play Spotify audio itself.</param>
+    /// <param name="device">Host-configured capability flags; null uses <see cref="ConnectDeviceConfig"/> defaults.</param>
     /// <returns>Configured Capabilities instance.</returns>
     public static Capabilities CreateDefaultCapabilities(
         int volumeSteps = DefaultVolumeSteps,
-        bool localSpotifyPlaybackEnabled = true)
+        bool localSpotifyPlaybackEnabled = true,
+        ConnectDeviceConfig? device = null)
     {
+        device ??= new ConnectDeviceConfig();
         var capabilities = new Capabilities
         {
             CanBePlayer = localSpotifyPlaybackEnabled,
             GaiaEqConnectId = true,
-            SupportsLogout = true,
+            SupportsLogout = device.SupportsLogout,
             IsObservable = true,
             CommandAcks = true,
-            SupportsRename = false,
+            SupportsRename = device.SupportsRename,
+            Hidden = device.Hidden,
+            // Fixed-volume outputs: remotes hide the slider instead of sending no-op commands.
+            DisableVolume = !device.SupportsVolume,
             SupportsPlaylistV2 = true,
             IsControllable = localSpotifyPlaybackEnabled,
             SupportsExternalEpisodes = true,
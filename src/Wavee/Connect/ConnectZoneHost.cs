@@ -0,0 +1,314 @@
+using Microsoft.Extensions.Logging;
+using Wavee.Connect.Diagnostics;
+using Wavee.Core.Authentication;
+using Wavee.Core.Session;
+
+namespace Wavee.Connect;
+
+/// <summary>
+/// Hosts several Spotify Connect devices ("zones") for one account in a single process,
+/// each with its own name, device id, session and player.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Spotify identifies a Connect device by its AP login and dealer connection, so every
+/// zone runs its own <see cref="Session"/>. Zones share the HTTP client factory (and with
+/// it the connection pool), the base configuration and the account login: the first zone
+/// authenticates with the host's credentials, later zones with an access token from a
+/// zone that is already connected.
+/// </para>
+/// <para>
+/// Each zone can get a player from the factory passed to <see cref="AddZoneAsync"/>. The
+/// host wires it into the zone's Connect state and disposes it (and so its sink) when the
+/// zone is removed.
+/// </para>
+/// </remarks>
+public sealed class ConnectZoneHost : IAsyncDisposable
+{
+    private readonly SessionConfig _baseConfig;
+    private readonly IHttpClientFactory _httpClientFactory;
+    private readonly Credentials _credentials;
+    private readonly ICredentialsCache? _credentialsCache;
+    private readonly ILogger? _logger;
+    private readonly IRemoteStateRecorder? _remoteStateRecorder;
+    private readonly SemaphoreSlim _zonesLock = new(1, 1);
+    private readonly List<ConnectZone> _zones = new();
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a host for zones derived from <paramref name="baseConfig"/>.
+    /// </summary>
+    /// <param name="baseConfig">Settings shared by every zone; its device id seeds the zone ids.</param>
+    /// <param name="httpClientFactory">Factory shared by every zone's session.</param>
+    /// <param name="credentials">Login for the first zone.</param>
+    /// <param name="credentialsCache">Receives the reusable credentials from the first login.</param>
+    /// <param name="logger">Optional logger, shared by the zone sessions.</param>
+    /// <param name="remoteStateRecorder">Optional dealer/HTTP capture recorder.</param>
+    public ConnectZoneHost(
+        SessionConfig baseConfig,
+        IHttpClientFactory httpClientFactory,
+        Credentials credentials,
+        ICredentialsCache? credentialsCache = null,
+        ILogger? logger = null,
+        IRemoteStateRecorder? remoteStateRecorder = null)
+    {
+        _baseConfig = baseConfig ?? throw new ArgumentNullException(nameof(baseConfig));
+        _httpClientFactory = httpClientFactory ?? throw new ArgumentNullException(nameof(httpClientFactory));
+        _credentials = credentials ?? throw new ArgumentNullException(nameof(credentials));
+        _credentialsCache = credentialsCache;
+        _logger = logger;
+        _remoteStateRecorder = remoteStateRecorder;
+    }
+
+    /// <summary>
+    /// Gets a snapshot of the running zones, in the order they were added.
+    /// </summary>
+    public IReadOnlyList<ConnectZone> Zones
+    {
+        get
+        {
+            lock (_zones) return _zones.ToArray();
+        }
+    }
+
+    /// <summary>
+    /// Creates, connects and announces a new zone.
+    /// </summary>
+    /// <param name="options">Zone identity and overrides.</param>
+    /// <param name="playerFactory">
+    /// Builds the zone's player once its session is connected (e.g. a
+    /// <c>PlaybackOrchestrator</c> over its own audio sink). Null leaves the zone
+    /// controller-only. A player that implements <see cref="IAsyncDisposable"/> is
+    /// disposed with the zone.
+    /// </param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The connected zone.</returns>
+    /// <exception cref="InvalidOperationException">A zone with the same name or device id already exists.</exception>
+    public async Task<ConnectZone> AddZoneAsync(
+        ConnectZoneOptions options,
+        Func<ConnectZone, CancellationToken, Task<IPlaybackEngine?>>? playerFactory = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(options);
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        var config = CreateZoneConfig(_baseConfig, options);
+
+        await _zonesLock.WaitAsync(cancellationToken);
+        try
+        {
+            lock (_zones)
+            {
+                if (_zones.Any(z => z.DeviceId == config.DeviceId
+                                    || string.Equals(z.DeviceName, config.DeviceName, StringComparison.OrdinalIgnoreCase)))
+                {
+                    throw new InvalidOperationException($"Zone '{config.DeviceName}' ({config.DeviceId}) already exists");
+                }
+            }
+
+            var credentials = await GetZoneCredentialsAsync(cancellationToken);
+            var session = Session.Create(config, _httpClientFactory, _logger, _remoteStateRecorder);
+            var zone = new ConnectZone(session);
+            try
+            {
+                // Only the account login produces reusable credentials worth caching.
+                var cache = ReferenceEquals(credentials, _credentials) ? _credentialsCache : null;
+                await session.ConnectAsync(credentials, cache, cancellationToken);
+
+                if (playerFactory is not null
+                    && await playerFactory(zone, cancellationToken) is { } player)
+                {
+                    zone.Player = player;
+                    session.PlaybackState?.EnableBidirectionalMode(player, session.SpClient, session);
+                }
+            }
+            catch
+            {
+                await zone.DisposeAsync();
+                throw;
+            }
+
+            int count;
+            lock (_zones)
+            {
+                _zones.Add(zone);
+                count = _zones.Count;
+            }
+            _logger?.LogInformation("Connect zone added: {Name} ({DeviceId}), {Count} zone(s) running",
+                zone.DeviceName, zone.DeviceId, count);
+            return zone;
+        }
+        finally
+        {
+            _zonesLock.Release();
+        }
+    }
+
+    /// <summary>
+    /// Disconnects a zone and disposes its player and session.
+    /// </summary>
+    /// <param name="deviceId">Device id of the zone to remove.</param>
+    /// <param name="cancellationToken">Cancellation token for waiting on a zone being added.</param>
+    /// <returns>False when no zone has that id.</returns>
+    public async Task<bool> RemoveZoneAsync(string deviceId, CancellationToken cancellationToken = default)
+    {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
+        await _zonesLock.WaitAsync(cancellationToken);
+        try
+        {
+            ConnectZone? zone;
+            lock (_zones)
+            {
+                zone = _zones.FirstOrDefault(z => z.DeviceId == deviceId);
+                if (zone is null)
+                    return false;
+                _zones.Remove(zone);
+            }
+
+            await zone.DisposeAsync();
+            _logger?.LogInformation("Connect zone removed: {Name} ({DeviceId})", zone.DeviceName, zone.DeviceId);
+            return true;
+        }
+        finally
+        {
+            _zonesLock.Release();
+        }
+    }
+
+    /// <summary>
+    /// Builds a zone's session configuration: the base settings with the zone's identity,
+    /// and a device id derived from the base id and zone name unless one is given.
+    /// </summary>
+    internal static SessionConfig CreateZoneConfig(SessionConfig baseConfig, ConnectZoneOptions options)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(options.DeviceName);
+
+        return baseConfig with
+        {
+            DeviceId = options.DeviceId ?? DeviceIdGenerator.Derive(baseConfig.DeviceId, options.DeviceName),
+            DeviceName = options.DeviceName,
+            DeviceType = options.DeviceType ?? baseConfig.DeviceType,
+            InitialVolume = options.InitialVolume ?? baseConfig.InitialVolume,
+            ConnectDevice = options.ConnectDevice ?? baseConfig.ConnectDevice
+        };
+    }
+
+    private async Task<Credentials> GetZoneCredentialsAsync(CancellationToken cancellationToken)
+    {
+        ConnectZone? connected;
+        lock (_zones) connected = _zones.FirstOrDefault(z => z.Session.IsConnected());
+
+        if (connected is null)
+            return _credentials;
+
+        var token = await connected.Session.GetAccessTokenAsync(cancellationToken);
+        return Credentials.WithAccessToken(token.Token);
+    }
+
+    /// <summary>
+    /// Disposes every zone.
+    /// </summary>
+    public async ValueTask DisposeAsync()
+    {
+        if (_disposed)
+            return;
+        _disposed = true;
+
+        ConnectZone[] zones;
+        lock (_zones)
+        {
+            zones = _zones.ToArray();
+            _zones.Clear();
+        }
+
+        foreach (var zone in zones)
+        {
+            try
+            {
+                await zone.DisposeAsync();
+            }
+            catch (Exception ex)
+            {
+                _logger?.LogWarning(ex, "Failed to dispose Connect zone {Name}", zone.DeviceName);
+            }
+        }
+
+        _zonesLock.Dispose();
+    }
+}
+
+/// <summary>
+/// Identity and per-zone overrides for a <see cref="ConnectZoneHost"/> zone.
+/// Unset values come from the host's base configuration.
+/// </summary>
+public sealed record ConnectZoneOptions
+{
+    /// <summary>
+    /// Name shown in Connect device pickers. Must be unique within the host.
+    /// </summary>
+    public required string DeviceName { get; init; }
+
+    /// <summary>
+    /// Explicit device id. Null derives a stable id from the base id and <see cref="DeviceName"/>.
+    /// </summary>
+    public string? DeviceId { get; init; }
+
+    /// <summary>
+    /// Device type icon shown to remotes.
+    /// </summary>
+    public DeviceType? DeviceType { get; init; }
+
+    /// <summary>
+    /// Initial volume (0-65535).
+    /// </summary>
+    public int? InitialVolume { get; init; }
+
+    /// <summary>
+    /// Capability flags, e.g. fixed volume for a zone wired to a line-level DAC.
+    /// </summary>
+    public ConnectDeviceConfig? ConnectDevice { get; init; }
+}
+
+/// <summary>
+/// One logical Connect device of a <see cref="ConnectZoneHost"/>.
+/// </summary>
+public sealed class ConnectZone : IAsyncDisposable
+{
+    internal ConnectZone(Session session)
+    {
+        Session = session;
+    }
+
+    /// <summary>
+    /// Gets the zone's session.
+    /// </summary>
+    public Session Session { get; }
+
+    /// <summary>
+    /// Gets the zone's device id.
+    /// </summary>
+    public string DeviceId => Session.Config.DeviceId;
+
+    /// <summary>
+    /// Gets the zone's display name.
+    /// </summary>
+    public string DeviceName => Session.Config.DeviceName;
+
+    /// <summary>
+    /// Gets the zone's player, or null for a controller-only zone.
+    /// </summary>
+    public IPlaybackEngine? Player { get; internal set; }
+
+    /// <summary>
+    /// Disposes the player (releasing its sink) before the session.
+    /// </summary>
+    public async ValueTask DisposeAsync()
+    {
+        if (Player is IAsyncDisposable player)
+            await player.DisposeAsync();
+        Player = null;
+
+        await Session.DisposeAsync();
+    }
+}
@@ -3,6 +3,8 @@ using System.IO.Pipelines;
 using System.Net.WebSockets;
 using System.Text;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Connect.Connection;
 
@@ -13,6 +15,8 @@ namespace Wavee.Connect.Connection;
 internal sealed class DealerConnection : IDealerConnection
 {
     private readonly ILogger? _logger;
+    private readonly HttpMessageInvoker? _invoker;
+    private readonly int _maxMessageBytes;
     private Pipe _receivePipe;
     private ClientWebSocket? _webSocket;
     private CancellationTokenSource? _cts;
@@ -46,9 +50,25 @@ internal sealed class DealerConnection : IDealerConnection
         private set => _state = value;
     }
 
-    public DealerConnection(ILogger? logger = null)
+    /// <param name="logger">Optional logger.</param>
+    /// <param name="invoker">
+    /// Optional invoker the WebSocket upgrade is sent through, e.g. one bound to a specific
+    /// local interface. Null uses <see cref="ClientWebSocket"/>'s own handler.
+    /// </param>
+    /// <param name="maxMessageBytes">
+    /// Largest WebSocket message accepted. A bigger one closes the connection with
+    /// <see cref="WebSocketCloseStatus.MessageTooBig"/> before it is buffered in full.
+    /// </param>
+    public DealerConnection(
+        ILogger? logger = null,
+        HttpMessageInvoker? invoker = null,
+        int maxMessageBytes = ProtocolLimitsConfig.DefaultMaxMessageBytes)
     {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxMessageBytes);
+
         _logger = logger;
+        _invoker = invoker;
+        _maxMessageBytes = maxMessageBytes;
 
         // Configure pipe with backpressure thresholds
         _receivePipe = new Pipe(new PipeOptions(
@@ -94,10 +114,10 @@ internal sealed class DealerConnection : IDealerConnection
 
         try
         {
-            await _webSocket.ConnectAsync(new Uri(wsUrl), cancellationToken);
+            await _webSocket.ConnectAsync(new Uri(wsUrl), _invoker, cancellationToken);
             State = ConnectionState.Connected;
 
-            _logger?.LogDebug("WebSocket connected to {Url}", wsUrl);
+            _logger?.LogDebug("WebSocket connected to {Url}", LogRedaction.Url(wsUrl));
 
             // Start pipeline tasks
             _receiveTask = FillPipeAsync(_cts.Token);
@@ -202,6 +222,7 @@ internal sealed class DealerConnection : IDealerConnection
     private async Task FillPipeAsync(CancellationToken cancellationToken)
     {
         var writer = _receivePipe.Writer;
+        var messageBytes = 0L;
 
         try
         {
@@ -261,11 +282,24 @@ internal sealed class DealerConnection : IDealerConnection
                     break;
                 }
 
+                messageBytes += result.Count;
+                if (messageBytes > _maxMessageBytes)
+                {
+                    // Frames already handed to the pipe can't be taken back, so the
+                    // connection is dropped rather than skipping the rest of the message.
+                    _logger?.LogWarning("Dealer message exceeds {Limit} bytes (limits.maxMessageBytes), closing connection",
+                        _maxMessageBytes);
+                    await CloseTooBigAsync();
+                    Closed?.Invoke(this, WebSocketCloseStatus.MessageTooBig);
+                    break;
+                }
+
                 writer.Advance(result.Count);
 
                 // Flush when message is complete
                 if (result.EndOfMessage)
                 {
+                    messageBytes = 0;
                     var flushResult = await writer.FlushAsync(cancellationToken);
                     if (flushResult.IsCompleted)
                         break;
@@ -296,6 +330,18 @@ internal sealed class DealerConnection : IDealerConnection
         }
     }
 
+    private async Task CloseTooBigAsync()
+    {
+        try
+        {
+            await _webSocket!.CloseOutputAsync(WebSocketCloseStatus.MessageTooBig, "Message too big", CancellationToken.None);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogTrace(ex, "Error sending MessageTooBig close");
+        }
+    }
+
     /// <summary>
     /// Processes complete messages from the pipe (consumer).
     /// </summary>
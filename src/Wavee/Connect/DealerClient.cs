@@ -9,6 +9,7 @@ using Wavee.Connect.Diagnostics;
 using Microsoft.Extensions.Logging;
 using Wavee.Connect.Connection;
 using Wavee.Connect.Protocol;
+using Wavee.Core.Diagnostics;
 using Wavee.Core.Session;
 using Wavee.Core.Utilities;
 
@@ -47,6 +48,10 @@ public sealed class DealerClient : IAsyncDisposable
     private readonly ConcurrentDictionary<string, PendingRequest> _pendingRequests = new();
     private Timer? _requestTimeoutTimer;
 
+    // Per-topic message counters for diagnostics (bounded, see RecordTopic)
+    private readonly ConcurrentDictionary<string, long> _topicCounts = new(StringComparer.OrdinalIgnoreCase);
+    private const int MaxTrackedTopics = 64;
+
     // Queue for messages received before PlaybackStateManager subscribes
     private Channel<DealerMessage>? _pendingMessagesQueue;
     private bool _isReadyToProcess;
@@ -61,6 +66,20 @@ public sealed class DealerClient : IAsyncDisposable
 
     private CancellationTokenSource? _cts;
     private bool _disposed;
+    private volatile bool _suspended;
+    private volatile bool _powerSave;
+
+    /// <summary>
+    /// Number of dealer REQUESTs still awaiting a reply.
+    /// </summary>
+    public int PendingRequestCount => _pendingRequests.Count;
+
+    /// <summary>
+    /// Message counts per topic (URI truncated to its first three path segments) received
+    /// on this client. Used by session diagnostics to show which pushes are flowing.
+    /// </summary>
+    public IReadOnlyDictionary<string, long> GetTopicCounts()
+        => new SortedDictionary<string, long>(_topicCounts, StringComparer.OrdinalIgnoreCase);
 
     /// <summary>
     /// Observable stream of all dealer messages.
@@ -111,7 +130,7 @@ public sealed class DealerClient : IAsyncDisposable
     {
         _config = config ?? new DealerClientConfig();
         _logger = _config.Logger;
-        _connection = connection ?? new DealerConnection(_config.Logger);
+        _connection = connection ?? new DealerConnection(_config.Logger, _config.WebSocketInvoker, _config.Limits.MaxMessageBytes);
         _remoteStateRecorder = remoteStateRecorder;
 
         // Initialize SafeSubjects with logger for exception isolation
@@ -204,7 +223,11 @@ public sealed class DealerClient : IAsyncDisposable
                     var wsUrl = $"wss://{dealer}/?access_token={accessToken.Token}";
                     _logger?.LogDebug("Attempting connection to dealer: {Dealer}", dealer);
 
-                    await _connection.ConnectAsync(wsUrl, cancellationToken);
+                    using (var attemptCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken))
+                    {
+                        attemptCts.CancelAfter(_config.ConnectionTimeout);
+                        await _connection.ConnectAsync(wsUrl, attemptCts.Token);
+                    }
 
                     _connectionState.OnNext(Connection.ConnectionState.Connected);
                     _logger?.LogInformation("Connected to dealer: {Dealer}", dealer);
@@ -275,6 +298,9 @@ public sealed class DealerClient : IAsyncDisposable
 #pragma warning restore IL2026, IL3050
 
         await _connection.SendAsync(replyJson, cancellationToken);
+        _config.Bandwidth?.RecordSent(TrafficCategory.Control, Encoding.UTF8.GetByteCount(replyJson));
+        _config.TrafficRecorder?.RecordOutbound(replyJson);
+        _config.ProtocolTrace?.RecordDealerMessage(ProtocolTraceDirection.Outbound, Encoding.UTF8.GetBytes(replyJson));
 
         if (_remoteStateRecorder != null)
         {
@@ -427,6 +453,73 @@ public sealed class DealerClient : IAsyncDisposable
         _logger?.LogInformation("Disconnected from dealer");
     }
 
+    /// <summary>
+    /// Disconnects and keeps the dealer down until <see cref="ResumeAsync"/> is called:
+    /// closes, heartbeat timeouts and <see cref="ReconnectNow"/> no longer trigger a reconnect.
+    /// </summary>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    public async ValueTask SuspendAsync(CancellationToken cancellationToken = default)
+    {
+        _suspended = true;
+
+        if (_reconnectionManager != null)
+            await _reconnectionManager.CancelReconnectionAsync();
+
+        await StopHeartbeatAsync();
+        await DisconnectAsync(cancellationToken);
+    }
+
+    /// <summary>
+    /// Reconnects a dealer stopped by <see cref="SuspendAsync"/> using the session it was
+    /// last connected with.
+    /// </summary>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <exception cref="DealerException">Thrown if connection fails.</exception>
+    public async ValueTask ResumeAsync(CancellationToken cancellationToken = default)
+    {
+        _suspended = false;
+
+        if (_connectionState.Value == Connection.ConnectionState.Connected)
+            return;
+
+        if (_session == null || _httpClient == null)
+            throw new InvalidOperationException("Dealer was never connected; call ConnectAsync first");
+
+        await ConnectAsync(_session, _httpClient, cancellationToken);
+    }
+
+    /// <summary>
+    /// Switches the heartbeat between <see cref="DealerClientConfig.PingInterval"/> and
+    /// the slower <see cref="DealerClientConfig.IdlePingInterval"/>. The choice survives
+    /// reconnects.
+    /// </summary>
+    /// <param name="enabled">True to ping at the idle interval.</param>
+    public void SetPowerSave(bool enabled)
+    {
+        if (_powerSave == enabled)
+            return;
+
+        _powerSave = enabled;
+        _heartbeatManager?.SetPingInterval(CurrentPingInterval);
+        _logger?.LogDebug("Dealer power save {State}", enabled ? "on" : "off");
+    }
+
+    private TimeSpan CurrentPingInterval => _powerSave ? _config.IdlePingInterval : _config.PingInterval;
+
+    /// <summary>
+    /// Drops the current WebSocket and reconnects without waiting for the heartbeat
+    /// to notice it is dead, or cuts short the backoff of a reconnection already in
+    /// progress. Call when the network path changed (Wi-Fi roam, VPN up/down).
+    /// </summary>
+    public void ReconnectNow()
+    {
+        if (_disposed || _suspended || !_config.EnableAutoReconnect || _reconnectionManager == null)
+            return;
+
+        _logger?.LogInformation("Reconnecting dealer immediately (state={State})", _connectionState.Value);
+        _reconnectionManager.TriggerReconnection(immediate: true);
+    }
+
     /// <summary>
     /// Handles raw WebSocket messages from DealerConnection.
     /// Parses and routes to appropriate observable streams.
@@ -436,6 +529,10 @@ public sealed class DealerClient : IAsyncDisposable
         if (_disposed || _cts?.IsCancellationRequested == true)
             return;
 
+        _config.Bandwidth?.RecordReceived(TrafficCategory.Control, rawBytes.Length);
+        _config.TrafficRecorder?.RecordInbound(rawBytes.Span);
+        _config.ProtocolTrace?.RecordDealerMessage(ProtocolTraceDirection.Inbound, rawBytes.Span);
+
         try
         {
             // Parse message type
@@ -462,7 +559,7 @@ public sealed class DealerClient : IAsyncDisposable
                         System.Text.Encoding.UTF8.GetString(rawBytes.Span));
 
                     // Parse and dispatch via AsyncWorker (handles batched messages)
-                    var messages = Protocol.MessageParser.ParseMessages(rawBytes.Span, _logger);
+                    var messages = Protocol.MessageParser.ParseMessages(rawBytes.Span, _logger, _config.Limits);
                     if (messages.Count == 0)
                     {
                         _logger?.LogWarning("Failed to parse MESSAGE(s)");
@@ -482,6 +579,8 @@ public sealed class DealerClient : IAsyncDisposable
                                 message.Headers.Count > 0 ? string.Join(", ", message.Headers.Select(h => $"{h.Key}={h.Value}")) : "(none)");
                         }
 
+                        RecordTopic(message.Uri);
+
                         // Check for connection ID message
                         if (message.Uri.StartsWith("hm://pusher/v1/connections/", StringComparison.OrdinalIgnoreCase))
                         {
@@ -499,7 +598,7 @@ public sealed class DealerClient : IAsyncDisposable
 
                 case Protocol.MessageType.Request:
                     // Parse and dispatch via AsyncWorker
-                    if (Protocol.MessageParser.TryParseRequest(rawBytes.Span, out var request, _logger) && request != null)
+                    if (Protocol.MessageParser.TryParseRequest(rawBytes.Span, out var request, _logger, _config.Limits) && request != null)
                     {
                         _logger?.LogDebug("REQUEST received: ident={MessageIdent}, key={Key}, pendingCount={PendingCount}",
                             request.MessageIdent, request.Key, _pendingRequests.Count);
@@ -557,6 +656,9 @@ public sealed class DealerClient : IAsyncDisposable
     /// </summary>
     private ValueTask SendPongAsync()
     {
+        _config.Bandwidth?.RecordSent(TrafficCategory.Control, PongMessageBytes.Length);
+        _config.TrafficRecorder?.RecordOutbound(PongMessageBytes.Span);
+        _config.ProtocolTrace?.RecordDealerMessage(ProtocolTraceDirection.Outbound, PongMessageBytes.Span);
         return _connection.SendAsync(PongMessageBytes);
     }
 
@@ -607,7 +709,7 @@ public sealed class DealerClient : IAsyncDisposable
         _ = StopHeartbeatAsync();
 
         // Trigger reconnection if enabled
-        if (_config.EnableAutoReconnect && !_disposed)
+        if (_config.EnableAutoReconnect && !_disposed && !_suspended)
         {
             _reconnectionManager?.TriggerReconnection();
         }
@@ -629,7 +731,7 @@ public sealed class DealerClient : IAsyncDisposable
         _ = StopHeartbeatAsync();
 
         // Trigger reconnection if enabled
-        if (_config.EnableAutoReconnect && !_disposed)
+        if (_config.EnableAutoReconnect && !_disposed && !_suspended)
         {
             _reconnectionManager?.TriggerReconnection();
         }
@@ -641,10 +743,11 @@ public sealed class DealerClient : IAsyncDisposable
     private void InitializeHeartbeat()
     {
         _heartbeatManager = new HeartbeatManager(
-            _config.PingInterval,
+            CurrentPingInterval,
             _config.PongTimeout,
             SendPingAsync,
-            _logger);
+            _logger,
+            _config.TimeProvider);
 
         _heartbeatManager.HeartbeatTimeout += OnHeartbeatTimeout;
         _heartbeatManager.Start();
@@ -662,7 +765,8 @@ public sealed class DealerClient : IAsyncDisposable
             _config.MaxReconnectDelay,
             _config.MaxReconnectAttempts,
             ReconnectInternalAsync,
-            _logger);
+            _logger,
+            _config.TimeProvider);
 
         _reconnectionManager.ReconnectionSucceeded += OnReconnectionSucceeded;
         _reconnectionManager.ReconnectionFailed += OnReconnectionFailed;
@@ -692,7 +796,7 @@ public sealed class DealerClient : IAsyncDisposable
         }
 
         // Trigger reconnection
-        if (_config.EnableAutoReconnect && !_disposed)
+        if (_config.EnableAutoReconnect && !_disposed && !_suspended)
         {
             _reconnectionManager?.TriggerReconnection();
         }
@@ -773,6 +877,9 @@ public sealed class DealerClient : IAsyncDisposable
     {
         try
         {
+            _config.Bandwidth?.RecordSent(TrafficCategory.Control, PingMessageBytes.Length);
+            _config.TrafficRecorder?.RecordOutbound(PingMessageBytes.Span);
+            _config.ProtocolTrace?.RecordDealerMessage(ProtocolTraceDirection.Outbound, PingMessageBytes.Span);
             return _connection.SendAsync(PingMessageBytes);
         }
         catch (ObjectDisposedException)
@@ -789,6 +896,30 @@ public sealed class DealerClient : IAsyncDisposable
         }
     }
 
+    private void RecordTopic(string uri)
+    {
+        var topic = TopicOf(uri);
+        if (_topicCounts.Count >= MaxTrackedTopics && !_topicCounts.ContainsKey(topic))
+            return;
+        _topicCounts.AddOrUpdate(topic, 1, static (_, count) => count + 1);
+    }
+
+    private static string TopicOf(string uri)
+    {
+        var query = uri.IndexOf('?');
+        if (query >= 0) uri = uri[..query];
+
+        var schemeEnd = uri.IndexOf("://", StringComparison.Ordinal);
+        var start = schemeEnd >= 0 ? schemeEnd + 3 : 0;
+        var segments = 0;
+        for (var k = start; k < uri.Length; k++)
+        {
+            if (uri[k] == '/' && ++segments == 3)
+                return uri[..k];
+        }
+        return uri;
+    }
+
     /// <summary>
     /// Handles connection ID messages from hm://pusher/v1/connections/.
     /// Extracts and stores the Spotify-Connection-Id header.
@@ -1,4 +1,5 @@
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
 
 namespace Wavee.Connect;
 
@@ -13,7 +14,7 @@ public sealed class DealerClientConfig
     public ILogger? Logger { get; init; }
 
     /// <summary>
-    /// Timeout for connection attempts. Default is 30 seconds.
+    /// Timeout for the WebSocket connect to a single dealer endpoint. Default is 30 seconds.
     /// </summary>
     public TimeSpan ConnectionTimeout { get; init; } = TimeSpan.FromSeconds(30);
 
@@ -22,6 +23,33 @@ public sealed class DealerClientConfig
     /// </summary>
     public TimeSpan OperationTimeout { get; init; } = TimeSpan.FromSeconds(10);
 
+    /// <summary>
+    /// Invoker the WebSocket upgrade is sent through. Null uses the default handler.
+    /// Not owned by the client; the caller disposes it.
+    /// </summary>
+    public HttpMessageInvoker? WebSocketInvoker { get; init; }
+
+    /// <summary>
+    /// Size, count and depth limits for inbound dealer messages. Default is <see cref="ProtocolLimitsConfig"/>'s defaults.
+    /// </summary>
+    public ProtocolLimitsConfig Limits { get; init; } = new();
+
+    /// <summary>
+    /// Meter that dealer frames are counted on as <see cref="TrafficCategory.Control"/>. Null disables counting.
+    /// </summary>
+    public BandwidthMeter? Bandwidth { get; init; }
+
+    /// <summary>
+    /// Recorder that every dealer frame is written to, for replaying with
+    /// <see cref="Diagnostics.DealerReplayConnection"/>. Not owned by the client. Null disables recording.
+    /// </summary>
+    public Diagnostics.DealerTrafficRecorder? TrafficRecorder { get; init; }
+
+    /// <summary>
+    /// Protocol trace that dealer frames are written to. Not owned by the client. Null disables tracing.
+    /// </summary>
+    public Core.Diagnostics.ProtocolTraceWriter? ProtocolTrace { get; init; }
+
     /// <summary>
     /// Whether to automatically start the connection on client creation.
     /// Default is false.
@@ -33,6 +61,13 @@ public sealed class DealerClientConfig
     /// </summary>
     public TimeSpan PingInterval { get; init; } = TimeSpan.FromSeconds(30);
 
+    /// <summary>
+    /// Interval between PING messages while in power save (see
+    /// <see cref="DealerClient.SetPowerSave"/>). Default is 2 minutes, well inside the
+    /// dealer's idle cutoff so the socket stays open for incoming Connect commands.
+    /// </summary>
+    public TimeSpan IdlePingInterval { get; init; } = TimeSpan.FromMinutes(2);
+
     /// <summary>
     /// Maximum time to wait for PONG response before considering connection dead.
     /// Default is 3 seconds.
@@ -58,4 +93,9 @@ public sealed class DealerClientConfig
     /// Maximum number of reconnection attempts. Null means unlimited. Default is unlimited.
     /// </summary>
     public int? MaxReconnectAttempts { get; init; } = null;
+
+    /// <summary>
+    /// Clock for the heartbeat timer and reconnect backoff. Default is <see cref="System.TimeProvider.System"/>.
+    /// </summary>
+    public TimeProvider TimeProvider { get; init; } = TimeProvider.System;
 }
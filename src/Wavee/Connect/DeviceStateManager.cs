@@ -1,6 +1,7 @@
 using System.Reactive.Linq;
 using System.Reactive.Subjects;
 using Microsoft.Extensions.Logging;
+using Wavee.Connect.Commands;
 using Wavee.Connect.Diagnostics;
 using Wavee.Connect.Protocol;
 using Wavee.Core.Http;
@@ -23,6 +24,7 @@ public sealed class DeviceStateManager : IAsyncDisposable
     private readonly DealerClient _dealerClient;
     private readonly ILogger? _logger;
     private readonly IRemoteStateRecorder? _remoteStateRecorder;
+    private readonly RemoteCommandLog? _commandLog;
 
     // Subscriptions
     private readonly IDisposable _connectionIdSubscription;
@@ -64,22 +66,27 @@ public sealed class DeviceStateManager : IAsyncDisposable
     /// <param name="dealerClient">DealerClient for receiving connection ID and messages.</param>
     /// <param name="initialVolume">Initial volume (0-65535, default is half max).</param>
     /// <param name="logger">Optional logger for diagnostics.</param>
+    /// <param name="commandLog">Optional history that records remote volume commands.</param>
     public DeviceStateManager(
         ISession session,
         SpClient spClient,
         DealerClient dealerClient,
         int initialVolume = ConnectStateHelpers.MaxVolume / 2,
         ILogger? logger = null,
-        IRemoteStateRecorder? remoteStateRecorder = null)
+        IRemoteStateRecorder? remoteStateRecorder = null,
+        RemoteCommandLog? commandLog = null)
     {
         _session = session ?? throw new ArgumentNullException(nameof(session));
         _spClient = spClient ?? throw new ArgumentNullException(nameof(spClient));
         _dealerClient = dealerClient ?? throw new ArgumentNullException(nameof(dealerClient));
         _logger = logger;
         _remoteStateRecorder = remoteStateRecorder;
+        _commandLog = commandLog;
 
         // Initialize device info
-        _currentVolume = Math.Clamp(initialVolume, 0, ConnectStateHelpers.MaxVolume);
+        _currentVolume = session.Config.ConnectDevice.SupportsVolume
+            ? Math.Clamp(initialVolume, 0, ConnectStateHelpers.MaxVolume)
+            : ConnectStateHelpers.MaxVolume;
         _deviceInfo = ConnectStateHelpers.CreateDeviceInfo(
             session.Config,
             _currentVolume);
@@ -143,21 +150,18 @@ public sealed class DeviceStateManager : IAsyncDisposable
         _logger?.LogTrace("OnVolumeMessage called: uri={Uri}, payloadSize={Size}",
             message.Uri, message.Payload.Length);
 
-        try
+        if (!_session.Config.ConnectDevice.SupportsVolume)
         {
-            var payload = message.Payload;
-
-            // Check if payload is gzipped (same pattern as PlaybackStateHelpers)
-            if (message.Headers.TryGetValue("Transfer-Encoding", out var encoding) &&
-                encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase))
-            {
-                payload = PlaybackStateHelpers.DecompressGzip(payload);
-                _logger?.LogTrace("Decompressed gzip volume payload: {OriginalSize} -> {DecompressedSize}",
-                    message.Payload.Length, payload.Length);
-            }
+            // We advertise disable_volume; a stray command must not move the output.
+            _logger?.LogDebug("Ignoring remote volume command: device has fixed volume");
+            _commandLog?.Record("set_volume", null, "ignored: fixed volume", result: null);
+            return;
+        }
 
-            // Parse SetVolumeCommand protobuf
-            var volumeCommand = SetVolumeCommand.Parser.ParseFrom(payload);
+        try
+        {
+            // Parse SetVolumeCommand protobuf (MessageParser has already undone any compression)
+            var volumeCommand = SetVolumeCommand.Parser.ParseFrom(message.Payload);
             var newVolume = (int)volumeCommand.Volume;
 
             if (_remoteStateRecorder != null)
@@ -184,7 +188,21 @@ public sealed class DeviceStateManager : IAsyncDisposable
                     notes: notes);
             }
 
+            if (_session.Config.CommandPolicy?.EvaluateVolume(newVolume, _currentVolume) is { IsAllowed: false } decision)
+            {
+                _logger?.LogInformation("Rejected remote volume {Volume}: {Reason}", newVolume, decision.Reason);
+                _commandLog?.Record("set_volume", null,
+                    $"{ConnectStateHelpers.VolumeToPercentage(newVolume)}%", result: null, decision.Reason);
+
+                // Volume messages take no reply; re-announcing the unchanged volume is how
+                // remotes learn the change didn't stick (their slider snaps back).
+                await UpdateStateAsync(PutStateReason.VolumeChanged);
+                return;
+            }
+
             _logger?.LogInformation("Volume changed to {Volume}", newVolume);
+            _commandLog?.Record("set_volume", null,
+                $"{ConnectStateHelpers.VolumeToPercentage(newVolume)}%", result: null);
             _logger?.LogTrace("Updating device info volume: old={OldVolume}, new={NewVolume}",
                 _deviceInfo.Volume, newVolume);
 
@@ -264,7 +282,7 @@ public sealed class DeviceStateManager : IAsyncDisposable
                 },
                 PutStateReason = reason,
                 IsActive = _isActive,
-                ClientSideTimestamp = (ulong)DateTimeOffset.UtcNow.ToUnixTimeMilliseconds(),
+                ClientSideTimestamp = (ulong)_session.Clock.NowMs,
                 MessageId = _messageId++
             };
 
@@ -385,11 +403,15 @@ public sealed class DeviceStateManager : IAsyncDisposable
 
     /// <summary>
     /// Updates the device volume locally and sends state update.
+    /// No-op when <see cref="ConnectDeviceConfig.SupportsVolume"/> is false.
     /// </summary>
     /// <param name="volume">New volume (0-65535).</param>
     /// <param name="cancellationToken">Cancellation token.</param>
     public async Task SetVolumeAsync(int volume, CancellationToken cancellationToken = default)
     {
+        if (!_session.Config.ConnectDevice.SupportsVolume)
+            return;
+
         volume = Math.Clamp(volume, 0, ConnectStateHelpers.MaxVolume);
 
         if (_currentVolume == volume)
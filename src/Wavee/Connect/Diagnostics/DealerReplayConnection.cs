@@ -0,0 +1,130 @@
+using System.Net.WebSockets;
+using System.Text;
+using Wavee.Connect.Connection;
+
+namespace Wavee.Connect.Diagnostics;
+
+/// <summary>
+/// <see cref="IDealerConnection"/> that plays the inbound frames of a recorded dealer log back
+/// into a <see cref="DealerClient"/> and captures what the client sends, so a user-submitted
+/// log can reproduce a Connect command-handling bug deterministically.
+/// </summary>
+/// <remarks>
+/// Nothing is replayed until <see cref="ReplayAsync"/> is called; frames are delivered one at
+/// a time, in log order, each awaited before the next. Outbound entries in the log are not
+/// sent anywhere — compare them against <see cref="Sent"/> to spot behaviour changes.
+/// </remarks>
+public sealed class DealerReplayConnection : IDealerConnection
+{
+    private readonly IReadOnlyList<DealerTrafficEntry> _entries;
+    private readonly TimeProvider _timeProvider;
+    private readonly List<string> _sent = new();
+    private readonly object _sentLock = new();
+
+    /// <summary>
+    /// Creates a connection that replays <paramref name="entries"/>.
+    /// </summary>
+    /// <param name="entries">Log entries, typically from <see cref="DealerTrafficLog"/>.</param>
+    /// <param name="timeProvider">Clock used when replaying with recorded timing.</param>
+    public DealerReplayConnection(IEnumerable<DealerTrafficEntry> entries, TimeProvider? timeProvider = null)
+    {
+        ArgumentNullException.ThrowIfNull(entries);
+        _entries = entries.ToList();
+        _timeProvider = timeProvider ?? TimeProvider.System;
+    }
+
+    public event Func<ReadOnlyMemory<byte>, ValueTask>? MessageReceived;
+    public event EventHandler<WebSocketCloseStatus?>? Closed;
+
+    // A replay has no socket to fail.
+    public event EventHandler<Exception>? Error { add { } remove { } }
+
+    public ConnectionState State { get; private set; } = ConnectionState.Disconnected;
+
+    /// <summary>
+    /// Frames the client sent, in order.
+    /// </summary>
+    public IReadOnlyList<string> Sent
+    {
+        get
+        {
+            lock (_sentLock)
+                return _sent.ToList();
+        }
+    }
+
+    /// <summary>
+    /// Frames the client sent when the log was recorded, in order.
+    /// </summary>
+    public IReadOnlyList<string> RecordedOutbound => _entries
+        .Where(e => e.Direction == RemoteStateDirection.Outbound && e.Json is not null)
+        .Select(e => e.Json!)
+        .ToList();
+
+    /// <summary>
+    /// Delivers every inbound frame of the log to <see cref="MessageReceived"/>.
+    /// </summary>
+    /// <param name="preserveTiming">
+    /// Wait until each frame's recorded offset before delivering it. When false, frames are
+    /// delivered back to back.
+    /// </param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Number of frames delivered.</returns>
+    public async Task<int> ReplayAsync(bool preserveTiming = false, CancellationToken cancellationToken = default)
+    {
+        var started = _timeProvider.GetTimestamp();
+        var delivered = 0;
+
+        foreach (var entry in _entries)
+        {
+            if (entry.Direction != RemoteStateDirection.Inbound || entry.Json is null)
+                continue;
+
+            if (preserveTiming)
+            {
+                var wait = entry.Offset - _timeProvider.GetElapsedTime(started);
+                if (wait > TimeSpan.Zero)
+                    await Task.Delay(wait, _timeProvider, cancellationToken);
+            }
+
+            cancellationToken.ThrowIfCancellationRequested();
+            if (MessageReceived is { } handler)
+                await handler(Encoding.UTF8.GetBytes(entry.Json));
+            delivered++;
+        }
+
+        return delivered;
+    }
+
+    public ValueTask ConnectAsync(string wsUrl, CancellationToken cancellationToken = default)
+    {
+        State = ConnectionState.Connected;
+        return ValueTask.CompletedTask;
+    }
+
+    public ValueTask SendAsync(ReadOnlyMemory<byte> utf8Message, CancellationToken cancellationToken = default)
+        => SendAsync(Encoding.UTF8.GetString(utf8Message.Span), cancellationToken);
+
+    public ValueTask SendAsync(string message, CancellationToken cancellationToken = default)
+    {
+        lock (_sentLock)
+            _sent.Add(message);
+        return ValueTask.CompletedTask;
+    }
+
+    public ValueTask CloseAsync(CancellationToken cancellationToken = default)
+    {
+        if (State == ConnectionState.Connected)
+        {
+            State = ConnectionState.Disconnected;
+            Closed?.Invoke(this, WebSocketCloseStatus.NormalClosure);
+        }
+        return ValueTask.CompletedTask;
+    }
+
+    public ValueTask DisposeAsync()
+    {
+        State = ConnectionState.Disconnected;
+        return ValueTask.CompletedTask;
+    }
+}
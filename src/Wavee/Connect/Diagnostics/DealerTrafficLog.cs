@@ -0,0 +1,77 @@
+using System.Text.Json;
+
+namespace Wavee.Connect.Diagnostics;
+
+/// <summary>
+/// One dealer frame from a <see cref="DealerTrafficRecorder"/> log.
+/// </summary>
+/// <param name="Offset">Time since recording started.</param>
+/// <param name="Direction"><see cref="RemoteStateDirection.Inbound"/> or <see cref="RemoteStateDirection.Outbound"/>.</param>
+/// <param name="Json">The redacted frame, or null if the recorded frame was not valid JSON.</param>
+public sealed record DealerTrafficEntry(TimeSpan Offset, RemoteStateDirection Direction, string? Json);
+
+/// <summary>
+/// Reads logs written by <see cref="DealerTrafficRecorder"/>.
+/// </summary>
+public static class DealerTrafficLog
+{
+    /// <summary>
+    /// Reads every entry from <paramref name="reader"/>. Blank lines are skipped.
+    /// </summary>
+    /// <exception cref="FormatException">A line is not a recorder entry.</exception>
+    public static IReadOnlyList<DealerTrafficEntry> Read(TextReader reader)
+    {
+        ArgumentNullException.ThrowIfNull(reader);
+
+        var entries = new List<DealerTrafficEntry>();
+        var lineNumber = 0;
+        while (reader.ReadLine() is { } line)
+        {
+            lineNumber++;
+            if (string.IsNullOrWhiteSpace(line))
+                continue;
+            entries.Add(ParseLine(line, lineNumber));
+        }
+
+        return entries;
+    }
+
+    /// <summary>
+    /// Reads every entry from the file at <paramref name="path"/>.
+    /// </summary>
+    public static IReadOnlyList<DealerTrafficEntry> ReadFile(string path)
+    {
+        using var reader = File.OpenText(path);
+        return Read(reader);
+    }
+
+    /// <summary>
+    /// Reads every entry from log text.
+    /// </summary>
+    public static IReadOnlyList<DealerTrafficEntry> Parse(string log)
+    {
+        using var reader = new StringReader(log);
+        return Read(reader);
+    }
+
+    private static DealerTrafficEntry ParseLine(string line, int lineNumber)
+    {
+        try
+        {
+            using var doc = JsonDocument.Parse(line);
+            var root = doc.RootElement;
+
+            var offset = TimeSpan.FromMilliseconds(root.GetProperty("offsetMs").GetInt64());
+            var direction = Enum.Parse<RemoteStateDirection>(root.GetProperty("direction").GetString()!, ignoreCase: true);
+            if (direction == RemoteStateDirection.Internal)
+                throw new FormatException($"Line {lineNumber}: direction must be Inbound or Outbound");
+
+            var json = root.TryGetProperty("message", out var message) ? message.GetRawText() : null;
+            return new DealerTrafficEntry(offset, direction, json);
+        }
+        catch (Exception ex) when (ex is JsonException or KeyNotFoundException or InvalidOperationException or ArgumentException)
+        {
+            throw new FormatException($"Line {lineNumber} is not a dealer traffic entry", ex);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+using System.Buffers;
+using System.Text;
+using System.Text.Encodings.Web;
+using System.Text.Json;
+using Wavee.Core.Utilities;
+
+namespace Wavee.Connect.Diagnostics;
+
+/// <summary>
+/// Writes every dealer WebSocket frame, in both directions, to a JSON Lines log that
+/// <see cref="DealerTrafficLog"/> can read back and <see cref="DealerReplayConnection"/> can replay.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Each line is <c>{"offsetMs":…,"direction":"Inbound"|"Outbound","message":{…}}</c>, with the
+/// offset measured from when the recorder was created.
+/// </para>
+/// <para>
+/// Tokens, cookies and sensitive URL query parameters are redacted through
+/// <see cref="LogRedaction"/> before anything reaches the writer. Payloads are kept verbatim —
+/// they are what makes a log replayable — so they still name tracks, playlists and devices;
+/// users should read a log before attaching it to a bug report.
+/// </para>
+/// <para>
+/// Thread-safe. Frames that are not valid JSON are written as a placeholder with their size.
+/// </para>
+/// </remarks>
+public sealed class DealerTrafficRecorder : IDisposable
+{
+    private static readonly HashSet<string> SensitiveFields = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "access_token", "token", "client_token", "password", "credentials",
+        "Authorization", "client-token", "Cookie", "Set-Cookie", "Proxy-Authorization"
+    };
+
+    // Logs are meant to be read by people before they are shared; keep "<redacted>" legible.
+    private static readonly JsonWriterOptions WriterOptions = new()
+    {
+        Encoder = JavaScriptEncoder.UnsafeRelaxedJsonEscaping
+    };
+
+    private readonly object _lock = new();
+    private readonly TextWriter _writer;
+    private readonly bool _ownsWriter;
+    private readonly TimeProvider _timeProvider;
+    private readonly long _started;
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a recorder that writes to <paramref name="writer"/>.
+    /// </summary>
+    /// <param name="writer">Destination for the log lines.</param>
+    /// <param name="ownsWriter">Whether <see cref="Dispose"/> also disposes <paramref name="writer"/>.</param>
+    /// <param name="timeProvider">Clock for entry offsets. Default is the system clock.</param>
+    public DealerTrafficRecorder(TextWriter writer, bool ownsWriter = false, TimeProvider? timeProvider = null)
+    {
+        _writer = writer ?? throw new ArgumentNullException(nameof(writer));
+        _ownsWriter = ownsWriter;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _started = _timeProvider.GetTimestamp();
+    }
+
+    /// <summary>
+    /// Creates a recorder that appends to the file at <paramref name="path"/>.
+    /// </summary>
+    public static DealerTrafficRecorder CreateFile(string path, TimeProvider? timeProvider = null)
+    {
+        var stream = new FileStream(path, FileMode.Append, FileAccess.Write, FileShare.Read);
+        return new DealerTrafficRecorder(new StreamWriter(stream, new UTF8Encoding(false)), ownsWriter: true, timeProvider);
+    }
+
+    /// <summary>Records a frame received from the dealer.</summary>
+    public void RecordInbound(ReadOnlySpan<byte> utf8Message)
+        => Record(RemoteStateDirection.Inbound, utf8Message);
+
+    /// <summary>Records a frame sent to the dealer.</summary>
+    public void RecordOutbound(ReadOnlySpan<byte> utf8Message)
+        => Record(RemoteStateDirection.Outbound, utf8Message);
+
+    /// <summary>Records a frame sent to the dealer.</summary>
+    public void RecordOutbound(string message)
+        => Record(RemoteStateDirection.Outbound, Encoding.UTF8.GetBytes(message));
+
+    /// <summary>
+    /// Returns the JSON of <paramref name="utf8Message"/> with sensitive values redacted, or null
+    /// if it is not valid JSON.
+    /// </summary>
+    public static string? Redact(ReadOnlySpan<byte> utf8Message)
+    {
+        var buffer = new ArrayBufferWriter<byte>(utf8Message.Length);
+        using (var writer = new Utf8JsonWriter(buffer, WriterOptions))
+        {
+            try
+            {
+                var reader = new Utf8JsonReader(utf8Message);
+                CopyRedacted(ref reader, writer);
+            }
+            catch (JsonException)
+            {
+                return null;
+            }
+        }
+
+        return Encoding.UTF8.GetString(buffer.WrittenSpan);
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _disposed = true;
+
+            _writer.Flush();
+            if (_ownsWriter)
+                _writer.Dispose();
+        }
+    }
+
+    private void Record(RemoteStateDirection direction, ReadOnlySpan<byte> utf8Message)
+    {
+        var offset = _timeProvider.GetElapsedTime(_started);
+        var message = Redact(utf8Message);
+
+        var buffer = new ArrayBufferWriter<byte>(utf8Message.Length + 64);
+        using (var writer = new Utf8JsonWriter(buffer, WriterOptions))
+        {
+            writer.WriteStartObject();
+            writer.WriteNumber("offsetMs", (long)offset.TotalMilliseconds);
+            writer.WriteString("direction", direction.ToString());
+            if (message is not null)
+            {
+                writer.WritePropertyName("message");
+                writer.WriteRawValue(message, skipInputValidation: true);
+            }
+            else
+            {
+                writer.WriteString("invalid", LogRedaction.Bytes(utf8Message));
+            }
+            writer.WriteEndObject();
+        }
+
+        var line = Encoding.UTF8.GetString(buffer.WrittenSpan);
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _writer.WriteLine(line);
+            _writer.Flush();
+        }
+    }
+
+    private static void CopyRedacted(ref Utf8JsonReader reader, Utf8JsonWriter writer)
+    {
+        // Values are redacted by the name of the property that holds them; array elements
+        // inherit the name of the property holding the array.
+        var scopes = new Stack<string?>();
+        string? property = null;
+        while (reader.Read())
+        {
+            switch (reader.TokenType)
+            {
+                case JsonTokenType.StartObject:
+                    scopes.Push(property);
+                    writer.WriteStartObject();
+                    break;
+                case JsonTokenType.StartArray:
+                    scopes.Push(property);
+                    writer.WriteStartArray();
+                    break;
+                case JsonTokenType.EndObject:
+                    property = scopes.Pop();
+                    writer.WriteEndObject();
+                    break;
+                case JsonTokenType.EndArray:
+                    property = scopes.Pop();
+                    writer.WriteEndArray();
+                    break;
+                case JsonTokenType.PropertyName:
+                    property = reader.GetString();
+                    writer.WritePropertyName(property!);
+                    break;
+                case JsonTokenType.String:
+                    writer.WriteStringValue(RedactString(property, reader.GetString()!));
+                    break;
+                default:
+                    writer.WriteRawValue(reader.ValueSpan, skipInputValidation: true);
+                    break;
+            }
+        }
+    }
+
+    private static string RedactString(string? property, string value)
+    {
+        if (property is not null && SensitiveFields.Contains(property))
+            return LogRedaction.Secret(value);
+
+        if (value.StartsWith("https://", StringComparison.OrdinalIgnoreCase) ||
+            value.StartsWith("http://", StringComparison.OrdinalIgnoreCase) ||
+            value.StartsWith("wss://", StringComparison.OrdinalIgnoreCase))
+            return LogRedaction.Url(value);
+
+        return value;
+    }
+}
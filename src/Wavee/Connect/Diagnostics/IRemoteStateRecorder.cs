@@ -1,5 +1,6 @@
 using System;
 using System.Collections.Generic;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Connect.Diagnostics;
 
@@ -33,7 +34,7 @@ public static class RemoteStateRecorderExtensions
             CorrelationId = correlationId,
             ElapsedMs = elapsedMs,
             PayloadBytes = payloadBytes,
-            Headers = headers,
+            Headers = LogRedaction.Headers(headers),
             JsonBody = jsonBody,
             Notes = notes,
         });
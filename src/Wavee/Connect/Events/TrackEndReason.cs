@@ -0,0 +1,75 @@
+namespace Wavee.Connect.Events;
+
+/// <summary>
+/// Why a track stopped being the current track, as reported to local
+/// consumers (scrobblers, listening stats). Coarser than
+/// <see cref="PlaybackReason"/>, which mirrors Spotify's wire values.
+/// </summary>
+public enum TrackEndReason
+{
+    /// <summary>Played to the end ("finished").</summary>
+    Finished,
+
+    /// <summary>User skipped to the next track ("skipped_forward").</summary>
+    SkippedForward,
+
+    /// <summary>User went back to the previous track ("skipped_back").</summary>
+    SkippedBack,
+
+    /// <summary>Playback failed; see the accompanying error kind ("error").</summary>
+    Error,
+
+    /// <summary>Another Connect device took over playback ("remote_transfer").</summary>
+    RemoteTransfer,
+
+    /// <summary>The user signed out ("logout").</summary>
+    Logout,
+
+    /// <summary>Playback was stopped, or the player shut down ("stopped").</summary>
+    Stopped,
+
+    /// <summary>A new play request replaced the track ("replaced").</summary>
+    Replaced
+}
+
+/// <summary>
+/// Extension methods for TrackEndReason.
+/// </summary>
+public static class TrackEndReasonExtensions
+{
+    /// <summary>
+    /// Gets the snake_case value used in serialized events.
+    /// </summary>
+    public static string ToEventValue(this TrackEndReason reason)
+    {
+        return reason switch
+        {
+            TrackEndReason.Finished => "finished",
+            TrackEndReason.SkippedForward => "skipped_forward",
+            TrackEndReason.SkippedBack => "skipped_back",
+            TrackEndReason.Error => "error",
+            TrackEndReason.RemoteTransfer => "remote_transfer",
+            TrackEndReason.Logout => "logout",
+            TrackEndReason.Stopped => "stopped",
+            TrackEndReason.Replaced => "replaced",
+            _ => "unknown"
+        };
+    }
+
+    /// <summary>
+    /// Gets the reason_end reported to Spotify for a track that ended this way.
+    /// </summary>
+    public static PlaybackReason ToPlaybackReason(this TrackEndReason reason)
+    {
+        return reason switch
+        {
+            TrackEndReason.Finished => PlaybackReason.TrackDone,
+            TrackEndReason.SkippedForward => PlaybackReason.ForwardBtn,
+            TrackEndReason.SkippedBack => PlaybackReason.BackBtn,
+            TrackEndReason.Error => PlaybackReason.TrackError,
+            TrackEndReason.Logout => PlaybackReason.Logout,
+            TrackEndReason.Replaced => PlaybackReason.ClickRow,
+            _ => PlaybackReason.EndPlay
+        };
+    }
+}
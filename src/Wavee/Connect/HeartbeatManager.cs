@@ -10,15 +10,16 @@ namespace Wavee.Connect;
 internal sealed class HeartbeatManager : IAsyncDisposable
 {
     private readonly ILogger? _logger;
-    private readonly TimeSpan _pingInterval;
+    private TimeSpan _pingInterval;
     private readonly TimeSpan _pongTimeout;
     private readonly Func<ValueTask> _sendPingAsync;
+    private readonly TimeProvider _timeProvider;
 
     private PeriodicTimer? _pingTimer;
     private CancellationTokenSource? _cts;
     private Task? _heartbeatTask;
 
-    private DateTime _lastPongReceived;
+    private DateTimeOffset _lastPongReceived;
     private bool _waitingForPong;
     private readonly object _lock = new();
 
@@ -34,17 +35,20 @@ internal sealed class HeartbeatManager : IAsyncDisposable
     /// <param name="pongTimeout">Maximum time to wait for PONG response.</param>
     /// <param name="sendPingAsync">Callback to send PING message.</param>
     /// <param name="logger">Optional logger for diagnostic output.</param>
+    /// <param name="timeProvider">Clock for the ping timer and PONG timeout. Defaults to the system clock.</param>
     public HeartbeatManager(
         TimeSpan pingInterval,
         TimeSpan pongTimeout,
         Func<ValueTask> sendPingAsync,
-        ILogger? logger = null)
+        ILogger? logger = null,
+        TimeProvider? timeProvider = null)
     {
         _pingInterval = pingInterval;
         _pongTimeout = pongTimeout;
         _sendPingAsync = sendPingAsync ?? throw new ArgumentNullException(nameof(sendPingAsync));
         _logger = logger;
-        _lastPongReceived = DateTime.UtcNow;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _lastPongReceived = _timeProvider.GetUtcNow();
     }
 
     /// <summary>
@@ -56,13 +60,34 @@ internal sealed class HeartbeatManager : IAsyncDisposable
             throw new InvalidOperationException("Heartbeat already started");
 
         _cts = new CancellationTokenSource();
-        _pingTimer = new PeriodicTimer(_pingInterval);
+        _pingTimer = new PeriodicTimer(_pingInterval, _timeProvider);
         _heartbeatTask = RunHeartbeatLoopAsync(_cts.Token);
 
         _logger?.LogDebug("Heartbeat manager started (interval: {Interval}s, timeout: {Timeout}s)",
             _pingInterval.TotalSeconds, _pongTimeout.TotalSeconds);
     }
 
+    /// <summary>
+    /// Changes the interval between PING messages. Takes effect from the next tick.
+    /// </summary>
+    /// <param name="pingInterval">New interval; must be positive.</param>
+    public void SetPingInterval(TimeSpan pingInterval)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThanOrEqual(pingInterval, TimeSpan.Zero);
+
+        lock (_lock)
+        {
+            if (_pingInterval == pingInterval)
+                return;
+
+            _pingInterval = pingInterval;
+            if (_pingTimer != null)
+                _pingTimer.Period = pingInterval;
+        }
+
+        _logger?.LogDebug("Heartbeat interval changed to {Interval}s", pingInterval.TotalSeconds);
+    }
+
     /// <summary>
     /// Records that a PONG message was received.
     /// Should be called when the dealer client receives a PONG.
@@ -71,7 +96,7 @@ internal sealed class HeartbeatManager : IAsyncDisposable
     {
         lock (_lock)
         {
-            _lastPongReceived = DateTime.UtcNow;
+            _lastPongReceived = _timeProvider.GetUtcNow();
             _waitingForPong = false;
         }
 
@@ -126,7 +151,7 @@ internal sealed class HeartbeatManager : IAsyncDisposable
                 {
                     if (_waitingForPong)
                     {
-                        var elapsed = DateTime.UtcNow - _lastPongReceived;
+                        var elapsed = _timeProvider.GetUtcNow() - _lastPongReceived;
                         if (elapsed > _pongTimeout)
                         {
                             timedOut = true;
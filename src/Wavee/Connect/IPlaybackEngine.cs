@@ -1,6 +1,7 @@
 using System.Collections.Generic;
 using Wavee.Audio.Queue;
 using Wavee.Connect.Commands;
+using Wavee.Connect.Events;
 using Wavee.Playback.Contracts;
 
 namespace Wavee.Connect;
@@ -71,6 +72,16 @@ public interface IPlaybackEngine
     /// <returns>Task completing when stopped.</returns>
     Task StopAsync(CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Stops playback, recording why the current track ended.
+    /// Engines that don't report track ends ignore the reason.
+    /// </summary>
+    /// <param name="reason">Why the track ended (e.g. remote transfer, logout).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Task completing when stopped.</returns>
+    Task StopAsync(TrackEndReason reason, CancellationToken cancellationToken = default)
+        => StopAsync(cancellationToken);
+
     /// <summary>
     /// Resumes paused playback.
     /// </summary>
@@ -86,6 +97,15 @@ public interface IPlaybackEngine
     /// <returns>Task completing when seek completes.</returns>
     Task SeekAsync(long positionMs, CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Seeks to a time position. Implementations clamp to the track duration.
+    /// </summary>
+    /// <param name="position">Target position from the start of the track.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Task completing when seek completes.</returns>
+    Task SeekToAsync(TimeSpan position, CancellationToken cancellationToken = default)
+        => SeekAsync((long)position.TotalMilliseconds, cancellationToken);
+
     /// <summary>
     /// Skips to next track in queue.
     /// </summary>
@@ -332,6 +352,11 @@ public sealed record LocalPlaybackState
     /// </summary>
     public bool Shuffling { get; init; }
 
+    /// <summary>
+    /// Seed of the active shuffle order (null when not shuffling).
+    /// </summary>
+    public int? ShuffleSeed { get; init; }
+
     /// <summary>
     /// Whether repeat context (playlist/album) is enabled.
     /// </summary>
@@ -469,6 +494,12 @@ public sealed record LocalPlaybackState
     /// </summary>
     public IReadOnlyDictionary<string, string>? ExtraMetadata { get; init; }
 
+    /// <summary>
+    /// Running engine counters for the current track (bytes, cache, decode time,
+    /// underruns). Null when the engine doesn't report them.
+    /// </summary>
+    public EngineTrackCounters? TrackCounters { get; init; }
+
     /// <summary>
     /// Creates an empty/stopped playback state.
     /// </summary>
@@ -482,6 +513,19 @@ public sealed record LocalPlaybackState
     };
 }
 
+/// <summary>
+/// Running per-track counters reported by the audio engine.
+/// </summary>
+/// <param name="BytesDownloaded">Bytes fetched so far (head data + CDN); 0 for cache hits and local files.</param>
+/// <param name="FromCache">True when the track is served from the persistent audio cache.</param>
+/// <param name="DecodeMs">Time spent decoding, including waits on the stream.</param>
+/// <param name="Underruns">Output underruns since the track started.</param>
+public sealed record EngineTrackCounters(
+    long BytesDownloaded,
+    bool FromCache,
+    long DecodeMs,
+    long Underruns);
+
 /// <summary>
 /// Playback error information.
 /// </summary>
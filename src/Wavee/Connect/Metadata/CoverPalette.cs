@@ -0,0 +1,104 @@
+using System.Globalization;
+
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// A small color palette derived from a track's cover art, for theming a player UI.
+/// All colors are <c>#RRGGBB</c> hex strings.
+/// </summary>
+/// <param name="Dominant">The cover's dominant color, unadjusted.</param>
+/// <param name="Dark">A darkened variant readable behind white text.</param>
+/// <param name="Light">A lightened variant readable behind black text.</param>
+/// <param name="Vibrant">The dominant hue pushed to a saturated mid tone, for accents.</param>
+public sealed record CoverPalette(
+    string Dominant,
+    string Dark,
+    string Light,
+    string Vibrant)
+{
+    private const double VibrantSaturation = 0.7;
+    private const double MinVibrantLightness = 0.45;
+    private const double MaxVibrantLightness = 0.6;
+
+    /// <summary>
+    /// Derives the vibrant accent for <paramref name="dominantHex"/>: saturation raised to
+    /// at least 70% and lightness clamped to 45-60%. Near-greys are returned unchanged,
+    /// since boosting them would invent a hue the cover doesn't have.
+    /// </summary>
+    /// <param name="dominantHex">Color as <c>#RRGGBB</c> or <c>RRGGBB</c>.</param>
+    /// <returns>The accent as <c>#RRGGBB</c>, or null when <paramref name="dominantHex"/> isn't a valid color.</returns>
+    public static string? ToVibrant(string dominantHex)
+    {
+        if (!TryParseHex(dominantHex, out var r, out var g, out var b))
+            return null;
+
+        var (h, s, l) = ToHsl(r, g, b);
+        if (s >= 0.05)
+        {
+            s = Math.Max(s, VibrantSaturation);
+            l = Math.Clamp(l, MinVibrantLightness, MaxVibrantLightness);
+        }
+
+        var (vr, vg, vb) = FromHsl(h, s, l);
+        return $"#{vr:X2}{vg:X2}{vb:X2}";
+    }
+
+    private static bool TryParseHex(string hex, out byte r, out byte g, out byte b)
+    {
+        r = g = b = 0;
+        var span = hex.AsSpan().TrimStart('#');
+        if (span.Length != 6 || !int.TryParse(span, NumberStyles.HexNumber, CultureInfo.InvariantCulture, out var rgb))
+            return false;
+
+        r = (byte)(rgb >> 16);
+        g = (byte)(rgb >> 8);
+        b = (byte)rgb;
+        return true;
+    }
+
+    private static (double H, double S, double L) ToHsl(byte r, byte g, byte b)
+    {
+        double rd = r / 255.0, gd = g / 255.0, bd = b / 255.0;
+        var max = Math.Max(rd, Math.Max(gd, bd));
+        var min = Math.Min(rd, Math.Min(gd, bd));
+        var l = (max + min) / 2;
+        var d = max - min;
+        if (d == 0)
+            return (0, 0, l);
+
+        var s = l > 0.5 ? d / (2 - max - min) : d / (max + min);
+        double h;
+        if (max == rd)
+            h = (gd - bd) / d + (gd < bd ? 6 : 0);
+        else if (max == gd)
+            h = (bd - rd) / d + 2;
+        else
+            h = (rd - gd) / d + 4;
+
+        return (h / 6, s, l);
+    }
+
+    private static (byte R, byte G, byte B) FromHsl(double h, double s, double l)
+    {
+        if (s == 0)
+        {
+            var grey = (byte)Math.Round(l * 255);
+            return (grey, grey, grey);
+        }
+
+        var q = l < 0.5 ? l * (1 + s) : l + s - l * s;
+        var p = 2 * l - q;
+        return (Channel(p, q, h + 1.0 / 3), Channel(p, q, h), Channel(p, q, h - 1.0 / 3));
+
+        static byte Channel(double p, double q, double t)
+        {
+            if (t < 0) t += 1;
+            if (t > 1) t -= 1;
+            var v = t < 1.0 / 6 ? p + (q - p) * 6 * t
+                : t < 0.5 ? q
+                : t < 2.0 / 3 ? p + (q - p) * (2.0 / 3 - t) * 6
+                : p;
+            return (byte)Math.Round(v * 255);
+        }
+    }
+}
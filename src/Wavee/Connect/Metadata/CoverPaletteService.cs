@@ -0,0 +1,118 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Http;
+using Wavee.Core.Http.Pathfinder;
+
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// A track change paired with the palette of its cover art.
+/// </summary>
+/// <param name="State">The playback state from the track change.</param>
+/// <param name="Palette">Palette of the new track's cover.</param>
+public sealed record TrackPaletteChanged(PlaybackState State, CoverPalette Palette);
+
+/// <summary>
+/// Computes <see cref="CoverPalette"/>s for cover art, so frontends don't each need an
+/// image decoder and a color quantiser.
+/// </summary>
+/// <remarks>
+/// The cover is analysed through <see cref="IColorService"/> (Spotify's
+/// <c>fetchExtractedColors</c>, the same colors the official clients theme with, with its
+/// caching and batching), and the vibrant accent is derived locally from the dominant color.
+/// </remarks>
+public sealed class CoverPaletteService
+{
+    private const string ImageUriPrefix = "spotify:image:";
+    private const string ImageUrlPrefix = "https://i.scdn.co/image/";
+
+    private readonly IColorService _colors;
+    private readonly ILogger? _logger;
+
+    /// <summary>
+    /// Creates a service backed by <paramref name="colors"/>.
+    /// </summary>
+    /// <param name="colors">Color extraction service.</param>
+    /// <param name="logger">Optional logger for lookup failures.</param>
+    public CoverPaletteService(IColorService colors, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(colors);
+
+        _colors = colors;
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the palette of a cover image.
+    /// </summary>
+    /// <param name="imageUri">Image as <c>spotify:image:{id}</c> or <c>https://i.scdn.co/image/{id}</c>.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The palette, or null when the image couldn't be analysed.</returns>
+    public async Task<CoverPalette?> GetPaletteAsync(string imageUri, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(imageUri);
+
+        var url = imageUri.StartsWith(ImageUriPrefix, StringComparison.Ordinal)
+            ? ImageUrlPrefix + imageUri[ImageUriPrefix.Length..]
+            : imageUri;
+
+        try
+        {
+            return FromColor(await _colors.GetColorAsync(url, cancellationToken));
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Failed to extract cover colors for {Url}", url);
+            return null;
+        }
+    }
+    /// <summary>
+    /// Gets the palette of <paramref name="track"/>'s cover, preferring the largest image.
+    /// </summary>
+    /// <returns>The palette, or null when the track has no cover or it couldn't be analysed.</returns>
+    public Task<CoverPalette?> GetPaletteAsync(TrackInfo track, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(track);
+
+        var image = track.ImageLargeUrl ?? track.ImageUrl ?? track.ImageXLargeUrl ?? track.ImageSmallUrl;
+        return image is null
+            ? Task.FromResult<CoverPalette?>(null)
+            : GetPaletteAsync(image, cancellationToken);
+    }
+
+    /// <summary>
+    /// Pairs each track from <paramref name="trackChanges"/> (typically
+    /// <see cref="PlaybackStateManager.TrackChanged"/>) with its cover palette.
+    /// </summary>
+    /// <remarks>
+    /// Tracks whose cover can't be analysed are skipped. A new track cancels the lookup
+    /// still running for the previous one.
+    /// </remarks>
+    public IObservable<TrackPaletteChanged> WatchTrackChanges(IObservable<PlaybackState> trackChanges)
+    {
+        ArgumentNullException.ThrowIfNull(trackChanges);
+
+        return trackChanges
+            .Where(static s => s.Track is not null)
+            .Select(s => Observable.FromAsync(async ct =>
+                await GetPaletteAsync(s.Track!, ct) is { } palette
+                    ? new TrackPaletteChanged(s, palette)
+                    : null))
+            .Switch()
+            .Where(static p => p is not null)
+            .Select(static p => p!);
+    }
+
+    private static CoverPalette? FromColor(ExtractedColor? color)
+    {
+        var dominant = color?.RawHex ?? color?.DarkHex;
+        if (dominant is null)
+            return null;
+
+        return new CoverPalette(
+            dominant,
+            color!.DarkHex ?? dominant,
+            color.LightHex ?? dominant,
+            CoverPalette.ToVibrant(dominant) ?? dominant);
+    }
+}
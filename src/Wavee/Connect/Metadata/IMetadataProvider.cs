@@ -0,0 +1,28 @@
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// Supplies extra track metadata (genres, moods, BPM, key) from a source outside Spotify,
+/// merged into <see cref="TrackInfo.Metadata"/> by a <see cref="MetadataProviderChain"/>.
+/// </summary>
+/// <remarks>
+/// Implementations typically call an external web service. They should honour the
+/// cancellation token: the chain cancels a lookup when the track changes again or the
+/// per-provider timeout passes.
+/// </remarks>
+public interface IMetadataProvider
+{
+    /// <summary>
+    /// Short stable name, used in logs and as part of the cache key.
+    /// </summary>
+    string Name { get; }
+
+    /// <summary>
+    /// Looks up extra metadata for <paramref name="track"/>.
+    /// </summary>
+    /// <param name="track">The track, with the metadata merged by earlier providers in the chain.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Keys to add (e.g. <c>bpm</c>, <c>genre</c>), or null when the provider knows nothing about the track.</returns>
+    ValueTask<IReadOnlyDictionary<string, string>?> GetMetadataAsync(
+        TrackInfo track,
+        CancellationToken cancellationToken);
+}
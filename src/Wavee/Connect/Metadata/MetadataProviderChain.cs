@@ -0,0 +1,145 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage;
+
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// Runs a list of <see cref="IMetadataProvider"/>s over a track and merges what they
+/// return into its <see cref="TrackInfo.Metadata"/>.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Providers run in order and each sees the metadata merged so far, so a later provider
+/// can build on an earlier one (an ISRC lookup feeding a BPM service). Keys already
+/// present win: Spotify's own metadata is never overwritten, and an earlier provider
+/// takes precedence over a later one.
+/// </para>
+/// <para>
+/// Answers are cached per provider and track URI, so replays and repeat plays don't hit
+/// external services again. A provider that throws or exceeds the timeout is skipped for
+/// that track (and not cached, so the next play retries) without holding up the others.
+/// </para>
+/// </remarks>
+public sealed class MetadataProviderChain
+{
+    /// <summary>
+    /// Default time one provider may take per track.
+    /// </summary>
+    public static readonly TimeSpan DefaultProviderTimeout = TimeSpan.FromSeconds(5);
+
+    private static readonly IReadOnlyDictionary<string, string> Empty = new Dictionary<string, string>();
+
+    private readonly IReadOnlyList<IMetadataProvider> _providers;
+    private readonly HotCache<IReadOnlyDictionary<string, string>> _cache;
+    private readonly TimeSpan _providerTimeout;
+    private readonly ILogger? _logger;
+
+    /// <summary>
+    /// Creates a chain over <paramref name="providers"/>, in priority order.
+    /// </summary>
+    /// <param name="providers">Providers to run, highest priority first.</param>
+    /// <param name="cacheSize">Maximum cached (provider, track) answers.</param>
+    /// <param name="providerTimeout">Time one provider may take per track; defaults to <see cref="DefaultProviderTimeout"/>.</param>
+    /// <param name="logger">Optional logger for provider failures.</param>
+    public MetadataProviderChain(
+        IEnumerable<IMetadataProvider> providers,
+        int cacheSize = 1_000,
+        TimeSpan? providerTimeout = null,
+        ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(providers);
+
+        _providers = providers.ToArray();
+        _cache = new HotCache<IReadOnlyDictionary<string, string>>(cacheSize, logger);
+        _providerTimeout = providerTimeout ?? DefaultProviderTimeout;
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the providers, in the order they run.
+    /// </summary>
+    public IReadOnlyList<IMetadataProvider> Providers => _providers;
+
+    /// <summary>
+    /// Returns <paramref name="track"/> with every provider's metadata merged in.
+    /// </summary>
+    /// <param name="track">Track to enrich.</param>
+    /// <param name="cancellationToken">Cancels every outstanding lookup.</param>
+    /// <returns>A copy of the track; the same instance when no provider added anything.</returns>
+    public async Task<TrackInfo> EnrichAsync(TrackInfo track, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(track);
+
+        Dictionary<string, string>? merged = null;
+        var current = track;
+
+        foreach (var provider in _providers)
+        {
+            var extra = await GetAsync(provider, current, cancellationToken);
+            if (extra.Count == 0)
+                continue;
+
+            merged ??= new Dictionary<string, string>(track.Metadata);
+            var added = false;
+            foreach (var (key, value) in extra)
+                added |= merged.TryAdd(key, value);
+
+            if (added)
+                current = current with { Metadata = new Dictionary<string, string>(merged) };
+        }
+
+        return current;
+    }
+
+    /// <summary>
+    /// Enriches each track from <paramref name="trackChanges"/> (typically
+    /// <see cref="PlaybackStateManager.TrackChanged"/>), emitting the merged track once all
+    /// providers have answered.
+    /// </summary>
+    /// <remarks>
+    /// A new track cancels the lookups still running for the previous one, so a
+    /// fast-skipping listener never sees metadata for a track that is no longer playing.
+    /// </remarks>
+    public IObservable<TrackInfo> EnrichTrackChanges(IObservable<PlaybackState> trackChanges)
+    {
+        ArgumentNullException.ThrowIfNull(trackChanges);
+
+        return trackChanges
+            .Where(static s => s.Track is not null)
+            .Select(s => Observable.FromAsync(ct => EnrichAsync(s.Track!, ct)))
+            .Switch();
+    }
+
+    private async ValueTask<IReadOnlyDictionary<string, string>> GetAsync(
+        IMetadataProvider provider,
+        TrackInfo track,
+        CancellationToken cancellationToken)
+    {
+        var cacheKey = $"{provider.Name}|{track.Uri}";
+        if (_cache.Get(cacheKey) is { } cached)
+            return cached;
+
+        using var timeout = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+        timeout.CancelAfter(_providerTimeout);
+
+        IReadOnlyDictionary<string, string> result;
+        try
+        {
+            result = await provider.GetMetadataAsync(track, timeout.Token) ?? Empty;
+        }
+        catch (OperationCanceledException) when (!cancellationToken.IsCancellationRequested)
+        {
+            _logger?.LogWarning("Metadata provider {Provider} timed out for {Uri}", provider.Name, track.Uri);
+            return Empty;
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Metadata provider {Provider} failed for {Uri}", provider.Name, track.Uri);
+            return Empty;
+        }
+
+        _cache.Set(cacheKey, result);
+        return result;
+    }
+}
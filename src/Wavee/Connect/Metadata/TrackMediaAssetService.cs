@@ -0,0 +1,109 @@
+using System.Reactive.Linq;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Http;
+using Wavee.Core.Http.Pathfinder;
+using Wavee.Core.Storage;
+
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// Fetches canvas videos and related music videos for tracks through Pathfinder's
+/// <c>queryNpvArtist</c> query.
+/// </summary>
+/// <remarks>
+/// Results are cached per track URI, including "no assets", so replays don't query again.
+/// Failures are logged and not cached. Episodes and tracks without an artist URI have no
+/// assets and are answered without a request.
+/// </remarks>
+public sealed class TrackMediaAssetService
+{
+    private readonly IPathfinderClient _pathfinder;
+    private readonly HotCache<TrackMediaAssets> _cache;
+    private readonly ILogger? _logger;
+
+    /// <summary>
+    /// Creates a service backed by <paramref name="pathfinder"/>.
+    /// </summary>
+    /// <param name="pathfinder">Pathfinder client used for lookups.</param>
+    /// <param name="cacheSize">Maximum number of tracks kept in the cache.</param>
+    /// <param name="logger">Optional logger for lookup failures.</param>
+    public TrackMediaAssetService(IPathfinderClient pathfinder, int cacheSize = 500, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(pathfinder);
+
+        _pathfinder = pathfinder;
+        _cache = new HotCache<TrackMediaAssets>(cacheSize, logger);
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the media assets for <paramref name="track"/>.
+    /// </summary>
+    /// <param name="track">Track to look up.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The assets; null when the track can't have any or the lookup failed.</returns>
+    public async Task<TrackMediaAssets?> GetAssetsAsync(TrackInfo track, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(track);
+
+        if (!track.Uri.StartsWith("spotify:track:", StringComparison.Ordinal)
+            || string.IsNullOrEmpty(track.ArtistUri))
+            return null;
+
+        if (_cache.Get(track.Uri) is { } cached)
+            return cached;
+
+        TrackMediaAssets assets;
+        try
+        {
+            var response = await _pathfinder.GetNpvArtistAsync(
+                track.ArtistUri, track.Uri, ct: cancellationToken);
+            assets = FromResponse(track.Uri, response);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogWarning(ex, "Failed to fetch media assets for {Uri}", track.Uri);
+            return null;
+        }
+
+        _cache.Set(track.Uri, assets);
+        return assets;
+    }
+
+    /// <summary>
+    /// Looks up each track from <paramref name="trackChanges"/> (typically
+    /// <see cref="PlaybackStateManager.TrackChanged"/>), emitting its assets once they
+    /// are available.
+    /// </summary>
+    /// <remarks>
+    /// Only tracks that actually have a canvas or related videos are emitted. A new track
+    /// cancels the lookup still running for the previous one.
+    /// </remarks>
+    public IObservable<TrackMediaAssets> WatchTrackChanges(IObservable<PlaybackState> trackChanges)
+    {
+        ArgumentNullException.ThrowIfNull(trackChanges);
+
+        return trackChanges
+            .Where(static s => s.Track is not null)
+            .Select(s => Observable.FromAsync(ct => GetAssetsAsync(s.Track!, ct)))
+            .Switch()
+            .Where(static a => a is { HasAssets: true })
+            .Select(static a => a!);
+    }
+
+    private static TrackMediaAssets FromResponse(string trackUri, NpvArtistResponse response)
+    {
+        var union = response.Data?.TrackUnion;
+
+        var canvas = union?.Canvas is { Url: { Length: > 0 } url } c
+            ? new CanvasAsset(url, c.Type, c.FileId, c.Uri)
+            : null;
+
+        var videos = union?.RelatedVideos?.Items?
+            .Select(static v => v.TrackOfVideo?.Data?.Uri ?? v.Uri)
+            .OfType<string>()
+            .ToArray() ?? [];
+
+        return new TrackMediaAssets(trackUri, canvas, videos);
+    }
+}
@@ -0,0 +1,34 @@
+namespace Wavee.Connect.Metadata;
+
+/// <summary>
+/// Visual assets for a track beyond its cover art: the canvas loop shown behind the
+/// Now Playing view and any music videos linked to it.
+/// </summary>
+/// <param name="TrackUri">Track the assets belong to.</param>
+/// <param name="Canvas">The track's canvas; null when the artist hasn't uploaded one.</param>
+/// <param name="RelatedVideoUris">URIs of music videos for the track, in Spotify's order.</param>
+public sealed record TrackMediaAssets(
+    string TrackUri,
+    CanvasAsset? Canvas,
+    IReadOnlyList<string> RelatedVideoUris)
+{
+    /// <summary>
+    /// Gets whether the track has anything beyond cover art.
+    /// </summary>
+    public bool HasAssets => Canvas is not null || RelatedVideoUris.Count > 0;
+}
+
+/// <summary>
+/// A track canvas: a short looping video (or still image) hosted on Spotify's CDN.
+/// </summary>
+/// <param name="Url">Direct CDN URL of the asset.</param>
+/// <param name="Type">Spotify's asset type, e.g. <c>VIDEO_LOOPING</c> or <c>IMAGE</c>.</param>
+/// <param name="FileId">CDN file id, when given.</param>
+/// <param name="Uri">Canvas URI (<c>spotify:canvas:...</c>), when given.</param>
+public sealed record CanvasAsset(string Url, string? Type, string? FileId, string? Uri)
+{
+    /// <summary>
+    /// Gets whether the canvas is a video rather than a still image.
+    /// </summary>
+    public bool IsVideo => Type?.StartsWith("VIDEO", StringComparison.OrdinalIgnoreCase) == true;
+}
@@ -388,6 +388,12 @@ public sealed record PlaybackOptions
     /// Whether repeat track is enabled.
     /// </summary>
     public bool RepeatingTrack { get; init; }
+
+    /// <summary>
+    /// Seed of the shuffle order, when the publisher shared one via
+    /// <c>context_metadata["shuffle.seed"]</c>.
+    /// </summary>
+    public int? ShuffleSeed { get; init; }
 }
 
 /// <summary>
@@ -1,4 +1,3 @@
-using System.IO.Compression;
 using System.Globalization;
 using Google.Protobuf.Collections;
 using Microsoft.Extensions.Logging;
@@ -14,7 +13,7 @@ namespace Wavee.Connect;
 /// Helper methods for playback state conversion and cluster message processing.
 /// </summary>
 /// <remarks>
-/// WHY: Encapsulates protobuf → domain model conversion and gzip handling.
+/// WHY: Encapsulates protobuf → domain model conversion.
 /// Keeps PlaybackStateManager focused on state management and observables.
 ///
 /// PATTERN: Static helper class similar to ConnectStateHelpers.
@@ -27,9 +26,21 @@ public static class PlaybackStateHelpers
     // a publish-worthy seek.
     private const long PositionChangeThresholdMs = 2000;
 
+    /// <summary>
+    /// <c>context_metadata</c> key carrying the shuffle seed, so peers and
+    /// transfers reproduce the exact upcoming order Wavee will play.
+    /// </summary>
+    public const string ShuffleSeedMetadataKey = "shuffle.seed";
+
+    /// <summary>
+    /// Parses a <see cref="ShuffleSeedMetadataKey"/> value. Null if absent or malformed.
+    /// </summary>
+    public static int? ParseShuffleSeed(string? value)
+        => int.TryParse(value, NumberStyles.Integer, CultureInfo.InvariantCulture, out var seed) ? seed : null;
+
     /// <summary>
     /// Tries to parse a dealer message as a ClusterUpdate protobuf.
-    /// Handles gzip decompression if Transfer-Encoding header indicates compression.
+    /// The payload is already decompressed by <see cref="MessageParser"/>.
     /// </summary>
     /// <param name="message">Dealer message from DealerClient.Messages.</param>
     /// <param name="clusterUpdate">Parsed ClusterUpdate (if successful).</param>
@@ -40,17 +51,7 @@ public static class PlaybackStateHelpers
 
         try
         {
-            var payload = message.Payload;
-
-            // Check if payload is gzipped
-            if (message.Headers.TryGetValue("Transfer-Encoding", out var encoding) &&
-                encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase))
-            {
-                payload = DecompressGzip(payload);
-            }
-
-            // Parse protobuf
-            clusterUpdate = ClusterUpdate.Parser.ParseFrom(payload);
+            clusterUpdate = ClusterUpdate.Parser.ParseFrom(message.Payload);
             return true;
         }
         catch
@@ -61,7 +62,7 @@ public static class PlaybackStateHelpers
 
     /// <summary>
     /// Tries to parse a dealer message as a Cluster protobuf (used by PUT state responses).
-    /// Handles gzip decompression if Transfer-Encoding header indicates compression.
+    /// The payload is already decompressed by <see cref="MessageParser"/>.
     /// </summary>
     /// <param name="message">Dealer message from PUT state response.</param>
     /// <param name="cluster">Parsed Cluster (if successful).</param>
@@ -72,17 +73,8 @@ public static class PlaybackStateHelpers
 
         try
         {
-            var payload = message.Payload;
-
-            // Check if payload is gzipped
-            if (message.Headers.TryGetValue("Transfer-Encoding", out var encoding) &&
-                encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase))
-            {
-                payload = DecompressGzip(payload);
-            }
-
             // Parse protobuf as Cluster (not ClusterUpdate)
-            cluster = Cluster.Parser.ParseFrom(payload);
+            cluster = Cluster.Parser.ParseFrom(message.Payload);
             return true;
         }
         catch
@@ -91,21 +83,6 @@ public static class PlaybackStateHelpers
         }
     }
 
-    /// <summary>
-    /// Decompresses gzipped payload using GZipStream.
-    /// </summary>
-    /// <param name="gzippedData">Gzipped byte array.</param>
-    /// <returns>Decompressed byte array.</returns>
-    public static byte[] DecompressGzip(byte[] gzippedData)
-    {
-        using var inputStream = new MemoryStream(gzippedData);
-        using var gzipStream = new GZipStream(inputStream, CompressionMode.Decompress);
-        using var outputStream = new MemoryStream();
-
-        gzipStream.CopyTo(outputStream);
-        return outputStream.ToArray();
-    }
-
     private static bool IsSpotifyEpisodeUri(string? uri)
         => uri?.StartsWith("spotify:episode:", StringComparison.Ordinal) == true;
 
@@ -396,7 +373,11 @@ public static class PlaybackStateHelpers
                 {
                     Shuffling = ps.Options.ShufflingContext,
                     RepeatingContext = ps.Options.RepeatingContext,
-                    RepeatingTrack = ps.Options.RepeatingTrack
+                    RepeatingTrack = ps.Options.RepeatingTrack,
+                    ShuffleSeed = ps.Options.ShufflingContext
+                                  && ps.ContextMetadata.TryGetValue(ShuffleSeedMetadataKey, out var seed)
+                        ? ParseShuffleSeed(seed)
+                        : null
                 }
                 : prev.Options,
             // Always from cluster
@@ -596,7 +577,8 @@ public static class PlaybackStateHelpers
             {
                 Shuffling = localState.Shuffling,
                 RepeatingContext = localState.RepeatingContext,
-                RepeatingTrack = localState.RepeatingTrack
+                RepeatingTrack = localState.RepeatingTrack,
+                ShuffleSeed = localState.Shuffling ? localState.ShuffleSeed : null
             },
             ActiveDeviceId = !string.IsNullOrEmpty(localState.ActiveDeviceId)
                 ? localState.ActiveDeviceId
@@ -762,6 +744,11 @@ public static class PlaybackStateHelpers
         // "Now Playing" cards can show the context subtitle.
         playerState.ContextMetadata["player.arch"] = "2";
         playerState.ContextMetadata["mixer_enabled"] = state.MixerEnabled ? "true" : "false";
+        if (state.Options.Shuffling && state.Options.ShuffleSeed is { } shuffleSeed)
+        {
+            playerState.ContextMetadata[ShuffleSeedMetadataKey] =
+                shuffleSeed.ToString(CultureInfo.InvariantCulture);
+        }
         if (!string.IsNullOrEmpty(state.ContextDescription))
         {
             playerState.ContextMetadata["context_description"] = state.ContextDescription;
@@ -3,6 +3,7 @@ using System.Reactive.Subjects;
 using Google.Protobuf;
 using Microsoft.Extensions.Logging;
 using Wavee.Connect.Diagnostics;
+using Wavee.Connect.Events;
 using Wavee.Connect.Protocol;
 using Wavee.Core.Audio;
 using Wavee.Core.Http;
@@ -26,7 +27,7 @@ namespace Wavee.Connect;
 ///    - Works immediately without audio pipeline
 ///
 /// 2. **Bidirectional** (future): Reads cluster AND publishes local state
-///    - Use secondary constructor with IPlaybackEngine, SpClient, ISession
+///    - Use secondary constructor with IPlaybackEngine, ISpClient, ISession
 ///    - Requires audio pipeline implementation
 ///
 /// USAGE (Remote-only - works now):
@@ -59,7 +60,7 @@ public sealed class PlaybackStateManager : IAsyncDisposable
 {
     private readonly DealerClient _dealerClient;
     private IPlaybackEngine? _playbackEngine;  // Null in remote-only mode
-    private SpClient? _spClient;                // Null in remote-only mode
+    private ISpClient? _spClient;                // Null in remote-only mode
     private ISession? _session;                 // Null in remote-only mode
     private IExtendedMetadataClient? _metadataClient; // For enriching incomplete cluster metadata
     private CancellationTokenSource? _enrichCts;
@@ -194,14 +195,15 @@ public sealed class PlaybackStateManager : IAsyncDisposable
     /// </summary>
     /// <param name="userInitiated">True when the user explicitly pressed play
     /// (skips freshness and paused-state guards).</param>
-    public async Task ResumeAsync(bool userInitiated = false)
+    /// <param name="cancellationToken">Cancels the resume or ghost-track load.</param>
+    public async Task ResumeAsync(bool userInitiated = false, CancellationToken cancellationToken = default)
     {
         if (_playbackEngine == null) return;
 
         // Engine has a track loaded → normal resume
         if (!string.IsNullOrEmpty(_playbackEngine.CurrentState.TrackUri))
         {
-            await _playbackEngine.ResumeAsync();
+            await _playbackEngine.ResumeAsync(cancellationToken);
             return;
         }
 
@@ -260,7 +262,7 @@ public sealed class PlaybackStateManager : IAsyncDisposable
                 PositionMs = resumePosition > 0 ? resumePosition : null,
                 SkipToIndex = _currentState.CurrentIndex > 0 ? _currentState.CurrentIndex : null,
             };
-            await _playbackEngine.PlayAsync(playCommand);
+            await _playbackEngine.PlayAsync(playCommand, cancellationToken);
         }
     }
 
@@ -311,7 +313,7 @@ public sealed class PlaybackStateManager : IAsyncDisposable
     public PlaybackStateManager(
         DealerClient dealerClient,
         IPlaybackEngine playbackEngine,
-        SpClient spClient,
+        ISpClient spClient,
         ISession session,
         ILogger? logger = null,
         IRemoteStateRecorder? remoteStateRecorder = null)
@@ -363,7 +365,7 @@ public sealed class PlaybackStateManager : IAsyncDisposable
     /// </param>
     public void EnableBidirectionalMode(
         IPlaybackEngine playbackEngine,
-        SpClient spClient,
+        ISpClient spClient,
         ISession session,
         bool suppressClusterUpdates = false)
     {
@@ -552,7 +554,7 @@ public sealed class PlaybackStateManager : IAsyncDisposable
                 _playbackStartedAt = 0;
 
                 // Stop local playback — observe the task so exceptions are not silently swallowed
-                _ = _playbackEngine!.StopAsync().ContinueWith(t =>
+                _ = _playbackEngine!.StopAsync(TrackEndReason.RemoteTransfer).ContinueWith(t =>
                 {
                     if (t.IsFaulted)
                         _logger?.LogError(t.Exception?.InnerException, "Failed to stop local playback after device takeover");
@@ -1,15 +1,69 @@
+using System.Diagnostics.CodeAnalysis;
 using System.Text.Json;
+using System.Text.Json.Serialization.Metadata;
+using Google.Protobuf;
 
 namespace Wavee.Connect.Protocol;
 
 /// <summary>
 /// Dealer message (MESSAGE type).
 /// </summary>
+/// <remarks>
+/// <see cref="Payload"/> is already reassembled from all payload parts and decompressed,
+/// so it can be handed straight to a protobuf or JSON parser.
+/// </remarks>
 public sealed record DealerMessage
 {
     public required string Uri { get; init; }
     public required IReadOnlyDictionary<string, string> Headers { get; init; }
     public required byte[] Payload { get; init; }
+
+    /// <summary>
+    /// Decodes <see cref="Payload"/> as protobuf message <typeparamref name="T"/>.
+    /// </summary>
+    /// <param name="parser">Generated parser, e.g. <c>ClusterUpdate.Parser</c>.</param>
+    /// <param name="value">The decoded message (if successful).</param>
+    /// <returns>False if the payload is empty or not a valid <typeparamref name="T"/>.</returns>
+    public bool TryParsePayload<T>(MessageParser<T> parser, [NotNullWhen(true)] out T? value)
+        where T : IMessage<T>
+    {
+        value = default;
+        if (Payload.Length == 0)
+            return false;
+
+        try
+        {
+            value = parser.ParseFrom(Payload);
+            return true;
+        }
+        catch (InvalidProtocolBufferException)
+        {
+            return false;
+        }
+    }
+
+    /// <summary>
+    /// Decodes <see cref="Payload"/> as JSON using source-generated metadata (AOT-safe).
+    /// </summary>
+    /// <param name="typeInfo">Type info from a <see cref="System.Text.Json.Serialization.JsonSerializerContext"/>.</param>
+    /// <param name="value">The decoded value (if successful).</param>
+    /// <returns>False if the payload is empty or not valid JSON for <typeparamref name="T"/>.</returns>
+    public bool TryParseJsonPayload<T>(JsonTypeInfo<T> typeInfo, [NotNullWhen(true)] out T? value)
+    {
+        value = default;
+        if (Payload.Length == 0)
+            return false;
+
+        try
+        {
+            value = JsonSerializer.Deserialize(Payload, typeInfo);
+            return value is not null;
+        }
+        catch (JsonException)
+        {
+            return false;
+        }
+    }
 }
 
 /// <summary>
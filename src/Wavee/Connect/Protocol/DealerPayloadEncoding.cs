@@ -0,0 +1,76 @@
+using System.IO.Compression;
+
+namespace Wavee.Connect.Protocol;
+
+/// <summary>
+/// Decodes dealer payloads compressed per their <c>Transfer-Encoding</c> header.
+/// </summary>
+/// <remarks>
+/// Connect state pushes arrive gzip-compressed (we advertise <c>supports_gzip_pushes</c>);
+/// <c>deflate</c> is accepted as either zlib-wrapped or raw, since servers disagree on
+/// which one the token means.
+/// </remarks>
+internal static class DealerPayloadEncoding
+{
+    /// <summary>
+    /// Header carrying the payload encoding.
+    /// </summary>
+    public const string HeaderName = "Transfer-Encoding";
+
+    /// <summary>
+    /// Whether <paramref name="encoding"/> is a compression this class can undo.
+    /// </summary>
+    public static bool IsCompressed(string? encoding)
+        => encoding is not null
+           && (encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase)
+               || encoding.Equals("deflate", StringComparison.OrdinalIgnoreCase));
+
+    /// <summary>
+    /// Decompresses <paramref name="payload"/>.
+    /// </summary>
+    /// <param name="payload">Compressed bytes.</param>
+    /// <param name="encoding">Value of the <see cref="HeaderName"/> header.</param>
+    /// <param name="maxBytes">Largest decompressed size accepted; stops decompression bombs early.</param>
+    /// <exception cref="NotSupportedException">Unknown encoding.</exception>
+    /// <exception cref="InvalidDataException">
+    /// Payload is not valid for the encoding, or decompresses to more than <paramref name="maxBytes"/>.
+    /// </exception>
+    public static byte[] Decode(byte[] payload, string encoding, int maxBytes = int.MaxValue)
+    {
+        using var input = new MemoryStream(payload);
+        using var decoder = OpenDecoder(input, payload, encoding);
+        using var output = new MemoryStream((int)Math.Min((long)payload.Length * 4, maxBytes));
+
+        var buffer = new byte[16 * 1024];
+        int read;
+        while ((read = decoder.Read(buffer)) > 0)
+        {
+            if (output.Length + read > maxBytes)
+                throw new InvalidDataException($"Decompressed payload exceeds {maxBytes} bytes");
+            output.Write(buffer, 0, read);
+        }
+
+        return output.ToArray();
+    }
+
+    private static Stream OpenDecoder(Stream input, byte[] payload, string encoding)
+    {
+        if (encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase))
+            return new GZipStream(input, CompressionMode.Decompress);
+
+        if (encoding.Equals("deflate", StringComparison.OrdinalIgnoreCase))
+        {
+            return HasZlibHeader(payload)
+                ? new ZLibStream(input, CompressionMode.Decompress)
+                : new DeflateStream(input, CompressionMode.Decompress);
+        }
+
+        throw new NotSupportedException($"Unsupported dealer payload encoding '{encoding}'");
+    }
+
+    // RFC 1950: CM=8 in the low nibble of CMF, and CMF*256+FLG divisible by 31
+    private static bool HasZlibHeader(byte[] payload)
+        => payload.Length >= 2
+           && (payload[0] & 0x0F) == 8
+           && ((payload[0] << 8) | payload[1]) % 31 == 0;
+}
@@ -1,9 +1,9 @@
 using System.Buffers;
 using System.Buffers.Text;
-using System.IO.Compression;
 using System.Text;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
 
 namespace Wavee.Connect.Protocol;
 
@@ -33,6 +33,8 @@ internal static class MessageParser
     private static readonly IReadOnlyDictionary<string, string> EmptyHeaders =
         new Dictionary<string, string>(0);
 
+    private static readonly ProtocolLimitsConfig DefaultLimits = new();
+
     /// <summary>
     /// Parses the message type from raw JSON bytes.
     /// </summary>
@@ -83,10 +85,17 @@ internal static class MessageParser
     /// </summary>
     /// <param name="utf8Json">Raw UTF-8 JSON bytes.</param>
     /// <param name="message">Parsed message (if successful).</param>
-    /// <returns>True if parsing succeeded.</returns>
-    public static bool TryParseMessage(ReadOnlySpan<byte> utf8Json, out DealerMessage? message, ILogger? logger = null)
+    /// <param name="logger">Optional logger for diagnostics.</param>
+    /// <param name="limits">Header, part, size and depth limits. Null uses the defaults.</param>
+    /// <returns>True if parsing succeeded; false for malformed messages and messages over a limit.</returns>
+    public static bool TryParseMessage(
+        ReadOnlySpan<byte> utf8Json,
+        out DealerMessage? message,
+        ILogger? logger = null,
+        ProtocolLimitsConfig? limits = null)
     {
         message = null;
+        limits ??= DefaultLimits;
 
         try
         {
@@ -94,7 +103,7 @@ internal static class MessageParser
             // NOTE: Utf8JsonReader in .NET has no MaxTokenSize option - it uses internal limits
             var options = new JsonReaderOptions
             {
-                MaxDepth = 64,
+                MaxDepth = limits.MaxJsonDepth,
                 CommentHandling = JsonCommentHandling.Skip
             };
             var reader = new Utf8JsonReader(utf8Json, options);
@@ -115,12 +124,12 @@ internal static class MessageParser
                     else if (reader.ValueTextEquals(HeadersPropertyName))
                     {
                         reader.Read();
-                        headers = ParseHeaders(ref reader);
+                        headers = ParseHeaders(ref reader, limits.MaxHeaders);
                     }
                     else if (reader.ValueTextEquals(PayloadsPropertyName))
                     {
                         reader.Read();
-                        payload = ParsePayloads(ref reader);
+                        payload = ParsePayloads(ref reader, limits);
                     }
                 }
             }
@@ -140,6 +149,29 @@ internal static class MessageParser
                 return false;
             }
 
+            // Undo Transfer-Encoding here so subscribers always see the plain protobuf.
+            // The header is dropped with it — leaving it would make consumers that still
+            // check it decompress twice.
+            if (payload is { Length: > 0 } &&
+                headers != null &&
+                headers.TryGetValue(DealerPayloadEncoding.HeaderName, out var encoding) &&
+                DealerPayloadEncoding.IsCompressed(encoding))
+            {
+                try
+                {
+                    var compressedLength = payload.Length;
+                    payload = DealerPayloadEncoding.Decode(payload, encoding, limits.MaxMessageBytes);
+                    headers.Remove(DealerPayloadEncoding.HeaderName);
+                    logger?.LogTrace("Decompressed {Encoding} MESSAGE payload for {Uri}: {Compressed} -> {Size} bytes",
+                        encoding, uri, compressedLength, payload.Length);
+                }
+                catch (InvalidDataException ex)
+                {
+                    logger?.LogWarning(ex, "Failed to decompress {Encoding} MESSAGE payload for {Uri}", encoding, uri);
+                    return false;
+                }
+            }
+
             message = new DealerMessage
             {
                 Uri = uri,
@@ -167,17 +199,24 @@ internal static class MessageParser
     /// </summary>
     /// <param name="utf8Json">Raw UTF-8 JSON bytes.</param>
     /// <param name="request">Parsed request (if successful).</param>
-    /// <returns>True if parsing succeeded.</returns>
-    public static bool TryParseRequest(ReadOnlySpan<byte> utf8Json, out DealerRequest? request, ILogger? logger = null)
+    /// <param name="logger">Optional logger for diagnostics.</param>
+    /// <param name="limits">Header, size and depth limits. Null uses the defaults.</param>
+    /// <returns>True if parsing succeeded; false for malformed requests and requests over a limit.</returns>
+    public static bool TryParseRequest(
+        ReadOnlySpan<byte> utf8Json,
+        out DealerRequest? request,
+        ILogger? logger = null,
+        ProtocolLimitsConfig? limits = null)
     {
         request = null;
+        limits ??= DefaultLimits;
 
         try
         {
             // Configure reader to handle large payloads
             var options = new JsonReaderOptions
             {
-                MaxDepth = 64,
+                MaxDepth = limits.MaxJsonDepth,
                 CommentHandling = JsonCommentHandling.Skip
             };
             var reader = new Utf8JsonReader(utf8Json, options);
@@ -205,7 +244,7 @@ internal static class MessageParser
                     else if (reader.ValueTextEquals(HeadersPropertyName))
                     {
                         reader.Read();
-                        headers = ParseHeaders(ref reader);
+                        headers = ParseHeaders(ref reader, limits.MaxHeaders);
                     }
                     else if (reader.ValueTextEquals(PayloadPropertyName))
                     {
@@ -217,31 +256,32 @@ internal static class MessageParser
                 }
             }
 
-            // Handle gzip-compressed payloads (librespot compatibility)
-            // When Transfer-Encoding header is "gzip", payload contains { "compressed": "<base64>" }
+            // Handle compressed payloads (librespot compatibility)
+            // When Transfer-Encoding is gzip/deflate, payload contains { "compressed": "<base64>" }
             if (hasPayload &&
                 headers != null &&
-                headers.TryGetValue("Transfer-Encoding", out var encoding) &&
-                encoding.Equals("gzip", StringComparison.OrdinalIgnoreCase))
+                headers.TryGetValue(DealerPayloadEncoding.HeaderName, out var encoding) &&
+                DealerPayloadEncoding.IsCompressed(encoding))
             {
-                if (payload.TryGetProperty("compressed", out var compressedProp))
+                if (payload.ValueKind == JsonValueKind.Object &&
+                    payload.TryGetProperty("compressed", out var compressedProp))
                 {
                     try
                     {
                         var base64 = compressedProp.GetString();
                         if (!string.IsNullOrEmpty(base64))
                         {
-                            var gzipBytes = Convert.FromBase64String(base64);
-                            using var ms = new MemoryStream(gzipBytes);
-                            using var gzip = new GZipStream(ms, CompressionMode.Decompress);
-                            using var decompressedDoc = JsonDocument.Parse(gzip);
+                            var decoded = DealerPayloadEncoding.Decode(
+                                Convert.FromBase64String(base64), encoding, limits.MaxMessageBytes);
+                            using var decompressedDoc = JsonDocument.Parse(
+                                decoded, new JsonDocumentOptions { MaxDepth = limits.MaxJsonDepth });
                             payload = decompressedDoc.RootElement.Clone();
-                            logger?.LogTrace("Decompressed gzip payload successfully");
+                            logger?.LogTrace("Decompressed {Encoding} payload successfully", encoding);
                         }
                     }
                     catch (Exception ex)
                     {
-                        logger?.LogWarning(ex, "Failed to decompress gzip payload");
+                        logger?.LogWarning(ex, "Failed to decompress {Encoding} payload", encoding);
                         return false;
                     }
                 }
@@ -307,7 +347,8 @@ internal static class MessageParser
     /// <summary>
     /// Parses headers object from JSON.
     /// </summary>
-    private static Dictionary<string, string>? ParseHeaders(ref Utf8JsonReader reader)
+    /// <exception cref="InvalidDataException">More than <paramref name="maxHeaders"/> headers.</exception>
+    private static Dictionary<string, string>? ParseHeaders(ref Utf8JsonReader reader, int maxHeaders)
     {
         if (reader.TokenType != JsonTokenType.StartObject)
             return null;
@@ -326,7 +367,11 @@ internal static class MessageParser
                 var value = reader.GetString();
 
                 if (key != null && value != null)
+                {
                     headers[key] = value;
+                    if (headers.Count > maxHeaders)
+                        throw new InvalidDataException($"Message has more than {maxHeaders} headers (limits.maxHeaders)");
+                }
             }
         }
 
@@ -339,8 +384,12 @@ internal static class MessageParser
     /// </summary>
     /// <param name="utf8Json">Raw UTF-8 JSON bytes (may contain multiple objects).</param>
     /// <param name="logger">Optional logger for diagnostics.</param>
+    /// <param name="limits">Limits applied to each message. Null uses the defaults.</param>
     /// <returns>List of parsed messages.</returns>
-    public static List<DealerMessage> ParseMessages(ReadOnlySpan<byte> utf8Json, ILogger? logger = null)
+    public static List<DealerMessage> ParseMessages(
+        ReadOnlySpan<byte> utf8Json,
+        ILogger? logger = null,
+        ProtocolLimitsConfig? limits = null)
     {
         var messages = new List<DealerMessage>();
 
@@ -355,13 +404,13 @@ internal static class MessageParser
             if (endIndex <= 0)
             {
                 // Try to parse whatever is left
-                if (TryParseMessage(remaining, out var lastMessage, logger) && lastMessage != null)
+                if (TryParseMessage(remaining, out var lastMessage, logger, limits) && lastMessage != null)
                     messages.Add(lastMessage);
                 break;
             }
 
             var jsonObject = remaining.Slice(0, endIndex);
-            if (TryParseMessage(jsonObject, out var message, logger) && message != null)
+            if (TryParseMessage(jsonObject, out var message, logger, limits) && message != null)
             {
                 messages.Add(message);
             }
@@ -430,11 +479,18 @@ internal static class MessageParser
     }
 
     /// <summary>
-    /// Parses payloads array (base64 encoded) and decodes into single payload.
-    /// Uses JsonDocument to handle multi-segment buffers and provides GetBytesFromBase64()
-    /// for efficient direct decoding without intermediate string allocation.
+    /// Parses the payloads array and reassembles it into a single payload.
     /// </summary>
-    private static byte[]? ParsePayloads(ref Utf8JsonReader reader)
+    /// <remarks>
+    /// Large pushes are split across several array entries; the parts are concatenated in
+    /// order before any Transfer-Encoding is undone (the compressed stream spans all parts).
+    /// String entries are base64 protobuf/binary chunks and are decoded with
+    /// GetBytesFromBase64(); object/array entries are inline JSON and contribute their UTF-8
+    /// text. A part that is not valid base64 drops the whole payload, since a partial
+    /// protobuf would decode into a silently wrong message.
+    /// </remarks>
+    /// <exception cref="InvalidDataException">More parts or bytes than <paramref name="limits"/> allow.</exception>
+    private static byte[]? ParsePayloads(ref Utf8JsonReader reader, ProtocolLimitsConfig limits)
     {
         if (reader.TokenType != JsonTokenType.StartArray)
             return null;
@@ -448,24 +504,39 @@ internal static class MessageParser
             if (array.ValueKind != JsonValueKind.Array)
                 return null;
 
-            // Get first element (dealer protocol uses single payload)
-            using var enumerator = array.EnumerateArray();
-            if (enumerator.MoveNext())
+            var partCount = array.GetArrayLength();
+            if (partCount > limits.MaxParts)
+                throw new InvalidDataException($"Message has {partCount} payload parts, limit is {limits.MaxParts} (limits.maxParts)");
+
+            // Common case: a single part, no copy needed
+            if (partCount == 1)
+                return DecodePayloadPart(array[0]);
+
+            var buffer = new ArrayBufferWriter<byte>();
+            foreach (var element in array.EnumerateArray())
             {
-                var element = enumerator.Current;
-                if (element.ValueKind == JsonValueKind.String)
-                {
-                    // GetBytesFromBase64() decodes directly from UTF-8 base64 to bytes
-                    // More efficient than GetString() + Convert.FromBase64String()
-                    return element.GetBytesFromBase64();
-                }
+                var part = DecodePayloadPart(element);
+                if (part is null)
+                    return null;
+                if ((long)buffer.WrittenCount + part.Length > limits.MaxMessageBytes)
+                    throw new InvalidDataException($"Message payload exceeds {limits.MaxMessageBytes} bytes (limits.maxMessageBytes)");
+                buffer.Write(part);
             }
 
-            return null;
+            return buffer.WrittenCount == 0 ? null : buffer.WrittenSpan.ToArray();
         }
-        catch
+        catch (Exception ex) when (ex is not InvalidDataException)
         {
             return null;
         }
     }
+
+    private static byte[]? DecodePayloadPart(JsonElement element) => element.ValueKind switch
+    {
+        // GetBytesFromBase64() decodes directly from UTF-8 base64 to bytes
+        // More efficient than GetString() + Convert.FromBase64String()
+        JsonValueKind.String => element.GetBytesFromBase64(),
+        JsonValueKind.Object or JsonValueKind.Array => Encoding.UTF8.GetBytes(element.GetRawText()),
+        _ => null
+    };
 }
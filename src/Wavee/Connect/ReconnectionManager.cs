@@ -12,11 +12,14 @@ internal sealed class ReconnectionManager : IAsyncDisposable
     private readonly TimeSpan _maxDelay;
     private readonly int? _maxAttempts;
     private readonly Func<ValueTask> _reconnectCallback;
+    private readonly TimeProvider _timeProvider;
 
     private int _attemptCount;
     private CancellationTokenSource? _cts;
     private Task? _reconnectTask;
     private bool _isReconnecting;
+    private bool _skipNextDelay;
+    private TaskCompletionSource? _wakeSignal;
     private readonly object _lock = new();
 
     /// <summary>
@@ -37,18 +40,21 @@ internal sealed class ReconnectionManager : IAsyncDisposable
     /// <param name="maxAttempts">Maximum number of attempts (null for unlimited).</param>
     /// <param name="reconnectCallback">Async callback to execute reconnection logic.</param>
     /// <param name="logger">Optional logger for diagnostic output.</param>
+    /// <param name="timeProvider">Clock for the backoff delays. Defaults to the system clock.</param>
     public ReconnectionManager(
         TimeSpan initialDelay,
         TimeSpan maxDelay,
         int? maxAttempts,
         Func<ValueTask> reconnectCallback,
-        ILogger? logger = null)
+        ILogger? logger = null,
+        TimeProvider? timeProvider = null)
     {
         _initialDelay = initialDelay;
         _maxDelay = maxDelay;
         _maxAttempts = maxAttempts;
         _reconnectCallback = reconnectCallback ?? throw new ArgumentNullException(nameof(reconnectCallback));
         _logger = logger;
+        _timeProvider = timeProvider ?? TimeProvider.System;
     }
 
     /// <summary>
@@ -73,18 +79,33 @@ internal sealed class ReconnectionManager : IAsyncDisposable
     /// <summary>
     /// Initiates a reconnection attempt.
     /// </summary>
-    public void TriggerReconnection()
+    /// <param name="immediate">
+    /// Attempt right away instead of after the initial delay. If a reconnection is
+    /// already backing off, its current wait is cut short and the backoff restarts.
+    /// Used when the network changed and a retry is likely to succeed now.
+    /// </param>
+    public void TriggerReconnection(bool immediate = false)
     {
         lock (_lock)
         {
             if (_isReconnecting)
             {
+                if (immediate)
+                {
+                    _attemptCount = 0;
+                    _skipNextDelay = true;
+                    _wakeSignal?.TrySetResult();
+                    _logger?.LogDebug("Reconnection in progress, skipping remaining backoff");
+                    return;
+                }
+
                 _logger?.LogDebug("Reconnection already in progress, ignoring trigger");
                 return;
             }
 
             _isReconnecting = true;
             _attemptCount = 0;
+            _skipNextDelay = immediate;
         }
 
         _cts = new CancellationTokenSource();
@@ -184,15 +205,26 @@ internal sealed class ReconnectionManager : IAsyncDisposable
                 }
 
                 _attemptCount++;
-                var delay = CalculateDelay();
+                TimeSpan delay;
+                TaskCompletionSource wakeSignal;
+                lock (_lock)
+                {
+                    delay = _skipNextDelay ? TimeSpan.Zero : CalculateDelay();
+                    _skipNextDelay = false;
+                    wakeSignal = _wakeSignal = new TaskCompletionSource(TaskCreationOptions.RunContinuationsAsynchronously);
+                }
 
                 _logger?.LogInformation("Reconnection attempt {Attempt}{MaxInfo} after {Delay}s delay",
                     _attemptCount,
                     _maxAttempts.HasValue ? $"/{_maxAttempts.Value}" : "",
                     delay.TotalSeconds);
 
-                // Wait before attempting
-                await Task.Delay(delay, cancellationToken);
+                // Wait before attempting (an immediate trigger ends the wait early)
+                if (delay > TimeSpan.Zero)
+                {
+                    await Task.WhenAny(Task.Delay(delay, _timeProvider, cancellationToken), wakeSignal.Task);
+                    cancellationToken.ThrowIfCancellationRequested();
+                }
 
                 // Attempt reconnection
                 try
@@ -160,6 +160,9 @@ public sealed class AudioKeyManager : IAsyncDisposable
             }
         }
 
+        // Offline mode: only keys already on disk are usable.
+        OfflineModeException.ThrowIfOffline(_session);
+
         // Session-scoped bypass: once we've proven AudioKey is dead for this
         // session (entitlement denied or channel chronically broken), skip the
         // entire AP retry loop and go straight to PlayPlay. Saves ~10s per
@@ -589,6 +592,11 @@ public sealed class AudioKeyManager : IAsyncDisposable
         await _session.SendAsync(PacketType.RequestKey, packet, cancellationToken);
     }
 
+    /// <summary>
+    /// Number of AudioKey requests awaiting a response.
+    /// </summary>
+    public int PendingCount => _pending.Count;
+
     /// <summary>
     /// Resets the sequence number and cancels all pending requests.
     /// Called when reconnecting to AP due to stale connection.
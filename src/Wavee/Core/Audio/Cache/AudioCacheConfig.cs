@@ -1,3 +1,5 @@
+using Wavee.Core.Storage;
+
 namespace Wavee.Core.Audio.Cache;
 
 /// <summary>
@@ -7,11 +9,9 @@ public sealed record AudioCacheConfig
 {
     /// <summary>
     /// Directory where cached audio chunks are stored.
-    /// Default: %LOCALAPPDATA%/Wavee/AudioCache
+    /// Default: <see cref="WaveePaths.AudioCacheDirectory"/>
     /// </summary>
-    public string CacheDirectory { get; init; } = Path.Combine(
-        Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-        "Wavee", "AudioCache");
+    public string CacheDirectory { get; init; } = WaveePaths.Default.AudioCacheDirectory;
 
     /// <summary>
     /// Maximum cache size in bytes.
@@ -44,6 +44,12 @@ public sealed record AudioCacheConfig
     /// </summary>
     public double MinFreeSpacePercent { get; init; } = 0.10;
 
+    /// <summary>
+    /// How long to wait at startup for another Wavee process to release the cache directory.
+    /// Default: zero (fail immediately)
+    /// </summary>
+    public TimeSpan LockWaitTimeout { get; init; } = TimeSpan.Zero;
+
     /// <summary>
     /// Default configuration.
     /// </summary>
@@ -1,5 +1,7 @@
 using System.Collections.Concurrent;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Core.Audio.Cache;
 
@@ -17,6 +19,7 @@ public sealed class AudioCacheManager : IAsyncDisposable
 {
     private readonly AudioCacheConfig _config;
     private readonly ILogger? _logger;
+    private readonly LogSampling _sampling;
     private readonly ConcurrentDictionary<string, CacheEntry> _entries = new();
     private readonly SemaphoreSlim _writeLock = new(1, 1);
     private readonly CancellationTokenSource _pruneCts = new();
@@ -26,10 +29,14 @@ public sealed class AudioCacheManager : IAsyncDisposable
     /// <summary>
     /// Creates a new AudioCacheManager.
     /// </summary>
-    public AudioCacheManager(AudioCacheConfig? config = null, ILogger? logger = null)
+    /// <param name="config">Cache settings; defaults to <see cref="AudioCacheConfig.Default"/>.</param>
+    /// <param name="logger">Optional logger.</param>
+    /// <param name="sampling">Samplers for per-chunk debug logs, usually the session's <c>LogSampling</c>; null logs every chunk.</param>
+    public AudioCacheManager(AudioCacheConfig? config = null, ILogger? logger = null, LogSampling? sampling = null)
     {
         _config = config ?? AudioCacheConfig.Default;
         _logger = logger;
+        _sampling = sampling ?? new LogSampling(new SamplingConfig());
 
         if (_config.EnableCaching)
         {
@@ -139,8 +146,11 @@ public sealed class AudioCacheManager : IAsyncDisposable
             // Update last accessed time
             entry.LastAccessed = DateTime.UtcNow;
 
-            _logger?.LogDebug("Cache hit: file {FileId} chunk {ChunkIndex}",
-                entry.FileId, chunkIndex);
+            if (ShouldLogChunk(out var suppressed))
+            {
+                _logger!.LogDebug("Cache hit: file {FileId} chunk {ChunkIndex} ({Suppressed} chunk logs suppressed)",
+                    entry.FileId, chunkIndex, suppressed);
+            }
 
             return data;
         }
@@ -189,8 +199,11 @@ public sealed class AudioCacheManager : IAsyncDisposable
             entry.CachedChunks.Add(chunkIndex);
             entry.LastAccessed = DateTime.UtcNow;
 
-            _logger?.LogDebug("Cached chunk {ChunkIndex} for file {FileId} ({Bytes} bytes)",
-                chunkIndex, key, data.Length);
+            if (ShouldLogChunk(out var suppressed))
+            {
+                _logger!.LogDebug("Cached chunk {ChunkIndex} for file {FileId} ({Bytes} bytes, {Suppressed} chunk logs suppressed)",
+                    chunkIndex, key, data.Length, suppressed);
+            }
 
             // Periodically save entry metadata
             if (entry.CachedChunks.Count % 10 == 0)
@@ -234,12 +247,15 @@ public sealed class AudioCacheManager : IAsyncDisposable
     /// <summary>
     /// Deletes a cached file and its chunks.
     /// </summary>
-    public async Task DeleteFileAsync(FileId fileId)
+    public async Task DeleteFileAsync(FileId fileId, CancellationToken cancellationToken = default)
     {
+        cancellationToken.ThrowIfCancellationRequested();
+
         var key = fileId.ToBase16();
 
         if (_entries.TryRemove(key, out var entry))
         {
+            // Not cancellable past this point: the entry is already unindexed.
             await _writeLock.WaitAsync();
             try
             {
@@ -294,7 +310,7 @@ public sealed class AudioCacheManager : IAsyncDisposable
             var fileId = FileId.FromBase16(entry.FileId);
             var entrySize = entry.CachedBytes;
 
-            await DeleteFileAsync(fileId);
+            await DeleteFileAsync(fileId, cancellationToken);
             currentSize -= entrySize;
 
             _logger?.LogDebug("Pruned file {FileId}, freed {SizeKB}KB",
@@ -308,12 +324,12 @@ public sealed class AudioCacheManager : IAsyncDisposable
     /// <summary>
     /// Clears the entire cache.
     /// </summary>
-    public async Task ClearCacheAsync()
+    public async Task ClearCacheAsync(CancellationToken cancellationToken = default)
     {
         if (!_config.EnableCaching)
             return;
 
-        await _writeLock.WaitAsync();
+        await _writeLock.WaitAsync(cancellationToken);
         try
         {
             _entries.Clear();
@@ -398,8 +414,9 @@ public sealed class AudioCacheManager : IAsyncDisposable
             if (!Directory.Exists(directory))
                 Directory.CreateDirectory(directory);
 
+            // A crash mid-write must leave the previous index, not a torn one.
             var metadataPath = Path.Combine(directory, "metadata.json");
-            File.WriteAllText(metadataPath, entry.ToJson());
+            AtomicFile.ReplaceAllText(metadataPath, entry.ToJson());
         }
         catch (Exception ex)
         {
@@ -417,6 +434,15 @@ public sealed class AudioCacheManager : IAsyncDisposable
         return Path.Combine(GetFileDirectory(fileIdHex), $"{chunkIndex:D4}.chunk");
     }
 
+    // Per-chunk logs fire dozens of times per track; sample them per the session's SessionConfig.Sampling.
+    private bool ShouldLogChunk(out int suppressed)
+    {
+        suppressed = 0;
+        return _logger is not null
+               && _logger.IsEnabled(LogLevel.Debug)
+               && _sampling.AudioChunks.ShouldSample(out suppressed);
+    }
+
     private async Task RunPruneLoopAsync(CancellationToken cancellationToken)
     {
         while (!cancellationToken.IsCancellationRequested)
@@ -3,6 +3,7 @@ using System.Net;
 using System.Net.Http.Headers;
 using Microsoft.Extensions.Logging;
 using Wavee.Core.Audio.Cache;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.Core.Audio.Download;
 
@@ -33,7 +34,7 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
     private readonly FileStream _tempFile;
     private readonly string _tempFilePath;
     private readonly SemaphoreSlim _fetchLock = new(1, 1);
-    private readonly ArrayPool<byte> _bufferPool = ArrayPool<byte>.Shared;
+    private readonly ArrayPool<byte> _bufferPool = SlabBufferPool.Chunks;
     private readonly CancellationTokenSource _disposeCts = new();
 
     private long _position;
@@ -125,7 +126,7 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
             _downloadedRanges.AddRange(0, headData.Length);
             _bytesDownloadedTotal = headData.Length;
 
-            // Cache the head data too (use ArrayPool to avoid LOH allocations)
+            // Cache the head data too (pooled to avoid LOH allocations)
             if (_cache != null)
             {
                 var cacheChunkSize = AudioCacheConfig.Default.ChunkSize;
@@ -133,10 +134,10 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
                 {
                     var chunkIdx = offset / cacheChunkSize;
                     var len = Math.Min(cacheChunkSize, headData.Length - offset);
-                    var chunk = ArrayPool<byte>.Shared.Rent(len);
+                    var chunk = _bufferPool.Rent(len);
                     Buffer.BlockCopy(headData, offset, chunk, 0, len);
                     _ = _cache.WriteChunkAsync(fileId, chunkIdx, chunk.AsMemory(0, len), CancellationToken.None)
-                        .ContinueWith(_ => ArrayPool<byte>.Shared.Return(chunk),
+                        .ContinueWith(_ => _bufferPool.Return(chunk),
                             TaskContinuationOptions.ExecuteSynchronously);
                 }
             }
@@ -458,15 +459,15 @@ public sealed class ProgressiveDownloader : Stream, IAsyncDisposable
             WriteToTempFile(start, buffer.AsSpan(0, totalRead));
 
             // Write to cache (fire-and-forget, don't block playback)
-            // Use ArrayPool to avoid LOH allocations (chunks are 512KB+ → go to LOH with new byte[])
+            // Pooled to avoid LOH allocations (chunks are 512KB+ → go to LOH with new byte[])
             if (_cache != null && totalRead > 0)
             {
                 var cacheChunkSize = AudioCacheConfig.Default.ChunkSize;
                 var chunkIdx = (int)(start / cacheChunkSize);
-                var chunkData = ArrayPool<byte>.Shared.Rent(totalRead);
+                var chunkData = _bufferPool.Rent(totalRead);
                 Buffer.BlockCopy(buffer, 0, chunkData, 0, totalRead);
                 _ = _cache.WriteChunkAsync(_fileId, chunkIdx, chunkData.AsMemory(0, totalRead), _disposeCts.Token)
-                    .ContinueWith(_ => ArrayPool<byte>.Shared.Return(chunkData),
+                    .ContinueWith(_ => _bufferPool.Return(chunkData),
                         TaskContinuationOptions.ExecuteSynchronously);
             }
 
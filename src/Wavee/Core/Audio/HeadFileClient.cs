@@ -1,4 +1,5 @@
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Core.Audio;
 
@@ -92,7 +93,7 @@ public sealed class HeadFileClient
             ? template.Replace("{file_id}", fileIdHex, StringComparison.Ordinal)
             : template.TrimEnd('/') + "/" + fileIdHex;
 
-        _logger?.LogDebug("Fetching head file for {FileId} from {Url}", fileIdHex, url);
+        _logger?.LogDebug("Fetching head file for {FileId} from {Url}", fileIdHex, LogRedaction.Url(url));
 
         using var cts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
         cts.CancelAfter(DefaultTimeout);
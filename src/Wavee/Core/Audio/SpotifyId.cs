@@ -133,27 +133,33 @@ public readonly struct SpotifyId : IEquatable<SpotifyId>
             SpotifyIdType.Show => "show",
             SpotifyIdType.User => "user",
             SpotifyIdType.Local => "local",
+            SpotifyIdType.Ad => "ad",
+            SpotifyIdType.Interruption => "interruption",
             _ => "unknown"
         };
 
-        return $"spotify:{typeStr}:{ToBase62()}";
+        // Ad and interruption ids are written as hex on the wire.
+        return Type is SpotifyIdType.Ad or SpotifyIdType.Interruption
+            ? $"spotify:{typeStr}:{ToBase16()}"
+            : $"spotify:{typeStr}:{ToBase62()}";
     }
 
     /// <summary>
-    /// Parses a Spotify URI (e.g., "spotify:track:4iV5W9uYEdYUVa79Axb7Rh").
+    /// Classifies a URI by its type segment without parsing the ID. Unlike
+    /// <see cref="FromUri"/> this accepts URIs that carry no 128-bit ID:
+    /// <c>spotify:local:{artist}:{album}:{title}:{seconds}</c> and <c>spotify:delimiter</c>.
     /// </summary>
-    /// <param name="uri">The URI to parse.</param>
-    /// <returns>Parsed SpotifyId.</returns>
-    /// <exception cref="ArgumentException">Thrown if the URI format is invalid.</exception>
-    public static SpotifyId FromUri(string uri)
+    /// <returns>The type, or <see cref="SpotifyIdType.Unknown"/> for non-Spotify or unrecognised URIs.</returns>
+    public static SpotifyIdType GetUriType(string? uri)
     {
-        ArgumentException.ThrowIfNullOrWhiteSpace(uri);
+        if (string.IsNullOrEmpty(uri) || !uri.StartsWith("spotify:", StringComparison.Ordinal))
+            return SpotifyIdType.Unknown;
 
-        var parts = uri.Split(':');
-        if (parts.Length < 3 || parts[0] != "spotify")
-            throw new ArgumentException($"Invalid Spotify URI format: {uri}", nameof(uri));
+        var rest = uri.AsSpan("spotify:".Length);
+        var colon = rest.IndexOf(':');
+        var type = (colon < 0 ? rest : rest[..colon]).ToString().ToLowerInvariant();
 
-        var type = parts[1].ToLowerInvariant() switch
+        return type switch
         {
             "track" => SpotifyIdType.Track,
             "album" => SpotifyIdType.Album,
@@ -163,8 +169,43 @@ public readonly struct SpotifyId : IEquatable<SpotifyId>
             "show" => SpotifyIdType.Show,
             "user" => SpotifyIdType.User,
             "local" => SpotifyIdType.Local,
+            "ad" => SpotifyIdType.Ad,
+            "interruption" => SpotifyIdType.Interruption,
+            "delimiter" => SpotifyIdType.Delimiter,
             _ => SpotifyIdType.Unknown
         };
+    }
+
+    /// <summary>
+    /// True for context entries that are not part of the user's content: ads,
+    /// interruptions, delimiters and <c>spotify:meta:*</c> page markers. None of
+    /// these should be resolved or played as tracks.
+    /// </summary>
+    public static bool IsContextMarker(string? uri)
+        => GetUriType(uri) is SpotifyIdType.Ad or SpotifyIdType.Interruption or SpotifyIdType.Delimiter
+           || (uri is not null && uri.StartsWith("spotify:meta:", StringComparison.Ordinal));
+
+    /// <summary>
+    /// Parses a Spotify URI (e.g., "spotify:track:4iV5W9uYEdYUVa79Axb7Rh").
+    /// </summary>
+    /// <param name="uri">The URI to parse.</param>
+    /// <returns>Parsed SpotifyId.</returns>
+    /// <exception cref="ArgumentException">Thrown if the URI format is invalid.</exception>
+    public static SpotifyId FromUri(string uri)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(uri);
+
+        var parts = uri.Split(':');
+        if (parts.Length < 3 || parts[0] != "spotify")
+            throw new ArgumentException($"Invalid Spotify URI format: {uri}", nameof(uri));
+
+        var type = GetUriType(uri);
+        if (type == SpotifyIdType.Local)
+            throw new ArgumentException($"Local file URIs have no Spotify ID, use {nameof(SpotifyLocalUri)}: {uri}", nameof(uri));
+
+        // Ads and interruptions use 32-character hex ids; accept base62 as well.
+        if ((type is SpotifyIdType.Ad or SpotifyIdType.Interruption) && parts[2].Length == RawLength * 2)
+            return FromBase16(parts[2], type);
 
         return FromBase62(parts[2], type);
     }
@@ -360,5 +401,14 @@ public enum SpotifyIdType
     Episode,
     Show,
     User,
-    Local
+    Local,
+
+    /// <summary>Audio or video ad inserted into free-tier contexts.</summary>
+    Ad,
+
+    /// <summary>Non-music interruption (e.g. a jingle or announcement) inserted into a context.</summary>
+    Interruption,
+
+    /// <summary><c>spotify:delimiter</c> end-of-content marker; carries no ID.</summary>
+    Delimiter
 }
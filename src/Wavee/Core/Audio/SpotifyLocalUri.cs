@@ -0,0 +1,51 @@
+using System.Globalization;
+using System.Net;
+
+namespace Wavee.Core.Audio;
+
+/// <summary>
+/// A local file that a Spotify client added to a playlist:
+/// <c>spotify:local:{artist}:{album}:{title}:{seconds}</c>, each field URL-encoded with
+/// <c>+</c> for spaces. The URI carries the file's tags instead of an ID, so Spotify has
+/// nothing to stream; only the device holding the file can play it.
+/// </summary>
+/// <param name="Artist">Artist tag, or null when empty.</param>
+/// <param name="Album">Album tag, or null when empty.</param>
+/// <param name="Title">Title tag (falls back to the file name on the adding device).</param>
+/// <param name="Duration">Track length, or null when the URI omits it.</param>
+public sealed record SpotifyLocalUri(string? Artist, string? Album, string Title, TimeSpan? Duration)
+{
+    private const string Prefix = "spotify:local:";
+
+    /// <summary>
+    /// Parses a <c>spotify:local:</c> URI.
+    /// </summary>
+    public static bool TryParse(string? uri, out SpotifyLocalUri result)
+    {
+        result = null!;
+        if (string.IsNullOrEmpty(uri) || !uri.StartsWith(Prefix, StringComparison.OrdinalIgnoreCase))
+            return false;
+
+        // Colons inside fields are percent-encoded, so a plain split is safe.
+        var parts = uri[Prefix.Length..].Split(':');
+        if (parts.Length != 4)
+            return false;
+
+        var title = Decode(parts[2]);
+        if (title is null)
+            return false;
+
+        TimeSpan? duration = int.TryParse(parts[3], NumberStyles.None, CultureInfo.InvariantCulture, out var seconds)
+            ? TimeSpan.FromSeconds(seconds)
+            : null;
+
+        result = new SpotifyLocalUri(Decode(parts[0]), Decode(parts[1]), title, duration);
+        return true;
+    }
+
+    private static string? Decode(string field)
+    {
+        var decoded = WebUtility.UrlDecode(field);
+        return string.IsNullOrWhiteSpace(decoded) ? null : decoded;
+    }
+}
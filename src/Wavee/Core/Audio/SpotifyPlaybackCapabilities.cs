@@ -5,7 +5,7 @@ namespace Wavee.Core.Audio;
 /// </summary>
 public static class SpotifyPlaybackCapabilities
 {
-#if WAVEE_SPOTIFY_PLAYBACK_STUBS
+#if WAVEE_SPOTIFY_PLAYBACK_STUBS || WAVEE_PROTOCOL_ONLY
     public const bool DefaultLocalSpotifyPlaybackEnabled = false;
 #else
     public const bool DefaultLocalSpotifyPlaybackEnabled = true;
@@ -53,8 +53,16 @@ internal static class BlobDecryptor
         Span<byte> aesKey = stackalloc byte[AesKeySize];
         DeriveAesKey(secret, username, aesKey);
 
-        // Step 3-8: Decode and decrypt blob
-        return DecryptAndParseBlob(username, encryptedBlob, aesKey);
+        try
+        {
+            // Step 3-8: Decode and decrypt blob
+            return DecryptAndParseBlob(username, encryptedBlob, aesKey);
+        }
+        finally
+        {
+            CryptographicOperations.ZeroMemory(secret);
+            CryptographicOperations.ZeroMemory(aesKey);
+        }
     }
 
     /// <summary>
@@ -129,7 +137,10 @@ internal static class BlobDecryptor
     {
         using var aes = Aes.Create();
         aes.KeySize = 192;
-        aes.Key = key.ToArray();
+        // Aes copies the key internally and clears its copy on Dispose.
+        var keyCopy = key.ToArray();
+        aes.Key = keyCopy;
+        CryptographicOperations.ZeroMemory(keyCopy);
         aes.Mode = CipherMode.ECB;
         aes.Padding = PaddingMode.None;
 
@@ -144,6 +155,7 @@ internal static class BlobDecryptor
                 var block = data.Slice(offset, AesBlockSize).ToArray();
                 decryptor.TransformBlock(block, 0, AesBlockSize, block, 0);
                 block.CopyTo(data.Slice(offset));
+                CryptographicOperations.ZeroMemory(block);
             }
         }
     }
@@ -116,6 +116,12 @@ public sealed record Credentials
         return BlobDecryptor.Decrypt(username, encryptedBlob, deviceId);
     }
 
+    /// <summary>
+    /// Overwrites <see cref="AuthData"/> with zeros in place. Called by the owning session
+    /// on dispose so the reusable blob doesn't outlive it in memory.
+    /// </summary>
+    internal void ZeroAuthData() => System.Security.Cryptography.CryptographicOperations.ZeroMemory(AuthData);
+
     /// <summary>
     /// Returns a string representation of the credentials without revealing sensitive data.
     /// </summary>
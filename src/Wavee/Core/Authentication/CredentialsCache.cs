@@ -1,8 +1,8 @@
-using System.Runtime.Versioning;
 using System.Security.Cryptography;
 using System.Text;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage;
 
 namespace Wavee.Core.Authentication;
 
@@ -49,39 +49,46 @@ public interface ICredentialsCache
 }
 
 /// <summary>
-/// Default credentials cache implementation that stores encrypted credentials in the user's app data folder.
-/// Uses DPAPI (Data Protection API) on Windows for encryption, falls back to unencrypted on other platforms.
+/// Default credentials cache implementation. Serializes credentials to JSON and hands the
+/// bytes to an <see cref="ISecretStore"/> — by default a <see cref="FileSecretStore"/> in the
+/// user's app data folder (DPAPI on Windows, owner-only file permissions elsewhere).
 /// </summary>
 public sealed class CredentialsCache : ICredentialsCache
 {
-    private readonly string _cacheDirectory;
+    private const string CredentialsKeySuffix = "_credentials";
+    private const string DefaultCredentialsKey = "default" + CredentialsKeySuffix;
+    private const string LastUserKey = "last_user";
+    private const string LegacyLastUserFileName = "last_user.txt";
+
+    private readonly ISecretStore _store;
+    private readonly string? _legacyDirectory;
     private readonly ILogger? _logger;
-    private readonly bool _useEncryption;
 
     /// <summary>
-    /// Creates a new credentials cache.
+    /// Creates a new credentials cache backed by a <see cref="FileSecretStore"/>.
     /// </summary>
     /// <param name="cacheDirectory">
-    /// Directory to store credentials files. If null, uses default location
-    /// (%APPDATA%/Wavee/credentials on Windows, ~/.wavee/credentials on Unix).
+    /// Directory to store credentials files. If null, uses <see cref="WaveePaths.CredentialsDirectory"/>.
     /// </param>
     /// <param name="logger">Optional logger for diagnostics.</param>
     public CredentialsCache(string? cacheDirectory = null, ILogger? logger = null)
     {
-        _cacheDirectory = cacheDirectory ?? GetDefaultCacheDirectory();
+        _legacyDirectory = cacheDirectory ?? WaveePaths.Default.CredentialsDirectory;
+        _store = new FileSecretStore(_legacyDirectory, logger);
         _logger = logger;
+    }
 
-        // DPAPI is only available on Windows
-        _useEncryption = OperatingSystem.IsWindows();
-
-        if (!_useEncryption)
-        {
-            _logger?.LogWarning(
-                "Credentials encryption not available on this platform. Credentials will be stored unencrypted.");
-        }
-
-        // Ensure cache directory exists
-        Directory.CreateDirectory(_cacheDirectory);
+    /// <summary>
+    /// Creates a new credentials cache on top of an arbitrary secret store
+    /// (OS keyring, in-memory, ...).
+    /// </summary>
+    /// <param name="store">Secret store receiving the serialized credentials.</param>
+    /// <param name="logger">Optional logger for diagnostics.</param>
+    public CredentialsCache(ISecretStore store, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(store);
+        _store = store;
+        _logger = logger;
     }
 
     /// <inheritdoc/>
@@ -89,39 +96,26 @@ public sealed class CredentialsCache : ICredentialsCache
         string? username = null,
         CancellationToken cancellationToken = default)
     {
-        var filePath = GetCredentialsFilePath(username);
-
-        if (!File.Exists(filePath))
-        {
-            _logger?.LogDebug("No cached credentials found at {FilePath}", filePath);
-            return null;
-        }
+        var key = GetCredentialsKey(username);
 
         try
         {
-            _logger?.LogDebug("Loading cached credentials from {FilePath}", filePath);
-
-            // Read file
-            var encryptedData = await File.ReadAllBytesAsync(filePath, cancellationToken);
-
-            // Decrypt (if encryption is available - DPAPI on Windows only)
-            byte[] decryptedData;
-            if (OperatingSystem.IsWindows() && _useEncryption)
-            {
-                decryptedData = UnprotectWindows(encryptedData);
-            }
-            else
+            var data = await _store.ReadAsync(key, cancellationToken);
+            if (data == null)
             {
-                decryptedData = encryptedData;
+                _logger?.LogDebug("No cached credentials found for {Key}", key);
+                return null;
             }
 
+            _logger?.LogDebug("Loading cached credentials for {Key}", key);
+
             // Deserialize (using source-generated context for AOT)
-            var json = Encoding.UTF8.GetString(decryptedData);
+            var json = Encoding.UTF8.GetString(data);
             var credentials = JsonSerializer.Deserialize(json, AuthenticationJsonSerializerContext.Default.Credentials);
 
             if (credentials == null)
             {
-                _logger?.LogWarning("Failed to deserialize credentials from {FilePath}", filePath);
+                _logger?.LogWarning("Failed to deserialize credentials for {Key}", key);
                 return null;
             }
 
@@ -152,29 +146,15 @@ public sealed class CredentialsCache : ICredentialsCache
     {
         ArgumentNullException.ThrowIfNull(credentials);
 
-        var filePath = GetCredentialsFilePath(credentials.Username);
+        var key = GetCredentialsKey(credentials.Username);
 
         try
         {
-            _logger?.LogDebug("Saving credentials to {FilePath}", filePath);
+            _logger?.LogDebug("Saving credentials for {Key}", key);
 
             // Serialize (using source-generated context for AOT)
             var json = JsonSerializer.Serialize(credentials, AuthenticationJsonSerializerContext.Default.Credentials);
-            var jsonBytes = Encoding.UTF8.GetBytes(json);
-
-            // Encrypt (if available - DPAPI on Windows only)
-            byte[] dataToWrite;
-            if (OperatingSystem.IsWindows() && _useEncryption)
-            {
-                dataToWrite = ProtectWindows(jsonBytes);
-            }
-            else
-            {
-                dataToWrite = jsonBytes;
-            }
-
-            // Write to file
-            await File.WriteAllBytesAsync(filePath, dataToWrite, cancellationToken);
+            await _store.WriteAsync(key, Encoding.UTF8.GetBytes(json), cancellationToken);
 
             _logger?.LogInformation("Saved credentials to cache for user: {Username}", credentials.Username ?? "<unknown>");
 
@@ -195,71 +175,71 @@ public sealed class CredentialsCache : ICredentialsCache
     }
 
     /// <inheritdoc/>
-    public Task ClearCredentialsAsync(string? username = null, CancellationToken cancellationToken = default)
+    public async Task ClearCredentialsAsync(string? username = null, CancellationToken cancellationToken = default)
     {
-        var filePath = GetCredentialsFilePath(username);
+        var key = GetCredentialsKey(username);
 
         try
         {
-            if (File.Exists(filePath))
-            {
-                File.Delete(filePath);
-                _logger?.LogInformation("Cleared cached credentials at {FilePath}", filePath);
-            }
+            await _store.DeleteAsync(key, cancellationToken);
+            _logger?.LogInformation("Cleared cached credentials for {Key}", key);
         }
         catch (Exception ex)
         {
             _logger?.LogError(ex, "Failed to clear cached credentials");
         }
-
-        return Task.CompletedTask;
     }
 
     /// <inheritdoc/>
-    public Task ClearAllCredentialsAsync(CancellationToken cancellationToken = default)
+    public async Task ClearAllCredentialsAsync(CancellationToken cancellationToken = default)
     {
         try
         {
-            if (Directory.Exists(_cacheDirectory))
+            foreach (var key in await _store.ListKeysAsync(cancellationToken))
             {
-                foreach (var file in Directory.EnumerateFiles(_cacheDirectory, "*_credentials.dat"))
-                {
-                    try { File.Delete(file); }
-                    catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete credentials file {FilePath}", file); }
-                }
-
-                var lastUserFile = Path.Combine(_cacheDirectory, "last_user.txt");
-                if (File.Exists(lastUserFile))
-                {
-                    try { File.Delete(lastUserFile); }
-                    catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete last-username marker"); }
-                }
+                if (!key.EndsWith(CredentialsKeySuffix, StringComparison.Ordinal) && key != LastUserKey)
+                    continue;
+
+                try { await _store.DeleteAsync(key, cancellationToken); }
+                catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete cached secret {Key}", key); }
+            }
+
+            var legacyLastUserFile = GetLegacyLastUserFilePath();
+            if (legacyLastUserFile != null && File.Exists(legacyLastUserFile))
+            {
+                try { File.Delete(legacyLastUserFile); }
+                catch (Exception ex) { _logger?.LogWarning(ex, "Failed to delete last-username marker"); }
             }
 
-            _logger?.LogInformation("Cleared all cached credentials in {Directory}", _cacheDirectory);
+            _logger?.LogInformation("Cleared all cached credentials");
         }
         catch (Exception ex)
         {
             _logger?.LogError(ex, "Failed to clear all cached credentials");
         }
-
-        return Task.CompletedTask;
     }
 
     /// <inheritdoc/>
     public async Task<string?> LoadLastUsernameAsync(CancellationToken cancellationToken = default)
     {
-        var filePath = Path.Combine(_cacheDirectory, "last_user.txt");
-
-        if (!File.Exists(filePath))
-        {
-            _logger?.LogDebug("No last username file found at {FilePath}", filePath);
-            return null;
-        }
-
         try
         {
-            var username = await File.ReadAllTextAsync(filePath, cancellationToken);
+            var data = await _store.ReadAsync(LastUserKey, cancellationToken);
+            string? username = data != null ? Encoding.UTF8.GetString(data) : null;
+
+            // Pre-ISecretStore builds kept the marker as plain last_user.txt.
+            var legacyLastUserFile = GetLegacyLastUserFilePath();
+            if (username == null && legacyLastUserFile != null && File.Exists(legacyLastUserFile))
+            {
+                username = await File.ReadAllTextAsync(legacyLastUserFile, cancellationToken);
+            }
+
+            if (username == null)
+            {
+                _logger?.LogDebug("No last username stored");
+                return null;
+            }
+
             _logger?.LogDebug("Loaded last username: {Username}", username);
             return string.IsNullOrWhiteSpace(username) ? null : username.Trim();
         }
@@ -275,11 +255,9 @@ public sealed class CredentialsCache : ICredentialsCache
     /// </summary>
     private async Task SaveLastUsernameAsync(string username, CancellationToken cancellationToken = default)
     {
-        var filePath = Path.Combine(_cacheDirectory, "last_user.txt");
-
         try
         {
-            await File.WriteAllTextAsync(filePath, username, cancellationToken);
+            await _store.WriteAsync(LastUserKey, Encoding.UTF8.GetBytes(username), cancellationToken);
             _logger?.LogDebug("Saved last username: {Username}", username);
         }
         catch (Exception ex)
@@ -290,25 +268,17 @@ public sealed class CredentialsCache : ICredentialsCache
     }
 
     /// <summary>
-    /// Gets the file path for a credentials cache file.
+    /// Gets the secret store key for a user's credentials.
     /// </summary>
-    private string GetCredentialsFilePath(string? username)
+    private static string GetCredentialsKey(string? username)
     {
-        var fileName = string.IsNullOrWhiteSpace(username)
-            ? "default_credentials.dat"
-            : $"{SanitizeFilename(username)}_credentials.dat";
-
-        return Path.Combine(_cacheDirectory, fileName);
+        return string.IsNullOrWhiteSpace(username)
+            ? DefaultCredentialsKey
+            : SanitizeFilename(username) + CredentialsKeySuffix;
     }
 
-    /// <summary>
-    /// Gets the default cache directory based on platform.
-    /// </summary>
-    private static string GetDefaultCacheDirectory()
-    {
-        var appData = Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData);
-        return Path.Combine(appData, "Wavee", "credentials");
-    }
+    private string? GetLegacyLastUserFilePath()
+        => _legacyDirectory != null ? Path.Combine(_legacyDirectory, LegacyLastUserFileName) : null;
 
     /// <summary>
     /// Sanitizes a username for use in a filename.
@@ -325,18 +295,4 @@ public sealed class CredentialsCache : ICredentialsCache
 
         return sanitized.ToString();
     }
-
-    /// <summary>
-    /// Encrypts data using DPAPI (Windows only).
-    /// </summary>
-    [SupportedOSPlatform("windows")]
-    private static byte[] ProtectWindows(byte[] data)
-        => ProtectedData.Protect(data, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
-
-    /// <summary>
-    /// Decrypts data using DPAPI (Windows only).
-    /// </summary>
-    [SupportedOSPlatform("windows")]
-    private static byte[] UnprotectWindows(byte[] data)
-        => ProtectedData.Unprotect(data, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
 }
@@ -0,0 +1,121 @@
+using System.Runtime.Versioning;
+using System.Security.Cryptography;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Utilities;
+
+namespace Wavee.Core.Authentication;
+
+/// <summary>
+/// <see cref="ISecretStore"/> that writes one <c>{key}.dat</c> file per secret.
+/// </summary>
+/// <remarks>
+/// On Windows the bytes are DPAPI-encrypted (<see cref="DataProtectionScope.CurrentUser"/>).
+/// Elsewhere DPAPI isn't available, so the directory is created <c>0700</c> and every file
+/// <c>0600</c> — the blob is still plaintext on disk, but no longer world-readable.
+/// Writes go through <see cref="AtomicFile"/>, so power loss mid-write leaves the previous
+/// secret readable rather than a torn file.
+/// </remarks>
+public sealed class FileSecretStore : ISecretStore
+{
+    private const string Extension = ".dat";
+
+    private readonly string _directory;
+    private readonly ILogger? _logger;
+
+    /// <summary>
+    /// Creates a new file-backed secret store.
+    /// </summary>
+    /// <param name="directory">Directory holding the secret files. Created if missing.</param>
+    /// <param name="logger">Optional logger for diagnostics.</param>
+    public FileSecretStore(string directory, ILogger? logger = null)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(directory);
+
+        _directory = directory;
+        _logger = logger;
+
+        if (!OperatingSystem.IsWindows())
+        {
+            _logger?.LogWarning(
+                "Secret encryption not available on this platform. Secrets will be stored unencrypted with owner-only permissions.");
+            Directory.CreateDirectory(_directory, UnixFileMode.UserRead | UnixFileMode.UserWrite | UnixFileMode.UserExecute);
+        }
+        else
+        {
+            Directory.CreateDirectory(_directory);
+        }
+    }
+
+    /// <inheritdoc/>
+    public async Task<byte[]?> ReadAsync(string key, CancellationToken cancellationToken = default)
+    {
+        var filePath = GetFilePath(key);
+        if (!OperatingSystem.IsWindows())
+            return await AtomicFile.ReadAllBytesAsync(filePath, logger: _logger, cancellationToken: cancellationToken);
+
+        // A torn DPAPI blob fails to unprotect; that is what sends the read to the backup.
+        byte[]? secret = null;
+        await AtomicFile.ReadAllBytesAsync(filePath, data =>
+        {
+            secret = UnprotectWindows(data);
+            return true;
+        }, _logger, cancellationToken);
+        return secret;
+    }
+
+    /// <inheritdoc/>
+    public async Task WriteAsync(string key, byte[] secret, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(secret);
+
+        var filePath = GetFilePath(key);
+        var data = OperatingSystem.IsWindows() ? ProtectWindows(secret) : secret;
+
+        // Create with 0600 up-front so the blob is never briefly world-readable.
+        await AtomicFile.WriteAllBytesAsync(filePath, data, UnixFileMode.UserRead | UnixFileMode.UserWrite, cancellationToken);
+
+        if (!OperatingSystem.IsWindows())
+        {
+            // Tighten files written by older versions with the default umask.
+            File.SetUnixFileMode(filePath, UnixFileMode.UserRead | UnixFileMode.UserWrite);
+        }
+    }
+
+    /// <inheritdoc/>
+    public Task DeleteAsync(string key, CancellationToken cancellationToken = default)
+    {
+        AtomicFile.Delete(GetFilePath(key));
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc/>
+    public Task<IReadOnlyList<string>> ListKeysAsync(CancellationToken cancellationToken = default)
+    {
+        if (!Directory.Exists(_directory))
+            return Task.FromResult<IReadOnlyList<string>>(Array.Empty<string>());
+
+        var keys = Directory.EnumerateFiles(_directory, "*" + Extension)
+            .Where(p => p.EndsWith(Extension, StringComparison.OrdinalIgnoreCase))
+            .Select(Path.GetFileNameWithoutExtension)
+            .OfType<string>()
+            .ToArray();
+        return Task.FromResult<IReadOnlyList<string>>(keys);
+    }
+
+    private string GetFilePath(string key)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+        if (key.IndexOfAny(Path.GetInvalidFileNameChars()) >= 0)
+            throw new ArgumentException($"Secret key '{key}' is not filename-safe", nameof(key));
+
+        return Path.Combine(_directory, key + Extension);
+    }
+
+    [SupportedOSPlatform("windows")]
+    private static byte[] ProtectWindows(byte[] data)
+        => ProtectedData.Protect(data, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
+
+    [SupportedOSPlatform("windows")]
+    private static byte[] UnprotectWindows(byte[] data)
+        => ProtectedData.Unprotect(data, optionalEntropy: null, scope: DataProtectionScope.CurrentUser);
+}
@@ -0,0 +1,46 @@
+namespace Wavee.Core.Authentication;
+
+/// <summary>
+/// Persistence backend for small secrets (reusable auth blobs, tokens).
+/// </summary>
+/// <remarks>
+/// Keys are opaque, filename-safe identifiers chosen by the caller
+/// (e.g. <c>"alice_credentials"</c>). Implementations decide how the bytes are
+/// protected at rest:
+/// <list type="bullet">
+/// <item><see cref="FileSecretStore"/> — one file per key, DPAPI on Windows, owner-only permissions elsewhere.</item>
+/// <item><see cref="InMemorySecretStore"/> — process lifetime only (tests, kiosk/ephemeral sessions).</item>
+/// <item><c>WindowsCredentialSecretStore</c> — Windows Credential Manager; compiled only with <c>WaveeEnableKeyring=true</c>.</item>
+/// </list>
+/// </remarks>
+public interface ISecretStore
+{
+    /// <summary>
+    /// Reads a secret.
+    /// </summary>
+    /// <param name="key">Secret identifier.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The secret bytes, or null if no secret is stored under <paramref name="key"/>.</returns>
+    Task<byte[]?> ReadAsync(string key, CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Creates or replaces a secret.
+    /// </summary>
+    /// <param name="key">Secret identifier.</param>
+    /// <param name="secret">Secret bytes.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    Task WriteAsync(string key, byte[] secret, CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Deletes a secret. No-op if it doesn't exist.
+    /// </summary>
+    /// <param name="key">Secret identifier.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    Task DeleteAsync(string key, CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Lists every key currently held by this store.
+    /// </summary>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    Task<IReadOnlyList<string>> ListKeysAsync(CancellationToken cancellationToken = default);
+}
@@ -0,0 +1,40 @@
+using System.Collections.Concurrent;
+
+namespace Wavee.Core.Authentication;
+
+/// <summary>
+/// <see cref="ISecretStore"/> that keeps secrets in process memory only.
+/// Nothing survives a restart — use for tests or sessions that must not persist credentials.
+/// </summary>
+public sealed class InMemorySecretStore : ISecretStore
+{
+    private readonly ConcurrentDictionary<string, byte[]> _secrets = new(StringComparer.Ordinal);
+
+    /// <inheritdoc/>
+    public Task<byte[]?> ReadAsync(string key, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+        return Task.FromResult(_secrets.TryGetValue(key, out var secret) ? (byte[]?)secret.ToArray() : null);
+    }
+
+    /// <inheritdoc/>
+    public Task WriteAsync(string key, byte[] secret, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+        ArgumentNullException.ThrowIfNull(secret);
+        _secrets[key] = secret.ToArray();
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc/>
+    public Task DeleteAsync(string key, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+        _secrets.TryRemove(key, out _);
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc/>
+    public Task<IReadOnlyList<string>> ListKeysAsync(CancellationToken cancellationToken = default)
+        => Task.FromResult<IReadOnlyList<string>>(_secrets.Keys.ToArray());
+}
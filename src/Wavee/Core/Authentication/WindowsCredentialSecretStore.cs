@@ -0,0 +1,165 @@
+#if WAVEE_KEYRING
+using System.ComponentModel;
+using System.Runtime.InteropServices;
+using System.Runtime.Versioning;
+
+namespace Wavee.Core.Authentication;
+
+/// <summary>
+/// <see cref="ISecretStore"/> backed by the Windows Credential Manager (the OS keyring).
+/// </summary>
+/// <remarks>
+/// Only compiled when the project is built with <c>-p:WaveeEnableKeyring=true</c>. Each key
+/// becomes a <c>CRED_TYPE_GENERIC</c> entry named <c>Wavee/{key}</c> with
+/// <c>CRED_PERSIST_LOCAL_MACHINE</c> persistence, so the blob never touches the app data folder.
+/// </remarks>
+[SupportedOSPlatform("windows")]
+public sealed class WindowsCredentialSecretStore : ISecretStore
+{
+    private const string TargetPrefix = "Wavee/";
+    private const int CredTypeGeneric = 1;
+    private const int CredPersistLocalMachine = 2;
+    private const int ErrorNotFound = 1168;
+
+    /// <inheritdoc/>
+    public Task<byte[]?> ReadAsync(string key, CancellationToken cancellationToken = default)
+    {
+        var target = GetTargetName(key);
+        if (!CredRead(target, CredTypeGeneric, 0, out var credentialPtr))
+        {
+            var error = Marshal.GetLastWin32Error();
+            if (error == ErrorNotFound)
+                return Task.FromResult<byte[]?>(null);
+            throw new Win32Exception(error, $"CredRead failed for {target}");
+        }
+
+        try
+        {
+            var credential = Marshal.PtrToStructure<NativeCredential>(credentialPtr);
+            var secret = new byte[credential.CredentialBlobSize];
+            if (secret.Length > 0)
+                Marshal.Copy(credential.CredentialBlob, secret, 0, secret.Length);
+            return Task.FromResult<byte[]?>(secret);
+        }
+        finally
+        {
+            CredFree(credentialPtr);
+        }
+    }
+
+    /// <inheritdoc/>
+    public Task WriteAsync(string key, byte[] secret, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(secret);
+
+        var target = GetTargetName(key);
+        var blob = Marshal.AllocHGlobal(Math.Max(secret.Length, 1));
+        try
+        {
+            Marshal.Copy(secret, 0, blob, secret.Length);
+            var credential = new NativeCredential
+            {
+                Type = CredTypeGeneric,
+                TargetName = target,
+                CredentialBlobSize = secret.Length,
+                CredentialBlob = blob,
+                Persist = CredPersistLocalMachine,
+                UserName = Environment.UserName
+            };
+
+            if (!CredWrite(ref credential, 0))
+                throw new Win32Exception(Marshal.GetLastWin32Error(), $"CredWrite failed for {target}");
+        }
+        finally
+        {
+            // Scrub the unmanaged copy before releasing it.
+            for (var i = 0; i < secret.Length; i++)
+                Marshal.WriteByte(blob, i, 0);
+            Marshal.FreeHGlobal(blob);
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc/>
+    public Task DeleteAsync(string key, CancellationToken cancellationToken = default)
+    {
+        var target = GetTargetName(key);
+        if (!CredDelete(target, CredTypeGeneric, 0))
+        {
+            var error = Marshal.GetLastWin32Error();
+            if (error != ErrorNotFound)
+                throw new Win32Exception(error, $"CredDelete failed for {target}");
+        }
+
+        return Task.CompletedTask;
+    }
+
+    /// <inheritdoc/>
+    public Task<IReadOnlyList<string>> ListKeysAsync(CancellationToken cancellationToken = default)
+    {
+        if (!CredEnumerate(TargetPrefix + "*", 0, out var count, out var credentialsPtr))
+        {
+            var error = Marshal.GetLastWin32Error();
+            if (error == ErrorNotFound)
+                return Task.FromResult<IReadOnlyList<string>>(Array.Empty<string>());
+            throw new Win32Exception(error, "CredEnumerate failed");
+        }
+
+        try
+        {
+            var keys = new List<string>(count);
+            for (var i = 0; i < count; i++)
+            {
+                var entryPtr = Marshal.ReadIntPtr(credentialsPtr, i * IntPtr.Size);
+                var credential = Marshal.PtrToStructure<NativeCredential>(entryPtr);
+                if (credential.TargetName?.StartsWith(TargetPrefix, StringComparison.Ordinal) == true)
+                    keys.Add(credential.TargetName[TargetPrefix.Length..]);
+            }
+            return Task.FromResult<IReadOnlyList<string>>(keys);
+        }
+        finally
+        {
+            CredFree(credentialsPtr);
+        }
+    }
+
+    private static string GetTargetName(string key)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+        return TargetPrefix + key;
+    }
+
+    [StructLayout(LayoutKind.Sequential, CharSet = CharSet.Unicode)]
+    private struct NativeCredential
+    {
+        public int Flags;
+        public int Type;
+        public string? TargetName;
+        public string? Comment;
+        public long LastWritten;
+        public int CredentialBlobSize;
+        public IntPtr CredentialBlob;
+        public int Persist;
+        public int AttributeCount;
+        public IntPtr Attributes;
+        public string? TargetAlias;
+        public string? UserName;
+    }
+
+    [DllImport("advapi32.dll", EntryPoint = "CredReadW", CharSet = CharSet.Unicode, SetLastError = true)]
+    private static extern bool CredRead(string target, int type, int reservedFlag, out IntPtr credential);
+
+    [DllImport("advapi32.dll", EntryPoint = "CredWriteW", CharSet = CharSet.Unicode, SetLastError = true)]
+    private static extern bool CredWrite(ref NativeCredential credential, int flags);
+
+    [DllImport("advapi32.dll", EntryPoint = "CredDeleteW", CharSet = CharSet.Unicode, SetLastError = true)]
+    private static extern bool CredDelete(string target, int type, int flags);
+
+    [DllImport("advapi32.dll", EntryPoint = "CredEnumerateW", CharSet = CharSet.Unicode, SetLastError = true)]
+    private static extern bool CredEnumerate(string filter, int flags, out int count, out IntPtr credentials);
+
+    [DllImport("advapi32.dll", SetLastError = false)]
+    private static extern void CredFree(IntPtr buffer);
+}
+#endif
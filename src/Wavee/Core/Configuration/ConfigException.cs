@@ -0,0 +1,32 @@
+namespace Wavee.Core.Configuration;
+
+/// <summary>
+/// Exception thrown when a configuration file or environment variable can't be applied.
+/// </summary>
+public sealed class ConfigException : Exception
+{
+    /// <summary>
+    /// Dotted key of the offending setting (e.g. <c>network.localAddress</c>), or null
+    /// when the whole source is unusable (missing file, malformed JSON).
+    /// </summary>
+    public string? Key { get; }
+
+    /// <summary>
+    /// Where the value came from: a file path or an environment variable name.
+    /// </summary>
+    public string Source { get; }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ConfigException"/> class.
+    /// </summary>
+    /// <param name="key">Offending key, if any.</param>
+    /// <param name="source">File path or environment variable name.</param>
+    /// <param name="message">What is wrong with the value.</param>
+    /// <param name="innerException">The inner exception.</param>
+    public ConfigException(string? key, string source, string message, Exception? innerException = null)
+        : base(key is null ? $"{source}: {message}" : $"{source}: '{key}' {message}", innerException)
+    {
+        Key = key;
+        Source = source;
+    }
+}
@@ -0,0 +1,206 @@
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Core.Configuration;
+
+/// <summary>
+/// Holds the live <see cref="WaveeConfig"/> and re-reads it on demand (SIGHUP, a control
+/// API call), applying only the settings that are safe to change while a session runs.
+/// </summary>
+/// <remarks>
+/// The reloadable settings are listed in <see cref="ReloadableKeys"/>. Anything else that
+/// differs in the new configuration — device identity, network, cache — is reported in
+/// <see cref="ConfigChangedEventArgs.RestartRequiredKeys"/> and left at its current value
+/// until the host restarts. A reload that fails validation changes nothing.
+/// </remarks>
+public sealed class ConfigReloader
+{
+    private readonly Func<WaveeConfig> _load;
+    private readonly ILogger? _logger;
+    private readonly object _lock = new();
+    private WaveeConfig _current;
+
+    /// <summary>
+    /// Raised after a reload changed at least one reloadable setting, on the thread that
+    /// called <see cref="Reload"/>.
+    /// </summary>
+    public event EventHandler<ConfigChangedEventArgs>? ConfigChanged;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ConfigReloader"/> class.
+    /// </summary>
+    /// <param name="initial">Configuration the host started with.</param>
+    /// <param name="load">Re-reads the configuration, typically via <see cref="WaveeConfigLoader.Load"/>.</param>
+    /// <param name="logger">Optional logger for diagnostic output.</param>
+    public ConfigReloader(WaveeConfig initial, Func<WaveeConfig> load, ILogger? logger = null)
+    {
+        _current = initial ?? throw new ArgumentNullException(nameof(initial));
+        _load = load ?? throw new ArgumentNullException(nameof(load));
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the keys a reload applies without restarting.
+    /// </summary>
+    public static IReadOnlyList<string> ReloadableKeys { get; } =
+    [
+        "player.quality", "player.normalization", "player.prefetch",
+        "player.blockedTracks", "player.blockedArtists", "player.maxTrackDuration", "player.skipLiveVersions",
+        "logging.level"
+    ];
+
+    /// <summary>
+    /// Gets the configuration currently in effect.
+    /// </summary>
+    public WaveeConfig Current
+    {
+        get
+        {
+            lock (_lock)
+            {
+                return _current;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Re-reads the configuration and applies the reloadable settings that changed.
+    /// </summary>
+    /// <returns>What changed; <see cref="ConfigChangedEventArgs.ChangedKeys"/> is empty when nothing did.</returns>
+    /// <exception cref="ConfigException">The new configuration is invalid; nothing was applied.</exception>
+    public ConfigChangedEventArgs Reload()
+    {
+        var loaded = _load();
+
+        ConfigChangedEventArgs args;
+        lock (_lock)
+        {
+            var previous = _current;
+            var next = previous with
+            {
+                Player = previous.Player with
+                {
+                    Quality = loaded.Player.Quality,
+                    NormalizationEnabled = loaded.Player.NormalizationEnabled,
+                    PrefetchEnabled = loaded.Player.PrefetchEnabled,
+                    BlockedTracks = loaded.Player.BlockedTracks,
+                    BlockedArtists = loaded.Player.BlockedArtists,
+                    MaxTrackDuration = loaded.Player.MaxTrackDuration,
+                    SkipLiveVersions = loaded.Player.SkipLiveVersions
+                },
+                LogLevel = loaded.LogLevel
+            };
+
+            args = new ConfigChangedEventArgs(previous, next, Diff(previous, next), RestartRequired(previous, loaded));
+            _current = next;
+        }
+
+        if (args.RestartRequiredKeys.Count > 0)
+        {
+            _logger?.LogWarning("Configuration reload ignored {Keys}; restart to apply",
+                string.Join(", ", args.RestartRequiredKeys));
+        }
+
+        if (args.ChangedKeys.Count == 0)
+        {
+            _logger?.LogInformation("Configuration reloaded; nothing changed");
+            return args;
+        }
+
+        _logger?.LogInformation("Configuration reloaded: {Keys}", string.Join(", ", args.ChangedKeys));
+        ConfigChanged?.Invoke(this, args);
+        return args;
+    }
+
+    private static List<string> Diff(WaveeConfig previous, WaveeConfig next)
+    {
+        var keys = new List<string>();
+        if (previous.Player.Quality != next.Player.Quality)
+            keys.Add("player.quality");
+        if (previous.Player.NormalizationEnabled != next.Player.NormalizationEnabled)
+            keys.Add("player.normalization");
+        if (previous.Player.PrefetchEnabled != next.Player.PrefetchEnabled)
+            keys.Add("player.prefetch");
+        if (!previous.Player.BlockedTracks.SequenceEqual(next.Player.BlockedTracks))
+            keys.Add("player.blockedTracks");
+        if (!previous.Player.BlockedArtists.SequenceEqual(next.Player.BlockedArtists))
+            keys.Add("player.blockedArtists");
+        if (previous.Player.MaxTrackDuration != next.Player.MaxTrackDuration)
+            keys.Add("player.maxTrackDuration");
+        if (previous.Player.SkipLiveVersions != next.Player.SkipLiveVersions)
+            keys.Add("player.skipLiveVersions");
+        if (previous.LogLevel != next.LogLevel)
+            keys.Add("logging.level");
+        return keys;
+    }
+
+    private static List<string> RestartRequired(WaveeConfig current, WaveeConfig loaded)
+    {
+        // Reported per section: the session holds these for its lifetime.
+        var keys = new List<string>();
+        if (loaded.Session with
+            {
+                Network = current.Session.Network,
+                ConnectDevice = current.Session.ConnectDevice,
+                Sampling = current.Session.Sampling,
+                Limits = current.Session.Limits
+            } != current.Session)
+            keys.Add("session");
+        if (loaded.Session.Network with { TlsPins = current.Session.Network.TlsPins } != current.Session.Network
+            || !loaded.Session.Network.TlsPins.SequenceEqual(current.Session.Network.TlsPins))
+            keys.Add("network");
+        if (loaded.Session.ConnectDevice != current.Session.ConnectDevice)
+            keys.Add("connect");
+        if (loaded.Session.Sampling != current.Session.Sampling)
+            keys.Add("sampling");
+        if (loaded.Session.Limits != current.Session.Limits)
+            keys.Add("limits");
+        if (loaded.Player.InitialVolumePercent != current.Player.InitialVolumePercent)
+            keys.Add("player.initialVolumePercent");
+        if (loaded.Player.DecoderBackend != current.Player.DecoderBackend)
+            keys.Add("player.decoder");
+        if (loaded.Cache != current.Cache)
+            keys.Add("cache");
+        return keys;
+    }
+}
+
+/// <summary>
+/// Event arguments for <see cref="ConfigReloader.ConfigChanged"/>.
+/// </summary>
+public sealed class ConfigChangedEventArgs : EventArgs
+{
+    /// <summary>
+    /// Configuration before the reload.
+    /// </summary>
+    public WaveeConfig Previous { get; }
+
+    /// <summary>
+    /// Configuration now in effect.
+    /// </summary>
+    public WaveeConfig Current { get; }
+
+    /// <summary>
+    /// Reloadable keys whose value changed.
+    /// </summary>
+    public IReadOnlyList<string> ChangedKeys { get; }
+
+    /// <summary>
+    /// Keys or sections that differ in the new configuration but need a restart.
+    /// </summary>
+    public IReadOnlyList<string> RestartRequiredKeys { get; }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ConfigChangedEventArgs"/> class.
+    /// </summary>
+    public ConfigChangedEventArgs(
+        WaveeConfig previous,
+        WaveeConfig current,
+        IReadOnlyList<string> changedKeys,
+        IReadOnlyList<string> restartRequiredKeys)
+    {
+        Previous = previous;
+        Current = current;
+        ChangedKeys = changedKeys;
+        RestartRequiredKeys = restartRequiredKeys;
+    }
+}
@@ -0,0 +1,91 @@
+using Microsoft.Extensions.Logging;
+using Wavee.AudioIpc;
+using Wavee.Core.Audio;
+using Wavee.Core.Audio.Cache;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Configuration;
+
+/// <summary>
+/// Typed configuration for a headless Wavee host, as produced by <see cref="WaveeConfigLoader"/>.
+/// </summary>
+/// <remarks>
+/// Network settings live on <see cref="SessionConfig.Network"/>, Connect device
+/// capabilities on <see cref="SessionConfig.ConnectDevice"/> and log/event sampling on
+/// <see cref="SessionConfig.Sampling"/>; the loader exposes them under their own
+/// <c>network.*</c>, <c>connect.*</c> and <c>sampling.*</c> keys.
+/// </remarks>
+public sealed record WaveeConfig
+{
+    /// <summary>
+    /// Session and network settings.
+    /// </summary>
+    public required SessionConfig Session { get; init; }
+
+    /// <summary>
+    /// Local playback settings.
+    /// </summary>
+    public PlayerConfig Player { get; init; } = new();
+
+    /// <summary>
+    /// Persistent audio cache settings.
+    /// </summary>
+    public AudioCacheConfig Cache { get; init; } = AudioCacheConfig.Default;
+
+    /// <summary>
+    /// Minimum level the host logs at. Default is <see cref="LogLevel.Information"/>.
+    /// </summary>
+    public LogLevel LogLevel { get; init; } = LogLevel.Information;
+}
+
+/// <summary>
+/// Local playback settings for a headless host.
+/// </summary>
+public sealed record PlayerConfig
+{
+    /// <summary>
+    /// Preferred streaming quality. Default is <see cref="AudioQuality.VeryHigh"/>;
+    /// free accounts fall back to the best quality they are allowed.
+    /// </summary>
+    public AudioQuality Quality { get; init; } = AudioQuality.VeryHigh;
+
+    /// <summary>
+    /// Apply ReplayGain-style loudness normalization. Default is true.
+    /// </summary>
+    public bool NormalizationEnabled { get; init; } = true;
+
+    /// <summary>
+    /// Resolve and buffer the next track ahead of the current one ending. Default is true.
+    /// </summary>
+    public bool PrefetchEnabled { get; init; } = true;
+
+    /// <summary>
+    /// Volume (0-100) announced before the first Connect state arrives. Default is 50.
+    /// </summary>
+    public int InitialVolumePercent { get; init; } = 50;
+
+    /// <summary>
+    /// Track URIs the player skips instead of playing.
+    /// </summary>
+    public IReadOnlyList<string> BlockedTracks { get; init; } = [];
+
+    /// <summary>
+    /// Artist URIs or names whose tracks the player skips.
+    /// </summary>
+    public IReadOnlyList<string> BlockedArtists { get; init; } = [];
+
+    /// <summary>
+    /// Tracks longer than this are skipped. Zero (default) allows any length.
+    /// </summary>
+    public TimeSpan MaxTrackDuration { get; init; }
+
+    /// <summary>
+    /// Skip live recordings. Default is false.
+    /// </summary>
+    public bool SkipLiveVersions { get; init; }
+
+    /// <summary>
+    /// Which decoders the AudioHost uses. Default is <see cref="AudioDecoderBackend.Native"/>.
+    /// </summary>
+    public AudioDecoderBackend DecoderBackend { get; init; } = AudioDecoderBackend.Native;
+}
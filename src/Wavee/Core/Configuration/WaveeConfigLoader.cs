@@ -0,0 +1,368 @@
+using System.Collections;
+using System.Globalization;
+using System.Net;
+using System.Text;
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Wavee.AudioIpc;
+using Wavee.Core.Audio;
+using Wavee.Core.Connection;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Configuration;
+
+/// <summary>
+/// Builds a <see cref="WaveeConfig"/> by layering a JSON file and <c>WAVEE_*</c>
+/// environment variables over defaults.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Precedence is defaults, then the file, then the environment. File keys are grouped by
+/// section (<c>{"network": {"apConnectTimeout": 20}}</c>); each key also has an environment
+/// variable named after it (<c>WAVEE_NETWORK_AP_CONNECT_TIMEOUT</c>). See <see cref="Keys"/>.
+/// </para>
+/// <para>
+/// Unknown keys in the file are rejected so typos surface. Unknown <c>WAVEE_*</c> variables
+/// are ignored, since hosts use the prefix for their own settings too. The file is JSON
+/// (comments and trailing commas allowed); TOML is not supported.
+/// </para>
+/// <para>
+/// Timeouts and sampling intervals accept seconds as a number or a <see cref="TimeSpan"/>
+/// string (<c>"00:00:20"</c>); a zero interval turns sampling off.
+/// Enums accept their member names, case-insensitively.
+/// </para>
+/// </remarks>
+public static class WaveeConfigLoader
+{
+    /// <summary>
+    /// Environment variable naming the config file when no path is passed explicitly.
+    /// </summary>
+    public const string FileEnvironmentVariable = "WAVEE_CONFIG";
+
+    private const string EnvironmentPrefix = "WAVEE_";
+
+    private delegate WaveeConfig Setter(WaveeConfig config, string value);
+
+    private static readonly (string Key, Setter Apply)[] Settings =
+    [
+        ("session.deviceName", (c, v) => c with { Session = c.Session with { DeviceName = ParseNonEmpty(v) } }),
+        ("session.deviceType", (c, v) => c with { Session = c.Session with { DeviceType = ParseEnum<DeviceType>(v) } }),
+        ("session.preferredLocale", (c, v) => c with { Session = c.Session with { PreferredLocale = ParseNonEmpty(v) } }),
+        ("session.metadataLocale", (c, v) => c with { Session = c.Session with { MetadataLocale = ParseNonEmpty(v) } }),
+        ("session.metadataCountry", (c, v) => c with { Session = c.Session with { MetadataCountry = ParseNonEmpty(v) } }),
+        ("session.timeZone", (c, v) => c with { Session = c.Session with { TimeZone = ParseNonEmpty(v) } }),
+        ("session.apPort", (c, v) => c with { Session = c.Session with { ApPort = ParseInt(v, 1, 65535) } }),
+        ("session.enableConnect", (c, v) => c with { Session = c.Session with { EnableConnect = ParseBool(v) } }),
+        ("session.reconnectOnNetworkChange", (c, v) => c with { Session = c.Session with { ReconnectOnNetworkChange = ParseBool(v) } }),
+        ("session.powerSaveIdle", (c, v) => c with { Session = c.Session with { PowerSaveIdleTimeout = ParseInterval(v) } }),
+
+        ("network.apConnectTimeout", (c, v) => WithNetwork(c, n => n with { ApConnectTimeout = ParseTimeout(v) })),
+        ("network.apHandshakeTimeout", (c, v) => WithNetwork(c, n => n with { ApHandshakeTimeout = ParseTimeout(v) })),
+        ("network.dealerConnectTimeout", (c, v) => WithNetwork(c, n => n with { DealerConnectTimeout = ParseTimeout(v) })),
+        ("network.cdnRequestTimeout", (c, v) => WithNetwork(c, n => n with { CdnRequestTimeout = ParseTimeout(v) })),
+        ("network.spClientRequestTimeout", (c, v) => WithNetwork(c, n => n with { SpClientRequestTimeout = ParseTimeout(v) })),
+        ("network.maxResponseBytes", (c, v) => WithNetwork(c, n => n with { MaxResponseBytes = ParseLong(v, 1, long.MaxValue) })),
+        ("network.localAddress", (c, v) => WithNetwork(c, n => n with { LocalAddress = ParseAddress(v) })),
+        ("network.addressFamily", (c, v) => WithNetwork(c, n => n with { AddressFamily = ParseEnum<AddressFamilyPreference>(v) })),
+        ("network.tlsPinning", (c, v) => WithNetwork(c, n => n with { TlsPinning = ParseEnum<TlsPinningMode>(v) })),
+        ("network.tlsPins", (c, v) => WithNetwork(c, n => n with { TlsPins = ParsePins(v) })),
+
+        ("connect.volumeSteps", (c, v) => WithConnectDevice(c, d => d with { VolumeSteps = ParseInt(v, 1, 65535) })),
+        ("connect.supportsVolume", (c, v) => WithConnectDevice(c, d => d with { SupportsVolume = ParseBool(v) })),
+        ("connect.supportsRename", (c, v) => WithConnectDevice(c, d => d with { SupportsRename = ParseBool(v) })),
+        ("connect.supportsLogout", (c, v) => WithConnectDevice(c, d => d with { SupportsLogout = ParseBool(v) })),
+        ("connect.hidden", (c, v) => WithConnectDevice(c, d => d with { Hidden = ParseBool(v) })),
+        ("connect.brand", (c, v) => WithConnectDevice(c, d => d with { Brand = ParseNonEmpty(v) })),
+        ("connect.model", (c, v) => WithConnectDevice(c, d => d with { Model = ParseNonEmpty(v) })),
+
+        ("sampling.positionLog", (c, v) => WithSampling(c, s => s with { PositionLogInterval = ParseInterval(v) })),
+        ("sampling.audioChunkLog", (c, v) => WithSampling(c, s => s with { AudioChunkLogInterval = ParseInterval(v) })),
+        ("sampling.stateEvents", (c, v) => WithSampling(c, s => s with { StateEventInterval = ParseInterval(v) })),
+
+        ("limits.maxApPacketBytes", (c, v) => WithLimits(c, l => l with { MaxApPacketBytes = ParseInt(v, 1, ProtocolLimitsConfig.MaxApPacketSize) })),
+        ("limits.maxMessageBytes", (c, v) => WithLimits(c, l => l with { MaxMessageBytes = ParseInt(v, 1, int.MaxValue) })),
+        ("limits.maxParts", (c, v) => WithLimits(c, l => l with { MaxParts = ParseInt(v, 1, int.MaxValue) })),
+        ("limits.maxHeaders", (c, v) => WithLimits(c, l => l with { MaxHeaders = ParseInt(v, 1, int.MaxValue) })),
+        ("limits.maxJsonDepth", (c, v) => WithLimits(c, l => l with { MaxJsonDepth = ParseInt(v, 1, int.MaxValue) })),
+
+        ("player.quality", (c, v) => c with { Player = c.Player with { Quality = ParseEnum<AudioQuality>(v) } }),
+        ("player.normalization", (c, v) => c with { Player = c.Player with { NormalizationEnabled = ParseBool(v) } }),
+        ("player.prefetch", (c, v) => c with { Player = c.Player with { PrefetchEnabled = ParseBool(v) } }),
+        ("player.initialVolumePercent", (c, v) => c with { Player = c.Player with { InitialVolumePercent = ParseInt(v, 0, 100) } }),
+        ("player.blockedTracks", (c, v) => c with { Player = c.Player with { BlockedTracks = ParseList(v) } }),
+        ("player.blockedArtists", (c, v) => c with { Player = c.Player with { BlockedArtists = ParseList(v) } }),
+        ("player.maxTrackDuration", (c, v) => c with { Player = c.Player with { MaxTrackDuration = ParseInterval(v) } }),
+        ("player.skipLiveVersions", (c, v) => c with { Player = c.Player with { SkipLiveVersions = ParseBool(v) } }),
+        ("player.decoder", (c, v) => c with { Player = c.Player with { DecoderBackend = ParseEnum<AudioDecoderBackend>(v) } }),
+
+        ("cache.enabled", (c, v) => c with { Cache = c.Cache with { EnableCaching = ParseBool(v) } }),
+        ("cache.directory", (c, v) => c with { Cache = c.Cache with { CacheDirectory = ParseNonEmpty(v) } }),
+        ("cache.maxSizeBytes", (c, v) => c with { Cache = c.Cache with { MaxCacheSizeBytes = ParseLong(v, 0, long.MaxValue) } }),
+        ("cache.lockWait", (c, v) => c with { Cache = c.Cache with { LockWaitTimeout = ParseInterval(v) } }),
+
+        ("logging.level", (c, v) => c with { LogLevel = ParseEnum<LogLevel>(v) }),
+    ];
+
+    private static readonly Dictionary<string, Setter> ByKey =
+        Settings.ToDictionary(s => s.Key, s => s.Apply, StringComparer.OrdinalIgnoreCase);
+
+    /// <summary>
+    /// Gets every supported dotted key, in documentation order.
+    /// </summary>
+    public static IReadOnlyList<string> Keys { get; } = Settings.Select(s => s.Key).ToArray();
+
+    /// <summary>
+    /// Loads configuration over <paramref name="defaults"/>.
+    /// </summary>
+    /// <param name="defaults">Base values; must carry the host's device id.</param>
+    /// <param name="filePath">
+    /// JSON file to read. When null, <see cref="FileEnvironmentVariable"/> is consulted;
+    /// when that is unset too, <paramref name="defaultFilePath"/> is used if it exists.
+    /// </param>
+    /// <param name="environment">
+    /// Environment variables to read. Null reads the process environment.
+    /// </param>
+    /// <param name="defaultFilePath">
+    /// File read when nothing names one, typically <see cref="Storage.WaveePaths.ConfigFile"/>.
+    /// Unlike an explicit path, a missing default file is not an error.
+    /// </param>
+    /// <returns>The merged configuration.</returns>
+    /// <exception cref="ConfigException">
+    /// The file is missing or malformed, or a value is unknown or invalid.
+    /// </exception>
+    public static WaveeConfig Load(
+        WaveeConfig defaults,
+        string? filePath = null,
+        IDictionary? environment = null,
+        string? defaultFilePath = null)
+    {
+        ArgumentNullException.ThrowIfNull(defaults);
+        environment ??= Environment.GetEnvironmentVariables();
+
+        filePath ??= environment[FileEnvironmentVariable] as string;
+        if (string.IsNullOrWhiteSpace(filePath) && defaultFilePath is not null && File.Exists(defaultFilePath))
+            filePath = defaultFilePath;
+
+        var config = defaults;
+
+        if (!string.IsNullOrWhiteSpace(filePath))
+        {
+            if (!File.Exists(filePath))
+                throw new ConfigException(null, filePath, "does not exist");
+
+            config = ApplyJson(config, File.ReadAllText(filePath), filePath);
+        }
+
+        return ApplyEnvironment(config, environment);
+    }
+
+    /// <summary>
+    /// Gets the environment variable that overrides <paramref name="key"/>,
+    /// e.g. <c>network.apConnectTimeout</c> → <c>WAVEE_NETWORK_AP_CONNECT_TIMEOUT</c>.
+    /// </summary>
+    public static string GetEnvironmentVariableName(string key)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(key);
+
+        var builder = new StringBuilder(EnvironmentPrefix);
+        foreach (var ch in key)
+        {
+            if (ch == '.')
+                builder.Append('_');
+            else if (char.IsUpper(ch) && builder.Length > 0 && builder[^1] != '_')
+                builder.Append('_').Append(ch);
+            else
+                builder.Append(char.ToUpperInvariant(ch));
+        }
+        return builder.ToString();
+    }
+
+    internal static WaveeConfig ApplyJson(WaveeConfig config, string json, string source)
+    {
+        JsonDocument document;
+        try
+        {
+            document = JsonDocument.Parse(json, new JsonDocumentOptions
+            {
+                CommentHandling = JsonCommentHandling.Skip,
+                AllowTrailingCommas = true
+            });
+        }
+        catch (JsonException ex)
+        {
+            throw new ConfigException(null, source, $"is not valid JSON: {ex.Message}", ex);
+        }
+
+        using (document)
+        {
+            if (document.RootElement.ValueKind != JsonValueKind.Object)
+                throw new ConfigException(null, source, "must contain a JSON object");
+
+            var values = new List<(string Key, string Value)>();
+            Flatten(document.RootElement, prefix: null, values, source);
+
+            foreach (var (key, value) in values)
+            {
+                if (!ByKey.TryGetValue(key, out var apply))
+                    throw new ConfigException(key, source, "is not a known setting");
+
+                config = Apply(config, key, value, source, apply);
+            }
+        }
+
+        return config;
+    }
+
+    internal static WaveeConfig ApplyEnvironment(WaveeConfig config, IDictionary environment)
+    {
+        foreach (var (key, apply) in Settings)
+        {
+            var name = GetEnvironmentVariableName(key);
+            if (environment[name] is string value && value.Length > 0)
+                config = Apply(config, key, value, name, apply);
+        }
+
+        return config;
+    }
+
+    private static WaveeConfig Apply(WaveeConfig config, string key, string value, string source, Setter apply)
+    {
+        try
+        {
+            return apply(config, value.Trim());
+        }
+        catch (FormatException ex)
+        {
+            throw new ConfigException(key, source, ex.Message, ex);
+        }
+    }
+
+    private static void Flatten(JsonElement element, string? prefix, List<(string, string)> values, string source)
+    {
+        foreach (var property in element.EnumerateObject())
+        {
+            var key = prefix is null ? property.Name : $"{prefix}.{property.Name}";
+            switch (property.Value.ValueKind)
+            {
+                case JsonValueKind.Object:
+                    Flatten(property.Value, key, values, source);
+                    break;
+                case JsonValueKind.Array:
+                    throw new ConfigException(key, source, "cannot be an array");
+                case JsonValueKind.Null:
+                    // Explicit null keeps the default.
+                    break;
+                case JsonValueKind.String:
+                    values.Add((key, property.Value.GetString()!));
+                    break;
+                default:
+                    values.Add((key, property.Value.GetRawText()));
+                    break;
+            }
+        }
+    }
+
+    private static WaveeConfig WithNetwork(WaveeConfig config, Func<NetworkConfig, NetworkConfig> update) =>
+        config with { Session = config.Session with { Network = update(config.Session.Network) } };
+
+    private static WaveeConfig WithConnectDevice(WaveeConfig config, Func<ConnectDeviceConfig, ConnectDeviceConfig> update) =>
+        config with { Session = config.Session with { ConnectDevice = update(config.Session.ConnectDevice) } };
+
+    private static WaveeConfig WithSampling(WaveeConfig config, Func<SamplingConfig, SamplingConfig> update) =>
+        config with { Session = config.Session with { Sampling = update(config.Session.Sampling) } };
+
+    private static WaveeConfig WithLimits(WaveeConfig config, Func<ProtocolLimitsConfig, ProtocolLimitsConfig> update) =>
+        config with { Session = config.Session with { Limits = update(config.Session.Limits) } };
+
+    private static string ParseNonEmpty(string value) =>
+        value.Length > 0 ? value : throw new FormatException("must not be empty");
+
+    private static bool ParseBool(string value) => value.ToLowerInvariant() switch
+    {
+        "true" or "1" or "yes" or "on" => true,
+        "false" or "0" or "no" or "off" => false,
+        _ => throw new FormatException($"must be true or false, got '{value}'")
+    };
+
+    private static int ParseInt(string value, int min, int max) =>
+        (int)ParseLong(value, min, max);
+
+    private static long ParseLong(string value, long min, long max)
+    {
+        if (!long.TryParse(value, NumberStyles.Integer, CultureInfo.InvariantCulture, out var result))
+            throw new FormatException($"must be an integer, got '{value}'");
+        if (result < min || result > max)
+            throw new FormatException($"must be between {min} and {max}, got {result}");
+        return result;
+    }
+
+    private static TimeSpan ParseTimeout(string value)
+    {
+        var result = ParseDuration(value, "00:00:30");
+
+        if (result <= TimeSpan.Zero)
+            throw new FormatException($"must be positive, got {value}");
+        return result;
+    }
+
+    private static TimeSpan ParseInterval(string value)
+    {
+        var result = ParseDuration(value, "00:00:05");
+
+        if (result < TimeSpan.Zero)
+            throw new FormatException($"must not be negative, got {value}");
+        return result;
+    }
+
+    // Seconds as a number, or a TimeSpan string. Out-of-range numbers (1e300, NaN) are
+    // validation errors rather than an OverflowException escaping the loader.
+    private static TimeSpan ParseDuration(string value, string example)
+    {
+        if (double.TryParse(value, NumberStyles.Float, CultureInfo.InvariantCulture, out var seconds))
+        {
+            try
+            {
+                return TimeSpan.FromSeconds(seconds);
+            }
+            catch (Exception ex) when (ex is OverflowException or ArgumentException)
+            {
+                throw new FormatException($"must be a finite number of seconds up to {TimeSpan.MaxValue.TotalSeconds:F0}, got {value}");
+            }
+        }
+
+        return TimeSpan.TryParse(value, CultureInfo.InvariantCulture, out var result)
+            ? result
+            : throw new FormatException($"must be seconds or a time span like {example}, got '{value}'");
+    }
+
+    private static IPAddress ParseAddress(string value) =>
+        IPAddress.TryParse(value, out var address)
+            ? address
+            : throw new FormatException($"must be an IPv4 or IPv6 address, got '{value}'");
+
+    // Comma-separated, since arrays don't map onto a single environment variable.
+    private static string[] ParseList(string value) =>
+        value.Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
+
+    private static string[] ParsePins(string value)
+    {
+        var pins = ParseList(value);
+        foreach (var pin in pins)
+        {
+            if (!CertificatePinning.TryNormalizePin(pin, out _))
+                throw new FormatException($"must be comma-separated base64 SHA-256 hashes, got '{pin}'");
+        }
+        return pins;
+    }
+
+    private static T ParseEnum<T>(string value) where T : struct, Enum
+    {
+        // Names only: Enum.TryParse would also accept arbitrary numbers.
+        foreach (var name in Enum.GetNames<T>())
+        {
+            if (string.Equals(name, value, StringComparison.OrdinalIgnoreCase))
+                return Enum.Parse<T>(name);
+        }
+        throw new FormatException($"must be one of {string.Join(", ", Enum.GetNames<T>())}, got '{value}'");
+    }
+}
@@ -2,6 +2,7 @@ using System.Buffers;
 using System.Buffers.Binary;
 using Microsoft.Extensions.Logging;
 using Wavee.Core.Crypto;
+using Wavee.Core.Session;
 
 namespace Wavee.Core.Connection;
 
@@ -27,9 +28,20 @@ public sealed class ApCodec : IDisposable
     private readonly ShannonCipher _encodeCipher;
     private readonly ShannonCipher _decodeCipher;
     private readonly ILogger? _logger;
+    private readonly int _maxPayloadSize;
     private uint _encodeNonce;
     private uint _decodeNonce;
 
+    /// <summary>
+    /// Number of packets encoded so far (the next send nonce).
+    /// </summary>
+    public uint EncodeNonce => _encodeNonce;
+
+    /// <summary>
+    /// Number of packets decoded so far (the next receive nonce).
+    /// </summary>
+    public uint DecodeNonce => _decodeNonce;
+
     // Decode state machine
     private DecodeState _state;
     private byte _pendingCommand;
@@ -41,12 +53,23 @@ public sealed class ApCodec : IDisposable
     /// <param name="sendKey">32-byte key for encoding (sending) packets.</param>
     /// <param name="receiveKey">32-byte key for decoding (receiving) packets.</param>
     /// <param name="logger">Optional logger for diagnostic output.</param>
+    /// <param name="maxPayloadSize">
+    /// Largest payload accepted by <see cref="TryDecode"/>; a header announcing more fails the decode.
+    /// </param>
     /// <exception cref="ArgumentException">Thrown if keys are not 32 bytes.</exception>
-    public ApCodec(ReadOnlySpan<byte> sendKey, ReadOnlySpan<byte> receiveKey, ILogger? logger = null)
+    public ApCodec(
+        ReadOnlySpan<byte> sendKey,
+        ReadOnlySpan<byte> receiveKey,
+        ILogger? logger = null,
+        int maxPayloadSize = ProtocolLimitsConfig.MaxApPacketSize)
     {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxPayloadSize);
+        ArgumentOutOfRangeException.ThrowIfGreaterThan(maxPayloadSize, ProtocolLimitsConfig.MaxApPacketSize);
+
         _encodeCipher = new ShannonCipher(sendKey);
         _decodeCipher = new ShannonCipher(receiveKey);
         _logger = logger;
+        _maxPayloadSize = maxPayloadSize;
         _encodeNonce = 0;
         _decodeNonce = 0;
         _state = DecodeState.Header;
@@ -140,6 +163,13 @@ public sealed class ApCodec : IDisposable
             // Parse header
             _pendingCommand = header[0];
             _pendingPayloadSize = BinaryPrimitives.ReadUInt16BigEndian(header[1..]);
+            if (_pendingPayloadSize > _maxPayloadSize)
+            {
+                _logger?.LogWarning("Rejecting oversized packet (command=0x{Command:X2}, payload={PayloadSize} bytes, limit={Limit})",
+                    _pendingCommand, _pendingPayloadSize, _maxPayloadSize);
+                throw new ApCodecException(
+                    $"Packet payload of {_pendingPayloadSize} bytes exceeds the {_maxPayloadSize}-byte limit (limits.maxApPacketBytes)");
+            }
 
             reader.Advance(HeaderSize);
             _state = DecodeState.Payload;
@@ -173,7 +203,7 @@ public sealed class ApCodec : IDisposable
             if (!reader.TryCopyTo(receivedMac))
                 throw new ApCodecException("Failed to read MAC from buffer sequence");
 
-            if (!receivedMac.SequenceEqual(expectedMac))
+            if (!ConstantTime.BytesEqual(receivedMac, expectedMac))
             {
                 _logger?.LogWarning("MAC verification failed for packet (command=0x{Command:X2}, payload={PayloadSize} bytes)",
                     _pendingCommand, _pendingPayloadSize);
@@ -199,8 +229,9 @@ public sealed class ApCodec : IDisposable
     /// </summary>
     public void Dispose()
     {
-        // ShannonCipher does not implement IDisposable
-        // No resources to dispose
+        // Zero the Shannon key schedules for both directions.
+        _encodeCipher.Dispose();
+        _decodeCipher.Dispose();
     }
 
     private enum DecodeState
@@ -69,6 +69,16 @@ public sealed class ApTransport : IApTransport
         return new ApTransport(stream, codec, logger);
     }
 
+    /// <summary>
+    /// Current send nonce of the underlying codec.
+    /// </summary>
+    public uint EncodeNonce => _codec.EncodeNonce;
+
+    /// <summary>
+    /// Current receive nonce of the underlying codec.
+    /// </summary>
+    public uint DecodeNonce => _codec.DecodeNonce;
+
     /// <summary>
     /// Sends a packet to the Spotify server.
     /// </summary>
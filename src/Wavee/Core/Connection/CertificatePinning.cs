@@ -0,0 +1,123 @@
+using System.Net.Security;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Connection;
+
+/// <summary>
+/// Checks TLS connections to Spotify hosts against <see cref="NetworkConfig.TlsPins"/>.
+/// </summary>
+/// <remarks>
+/// Installed on the dealer WebSocket handler by the session. The spclient/login5 handler
+/// belongs to the host's <c>"Wavee"</c> HTTP client, so hosts must build it through
+/// <see cref="Apply"/> (next to <see cref="NetworkBinding.Apply"/>) for pinning to cover it.
+/// Only hosts under <c>spotify.com</c> are pinned; image and audio CDNs are third-party and
+/// keep plain CA validation.
+/// </remarks>
+public static class CertificatePinning
+{
+    private const string PinPrefix = "sha256/";
+
+    /// <summary>
+    /// Gets whether the config turns pinning on.
+    /// </summary>
+    public static bool IsEnabled(NetworkConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+        return config.TlsPinning != TlsPinningMode.Off && config.TlsPins.Count > 0;
+    }
+
+    /// <summary>
+    /// Installs the pin check on <paramref name="handler"/>. Leaves the handler untouched
+    /// when pinning is off.
+    /// </summary>
+    /// <returns>The same handler, for chaining.</returns>
+    public static SocketsHttpHandler Apply(SocketsHttpHandler handler, NetworkConfig config, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(handler);
+        if (!IsEnabled(config))
+            return handler;
+
+        handler.SslOptions.RemoteCertificateValidationCallback = CreateValidator(config, logger);
+        return handler;
+    }
+
+    /// <summary>
+    /// Creates a validation callback that requires a clean CA chain and, for Spotify hosts,
+    /// a pinned key somewhere in it.
+    /// </summary>
+    public static RemoteCertificateValidationCallback CreateValidator(NetworkConfig config, ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var pins = config.TlsPins
+            .Select(p => TryNormalizePin(p, out var pin) ? pin : null)
+            .OfType<string>()
+            .ToHashSet(StringComparer.Ordinal);
+        var enforce = config.TlsPinning == TlsPinningMode.Enforce;
+
+        return (sender, certificate, chain, errors) =>
+        {
+            if (errors != SslPolicyErrors.None)
+                return false;
+
+            var host = (sender as SslStream)?.TargetHostName;
+            if (host is not null && !IsPinnedHost(host))
+                return true;
+
+            var presented = GetChainPins(certificate, chain);
+            if (presented.Any(pins.Contains))
+                return true;
+
+            logger?.LogWarning(
+                "TLS pin mismatch for {Host} ({Mode}); chain keys: {Pins}",
+                host ?? "(unknown host)",
+                enforce ? "rejecting" : "report only",
+                string.Join(", ", presented.Select(p => PinPrefix + p)));
+            return !enforce;
+        };
+    }
+
+    /// <summary>
+    /// Computes the pin for <paramref name="certificate"/>: base64 SHA-256 of its
+    /// SubjectPublicKeyInfo, without the <c>sha256/</c> prefix.
+    /// </summary>
+    public static string ComputePin(X509Certificate2 certificate)
+    {
+        ArgumentNullException.ThrowIfNull(certificate);
+        return Convert.ToBase64String(SHA256.HashData(certificate.PublicKey.ExportSubjectPublicKeyInfo()));
+    }
+
+    /// <summary>
+    /// Strips the optional <c>sha256/</c> prefix and checks the rest is a base64 SHA-256 hash.
+    /// </summary>
+    internal static bool TryNormalizePin(string value, out string pin)
+    {
+        pin = value.Trim();
+        if (pin.StartsWith(PinPrefix, StringComparison.OrdinalIgnoreCase))
+            pin = pin[PinPrefix.Length..];
+
+        Span<byte> hash = stackalloc byte[SHA256.HashSizeInBytes + 1];
+        return Convert.TryFromBase64String(pin, hash, out var written) && written == SHA256.HashSizeInBytes;
+    }
+
+    internal static bool IsPinnedHost(string host)
+        => host.Equals("spotify.com", StringComparison.OrdinalIgnoreCase)
+           || host.EndsWith(".spotify.com", StringComparison.OrdinalIgnoreCase);
+
+    internal static List<string> GetChainPins(X509Certificate? certificate, X509Chain? chain)
+    {
+        var pins = new List<string>();
+        if (chain is not null)
+        {
+            foreach (var element in chain.ChainElements)
+                pins.Add(ComputePin(element.Certificate));
+        }
+
+        if (pins.Count == 0 && certificate is X509Certificate2 leaf)
+            pins.Add(ComputePin(leaf));
+        return pins;
+    }
+}
@@ -7,18 +7,23 @@ namespace Wavee.Core.Connection;
 /// Diffie-Hellman key pair for Spotify handshake.
 /// Uses 768-bit DH (Oakley Group 1) with Spotify's parameters.
 /// </summary>
-public sealed class DiffieHellmanKeys
+/// <remarks>
+/// The private exponent is held as raw bytes (not a <see cref="BigInteger"/>, which is
+/// immutable and can't be wiped) and zeroed on <see cref="Dispose"/>. The transient
+/// BigInteger built inside <see cref="ComputeSharedSecret"/> is short-lived and
+/// unreachable once the call returns.
+/// </remarks>
+public sealed class DiffieHellmanKeys : IDisposable
 {
     private const int PrivateKeySize = 95; // 95 bytes for private key
 
-    private readonly BigInteger _privateKey;
-    private readonly BigInteger _publicKey;
+    private readonly byte[] _privateKeyBytes;
     private readonly byte[] _publicKeyBytes;
+    private bool _disposed;
 
-    private DiffieHellmanKeys(BigInteger privateKey, BigInteger publicKey, byte[] publicKeyBytes)
+    private DiffieHellmanKeys(byte[] privateKeyBytes, byte[] publicKeyBytes)
     {
-        _privateKey = privateKey;
-        _publicKey = publicKey;
+        _privateKeyBytes = privateKeyBytes;
         _publicKeyBytes = publicKeyBytes;
     }
 
@@ -30,12 +35,19 @@ public sealed class DiffieHellmanKeys
     /// <summary>
     /// Generates a new random Diffie-Hellman key pair.
     /// </summary>
+    /// <param name="rng">
+    /// Randomness source. Null uses the system CSPRNG; tests inject a seeded generator
+    /// to reproduce handshake vectors byte-for-byte.
+    /// </param>
     /// <returns>A new <see cref="DiffieHellmanKeys"/> instance with randomly generated keys.</returns>
-    public static DiffieHellmanKeys GenerateRandom()
+    public static DiffieHellmanKeys GenerateRandom(RandomNumberGenerator? rng = null)
     {
         // Generate 95 random bytes for the private key (same as librespot)
-        Span<byte> privateKeyBytes = stackalloc byte[PrivateKeySize];
-        RandomNumberGenerator.Fill(privateKeyBytes);
+        var privateKeyBytes = new byte[PrivateKeySize];
+        if (rng is null)
+            RandomNumberGenerator.Fill(privateKeyBytes);
+        else
+            rng.GetBytes(privateKeyBytes);
 
         // Convert to BigInteger (little-endian for .NET BigInteger)
         var privateKey = new BigInteger(privateKeyBytes, isUnsigned: true, isBigEndian: false);
@@ -50,7 +62,7 @@ public sealed class DiffieHellmanKeys
         // Convert public key to big-endian byte array (Spotify protocol format)
         var publicKeyBytes = publicKey.ToByteArray(isUnsigned: true, isBigEndian: true);
 
-        return new DiffieHellmanKeys(privateKey, publicKey, publicKeyBytes);
+        return new DiffieHellmanKeys(privateKeyBytes, publicKeyBytes);
     }
 
     /// <summary>
@@ -61,6 +73,8 @@ public sealed class DiffieHellmanKeys
     /// <exception cref="ArgumentException">Thrown if the remote public key is invalid.</exception>
     public byte[] ComputeSharedSecret(ReadOnlySpan<byte> remotePublicKey)
     {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
         if (remotePublicKey.IsEmpty)
             throw new ArgumentException("Remote public key cannot be empty", nameof(remotePublicKey));
 
@@ -69,9 +83,22 @@ public sealed class DiffieHellmanKeys
         var prime = new BigInteger(ConnectionConstants.DiffieHellmanPrime, isUnsigned: true, isBigEndian: true);
 
         // Compute shared secret: remote_public^private mod p
-        var sharedSecret = BigInteger.ModPow(remotePubKey, _privateKey, prime);
+        var privateKey = new BigInteger(_privateKeyBytes, isUnsigned: true, isBigEndian: false);
+        var sharedSecret = BigInteger.ModPow(remotePubKey, privateKey, prime);
 
         // Convert to big-endian byte array
         return sharedSecret.ToByteArray(isUnsigned: true, isBigEndian: true);
     }
+
+    /// <summary>
+    /// Zeroes the private key. The key pair is unusable afterwards.
+    /// </summary>
+    public void Dispose()
+    {
+        if (_disposed)
+            return;
+
+        CryptographicOperations.ZeroMemory(_privateKeyBytes);
+        _disposed = true;
+    }
 }
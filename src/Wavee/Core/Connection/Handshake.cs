@@ -6,6 +6,7 @@ using System.Security.Cryptography;
 using Google.Protobuf;
 using Microsoft.Extensions.Logging;
 using Wavee.Core;
+using Wavee.Core.Session;
 using Wavee.Protocol;
 
 namespace Wavee.Core.Connection;
@@ -35,28 +36,39 @@ public static class Handshake
     /// <param name="stream">Connected network stream to Spotify AP.</param>
     /// <param name="logger">Optional logger for diagnostic output.</param>
     /// <param name="cancellationToken">Cancellation token.</param>
+    /// <param name="rng">
+    /// Randomness source for the DH private key and client nonce. Null uses the system
+    /// CSPRNG; only tests should pass a seeded generator.
+    /// </param>
+    /// <param name="limits">
+    /// Bounds the server response and the packets the resulting codec accepts. Null uses the defaults.
+    /// </param>
     /// <returns>Configured ApTransport ready for sending/receiving packets.</returns>
     /// <exception cref="HandshakeException">Thrown if handshake fails or server verification fails.</exception>
     public static async Task<ApTransport> PerformHandshakeAsync(
         Stream stream,
         ILogger? logger = null,
-        CancellationToken cancellationToken = default)
+        CancellationToken cancellationToken = default,
+        RandomNumberGenerator? rng = null,
+        ProtocolLimitsConfig? limits = null)
     {
+        limits ??= new ProtocolLimitsConfig();
+
         try
         {
             logger?.LogDebug("Handshake starting");
 
             // Generate DH key pair
-            var localKeys = DiffieHellmanKeys.GenerateRandom();
+            using var localKeys = DiffieHellmanKeys.GenerateRandom(rng);
             logger?.LogTrace("Generated DH public key ({KeyLength} bytes)", localKeys.PublicKey.Length);
 
             // Send ClientHello and accumulate packets for key derivation
             logger?.LogDebug("Sending ClientHello");
-            var accumulator = await SendClientHelloAsync(stream, localKeys.PublicKey, cancellationToken);
+            var accumulator = await SendClientHelloAsync(stream, localKeys.PublicKey, rng, cancellationToken);
 
             // Receive server response
             logger?.LogDebug("Receiving server response");
-            var response = await ReceiveServerResponseAsync(stream, accumulator, cancellationToken);
+            var response = await ReceiveServerResponseAsync(stream, accumulator, limits.MaxApPacketBytes, cancellationToken);
             logger?.LogDebug("Received server response ({ServerKeyLength} bytes)", response.Gs.Length);
 
             // Verify server signature to prevent MITM attacks
@@ -67,6 +79,7 @@ public static class Handshake
             // Compute shared secret and derive keys
             var sharedSecret = localKeys.ComputeSharedSecret(response.Gs);
             var (challenge, sendKey, receiveKey) = DeriveKeys(sharedSecret, accumulator);
+            CryptographicOperations.ZeroMemory(sharedSecret);
             logger?.LogTrace("Derived encryption keys (send={SendKeyLength} bytes, receive={ReceiveKeyLength} bytes)",
                 sendKey.Length, receiveKey.Length);
 
@@ -74,8 +87,11 @@ public static class Handshake
             logger?.LogDebug("Sending ClientResponsePlaintext");
             await SendClientResponseAsync(stream, challenge, cancellationToken);
 
-            // Create codec and wrap in transport
-            var codec = new ApCodec(sendKey, receiveKey, logger);
+            // Create codec and wrap in transport. The Shannon ciphers keep their own
+            // key schedule, so the raw key copies can be wiped right away.
+            var codec = new ApCodec(sendKey, receiveKey, logger, limits.MaxApPacketBytes);
+            CryptographicOperations.ZeroMemory(sendKey);
+            CryptographicOperations.ZeroMemory(receiveKey);
             var transport = ApTransport.Create(stream, codec, logger);
 
             logger?.LogInformation("Handshake complete, connection encrypted");
@@ -96,11 +112,15 @@ public static class Handshake
     private static async Task<List<byte>> SendClientHelloAsync(
         Stream stream,
         ReadOnlyMemory<byte> publicKey,
+        RandomNumberGenerator? rng,
         CancellationToken cancellationToken)
     {
         // Generate random client nonce
         var clientNonce = new byte[ClientNonceSize];
-        RandomNumberGenerator.Fill(clientNonce);
+        if (rng is null)
+            RandomNumberGenerator.Fill(clientNonce);
+        else
+            rng.GetBytes(clientNonce);
 
         // Determine platform
         var platform = GetPlatform();
@@ -166,6 +186,7 @@ public static class Handshake
     private static async Task<ServerResponse> ReceiveServerResponseAsync(
         Stream stream,
         List<byte> accumulator,
+        int maxSize,
         CancellationToken cancellationToken)
     {
         // Use PipeReader for efficient async I/O
@@ -175,6 +196,9 @@ public static class Handshake
             // Read size (4 bytes)
             var sizeBuffer = await ReadExactBytesAsync(reader, 4, accumulator, cancellationToken);
             var size = BinaryPrimitives.ReadUInt32BigEndian(sizeBuffer);
+            if (size < 4 || size - 4 > (uint)maxSize)
+                throw new HandshakeException(HandshakeReason.ProtocolError,
+                    $"Server response length {size} is outside 4..{(long)maxSize + 4} (limits.maxApPacketBytes)");
 
             // Read message (size - 4 bytes)
             var messageBuffer = await ReadExactBytesAsync(reader, (int)(size - 4), accumulator, cancellationToken);
@@ -356,6 +380,7 @@ public static class Handshake
             // Extract keys
             var sendKey = data[20..52];
             var receiveKey = data[52..84];
+            CryptographicOperations.ZeroMemory(data);
 
             return (challenge, sendKey, receiveKey);
         }
@@ -0,0 +1,124 @@
+using System.Net;
+using System.Net.Sockets;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Connection;
+
+/// <summary>
+/// Opens outgoing TCP connections honouring <see cref="NetworkConfig.LocalAddress"/> and
+/// <see cref="NetworkConfig.AddressFamily"/>.
+/// </summary>
+/// <remarks>
+/// Used directly for the Access Point socket, and as the connect callback of the HTTP
+/// handlers behind spclient, login5 and the dealer WebSocket, so every session transport
+/// leaves through the same interface.
+/// </remarks>
+public static class NetworkBinding
+{
+    /// <summary>
+    /// Gets whether the config asks for anything beyond the OS defaults.
+    /// </summary>
+    public static bool IsCustomized(NetworkConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+        return config.LocalAddress is not null || config.AddressFamily != AddressFamilyPreference.Any;
+    }
+
+    /// <summary>
+    /// Installs a connect callback on <paramref name="handler"/> that binds and filters
+    /// like <see cref="ConnectAsync"/>. Leaves the handler untouched when the config uses
+    /// OS defaults.
+    /// </summary>
+    /// <returns>The same handler, for chaining.</returns>
+    public static SocketsHttpHandler Apply(SocketsHttpHandler handler, NetworkConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(handler);
+        if (!IsCustomized(config))
+            return handler;
+
+        handler.ConnectCallback = async (context, ct) =>
+        {
+            var socket = await ConnectAsync(context.DnsEndPoint.Host, context.DnsEndPoint.Port, config, ct);
+            return new NetworkStream(socket, ownsSocket: true);
+        };
+        return handler;
+    }
+
+    /// <summary>
+    /// Resolves <paramref name="host"/>, orders the addresses by the configured family
+    /// preference and connects to the first one that accepts, from the configured local
+    /// address when one is set.
+    /// </summary>
+    /// <returns>A connected socket. The caller owns it.</returns>
+    /// <exception cref="SocketException">No candidate address accepted the connection.</exception>
+    public static async Task<Socket> ConnectAsync(
+        string host,
+        int port,
+        NetworkConfig config,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(host);
+        ArgumentNullException.ThrowIfNull(config);
+
+        var addresses = IPAddress.TryParse(host, out var literal)
+            ? [literal]
+            : await Dns.GetHostAddressesAsync(host, cancellationToken);
+
+        var candidates = OrderCandidates(addresses, config);
+        if (candidates.Count == 0)
+        {
+            throw new SocketException(
+                (int)SocketError.AddressFamilyNotSupported,
+                $"{host} has no address usable with family preference {config.AddressFamily}" +
+                (config.LocalAddress is null ? string.Empty : $" and local address {config.LocalAddress}"));
+        }
+
+        SocketException? lastError = null;
+        foreach (var address in candidates)
+        {
+            var socket = new Socket(address.AddressFamily, SocketType.Stream, ProtocolType.Tcp) { NoDelay = true };
+            try
+            {
+                if (config.LocalAddress is not null)
+                    socket.Bind(new IPEndPoint(config.LocalAddress, 0));
+
+                await socket.ConnectAsync(new IPEndPoint(address, port), cancellationToken);
+                return socket;
+            }
+            catch (SocketException ex)
+            {
+                socket.Dispose();
+                lastError = ex;
+            }
+            catch
+            {
+                socket.Dispose();
+                throw;
+            }
+        }
+
+        throw lastError!;
+    }
+
+    /// <summary>
+    /// Filters resolved addresses to those the config allows and puts the preferred
+    /// family first, keeping DNS order within each family.
+    /// </summary>
+    internal static List<IPAddress> OrderCandidates(IEnumerable<IPAddress> addresses, NetworkConfig config)
+    {
+        var usable = addresses
+            .Select(a => a.IsIPv4MappedToIPv6 ? a.MapToIPv4() : a)
+            .Where(a => a.AddressFamily is AddressFamily.InterNetwork or AddressFamily.InterNetworkV6)
+            .Where(a => config.LocalAddress is null || a.AddressFamily == config.LocalAddress.AddressFamily)
+            .Distinct();
+
+        return config.AddressFamily switch
+        {
+            AddressFamilyPreference.IPv4Only => usable.Where(a => a.AddressFamily == AddressFamily.InterNetwork).ToList(),
+            AddressFamilyPreference.IPv6Only => usable.Where(a => a.AddressFamily == AddressFamily.InterNetworkV6).ToList(),
+            AddressFamilyPreference.PreferIPv4 => usable.OrderBy(a => a.AddressFamily == AddressFamily.InterNetwork ? 0 : 1).ToList(),
+            AddressFamilyPreference.PreferIPv6 => usable.OrderBy(a => a.AddressFamily == AddressFamily.InterNetworkV6 ? 0 : 1).ToList(),
+            _ => usable.ToList()
+        };
+    }
+}
@@ -0,0 +1,206 @@
+using System.Net;
+using System.Net.NetworkInformation;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Core.Connection;
+
+/// <summary>
+/// Watches the machine's network path and raises <see cref="NetworkChanged"/> when it
+/// changes (Wi-Fi roam to another network, VPN up/down, Ethernet plugged or unplugged).
+/// </summary>
+/// <remarks>
+/// Long-lived sockets bound to an address that no longer routes don't fail until TCP
+/// gives up, which can take minutes. Listeners use this to re-establish them right away.
+///
+/// OS address-change notifications are used where the platform supports them, with a
+/// polling fallback otherwise. Notifications arrive in bursts while an interface comes
+/// up, so they are debounced, and the event only fires when the set of usable
+/// interfaces, their addresses or their gateways actually differs from before.
+/// </remarks>
+public sealed class NetworkChangeMonitor : IDisposable
+{
+    /// <summary>Quiet period after the last OS notification before the path is re-read.</summary>
+    public static readonly TimeSpan DefaultDebounce = TimeSpan.FromSeconds(2);
+
+    /// <summary>Poll interval when the platform has no change notifications.</summary>
+    public static readonly TimeSpan DefaultPollInterval = TimeSpan.FromSeconds(10);
+
+    private readonly ILogger? _logger;
+    private readonly TimeSpan _debounce;
+    private readonly object _lock = new();
+    private readonly Timer _debounceTimer;
+    private readonly Timer? _pollTimer;
+    private readonly bool _subscribed;
+    private string _signature;
+    private bool _disposed;
+
+    /// <summary>
+    /// Raised on a thread-pool thread after the network path changed.
+    /// </summary>
+    public event EventHandler<NetworkChangedEventArgs>? NetworkChanged;
+
+    /// <summary>
+    /// Starts watching the network path.
+    /// </summary>
+    /// <param name="logger">Optional logger for diagnostic output.</param>
+    /// <param name="debounce">Override for <see cref="DefaultDebounce"/>.</param>
+    /// <param name="pollInterval">Override for <see cref="DefaultPollInterval"/>.</param>
+    public NetworkChangeMonitor(ILogger? logger = null, TimeSpan? debounce = null, TimeSpan? pollInterval = null)
+    {
+        _logger = logger;
+        _debounce = debounce ?? DefaultDebounce;
+        _signature = ReadNetworkSignature();
+        _debounceTimer = new Timer(_ => Evaluate(), null, Timeout.Infinite, Timeout.Infinite);
+
+        try
+        {
+            NetworkChange.NetworkAddressChanged += OnNetworkAddressChanged;
+            NetworkChange.NetworkAvailabilityChanged += OnNetworkAvailabilityChanged;
+            _subscribed = true;
+        }
+        catch (Exception ex) when (ex is PlatformNotSupportedException or NetworkInformationException)
+        {
+            var interval = pollInterval ?? DefaultPollInterval;
+            _logger?.LogDebug(ex, "Network change notifications unavailable; polling every {Interval}s", interval.TotalSeconds);
+            _pollTimer = new Timer(_ => Evaluate(), null, interval, interval);
+        }
+    }
+
+    /// <summary>
+    /// Gets whether any usable (up, non-loopback, addressed) interface exists.
+    /// </summary>
+    public bool IsNetworkAvailable
+    {
+        get
+        {
+            lock (_lock)
+            {
+                return _signature.Length > 0;
+            }
+        }
+    }
+
+    private void OnNetworkAddressChanged(object? sender, EventArgs e) => ScheduleEvaluate();
+
+    private void OnNetworkAvailabilityChanged(object? sender, NetworkAvailabilityEventArgs e) => ScheduleEvaluate();
+
+    private void ScheduleEvaluate()
+    {
+        lock (_lock)
+        {
+            if (!_disposed)
+                _debounceTimer.Change(_debounce, Timeout.InfiniteTimeSpan);
+        }
+    }
+
+    private void Evaluate()
+    {
+        var current = ReadNetworkSignature();
+        lock (_lock)
+        {
+            if (_disposed || string.Equals(current, _signature, StringComparison.Ordinal))
+                return;
+            _signature = current;
+        }
+
+        var available = current.Length > 0;
+        _logger?.LogInformation("Network path changed (available={Available})", available);
+
+        try
+        {
+            NetworkChanged?.Invoke(this, new NetworkChangedEventArgs(available));
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogError(ex, "NetworkChanged subscriber threw");
+        }
+    }
+
+    /// <summary>
+    /// Builds a stable description of the usable interfaces, their unicast addresses
+    /// and gateways. Empty when no interface is usable.
+    /// </summary>
+    internal static string ReadNetworkSignature()
+    {
+        try
+        {
+            var parts = new List<string>();
+            foreach (var nic in NetworkInterface.GetAllNetworkInterfaces())
+            {
+                if (nic.OperationalStatus != OperationalStatus.Up ||
+                    nic.NetworkInterfaceType == NetworkInterfaceType.Loopback)
+                    continue;
+
+                var properties = nic.GetIPProperties();
+                var addresses = properties.UnicastAddresses
+                    .Select(a => a.Address)
+                    .Where(a => !IPAddress.IsLoopback(a) && !a.IsIPv6LinkLocal)
+                    .Select(a => a.ToString())
+                    .Order(StringComparer.Ordinal)
+                    .ToList();
+                if (addresses.Count == 0)
+                    continue;
+
+                IEnumerable<string> gateways;
+                try
+                {
+                    gateways = properties.GatewayAddresses.Select(g => g.Address.ToString()).Order(StringComparer.Ordinal);
+                }
+                catch (PlatformNotSupportedException)
+                {
+                    gateways = [];
+                }
+
+                parts.Add($"{nic.Id}={string.Join(',', addresses)}|{string.Join(',', gateways)}");
+            }
+
+            parts.Sort(StringComparer.Ordinal);
+            return string.Join(';', parts);
+        }
+        catch (NetworkInformationException)
+        {
+            return string.Empty;
+        }
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _disposed = true;
+        }
+
+        if (_subscribed)
+        {
+            NetworkChange.NetworkAddressChanged -= OnNetworkAddressChanged;
+            NetworkChange.NetworkAvailabilityChanged -= OnNetworkAvailabilityChanged;
+        }
+
+        _debounceTimer.Dispose();
+        _pollTimer?.Dispose();
+    }
+}
+
+/// <summary>
+/// Event arguments for <see cref="NetworkChangeMonitor.NetworkChanged"/>.
+/// </summary>
+public sealed class NetworkChangedEventArgs : EventArgs
+{
+    /// <summary>
+    /// Whether a usable network exists after the change. False means connectivity was
+    /// lost; reconnecting is pointless until a later change reports true.
+    /// </summary>
+    public bool IsAvailable { get; }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="NetworkChangedEventArgs"/> class.
+    /// </summary>
+    /// <param name="isAvailable">Whether a usable network exists.</param>
+    public NetworkChangedEventArgs(bool isAvailable)
+    {
+        IsAvailable = isAvailable;
+    }
+}
@@ -1,6 +1,4 @@
-using System.Runtime.CompilerServices;
-using System.Runtime.InteropServices;
-using System.Security.Cryptography;
+using Wavee.Playback.Contracts;
 
 namespace Wavee.Core.Crypto;
 
@@ -8,23 +6,14 @@ namespace Wavee.Core.Crypto;
 /// AES-128-CTR decryption stream for Spotify audio files.
 /// Wraps an encrypted audio source and provides transparent decryption with seeking support.
 /// Pass null key for unencrypted pass-through mode.
+/// The keystream comes from <see cref="AesCtrKeystream"/>, which uses AES-NI / ARMv8 crypto when present.
 /// </summary>
 public sealed class AudioDecryptStream : Stream
 {
-    private static readonly byte[] AudioAesIv =
-    [
-        0x72, 0xe0, 0x67, 0xfb, 0xdd, 0xcb, 0xcf, 0x77,
-        0xeb, 0xe8, 0xbc, 0x64, 0x3f, 0x63, 0x0d, 0x93
-    ];
-
-    private const int AesBlockSize = 16;
-
     private readonly Stream _baseStream;
-    private readonly Aes? _aes;
+    private readonly AesCtrKeystream? _keystream;
     private readonly long _decryptionStartOffset;
-    private readonly byte[] _keystreamBlock = new byte[AesBlockSize];
     private long _position;
-    private long _keystreamBlockPosition = -1;
     private bool _disposed;
 
     public AudioDecryptStream(byte[]? key, Stream baseStream, long decryptionStartOffset = 0)
@@ -43,15 +32,14 @@ public sealed class AudioDecryptStream : Stream
         if (key is null)
             return;
 
-        if (key.Length != 16)
-            throw new ArgumentException("AES-128 key must be exactly 16 bytes", nameof(key));
-
-        _aes = Aes.Create();
-        _aes.KeySize = 128;
-        _aes.Key = key;
-        _aes.Padding = PaddingMode.None;
+        _keystream = new AesCtrKeystream(key);
     }
 
+    /// <summary>
+    /// AES implementation in use, or null in pass-through mode.
+    /// </summary>
+    public AesBackend? Backend => _keystream?.Backend;
+
     public override bool CanRead => true;
     public override bool CanSeek => _baseStream.CanSeek;
     public override bool CanWrite => false;
@@ -73,7 +61,7 @@ public sealed class AudioDecryptStream : Stream
         if (buffer.Length == 0)
             return 0;
 
-        if (_aes is null)
+        if (_keystream is null)
         {
             var bytesRead = _baseStream.Read(buffer);
             _position += bytesRead;
@@ -93,7 +81,7 @@ public sealed class AudioDecryptStream : Stream
             var decryptStart = Math.Max(_position, _decryptionStartOffset);
             var skipBytes = (int)(decryptStart - _position);
             var decryptLength = totalRead - skipBytes;
-            DecryptCtr(buffer.Slice(skipBytes, decryptLength), decryptStart);
+            _keystream.Apply(buffer.Slice(skipBytes, decryptLength), decryptStart);
         }
 
         _position += totalRead;
@@ -126,79 +114,6 @@ public sealed class AudioDecryptStream : Stream
     public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
     public override void Flush() { }
 
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private void DecryptCtr(Span<byte> buffer, long streamPosition)
-    {
-        if (_aes is null)
-            return;
-
-        var currentPosition = streamPosition;
-        var bufferOffset = 0;
-
-        while (bufferOffset < buffer.Length)
-        {
-            var blockIndex = currentPosition / AesBlockSize;
-            var offsetInBlock = (int)(currentPosition % AesBlockSize);
-
-            if (_keystreamBlockPosition != blockIndex)
-            {
-                GenerateKeystreamBlock(blockIndex, _keystreamBlock);
-                _keystreamBlockPosition = blockIndex;
-            }
-
-            var bytesToProcess = Math.Min(buffer.Length - bufferOffset, AesBlockSize - offsetInBlock);
-            XorBytes(
-                buffer.Slice(bufferOffset, bytesToProcess),
-                MemoryMarshal.CreateReadOnlySpan(ref _keystreamBlock[offsetInBlock], bytesToProcess));
-
-            bufferOffset += bytesToProcess;
-            currentPosition += bytesToProcess;
-        }
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private static void XorBytes(Span<byte> dst, ReadOnlySpan<byte> key)
-    {
-        var i = 0;
-        var end = dst.Length - sizeof(ulong);
-        ref var dRef = ref MemoryMarshal.GetReference(dst);
-        ref var kRef = ref MemoryMarshal.GetReference(key);
-        while (i <= end)
-        {
-            var d = Unsafe.ReadUnaligned<ulong>(ref Unsafe.Add(ref dRef, i));
-            var k = Unsafe.ReadUnaligned<ulong>(ref Unsafe.Add(ref kRef, i));
-            Unsafe.WriteUnaligned(ref Unsafe.Add(ref dRef, i), d ^ k);
-            i += sizeof(ulong);
-        }
-
-        for (; i < dst.Length; i++)
-            Unsafe.Add(ref dRef, i) ^= Unsafe.Add(ref kRef, i);
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private void GenerateKeystreamBlock(long blockIndex, Span<byte> output)
-    {
-        if (_aes is null)
-            return;
-
-        Span<byte> counterBlock = stackalloc byte[AesBlockSize];
-        AudioAesIv.CopyTo(counterBlock);
-        AddBigEndianSpan(counterBlock, blockIndex);
-        _aes.EncryptEcb(counterBlock, output, PaddingMode.None);
-    }
-
-    [MethodImpl(MethodImplOptions.AggressiveInlining)]
-    private static void AddBigEndianSpan(Span<byte> buffer, long value)
-    {
-        var carry = (ulong)value;
-        for (var i = AesBlockSize - 1; i >= 0 && carry > 0; i--)
-        {
-            var sum = buffer[i] + carry;
-            buffer[i] = (byte)sum;
-            carry = sum >> 8;
-        }
-    }
-
     protected override void Dispose(bool disposing)
     {
         if (_disposed)
@@ -206,7 +121,8 @@ public sealed class AudioDecryptStream : Stream
 
         if (disposing)
         {
-            _aes?.Dispose();
+            // Clears the expanded round keys.
+            _keystream?.Dispose();
             _baseStream.Dispose();
         }
 
@@ -219,7 +135,7 @@ public sealed class AudioDecryptStream : Stream
         if (_disposed)
             return;
 
-        _aes?.Dispose();
+        _keystream?.Dispose();
         await _baseStream.DisposeAsync().ConfigureAwait(false);
         _disposed = true;
         GC.SuppressFinalize(this);
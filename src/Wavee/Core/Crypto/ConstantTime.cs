@@ -0,0 +1,38 @@
+using System.Security.Cryptography;
+using System.Text;
+
+namespace Wavee.Core.Crypto;
+
+/// <summary>
+/// Constant-time equality for secret-dependent comparisons (MACs, challenge
+/// responses, CSRF state, integrity digests).
+/// </summary>
+/// <remarks>
+/// <see cref="MemoryExtensions.SequenceEqual{T}(ReadOnlySpan{T}, ReadOnlySpan{T})"/> and
+/// <c>string ==</c> return at the first differing byte, which leaks how much of a forged
+/// value was right. Route every such check through here; <c>ConstantTimeGuardTests</c>
+/// fails the build if a plain comparison creeps back into the crypto / auth folders.
+/// Lengths are not hidden — callers compare fixed-size tags, so that's not secret.
+/// </remarks>
+public static class ConstantTime
+{
+    /// <summary>
+    /// Compares two byte sequences in time that depends only on their length.
+    /// </summary>
+    public static bool BytesEqual(ReadOnlySpan<byte> left, ReadOnlySpan<byte> right)
+        => CryptographicOperations.FixedTimeEquals(left, right);
+
+    /// <summary>
+    /// Compares two strings by their UTF-8 bytes in time that depends only on their length.
+    /// Null only equals null.
+    /// </summary>
+    public static bool StringsEqual(string? left, string? right)
+    {
+        if (left is null || right is null)
+            return left is null && right is null;
+
+        return CryptographicOperations.FixedTimeEquals(
+            Encoding.UTF8.GetBytes(left),
+            Encoding.UTF8.GetBytes(right));
+    }
+}
@@ -1,6 +1,7 @@
 using System.Buffers.Binary;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
+using System.Security.Cryptography;
 
 namespace Wavee.Core.Crypto;
 
@@ -10,8 +11,15 @@ namespace Wavee.Core.Crypto;
 ///
 /// This cipher is used to encrypt/decrypt all packets after the initial handshake.
 /// Each packet uses a 4-byte big-endian nonce that is incremented after each packet.
+///
+/// Dispose zeroes the key-derived register state so it doesn't linger in memory
+/// after the AP connection closes.
+///
+/// Bulk word processing and diffusion keep the registers in SIMD registers where the CPU
+/// supports it (see ShannonCipher.Simd.cs); the scalar code below is the portable fallback
+/// and the reference both paths are tested against.
 /// </summary>
-public sealed class ShannonCipher
+public sealed partial class ShannonCipher : IDisposable
 {
     private const int N = 16; // LFSR register length
     private const int FOLD = N; // Diffusion iterations
@@ -39,15 +47,30 @@ public sealed class ShannonCipher
     // Number of buffered bits in sbuf/mbuf
     private int _nbuf;
 
+    private bool _disposed;
+
+    // Whether the vectorized paths are used (hardware support and not disabled)
+    private readonly bool _simd;
+
     /// <summary>
     /// Initializes a new Shannon cipher with the given key.
     /// </summary>
     /// <param name="key">32-byte encryption key</param>
     public ShannonCipher(ReadOnlySpan<byte> key)
+        : this(key, allowSimd: true)
+    {
+    }
+
+    /// <summary>
+    /// Initializes a new Shannon cipher, optionally forcing the portable scalar path.
+    /// </summary>
+    internal ShannonCipher(ReadOnlySpan<byte> key, bool allowSimd)
     {
         if (key.Length != 32)
             throw new ArgumentException("Shannon key must be exactly 32 bytes", nameof(key));
 
+        _simd = allowSimd && IsSimdSupported;
+
         InitState();
         LoadKey(key, key.Length);
         GenKonst();
@@ -63,6 +86,8 @@ public sealed class ShannonCipher
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
     public void NonceU32(uint nonce)
     {
+        ObjectDisposedException.ThrowIf(_disposed, this);
+
         // Convert big-endian nonce to little-endian bytes for processing
         Span<byte> nonceBytes = stackalloc byte[4];
         BinaryPrimitives.WriteUInt32BigEndian(nonceBytes, nonce);
@@ -144,12 +169,30 @@ public sealed class ShannonCipher
         Span<byte> computedMac = stackalloc byte[4];
         Finish(computedMac);
 
-        if (!receivedMac.SequenceEqual(computedMac))
+        if (!ConstantTime.BytesEqual(receivedMac, computedMac))
         {
             throw new InvalidDataException("Shannon MAC verification failed");
         }
     }
 
+    /// <summary>
+    /// Zeroes all key-derived state. The cipher is unusable afterwards.
+    /// </summary>
+    public void Dispose()
+    {
+        if (_disposed)
+            return;
+
+        CryptographicOperations.ZeroMemory(MemoryMarshal.AsBytes(_R.AsSpan()));
+        CryptographicOperations.ZeroMemory(MemoryMarshal.AsBytes(_CRC.AsSpan()));
+        CryptographicOperations.ZeroMemory(MemoryMarshal.AsBytes(_initR.AsSpan()));
+        _konst = 0;
+        _sbuf = 0;
+        _mbuf = 0;
+        _nbuf = 0;
+        _disposed = true;
+    }
+
     #region Core Shannon Algorithm
 
     /// <summary>
@@ -273,6 +316,12 @@ public sealed class ShannonCipher
     /// </summary>
     private void Diffuse()
     {
+        if (_simd)
+        {
+            DiffuseSimd();
+            return;
+        }
+
         for (int i = 0; i < FOLD; i++)
         {
             Cycle();
@@ -363,6 +412,13 @@ public sealed class ShannonCipher
             _mbuf = 0;
         }
 
+        if (_simd && remaining >= SimdMinBytes)
+        {
+            var processed = ProcessWordsSimd(buffer.Slice(offset, remaining & ~0x3), encrypt: true);
+            offset += processed;
+            remaining -= processed;
+        }
+
         // Process full words (4 bytes at a time)
         while (remaining >= 4)
         {
@@ -436,6 +492,13 @@ public sealed class ShannonCipher
             _mbuf = 0;
         }
 
+        if (_simd && remaining >= SimdMinBytes)
+        {
+            var processed = ProcessWordsSimd(buffer.Slice(offset, remaining & ~0x3), encrypt: false);
+            offset += processed;
+            remaining -= processed;
+        }
+
         // Process full words (4 bytes at a time)
         while (remaining >= 4)
         {
@@ -1,3 +1,5 @@
+using Wavee.Core.Storage;
+
 namespace Wavee.Core.DependencyInjection;
 
 /// <summary>
@@ -7,25 +9,19 @@ public class WaveeCacheOptions
 {
     /// <summary>
     /// Path to the SQLite database file.
-    /// Default: %APPDATA%/Wavee/metadata.db
+    /// Default: <see cref="WaveePaths.MetadataDatabase"/>
     /// </summary>
-    public string DatabasePath { get; set; } = Path.Combine(
-        Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData),
-        "Wavee",
-        "metadata.db");
+    public string DatabasePath { get; set; } = WaveePaths.Default.MetadataDatabase;
 
     /// <summary>
     /// Root directory for the local-file artwork cache. Backs the
     /// <c>wavee-artwork://{hash}</c> URI scheme. Default:
-    /// <c>%APPDATA%/Wavee/local-artwork</c> — same parent as the metadata
+    /// <see cref="WaveePaths.LocalArtworkDirectory"/> — same parent as the metadata
     /// DB so enrichment writers (which derive their path from the DB
     /// connection string) and the UI resolver (which reads
     /// <see cref="LocalArtworkDirectory"/>) point to the same folder.
     /// </summary>
-    public string LocalArtworkDirectory { get; set; } = Path.Combine(
-        Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData),
-        "Wavee",
-        "local-artwork");
+    public string LocalArtworkDirectory { get; set; } = WaveePaths.Default.LocalArtworkDirectory;
 
     /// <summary>
     /// Preferred 2-character Spotify locale for localized metadata cache rows.
@@ -111,4 +107,16 @@ public class WaveeCacheOptions
     /// Default: 30 minutes.
     /// </summary>
     public TimeSpan DefaultMaxAge { get; set; } = TimeSpan.FromMinutes(30);
+
+    /// <summary>
+    /// Whether to take an exclusive cross-process lock on the database's directory
+    /// (see <see cref="Storage.CacheDirectoryLock"/>). Default: true.
+    /// </summary>
+    public bool LockCacheDirectory { get; set; } = true;
+
+    /// <summary>
+    /// How long to wait for another process to release the cache lock before
+    /// <see cref="Storage.CacheLockedException"/> is thrown. Default: zero (fail immediately).
+    /// </summary>
+    public TimeSpan CacheLockTimeout { get; set; } = TimeSpan.Zero;
 }
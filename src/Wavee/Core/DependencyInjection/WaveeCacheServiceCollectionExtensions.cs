@@ -27,10 +27,20 @@ public static class WaveeCacheServiceCollectionExtensions
         configureOptions?.Invoke(options);
         services.AddSingleton(options);
 
+        // Cross-process lock on the cache directory; released when the container is disposed
+        services.AddSingleton(sp =>
+        {
+            var opts = sp.GetRequiredService<WaveeCacheOptions>();
+            var directory = Path.GetDirectoryName(Path.GetFullPath(opts.DatabasePath))!;
+            return CacheDirectoryLock.Acquire(directory, opts.CacheLockTimeout, sp.GetService<ILogger<CacheDirectoryLock>>());
+        });
+
         // Register metadata database (singleton)
         services.AddSingleton<IMetadataDatabase>(sp =>
         {
             var opts = sp.GetRequiredService<WaveeCacheOptions>();
+            if (opts.LockCacheDirectory)
+                sp.GetRequiredService<CacheDirectoryLock>();
             var logger = sp.GetService<ILogger<MetadataDatabase>>();
             return new MetadataDatabase(opts.DatabasePath, opts.DatabaseHotCacheSize, opts.SpotifyMetadataLocale, logger);
         });
@@ -0,0 +1,27 @@
+using Microsoft.Extensions.DependencyInjection;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.DependencyInjection;
+
+/// <summary>
+/// Extension methods for configuring the <c>"Wavee"</c> named HTTP client.
+/// </summary>
+public static class WaveeHttpClientBuilderExtensions
+{
+    /// <summary>
+    /// Counts the client's traffic on <paramref name="meter"/>. Pass the same meter to the
+    /// session through <see cref="SessionConfig.Bandwidth"/> so HTTP and AP traffic add up
+    /// in <see cref="Session.Session.Bandwidth"/>.
+    /// </summary>
+    /// <param name="builder">The named client's builder.</param>
+    /// <param name="meter">Meter the request and response bodies are added to.</param>
+    /// <returns>The builder for chaining.</returns>
+    public static IHttpClientBuilder AddBandwidthMetering(this IHttpClientBuilder builder, BandwidthMeter meter)
+    {
+        ArgumentNullException.ThrowIfNull(builder);
+        ArgumentNullException.ThrowIfNull(meter);
+
+        return builder.AddHttpMessageHandler(() => new BandwidthMeteringHandler(meter));
+    }
+}
@@ -0,0 +1,191 @@
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Wavee.Core.Diagnostics;
+
+/// <summary>
+/// Connection a <see cref="ProtocolTraceRecord"/> was captured on.
+/// </summary>
+public enum ProtocolTraceChannel
+{
+    /// <summary>AP packets other than Mercury.</summary>
+    Ap,
+
+    /// <summary>Mercury request, subscription and event packets on the AP connection.</summary>
+    Mercury,
+
+    /// <summary>Dealer WebSocket frames.</summary>
+    Dealer
+}
+
+/// <summary>
+/// Whether a traced packet or frame was sent or received.
+/// </summary>
+public enum ProtocolTraceDirection
+{
+    /// <summary>Sent by this client.</summary>
+    Outbound,
+
+    /// <summary>Received from Spotify.</summary>
+    Inbound
+}
+
+/// <summary>
+/// First line of a <c>.wavee-trace</c> file.
+/// </summary>
+/// <param name="Format">Always <see cref="ProtocolTrace.FormatName"/>.</param>
+/// <param name="Version">Format version; readers reject versions they don't know.</param>
+/// <param name="StartedAtUtc">When capture started; record offsets count from here.</param>
+/// <param name="Client">Wavee version that wrote the trace.</param>
+/// <param name="Platform">OS, architecture and runtime the trace was captured on.</param>
+public sealed record ProtocolTraceHeader(
+    string Format,
+    int Version,
+    DateTimeOffset StartedAtUtc,
+    string? Client,
+    string? Platform);
+
+/// <summary>
+/// One AP packet, Mercury packet or dealer frame in a <c>.wavee-trace</c> file.
+/// </summary>
+/// <remarks>
+/// Secrets never reach a record: credential, key and audio packets keep only their size, Mercury
+/// keeps the header with redacted user fields and the size of each body part, and dealer
+/// frames are stored as JSON with tokens and secret URL parameters redacted.
+/// </remarks>
+public sealed record ProtocolTraceRecord
+{
+    /// <summary>Milliseconds since <see cref="ProtocolTraceHeader.StartedAtUtc"/>.</summary>
+    public required long OffsetMs { get; init; }
+
+    /// <summary>Sent or received.</summary>
+    public required ProtocolTraceDirection Direction { get; init; }
+
+    /// <summary>Connection the record was captured on.</summary>
+    public required ProtocolTraceChannel Channel { get; init; }
+
+    /// <summary>AP packet type name, or the dealer frame's <c>type</c>.</summary>
+    public required string Command { get; init; }
+
+    /// <summary>Payload size in bytes, before redaction.</summary>
+    public int Size { get; init; }
+
+    /// <summary>Mercury sequence number.</summary>
+    public ulong? Sequence { get; init; }
+
+    /// <summary>Whether this is the last Mercury packet for <see cref="Sequence"/>.</summary>
+    public bool? Final { get; init; }
+
+    /// <summary>Mercury method (<c>GET</c>, <c>SUB</c>, ...).</summary>
+    public string? Method { get; init; }
+
+    /// <summary>Mercury URI, or the dealer frame's <c>uri</c> / <c>message_ident</c>.</summary>
+    public string? Uri { get; init; }
+
+    /// <summary>Mercury status code.</summary>
+    public int? Status { get; init; }
+
+    /// <summary>Mercury content type.</summary>
+    public string? ContentType { get; init; }
+
+    /// <summary>Mercury user fields, redacted.</summary>
+    public IReadOnlyDictionary<string, string>? Headers { get; init; }
+
+    /// <summary>Sizes of the Mercury body parts in this packet.</summary>
+    public IReadOnlyList<int>? Parts { get; init; }
+
+    /// <summary>Hex payload of AP packets that carry nothing sensitive (pings, country code, errors).</summary>
+    public string? Payload { get; init; }
+
+    /// <summary>The redacted dealer frame.</summary>
+    public JsonElement? Message { get; init; }
+}
+
+/// <summary>
+/// A <c>.wavee-trace</c> file: a capture of AP, Mercury and dealer traffic with secrets
+/// redacted, meant to be attached to bug reports. Written by <see cref="ProtocolTraceWriter"/>,
+/// printed by <see cref="ProtocolTraceFormatter"/>.
+/// </summary>
+/// <remarks>
+/// The format is JSON Lines: a <see cref="ProtocolTraceHeader"/>, then one
+/// <see cref="ProtocolTraceRecord"/> per line in capture order. Property names are camelCase
+/// and enums are strings, so a trace is also readable with <c>jq</c>.
+/// </remarks>
+/// <param name="Header">Capture metadata.</param>
+/// <param name="Records">Records in capture order.</param>
+public sealed record ProtocolTrace(ProtocolTraceHeader Header, IReadOnlyList<ProtocolTraceRecord> Records)
+{
+    /// <summary>Value of <see cref="ProtocolTraceHeader.Format"/>.</summary>
+    public const string FormatName = "wavee-trace";
+
+    /// <summary>Format version written by this build.</summary>
+    public const int CurrentVersion = 1;
+
+    /// <summary>File extension for traces.</summary>
+    public const string FileExtension = ".wavee-trace";
+
+    /// <summary>
+    /// Reads a trace. Blank lines are skipped.
+    /// </summary>
+    /// <exception cref="FormatException">The input is not a trace this build can read.</exception>
+    public static ProtocolTrace Read(TextReader reader)
+    {
+        ArgumentNullException.ThrowIfNull(reader);
+
+        var first = reader.ReadLine();
+        ProtocolTraceHeader? header;
+        try
+        {
+            header = first is null
+                ? null
+                : JsonSerializer.Deserialize(first, ProtocolTraceJsonContext.Default.ProtocolTraceHeader);
+        }
+        catch (JsonException ex)
+        {
+            throw new FormatException("Not a Wavee protocol trace", ex);
+        }
+
+        if (header?.Format != FormatName)
+            throw new FormatException("Not a Wavee protocol trace");
+        if (header.Version > CurrentVersion)
+            throw new FormatException($"Trace version {header.Version} is newer than this build supports ({CurrentVersion})");
+
+        var records = new List<ProtocolTraceRecord>();
+        var lineNumber = 1;
+        while (reader.ReadLine() is { } line)
+        {
+            lineNumber++;
+            if (string.IsNullOrWhiteSpace(line))
+                continue;
+
+            try
+            {
+                records.Add(JsonSerializer.Deserialize(line, ProtocolTraceJsonContext.Default.ProtocolTraceRecord)
+                    ?? throw new FormatException($"Line {lineNumber} is empty"));
+            }
+            catch (JsonException ex)
+            {
+                throw new FormatException($"Line {lineNumber} is not a trace record", ex);
+            }
+        }
+
+        return new ProtocolTrace(header, records);
+    }
+
+    /// <summary>
+    /// Reads the trace at <paramref name="path"/>.
+    /// </summary>
+    public static ProtocolTrace ReadFile(string path)
+    {
+        using var reader = File.OpenText(path);
+        return Read(reader);
+    }
+}
+
+[JsonSerializable(typeof(ProtocolTraceHeader))]
+[JsonSerializable(typeof(ProtocolTraceRecord))]
+[JsonSourceGenerationOptions(
+    PropertyNamingPolicy = JsonKnownNamingPolicy.CamelCase,
+    DefaultIgnoreCondition = JsonIgnoreCondition.WhenWritingNull,
+    UseStringEnumConverter = true)]
+internal sealed partial class ProtocolTraceJsonContext : JsonSerializerContext;
@@ -0,0 +1,132 @@
+using System.Text;
+using System.Text.Encodings.Web;
+using System.Text.Json;
+
+namespace Wavee.Core.Diagnostics;
+
+/// <summary>
+/// Renders a <see cref="ProtocolTrace"/> as a human-readable timeline, one line per record.
+/// </summary>
+/// <remarks>
+/// Example:
+/// <code>
+/// wavee-trace v1, started 2026-10-16 09:12:03Z, Wavee 1.0.0.0 on Linux X64, 3 records
+/// +0:00.000 → AP       Ping 4 B 00000000
+/// +0:01.204 → Mercury  MercuryReq #12 GET hm://metadata/4/track/… (final, 0 parts)
+/// +0:01.388 ← Mercury  MercuryReq #12 200 (final, 1 part: 532 B)
+/// </code>
+/// Dealer frames are followed by their indented JSON when <c>includeMessages</c> is true.
+/// </remarks>
+public static class ProtocolTraceFormatter
+{
+    private static readonly JsonWriterOptions IndentedOptions = new()
+    {
+        Indented = true,
+        Encoder = JavaScriptEncoder.UnsafeRelaxedJsonEscaping
+    };
+
+    /// <summary>
+    /// Writes <paramref name="trace"/> to <paramref name="output"/>.
+    /// </summary>
+    /// <param name="trace">Trace to render.</param>
+    /// <param name="output">Destination.</param>
+    /// <param name="includeMessages">Print dealer frames in full under their summary line.</param>
+    public static void Write(ProtocolTrace trace, TextWriter output, bool includeMessages = true)
+    {
+        ArgumentNullException.ThrowIfNull(trace);
+        ArgumentNullException.ThrowIfNull(output);
+
+        var header = trace.Header;
+        output.WriteLine(
+            $"{header.Format} v{header.Version}, started {header.StartedAtUtc.UtcDateTime:yyyy-MM-dd HH:mm:ss}Z" +
+            $"{(header.Client is null ? string.Empty : $", {header.Client}")}" +
+            $"{(header.Platform is null ? string.Empty : $" on {header.Platform}")}, {trace.Records.Count} records");
+
+        foreach (var record in trace.Records)
+        {
+            output.WriteLine(FormatRecord(record));
+            if (includeMessages && record.Message is { } message)
+                WriteIndentedJson(output, message);
+        }
+    }
+
+    /// <summary>
+    /// Renders <paramref name="trace"/> as a string.
+    /// </summary>
+    public static string Format(ProtocolTrace trace, bool includeMessages = true)
+    {
+        using var writer = new StringWriter();
+        Write(trace, writer, includeMessages);
+        return writer.ToString();
+    }
+
+    /// <summary>
+    /// Renders the summary line for one record.
+    /// </summary>
+    public static string FormatRecord(ProtocolTraceRecord record)
+    {
+        ArgumentNullException.ThrowIfNull(record);
+
+        var offset = TimeSpan.FromMilliseconds(record.OffsetMs);
+        var sb = new StringBuilder();
+        sb.Append('+').Append((int)offset.TotalMinutes).Append(':')
+            .Append(offset.Seconds.ToString("00")).Append('.').Append(offset.Milliseconds.ToString("000"))
+            .Append(record.Direction == ProtocolTraceDirection.Outbound ? " → " : " ← ")
+            .Append(record.Channel.ToString().PadRight(8))
+            .Append(' ').Append(record.Command);
+
+        switch (record.Channel)
+        {
+            case ProtocolTraceChannel.Mercury:
+                if (record.Sequence is { } seq)
+                    sb.Append(" #").Append(seq);
+                if (record.Method is not null)
+                    sb.Append(' ').Append(record.Method);
+                if (record.Status is { } status)
+                    sb.Append(' ').Append(status);
+                if (record.Uri is not null)
+                    sb.Append(' ').Append(record.Uri);
+                if (record.ContentType is not null)
+                    sb.Append(" [").Append(record.ContentType).Append(']');
+
+                var parts = record.Parts ?? [];
+                sb.Append(" (").Append(record.Final == true ? "final, " : string.Empty)
+                    .Append(parts.Count).Append(parts.Count == 1 ? " part" : " parts");
+                if (parts.Count > 0)
+                    sb.Append(": ").Append(string.Join(", ", parts.Select(p => $"{p} B")));
+                sb.Append(')');
+
+                if (record.Headers is { Count: > 0 } headers)
+                    sb.Append(' ').Append(string.Join(' ', headers.Select(h => $"{h.Key}={h.Value}")));
+                break;
+
+            case ProtocolTraceChannel.Dealer:
+                if (record.Uri is not null)
+                    sb.Append(' ').Append(record.Uri);
+                if (record.Message is { ValueKind: JsonValueKind.Object } message &&
+                    message.TryGetProperty("key", out var key) && key.ValueKind == JsonValueKind.String)
+                    sb.Append(" key=").Append(key.GetString());
+                sb.Append(' ').Append(record.Size).Append(" B");
+                break;
+
+            default:
+                sb.Append(' ').Append(record.Size).Append(" B");
+                if (record.Payload is { Length: > 0 } payload)
+                    sb.Append(' ').Append(payload);
+                break;
+        }
+
+        return sb.ToString();
+    }
+
+    private static void WriteIndentedJson(TextWriter output, JsonElement message)
+    {
+        using var stream = new MemoryStream();
+        using (var writer = new Utf8JsonWriter(stream, IndentedOptions))
+            message.WriteTo(writer);
+
+        using var reader = new StringReader(Encoding.UTF8.GetString(stream.ToArray()));
+        while (reader.ReadLine() is { } line)
+            output.WriteLine("    " + line);
+    }
+}
@@ -0,0 +1,246 @@
+using System.Buffers.Binary;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Text.Json;
+using Wavee.Connect.Diagnostics;
+using Wavee.Core.Session;
+using Wavee.Core.Utilities;
+
+namespace Wavee.Core.Diagnostics;
+
+/// <summary>
+/// Writes AP, Mercury and dealer traffic to a <c>.wavee-trace</c> file with secrets redacted
+/// (see <see cref="ProtocolTrace"/> for the format).
+/// </summary>
+/// <remarks>
+/// <para>
+/// Redaction happens here, before anything reaches the writer: login and welcome packets,
+/// audio keys and audio data keep only their size; Mercury packets keep the decoded header
+/// (URI query secrets and credential user fields redacted) and the size of each body part;
+/// dealer frames go through <see cref="DealerTrafficRecorder.Redact"/>. Only a handful of
+/// control packets — pings, country code, error codes — keep their payload.
+/// </para>
+/// <para>
+/// Thread-safe. Recording after <see cref="Dispose"/> is a no-op, so a session that is still
+/// shutting down can't fail on a closed trace.
+/// </para>
+/// </remarks>
+public sealed class ProtocolTraceWriter : IDisposable
+{
+    private static readonly HashSet<PacketType> PayloadSafePackets =
+    [
+        PacketType.Ping, PacketType.Pong, PacketType.PongAck, PacketType.CountryCode,
+        PacketType.ChannelError, PacketType.AesKeyError, PacketType.AuthFailure, PacketType.LicenseVersion
+    ];
+
+    private readonly object _lock = new();
+    private readonly TextWriter _writer;
+    private readonly bool _ownsWriter;
+    private readonly TimeProvider _timeProvider;
+    private readonly long _started;
+
+    // Mercury messages span packets; only the first packet of a sequence starts with the header part.
+    private readonly HashSet<(ProtocolTraceDirection, byte, ulong)> _openMercury = new();
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a writer and writes the trace header.
+    /// </summary>
+    /// <param name="writer">Destination for the trace.</param>
+    /// <param name="ownsWriter">Whether <see cref="Dispose"/> also disposes <paramref name="writer"/>.</param>
+    /// <param name="timeProvider">Clock for record offsets. Default is the system clock.</param>
+    public ProtocolTraceWriter(TextWriter writer, bool ownsWriter = false, TimeProvider? timeProvider = null)
+    {
+        _writer = writer ?? throw new ArgumentNullException(nameof(writer));
+        _ownsWriter = ownsWriter;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _started = _timeProvider.GetTimestamp();
+
+        var header = new ProtocolTraceHeader(
+            ProtocolTrace.FormatName,
+            ProtocolTrace.CurrentVersion,
+            _timeProvider.GetUtcNow(),
+            $"Wavee {typeof(ProtocolTraceWriter).Assembly.GetName().Version}",
+            $"{RuntimeInformation.OSDescription} {RuntimeInformation.ProcessArchitecture}, {RuntimeInformation.FrameworkDescription}");
+        _writer.WriteLine(JsonSerializer.Serialize(header, ProtocolTraceJsonContext.Default.ProtocolTraceHeader));
+        _writer.Flush();
+    }
+
+    /// <summary>
+    /// Creates a writer for a new trace file at <paramref name="path"/>, replacing any existing one.
+    /// </summary>
+    public static ProtocolTraceWriter CreateFile(string path, TimeProvider? timeProvider = null)
+    {
+        var stream = new FileStream(path, FileMode.Create, FileAccess.Write, FileShare.Read);
+        return new ProtocolTraceWriter(new StreamWriter(stream, new UTF8Encoding(false)), ownsWriter: true, timeProvider);
+    }
+
+    /// <summary>
+    /// Records a decrypted AP packet. Mercury packets are decoded into
+    /// <see cref="ProtocolTraceChannel.Mercury"/> records.
+    /// </summary>
+    public void RecordApPacket(ProtocolTraceDirection direction, byte command, ReadOnlySpan<byte> payload)
+    {
+        var type = Enum.IsDefined((PacketType)command) ? (PacketType)command : PacketType.Unknown;
+        var name = type == PacketType.Unknown ? $"0x{command:X2}" : type.ToString();
+
+        if (type is PacketType.MercuryReq or PacketType.MercurySub or PacketType.MercuryUnsub or PacketType.MercuryEvent)
+        {
+            lock (_lock)
+            {
+                if (TryDecodeMercury(direction, command, name, payload, out var mercury))
+                {
+                    Write(mercury);
+                    return;
+                }
+            }
+        }
+
+        Write(new ProtocolTraceRecord
+        {
+            OffsetMs = GetOffsetMs(),
+            Direction = direction,
+            Channel = ProtocolTraceChannel.Ap,
+            Command = name,
+            Size = payload.Length,
+            Payload = PayloadSafePackets.Contains(type) ? Convert.ToHexString(payload) : null
+        });
+    }
+
+    /// <summary>
+    /// Records a dealer WebSocket frame.
+    /// </summary>
+    public void RecordDealerMessage(ProtocolTraceDirection direction, ReadOnlySpan<byte> utf8Message)
+    {
+        var redacted = DealerTrafficRecorder.Redact(utf8Message);
+        JsonElement? message = null;
+        string command = "invalid";
+        string? uri = null;
+        if (redacted is not null)
+        {
+            using var doc = JsonDocument.Parse(redacted);
+            var root = doc.RootElement;
+            message = root.Clone();
+            if (root.ValueKind == JsonValueKind.Object)
+            {
+                command = root.TryGetProperty("type", out var type) ? type.GetString() ?? "?" : "?";
+                uri = root.TryGetProperty("uri", out var u) ? u.GetString()
+                    : root.TryGetProperty("message_ident", out var ident) ? ident.GetString()
+                    : null;
+            }
+        }
+
+        Write(new ProtocolTraceRecord
+        {
+            OffsetMs = GetOffsetMs(),
+            Direction = direction,
+            Channel = ProtocolTraceChannel.Dealer,
+            Command = command,
+            Size = utf8Message.Length,
+            Uri = uri,
+            Message = message
+        });
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _disposed = true;
+
+            _writer.Flush();
+            if (_ownsWriter)
+                _writer.Dispose();
+        }
+    }
+
+    private bool TryDecodeMercury(
+        ProtocolTraceDirection direction,
+        byte command,
+        string name,
+        ReadOnlySpan<byte> data,
+        out ProtocolTraceRecord record)
+    {
+        record = null!;
+        try
+        {
+            var offset = 0;
+            var seqLen = BinaryPrimitives.ReadUInt16BigEndian(data);
+            offset += 2;
+            if (seqLen > 8)
+                return false;
+            Span<byte> padded = stackalloc byte[8];
+            data.Slice(offset, seqLen).CopyTo(padded[(8 - seqLen)..]);
+            var seq = BinaryPrimitives.ReadUInt64BigEndian(padded);
+            offset += seqLen;
+
+            var final = (data[offset++] & 0x01) != 0;
+            var partCount = BinaryPrimitives.ReadUInt16BigEndian(data[offset..]);
+            offset += 2;
+
+            var parts = new List<int>(partCount);
+            Protocol.Header? header = null;
+            var key = (direction, command, seq);
+            var first = _openMercury.Add(key);
+            for (var i = 0; i < partCount && offset + 2 <= data.Length; i++)
+            {
+                var size = BinaryPrimitives.ReadUInt16BigEndian(data[offset..]);
+                offset += 2;
+                var part = data.Slice(offset, Math.Min(size, data.Length - offset));
+                offset += part.Length;
+
+                if (first && i == 0)
+                    header = Protocol.Header.Parser.ParseFrom(part);
+                else
+                    parts.Add(part.Length);
+            }
+
+            if (final)
+                _openMercury.Remove(key);
+
+            record = new ProtocolTraceRecord
+            {
+                OffsetMs = GetOffsetMs(),
+                Direction = direction,
+                Channel = ProtocolTraceChannel.Mercury,
+                Command = name,
+                Size = data.Length,
+                Sequence = seq,
+                Final = final,
+                Method = header?.HasMethod == true ? header.Method : null,
+                Uri = header?.HasUri == true ? LogRedaction.Url(header.Uri) : null,
+                Status = header?.HasStatusCode == true ? header.StatusCode : null,
+                ContentType = header?.HasContentType == true ? header.ContentType : null,
+                Headers = header is { UserFields.Count: > 0 }
+                    ? LogRedaction.Headers(header.UserFields.ToDictionary(
+                        f => f.Key, f => f.Value.ToStringUtf8(), StringComparer.OrdinalIgnoreCase))
+                    : null,
+                Parts = parts
+            };
+            return true;
+        }
+        catch (Exception ex) when (ex is ArgumentOutOfRangeException or IndexOutOfRangeException
+                                       or Google.Protobuf.InvalidProtocolBufferException or ArgumentException)
+        {
+            // Malformed: fall back to a plain AP record with the size only.
+            return false;
+        }
+    }
+
+    private long GetOffsetMs() => (long)_timeProvider.GetElapsedTime(_started).TotalMilliseconds;
+
+    private void Write(ProtocolTraceRecord record)
+    {
+        var line = JsonSerializer.Serialize(record, ProtocolTraceJsonContext.Default.ProtocolTraceRecord);
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _writer.WriteLine(line);
+            _writer.Flush();
+        }
+    }
+}
@@ -0,0 +1,230 @@
+using System.Buffers;
+using System.Diagnostics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using Wavee.Core.Connection;
+using Wavee.Core.Crypto;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.Core.Diagnostics;
+
+/// <summary>
+/// Runs the embedded known-answer vectors for the protocol crypto plus a few loopback checks
+/// and reports which ones pass on this machine.
+/// </summary>
+/// <remarks>
+/// Meant for diagnosing platform-specific breakage — a JIT/AOT miscompile of the Shannon word
+/// ops on ARM, a broken AES provider — that otherwise only shows up as "login hangs" or
+/// "audio is noise". The vectors are the librespot ones the unit tests use, so a pass here means
+/// the build talks the same wire format as the reference client. Hosts add their own checks
+/// (audio sink probing lives in AudioHost) through <c>additionalChecks</c>.
+/// </remarks>
+public static class SelfTest
+{
+    private static readonly byte[] ShannonKey =
+    [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+        0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f
+    ];
+
+    // (nonce, plaintext, ciphertext, mac) from librespot's shannon crate 0.2.0.
+    private static readonly (uint Nonce, byte[] Plaintext, byte[] Ciphertext, byte[] Mac)[] ShannonVectors =
+    [
+        (0, [0x01, 0x02, 0x03, 0x04], [0xcb, 0x7f, 0xea, 0x2f], [0x80, 0x3a, 0x07, 0x7f]),
+        (1, [0x01, 0x02, 0x03, 0x04], [0xba, 0x95, 0x25, 0xab], [0xae, 0x02, 0xa2, 0xc0]),
+        (0, [], [], [0x0a, 0xab, 0x57, 0x02]),
+        (0, "Hello, World!"u8.ToArray(),
+            [0x82, 0x18, 0x85, 0x47, 0x57, 0x85, 0xea, 0x2e, 0xae, 0x01, 0x7e, 0xfe, 0xbe],
+            [0x81, 0x49, 0x3f, 0x7e]),
+        (2, [0x12, 0x34, 0x56, 0x78], [0xdd, 0x3f, 0x13, 0x8e], [0xf0, 0x78, 0x5c, 0x24]),
+    ];
+
+    private static readonly byte[] AudioKey =
+    [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+    ];
+
+    // Spotify audio AES-128-CTR of bytes 0x00..0x27 under AudioKey, from librespot's AudioDecrypt.
+    private static readonly byte[] AudioCiphertext =
+    [
+        0xf5, 0x49, 0x07, 0x5f, 0x9a, 0x45, 0x52, 0x5e,
+        0x71, 0x0d, 0xad, 0xda, 0x84, 0xc9, 0x8b, 0x76,
+        0x15, 0x9f, 0xbc, 0xa5, 0x02, 0x20, 0xc1, 0x29,
+        0xe8, 0xba, 0xa8, 0x42, 0xf1, 0xc8, 0x86, 0x53,
+        0xfe, 0x74, 0x27, 0x8d, 0x47, 0x90, 0x5d, 0x5c
+    ];
+
+    /// <summary>
+    /// The built-in checks, in the order <see cref="RunAsync"/> runs them.
+    /// </summary>
+    public static IReadOnlyList<SelfTestCheck> BuiltInChecks { get; } =
+    [
+        new("shannon-vectors", _ => Task.FromResult(CheckShannonVectors())),
+        new("aes-ctr-vectors", _ => Task.FromResult(CheckAudioDecryptVectors())),
+        new("ap-codec-loopback", _ => Task.FromResult(CheckApCodecLoopback())),
+    ];
+
+    /// <summary>
+    /// Runs <see cref="BuiltInChecks"/> followed by <paramref name="additionalChecks"/>.
+    /// A check that throws is reported as failed; the run always completes.
+    /// </summary>
+    /// <param name="additionalChecks">Host-specific checks, e.g. audio sink probing.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The report.</returns>
+    public static async Task<SelfTestReport> RunAsync(
+        IEnumerable<SelfTestCheck>? additionalChecks = null,
+        CancellationToken cancellationToken = default)
+    {
+        var results = new List<SelfTestResult>();
+        foreach (var check in BuiltInChecks.Concat(additionalChecks ?? []))
+        {
+            cancellationToken.ThrowIfCancellationRequested();
+            var stopwatch = Stopwatch.StartNew();
+            try
+            {
+                var detail = await check.Run(cancellationToken).ConfigureAwait(false);
+                results.Add(new SelfTestResult(check.Name, true, detail, stopwatch.Elapsed));
+            }
+            catch (Exception ex) when (ex is not OperationCanceledException)
+            {
+                results.Add(new SelfTestResult(check.Name, false, ex.Message, stopwatch.Elapsed));
+            }
+        }
+
+        return new SelfTestReport(
+            $"{RuntimeInformation.OSDescription} {RuntimeInformation.ProcessArchitecture}, " +
+            $"{RuntimeInformation.FrameworkDescription}{(RuntimeFeature.IsDynamicCodeCompiled ? string.Empty : " (AOT)")}",
+            results);
+    }
+
+    private static string CheckShannonVectors()
+    {
+        Span<byte> actualMac = stackalloc byte[4];
+        for (var i = 0; i < ShannonVectors.Length; i++)
+        {
+            var (nonce, plaintext, ciphertext, mac) = ShannonVectors[i];
+
+            using var encryptor = new ShannonCipher(ShannonKey);
+            encryptor.NonceU32(nonce);
+            var buffer = plaintext.ToArray();
+            encryptor.Encrypt(buffer);
+            encryptor.Finish(actualMac);
+
+            if (!buffer.AsSpan().SequenceEqual(ciphertext))
+                throw new InvalidOperationException($"vector {i}: ciphertext {Convert.ToHexString(buffer)}, expected {Convert.ToHexString(ciphertext)}");
+            if (!actualMac.SequenceEqual(mac))
+                throw new InvalidOperationException($"vector {i}: MAC {Convert.ToHexString(actualMac)}, expected {Convert.ToHexString(mac)}");
+
+            using var decryptor = new ShannonCipher(ShannonKey);
+            decryptor.NonceU32(nonce);
+            decryptor.Decrypt(buffer);
+            decryptor.CheckMac(mac);
+            if (!buffer.AsSpan().SequenceEqual(plaintext))
+                throw new InvalidOperationException($"vector {i}: decryption did not restore the plaintext");
+        }
+
+        return $"{ShannonVectors.Length} vectors";
+    }
+
+    private static string CheckAudioDecryptVectors()
+    {
+        // Whole buffer, then every offset individually to exercise the counter at block boundaries.
+        using (var stream = new AudioDecryptStream(AudioKey, new MemoryStream(AudioCiphertext)))
+        {
+            var plaintext = new byte[AudioCiphertext.Length];
+            stream.ReadExactly(plaintext);
+            for (var i = 0; i < plaintext.Length; i++)
+            {
+                if (plaintext[i] != i)
+                    throw new InvalidOperationException($"byte {i}: got 0x{plaintext[i]:x2}, expected 0x{i:x2}");
+            }
+        }
+
+        using (var stream = new AudioDecryptStream(AudioKey, new MemoryStream(AudioCiphertext)))
+        {
+            for (var i = AudioCiphertext.Length - 1; i >= 0; i--)
+            {
+                stream.Position = i;
+                var value = stream.ReadByte();
+                if (value != i)
+                    throw new InvalidOperationException($"seek to {i}: got 0x{value:x2}, expected 0x{i:x2}");
+            }
+        }
+
+        return $"{AudioCiphertext.Length} bytes, sequential and seeking, {AesCtrKeystream.DefaultBackend} backend";
+    }
+
+    private static string CheckApCodecLoopback()
+    {
+        var clientKey = ShannonKey;
+        var serverKey = ShannonKey.Select(b => (byte)~b).ToArray();
+        using var client = new ApCodec(clientKey, serverKey);
+        using var server = new ApCodec(serverKey, clientKey);
+
+        // Sizes cover empty, non-word-aligned and maximum-length payloads; several packets
+        // so the per-packet nonce has to advance identically on both ends.
+        int[] sizes = [0, 1, 13, 4096, ushort.MaxValue];
+        var writer = new ArrayBufferWriter<byte>();
+        var payloads = sizes.Select(size => Enumerable.Range(0, size).Select(i => (byte)(i * 31 + size)).ToArray()).ToArray();
+        for (var i = 0; i < payloads.Length; i++)
+            client.Encode(writer, (byte)(0x40 + i), payloads[i]);
+
+        var buffer = new ReadOnlySequence<byte>(writer.WrittenMemory);
+        for (var i = 0; i < payloads.Length; i++)
+        {
+            if (!server.TryDecode(ref buffer, out var consumed, out var command, out var payload))
+                throw new InvalidOperationException($"packet {i}: not decoded from a complete buffer");
+
+            buffer = buffer.Slice(consumed);
+            if (command != 0x40 + i || !payload.AsSpan().SequenceEqual(payloads[i]))
+                throw new InvalidOperationException($"packet {i}: round-trip mismatch (command 0x{command:x2}, {payload.Length} bytes)");
+        }
+
+        // A flipped payload bit must fail the MAC rather than decode.
+        var tamperedWriter = new ArrayBufferWriter<byte>();
+        client.Encode(tamperedWriter, 0x4f, [1, 2, 3, 4]);
+        var tampered = tamperedWriter.WrittenSpan.ToArray();
+        tampered[^5] ^= 0x01;
+        var tamperedBuffer = new ReadOnlySequence<byte>(tampered);
+        try
+        {
+            server.TryDecode(ref tamperedBuffer, out _, out _, out _);
+            throw new InvalidOperationException("tampered packet was accepted");
+        }
+        catch (ApCodecException)
+        {
+        }
+
+        return $"{payloads.Length} packets, tamper rejected";
+    }
+}
+
+/// <summary>
+/// A named self-test step.
+/// </summary>
+/// <param name="Name">Short identifier shown in the report.</param>
+/// <param name="Run">Performs the check and returns a one-line detail; throws on failure.</param>
+public sealed record SelfTestCheck(string Name, Func<CancellationToken, Task<string>> Run);
+
+/// <summary>
+/// Outcome of one <see cref="SelfTestCheck"/>.
+/// </summary>
+/// <param name="Name">Check name.</param>
+/// <param name="Passed">Whether it passed.</param>
+/// <param name="Detail">What was checked, or why it failed.</param>
+/// <param name="Elapsed">How long it took.</param>
+public sealed record SelfTestResult(string Name, bool Passed, string Detail, TimeSpan Elapsed);
+
+/// <summary>
+/// Result of <see cref="SelfTest.RunAsync"/>.
+/// </summary>
+/// <param name="Platform">OS, architecture and runtime the checks ran on.</param>
+/// <param name="Results">Per-check outcomes, in run order.</param>
+public sealed record SelfTestReport(string Platform, IReadOnlyList<SelfTestResult> Results)
+{
+    /// <summary>True when every check passed.</summary>
+    public bool Passed => Results.All(r => r.Passed);
+}
@@ -27,18 +27,20 @@ public sealed record AccessToken
     /// <summary>
     /// Checks if the token is expired.
     /// </summary>
+    /// <param name="now">Current time; pass the server-corrected clock when available. Defaults to the local clock.</param>
     /// <returns>True if the token has expired, false otherwise.</returns>
-    public bool IsExpired() => DateTimeOffset.UtcNow >= ExpiresAt;
+    public bool IsExpired(DateTimeOffset? now = null) => (now ?? DateTimeOffset.UtcNow) >= ExpiresAt;
 
     /// <summary>
     /// Checks if the token should be refreshed (with buffer time before expiry).
     /// </summary>
     /// <param name="threshold">Time before expiry to trigger refresh. Defaults to 5 minutes.</param>
+    /// <param name="now">Current time; pass the server-corrected clock when available. Defaults to the local clock.</param>
     /// <returns>True if the token should be refreshed, false otherwise.</returns>
-    public bool ShouldRefresh(TimeSpan? threshold = null)
+    public bool ShouldRefresh(TimeSpan? threshold = null, DateTimeOffset? now = null)
     {
         var refreshThreshold = threshold ?? TimeSpan.FromMinutes(5);
-        return DateTimeOffset.UtcNow >= ExpiresAt - refreshThreshold;
+        return (now ?? DateTimeOffset.UtcNow) >= ExpiresAt - refreshThreshold;
     }
 
     /// <summary>
@@ -46,15 +48,16 @@ public sealed record AccessToken
     /// </summary>
     /// <param name="token">The access token string.</param>
     /// <param name="expiresInSeconds">Token lifetime in seconds.</param>
+    /// <param name="issuedAt">Time the token was requested, on the same clock later passed to <see cref="ShouldRefresh"/>. Defaults to the local clock.</param>
     /// <returns>A new AccessToken instance.</returns>
-    internal static AccessToken FromLogin5Response(string token, int expiresInSeconds)
+    internal static AccessToken FromLogin5Response(string token, int expiresInSeconds, DateTimeOffset? issuedAt = null)
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(token);
 
         return new AccessToken
         {
             Token = token,
-            ExpiresAt = DateTimeOffset.UtcNow.AddSeconds(expiresInSeconds)
+            ExpiresAt = (issuedAt ?? DateTimeOffset.UtcNow).AddSeconds(expiresInSeconds)
         };
     }
 
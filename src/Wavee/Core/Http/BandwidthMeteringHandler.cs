@@ -0,0 +1,167 @@
+using System.Net;
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Counts request and response bodies on a <see cref="BandwidthMeter"/>, categorised by host.
+/// </summary>
+/// <remarks>
+/// Registered on the host's <c>"Wavee"</c> named client with
+/// <see cref="WaveeHttpClientBuilderExtensions.AddBandwidthMetering"/>; the session picks up
+/// the same meter through <see cref="SessionConfig.Bandwidth"/>. Request bodies are counted
+/// by their declared length. The response body is counted as the caller reads it, so
+/// buffered and streamed responses are both covered.
+/// </remarks>
+public sealed class BandwidthMeteringHandler : DelegatingHandler
+{
+    private readonly BandwidthMeter _meter;
+
+    public BandwidthMeteringHandler(BandwidthMeter meter)
+    {
+        _meter = meter ?? throw new ArgumentNullException(nameof(meter));
+    }
+
+    protected override async Task<HttpResponseMessage> SendAsync(
+        HttpRequestMessage request,
+        CancellationToken cancellationToken)
+    {
+        var category = Classify(request.RequestUri);
+        if (request.Content?.Headers.ContentLength is { } sent)
+            _meter.RecordSent(category, sent);
+
+        var response = await base.SendAsync(request, cancellationToken).ConfigureAwait(false);
+        response.Content = new MeteredContent(response.Content, _meter, category);
+        return response;
+    }
+
+    /// <summary>
+    /// Maps a request to a traffic category by host. Anything unrecognised counts as metadata.
+    /// </summary>
+    internal static TrafficCategory Classify(Uri? uri)
+    {
+        var host = uri?.IsAbsoluteUri == true ? uri.Host : string.Empty;
+
+        if (host.StartsWith("audio", StringComparison.OrdinalIgnoreCase)
+            || host.StartsWith("heads-", StringComparison.OrdinalIgnoreCase)
+            || host.StartsWith("video-", StringComparison.OrdinalIgnoreCase)
+            || host.Equals("p.scdn.co", StringComparison.OrdinalIgnoreCase))
+            return TrafficCategory.Audio;
+
+        if (host.EndsWith(".scdn.co", StringComparison.OrdinalIgnoreCase)
+            || host.Contains("image", StringComparison.OrdinalIgnoreCase))
+            return TrafficCategory.Images;
+
+        if (host.StartsWith("apresolve.", StringComparison.OrdinalIgnoreCase)
+            || host.StartsWith("login5.", StringComparison.OrdinalIgnoreCase)
+            || host.StartsWith("clienttoken.", StringComparison.OrdinalIgnoreCase)
+            || host.StartsWith("accounts.", StringComparison.OrdinalIgnoreCase)
+            || host.Contains("dealer", StringComparison.OrdinalIgnoreCase))
+            return TrafficCategory.Control;
+
+        return TrafficCategory.Metadata;
+    }
+
+    /// <summary>
+    /// Response content that counts bytes as they are read from the inner content.
+    /// </summary>
+    private sealed class MeteredContent : HttpContent
+    {
+        private readonly HttpContent _inner;
+        private readonly BandwidthMeter _meter;
+        private readonly TrafficCategory _category;
+
+        public MeteredContent(HttpContent inner, BandwidthMeter meter, TrafficCategory category)
+        {
+            _inner = inner;
+            _meter = meter;
+            _category = category;
+
+            foreach (var header in inner.Headers)
+                Headers.TryAddWithoutValidation(header.Key, header.Value);
+        }
+
+        protected override async Task<Stream> CreateContentReadStreamAsync(CancellationToken cancellationToken)
+            => new MeteredStream(await _inner.ReadAsStreamAsync(cancellationToken).ConfigureAwait(false), _meter, _category);
+
+        protected override Task<Stream> CreateContentReadStreamAsync()
+            => CreateContentReadStreamAsync(CancellationToken.None);
+
+        protected override Task SerializeToStreamAsync(Stream stream, TransportContext? context)
+            => SerializeToStreamAsync(stream, context, CancellationToken.None);
+
+        protected override async Task SerializeToStreamAsync(Stream stream, TransportContext? context, CancellationToken cancellationToken)
+        {
+            await using var source = await CreateContentReadStreamAsync(cancellationToken).ConfigureAwait(false);
+            await source.CopyToAsync(stream, cancellationToken).ConfigureAwait(false);
+        }
+
+        protected override bool TryComputeLength(out long length)
+        {
+            length = _inner.Headers.ContentLength ?? 0;
+            return _inner.Headers.ContentLength.HasValue;
+        }
+
+        protected override void Dispose(bool disposing)
+        {
+            if (disposing)
+                _inner.Dispose();
+            base.Dispose(disposing);
+        }
+    }
+
+    private sealed class MeteredStream : Stream
+    {
+        private readonly Stream _inner;
+        private readonly BandwidthMeter _meter;
+        private readonly TrafficCategory _category;
+
+        public MeteredStream(Stream inner, BandwidthMeter meter, TrafficCategory category)
+        {
+            _inner = inner;
+            _meter = meter;
+            _category = category;
+        }
+
+        public override bool CanRead => true;
+        public override bool CanSeek => false;
+        public override bool CanWrite => false;
+        public override long Length => throw new NotSupportedException();
+
+        public override long Position
+        {
+            get => throw new NotSupportedException();
+            set => throw new NotSupportedException();
+        }
+
+        public override int Read(byte[] buffer, int offset, int count)
+            => Read(buffer.AsSpan(offset, count));
+
+        public override int Read(Span<byte> buffer)
+            => Count(_inner.Read(buffer));
+
+        public override async ValueTask<int> ReadAsync(Memory<byte> buffer, CancellationToken cancellationToken = default)
+            => Count(await _inner.ReadAsync(buffer, cancellationToken).ConfigureAwait(false));
+
+        public override Task<int> ReadAsync(byte[] buffer, int offset, int count, CancellationToken cancellationToken)
+            => ReadAsync(buffer.AsMemory(offset, count), cancellationToken).AsTask();
+
+        public override void Flush() { }
+        public override long Seek(long offset, SeekOrigin origin) => throw new NotSupportedException();
+        public override void SetLength(long value) => throw new NotSupportedException();
+        public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
+
+        protected override void Dispose(bool disposing)
+        {
+            if (disposing)
+                _inner.Dispose();
+            base.Dispose(disposing);
+        }
+
+        private int Count(int read)
+        {
+            _meter.RecordReceived(_category, read);
+            return read;
+        }
+    }
+}
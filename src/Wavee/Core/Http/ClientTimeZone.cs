@@ -0,0 +1,61 @@
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Resolves the IANA time zone reported to the home feed, which picks content by time of day
+/// (morning mixes, evening wind-down). See <see cref="SessionConfig.TimeZone"/>.
+/// </summary>
+internal static class ClientTimeZone
+{
+    private const string Utc = "UTC";
+
+    /// <summary>
+    /// Resolves <paramref name="configured"/>, falling back to the <c>TZ</c> environment
+    /// variable, then the system time zone, then UTC.
+    /// </summary>
+    public static string Resolve(string? configured)
+        => Resolve(configured, Environment.GetEnvironmentVariable("TZ"), TimeZoneInfo.Local);
+
+    internal static string Resolve(string? configured, string? tzVariable, TimeZoneInfo local)
+    {
+        if (TryGetIanaId(configured, out var id))
+            return id;
+
+        // POSIX allows a leading ':' to mean "read from the zoneinfo database".
+        if (TryGetIanaId(tzVariable?.TrimStart(':'), out id))
+            return id;
+
+        return TryGetIanaId(local, out id) ? id : Utc;
+    }
+
+    /// <summary>
+    /// Converts an IANA or Windows time zone id to its IANA form.
+    /// </summary>
+    public static bool TryGetIanaId(string? value, out string ianaId)
+    {
+        ianaId = string.Empty;
+        if (string.IsNullOrWhiteSpace(value))
+            return false;
+
+        return TimeZoneInfo.TryFindSystemTimeZoneById(value.Trim(), out var zone) && TryGetIanaId(zone, out ianaId);
+    }
+
+    private static bool TryGetIanaId(TimeZoneInfo zone, out string ianaId)
+    {
+        if (zone.HasIanaId)
+        {
+            ianaId = zone.Id;
+            return true;
+        }
+
+        if (TimeZoneInfo.TryConvertWindowsIdToIanaId(zone.Id, out var converted))
+        {
+            ianaId = converted;
+            return true;
+        }
+
+        ianaId = string.Empty;
+        return false;
+    }
+}
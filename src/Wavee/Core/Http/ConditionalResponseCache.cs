@@ -0,0 +1,108 @@
+using System.Net;
+using System.Net.Http.Headers;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage;
+
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Remembers spclient GET responses that carried an <c>ETag</c>, so the next request for the
+/// same resource can be sent with <c>If-None-Match</c> and a <c>304 Not Modified</c> answered
+/// from memory.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Entries are keyed by URL and auth scope (the logged-in account), so one account never
+/// revalidates against, or is served, another account's body. Least recently used entries
+/// are evicted past <see cref="MaxEntries"/>; bodies over <see cref="MaxBodyBytes"/> are not
+/// cached. Shared across the short-lived <see cref="SpClient"/> instances of a session.
+/// </para>
+/// <para>
+/// Most useful for resources re-requested often but rarely changed, such as the rootlist
+/// and playlist headers.
+/// </para>
+/// </remarks>
+public sealed class ConditionalResponseCache
+{
+    /// <summary>
+    /// Default maximum number of cached responses.
+    /// </summary>
+    public const int DefaultMaxEntries = 256;
+
+    /// <summary>
+    /// Default largest body cached: 4 MB.
+    /// </summary>
+    public const long DefaultMaxBodyBytes = 4L * 1024 * 1024;
+
+    internal sealed record Entry(EntityTagHeaderValue ETag, byte[] Body, MediaTypeHeaderValue? ContentType);
+
+    private readonly HotCache<Entry> _entries;
+
+    /// <summary>
+    /// Creates an empty cache.
+    /// </summary>
+    /// <param name="maxEntries">Maximum number of cached responses. Must be positive.</param>
+    /// <param name="maxBodyBytes">Largest body cached. Must be positive.</param>
+    /// <param name="logger">Optional logger for diagnostics.</param>
+    public ConditionalResponseCache(
+        int maxEntries = DefaultMaxEntries,
+        long maxBodyBytes = DefaultMaxBodyBytes,
+        ILogger? logger = null)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxEntries);
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxBodyBytes);
+
+        MaxEntries = maxEntries;
+        MaxBodyBytes = maxBodyBytes;
+        _entries = new HotCache<Entry>(maxEntries, logger);
+    }
+
+    /// <summary>
+    /// Gets the maximum number of cached responses.
+    /// </summary>
+    public int MaxEntries { get; }
+
+    /// <summary>
+    /// Gets the largest body cached, in bytes.
+    /// </summary>
+    public long MaxBodyBytes { get; }
+
+    /// <summary>
+    /// Gets the number of cached responses.
+    /// </summary>
+    public int Count => _entries.Count;
+
+    /// <summary>
+    /// Removes every cached response.
+    /// </summary>
+    public void Clear() => _entries.Clear();
+
+    internal Entry? Get(string scope, string url) => _entries.Get(Key(scope, url));
+
+    internal void Set(string scope, string url, EntityTagHeaderValue etag, byte[] body, MediaTypeHeaderValue? contentType)
+    {
+        if (body.Length > MaxBodyBytes)
+            return;
+
+        _entries.Set(Key(scope, url), new Entry(etag, body, contentType));
+    }
+
+    /// <summary>
+    /// Builds the <c>200 OK</c> handed to callers in place of a <c>304</c>.
+    /// </summary>
+    internal static HttpResponseMessage ToResponse(Entry entry, HttpRequestMessage request)
+    {
+        var content = new ByteArrayContent(entry.Body);
+        content.Headers.ContentType = entry.ContentType;
+
+        var response = new HttpResponseMessage(HttpStatusCode.OK)
+        {
+            Content = content,
+            RequestMessage = request
+        };
+        response.Headers.ETag = entry.ETag;
+        return response;
+    }
+
+    private static string Key(string scope, string url) => $"{scope}|{url}";
+}
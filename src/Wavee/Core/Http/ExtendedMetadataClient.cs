@@ -354,7 +354,7 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
         CancellationToken cancellationToken)
     {
         // Get country and catalogue for header
-        var countryCode = await _session.GetCountryCodeAsync(cancellationToken);
+        var countryCode = await MetadataRequestLocale.GetCountryAsync(_session, cancellationToken);
         var accountType = await _session.GetAccountTypeAsync(cancellationToken);
         var catalogue = MapAccountTypeToCatalogue(accountType);
 
@@ -434,7 +434,8 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
 
         httpResponse.EnsureSuccessStatusCode();
 
-        var response = await ParseResponseAsync(BatchedExtensionResponse.Parser, httpResponse, cancellationToken);
+        var response = await ParseResponseAsync(
+            BatchedExtensionResponse.Parser, httpResponse, _session.Config.Network.MaxResponseBytes, cancellationToken);
 
         _logger?.LogDebug("Extended metadata response: {Count} extension arrays", response.ExtendedMetadata.Count);
 
@@ -567,12 +568,12 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
 
     internal static async Task<byte[]> ReadResponseBytesAsync(
         HttpResponseMessage response,
+        long maxResponseBytes = NetworkConfig.DefaultMaxResponseBytes,
         CancellationToken cancellationToken = default)
     {
         ArgumentNullException.ThrowIfNull(response);
 
-        await using var baseStream = await response.Content.ReadAsStreamAsync(cancellationToken);
-        using var decodedStream = CreateDecodedStream(baseStream, response.Content.Headers.ContentEncoding);
+        await using var decodedStream = await OpenLimitedStreamAsync(response, maxResponseBytes, cancellationToken);
         using var buffer = new MemoryStream();
         await decodedStream.CopyToAsync(buffer, cancellationToken);
         return buffer.ToArray();
@@ -588,21 +589,39 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
     /// profiling pass. <c>MessageParser&lt;T&gt;.ParseFrom(Stream)</c> reads
     /// the protobuf wire format incrementally from the decompressed stream,
     /// keeping every per-call allocation pool-sized.
+    /// Bodies whose decoded size passes <paramref name="maxResponseBytes"/> throw
+    /// <see cref="SpClientFailureReason.TooLarge"/> as soon as the limit is crossed.
     /// </summary>
     internal static async Task<T> ParseResponseAsync<T>(
         Google.Protobuf.MessageParser<T> parser,
         HttpResponseMessage response,
+        long maxResponseBytes = NetworkConfig.DefaultMaxResponseBytes,
         CancellationToken cancellationToken = default)
         where T : Google.Protobuf.IMessage<T>
     {
         ArgumentNullException.ThrowIfNull(parser);
         ArgumentNullException.ThrowIfNull(response);
 
-        await using var baseStream = await response.Content.ReadAsStreamAsync(cancellationToken);
-        using var decodedStream = CreateDecodedStream(baseStream, response.Content.Headers.ContentEncoding);
+        await using var decodedStream = await OpenLimitedStreamAsync(response, maxResponseBytes, cancellationToken);
         return parser.ParseFrom(decodedStream);
     }
 
+    private static async Task<Stream> OpenLimitedStreamAsync(
+        HttpResponseMessage response,
+        long maxResponseBytes,
+        CancellationToken cancellationToken)
+    {
+        var description = $"Response from {response.RequestMessage?.RequestUri?.AbsolutePath ?? "spclient"}";
+
+        // A compressed body already over the limit can only decode larger; skip the download.
+        if (response.Content.Headers.ContentLength is { } contentLength && contentLength > maxResponseBytes)
+            throw ResponseSizeLimitStream.TooLarge(description, maxResponseBytes, contentLength);
+
+        var baseStream = await response.Content.ReadAsStreamAsync(cancellationToken);
+        var decodedStream = CreateDecodedStream(baseStream, response.Content.Headers.ContentEncoding);
+        return new ResponseSizeLimitStream(decodedStream, maxResponseBytes, description);
+    }
+
     private static Stream CreateDecodedStream(Stream baseStream, ICollection<string> encodings)
     {
         Stream current = baseStream;
@@ -622,18 +641,6 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
         return current;
     }
 
-    private string? GetEffectiveLocale()
-    {
-        var sessionLocale = _session.GetPreferredLocale();
-        if (!string.IsNullOrEmpty(sessionLocale))
-        {
-            return sessionLocale;
-        }
-
-        var userData = _session.GetUserData();
-        return userData?.PreferredLocale;
-    }
-
     private async Task AddExtendedMetadataHeadersAsync(
         HttpRequestMessage request,
         string accessToken,
@@ -647,7 +654,7 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
         request.Headers.AcceptEncoding.Add(new StringWithQualityHeaderValue("br"));
         request.Headers.AcceptEncoding.Add(new StringWithQualityHeaderValue("zstd"));
         request.Headers.Connection.Add("keep-alive");
-        request.Headers.TryAddWithoutValidation("Accept-Language", GetMetadataRequestLanguage());
+        request.Headers.TryAddWithoutValidation("Accept-Language", MetadataRequestLocale.GetAcceptLanguage(_session));
         request.Headers.TryAddWithoutValidation("App-Platform", SpotifyClientIdentity.AppPlatform);
         request.Headers.TryAddWithoutValidation("Spotify-App-Version", SpotifyClientIdentity.AppVersionHeader);
         request.Headers.TryAddWithoutValidation("client-feature-id", clientFeatureId);
@@ -671,19 +678,6 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
         }
     }
 
-    private string GetMetadataRequestLanguage()
-    {
-        var locale = GetEffectiveLocale();
-        if (string.IsNullOrWhiteSpace(locale))
-        {
-            return "en";
-        }
-
-        var separatorIndex = locale.IndexOfAny(['-', '_']);
-        var language = separatorIndex > 0 ? locale[..separatorIndex] : locale;
-        return language.ToLowerInvariant();
-    }
-
     private async Task CacheResponseAsync(BatchedExtensionResponse response, CancellationToken cancellationToken)
     {
         // Pass 1: collect every extension write into one flat list and accumulate
@@ -1272,6 +1266,8 @@ public sealed class ExtendedMetadataClient : IExtendedMetadataClient
         HttpRequestMessage request,
         CancellationToken cancellationToken)
     {
+        OfflineModeException.ThrowIfOffline(_session);
+
         Exception? lastException = null;
 
         for (int attempt = 0; attempt < MaxRetries; attempt++)
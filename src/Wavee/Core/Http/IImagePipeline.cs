@@ -0,0 +1,22 @@
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Loads image bytes (cover art, avatars) with an in-memory cache capped by total size.
+/// </summary>
+public interface IImagePipeline
+{
+    /// <summary>
+    /// Gets an image, from the cache when present.
+    /// </summary>
+    /// <param name="imageUri">Image as <c>spotify:image:{id}</c> or an absolute URL.</param>
+    /// <param name="cancellationToken">
+    /// Stops waiting. A download other callers are also waiting on keeps running.
+    /// </param>
+    /// <returns>The encoded image bytes (shared; do not modify), or null when the image can't be fetched.</returns>
+    Task<byte[]?> GetAsync(string imageUri, CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Gets an image only if it is already cached. Never starts a download.
+    /// </summary>
+    byte[]? TryGetCached(string imageUri);
+}
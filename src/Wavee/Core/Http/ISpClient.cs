@@ -439,6 +439,28 @@ public interface ISpClient
         string username,
         CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Fetches the users following <paramref name="username"/> via the spclient profile endpoint.
+    /// The list is empty when the user hides their followers.
+    /// </summary>
+    Task<SpotifyFollowingResponse> GetUserFollowersAsync(
+        string username,
+        CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// Fetches one page of a user's public playlists via the spclient profile endpoint.
+    /// <see cref="GetUserProfileAsync"/> returns only the first few.
+    /// </summary>
+    /// <param name="username">Username or <c>spotify:user:</c> URI.</param>
+    /// <param name="offset">Index of the first playlist.</param>
+    /// <param name="limit">Maximum playlists to return (1-200).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    Task<SpotifyPublicPlaylistsPage> GetUserPublicPlaylistsAsync(
+        string username,
+        int offset = 0,
+        int limit = 50,
+        CancellationToken cancellationToken = default);
+
     /// <summary>
     /// Follows a user via Web API <c>PUT /v1/me/following?type=user&amp;ids={id}</c>.
     /// Accepts either a bare user id (<c>abc</c>) or a full URI (<c>spotify:user:abc</c>).
@@ -457,6 +479,15 @@ public interface ISpClient
         string usernameOrUri,
         CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Checks whether the current user follows a user via Web API
+    /// <c>GET /v1/me/following/contains?type=user&amp;ids={id}</c>.
+    /// Accepts either a bare user id (<c>abc</c>) or a full URI (<c>spotify:user:abc</c>).
+    /// </summary>
+    Task<bool> IsFollowingUserAsync(
+        string usernameOrUri,
+        CancellationToken cancellationToken = default);
+
     /// <summary>
     /// Fetches extended top track URIs for an artist (beyond the initial overview set).
     /// Returns just URIs — caller must enrich via extended-metadata.
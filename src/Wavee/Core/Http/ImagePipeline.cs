@@ -0,0 +1,294 @@
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Storage.Abstractions;
+
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Image loader with a bytes-capped LRU cache and request deduplication.
+/// </summary>
+/// <remarks>
+/// <para>
+/// Concurrent requests for the same image share one download, so a library view asking
+/// for the same cover from many rows fetches it once. The download belongs to the pipeline,
+/// not to any caller: a caller cancelling (a row scrolled off screen) stops only its own wait.
+/// </para>
+/// <para>
+/// Cached images are evicted least-recently-used first once their total size exceeds
+/// <see cref="MaxBytes"/>. An image larger than the whole budget is returned but not cached.
+/// Failed downloads are not cached. No lock is held across I/O.
+/// </para>
+/// </remarks>
+public sealed class ImagePipeline : IImagePipeline, ICleanableCache, IDisposable
+{
+    /// <summary>
+    /// Default cache budget: 64 MB, roughly a thousand 300px covers.
+    /// </summary>
+    public const long DefaultMaxBytes = 64L * 1024 * 1024;
+
+    private const string ImageUriPrefix = "spotify:image:";
+    private const string ImageUrlPrefix = "https://i.scdn.co/image/";
+
+    private sealed record Entry(string Url, byte[] Bytes)
+    {
+        public DateTimeOffset LastAccessed { get; set; }
+    }
+
+    private readonly HttpClient _httpClient;
+    private readonly TimeProvider _timeProvider;
+    private readonly Func<bool>? _isOffline;
+    private readonly ILogger? _logger;
+    private readonly object _lock = new();
+    private readonly Dictionary<string, LinkedListNode<Entry>> _entries = new(StringComparer.Ordinal);
+    private readonly LinkedList<Entry> _lru = new();  // Head = most recent
+    private readonly Dictionary<string, Task<byte[]?>> _inFlight = new(StringComparer.Ordinal);
+    private readonly CancellationTokenSource _disposeCts = new();
+    private long _currentBytes;
+    private bool _disposed;
+
+    /// <summary>
+    /// Creates a pipeline downloading through <paramref name="httpClient"/>.
+    /// </summary>
+    /// <param name="httpClient">HTTP client used for downloads.</param>
+    /// <param name="maxBytes">Cache budget in bytes. Must be positive.</param>
+    /// <param name="timeProvider">Clock for stale-entry cleanup; defaults to <see cref="TimeProvider.System"/>.</param>
+    /// <param name="isOffline">Optional offline-mode check; while it returns true, cache misses return null without downloading.</param>
+    /// <param name="logger">Optional logger for failed downloads.</param>
+    public ImagePipeline(
+        HttpClient httpClient,
+        long maxBytes = DefaultMaxBytes,
+        TimeProvider? timeProvider = null,
+        Func<bool>? isOffline = null,
+        ILogger? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(httpClient);
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxBytes);
+
+        _httpClient = httpClient;
+        MaxBytes = maxBytes;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _isOffline = isOffline;
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the cache budget in bytes.
+    /// </summary>
+    public long MaxBytes { get; }
+
+    /// <summary>
+    /// Gets the total size of the cached images.
+    /// </summary>
+    public long CurrentBytes
+    {
+        get
+        {
+            lock (_lock)
+            {
+                return _currentBytes;
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public string CacheName => "Images";
+
+    /// <inheritdoc />
+    public int CurrentCount
+    {
+        get
+        {
+            lock (_lock)
+            {
+                return _entries.Count;
+            }
+        }
+    }
+
+    /// <inheritdoc />
+    public async Task<byte[]?> GetAsync(string imageUri, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(imageUri);
+
+        var url = Normalize(imageUri);
+        Task<byte[]?>? download;
+
+        lock (_lock)
+        {
+            ObjectDisposedException.ThrowIf(_disposed, this);
+
+            if (TouchNoLock(url) is { } cached)
+                return cached;
+
+            if (_isOffline?.Invoke() == true)
+                return null;
+
+            if (!_inFlight.TryGetValue(url, out download))
+            {
+                download = Task.Run(() => DownloadAsync(url));
+                _inFlight[url] = download;
+            }
+        }
+
+        return await download.WaitAsync(cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public byte[]? TryGetCached(string imageUri)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(imageUri);
+
+        lock (_lock)
+        {
+            return TouchNoLock(Normalize(imageUri));
+        }
+    }
+
+    /// <summary>
+    /// Removes one image from the cache.
+    /// </summary>
+    /// <returns>True if it was cached.</returns>
+    public bool Remove(string imageUri)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(imageUri);
+
+        lock (_lock)
+        {
+            if (!_entries.TryGetValue(Normalize(imageUri), out var node))
+                return false;
+
+            RemoveNoLock(node);
+            return true;
+        }
+    }
+
+    /// <inheritdoc />
+    public Task<int> CleanupStaleEntriesAsync(TimeSpan maxAge, CancellationToken ct = default)
+    {
+        var cutoff = _timeProvider.GetUtcNow() - maxAge;
+        var removed = 0;
+
+        lock (_lock)
+        {
+            while (_lru.Last is { } oldest && oldest.Value.LastAccessed < cutoff)
+            {
+                RemoveNoLock(oldest);
+                removed++;
+            }
+        }
+
+        return Task.FromResult(removed);
+    }
+
+    /// <inheritdoc />
+    public Task<int> ClearAsync(CancellationToken ct = default)
+    {
+        lock (_lock)
+        {
+            var count = _entries.Count;
+            _entries.Clear();
+            _lru.Clear();
+            _currentBytes = 0;
+            return Task.FromResult(count);
+        }
+    }
+
+    /// <summary>
+    /// Cancels the downloads in flight and waits for them to finish before releasing
+    /// their token; their waiters get null.
+    /// </summary>
+    public void Dispose()
+    {
+        Task<byte[]?>[] inFlight;
+        lock (_lock)
+        {
+            if (_disposed)
+                return;
+            _disposed = true;
+            inFlight = _inFlight.Values.ToArray();
+        }
+
+        _disposeCts.Cancel();
+        // DownloadAsync never throws, and takes _lock only in its finally: wait outside it.
+        Task.WaitAll(inFlight);
+        _disposeCts.Dispose();
+    }
+
+    private async Task<byte[]?> DownloadAsync(string url)
+    {
+        try
+        {
+            using var response = await _httpClient.GetAsync(url, _disposeCts.Token);
+            if (!response.IsSuccessStatusCode)
+            {
+                _logger?.LogDebug("Image download failed for {Url}: {Status}", url, (int)response.StatusCode);
+                return null;
+            }
+
+            var bytes = await response.Content.ReadAsByteArrayAsync(_disposeCts.Token);
+            Store(url, bytes);
+            return bytes;
+        }
+        catch (OperationCanceledException) when (_disposeCts.IsCancellationRequested)
+        {
+            return null;
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogDebug(ex, "Image download failed for {Url}", url);
+            return null;
+        }
+        finally
+        {
+            lock (_lock)
+            {
+                _inFlight.Remove(url);
+            }
+        }
+    }
+
+    private void Store(string url, byte[] bytes)
+    {
+        if (bytes.Length > MaxBytes)
+            return;
+
+        lock (_lock)
+        {
+            if (_entries.TryGetValue(url, out var existing))
+                RemoveNoLock(existing);
+
+            var entry = new Entry(url, bytes) { LastAccessed = _timeProvider.GetUtcNow() };
+            _entries[url] = _lru.AddFirst(entry);
+            _currentBytes += bytes.Length;
+
+            while (_currentBytes > MaxBytes && _lru.Last is { } oldest)
+                RemoveNoLock(oldest);
+        }
+    }
+
+    private byte[]? TouchNoLock(string url)
+    {
+        if (!_entries.TryGetValue(url, out var node))
+            return null;
+
+        node.Value.LastAccessed = _timeProvider.GetUtcNow();
+        if (!ReferenceEquals(_lru.First, node))
+        {
+            _lru.Remove(node);
+            _lru.AddFirst(node);
+        }
+
+        return node.Value.Bytes;
+    }
+
+    private void RemoveNoLock(LinkedListNode<Entry> node)
+    {
+        _entries.Remove(node.Value.Url);
+        _lru.Remove(node);
+        _currentBytes -= node.Value.Bytes.Length;
+    }
+
+    private static string Normalize(string imageUri) =>
+        imageUri.StartsWith(ImageUriPrefix, StringComparison.Ordinal)
+            ? ImageUrlPrefix + imageUri[ImageUriPrefix.Length..]
+            : imageUri;
+}
@@ -56,13 +56,15 @@ internal sealed class Login5Client
     /// <param name="authData">Authentication data from stored credentials.</param>
     /// <param name="clientToken">Optional client token for additional security.</param>
     /// <param name="cancellationToken">Cancellation token.</param>
+    /// <param name="issuedAt">Request time on the caller's (server-corrected) clock; expiry is anchored to it.</param>
     /// <returns>Access token with expiry information.</returns>
     /// <exception cref="Login5Exception">Thrown if authentication fails.</exception>
     public async Task<AccessToken> GetAccessTokenAsync(
         string username,
         byte[] authData,
         string? clientToken = null,
-        CancellationToken cancellationToken = default)
+        CancellationToken cancellationToken = default,
+        DateTimeOffset? issuedAt = null)
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(username);
         ArgumentNullException.ThrowIfNull(authData);
@@ -108,7 +110,8 @@ internal sealed class Login5Client
 
         var accessToken = AccessToken.FromLogin5Response(
             response.Ok.AccessToken,
-            response.Ok.AccessTokenExpiresIn);
+            response.Ok.AccessTokenExpiresIn,
+            issuedAt);
 
         _logger?.LogInformation("Access token obtained (expires {ExpiresAt})", accessToken.ExpiresAt);
 
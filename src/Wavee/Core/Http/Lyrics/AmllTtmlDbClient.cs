@@ -1,5 +1,6 @@
 using System.Text.Json;
 using System.Text.RegularExpressions;
+using Wavee.Core.Storage;
 
 namespace Wavee.Core.Http.Lyrics;
 
@@ -23,7 +24,7 @@ public sealed class AmllTtmlDbClient
     private static readonly Regex PunctuationRegex = new(@"[\p{P}\p{S}]", RegexOptions.Compiled);
     private static readonly Regex CollapseWhitespaceRegex = new(@"\s+", RegexOptions.Compiled);
 
-    private static string CacheDir => Path.Combine(Path.GetTempPath(), "wavee");
+    private static string CacheDir => WaveePaths.Default.LyricsDirectory;
     private static string IndexPath => Path.Combine(CacheDir, "amll-ttml-index.jsonl");
     private static string TimestampPath => Path.Combine(CacheDir, "amll-ttml-index-ts.txt");
 
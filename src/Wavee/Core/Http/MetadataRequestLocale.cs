@@ -0,0 +1,133 @@
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Resolves the locale and market sent with metadata requests from the session overrides
+/// (<see cref="SessionConfig.MetadataLocale"/>, <see cref="SessionConfig.MetadataCountry"/>,
+/// the preferred locale) and the account defaults.
+/// </summary>
+internal static class MetadataRequestLocale
+{
+    /// <summary>
+    /// Gets the locale for <c>Accept-Language</c> and GraphQL <c>locale</c> variables,
+    /// or null when neither an override nor the account provides one.
+    /// </summary>
+    /// <remarks>
+    /// The preferred locale (set at startup or through <c>UpdateLocaleAsync</c>) picks the
+    /// language; <see cref="SessionConfig.MetadataLocale"/> only refines it to a region, so
+    /// changing the language at runtime is never silently overridden by config.
+    /// </remarks>
+    public static string? GetLocale(ISession session)
+    {
+        var preferred = session.GetPreferredLocale();
+        if (TryNormalizeLocale(session.Config.MetadataLocale, out var locale)
+            && (string.IsNullOrEmpty(preferred) || string.Equals(GetLanguage(locale), preferred, StringComparison.OrdinalIgnoreCase)))
+        {
+            return locale;
+        }
+
+        if (!string.IsNullOrEmpty(preferred))
+            return preferred;
+
+        return session.GetUserData()?.PreferredLocale;
+    }
+
+    /// <summary>
+    /// Gets the <c>Accept-Language</c> value for spclient metadata endpoints: the bare language
+    /// (for example <c>"pt"</c>, defaulting to <c>"en"</c>) as the desktop client sends it, or
+    /// the full <see cref="SessionConfig.MetadataLocale"/> when that override applies.
+    /// </summary>
+    public static string GetAcceptLanguage(ISession session)
+    {
+        var locale = GetLocale(session);
+        if (string.IsNullOrWhiteSpace(locale))
+            return "en";
+
+        return TryNormalizeLocale(session.Config.MetadataLocale, out var configured) && locale == configured
+            ? locale
+            : GetLanguage(locale);
+    }
+
+    /// <summary>
+    /// Gets the country for metadata lookups: <see cref="SessionConfig.MetadataCountry"/>
+    /// when set, otherwise the account country.
+    /// </summary>
+    public static async Task<string> GetCountryAsync(ISession session, CancellationToken cancellationToken)
+    {
+        if (TryNormalizeCountry(session.Config.MetadataCountry, out var country))
+            return country;
+
+        return await session.GetCountryCodeAsync(cancellationToken);
+    }
+
+    /// <summary>
+    /// Gets the value of a <c>market</c> query parameter: the override country, or
+    /// <c>from_token</c> to let Spotify use the account country.
+    /// </summary>
+    public static string GetMarket(ISession session)
+        => TryNormalizeCountry(session.Config.MetadataCountry, out var country) ? country : "from_token";
+
+    /// <summary>
+    /// Normalizes a BCP 47 tag such as <c>pt_br</c> to <c>pt-BR</c>. Accepts a 2-3 letter
+    /// language, optionally followed by a 4-letter script and/or a 2-letter or 3-digit region.
+    /// </summary>
+    public static bool TryNormalizeLocale(string? value, out string locale)
+    {
+        locale = string.Empty;
+        if (string.IsNullOrWhiteSpace(value))
+            return false;
+
+        var parts = value.Trim().Split('-', '_');
+        if (parts[0].Length is < 2 or > 3 || !parts[0].All(char.IsAsciiLetter) || parts.Length > 3)
+            return false;
+
+        var normalized = new List<string>(parts.Length) { parts[0].ToLowerInvariant() };
+        var index = 1;
+        if (index < parts.Length && parts[index].Length == 4 && parts[index].All(char.IsAsciiLetter))
+        {
+            normalized.Add(char.ToUpperInvariant(parts[index][0]) + parts[index][1..].ToLowerInvariant());
+            index++;
+        }
+        if (index < parts.Length)
+        {
+            var region = parts[index];
+            if (region.Length == 2 && region.All(char.IsAsciiLetter))
+                normalized.Add(region.ToUpperInvariant());
+            else if (region.Length == 3 && region.All(char.IsAsciiDigit))
+                normalized.Add(region);
+            else
+                return false;
+            index++;
+        }
+        if (index != parts.Length)
+            return false;
+
+        locale = string.Join('-', normalized);
+        return true;
+    }
+
+    /// <summary>
+    /// Normalizes an ISO 3166-1 alpha-2 country code to upper case.
+    /// </summary>
+    public static bool TryNormalizeCountry(string? value, out string country)
+    {
+        country = string.Empty;
+        if (string.IsNullOrWhiteSpace(value))
+            return false;
+
+        var trimmed = value.Trim();
+        if (trimmed.Length != 2 || !trimmed.All(char.IsAsciiLetter))
+            return false;
+
+        country = trimmed.ToUpperInvariant();
+        return true;
+    }
+
+    private static string GetLanguage(string locale)
+    {
+        var separatorIndex = locale.IndexOfAny(['-', '_']);
+        var language = separatorIndex > 0 ? locale[..separatorIndex] : locale;
+        return language.ToLowerInvariant();
+    }
+}
@@ -11,9 +11,7 @@ public sealed class HomeVariables
     public string HomeEndUserIntegration { get; set; } = "INTEGRATION_WEB_PLAYER";
 
     [JsonPropertyName("timeZone")]
-    public string TimeZone { get; set; } = TimeZoneInfo.Local.HasIanaId
-        ? TimeZoneInfo.Local.Id
-        : TimeZoneInfo.TryConvertWindowsIdToIanaId(TimeZoneInfo.Local.Id, out var iana) ? iana : "UTC";
+    public string TimeZone { get; set; } = ClientTimeZone.Resolve(null);
 
     [JsonPropertyName("sp_t")]
     public string SpT { get; set; } = "";
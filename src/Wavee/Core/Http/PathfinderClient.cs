@@ -428,6 +428,7 @@ public sealed class PathfinderClient : IPathfinderClient
     {
         var variables = new HomeVariables
         {
+            TimeZone = ClientTimeZone.Resolve(_session.Config.TimeZone),
             SectionItemsLimit = sectionItemsLimit,
             Facet = facet ?? ""
         };
@@ -889,17 +890,7 @@ public sealed class PathfinderClient : IPathfinderClient
             ct);
     }
 
-    private string? GetEffectiveLocale()
-    {
-        var sessionLocale = _session.GetPreferredLocale();
-        if (!string.IsNullOrEmpty(sessionLocale))
-        {
-            return sessionLocale;
-        }
-
-        var userData = _session.GetUserData();
-        return userData?.PreferredLocale;
-    }
+    private string? GetEffectiveLocale() => MetadataRequestLocale.GetLocale(_session);
 
     /// <inheritdoc />
     public async Task<ConcertLocationsResponse> GetConcertLocationByLatLonAsync(
@@ -1214,6 +1205,8 @@ public sealed class PathfinderClient : IPathfinderClient
         HttpRequestMessage request,
         CancellationToken cancellationToken)
     {
+        OfflineModeException.ThrowIfOffline(_session);
+
         Exception? lastException = null;
 
         for (int attempt = 0; attempt < MaxRetries; attempt++)
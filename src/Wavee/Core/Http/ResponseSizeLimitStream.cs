@@ -0,0 +1,90 @@
+namespace Wavee.Core.Http;
+
+/// <summary>
+/// Read-only wrapper that fails with <see cref="SpClientFailureReason.TooLarge"/> once more
+/// than a fixed number of bytes has come through, so a decoder reading a response
+/// incrementally stops before the message outgrows the device instead of after.
+/// </summary>
+/// <remarks>
+/// Wrap the <em>decoded</em> stream: the limit is on the protobuf bytes the parser sees, which
+/// is what the materialized message scales with. Compressed sizes are checked up front from
+/// Content-Length by <see cref="ExtendedMetadataClient.ParseResponseAsync{T}"/>.
+/// </remarks>
+internal sealed class ResponseSizeLimitStream : Stream
+{
+    private readonly Stream _inner;
+    private readonly long _maxBytes;
+    private readonly string _description;
+    private long _read;
+
+    public ResponseSizeLimitStream(Stream inner, long maxBytes, string description)
+    {
+        ArgumentNullException.ThrowIfNull(inner);
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxBytes);
+
+        _inner = inner;
+        _maxBytes = maxBytes;
+        _description = description;
+    }
+
+    /// <summary>
+    /// Bytes read so far.
+    /// </summary>
+    public long BytesRead => _read;
+
+    public override bool CanRead => true;
+    public override bool CanSeek => false;
+    public override bool CanWrite => false;
+    public override long Length => throw new NotSupportedException();
+
+    public override long Position
+    {
+        get => _read;
+        set => throw new NotSupportedException();
+    }
+
+    public override int Read(byte[] buffer, int offset, int count)
+        => Read(buffer.AsSpan(offset, count));
+
+    public override int Read(Span<byte> buffer)
+        => Count(_inner.Read(Limit(buffer)));
+
+    public override async ValueTask<int> ReadAsync(Memory<byte> buffer, CancellationToken cancellationToken = default)
+        => Count(await _inner.ReadAsync(Limit(buffer), cancellationToken).ConfigureAwait(false));
+
+    public override Task<int> ReadAsync(byte[] buffer, int offset, int count, CancellationToken cancellationToken)
+        => ReadAsync(buffer.AsMemory(offset, count), cancellationToken).AsTask();
+
+    public override void Flush() { }
+    public override long Seek(long offset, SeekOrigin origin) => throw new NotSupportedException();
+    public override void SetLength(long value) => throw new NotSupportedException();
+    public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
+
+    protected override void Dispose(bool disposing)
+    {
+        if (disposing)
+            _inner.Dispose();
+        base.Dispose(disposing);
+    }
+
+    // Ask for at most one byte past the limit: enough to tell "exactly at the limit" from "over".
+    private Span<byte> Limit(Span<byte> buffer)
+        => buffer[..(int)Math.Min(buffer.Length, _maxBytes - _read + 1)];
+
+    private Memory<byte> Limit(Memory<byte> buffer)
+        => buffer[..(int)Math.Min(buffer.Length, _maxBytes - _read + 1)];
+
+    private int Count(int read)
+    {
+        _read += read;
+        if (_read > _maxBytes)
+            throw TooLarge(_description, _maxBytes);
+        return read;
+    }
+
+    internal static SpClientException TooLarge(string description, long maxBytes, long? actualBytes = null)
+        => new(SpClientFailureReason.TooLarge,
+            $"{description} exceeds the {maxBytes:N0}-byte response limit" +
+            (actualBytes is { } actual ? $" ({actual:N0} bytes)" : string.Empty) +
+            " (network.maxResponseBytes)");
+}
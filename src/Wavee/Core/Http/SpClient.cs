@@ -22,6 +22,7 @@ using Wavee.Protocol.ExtendedMetadata;
 using Wavee.Protocol.Playplay;
 using Wavee.Protocol.Resumption;
 using Wavee.Protocol.Storage;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Core.Http;
 
@@ -60,6 +61,9 @@ public sealed class SpClient : ISpClient
     private readonly ILogger? _logger;
     private readonly ClientTokenManager? _clientTokenManager;
     private readonly IRemoteStateRecorder? _remoteStateRecorder;
+    private readonly ConditionalResponseCache? _responseCache;
+    private readonly TimeSpan _requestTimeout;
+    private readonly long _maxResponseBytes;
     private const string ExtendedMetadataContentType = "application/protobuf";
     private const string PlayerMetadataClientFeatureId = "player_mdata";
 
@@ -75,9 +79,13 @@ public sealed class SpClient : ISpClient
     /// <param name="httpClient">HTTP client for making requests.</param>
     /// <param name="baseUrl">Resolved SpClient endpoint (e.g., "spclient.wg.spotify.com:443").</param>
     /// <param name="logger">Optional logger for diagnostics.</param>
+    /// <param name="requestTimeout">Per-attempt timeout; defaults to <see cref="NetworkConfig.SpClientRequestTimeout"/>.</param>
+    /// <param name="responseCache">ETag cache for conditional GETs; null sends every GET unconditionally.</param>
+    /// <param name="maxResponseBytes">Largest decoded protobuf body parsed; defaults to <see cref="NetworkConfig.MaxResponseBytes"/>.</param>
     internal SpClient(ISession session, HttpClient httpClient, string baseUrl,
         ClientTokenManager? clientTokenManager = null, ILogger? logger = null,
-        IRemoteStateRecorder? remoteStateRecorder = null)
+        IRemoteStateRecorder? remoteStateRecorder = null, TimeSpan? requestTimeout = null,
+        ConditionalResponseCache? responseCache = null, long? maxResponseBytes = null)
     {
         ArgumentNullException.ThrowIfNull(session);
         ArgumentNullException.ThrowIfNull(httpClient);
@@ -88,6 +96,9 @@ public sealed class SpClient : ISpClient
         _clientTokenManager = clientTokenManager;
         _logger = logger;
         _remoteStateRecorder = remoteStateRecorder;
+        _responseCache = responseCache;
+        _requestTimeout = requestTimeout ?? new NetworkConfig().SpClientRequestTimeout;
+        _maxResponseBytes = maxResponseBytes ?? NetworkConfig.DefaultMaxResponseBytes;
 
         // Normalize base URL: remove port suffix and ensure https:// prefix
         var hostOnly = baseUrl.Split(':')[0];
@@ -115,6 +126,7 @@ public sealed class SpClient : ISpClient
         CancellationToken cancellationToken = default)
     {
         ArgumentNullException.ThrowIfNull(body);
+        OfflineModeException.ThrowIfOffline(_session);
 
         var accessToken = await _session.GetAccessTokenAsync(cancellationToken);
         var url = $"{_baseUrl}/gabo-receiver-service/v3/events/";
@@ -196,7 +208,7 @@ public sealed class SpClient : ISpClient
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(trackId);
 
-        var url = $"{_baseUrl}/metadata/4/track/{trackId}?market=from_token";
+        var url = $"{_baseUrl}/metadata/4/track/{trackId}?market={MetadataRequestLocale.GetMarket(_session)}";
         return await GetProtobufAsync(url, cancellationToken);
     }
 
@@ -213,7 +225,7 @@ public sealed class SpClient : ISpClient
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(albumId);
 
-        var url = $"{_baseUrl}/metadata/4/album/{albumId}?market=from_token";
+        var url = $"{_baseUrl}/metadata/4/album/{albumId}?market={MetadataRequestLocale.GetMarket(_session)}";
         return await GetProtobufAsync(url, cancellationToken);
     }
 
@@ -230,7 +242,7 @@ public sealed class SpClient : ISpClient
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(artistId);
 
-        var url = $"{_baseUrl}/metadata/4/artist/{artistId}?market=from_token";
+        var url = $"{_baseUrl}/metadata/4/artist/{artistId}?market={MetadataRequestLocale.GetMarket(_session)}";
         return await GetProtobufAsync(url, cancellationToken);
     }
 
@@ -279,7 +291,7 @@ public sealed class SpClient : ISpClient
         ExtensionKind extensionKind,
         CancellationToken cancellationToken)
     {
-        var countryCode = await _session.GetCountryCodeAsync(cancellationToken);
+        var countryCode = await MetadataRequestLocale.GetCountryAsync(_session, cancellationToken);
         var accountType = await _session.GetAccountTypeAsync(cancellationToken);
         var catalogue = accountType switch
         {
@@ -315,7 +327,7 @@ public sealed class SpClient : ISpClient
         httpRequest.Headers.AcceptEncoding.Add(new StringWithQualityHeaderValue("gzip"));
         httpRequest.Headers.AcceptEncoding.Add(new StringWithQualityHeaderValue("deflate"));
         httpRequest.Headers.Connection.Add("keep-alive");
-        httpRequest.Headers.TryAddWithoutValidation("Accept-Language", GetMetadataRequestLanguage());
+        httpRequest.Headers.TryAddWithoutValidation("Accept-Language", MetadataRequestLocale.GetAcceptLanguage(_session));
         httpRequest.Headers.TryAddWithoutValidation("App-Platform", SpotifyClientIdentity.AppPlatform);
         httpRequest.Headers.TryAddWithoutValidation("Spotify-App-Version", SpotifyClientIdentity.AppVersionHeader);
         httpRequest.Headers.TryAddWithoutValidation("client-feature-id", PlayerMetadataClientFeatureId);
@@ -364,7 +376,8 @@ public sealed class SpClient : ISpClient
 
         httpResponse.EnsureSuccessStatusCode();
 
-        var response = await ExtendedMetadataClient.ParseResponseAsync(BatchedExtensionResponse.Parser, httpResponse, cancellationToken);
+        var response = await ExtendedMetadataClient.ParseResponseAsync(
+            BatchedExtensionResponse.Parser, httpResponse, _maxResponseBytes, cancellationToken);
         var extensionData = response.GetExtensionData(entityUri, extensionKind);
         if (extensionData?.ExtensionData is null)
             throw new SpClientException(SpClientFailureReason.NotFound, $"Extended metadata not found: {entityUri}");
@@ -372,17 +385,6 @@ public sealed class SpClient : ISpClient
         return extensionData.ExtensionData.Value.ToByteArray();
     }
 
-    private string GetMetadataRequestLanguage()
-    {
-        var locale = GetEffectiveLocale();
-        if (string.IsNullOrWhiteSpace(locale))
-            return "en";
-
-        var separatorIndex = locale.IndexOfAny(['-', '_']);
-        var language = separatorIndex > 0 ? locale[..separatorIndex] : locale;
-        return language.ToLowerInvariant();
-    }
-
     /// <summary>
     /// Gets show (podcast) metadata.
     /// </summary>
@@ -396,7 +398,7 @@ public sealed class SpClient : ISpClient
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(showId);
 
-        var url = $"{_baseUrl}/metadata/4/show/{showId}?market=from_token";
+        var url = $"{_baseUrl}/metadata/4/show/{showId}?market={MetadataRequestLocale.GetMarket(_session)}";
         return await GetProtobufAsync(url, cancellationToken);
     }
 
@@ -763,7 +765,7 @@ public sealed class SpClient : ISpClient
         }
 
         // Send request with retry logic
-        var response = await SendWithRetryAsync(request, cancellationToken);
+        var response = await SendConditionalAsync(request, cancellationToken);
 
         switch (response.StatusCode)
         {
@@ -791,7 +793,7 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        return await response.Content.ReadAsByteArrayAsync(cancellationToken);
+        return await ExtendedMetadataClient.ReadResponseBytesAsync(response, _maxResponseBytes, cancellationToken);
     }
 
     #region Context Resolution
@@ -1211,8 +1213,8 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
-        var pageResponse = PageResponse.Parser.ParseFrom(responseBytes);
+        var pageResponse = await ExtendedMetadataClient.ParseResponseAsync(
+            PageResponse.Parser, response, _maxResponseBytes, cancellationToken);
 
         _logger?.LogDebug("Collection page fetched: {Set}, items={Count}, hasMore={HasMore}",
             set, pageResponse.Items.Count, !string.IsNullOrEmpty(pageResponse.NextPageToken));
@@ -1275,8 +1277,8 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
-        var deltaResponse = DeltaResponse.Parser.ParseFrom(responseBytes);
+        var deltaResponse = await ExtendedMetadataClient.ParseResponseAsync(
+            DeltaResponse.Parser, response, _maxResponseBytes, cancellationToken);
 
         _logger?.LogDebug("Collection delta fetched: {Set}, deltaUpdatePossible={DeltaUpdatePossible}, changes={Count}",
             set, deltaResponse.DeltaUpdatePossible, deltaResponse.Items.Count);
@@ -1563,7 +1565,7 @@ public sealed class SpClient : ISpClient
 
         _logger?.LogDebug("Fetching playlist: {Uri}", playlistUri);
 
-        var response = await SendWithRetryAsync(request, cancellationToken);
+        var response = await SendConditionalAsync(request, cancellationToken);
 
         switch (response.StatusCode)
         {
@@ -1587,7 +1589,7 @@ public sealed class SpClient : ISpClient
         // and contribute to the fragmentation observed in the navigation
         // perf trace. ParseFrom(Stream) reads the wire format incrementally.
         var content = await ExtendedMetadataClient.ParseResponseAsync(
-            Protocol.Playlist.SelectedListContent.Parser, response, cancellationToken);
+            Protocol.Playlist.SelectedListContent.Parser, response, _maxResponseBytes, cancellationToken);
 
         _logger?.LogDebug("Playlist fetched: {Uri}, length={Length}, revision={HasRevision}",
             playlistUri, content.Length, content.Revision?.Length > 0);
@@ -1687,8 +1689,8 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
-        var diffContent = Protocol.Playlist.SelectedListContent.Parser.ParseFrom(responseBytes);
+        var diffContent = await ExtendedMetadataClient.ParseResponseAsync(
+            Protocol.Playlist.SelectedListContent.Parser, response, _maxResponseBytes, cancellationToken);
 
         var diffNewRevB64 = diffContent.Revision?.Length > 0
             ? Convert.ToBase64String(diffContent.Revision.ToByteArray())
@@ -1697,7 +1699,7 @@ public sealed class SpClient : ISpClient
         var diffContentsItems = diffContent.Contents?.Items?.Count ?? 0;
         _logger?.LogInformation(
             "[playlist-diff] response status={Code} bytes={Bytes} upToDate={UpToDate} newRev={New} ops={Ops} contents.items={CI} length={Length}",
-            (int)response.StatusCode, responseBytes.Length, diffContent.UpToDate,
+            (int)response.StatusCode, diffContent.CalculateSize(), diffContent.UpToDate,
             diffNewRevB64, diffOpsCount, diffContentsItems, diffContent.Length);
 
         return diffContent;
@@ -1747,7 +1749,7 @@ public sealed class SpClient : ISpClient
             case HttpStatusCode.Forbidden:
                 throw new SpClientException(SpClientFailureReason.Unauthorized, $"Cannot modify playlist: {playlistUri}");
             case HttpStatusCode.Conflict:
-                throw new SpClientException(SpClientFailureReason.RequestFailed, "Playlist revision conflict - refetch and retry");
+                throw new SpClientException(SpClientFailureReason.RevisionConflict, "Playlist revision conflict - refetch and retry");
         }
 
         if ((int)response.StatusCode >= 500)
@@ -1757,8 +1759,8 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
-        responseBytes = MaybeDecompressZstd(responseBytes, response.Content.Headers.ContentEncoding);
+        var responseBytes = await ExtendedMetadataClient.ReadResponseBytesAsync(response, _maxResponseBytes, cancellationToken);
+        responseBytes = MaybeDecompressZstd(responseBytes, []);
         return Protocol.Playlist.SelectedListContent.Parser.ParseFrom(responseBytes);
     }
 
@@ -1896,7 +1898,7 @@ public sealed class SpClient : ISpClient
             Info = new Protocol.Playlist.ChangeInfo
             {
                 User = canonicalUsername,
-                Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds(),
+                Timestamp = _session.Clock.NowMs,
             },
         };
 
@@ -1959,15 +1961,15 @@ public sealed class SpClient : ISpClient
             case HttpStatusCode.Forbidden:
                 throw new SpClientException(SpClientFailureReason.Unauthorized, "Cannot modify rootlist");
             case HttpStatusCode.Conflict:
-                throw new SpClientException(SpClientFailureReason.RequestFailed, "Rootlist revision conflict - refetch and retry");
+                throw new SpClientException(SpClientFailureReason.RevisionConflict, "Rootlist revision conflict - refetch and retry");
         }
         if ((int)response.StatusCode >= 500)
             throw new SpClientException(SpClientFailureReason.ServerError, $"Server error: {response.StatusCode}");
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
-        return Protocol.Playlist.SelectedListContent.Parser.ParseFrom(responseBytes);
+        return await ExtendedMetadataClient.ParseResponseAsync(
+            Protocol.Playlist.SelectedListContent.Parser, response, _maxResponseBytes, cancellationToken);
     }
 
     /// <summary>
@@ -2111,7 +2113,7 @@ public sealed class SpClient : ISpClient
 
         response.EnsureSuccessStatusCode();
 
-        var responseBytes = await response.Content.ReadAsByteArrayAsync(cancellationToken);
+        var responseBytes = await ExtendedMetadataClient.ReadResponseBytesAsync(response, _maxResponseBytes, cancellationToken);
         var parsed = Protocol.Playlist.SelectedListContent.Parser.ParseFrom(responseBytes);
 
         // Diagnostic: how much state does Spotify ship in the /signals
@@ -2220,12 +2222,54 @@ public sealed class SpClient : ISpClient
     /// <summary>
     /// Fetches a user's following list via the spclient profile endpoint.
     /// </summary>
-    public async Task<SpotifyFollowingResponse> GetUserFollowingAsync(
+    public Task<SpotifyFollowingResponse> GetUserFollowingAsync(
+        string username, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(username);
+
+        return GetProfileViewAsync(
+            $"{Uri.EscapeDataString(ToUsername(username))}/following?market=from_token",
+            SpotifyUserProfileJsonContext.Default.SpotifyFollowingResponse,
+            "user following",
+            cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<SpotifyFollowingResponse> GetUserFollowersAsync(
         string username, CancellationToken cancellationToken = default)
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(username);
 
-        var url = $"{_baseUrl}/user-profile-view/v3/profile/{Uri.EscapeDataString(username)}/following?market=from_token";
+        return GetProfileViewAsync(
+            $"{Uri.EscapeDataString(ToUsername(username))}/followers?market=from_token",
+            SpotifyUserProfileJsonContext.Default.SpotifyFollowingResponse,
+            "user followers",
+            cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<SpotifyPublicPlaylistsPage> GetUserPublicPlaylistsAsync(
+        string username, int offset = 0, int limit = 50, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(username);
+        ArgumentOutOfRangeException.ThrowIfNegative(offset);
+        ArgumentOutOfRangeException.ThrowIfLessThan(limit, 1);
+        ArgumentOutOfRangeException.ThrowIfGreaterThan(limit, 200);
+
+        return GetProfileViewAsync(
+            $"{Uri.EscapeDataString(ToUsername(username))}/playlists?offset={offset}&limit={limit}&market=from_token",
+            SpotifyUserProfileJsonContext.Default.SpotifyPublicPlaylistsPage,
+            "user playlists",
+            cancellationToken);
+    }
+
+    private async Task<T> GetProfileViewAsync<T>(
+        string pathAndQuery,
+        System.Text.Json.Serialization.Metadata.JsonTypeInfo<T> typeInfo,
+        string description,
+        CancellationToken cancellationToken) where T : class
+    {
+        var url = $"{_baseUrl}/user-profile-view/v3/profile/{pathAndQuery}";
         var accessToken = await _session.GetAccessTokenAsync(cancellationToken);
 
         using var request = new HttpRequestMessage(HttpMethod.Get, url);
@@ -2245,19 +2289,27 @@ public sealed class SpClient : ISpClient
 
         var response = await SendWithRetryAsync(request, cancellationToken);
 
+        if (response.StatusCode == HttpStatusCode.NotFound)
+            throw new SpClientException(SpClientFailureReason.NotFound, $"Failed to get {description}: user not found");
+
         if (!response.IsSuccessStatusCode)
         {
             var body = await response.Content.ReadAsStringAsync(cancellationToken);
             throw new SpClientException(
                 SpClientFailureReason.ServerError,
-                $"Failed to get user following: {response.StatusCode} - {body}");
+                $"Failed to get {description}: {response.StatusCode} - {body}");
         }
 
         var json = await response.Content.ReadAsStreamAsync(cancellationToken);
-        return await JsonSerializer.DeserializeAsync(json, SpotifyUserProfileJsonContext.Default.SpotifyFollowingResponse, cancellationToken)
-            ?? throw new SpClientException(SpClientFailureReason.InvalidResponse, "Empty following response");
+        return await JsonSerializer.DeserializeAsync(json, typeInfo, cancellationToken)
+            ?? throw new SpClientException(SpClientFailureReason.InvalidResponse, $"Empty {description} response");
     }
 
+    private static string ToUsername(string usernameOrUri)
+        => usernameOrUri.StartsWith("spotify:user:", StringComparison.Ordinal)
+            ? usernameOrUri["spotify:user:".Length..]
+            : usernameOrUri;
+
     /// <inheritdoc />
     public Task<bool> FollowUserAsync(string usernameOrUri, CancellationToken cancellationToken = default)
         => SetUserFollowAsync(usernameOrUri, follow: true, cancellationToken);
@@ -2293,6 +2345,36 @@ public sealed class SpClient : ISpClient
         return true;
     }
 
+    /// <inheritdoc />
+    public async Task<bool> IsFollowingUserAsync(string usernameOrUri, CancellationToken cancellationToken = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(usernameOrUri);
+
+        var id = ToUsername(usernameOrUri);
+        var url = $"https://api.spotify.com/v1/me/following/contains?type=user&ids={Uri.EscapeDataString(id)}";
+        var accessToken = await _session.GetAccessTokenAsync(cancellationToken);
+
+        using var request = new HttpRequestMessage(HttpMethod.Get, url);
+        request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", accessToken.Token);
+
+        var response = await SendWithRetryAsync(request, cancellationToken);
+        if (!response.IsSuccessStatusCode)
+        {
+            var body = await response.Content.ReadAsStringAsync(cancellationToken);
+            throw new SpClientException(
+                SpClientFailureReason.ServerError,
+                $"Failed to check follow state for user {id}: {response.StatusCode} - {body}");
+        }
+
+        // Response is a bool array aligned with the ids query parameter.
+        using var json = await JsonDocument.ParseAsync(
+            await response.Content.ReadAsStreamAsync(cancellationToken),
+            cancellationToken: cancellationToken);
+        return json.RootElement.ValueKind == JsonValueKind.Array
+            && json.RootElement.GetArrayLength() > 0
+            && json.RootElement[0].ValueKind == JsonValueKind.True;
+    }
+
     /// <inheritdoc />
     public async Task<long> GetPlaylistFollowerCountAsync(
         string playlistId, CancellationToken cancellationToken = default)
@@ -2505,6 +2587,8 @@ public sealed class SpClient : ISpClient
     /// </summary>
     public async Task<long> GetMelodyTimeAsync(CancellationToken cancellationToken = default)
     {
+        OfflineModeException.ThrowIfOffline(_session);
+
         var accessToken = await _session.GetAccessTokenAsync(cancellationToken);
 
         var url = $"{_baseUrl}/melody/v1/time";
@@ -2781,20 +2865,8 @@ public sealed class SpClient : ISpClient
     /// <summary>
     /// Gets the effective locale for API requests.
     /// </summary>
-    /// <returns>Locale string (e.g., "en", "es", "fr") or null if not available.</returns>
-    private string? GetEffectiveLocale()
-    {
-        // 1. Use session override if set (via UpdateLocaleAsync)
-        var sessionLocale = _session.GetPreferredLocale();
-        if (!string.IsNullOrEmpty(sessionLocale))
-        {
-            return sessionLocale;
-        }
-
-        // 2. Fall back to Spotify's locale from ProductInfo packet
-        var userData = _session.GetUserData();
-        return userData?.PreferredLocale;
-    }
+    /// <returns>Locale string (e.g., "en", "pt-BR") or null if not available.</returns>
+    private string? GetEffectiveLocale() => MetadataRequestLocale.GetLocale(_session);
 
     /// <summary>
     /// Sends an HTTP request with exponential backoff retry logic.
@@ -2876,10 +2948,55 @@ public sealed class SpClient : ISpClient
             ? "Win32_ARM64"
             : SpotifyClientIdentity.AppPlatform;
 
+    /// <summary>
+    /// Sends a GET through <see cref="SendWithRetryAsync"/>, revalidating against the
+    /// <see cref="ConditionalResponseCache"/>: a cached ETag is sent as <c>If-None-Match</c>,
+    /// and a <c>304</c> is turned back into a <c>200</c> carrying the cached body, so callers
+    /// never see the difference.
+    /// </summary>
+    private async Task<HttpResponseMessage> SendConditionalAsync(
+        HttpRequestMessage request,
+        CancellationToken cancellationToken)
+    {
+        if (_responseCache is null || request.Method != HttpMethod.Get || request.RequestUri is null)
+            return await SendWithRetryAsync(request, cancellationToken);
+
+        var scope = _session.GetUserData()?.Username ?? string.Empty;
+        var url = request.RequestUri.ToString();
+        var cached = _responseCache.Get(scope, url);
+        if (cached is not null)
+            request.Headers.IfNoneMatch.Add(cached.ETag);
+
+        var response = await SendWithRetryAsync(request, cancellationToken);
+
+        if (response.StatusCode == HttpStatusCode.NotModified && cached is not null)
+        {
+            _logger?.LogDebug("SpClient 304 for {Url}, serving {Size} cached bytes", url, cached.Body.Length);
+            response.Dispose();
+            return ConditionalResponseCache.ToResponse(cached, request);
+        }
+
+        if (response.IsSuccessStatusCode && response.Headers.ETag is { } etag)
+        {
+            var body = await response.Content.ReadAsByteArrayAsync(cancellationToken);
+            var contentType = response.Content.Headers.ContentType;
+            _responseCache.Set(scope, url, etag, body, contentType);
+
+            var original = response.Content;
+            response.Content = new ByteArrayContent(body);
+            response.Content.Headers.ContentType = contentType;
+            original.Dispose();
+        }
+
+        return response;
+    }
+
     private async Task<HttpResponseMessage> SendWithRetryAsync(
         HttpRequestMessage request,
         CancellationToken cancellationToken)
     {
+        OfflineModeException.ThrowIfOffline(_session);
+
         Exception? lastException = null;
 
         for (int attempt = 0; attempt < MaxRetries; attempt++)
@@ -2892,9 +3009,16 @@ public sealed class SpClient : ISpClient
             if (cancellationToken.IsCancellationRequested)
                 cancellationToken.ThrowIfCancellationRequested();
 
+            using var attemptCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+            attemptCts.CancelAfter(_requestTimeout);
+
             try
             {
-                var response = await _httpClient.SendAsync(request, cancellationToken);
+                var response = await _httpClient.SendAsync(request, attemptCts.Token);
+
+                // spclient's Date header doubles as a coarse skew hint before melody sync lands.
+                if (response.Headers.Date is { } serverDate)
+                    _session.Clock.ObserveServerTime(serverDate, TimeSpan.FromSeconds(1));
 
                 // Only these are retryable status codes. Every other
                 // response (including 4xx NotFound / Unauthorized / Forbidden)
@@ -2926,6 +3050,24 @@ public sealed class SpClient : ISpClient
                 // into MaxRetries first-chance OCE throws under a debugger.
                 throw;
             }
+            catch (OperationCanceledException ex) when (attemptCts.IsCancellationRequested)
+            {
+                // Per-attempt timeout, not the caller: retry like any other transient failure.
+                lastException = new TimeoutException(
+                    $"SpClient request timed out after {_requestTimeout.TotalSeconds}s", ex);
+                _logger?.LogWarning(
+                    "SpClient HTTP request timed out after {Timeout}s (attempt {Attempt}/{MaxRetries})",
+                    _requestTimeout.TotalSeconds,
+                    attempt + 1,
+                    MaxRetries);
+
+                if (attempt < MaxRetries - 1)
+                {
+                    var delay = TimeSpan.FromSeconds(Math.Pow(2, attempt));
+                    await Task.Delay(delay, cancellationToken);
+                    continue;
+                }
+            }
             catch (HttpRequestException ex)
             {
                 lastException = ex;
@@ -3032,6 +3174,7 @@ public sealed class SpClient : ISpClient
         ArgumentNullException.ThrowIfNull(uri);
         if (!uri.IsAbsoluteUri)
             throw new ArgumentException("Video segment URI must be absolute.", nameof(uri));
+        OfflineModeException.ThrowIfOffline(_session);
 
         _logger?.LogDebug("[DRM] Video segment GET {Url}", SanitizeLogUri(uri));
 
@@ -3223,6 +3366,7 @@ public sealed class SpClient : ISpClient
     private static string SanitizeLogUri(Uri uri)
     {
         var text = uri.ToString();
+        if (LogRedaction.UnsafeLogSecrets) return text;
         var queryIndex = text.IndexOf('?');
         return queryIndex >= 0 ? text[..queryIndex] : text;
     }
@@ -59,5 +59,16 @@ public enum SpClientFailureReason
     /// <summary>
     /// Response body was empty or could not be deserialized.
     /// </summary>
-    InvalidResponse
+    InvalidResponse,
+
+    /// <summary>
+    /// Conflict (409) - the base revision of a playlist/rootlist change is stale.
+    /// </summary>
+    RevisionConflict,
+
+    /// <summary>
+    /// Response body is larger than <see cref="Session.NetworkConfig.MaxResponseBytes"/>;
+    /// decoding stopped before it was materialized.
+    /// </summary>
+    TooLarge
 }
@@ -10,6 +10,7 @@ namespace Wavee.Core.Http;
 /// </summary>
 [JsonSerializable(typeof(SpotifyUserProfile))]
 [JsonSerializable(typeof(SpotifyFollowingResponse))]
+[JsonSerializable(typeof(SpotifyPublicPlaylistsPage))]
 [JsonSourceGenerationOptions(PropertyNameCaseInsensitive = true)]
 internal partial class SpotifyUserProfileJsonContext : JsonSerializerContext
 {
@@ -24,6 +25,19 @@ public sealed record SpotifyFollowingResponse
     public IReadOnlyList<SpotifyProfileArtist>? Profiles { get; init; }
 }
 
+/// <summary>
+/// Response from spclient /user-profile-view/v3/profile/{username}/playlists: one page of
+/// a user's public playlists.
+/// </summary>
+public sealed record SpotifyPublicPlaylistsPage
+{
+    [JsonPropertyName("public_playlists")]
+    public IReadOnlyList<SpotifyProfilePlaylist>? PublicPlaylists { get; init; }
+
+    [JsonPropertyName("total_public_playlists_count")]
+    public int? TotalPublicPlaylistsCount { get; init; }
+}
+
 /// <summary>
 /// User profile from spclient /user-profile-view/v3/profile/{username}.
 /// Also handles public Web API /v1/me response shape.
@@ -41,6 +55,9 @@ public sealed record SpotifyUserProfile
     [JsonPropertyName("image_url")]
     public string? ImageUrl { get; init; }
 
+    [JsonPropertyName("followers_count")]
+    public int? FollowersCount { get; init; }
+
     [JsonPropertyName("following_count")]
     public int? FollowingCount { get; init; }
 
@@ -170,6 +187,9 @@ public sealed record SpotifyProfilePlaylist
     [JsonPropertyName("owner_uri")]
     public string? OwnerUri { get; init; }
 
+    [JsonPropertyName("followers_count")]
+    public int? FollowersCount { get; init; }
+
     [JsonPropertyName("is_following")]
     public bool? IsFollowing { get; init; }
 }
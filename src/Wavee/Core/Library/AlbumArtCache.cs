@@ -13,8 +13,9 @@ public interface IAlbumArtCache
     /// </summary>
     /// <param name="artData">Raw image data.</param>
     /// <param name="mimeType">MIME type of the image (image/jpeg, image/png, etc.).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
     /// <returns>File path to the cached image, or null if no data provided.</returns>
-    Task<string?> CacheArtAsync(byte[]? artData, string? mimeType);
+    Task<string?> CacheArtAsync(byte[]? artData, string? mimeType, CancellationToken cancellationToken = default);
 
     /// <summary>
     /// Gets the path to a cached image by its hash, if it exists.
@@ -27,8 +28,9 @@ public interface IAlbumArtCache
     /// Clears old cached images that haven't been accessed recently.
     /// </summary>
     /// <param name="maxAge">Maximum age of cached files to keep.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
     /// <returns>Number of files deleted.</returns>
-    Task<int> CleanupAsync(TimeSpan maxAge);
+    Task<int> CleanupAsync(TimeSpan maxAge, CancellationToken cancellationToken = default);
 }
 
 /// <summary>
@@ -50,7 +52,7 @@ public sealed class AlbumArtCache : IAlbumArtCache
     }
 
     /// <inheritdoc/>
-    public async Task<string?> CacheArtAsync(byte[]? artData, string? mimeType)
+    public async Task<string?> CacheArtAsync(byte[]? artData, string? mimeType, CancellationToken cancellationToken = default)
     {
         if (artData == null || artData.Length == 0)
             return null;
@@ -76,13 +78,13 @@ public sealed class AlbumArtCache : IAlbumArtCache
         }
 
         // Write to cache (with lock to prevent concurrent writes of same file)
-        await _writeLock.WaitAsync();
+        await _writeLock.WaitAsync(cancellationToken);
         try
         {
             // Double-check after acquiring lock
             if (!File.Exists(cachedPath))
             {
-                await File.WriteAllBytesAsync(cachedPath, artData);
+                await File.WriteAllBytesAsync(cachedPath, artData, cancellationToken);
             }
         }
         finally
@@ -107,7 +109,7 @@ public sealed class AlbumArtCache : IAlbumArtCache
     }
 
     /// <inheritdoc/>
-    public Task<int> CleanupAsync(TimeSpan maxAge)
+    public Task<int> CleanupAsync(TimeSpan maxAge, CancellationToken cancellationToken = default)
     {
         var cutoff = DateTime.UtcNow - maxAge;
         var deleted = 0;
@@ -117,6 +119,8 @@ public sealed class AlbumArtCache : IAlbumArtCache
             var files = Directory.GetFiles(_cacheDir);
             foreach (var file in files)
             {
+                cancellationToken.ThrowIfCancellationRequested();
+
                 try
                 {
                     var lastAccess = File.GetLastAccessTimeUtc(file);
@@ -132,7 +136,7 @@ public sealed class AlbumArtCache : IAlbumArtCache
                 }
             }
         }
-        catch
+        catch (Exception ex) when (ex is not OperationCanceledException)
         {
             // Ignore directory access failures
         }
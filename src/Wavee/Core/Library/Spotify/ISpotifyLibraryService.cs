@@ -118,6 +118,25 @@ public interface ISpotifyLibraryService : IAsyncDisposable
     /// </summary>
     Task<bool> IsAlbumSavedAsync(string albumUri, CancellationToken ct = default);
 
+    /// <summary>
+    /// Checks if an artist is followed, from the local database.
+    /// </summary>
+    Task<bool> IsArtistFollowedAsync(string artistUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Checks if a playlist is in the user's rootlist (owned or followed).
+    /// Reads the cached rootlist; false when no playlist cache is configured.
+    /// </summary>
+    Task<bool> IsPlaylistFollowedAsync(string playlistUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Checks if the current user follows a user. Always asks the Web API —
+    /// user follows are not synced locally.
+    /// </summary>
+    /// <param name="userUri">A bare user id or a <c>spotify:user:</c> URI.</param>
+    /// <param name="ct">Cancellation token.</param>
+    Task<bool> IsUserFollowedAsync(string userUri, CancellationToken ct = default);
+
     #endregion
 
     #region Write Operations (Modify Spotify's Library)
@@ -135,6 +154,22 @@ public interface ISpotifyLibraryService : IAsyncDisposable
     /// </summary>
     Task<bool> RemoveTrackAsync(string trackUri, CancellationToken ct = default);
 
+    /// <summary>
+    /// Sets the liked state of a track. Convenience for a heart button: routes to
+    /// <see cref="SaveTrackAsync"/> or <see cref="RemoveTrackAsync"/>.
+    /// </summary>
+    /// <param name="trackUri">The track URI.</param>
+    /// <param name="liked">True to save the track, false to remove it.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>True if the local state was updated.</returns>
+    Task<bool> SetTrackLikedAsync(string trackUri, bool liked, CancellationToken ct = default);
+
+    /// <summary>
+    /// Flips the liked state of a track.
+    /// </summary>
+    /// <returns>The new liked state, or the unchanged state if the write failed.</returns>
+    Task<bool> ToggleTrackLikedAsync(string trackUri, CancellationToken ct = default);
+
     /// <summary>
     /// Saves an album to the library.
     /// </summary>
@@ -155,6 +190,32 @@ public interface ISpotifyLibraryService : IAsyncDisposable
     /// </summary>
     Task<bool> UnfollowArtistAsync(string artistUri, CancellationToken ct = default);
 
+    /// <summary>
+    /// Follows a playlist by adding it to the top of the user's rootlist. Unlike the
+    /// collection writes this goes straight to the server; the emitted change is
+    /// reverted if the rootlist write fails.
+    /// </summary>
+    /// <returns>True if the playlist is followed afterwards.</returns>
+    Task<bool> FollowPlaylistAsync(string playlistUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Unfollows a playlist by removing it from the user's rootlist.
+    /// </summary>
+    /// <returns>True if the playlist is no longer followed afterwards.</returns>
+    Task<bool> UnfollowPlaylistAsync(string playlistUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Follows a user via the Web API. The emitted change is reverted on failure.
+    /// </summary>
+    /// <param name="userUri">A bare user id or a <c>spotify:user:</c> URI.</param>
+    /// <param name="ct">Cancellation token.</param>
+    Task<bool> FollowUserAsync(string userUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Unfollows a user via the Web API. The emitted change is reverted on failure.
+    /// </summary>
+    Task<bool> UnfollowUserAsync(string userUri, CancellationToken ct = default);
+
     /// <summary>
     /// Subscribes to (follows) a podcast show.
     /// </summary>
@@ -186,7 +247,7 @@ public interface ISpotifyLibraryService : IAsyncDisposable
     /// Processes pending outbox operations, syncing local changes to Spotify API.
     /// Call periodically or after reconnect. Returns the number of failed operations.
     /// </summary>
-    Task<int> ProcessOutboxAsync();
+    Task<int> ProcessOutboxAsync(CancellationToken ct = default);
 
     #endregion
 
@@ -202,9 +263,46 @@ public interface ISpotifyLibraryService : IAsyncDisposable
     /// </summary>
     IObservable<SyncProgress> SyncProgress { get; }
 
+    /// <summary>
+    /// Per-item saved-state changes. Emits immediately on local save/remove
+    /// (optimistic, before the outbox reaches Spotify), when a failed local write
+    /// is reverted, and for every item in a real-time collection update.
+    /// Playlist and user follows report here too, as
+    /// <see cref="SpotifyLibraryItemType.Playlist"/> and <see cref="SpotifyLibraryItemType.User"/>.
+    /// </summary>
+    IObservable<SavedStateChangedEvent> SavedStateChanged { get; }
+
     #endregion
 }
 
+/// <summary>
+/// Saved-state change for a single library item.
+/// </summary>
+/// <param name="Uri">Spotify URI of the item.</param>
+/// <param name="ItemType">Library item type.</param>
+/// <param name="IsSaved">The new saved state.</param>
+/// <param name="Source">Where the change originated.</param>
+public sealed record SavedStateChangedEvent(
+    string Uri,
+    SpotifyLibraryItemType ItemType,
+    bool IsSaved,
+    SavedStateChangeSource Source);
+
+/// <summary>
+/// Origin of a <see cref="SavedStateChangedEvent"/>.
+/// </summary>
+public enum SavedStateChangeSource
+{
+    /// <summary>Optimistic local change made through this service.</summary>
+    Local,
+
+    /// <summary>A local change rolled back because the write failed.</summary>
+    Reverted,
+
+    /// <summary>Change pushed from another device via the dealer.</summary>
+    Remote
+}
+
 /// <summary>
 /// Progress update for library sync operations.
 /// </summary>
@@ -1,6 +1,7 @@
 using System.Reactive.Linq;
 using System.Reactive.Subjects;
 using System.Text.Json;
+using Google.Protobuf;
 using Microsoft.Extensions.Logging;
 using Wavee.Connect;
 using Wavee.Core.Http;
@@ -48,6 +49,7 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     private readonly ILogger? _logger;
     private readonly Subject<SyncProgress> _progressSubject = new();
     private readonly Subject<LibraryChangeEvent> _libraryChanged = new();
+    private readonly Subject<SavedStateChangedEvent> _savedStateChanged = new();
     private IDisposable? _changeSubscription;
     private bool _disposed;
 
@@ -96,6 +98,9 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     /// </summary>
     public IObservable<LibraryChangeEvent> LibraryChanged => _libraryChanged.AsObservable();
 
+    /// <inheritdoc/>
+    public IObservable<SavedStateChangedEvent> SavedStateChanged => _savedStateChanged.AsObservable();
+
     private string GetUsername()
     {
         var userData = _session.GetUserData();
@@ -890,6 +895,31 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
         return await _database.IsInSpotifyLibraryAsync(albumUri, ct);
     }
 
+    /// <inheritdoc/>
+    public async Task<bool> IsArtistFollowedAsync(string artistUri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(artistUri);
+        return await _database.IsInSpotifyLibraryAsync(artistUri, ct);
+    }
+
+    /// <inheritdoc/>
+    public async Task<bool> IsPlaylistFollowedAsync(string playlistUri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(playlistUri);
+        if (_playlistCache == null)
+            return false;
+
+        var rootlist = await _playlistCache.GetRootlistAsync(ct: ct);
+        return RootlistGraph.FindRootlistPlaylistIndex(rootlist, playlistUri) >= 0;
+    }
+
+    /// <inheritdoc/>
+    public Task<bool> IsUserFollowedAsync(string userUri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(userUri);
+        return _spClient.IsFollowingUserAsync(userUri, ct);
+    }
+
     #endregion
 
     #region Write Operations
@@ -902,6 +932,18 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     public Task<bool> RemoveTrackAsync(string trackUri, CancellationToken ct = default)
         => RemoveItemAsync(trackUri, CollectionSet, SpotifyLibraryItemType.Track, "track", ct);
 
+    /// <inheritdoc/>
+    public Task<bool> SetTrackLikedAsync(string trackUri, bool liked, CancellationToken ct = default)
+        => liked ? SaveTrackAsync(trackUri, ct) : RemoveTrackAsync(trackUri, ct);
+
+    /// <inheritdoc/>
+    public async Task<bool> ToggleTrackLikedAsync(string trackUri, CancellationToken ct = default)
+    {
+        var liked = await IsTrackLikedAsync(trackUri, ct);
+        var ok = await SetTrackLikedAsync(trackUri, !liked, ct);
+        return ok ? !liked : liked;
+    }
+
     /// <inheritdoc/>
     public Task<bool> SaveAlbumAsync(string albumUri, CancellationToken ct = default)
         => SaveItemAsync(albumUri, CollectionSet, SpotifyLibraryItemType.Album, "album", ct);
@@ -918,6 +960,22 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     public Task<bool> UnfollowArtistAsync(string artistUri, CancellationToken ct = default)
         => RemoveItemAsync(artistUri, ArtistsSet, SpotifyLibraryItemType.Artist, "artist", ct);
 
+    /// <inheritdoc/>
+    public Task<bool> FollowPlaylistAsync(string playlistUri, CancellationToken ct = default)
+        => SetPlaylistFollowedAsync(playlistUri, follow: true, ct);
+
+    /// <inheritdoc/>
+    public Task<bool> UnfollowPlaylistAsync(string playlistUri, CancellationToken ct = default)
+        => SetPlaylistFollowedAsync(playlistUri, follow: false, ct);
+
+    /// <inheritdoc/>
+    public Task<bool> FollowUserAsync(string userUri, CancellationToken ct = default)
+        => SetUserFollowedAsync(userUri, follow: true, ct);
+
+    /// <inheritdoc/>
+    public Task<bool> UnfollowUserAsync(string userUri, CancellationToken ct = default)
+        => SetUserFollowedAsync(userUri, follow: false, ct);
+
     /// <summary>
     /// Subscribes to a podcast show.
     /// </summary>
@@ -1095,11 +1153,20 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(uri);
 
+        // What a failed save reverts listeners (and the database) to.
+        var wasSaved = await _database.IsInSpotifyLibraryAsync(uri, ct);
+
+        // Emit before writing so a heart button flips instantly; reverted below
+        // if the local write or the enqueue fails.
+        _savedStateChanged.OnNext(new SavedStateChangedEvent(uri, itemType, true, SavedStateChangeSource.Local));
+        var stored = false;
+
         try
         {
             // 1. Optimistically update local database (instant UI feedback)
             var addedAt = DateTimeOffset.UtcNow.ToUnixTimeSeconds();
             await _database.AddToSpotifyLibraryAsync(uri, itemType, addedAt, ct);
+            stored = true;
 
             // 2. Ensure entity metadata exists so INNER JOIN queries find this item
             var extensionKind = itemType switch
@@ -1122,7 +1189,7 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
                 }
             }
 
-            // 3. Enqueue for background API sync (no rollback — local state is source of truth)
+            // 3. Enqueue for background API sync. Once enqueued, local state is the source of truth.
             var savePayload = JsonSerializer.Serialize(new LibraryOpPayload(itemType), LibraryOpJson.Default.LibraryOpPayload);
             await _database.EnqueueOutboxAsync(LibrarySaveHandler.Kind, uri, savePayload, ct);
 
@@ -1136,6 +1203,10 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
         catch (Exception ex)
         {
             _logger?.LogError(ex, "Failed to save {ItemType}: {Uri}", displayName, uri);
+            // Nothing will sync the local row to the server, so take it back out.
+            if (stored && !wasSaved)
+                await RollBackLibraryWriteAsync(() => _database.RemoveFromSpotifyLibraryAsync(uri, itemType, CancellationToken.None), uri);
+            _savedStateChanged.OnNext(new SavedStateChangedEvent(uri, itemType, wasSaved, SavedStateChangeSource.Reverted));
             return false;
         }
     }
@@ -1149,10 +1220,16 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     {
         ArgumentException.ThrowIfNullOrWhiteSpace(uri);
 
+        var wasSaved = await _database.IsInSpotifyLibraryAsync(uri, ct);
+
+        _savedStateChanged.OnNext(new SavedStateChangedEvent(uri, itemType, false, SavedStateChangeSource.Local));
+        var stored = false;
+
         try
         {
             // 1. Optimistically update local database (instant UI feedback)
             await _database.RemoveFromSpotifyLibraryAsync(uri, ct);
+            stored = true;
 
             // 2. Enqueue for background API sync
             var removePayload = JsonSerializer.Serialize(new LibraryOpPayload(itemType), LibraryOpJson.Default.LibraryOpPayload);
@@ -1168,10 +1245,146 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
         catch (Exception ex)
         {
             _logger?.LogError(ex, "Failed to remove {ItemType}: {Uri}", displayName, uri);
+            // The original added-at time is gone with the row; it comes back as now.
+            if (stored && wasSaved)
+            {
+                var addedAt = DateTimeOffset.UtcNow.ToUnixTimeSeconds();
+                await RollBackLibraryWriteAsync(() => _database.AddToSpotifyLibraryAsync(uri, itemType, addedAt, CancellationToken.None), uri);
+            }
+            _savedStateChanged.OnNext(new SavedStateChangedEvent(uri, itemType, wasSaved, SavedStateChangeSource.Reverted));
             return false;
         }
     }
 
+    // Undoes a local library write whose outbox entry couldn't be enqueued.
+    private async Task RollBackLibraryWriteAsync(Func<Task> undo, string uri)
+    {
+        try
+        {
+            await undo();
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogError(ex, "Failed to roll back local library change for {Uri}", uri);
+        }
+    }
+
+    private async Task<bool> SetPlaylistFollowedAsync(string playlistUri, bool follow, CancellationToken ct)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(playlistUri);
+        if (_playlistCache == null)
+        {
+            _logger?.LogWarning("Cannot change follow state of {Uri}: no playlist cache configured", playlistUri);
+            return false;
+        }
+
+        _savedStateChanged.OnNext(new SavedStateChangedEvent(
+            playlistUri, SpotifyLibraryItemType.Playlist, follow, SavedStateChangeSource.Local));
+
+        try
+        {
+            var rootlist = await _playlistCache.GetRootlistAsync(ct: ct);
+            var index = RootlistGraph.FindRootlistPlaylistIndex(rootlist, playlistUri);
+
+            // Already in the requested state — nothing to write.
+            if ((index >= 0) == follow)
+                return true;
+
+            var nowMs = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds();
+            var op = follow
+                ? new Protocol.Playlist.Op
+                {
+                    // Prepend, matching the desktop client's follow placement.
+                    Kind = Protocol.Playlist.Op.Types.Kind.Add,
+                    Add = new Protocol.Playlist.Add
+                    {
+                        FromIndex = 0,
+                        Items =
+                        {
+                            new Protocol.Playlist.Item
+                            {
+                                Uri = playlistUri,
+                                Attributes = new Protocol.Playlist.ItemAttributes { Timestamp = nowMs, Public = true },
+                            }
+                        }
+                    }
+                }
+                : new Protocol.Playlist.Op
+                {
+                    Kind = Protocol.Playlist.Op.Types.Kind.Rem,
+                    Rem = new Protocol.Playlist.Rem { FromIndex = index, Length = 1 }
+                };
+
+            var username = GetUsername();
+            var changes = new Protocol.Playlist.ListChanges
+            {
+                BaseRevision = ByteString.CopyFrom(rootlist.Revision),
+                Deltas =
+                {
+                    new Protocol.Playlist.Delta
+                    {
+                        Ops = { op },
+                        Info = RootlistGraph.BuildRootlistChangeInfo(username, nowMs),
+                    }
+                },
+                WantResultingRevisions = true,
+                WantSyncResult = true,
+                Nonces = { RootlistGraph.NextRootlistNonce() },
+            };
+
+            await _spClient.PostRootlistChangesAsync(username, changes, ct);
+            _logger?.LogInformation("{Action} playlist: {Uri}", follow ? "Follow" : "Unfollow", playlistUri);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Failed to {Action} playlist {Uri}", follow ? "follow" : "unfollow", playlistUri);
+            _savedStateChanged.OnNext(new SavedStateChangedEvent(
+                playlistUri, SpotifyLibraryItemType.Playlist, !follow, SavedStateChangeSource.Reverted));
+            return false;
+        }
+
+        // Best-effort: the sidebar picks up the new rootlist through the cache's Changes stream.
+        try
+        {
+            await _playlistCache.InvalidateAsync(PlaylistCacheUris.Rootlist, ct);
+            await _playlistCache.GetRootlistAsync(forceRefresh: true, ct);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogDebug(ex, "Rootlist refresh failed after changing follow state of {Uri}", playlistUri);
+        }
+
+        return true;
+    }
+
+    private async Task<bool> SetUserFollowedAsync(string userUri, bool follow, CancellationToken ct)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(userUri);
+
+        _savedStateChanged.OnNext(new SavedStateChangedEvent(
+            userUri, SpotifyLibraryItemType.User, follow, SavedStateChangeSource.Local));
+
+        bool ok;
+        try
+        {
+            ok = follow
+                ? await _spClient.FollowUserAsync(userUri, ct)
+                : await _spClient.UnfollowUserAsync(userUri, ct);
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Failed to {Action} user {Uri}", follow ? "follow" : "unfollow", userUri);
+            ok = false;
+        }
+
+        if (!ok)
+        {
+            _savedStateChanged.OnNext(new SavedStateChangedEvent(
+                userUri, SpotifyLibraryItemType.User, !follow, SavedStateChangeSource.Reverted));
+        }
+        return ok;
+    }
+
     /// <summary>
     /// Processes pending outbox operations, syncing local changes to Spotify API.
     /// Now a thin delegate to the shared <see cref="IOutboxProcessor"/> — the
@@ -1179,7 +1392,7 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
     /// library save/remove and playlist bulk-add drain through the same
     /// processor, so this call also flushes pending playlist ops.
     /// </summary>
-    public Task<int> ProcessOutboxAsync() => _outboxProcessor.RunAsync();
+    public Task<int> ProcessOutboxAsync(CancellationToken ct = default) => _outboxProcessor.RunAsync(ct: ct);
 
     // GetSetForItemType moved to LibraryOpDispatch — only called by the
     // library save/remove outbox handlers now.
@@ -1321,6 +1534,8 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
                         // Determine item type from URI prefix for "collection" set,
                         // otherwise use set name
                         var itemType = GetItemTypeFromUri(item.ItemUri, changeEvent.Set);
+                        _savedStateChanged.OnNext(new SavedStateChangedEvent(
+                            item.ItemUri, itemType, !item.IsRemoved, SavedStateChangeSource.Remote));
 
                         if (item.IsRemoved)
                         {
@@ -1597,6 +1812,8 @@ public sealed class SpotifyLibraryService : ISpotifyLibraryService
         _progressSubject.Dispose();
         _libraryChanged.OnCompleted();
         _libraryChanged.Dispose();
+        _savedStateChanged.OnCompleted();
+        _savedStateChanged.Dispose();
 
         await Task.CompletedTask;
     }
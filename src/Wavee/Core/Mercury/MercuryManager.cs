@@ -2,8 +2,11 @@ using System.Buffers.Binary;
 using System.Collections.Concurrent;
 using System.Text;
 using Google.Protobuf;
+using System.Reactive.Linq;
 using Microsoft.Extensions.Logging;
+using Wavee.Connect;
 using Wavee.Core.Session;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Core.Mercury;
 
@@ -12,6 +15,19 @@ namespace Wavee.Core.Mercury;
 /// </summary>
 public sealed record MercuryResponse(string Uri, int StatusCode, IReadOnlyList<byte[]> Payload);
 
+/// <summary>
+/// Diagnostic view of an in-flight Mercury request.
+/// </summary>
+public sealed record MercuryPendingRequestInfo(ulong Sequence, string Method, string Uri, TimeSpan Age);
+
+/// <summary>
+/// Event pushed for an active Mercury subscription.
+/// </summary>
+/// <param name="Uri">Event URI from the Mercury header (may extend the subscribed URI).</param>
+/// <param name="Payload">Payload parts after the header.</param>
+/// <param name="IsReplay">True when re-delivering retained state after a resubscribe that returned no fresh state.</param>
+public sealed record MercuryEvent(string Uri, IReadOnlyList<byte[]> Payload, bool IsReplay = false);
+
 /// <summary>
 /// Manages Mercury protocol requests over the AP TCP connection.
 /// Mercury is Spotify's internal request/response protocol for accessing
@@ -19,15 +35,25 @@ public sealed record MercuryResponse(string Uri, int StatusCode, IReadOnlyList<b
 /// </summary>
 public sealed class MercuryManager
 {
+    // Server-pushed events still waiting for their final packet
+    private const int MaxPartialEvents = 64;
+
     private readonly ISession _session;
     private readonly ILogger? _logger;
+    private readonly ProtocolLimitsConfig _limits;
     private readonly ConcurrentDictionary<ulong, MercuryPendingRequest> _pending = new();
+    private readonly ConcurrentDictionary<string, MercurySubscription> _subscriptions = new(StringComparer.Ordinal);
+    private readonly ConcurrentDictionary<ulong, List<byte[]>> _partialEvents = new();
     private ulong _sequence;
 
-    public MercuryManager(ISession session, ILogger? logger = null)
+    /// <param name="session">Session the requests are sent through.</param>
+    /// <param name="logger">Optional logger.</param>
+    /// <param name="limits">Part count, size and header limits for inbound messages. Null uses the defaults.</param>
+    public MercuryManager(ISession session, ILogger? logger = null, ProtocolLimitsConfig? limits = null)
     {
         _session = session ?? throw new ArgumentNullException(nameof(session));
         _logger = logger;
+        _limits = limits ?? new ProtocolLimitsConfig();
     }
 
     /// <summary>
@@ -42,6 +68,96 @@ public sealed class MercuryManager
     public Task<MercuryResponse> SendAsync(string uri, byte[]? payload = null, CancellationToken ct = default)
         => SendRequestAsync("SEND", uri, payload, ct);
 
+    /// <summary>
+    /// URIs with an active SUB. These are re-sent by <see cref="ResubscribeAsync"/>
+    /// after the AP connection is replaced.
+    /// </summary>
+    public IReadOnlyCollection<string> Subscriptions => _subscriptions.Keys.ToList();
+
+    /// <summary>
+    /// Subscribes to events published under <paramref name="uri"/> (prefix match, like librespot).
+    /// </summary>
+    /// <remarks>
+    /// Subscribing twice to the same URI reuses the existing SUB. New observers immediately
+    /// receive the latest retained event, so a listener attached after a reconnect still
+    /// starts from current state.
+    /// </remarks>
+    public async Task<IObservable<MercuryEvent>> SubscribeAsync(string uri, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(uri);
+
+        if (_subscriptions.TryGetValue(uri, out var existing))
+            return existing.Events;
+
+        var response = await SendRequestAsync("SUB", uri, null, ct, PacketType.MercurySub);
+        var subscription = _subscriptions.GetOrAdd(uri, u => new MercurySubscription(u, _logger));
+        if (response.Payload.Count > 0)
+            subscription.Publish(new MercuryEvent(response.Uri is { Length: > 0 } u ? u : uri, response.Payload));
+
+        _logger?.LogDebug("Mercury subscribed: {Uri}", LogRedaction.Url(uri));
+        return subscription.Events;
+    }
+
+    /// <summary>
+    /// Drops the subscription for <paramref name="uri"/> and completes its observers.
+    /// The UNSUB is best effort; the local subscription is removed even if it fails.
+    /// </summary>
+    public async Task UnsubscribeAsync(string uri, CancellationToken ct = default)
+    {
+        if (!_subscriptions.TryRemove(uri, out var subscription))
+            return;
+
+        subscription.Complete();
+
+        try
+        {
+            await SendRequestAsync("UNSUB", uri, null, ct, PacketType.MercuryUnsub);
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogDebug(ex, "Mercury UNSUB failed for {Uri}", LogRedaction.Url(uri));
+        }
+    }
+
+    /// <summary>
+    /// Re-sends SUB for every active subscription. Call after the AP connection is
+    /// replaced — the server forgets subscriptions with the old connection.
+    /// </summary>
+    /// <remarks>
+    /// When the SUB response carries state it is published as a fresh event; otherwise the
+    /// latest retained event is re-published with <see cref="MercuryEvent.IsReplay"/> set, so
+    /// listeners re-sync instead of silently going stale. Failed URIs stay registered and are
+    /// retried on the next call.
+    /// </remarks>
+    /// <returns>Number of subscriptions that failed to re-subscribe.</returns>
+    public async Task<int> ResubscribeAsync(CancellationToken ct = default)
+    {
+        var failed = 0;
+        foreach (var subscription in _subscriptions.Values.ToList())
+        {
+            ct.ThrowIfCancellationRequested();
+
+            try
+            {
+                var response = await SendRequestAsync("SUB", subscription.Uri, null, ct, PacketType.MercurySub);
+                if (response.Payload.Count > 0)
+                    subscription.Publish(new MercuryEvent(
+                        response.Uri is { Length: > 0 } u ? u : subscription.Uri, response.Payload));
+                else if (subscription.Latest is { } latest)
+                    subscription.Publish(latest with { IsReplay = true });
+            }
+            catch (Exception ex) when (ex is not OperationCanceledException)
+            {
+                failed++;
+                _logger?.LogWarning(ex, "Mercury resubscribe failed for {Uri}", LogRedaction.Url(subscription.Uri));
+            }
+        }
+
+        _logger?.LogInformation("Mercury resubscribed {Count} URI(s), {Failed} failed",
+            _subscriptions.Count - failed, failed);
+        return failed;
+    }
+
     /// <summary>
     /// Dispatches an incoming Mercury packet from the session dispatcher.
     /// </summary>
@@ -49,7 +165,7 @@ public sealed class MercuryManager
     {
         try
         {
-            ParseResponse(payload);
+            ParseResponse(command, payload);
         }
         catch (Exception ex)
         {
@@ -58,10 +174,14 @@ public sealed class MercuryManager
     }
 
     private async Task<MercuryResponse> SendRequestAsync(
-        string method, string uri, byte[]? payload, CancellationToken ct)
+        string method, string uri, byte[]? payload, CancellationToken ct,
+        PacketType packetType = PacketType.MercuryReq)
     {
+        // Every GET, SEND, SUB and UNSUB goes through here.
+        OfflineModeException.ThrowIfOffline(_session);
+
         var seq = Interlocked.Increment(ref _sequence);
-        var pending = new MercuryPendingRequest();
+        var pending = new MercuryPendingRequest(method, uri);
 
         if (!_pending.TryAdd(seq, pending))
             throw new InvalidOperationException($"Mercury sequence {seq} already pending");
@@ -69,7 +189,7 @@ public sealed class MercuryManager
         try
         {
             var packet = BuildRequestPacket(seq, method, uri, payload);
-            await _session.SendAsync(PacketType.MercuryReq, packet, ct);
+            await _session.SendAsync(packetType, packet, ct);
 
             _logger?.LogDebug("Mercury {Method} sent: seq={Seq}, uri={Uri}", method, seq, uri);
 
@@ -130,7 +250,7 @@ public sealed class MercuryManager
         return ms.ToArray();
     }
 
-    private void ParseResponse(ReadOnlySpan<byte> data)
+    private void ParseResponse(byte command, ReadOnlySpan<byte> data)
     {
         var offset = 0;
 
@@ -161,10 +281,26 @@ public sealed class MercuryManager
         var partCount = BinaryPrimitives.ReadUInt16BigEndian(data.Slice(offset));
         offset += 2;
 
-        // Look up pending request
-        if (!_pending.TryGetValue(seq, out var pending))
+        // Look up pending request; server-pushed events use their own sequence space
+        List<byte[]> parts;
+        MercuryPendingRequest? pending = null;
+        if (_pending.TryGetValue(seq, out pending))
+        {
+            parts = pending.Parts;
+        }
+        else if (command == (byte)PacketType.MercuryEvent)
+        {
+            // Partial events are only dropped on their final packet; cap how many can be open
+            if (!_partialEvents.ContainsKey(seq) && _partialEvents.Count >= MaxPartialEvents)
+            {
+                _logger?.LogWarning("Dropping Mercury event seq={Seq}: {Count} partial events already open", seq, _partialEvents.Count);
+                return;
+            }
+            parts = _partialEvents.GetOrAdd(seq, _ => []);
+        }
+        else
         {
-            _logger?.LogDebug("Mercury response for unknown seq={Seq} (possibly event)", seq);
+            _logger?.LogDebug("Mercury response for unknown seq={Seq} (cmd=0x{Cmd:X2})", seq, command);
             return;
         }
 
@@ -176,15 +312,80 @@ public sealed class MercuryManager
             offset += 2;
 
             if (offset + partSize > data.Length) break;
-            pending.Parts.Add(data.Slice(offset, partSize).ToArray());
+            if (parts.Count >= _limits.MaxParts || TotalSize(parts) + partSize > _limits.MaxMessageBytes)
+            {
+                RejectOversized(seq, pending);
+                return;
+            }
+            parts.Add(data.Slice(offset, partSize).ToArray());
             offset += partSize;
         }
 
         // Check if final (0x01 flag)
         if ((flags & 0x01) != 0)
         {
-            CompleteRequest(seq, pending);
+            if (pending is not null)
+                CompleteRequest(seq, pending);
+            else if (_partialEvents.TryRemove(seq, out var eventParts))
+                DispatchEvent(eventParts);
+        }
+    }
+
+    private void RejectOversized(ulong seq, MercuryPendingRequest? pending)
+    {
+        _logger?.LogWarning("Dropping Mercury message seq={Seq}: exceeds {MaxParts} parts or {MaxBytes} bytes",
+            seq, _limits.MaxParts, _limits.MaxMessageBytes);
+
+        if (pending is not null)
+        {
+            pending.Parts.Clear();
+            pending.Tcs.TrySetException(new InvalidDataException(
+                $"Mercury response for {pending.Uri} exceeds {_limits.MaxParts} parts or {_limits.MaxMessageBytes} bytes (limits.maxParts, limits.maxMessageBytes)"));
+        }
+        else
+        {
+            _partialEvents.TryRemove(seq, out _);
+        }
+    }
+
+    private static long TotalSize(List<byte[]> parts)
+    {
+        long total = 0;
+        foreach (var part in parts)
+            total += part.Length;
+        return total;
+    }
+
+    private Protocol.Header ParseHeader(byte[] headerPart)
+    {
+        var header = Protocol.Header.Parser.ParseFrom(headerPart);
+        if (header.UserFields.Count > _limits.MaxHeaders)
+            throw new InvalidDataException(
+                $"Mercury header has {header.UserFields.Count} user fields, limit is {_limits.MaxHeaders} (limits.maxHeaders)");
+        return header;
+    }
+
+    private void DispatchEvent(List<byte[]> parts)
+    {
+        if (parts.Count == 0)
+            return;
+
+        var header = ParseHeader(parts[0]);
+        var uri = header.Uri ?? "";
+        var evt = new MercuryEvent(uri, parts.Skip(1).ToList());
+
+        var delivered = false;
+        foreach (var subscription in _subscriptions.Values)
+        {
+            if (!uri.StartsWith(subscription.Uri, StringComparison.Ordinal))
+                continue;
+
+            subscription.Publish(evt);
+            delivered = true;
         }
+
+        if (!delivered)
+            _logger?.LogDebug("Mercury event with no subscriber: {Uri}", LogRedaction.Url(uri));
     }
 
     private void CompleteRequest(ulong seq, MercuryPendingRequest pending)
@@ -196,8 +397,16 @@ public sealed class MercuryManager
         }
 
         // First part is the protobuf Header
-        var headerPart = pending.Parts[0];
-        var header = Protocol.Header.Parser.ParseFrom(headerPart);
+        Protocol.Header header;
+        try
+        {
+            header = ParseHeader(pending.Parts[0]);
+        }
+        catch (Exception ex) when (ex is InvalidDataException or InvalidProtocolBufferException)
+        {
+            pending.Tcs.TrySetException(ex);
+            return;
+        }
 
         var response = new MercuryResponse(
             header.Uri ?? "",
@@ -226,6 +435,7 @@ public sealed class MercuryManager
 
     /// <summary>
     /// Cancels all pending requests. Called on reconnection.
+    /// Subscriptions are kept so <see cref="ResubscribeAsync"/> can restore them.
     /// </summary>
     public void Reset()
     {
@@ -234,11 +444,71 @@ public sealed class MercuryManager
             kvp.Value.Tcs.TrySetCanceled();
         }
         _pending.Clear();
+        _partialEvents.Clear();
         _sequence = 0;
     }
 
-    private sealed class MercuryPendingRequest
+    /// <summary>
+    /// Snapshot of in-flight requests, oldest first. Used by session diagnostics.
+    /// </summary>
+    public IReadOnlyList<MercuryPendingRequestInfo> GetPendingRequests()
+    {
+        var now = DateTime.UtcNow;
+        return _pending
+            .OrderBy(kvp => kvp.Key)
+            .Select(kvp => new MercuryPendingRequestInfo(
+                kvp.Key,
+                kvp.Value.Method,
+                LogRedaction.Url(kvp.Value.Uri),
+                now - kvp.Value.StartedUtc))
+            .ToList();
+    }
+
+    private sealed class MercurySubscription(string uri, ILogger? logger)
+    {
+        private readonly object _lock = new();
+        private readonly SafeSubject<MercuryEvent> _subject = new(logger);
+
+        public string Uri { get; } = uri;
+
+        public MercuryEvent? Latest { get; private set; }
+
+        /// <summary>
+        /// Hot stream that starts with the latest retained event, if any.
+        /// </summary>
+        public IObservable<MercuryEvent> Events => Observable.Create<MercuryEvent>(observer =>
+        {
+            lock (_lock)
+            {
+                if (Latest is { } latest)
+                    observer.OnNext(latest);
+                return _subject.Subscribe(observer);
+            }
+        });
+
+        public void Publish(MercuryEvent evt)
+        {
+            lock (_lock)
+            {
+                Latest = evt;
+                _subject.OnNext(evt);
+            }
+        }
+
+        public void Complete()
+        {
+            lock (_lock)
+            {
+                _subject.OnCompleted();
+            }
+        }
+    }
+
+    private sealed class MercuryPendingRequest(string method, string uri)
     {
+        public string Method { get; } = method;
+        public string Uri { get; } = uri;
+        public DateTime StartedUtc { get; } = DateTime.UtcNow;
         public TaskCompletionSource<MercuryResponse> Tcs { get; } = new(TaskCreationOptions.RunContinuationsAsynchronously);
         public List<byte[]> Parts { get; } = [];
     }
@@ -0,0 +1,92 @@
+using System.Globalization;
+
+namespace Wavee.Core.Playlists;
+
+/// <summary>
+/// Kind of server-regenerated playlist. Spotify rewrites these in place — same URI,
+/// new revision, mostly new tracks — rather than editing them item by item.
+/// </summary>
+public enum DynamicPlaylistKind
+{
+    /// <summary>A regular playlist; changes are edits.</summary>
+    None,
+
+    /// <summary>daylist — regenerated several times a day.</summary>
+    Daylist,
+
+    /// <summary>Personalised mixes: Discover Weekly, Release Radar, Daily Mixes, "inspired by" mixes.</summary>
+    PersonalizedMix,
+
+    /// <summary>Blend shared between two or more users; regenerated daily.</summary>
+    Blend,
+
+    /// <summary>Charts (Top 50, Viral 50); regenerated daily or weekly.</summary>
+    Chart,
+
+    /// <summary>Any other Spotify-owned editorial playlist.</summary>
+    Editorial
+}
+
+/// <summary>
+/// Classifies playlists by their <c>format</c> attribute and owner so callers can
+/// tell a regeneration from a user edit.
+/// </summary>
+public static class DynamicPlaylists
+{
+    private const string SpotifyOwner = "spotify";
+
+    /// <summary>
+    /// Classifies a cached playlist.
+    /// </summary>
+    public static DynamicPlaylistKind Classify(CachedPlaylist playlist)
+    {
+        ArgumentNullException.ThrowIfNull(playlist);
+        return Classify(playlist.FormatAttributes, playlist.OwnerUsername);
+    }
+
+    /// <summary>
+    /// Classifies a playlist from its format attributes and owner username.
+    /// </summary>
+    public static DynamicPlaylistKind Classify(IReadOnlyDictionary<string, string>? formatAttributes, string? ownerUsername)
+    {
+        var format = formatAttributes is not null && formatAttributes.TryGetValue("format", out var value)
+            ? value.Trim().ToLowerInvariant()
+            : string.Empty;
+
+        switch (format)
+        {
+            case "daylist":
+                return DynamicPlaylistKind.Daylist;
+            case "chart":
+                return DynamicPlaylistKind.Chart;
+            case "discover-weekly":
+            case "release-radar":
+            case "daily-mix":
+            case "daily_mix":
+            case "inspiredby-mix":
+            case "artist-mix":
+            case "genre-mix":
+            case "mood-mix":
+            case "decade-mix":
+                return DynamicPlaylistKind.PersonalizedMix;
+        }
+
+        if (format.StartsWith("blend", StringComparison.Ordinal))
+            return DynamicPlaylistKind.Blend;
+
+        return string.Equals(ownerUsername, SpotifyOwner, StringComparison.OrdinalIgnoreCase)
+            ? DynamicPlaylistKind.Editorial
+            : DynamicPlaylistKind.None;
+    }
+
+    /// <summary>
+    /// Reads the server's <c>last_updated</c> format attribute (charts and some mixes
+    /// carry it). Null when absent or unparseable.
+    /// </summary>
+    public static DateTimeOffset? GetLastUpdated(IReadOnlyDictionary<string, string>? formatAttributes)
+        => formatAttributes is not null
+           && formatAttributes.TryGetValue("last_updated", out var value)
+           && DateTimeOffset.TryParse(value, CultureInfo.InvariantCulture, DateTimeStyles.AssumeUniversal, out var updated)
+            ? updated
+            : null;
+}
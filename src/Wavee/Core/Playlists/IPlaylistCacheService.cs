@@ -50,6 +50,14 @@ public interface IPlaylistCacheService
         CancellationToken ct = default);
 
     IObservable<PlaylistChangeEvent> Changes { get; }
+
+    /// <summary>
+    /// Fires when a server-regenerated playlist (daylist, Discover Weekly, blends,
+    /// charts, editorial) moves to a new revision, on top of the regular
+    /// <see cref="Changes"/> event. Lets players reload a playing context instead of
+    /// treating the rewrite as an edit.
+    /// </summary>
+    IObservable<PlaylistRefreshedEvent> Refreshed { get; }
 }
 
 public static class PlaylistCacheUris
@@ -221,3 +229,17 @@ public sealed record PlaylistChangeEvent
     public required string Uri { get; init; }
     public PlaylistChangeKind Kind { get; init; }
 }
+
+/// <summary>
+/// A dynamic playlist was regenerated. See <see cref="IPlaylistCacheService.Refreshed"/>.
+/// </summary>
+public sealed record PlaylistRefreshedEvent
+{
+    public required string Uri { get; init; }
+    public DynamicPlaylistKind Kind { get; init; }
+    public byte[] PreviousRevision { get; init; } = [];
+    public byte[] Revision { get; init; } = [];
+
+    /// <summary>Server-reported regeneration time (<c>last_updated</c>), when present.</summary>
+    public DateTimeOffset? UpdatedAt { get; init; }
+}
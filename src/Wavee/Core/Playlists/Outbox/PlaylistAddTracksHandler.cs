@@ -1,17 +1,14 @@
 using System;
 using System.Collections.Generic;
 using System.Linq;
-using System.Security.Cryptography;
 using System.Text.Json;
 using System.Text.Json.Serialization;
 using System.Threading;
 using System.Threading.Tasks;
-using Google.Protobuf;
 using Wavee.Core.Http;
 using Wavee.Core.Session;
 using Wavee.Core.Storage.Abstractions;
 using Wavee.Core.Storage.Outbox;
-using Wavee.Protocol.Playlist;
 
 namespace Wavee.Core.Playlists.Outbox;
 
@@ -94,36 +91,11 @@ public sealed class PlaylistAddTracksHandler : IOutboxHandler
             var take = Math.Min(ChunkSize, payload.Uris.Count - offset);
             var cached = await _playlistCache.GetPlaylistAsync(playlistUri, ct: ct);
 
-            var addOp = new Op
-            {
-                Kind = Op.Types.Kind.Add,
-                Add = new Add { AddLast = true }
-            };
-            var nowMs = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds();
-            for (var i = offset; i < offset + take; i++)
-            {
-                addOp.Add.Items.Add(new Item
-                {
-                    Uri = payload.Uris[i],
-                    Attributes = new ItemAttributes { Timestamp = nowMs },
-                });
-            }
-
-            var changes = new ListChanges
-            {
-                BaseRevision = ByteString.CopyFrom(cached.Revision),
-                Deltas =
-                {
-                    new Delta
-                    {
-                        Ops = { addOp },
-                        Info = new ChangeInfo { User = username, Timestamp = nowMs },
-                    }
-                },
-                WantResultingRevisions = true,
-                WantSyncResult = true,
-                Nonces = { RandomNumberGenerator.GetInt32(1, int.MaxValue) },
-            };
+            var changes = PlaylistEditor.BuildAppendChanges(
+                cached.Revision,
+                payload.Uris.Skip(offset).Take(take),
+                username,
+                _session.Clock.NowMs);
 
             var fresh = await _spClient.ChangePlaylistAsync(playlistUri, changes, ct);
             await _playlistCache.ApplyFreshContentAsync(playlistUri, fresh, ct);
@@ -44,6 +44,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
     private readonly IRemoteStateRecorder? _remoteStateRecorder;
     private readonly HotCache<CachedPlaylist> _hotCache;
     private readonly Subject<PlaylistChangeEvent> _changes = new();
+    private readonly Subject<PlaylistRefreshedEvent> _refreshed = new();
     // Lazy-wrapped so GetOrAdd+factory can be invoked twice under contention
     // without firing the factory's work twice: only the Lazy that wins the
     // dictionary slot ever has .Value accessed. The losing Lazy is discarded
@@ -107,6 +108,8 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
 
     public IObservable<PlaylistChangeEvent> Changes => _changes.AsObservable();
 
+    public IObservable<PlaylistRefreshedEvent> Refreshed => _refreshed.AsObservable();
+
     public Task ClearAllAsync(CancellationToken ct = default)
     {
         _hotCache.Clear();
@@ -343,6 +346,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
             Uri = playlistUri,
             Kind = existing == null ? PlaylistChangeKind.Replaced : PlaylistChangeKind.Updated
         });
+        PublishRefreshIfDynamic(existing, merged);
 
         return merged;
     }
@@ -751,6 +755,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
                                 Uri = playlistUri,
                                 Kind = PlaylistChangeKind.Updated
                             });
+                            PublishRefreshIfDynamic(existing, mergedFromOps);
                         }
 
                         return mergedFromOps;
@@ -782,6 +787,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
                             Uri = playlistUri,
                             Kind = PlaylistChangeKind.Updated
                         });
+                        PublishRefreshIfDynamic(existing, mergedFromDiff);
                     }
 
                     return mergedFromDiff;
@@ -828,6 +834,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
                     Uri = playlistUri,
                     Kind = existing == null ? PlaylistChangeKind.Replaced : PlaylistChangeKind.Updated
                 });
+                PublishRefreshIfDynamic(existing, merged);
             }
 
             return merged;
@@ -1277,6 +1284,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
                     Uri = playlistUri,
                     Kind = PlaylistChangeKind.Updated
                 });
+                PublishRefreshIfDynamic(existing, merged);
             }
 
             _logger?.LogInformation(
@@ -1428,6 +1436,31 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
         string.Equals(uri, PlaylistCacheUris.Rootlist, StringComparison.Ordinal) ||
         uri.Contains(":rootlist", StringComparison.Ordinal);
 
+    /// <summary>
+    /// Raises <see cref="Refreshed"/> when a server-regenerated playlist moved to a
+    /// new revision. Called next to every <see cref="PlaylistChangeKind.Updated"/>
+    /// emit; a first fetch has nothing to compare against and never counts.
+    /// </summary>
+    private void PublishRefreshIfDynamic(CachedPlaylist? previous, CachedPlaylist current)
+    {
+        if (previous is not { Revision.Length: > 0 } || RevisionsEqual(previous.Revision, current.Revision))
+            return;
+
+        var kind = DynamicPlaylists.Classify(current);
+        if (kind == DynamicPlaylistKind.None)
+            return;
+
+        _logger?.LogInformation("[playlist-refresh] {Kind} playlist {Uri} regenerated", kind, current.Uri);
+        _refreshed.OnNext(new PlaylistRefreshedEvent
+        {
+            Uri = current.Uri,
+            Kind = kind,
+            PreviousRevision = previous.Revision,
+            Revision = current.Revision,
+            UpdatedAt = DynamicPlaylists.GetLastUpdated(current.FormatAttributes)
+        });
+    }
+
     private static bool RevisionsEqual(byte[]? left, byte[]? right)
     {
         if (ReferenceEquals(left, right))
@@ -1543,5 +1576,7 @@ public sealed class PlaylistCacheService : IPlaylistCacheService, IDisposable
         _libraryChangeManager?.DisposeAsync().AsTask().GetAwaiter().GetResult();
         _changes.OnCompleted();
         _changes.Dispose();
+        _refreshed.OnCompleted();
+        _refreshed.Dispose();
     }
 }
@@ -0,0 +1,212 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Security.Cryptography;
+using System.Threading;
+using System.Threading.Tasks;
+using Google.Protobuf;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Protocol.Playlist;
+
+namespace Wavee.Core.Playlists;
+
+/// <summary>
+/// "Add to playlist" convenience on top of the raw Playlist4 delta machinery.
+/// Reads the current contents from <see cref="IPlaylistCacheService"/>, skips
+/// tracks that are already present (unless the caller opts in to duplicates),
+/// posts a single Add op against the cached revision and folds the response
+/// back into the cache.
+/// </summary>
+/// <remarks>
+/// A 409 from <c>/changes</c> means someone else edited the playlist after our
+/// snapshot was taken. The editor force-refreshes, re-runs duplicate detection
+/// against the new contents and retries up to <see cref="MaxConflictRetries"/>
+/// times before surfacing the conflict.
+/// <para/>
+/// Unlike <see cref="Outbox.PlaylistAddTracksHandler"/> this runs inline — use
+/// it for interactive single-track adds where the UI wants to know about
+/// duplicates before anything is written.
+/// </remarks>
+public sealed class PlaylistEditor
+{
+    /// <summary>Refetch-and-retry attempts after a revision conflict.</summary>
+    public const int MaxConflictRetries = 3;
+
+    private readonly ISpClient _spClient;
+    private readonly ISession _session;
+    private readonly IPlaylistCacheService _playlistCache;
+    private readonly ILogger? _logger;
+
+    public PlaylistEditor(
+        ISpClient spClient,
+        ISession session,
+        IPlaylistCacheService playlistCache,
+        ILogger? logger = null)
+    {
+        _spClient = spClient ?? throw new ArgumentNullException(nameof(spClient));
+        _session = session ?? throw new ArgumentNullException(nameof(session));
+        _playlistCache = playlistCache ?? throw new ArgumentNullException(nameof(playlistCache));
+        _logger = logger;
+    }
+
+    /// <summary>
+    /// Appends a single track to the end of a playlist.
+    /// </summary>
+    /// <param name="playlistUri">Target playlist URI.</param>
+    /// <param name="trackUri">Track (or episode) URI to append.</param>
+    /// <param name="allowDuplicates">Add even if the track is already in the playlist.</param>
+    /// <param name="ct">Cancellation token.</param>
+    public Task<AddToPlaylistResult> AddTrackAsync(
+        string playlistUri,
+        string trackUri,
+        bool allowDuplicates = false,
+        CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(trackUri);
+        return AddTracksAsync(playlistUri, [trackUri], allowDuplicates, ct);
+    }
+
+    /// <summary>
+    /// Appends tracks to the end of a playlist in one delta.
+    /// </summary>
+    /// <param name="playlistUri">Target playlist URI.</param>
+    /// <param name="trackUris">Track (or episode) URIs to append, in order.</param>
+    /// <param name="allowDuplicates">
+    /// When false, URIs already in the playlist (or repeated within
+    /// <paramref name="trackUris"/>) are skipped and reported in
+    /// <see cref="AddToPlaylistResult.Duplicates"/>.
+    /// </param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <exception cref="SpClientException">
+    /// The write failed, or the revision still conflicted after
+    /// <see cref="MaxConflictRetries"/> refetches.
+    /// </exception>
+    public async Task<AddToPlaylistResult> AddTracksAsync(
+        string playlistUri,
+        IReadOnlyList<string> trackUris,
+        bool allowDuplicates = false,
+        CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(playlistUri);
+        ArgumentNullException.ThrowIfNull(trackUris);
+
+        var username = _session.GetUserData()?.Username
+                       ?? throw new InvalidOperationException("not authenticated");
+
+        for (var attempt = 0; ; attempt++)
+        {
+            ct.ThrowIfCancellationRequested();
+
+            var playlist = await _playlistCache.GetPlaylistAsync(playlistUri, forceRefresh: attempt > 0, ct);
+            var (toAdd, duplicates) = allowDuplicates
+                ? (trackUris, (IReadOnlyList<string>)Array.Empty<string>())
+                : PartitionDuplicates(playlist.Items, trackUris);
+
+            if (toAdd.Count == 0)
+            {
+                _logger?.LogDebug("Nothing to add to {Playlist}: {Count} duplicate(s)", playlistUri, duplicates.Count);
+                return new AddToPlaylistResult(Array.Empty<string>(), duplicates, playlist.Revision);
+            }
+
+            var changes = BuildAppendChanges(playlist.Revision, toAdd, username, _session.Clock.NowMs);
+            try
+            {
+                var fresh = await _spClient.ChangePlaylistAsync(playlistUri, changes, ct);
+                var updated = await _playlistCache.ApplyFreshContentAsync(playlistUri, fresh, ct);
+
+                _logger?.LogInformation("Added {Count} item(s) to {Playlist} ({Duplicates} duplicate(s) skipped)",
+                    toAdd.Count, playlistUri, duplicates.Count);
+                return new AddToPlaylistResult(toAdd, duplicates, updated.Revision);
+            }
+            catch (SpClientException ex) when (ex.Reason == SpClientFailureReason.RevisionConflict
+                                               && attempt < MaxConflictRetries)
+            {
+                _logger?.LogDebug("Revision conflict adding to {Playlist}, refetching (attempt {Attempt})",
+                    playlistUri, attempt + 1);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Splits <paramref name="candidates"/> into URIs not yet in
+    /// <paramref name="existing"/> and those that are. Repeats within the
+    /// candidate list count as duplicates after their first occurrence.
+    /// </summary>
+    internal static (IReadOnlyList<string> ToAdd, IReadOnlyList<string> Duplicates) PartitionDuplicates(
+        IReadOnlyList<CachedPlaylistItem> existing,
+        IReadOnlyList<string> candidates)
+    {
+        var seen = new HashSet<string>(existing.Select(static i => i.Uri), StringComparer.Ordinal);
+        var toAdd = new List<string>(candidates.Count);
+        var duplicates = new List<string>();
+
+        foreach (var uri in candidates)
+        {
+            if (seen.Add(uri))
+                toAdd.Add(uri);
+            else
+                duplicates.Add(uri);
+        }
+
+        return (toAdd, duplicates);
+    }
+
+    /// <summary>
+    /// Builds a single-delta <see cref="ListChanges"/> that appends
+    /// <paramref name="uris"/> against <paramref name="baseRevision"/>.
+    /// </summary>
+    /// <param name="nowMs">Server-aligned time (<c>ISession.Clock.NowMs</c>) stamped on the items and the delta.</param>
+    internal static ListChanges BuildAppendChanges(
+        byte[] baseRevision,
+        IEnumerable<string> uris,
+        string username,
+        long nowMs)
+    {
+        var addOp = new Op
+        {
+            Kind = Op.Types.Kind.Add,
+            Add = new Add { AddLast = true }
+        };
+        foreach (var uri in uris)
+        {
+            addOp.Add.Items.Add(new Item
+            {
+                Uri = uri,
+                Attributes = new ItemAttributes { Timestamp = nowMs },
+            });
+        }
+
+        return new ListChanges
+        {
+            BaseRevision = ByteString.CopyFrom(baseRevision),
+            Deltas =
+            {
+                new Delta
+                {
+                    Ops = { addOp },
+                    Info = new ChangeInfo { User = username, Timestamp = nowMs },
+                }
+            },
+            WantResultingRevisions = true,
+            WantSyncResult = true,
+            Nonces = { RandomNumberGenerator.GetInt32(1, int.MaxValue) },
+        };
+    }
+}
+
+/// <summary>
+/// Outcome of <see cref="PlaylistEditor.AddTracksAsync"/>.
+/// </summary>
+/// <param name="Added">URIs actually appended, in order.</param>
+/// <param name="Duplicates">URIs skipped because they were already present.</param>
+/// <param name="Revision">Playlist revision after the write (or the unchanged revision when nothing was added).</param>
+public sealed record AddToPlaylistResult(
+    IReadOnlyList<string> Added,
+    IReadOnlyList<string> Duplicates,
+    byte[] Revision)
+{
+    /// <summary>True when every requested URI was skipped as a duplicate.</summary>
+    public bool AllDuplicates => Added.Count == 0 && Duplicates.Count > 0;
+}
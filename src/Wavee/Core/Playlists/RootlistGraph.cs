@@ -1,16 +1,13 @@
-using System;
 using System.Security.Cryptography;
-using Wavee.Core.Playlists;
 
-namespace Wavee.UI.WinUI.Data.Contexts.Helpers;
+namespace Wavee.Core.Playlists;
 
 /// <summary>
 /// Index- and span-walking helpers for the user's rootlist (playlist tree).
-/// Shared between <see cref="RootlistService"/> (folder + move ops) and
-/// <see cref="LibraryDataService"/> (delete-playlist + follow paths still
-/// touch the rootlist directly).
+/// Shared by the library service's follow path and the desktop app's rootlist
+/// editors (folders, moves, create and delete).
 /// </summary>
-internal static class RootlistGraph
+public static class RootlistGraph
 {
     /// <summary>
     /// Locates a rootlist playlist entry by URI. Returns the items-array
@@ -0,0 +1,104 @@
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// What a chunk of network traffic was for, as counted by <see cref="BandwidthMeter"/>.
+/// </summary>
+public enum TrafficCategory
+{
+    /// <summary>Track and music-video streams, previews, head files, AP stream chunks and audio keys.</summary>
+    Audio,
+
+    /// <summary>spclient, Pathfinder and Mercury: metadata, playlists, lyrics, search.</summary>
+    Metadata,
+
+    /// <summary>Cover art, avatars and other media from the image CDNs.</summary>
+    Images,
+
+    /// <summary>Login, tokens, endpoint resolution, keep-alives and the dealer WebSocket.</summary>
+    Control
+}
+
+/// <summary>
+/// Thread-safe byte counters per <see cref="TrafficCategory"/>, kept for the lifetime of a
+/// session so users on metered connections can see where data goes.
+/// </summary>
+/// <remarks>
+/// Counts are application payload bytes: HTTP bodies after decompression, AP packets with
+/// their framing, dealer frames as text. TLS, TCP and HTTP header overhead is not included,
+/// so totals run a few percent under what the OS reports.
+/// </remarks>
+public sealed class BandwidthMeter
+{
+    private static readonly TrafficCategory[] Categories = Enum.GetValues<TrafficCategory>();
+
+    private readonly long[] _sent = new long[Categories.Length];
+    private readonly long[] _received = new long[Categories.Length];
+    private readonly DateTimeOffset _since;
+
+    /// <summary>
+    /// Creates a meter with all counters at zero.
+    /// </summary>
+    public BandwidthMeter(TimeProvider? timeProvider = null)
+    {
+        _since = (timeProvider ?? TimeProvider.System).GetUtcNow();
+    }
+
+    /// <summary>
+    /// Adds traffic to <paramref name="category"/>.
+    /// </summary>
+    public void Record(TrafficCategory category, long bytesSent, long bytesReceived)
+    {
+        var index = (int)category;
+        if ((uint)index >= (uint)Categories.Length)
+            throw new ArgumentOutOfRangeException(nameof(category));
+
+        if (bytesSent > 0)
+            Interlocked.Add(ref _sent[index], bytesSent);
+        if (bytesReceived > 0)
+            Interlocked.Add(ref _received[index], bytesReceived);
+    }
+
+    /// <summary>Adds uploaded bytes to <paramref name="category"/>.</summary>
+    public void RecordSent(TrafficCategory category, long bytes) => Record(category, bytes, 0);
+
+    /// <summary>Adds downloaded bytes to <paramref name="category"/>.</summary>
+    public void RecordReceived(TrafficCategory category, long bytes) => Record(category, 0, bytes);
+
+    /// <summary>
+    /// Counters since the meter was created.
+    /// </summary>
+    public BandwidthStatistics GetStatistics()
+    {
+        var categories = new Dictionary<TrafficCategory, TrafficCounters>(Categories.Length);
+        foreach (var category in Categories)
+        {
+            categories[category] = new TrafficCounters(
+                Interlocked.Read(ref _sent[(int)category]),
+                Interlocked.Read(ref _received[(int)category]));
+        }
+
+        return new BandwidthStatistics(_since, categories);
+    }
+}
+
+/// <summary>
+/// Snapshot of a <see cref="BandwidthMeter"/>.
+/// </summary>
+/// <param name="Since">When counting started.</param>
+/// <param name="Categories">Counters for every <see cref="TrafficCategory"/>.</param>
+public sealed record BandwidthStatistics(
+    DateTimeOffset Since,
+    IReadOnlyDictionary<TrafficCategory, TrafficCounters> Categories)
+{
+    /// <summary>Sum over all categories.</summary>
+    public TrafficCounters Total => new(
+        Categories.Values.Sum(c => c.BytesSent),
+        Categories.Values.Sum(c => c.BytesReceived));
+}
+
+/// <summary>
+/// Bytes sent and received for one <see cref="TrafficCategory"/>.
+/// </summary>
+/// <param name="BytesSent">Bytes uploaded.</param>
+/// <param name="BytesReceived">Bytes downloaded.</param>
+public readonly record struct TrafficCounters(long BytesSent, long BytesReceived);
@@ -0,0 +1,53 @@
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// How this device presents itself to other Spotify Connect clients: the capability
+/// flags and identity fields published in every PutState.
+/// </summary>
+/// <remarks>
+/// Remote UIs render controls from these flags. A fixed-volume output (a DAC at line
+/// level, an amplifier with its own knob) should set <see cref="SupportsVolume"/> to
+/// false so phones and desktops hide the volume slider instead of offering one that
+/// does nothing.
+/// </remarks>
+public sealed record ConnectDeviceConfig
+{
+    /// <summary>
+    /// Number of discrete steps remote volume controls move in. Must be positive.
+    /// Default is 64.
+    /// </summary>
+    public int VolumeSteps { get; init; } = 64;
+
+    /// <summary>
+    /// Whether remote clients may change volume. When false the device advertises
+    /// <c>disable_volume</c>, reports full volume and ignores remote volume commands.
+    /// Default is true.
+    /// </summary>
+    public bool SupportsVolume { get; init; } = true;
+
+    /// <summary>
+    /// Whether remote clients may rename the device. Default is false.
+    /// </summary>
+    public bool SupportsRename { get; init; }
+
+    /// <summary>
+    /// Whether remote clients may log the device out. Default is true.
+    /// </summary>
+    public bool SupportsLogout { get; init; } = true;
+
+    /// <summary>
+    /// Keeps the device out of remote device pickers while it still publishes state.
+    /// Default is false.
+    /// </summary>
+    public bool Hidden { get; init; }
+
+    /// <summary>
+    /// Brand shown in remote device lists. Default is "spotify", matching desktop.
+    /// </summary>
+    public string Brand { get; init; } = "spotify";
+
+    /// <summary>
+    /// Model shown in remote device lists. Default is "PC laptop", matching desktop.
+    /// </summary>
+    public string Model { get; init; } = "PC laptop";
+}
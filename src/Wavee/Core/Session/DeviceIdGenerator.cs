@@ -0,0 +1,48 @@
+using System.Security.Cryptography;
+
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Generates Spotify Connect device ids (32 lowercase hex chars, 16 random bytes).
+/// </summary>
+/// <remarks>
+/// Same shape as the <c>Guid.NewGuid().ToString("N")</c> ids hosts used to mint, so ids
+/// already persisted in <c>device_id.txt</c> stay valid. The optional generator lets
+/// discovery / announce tests pin the id byte-for-byte.
+/// </remarks>
+public static class DeviceIdGenerator
+{
+    private const int DeviceIdBytes = 16;
+
+    /// <summary>
+    /// Creates a new random device id.
+    /// </summary>
+    /// <param name="rng">Randomness source. Null uses the system CSPRNG.</param>
+    /// <returns>32-character lowercase hex device id.</returns>
+    public static string Generate(RandomNumberGenerator? rng = null)
+    {
+        Span<byte> bytes = stackalloc byte[DeviceIdBytes];
+        if (rng is null)
+            RandomNumberGenerator.Fill(bytes);
+        else
+            rng.GetBytes(bytes);
+
+        return Convert.ToHexStringLower(bytes);
+    }
+
+    /// <summary>
+    /// Derives a stable device id for a named sub-device of <paramref name="baseDeviceId"/>,
+    /// so each zone of a multi-zone host keeps its identity across restarts.
+    /// </summary>
+    /// <param name="baseDeviceId">Host's persisted device id.</param>
+    /// <param name="name">Zone name; compared case-insensitively.</param>
+    /// <returns>32-character lowercase hex device id.</returns>
+    public static string Derive(string baseDeviceId, string name)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(baseDeviceId);
+        ArgumentException.ThrowIfNullOrWhiteSpace(name);
+
+        var input = System.Text.Encoding.UTF8.GetBytes($"{baseDeviceId}/{name.Trim().ToLowerInvariant()}");
+        return Convert.ToHexStringLower(SHA256.HashData(input).AsSpan(0, DeviceIdBytes));
+    }
+}
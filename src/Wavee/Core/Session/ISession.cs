@@ -88,6 +88,12 @@ public interface ISession
     /// (e.g., reconnecting after AudioKey timeout).
     /// </summary>
     IObservable<SessionConnectionState> ConnectionState { get; }
+
+    /// <summary>
+    /// Whether offline mode is on. While set, the session makes no network requests
+    /// and callers get an <see cref="OfflineModeException"/> instead.
+    /// </summary>
+    bool IsOfflineMode { get; }
 }
 
 /// <summary>
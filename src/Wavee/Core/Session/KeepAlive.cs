@@ -30,14 +30,16 @@ internal sealed class KeepAlive
     private static readonly TimeSpan PongAckTimeout = TimeSpan.FromSeconds(20);
 
     private readonly ILogger? _logger;
+    private readonly TimeProvider _timeProvider;
 
     private KeepAliveState _state;
-    private DateTime _stateEnteredAt;
+    private DateTimeOffset _stateEnteredAt;
     private bool _isFirstCycle;
 
-    public KeepAlive(ILogger? logger = null)
+    public KeepAlive(ILogger? logger = null, TimeProvider? timeProvider = null)
     {
         _logger = logger;
+        _timeProvider = timeProvider ?? TimeProvider.System;
         Reset();
     }
 
@@ -47,7 +49,7 @@ internal sealed class KeepAlive
     public void Reset()
     {
         _state = KeepAliveState.ExpectingPing;
-        _stateEnteredAt = DateTime.UtcNow;
+        _stateEnteredAt = _timeProvider.GetUtcNow();
         _isFirstCycle = true;
     }
 
@@ -57,7 +59,7 @@ internal sealed class KeepAlive
     /// </summary>
     public KeepAliveAction Evaluate()
     {
-        var elapsed = DateTime.UtcNow - _stateEnteredAt;
+        var elapsed = _timeProvider.GetUtcNow() - _stateEnteredAt;
 
         switch (_state)
         {
@@ -137,7 +139,7 @@ internal sealed class KeepAlive
     private void TransitionTo(KeepAliveState newState)
     {
         _state = newState;
-        _stateEnteredAt = DateTime.UtcNow;
+        _stateEnteredAt = _timeProvider.GetUtcNow();
     }
 }
 
@@ -0,0 +1,139 @@
+using System.Net;
+
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Connect and request timeouts, interface and address-family selection, and TLS pinning
+/// for every transport the session opens.
+/// </summary>
+/// <remarks>
+/// Defaults suit a typical broadband connection. Raise them for high-latency links
+/// (satellite, congested mobile tethering) where a slow-but-working connection would
+/// otherwise be abandoned and retried. Each value bounds a single attempt; retry and
+/// fallback loops (next AP, next dealer, next CDN URL) still run on top of it.
+/// </remarks>
+public sealed record NetworkConfig
+{
+    /// <summary>
+    /// Default for <see cref="MaxResponseBytes"/>: 64 MiB.
+    /// </summary>
+    public const long DefaultMaxResponseBytes = 64L * 1024 * 1024;
+
+    /// <summary>
+    /// Time allowed for the TCP connect to a single Access Point. Default is 10 seconds.
+    /// </summary>
+    public TimeSpan ApConnectTimeout { get; init; } = TimeSpan.FromSeconds(10);
+
+    /// <summary>
+    /// Time allowed for the Access Point key exchange once TCP is up. Default is 10 seconds.
+    /// </summary>
+    public TimeSpan ApHandshakeTimeout { get; init; } = TimeSpan.FromSeconds(10);
+
+    /// <summary>
+    /// Time allowed for the dealer WebSocket upgrade against a single endpoint.
+    /// Default is 30 seconds.
+    /// </summary>
+    public TimeSpan DealerConnectTimeout { get; init; } = TimeSpan.FromSeconds(30);
+
+    /// <summary>
+    /// Time allowed for a single CDN range request while streaming audio.
+    /// Default is 15 seconds.
+    /// </summary>
+    public TimeSpan CdnRequestTimeout { get; init; } = TimeSpan.FromSeconds(15);
+
+    /// <summary>
+    /// Time allowed for a single spclient HTTP attempt, headers and body included.
+    /// Timed-out attempts are retried like other transient failures. Default is 30 seconds.
+    /// </summary>
+    public TimeSpan SpClientRequestTimeout { get; init; } = TimeSpan.FromSeconds(30);
+
+    /// <summary>
+    /// Largest decoded spclient / extended-metadata response that will be parsed. Bigger
+    /// responses (huge playlists, full rootlists) fail with
+    /// <see cref="Http.SpClientFailureReason.TooLarge"/> instead of exhausting memory on small
+    /// devices. Default is <see cref="DefaultMaxResponseBytes"/>.
+    /// </summary>
+    public long MaxResponseBytes { get; init; } = DefaultMaxResponseBytes;
+
+    /// <summary>
+    /// Local address outgoing connections bind to, selecting the interface on multi-homed
+    /// hosts. Only remote addresses of the same family are tried. Null lets the OS pick.
+    /// </summary>
+    public IPAddress? LocalAddress { get; init; }
+
+    /// <summary>
+    /// Which IP family to use when a host resolves to both. Default is
+    /// <see cref="AddressFamilyPreference.Any"/> (resolver order).
+    /// </summary>
+    public AddressFamilyPreference AddressFamily { get; init; } = AddressFamilyPreference.Any;
+
+    /// <summary>
+    /// Whether TLS connections to Spotify hosts (spclient, dealer, login5, apresolve) must
+    /// present a key from <see cref="TlsPins"/>. Default is <see cref="TlsPinningMode.Off"/>.
+    /// </summary>
+    /// <remarks>
+    /// Pinning comes on top of normal CA validation, never instead of it. If Spotify rotates
+    /// keys and connections start failing, switch to <see cref="TlsPinningMode.ReportOnly"/>
+    /// (<c>WAVEE_NETWORK_TLS_PINNING=ReportOnly</c>) to keep working while logging mismatches.
+    /// </remarks>
+    public TlsPinningMode TlsPinning { get; init; } = TlsPinningMode.Off;
+
+    /// <summary>
+    /// Accepted public keys: base64 SHA-256 of a certificate's SubjectPublicKeyInfo, with an
+    /// optional <c>sha256/</c> prefix. A connection passes when any certificate in its chain
+    /// matches, so pinning an intermediate survives leaf renewals.
+    /// </summary>
+    public IReadOnlyList<string> TlsPins { get; init; } = [];
+}
+
+/// <summary>
+/// Enforcement of <see cref="NetworkConfig.TlsPins"/>.
+/// </summary>
+public enum TlsPinningMode
+{
+    /// <summary>
+    /// System CA validation only.
+    /// </summary>
+    Off,
+
+    /// <summary>
+    /// Reject Spotify connections whose chain has no pinned key.
+    /// </summary>
+    Enforce,
+
+    /// <summary>
+    /// Log Spotify connections whose chain has no pinned key, but allow them.
+    /// </summary>
+    ReportOnly
+}
+
+/// <summary>
+/// IP family selection for outgoing connections.
+/// </summary>
+public enum AddressFamilyPreference
+{
+    /// <summary>
+    /// Try addresses in the order the resolver returned them.
+    /// </summary>
+    Any,
+
+    /// <summary>
+    /// Try IPv4 addresses first, then IPv6.
+    /// </summary>
+    PreferIPv4,
+
+    /// <summary>
+    /// Try IPv6 addresses first, then IPv4.
+    /// </summary>
+    PreferIPv6,
+
+    /// <summary>
+    /// Never connect over IPv6.
+    /// </summary>
+    IPv4Only,
+
+    /// <summary>
+    /// Never connect over IPv4 (IPv6-only networks, NAT64).
+    /// </summary>
+    IPv6Only
+}
@@ -0,0 +1,29 @@
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Exception thrown when an operation needs the network while the session is in offline mode.
+/// </summary>
+/// <remarks>
+/// Derives from <see cref="HttpRequestException"/> so callers that already treat transport
+/// failures as "no connectivity" (retry loops, the outbox) handle it the same way.
+/// </remarks>
+public sealed class OfflineModeException : HttpRequestException
+{
+    /// <summary>
+    /// Initializes a new instance of the <see cref="OfflineModeException"/> class.
+    /// </summary>
+    /// <param name="message">The exception message.</param>
+    public OfflineModeException(string message = "The session is in offline mode")
+        : base(message)
+    {
+    }
+
+    /// <summary>
+    /// Throws an <see cref="OfflineModeException"/> if <paramref name="session"/> is in offline mode.
+    /// </summary>
+    internal static void ThrowIfOffline(ISession session)
+    {
+        if (session.IsOfflineMode)
+            throw new OfflineModeException();
+    }
+}
@@ -0,0 +1,55 @@
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Upper bounds on inbound protocol frames: AP packets, Mercury messages and dealer
+/// WebSocket messages.
+/// </summary>
+/// <remarks>
+/// Every length, count and depth in these frames comes from the remote side. The limits
+/// keep a misbehaving server, or anyone in front of the unencrypted handshake and the
+/// dealer TLS, from making the client allocate without bound. Defaults sit well above
+/// anything Spotify sends; lower them on memory-constrained devices. A frame over a limit
+/// is dropped (Mercury, dealer payloads) or fails the connection (AP packets, dealer
+/// messages), since the stream can't be resynchronised past it.
+/// </remarks>
+public sealed record ProtocolLimitsConfig
+{
+    /// <summary>
+    /// Largest AP packet payload the wire format can express (a 16-bit length).
+    /// </summary>
+    public const int MaxApPacketSize = ushort.MaxValue;
+
+    /// <summary>
+    /// Default for <see cref="MaxMessageBytes"/>: 16 MiB.
+    /// </summary>
+    public const int DefaultMaxMessageBytes = 16 * 1024 * 1024;
+
+    /// <summary>
+    /// Largest AP packet payload, and handshake response, that is accepted.
+    /// At most <see cref="MaxApPacketSize"/>, which is the default.
+    /// </summary>
+    public int MaxApPacketBytes { get; init; } = MaxApPacketSize;
+
+    /// <summary>
+    /// Largest reassembled Mercury message, dealer WebSocket message or decompressed dealer
+    /// payload. Default is <see cref="DefaultMaxMessageBytes"/>.
+    /// </summary>
+    public int MaxMessageBytes { get; init; } = DefaultMaxMessageBytes;
+
+    /// <summary>
+    /// Most parts in one Mercury message or entries in one dealer <c>payloads</c> array.
+    /// Default is 256.
+    /// </summary>
+    public int MaxParts { get; init; } = 256;
+
+    /// <summary>
+    /// Most headers on one dealer message, or user fields on one Mercury header.
+    /// Default is 64.
+    /// </summary>
+    public int MaxHeaders { get; init; } = 64;
+
+    /// <summary>
+    /// Deepest JSON nesting accepted in a dealer message. Default is 64.
+    /// </summary>
+    public int MaxJsonDepth { get; init; } = 64;
+}
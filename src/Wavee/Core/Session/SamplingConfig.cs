@@ -0,0 +1,30 @@
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Rate limits for high-frequency diagnostics: debug logs and pushed events that fire
+/// many times per track.
+/// </summary>
+/// <remarks>
+/// Each interval bounds one module to at most one entry per interval. The first entry
+/// always goes through, and a sampled log line reports how many entries it stood in for,
+/// so an always-on daemon keeps an occasional trace without paying for every update.
+/// <see cref="TimeSpan.Zero"/> turns sampling off for that module.
+/// </remarks>
+public sealed record SamplingConfig
+{
+    /// <summary>
+    /// Minimum gap between "Position changed" debug logs. Default is zero (every change).
+    /// </summary>
+    public TimeSpan PositionLogInterval { get; init; } = TimeSpan.Zero;
+
+    /// <summary>
+    /// Minimum gap between per-chunk audio cache debug logs. Default is zero (every chunk).
+    /// </summary>
+    public TimeSpan AudioChunkLogInterval { get; init; } = TimeSpan.Zero;
+
+    /// <summary>
+    /// Minimum gap between <c>player.state</c> events pushed to daemon integrations.
+    /// Changes inside the window are coalesced into the latest state. Default is 250 ms.
+    /// </summary>
+    public TimeSpan StateEventInterval { get; init; } = TimeSpan.FromMilliseconds(250);
+}
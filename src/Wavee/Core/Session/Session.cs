@@ -7,15 +7,18 @@ using Wavee.Connect;
 using Wavee.Connect.Commands;
 using Wavee.Connect.Diagnostics;
 using Wavee.Connect.Events;
+using Wavee.Connect.Metadata;
 using Wavee.Connect.Protocol;
 using Wavee.Core.Audio;
 using Wavee.Core.Authentication;
 using Wavee.Core.Connection;
+using Wavee.Core.Diagnostics;
 using Wavee.Core.Http;
 using System.Reactive.Linq;
 using System.Reactive.Subjects;
 using Wavee.Core.Mercury;
 using Wavee.Core.Time;
+using Wavee.Core.Utilities;
 
 namespace Wavee.Core.Session;
 
@@ -42,10 +45,13 @@ public sealed class Session : ISession, IAsyncDisposable
     private readonly SessionConfig _config;
     private readonly SessionData _data;
     private readonly ILogger? _logger;
+    private readonly TimeProvider _timeProvider;
     private readonly IRemoteStateRecorder? _remoteStateRecorder;
     private readonly HttpClient _httpClient;
+    private readonly BandwidthMeter _bandwidth;
     private readonly SemaphoreSlim _connectLock = new(1, 1);
     private readonly SemaphoreSlim _tokenRefreshLock = new(1, 1);
+    private readonly RemoteCommandLog _commandLog;
 
     // Packet dispatcher
     private readonly Channel<(byte command, byte[] payload)> _sendQueue;
@@ -68,8 +74,18 @@ public sealed class Session : ISession, IAsyncDisposable
     private readonly BehaviorSubject<SessionConnectionState> _connectionState = new(SessionConnectionState.Connected);
     public IObservable<SessionConnectionState> ConnectionState => _connectionState;
 
+    // Offline mode (see SetOfflineModeAsync)
+    private readonly BehaviorSubject<bool> _offlineMode = new(false);
+    public bool IsOfflineMode => _offlineMode.Value;
+
+    /// <summary>
+    /// Emits the current offline-mode flag on subscribe and whenever it is switched.
+    /// </summary>
+    public IObservable<bool> OfflineModeChanged => _offlineMode.DistinctUntilChanged();
+
     // Connect subsystem
     private DealerClient? _dealerClient;
+    private HttpMessageInvoker? _dealerInvoker;
     private DeviceStateManager? _deviceStateManager;
     private ConnectCommandHandler? _commandHandler;
     private PlaybackStateManager? _playbackStateManager;
@@ -88,8 +104,11 @@ public sealed class Session : ISession, IAsyncDisposable
     private KeepAlive? _keepAlive;
 
     // Proactive AP health check: timestamp of last packet received from AP
-    private DateTime _lastApPacketUtc = DateTime.UtcNow;
+    private DateTime _lastApPacketUtc;
     private IDisposable? _dealerReconnectSubscription;
+
+    // Re-establishes AP and dealer connections when the network path changes
+    private NetworkChangeMonitor? _networkMonitor;
     private IDisposable? _recorderDealerStateSubscription;
     private IDisposable? _recorderConnectionIdSubscription;
 
@@ -98,6 +117,11 @@ public sealed class Session : ISession, IAsyncDisposable
 
     // Pathfinder client (cached — one instance per session)
     private PathfinderClient? _pathfinderClient;
+    private TrackMediaAssetService? _mediaAssets;
+    private ImagePipeline? _images;
+
+    // ETag cache shared by the per-access SpClient instances
+    private readonly ConditionalResponseCache _responseCache = new();
 
     // Event subsystem
     private EventService? _eventService;
@@ -117,6 +141,36 @@ public sealed class Session : ISession, IAsyncDisposable
     /// </summary>
     public SessionConfig Config => _config;
 
+    /// <summary>
+    /// Gets the per-category byte counters for this session's traffic.
+    /// </summary>
+    /// <remarks>
+    /// AP and dealer traffic is counted here directly, HTTP by the <c>"Wavee"</c> client when it
+    /// meters onto <see cref="SessionConfig.Bandwidth"/>. Audio fetched by the AudioHost is
+    /// added by the <see cref="Wavee.Audio.PlaybackOrchestrator"/> built through
+    /// <see cref="Wavee.Audio.PlayerBuilder"/>.
+    /// </remarks>
+    public BandwidthMeter Bandwidth => _bandwidth;
+
+    /// <summary>
+    /// Gets the debug log samplers built from <see cref="SessionConfig.Sampling"/>; pass them
+    /// to components that log per chunk, such as the audio cache.
+    /// </summary>
+    public LogSampling LogSampling { get; }
+
+    /// <summary>
+    /// Gets or sets the recorder dealer traffic is written to. Takes effect for the dealer
+    /// connection created by the next <see cref="ConnectAsync"/>; the caller owns and disposes it.
+    /// </summary>
+    public DealerTrafficRecorder? DealerTrafficRecorder { get; set; }
+
+    /// <summary>
+    /// Gets or sets the <c>.wavee-trace</c> writer that AP, Mercury and dealer traffic is written to.
+    /// AP packets are traced from the next one on; dealer frames from the next <see cref="ConnectAsync"/>.
+    /// The caller owns and disposes it.
+    /// </summary>
+    public ProtocolTraceWriter? ProtocolTraceWriter { get; set; }
+
     private Session(
         SessionConfig config,
         IHttpClientFactory httpClientFactory,
@@ -127,9 +181,17 @@ public sealed class Session : ISession, IAsyncDisposable
 
         _config = config;
         _logger = logger;
+        _timeProvider = config.TimeProvider ?? TimeProvider.System;
+        _lastApPacketUtc = _timeProvider.GetUtcNow().UtcDateTime;
         _remoteStateRecorder = remoteStateRecorder;
+        _bandwidth = config.Bandwidth ?? new BandwidthMeter(_timeProvider);
+        LogSampling = new LogSampling(config.Sampling, _timeProvider);
         _httpClient = httpClientFactory.CreateClient("Wavee");
         _data = new SessionData(config, _httpClient, logger);
+        _commandLog = new RemoteCommandLog(
+            resolveDeviceName: ResolveConnectDeviceName,
+            resolveUsername: () => _data.GetUserData()?.Username,
+            timeProvider: _timeProvider);
 
         // Bounded channel for send queue (backpressure)
         _sendQueue = Channel.CreateBounded<(byte, byte[])>(new BoundedChannelOptions(100)
@@ -153,6 +215,10 @@ public sealed class Session : ISession, IAsyncDisposable
     {
         ArgumentNullException.ThrowIfNull(config);
         ArgumentNullException.ThrowIfNull(httpClientFactory);
+
+        if (LogRedaction.UnsafeLogSecrets)
+            logger?.LogWarning("UnsafeLogSecrets is enabled: tokens and keys will be written to logs verbatim");
+
         return new Session(config, httpClientFactory, logger, remoteStateRecorder);
     }
 
@@ -258,12 +324,19 @@ public sealed class Session : ISession, IAsyncDisposable
             _clientTokenManager = new ClientTokenManager(_httpClient, _config, _logger);
 
             // 8. Initialize Mercury protocol (for keymaster tokens, subscriptions, etc.)
-            _mercuryManager = new MercuryManager(this, _logger);
+            _mercuryManager = new MercuryManager(this, _logger, _config.Limits);
             _keymasterTokenProvider = new KeymasterTokenProvider(
                 _mercuryManager, _config, _config.DeviceId, _logger);
 
             // 8. Initialize Spotify Connect subsystem
             await InitializeConnectSubsystemAsync(cancellationToken);
+
+            // 9. Reconnect proactively when the network changes
+            if (_config.ReconnectOnNetworkChange && _networkMonitor is null)
+            {
+                _networkMonitor = new NetworkChangeMonitor(_logger);
+                _networkMonitor.NetworkChanged += OnNetworkChanged;
+            }
         }
         finally
         {
@@ -304,8 +377,11 @@ public sealed class Session : ISession, IAsyncDisposable
 
         _playbackStateManager.PositionChanged.Subscribe(state =>
         {
-            _logger?.LogDebug("Position changed: {Position}ms / {Duration}ms",
-                state.PositionMs, state.DurationMs);
+            if (_logger is null || !_logger.IsEnabled(LogLevel.Debug) || !LogSampling.Position.ShouldSample(out var suppressed))
+                return;
+
+            _logger.LogDebug("Position changed: {Position}ms / {Duration}ms ({Suppressed} suppressed)",
+                state.PositionMs, state.DurationMs, suppressed);
         });
 
         _playbackStateManager.ActiveDeviceChanged.Subscribe(state =>
@@ -358,9 +434,28 @@ public sealed class Session : ISession, IAsyncDisposable
         {
             _logger?.LogDebug("Initializing Spotify Connect subsystem");
 
+            // Interface binding / family selection and TLS pinning need their own handler
+            // for the WebSocket upgrade; ClientWebSocket doesn't go through _httpClient.
+            if (_dealerInvoker is null
+                && (NetworkBinding.IsCustomized(_config.Network) || CertificatePinning.IsEnabled(_config.Network)))
+            {
+                _dealerInvoker = new HttpMessageInvoker(CertificatePinning.Apply(
+                    NetworkBinding.Apply(new SocketsHttpHandler(), _config.Network), _config.Network, _logger));
+            }
+
             // Create and connect DealerClient with config containing logger
             _dealerClient = new DealerClient(
-                config: new DealerClientConfig { Logger = _logger },
+                config: new DealerClientConfig
+                {
+                    Logger = _logger,
+                    ConnectionTimeout = _config.Network.DealerConnectTimeout,
+                    WebSocketInvoker = _dealerInvoker,
+                    Limits = _config.Limits,
+                    Bandwidth = _bandwidth,
+                    TrafficRecorder = DealerTrafficRecorder,
+                    ProtocolTrace = ProtocolTraceWriter,
+                    TimeProvider = _timeProvider
+                },
                 remoteStateRecorder: _remoteStateRecorder);
             SubscribeRemoteStateRecorder(_dealerClient);
             await _dealerClient.ConnectAsync(this, _httpClient, cancellationToken);
@@ -380,10 +475,12 @@ public sealed class Session : ISession, IAsyncDisposable
                 _dealerClient,
                 initialVolume: _config.InitialVolume,
                 logger: _logger,
-                remoteStateRecorder: _remoteStateRecorder);
+                remoteStateRecorder: _remoteStateRecorder,
+                commandLog: _commandLog);
 
             // Create command handler for processing incoming REQUESTs
-            _commandHandler = new ConnectCommandHandler(_dealerClient, _logger, _remoteStateRecorder);
+            _commandHandler = new ConnectCommandHandler(
+                _dealerClient, _logger, _remoteStateRecorder, _commandLog, _config.CommandPolicy);
             _logger?.LogDebug("ConnectCommandHandler created");
 
             // Create playback state manager for processing cluster MESSAGEs
@@ -415,7 +512,11 @@ public sealed class Session : ISession, IAsyncDisposable
             // context_application_desktop.session_id (16 random bytes,
             // generated once per Session instance — same lifetime as the
             // desktop client's "session id" semantics).
-            var appSessionId = System.Security.Cryptography.RandomNumberGenerator.GetBytes(16);
+            var appSessionId = new byte[16];
+            if (_config.Rng is null)
+                System.Security.Cryptography.RandomNumberGenerator.Fill(appSessionId);
+            else
+                _config.Rng.GetBytes(appSessionId);
             _eventService = new EventService(
                 (SpClient)SpClient,
                 deviceIdHex: _config.DeviceId,
@@ -452,12 +553,14 @@ public sealed class Session : ISession, IAsyncDisposable
     /// <param name="packetType">The packet type.</param>
     /// <param name="payload">The payload data.</param>
     /// <param name="cancellationToken">Cancellation token.</param>
+    /// <exception cref="OfflineModeException">Thrown if the session is in offline mode.</exception>
     /// <exception cref="SessionException">Thrown if the session is not connected.</exception>
     public async ValueTask SendAsync(
         PacketType packetType,
         ReadOnlyMemory<byte> payload,
         CancellationToken cancellationToken = default)
     {
+        OfflineModeException.ThrowIfOffline(this);
         if (!_data.IsConnected())
             throw new SessionException(SessionFailureReason.Disposed, "Session not connected");
 
@@ -498,7 +601,10 @@ public sealed class Session : ISession, IAsyncDisposable
         _spClientEndpoint ?? "spclient.wg.spotify.com:443",
         _clientTokenManager,
         _logger,
-        _remoteStateRecorder);
+        _remoteStateRecorder,
+        _config.Network.SpClientRequestTimeout,
+        _responseCache,
+        _config.Network.MaxResponseBytes);
 
     /// <summary>
     /// Gets the resolved SpClient endpoint URL.
@@ -519,7 +625,7 @@ public sealed class Session : ISession, IAsyncDisposable
     /// Gets the clock synchronization service for correcting local-vs-server time skew.
     /// Lazily initialized on first access after SpClient is available.
     /// </summary>
-    public SpotifyClockService Clock => _clockService ??= new SpotifyClockService(SpClient, _logger);
+    public SpotifyClockService Clock => _clockService ??= new SpotifyClockService(SpClient, _logger, _timeProvider);
 
     /// <summary>
     /// Gets the Pathfinder client for GraphQL API requests (search, browse, etc).
@@ -535,6 +641,21 @@ public sealed class Session : ISession, IAsyncDisposable
         clientTokenManager: _clientTokenManager,
         logger: _logger);
 
+    /// <summary>
+    /// Gets the service fetching canvas videos and related music videos for tracks.
+    /// </summary>
+    /// <remarks>
+    /// Pair with <see cref="PlaybackStateManager.TrackChanged"/> via
+    /// <see cref="TrackMediaAssetService.WatchTrackChanges"/> to be told when the playing
+    /// track's assets arrive.
+    /// </remarks>
+    public TrackMediaAssetService MediaAssets => _mediaAssets ??= new TrackMediaAssetService(Pathfinder, logger: _logger);
+
+    /// <summary>
+    /// Gets the image loader for cover art and avatars, with a size-capped memory cache.
+    /// </summary>
+    public IImagePipeline Images => _images ??= new ImagePipeline(_httpClient, timeProvider: _timeProvider, isOffline: () => IsOfflineMode, logger: _logger);
+
     /// <summary>
     /// Gets the Spotify Connect dealer client for real-time communication.
     /// </summary>
@@ -568,6 +689,25 @@ public sealed class Session : ISession, IAsyncDisposable
     /// <returns>ConnectCommandHandler instance, or null if Connect is disabled.</returns>
     public ConnectCommandHandler? CommandHandler => _commandHandler;
 
+    /// <summary>
+    /// Gets the history of remote Connect commands this device received, with the
+    /// sending device and the reply each got.
+    /// </summary>
+    /// <remarks>
+    /// Kept across dealer reconnects; empty when Connect is disabled.
+    /// </remarks>
+    public RemoteCommandLog CommandLog => _commandLog;
+
+    // Sender names come from the last cluster update; unknown devices stay unnamed.
+    private string? ResolveConnectDeviceName(string deviceId)
+    {
+        if (deviceId == _config.DeviceId)
+            return _config.DeviceName;
+
+        var devices = _playbackStateManager?.CurrentState.AvailableConnectDevices;
+        return devices?.FirstOrDefault(d => d.DeviceId == deviceId)?.Name;
+    }
+
     /// <summary>
     /// Gets the Spotify Connect playback state manager for tracking remote state updates.
     /// </summary>
@@ -608,6 +748,42 @@ public sealed class Session : ISession, IAsyncDisposable
         }
     }
 
+    /// <summary>
+    /// Captures a snapshot of connection state, nonce counters, pending requests,
+    /// dealer topics and cache statistics for attaching to bug reports.
+    /// Safe to call at any time, including while disconnected.
+    /// </summary>
+    public SessionDiagnostics GetDiagnostics()
+    {
+        var transport = _data.GetTransport();
+        var (apUrl, connectedAt) = _data.GetConnectionInfo();
+        var clock = Clock;
+
+        var connection = new ConnectionDiagnostics(
+            State: _connectionState.Value,
+            IsAuthenticated: _data.IsConnected(),
+            ApUrl: apUrl,
+            ConnectedAtUtc: transport != null ? connectedAt : null,
+            LastApPacketUtc: _lastApPacketUtc,
+            EncodeNonce: transport?.EncodeNonce,
+            DecodeNonce: transport?.DecodeNonce,
+            ClockOffsetMs: clock.OffsetMs,
+            ClockIsCoarse: clock.IsCoarse);
+
+        var dealer = _dealerClient is { } d
+            ? new DealerDiagnostics(d.CurrentState, d.CurrentConnectionId, d.PendingRequestCount, d.GetTopicCounts())
+            : null;
+
+        return new SessionDiagnostics(
+            CapturedAtUtc: clock.UtcNow,
+            Connection: connection,
+            Dealer: dealer,
+            PendingMercuryRequests: _mercuryManager?.GetPendingRequests() ?? [],
+            PendingAudioKeyRequests: _audioKeyManager?.PendingCount ?? 0,
+            Cache: _cacheService?.GetStatistics(),
+            Bandwidth: _bandwidth.GetStatistics());
+    }
+
     /// <summary>
     /// Injects a disk-backed cache for AudioKey persistence. Call this early —
     /// before the first track plays — so <see cref="AudioKeys"/> is constructed
@@ -761,19 +937,21 @@ public sealed class Session : ISession, IAsyncDisposable
     {
         // Fast path: valid token already cached — no lock needed.
         var token = _data.GetAccessToken();
-        if (token != null && !token.ShouldRefresh())
+        if (token != null && !token.ShouldRefresh(now: Clock.UtcNow))
             return token;
 
         // Serialize concurrent refresh attempts so only one login5 call runs at a time.
         // The double-check inside the lock handles the "thundering herd" at startup where
         // SpClient, DealerClient, ClockService, etc. all call GetAccessTokenAsync
         // simultaneously and would otherwise each trigger a separate login5 flow.
+        OfflineModeException.ThrowIfOffline(this);
+
         await _tokenRefreshLock.WaitAsync(cancellationToken);
         try
         {
             // Double-check: another caller may have refreshed while we waited.
             token = _data.GetAccessToken();
-            if (token != null && !token.ShouldRefresh())
+            if (token != null && !token.ShouldRefresh(now: Clock.UtcNow))
                 return token;
 
             _logger?.LogDebug("Access token expired or missing, refreshing via login5");
@@ -791,7 +969,8 @@ public sealed class Session : ISession, IAsyncDisposable
                 storedCredentials.Username!,
                 storedCredentials.AuthData,
                 clientToken: null,
-                cancellationToken);
+                cancellationToken,
+                issuedAt: Clock.UtcNow);
 
             _data.SetAccessToken(token);
             _logger?.LogInformation("Access token refreshed (expires {ExpiresAt})", token.ExpiresAt);
@@ -810,6 +989,7 @@ public sealed class Session : ISession, IAsyncDisposable
     /// <param name="locale">2-character ISO 639-1 language code (e.g., "en", "es", "fr").</param>
     /// <param name="cancellationToken">Cancellation token.</param>
     /// <exception cref="ArgumentException">Thrown if locale is not exactly 2 characters.</exception>
+    /// <exception cref="OfflineModeException">Thrown if the session is in offline mode.</exception>
     /// <exception cref="SessionException">Thrown if session is not connected.</exception>
     public async Task UpdateLocaleAsync(string locale, CancellationToken cancellationToken = default)
     {
@@ -818,6 +998,7 @@ public sealed class Session : ISession, IAsyncDisposable
         if (locale.Length != 2)
             throw new ArgumentException("Locale must be exactly 2 characters (ISO 639-1 language code)", nameof(locale));
 
+        OfflineModeException.ThrowIfOffline(this);
         if (!_data.IsConnected())
             throw new SessionException(SessionFailureReason.Disposed, "Session not connected");
 
@@ -870,30 +1051,51 @@ public sealed class Session : ISession, IAsyncDisposable
 
         var host = parts[0];
 
-        // Connect TCP socket
-        var tcpClient = new TcpClient();
-        await tcpClient.ConnectAsync(host, port, cancellationToken);
-        var stream = tcpClient.GetStream();
+        var network = _config.Network;
+
+        // Connect TCP socket. A dead AP otherwise hangs until the OS gives up on
+        // the SYN, which can take well over a minute before the next AP is tried.
+        Socket socket;
+        using (var connectCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken))
+        {
+            connectCts.CancelAfter(network.ApConnectTimeout);
+            try
+            {
+                socket = await NetworkBinding.ConnectAsync(host, port, network, connectCts.Token);
+            }
+            catch (OperationCanceledException) when (!cancellationToken.IsCancellationRequested)
+            {
+                throw new SessionException(
+                    SessionFailureReason.ConnectionFailed,
+                    $"Timed out connecting to {apUrl} after {network.ApConnectTimeout.TotalSeconds}s");
+            }
+        }
+
+        var stream = new NetworkStream(socket, ownsSocket: true);
 
         // Perform handshake
+        using var handshakeCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+        handshakeCts.CancelAfter(network.ApHandshakeTimeout);
         try
         {
-            return await Handshake.PerformHandshakeAsync(stream, _logger, cancellationToken);
+            return await Handshake.PerformHandshakeAsync(stream, _logger, handshakeCts.Token, _config.Rng, _config.Limits);
         }
         catch (Exception ex)
         {
             await stream.DisposeAsync();
-            tcpClient.Dispose();
+            var timedOut = ex is OperationCanceledException && !cancellationToken.IsCancellationRequested;
             throw new SessionException(
                 SessionFailureReason.HandshakeFailed,
-                $"Handshake failed with {apUrl}",
+                timedOut
+                    ? $"Handshake with {apUrl} timed out after {network.ApHandshakeTimeout.TotalSeconds}s"
+                    : $"Handshake failed with {apUrl}",
                 ex);
         }
     }
 
     private async Task DispatchLoop(CancellationToken cancellationToken)
     {
-        _keepAlive = new KeepAlive(_logger);
+        _keepAlive = new KeepAlive(_logger, _timeProvider);
 
         // Reuse the same receive task across loop iterations to avoid concurrent PipeReader access
         // This is critical: creating a new ReceiveAsync while the previous one is pending causes corruption
@@ -936,6 +1138,8 @@ public sealed class Session : ISession, IAsyncDisposable
                     try
                     {
                         await transport.SendAsync(cmd, payload, cancellationToken);
+                        _bandwidth.RecordSent(ClassifyPacket((PacketType)cmd), ApCodec.GetEncodedSize(payload.Length));
+                        ProtocolTraceWriter?.RecordApPacket(ProtocolTraceDirection.Outbound, cmd, payload);
                     }
                     catch (ObjectDisposedException)
                     {
@@ -965,7 +1169,7 @@ public sealed class Session : ISession, IAsyncDisposable
                     }
                 }
 
-                var timeoutTask = Task.Delay(TimeSpan.FromMilliseconds(100), cancellationToken);
+                var timeoutTask = Task.Delay(TimeSpan.FromMilliseconds(100), _timeProvider, cancellationToken);
 
                 var completedTask = await Task.WhenAny(receiveTask, timeoutTask);
 
@@ -1000,7 +1204,9 @@ public sealed class Session : ISession, IAsyncDisposable
                     }
 
                     var (cmd, payload) = packet.Value;
-                    _lastApPacketUtc = DateTime.UtcNow;
+                    _lastApPacketUtc = _timeProvider.GetUtcNow().UtcDateTime;
+                    _bandwidth.RecordReceived(ClassifyPacket((PacketType)cmd), ApCodec.GetEncodedSize(payload.Length));
+                    ProtocolTraceWriter?.RecordApPacket(ProtocolTraceDirection.Inbound, cmd, payload);
                     HandlePacket((PacketType)cmd, payload);
                 }
                 // else: timeout, receiveTask stays assigned and will be checked on next iteration
@@ -1043,6 +1249,18 @@ public sealed class Session : ISession, IAsyncDisposable
         }
     }
 
+    // Mercury carries metadata; stream chunks and audio keys belong to playback; the rest
+    // (pings, country code, product info, ...) is session control.
+    internal static TrafficCategory ClassifyPacket(PacketType packetType) => packetType switch
+    {
+        PacketType.MercuryReq or PacketType.MercurySub or PacketType.MercuryUnsub or PacketType.MercuryEvent
+            => TrafficCategory.Metadata,
+        PacketType.StreamChunk or PacketType.StreamChunkRes or PacketType.ChannelError or PacketType.ChannelAbort
+            or PacketType.RequestKey or PacketType.AesKey or PacketType.AesKeyError
+            => TrafficCategory.Audio,
+        _ => TrafficCategory.Control
+    };
+
     private void HandlePacket(PacketType packetType, byte[] payload)
     {
         _logger?.LogTrace("Received packet: {PacketType} ({Size} bytes)", packetType, payload.Length);
@@ -1059,10 +1277,15 @@ public sealed class Session : ISession, IAsyncDisposable
                 if (payload.Length >= 4)
                 {
                     var serverTimestamp = (long)System.Buffers.Binary.BinaryPrimitives.ReadUInt32BigEndian(payload);
-                    var localTimestamp = DateTimeOffset.UtcNow.ToUnixTimeSeconds();
+                    var localTimestamp = _timeProvider.GetUtcNow().ToUnixTimeSeconds();
                     var timeDelta = serverTimestamp - localTimestamp;
                     _logger?.LogTrace("Received Ping from server (timestamp={ServerTs}, delta={Delta}s)",
                         serverTimestamp, timeDelta);
+
+                    // Coarse skew hint — only used until the melody sync lands.
+                    Clock.ObserveServerTime(
+                        DateTimeOffset.FromUnixTimeSeconds(serverTimestamp),
+                        TimeSpan.FromSeconds(1));
                 }
                 else
                 {
@@ -1191,6 +1414,7 @@ public sealed class Session : ISession, IAsyncDisposable
                             VideoKeyframeUrl = videoKeyframeUrl,
                             IsClientDeprecated = isClientDeprecated,
                             LoudnessLevels = loudnessLevels,
+                            ProductAttributes = attributes,
                         };
                         _data.SetUserData(updatedUserData);
                         _logger?.LogDebug("Updated UserData with ProductInfo fields (deprecated={Deprecated}, loudness={Loudness})",
@@ -1292,6 +1516,7 @@ public sealed class Session : ISession, IAsyncDisposable
         {
             if (!IsConnected()) return;
 
+            StopNetworkMonitor();
             await DisconnectInternalAsync();
             _connectionState.OnNext(SessionConnectionState.Disconnected);
             OnDisconnected();
@@ -1316,6 +1541,8 @@ public sealed class Session : ISession, IAsyncDisposable
     /// </remarks>
     public async Task ReconnectApAsync(CancellationToken cancellationToken = default)
     {
+        OfflineModeException.ThrowIfOffline(this);
+
         await _connectLock.WaitAsync(cancellationToken);
         try
         {
@@ -1405,8 +1632,10 @@ public sealed class Session : ISession, IAsyncDisposable
             _data.SetTransport(newTransport, connectedAp);
             _data.SetStoredCredentials(reusableCredentials);
 
-            // 8. Reset AudioKeyManager sequence
+            // 8. Reset AudioKeyManager sequence and fail in-flight Mercury requests
+            //    (their responses died with the old connection)
             _audioKeyManager?.ResetSequence();
+            _mercuryManager?.Reset();
 
             // 9. Restart dispatcher (dispose old CTS to prevent leak)
             _dispatchCts?.Dispose();
@@ -1415,11 +1644,16 @@ public sealed class Session : ISession, IAsyncDisposable
             _logger?.LogDebug("Restarted packet dispatcher");
 
             _logger?.LogInformation("AP reconnection successful");
+            // A fresh connection isn't stale; keeps OnDealerReconnected from reconnecting it again
+            _lastApPacketUtc = _timeProvider.GetUtcNow().UtcDateTime;
 
             // Restart clock sync after reconnection
             Clock.Start();
 
             _connectionState.OnNext(SessionConnectionState.Connected);
+
+            // The server dropped our Mercury SUBs with the old connection
+            TriggerBackgroundResubscribe();
         }
         catch
         {
@@ -1432,6 +1666,52 @@ public sealed class Session : ISession, IAsyncDisposable
         }
     }
 
+    /// <summary>
+    /// Switches offline mode on or off at runtime.
+    /// </summary>
+    /// <remarks>
+    /// Turning it on closes the AP connection and suspends the dealer. From then on token
+    /// refreshes, AP reconnects, spclient/Pathfinder/extended-metadata requests and audio key
+    /// requests throw <see cref="OfflineModeException"/>, and the track resolver only accepts
+    /// tracks whose audio is in the offline cache. Turning it off reconnects the AP and
+    /// resumes the dealer.
+    /// </remarks>
+    /// <param name="enabled">True to go offline, false to go back online.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    public async Task SetOfflineModeAsync(bool enabled, CancellationToken cancellationToken = default)
+    {
+        if (enabled == _offlineMode.Value)
+            return;
+
+        if (!enabled)
+        {
+            _logger?.LogInformation("Leaving offline mode");
+            _offlineMode.OnNext(false);
+
+            await ReconnectApAsync(cancellationToken);
+            if (_dealerClient is not null)
+                await _dealerClient.ResumeAsync(cancellationToken);
+            return;
+        }
+
+        _logger?.LogInformation("Entering offline mode");
+        await _connectLock.WaitAsync(cancellationToken);
+        try
+        {
+            // Flag first so nothing reconnects while we are tearing the connection down
+            _offlineMode.OnNext(true);
+            await DisconnectInternalAsync();
+            _connectionState.OnNext(SessionConnectionState.Disconnected);
+        }
+        finally
+        {
+            _connectLock.Release();
+        }
+
+        if (_dealerClient is not null)
+            await _dealerClient.SuspendAsync(cancellationToken);
+    }
+
     /// <summary>
     /// Called when the dealer WebSocket reconnects. Checks if the AP TCP connection
     /// is likely stale and proactively reconnects to avoid AudioKey timeout delays.
@@ -1439,7 +1719,7 @@ public sealed class Session : ISession, IAsyncDisposable
     private async void OnDealerReconnected()
     {
         const int stalenessThresholdSeconds = 10;
-        var elapsed = DateTime.UtcNow - _lastApPacketUtc;
+        var elapsed = _timeProvider.GetUtcNow().UtcDateTime - _lastApPacketUtc;
 
         if (elapsed.TotalSeconds < stalenessThresholdSeconds)
         {
@@ -1465,6 +1745,41 @@ public sealed class Session : ISession, IAsyncDisposable
         }
     }
 
+    /// <summary>
+    /// Called when the network path changes (Wi-Fi roam, VPN up/down). Sockets on the old
+    /// path would otherwise sit dead until TCP times out, so both the AP and the dealer
+    /// are reconnected right away.
+    /// </summary>
+    private async void OnNetworkChanged(object? sender, NetworkChangedEventArgs e)
+    {
+        if (!e.IsAvailable)
+        {
+            _logger?.LogWarning("Network lost; reconnecting once it comes back");
+            return;
+        }
+
+        if (IsOfflineMode)
+            return;
+
+        if (_data.GetStoredCredentials() is null)
+            return;
+
+        _logger?.LogInformation("Network changed — reconnecting Access Point and dealer");
+        try
+        {
+            using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(30));
+            await ReconnectApAsync(cts.Token);
+            _logger?.LogInformation("AP reconnection after network change succeeded");
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogError(ex, "AP reconnection after network change failed");
+        }
+
+        // AP first: a dealer reconnect that lands before it would see a stale AP and reconnect it too.
+        _dealerClient?.ReconnectNow();
+    }
+
     private void SubscribeRemoteStateRecorder(DealerClient dealerClient)
     {
         if (_remoteStateRecorder == null) return;
@@ -1515,6 +1830,16 @@ public sealed class Session : ISession, IAsyncDisposable
         _recorderConnectionIdSubscription = null;
     }
 
+    private void StopNetworkMonitor()
+    {
+        if (_networkMonitor is null)
+            return;
+
+        _networkMonitor.NetworkChanged -= OnNetworkChanged;
+        _networkMonitor.Dispose();
+        _networkMonitor = null;
+    }
+
     private void OnDisconnected()
     {
         Disconnected?.Invoke(this, EventArgs.Empty);
@@ -1527,6 +1852,9 @@ public sealed class Session : ISession, IAsyncDisposable
     /// </summary>
     private void TriggerBackgroundReconnect(string reason)
     {
+        if (IsOfflineMode)
+            return;
+
         _ = Task.Run(async () =>
         {
             try
@@ -1542,6 +1870,30 @@ public sealed class Session : ISession, IAsyncDisposable
         });
     }
 
+    /// <summary>
+    /// Re-sends Mercury SUB requests on a pool thread after an AP reconnect, so
+    /// subscribers keep receiving events on the new connection.
+    /// </summary>
+    private void TriggerBackgroundResubscribe()
+    {
+        var mercury = _mercuryManager;
+        if (mercury is null || mercury.Subscriptions.Count == 0)
+            return;
+
+        _ = Task.Run(async () =>
+        {
+            try
+            {
+                using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(30));
+                await mercury.ResubscribeAsync(cts.Token);
+            }
+            catch (Exception ex)
+            {
+                _logger?.LogError(ex, "Mercury resubscribe after AP reconnect failed");
+            }
+        });
+    }
+
     /// <summary>
     /// Disposes the session and releases all resources.
     /// </summary>
@@ -1569,6 +1921,7 @@ public sealed class Session : ISession, IAsyncDisposable
         _dealerReconnectSubscription?.Dispose();
         _dealerReconnectSubscription = null;
         DisposeRemoteStateRecorderSubscriptions();
+        StopNetworkMonitor();
 
         if (_dealerClient is not null)
         {
@@ -1576,6 +1929,9 @@ public sealed class Session : ISession, IAsyncDisposable
             _dealerClient = null;
         }
 
+        _dealerInvoker?.Dispose();
+        _dealerInvoker = null;
+
         if (_audioKeyManager is not null)
         {
             await _audioKeyManager.DisposeAsync();
@@ -1591,6 +1947,9 @@ public sealed class Session : ISession, IAsyncDisposable
         _clockService?.Dispose();
         _clockService = null;
 
+        _images?.Dispose();
+        _images = null;
+
         await DisconnectInternalAsync();
 
         _data.Dispose();
@@ -0,0 +1,315 @@
+using System.Net;
+using System.Net.Sockets;
+using Microsoft.Extensions.Logging;
+using Wavee.Connect;
+using Wavee.Connect.Commands;
+using Wavee.Connect.Diagnostics;
+using Wavee.Core.Configuration;
+using Wavee.Core.Connection;
+using Wavee.Core.Http;
+
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Fluent alternative to <see cref="SessionConfig"/> + <see cref="Session.Create"/> that
+/// validates everything up front.
+/// </summary>
+/// <remarks>
+/// Only the device id and an <see cref="IHttpClientFactory"/> are required; every other
+/// option keeps the <see cref="SessionConfig"/> default unless set. New options are added
+/// as new methods, so callers never see a signature change. Validation failures throw
+/// <see cref="ConfigException"/> with the same dotted keys as <see cref="WaveeConfigLoader"/>
+/// (<c>session.deviceId</c>, <c>network.apConnectTimeout</c>, ...).
+/// </remarks>
+/// <example>
+/// <code>
+/// var session = new SessionBuilder()
+///     .WithDeviceId(deviceId)
+///     .WithDeviceName("Kitchen")
+///     .WithHttpClientFactory(httpClientFactory)
+///     .Build();
+/// </code>
+/// </example>
+public sealed class SessionBuilder
+{
+    private SessionConfig _config = new() { DeviceId = string.Empty };
+    private IHttpClientFactory? _httpClientFactory;
+    private ILogger? _logger;
+    private IRemoteStateRecorder? _remoteStateRecorder;
+
+    /// <summary>
+    /// Starts from an existing configuration instead of the defaults.
+    /// </summary>
+    public SessionBuilder FromConfig(SessionConfig config)
+    {
+        _config = config ?? throw new ArgumentNullException(nameof(config));
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.DeviceId"/>. Required.</summary>
+    public SessionBuilder WithDeviceId(string deviceId)
+    {
+        _config = _config with { DeviceId = deviceId };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.DeviceName"/>.</summary>
+    public SessionBuilder WithDeviceName(string deviceName)
+    {
+        _config = _config with { DeviceName = deviceName };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.DeviceType"/>.</summary>
+    public SessionBuilder WithDeviceType(DeviceType deviceType)
+    {
+        _config = _config with { DeviceType = deviceType };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.ClientId"/>.</summary>
+    public SessionBuilder WithClientId(string? clientId)
+    {
+        _config = _config with { ClientId = clientId };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.ApPort"/>.</summary>
+    public SessionBuilder WithApPort(int? port)
+    {
+        _config = _config with { ApPort = port };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.Proxy"/>.</summary>
+    public SessionBuilder WithProxy(IWebProxy? proxy)
+    {
+        _config = _config with { Proxy = proxy };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.PreferredLocale"/>.</summary>
+    public SessionBuilder WithPreferredLocale(string? locale)
+    {
+        _config = _config with { PreferredLocale = locale };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.MetadataLocale"/> and <see cref="SessionConfig.MetadataCountry"/>.</summary>
+    public SessionBuilder WithMetadataLocale(string? locale, string? country = null)
+    {
+        _config = _config with { MetadataLocale = locale, MetadataCountry = country };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.TimeZone"/>.</summary>
+    public SessionBuilder WithTimeZone(string? timeZone)
+    {
+        _config = _config with { TimeZone = timeZone };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.PowerSaveIdleTimeout"/>; zero disables power save.</summary>
+    public SessionBuilder WithPowerSaveIdleTimeout(TimeSpan idleTimeout)
+    {
+        _config = _config with { PowerSaveIdleTimeout = idleTimeout };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.InitialVolume"/> from a 0-100 percentage.</summary>
+    public SessionBuilder WithInitialVolumePercent(int percent)
+    {
+        if (percent is < 0 or > 100)
+            throw new ConfigException("session.initialVolume", nameof(SessionBuilder), $"must be between 0 and 100, got {percent}");
+
+        _config = _config with { InitialVolume = ConnectStateHelpers.VolumeFromPercentage(percent) };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.EnableConnect"/>.</summary>
+    public SessionBuilder WithConnect(bool enabled)
+    {
+        _config = _config with { EnableConnect = enabled };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.ReconnectOnNetworkChange"/>.</summary>
+    public SessionBuilder WithReconnectOnNetworkChange(bool enabled)
+    {
+        _config = _config with { ReconnectOnNetworkChange = enabled };
+        return this;
+    }
+
+    /// <summary>Replaces <see cref="SessionConfig.Network"/>.</summary>
+    public SessionBuilder WithNetwork(NetworkConfig network)
+    {
+        _config = _config with { Network = network ?? throw new ArgumentNullException(nameof(network)) };
+        return this;
+    }
+
+    /// <summary>Adjusts <see cref="SessionConfig.Network"/>, e.g. <c>n =&gt; n with { ApConnectTimeout = ... }</c>.</summary>
+    public SessionBuilder ConfigureNetwork(Func<NetworkConfig, NetworkConfig> configure)
+    {
+        ArgumentNullException.ThrowIfNull(configure);
+        _config = _config with { Network = configure(_config.Network) };
+        return this;
+    }
+
+    /// <summary>Replaces <see cref="SessionConfig.ConnectDevice"/>.</summary>
+    public SessionBuilder WithConnectDevice(ConnectDeviceConfig device)
+    {
+        _config = _config with { ConnectDevice = device ?? throw new ArgumentNullException(nameof(device)) };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.CommandPolicy"/>.</summary>
+    public SessionBuilder WithCommandPolicy(IRemoteCommandPolicy? policy)
+    {
+        _config = _config with { CommandPolicy = policy };
+        return this;
+    }
+
+    /// <summary>Replaces <see cref="SessionConfig.Sampling"/>.</summary>
+    public SessionBuilder WithSampling(SamplingConfig sampling)
+    {
+        _config = _config with { Sampling = sampling ?? throw new ArgumentNullException(nameof(sampling)) };
+        return this;
+    }
+
+    /// <summary>Replaces <see cref="SessionConfig.Limits"/>.</summary>
+    public SessionBuilder WithLimits(ProtocolLimitsConfig limits)
+    {
+        _config = _config with { Limits = limits ?? throw new ArgumentNullException(nameof(limits)) };
+        return this;
+    }
+
+    /// <summary>Sets <see cref="SessionConfig.TimeProvider"/>. Tests and simulations only.</summary>
+    public SessionBuilder WithTimeProvider(TimeProvider? timeProvider)
+    {
+        _config = _config with { TimeProvider = timeProvider };
+        return this;
+    }
+
+    /// <summary>Sets the factory for the <c>"Wavee"</c> HTTP client. Required.</summary>
+    public SessionBuilder WithHttpClientFactory(IHttpClientFactory httpClientFactory)
+    {
+        _httpClientFactory = httpClientFactory;
+        return this;
+    }
+
+    /// <summary>Sets the session logger.</summary>
+    public SessionBuilder WithLogger(ILogger? logger)
+    {
+        _logger = logger;
+        return this;
+    }
+
+    /// <summary>Sets the optional dealer/HTTP capture recorder.</summary>
+    public SessionBuilder WithRemoteStateRecorder(IRemoteStateRecorder? recorder)
+    {
+        _remoteStateRecorder = recorder;
+        return this;
+    }
+
+    /// <summary>
+    /// Validates and returns the configuration without creating a session.
+    /// </summary>
+    /// <exception cref="ConfigException">A setting is missing or out of range.</exception>
+    public SessionConfig BuildConfig()
+    {
+        Validate(_config);
+        return _config;
+    }
+
+    /// <summary>
+    /// Validates the options and creates the session. Call
+    /// <see cref="Session.ConnectAsync"/> on the result to connect.
+    /// </summary>
+    /// <exception cref="ConfigException">A setting is missing or out of range.</exception>
+    public Session Build()
+    {
+        var config = BuildConfig();
+        if (_httpClientFactory is null)
+            throw new ConfigException("httpClientFactory", nameof(SessionBuilder), "is required");
+
+        return Session.Create(config, _httpClientFactory, _logger, _remoteStateRecorder);
+    }
+
+    private static void Validate(SessionConfig config)
+    {
+        if (string.IsNullOrWhiteSpace(config.DeviceId))
+            Fail("session.deviceId", "is required");
+        if (string.IsNullOrWhiteSpace(config.DeviceName))
+            Fail("session.deviceName", "must not be empty");
+        if (config.ApPort is { } port && port is < 1 or > 65535)
+            Fail("session.apPort", $"must be between 1 and 65535, got {port}");
+        if (config.InitialVolume is < 0 or > 65535)
+            Fail("session.initialVolume", $"must be between 0 and 65535, got {config.InitialVolume}");
+        if (config.ConnectDevice.VolumeSteps <= 0)
+            Fail("connect.volumeSteps", $"must be positive, got {config.ConnectDevice.VolumeSteps}");
+        if (config.MetadataLocale is { } metadataLocale && !MetadataRequestLocale.TryNormalizeLocale(metadataLocale, out _))
+            Fail("session.metadataLocale", $"'{metadataLocale}' is not a locale such as pt-BR");
+        if (config.MetadataCountry is { } metadataCountry && !MetadataRequestLocale.TryNormalizeCountry(metadataCountry, out _))
+            Fail("session.metadataCountry", $"'{metadataCountry}' is not a two-letter country code");
+        if (config.TimeZone is { } timeZone && !ClientTimeZone.TryGetIanaId(timeZone, out _))
+            Fail("session.timeZone", $"'{timeZone}' is not a known time zone");
+
+        RequireNonNegative("session.powerSaveIdle", config.PowerSaveIdleTimeout);
+
+        var sampling = config.Sampling;
+        RequireNonNegative("sampling.positionLog", sampling.PositionLogInterval);
+        RequireNonNegative("sampling.audioChunkLog", sampling.AudioChunkLogInterval);
+        RequireNonNegative("sampling.stateEvents", sampling.StateEventInterval);
+
+        var limits = config.Limits;
+        if (limits.MaxApPacketBytes is < 1 or > ProtocolLimitsConfig.MaxApPacketSize)
+            Fail("limits.maxApPacketBytes", $"must be between 1 and {ProtocolLimitsConfig.MaxApPacketSize}, got {limits.MaxApPacketBytes}");
+        RequirePositive("limits.maxMessageBytes", limits.MaxMessageBytes);
+        RequirePositive("limits.maxParts", limits.MaxParts);
+        RequirePositive("limits.maxHeaders", limits.MaxHeaders);
+        RequirePositive("limits.maxJsonDepth", limits.MaxJsonDepth);
+
+        var network = config.Network;
+        RequirePositive("network.apConnectTimeout", network.ApConnectTimeout);
+        RequirePositive("network.apHandshakeTimeout", network.ApHandshakeTimeout);
+        RequirePositive("network.dealerConnectTimeout", network.DealerConnectTimeout);
+        RequirePositive("network.cdnRequestTimeout", network.CdnRequestTimeout);
+        RequirePositive("network.spClientRequestTimeout", network.SpClientRequestTimeout);
+        if (network.MaxResponseBytes <= 0)
+            Fail("network.maxResponseBytes", $"must be positive, got {network.MaxResponseBytes}");
+        if (network.LocalAddress is { } local
+            && (network.AddressFamily == AddressFamilyPreference.IPv4Only && local.AddressFamily != AddressFamily.InterNetwork
+                || network.AddressFamily == AddressFamilyPreference.IPv6Only && local.AddressFamily != AddressFamily.InterNetworkV6))
+        {
+            Fail("network.localAddress", $"{local} conflicts with addressFamily {network.AddressFamily}");
+        }
+        if (network.TlsPinning != TlsPinningMode.Off && network.TlsPins.Count == 0)
+            Fail("network.tlsPins", $"must list at least one pin when tlsPinning is {network.TlsPinning}");
+        foreach (var pin in network.TlsPins)
+        {
+            if (!CertificatePinning.TryNormalizePin(pin, out _))
+                Fail("network.tlsPins", $"'{pin}' is not a base64 SHA-256 public key hash");
+        }
+    }
+
+    private static void RequirePositive(string key, TimeSpan value)
+    {
+        if (value <= TimeSpan.Zero)
+            Fail(key, $"must be positive, got {value}");
+    }
+
+    private static void RequirePositive(string key, int value)
+    {
+        if (value <= 0)
+            Fail(key, $"must be positive, got {value}");
+    }
+
+    private static void RequireNonNegative(string key, TimeSpan value)
+    {
+        if (value < TimeSpan.Zero)
+            Fail(key, $"must not be negative, got {value}");
+    }
+
+    private static void Fail(string key, string message) =>
+        throw new ConfigException(key, nameof(SessionBuilder), message);
+}
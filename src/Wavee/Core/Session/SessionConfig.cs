@@ -1,4 +1,6 @@
 using System.Net;
+using System.Security.Cryptography;
+using Wavee.Connect.Commands;
 using Wavee.Core.Audio;
 
 namespace Wavee.Core.Session;
@@ -81,12 +83,106 @@ public sealed record SessionConfig
     /// </remarks>
     public int InitialVolume { get; init; } = 32767;
 
+    /// <summary>
+    /// Reconnect the Access Point and dealer as soon as the OS reports a network change
+    /// (Wi-Fi roam, VPN up/down) instead of waiting for the old sockets to time out.
+    /// </summary>
+    public bool ReconnectOnNetworkChange { get; init; } = true;
+
+    /// <summary>
+    /// Time without playback before players built by <c>PlayerBuilder</c> enter power save:
+    /// the audio output is released, caches are trimmed and dealer pings slow to
+    /// <c>DealerClientConfig.IdlePingInterval</c>. Any Connect command wakes it.
+    /// Zero (the default) disables power save.
+    /// </summary>
+    public TimeSpan PowerSaveIdleTimeout { get; init; }
+
+    /// <summary>
+    /// Per-transport connect and request timeouts (AP, dealer, CDN, spclient).
+    /// </summary>
+    public NetworkConfig Network { get; init; } = new();
+
+    /// <summary>
+    /// Capability flags and identity advertised to other Connect clients
+    /// (volume steps, fixed volume, rename, brand/model).
+    /// </summary>
+    public ConnectDeviceConfig ConnectDevice { get; init; } = new();
+
+    /// <summary>
+    /// Rate limits for high-frequency debug logs and pushed events (position, audio chunks).
+    /// </summary>
+    public SamplingConfig Sampling { get; init; } = new();
+
+    /// <summary>
+    /// Size, count and depth limits for inbound AP, Mercury and dealer frames.
+    /// </summary>
+    public ProtocolLimitsConfig Limits { get; init; } = new();
+
+    /// <summary>
+    /// Guardrail deciding which remote Connect commands this device carries out
+    /// (volume caps, quiet hours). Null accepts every supported command.
+    /// </summary>
+    public IRemoteCommandPolicy? CommandPolicy { get; init; }
+
     /// <summary>
     /// Preferred 2-character Spotify locale override (for example "en" or "ko").
     /// When null or empty, Spotify services use their default locale.
     /// </summary>
     public string? PreferredLocale { get; init; }
 
+    /// <summary>
+    /// BCP 47 locale sent with metadata requests (for example "pt-BR" or "zh-TW"), so translated
+    /// album titles and editorial content come back in a regional variant. Used only while its
+    /// language matches the preferred locale (or no preferred locale is set). Null sends the
+    /// preferred locale alone.
+    /// </summary>
+    public string? MetadataLocale { get; init; }
+
+    /// <summary>
+    /// ISO 3166-1 alpha-2 market used for metadata lookups instead of the account country
+    /// (for example "JP"). Playback and licensing still follow the account country, so entities
+    /// the account can't play stay restricted. Null uses the account country.
+    /// </summary>
+    public string? MetadataCountry { get; init; }
+
+    /// <summary>
+    /// IANA time zone (for example "Europe/Amsterdam") reported to the home feed, which picks
+    /// content by time of day. Null uses the <c>TZ</c> environment variable, then the system
+    /// time zone — set this for daemons in containers whose clock is UTC.
+    /// </summary>
+    public string? TimeZone { get; init; }
+
+    /// <summary>
+    /// Randomness source for the AP handshake (DH private key, client nonce) and other
+    /// per-session random identifiers. Null uses the system CSPRNG.
+    /// </summary>
+    /// <remarks>
+    /// Exists so handshake and discovery flows can be replayed deterministically in tests.
+    /// Never set this in production — a predictable generator breaks the key exchange.
+    /// </remarks>
+    public RandomNumberGenerator? Rng { get; init; }
+
+    /// <summary>
+    /// Clock driving the AP keep-alive, token expiry checks, clock-sync interval and the
+    /// dealer's heartbeat and reconnect backoff. Null uses <see cref="TimeProvider.System"/>.
+    /// </summary>
+    /// <remarks>
+    /// Exists so simulation tests can advance virtual time and cover hours of session
+    /// behaviour (token refresh, keep-alive cycles, reconnect storms) in milliseconds.
+    /// </remarks>
+    public TimeProvider? TimeProvider { get; init; }
+
+    /// <summary>
+    /// Meter the session counts its traffic on. Null gives the session its own meter, which
+    /// then only sees AP and dealer traffic.
+    /// </summary>
+    /// <remarks>
+    /// HTTP bodies are counted by the <c>"Wavee"</c> client's handler, so set this to the meter
+    /// given to <see cref="Wavee.Core.DependencyInjection.WaveeHttpClientBuilderExtensions.AddBandwidthMetering"/>.
+    /// Sessions sharing a config (e.g. Connect zones) share the meter.
+    /// </remarks>
+    public BandwidthMeter? Bandwidth { get; init; }
+
     /// <summary>
     /// Gets the effective client ID (user-provided or platform default).
     /// </summary>
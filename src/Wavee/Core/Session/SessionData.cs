@@ -128,6 +128,23 @@ internal sealed class SessionData : IDisposable
         }
     }
 
+    /// <summary>
+    /// Gets the AP endpoint and the time the current transport was installed.
+    /// </summary>
+    public (string? ApUrl, DateTime ConnectedAtUtc) GetConnectionInfo()
+    {
+        if (_disposed) return (null, default);
+        _lock.EnterReadLock();
+        try
+        {
+            return (_apUrl, _connectedAt);
+        }
+        finally
+        {
+            _lock.ExitReadLock();
+        }
+    }
+
     /// <summary>
     /// Sets user data after successful authentication.
     /// </summary>
@@ -360,6 +377,10 @@ internal sealed class SessionData : IDisposable
                 catch { /* Best effort — transport disposal on sync Dispose path */ }
             }
             _transport = null;
+
+            // The reusable auth blob is owned by this session — wipe it.
+            _storedCredentials?.ZeroAuthData();
+            _storedCredentials = null;
         }
         finally
         {
@@ -0,0 +1,76 @@
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using Wavee.Connect.Connection;
+using Wavee.Core.Mercury;
+using Wavee.Core.Storage;
+
+namespace Wavee.Core.Session;
+
+/// <summary>
+/// Point-in-time snapshot of session internals, intended to be attached to bug reports
+/// for hangs and Connect desyncs. Contains no credentials or tokens.
+/// </summary>
+/// <param name="CapturedAtUtc">When the snapshot was taken (server-corrected clock).</param>
+/// <param name="Connection">AP connection state.</param>
+/// <param name="Dealer">Dealer websocket state, or null if Connect was not started.</param>
+/// <param name="PendingMercuryRequests">In-flight Mercury requests, oldest first.</param>
+/// <param name="PendingAudioKeyRequests">AudioKey requests awaiting a response.</param>
+/// <param name="Cache">Cache statistics, or null if no cache service was registered.</param>
+/// <param name="Bandwidth">Bytes sent and received per traffic category since the session was created.</param>
+public sealed record SessionDiagnostics(
+    DateTimeOffset CapturedAtUtc,
+    ConnectionDiagnostics Connection,
+    DealerDiagnostics? Dealer,
+    IReadOnlyList<MercuryPendingRequestInfo> PendingMercuryRequests,
+    int PendingAudioKeyRequests,
+    CacheStatistics? Cache,
+    BandwidthStatistics Bandwidth)
+{
+    /// <summary>
+    /// Serializes the snapshot as indented JSON (AOT-safe).
+    /// </summary>
+    public string ToJson() => JsonSerializer.Serialize(this, SessionDiagnosticsJsonContext.Default.SessionDiagnostics);
+}
+
+/// <summary>
+/// AP connection portion of <see cref="SessionDiagnostics"/>.
+/// </summary>
+/// <param name="State">Last published session connection state.</param>
+/// <param name="IsAuthenticated">Whether a transport and user data are present.</param>
+/// <param name="ApUrl">AP endpoint currently in use.</param>
+/// <param name="ConnectedAtUtc">When the current transport was installed.</param>
+/// <param name="LastApPacketUtc">When the last packet was received from the AP.</param>
+/// <param name="EncodeNonce">Shannon send nonce (packets sent on this transport).</param>
+/// <param name="DecodeNonce">Shannon receive nonce (packets received on this transport).</param>
+/// <param name="ClockOffsetMs">Local → server clock offset in milliseconds.</param>
+/// <param name="ClockIsCoarse">True if the clock offset only comes from coarse hints.</param>
+public sealed record ConnectionDiagnostics(
+    SessionConnectionState State,
+    bool IsAuthenticated,
+    string? ApUrl,
+    DateTime? ConnectedAtUtc,
+    DateTime LastApPacketUtc,
+    uint? EncodeNonce,
+    uint? DecodeNonce,
+    long ClockOffsetMs,
+    bool ClockIsCoarse);
+
+/// <summary>
+/// Dealer portion of <see cref="SessionDiagnostics"/>.
+/// </summary>
+/// <param name="State">Dealer websocket state.</param>
+/// <param name="ConnectionId">Current Spotify-Connection-Id, if any.</param>
+/// <param name="PendingRequests">Dealer REQUESTs still awaiting a reply.</param>
+/// <param name="Topics">Message counts per subscribed topic.</param>
+public sealed record DealerDiagnostics(
+    ConnectionState State,
+    string? ConnectionId,
+    int PendingRequests,
+    IReadOnlyDictionary<string, long> Topics);
+
+[JsonSerializable(typeof(SessionDiagnostics))]
+[JsonSourceGenerationOptions(
+    PropertyNamingPolicy = JsonKnownNamingPolicy.CamelCase,
+    WriteIndented = true,
+    UseStringEnumConverter = true)]
+internal sealed partial class SessionDiagnosticsJsonContext : JsonSerializerContext;
@@ -85,6 +85,13 @@ public sealed record UserData
     /// surfacing so we notice when it starts biting.
     /// </summary>
     public bool IsClientDeprecated { get; init; }
+
+    /// <summary>
+    /// Every ProductInfo attribute as sent (<c>ads</c>, <c>on-demand</c>, <c>catalogue</c>, ...).
+    /// Read by <c>ProductRestrictions</c> for free-tier playback rules.
+    /// Will be null until the ProductInfo packet (0x50) is received.
+    /// </summary>
+    public IReadOnlyDictionary<string, string>? ProductAttributes { get; init; }
 }
 
 /// <summary>
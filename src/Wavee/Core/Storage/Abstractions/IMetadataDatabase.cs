@@ -601,6 +601,96 @@ public interface IMetadataDatabase : IAsyncDisposable
     /// </summary>
     Task FailOutboxAsync(long id, string? error, CancellationToken ct = default);
 
+    /// <summary>
+    /// Counts the outbox operations still waiting to sync.
+    /// </summary>
+    Task<int> CountOutboxAsync(CancellationToken ct = default);
+
+    #endregion
+
+    #region Offline Track Operations
+
+    /// <summary>
+    /// Records (or replaces) the offline-store entry for a downloaded track.
+    /// </summary>
+    Task UpsertOfflineTrackAsync(OfflineTrackEntry entry, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets the offline-store entry for a track, or null if it was never downloaded.
+    /// </summary>
+    Task<OfflineTrackEntry?> GetOfflineTrackAsync(string trackUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets every offline-store entry, oldest download first.
+    /// </summary>
+    Task<List<OfflineTrackEntry>> GetOfflineTracksAsync(CancellationToken ct = default);
+
+    /// <summary>
+    /// Removes the offline-store entry for a track. Files on disk are left to the caller.
+    /// </summary>
+    Task DeleteOfflineTrackAsync(string trackUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Stamps an offline track as just played. No-op for tracks that aren't in the store.
+    /// </summary>
+    Task TouchOfflineTrackAsync(string trackUri, long playedAt, CancellationToken ct = default);
+
+    /// <summary>
+    /// Pins a playlist/album to the offline store, replacing the tracks recorded for it.
+    /// Keeps the original pin time when the context was already pinned.
+    /// </summary>
+    Task SetOfflinePinAsync(string contextUri, IReadOnlyList<string> trackUris, CancellationToken ct = default);
+
+    /// <summary>
+    /// Unpins a playlist/album. Its tracks stay downloaded but become evictable.
+    /// </summary>
+    Task DeleteOfflinePinAsync(string contextUri, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets every pinned context with the tracks recorded for it.
+    /// </summary>
+    Task<List<OfflinePinEntry>> GetOfflinePinsAsync(CancellationToken ct = default);
+
+    #endregion
+
+    #region Listening Stats Operations
+
+    // Listening stats are kept per track per UTC hour. Range queries take the
+    // hours that start in [from, to), so pass hour-aligned bounds (a local
+    // midnight is one for whole-hour time zones).
+
+    /// <summary>
+    /// Adds one listen to the stats bucket of the hour it started in.
+    /// </summary>
+    Task RecordListeningAsync(ListeningEntry entry, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets the totals over a time range.
+    /// </summary>
+    Task<ListeningSummary> GetListeningSummaryAsync(DateTimeOffset from, DateTimeOffset to, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets the most listened artists in a time range, by time listened.
+    /// </summary>
+    Task<List<ListeningArtistStat>> GetTopListenedArtistsAsync(
+        DateTimeOffset from, DateTimeOffset to, int limit = 10, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets the most played tracks in a time range, by counted plays then time listened.
+    /// </summary>
+    Task<List<ListeningTrackStat>> GetTopListenedTracksAsync(
+        DateTimeOffset from, DateTimeOffset to, int limit = 10, CancellationToken ct = default);
+
+    /// <summary>
+    /// Gets the hours in a time range that had any listening, oldest first.
+    /// </summary>
+    Task<List<ListeningHourStat>> GetListeningByHourAsync(DateTimeOffset from, DateTimeOffset to, CancellationToken ct = default);
+
+    /// <summary>
+    /// Deletes the stats for hours that started before <paramref name="before"/>.
+    /// </summary>
+    Task DeleteListeningStatsAsync(DateTimeOffset before, CancellationToken ct = default);
+
     #endregion
 }
 
@@ -696,6 +786,87 @@ public sealed record OutboxEntry
     public string? LastError { get; init; }
 }
 
+/// <summary>
+/// A track in the offline store. The encrypted audio lives in the AudioHost disk
+/// cache under <see cref="FileId"/>; <see cref="ArtifactPath"/> is set when a
+/// post-download transform produced an extra file (e.g. an Opus transcode).
+/// </summary>
+public sealed record OfflineTrackEntry
+{
+    public required string TrackUri { get; init; }
+    /// <summary>Spotify file id (40-char hex) of the stored audio file.</summary>
+    public required string FileId { get; init; }
+    public required string Codec { get; init; }
+    public int BitrateKbps { get; init; }
+    /// <summary>Size of the stored encrypted file in bytes.</summary>
+    public long SizeBytes { get; init; }
+    /// <summary>Name of the transform that produced <see cref="ArtifactPath"/>, or null when none ran.</summary>
+    public string? Transform { get; init; }
+    public string? ArtifactPath { get; init; }
+    public long? ArtifactSizeBytes { get; init; }
+    /// <summary>Lower-case hex SHA-256 of the artifact, recorded when it was written.</summary>
+    public string? ArtifactSha256 { get; init; }
+    /// <summary>Unix seconds.</summary>
+    public long DownloadedAt { get; init; }
+    /// <summary>Unix seconds of the last play from the store, or null if never played.</summary>
+    public long? LastPlayedAt { get; init; }
+}
+
+/// <summary>
+/// A playlist or album pinned to the offline store. Pinned tracks are never evicted by the quota.
+/// </summary>
+public sealed record OfflinePinEntry
+{
+    public required string ContextUri { get; init; }
+    /// <summary>Unix seconds.</summary>
+    public long PinnedAt { get; init; }
+    public required IReadOnlyList<string> TrackUris { get; init; }
+}
+
+/// <summary>
+/// One listen to fold into the listening stats.
+/// </summary>
+public sealed record ListeningEntry
+{
+    public required string TrackUri { get; init; }
+    public string? Title { get; init; }
+    /// <summary>Name of the first artist.</summary>
+    public string? ArtistName { get; init; }
+    public string? ArtistUri { get; init; }
+    /// <summary>When the track started; picks the hour bucket.</summary>
+    public DateTimeOffset StartedAt { get; init; }
+    /// <summary>Time the track was audible.</summary>
+    public long MsPlayed { get; init; }
+    /// <summary>True when the listen counts towards play counts; otherwise only its time is added.</summary>
+    public bool CountsAsPlay { get; init; }
+}
+
+/// <summary>
+/// Listening totals over a time range.
+/// </summary>
+public sealed record ListeningSummary(long MsPlayed, int PlayCount, int TrackCount, int ArtistCount);
+
+/// <summary>
+/// Listening for one artist over a time range. Either the URI or the name may be null.
+/// </summary>
+public sealed record ListeningArtistStat(string? ArtistUri, string? ArtistName, long MsPlayed, int PlayCount);
+
+/// <summary>
+/// Listening for one track over a time range.
+/// </summary>
+public sealed record ListeningTrackStat(
+    string TrackUri,
+    string? Title,
+    string? ArtistName,
+    string? ArtistUri,
+    long MsPlayed,
+    int PlayCount);
+
+/// <summary>
+/// Listening in one UTC hour.
+/// </summary>
+public sealed record ListeningHourStat(DateTimeOffset HourStart, long MsPlayed, int PlayCount);
+
 /// <summary>
 /// Type of item in the Spotify library.
 /// </summary>
@@ -710,7 +881,13 @@ public enum SpotifyLibraryItemType
     ArtistBan = 7,
     ListenLater = 8,
     YlPin = 9,
-    Enhanced = 10
+    Enhanced = 10,
+
+    /// <summary>
+    /// Followed user. Only reported through saved-state events — user follows live on
+    /// the Web API and are never stored in the library table.
+    /// </summary>
+    User = 11
 }
 
 /// <summary>
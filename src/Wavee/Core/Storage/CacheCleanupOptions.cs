@@ -15,4 +15,10 @@ public sealed class CacheCleanupOptions
     /// Default: 30 minutes.
     /// </summary>
     public TimeSpan DefaultMaxAge { get; set; } = TimeSpan.FromMinutes(30);
+
+    /// <summary>
+    /// Maximum age used by <see cref="CacheCleanupService.ShrinkAsync"/> when the app
+    /// goes idle. Default: 2 minutes.
+    /// </summary>
+    public TimeSpan IdleMaxAge { get; set; } = TimeSpan.FromMinutes(2);
 }
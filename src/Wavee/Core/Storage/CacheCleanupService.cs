@@ -67,7 +67,17 @@ public sealed class CacheCleanupService : IAsyncDisposable
     /// Runs a single cleanup pass across all registered caches.
     /// Can be called manually for testing or on-demand cleanup.
     /// </summary>
-    public async Task RunCleanupPassAsync(CancellationToken ct = default)
+    public Task RunCleanupPassAsync(CancellationToken ct = default)
+        => RunCleanupPassAsync(_options.DefaultMaxAge, ct);
+
+    /// <summary>
+    /// Runs a cleanup pass with <see cref="CacheCleanupOptions.IdleMaxAge"/>, evicting
+    /// everything not touched in the last few minutes. Call when playback goes idle.
+    /// </summary>
+    public Task ShrinkAsync(CancellationToken ct = default)
+        => RunCleanupPassAsync(_options.IdleMaxAge, ct);
+
+    private async Task RunCleanupPassAsync(TimeSpan maxAge, CancellationToken ct)
     {
         var totalRemoved = 0;
 
@@ -78,7 +88,7 @@ public sealed class CacheCleanupService : IAsyncDisposable
                 ct.ThrowIfCancellationRequested();
 
                 var countBefore = cache.CurrentCount;
-                var removed = await cache.CleanupStaleEntriesAsync(_options.DefaultMaxAge, ct);
+                var removed = await cache.CleanupStaleEntriesAsync(maxAge, ct);
                 totalRemoved += removed;
 
                 if (removed > 0)
@@ -0,0 +1,183 @@
+using System.Diagnostics;
+using System.Globalization;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Core.Storage;
+
+/// <summary>
+/// Advisory, cross-process lock on a cache directory. Held for the lifetime of the process
+/// that owns the metadata database so a second Wavee instance pointed at the same directory
+/// fails fast (or waits) instead of writing to the index concurrently.
+/// </summary>
+/// <remarks>
+/// The lock is an exclusive handle on <c>{dir}/.wavee.lock</c> (<c>LockFileEx</c> on Windows,
+/// <c>flock</c> on Unix), so the OS drops it when the process dies — there is never a stale
+/// lock to clean up. The lock file itself is left in place; deleting it would let a waiter
+/// lock an unlinked inode. Holder details go to a separate <c>.wavee.lock.owner</c> file
+/// because the locked file can't be read by anyone else.
+/// </remarks>
+public sealed class CacheDirectoryLock : IDisposable
+{
+    internal const string LockFileName = ".wavee.lock";
+    internal const string OwnerFileName = ".wavee.lock.owner";
+
+    private static readonly TimeSpan PollInterval = TimeSpan.FromMilliseconds(250);
+
+    private FileStream? _handle;
+
+    private CacheDirectoryLock(string directory, FileStream handle)
+    {
+        Directory = directory;
+        _handle = handle;
+    }
+
+    /// <summary>
+    /// The locked directory.
+    /// </summary>
+    public string Directory { get; }
+
+    /// <summary>
+    /// Takes the lock, waiting up to <paramref name="wait"/> for another process to release it.
+    /// </summary>
+    /// <param name="directory">Cache directory to lock; created if missing.</param>
+    /// <param name="wait">How long to wait for the holder. Null or zero fails immediately.</param>
+    /// <param name="logger">Optional logger.</param>
+    /// <exception cref="CacheLockedException">Another process still holds the lock.</exception>
+    public static CacheDirectoryLock Acquire(string directory, TimeSpan? wait = null, ILogger? logger = null)
+        => AcquireAsync(directory, wait, logger).GetAwaiter().GetResult();
+
+    /// <summary>
+    /// Takes the lock, waiting up to <paramref name="wait"/> for another process to release it.
+    /// </summary>
+    /// <param name="directory">Cache directory to lock; created if missing.</param>
+    /// <param name="wait">How long to wait for the holder. Null or zero fails immediately.</param>
+    /// <param name="logger">Optional logger.</param>
+    /// <param name="cancellationToken">Cancels the wait.</param>
+    /// <exception cref="CacheLockedException">Another process still holds the lock.</exception>
+    public static async Task<CacheDirectoryLock> AcquireAsync(
+        string directory,
+        TimeSpan? wait = null,
+        ILogger? logger = null,
+        CancellationToken cancellationToken = default)
+    {
+        directory = Path.GetFullPath(directory);
+        System.IO.Directory.CreateDirectory(directory);
+        var lockPath = Path.Combine(directory, LockFileName);
+        var deadline = Stopwatch.StartNew();
+        var loggedWait = false;
+
+        while (true)
+        {
+            var handle = TryOpen(lockPath, out var error);
+            if (handle != null)
+            {
+                WriteOwner(directory);
+                if (loggedWait)
+                    logger?.LogInformation("Acquired cache lock on {Directory} after {Elapsed}", directory, deadline.Elapsed);
+                return new CacheDirectoryLock(directory, handle);
+            }
+
+            var owner = ReadOwner(directory);
+            if (wait is not { } timeout || deadline.Elapsed >= timeout)
+            {
+                if (loggedWait)
+                {
+                    logger?.LogError(
+                        "Gave up on the cache lock on {Directory} after {Timeout}; still held by {ProcessName} {ProcessId}",
+                        directory, timeout, owner.ProcessName, owner.ProcessId);
+                }
+
+                throw new CacheLockedException(
+                    $"Cache directory '{directory}' is in use by another Wavee process" +
+                    (owner.ProcessId is { } pid ? $" ({owner.ProcessName ?? "pid"} {pid})" : string.Empty) +
+                    (loggedWait ? $"; gave up after waiting {timeout}." : "."),
+                    directory,
+                    owner.ProcessId,
+                    error);
+            }
+
+            if (!loggedWait)
+            {
+                logger?.LogWarning(
+                    "Cache directory {Directory} is locked by process {ProcessId}; waiting up to {Timeout}",
+                    directory, owner.ProcessId, timeout);
+                loggedWait = true;
+            }
+
+            var remaining = timeout - deadline.Elapsed;
+            await Task.Delay(remaining < PollInterval ? remaining : PollInterval, cancellationToken).ConfigureAwait(false);
+        }
+    }
+
+    /// <summary>
+    /// Releases the lock. Safe to call more than once.
+    /// </summary>
+    public void Dispose()
+    {
+        var handle = Interlocked.Exchange(ref _handle, null);
+        if (handle == null)
+            return;
+
+        try
+        {
+            File.Delete(Path.Combine(Directory, OwnerFileName));
+        }
+        catch (IOException)
+        {
+        }
+        catch (UnauthorizedAccessException)
+        {
+        }
+
+        handle.Dispose();
+    }
+
+    private static FileStream? TryOpen(string lockPath, out IOException? error)
+    {
+        try
+        {
+            error = null;
+            return new FileStream(lockPath, FileMode.OpenOrCreate, FileAccess.ReadWrite, FileShare.None, 1);
+        }
+        catch (IOException ex)
+        {
+            error = ex;
+            return null;
+        }
+    }
+
+    private static void WriteOwner(string directory)
+    {
+        try
+        {
+            using var process = Process.GetCurrentProcess();
+            File.WriteAllText(Path.Combine(directory, OwnerFileName),
+                $"{Environment.ProcessId.ToString(CultureInfo.InvariantCulture)} {process.ProcessName}");
+        }
+        catch (IOException)
+        {
+        }
+        catch (UnauthorizedAccessException)
+        {
+        }
+    }
+
+    private static (int? ProcessId, string? ProcessName) ReadOwner(string directory)
+    {
+        try
+        {
+            var parts = File.ReadAllText(Path.Combine(directory, OwnerFileName)).Trim().Split(' ', 2);
+            return int.TryParse(parts[0], NumberStyles.None, CultureInfo.InvariantCulture, out var pid)
+                ? (pid, parts.Length > 1 ? parts[1] : null)
+                : (null, null);
+        }
+        catch (IOException)
+        {
+            return (null, null);
+        }
+        catch (UnauthorizedAccessException)
+        {
+            return (null, null);
+        }
+    }
+}
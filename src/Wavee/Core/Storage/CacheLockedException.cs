@@ -0,0 +1,28 @@
+using System;
+
+namespace Wavee.Core.Storage;
+
+/// <summary>
+/// Thrown by <see cref="CacheDirectoryLock"/> when another process holds the cache
+/// directory. Hosts catch this at startup and tell the user which instance to close,
+/// or set <see cref="DependencyInjection.WaveeCacheOptions.CacheLockTimeout"/> to wait.
+/// </summary>
+public sealed class CacheLockedException : IOException
+{
+    /// <summary>The contested cache directory.</summary>
+    public string Directory { get; }
+
+    /// <summary>Process id of the holder, when it could be read; may be stale.</summary>
+    public int? HolderProcessId { get; }
+
+    public CacheLockedException(
+        string message,
+        string directory,
+        int? holderProcessId,
+        Exception? innerException = null)
+        : base(message, innerException)
+    {
+        Directory = directory;
+        HolderProcessId = holderProcessId;
+    }
+}
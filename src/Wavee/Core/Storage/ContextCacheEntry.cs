@@ -74,6 +74,13 @@ public sealed record ContextCacheEntry : ICacheEntry
     /// </summary>
     public int PageCount { get; init; } = 1;
 
+    /// <summary>
+    /// Context revision this entry was resolved at (base64 playlist revision), or
+    /// null when the revision was unknown. A hit is only served while it matches
+    /// the latest revision the resolver has seen for <see cref="Uri"/>.
+    /// </summary>
+    public string? Revision { get; init; }
+
     /// <summary>
     /// Gets whether the cached context is still valid.
     /// </summary>
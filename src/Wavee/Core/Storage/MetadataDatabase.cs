@@ -88,7 +88,13 @@ public sealed class MetadataDatabase : IMetadataDatabase
     //      (resume cursor for chunked ops). Library save/remove + playlist
     //      add-tracks both queue through the same table now, processed by
     //      Wavee.Core.Storage.Outbox.OutboxProcessor.
-    private const int CurrentSchemaVersion = 22;
+    // v23: Added offline_tracks — one row per downloaded track with the stored
+    //      file/bitrate and the optional post-download artifact + its SHA-256.
+    // v24: offline_tracks.last_played_at for LRU eviction; offline_pins +
+    //      offline_pin_tracks record pinned playlists/albums and their tracks.
+    // v25: listening_stats — listened time and play counts per track per hour,
+    //      for local "your week in music" summaries.
+    private const int CurrentSchemaVersion = 25;
 
     /// <summary>
     /// Creates a new MetadataDatabase.
@@ -304,6 +310,66 @@ public sealed class MetadataDatabase : IMetadataDatabase
                     last_error      TEXT    NULL
                 );
                 CREATE INDEX IF NOT EXISTS idx_outbox_created ON outbox(created_at);
+                """),
+
+        // v23: Offline store index. The audio itself stays in the AudioHost
+        // disk cache (keyed by file id); this table records which file was
+        // downloaded for a track and the optional transformed artifact.
+        new SchemaMigration(
+            FromVersion: 22,
+            ToVersion: 23,
+            Sql: """
+                CREATE TABLE IF NOT EXISTS offline_tracks (
+                    track_uri       TEXT PRIMARY KEY NOT NULL,
+                    file_id         TEXT    NOT NULL,
+                    codec           TEXT    NOT NULL,
+                    bitrate_kbps    INTEGER NOT NULL,
+                    size_bytes      INTEGER NOT NULL,
+                    transform       TEXT    NULL,
+                    artifact_path   TEXT    NULL,
+                    artifact_size   INTEGER NULL,
+                    artifact_sha256 TEXT    NULL,
+                    downloaded_at   INTEGER NOT NULL
+                );
+                """),
+
+        // v24: Quota management for the offline store. last_played_at drives
+        // LRU eviction; pinned contexts (and the tracks they held when pinned)
+        // are never evicted.
+        new SchemaMigration(
+            FromVersion: 23,
+            ToVersion: 24,
+            Sql: """
+                ALTER TABLE offline_tracks ADD COLUMN last_played_at INTEGER NULL;
+                CREATE TABLE IF NOT EXISTS offline_pins (
+                    context_uri TEXT PRIMARY KEY NOT NULL,
+                    pinned_at   INTEGER NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS offline_pin_tracks (
+                    context_uri TEXT NOT NULL,
+                    track_uri   TEXT NOT NULL,
+                    PRIMARY KEY (context_uri, track_uri)
+                );
+                CREATE INDEX IF NOT EXISTS idx_offline_pin_tracks_track ON offline_pin_tracks(track_uri);
+                """),
+
+        // v25: Hourly listening aggregates. Rows are folded in as tracks end
+        // and never expire with the metadata cache.
+        new SchemaMigration(
+            FromVersion: 24,
+            ToVersion: 25,
+            Sql: """
+                CREATE TABLE IF NOT EXISTS listening_stats (
+                    hour_start  INTEGER NOT NULL,
+                    track_uri   TEXT    NOT NULL,
+                    title       TEXT    NULL,
+                    artist_name TEXT    NULL,
+                    artist_uri  TEXT    NULL,
+                    play_count  INTEGER NOT NULL DEFAULT 0,
+                    ms_played   INTEGER NOT NULL DEFAULT 0,
+                    PRIMARY KEY (hour_start, track_uri)
+                );
+                CREATE INDEX IF NOT EXISTS idx_listening_stats_artist ON listening_stats(artist_uri);
                 """)
     ];
 
@@ -1126,6 +1192,61 @@ public sealed class MetadataDatabase : IMetadataDatabase
                 cmd.ExecuteNonQuery();
             }
 
+            // Offline store index — which audio file was downloaded for a track
+            // (at the download quality, not the streaming one) plus the optional
+            // post-download artifact and its SHA-256 for integrity checks.
+            using (var cmd = connection.CreateCommand())
+            {
+                cmd.Transaction = transaction;
+                cmd.CommandText = """
+                    CREATE TABLE IF NOT EXISTS offline_tracks (
+                        track_uri       TEXT PRIMARY KEY NOT NULL,
+                        file_id         TEXT    NOT NULL,
+                        codec           TEXT    NOT NULL,
+                        bitrate_kbps    INTEGER NOT NULL,
+                        size_bytes      INTEGER NOT NULL,
+                        transform       TEXT    NULL,
+                        artifact_path   TEXT    NULL,
+                        artifact_size   INTEGER NULL,
+                        artifact_sha256 TEXT    NULL,
+                        downloaded_at   INTEGER NOT NULL,
+                        last_played_at  INTEGER NULL
+                    );
+                    CREATE TABLE IF NOT EXISTS offline_pins (
+                        context_uri TEXT PRIMARY KEY NOT NULL,
+                        pinned_at   INTEGER NOT NULL
+                    );
+                    CREATE TABLE IF NOT EXISTS offline_pin_tracks (
+                        context_uri TEXT NOT NULL,
+                        track_uri   TEXT NOT NULL,
+                        PRIMARY KEY (context_uri, track_uri)
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_offline_pin_tracks_track ON offline_pin_tracks(track_uri);
+                    """;
+                cmd.ExecuteNonQuery();
+            }
+
+            // Listening stats — listened time and counted plays per track, bucketed
+            // by the UTC hour the track started in.
+            using (var cmd = connection.CreateCommand())
+            {
+                cmd.Transaction = transaction;
+                cmd.CommandText = """
+                    CREATE TABLE IF NOT EXISTS listening_stats (
+                        hour_start  INTEGER NOT NULL,
+                        track_uri   TEXT    NOT NULL,
+                        title       TEXT    NULL,
+                        artist_name TEXT    NULL,
+                        artist_uri  TEXT    NULL,
+                        play_count  INTEGER NOT NULL DEFAULT 0,
+                        ms_played   INTEGER NOT NULL DEFAULT 0,
+                        PRIMARY KEY (hour_start, track_uri)
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_listening_stats_artist ON listening_stats(artist_uri);
+                    """;
+                cmd.ExecuteNonQuery();
+            }
+
             // Indexes for common query patterns
             using (var cmd = connection.CreateCommand())
             {
@@ -2998,6 +3119,222 @@ public sealed class MetadataDatabase : IMetadataDatabase
         finally { _writeLock.Release(); }
     }
 
+    public async Task<int> CountOutboxAsync(CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = "SELECT COUNT(*) FROM outbox;";
+        return Convert.ToInt32(await cmd.ExecuteScalarAsync(ct));
+    }
+
+    #endregion
+
+    #region Offline Track Operations
+
+    private const string OfflineTrackColumns =
+        "track_uri, file_id, codec, bitrate_kbps, size_bytes, transform, artifact_path, artifact_size, artifact_sha256, downloaded_at, last_played_at";
+
+    public async Task UpsertOfflineTrackAsync(OfflineTrackEntry entry, CancellationToken ct = default)
+    {
+        ArgumentNullException.ThrowIfNull(entry);
+
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = $"""
+                INSERT OR REPLACE INTO offline_tracks ({OfflineTrackColumns})
+                VALUES ($uri, $file, $codec, $bitrate, $size, $transform, $path, $artifactSize, $sha, $downloaded, $played);
+                """;
+            cmd.Parameters.AddWithValue("$uri", entry.TrackUri);
+            cmd.Parameters.AddWithValue("$file", entry.FileId);
+            cmd.Parameters.AddWithValue("$codec", entry.Codec);
+            cmd.Parameters.AddWithValue("$bitrate", entry.BitrateKbps);
+            cmd.Parameters.AddWithValue("$size", entry.SizeBytes);
+            cmd.Parameters.AddWithValue("$transform", (object?)entry.Transform ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$path", (object?)entry.ArtifactPath ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$artifactSize", (object?)entry.ArtifactSizeBytes ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$sha", (object?)entry.ArtifactSha256 ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$downloaded", entry.DownloadedAt);
+            cmd.Parameters.AddWithValue("$played", (object?)entry.LastPlayedAt ?? DBNull.Value);
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    public async Task<OfflineTrackEntry?> GetOfflineTrackAsync(string trackUri, CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = $"SELECT {OfflineTrackColumns} FROM offline_tracks WHERE track_uri = $uri;";
+        cmd.Parameters.AddWithValue("$uri", trackUri);
+
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        return await reader.ReadAsync(ct) ? ReadOfflineTrack(reader) : null;
+    }
+
+    public async Task<List<OfflineTrackEntry>> GetOfflineTracksAsync(CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = $"SELECT {OfflineTrackColumns} FROM offline_tracks ORDER BY downloaded_at;";
+
+        var results = new List<OfflineTrackEntry>();
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        while (await reader.ReadAsync(ct))
+            results.Add(ReadOfflineTrack(reader));
+        return results;
+    }
+
+    public async Task DeleteOfflineTrackAsync(string trackUri, CancellationToken ct = default)
+    {
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = "DELETE FROM offline_tracks WHERE track_uri = $uri;";
+            cmd.Parameters.AddWithValue("$uri", trackUri);
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    private static OfflineTrackEntry ReadOfflineTrack(SqliteDataReader reader) => new()
+    {
+        TrackUri = reader.GetString(0),
+        FileId = reader.GetString(1),
+        Codec = reader.GetString(2),
+        BitrateKbps = reader.GetInt32(3),
+        SizeBytes = reader.GetInt64(4),
+        Transform = reader.IsDBNull(5) ? null : reader.GetString(5),
+        ArtifactPath = reader.IsDBNull(6) ? null : reader.GetString(6),
+        ArtifactSizeBytes = reader.IsDBNull(7) ? null : reader.GetInt64(7),
+        ArtifactSha256 = reader.IsDBNull(8) ? null : reader.GetString(8),
+        DownloadedAt = reader.GetInt64(9),
+        LastPlayedAt = reader.IsDBNull(10) ? null : reader.GetInt64(10)
+    };
+
+    public async Task TouchOfflineTrackAsync(string trackUri, long playedAt, CancellationToken ct = default)
+    {
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = "UPDATE offline_tracks SET last_played_at = $played WHERE track_uri = $uri;";
+            cmd.Parameters.AddWithValue("$uri", trackUri);
+            cmd.Parameters.AddWithValue("$played", playedAt);
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    public async Task SetOfflinePinAsync(string contextUri, IReadOnlyList<string> trackUris, CancellationToken ct = default)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(contextUri);
+        ArgumentNullException.ThrowIfNull(trackUris);
+
+        var now = DateTimeOffset.UtcNow.ToUnixTimeSeconds();
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var tx = (SqliteTransaction)await connection.BeginTransactionAsync(ct);
+
+            using (var cmd = connection.CreateCommand())
+            {
+                cmd.Transaction = tx;
+                cmd.CommandText = """
+                    INSERT INTO offline_pins (context_uri, pinned_at) VALUES ($context, $now)
+                    ON CONFLICT(context_uri) DO NOTHING;
+                    DELETE FROM offline_pin_tracks WHERE context_uri = $context;
+                    """;
+                cmd.Parameters.AddWithValue("$context", contextUri);
+                cmd.Parameters.AddWithValue("$now", now);
+                await cmd.ExecuteNonQueryAsync(ct);
+            }
+
+            using (var cmd = connection.CreateCommand())
+            {
+                cmd.Transaction = tx;
+                cmd.CommandText = "INSERT OR IGNORE INTO offline_pin_tracks (context_uri, track_uri) VALUES ($context, $track);";
+                cmd.Parameters.AddWithValue("$context", contextUri);
+                var pTrack = cmd.Parameters.Add("$track", SqliteType.Text);
+                foreach (var trackUri in trackUris)
+                {
+                    pTrack.Value = trackUri;
+                    await cmd.ExecuteNonQueryAsync(ct);
+                }
+            }
+
+            await tx.CommitAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    public async Task DeleteOfflinePinAsync(string contextUri, CancellationToken ct = default)
+    {
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = """
+                DELETE FROM offline_pin_tracks WHERE context_uri = $context;
+                DELETE FROM offline_pins WHERE context_uri = $context;
+                """;
+            cmd.Parameters.AddWithValue("$context", contextUri);
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    public async Task<List<OfflinePinEntry>> GetOfflinePinsAsync(CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = """
+            SELECT p.context_uri, p.pinned_at, t.track_uri
+            FROM offline_pins p
+            LEFT JOIN offline_pin_tracks t ON t.context_uri = p.context_uri
+            ORDER BY p.pinned_at, p.context_uri;
+            """;
+
+        var pins = new List<OfflinePinEntry>();
+        var tracks = new Dictionary<string, List<string>>(StringComparer.Ordinal);
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        while (await reader.ReadAsync(ct))
+        {
+            var contextUri = reader.GetString(0);
+            if (!tracks.TryGetValue(contextUri, out var list))
+            {
+                list = [];
+                tracks[contextUri] = list;
+                pins.Add(new OfflinePinEntry
+                {
+                    ContextUri = contextUri,
+                    PinnedAt = reader.GetInt64(1),
+                    TrackUris = list
+                });
+            }
+
+            if (!reader.IsDBNull(2))
+                list.Add(reader.GetString(2));
+        }
+        return pins;
+    }
+
     #endregion
 
     #region Sync State Operations
@@ -3235,6 +3572,181 @@ public sealed class MetadataDatabase : IMetadataDatabase
 
     #endregion
 
+    #region Listening Stats Operations
+
+    /// <summary>
+    /// Folds one listen into its track's bucket for the UTC hour it started in.
+    /// </summary>
+    public async Task RecordListeningAsync(ListeningEntry entry, CancellationToken ct = default)
+    {
+        ArgumentNullException.ThrowIfNull(entry);
+
+        var hourStart = entry.StartedAt.ToUnixTimeSeconds() / 3600 * 3600;
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = """
+                INSERT INTO listening_stats (hour_start, track_uri, title, artist_name, artist_uri, play_count, ms_played)
+                VALUES ($hour, $uri, $title, $artist, $artistUri, $plays, $ms)
+                ON CONFLICT(hour_start, track_uri) DO UPDATE SET
+                    title       = COALESCE(excluded.title, title),
+                    artist_name = COALESCE(excluded.artist_name, artist_name),
+                    artist_uri  = COALESCE(excluded.artist_uri, artist_uri),
+                    play_count  = play_count + excluded.play_count,
+                    ms_played   = ms_played + excluded.ms_played;
+                """;
+            cmd.Parameters.AddWithValue("$hour", hourStart);
+            cmd.Parameters.AddWithValue("$uri", entry.TrackUri);
+            cmd.Parameters.AddWithValue("$title", (object?)entry.Title ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$artist", (object?)entry.ArtistName ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$artistUri", (object?)entry.ArtistUri ?? DBNull.Value);
+            cmd.Parameters.AddWithValue("$plays", entry.CountsAsPlay ? 1 : 0);
+            cmd.Parameters.AddWithValue("$ms", entry.MsPlayed);
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    public async Task<ListeningSummary> GetListeningSummaryAsync(
+        DateTimeOffset from, DateTimeOffset to, CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = """
+            SELECT COALESCE(SUM(ms_played), 0),
+                   COALESCE(SUM(play_count), 0),
+                   COUNT(DISTINCT track_uri),
+                   COUNT(DISTINCT COALESCE(artist_uri, artist_name))
+            FROM listening_stats
+            WHERE hour_start >= $from AND hour_start < $to;
+            """;
+        AddListeningRange(cmd, from, to);
+
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        await reader.ReadAsync(ct);
+        return new ListeningSummary(
+            reader.GetInt64(0), reader.GetInt32(1), reader.GetInt32(2), reader.GetInt32(3));
+    }
+
+    public async Task<List<ListeningArtistStat>> GetTopListenedArtistsAsync(
+        DateTimeOffset from, DateTimeOffset to, int limit = 10, CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        // Grouped by URI where known so a renamed artist stays one row; MAX picks
+        // any one stored name for it.
+        cmd.CommandText = """
+            SELECT MAX(artist_uri), MAX(artist_name), SUM(ms_played), SUM(play_count)
+            FROM listening_stats
+            WHERE hour_start >= $from AND hour_start < $to
+              AND (artist_uri IS NOT NULL OR artist_name IS NOT NULL)
+            GROUP BY COALESCE(artist_uri, artist_name)
+            ORDER BY SUM(ms_played) DESC
+            LIMIT $limit;
+            """;
+        AddListeningRange(cmd, from, to);
+        cmd.Parameters.AddWithValue("$limit", limit);
+
+        var results = new List<ListeningArtistStat>();
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        while (await reader.ReadAsync(ct))
+        {
+            results.Add(new ListeningArtistStat(
+                reader.IsDBNull(0) ? null : reader.GetString(0),
+                reader.IsDBNull(1) ? null : reader.GetString(1),
+                reader.GetInt64(2),
+                reader.GetInt32(3)));
+        }
+        return results;
+    }
+
+    public async Task<List<ListeningTrackStat>> GetTopListenedTracksAsync(
+        DateTimeOffset from, DateTimeOffset to, int limit = 10, CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = """
+            SELECT track_uri, MAX(title), MAX(artist_name), MAX(artist_uri), SUM(ms_played), SUM(play_count)
+            FROM listening_stats
+            WHERE hour_start >= $from AND hour_start < $to
+            GROUP BY track_uri
+            ORDER BY SUM(play_count) DESC, SUM(ms_played) DESC
+            LIMIT $limit;
+            """;
+        AddListeningRange(cmd, from, to);
+        cmd.Parameters.AddWithValue("$limit", limit);
+
+        var results = new List<ListeningTrackStat>();
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        while (await reader.ReadAsync(ct))
+        {
+            results.Add(new ListeningTrackStat(
+                reader.GetString(0),
+                reader.IsDBNull(1) ? null : reader.GetString(1),
+                reader.IsDBNull(2) ? null : reader.GetString(2),
+                reader.IsDBNull(3) ? null : reader.GetString(3),
+                reader.GetInt64(4),
+                reader.GetInt32(5)));
+        }
+        return results;
+    }
+
+    public async Task<List<ListeningHourStat>> GetListeningByHourAsync(
+        DateTimeOffset from, DateTimeOffset to, CancellationToken ct = default)
+    {
+        using var connection = CreateConnection();
+        await connection.OpenAsync(ct);
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = """
+            SELECT hour_start, SUM(ms_played), SUM(play_count)
+            FROM listening_stats
+            WHERE hour_start >= $from AND hour_start < $to
+            GROUP BY hour_start
+            ORDER BY hour_start;
+            """;
+        AddListeningRange(cmd, from, to);
+
+        var results = new List<ListeningHourStat>();
+        using var reader = await cmd.ExecuteReaderAsync(ct);
+        while (await reader.ReadAsync(ct))
+        {
+            results.Add(new ListeningHourStat(
+                DateTimeOffset.FromUnixTimeSeconds(reader.GetInt64(0)),
+                reader.GetInt64(1),
+                reader.GetInt32(2)));
+        }
+        return results;
+    }
+
+    public async Task DeleteListeningStatsAsync(DateTimeOffset before, CancellationToken ct = default)
+    {
+        await _writeLock.WaitAsync(ct);
+        try
+        {
+            using var connection = CreateConnection();
+            await connection.OpenAsync(ct);
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = "DELETE FROM listening_stats WHERE hour_start < $before;";
+            cmd.Parameters.AddWithValue("$before", before.ToUnixTimeSeconds());
+            await cmd.ExecuteNonQueryAsync(ct);
+        }
+        finally { _writeLock.Release(); }
+    }
+
+    private static void AddListeningRange(SqliteCommand cmd, DateTimeOffset from, DateTimeOffset to)
+    {
+        cmd.Parameters.AddWithValue("$from", from.ToUnixTimeSeconds());
+        cmd.Parameters.AddWithValue("$to", to.ToUnixTimeSeconds());
+    }
+
+    #endregion
+
     #region Spotify Playlist Operations
 
     /// <summary>
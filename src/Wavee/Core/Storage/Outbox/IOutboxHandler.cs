@@ -27,4 +27,12 @@ public interface IOutboxHandler
     /// retries can resume rather than replay.
     /// </summary>
     Task ProcessAsync(OutboxEntry entry, CancellationToken ct);
+
+    /// <summary>
+    /// Settle an entry whose <see cref="ProcessAsync"/> hit a revision conflict — the
+    /// server state moved on while the op sat in the queue (typically offline).
+    /// Default keeps it queued for a retry; override to rebase or discard.
+    /// </summary>
+    Task<OutboxConflictResolution> ResolveConflictAsync(OutboxEntry entry, CancellationToken ct)
+        => Task.FromResult(OutboxConflictResolution.Retry);
 }
@@ -1,3 +1,4 @@
+using System;
 using System.Threading;
 using System.Threading.Tasks;
 
@@ -20,4 +21,15 @@ public interface IOutboxProcessor
     /// Returns the number of entries that failed this run (still queued for retry).
     /// </summary>
     Task<int> RunAsync(int limit = 50, CancellationToken ct = default);
+
+    /// <summary>
+    /// Emits every entry processed, with its outcome.
+    /// </summary>
+    IObservable<OutboxOpResult> Processed { get; }
+
+    /// <summary>
+    /// Emits the queue size and connectivity after every run, including runs
+    /// skipped while offline.
+    /// </summary>
+    IObservable<OutboxStatus> Status { get; }
 }
@@ -1,9 +1,16 @@
 using System;
 using System.Collections.Generic;
+using System.IO;
 using System.Linq;
+using System.Net.Http;
+using System.Net.Sockets;
+using System.Reactive.Linq;
 using System.Threading;
 using System.Threading.Tasks;
 using Microsoft.Extensions.Logging;
+using Wavee.Connect;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
 using Wavee.Core.Storage.Abstractions;
 
 namespace Wavee.Core.Storage.Outbox;
@@ -12,8 +19,14 @@ namespace Wavee.Core.Storage.Outbox;
 /// Default <see cref="IOutboxProcessor"/>. Holds the single retry loop the
 /// whole app uses for background-synced operations; per-op behaviour comes from
 /// the <see cref="IOutboxHandler"/> instances registered via DI.
+///
+/// Writes made while offline stay queued in SQLite. Attach the session's
+/// connection state with <see cref="ReplayWhenOnline"/> and the processor holds
+/// runs back while disconnected and drains the queue once connectivity returns.
+/// A network failure mid-run also stops the run without burning a retry; an op
+/// the server rejects outright is dropped so it can't hold up later edits.
 /// </summary>
-public sealed class OutboxProcessor : IOutboxProcessor
+public sealed class OutboxProcessor : IOutboxProcessor, IDisposable
 {
     /// <summary>Cap retries so a permanently-broken op doesn't stick in the queue forever.</summary>
     public const int MaxRetries = 10;
@@ -21,15 +34,25 @@ public sealed class OutboxProcessor : IOutboxProcessor
     private readonly IMetadataDatabase _db;
     private readonly IReadOnlyDictionary<string, IOutboxHandler> _handlers;
     private readonly ILogger? _logger;
+    private readonly TimeProvider _timeProvider;
+    private readonly SafeSubject<OutboxOpResult> _processed;
+    private readonly SafeSubject<OutboxStatus> _status;
+    private IDisposable? _connectivitySubscription;
+    private volatile bool _isOnline = true;
+    private DateTimeOffset? _lastRunAt;
     private int _runningFlag;
 
     public OutboxProcessor(
         IMetadataDatabase db,
         IEnumerable<IOutboxHandler> handlers,
-        ILogger<OutboxProcessor>? logger = null)
+        ILogger<OutboxProcessor>? logger = null,
+        TimeProvider? timeProvider = null)
     {
         _db = db ?? throw new ArgumentNullException(nameof(db));
         _logger = logger;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+        _processed = new SafeSubject<OutboxOpResult>(logger);
+        _status = new SafeSubject<OutboxStatus>(logger);
 
         // Build the kind→handler map. Duplicate kinds are a wiring bug — fail
         // loud rather than silently shadow.
@@ -45,21 +68,79 @@ public sealed class OutboxProcessor : IOutboxProcessor
         _handlers = dict;
     }
 
+    /// <inheritdoc />
+    public IObservable<OutboxOpResult> Processed => _processed;
+
+    /// <inheritdoc />
+    public IObservable<OutboxStatus> Status => _status;
+
+    /// <summary>
+    /// False while the attached connection state says the session is offline.
+    /// </summary>
+    public bool IsOnline => _isOnline;
+
+    /// <summary>
+    /// Gates runs on <paramref name="connectionState"/> (typically
+    /// <see cref="ISession.ConnectionState"/>): while not
+    /// <see cref="SessionConnectionState.Connected"/>, <see cref="RunAsync"/> leaves
+    /// the queue alone, and each return to Connected drains it. Replaces any
+    /// previously attached source.
+    /// </summary>
+    public void ReplayWhenOnline(IObservable<SessionConnectionState> connectionState)
+    {
+        ArgumentNullException.ThrowIfNull(connectionState);
+
+        _connectivitySubscription?.Dispose();
+        _connectivitySubscription = connectionState
+            .Select(static s => s == SessionConnectionState.Connected)
+            .DistinctUntilChanged()
+            .Subscribe(online =>
+            {
+                _isOnline = online;
+                if (online)
+                {
+                    _logger?.LogDebug("Connectivity restored, replaying outbox");
+                    _ = DrainAsync();
+                }
+            });
+    }
+
     public async Task<int> RunAsync(int limit = 50, CancellationToken ct = default)
     {
+        var batch = await RunBatchAsync(limit, ct).ConfigureAwait(false);
+        return batch?.Failed ?? 0;
+    }
+
+    /// <summary>
+    /// One pass over up to <paramref name="limit"/> entries. Null when skipped
+    /// (offline, or another run already in flight).
+    /// </summary>
+    private async Task<(int Dequeued, int Failed)?> RunBatchAsync(int limit, CancellationToken ct)
+    {
+        if (!_isOnline)
+        {
+            // Offline: writes stay queued until connectivity returns. Still report
+            // status so the UI can show "n changes waiting to sync".
+            await PublishStatusAsync(ct).ConfigureAwait(false);
+            return null;
+        }
+
         // Serialize overlapping invocations to a single in-flight run; callers
-        // can fire-and-forget without coordinating. Returning 0 here is the
+        // can fire-and-forget without coordinating. Returning null here is the
         // signal "another run is already draining; nothing to do".
-        if (Interlocked.Exchange(ref _runningFlag, 1) == 1) return 0;
+        if (Interlocked.Exchange(ref _runningFlag, 1) == 1) return null;
 
         var failed = 0;
+        var dequeued = 0;
         try
         {
             var ops = await _db.DequeueOutboxAsync(limit, ct).ConfigureAwait(false);
-            if (ops.Count == 0) return 0;
+            dequeued = ops.Count;
+            if (ops.Count == 0) return (0, 0);
 
-            foreach (var op in ops)
+            for (var i = 0; i < ops.Count; i++)
             {
+                var op = ops[i];
                 try
                 {
                     if (op.RetryCount >= MaxRetries)
@@ -68,6 +149,7 @@ public sealed class OutboxProcessor : IOutboxProcessor
                             "Outbox op exceeded max retries, dropping: {Kind} {Uri} (last error: {Error})",
                             op.OpKind, op.PrimaryUri, op.LastError);
                         await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                        _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Dropped, op.LastError));
                         failed++;
                         continue;
                     }
@@ -81,12 +163,14 @@ public sealed class OutboxProcessor : IOutboxProcessor
                             "Outbox op has no registered handler, dropping: {Kind} {Uri}",
                             op.OpKind, op.PrimaryUri);
                         await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                        _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Dropped, "No handler registered"));
                         failed++;
                         continue;
                     }
 
                     await handler.ProcessAsync(op, ct).ConfigureAwait(false);
                     await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                    _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Synced));
 
                     _logger?.LogDebug("Outbox synced: {Kind} {Uri}", op.OpKind, op.PrimaryUri);
                 }
@@ -96,6 +180,61 @@ public sealed class OutboxProcessor : IOutboxProcessor
                     // RunAsync picks it up.
                     throw;
                 }
+                catch (Exception ex) when (IsConnectivityFailure(ex))
+                {
+                    // Lost the network mid-run. Not the op's fault: leave it (and
+                    // the rest of the batch) queued without burning a retry.
+                    failed += ops.Count - i;
+                    _logger?.LogInformation(
+                        "Outbox paused, network unavailable ({Error}); {Count} ops stay queued",
+                        ex.Message, ops.Count - i);
+                    break;
+                }
+                catch (SpClientException ex) when (ex.Reason == SpClientFailureReason.RevisionConflict)
+                {
+                    var handler = _handlers[op.OpKind];
+                    var resolution = await handler.ResolveConflictAsync(op, ct).ConfigureAwait(false);
+                    _logger?.LogInformation(
+                        "Outbox op conflicted with server state, {Resolution}: {Kind} {Uri}",
+                        resolution, op.OpKind, op.PrimaryUri);
+
+                    switch (resolution)
+                    {
+                        case OutboxConflictResolution.Resolved:
+                            await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                            _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Synced));
+                            break;
+                        case OutboxConflictResolution.Discard:
+                            await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                            _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Conflict, ex.Message));
+                            failed++;
+                            break;
+                        default:
+                            await _db.FailOutboxAsync(op.Id, ex.Message, CancellationToken.None).ConfigureAwait(false);
+                            _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Retrying, ex.Message));
+                            failed++;
+                            break;
+                    }
+                }
+                catch (SpClientException ex) when (ex.Reason == SpClientFailureReason.NotFound)
+                {
+                    // Target deleted while the op was queued (e.g. playlist removed
+                    // on another device). Retrying can't succeed.
+                    failed++;
+                    _logger?.LogWarning("Outbox op target no longer exists, dropping: {Kind} {Uri}", op.OpKind, op.PrimaryUri);
+                    await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                    _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Dropped, ex.Message));
+                }
+                catch (SpClientException ex) when (ex.Reason == SpClientFailureReason.RequestFailed)
+                {
+                    // Rejected request (4xx) that no transport failure explains.
+                    // Retrying sends the same bytes, so it can only fail again.
+                    failed++;
+                    _logger?.LogWarning(
+                        ex, "Outbox op rejected by server, dropping: {Kind} {Uri}", op.OpKind, op.PrimaryUri);
+                    await _db.CompleteOutboxAsync(op.Id, ct).ConfigureAwait(false);
+                    _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Dropped, ex.Message));
+                }
                 catch (Exception ex)
                 {
                     failed++;
@@ -103,6 +242,7 @@ public sealed class OutboxProcessor : IOutboxProcessor
                         ex, "Outbox op failed (retry {Count}): {Kind} {Uri}",
                         op.RetryCount + 1, op.OpKind, op.PrimaryUri);
                     await _db.FailOutboxAsync(op.Id, ex.Message, CancellationToken.None).ConfigureAwait(false);
+                    _processed.OnNext(new OutboxOpResult(op, OutboxOpOutcome.Retrying, ex.Message));
                 }
             }
         }
@@ -113,8 +253,65 @@ public sealed class OutboxProcessor : IOutboxProcessor
         }
         finally
         {
+            _lastRunAt = _timeProvider.GetUtcNow();
             Interlocked.Exchange(ref _runningFlag, 0);
         }
-        return failed;
+
+        await PublishStatusAsync(ct).ConfigureAwait(false);
+        return (dequeued, failed);
+    }
+
+    public void Dispose()
+    {
+        _connectivitySubscription?.Dispose();
+        _processed.Dispose();
+        _status.Dispose();
+    }
+
+    /// <summary>
+    /// Runs full batches until the queue is empty, a batch has failures, or
+    /// connectivity drops.
+    /// </summary>
+    private async Task DrainAsync()
+    {
+        const int batchSize = 50;
+        try
+        {
+            while (await RunBatchAsync(batchSize, CancellationToken.None).ConfigureAwait(false)
+                   is { Failed: 0, Dequeued: batchSize })
+            {
+            }
+        }
+        catch (Exception ex)
+        {
+            _logger?.LogWarning(ex, "Outbox replay failed");
+        }
+    }
+
+    private async Task PublishStatusAsync(CancellationToken ct)
+    {
+        try
+        {
+            var pending = await _db.CountOutboxAsync(ct).ConfigureAwait(false);
+            _status.OnNext(new OutboxStatus(pending, _isOnline, _lastRunAt));
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            _logger?.LogDebug(ex, "Failed to read outbox size");
+        }
     }
+
+    /// <summary>
+    /// Transport-level failures only. An HTTP error status is the server answering,
+    /// so it counts against the op; SpClient's give-up wrapper counts when the
+    /// last attempt died in transport.
+    /// </summary>
+    private static bool IsConnectivityFailure(Exception ex) => ex switch
+    {
+        HttpRequestException { StatusCode: null } => true,
+        SocketException or TimeoutException => true,
+        IOException { InnerException: SocketException } => true,
+        SpClientException { InnerException: { } inner } => IsConnectivityFailure(inner),
+        _ => false
+    };
 }
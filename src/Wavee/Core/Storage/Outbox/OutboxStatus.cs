@@ -0,0 +1,54 @@
+using System;
+using Wavee.Core.Storage.Abstractions;
+
+namespace Wavee.Core.Storage.Outbox;
+
+/// <summary>
+/// What happened to one outbox entry during a run.
+/// </summary>
+public enum OutboxOpOutcome
+{
+    /// <summary>Applied on the server and removed from the queue.</summary>
+    Synced,
+
+    /// <summary>Failed; stays queued with its retry count incremented.</summary>
+    Retrying,
+
+    /// <summary>Conflicted with newer server state; the handler chose to discard it.</summary>
+    Conflict,
+
+    /// <summary>Removed without being applied: retries exhausted, target gone, or no handler.</summary>
+    Dropped
+}
+
+/// <summary>
+/// One processed outbox entry, emitted by <see cref="IOutboxProcessor.Processed"/>.
+/// </summary>
+/// <param name="Entry">The entry as dequeued.</param>
+/// <param name="Outcome">What happened to it.</param>
+/// <param name="Error">Failure message for every outcome but <see cref="OutboxOpOutcome.Synced"/>.</param>
+public sealed record OutboxOpResult(OutboxEntry Entry, OutboxOpOutcome Outcome, string? Error = null);
+
+/// <summary>
+/// Snapshot of the queue, emitted by <see cref="IOutboxProcessor.Status"/> after every run.
+/// </summary>
+/// <param name="Pending">Operations still waiting to sync.</param>
+/// <param name="IsOnline">False while the processor holds writes back for lack of connectivity.</param>
+/// <param name="LastRunAt">When the queue was last drained; null before the first run.</param>
+public sealed record OutboxStatus(int Pending, bool IsOnline, DateTimeOffset? LastRunAt);
+
+/// <summary>
+/// How a handler settles an operation the server rejected as conflicting
+/// (<see cref="Http.SpClientFailureReason.RevisionConflict"/>).
+/// </summary>
+public enum OutboxConflictResolution
+{
+    /// <summary>Keep it queued and try again on the next run (e.g. against a refreshed revision).</summary>
+    Retry,
+
+    /// <summary>The server's state wins; drop the operation.</summary>
+    Discard,
+
+    /// <summary>The handler re-applied the change itself; the operation is done.</summary>
+    Resolved
+}
@@ -0,0 +1,266 @@
+namespace Wavee.Core.Storage;
+
+/// <summary>
+/// Platform-appropriate directories for everything Wavee keeps on disk, and the typed
+/// paths inside them. Persistence code takes its defaults from <see cref="Default"/>
+/// instead of combining special folders itself.
+/// </summary>
+/// <remarks>
+/// <list type="table">
+///   <listheader><term>Platform</term><description>Config / Data · Cache · Logs</description></listheader>
+///   <item><term>Windows</term><description><c>%APPDATA%\Wavee</c> · <c>%LOCALAPPDATA%\Wavee</c> · <c>%LOCALAPPDATA%\Wavee\Logs</c></description></item>
+///   <item><term>macOS</term><description><c>~/Library/Application Support/Wavee</c> · <c>~/Library/Caches/Wavee</c> · <c>~/Library/Logs/Wavee</c></description></item>
+///   <item><term>Linux</term><description><c>$XDG_CONFIG_HOME/wavee</c>, <c>$XDG_DATA_HOME/wavee</c> · <c>$XDG_CACHE_HOME/wavee</c> · <c>$XDG_STATE_HOME/wavee/logs</c></description></item>
+///   <item><term>Android</term><description><c>&lt;filesDir&gt;/wavee</c> · <c>&lt;cacheDir&gt;/wavee</c> · <c>&lt;cacheDir&gt;/wavee/logs</c></description></item>
+/// </list>
+/// <see cref="HomeEnvironmentVariable"/> puts all of them under one directory instead
+/// (portable installs, containers). Data written by earlier versions to the old
+/// .NET special-folder locations on macOS and Linux keeps being used until it is moved.
+/// </remarks>
+#if WAVEE_AUDIOHOST
+internal
+#else
+public
+#endif
+sealed class WaveePaths
+{
+    /// <summary>
+    /// Environment variable that, when set, roots every directory at its value:
+    /// <c>config</c>, <c>data</c>, <c>cache</c> and <c>logs</c> beneath it.
+    /// </summary>
+    public const string HomeEnvironmentVariable = "WAVEE_HOME";
+
+    private readonly string? _legacyDataDirectory;
+    private readonly string? _legacyCacheDirectory;
+
+    /// <summary>
+    /// Creates a layout from explicit directories, for hosts that manage storage themselves.
+    /// </summary>
+    public WaveePaths(string configDirectory, string dataDirectory, string cacheDirectory, string logDirectory)
+        : this(configDirectory, dataDirectory, cacheDirectory, logDirectory, null, null)
+    {
+    }
+
+    private WaveePaths(
+        string configDirectory,
+        string dataDirectory,
+        string cacheDirectory,
+        string logDirectory,
+        string? legacyDataDirectory,
+        string? legacyCacheDirectory)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(configDirectory);
+        ArgumentException.ThrowIfNullOrWhiteSpace(dataDirectory);
+        ArgumentException.ThrowIfNullOrWhiteSpace(cacheDirectory);
+        ArgumentException.ThrowIfNullOrWhiteSpace(logDirectory);
+
+        ConfigDirectory = configDirectory;
+        DataDirectory = dataDirectory;
+        CacheDirectory = cacheDirectory;
+        LogDirectory = logDirectory;
+        _legacyDataDirectory = Same(legacyDataDirectory, dataDirectory) ? null : legacyDataDirectory;
+        _legacyCacheDirectory = Same(legacyCacheDirectory, cacheDirectory) ? null : legacyCacheDirectory;
+    }
+
+    /// <summary>
+    /// Layout for the current platform and environment.
+    /// </summary>
+    public static WaveePaths Default { get; } = Resolve(
+        CurrentPlatform(),
+        Environment.GetEnvironmentVariable,
+        folder => Environment.GetFolderPath(folder),
+        Environment.GetFolderPath(Environment.SpecialFolder.UserProfile));
+
+    /// <summary>
+    /// Android layout from the app's <c>Context.getFilesDir()</c> and <c>getCacheDir()</c>.
+    /// </summary>
+    /// <remarks>
+    /// For hosts started through JNI (a native library loaded with <c>System.loadLibrary</c>),
+    /// where HOME and TMPDIR aren't set up the way .NET for Android does it: the Java side
+    /// passes both paths as plain strings before anything touches storage.
+    /// </remarks>
+    public static WaveePaths ForAndroid(string filesDirectory, string cacheDirectory)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(filesDirectory);
+        ArgumentException.ThrowIfNullOrWhiteSpace(cacheDirectory);
+
+        var data = Path.Combine(filesDirectory, "wavee");
+        var cache = Path.Combine(cacheDirectory, "wavee");
+        return new WaveePaths(data, data, cache, Path.Combine(cache, "logs"));
+    }
+
+    /// <summary>
+    /// User-edited settings, such as <see cref="ConfigFile"/>.
+    /// </summary>
+    public string ConfigDirectory { get; }
+
+    /// <summary>
+    /// State that must survive: credentials, device id, the metadata database.
+    /// </summary>
+    public string DataDirectory { get; }
+
+    /// <summary>
+    /// Re-downloadable data the OS or user may delete at any time.
+    /// </summary>
+    public string CacheDirectory { get; }
+
+    /// <summary>
+    /// Log files.
+    /// </summary>
+    public string LogDirectory { get; }
+
+    /// <summary>
+    /// Configuration file read when neither <c>--config</c> nor <c>WAVEE_CONFIG</c> names one.
+    /// </summary>
+    public string ConfigFile => Path.Combine(ConfigDirectory, "wavee.json");
+
+    /// <summary>
+    /// Persisted Connect device id.
+    /// </summary>
+    public string DeviceIdFile => DataPath("device_id.txt");
+
+    /// <summary>
+    /// Directory of the file-backed credentials store.
+    /// </summary>
+    public string CredentialsDirectory => DataPath("credentials");
+
+    /// <summary>
+    /// SQLite metadata and library database.
+    /// </summary>
+    public string MetadataDatabase => DataPath("metadata.db");
+
+    /// <summary>
+    /// Local-file artwork cache. Kept next to <see cref="MetadataDatabase"/>, since
+    /// enrichment writers derive it from the database location.
+    /// </summary>
+    public string LocalArtworkDirectory => Path.Combine(Path.GetDirectoryName(MetadataDatabase)!, "local-artwork");
+
+    /// <summary>
+    /// Persistent audio cache.
+    /// </summary>
+    public string AudioCacheDirectory => CachePath("AudioCache");
+
+    /// <summary>
+    /// Downloaded native libraries used by AudioHost, and their provisioning failure markers.
+    /// </summary>
+    public string NativeDepsDirectory => CachePath("NativeDeps");
+
+    /// <summary>
+    /// Downloaded lyrics indexes.
+    /// </summary>
+    public string LyricsDirectory => Path.Combine(CacheDirectory, "lyrics");
+
+    internal static WaveePaths Resolve(
+        PathPlatform platform,
+        Func<string, string?> getEnvironmentVariable,
+        Func<Environment.SpecialFolder, string> getFolderPath,
+        string home)
+    {
+        if (NonEmpty(getEnvironmentVariable(HomeEnvironmentVariable)) is { } root)
+        {
+            return new WaveePaths(
+                Path.Combine(root, "config"),
+                Path.Combine(root, "data"),
+                Path.Combine(root, "cache"),
+                Path.Combine(root, "logs"));
+        }
+
+        // Where earlier versions wrote, straight from .NET's special folders.
+        var legacyData = Path.Combine(getFolderPath(Environment.SpecialFolder.ApplicationData), "Wavee");
+        var legacyCache = Path.Combine(getFolderPath(Environment.SpecialFolder.LocalApplicationData), "Wavee");
+
+        switch (platform)
+        {
+            case PathPlatform.Android:
+            {
+                // The runtime points HOME at Context.getFilesDir() and TMPDIR at getCacheDir(), both
+                // app-private, so nothing here needs storage permissions and the OS may trim the cache.
+                var cacheRoot = NonEmpty(getEnvironmentVariable("TMPDIR")) is { } tmp && Path.IsPathRooted(tmp)
+                    ? tmp
+                    : Path.Combine(Path.GetDirectoryName(Path.TrimEndingDirectorySeparator(home)) ?? home, "cache");
+                return ForAndroid(home, cacheRoot);
+            }
+
+            case PathPlatform.Windows:
+                return new WaveePaths(legacyData, legacyData, legacyCache, Path.Combine(legacyCache, "Logs"));
+
+            case PathPlatform.MacOS:
+            {
+                var library = Path.Combine(home, "Library");
+                var support = Path.Combine(library, "Application Support", "Wavee");
+                return new WaveePaths(
+                    support,
+                    support,
+                    Path.Combine(library, "Caches", "Wavee"),
+                    Path.Combine(library, "Logs", "Wavee"),
+                    legacyData,
+                    legacyCache);
+            }
+
+            default:
+            {
+                string Xdg(string variable, params string[] fallback)
+                {
+                    // The spec says relative values are invalid and must be ignored.
+                    var value = NonEmpty(getEnvironmentVariable(variable));
+                    var baseDir = value is not null && Path.IsPathRooted(value)
+                        ? value
+                        : Path.Combine([home, .. fallback]);
+                    return Path.Combine(baseDir, "wavee");
+                }
+
+                return new WaveePaths(
+                    Xdg("XDG_CONFIG_HOME", ".config"),
+                    Xdg("XDG_DATA_HOME", ".local", "share"),
+                    Xdg("XDG_CACHE_HOME", ".cache"),
+                    Path.Combine(Xdg("XDG_STATE_HOME", ".local", "state"), "logs"),
+                    legacyData,
+                    legacyCache);
+            }
+        }
+    }
+
+    private string DataPath(string name) => PreferExisting(DataDirectory, _legacyDataDirectory, name);
+
+    private string CachePath(string name) => PreferExisting(CacheDirectory, _legacyCacheDirectory, name);
+
+    /// <summary>
+    /// The path under <paramref name="directory"/>, unless only the legacy location has it.
+    /// </summary>
+    private static string PreferExisting(string directory, string? legacyDirectory, string name)
+    {
+        var path = Path.Combine(directory, name);
+        if (legacyDirectory is null || Path.Exists(path))
+            return path;
+
+        var legacyPath = Path.Combine(legacyDirectory, name);
+        return Path.Exists(legacyPath) ? legacyPath : path;
+    }
+
+    private static PathPlatform CurrentPlatform()
+        => OperatingSystem.IsWindows() ? PathPlatform.Windows
+            : OperatingSystem.IsMacOS() || OperatingSystem.IsMacCatalyst() ? PathPlatform.MacOS
+            : OperatingSystem.IsAndroid() ? PathPlatform.Android
+            : PathPlatform.Unix;
+
+    private static string? NonEmpty(string? value) => string.IsNullOrWhiteSpace(value) ? null : value;
+
+    private static bool Same(string? a, string b)
+        => a is not null && string.Equals(Path.TrimEndingDirectorySeparator(a), Path.TrimEndingDirectorySeparator(b),
+            OperatingSystem.IsWindows() ? StringComparison.OrdinalIgnoreCase : StringComparison.Ordinal);
+}
+
+/// <summary>
+/// Directory conventions <see cref="WaveePaths"/> follows.
+/// </summary>
+internal enum PathPlatform
+{
+    Windows,
+    MacOS,
+
+    /// <summary>App-private internal storage: the files and cache directories of the app's Context.</summary>
+    Android,
+
+    /// <summary>Linux and other Unix-likes: XDG base directories.</summary>
+    Unix
+}
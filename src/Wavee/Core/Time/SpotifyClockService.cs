@@ -11,6 +11,11 @@ namespace Wavee.Core.Time;
 /// Uses an NTP-style algorithm: for each sample, records local time before and after
 /// the request, then estimates offset as serverTime - midpoint(localBefore, localAfter).
 /// Takes multiple samples per round and picks the one with the lowest RTT (most accurate).
+///
+/// Until the first melody round succeeds, coarse server-time hints (the AP Ping
+/// timestamp, HTTP <c>Date</c> response headers) are fed in via
+/// <see cref="ObserveServerTime"/> so a device whose RTC is badly off (e.g. a Pi
+/// without a battery) still gets a usable offset for token expiry and reporting.
 /// </remarks>
 public sealed class SpotifyClockService : IDisposable
 {
@@ -18,19 +23,22 @@ public sealed class SpotifyClockService : IDisposable
 
     private readonly ISpClient _spClient;
     private readonly ILogger? _logger;
+    private readonly TimeProvider _timeProvider;
     private CancellationTokenSource _cts = new();
     private Task? _backgroundTask;
 
     private long _offsetMs;  // serverTime - localTime (add to local to get server time)
     private long _lastRttMs;
     private bool _isSynced;
+    private bool _hasCoarseOffset;
     private DateTimeOffset _lastSyncUtc;
     private int _syncIntervalMinutes = 10;
 
-    public SpotifyClockService(ISpClient spClient, ILogger? logger = null)
+    public SpotifyClockService(ISpClient spClient, ILogger? logger = null, TimeProvider? timeProvider = null)
     {
         _spClient = spClient;
         _logger = logger;
+        _timeProvider = timeProvider ?? TimeProvider.System;
     }
 
     /// <summary>
@@ -47,7 +55,13 @@ public sealed class SpotifyClockService : IDisposable
     /// Returns the current time in Unix milliseconds, corrected for Spotify server clock offset.
     /// Falls back to local clock if no sync has been performed.
     /// </summary>
-    public long NowMs => DateTimeOffset.UtcNow.ToUnixTimeMilliseconds() + _offsetMs;
+    public long NowMs => _timeProvider.GetUtcNow().ToUnixTimeMilliseconds() + _offsetMs;
+
+    /// <summary>
+    /// Current time as a <see cref="DateTimeOffset"/>, corrected for Spotify server clock offset.
+    /// Use this instead of <see cref="DateTimeOffset.UtcNow"/> for token expiry and timestamps sent to Spotify.
+    /// </summary>
+    public DateTimeOffset UtcNow => DateTimeOffset.FromUnixTimeMilliseconds(NowMs);
 
     /// <summary>
     /// Current estimated offset in ms (positive = local clock is behind server).
@@ -65,6 +79,11 @@ public sealed class SpotifyClockService : IDisposable
     /// </summary>
     public bool IsSynced => _isSynced;
 
+    /// <summary>
+    /// Whether the current offset comes from a coarse server-time hint rather than a melody sync round.
+    /// </summary>
+    public bool IsCoarse => _hasCoarseOffset && !_isSynced;
+
     /// <summary>
     /// When the last successful sync completed (UTC).
     /// </summary>
@@ -97,9 +116,9 @@ public sealed class SpotifyClockService : IDisposable
         {
             try
             {
-                var t1 = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds();
+                var t1 = _timeProvider.GetUtcNow().ToUnixTimeMilliseconds();
                 var serverTime = await _spClient.GetMelodyTimeAsync(cancellationToken);
-                var t2 = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds();
+                var t2 = _timeProvider.GetUtcNow().ToUnixTimeMilliseconds();
 
                 var rtt = t2 - t1;
                 var midpoint = (t1 + t2) / 2;
@@ -127,7 +146,7 @@ public sealed class SpotifyClockService : IDisposable
             _offsetMs = bestOffset;
             _lastRttMs = bestRtt;
             _isSynced = true;
-            _lastSyncUtc = DateTimeOffset.UtcNow;
+            _lastSyncUtc = _timeProvider.GetUtcNow();
 
             _logger?.LogInformation(
                 "Clock sync complete: offset={Offset}ms (was {PreviousOffset}ms), bestRtt={Rtt}ms",
@@ -139,6 +158,33 @@ public sealed class SpotifyClockService : IDisposable
         }
     }
 
+    /// <summary>
+    /// Feeds a coarse server-time hint (AP Ping timestamp, HTTP <c>Date</c> header).
+    /// </summary>
+    /// <remarks>
+    /// Ignored once a melody sync round has succeeded — those samples are RTT-corrected
+    /// and millisecond-precise. Otherwise the offset is only moved when the hint disagrees
+    /// with the current estimate by more than <paramref name="resolution"/>, so second-granular
+    /// headers don't make the clock jitter.
+    /// </remarks>
+    /// <param name="serverTime">Server time reported by the hint.</param>
+    /// <param name="resolution">Granularity of the hint (1s for both Ping and <c>Date</c>).</param>
+    public void ObserveServerTime(DateTimeOffset serverTime, TimeSpan resolution)
+    {
+        if (_isSynced) return;
+
+        var candidate = serverTime.ToUnixTimeMilliseconds() - _timeProvider.GetUtcNow().ToUnixTimeMilliseconds();
+        if (Math.Abs(candidate - _offsetMs) <= (long)resolution.TotalMilliseconds) return;
+
+        var previousOffset = _offsetMs;
+        _offsetMs = candidate;
+        _hasCoarseOffset = true;
+
+        _logger?.LogInformation(
+            "Clock skew detected from server hint: offset={Offset}ms (was {PreviousOffset}ms)",
+            candidate, previousOffset);
+    }
+
     private void RestartTimer()
     {
         _cts.Cancel();
@@ -160,7 +206,7 @@ public sealed class SpotifyClockService : IDisposable
         }
 
         // Periodic re-sync
-        using var timer = new PeriodicTimer(TimeSpan.FromMinutes(_syncIntervalMinutes));
+        using var timer = new PeriodicTimer(TimeSpan.FromMinutes(_syncIntervalMinutes), _timeProvider);
         while (await timer.WaitForNextTickAsync(cancellationToken))
         {
             try
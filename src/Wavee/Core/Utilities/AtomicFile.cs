@@ -0,0 +1,244 @@
+using System.Runtime.InteropServices;
+using System.Text;
+using Microsoft.Extensions.Logging;
+
+namespace Wavee.Core.Utilities;
+
+/// <summary>
+/// Crash-safe replacement for <c>File.WriteAll*</c> on small state files (credentials,
+/// tokens, settings, markers).
+/// </summary>
+/// <remarks>
+/// Writes go to <c>{path}.tmp</c>, are fsynced, then renamed over <c>{path}</c>; the previous
+/// version is kept as <c>{path}.bak</c>. On Unix the directory is fsynced too, so the rename
+/// itself survives a power loss. After a power loss the file is either the old or the
+/// new version, never a torn mix. Reads through <see cref="ReadAllBytesAsync"/> fall back to
+/// the backup when the primary is missing or fails the caller's validation (e.g. a file torn by
+/// an older, non-atomic build), and restore it.
+/// </remarks>
+public static partial class AtomicFile
+{
+    private const string TempSuffix = ".tmp";
+    private const string BackupSuffix = ".bak";
+
+    /// <summary>
+    /// Atomically replaces <paramref name="path"/> with <paramref name="data"/>.
+    /// </summary>
+    /// <param name="path">Destination file.</param>
+    /// <param name="data">New contents.</param>
+    /// <param name="unixMode">Permissions for the file on Unix, applied before any byte is written.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    public static async Task WriteAllBytesAsync(
+        string path,
+        ReadOnlyMemory<byte> data,
+        UnixFileMode? unixMode = null,
+        CancellationToken cancellationToken = default)
+    {
+        var tempPath = path + TempSuffix;
+        await using (var stream = new FileStream(tempPath, CreateOptions(unixMode, FileOptions.Asynchronous)))
+        {
+            await stream.WriteAsync(data, cancellationToken);
+            stream.Flush(flushToDisk: true);
+        }
+
+        Commit(tempPath, path);
+    }
+
+    /// <summary>
+    /// Atomically replaces <paramref name="path"/> with <paramref name="data"/>.
+    /// </summary>
+    public static void WriteAllBytes(string path, ReadOnlySpan<byte> data, UnixFileMode? unixMode = null)
+    {
+        var tempPath = path + TempSuffix;
+        using (var stream = new FileStream(tempPath, CreateOptions(unixMode, FileOptions.None)))
+        {
+            stream.Write(data);
+            stream.Flush(flushToDisk: true);
+        }
+
+        Commit(tempPath, path);
+    }
+
+    /// <summary>
+    /// Atomically replaces <paramref name="path"/> with UTF-8 <paramref name="contents"/>.
+    /// </summary>
+    public static Task WriteAllTextAsync(string path, string contents, CancellationToken cancellationToken = default)
+        => WriteAllBytesAsync(path, Encoding.UTF8.GetBytes(contents), cancellationToken: cancellationToken);
+
+    /// <summary>
+    /// Atomically replaces <paramref name="path"/> with UTF-8 <paramref name="contents"/>.
+    /// </summary>
+    public static void WriteAllText(string path, string contents)
+        => WriteAllBytes(path, Encoding.UTF8.GetBytes(contents));
+
+    /// <summary>
+    /// Atomically replaces <paramref name="path"/> with UTF-8 <paramref name="contents"/>
+    /// without keeping a backup — for state that can be rebuilt, such as cache indexes.
+    /// </summary>
+    public static void ReplaceAllText(string path, string contents)
+    {
+        var tempPath = path + TempSuffix;
+        using (var stream = new FileStream(tempPath, CreateOptions(null, FileOptions.None)))
+        {
+            stream.Write(Encoding.UTF8.GetBytes(contents));
+            stream.Flush(flushToDisk: true);
+        }
+
+        File.Move(tempPath, path, overwrite: true);
+        FlushDirectory(path);
+    }
+
+    /// <summary>
+    /// Reads <paramref name="path"/>, falling back to the last good backup when it is missing,
+    /// empty or rejected by <paramref name="validate"/>. A usable backup is restored in place.
+    /// </summary>
+    /// <param name="path">File to read.</param>
+    /// <param name="validate">
+    /// Returns false (or throws) when the bytes are unusable. Null accepts any non-empty content.
+    /// </param>
+    /// <param name="logger">Optional logger for recoveries.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The contents, or null when neither the file nor its backup is usable.</returns>
+    public static async Task<byte[]?> ReadAllBytesAsync(
+        string path,
+        Func<byte[], bool>? validate = null,
+        ILogger? logger = null,
+        CancellationToken cancellationToken = default)
+    {
+        var primary = await TryReadAsync(path, validate, cancellationToken);
+        if (primary != null)
+            return primary;
+
+        var backupPath = path + BackupSuffix;
+        var backup = await TryReadAsync(backupPath, validate, cancellationToken);
+        if (backup == null)
+        {
+            if (File.Exists(path))
+                logger?.LogWarning("{Path} is unreadable and has no usable backup", path);
+            return null;
+        }
+
+        logger?.LogWarning("{Path} was missing or corrupt; recovered the previous version from backup", path);
+        try
+        {
+            var restorePath = path + ".restore";
+            File.Copy(backupPath, restorePath, overwrite: true);
+            File.Move(restorePath, path, overwrite: true);
+        }
+        catch (IOException ex)
+        {
+            logger?.LogDebug(ex, "Failed to restore {Path} from backup", path);
+        }
+
+        return backup;
+    }
+
+    /// <summary>
+    /// Reads UTF-8 text with the same fallback as <see cref="ReadAllBytesAsync"/>.
+    /// </summary>
+    public static async Task<string?> ReadAllTextAsync(
+        string path,
+        Func<string, bool>? validate = null,
+        ILogger? logger = null,
+        CancellationToken cancellationToken = default)
+    {
+        var bytes = await ReadAllBytesAsync(
+            path,
+            validate == null ? null : b => validate(Encoding.UTF8.GetString(b)),
+            logger,
+            cancellationToken);
+        return bytes == null ? null : Encoding.UTF8.GetString(bytes);
+    }
+
+    /// <summary>
+    /// Deletes the file along with its backup and any uncommitted temp file. Use this for
+    /// secrets so no earlier version lingers in the backup.
+    /// </summary>
+    public static void Delete(string path)
+    {
+        TryDelete(path + TempSuffix);
+        TryDelete(path + BackupSuffix);
+        if (File.Exists(path))
+            File.Delete(path);
+    }
+
+    private static FileStreamOptions CreateOptions(UnixFileMode? unixMode, FileOptions options)
+    {
+        var streamOptions = new FileStreamOptions
+        {
+            Mode = FileMode.Create,
+            Access = FileAccess.Write,
+            Share = FileShare.None,
+            Options = options
+        };
+        if (unixMode is { } mode && !OperatingSystem.IsWindows())
+            streamOptions.UnixCreateMode = mode;
+        return streamOptions;
+    }
+
+    private static void Commit(string tempPath, string path)
+    {
+        if (File.Exists(path))
+            File.Replace(tempPath, path, path + BackupSuffix, ignoreMetadataErrors: true);
+        else
+            File.Move(tempPath, path);
+        FlushDirectory(path);
+    }
+
+    // Makes the rename durable. Windows has no portable equivalent (NTFS journals it), and
+    // some filesystems refuse fsync on a directory; either way the file itself is already safe.
+    private static void FlushDirectory(string path)
+    {
+        if (OperatingSystem.IsWindows() || Path.GetDirectoryName(Path.GetFullPath(path)) is not { } directory)
+            return;
+
+        var fd = Open(directory, 0 /* O_RDONLY */);
+        if (fd < 0)
+            return;
+
+        Fsync(fd);
+        Close(fd);
+    }
+
+    [LibraryImport("libc", EntryPoint = "open", StringMarshalling = StringMarshalling.Utf8)]
+    private static partial int Open(string path, int flags);
+
+    [LibraryImport("libc", EntryPoint = "fsync")]
+    private static partial int Fsync(int fd);
+
+    [LibraryImport("libc", EntryPoint = "close")]
+    private static partial int Close(int fd);
+
+    private static async Task<byte[]?> TryReadAsync(string path, Func<byte[], bool>? validate, CancellationToken ct)
+    {
+        if (!File.Exists(path))
+            return null;
+
+        try
+        {
+            var bytes = await File.ReadAllBytesAsync(path, ct);
+            if (bytes.Length == 0)
+                return null;
+            return validate == null || validate(bytes) ? bytes : null;
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            return null;
+        }
+    }
+
+    private static void TryDelete(string path)
+    {
+        try
+        {
+            if (File.Exists(path))
+                File.Delete(path);
+        }
+        catch (IOException)
+        {
+        }
+        catch (UnauthorizedAccessException)
+        {
+        }
+    }
+}
@@ -0,0 +1,63 @@
+namespace Wavee.Core.Utilities;
+
+/// <summary>
+/// Lets through at most one event per interval and counts the ones it drops in between.
+/// </summary>
+/// <remarks>
+/// The first event always passes. Later events pass once <see cref="Interval"/> has
+/// elapsed since the last one that did, and report how many were suppressed meanwhile so
+/// the sample can say what it stands for. Thread-safe.
+/// </remarks>
+public sealed class EventSampler
+{
+    private readonly TimeProvider _timeProvider;
+    private readonly object _lock = new();
+    private long? _lastEmitted;
+    private int _suppressed;
+
+    /// <summary>
+    /// Creates a sampler.
+    /// </summary>
+    /// <param name="interval">Minimum gap between samples; zero or negative lets everything through.</param>
+    /// <param name="timeProvider">Clock; defaults to <see cref="TimeProvider.System"/>.</param>
+    public EventSampler(TimeSpan interval, TimeProvider? timeProvider = null)
+    {
+        Interval = interval;
+        _timeProvider = timeProvider ?? TimeProvider.System;
+    }
+
+    /// <summary>
+    /// Gets the minimum gap between samples.
+    /// </summary>
+    public TimeSpan Interval { get; }
+
+    /// <summary>
+    /// Decides whether the current event should be emitted.
+    /// </summary>
+    /// <param name="suppressed">Events dropped since the previous sample; zero when this one is dropped.</param>
+    /// <returns>True when the caller should emit the event.</returns>
+    public bool ShouldSample(out int suppressed)
+    {
+        if (Interval <= TimeSpan.Zero)
+        {
+            suppressed = 0;
+            return true;
+        }
+
+        var now = _timeProvider.GetTimestamp();
+        lock (_lock)
+        {
+            if (_lastEmitted is { } last && _timeProvider.GetElapsedTime(last, now) < Interval)
+            {
+                _suppressed++;
+                suppressed = 0;
+                return false;
+            }
+
+            _lastEmitted = now;
+            suppressed = _suppressed;
+            _suppressed = 0;
+            return true;
+        }
+    }
+}
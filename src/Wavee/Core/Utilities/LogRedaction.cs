@@ -0,0 +1,123 @@
+using System.Text;
+
+namespace Wavee.Core.Utilities;
+
+/// <summary>
+/// Single choke point for rendering sensitive values (tokens, keys, credential blobs,
+/// signed URLs) into log messages and diagnostic recordings.
+/// </summary>
+/// <remarks>
+/// Every helper returns a placeholder that keeps the value's shape (length, host/path)
+/// but never its content — regardless of log level, including Trace. Set
+/// <see cref="UnsafeLogSecrets"/> once at process startup to emit the raw values when
+/// debugging the wire protocol; never ship a build with it on.
+/// </remarks>
+public static class LogRedaction
+{
+    private const string Placeholder = "<redacted>";
+
+    private static readonly string[] SensitiveQueryParameters =
+    [
+        "access_token", "token", "__token__", "client_token", "key", "signature", "sig", "hmac", "code", "password"
+    ];
+
+    private static readonly HashSet<string> SensitiveHeaders = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "Authorization", "client-token", "Cookie", "Set-Cookie", "Proxy-Authorization"
+    };
+
+    private static volatile bool _unsafeLogSecrets;
+
+    /// <summary>
+    /// When true, every helper passes values through unchanged. Process-wide, off by default.
+    /// </summary>
+    /// <remarks>
+    /// Logs are process-wide, so this is too: it covers every session in the process and no
+    /// session setting turns it on or off. Only the host sets it.
+    /// </remarks>
+    public static bool UnsafeLogSecrets
+    {
+        get => _unsafeLogSecrets;
+        set => _unsafeLogSecrets = value;
+    }
+
+    /// <summary>
+    /// Redacts a secret string (access token, client token, password).
+    /// </summary>
+    public static string Secret(string? value)
+    {
+        if (value is null) return "<null>";
+        return _unsafeLogSecrets ? value : $"{Placeholder}({value.Length} chars)";
+    }
+
+    /// <summary>
+    /// Redacts raw key material or credential blobs.
+    /// </summary>
+    public static string Bytes(ReadOnlySpan<byte> value)
+        => _unsafeLogSecrets ? Convert.ToHexString(value) : $"{Placeholder}({value.Length} bytes)";
+
+    /// <summary>
+    /// Redacts the values of sensitive query parameters in a URL, keeping scheme, host,
+    /// path and non-sensitive parameters intact.
+    /// </summary>
+    public static string Url(string? url)
+    {
+        if (string.IsNullOrEmpty(url) || _unsafeLogSecrets) return url ?? string.Empty;
+
+        var queryIndex = url.IndexOf('?');
+        if (queryIndex < 0) return url;
+
+        var sb = new StringBuilder(url.Length);
+        sb.Append(url, 0, queryIndex + 1);
+
+        var query = url.AsSpan(queryIndex + 1);
+        var first = true;
+        foreach (var range in query.Split('&'))
+        {
+            var pair = query[range];
+            if (!first) sb.Append('&');
+            first = false;
+
+            var eq = pair.IndexOf('=');
+            var name = eq >= 0 ? pair[..eq] : pair;
+            if (eq >= 0 && IsSensitiveQueryParameter(name))
+            {
+                sb.Append(name).Append('=').Append(Placeholder);
+            }
+            else
+            {
+                sb.Append(pair);
+            }
+        }
+
+        return sb.ToString();
+    }
+
+    /// <summary>
+    /// Returns a copy of <paramref name="headers"/> with credential-bearing headers redacted.
+    /// </summary>
+    public static IReadOnlyDictionary<string, string>? Headers(IReadOnlyDictionary<string, string>? headers)
+    {
+        if (headers is null || _unsafeLogSecrets) return headers;
+
+        Dictionary<string, string>? copy = null;
+        foreach (var (name, value) in headers)
+        {
+            if (!SensitiveHeaders.Contains(name)) continue;
+            copy ??= new Dictionary<string, string>(headers, StringComparer.OrdinalIgnoreCase);
+            copy[name] = Secret(value);
+        }
+
+        return copy ?? headers;
+    }
+
+    private static bool IsSensitiveQueryParameter(ReadOnlySpan<char> name)
+    {
+        foreach (var candidate in SensitiveQueryParameters)
+        {
+            if (name.Equals(candidate, StringComparison.OrdinalIgnoreCase))
+                return true;
+        }
+        return false;
+    }
+}
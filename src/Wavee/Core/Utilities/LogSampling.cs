@@ -0,0 +1,37 @@
+using Wavee.Core.Session;
+
+namespace Wavee.Core.Utilities;
+
+/// <summary>
+/// Samplers for debug logs that fire many times per track, built from one <see cref="SamplingConfig"/>.
+/// </summary>
+/// <remarks>
+/// Every <see cref="Wavee.Core.Session.Session"/> owns one, built from its <see cref="SessionConfig.Sampling"/>;
+/// components without a session reference (the audio cache) take one through their
+/// constructor. Sessions in the same process keep their own limits.
+/// </remarks>
+public sealed class LogSampling
+{
+    /// <summary>
+    /// Creates samplers using the intervals from <paramref name="config"/>.
+    /// </summary>
+    /// <param name="config">Sampling intervals.</param>
+    /// <param name="timeProvider">Clock; defaults to <see cref="TimeProvider.System"/>.</param>
+    public LogSampling(SamplingConfig config, TimeProvider? timeProvider = null)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        Position = new EventSampler(config.PositionLogInterval, timeProvider);
+        AudioChunks = new EventSampler(config.AudioChunkLogInterval, timeProvider);
+    }
+
+    /// <summary>
+    /// Gets the sampler for playback position logs.
+    /// </summary>
+    public EventSampler Position { get; }
+
+    /// <summary>
+    /// Gets the sampler for per-chunk audio cache logs.
+    /// </summary>
+    public EventSampler AudioChunks { get; }
+}
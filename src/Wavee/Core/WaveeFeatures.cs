@@ -0,0 +1,37 @@
+namespace Wavee.Core;
+
+/// <summary>
+/// Which optional parts of the library this build contains, for consumers that ship
+/// against more than one build profile.
+/// </summary>
+/// <remarks>
+/// <list type="table">
+///   <listheader><term>Feature</term><description>MSBuild switch</description></listheader>
+///   <item><term><see cref="Playback"/></term><description>on unless <c>-p:WaveeProtocolOnly=true</c></description></item>
+///   <item><term><see cref="Keyring"/></term><description><c>-p:WaveeEnableKeyring=true</c></description></item>
+/// </list>
+/// Session, AP crypto, Mercury, dealer/Connect state, SpClient, Pathfinder, Login5,
+/// storage and audio keys are always present.
+/// </remarks>
+public static class WaveeFeatures
+{
+    /// <summary>
+    /// Playback orchestration, the AudioHost IPC client, CDN download, the audio cache
+    /// and video (<c>Wavee.Audio</c>, <c>Wavee.AudioIpc</c>, <c>Wavee.Core.Audio.Download</c>,
+    /// <c>Wavee.Core.Video</c>). Absent from the protocol-only profile.
+    /// </summary>
+#if WAVEE_PROTOCOL_ONLY
+    public const bool Playback = false;
+#else
+    public const bool Playback = true;
+#endif
+
+    /// <summary>
+    /// OS keyring secret store (Windows Credential Manager).
+    /// </summary>
+#if WAVEE_KEYRING
+    public const bool Keyring = true;
+#else
+    public const bool Keyring = false;
+#endif
+}
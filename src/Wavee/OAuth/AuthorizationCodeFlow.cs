@@ -4,6 +4,7 @@ using System.Security.Cryptography;
 using System.Text;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
+using Wavee.Core.Crypto;
 
 namespace Wavee.OAuth;
 
@@ -185,7 +186,7 @@ internal sealed class AuthorizationCodeFlow : IDisposable
             var returnedState = queryParams.TryGetValue("state", out var stateValue) ? stateValue : null;
 
             // Validate state parameter (CSRF protection)
-            if (returnedState != _expectedState)
+            if (!ConstantTime.StringsEqual(returnedState, _expectedState))
             {
                 _logger?.LogError("State parameter mismatch - possible CSRF attack");
                 await SendBrowserResponseAsync(response, false);
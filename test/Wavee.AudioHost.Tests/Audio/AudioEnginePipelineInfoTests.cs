@@ -0,0 +1,133 @@
+using FluentAssertions;
+using Wavee.AudioHost.Audio;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Decoders;
+using Wavee.AudioHost.Audio.Processors;
+using Wavee.AudioHost.Tests.Helpers;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Tests.Audio;
+
+/// <summary>
+/// Tests for AudioEngine.GetPipelineInfoAsync - validates that every stage reports the
+/// format it actually emits and that no stage is made up.
+///
+/// WHY: The pipeline view is what format negotiation and buffering problems are
+/// diagnosed from. Bugs here will cause:
+/// - A sink that opened the device at another rate or depth looking like it matches the decoder
+/// - Buffer sizes computed with the wrong bytes-per-second
+/// - A decryptor listed for files that are never decrypted
+/// </summary>
+public sealed class AudioEnginePipelineInfoTests : IAsyncDisposable
+{
+    private static readonly AudioFormat DecoderFormat = AudioFormat.CdQuality;
+    private static readonly AudioFormat DeviceFormat = new(48_000, 2, 32);
+
+    private readonly HoldingSink _sink = new(DeviceFormat);
+    private readonly VolumeProcessor _volume = new();
+    private readonly AudioEngine _engine;
+    private readonly string _file = Path.Combine(Path.GetTempPath(), $"wavee-pipeline-{Guid.NewGuid():N}.pcm");
+
+    public AudioEnginePipelineInfoTests()
+    {
+        var registry = new AudioDecoderRegistry();
+        registry.Register(new PcmSourceDecoder(DecoderFormat), "pcm");
+        var chain = new AudioProcessingChain();
+        chain.AddProcessor(_volume);
+        _engine = new AudioEngine(_sink, registry, chain, new HttpClient());
+    }
+
+    [Fact]
+    public async Task GetPipelineInfoAsync_WhenIdle_ShouldReportNoStages()
+    {
+        // Act
+        var info = await _engine.GetPipelineInfoAsync();
+
+        // Assert
+        info.Stages.Should().BeEmpty();
+        info.BufferPools.Should().NotBeEmpty();
+    }
+
+    [Fact]
+    public async Task GetPipelineInfoAsync_LocalFile_ShouldReportEachStagesOwnFormat()
+    {
+        // Arrange
+        await File.WriteAllBytesAsync(_file, TestSignals.Tones(DecoderFormat, TimeSpan.FromSeconds(5), 0.5, 440));
+        await _engine.PlayAsync(new PlayLocalFileCommand { TrackUri = "wavee:local:track:a", FilePath = _file });
+        await _sink.FirstWrite.WaitAsync(TimeSpan.FromSeconds(10));
+
+        // Act
+        var info = await _engine.GetPipelineInfoAsync();
+
+        // Assert
+        info.TrackUri.Should().Be("wavee:local:track:a");
+        info.Stages.Select(s => s.Kind).Should().Equal("fetcher", "decoder", "processor", "sink");
+
+        var fetcher = info.Stages[0];
+        fetcher.Name.Should().Be("LocalFile");
+        fetcher.Format.Should().BeNull();
+
+        info.Stages[1].Format.Should().BeEquivalentTo(new PcmFormatInfo { SampleRate = 44_100, Channels = 2, BitsPerSample = 16 });
+
+        var processor = info.Stages[2];
+        processor.Name.Should().Be(_volume.ProcessorName);
+        processor.Format.Should().BeEquivalentTo(new PcmFormatInfo { SampleRate = 44_100, Channels = 2, BitsPerSample = 16 });
+
+        var sink = info.Stages[3];
+        sink.Format.Should().BeEquivalentTo(new PcmFormatInfo { SampleRate = 48_000, Channels = 2, BitsPerSample = 32 });
+        sink.BufferedMs.Should().Be(HoldingSink.BufferedMs);
+        sink.BufferedBytes.Should().Be(HoldingSink.BufferedMs * (long)DeviceFormat.BytesPerSecond / 1000);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _engine.DisposeAsync();
+        File.Delete(_file);
+    }
+
+    /// <summary>
+    /// Sink that reports a device format of its own and holds the first write, so the
+    /// track stays loaded while the pipeline is inspected.
+    /// </summary>
+    private sealed class HoldingSink(AudioFormat deviceFormat) : IAudioSink
+    {
+        public const int BufferedMs = 500;
+
+        private readonly TaskCompletionSource _firstWrite = new(TaskCreationOptions.RunContinuationsAsynchronously);
+        private AudioFormat? _format;
+
+        public Task FirstWrite => _firstWrite.Task;
+
+        public string SinkName => "Holding";
+
+        public AudioFormat? Format => _format;
+
+        public long PlaybackPositionMs => 0;
+
+        public Task InitializeAsync(AudioFormat format, int bufferSizeMs = 100, CancellationToken cancellationToken = default)
+        {
+            _format = deviceFormat;
+            return Task.CompletedTask;
+        }
+
+        public async Task WriteAsync(ReadOnlyMemory<byte> audioData, CancellationToken cancellationToken = default)
+        {
+            _firstWrite.TrySetResult();
+            await Task.Delay(Timeout.Infinite, cancellationToken);
+        }
+
+        public Task<AudioSinkStatus> GetStatusAsync() => Task.FromResult(new AudioSinkStatus(0, BufferedMs, true));
+
+        public Task PauseAsync() => Task.CompletedTask;
+
+        public Task<bool> ResumeAsync() => Task.FromResult(true);
+
+        public Task FlushAsync() => Task.CompletedTask;
+
+        public void SetBasePosition(long positionMs) { }
+
+        public Task DrainAsync(CancellationToken cancellationToken = default) => Task.CompletedTask;
+
+        public ValueTask DisposeAsync() => ValueTask.CompletedTask;
+    }
+}
@@ -0,0 +1,107 @@
+using System.Security.Cryptography;
+using FluentAssertions;
+using Wavee.AudioHost.Audio;
+using Wavee.AudioHost.Audio.Decoders;
+using Wavee.AudioHost.Audio.Streaming;
+using Wavee.AudioHost.Tests.Helpers;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Tests.Audio.Decoders;
+
+/// <summary>
+/// Golden-file tests for the decrypt + decode pipeline - validates that encrypted fixtures in
+/// <c>Fixtures/Golden</c> decrypt and decode to exactly the PCM recorded in golden.json.
+///
+/// WHY: Playback output depends on AES backends, NVorbis and float-to-PCM conversion, all of
+/// which differ per CPU and version. Bugs here will cause:
+/// - Static or silence on one architecture only (keystream or endianness drift)
+/// - Subtly different audio after an NVorbis update going unnoticed
+/// - Normalization gain read from the wrong bytes
+/// </summary>
+public class GoldenDecodeTests
+{
+    public static TheoryData<string> Fixtures => GoldenFixtures.Names;
+
+    [Theory]
+    [MemberData(nameof(Fixtures))]
+    public void Decrypt_EverySupportedAesBackend_ShouldMatchPlaintextChecksum(string file)
+    {
+        // Arrange
+        var fixture = GoldenFixtures.Get(file);
+        var encrypted = GoldenFixtures.ReadEncrypted(fixture);
+        var backends = Enum.GetValues<AesBackend>().Where(AesCtrKeystream.IsSupported).ToList();
+
+        foreach (var backend in backends)
+        {
+            // Act
+            var data = encrypted.ToArray();
+            using (var keystream = new AesCtrKeystream(Convert.FromHexString(fixture.Key), backend))
+                keystream.Apply(data, 0);
+
+            // Assert
+            Convert.ToHexStringLower(SHA256.HashData(data)).Should().Be(fixture.PlainSha256, $"{backend} must decrypt {file}");
+        }
+    }
+
+    [Theory]
+    [MemberData(nameof(Fixtures))]
+    public void Decrypt_ThroughDecryptStream_ShouldExposeNormalizationData(string file)
+    {
+        // Arrange
+        var fixture = GoldenFixtures.Get(file);
+        using var stream = OpenDecrypted(fixture);
+        var header = new byte[NormalizationData.FileOffset + NormalizationData.Size];
+
+        // Act
+        stream.ReadExactly(header);
+        var normalization = NormalizationData.Parse(header.AsSpan(NormalizationData.FileOffset));
+
+        // Assert
+        var expected = fixture.Normalization;
+        normalization.Should().Be(new NormalizationData(
+            expected.TrackGainDb, expected.TrackPeak, expected.AlbumGainDb, expected.AlbumPeak));
+    }
+
+    [Theory]
+    [MemberData(nameof(Fixtures))]
+    public async Task Decode_ShouldProduceGoldenPcm(string file)
+    {
+        // Arrange
+        var fixture = GoldenFixtures.Get(file);
+        await using var stream = OpenDecrypted(fixture);
+        var decoder = new VorbisDecoder();
+        using var hash = IncrementalHash.CreateHash(HashAlgorithmName.SHA256);
+        long bytes = 0;
+
+        // Act
+        var format = await decoder.GetFormatAsync(stream);
+        await foreach (var buffer in decoder.DecodeAsync(stream))
+        {
+            hash.AppendData(buffer.Data.Span);
+            bytes += buffer.Data.Length;
+            buffer.Return();
+        }
+        var pcmSha256 = Convert.ToHexStringLower(hash.GetHashAndReset());
+
+        // Assert
+        format.SampleRate.Should().Be(fixture.SampleRate);
+        format.Channels.Should().Be(fixture.Channels);
+        format.BitsPerSample.Should().Be(16);
+        (bytes / format.BytesPerFrame).Should().Be(fixture.Frames, "output is trimmed to the last granule position");
+
+        if (pcmSha256 != fixture.PcmSha256 && GoldenFixtures.IsUpdating)
+        {
+            GoldenFixtures.Record(file, pcmSha256);
+            return;
+        }
+
+        if (fixture.PcmSha256 is null)
+            Assert.Skip($"No golden PCM checksum recorded for {file}; run with {GoldenFixtures.UpdateVariable}=1 to record {pcmSha256}");
+
+        pcmSha256.Should().Be(fixture.PcmSha256,
+            $"decoded PCM for {file} drifted; if the change is intended, re-record with {GoldenFixtures.UpdateVariable}=1");
+    }
+
+    private static AudioDecryptStream OpenDecrypted(GoldenFixture fixture)
+        => new(Convert.FromHexString(fixture.Key), new MemoryStream(GoldenFixtures.ReadEncrypted(fixture), writable: false));
+}
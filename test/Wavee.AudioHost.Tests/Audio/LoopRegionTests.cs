@@ -0,0 +1,202 @@
+using FluentAssertions;
+using Wavee.AudioHost.Audio;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Tests.Audio;
+
+/// <summary>
+/// Tests for LoopRegion - validates that the buffer crossing the loop end is cut at the
+/// end's exact frame, that the sink position is mapped back into the loop, and that a
+/// loop never outlives its track.
+///
+/// WHY: The A-B loop splices audio without flushing the sink. Bugs here will cause:
+/// - Clicks or a few ms of the track past B on every pass (off-by-some-frames cut)
+/// - Position running past the loop end in the UI and on remote Connect devices
+/// - A loop from the previous track firing in the next one
+/// </summary>
+public class LoopRegionTests
+{
+    private const string Track = "spotify:track:a";
+    private static readonly AudioFormat Format = AudioFormat.CdQuality;
+
+    private static int Bytes(int frames) => frames * Format.BytesPerFrame;
+
+    [Fact]
+    public void Clip_BufferCrossingLoopEnd_ShouldCutAtEndFrame()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000).Should().BeTrue();
+        loop.Clip(Bytes(88_100), out _); // 100 frames short of 2000 ms
+
+        // Act
+        var written = loop.Clip(Bytes(1024), out var loopStartMs);
+
+        // Assert
+        written.Should().Be(Bytes(100));
+        loopStartMs.Should().Be(1000);
+        loop.HasSpliced.Should().BeTrue();
+    }
+
+    [Fact]
+    public void Clip_BufferEndingOnLoopEnd_ShouldLoopAfterWholeBuffer()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 0, endMs: 1000);
+
+        // Act
+        var written = loop.Clip(Bytes(44_100), out var loopStartMs);
+
+        // Assert
+        written.Should().Be(Bytes(44_100));
+        loopStartMs.Should().Be(0);
+    }
+
+    [Fact]
+    public void Clip_AfterLoopBack_ShouldCountFromLoopStart()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+        loop.Clip(Bytes(88_200), out _);
+
+        // Act — one loop length from the start reaches the end again
+        var written = loop.Clip(Bytes(50_000), out var loopStartMs);
+
+        // Assert
+        written.Should().Be(Bytes(44_100));
+        loopStartMs.Should().Be(1000);
+    }
+
+    [Fact]
+    public void Clip_WithoutLoop_ShouldWriteEverything()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+
+        // Act
+        var written = loop.Clip(Bytes(100_000), out var loopStartMs);
+
+        // Assert
+        written.Should().Be(Bytes(100_000));
+        loopStartMs.Should().BeNull();
+    }
+
+    [Fact]
+    public void Clip_DecodedPastLoopEnd_ShouldNotLoop()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 3000);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+
+        // Act
+        var written = loop.Clip(Bytes(1024), out var loopStartMs);
+
+        // Assert
+        written.Should().Be(Bytes(1024));
+        loopStartMs.Should().BeNull();
+        loop.IsDecodedPast(2000).Should().BeTrue();
+    }
+
+    [Theory]
+    [InlineData(1500, 1500)]
+    [InlineData(2000, 1000)]
+    [InlineData(2250, 1250)]
+    [InlineData(3100, 1100)]
+    public void MapPosition_AfterSplice_ShouldWrapIntoLoop(long sinkMs, long expectedMs)
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+        loop.Clip(Bytes(100_000), out _);
+
+        // Act
+        var heard = loop.MapPosition(sinkMs);
+
+        // Assert
+        heard.Should().Be(expectedMs);
+    }
+
+    [Fact]
+    public void MapPosition_BeforeSplice_ShouldPassThrough()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+
+        // Act & Assert
+        loop.MapPosition(2500).Should().Be(2500);
+    }
+
+    [Fact]
+    public void Start_OtherTrack_ShouldDropLoop()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+
+        // Act
+        loop.Start("spotify:track:b", Format, positionMs: 0);
+
+        // Assert
+        loop.Current.Should().BeNull();
+    }
+
+    [Fact]
+    public void Start_SameTrack_ShouldKeepLoop()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Set(Track, startMs: 1000, endMs: 2000);
+
+        // Act — pipeline restart after a stall
+        loop.Start(Track, Format, positionMs: 1500);
+
+        // Assert
+        loop.Current.Should().Be((1000L, 2000L));
+    }
+
+    [Theory]
+    [InlineData(Track, -1, 1000)]
+    [InlineData(Track, 2000, 2000)]
+    [InlineData(Track, 2000, 1000)]
+    [InlineData("spotify:track:b", 1000, 2000)]
+    public void Set_InvalidRegion_ShouldBeRejected(string trackUri, long startMs, long endMs)
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+
+        // Act
+        var accepted = loop.Set(trackUri, startMs, endMs);
+
+        // Assert
+        accepted.Should().BeFalse();
+        loop.Current.Should().BeNull();
+    }
+
+    [Fact]
+    public void Reset_ShouldRefuseLoopsUntilNextStart()
+    {
+        // Arrange
+        var loop = new LoopRegion();
+        loop.Start(Track, Format, positionMs: 0);
+        loop.Reset();
+
+        // Act
+        var accepted = loop.Set(Track, 1000, 2000);
+
+        // Assert
+        accepted.Should().BeFalse();
+    }
+}
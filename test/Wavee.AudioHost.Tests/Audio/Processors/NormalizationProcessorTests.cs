@@ -0,0 +1,42 @@
+using FluentAssertions;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Processors;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Tests.Audio.Processors;
+
+/// <summary>
+/// Tests for NormalizationProcessor - validates that dynamic normalization keeps limiting
+/// when the track needs no gain.
+///
+/// WHY: Tracks mastered at the target loudness get unity gain. Bugs here will cause:
+/// - Hot masters clipping through a limiter the user turned on
+/// - Dynamic and basic normalization sounding the same on loud tracks
+/// </summary>
+public class NormalizationProcessorTests
+{
+    private static readonly AudioFormat Format = AudioFormat.CdQuality;
+
+    [Fact]
+    public async Task ProcessInPlace_DynamicAtUnityGain_ShouldStillLimit()
+    {
+        // Arrange
+        var processor = new NormalizationProcessor { IsEnabled = true, Method = NormalizationMethod.Dynamic };
+        processor.Limiter.CeilingDb = -6f;
+        processor.Limiter.AttackMs = 0f;
+        await processor.InitializeAsync(Format);
+
+        var pcm = new byte[Format.BytesPerFrame * 64];
+        for (var i = 0; i < pcm.Length; i += 2)
+            BitConverter.TryWriteBytes(pcm.AsSpan(i), (short)30_000);
+
+        // Act
+        processor.ProcessInPlace(pcm);
+
+        // Assert
+        processor.CurrentGain.Should().Be(1.0f);
+        var ceiling = 32768 * MathF.Pow(10f, -6f / 20f);
+        for (var i = 0; i < pcm.Length; i += 2)
+            BitConverter.ToInt16(pcm, i).Should().BeLessThanOrEqualTo((short)Math.Ceiling(ceiling));
+    }
+}
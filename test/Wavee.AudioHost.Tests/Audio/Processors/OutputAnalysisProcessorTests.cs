@@ -0,0 +1,142 @@
+using System.Buffers.Binary;
+using FluentAssertions;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Processors;
+using Wavee.AudioHost.Tests.Helpers;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Tests.Audio.Processors;
+
+/// <summary>
+/// Tests for OutputAnalysisProcessor - validates that sustained digital silence and
+/// clipped output are reported once when they start and once when they clear, and that
+/// short pauses and single full-scale peaks are not.
+///
+/// WHY: These reports are how users tell a bad source or gain setting from a network
+/// or device problem. Bugs here will cause:
+/// - False alarms on quiet intros and loud, clean masters
+/// - Real clipping from normalization or EQ boosts going unnoticed
+/// - A flood of events for one long silence
+/// </summary>
+public class OutputAnalysisProcessorTests
+{
+    private static readonly AudioFormat Format = AudioFormat.CdQuality;
+
+    private static async Task<(OutputAnalysisProcessor Analysis, List<OutputDiagnostic> Reports)> CreateAsync()
+    {
+        var analysis = new OutputAnalysisProcessor { IsEnabled = true };
+        await analysis.InitializeAsync(Format);
+        var reports = new List<OutputDiagnostic>();
+        analysis.Diagnostics.Subscribe(reports.Add);
+        return (analysis, reports);
+    }
+
+    private static byte[] Silence(TimeSpan duration)
+        => new byte[(int)(Format.SampleRate * duration.TotalSeconds) * Format.BytesPerFrame];
+
+    // 100 Hz sine driven 6 dB past full scale and hard-clipped.
+    private static byte[] Clipped(TimeSpan duration)
+    {
+        var frames = (int)(Format.SampleRate * duration.TotalSeconds);
+        var pcm = new byte[frames * Format.BytesPerFrame];
+        for (var frame = 0; frame < frames; frame++)
+        {
+            var value = Math.Clamp(2 * Math.Sin(2 * Math.PI * 100 * frame / Format.SampleRate), -1, 1);
+            var sample = (short)Math.Round(value * short.MaxValue);
+            for (var channel = 0; channel < Format.Channels; channel++)
+                BinaryPrimitives.WriteInt16LittleEndian(pcm.AsSpan((frame * Format.Channels + channel) * 2), sample);
+        }
+
+        return pcm;
+    }
+
+    [Fact]
+    public void IsEnabled_ShouldDefaultToOff()
+    {
+        // Arrange & Act
+        var analysis = new OutputAnalysisProcessor();
+
+        // Assert
+        analysis.IsEnabled.Should().BeFalse();
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_SustainedSilence_ShouldReportStartAndEnd()
+    {
+        // Arrange
+        var (analysis, reports) = await CreateAsync();
+
+        // Act
+        analysis.ProcessInPlace(Silence(TimeSpan.FromSeconds(4)));
+        analysis.ProcessInPlace(TestSignals.Tones(Format, TimeSpan.FromMilliseconds(500), peak: 0.5, 440));
+
+        // Assert
+        reports.Should().HaveCount(2);
+        reports[0].Should().Be(new OutputDiagnostic(OutputDiagnosticKind.Silence, 0, 3000, Ended: false, 0));
+        reports[1].Should().Be(new OutputDiagnostic(OutputDiagnosticKind.Silence, 0, 4000, Ended: true, 0));
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_ShortSilence_ShouldNotReport()
+    {
+        // Arrange
+        var (analysis, reports) = await CreateAsync();
+
+        // Act
+        analysis.ProcessInPlace(TestSignals.Tones(Format, TimeSpan.FromSeconds(1), peak: 0.5, 440));
+        analysis.ProcessInPlace(Silence(TimeSpan.FromSeconds(2)));
+        analysis.ProcessInPlace(TestSignals.Tones(Format, TimeSpan.FromSeconds(1), peak: 0.5, 440));
+
+        // Assert
+        reports.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_ClippedAudio_ShouldReportStartAndEnd()
+    {
+        // Arrange
+        var (analysis, reports) = await CreateAsync();
+
+        // Act
+        analysis.ProcessInPlace(Clipped(TimeSpan.FromSeconds(2)));
+        analysis.ProcessInPlace(TestSignals.Tones(Format, TimeSpan.FromSeconds(2), peak: 0.5, 440));
+
+        // Assert
+        reports.Should().HaveCount(2);
+        reports[0].Kind.Should().Be(OutputDiagnosticKind.Clipping);
+        reports[0].Ended.Should().BeFalse();
+        reports[0].PositionMs.Should().Be(0);
+        reports[1].Ended.Should().BeTrue();
+        reports[1].DurationMs.Should().Be(2000);
+        reports[1].ClippedRuns.Should().BeGreaterThan(reports[0].ClippedRuns);
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_FullScalePeaks_ShouldNotReportClipping()
+    {
+        // Arrange — a clean sine touching full scale once per half-cycle
+        var (analysis, reports) = await CreateAsync();
+
+        // Act
+        analysis.ProcessInPlace(TestSignals.Tones(Format, TimeSpan.FromSeconds(3), peak: 1.0, 440));
+
+        // Assert
+        reports.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task Rebase_DuringSilence_ShouldEndReportAtOldPosition()
+    {
+        // Arrange
+        var (analysis, reports) = await CreateAsync();
+        analysis.Rebase(10_000);
+        analysis.ProcessInPlace(Silence(TimeSpan.FromSeconds(5)));
+
+        // Act — seek
+        analysis.Rebase(60_000);
+
+        // Assert
+        reports.Should().HaveCount(2);
+        reports[1].Should().Be(new OutputDiagnostic(OutputDiagnosticKind.Silence, 10_000, 5000, Ended: true, 0));
+    }
+}
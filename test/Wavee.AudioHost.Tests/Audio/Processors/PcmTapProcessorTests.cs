@@ -0,0 +1,150 @@
+using FluentAssertions;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Processors;
+using Wavee.AudioHost.Tests.Helpers;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.AudioHost.Tests.Audio.Processors;
+
+/// <summary>
+/// Tests for PcmTapProcessor - validates that the visualization tap publishes frames at
+/// the configured rate and size, that spectrum frames put a tone in the right band, and
+/// that the audio passes through untouched.
+///
+/// WHY: The tap runs on the playback thread for every buffer. Bugs here will cause:
+/// - Audible changes from a component that must be read-only
+/// - Visualizers flooding the IPC pipe, or starving, at the wrong frame rate
+/// - Spectrum displays that don't follow the music
+/// </summary>
+public class PcmTapProcessorTests
+{
+    private static readonly AudioFormat Format = AudioFormat.CdQuality;
+
+    private static async Task<(PcmTapProcessor Tap, List<PcmTapFrame> Frames)> CreateAsync(
+        PcmTapMode mode, int rateHz, int size)
+    {
+        var tap = new PcmTapProcessor();
+        await tap.InitializeAsync(Format);
+        tap.Configure(mode, rateHz, size);
+        var frames = new List<PcmTapFrame>();
+        tap.Frames.Subscribe(frames.Add);
+        return (tap, frames);
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_OneSecond_ShouldPublishAtConfiguredRate()
+    {
+        // Arrange
+        var (tap, frames) = await CreateAsync(PcmTapMode.Waveform, rateHz: 25, size: 32);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromSeconds(1), peak: 0.5, 440);
+
+        // Act — odd-sized buffers, as decoders deliver them
+        for (var offset = 0; offset < pcm.Length; offset += 4 * 1111)
+            tap.ProcessInPlace(pcm.AsSpan(offset, Math.Min(4 * 1111, pcm.Length - offset)));
+
+        // Assert
+        frames.Should().HaveCount(25);
+        frames.Should().OnlyContain(f => f.Values.Length == 32 && f.Mode == PcmTapMode.Waveform);
+        frames.Select(f => f.Sequence).Should().BeInAscendingOrder();
+        frames[^1].PositionMs.Should().Be(1000);
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_Waveform_ShouldFollowSignalPeak()
+    {
+        // Arrange
+        var (tap, frames) = await CreateAsync(PcmTapMode.Waveform, rateHz: 10, size: 16);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromMilliseconds(100), peak: 0.5, 440);
+
+        // Act
+        tap.ProcessInPlace(pcm);
+
+        // Assert
+        frames.Should().ContainSingle();
+        frames[0].Values.Max(v => Math.Abs(v)).Should().BeApproximately(0.5f, 0.01f);
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_Spectrum_ShouldPeakAtToneBand()
+    {
+        // Arrange
+        var (tap, frames) = await CreateAsync(PcmTapMode.Spectrum, rateHz: 10, size: 32);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromMilliseconds(100), peak: 0.9, 1000);
+
+        // Act
+        tap.ProcessInPlace(pcm);
+
+        // Assert
+        var values = frames.Should().ContainSingle().Which.Values;
+        values.Max().Should().BeGreaterThan(0.9f, "a 0.9 FS tone is about -1 dBFS");
+        values[0].Should().BeLessThan(0.5f, "the 20 Hz band is far from the tone");
+        values[^1].Should().BeLessThan(0.5f, "the top band is far from the tone");
+
+        var toneBand = Array.IndexOf(values, values.Max());
+        toneBand.Should().BeInRange(BandFor(1000) - 1, BandFor(1000) + 1);
+
+        // Bands are log-spaced from 20 Hz to 20 kHz.
+        static int BandFor(double hz) => (int)(Math.Log(hz / 20) / Math.Log(1000) * 32);
+    }
+
+    [Fact]
+    public async Task ProcessInPlace_ShouldNotModifyAudio()
+    {
+        // Arrange
+        var (tap, _) = await CreateAsync(PcmTapMode.Spectrum, rateHz: 60, size: 64);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromMilliseconds(200), peak: 0.8, 220, 5000);
+        var original = pcm.ToArray();
+
+        // Act
+        tap.ProcessInPlace(pcm);
+
+        // Assert
+        pcm.Should().Equal(original);
+    }
+
+    [Fact]
+    public async Task Configure_Off_ShouldDisableAndPublishNothing()
+    {
+        // Arrange
+        var (tap, frames) = await CreateAsync(PcmTapMode.Waveform, rateHz: 30, size: 16);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromMilliseconds(500), peak: 0.5, 440);
+
+        // Act
+        tap.Configure(PcmTapMode.Off, 30, 16);
+        tap.ProcessInPlace(pcm);
+
+        // Assert
+        tap.IsEnabled.Should().BeFalse();
+        frames.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task Rebase_ShouldStampFramesFromNewPosition()
+    {
+        // Arrange
+        var (tap, frames) = await CreateAsync(PcmTapMode.Waveform, rateHz: 10, size: 8);
+        var pcm = TestSignals.Tones(Format, TimeSpan.FromMilliseconds(100), peak: 0.5, 440);
+
+        // Act
+        tap.Rebase(60_000);
+        tap.ProcessInPlace(pcm);
+
+        // Assert
+        frames.Should().ContainSingle().Which.PositionMs.Should().Be(60_100);
+    }
+
+    [Fact]
+    public void Configure_OutOfRange_ShouldClamp()
+    {
+        // Arrange
+        var tap = new PcmTapProcessor();
+
+        // Act
+        tap.Configure(PcmTapMode.Spectrum, rateHz: 1000, size: 0);
+
+        // Assert
+        tap.RateHz.Should().Be(60);
+        tap.Size.Should().Be(1);
+        tap.IsEnabled.Should().BeTrue();
+    }
+}
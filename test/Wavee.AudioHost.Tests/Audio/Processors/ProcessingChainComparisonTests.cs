@@ -0,0 +1,131 @@
+using FluentAssertions;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Processors;
+using Wavee.AudioHost.Tests.Helpers;
+
+namespace Wavee.AudioHost.Tests.Audio.Processors;
+
+/// <summary>
+/// Tests for the A/B pipeline comparison harness - validates that decoded output from two
+/// pipeline configurations is compared correctly, and that the processing chain's zero-copy
+/// path matches the per-processor path it replaced.
+///
+/// WHY: The harness is the safety net for decoder and processor refactors. Bugs here will cause:
+/// - Audible regressions (gain changes, dropped tails) passing as "identical"
+/// - Refactors blocked by false mismatches on rounding-level noise
+/// - The in-place chain silently drifting from the crossfade fallback path
+/// </summary>
+public class ProcessingChainComparisonTests
+{
+    private static readonly AudioFormat Format = AudioFormat.CdQuality;
+
+    private static readonly byte[] Signal =
+        TestSignals.Tones(Format, TimeSpan.FromSeconds(2), peak: 0.9, 220, 1000, 5000);
+
+    private static Stream OpenSignal() => new MemoryStream(Signal, writable: false);
+
+    private static IAudioDecoder CreateDecoder() => new PcmSourceDecoder(Format);
+
+    [Fact]
+    public async Task RunAsync_SamePipeline_ShouldBeBitExact()
+    {
+        // Arrange
+        var a = PipelineVariant.DecodeOnly("decode", CreateDecoder);
+        var b = PipelineVariant.DecodeOnly("decode (odd chunks)", () => new PcmSourceDecoder(Format, chunkFrames: 777));
+
+        // Act
+        var result = await PipelineComparison.RunAsync(OpenSignal, a, b);
+
+        // Assert
+        result.IsWithin(PcmTolerance.BitExact, out var reason).Should().BeTrue(reason + "\n" + result.Describe());
+        result.FramesA.Should().Be(Signal.Length / Format.BytesPerFrame);
+        result.SnrDb.Should().Be(double.PositiveInfinity);
+        result.FirstDifferentFrame.Should().BeNull();
+    }
+
+    [Fact]
+    public async Task RunAsync_ChainInPlaceVsProcessorFallback_ShouldBeBitExact()
+    {
+        // Arrange
+        static IEnumerable<IAudioProcessor> CreateProcessors() =>
+        [
+            new VolumeProcessor { Volume = 0.5f },
+            new LimiterProcessor { IsEnabled = true, CeilingDb = -6f }
+        ];
+
+        var inPlace = PipelineVariant.WithChain("chain (in-place)", CreateDecoder, chain =>
+        {
+            foreach (var processor in CreateProcessors())
+                chain.AddProcessor(processor);
+        });
+        var fallback = PipelineVariant.WithProcessors("per-processor Process()", CreateDecoder, CreateProcessors);
+
+        // Act
+        var result = await PipelineComparison.RunAsync(OpenSignal, inPlace, fallback);
+
+        // Assert
+        result.IsWithin(PcmTolerance.BitExact, out var reason).Should().BeTrue(reason + "\n" + result.Describe());
+    }
+
+    [Fact]
+    public async Task RunAsync_SmallGainChange_ShouldFailTransparentTolerance()
+    {
+        // Arrange
+        var reference = PipelineVariant.DecodeOnly("reference", CreateDecoder);
+        var attenuated = PipelineVariant.WithChain("volume 0.99", CreateDecoder,
+            chain => chain.AddProcessor(new VolumeProcessor { Volume = 0.99f }));
+
+        // Act
+        var result = await PipelineComparison.RunAsync(OpenSignal, reference, attenuated);
+
+        // Assert
+        result.IsWithin(PcmTolerance.Transparent, out var reason).Should().BeFalse(result.Describe());
+        reason.Should().NotBeEmpty();
+        result.SnrDb.Should().BeInRange(35, 45, "a 1% gain error leaves a residual ~40 dB down");
+        result.FirstDifferentFrame.Should().NotBeNull();
+    }
+
+    [Fact]
+    public async Task RunAsync_TruncatedOutput_ShouldReportLengthMismatch()
+    {
+        // Arrange
+        var full = PipelineVariant.DecodeOnly("full", CreateDecoder);
+        var truncated = PipelineVariant.DecodeOnly("drops tail", () => new TailDroppingDecoder(CreateDecoder()));
+
+        // Act
+        var result = await PipelineComparison.RunAsync(OpenSignal, full, truncated);
+
+        // Assert
+        result.IsWithin(PcmTolerance.BitExact, out var reason).Should().BeFalse();
+        reason.Should().Contain("lengths differ");
+        result.FramesB.Should().BeLessThan(result.FramesA);
+        result.MaxAbsError.Should().Be(0, "the frames both pipelines produced are identical");
+    }
+
+    /// <summary>
+    /// Drops the decoder's last buffer - the classic end-of-stream regression.
+    /// </summary>
+    private sealed class TailDroppingDecoder(IAudioDecoder inner) : IAudioDecoder
+    {
+        public string FormatName => inner.FormatName;
+        public bool CanDecode(Stream stream) => inner.CanDecode(stream);
+        public Task<AudioFormat> GetFormatAsync(Stream stream, CancellationToken cancellationToken = default)
+            => inner.GetFormatAsync(stream, cancellationToken);
+        public void SeekTo(long positionMs) => inner.SeekTo(positionMs);
+
+        public async IAsyncEnumerable<AudioBuffer> DecodeAsync(
+            Stream stream,
+            long startPositionMs = 0,
+            Action<string>? onMetadataReceived = null,
+            [System.Runtime.CompilerServices.EnumeratorCancellation] CancellationToken cancellationToken = default)
+        {
+            AudioBuffer? pending = null;
+            await foreach (var buffer in inner.DecodeAsync(stream, startPositionMs, onMetadataReceived, cancellationToken))
+            {
+                if (pending is not null)
+                    yield return pending;
+                pending = buffer;
+            }
+        }
+    }
+}
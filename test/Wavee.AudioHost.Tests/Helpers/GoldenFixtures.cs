@@ -0,0 +1,107 @@
+using System.Runtime.CompilerServices;
+using System.Text.Json;
+using System.Text.Json.Nodes;
+using System.Text.Json.Serialization;
+
+namespace Wavee.AudioHost.Tests.Helpers;
+
+/// <summary>
+/// Expected decode results for one encrypted fixture in <c>Fixtures/Golden</c>.
+/// </summary>
+public sealed record GoldenFixture
+{
+    public required string File { get; init; }
+
+    /// <summary>
+    /// AES-128 audio key, hex. Test-only keys, not real Spotify keys.
+    /// </summary>
+    public required string Key { get; init; }
+
+    /// <summary>
+    /// SHA-256 of the decrypted file (Spotify header + Ogg).
+    /// </summary>
+    public required string PlainSha256 { get; init; }
+
+    public required GoldenNormalization Normalization { get; init; }
+    public required int SampleRate { get; init; }
+    public required int Channels { get; init; }
+
+    /// <summary>
+    /// Decoded length, the last Ogg granule position.
+    /// </summary>
+    public required long Frames { get; init; }
+
+    /// <summary>
+    /// SHA-256 of the decoded little-endian 16-bit PCM. Null until recorded.
+    /// </summary>
+    public string? PcmSha256 { get; init; }
+
+    public override string ToString() => File;
+}
+
+public sealed record GoldenNormalization(float TrackGainDb, float TrackPeak, float AlbumGainDb, float AlbumPeak);
+
+/// <summary>
+/// Loads <c>Fixtures/Golden/golden.json</c> and, when <see cref="UpdateVariable"/> is set,
+/// writes newly observed PCM checksums back to the source copy.
+/// </summary>
+public static class GoldenFixtures
+{
+    /// <summary>
+    /// Set to <c>1</c> to record missing or changed PCM checksums instead of failing.
+    /// Review the resulting golden.json diff before committing it.
+    /// </summary>
+    public const string UpdateVariable = "WAVEE_UPDATE_GOLDEN";
+
+    private const string ManifestName = "golden.json";
+
+    private static readonly JsonSerializerOptions JsonOptions = new()
+    {
+        PropertyNamingPolicy = JsonNamingPolicy.CamelCase,
+        DefaultIgnoreCondition = JsonIgnoreCondition.Never,
+        WriteIndented = true
+    };
+
+    private static readonly Lazy<IReadOnlyList<GoldenFixture>> Manifest = new(Load);
+    private static readonly Lock WriteLock = new();
+
+    public static string FixtureDirectory { get; } = Path.Combine(AppContext.BaseDirectory, "Fixtures", "Golden");
+
+    public static bool IsUpdating => Environment.GetEnvironmentVariable(UpdateVariable) == "1";
+
+    public static IReadOnlyList<GoldenFixture> All => Manifest.Value;
+
+    public static TheoryData<string> Names => new(All.Select(f => f.File));
+
+    public static GoldenFixture Get(string file) => All.Single(f => f.File == file);
+
+    public static byte[] ReadEncrypted(GoldenFixture fixture) => File.ReadAllBytes(Path.Combine(FixtureDirectory, fixture.File));
+
+    /// <summary>
+    /// Writes <paramref name="pcmSha256"/> for <paramref name="file"/> into the source tree's golden.json.
+    /// </summary>
+    public static void Record(string file, string pcmSha256)
+    {
+        lock (WriteLock)
+        {
+            var path = SourceManifestPath();
+            var root = JsonNode.Parse(File.ReadAllText(path))!;
+            var entry = root["fixtures"]!.AsArray().Single(n => (string?)n!["file"] == file)!;
+            entry["pcmSha256"] = pcmSha256;
+            File.WriteAllText(path, root.ToJsonString(JsonOptions) + Environment.NewLine);
+        }
+    }
+
+    private static IReadOnlyList<GoldenFixture> Load()
+    {
+        using var stream = File.OpenRead(Path.Combine(FixtureDirectory, ManifestName));
+        var manifest = JsonSerializer.Deserialize<ManifestFile>(stream, JsonOptions)
+            ?? throw new InvalidDataException($"{ManifestName} is empty");
+        return manifest.Fixtures;
+    }
+
+    private static string SourceManifestPath([CallerFilePath] string helperPath = "")
+        => Path.GetFullPath(Path.Combine(Path.GetDirectoryName(helperPath)!, "..", "Fixtures", "Golden", ManifestName));
+
+    private sealed record ManifestFile(List<GoldenFixture> Fixtures);
+}
@@ -0,0 +1,85 @@
+using System.Buffers.Binary;
+using System.Runtime.CompilerServices;
+using Wavee.AudioHost.Audio.Abstractions;
+
+namespace Wavee.AudioHost.Tests.Helpers;
+
+/// <summary>
+/// Test decoder that passes raw little-endian 16-bit PCM through in fixed-size chunks,
+/// so pipelines can be fed synthetic signals without encoded fixtures.
+/// </summary>
+public sealed class PcmSourceDecoder : IAudioDecoder
+{
+    private readonly AudioFormat _format;
+    private readonly int _chunkBytes;
+
+    public PcmSourceDecoder(AudioFormat? format = null, int chunkFrames = 1024)
+    {
+        _format = format ?? AudioFormat.CdQuality;
+        if (_format.BitsPerSample != 16)
+            throw new ArgumentException("Only 16-bit PCM is supported", nameof(format));
+        _chunkBytes = chunkFrames * _format.BytesPerFrame;
+    }
+
+    public string FormatName => "PCM";
+
+    public bool CanDecode(Stream stream) => true;
+
+    public Task<AudioFormat> GetFormatAsync(Stream stream, CancellationToken cancellationToken = default)
+        => Task.FromResult(_format);
+
+    public async IAsyncEnumerable<AudioBuffer> DecodeAsync(
+        Stream stream,
+        long startPositionMs = 0,
+        Action<string>? onMetadataReceived = null,
+        [EnumeratorCancellation] CancellationToken cancellationToken = default)
+    {
+        stream.Position = _format.MillisecondsToBytes(startPositionMs) / _format.BytesPerFrame * _format.BytesPerFrame;
+        var positionMs = startPositionMs;
+
+        while (true)
+        {
+            var chunk = new byte[_chunkBytes];
+            var read = await stream.ReadAtLeastAsync(chunk, _chunkBytes, throwOnEndOfStream: false, cancellationToken);
+            read -= read % _format.BytesPerFrame;
+            if (read == 0)
+                yield break;
+
+            yield return new AudioBuffer(chunk.AsMemory(0, read), positionMs);
+            positionMs += _format.BytesToMilliseconds(read);
+        }
+    }
+
+    public void SeekTo(long positionMs) { }
+}
+
+/// <summary>
+/// Synthetic 16-bit PCM signals for pipeline tests.
+/// </summary>
+public static class TestSignals
+{
+    /// <summary>
+    /// Sum of equal-amplitude sines, identical on every channel, peaking at
+    /// <paramref name="peak"/> (0-1 of full scale).
+    /// </summary>
+    public static byte[] Tones(AudioFormat format, TimeSpan duration, double peak, params double[] frequenciesHz)
+    {
+        var frames = (int)(format.SampleRate * duration.TotalSeconds);
+        var pcm = new byte[frames * format.BytesPerFrame];
+        var amplitude = peak * short.MaxValue / Math.Max(1, frequenciesHz.Length);
+
+        for (var frame = 0; frame < frames; frame++)
+        {
+            var t = (double)frame / format.SampleRate;
+            var value = 0.0;
+            foreach (var frequency in frequenciesHz)
+                value += Math.Sin(2 * Math.PI * frequency * t);
+
+            var sample = (short)Math.Round(value * amplitude);
+            for (var channel = 0; channel < format.Channels; channel++)
+                BinaryPrimitives.WriteInt16LittleEndian(pcm.AsSpan((frame * format.Channels + channel) * 2), sample);
+        }
+
+        return pcm;
+    }
+}
@@ -0,0 +1,287 @@
+using System.Buffers.Binary;
+using System.Globalization;
+using System.Text;
+using Wavee.AudioHost.Audio.Abstractions;
+using Wavee.AudioHost.Audio.Processors;
+
+namespace Wavee.AudioHost.Tests.Helpers;
+
+/// <summary>
+/// One side of an A/B comparison: a decoder plus the processing applied to its output.
+/// </summary>
+public sealed class PipelineVariant
+{
+    private PipelineVariant(
+        string name,
+        Func<IAudioDecoder> createDecoder,
+        Func<AudioFormat, CancellationToken, Task<Func<AudioBuffer, AudioBuffer>>> createStage)
+    {
+        Name = name;
+        CreateDecoder = createDecoder;
+        CreateStage = createStage;
+    }
+
+    public string Name { get; }
+
+    internal Func<IAudioDecoder> CreateDecoder { get; }
+
+    /// <summary>
+    /// Builds the per-buffer processing stage once the decoded format is known.
+    /// The stage may return its input or a new (possibly pooled) buffer.
+    /// </summary>
+    internal Func<AudioFormat, CancellationToken, Task<Func<AudioBuffer, AudioBuffer>>> CreateStage { get; }
+
+    /// <summary>
+    /// Decoder output only, no processing.
+    /// </summary>
+    public static PipelineVariant DecodeOnly(string name, Func<IAudioDecoder> createDecoder)
+        => new(name, createDecoder, (_, _) => Task.FromResult<Func<AudioBuffer, AudioBuffer>>(buffer => buffer));
+
+    /// <summary>
+    /// Decoder output run through an <see cref="AudioProcessingChain"/> (the zero-copy
+    /// in-place path AudioEngine uses).
+    /// </summary>
+    public static PipelineVariant WithChain(string name, Func<IAudioDecoder> createDecoder, Action<AudioProcessingChain> configure)
+        => new(name, createDecoder, async (format, ct) =>
+        {
+            var chain = new AudioProcessingChain();
+            configure(chain);
+            await chain.InitializeAsync(format, ct);
+            return chain.Process;
+        });
+
+    /// <summary>
+    /// Decoder output run through each enabled processor's <see cref="IAudioProcessor.Process"/>
+    /// in order, bypassing the chain.
+    /// </summary>
+    public static PipelineVariant WithProcessors(string name, Func<IAudioDecoder> createDecoder, Func<IEnumerable<IAudioProcessor>> createProcessors)
+        => new(name, createDecoder, async (format, ct) =>
+        {
+            var processors = createProcessors().ToList();
+            foreach (var processor in processors)
+                await processor.InitializeAsync(format, ct);
+
+            return input =>
+            {
+                var current = input;
+                foreach (var processor in processors.Where(p => p.IsEnabled))
+                {
+                    var next = processor.Process(current);
+                    if (!ReferenceEquals(current, input) && !ReferenceEquals(current, next))
+                        current.Return();
+                    current = next;
+                }
+                return current;
+            };
+        });
+
+    public override string ToString() => Name;
+}
+
+/// <summary>
+/// Limits two decoded outputs must stay within to be considered equivalent.
+/// Errors are in normalised full-scale units (1.0 = full scale).
+/// </summary>
+/// <param name="MaxAbsError">Largest allowed per-sample difference.</param>
+/// <param name="MinSnrDb">Smallest allowed signal-to-difference ratio.</param>
+/// <param name="MaxFrameCountDifference">Allowed difference in output length, in frames.</param>
+public sealed record PcmTolerance(double MaxAbsError, double MinSnrDb, int MaxFrameCountDifference = 0)
+{
+    /// <summary>
+    /// Identical samples and length.
+    /// </summary>
+    public static PcmTolerance BitExact { get; } = new(0, double.PositiveInfinity);
+
+    /// <summary>
+    /// Rounding-level differences only: about 2 LSB at 16-bit and at least 90 dB SNR,
+    /// well below anything audible.
+    /// </summary>
+    public static PcmTolerance Transparent { get; } = new(2.0 / 32768, 90);
+}
+
+/// <summary>
+/// Result of decoding the same input through two pipelines.
+/// </summary>
+public sealed class PcmComparison
+{
+    internal PcmComparison(
+        PipelineVariant a, PipelineVariant b,
+        AudioFormat formatA, AudioFormat formatB,
+        long framesA, long framesB,
+        double maxAbsError, double rmsError, double snrDb,
+        long? firstDifferentFrame)
+    {
+        A = a;
+        B = b;
+        FormatA = formatA;
+        FormatB = formatB;
+        FramesA = framesA;
+        FramesB = framesB;
+        MaxAbsError = maxAbsError;
+        RmsError = rmsError;
+        SnrDb = snrDb;
+        FirstDifferentFrame = firstDifferentFrame;
+    }
+
+    public PipelineVariant A { get; }
+    public PipelineVariant B { get; }
+    public AudioFormat FormatA { get; }
+    public AudioFormat FormatB { get; }
+    public long FramesA { get; }
+    public long FramesB { get; }
+
+    /// <summary>
+    /// Largest per-sample difference over the overlapping frames (full scale = 1.0).
+    /// </summary>
+    public double MaxAbsError { get; }
+
+    /// <summary>
+    /// Root-mean-square per-sample difference over the overlapping frames.
+    /// </summary>
+    public double RmsError { get; }
+
+    /// <summary>
+    /// Ratio of A's signal power to the difference power, in dB.
+    /// Positive infinity when the overlapping samples are identical.
+    /// </summary>
+    public double SnrDb { get; }
+
+    /// <summary>
+    /// First frame whose samples differ, or null when none do.
+    /// </summary>
+    public long? FirstDifferentFrame { get; }
+
+    public bool IsWithin(PcmTolerance tolerance, out string reason)
+    {
+        if (FormatA != FormatB)
+            reason = $"formats differ ({Format(FormatA)} vs {Format(FormatB)})";
+        else if (Math.Abs(FramesA - FramesB) > tolerance.MaxFrameCountDifference)
+            reason = $"lengths differ by {Math.Abs(FramesA - FramesB)} frames (allowed {tolerance.MaxFrameCountDifference})";
+        else if (MaxAbsError > tolerance.MaxAbsError)
+            reason = $"max abs error {MaxAbsError:G4} exceeds {tolerance.MaxAbsError:G4}";
+        else if (SnrDb < tolerance.MinSnrDb)
+            reason = $"SNR {FormatDb(SnrDb)} is below {FormatDb(tolerance.MinSnrDb)}";
+        else
+            reason = string.Empty;
+
+        return reason.Length == 0;
+    }
+
+    /// <summary>
+    /// Multi-line report for assertion messages.
+    /// </summary>
+    public string Describe()
+    {
+        var sb = new StringBuilder();
+        sb.AppendLine(CultureInfo.InvariantCulture, $"A: {A.Name} - {Format(FormatA)}, {FramesA} frames");
+        sb.AppendLine(CultureInfo.InvariantCulture, $"B: {B.Name} - {Format(FormatB)}, {FramesB} frames");
+        sb.AppendLine(CultureInfo.InvariantCulture, $"max abs error: {MaxAbsError:G4} ({MaxAbsError * 32768:F2} LSB@16)");
+        sb.AppendLine(CultureInfo.InvariantCulture, $"rms error: {RmsError:G4}");
+        sb.AppendLine(CultureInfo.InvariantCulture, $"SNR: {FormatDb(SnrDb)}");
+        sb.Append("first difference: ")
+            .Append(FirstDifferentFrame is { } frame ? $"frame {frame}" : "none");
+        return sb.ToString();
+    }
+
+    public override string ToString() => Describe();
+
+    private static string Format(AudioFormat format)
+        => $"{format.SampleRate} Hz/{format.Channels} ch/{format.BitsPerSample}-bit";
+
+    private static string FormatDb(double db)
+        => double.IsPositiveInfinity(db) ? "inf dB" : db.ToString("F1", CultureInfo.InvariantCulture) + " dB";
+}
+
+/// <summary>
+/// A/B harness: decodes the same input through two pipeline configurations and
+/// compares the PCM they produce. Use it around refactors of decoders and processors
+/// (e.g. an old and a new resampler) to catch audible regressions that unit tests on
+/// single buffers miss - drift across buffer boundaries, dropped tails, gain changes.
+/// </summary>
+public static class PipelineComparison
+{
+    /// <param name="openInput">Opens a fresh copy of the input; called once per variant.</param>
+    public static async Task<PcmComparison> RunAsync(
+        Func<Stream> openInput,
+        PipelineVariant a,
+        PipelineVariant b,
+        CancellationToken ct = default)
+    {
+        var (formatA, samplesA) = await RenderAsync(openInput, a, ct);
+        var (formatB, samplesB) = await RenderAsync(openInput, b, ct);
+
+        var overlap = Math.Min(samplesA.Count, samplesB.Count);
+        double maxAbs = 0, errorSum = 0, signalSum = 0;
+        long? firstDifferent = null;
+
+        for (var i = 0; i < overlap; i++)
+        {
+            var error = samplesA[i] - samplesB[i];
+            var abs = Math.Abs(error);
+            if (abs > 0 && firstDifferent is null)
+                firstDifferent = i / formatA.Channels;
+
+            maxAbs = Math.Max(maxAbs, abs);
+            errorSum += error * error;
+            signalSum += samplesA[i] * samplesA[i];
+        }
+
+        var rms = overlap == 0 ? 0 : Math.Sqrt(errorSum / overlap);
+        var snr = errorSum == 0 ? double.PositiveInfinity : 10 * Math.Log10(signalSum / errorSum);
+
+        return new PcmComparison(
+            a, b, formatA, formatB,
+            samplesA.Count / formatA.Channels, samplesB.Count / formatB.Channels,
+            maxAbs, rms, snr, firstDifferent);
+    }
+
+    private static async Task<(AudioFormat Format, List<double> Samples)> RenderAsync(
+        Func<Stream> openInput, PipelineVariant variant, CancellationToken ct)
+    {
+        await using var stream = openInput();
+        var decoder = variant.CreateDecoder();
+        var format = await decoder.GetFormatAsync(stream, ct);
+        stream.Position = 0;
+
+        var stage = await variant.CreateStage(format, ct);
+        var samples = new List<double>();
+
+        await foreach (var decoded in decoder.DecodeAsync(stream, 0, null, ct))
+        {
+            var processed = stage(decoded);
+            try
+            {
+                AppendSamples(processed.Data.Span, format.BitsPerSample, samples);
+            }
+            finally
+            {
+                if (!ReferenceEquals(processed, decoded))
+                    processed.Return();
+                decoded.Return();
+            }
+        }
+
+        return (format, samples);
+    }
+
+    private static void AppendSamples(ReadOnlySpan<byte> data, int bitsPerSample, List<double> samples)
+    {
+        switch (bitsPerSample)
+        {
+            case 16:
+                for (var i = 0; i + 2 <= data.Length; i += 2)
+                    samples.Add(BinaryPrimitives.ReadInt16LittleEndian(data[i..]) / 32768.0);
+                break;
+            case 24:
+                for (var i = 0; i + 3 <= data.Length; i += 3)
+                    samples.Add(((data[i] | (data[i + 1] << 8) | (data[i + 2] << 16)) << 8 >> 8) / 8388608.0);
+                break;
+            case 32:
+                for (var i = 0; i + 4 <= data.Length; i += 4)
+                    samples.Add(BinaryPrimitives.ReadInt32LittleEndian(data[i..]) / 2147483648.0);
+                break;
+            default:
+                throw new NotSupportedException($"Unsupported bit depth: {bitsPerSample}");
+        }
+    }
+}
@@ -0,0 +1,95 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for AudioSessionInterruption - validates when playback pauses and comes back
+/// around an OS audio session interruption.
+///
+/// WHY: Mobile hosts forward call and alarm interruptions here. Bugs here will cause:
+/// - Music playing over a phone call
+/// - Playback the user had paused starting by itself after a call
+/// - Playback resumed twice, or against the OS's resume hint
+/// </summary>
+public class AudioSessionInterruptionTests
+{
+    [Fact]
+    public void Interruption_WhilePlaying_ShouldPauseAndResumeWhenAllowed()
+    {
+        // Arrange
+        var interruption = new AudioSessionInterruption();
+
+        // Act
+        var pause = interruption.Begin(wasPlaying: true);
+        var interrupted = interruption.IsInterrupted;
+        var resume = interruption.End(shouldResume: true);
+
+        // Assert
+        pause.Should().BeTrue();
+        interrupted.Should().BeTrue();
+        resume.Should().BeTrue();
+        interruption.IsInterrupted.Should().BeFalse();
+    }
+
+    [Fact]
+    public void Interruption_WhilePaused_ShouldNotResume()
+    {
+        // Arrange
+        var interruption = new AudioSessionInterruption();
+
+        // Act
+        var pause = interruption.Begin(wasPlaying: false);
+        var resume = interruption.End(shouldResume: true);
+
+        // Assert
+        pause.Should().BeFalse();
+        resume.Should().BeFalse("the user had paused before the call");
+    }
+
+    [Fact]
+    public void End_WithoutResumeHint_ShouldStayPaused()
+    {
+        // Arrange
+        var interruption = new AudioSessionInterruption();
+        interruption.Begin(wasPlaying: true);
+
+        // Act & Assert
+        interruption.End(shouldResume: false).Should().BeFalse();
+        interruption.IsInterrupted.Should().BeFalse();
+    }
+
+    [Fact]
+    public void PlaybackStarted_DuringInterruption_ShouldCancelResume()
+    {
+        // Arrange
+        var interruption = new AudioSessionInterruption();
+        interruption.Begin(wasPlaying: true);
+
+        // Act
+        interruption.PlaybackStarted();
+
+        // Assert
+        interruption.End(shouldResume: true).Should().BeFalse("the user already took over");
+    }
+
+    [Fact]
+    public void RepeatedBeginAndEnd_ShouldOnlyActOnce()
+    {
+        // Arrange
+        var interruption = new AudioSessionInterruption();
+
+        // Act
+        var firstBegin = interruption.Begin(wasPlaying: true);
+        var secondBegin = interruption.Begin(wasPlaying: false);
+        var firstEnd = interruption.End(shouldResume: true);
+        var secondEnd = interruption.End(shouldResume: true);
+
+        // Assert
+        firstBegin.Should().BeTrue();
+        secondBegin.Should().BeFalse();
+        firstEnd.Should().BeTrue("the second Begin must not overwrite the playing state");
+        secondEnd.Should().BeFalse();
+    }
+}
@@ -0,0 +1,111 @@
+using System.Net;
+using System.Text;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Audio;
+using Wavee.Core.Http;
+using Wavee.Core.Storage;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for ContextResolver's context-resolve expansion - validates placeholder page
+/// loading and per-revision caching.
+///
+/// WHY: Play commands often carry just a context URI. Bugs here will cause:
+/// - Playback stopping after the first page of a large playlist
+/// - Edited playlists replaying their old track list until the cache TTL runs out
+/// </summary>
+public class ContextResolverCacheTests : IDisposable
+{
+    private const string PlaylistUri = "spotify:playlist:abc";
+
+    private const string ContextJson = """
+        {"uri":"spotify:playlist:abc","pages":[
+          {"tracks":[{"uri":"spotify:track:a","uid":"u1"},{"uri":"spotify:track:b","uid":"u2"}]},
+          {"page_url":"hm://context-resolve/v1/page/1"}]}
+        """;
+
+    private const string PageJson = """
+        {"tracks":[{"uri":"spotify:track:c","uid":"u3"},{"uri":"spotify:track:d","uid":"u4"}]}
+        """;
+
+    private readonly HttpClient _httpClient;
+    private readonly ContextResolver _resolver;
+    private int _resolveCalls;
+
+    public ContextResolverCacheTests()
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        handler.Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync((HttpRequestMessage request, CancellationToken _) =>
+            {
+                var isPage = request.RequestUri!.AbsolutePath.EndsWith("/page/1", StringComparison.Ordinal);
+                if (!isPage)
+                    Interlocked.Increment(ref _resolveCalls);
+                return new HttpResponseMessage(HttpStatusCode.OK)
+                {
+                    Content = new StringContent(isPage ? PageJson : ContextJson, Encoding.UTF8, "application/json")
+                };
+            });
+
+        _httpClient = new HttpClient(handler.Object);
+        var spClient = new SpClient(new MockSession(), _httpClient, "spclient.wg.spotify.com:443", null);
+        _resolver = new ContextResolver(
+            spClient,
+            Mock.Of<IExtendedMetadataClient>(),
+            Mock.Of<ICacheService>(),
+            new HotCache<ContextCacheEntry>(16));
+    }
+
+    public void Dispose() => _httpClient.Dispose();
+
+    [Fact]
+    public async Task LoadContextAsync_WithPlaceholderPage_ShouldFetchItOnDemand()
+    {
+        // Act
+        var result = await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+
+        // Assert
+        result.Tracks.Select(t => t.Uri).Should().Equal(
+            "spotify:track:a", "spotify:track:b", "spotify:track:c", "spotify:track:d");
+        result.NextPageUrl.Should().BeNull();
+    }
+
+    [Fact]
+    public async Task LoadContextAsync_AtSameRevision_ShouldServeFromCache()
+    {
+        // Arrange
+        _resolver.OnContextRevisionChanged(PlaylistUri, "rev1");
+        await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+
+        // Act
+        _resolver.OnContextRevisionChanged(PlaylistUri, "rev1");
+        await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+
+        // Assert
+        _resolveCalls.Should().Be(1);
+    }
+
+    [Fact]
+    public async Task LoadContextAsync_AfterRevisionChange_ShouldResolveAgain()
+    {
+        // Arrange
+        await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+
+        // Act
+        _resolver.OnContextRevisionChanged(PlaylistUri, "rev2");
+        await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+        await _resolver.LoadContextAsync(PlaylistUri, enrichMetadata: false);
+
+        // Assert
+        _resolveCalls.Should().Be(2, "the entry is re-cached at rev2 after one re-resolve");
+    }
+}
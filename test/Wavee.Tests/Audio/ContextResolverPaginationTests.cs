@@ -0,0 +1,50 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Core.Storage;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for ContextResolver's eager-load stop condition.
+/// </summary>
+public class ContextResolverPaginationTests
+{
+    private static List<CachedContextTrack> Tracks(int count) =>
+        Enumerable.Range(0, count)
+            .Select(i => new CachedContextTrack($"spotify:track:{i}", $"uid{i}", null))
+            .ToList();
+
+    [Fact]
+    public void HasEnoughForStart_BelowTarget_ShouldKeepLoading()
+    {
+        // ============================================================
+        // WHY: Starting playback needs a small buffer of tracks ahead;
+        //      stopping on the very first page would leave the queue
+        //      paging after every few tracks.
+        // ============================================================
+
+        // Act & Assert
+        ContextResolver.HasEnoughForStart(Tracks(ContextResolver.EagerTrackTarget - 1), null, null)
+            .Should().BeFalse();
+        ContextResolver.HasEnoughForStart(Tracks(ContextResolver.EagerTrackTarget), null, null)
+            .Should().BeTrue();
+    }
+
+    [Fact]
+    public void HasEnoughForStart_WithStartTrackNotYetLoaded_ShouldKeepLoading()
+    {
+        // ============================================================
+        // WHY: Clicking row 2,000 of a huge playlist must load far enough
+        //      to find that row, otherwise playback silently starts at 0.
+        // ============================================================
+
+        // Arrange
+        var tracks = Tracks(ContextResolver.EagerTrackTarget);
+
+        // Act & Assert
+        ContextResolver.HasEnoughForStart(tracks, "spotify:track:2000", null).Should().BeFalse();
+        ContextResolver.HasEnoughForStart(tracks, "spotify:track:5", null).Should().BeTrue();
+        ContextResolver.HasEnoughForStart(tracks, "spotify:track:5", "uid-missing").Should().BeFalse();
+    }
+}
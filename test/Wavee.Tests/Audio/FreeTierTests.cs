@@ -0,0 +1,77 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for ProductRestrictions and SkipLimiter - validates free-tier rules read
+/// from ProductInfo and the rolling hourly skip allowance.
+///
+/// WHY: These gate the skip button. Bugs here will cause:
+/// - Premium users hitting a skip limit
+/// - Free users skipping without limit, then the server refusing playback
+/// - A refused skip never becoming available again
+/// </summary>
+public class FreeTierTests
+{
+    private static readonly DateTimeOffset Start = new(2026, 10, 16, 12, 0, 0, TimeSpan.Zero);
+
+    [Fact]
+    public void FromAttributes_FreeAccount_ShouldApplyDefaultSkipLimit()
+    {
+        // Arrange
+        var attributes = new Dictionary<string, string> { ["type"] = "free", ["ads"] = "1", ["on-demand"] = "0" };
+
+        // Act
+        var restrictions = ProductRestrictions.FromAttributes(attributes);
+
+        // Assert
+        restrictions.Should().Be(new ProductRestrictions(true, false, ProductRestrictions.DefaultFreeSkipsPerHour));
+    }
+
+    [Fact]
+    public void FromAttributes_ShouldPreferServerSkipLimit()
+    {
+        // Arrange
+        var attributes = new Dictionary<string, string> { ["type"] = "free", ["skips-per-hour"] = "12" };
+
+        // Act & Assert
+        ProductRestrictions.FromAttributes(attributes).SkipsPerHour.Should().Be(12);
+    }
+
+    [Fact]
+    public void FromUserData_Premium_ShouldBeUnrestricted()
+    {
+        // Arrange
+        var userData = new UserData
+        {
+            Username = "alice",
+            AccountType = AccountType.Premium,
+            ProductAttributes = new Dictionary<string, string> { ["type"] = "premium", ["ads"] = "0" }
+        };
+
+        // Act & Assert
+        ProductRestrictions.FromUserData(userData).Should().Be(ProductRestrictions.None);
+        ProductRestrictions.FromUserData(new UserData { Username = "alice" }).Should().Be(ProductRestrictions.None);
+    }
+
+    [Fact]
+    public void SkipLimiter_ShouldRefuseOverLimitUntilOldestSkipExpires()
+    {
+        // Arrange
+        var limiter = new SkipLimiter();
+        limiter.TryConsume(2, Start, out _).Should().BeTrue();
+        limiter.TryConsume(2, Start.AddMinutes(10), out _).Should().BeTrue();
+
+        // Act
+        var refused = limiter.TryConsume(2, Start.AddMinutes(20), out var retryAfter);
+        var allowedLater = limiter.TryConsume(2, Start.AddMinutes(60), out _);
+
+        // Assert
+        refused.Should().BeFalse();
+        retryAfter.Should().Be(TimeSpan.FromMinutes(40));
+        allowedLater.Should().BeTrue();
+    }
+}
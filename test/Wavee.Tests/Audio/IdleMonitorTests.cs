@@ -0,0 +1,96 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for IdleMonitor - validates when power save is entered and left.
+///
+/// WHY: Power save closes the audio output and slows dealer pings. Bugs here will cause:
+/// - The output being released under a playing track
+/// - A device that never goes idle, or never wakes for a remote command
+/// - Dealer pings left at the idle rate during playback
+/// </summary>
+public class IdleMonitorTests
+{
+    private static readonly TimeSpan IdleTimeout = TimeSpan.FromMinutes(10);
+
+    [Fact]
+    public void Timeout_WithoutPlayback_ShouldEnterIdleOnce()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var changes = new List<bool>();
+        using var monitor = new IdleMonitor(IdleTimeout, changes.Add, time);
+
+        // Act
+        time.Advance(IdleTimeout - TimeSpan.FromSeconds(1));
+        var idleEarly = monitor.IsIdle;
+        time.Advance(TimeSpan.FromHours(1));
+
+        // Assert
+        idleEarly.Should().BeFalse();
+        monitor.IsIdle.Should().BeTrue();
+        changes.Should().Equal(true);
+    }
+
+    [Fact]
+    public void SetActive_WhilePlaying_ShouldNeverEnterIdle()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var changes = new List<bool>();
+        using var monitor = new IdleMonitor(IdleTimeout, changes.Add, time);
+
+        // Act
+        monitor.SetActive(true);
+        time.Advance(TimeSpan.FromHours(2));
+
+        // Assert
+        monitor.IsIdle.Should().BeFalse();
+        changes.Should().BeEmpty();
+    }
+
+    [Fact]
+    public void Wake_WhenIdle_ShouldLeaveIdleAndRearm()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var changes = new List<bool>();
+        using var monitor = new IdleMonitor(IdleTimeout, changes.Add, time);
+        time.Advance(IdleTimeout);
+
+        // Act
+        monitor.Wake();
+        var idleAfterWake = monitor.IsIdle;
+        time.Advance(IdleTimeout);
+
+        // Assert
+        idleAfterWake.Should().BeFalse();
+        changes.Should().Equal(true, false, true);
+    }
+
+    [Fact]
+    public void SetActive_AfterPlaybackStops_ShouldRestartCountdown()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var changes = new List<bool>();
+        using var monitor = new IdleMonitor(IdleTimeout, changes.Add, time);
+        time.Advance(IdleTimeout);
+        monitor.SetActive(true);
+
+        // Act
+        time.Advance(TimeSpan.FromHours(1));
+        monitor.SetActive(false);
+        time.Advance(IdleTimeout - TimeSpan.FromSeconds(1));
+        var idleEarly = monitor.IsIdle;
+        time.Advance(TimeSpan.FromSeconds(1));
+
+        // Assert
+        idleEarly.Should().BeFalse();
+        changes.Should().Equal(true, false, true);
+    }
+}
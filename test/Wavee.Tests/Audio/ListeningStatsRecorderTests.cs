@@ -0,0 +1,59 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for ListeningStatsRecorder - validates how a finished track becomes a listening stats entry.
+///
+/// WHY: These entries are all the local "your week in music" views see. Bugs here will cause:
+/// - Skipped tracks counted as plays
+/// - Collaborations credited to a joined "A, B" pseudo-artist
+/// - Empty rows for tracks that never made a sound
+/// </summary>
+public class ListeningStatsRecorderTests
+{
+    private static readonly DateTimeOffset T0 = new(2026, 1, 1, 20, 15, 0, TimeSpan.Zero);
+
+    private static TrackPlaybackStats Stats(TimeSpan played, string? artist = "A, B") => new(
+        "spotify:track:a", T0, T0 + played, 0, false, TimeSpan.Zero, 0, 0,
+        played, "Song", artist, "spotify:artist:a");
+
+    [Fact]
+    public void ToEntry_ShouldCreditFirstArtistAndKeepStartTime()
+    {
+        // Act
+        var entry = ListeningStatsRecorder.ToEntry(Stats(TimeSpan.FromMinutes(3)));
+
+        // Assert
+        entry.Should().NotBeNull();
+        entry!.TrackUri.Should().Be("spotify:track:a");
+        entry.Title.Should().Be("Song");
+        entry.ArtistName.Should().Be("A");
+        entry.ArtistUri.Should().Be("spotify:artist:a");
+        entry.StartedAt.Should().Be(T0);
+        entry.MsPlayed.Should().Be(180_000);
+        entry.CountsAsPlay.Should().BeTrue();
+    }
+
+    [Theory]
+    [InlineData(29_999, false)]
+    [InlineData(30_000, true)]
+    public void ToEntry_ShouldCountPlayFromThirtySeconds(int playedMs, bool expected)
+    {
+        // Act
+        var entry = ListeningStatsRecorder.ToEntry(Stats(TimeSpan.FromMilliseconds(playedMs)));
+
+        // Assert
+        entry!.MsPlayed.Should().Be(playedMs);
+        entry.CountsAsPlay.Should().Be(expected);
+    }
+
+    [Fact]
+    public void ToEntry_NothingHeard_ShouldReturnNull()
+    {
+        // Act & Assert
+        ListeningStatsRecorder.ToEntry(Stats(TimeSpan.Zero)).Should().BeNull();
+    }
+}
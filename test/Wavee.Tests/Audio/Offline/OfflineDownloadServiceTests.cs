@@ -0,0 +1,228 @@
+using System.Net;
+using System.Security.Cryptography;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Audio;
+using Wavee.Audio.Offline;
+using Wavee.Core.Audio;
+using Wavee.Core.Storage.Abstractions;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Audio.Offline;
+
+/// <summary>
+/// Tests for OfflineDownloadService - validates download quality selection and artifact integrity tracking.
+///
+/// WHY: Downloads are what the user plays with no network. Bugs here will cause:
+/// - Downloads stored at the streaming quality instead of the one the user picked
+/// - A transcoded artifact that silently rotted on disk being trusted
+/// - Pinned playlists evicted to make room, or the quota never enforced
+/// </summary>
+public class OfflineDownloadServiceTests : IDisposable
+{
+    private const string TrackUri = "spotify:track:abc";
+    private const string FileId = "0123456789abcdef0123456789abcdef01234567";
+
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-offline-" + Guid.NewGuid().ToString("N"));
+    private readonly Dictionary<string, OfflineTrackEntry> _rows = new();
+    private readonly Dictionary<string, IReadOnlyList<string>> _pins = new();
+    private readonly Mock<IMetadataDatabase> _db = new();
+    private readonly HttpClient _httpClient;
+
+    public OfflineDownloadServiceTests()
+    {
+        _db.Setup(d => d.GetOfflineTrackAsync(It.IsAny<string>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync((string uri, CancellationToken _) => _rows.GetValueOrDefault(uri));
+        _db.Setup(d => d.UpsertOfflineTrackAsync(It.IsAny<OfflineTrackEntry>(), It.IsAny<CancellationToken>()))
+            .Callback((OfflineTrackEntry e, CancellationToken _) => _rows[e.TrackUri] = e)
+            .Returns(Task.CompletedTask);
+        _db.Setup(d => d.GetOfflineTracksAsync(It.IsAny<CancellationToken>()))
+            .ReturnsAsync(() => _rows.Values.ToList());
+        _db.Setup(d => d.DeleteOfflineTrackAsync(It.IsAny<string>(), It.IsAny<CancellationToken>()))
+            .Callback((string uri, CancellationToken _) => _rows.Remove(uri))
+            .Returns(Task.CompletedTask);
+        _db.Setup(d => d.SetOfflinePinAsync(It.IsAny<string>(), It.IsAny<IReadOnlyList<string>>(), It.IsAny<CancellationToken>()))
+            .Callback((string uri, IReadOnlyList<string> tracks, CancellationToken _) => _pins[uri] = tracks)
+            .Returns(Task.CompletedTask);
+        _db.Setup(d => d.DeleteOfflinePinAsync(It.IsAny<string>(), It.IsAny<CancellationToken>()))
+            .Callback((string uri, CancellationToken _) => _pins.Remove(uri))
+            .Returns(Task.CompletedTask);
+        _db.Setup(d => d.GetOfflinePinsAsync(It.IsAny<CancellationToken>()))
+            .ReturnsAsync(() => _pins.Select(p => new OfflinePinEntry
+            {
+                ContextUri = p.Key,
+                PinnedAt = 0,
+                TrackUris = p.Value
+            }).ToList());
+
+        var handler = new Mock<HttpMessageHandler>();
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent([1, 2, 3, 4]) });
+        _httpClient = new HttpClient(handler.Object);
+    }
+
+    public void Dispose()
+    {
+        _httpClient.Dispose();
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+
+    [Fact]
+    public async Task DownloadAsync_ShouldResolveAtDownloadQualityAndRecordEntry()
+    {
+        // Arrange
+        AudioQuality? requested = null;
+        var service = CreateService((_, quality, _) =>
+        {
+            requested = quality;
+            return Task.FromResult(Resolution());
+        }, new OfflineDownloadOptions { Quality = AudioQuality.Normal });
+
+        // Act
+        var entry = await service.DownloadAsync(TrackUri);
+
+        // Assert
+        requested.Should().Be(AudioQuality.Normal);
+        using (var cached = AudioFileCache.OpenRead(_dir, FileId))
+        {
+            var bytes = new byte[4];
+            cached.ReadExactly(bytes);
+            bytes.Should().Equal(1, 2, 3, 4);
+        }
+        entry.BitrateKbps.Should().Be(96);
+        entry.SizeBytes.Should().Be(4);
+        entry.Transform.Should().BeNull();
+        _rows[TrackUri].Should().Be(entry);
+    }
+
+    [Fact]
+    public async Task DownloadAsync_WithTransform_ShouldRecordArtifactHash()
+    {
+        // Arrange
+        var service = CreateService((_, _, _) => Task.FromResult(Resolution()),
+            new OfflineDownloadOptions { Transform = new CopyTransform() });
+
+        // Act
+        var entry = await service.DownloadAsync(TrackUri);
+
+        // Assert
+        entry.Transform.Should().Be("copy");
+        entry.ArtifactPath.Should().NotBeNull();
+        entry.ArtifactSizeBytes.Should().Be(4);
+        entry.ArtifactSha256.Should().Be(Convert.ToHexStringLower(SHA256.HashData(File.ReadAllBytes(entry.ArtifactPath!))));
+        (await service.VerifyArtifactAsync(TrackUri)).Should().Be(OfflineArtifactStatus.Valid);
+    }
+
+    [Fact]
+    public async Task VerifyArtifactAsync_WhenArtifactChanged_ShouldReportCorruptAndDropIt()
+    {
+        // Arrange
+        var service = CreateService((_, _, _) => Task.FromResult(Resolution()),
+            new OfflineDownloadOptions { Transform = new CopyTransform() });
+        var entry = await service.DownloadAsync(TrackUri);
+        File.WriteAllBytes(entry.ArtifactPath!, [9, 9, 9, 9]);
+
+        // Act
+        var status = await service.VerifyArtifactAsync(TrackUri);
+
+        // Assert
+        status.Should().Be(OfflineArtifactStatus.Corrupt);
+        File.Exists(entry.ArtifactPath).Should().BeFalse();
+        _rows[TrackUri].ArtifactPath.Should().BeNull();
+        _rows[TrackUri].FileId.Should().Be(FileId, "the playable download itself is kept");
+    }
+
+    [Fact]
+    public async Task EnforceQuotaAsync_ShouldEvictLeastRecentlyPlayedUnpinnedTracks()
+    {
+        // Arrange
+        AddRow("spotify:track:old", downloadedAt: 100, lastPlayedAt: null);
+        AddRow("spotify:track:played", downloadedAt: 50, lastPlayedAt: 500);
+        AddRow("spotify:track:pinned", downloadedAt: 10, lastPlayedAt: null);
+        _pins["spotify:playlist:p"] = ["spotify:track:pinned"];
+        var service = CreateService((_, _, _) => Task.FromResult(Resolution()),
+            new OfflineDownloadOptions { QuotaBytes = 8 });
+
+        // Act
+        var evicted = await service.EnforceQuotaAsync();
+
+        // Assert
+        evicted.Should().Equal("spotify:track:old");
+        _rows.Keys.Should().BeEquivalentTo("spotify:track:played", "spotify:track:pinned");
+    }
+
+    [Fact]
+    public async Task PinAsync_ShouldDownloadMissingTracksAndProtectThemFromEviction()
+    {
+        // Arrange
+        AddRow("spotify:track:other", downloadedAt: 100, lastPlayedAt: null);
+        var service = CreateService((_, _, _) => Task.FromResult(Resolution()),
+            new OfflineDownloadOptions { QuotaBytes = 4 });
+
+        // Act
+        var downloaded = await service.PinAsync("spotify:album:a", [TrackUri]);
+        var usage = await service.GetStorageUsageAsync();
+
+        // Assert
+        downloaded.Should().Be(1);
+        _rows.Keys.Should().Equal(TrackUri);
+        usage.TotalBytes.Should().Be(4);
+        usage.Tracks.Should().ContainSingle().Which.IsPinned.Should().BeTrue();
+        usage.Pins.Should().ContainSingle().Which.Should().Be(new OfflinePinUsage("spotify:album:a", 1, 1, 4));
+    }
+
+    private void AddRow(string uri, long downloadedAt, long? lastPlayedAt)
+    {
+        _rows[uri] = new OfflineTrackEntry
+        {
+            TrackUri = uri,
+            FileId = uri.GetHashCode().ToString("x8"),
+            Codec = "vorbis",
+            BitrateKbps = 160,
+            SizeBytes = 4,
+            DownloadedAt = downloadedAt,
+            LastPlayedAt = lastPlayedAt
+        };
+    }
+
+    private OfflineDownloadService CreateService(
+        Func<string, AudioQuality, CancellationToken, Task<TrackResolution>> resolve,
+        OfflineDownloadOptions options)
+        => new(resolve, _db.Object, _httpClient, _dir, options);
+
+    private static TrackResolution Resolution() => new()
+    {
+        TrackUri = TrackUri,
+        Codec = "vorbis",
+        BitrateKbps = 96,
+        Metadata = new TrackMetadataDto(),
+        AudioKeyTask = Task.FromResult(new byte[16]),
+        CdnUrlTask = Task.FromResult("https://audio.example/file"),
+        FileSizeTask = Task.FromResult(4L),
+        SpotifyFileId = FileId
+    };
+
+    private sealed class CopyTransform : IDownloadTransform
+    {
+        public string Name => "copy";
+
+        public async Task<DownloadTransformResult> TransformAsync(DownloadTransformContext context, CancellationToken cancellationToken)
+        {
+            var path = Path.Combine(context.OutputDirectory, context.FileId + ".copy");
+            await using (var source = context.OpenEncrypted())
+            await using (var target = File.Create(path))
+            {
+                await source.CopyToAsync(target, cancellationToken);
+            }
+            return new DownloadTransformResult(path);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Core;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PlayableContext resolution from URIs and URLs.
+/// </summary>
+public class PlayableContextTests
+{
+    private const string Id = "4uLU6hMCjMI75M1A2tKUQC";
+
+    [Theory]
+    [InlineData("spotify:album:" + Id, SpotifyLinkKind.Album, "spotify:album:" + Id, null)]
+    [InlineData("https://open.spotify.com/playlist/" + Id + "?si=abc", SpotifyLinkKind.Playlist, "spotify:playlist:" + Id, null)]
+    [InlineData("https://open.spotify.com/intl-de/artist/" + Id, SpotifyLinkKind.Artist, "spotify:artist:" + Id, null)]
+    [InlineData("spotify:show:" + Id, SpotifyLinkKind.Show, "spotify:show:" + Id, null)]
+    [InlineData("https://open.spotify.com/track/" + Id, SpotifyLinkKind.Track, "spotify:track:" + Id, "spotify:track:" + Id)]
+    [InlineData("spotify:episode:" + Id, SpotifyLinkKind.Episode, "spotify:episode:" + Id, "spotify:episode:" + Id)]
+    public void TryResolve_WithSupportedLink_ShouldReturnCanonicalContext(
+        string input, SpotifyLinkKind kind, string contextUri, string? trackUri)
+    {
+        // ============================================================
+        // WHY: Users paste share links as often as URIs. Both must land
+        //      on the same canonical context, and single items must name
+        //      themselves as the start track so playback begins there.
+        // ============================================================
+
+        // Act
+        var ok = PlayableContext.TryResolve(input, out var context);
+
+        // Assert
+        ok.Should().BeTrue();
+        context!.Kind.Should().Be(kind);
+        context.ContextUri.Should().Be(contextUri);
+        context.TrackUri.Should().Be(trackUri);
+    }
+
+    [Theory]
+    [InlineData("spotify:user:someone")]
+    [InlineData("https://example.com/track/" + Id)]
+    [InlineData("not a link")]
+    [InlineData("")]
+    public void TryResolve_WithUnplayableInput_ShouldReturnFalse(string input)
+    {
+        // ============================================================
+        // WHY: Profiles, foreign hosts and garbage must be rejected up
+        //      front instead of failing later inside context-resolve.
+        // ============================================================
+
+        // Act
+        var ok = PlayableContext.TryResolve(input, out var context);
+
+        // Assert
+        ok.Should().BeFalse();
+        context.Should().BeNull();
+    }
+
+    [Fact]
+    public void ToPlayCommand_ShouldCarryContextAndStartTrack()
+    {
+        // ============================================================
+        // WHY: The resolved context feeds PlaybackOrchestrator.PlayAsync
+        //      directly; an empty sender marks the play as local UI.
+        // ============================================================
+
+        // Arrange
+        var context = PlayableContext.Resolve("spotify:track:" + Id);
+
+        // Act
+        var command = context.ToPlayCommand();
+
+        // Assert
+        command.ContextUri.Should().Be("spotify:track:" + Id);
+        command.TrackUri.Should().Be("spotify:track:" + Id);
+        command.SenderDeviceId.Should().BeEmpty();
+    }
+}
@@ -0,0 +1,162 @@
+using System.Reactive.Linq;
+using FluentAssertions;
+using Moq;
+using Wavee.Audio;
+using Wavee.Core.Audio;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Wavee.Playback.Contracts;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PlaybackOrchestrator hot cues and A-B loop - validates cue storage, jumping
+/// and the loop commands sent to AudioHost.
+///
+/// WHY: Cues and loops are driven live by DJ controllers and hotkeys. Bugs here will cause:
+/// - A cue pad seeking somewhere other than where it was set
+/// - An empty pad seeking to the start of the track
+/// - A loop that can't be left because AudioHost never hears it was cleared
+/// - Cues from the previous track applied to the next one
+/// </summary>
+public sealed class PlaybackOrchestratorCueTests : IAsyncLifetime
+{
+    private const string TrackUri = "spotify:track:4uLU6hMCjMI75M1A2tKUQC";
+    private const long PositionMs = 30_000;
+
+    private readonly HttpClient _httpClient = new();
+    private Session _session = null!;
+    private FakeAudioHost _host = null!;
+    private PlaybackOrchestrator _orchestrator = null!;
+
+    public async ValueTask InitializeAsync()
+    {
+        var httpClientFactory = new Mock<IHttpClientFactory>();
+        httpClientFactory.Setup(f => f.CreateClient(It.IsAny<string>())).Returns(() => new HttpClient());
+        _session = Session.Create(new SessionConfig { DeviceId = "device", DeviceName = "Test" }, httpClientFactory.Object);
+        var spClient = (SpClient)_session.SpClient;
+
+        _host = await FakeAudioHost.StartAsync();
+        _orchestrator = new PlaybackOrchestrator(
+            _host.Proxy,
+            new TrackResolver(_session, spClient, new HeadFileClient(_httpClient), _httpClient),
+            new ContextResolver(
+                spClient,
+                Mock.Of<IExtendedMetadataClient>(),
+                Mock.Of<ICacheService>(),
+                new HotCache<ContextCacheEntry>(16)),
+            commandHandler: null,
+            logger: null);
+
+        await LoadTrackAsync(TrackUri);
+    }
+
+    [Fact]
+    public async Task JumpToCuePointAsync_AfterSetCuePoint_ShouldSeekToCue()
+    {
+        // Arrange
+        var stored = _orchestrator.SetCuePoint(2);
+
+        // Act
+        var jumped = await _orchestrator.JumpToCuePointAsync(2);
+
+        // Assert
+        stored.Should().Be(PositionMs);
+        jumped.Should().BeTrue();
+        (await _host.WaitForAsync<SeekCommand>(IpcMessageTypes.Seek)).PositionMs.Should().Be(PositionMs);
+        _orchestrator.Cues.TrackUri.Should().Be(TrackUri);
+        _orchestrator.Cues.CuePoints[2].Should().Be(PositionMs);
+    }
+
+    [Fact]
+    public async Task JumpToCuePointAsync_EmptySlot_ShouldNotSeek()
+    {
+        // Arrange
+        _orchestrator.SetCuePoint(0, 90_000);
+        _orchestrator.ClearCuePoint(0);
+
+        // Act
+        var jumped = await _orchestrator.JumpToCuePointAsync(0);
+
+        // Assert
+        jumped.Should().BeFalse();
+        await _host.Proxy.SendPingAsync();
+        await _host.WaitForAsync(IpcMessageTypes.Ping);
+        _host.Received.Select(m => m.Type).Should().NotContain(IpcMessageTypes.Seek);
+    }
+
+    [Fact]
+    public async Task SetLoopOutAsync_AfterLoopIn_ShouldSendLoopRegion()
+    {
+        // Act
+        var markedIn = await _orchestrator.SetLoopInAsync(10_000);
+        var closed = await _orchestrator.SetLoopOutAsync(20_000);
+
+        // Assert
+        markedIn.Should().BeTrue();
+        closed.Should().BeTrue();
+        var region = await _host.WaitForAsync<SetLoopRegionCommand>(IpcMessageTypes.SetLoopRegion);
+        region.TrackUri.Should().Be(TrackUri);
+        region.StartMs.Should().Be(10_000);
+        region.EndMs.Should().Be(20_000);
+        _orchestrator.Cues.IsLooping.Should().BeTrue();
+    }
+
+    [Fact]
+    public async Task ClearLoopAsync_WhileLooping_ShouldClearRegionInAudioHost()
+    {
+        // Arrange
+        await _orchestrator.SetLoopAsync(10_000, 20_000);
+        await _host.WaitForAsync(IpcMessageTypes.SetLoopRegion);
+
+        // Act
+        await _orchestrator.ClearLoopAsync();
+
+        // Assert
+        var region = await _host.WaitForAsync<SetLoopRegionCommand>(IpcMessageTypes.SetLoopRegion);
+        region.StartMs.Should().BeNull();
+        region.EndMs.Should().BeNull();
+        _orchestrator.Cues.IsLooping.Should().BeFalse();
+        _orchestrator.Cues.LoopStartMs.Should().BeNull();
+    }
+
+    [Fact]
+    public async Task SetCuePoint_ThenTrackChange_ShouldClearCues()
+    {
+        // Arrange
+        _orchestrator.SetCuePoint(1);
+
+        // Act
+        await LoadTrackAsync("spotify:track:0000000000000000000000");
+
+        // Assert
+        _orchestrator.Cues.Should().Be(CueState.Empty);
+    }
+
+    // Paused, so the current position doesn't move with the clock.
+    private async Task LoadTrackAsync(string trackUri)
+    {
+        await _host.PushStateAsync(new PlaybackStateSnapshot
+        {
+            TrackUri = trackUri,
+            PositionMs = PositionMs,
+            DurationMs = 180_000,
+            IsPaused = true,
+            Timestamp = DateTimeOffset.UtcNow.ToUnixTimeMilliseconds()
+        });
+        await _orchestrator.StateChanges
+            .FirstAsync(s => s.TrackUri == trackUri)
+            .Timeout(TimeSpan.FromSeconds(10));
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _orchestrator.DisposeAsync();
+        await _host.DisposeAsync();
+        await _session.DisposeAsync();
+        _httpClient.Dispose();
+    }
+}
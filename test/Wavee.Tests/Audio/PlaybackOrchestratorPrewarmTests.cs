@@ -0,0 +1,150 @@
+using System.Net;
+using FluentAssertions;
+using Google.Protobuf;
+using Moq;
+using Moq.Protected;
+using Wavee.Audio;
+using Wavee.Connect.Commands;
+using Wavee.Core.Audio;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Wavee.Playback.Contracts;
+using Wavee.Protocol.Metadata;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PlaybackOrchestrator.PrewarmAsync - validates that a prewarmed track plays
+/// without resolving again and that AudioHost is only warmed up for streamed files.
+///
+/// WHY: Voice assistants prewarm the track they are about to play. Bugs here will cause:
+/// - The play resolving from scratch, losing the head start prewarm exists for
+/// - A prewarm for a fully cached file sending AudioHost no-op warm-up work
+/// </summary>
+public sealed class PlaybackOrchestratorPrewarmTests : IAsyncLifetime
+{
+    private const string TrackUri = "spotify:track:4uLU6hMCjMI75M1A2tKUQC";
+    private const string CdnUrl = "https://audio.example/file";
+    private static readonly byte[] FileIdBytes = Enumerable.Range(1, 20).Select(i => (byte)i).ToArray();
+    private static readonly string FileIdHex = Convert.ToHexString(FileIdBytes).ToLowerInvariant();
+    private static readonly byte[] HeadData = Enumerable.Range(0, 64).Select(i => (byte)i).ToArray();
+
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-prewarm-" + Guid.NewGuid().ToString("N"));
+    private readonly Mock<ICacheService> _cache = new();
+    private readonly Mock<IExtendedMetadataClient> _metadata = new();
+    private HttpClient _httpClient = null!;
+    private Session _session = null!;
+    private FakeAudioHost _host = null!;
+    private PlaybackOrchestrator _orchestrator = null!;
+
+    public async ValueTask InitializeAsync()
+    {
+        _cache.Setup(c => c.GetAudioKeyAsync(It.IsAny<string>(), It.IsAny<FileId>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new byte[16]);
+        _cache.Setup(c => c.GetHeadDataAsync(It.IsAny<FileId>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(HeadData);
+        _cache.Setup(c => c.GetCdnUrlAsync(It.IsAny<FileId>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new CdnCacheEntry(CdnUrl, DateTimeOffset.UtcNow.AddHours(1)));
+        _metadata.Setup(m => m.GetTrackAudioFilesAsync(TrackUri, It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new Track
+            {
+                Name = "Song",
+                Duration = 180_000,
+                File = { new AudioFile { FileId = ByteString.CopyFrom(FileIdBytes), Format = AudioFile.Types.Format.OggVorbis320 } }
+            });
+
+        // Answers the file-size HEAD request against the CDN URL.
+        var handler = new Mock<HttpMessageHandler>();
+        handler.Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent(new byte[4096]) });
+        _httpClient = new HttpClient(handler.Object);
+
+        var httpClientFactory = new Mock<IHttpClientFactory>();
+        httpClientFactory.Setup(f => f.CreateClient(It.IsAny<string>())).Returns(() => new HttpClient());
+        _session = Session.Create(new SessionConfig { DeviceId = "device", DeviceName = "Test" }, httpClientFactory.Object);
+        _session.SetCacheService(_cache.Object);
+        var spClient = (SpClient)_session.SpClient;
+
+        _host = await FakeAudioHost.StartAsync();
+        _orchestrator = new PlaybackOrchestrator(
+            _host.Proxy,
+            new TrackResolver(
+                _session, spClient, new HeadFileClient(_httpClient), _httpClient,
+                extendedMetadataClient: _metadata.Object,
+                cacheService: _cache.Object,
+                audioCacheDirectory: _dir),
+            new ContextResolver(spClient, _metadata.Object, _cache.Object, new HotCache<ContextCacheEntry>(16)),
+            commandHandler: null,
+            logger: null);
+    }
+
+    [Fact]
+    public async Task PrewarmAsync_ThenPlay_ShouldReuseResolutionAndWarmDecoder()
+    {
+        // Act
+        await _orchestrator.PrewarmAsync(TrackUri);
+        var prewarm = await _host.WaitForAsync<PrewarmTrackCommand>(IpcMessageTypes.Prewarm);
+
+        await _orchestrator.PlayAsync(new PlayCommand
+        {
+            Endpoint = "play",
+            MessageIdent = "local",
+            MessageId = 0,
+            SenderDeviceId = "device",
+            Key = "local/0",
+            PageTracks = [new PageTrack(TrackUri, "uid")]
+        });
+        var play = await _host.WaitForAsync<PlayTrackCommand>(IpcMessageTypes.PlayTrack);
+        var resolved = await _host.WaitForAsync<DeferredResolvedCommand>(IpcMessageTypes.DeferredResolved);
+
+        // Assert
+        prewarm.TrackUri.Should().Be(TrackUri);
+        prewarm.HeadData.Should().Be(Convert.ToBase64String(HeadData));
+
+        play.TrackUri.Should().Be(TrackUri);
+        play.HeadData.Should().Be(Convert.ToBase64String(HeadData));
+        resolved.DeferredId.Should().Be(play.DeferredId);
+        resolved.CdnUrl.Should().Be(CdnUrl);
+
+        _metadata.Verify(m => m.GetTrackAudioFilesAsync(TrackUri, It.IsAny<CancellationToken>()), Times.Once);
+        _cache.Verify(c => c.GetHeadDataAsync(It.IsAny<FileId>(), It.IsAny<CancellationToken>()), Times.Once);
+        _cache.Verify(c => c.GetCdnUrlAsync(It.IsAny<FileId>(), It.IsAny<CancellationToken>()), Times.Once);
+    }
+
+    [Fact]
+    public async Task PrewarmAsync_CachedFile_ShouldSkipDecoderWarmUp()
+    {
+        // Arrange
+        using (var writer = AudioFileCache.BeginWrite(_dir, FileIdHex))
+        {
+            writer.Write(new byte[4096]);
+            writer.Commit();
+        }
+
+        // Act
+        await _orchestrator.PrewarmAsync(TrackUri);
+
+        // Assert
+        await _host.Proxy.SendPingAsync();
+        await _host.WaitForAsync(IpcMessageTypes.Ping);
+        _host.Received.Select(m => m.Type).Should().NotContain(IpcMessageTypes.Prewarm);
+        _cache.Verify(c => c.GetHeadDataAsync(It.IsAny<FileId>(), It.IsAny<CancellationToken>()), Times.Never);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _orchestrator.DisposeAsync();
+        await _host.DisposeAsync();
+        await _session.DisposeAsync();
+        _httpClient.Dispose();
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+}
@@ -0,0 +1,88 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Connect;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PlaybackOrchestrator seek position helpers.
+/// Validates clamping and position extrapolation used for Seeked events.
+/// </summary>
+public class PlaybackOrchestratorSeekTests
+{
+    [Theory]
+    [InlineData(-500, 180_000, 0)]
+    [InlineData(90_000, 180_000, 90_000)]
+    [InlineData(200_000, 180_000, 180_000)]
+    [InlineData(200_000, 0, 200_000)]
+    public void ClampSeekPosition_ShouldClampToTrackBounds(long requested, long duration, long expected)
+    {
+        // ============================================================
+        // WHY: Remote devices send seeks past the end on scrub release;
+        //      unknown duration must not clamp everything to 0.
+        // ============================================================
+
+        PlaybackOrchestrator.ClampSeekPosition(requested, duration).Should().Be(expected);
+    }
+
+    [Fact]
+    public void ExtrapolatePosition_WhilePlaying_ShouldAdvanceFromAnchor()
+    {
+        // ============================================================
+        // WHY: Seeked.From must reflect where playback actually was,
+        //      not the last (up to 5 s old) published position.
+        // ============================================================
+
+        // Arrange
+        var state = LocalPlaybackState.Empty with
+        {
+            PositionMs = 10_000,
+            DurationMs = 180_000,
+            IsPlaying = true,
+            Timestamp = 1_000_000
+        };
+
+        // Act
+        var position = PlaybackOrchestrator.ExtrapolatePosition(state, nowMs: 1_002_500);
+
+        // Assert
+        position.Should().Be(12_500);
+    }
+
+    [Fact]
+    public void ExtrapolatePosition_WhilePaused_ShouldReturnAnchor()
+    {
+        // ============================================================
+        // WHY: Paused position must not drift with wall-clock time.
+        // ============================================================
+
+        var state = LocalPlaybackState.Empty with
+        {
+            PositionMs = 10_000,
+            DurationMs = 180_000,
+            IsPlaying = true,
+            IsPaused = true,
+            Timestamp = 1_000_000
+        };
+
+        PlaybackOrchestrator.ExtrapolatePosition(state, nowMs: 1_060_000).Should().Be(10_000);
+    }
+
+    [Theory]
+    [InlineData(2_000, 3, false)]
+    [InlineData(3_000, 3, false)]
+    [InlineData(3_001, 3, true)]
+    [InlineData(60_000, 0, false)]
+    [InlineData(6_000, 10, false)]
+    public void ShouldRestartOnPrevious_ShouldRespectThreshold(long positionMs, int thresholdSeconds, bool expected)
+    {
+        // ============================================================
+        // WHY: Local previous and Connect skip_prev share this rule;
+        //      a zero threshold must always go to the previous track.
+        // ============================================================
+
+        PlaybackOrchestrator.ShouldRestartOnPrevious(positionMs, TimeSpan.FromSeconds(thresholdSeconds))
+            .Should().Be(expected);
+    }
+}
@@ -0,0 +1,64 @@
+using FluentAssertions;
+using Wavee.Audio.Queue;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for seeded, reproducible shuffle order in PlaybackQueue.
+/// </summary>
+public class PlaybackQueueShuffleTests
+{
+    private static List<QueueTrack> Tracks(int from, int count) =>
+        Enumerable.Range(from, count).Select(i => new QueueTrack($"spotify:track:{i}", $"uid{i}")).ToList();
+
+    private static List<string> UpcomingOrder(int seed)
+    {
+        var queue = new PlaybackQueue();
+        queue.SetContext("spotify:playlist:abc", isInfinite: false);
+        queue.SetShuffle(true, seed);
+        queue.SetTracks(Tracks(0, 50), startIndex: 7);
+        queue.AppendTracks(Tracks(50, 20));
+        return queue.GetNextTracks().Select(t => t.Uri).ToList();
+    }
+
+    [Fact]
+    public void SetShuffle_WithSameSeed_ShouldProduceSameUpcomingOrder()
+    {
+        // ============================================================
+        // WHY: Connect peers receive our seed via context_metadata and
+        //      must be able to reproduce exactly the order we will play,
+        //      including lazily appended pages.
+        // ============================================================
+
+        // Act
+        var first = UpcomingOrder(seed: 1234);
+        var second = UpcomingOrder(seed: 1234);
+        var other = UpcomingOrder(seed: 4321);
+
+        // Assert
+        second.Should().Equal(first);
+        other.Should().NotEqual(first);
+    }
+
+    [Fact]
+    public void SetShuffle_Disable_ShouldClearSeed()
+    {
+        // ============================================================
+        // WHY: A stale seed must not be published once shuffle is off.
+        // ============================================================
+
+        // Arrange
+        var queue = new PlaybackQueue();
+        queue.SetTracks(Tracks(0, 10));
+        queue.SetShuffle(true);
+
+        // Act
+        var seedWhileShuffled = queue.ShuffleSeed;
+        queue.SetShuffle(false);
+
+        // Assert
+        seedWhileShuffled.Should().NotBeNull();
+        queue.ShuffleSeed.Should().BeNull();
+    }
+}
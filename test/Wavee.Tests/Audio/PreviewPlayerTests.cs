@@ -0,0 +1,56 @@
+using FluentAssertions;
+using Google.Protobuf;
+using Wavee.Audio;
+using Wavee.Protocol.Metadata;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PreviewPlayer - validates preview clip URLs built from track metadata.
+///
+/// WHY: Preview mode has no session to recover with; the URL is all AudioHost gets.
+/// Bugs here will cause:
+/// - Signed-out previews failing with 404s
+/// - An encrypted full-length file being fed to the preview path
+/// </summary>
+public class PreviewPlayerTests
+{
+    private static readonly byte[] FileId =
+    [
+        0x28, 0xe6, 0x27, 0x56, 0x1d, 0x00, 0x84, 0x9b, 0x82, 0x92,
+        0x75, 0x08, 0x15, 0xb5, 0x83, 0x1d, 0x9a, 0x1b, 0xd9, 0x3f
+    ];
+
+    [Fact]
+    public void GetPreviewUrl_ShouldUseLowercaseHexFileId()
+    {
+        // Act
+        var url = PreviewPlayer.GetPreviewUrl(FileId);
+
+        // Assert
+        url.Should().Be("https://p.scdn.co/mp3-preview/28e627561d00849b8292750815b5831d9a1bd93f");
+    }
+
+    [Fact]
+    public void GetPreviewUrl_FromTrack_ShouldPreferMp396()
+    {
+        // Arrange
+        var track = new Track();
+        track.Preview.Add(new AudioFile { FileId = ByteString.CopyFrom(new byte[20]), Format = AudioFile.Types.Format.Mp3160 });
+        track.Preview.Add(new AudioFile { FileId = ByteString.CopyFrom(FileId), Format = AudioFile.Types.Format.Mp396 });
+
+        // Act
+        var url = PreviewPlayer.GetPreviewUrl(track);
+
+        // Assert
+        url.Should().EndWith("28e627561d00849b8292750815b5831d9a1bd93f");
+    }
+
+    [Fact]
+    public void GetPreviewUrl_FromTrackWithoutPreview_ShouldReturnNull()
+    {
+        // Act & Assert
+        PreviewPlayer.GetPreviewUrl(new Track()).Should().BeNull();
+    }
+}
@@ -0,0 +1,76 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Core.Audio;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for PrewarmSlot - validates handing a prewarmed resolution to the play it was made for.
+///
+/// WHY: A prewarmed resolution replaces all network work at play time. Bugs here will cause:
+/// - The wrong track's head data and key being played
+/// - A stale CDN URL used after it expired
+/// - Prewarm silently doing nothing (play resolves from scratch)
+/// </summary>
+public class PrewarmSlotTests
+{
+    private const string Uri = "spotify:track:4uLU6hMCjMI75M1A2tKUQC";
+    private static readonly DateTimeOffset Now = new(2026, 10, 16, 12, 0, 0, TimeSpan.Zero);
+
+    private static TrackResolution CreateResolution(string uri) => new()
+    {
+        TrackUri = uri,
+        Codec = "vorbis",
+        Metadata = new TrackMetadataDto(),
+        AudioKeyTask = Task.FromResult(new byte[16]),
+        CdnUrlTask = Task.FromResult("https://audio.example/file"),
+        FileSizeTask = Task.FromResult(1024L)
+    };
+
+    [Fact]
+    public void Take_MatchingUri_ShouldReturnResolutionOnce()
+    {
+        // Arrange
+        var slot = new PrewarmSlot();
+        var resolution = CreateResolution(Uri);
+        slot.Store(Uri, AudioQuality.VeryHigh, resolution, Now);
+
+        // Act
+        var first = slot.Take(Uri, AudioQuality.VeryHigh, Now.AddSeconds(5));
+        var second = slot.Take(Uri, AudioQuality.VeryHigh, Now.AddSeconds(6));
+
+        // Assert
+        first.Should().BeSameAs(resolution);
+        second.Should().BeNull();
+    }
+
+    [Fact]
+    public void Take_OtherUriOrQuality_ShouldKeepPrewarm()
+    {
+        // Arrange
+        var slot = new PrewarmSlot();
+        slot.Store(Uri, AudioQuality.VeryHigh, CreateResolution(Uri), Now);
+
+        // Act
+        var otherTrack = slot.Take("spotify:track:0000000000000000000000", AudioQuality.VeryHigh, Now);
+        var otherQuality = slot.Take(Uri, AudioQuality.Normal, Now);
+
+        // Assert
+        otherTrack.Should().BeNull();
+        otherQuality.Should().BeNull();
+        slot.Take(Uri, AudioQuality.VeryHigh, Now).Should().NotBeNull();
+    }
+
+    [Fact]
+    public void Take_AfterLifetime_ShouldReturnNull()
+    {
+        // Arrange
+        var slot = new PrewarmSlot();
+        slot.Store(Uri, AudioQuality.VeryHigh, CreateResolution(Uri), Now);
+
+        // Act & Assert
+        slot.Take(Uri, AudioQuality.VeryHigh, Now + PrewarmSlot.Lifetime).Should().BeNull();
+    }
+}
@@ -0,0 +1,121 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Core.Configuration;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for TrackFilterRules - validates which user skip rule a track matches.
+///
+/// WHY: The player skips whatever these rules match, before the track starts. Bugs here will cause:
+/// - Blocked artists playing anyway on features or collaborations
+/// - Studio tracks such as "Live Forever" skipped as live recordings
+/// - Every track skipped when metadata is missing
+/// </summary>
+public class TrackFilterTests
+{
+    private const string TrackUri = "spotify:track:4uLU6hMCjMI75M1A2tKUQC";
+
+    [Fact]
+    public void Match_BlockedTrack_ShouldNameTheUri()
+    {
+        // Arrange
+        var rules = new TrackFilterRules { BlockedTracks = [TrackUri] };
+
+        // Act
+        var match = rules.Match(new TrackFilterCandidate(TrackUri));
+
+        // Assert
+        match.Should().Be(new TrackFilterMatch(TrackFilterRule.BlockedTrack, TrackUri));
+    }
+
+    [Theory]
+    [InlineData("spotify:artist:blocked", "Someone", "spotify:artist:blocked")]
+    [InlineData("spotify:artist:other", "the band", "The Band")]
+    [InlineData("spotify:artist:other", "Singer, The Band", "The Band")]
+    public void Match_BlockedArtist_ShouldMatchUriOrAnyArtistName(string artistUri, string artist, string expected)
+    {
+        // Arrange
+        var rules = new TrackFilterRules { BlockedArtists = ["spotify:artist:blocked", "The Band"] };
+
+        // Act
+        var match = rules.Match(new TrackFilterCandidate(TrackUri, Artist: artist, ArtistUri: artistUri));
+
+        // Assert
+        match.Should().NotBeNull();
+        match!.Rule.Should().Be(TrackFilterRule.BlockedArtist);
+        match.Detail.Should().BeEquivalentTo(expected);
+    }
+
+    [Fact]
+    public void Match_MaxDuration_ShouldSkipOnlyKnownLongerTracks()
+    {
+        // Arrange
+        var rules = new TrackFilterRules { MaxDuration = TimeSpan.FromMinutes(10) };
+
+        // Act & Assert
+        rules.Match(new TrackFilterCandidate(TrackUri, DurationMs: 11 * 60_000))!.Rule.Should().Be(TrackFilterRule.MaxDuration);
+        rules.Match(new TrackFilterCandidate(TrackUri, DurationMs: 10 * 60_000)).Should().BeNull();
+        rules.Match(new TrackFilterCandidate(TrackUri)).Should().BeNull("an unknown duration is checked again after resolution");
+    }
+
+    [Theory]
+    [InlineData("Song - Live", null, true)]
+    [InlineData("Song (Live at Wembley, 1986)", null, true)]
+    [InlineData("Song [Live]", null, true)]
+    [InlineData("Song", "Live at Budokan", true)]
+    [InlineData("Song", "Unplugged (Live)", true)]
+    [InlineData("Live Forever", "Definitely Maybe", false)]
+    [InlineData("Alive", "Live Wire", false)]
+    public void Match_SkipLiveVersions_ShouldRecognizeLiveTitlesAndAlbums(string title, string? album, bool expected)
+    {
+        // Arrange
+        var rules = new TrackFilterRules { SkipLiveVersions = true };
+
+        // Act
+        var match = rules.Match(new TrackFilterCandidate(TrackUri, Title: title, Album: album));
+
+        // Assert
+        (match?.Rule == TrackFilterRule.LiveVersion).Should().Be(expected);
+    }
+
+    [Fact]
+    public void Match_SeveralRules_ShouldReportTheFirstInRuleOrder()
+    {
+        // Arrange
+        var rules = new TrackFilterRules
+        {
+            BlockedArtists = ["The Band"],
+            MaxDuration = TimeSpan.FromMinutes(1),
+            SkipLiveVersions = true
+        };
+
+        // Act
+        var match = rules.Match(new TrackFilterCandidate(TrackUri, "Song - Live", "The Band", DurationMs: 120_000));
+
+        // Assert
+        match!.Rule.Should().Be(TrackFilterRule.BlockedArtist);
+    }
+
+    [Fact]
+    public void FromConfig_ShouldMapPlayerKeys()
+    {
+        // Arrange
+        var config = new PlayerConfig
+        {
+            BlockedTracks = [TrackUri],
+            MaxTrackDuration = TimeSpan.Zero,
+            SkipLiveVersions = true
+        };
+
+        // Act
+        var rules = TrackFilterRules.FromConfig(config);
+
+        // Assert
+        rules.BlockedTracks.Should().Equal(TrackUri);
+        rules.MaxDuration.Should().BeNull("zero means no limit");
+        rules.SkipLiveVersions.Should().BeTrue();
+        TrackFilterRules.FromConfig(new PlayerConfig()).IsEmpty.Should().BeTrue();
+    }
+}
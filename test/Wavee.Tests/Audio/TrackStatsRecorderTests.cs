@@ -0,0 +1,218 @@
+using FluentAssertions;
+using Wavee.Audio;
+using Wavee.Connect;
+using Wavee.Connect.Events;
+using Xunit;
+
+namespace Wavee.Tests.Audio;
+
+/// <summary>
+/// Tests for TrackStatsRecorder per-track folding and history ring buffer.
+/// </summary>
+public class TrackStatsRecorderTests
+{
+    private static readonly DateTimeOffset T0 = new(2026, 1, 1, 0, 0, 0, TimeSpan.Zero);
+
+    private static LocalPlaybackState Playing(string uri, EngineTrackCounters? counters = null) =>
+        LocalPlaybackState.Empty with { TrackUri = uri, IsPlaying = true, TrackCounters = counters };
+
+    [Fact]
+    public void Observe_TrackChange_ShouldCompletePreviousWithLatestCountersAndSeeks()
+    {
+        // ============================================================
+        // WHY: Stats are only useful if they describe the track that just
+        //      ended — the last counters the engine reported for it plus
+        //      the seeks issued while it was current, nothing from the next.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a", new EngineTrackCounters(100, false, 5, 0)), T0);
+        recorder.RecordSeek();
+        recorder.RecordSeek();
+        recorder.Observe(Playing("spotify:track:a", new EngineTrackCounters(4096, false, 80, 1)), T0.AddSeconds(5));
+
+        // Act
+        var completed = recorder.Observe(
+            Playing("spotify:track:b", new EngineTrackCounters(0, true, 0, 0)), T0.AddSeconds(200));
+
+        // Assert
+        completed.Should().NotBeNull();
+        completed!.TrackUri.Should().Be("spotify:track:a");
+        completed.BytesDownloaded.Should().Be(4096);
+        completed.FromCache.Should().BeFalse();
+        completed.DecodeTime.Should().Be(TimeSpan.FromMilliseconds(80));
+        completed.Underruns.Should().Be(1);
+        completed.SeekCount.Should().Be(2);
+        completed.StartedAt.Should().Be(T0);
+        completed.EndedAt.Should().Be(T0.AddSeconds(200));
+    }
+
+    [Fact]
+    public void Observe_IdlePushAfterStop_ShouldNotReopenTrack()
+    {
+        // ============================================================
+        // WHY: After Stop the engine keeps publishing the stopped track's
+        //      URI. Reopening it would emit a bogus second entry the next
+        //      time the track changes.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a"), T0);
+        recorder.Complete(T0.AddSeconds(10)).Should().NotBeNull();
+
+        // Act
+        recorder.Observe(LocalPlaybackState.Empty with { TrackUri = "spotify:track:a" }, T0.AddSeconds(11));
+        var afterChange = recorder.Observe(Playing("spotify:track:b"), T0.AddSeconds(12));
+
+        // Assert
+        afterChange.Should().BeNull();
+        recorder.GetHistory().Should().ContainSingle();
+    }
+
+    [Fact]
+    public void Observe_PausedAndBuffering_ShouldCountOnlyAudibleTime()
+    {
+        // ============================================================
+        // WHY: Listening stats are built from Played. Time spent paused or
+        //      waiting on the network was not heard and must not count.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        var playing = Playing("spotify:track:a") with { TrackTitle = "Song", TrackArtist = "A, B", ArtistUri = "spotify:artist:a" };
+        recorder.Observe(playing, T0);
+        recorder.Observe(playing with { IsPaused = true }, T0.AddSeconds(20));
+        recorder.Observe(playing with { IsBuffering = true }, T0.AddSeconds(80));
+        recorder.Observe(playing, T0.AddSeconds(85));
+
+        // Act
+        var completed = recorder.Complete(T0.AddSeconds(95));
+
+        // Assert
+        completed!.Played.Should().Be(TimeSpan.FromSeconds(30));
+        completed.Title.Should().Be("Song");
+        completed.Artist.Should().Be("A, B");
+        completed.ArtistUri.Should().Be("spotify:artist:a");
+    }
+
+    [Fact]
+    public void MarkEnded_Skip_ShouldStopPlayedTimeAndKeepReason()
+    {
+        // ============================================================
+        // WHY: Scrobblers need the reason and the exact time heard. Pushes
+        //      for the skipped track keep arriving until the next one loads;
+        //      counting them would inflate ms played past the skip.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a") with { PositionMs = 0 }, T0);
+        recorder.Observe(Playing("spotify:track:a") with { PositionMs = 40_000 }, T0.AddSeconds(40));
+
+        // Act
+        recorder.MarkEnded(TrackEndReason.SkippedForward, errorKind: null, positionMs: 42_500, T0.AddSeconds(42.5));
+        recorder.Observe(Playing("spotify:track:a") with { PositionMs = 43_000 }, T0.AddSeconds(43));
+        recorder.RecordSeek();
+        var completed = recorder.Observe(Playing("spotify:track:b"), T0.AddSeconds(45));
+
+        // Assert
+        completed!.TrackUri.Should().Be("spotify:track:a");
+        completed.EndReason.Should().Be(TrackEndReason.SkippedForward);
+        completed.ErrorKind.Should().BeNull();
+        completed.MsPlayed.Should().Be(42_500);
+        completed.EndedAt.Should().Be(T0.AddSeconds(42.5));
+        completed.SeekCount.Should().Be(0);
+    }
+
+    [Fact]
+    public void MarkEnded_Error_ShouldCarryErrorKindAndFirstReasonShouldWin()
+    {
+        // ============================================================
+        // WHY: A failed track is followed by a stop; the stop must not
+        //      overwrite the error, and consumers need to know what failed.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a"), T0);
+
+        // Act
+        recorder.MarkEnded(TrackEndReason.Error, PlaybackErrorType.NetworkError, 5_000, T0.AddSeconds(5));
+        recorder.MarkEnded(TrackEndReason.Stopped, errorKind: null, 5_000, T0.AddSeconds(6));
+        var completed = recorder.Complete(T0.AddSeconds(7));
+
+        // Assert
+        completed!.EndReason.Should().Be(TrackEndReason.Error);
+        completed.ErrorKind.Should().Be(PlaybackErrorType.NetworkError);
+    }
+
+    [Fact]
+    public void Observe_RepeatAfterFinish_ShouldStartNewEntryForSameTrack()
+    {
+        // ============================================================
+        // WHY: Repeat-track replays the same URI. Each play is its own
+        //      listen, so the restart (position back near zero) must close
+        //      the finished entry and open a new one.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a") with { PositionMs = 0 }, T0);
+        recorder.MarkEnded(TrackEndReason.Finished, errorKind: null, 180_000, T0.AddMinutes(3));
+
+        // Act
+        var completed = recorder.Observe(Playing("spotify:track:a") with { PositionMs = 200 }, T0.AddMinutes(3).AddSeconds(1));
+        var second = recorder.Complete(T0.AddMinutes(4).AddSeconds(1));
+
+        // Assert
+        completed!.EndReason.Should().Be(TrackEndReason.Finished);
+        completed.MsPlayed.Should().Be(180_000);
+        second!.TrackUri.Should().Be("spotify:track:a");
+        second.EndReason.Should().Be(TrackEndReason.Stopped);
+        second.Played.Should().Be(TimeSpan.FromMinutes(1));
+    }
+
+    [Fact]
+    public void Observe_UnmarkedTrackChange_ShouldReportReplaced()
+    {
+        // ============================================================
+        // WHY: A new play request moves the engine on without an explicit
+        //      end; it must still carry a reason rather than a misleading
+        //      default.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder();
+        recorder.Observe(Playing("spotify:track:a"), T0);
+
+        // Act
+        var completed = recorder.Observe(Playing("spotify:track:b"), T0.AddSeconds(10));
+
+        // Assert
+        completed!.EndReason.Should().Be(TrackEndReason.Replaced);
+        recorder.Complete(T0.AddSeconds(20))!.EndReason.Should().Be(TrackEndReason.Stopped);
+    }
+
+    [Fact]
+    public void GetHistory_BeyondCapacity_ShouldKeepMostRecentOldestFirst()
+    {
+        // ============================================================
+        // WHY: The history is a bounded ring buffer — a long session must
+        //      not grow memory, and callers expect chronological order.
+        // ============================================================
+
+        // Arrange
+        var recorder = new TrackStatsRecorder(capacity: 3);
+
+        // Act
+        for (var i = 0; i < 5; i++)
+            recorder.Observe(Playing($"spotify:track:{i}"), T0.AddMinutes(i));
+        recorder.Complete(T0.AddMinutes(5));
+
+        // Assert
+        recorder.GetHistory().Select(s => s.TrackUri)
+            .Should().Equal("spotify:track:2", "spotify:track:3", "spotify:track:4");
+    }
+}
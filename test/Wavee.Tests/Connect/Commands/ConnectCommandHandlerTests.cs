@@ -174,6 +174,27 @@ public class ConnectCommandHandlerTests
         await handler.DisposeAsync();
     }
 
+    [Fact]
+    public async Task AnyCommand_WhenReceived_ShouldAlsoDispatchToReceivedObservable()
+    {
+        // WHY: Power save wakes on any Connect command, not just play
+
+        // Arrange
+        var (handler, mockSource) = ConnectCommandTestHelpers.CreateTestCommandHandler();
+        var receivedCommands = new List<ConnectCommand>();
+        handler.Received.Subscribe(cmd => receivedCommands.Add(cmd));
+
+        // Act
+        mockSource.SimulateRequest(ConnectCommandTestHelpers.CreatePauseCommandRequest(102, "device_pause"));
+        mockSource.SimulateRequest(ConnectCommandTestHelpers.CreateResumeCommandRequest(103, "device_resume"));
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        receivedCommands.Select(c => c.Endpoint).Should().Equal("pause", "resume");
+
+        await handler.DisposeAsync();
+    }
+
     [Fact]
     public async Task SeekCommand_WhenReceived_ShouldDispatchToSeekObservable()
     {
@@ -238,6 +259,90 @@ public class ConnectCommandHandlerTests
         await handler.DisposeAsync();
     }
 
+    [Fact]
+    public async Task SkipPrevCommand_WithAllowSeekingFalse_ShouldParseOption()
+    {
+        // WHY: allow_seeking=false means "go back even if past the restart threshold"
+
+        // Arrange
+        var (handler, mockSource) = ConnectCommandTestHelpers.CreateTestCommandHandler();
+        var receivedCommands = new List<SkipPrevCommand>();
+        handler.SkipPrevCommands.Subscribe(cmd => receivedCommands.Add(cmd));
+
+        // Act
+        var request = MockCommandSource.CreateRequestFromJson(
+            messageId: 110,
+            deviceId: "device_skip",
+            messageIdent: "hm://connect-state/v1/skip_prev",
+            jsonPayload: "{\"options\":{\"allow_seeking\":false}}");
+        mockSource.SimulateRequest(request);
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        receivedCommands.Should().ContainSingle().Which.AllowSeeking.Should().BeFalse();
+
+        await handler.DisposeAsync();
+    }
+
+    [Fact]
+    public async Task PlayCommand_WithShuffleSeedInContextMetadata_ShouldParseSeed()
+    {
+        // WHY: A peer's shuffle seed lets the queue reproduce the order it displayed
+
+        // Arrange
+        var (handler, mockSource) = ConnectCommandTestHelpers.CreateTestCommandHandler();
+        var receivedCommands = new List<PlayCommand>();
+        handler.PlayCommands.Subscribe(cmd => receivedCommands.Add(cmd));
+
+        // Act
+        var request = MockCommandSource.CreateRequestFromJson(
+            messageId: 111,
+            deviceId: "device_play",
+            messageIdent: "hm://connect-state/v1/play",
+            jsonPayload: "{\"context\":{\"uri\":\"spotify:playlist:abc\",\"metadata\":{\"shuffle.seed\":\"98765\"}}," +
+                         "\"options\":{\"player_options_override\":{\"shuffling_context\":true}}}");
+        mockSource.SimulateRequest(request);
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        var options = receivedCommands.Should().ContainSingle().Which.Options;
+        options!.ShufflingContext.Should().BeTrue();
+        options.ShuffleSeed.Should().Be(98765);
+
+        await handler.DisposeAsync();
+    }
+
+    [Fact]
+    public async Task PlayCommand_WithPlaceholderPages_ShouldKeepInlinedTracksAndFirstPageUrl()
+    {
+        // WHY: Remotes inline page 0 and leave later pages as page_url placeholders;
+        //      losing the URL stops playback at the end of the first page
+
+        // Arrange
+        var (handler, mockSource) = ConnectCommandTestHelpers.CreateTestCommandHandler();
+        var receivedCommands = new List<PlayCommand>();
+        handler.PlayCommands.Subscribe(cmd => receivedCommands.Add(cmd));
+
+        // Act
+        var request = MockCommandSource.CreateRequestFromJson(
+            messageId: 112,
+            deviceId: "device_play",
+            messageIdent: "hm://connect-state/v1/play",
+            jsonPayload: "{\"context\":{\"uri\":\"spotify:playlist:abc\",\"pages\":[" +
+                         "{\"tracks\":[{\"uri\":\"spotify:track:a\",\"uid\":\"u1\"},{\"uri\":\"spotify:track:b\",\"uid\":\"u2\"}]}," +
+                         "{\"page_url\":\"hm://playlist/v2/playlist/abc/page/1\"}," +
+                         "{\"page_url\":\"hm://playlist/v2/playlist/abc/page/2\"}]}}");
+        mockSource.SimulateRequest(request);
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        var command = receivedCommands.Should().ContainSingle().Subject;
+        command.PageTracks!.Select(t => t.Uri).Should().Equal("spotify:track:a", "spotify:track:b");
+        command.NextPageUrl.Should().Be("hm://playlist/v2/playlist/abc/page/1");
+
+        await handler.DisposeAsync();
+    }
+
     [Fact]
     public async Task ShuffleCommand_WhenReceived_ShouldDispatchToShuffleObservable()
     {
@@ -920,4 +1025,101 @@ public class ConnectCommandHandlerTests
 
         await handler.DisposeAsync();
     }
+
+    // ================================================================
+    // SECTION 9: COMMAND HISTORY
+    // ================================================================
+
+    [Fact]
+    public async Task CommandLog_ShouldRecordSenderAndReplyForEveryCommand()
+    {
+        // WHY: The history answers "what paused my music?" - it must name the
+        // sending device and include commands the device refused
+
+        // Arrange
+        var mockSource = new MockCommandSource();
+        var log = new RemoteCommandLog(
+            resolveDeviceName: id => id == "phone" ? "Pixel 8" : null,
+            resolveUsername: () => "alice");
+        var handler = new ConnectCommandHandler(mockSource, commandLog: log);
+
+        // Act
+        mockSource.SimulateRequest(ConnectCommandTestHelpers.CreateSeekCommandRequest(700, "phone", 42000));
+        mockSource.SimulateRequest(ConnectCommandTestHelpers.CreateUnknownCommandRequest(701, "tablet", "unsupported_endpoint"));
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        var entries = log.GetEntries();
+        entries.Should().HaveCount(2);
+        entries.Should().ContainSingle(e => e.Endpoint == "seek_to").Which.Should().BeEquivalentTo(new
+        {
+            SenderDeviceId = "phone",
+            SenderDeviceName = "Pixel 8",
+            Username = "alice",
+            Detail = "42000ms",
+            Result = (RequestResult?)RequestResult.Success
+        });
+        entries.Should().ContainSingle(e => e.Endpoint == "unsupported_endpoint").Which.Result
+            .Should().Be(RequestResult.DeviceDoesNotSupportCommand);
+
+        await handler.DisposeAsync();
+    }
+
+    // ================================================================
+    // SECTION 10: COMMAND POLICY
+    // ================================================================
+
+    [Fact]
+    public async Task CommandPolicy_Rejection_ShouldReplyWithResultAndNotDispatch()
+    {
+        // WHY: A refused command must not reach the player, and the sender must be
+        // told it failed rather than seeing a silent success
+
+        // Arrange
+        var mockSource = new MockCommandSource();
+        var log = new RemoteCommandLog();
+        var handler = new ConnectCommandHandler(mockSource, commandLog: log, policy: new QuietHoursPolicy());
+        var transfers = new List<TransferCommand>();
+        var pauses = new List<PauseCommand>();
+        handler.TransferCommands.Subscribe(transfers.Add);
+        handler.PauseCommands.Subscribe(pauses.Add);
+
+        // Act
+        mockSource.SimulateRequest(MockCommandSource.CreateRequestFromJson(
+            messageId: 800,
+            deviceId: "phone",
+            messageIdent: "hm://connect-state/v1/transfer",
+            jsonPayload: "{}"));
+        mockSource.SimulateRequest(ConnectCommandTestHelpers.CreatePauseCommandRequest(801, "phone"));
+        await ConnectCommandTestHelpers.WaitForProcessingAsync();
+
+        // Assert
+        transfers.Should().BeEmpty();
+        pauses.Should().HaveCount(1, "the policy only refuses transfers");
+        mockSource.SentReplies.Should().Contain(r => r.key == "800/phone" && r.result == RequestResult.RateLimited);
+        mockSource.SentReplies.Should().Contain(r => r.key == "801/phone" && r.result == RequestResult.Success);
+        log.GetEntries().Should().ContainSingle(e => e.Endpoint == "transfer")
+            .Which.RejectionReason.Should().Be("quiet hours");
+
+        await handler.DisposeAsync();
+    }
+
+    [Fact]
+    public void RemoteCommandDecision_RejectWithSuccess_ShouldThrow()
+    {
+        // WHY: A rejection that replies Success would tell the sender the command worked
+
+        // Act
+        var act = () => RemoteCommandDecision.Reject("nope", RequestResult.Success);
+
+        // Assert
+        act.Should().Throw<ArgumentException>();
+    }
+
+    private sealed class QuietHoursPolicy : IRemoteCommandPolicy
+    {
+        public RemoteCommandDecision Evaluate(ConnectCommand command) => command is TransferCommand
+            ? RemoteCommandDecision.Reject("quiet hours", RequestResult.RateLimited)
+            : RemoteCommandDecision.Allow;
+    }
 }
@@ -0,0 +1,59 @@
+using FluentAssertions;
+using Wavee.Connect.Commands;
+using Wavee.Connect.Protocol;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Connect.Commands;
+
+/// <summary>
+/// Tests for RemoteCommandLog - validates the bounded command history.
+///
+/// WHY: The log runs for the lifetime of a daemon. Bugs here will cause:
+/// - Unbounded memory growth on busy households
+/// - Entries without a timestamp or sender, useless for tracing a stray pause
+/// </summary>
+public class RemoteCommandLogTests
+{
+    [Fact]
+    public void Record_BeyondCapacity_ShouldEvictOldestEntries()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var log = new RemoteCommandLog(capacity: 2, timeProvider: time);
+
+        // Act
+        log.Record("pause", "a", null, RequestResult.Success);
+        time.Advance(TimeSpan.FromSeconds(1));
+        log.Record("resume", "b", null, RequestResult.Success);
+        time.Advance(TimeSpan.FromSeconds(1));
+        log.Record("skip_next", "c", null, RequestResult.Success);
+
+        // Assert
+        var entries = log.GetEntries();
+        entries.Select(e => e.Endpoint).Should().Equal("resume", "skip_next");
+        entries[1].ReceivedAt.Should().Be(time.GetUtcNow());
+    }
+
+    [Fact]
+    public void Record_WithoutSender_ShouldNotResolveDeviceName()
+    {
+        // WHY: Volume messages carry no sender id; they must not be pinned on some device
+
+        // Arrange
+        var resolved = 0;
+        var log = new RemoteCommandLog(resolveDeviceName: _ => { resolved++; return "Phone"; });
+        RemoteCommandEntry? emitted = null;
+        using var subscription = log.Recorded.Subscribe(e => emitted = e);
+
+        // Act
+        log.Record("set_volume", null, "40%", result: null);
+
+        // Assert
+        resolved.Should().Be(0);
+        emitted.Should().NotBeNull();
+        emitted!.SenderDeviceId.Should().BeEmpty();
+        emitted.SenderDeviceName.Should().BeNull();
+        emitted.Detail.Should().Be("40%");
+    }
+}
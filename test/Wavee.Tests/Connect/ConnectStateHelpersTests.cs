@@ -112,6 +112,38 @@ public sealed class ConnectStateHelpersTests
         deviceInfo.Capabilities.VolumeSteps.Should().Be(customVolumeSteps, "custom volume steps applied");
     }
 
+    [Fact]
+    public void CreateDeviceInfo_WithConnectDeviceConfig_ShouldAdvertiseConfiguredCapabilities()
+    {
+        // WHY: Remote UIs pick their controls from these flags; a fixed-volume DAC
+        //      must not show a slider that does nothing
+
+        // Arrange
+        var config = new SessionConfig
+        {
+            DeviceId = "test_device",
+            ConnectDevice = new ConnectDeviceConfig
+            {
+                VolumeSteps = 20,
+                SupportsVolume = false,
+                SupportsRename = true,
+                Brand = "Acme",
+                Model = "Streamer 2"
+            }
+        };
+
+        // Act
+        var deviceInfo = ConnectStateHelpers.CreateDeviceInfo(config, volume: 1000);
+
+        // Assert
+        deviceInfo.Capabilities.VolumeSteps.Should().Be(20);
+        deviceInfo.Capabilities.DisableVolume.Should().BeTrue();
+        deviceInfo.Capabilities.SupportsRename.Should().BeTrue();
+        deviceInfo.Volume.Should().Be((uint)ConnectStateHelpers.MaxVolume, "fixed-volume devices report full volume");
+        deviceInfo.Brand.Should().Be("Acme");
+        deviceInfo.Model.Should().Be("Streamer 2");
+    }
+
     [Theory]
     [InlineData(DeviceType.Computer)]
     [InlineData(DeviceType.Tablet)]
@@ -0,0 +1,57 @@
+using FluentAssertions;
+using Wavee.Connect;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Connect;
+
+/// <summary>
+/// Tests for ConnectZoneHost - validates how zone session configs derive from the host's base config.
+///
+/// WHY: Every zone is a separate Connect device. Bugs here will cause:
+/// - Zones colliding on one device id (remotes see a single flickering device)
+/// - Zone overrides (fixed volume, device type) silently ignored
+/// </summary>
+public class ConnectZoneHostTests
+{
+    private static readonly SessionConfig BaseConfig = new()
+    {
+        DeviceId = "base-device",
+        DeviceName = "Server",
+        DeviceType = DeviceType.Computer
+    };
+
+    [Fact]
+    public void CreateZoneConfig_ShouldDeriveIdAndKeepSharedSettings()
+    {
+        // Act
+        var config = ConnectZoneHost.CreateZoneConfig(BaseConfig, new ConnectZoneOptions { DeviceName = "Kitchen" });
+
+        // Assert
+        config.DeviceName.Should().Be("Kitchen");
+        config.DeviceId.Should().Be(DeviceIdGenerator.Derive("base-device", "Kitchen"));
+        config.DeviceType.Should().Be(DeviceType.Computer);
+        config.Network.Should().BeSameAs(BaseConfig.Network);
+    }
+
+    [Fact]
+    public void CreateZoneConfig_WithOverrides_ShouldApplyThem()
+    {
+        // Arrange
+        var options = new ConnectZoneOptions
+        {
+            DeviceName = "Patio",
+            DeviceId = "patio-id",
+            DeviceType = DeviceType.Speaker,
+            ConnectDevice = new ConnectDeviceConfig { SupportsVolume = false }
+        };
+
+        // Act
+        var config = ConnectZoneHost.CreateZoneConfig(BaseConfig, options);
+
+        // Assert
+        config.DeviceId.Should().Be("patio-id");
+        config.DeviceType.Should().Be(DeviceType.Speaker);
+        config.ConnectDevice.SupportsVolume.Should().BeFalse();
+    }
+}
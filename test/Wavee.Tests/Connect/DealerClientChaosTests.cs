@@ -0,0 +1,154 @@
+using System.Diagnostics;
+using System.Net;
+using System.Text;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Connect;
+using Wavee.Connect.Connection;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Connect;
+
+/// <summary>
+/// Tests for DealerClient and HTTP callers under simulated bad networks (ChaosDealerConnection,
+/// ChaosHttpHandler) - validates reconnect after a dropped socket, delivery under latency and
+/// timeouts on slow links.
+///
+/// WHY: Real dealer and CDN connections drop, stall and crawl. Bugs here will cause:
+/// - Connect going silent after a Wi-Fi blip because no reconnect was scheduled
+/// - Messages lost while a slow link delays them
+/// - Requests hanging past their timeout on a throttled connection
+/// </summary>
+public class DealerClientChaosTests
+{
+    [Fact]
+    public async Task DroppedConnection_ShouldReconnect()
+    {
+        // Arrange
+        var inner = new MockDealerConnection();
+        var chaos = new ChaosDealerConnection(inner, new ChaosOptions { Latency = TimeSpan.FromMilliseconds(10) });
+        await using var client = new DealerClient(CreateConfig(), connection: chaos);
+        await client.ConnectAsync(DealerTestHelpers.CreateMockSession(), CreateDealerHttpClient());
+
+        var states = new List<ConnectionState>();
+        using var subscription = client.ConnectionState.Subscribe(s => { lock (states) states.Add(s); });
+
+        // Act
+        await chaos.DisconnectAsync();
+        var reconnected = await WaitUntilAsync(() =>
+        {
+            lock (states)
+                return states.Contains(ConnectionState.Disconnected) && client.CurrentState == ConnectionState.Connected;
+        });
+
+        // Assert
+        reconnected.Should().BeTrue("the dealer should reconnect after the socket drops");
+        chaos.Disconnects.Should().Be(1);
+        inner.State.Should().Be(ConnectionState.Connected);
+    }
+
+    [Fact]
+    public async Task MessagesUnderLatency_ShouldAllArriveInOrder()
+    {
+        // Arrange
+        var inner = new MockDealerConnection();
+        var chaos = new ChaosDealerConnection(inner, new ChaosOptions
+        {
+            Seed = 3,
+            Latency = TimeSpan.FromMilliseconds(5),
+            Jitter = TimeSpan.FromMilliseconds(10)
+        });
+        await using var client = new DealerClient(CreateConfig(), connection: chaos);
+        await client.ConnectAsync(DealerTestHelpers.CreateMockSession(), CreateDealerHttpClient());
+
+        var uris = new List<string>();
+        using var subscription = client.Messages.Subscribe(m => { lock (uris) uris.Add(m.Uri); });
+
+        // Act
+        for (var i = 0; i < 5; i++)
+            await inner.SimulateMessageAsync(DealerTestHelpers.CreateDealerMessage($"hm://test/{i}"));
+        var delivered = await WaitUntilAsync(() => { lock (uris) return uris.Count == 5; });
+
+        // Assert
+        delivered.Should().BeTrue();
+        uris.Should().Equal(Enumerable.Range(0, 5).Select(i => $"hm://test/{i}"));
+    }
+
+    [Fact]
+    public async Task ThrottledResponse_ShouldTakeAsLongAsTheLinkNeeds()
+    {
+        // Arrange
+        var handler = new ChaosHttpHandler(
+            new FixedBodyHandler(new byte[20_000]),
+            new ChaosOptions { BytesPerSecond = 100_000 });
+        using var httpClient = new HttpClient(handler);
+        var stopwatch = Stopwatch.StartNew();
+
+        // Act
+        var body = await httpClient.GetByteArrayAsync("https://audio4-fa.scdn.co/audio/abcd");
+
+        // Assert
+        body.Should().HaveCount(20_000);
+        stopwatch.Elapsed.Should().BeGreaterThan(TimeSpan.FromMilliseconds(150), "20 KB at 100 KB/s needs ~200 ms");
+    }
+
+    [Fact]
+    public async Task SlowResponse_ShouldHitClientTimeout()
+    {
+        // Arrange
+        var handler = new ChaosHttpHandler(
+            new FixedBodyHandler(new byte[10]),
+            new ChaosOptions { Latency = TimeSpan.FromSeconds(5) });
+        using var httpClient = new HttpClient(handler) { Timeout = TimeSpan.FromMilliseconds(100) };
+
+        // Act
+        var act = () => httpClient.GetByteArrayAsync("https://spclient.wg.spotify.com/metadata/4/track/abcd");
+
+        // Assert
+        await act.Should().ThrowAsync<TaskCanceledException>();
+    }
+
+    private static DealerClientConfig CreateConfig() => new()
+    {
+        // No PONGs come back from the mock, so keep the heartbeat out of the way.
+        PingInterval = TimeSpan.FromMinutes(5),
+        PongTimeout = TimeSpan.FromMinutes(1),
+        EnableAutoReconnect = true,
+        InitialReconnectDelay = TimeSpan.FromMilliseconds(50),
+        MaxReconnectDelay = TimeSpan.FromSeconds(1),
+        MaxReconnectAttempts = 3
+    };
+
+    // A fresh response per call: the dealer list is resolved again on every reconnect.
+    private static HttpClient CreateDealerHttpClient()
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        handler.Protected()
+            .Setup<Task<HttpResponseMessage>>("SendAsync", ItExpr.IsAny<HttpRequestMessage>(), ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent("{\"dealer\":[\"dealer.spotify.com:443\"]}", Encoding.UTF8, "application/json")
+            });
+        return new HttpClient(handler.Object);
+    }
+
+    private static async Task<bool> WaitUntilAsync(Func<bool> condition)
+    {
+        var deadline = DateTime.UtcNow + TimeSpan.FromSeconds(5);
+        while (DateTime.UtcNow < deadline)
+        {
+            if (condition())
+                return true;
+            await Task.Delay(10);
+        }
+        return condition();
+    }
+
+    private sealed class FixedBodyHandler(byte[] body) : HttpMessageHandler
+    {
+        protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+            => Task.FromResult(new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent(body) });
+    }
+}
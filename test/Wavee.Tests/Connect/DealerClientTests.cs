@@ -736,4 +736,35 @@ public class DealerClientTests
 
         await client.DisposeAsync();
     }
+
+    [Fact]
+    public async Task GetTopicCounts_ShouldGroupMessagesByTopic()
+    {
+        // ============================================================
+        // WHY: Session diagnostics show which dealer pushes are flowing.
+        //      Per-connection ids in the URI must not create new topics.
+        // ============================================================
+
+        // Arrange
+        var mockConnection = new MockDealerConnection();
+        var client = new DealerClient(DealerTestHelpers.CreateTestConfig(), connection: mockConnection);
+        await mockConnection.ConnectAsync("wss://test.spotify.com");
+
+        // Act
+        await mockConnection.SimulateMessageAsync(DealerTestHelpers.CreateDealerMessage(
+            "hm://connect-state/v1/cluster", new Dictionary<string, string>(), [1]));
+        await mockConnection.SimulateMessageAsync(DealerTestHelpers.CreateDealerMessage(
+            "hm://connect-state/v1/cluster", new Dictionary<string, string>(), [2]));
+        await mockConnection.SimulateMessageAsync(DealerTestHelpers.CreateDealerMessage(
+            "hm://pusher/v1/connections/abc123", new Dictionary<string, string>(), []));
+        await Task.Delay(100);
+
+        // Assert
+        var topics = client.GetTopicCounts();
+        topics.Should().Contain("hm://connect-state/v1/cluster", 2);
+        topics.Should().Contain("hm://pusher/v1/connections", 1);
+        topics.Keys.Should().NotContain(k => k.Contains("abc123"));
+
+        await client.DisposeAsync();
+    }
 }
@@ -0,0 +1,172 @@
+using System.Text;
+using System.Text.Json;
+using FluentAssertions;
+using Wavee.Connect;
+using Wavee.Connect.Commands;
+using Wavee.Connect.Diagnostics;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Connect.Diagnostics;
+
+/// <summary>
+/// Tests for DealerTrafficRecorder, DealerTrafficLog and DealerReplayConnection - validates
+/// redaction of recorded dealer frames, the log round trip, and replaying a log through
+/// DealerClient into ConnectCommandHandler.
+///
+/// WHY: Connect command bugs are reported from real sessions we can't reproduce by hand.
+/// Bugs here will cause:
+/// - Access tokens or cookies leaking into logs users attach to public issues
+/// - Logs that can't be read back, so a report can't be turned into a test
+/// - Replays that dispatch different commands or replies than the live session did
+/// </summary>
+public class DealerTrafficReplayTests
+{
+    private static string FixturePath(string name)
+        => Path.Combine(AppContext.BaseDirectory, "Connect", "Diagnostics", "Logs", name);
+
+    [Fact]
+    public void Redact_ShouldHideTokensAndSecretQueryParameters()
+    {
+        // Arrange
+        var frame = Encoding.UTF8.GetBytes("""
+            {"type":"message","uri":"hm://pusher/v1/connections/abc",
+             "headers":{"Authorization":"Bearer secret-token","Content-Type":"application/json"},
+             "payloads":[{"access_token":"secret-token","url":"wss://dealer.spotify.com/?access_token=secret-token&x=1"}],
+             "key":"1/phone"}
+            """);
+
+        // Act
+        var redacted = DealerTrafficRecorder.Redact(frame);
+
+        // Assert
+        redacted.Should().NotBeNull();
+        redacted.Should().NotContain("secret-token");
+        using var doc = JsonDocument.Parse(redacted!);
+        var root = doc.RootElement;
+        root.GetProperty("uri").GetString().Should().Be("hm://pusher/v1/connections/abc");
+        root.GetProperty("key").GetString().Should().Be("1/phone", "request keys are needed to match replies");
+        root.GetProperty("headers").GetProperty("Content-Type").GetString().Should().Be("application/json");
+        root.GetProperty("payloads")[0].GetProperty("url").GetString().Should().Contain("x=1");
+    }
+
+    [Fact]
+    public void Redact_WhenNotJson_ShouldReturnNull()
+    {
+        // Act
+        var redacted = DealerTrafficRecorder.Redact("not json {"u8);
+
+        // Assert
+        redacted.Should().BeNull();
+    }
+
+    [Fact]
+    public void RecordedLog_ShouldReadBackWithOffsetsAndDirections()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var writer = new StringWriter();
+        using (var recorder = new DealerTrafficRecorder(writer, timeProvider: time))
+        {
+            // Act
+            recorder.RecordInbound("""{"type":"ping"}"""u8);
+            time.Advance(TimeSpan.FromMilliseconds(250));
+            recorder.RecordOutbound("""{"type":"pong"}""");
+            recorder.RecordInbound("garbage"u8);
+        }
+
+        var entries = DealerTrafficLog.Parse(writer.ToString());
+
+        // Assert
+        entries.Should().HaveCount(3);
+        entries[0].Should().Be(new DealerTrafficEntry(TimeSpan.Zero, RemoteStateDirection.Inbound, """{"type":"ping"}"""));
+        entries[1].Should().Be(new DealerTrafficEntry(TimeSpan.FromMilliseconds(250), RemoteStateDirection.Outbound, """{"type":"pong"}"""));
+        entries[2].Json.Should().BeNull("invalid frames are kept only as a placeholder");
+    }
+
+    [Fact]
+    public void Parse_WhenLineIsNotAnEntry_ShouldThrowWithLineNumber()
+    {
+        // Act
+        var act = () => DealerTrafficLog.Parse("""
+            {"offsetMs":0,"direction":"Inbound","message":{"type":"ping"}}
+            {"direction":"Sideways"}
+            """);
+
+        // Assert
+        act.Should().Throw<FormatException>().WithMessage("Line 2*");
+    }
+
+    [Fact]
+    public async Task ReplayedLog_ShouldDispatchSameCommandsAndReplies()
+    {
+        // Arrange
+        var replay = new DealerReplayConnection(DealerTrafficLog.ReadFile(FixturePath("pause-seek-skip.jsonl")));
+        await using var client = new DealerClient(DealerTestHelpers.CreateTestConfig(), connection: replay);
+        await using var handler = new ConnectCommandHandler(client);
+
+        var endpoints = new List<string>();
+        long? seekPosition = null;
+        using var pauses = handler.PauseCommands.Subscribe(c => { lock (endpoints) endpoints.Add(c.Endpoint); });
+        using var seeks = handler.SeekCommands.Subscribe(c =>
+        {
+            seekPosition = c.PositionMs;
+            lock (endpoints) endpoints.Add(c.Endpoint);
+        });
+        using var skips = handler.SkipNextCommands.Subscribe(c => { lock (endpoints) endpoints.Add(c.Endpoint); });
+
+        // Act
+        var delivered = await replay.ReplayAsync();
+        var complete = await WaitUntilAsync(() => replay.Sent.Count >= replay.RecordedOutbound.Count);
+
+        // Assert
+        delivered.Should().Be(5);
+        complete.Should().BeTrue("every recorded reply and pong should be sent again");
+        endpoints.Should().Equal("pause", "seek_to", "skip_next");
+        seekPosition.Should().Be(42000);
+        replay.Sent.Select(Normalize).Should().BeEquivalentTo(replay.RecordedOutbound.Select(Normalize));
+    }
+
+    [Fact]
+    public async Task ReplayAsync_WithTiming_ShouldWaitForRecordedOffsets()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var replay = new DealerReplayConnection(
+        [
+            new DealerTrafficEntry(TimeSpan.Zero, RemoteStateDirection.Inbound, """{"type":"ping"}"""),
+            new DealerTrafficEntry(TimeSpan.FromSeconds(30), RemoteStateDirection.Inbound, """{"type":"ping"}""")
+        ], time);
+
+        var received = 0;
+        replay.MessageReceived += _ =>
+        {
+            Interlocked.Increment(ref received);
+            return ValueTask.CompletedTask;
+        };
+
+        // Act
+        var replaying = replay.ReplayAsync(preserveTiming: true);
+        time.WaitForPendingTimers().Should().BeTrue();
+
+        // Assert
+        Volatile.Read(ref received).Should().Be(1, "the second frame was recorded 30 s later");
+        time.Advance(TimeSpan.FromSeconds(30));
+        (await replaying).Should().Be(2);
+    }
+
+    private static string Normalize(string json)
+        => JsonSerializer.Serialize(JsonDocument.Parse(json).RootElement);
+
+    private static async Task<bool> WaitUntilAsync(Func<bool> condition)
+    {
+        var deadline = DateTime.UtcNow + TimeSpan.FromSeconds(5);
+        while (DateTime.UtcNow < deadline)
+        {
+            if (condition())
+                return true;
+            await Task.Delay(10);
+        }
+        return condition();
+    }
+}
@@ -215,6 +215,32 @@ public class HeartbeatManagerTests
         manager.StopAsync().AsTask().Wait();
     }
 
+    [Fact]
+    public async Task SetPingInterval_WhileRunning_ShouldRescheduleTimer()
+    {
+        // Arrange
+        var pingSignal = new ManualResetEventSlim(false);
+        var manager = new HeartbeatManager(
+            pingInterval: TimeSpan.FromMinutes(10),
+            pongTimeout: TimeSpan.FromMilliseconds(500),
+            sendPingAsync: () =>
+            {
+                pingSignal.Set();
+                return ValueTask.CompletedTask;
+            });
+
+        manager.Start();
+
+        // Act
+        manager.SetPingInterval(TimeSpan.FromMilliseconds(100));
+        var received = pingSignal.Wait(TimeSpan.FromSeconds(1));
+
+        await manager.StopAsync();
+
+        // Assert
+        received.Should().BeTrue("the shorter interval should apply without a restart");
+    }
+
     // ================================================================
     // THREAD SAFETY TESTS - Concurrent operations
     // ================================================================
@@ -0,0 +1,66 @@
+using FluentAssertions;
+using Moq;
+using Wavee.Connect;
+using Wavee.Connect.Metadata;
+using Wavee.Core.Http;
+using Wavee.Core.Http.Pathfinder;
+using Xunit;
+
+namespace Wavee.Tests.Connect.Metadata;
+
+/// <summary>
+/// Tests for CoverPaletteService and CoverPalette - validates palette mapping and vibrant derivation.
+///
+/// WHY: Frontends theme the whole player from this palette. Bugs here will cause:
+/// - Invented hues on black-and-white covers
+/// - Covers referenced as spotify:image URIs never being analysed
+/// </summary>
+public class CoverPaletteServiceTests
+{
+    [Fact]
+    public async Task GetPaletteAsync_ShouldMapColorsAndNormaliseImageUri()
+    {
+        // Arrange
+        var colors = new Mock<IColorService>();
+        colors
+            .Setup(c => c.GetColorAsync("https://i.scdn.co/image/ab67", It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new ExtractedColor("#0A1020", "#C0D0F0", "#1E3264"));
+        var service = new CoverPaletteService(colors.Object);
+
+        // Act
+        var palette = await service.GetPaletteAsync(new TrackInfo { Uri = "spotify:track:a", ImageLargeUrl = "spotify:image:ab67" });
+
+        // Assert
+        palette!.Dominant.Should().Be("#1E3264");
+        palette.Dark.Should().Be("#0A1020");
+        palette.Light.Should().Be("#C0D0F0");
+        palette.Vibrant.Should().NotBe(palette.Dominant);
+    }
+
+    [Fact]
+    public async Task GetPaletteAsync_TrackWithoutCover_ShouldNotQuery()
+    {
+        // Arrange
+        var colors = new Mock<IColorService>();
+        var service = new CoverPaletteService(colors.Object);
+
+        // Act
+        var palette = await service.GetPaletteAsync(new TrackInfo { Uri = "spotify:track:a" });
+
+        // Assert
+        palette.Should().BeNull();
+        colors.VerifyNoOtherCalls();
+    }
+
+    [Theory]
+    [InlineData("#336699", "#2273C3")]
+    [InlineData("#808080", "#808080")]
+    [InlineData("not-a-color", null)]
+    public void ToVibrant_ShouldSaturateColorsAndKeepGreys(string input, string? expected)
+    {
+        // WHY: Boosting a grey would give a black-and-white cover a red accent
+
+        // Act & Assert
+        CoverPalette.ToVibrant(input).Should().Be(expected);
+    }
+}
@@ -0,0 +1,116 @@
+using FluentAssertions;
+using Wavee.Connect;
+using Wavee.Connect.Metadata;
+using Xunit;
+
+namespace Wavee.Tests.Connect.Metadata;
+
+/// <summary>
+/// Tests for MetadataProviderChain - validates merge order, caching and failure isolation.
+///
+/// WHY: Providers call external services on every track change. Bugs here will cause:
+/// - Spotify's own fields being overwritten by third-party guesses
+/// - One flaky service blanking out data from all the others
+/// - Rate limits from re-querying services for tracks already looked up
+/// </summary>
+public class MetadataProviderChainTests
+{
+    private static readonly TrackInfo Track = new()
+    {
+        Uri = "spotify:track:abc",
+        Metadata = new Dictionary<string, string> { ["title"] = "Song" }
+    };
+
+    [Fact]
+    public async Task EnrichAsync_ShouldMergeInOrderWithoutOverwriting()
+    {
+        // Arrange
+        var chain = new MetadataProviderChain(
+        [
+            new StubProvider("isrc", _ => new() { ["isrc"] = "GB123", ["title"] = "Wrong" }),
+            new StubProvider("bpm", t => new() { ["bpm"] = t.Metadata["isrc"] == "GB123" ? "128" : "?", ["isrc"] = "XX" })
+        ]);
+
+        // Act
+        var result = await chain.EnrichAsync(Track);
+
+        // Assert
+        result.Metadata.Should().Equal(new Dictionary<string, string>
+        {
+            ["title"] = "Song",
+            ["isrc"] = "GB123",
+            ["bpm"] = "128"
+        });
+        Track.Metadata.Should().HaveCount(1, "the input track is not mutated");
+    }
+
+    [Fact]
+    public async Task EnrichAsync_SameTrackTwice_ShouldQueryProviderOnce()
+    {
+        // Arrange
+        var provider = new StubProvider("genre", _ => new() { ["genre"] = "house" });
+        var chain = new MetadataProviderChain([provider]);
+
+        // Act
+        await chain.EnrichAsync(Track);
+        var second = await chain.EnrichAsync(Track);
+
+        // Assert
+        provider.Calls.Should().Be(1);
+        second.Metadata["genre"].Should().Be("house");
+    }
+
+    [Fact]
+    public async Task EnrichAsync_WhenProviderFails_ShouldKeepOthersAndRetryLater()
+    {
+        // Arrange
+        var failing = new StubProvider("broken", _ => throw new HttpRequestException("503"));
+        var chain = new MetadataProviderChain([failing, new StubProvider("mood", _ => new() { ["mood"] = "calm" })]);
+
+        // Act
+        var first = await chain.EnrichAsync(Track);
+        await chain.EnrichAsync(Track);
+
+        // Assert
+        first.Metadata["mood"].Should().Be("calm");
+        failing.Calls.Should().Be(2, "failures are not cached");
+    }
+
+    [Fact]
+    public async Task EnrichAsync_WhenProviderTimesOut_ShouldSkipIt()
+    {
+        // Arrange
+        var slow = new SlowProvider();
+        var chain = new MetadataProviderChain([slow], providerTimeout: TimeSpan.FromMilliseconds(50));
+
+        // Act
+        var result = await chain.EnrichAsync(Track);
+
+        // Assert
+        result.Should().BeSameAs(Track);
+    }
+
+    private sealed class StubProvider(string name, Func<TrackInfo, Dictionary<string, string>> lookup) : IMetadataProvider
+    {
+        public int Calls { get; private set; }
+
+        public string Name => name;
+
+        public ValueTask<IReadOnlyDictionary<string, string>?> GetMetadataAsync(TrackInfo track, CancellationToken cancellationToken)
+        {
+            Calls++;
+            return ValueTask.FromResult<IReadOnlyDictionary<string, string>?>(lookup(track));
+        }
+    }
+
+    private sealed class SlowProvider : IMetadataProvider
+    {
+        public string Name => "slow";
+
+        public async ValueTask<IReadOnlyDictionary<string, string>?> GetMetadataAsync(TrackInfo track, CancellationToken cancellationToken)
+        {
+            await Task.Delay(Timeout.Infinite, cancellationToken);
+            return null;
+        }
+    }
+}
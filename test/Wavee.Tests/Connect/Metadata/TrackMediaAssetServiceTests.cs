@@ -0,0 +1,90 @@
+using FluentAssertions;
+using Moq;
+using Wavee.Connect;
+using Wavee.Connect.Metadata;
+using Wavee.Core.Http;
+using Wavee.Core.Http.Pathfinder;
+using Xunit;
+
+namespace Wavee.Tests.Connect.Metadata;
+
+/// <summary>
+/// Tests for TrackMediaAssetService - validates response mapping and caching.
+///
+/// WHY: Assets are fetched on every track change. Bugs here will cause:
+/// - Canvas videos missing from the Now Playing view
+/// - A Pathfinder request per replay instead of per track
+/// </summary>
+public class TrackMediaAssetServiceTests
+{
+    private static readonly TrackInfo Track = new()
+    {
+        Uri = "spotify:track:abc",
+        ArtistUri = "spotify:artist:xyz"
+    };
+
+    [Fact]
+    public async Task GetAssetsAsync_ShouldMapCanvasAndVideos()
+    {
+        // Arrange
+        var pathfinder = CreatePathfinder(new NpvTrackUnion
+        {
+            Canvas = new NpvCanvas { Url = "https://canvaz.scdn.co/a.mp4", Type = "VIDEO_LOOPING", FileId = "f1" },
+            RelatedVideos = new NpvRelatedVideosPage
+            {
+                Items = [new NpvRelatedVideo { TrackOfVideo = new NpvTrackOfVideo { Data = new NpvTrackOfVideoData { Uri = "spotify:track:video" } } }]
+            }
+        });
+        var service = new TrackMediaAssetService(pathfinder.Object);
+
+        // Act
+        var assets = await service.GetAssetsAsync(Track);
+
+        // Assert
+        assets!.Canvas.Should().Be(new CanvasAsset("https://canvaz.scdn.co/a.mp4", "VIDEO_LOOPING", "f1", null));
+        assets.Canvas!.IsVideo.Should().BeTrue();
+        assets.RelatedVideoUris.Should().Equal("spotify:track:video");
+    }
+
+    [Fact]
+    public async Task GetAssetsAsync_SameTrackTwice_ShouldQueryOnce()
+    {
+        // Arrange
+        var pathfinder = CreatePathfinder(new NpvTrackUnion());
+        var service = new TrackMediaAssetService(pathfinder.Object);
+
+        // Act
+        await service.GetAssetsAsync(Track);
+        var second = await service.GetAssetsAsync(Track);
+
+        // Assert
+        second!.HasAssets.Should().BeFalse();
+        pathfinder.Verify(p => p.GetNpvArtistAsync(It.IsAny<string>(), It.IsAny<string>(),
+            It.IsAny<int>(), It.IsAny<int>(), It.IsAny<CancellationToken>()), Times.Once);
+    }
+
+    [Fact]
+    public async Task GetAssetsAsync_Episode_ShouldNotQuery()
+    {
+        // Arrange
+        var pathfinder = CreatePathfinder(new NpvTrackUnion());
+        var service = new TrackMediaAssetService(pathfinder.Object);
+
+        // Act
+        var assets = await service.GetAssetsAsync(new TrackInfo { Uri = "spotify:episode:e", ArtistUri = "spotify:show:s" });
+
+        // Assert
+        assets.Should().BeNull();
+        pathfinder.VerifyNoOtherCalls();
+    }
+
+    private static Mock<IPathfinderClient> CreatePathfinder(NpvTrackUnion union)
+    {
+        var pathfinder = new Mock<IPathfinderClient>();
+        pathfinder
+            .Setup(p => p.GetNpvArtistAsync(It.IsAny<string>(), It.IsAny<string>(),
+                It.IsAny<int>(), It.IsAny<int>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new NpvArtistResponse { Data = new NpvArtistData { TrackUnion = union } });
+        return pathfinder;
+    }
+}
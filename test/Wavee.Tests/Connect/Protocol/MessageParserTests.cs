@@ -1,5 +1,9 @@
+using System.IO.Compression;
 using FluentAssertions;
+using Google.Protobuf;
 using Wavee.Connect.Protocol;
+using Wavee.Core.Session;
+using Wavee.Protocol.Player;
 using Wavee.Tests.Helpers;
 using Xunit;
 
@@ -123,7 +127,7 @@ public class MessageParserTests
         var expectedHeaders = new Dictionary<string, string>
         {
             ["Content-Type"] = "application/json",
-            ["Transfer-Encoding"] = "gzip"
+            ["Spotify-Connection-Id"] = "abc"
         };
         var expectedPayload = new byte[] { 1, 2, 3, 4, 5 };
 
@@ -466,4 +470,261 @@ public class MessageParserTests
         success.Should().BeTrue();
         request!.Command.ValueKind.Should().Be(System.Text.Json.JsonValueKind.Array);
     }
+
+    // ================================================================
+    // COMPRESSED PAYLOAD TESTS - Transfer-Encoding handled before dispatch
+    // ================================================================
+
+    [Theory]
+    [InlineData("gzip")]
+    [InlineData("deflate")]
+    [InlineData("GZIP")]
+    public void TryParseMessage_CompressedPayload_ShouldDecompressAndDropHeader(string encoding)
+    {
+        // WHY: Cluster updates arrive gzip-compressed; subscribers must get plain protobuf bytes
+        //      and must not see the header, or they would decompress a second time.
+
+        // Arrange
+        var plain = Enumerable.Range(0, 512).Select(i => (byte)(i % 7)).ToArray();
+        var json = DealerTestHelpers.CreateDealerMessage(
+            "hm://connect-state/v1/cluster",
+            new Dictionary<string, string> { ["Transfer-Encoding"] = encoding },
+            Compress(plain, encoding.ToLowerInvariant()));
+        var bytes = DealerTestHelpers.CreateMessageBytes(json);
+
+        // Act
+        var success = MessageParser.TryParseMessage(bytes, out var message);
+
+        // Assert
+        success.Should().BeTrue();
+        message!.Payload.Should().Equal(plain);
+        message.Headers.Should().NotContainKey("Transfer-Encoding");
+    }
+
+    [Fact]
+    public void TryParseMessage_RawDeflatePayload_ShouldDecompress()
+    {
+        // WHY: "deflate" is used for both zlib-wrapped and raw streams in the wild
+
+        // Arrange
+        var plain = "raw deflate"u8.ToArray();
+        using var ms = new MemoryStream();
+        using (var deflate = new DeflateStream(ms, CompressionLevel.Optimal, leaveOpen: true))
+            deflate.Write(plain);
+        var json = DealerTestHelpers.CreateDealerMessage(
+            "hm://test",
+            new Dictionary<string, string> { ["Transfer-Encoding"] = "deflate" },
+            ms.ToArray());
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message);
+
+        // Assert
+        success.Should().BeTrue();
+        message!.Payload.Should().Equal(plain);
+    }
+
+    [Fact]
+    public void TryParseMessage_CorruptGzipPayload_ShouldFail()
+    {
+        // Arrange
+        var json = DealerTestHelpers.CreateDealerMessage(
+            "hm://connect-state/v1/cluster",
+            new Dictionary<string, string> { ["Transfer-Encoding"] = "gzip" },
+            new byte[] { 1, 2, 3, 4, 5 });
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message);
+
+        // Assert
+        success.Should().BeFalse("an undecodable payload must not be dispatched as if it were plain");
+        message.Should().BeNull();
+    }
+
+    [Fact]
+    public void TryParseRequest_DeflateCompressedCommand_ShouldDecompress()
+    {
+        // Arrange
+        var command = """{"command":{"endpoint":"pause"}}"""u8.ToArray();
+        var compressed = Convert.ToBase64String(Compress(command, "deflate"));
+        var json = "{\"type\":\"request\",\"key\":\"1/dev\",\"message_ident\":\"hm://connect-state/v1/player/command\"," +
+                   "\"headers\":{\"Transfer-Encoding\":\"deflate\"},\"payload\":{\"compressed\":\"" + compressed + "\"}}";
+
+        // Act
+        var success = MessageParser.TryParseRequest(DealerTestHelpers.CreateMessageBytes(json), out var request);
+
+        // Assert
+        success.Should().BeTrue();
+        request!.Command.GetProperty("command").GetProperty("endpoint").GetString().Should().Be("pause");
+    }
+
+    // ================================================================
+    // MULTI-PART PAYLOAD TESTS - Reassembly and typed decoding
+    // ================================================================
+
+    [Fact]
+    public void TryParseMessage_MultiPartPayload_ShouldConcatenateParts()
+    {
+        // WHY: Large cluster updates are split across several base64 entries;
+        //      taking only the first one yields a truncated, undecodable protobuf.
+
+        // Arrange
+        var cluster = new ClusterUpdate { Cluster = new Cluster { ActiveDeviceId = new string('d', 300) } };
+        var bytes = cluster.ToByteArray();
+        var parts = new[] { bytes[..100], bytes[100..250], bytes[250..] }.Select(Convert.ToBase64String);
+        var json = "{\"type\":\"message\",\"uri\":\"hm://connect-state/v1/cluster\",\"headers\":{}," +
+                   "\"payloads\":[" + string.Join(",", parts.Select(p => $"\"{p}\"")) + "]}";
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message);
+
+        // Assert
+        success.Should().BeTrue();
+        message!.Payload.Should().Equal(bytes);
+        message.TryParsePayload(ClusterUpdate.Parser, out var parsed).Should().BeTrue();
+        parsed!.Cluster.ActiveDeviceId.Should().Be(cluster.Cluster.ActiveDeviceId);
+    }
+
+    [Fact]
+    public void TryParseMessage_MultiPartGzipPayload_ShouldDecompressAfterReassembly()
+    {
+        // Arrange
+        var plain = new ClusterUpdate { Cluster = new Cluster { ActiveDeviceId = "abc" } }.ToByteArray();
+        var compressed = Compress(plain, "gzip");
+        var half = compressed.Length / 2;
+        var json = "{\"type\":\"message\",\"uri\":\"hm://connect-state/v1/cluster\"," +
+                   "\"headers\":{\"Transfer-Encoding\":\"gzip\"}," +
+                   $"\"payloads\":[\"{Convert.ToBase64String(compressed[..half])}\",\"{Convert.ToBase64String(compressed[half..])}\"]}}";
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message);
+
+        // Assert
+        success.Should().BeTrue();
+        message!.Payload.Should().Equal(plain);
+    }
+
+    [Fact]
+    public void TryParseMessage_JsonObjectPayload_ShouldExposeUtf8Json()
+    {
+        // WHY: Some pushes carry inline JSON objects instead of base64 strings
+
+        // Arrange
+        var json = "{\"type\":\"message\",\"uri\":\"hm://collection/update\",\"headers\":{}," +
+                   "\"payloads\":[{\"items\":[1,2]}]}";
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message);
+
+        // Assert
+        success.Should().BeTrue();
+        System.Text.Encoding.UTF8.GetString(message!.Payload).Should().Be("{\"items\":[1,2]}");
+    }
+
+    [Fact]
+    public void TryParsePayload_WithGarbage_ShouldReturnFalse()
+    {
+        // Arrange
+        var message = new DealerMessage
+        {
+            Uri = "hm://connect-state/v1/cluster",
+            Headers = new Dictionary<string, string>(),
+            Payload = [0xFF, 0xFF, 0xFF]
+        };
+
+        // Act
+        var success = message.TryParsePayload(ClusterUpdate.Parser, out var parsed);
+
+        // Assert
+        success.Should().BeFalse();
+        parsed.Should().BeNull();
+    }
+
+    // ================================================================
+    // LIMIT TESTS - Inbound frames over ProtocolLimitsConfig are rejected
+    // ================================================================
+
+    [Fact]
+    public void TryParseMessage_TooManyHeaders_ShouldFail()
+    {
+        // Arrange
+        var headers = Enumerable.Range(0, 5).ToDictionary(i => $"h{i}", i => "v");
+        var json = DealerTestHelpers.CreateDealerMessage("hm://test", headers, [1, 2, 3]);
+        var limits = new ProtocolLimitsConfig { MaxHeaders = 4 };
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out var message, limits: limits);
+
+        // Assert
+        success.Should().BeFalse();
+        message.Should().BeNull();
+    }
+
+    [Fact]
+    public void TryParseMessage_TooManyPayloadParts_ShouldFail()
+    {
+        // Arrange
+        var part = "\"" + Convert.ToBase64String([1, 2, 3]) + "\"";
+        var json = "{\"type\":\"message\",\"uri\":\"hm://test\",\"headers\":{}," +
+                   $"\"payloads\":[{part},{part},{part}]}}";
+        var bytes = DealerTestHelpers.CreateMessageBytes(json);
+
+        // Act
+        var withinLimit = MessageParser.TryParseMessage(bytes, out _, limits: new ProtocolLimitsConfig { MaxParts = 3 });
+        var overLimit = MessageParser.TryParseMessage(bytes, out _, limits: new ProtocolLimitsConfig { MaxParts = 2 });
+
+        // Assert
+        withinLimit.Should().BeTrue();
+        overLimit.Should().BeFalse();
+    }
+
+    [Fact]
+    public void TryParseMessage_GzipPayloadOverLimit_ShouldFail()
+    {
+        // WHY: A few KB of gzip can expand to gigabytes; decompression must stop at the limit
+
+        // Arrange
+        var compressed = Compress(new byte[1024 * 1024], "gzip");
+        var json = DealerTestHelpers.CreateDealerMessage(
+            "hm://connect-state/v1/cluster",
+            new Dictionary<string, string> { ["Transfer-Encoding"] = "gzip" },
+            compressed);
+        var limits = new ProtocolLimitsConfig { MaxMessageBytes = 64 * 1024 };
+
+        // Act
+        var success = MessageParser.TryParseMessage(DealerTestHelpers.CreateMessageBytes(json), out _, limits: limits);
+
+        // Assert
+        success.Should().BeFalse();
+    }
+
+    [Fact]
+    public void TryParseRequest_NestingBeyondMaxDepth_ShouldFail()
+    {
+        // Arrange
+        var command = new string('[', 10) + new string(']', 10);
+        var json = "{\"type\":\"request\",\"key\":\"1/device\",\"message_ident\":\"hm://connect-state/v1/player/command\"," +
+                   $"\"payload\":{{\"command\":{command}}}}}";
+        var bytes = DealerTestHelpers.CreateMessageBytes(json);
+
+        // Act
+        var withinLimit = MessageParser.TryParseRequest(bytes, out _, limits: new ProtocolLimitsConfig { MaxJsonDepth = 16 });
+        var overLimit = MessageParser.TryParseRequest(bytes, out _, limits: new ProtocolLimitsConfig { MaxJsonDepth = 8 });
+
+        // Assert
+        withinLimit.Should().BeTrue();
+        overLimit.Should().BeFalse();
+    }
+
+    private static byte[] Compress(byte[] data, string encoding)
+    {
+        using var ms = new MemoryStream();
+        using (Stream compressor = encoding == "gzip"
+                   ? new GZipStream(ms, CompressionLevel.Optimal, leaveOpen: true)
+                   : new ZLibStream(ms, CompressionLevel.Optimal, leaveOpen: true))
+        {
+            compressor.Write(data);
+        }
+        return ms.ToArray();
+    }
 }
@@ -287,6 +287,67 @@ public class ReconnectionManagerTests
         attemptCount.Should().Be(finalAttemptCount, "no more attempts after cancellation");
     }
 
+    [Fact]
+    public async Task TriggerReconnection_Immediate_ShouldSkipInitialDelay()
+    {
+        // Arrange
+        var attempted = new ManualResetEventSlim(false);
+
+        var manager = new ReconnectionManager(
+            initialDelay: TimeSpan.FromSeconds(30), // Would time the test out if honored
+            maxDelay: TimeSpan.FromSeconds(60),
+            maxAttempts: 3,
+            reconnectCallback: () =>
+            {
+                attempted.Set();
+                return ValueTask.CompletedTask;
+            });
+
+        // Act
+        manager.TriggerReconnection(immediate: true);
+        var received = attempted.Wait(TimeSpan.FromSeconds(2));
+
+        await manager.DisposeAsync();
+
+        // Assert
+        received.Should().BeTrue("an immediate trigger should attempt without the initial delay");
+    }
+
+    [Fact]
+    public async Task TriggerReconnection_ImmediateWhileBackingOff_ShouldCutWaitShort()
+    {
+        // Arrange
+        var attempts = 0;
+        var secondAttempt = new ManualResetEventSlim(false);
+
+        var manager = new ReconnectionManager(
+            initialDelay: TimeSpan.FromSeconds(30),
+            maxDelay: TimeSpan.FromSeconds(60),
+            maxAttempts: 5,
+            reconnectCallback: () =>
+            {
+                if (Interlocked.Increment(ref attempts) >= 2)
+                {
+                    secondAttempt.Set();
+                    return ValueTask.CompletedTask;
+                }
+
+                throw new Exception("Connection failed");
+            });
+
+        // Act - first attempt runs immediately and fails, then the loop backs off for 30s+
+        manager.TriggerReconnection(immediate: true);
+        await Task.Delay(200);
+        manager.TriggerReconnection(immediate: true);
+        var received = secondAttempt.Wait(TimeSpan.FromSeconds(2));
+
+        await manager.DisposeAsync();
+
+        // Assert
+        received.Should().BeTrue("an immediate trigger should end the current backoff wait");
+        attempts.Should().Be(2);
+    }
+
     // ================================================================
     // STATE MANAGEMENT TESTS
     // ================================================================
@@ -359,4 +420,55 @@ public class ReconnectionManagerTests
         manager.AttemptCount.Should().Be(0, "reset should clear attempt count");
         manager.IsReconnecting.Should().BeFalse("reset should clear reconnecting flag");
     }
+
+    // ================================================================
+    // SIMULATION TESTS - Virtual time
+    // ================================================================
+
+    [Fact]
+    public async Task ReconnectStorm_OnVirtualClock_ShouldBackOffExponentiallyAndCap()
+    {
+        // ============================================================
+        // WHY: Over an hour-long outage the backoff must double up to
+        //      the cap and stay there, never hammering the dealer.
+        //      Virtual time runs the whole storm in milliseconds.
+        // ============================================================
+
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var attempts = new SemaphoreSlim(0);
+        var failed = new ManualResetEventSlim(false);
+        var manager = new ReconnectionManager(
+            initialDelay: TimeSpan.FromSeconds(1),
+            maxDelay: TimeSpan.FromSeconds(300),
+            maxAttempts: 20,
+            reconnectCallback: () =>
+            {
+                attempts.Release();
+                throw new Exception("AP unreachable");
+            },
+            timeProvider: time);
+        manager.ReconnectionFailed += (_, _) => failed.Set();
+        var start = time.GetUtcNow();
+        var delays = new List<TimeSpan>();
+
+        // Act
+        manager.TriggerReconnection();
+        for (var i = 0; i < 20; i++)
+        {
+            time.WaitForPendingTimers().Should().BeTrue($"attempt {i + 1} should schedule a backoff delay");
+            var due = time.NextTimerDueIn!.Value;
+            delays.Add(due);
+            time.Advance(due);
+            (await attempts.WaitAsync(TimeSpan.FromSeconds(2))).Should().BeTrue();
+        }
+
+        // Assert
+        failed.Wait(TimeSpan.FromSeconds(2)).Should().BeTrue("attempts are exhausted");
+        delays.Take(8).Select(d => d.TotalSeconds).Should().Equal(2, 4, 8, 16, 32, 64, 128, 256);
+        delays.Skip(8).Should().OnlyContain(d => d == TimeSpan.FromSeconds(300));
+        (time.GetUtcNow() - start).Should().BeGreaterThan(TimeSpan.FromHours(1));
+
+        await manager.DisposeAsync();
+    }
 }
@@ -372,4 +372,91 @@ public class SpotifyIdTests
         // Assert
         id.ToString().Should().Be(TestTrackUri);
     }
+
+    [Theory]
+    [InlineData("spotify:local:Artist:Album:Title:210", SpotifyIdType.Local)]
+    [InlineData("spotify:ad:6d1f91b13f7f4f1bb6a90e6a4b8b3f7d", SpotifyIdType.Ad)]
+    [InlineData("spotify:interruption:6d1f91b13f7f4f1bb6a90e6a4b8b3f7d", SpotifyIdType.Interruption)]
+    [InlineData("spotify:delimiter", SpotifyIdType.Delimiter)]
+    [InlineData("spotify:Track:4iV5W9uYEdYUVa79Axb7Rh", SpotifyIdType.Track)]
+    [InlineData("spotify:meta:page:0", SpotifyIdType.Unknown)]
+    [InlineData("wavee:local:track:abc", SpotifyIdType.Unknown)]
+    [InlineData(null, SpotifyIdType.Unknown)]
+    public void GetUriType_ReturnsTypeWithoutParsingId(string? uri, SpotifyIdType expected)
+    {
+        // ============================================================
+        // WHY: Contexts mix tracks with local files, ads and delimiters.
+        //      The type must be readable even when the ID part is not
+        //      a base62 ID, or the whole context load fails.
+        // ============================================================
+
+        // Act & Assert
+        SpotifyId.GetUriType(uri).Should().Be(expected);
+    }
+
+    [Theory]
+    [InlineData("spotify:delimiter", true)]
+    [InlineData("spotify:ad:6d1f91b13f7f4f1bb6a90e6a4b8b3f7d", true)]
+    [InlineData("spotify:meta:page:1", true)]
+    [InlineData("spotify:local:Artist:Album:Title:210", false)]
+    [InlineData(TestTrackUri, false)]
+    public void IsContextMarker_OnlyMatchesNonPlayableEntries(string uri, bool expected)
+    {
+        // ============================================================
+        // WHY: Markers are dropped from the queue; local files are kept
+        //      so the queue still lines up with the playlist.
+        // ============================================================
+
+        // Act & Assert
+        SpotifyId.IsContextMarker(uri).Should().Be(expected);
+    }
+
+    [Fact]
+    public void FromUri_AdUri_RoundTripsAsHex()
+    {
+        // ============================================================
+        // WHY: Ad and interruption IDs are hex, not base62.
+        // ============================================================
+
+        // Arrange
+        var uri = $"spotify:ad:{TestBase16}";
+
+        // Act
+        var id = SpotifyId.FromUri(uri);
+
+        // Assert
+        id.Type.Should().Be(SpotifyIdType.Ad);
+        id.ToUri().Should().Be(uri);
+    }
+
+    [Fact]
+    public void FromUri_LocalUri_ThrowsArgumentException()
+    {
+        // ============================================================
+        // WHY: Local file URIs carry tags, not an ID. Callers must use
+        //      SpotifyLocalUri instead of getting a garbage ID.
+        // ============================================================
+
+        // Act
+        var act = () => SpotifyId.FromUri("spotify:local:Artist:Album:Title:210");
+
+        // Assert
+        act.Should().Throw<ArgumentException>().WithMessage("*SpotifyLocalUri*");
+    }
+
+    [Fact]
+    public void SpotifyLocalUri_TryParse_DecodesFields()
+    {
+        // ============================================================
+        // WHY: Local entries are shown in the queue from their URI tags.
+        // ============================================================
+
+        // Act
+        var ok = SpotifyLocalUri.TryParse("spotify:local:The+Band:Live%3A+1999:Song+%231:215", out var local);
+
+        // Assert
+        ok.Should().BeTrue();
+        local.Should().Be(new SpotifyLocalUri("The Band", "Live: 1999", "Song #1", TimeSpan.FromSeconds(215)));
+        SpotifyLocalUri.TryParse(TestTrackUri, out _).Should().BeFalse();
+    }
 }
@@ -210,4 +210,23 @@ public class CredentialsTests
         // Verify username is safe to include (not sensitive)
         // But auth data must be hidden or masked
     }
+
+    [Fact]
+    public void ZeroAuthData_ShouldWipeBlobInPlace()
+    {
+        // ============================================================
+        // WHY: Session dispose wipes the reusable blob it owns so it
+        //      doesn't outlive the session in memory.
+        // ============================================================
+
+        // Arrange
+        var credentials = ProtobufHelpers.CreateValidCredentials("testuser");
+        var authData = credentials.AuthData;
+
+        // Act
+        credentials.ZeroAuthData();
+
+        // Assert
+        authData.Should().OnlyContain(b => b == 0);
+    }
 }
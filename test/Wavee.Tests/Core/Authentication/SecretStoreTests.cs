@@ -0,0 +1,61 @@
+using System.Threading.Tasks;
+using FluentAssertions;
+using Wavee.Core.Authentication;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Authentication;
+
+/// <summary>
+/// Tests for ISecretStore implementations and CredentialsCache layered on top of them.
+/// </summary>
+public class SecretStoreTests
+{
+    [Fact]
+    public async Task CredentialsCache_WithInMemoryStore_ShouldRoundtripWithoutTouchingDisk()
+    {
+        // ============================================================
+        // WHY: Callers that must not persist auth blobs (kiosk mode,
+        //      tests) plug in the in-memory store and still get the
+        //      full ICredentialsCache behaviour.
+        // ============================================================
+
+        // Arrange
+        var store = new InMemorySecretStore();
+        var cache = new CredentialsCache(store);
+        var credentials = ProtobufHelpers.CreateValidCredentials("alice");
+
+        // Act
+        await cache.SaveCredentialsAsync(credentials);
+        var loaded = await cache.LoadCredentialsAsync("alice");
+        var lastUser = await cache.LoadLastUsernameAsync();
+
+        // Assert
+        loaded.Should().NotBeNull();
+        loaded!.AuthData.Should().Equal(credentials.AuthData);
+        lastUser.Should().Be("alice");
+        (await store.ListKeysAsync()).Should().BeEquivalentTo(new[] { "alice_credentials", "last_user" });
+    }
+
+    [Fact]
+    public async Task ClearAllCredentialsAsync_ShouldOnlyRemoveCredentialKeys()
+    {
+        // ============================================================
+        // WHY: The store may be shared with other secrets (e.g. third-
+        //      party API tokens); sign-out must not wipe those.
+        // ============================================================
+
+        // Arrange
+        var store = new InMemorySecretStore();
+        var cache = new CredentialsCache(store);
+        await store.WriteAsync("tmdb", new byte[] { 1, 2, 3 });
+        await cache.SaveCredentialsAsync(ProtobufHelpers.CreateValidCredentials("bob"));
+
+        // Act
+        await cache.ClearAllCredentialsAsync();
+
+        // Assert
+        (await store.ListKeysAsync()).Should().BeEquivalentTo(new[] { "tmdb" });
+        (await cache.LoadLastUsernameAsync()).Should().BeNull();
+    }
+}
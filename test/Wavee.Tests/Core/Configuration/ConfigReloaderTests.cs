@@ -0,0 +1,103 @@
+using FluentAssertions;
+using Microsoft.Extensions.Logging;
+using Wavee.Core.Audio;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Configuration;
+
+/// <summary>
+/// Tests for ConfigReloader apply and restart-required rules.
+/// </summary>
+public class ConfigReloaderTests
+{
+    private static readonly WaveeConfig Initial = new()
+    {
+        Session = new SessionConfig { DeviceId = "device", DeviceName = "Initial" }
+    };
+
+    [Fact]
+    public void Reload_ReloadableChange_ShouldApplyAndRaiseConfigChanged()
+    {
+        // Arrange
+        var reloader = new ConfigReloader(Initial, () => Initial with
+        {
+            Player = Initial.Player with { Quality = AudioQuality.Normal },
+            LogLevel = LogLevel.Debug
+        });
+        ConfigChangedEventArgs? raised = null;
+        reloader.ConfigChanged += (_, e) => raised = e;
+
+        // Act
+        reloader.Reload();
+
+        // Assert
+        raised.Should().NotBeNull();
+        raised!.ChangedKeys.Should().Equal("player.quality", "logging.level");
+        reloader.Current.Player.Quality.Should().Be(AudioQuality.Normal);
+        reloader.Current.LogLevel.Should().Be(LogLevel.Debug);
+    }
+
+    [Fact]
+    public void Reload_SkipRuleChange_ShouldCompareListsByContent()
+    {
+        // Arrange
+        var reloader = new ConfigReloader(Initial, () => Initial with
+        {
+            Player = Initial.Player with
+            {
+                BlockedTracks = new List<string>(),
+                BlockedArtists = ["spotify:artist:6rqhFgbbKwnb9MLmUQDhG6"]
+            }
+        });
+
+        // Act
+        var result = reloader.Reload();
+
+        // Assert
+        result.ChangedKeys.Should().Equal("player.blockedArtists");
+        result.RestartRequiredKeys.Should().BeEmpty();
+        reloader.Current.Player.BlockedArtists.Should().Equal("spotify:artist:6rqhFgbbKwnb9MLmUQDhG6");
+    }
+
+    [Fact]
+    public void Reload_SessionChange_ShouldReportRestartRequiredAndKeepCurrent()
+    {
+        // Arrange
+        var reloader = new ConfigReloader(Initial, () => Initial with
+        {
+            Session = Initial.Session with
+            {
+                DeviceName = "Renamed",
+                Network = new NetworkConfig { ApConnectTimeout = TimeSpan.FromSeconds(30) }
+            }
+        });
+        var raised = false;
+        reloader.ConfigChanged += (_, _) => raised = true;
+
+        // Act
+        var result = reloader.Reload();
+
+        // Assert
+        raised.Should().BeFalse();
+        result.ChangedKeys.Should().BeEmpty();
+        result.RestartRequiredKeys.Should().Equal("session", "network");
+        reloader.Current.Session.DeviceName.Should().Be("Initial");
+    }
+
+    [Fact]
+    public void Reload_InvalidConfig_ShouldThrowAndKeepCurrent()
+    {
+        // Arrange
+        var reloader = new ConfigReloader(Initial,
+            () => throw new ConfigException("player.quality", "WAVEE_PLAYER_QUALITY", "is invalid"));
+
+        // Act
+        var act = () => reloader.Reload();
+
+        // Assert
+        act.Should().Throw<ConfigException>();
+        reloader.Current.Should().BeSameAs(Initial);
+    }
+}
@@ -0,0 +1,229 @@
+using System.Collections;
+using System.Net;
+using FluentAssertions;
+using Wavee.AudioIpc;
+using Wavee.Core.Audio;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Configuration;
+
+/// <summary>
+/// Tests for WaveeConfigLoader layering and validation.
+/// </summary>
+public class WaveeConfigLoaderTests
+{
+    private static readonly WaveeConfig Defaults = new()
+    {
+        Session = new SessionConfig { DeviceId = "device", DeviceName = "Default" }
+    };
+
+    [Fact]
+    public void ApplyJson_NestedSections_ShouldSetTypedValues()
+    {
+        // Arrange
+        const string json = """
+            {
+              // comments are allowed
+              "session": { "deviceName": "Kitchen", "deviceType": "speaker" },
+              "network": { "apConnectTimeout": 25, "dealerConnectTimeout": "00:01:00", "localAddress": "10.0.0.5" },
+              "player": { "quality": "High", "normalization": false },
+              "cache": { "maxSizeBytes": 1048576 },
+            }
+            """;
+
+        // Act
+        var result = WaveeConfigLoader.ApplyJson(Defaults, json, "wavee.json");
+
+        // Assert
+        result.Session.DeviceId.Should().Be("device");
+        result.Session.DeviceName.Should().Be("Kitchen");
+        result.Session.DeviceType.Should().Be(DeviceType.Speaker);
+        result.Session.Network.ApConnectTimeout.Should().Be(TimeSpan.FromSeconds(25));
+        result.Session.Network.DealerConnectTimeout.Should().Be(TimeSpan.FromMinutes(1));
+        result.Session.Network.LocalAddress.Should().Be(IPAddress.Parse("10.0.0.5"));
+        result.Player.Quality.Should().Be(AudioQuality.High);
+        result.Player.NormalizationEnabled.Should().BeFalse();
+        result.Cache.MaxCacheSizeBytes.Should().Be(1048576);
+    }
+
+    [Fact]
+    public void ApplyJson_ConnectSection_ShouldSetConnectDeviceCapabilities()
+    {
+        // Act
+        var result = WaveeConfigLoader.ApplyJson(
+            Defaults, """{"connect": {"volumeSteps": 16, "supportsVolume": false, "model": "Pi DAC"}}""", "wavee.json");
+
+        // Assert
+        result.Session.ConnectDevice.VolumeSteps.Should().Be(16);
+        result.Session.ConnectDevice.SupportsVolume.Should().BeFalse();
+        result.Session.ConnectDevice.Model.Should().Be("Pi DAC");
+        result.Session.ConnectDevice.Brand.Should().Be("spotify");
+    }
+
+    [Fact]
+    public void ApplyJson_SamplingSection_ShouldAcceptZeroAndRejectNegative()
+    {
+        // Act
+        var result = WaveeConfigLoader.ApplyJson(
+            Defaults, """{"sampling": {"positionLog": 30, "audioChunkLog": "00:00:05", "stateEvents": 0}}""", "wavee.json");
+        var act = () => WaveeConfigLoader.ApplyJson(Defaults, """{"sampling": {"positionLog": -1}}""", "wavee.json");
+
+        // Assert
+        result.Session.Sampling.PositionLogInterval.Should().Be(TimeSpan.FromSeconds(30));
+        result.Session.Sampling.AudioChunkLogInterval.Should().Be(TimeSpan.FromSeconds(5));
+        result.Session.Sampling.StateEventInterval.Should().Be(TimeSpan.Zero);
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("sampling.positionLog");
+    }
+
+    [Theory]
+    [InlineData("1e300")]
+    [InlineData("\"NaN\"")]
+    public void ApplyJson_TimeoutOutOfRange_ShouldReportKeyInsteadOfOverflowing(string value)
+    {
+        // Act
+        var act = () => WaveeConfigLoader.ApplyJson(
+            Defaults, $$"""{"network": {"apConnectTimeout": {{value}}}}""", "wavee.json");
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("network.apConnectTimeout");
+    }
+
+    [Fact]
+    public void ApplyJson_TlsPins_ShouldSplitCommaListAndRejectInvalidHashes()
+    {
+        // Arrange
+        var pin = Convert.ToBase64String(new byte[32]);
+
+        // Act
+        var result = WaveeConfigLoader.ApplyJson(
+            Defaults, $$"""{"network": {"tlsPinning": "enforce", "tlsPins": "sha256/{{pin}}, {{pin}}"}}""", "wavee.json");
+        var act = () => WaveeConfigLoader.ApplyJson(Defaults, """{"network": {"tlsPins": "sha256/abc"}}""", "wavee.json");
+
+        // Assert
+        result.Session.Network.TlsPinning.Should().Be(TlsPinningMode.Enforce);
+        result.Session.Network.TlsPins.Should().Equal($"sha256/{pin}", pin);
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("network.tlsPins");
+    }
+
+    [Fact]
+    public void ApplyJson_SkipRules_ShouldSplitListsAndParseDuration()
+    {
+        // Arrange
+        const string json = """
+            {"player": {
+              "blockedTracks": "spotify:track:a, spotify:track:b",
+              "blockedArtists": "spotify:artist:x,Some Band",
+              "maxTrackDuration": "00:10:00",
+              "skipLiveVersions": true
+            }}
+            """;
+
+        // Act
+        var result = WaveeConfigLoader.ApplyJson(Defaults, json, "wavee.json");
+
+        // Assert
+        result.Player.BlockedTracks.Should().Equal("spotify:track:a", "spotify:track:b");
+        result.Player.BlockedArtists.Should().Equal("spotify:artist:x", "Some Band");
+        result.Player.MaxTrackDuration.Should().Be(TimeSpan.FromMinutes(10));
+        result.Player.SkipLiveVersions.Should().BeTrue();
+    }
+
+    [Fact]
+    public void ApplyEnvironment_DecoderBackend_ShouldParseCaseInsensitively()
+    {
+        // Arrange
+        var environment = new Hashtable { ["WAVEE_PLAYER_DECODER"] = "bass" };
+
+        // Act
+        var result = WaveeConfigLoader.ApplyEnvironment(Defaults, environment);
+
+        // Assert
+        result.Player.DecoderBackend.Should().Be(AudioDecoderBackend.Bass);
+    }
+
+    [Fact]
+    public void ApplyEnvironment_ShouldOverrideFileValues()
+    {
+        // Arrange
+        var fromFile = WaveeConfigLoader.ApplyJson(Defaults, """{"player": {"initialVolumePercent": 20}}""", "wavee.json");
+        var environment = new Hashtable
+        {
+            ["WAVEE_PLAYER_INITIAL_VOLUME_PERCENT"] = "80",
+            ["WAVEE_HTTP_PORT"] = "8080"
+        };
+
+        // Act
+        var result = WaveeConfigLoader.ApplyEnvironment(fromFile, environment);
+
+        // Assert
+        result.Player.InitialVolumePercent.Should().Be(80);
+    }
+
+    [Fact]
+    public void Load_DefaultFile_ShouldApplyOnlyWhenNothingElseNamesAFile()
+    {
+        // Arrange
+        var defaultFile = Path.Combine(Path.GetTempPath(), "wavee-config-" + Guid.NewGuid().ToString("N") + ".json");
+        File.WriteAllText(defaultFile, """{"session": {"deviceName": "From default"}}""");
+
+        try
+        {
+            // Act
+            var fromDefault = WaveeConfigLoader.Load(Defaults, environment: new Hashtable(), defaultFilePath: defaultFile);
+            var missingDefault = WaveeConfigLoader.Load(Defaults, environment: new Hashtable(), defaultFilePath: defaultFile + ".missing");
+            var namedByEnvironment = () => WaveeConfigLoader.Load(
+                Defaults,
+                environment: new Hashtable { [WaveeConfigLoader.FileEnvironmentVariable] = defaultFile + ".other" },
+                defaultFilePath: defaultFile);
+
+            // Assert
+            fromDefault.Session.DeviceName.Should().Be("From default");
+            missingDefault.Session.DeviceName.Should().Be("Default");
+            namedByEnvironment.Should().Throw<ConfigException>("an explicitly named file must exist");
+        }
+        finally
+        {
+            File.Delete(defaultFile);
+        }
+    }
+
+    [Fact]
+    public void ApplyJson_UnknownKey_ShouldReportKeyAndSource()
+    {
+        // Act
+        var act = () => WaveeConfigLoader.ApplyJson(Defaults, """{"network": {"apTimeout": 5}}""", "wavee.json");
+
+        // Assert
+        var ex = act.Should().Throw<ConfigException>().Which;
+        ex.Key.Should().Be("network.apTimeout");
+        ex.Source.Should().Be("wavee.json");
+    }
+
+    [Fact]
+    public void ApplyEnvironment_InvalidValue_ShouldReportKeyAndVariable()
+    {
+        // Arrange
+        var environment = new Hashtable { ["WAVEE_NETWORK_ADDRESS_FAMILY"] = "ipv5" };
+
+        // Act
+        var act = () => WaveeConfigLoader.ApplyEnvironment(Defaults, environment);
+
+        // Assert
+        var ex = act.Should().Throw<ConfigException>().Which;
+        ex.Key.Should().Be("network.addressFamily");
+        ex.Source.Should().Be("WAVEE_NETWORK_ADDRESS_FAMILY");
+        ex.Message.Should().Contain("PreferIPv4");
+    }
+
+    [Theory]
+    [InlineData("network.apConnectTimeout", "WAVEE_NETWORK_AP_CONNECT_TIMEOUT")]
+    [InlineData("network.spClientRequestTimeout", "WAVEE_NETWORK_SP_CLIENT_REQUEST_TIMEOUT")]
+    [InlineData("cache.enabled", "WAVEE_CACHE_ENABLED")]
+    public void GetEnvironmentVariableName_ShouldUseScreamingSnakeCase(string key, string expected)
+    {
+        // Act & Assert
+        WaveeConfigLoader.GetEnvironmentVariableName(key).Should().Be(expected);
+    }
+}
@@ -0,0 +1,215 @@
+using System.Buffers;
+using FluentAssertions;
+using Wavee.Core.Connection;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Connection;
+
+/// <summary>
+/// End-to-end tests for ApCodec - validates two codecs wired back-to-back through ApTransport
+/// over an in-memory duplex stream, with packets split at arbitrary byte boundaries.
+///
+/// WHY: Every AP packet goes through this encrypt/frame/decrypt path. Bugs here will cause:
+/// - Nonce drift between the two sides after thousands of packets (garbage after a while)
+/// - Packets corrupted when TCP splits them mid-header or mid-MAC
+/// - Tampered, dropped or truncated packets being accepted instead of failing the connection
+/// </summary>
+public class ApCodecLoopbackTests
+{
+    private static readonly byte[] ClientKey = Enumerable.Range(0, 32).Select(i => (byte)(i * 7)).ToArray();
+    private static readonly byte[] ServerKey = ClientKey.Select(b => (byte)~b).ToArray();
+
+    private const int PacketsPerDirection = 2000;
+
+    [Theory]
+    [InlineData(1, 1)]
+    [InlineData(2, 7)]
+    [InlineData(3, 1500)]
+    [InlineData(null, 4096)]
+    public async Task Loopback_RandomPacketsBothWays_ShouldArriveIntactAndInOrder(int? splitSeed, int maxChunk)
+    {
+        // Arrange
+        var (clientStream, serverStream) = LoopbackStream.CreatePair(splitSeed, maxChunk);
+        await using var client = ApTransport.Create(clientStream, new ApCodec(ClientKey, ServerKey));
+        await using var server = ApTransport.Create(serverStream, new ApCodec(ServerKey, ClientKey));
+        // Byte-at-a-time splitting is slow enough that large packets are left to the other cases
+        var withLarge = maxChunk > 1;
+        var maxPayload = withLarge ? 2048 : 128;
+        var toServer = CreatePackets(seed: 100 + (splitSeed ?? 0), PacketsPerDirection, maxPayload, withLarge);
+        var toClient = CreatePackets(seed: 200 + (splitSeed ?? 0), PacketsPerDirection, maxPayload, withLarge);
+        using var timeout = new CancellationTokenSource(TimeSpan.FromSeconds(60));
+
+        // Act
+        var sends = Task.WhenAll(
+            SendAllAsync(client, toServer, timeout.Token),
+            SendAllAsync(server, toClient, timeout.Token));
+        var receivedByServer = ReceiveAsync(server, PacketsPerDirection, timeout.Token);
+        var receivedByClient = ReceiveAsync(client, PacketsPerDirection, timeout.Token);
+        await sends;
+
+        // Assert
+        (await receivedByServer).Should().Equal(toServer, SamePacket);
+        (await receivedByClient).Should().Equal(toClient, SamePacket);
+        client.EncodeNonce.Should().Be(PacketsPerDirection);
+        client.DecodeNonce.Should().Be(PacketsPerDirection);
+        server.EncodeNonce.Should().Be(PacketsPerDirection);
+        server.DecodeNonce.Should().Be(PacketsPerDirection);
+    }
+
+    [Fact]
+    public async Task Loopback_EmptyAndMaximumPayloads_ShouldRoundTrip()
+    {
+        // Arrange
+        var (clientStream, serverStream) = LoopbackStream.CreatePair(splitSeed: 4, maxChunk: 4096);
+        await using var client = ApTransport.Create(clientStream, new ApCodec(ClientKey, ServerKey));
+        await using var server = ApTransport.Create(serverStream, new ApCodec(ServerKey, ClientKey));
+        var random = new Random(4);
+        var largest = new byte[ushort.MaxValue];
+        random.NextBytes(largest);
+        List<(byte command, byte[] payload)> packets = [(0x49, []), (0xb2, largest), (0x4a, []), (0xb2, largest)];
+
+        // Act
+        var received = ReceiveAsync(server, packets.Count, CancellationToken.None);
+        await SendAllAsync(client, packets, CancellationToken.None);
+
+        // Assert
+        (await received).Should().Equal(packets, SamePacket);
+    }
+
+    [Fact]
+    public async Task Loopback_BitFlippedInMac_ShouldFailMacAfterEarlierPackets()
+    {
+        // Arrange
+        var packets = CreatePackets(seed: 5, count: 10, maxPayload: 256, withLarge: false);
+        var encoded = EncodeEach(packets);
+        encoded[6][^1] ^= 0x01; // the header and payload still decrypt correctly
+
+        // Act
+        var (received, error) = await ReceiveRawAsync(encoded);
+
+        // Assert
+        received.Should().Equal(packets.Take(6), SamePacket);
+        error.Should().BeOfType<ApCodecException>().Which.Message.Should().Contain("MAC verification failed");
+    }
+
+    [Fact]
+    public async Task Loopback_PacketDroppedInFlight_ShouldFailOnNextPacket()
+    {
+        // Arrange
+        var packets = CreatePackets(seed: 6, count: 10, maxPayload: 256, withLarge: false);
+        var encoded = EncodeEach(packets);
+        encoded.RemoveAt(3);
+
+        // Act
+        var (received, error) = await ReceiveRawAsync(encoded);
+
+        // Assert
+        received.Should().Equal(packets.Take(3), SamePacket);
+        error.Should().BeOfType<ApCodecException>("packet 4 is decrypted with packet 3's nonce");
+    }
+
+    [Fact]
+    public async Task Loopback_ReplayedPacket_ShouldFail()
+    {
+        // Arrange
+        var packets = CreatePackets(seed: 7, count: 10, maxPayload: 256, withLarge: false);
+        var encoded = EncodeEach(packets);
+        encoded.Insert(5, encoded[4]);
+
+        // Act
+        var (received, error) = await ReceiveRawAsync(encoded);
+
+        // Assert
+        received.Should().Equal(packets.Take(5), SamePacket);
+        error.Should().BeOfType<ApCodecException>("a replayed packet carries a stale nonce");
+    }
+
+    [Fact]
+    public async Task Loopback_ClosedMidPacket_ShouldReportIncompletePacket()
+    {
+        // Arrange
+        var packets = CreatePackets(seed: 8, count: 3, maxPayload: 256, withLarge: false);
+        var encoded = EncodeEach(packets);
+        encoded[2] = encoded[2][..^2]; // lose the end of the MAC
+
+        // Act
+        var (received, error) = await ReceiveRawAsync(encoded);
+
+        // Assert
+        received.Should().Equal(packets.Take(2), SamePacket);
+        error.Should().BeOfType<ApCodecException>().Which.Message.Should().Contain("incomplete packet");
+    }
+
+    private static List<(byte command, byte[] payload)> CreatePackets(int seed, int count, int maxPayload, bool withLarge)
+    {
+        var random = new Random(seed);
+        var packets = new List<(byte command, byte[] payload)>(count);
+        for (var i = 0; i < count; i++)
+        {
+            // Mostly small packets, with an occasional one large enough to span many reads
+            var size = withLarge && random.Next(50) == 0 ? random.Next(ushort.MaxValue + 1) : random.Next(maxPayload + 1);
+            var payload = new byte[size];
+            random.NextBytes(payload);
+            packets.Add(((byte)random.Next(256), payload));
+        }
+        return packets;
+    }
+
+    /// <summary>
+    /// Encodes each packet with a bare client codec so tests can drop, replay or corrupt them in flight.
+    /// </summary>
+    private static List<byte[]> EncodeEach(List<(byte command, byte[] payload)> packets)
+    {
+        using var codec = new ApCodec(ClientKey, ServerKey);
+        return packets.Select(p =>
+        {
+            var writer = new ArrayBufferWriter<byte>();
+            codec.Encode(writer, p.command, p.payload);
+            return writer.WrittenSpan.ToArray();
+        }).ToList();
+    }
+
+    /// <summary>
+    /// Writes <paramref name="encoded"/> to a server transport in small split pieces, closes the
+    /// connection, and receives until end of stream or a decode failure.
+    /// </summary>
+    private static async Task<(List<(byte command, byte[] payload)> Received, Exception? Error)> ReceiveRawAsync(List<byte[]> encoded)
+    {
+        var (clientStream, serverStream) = LoopbackStream.CreatePair(splitSeed: 9, maxChunk: 5);
+        await using var server = ApTransport.Create(serverStream, new ApCodec(ServerKey, ClientKey));
+
+        foreach (var bytes in encoded)
+            await clientStream.WriteAsync(bytes);
+        await clientStream.DisposeAsync();
+
+        var received = new List<(byte command, byte[] payload)>();
+        try
+        {
+            while (await server.ReceiveAsync() is { } packet)
+                received.Add(packet);
+            return (received, null);
+        }
+        catch (Exception ex)
+        {
+            return (received, ex);
+        }
+    }
+
+    private static async Task SendAllAsync(ApTransport transport, List<(byte command, byte[] payload)> packets, CancellationToken ct)
+    {
+        foreach (var (command, payload) in packets)
+            await transport.SendAsync(command, payload, ct);
+    }
+
+    private static async Task<List<(byte command, byte[] payload)>> ReceiveAsync(ApTransport transport, int count, CancellationToken ct)
+    {
+        var received = new List<(byte command, byte[] payload)>(count);
+        while (received.Count < count && await transport.ReceiveAsync(ct) is { } packet)
+            received.Add(packet);
+        return received;
+    }
+
+    private static bool SamePacket((byte command, byte[] payload) actual, (byte command, byte[] payload) expected)
+        => actual.command == expected.command && actual.payload.AsSpan().SequenceEqual(expected.payload);
+}
@@ -0,0 +1,67 @@
+using System.Buffers;
+using FluentAssertions;
+using Wavee.Core.Connection;
+using Xunit;
+
+namespace Wavee.Tests.Core.Connection;
+
+/// <summary>
+/// Tests for ApCodec - validates the inbound packet size limit.
+///
+/// WHY: The payload length comes from the (decrypted) packet header. Bugs here will cause:
+/// - Allocations sized by whatever the peer announces
+/// - Legitimate packets at the limit being rejected
+/// </summary>
+public class ApCodecTests
+{
+    private static readonly byte[] ClientKey = Enumerable.Range(0, 32).Select(i => (byte)i).ToArray();
+    private static readonly byte[] ServerKey = ClientKey.Select(b => (byte)~b).ToArray();
+
+    [Fact]
+    public void TryDecode_PayloadAtLimit_ShouldDecode()
+    {
+        // Arrange
+        using var client = new ApCodec(ClientKey, ServerKey);
+        using var server = new ApCodec(ServerKey, ClientKey, maxPayloadSize: 100);
+        var writer = new ArrayBufferWriter<byte>();
+        client.Encode(writer, 0x4a, new byte[100]);
+        var buffer = new ReadOnlySequence<byte>(writer.WrittenMemory);
+
+        // Act
+        var decoded = server.TryDecode(ref buffer, out _, out var command, out var payload);
+
+        // Assert
+        decoded.Should().BeTrue();
+        command.Should().Be(0x4a);
+        payload.Should().HaveCount(100);
+    }
+
+    [Fact]
+    public void TryDecode_PayloadOverLimit_ShouldThrowBeforeReadingPayload()
+    {
+        // Arrange
+        using var client = new ApCodec(ClientKey, ServerKey);
+        using var server = new ApCodec(ServerKey, ClientKey, maxPayloadSize: 100);
+        var writer = new ArrayBufferWriter<byte>();
+        client.Encode(writer, 0x4a, new byte[101]);
+
+        // Only the header has arrived
+        var buffer = new ReadOnlySequence<byte>(writer.WrittenMemory[..3]);
+
+        // Act
+        var act = () => server.TryDecode(ref buffer, out _, out _, out _);
+
+        // Assert
+        act.Should().Throw<ApCodecException>().WithMessage("*101 bytes*100-byte limit*");
+    }
+
+    [Fact]
+    public void Constructor_WithLimitAboveWireMaximum_ShouldThrow()
+    {
+        // Act
+        var act = () => new ApCodec(ClientKey, ServerKey, maxPayloadSize: ushort.MaxValue + 1);
+
+        // Assert
+        act.Should().Throw<ArgumentOutOfRangeException>();
+    }
+}
@@ -0,0 +1,86 @@
+using System.Net.Security;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using FluentAssertions;
+using Wavee.Core.Connection;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Connection;
+
+/// <summary>
+/// Tests for CertificatePinning - validates pin matching, modes and host scoping.
+///
+/// WHY: The validator runs on every spclient and dealer TLS handshake. Bugs here will cause:
+/// - Pinning silently accepting any CA-valid certificate
+/// - ReportOnly (the documented escape hatch) still rejecting connections
+/// - CA errors being ignored once a pin matches
+/// </summary>
+public class CertificatePinningTests
+{
+    private static readonly X509Certificate2 Certificate = CreateCertificate();
+    private static readonly string CertificatePin = CertificatePinning.ComputePin(Certificate);
+    private static readonly string OtherPin = Convert.ToBase64String(new byte[32]);
+
+    [Fact]
+    public void Validator_WithMatchingPin_ShouldAccept()
+    {
+        // Arrange
+        var validator = CreateValidator(TlsPinningMode.Enforce, "sha256/" + CertificatePin);
+
+        // Act & Assert
+        validator(null!, Certificate, null, SslPolicyErrors.None).Should().BeTrue();
+    }
+
+    [Fact]
+    public void Validator_WithoutMatchingPin_ShouldRejectOnlyWhenEnforcing()
+    {
+        // Arrange
+        var enforce = CreateValidator(TlsPinningMode.Enforce, OtherPin);
+        var reportOnly = CreateValidator(TlsPinningMode.ReportOnly, OtherPin);
+
+        // Act & Assert
+        enforce(null!, Certificate, null, SslPolicyErrors.None).Should().BeFalse();
+        reportOnly(null!, Certificate, null, SslPolicyErrors.None).Should().BeTrue();
+    }
+
+    [Fact]
+    public void Validator_WithCaErrors_ShouldRejectEvenWhenPinMatches()
+    {
+        // Arrange
+        var validator = CreateValidator(TlsPinningMode.ReportOnly, CertificatePin);
+
+        // Act & Assert
+        validator(null!, Certificate, null, SslPolicyErrors.RemoteCertificateChainErrors).Should().BeFalse();
+    }
+
+    [Theory]
+    [InlineData("gew4-spclient.spotify.com", true)]
+    [InlineData("login5.spotify.com", true)]
+    [InlineData("spotify.com", true)]
+    [InlineData("i.scdn.co", false)]
+    [InlineData("notspotify.com", false)]
+    public void IsPinnedHost_ShouldOnlyCoverSpotifyDomain(string host, bool expected)
+    {
+        // Act & Assert
+        CertificatePinning.IsPinnedHost(host).Should().Be(expected);
+    }
+
+    [Fact]
+    public void IsEnabled_WithoutPins_ShouldBeFalse()
+    {
+        // Act & Assert
+        CertificatePinning.IsEnabled(new NetworkConfig { TlsPinning = TlsPinningMode.Enforce }).Should().BeFalse();
+        CertificatePinning.IsEnabled(new NetworkConfig { TlsPins = [OtherPin] }).Should().BeFalse();
+    }
+
+    private static RemoteCertificateValidationCallback CreateValidator(TlsPinningMode mode, string pin)
+        => CertificatePinning.CreateValidator(new NetworkConfig { TlsPinning = mode, TlsPins = [pin] });
+
+    private static X509Certificate2 CreateCertificate()
+    {
+        using var key = ECDsa.Create(ECCurve.NamedCurves.nistP256);
+        var request = new CertificateRequest("CN=spclient.wg.spotify.com", key, HashAlgorithmName.SHA256);
+        return request.CreateSelfSigned(DateTimeOffset.UtcNow.AddDays(-1), DateTimeOffset.UtcNow.AddDays(1));
+    }
+}
@@ -0,0 +1,122 @@
+using System.IO;
+using FluentAssertions;
+using Moq;
+using Wavee.Core.Connection;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Connection;
+
+/// <summary>
+/// Tests for ChaosApTransport - validates the simulated latency, reordering and disconnects that
+/// reconnect and timeout tests build on.
+///
+/// WHY: A chaos wrapper that reorders packets it shouldn't, or delivers them early, makes every
+/// test built on it meaningless. Bugs here will cause:
+/// - Mercury parts or audio chunks of one stream arriving out of order (false failures)
+/// - Latency tests passing because packets were never actually held back
+/// - Disconnect tests passing without the session ever seeing a dropped socket
+/// </summary>
+public class ChaosApTransportTests
+{
+    [Fact]
+    public async Task ReceiveAsync_WithJitter_ShouldDeliverAllPacketsAndKeepPerCommandOrder()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var sent = new List<(byte command, byte[] payload)>();
+        for (byte i = 0; i < 4; i++)
+        {
+            sent.Add((0xb2, [i]));
+            sent.Add((0x0d, [i]));
+        }
+        await using var transport = new ChaosApTransport(
+            CreateInner(sent),
+            new ChaosOptions { Seed = 7, Jitter = TimeSpan.FromSeconds(1), TimeProvider = time });
+
+        // Act
+        var first = transport.ReceiveAsync().AsTask();
+        time.Advance(TimeSpan.FromSeconds(2));
+        var received = new List<(byte command, byte[] payload)> { (await first)!.Value };
+        for (var i = 1; i < sent.Count; i++)
+            received.Add((await transport.ReceiveAsync())!.Value);
+
+        // Assert
+        received.Should().BeEquivalentTo(sent);
+        foreach (var command in new byte[] { 0xb2, 0x0d })
+        {
+            received.Where(p => p.command == command).Select(p => p.payload[0])
+                .Should().BeInAscendingOrder("packets with the same command must keep their order");
+        }
+        (await transport.ReceiveAsync()).Should().BeNull("the inner transport is closed");
+    }
+
+    [Fact]
+    public async Task ReceiveAsync_WithLatency_ShouldHoldPacketUntilDue()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        await using var transport = new ChaosApTransport(
+            CreateInner([(0x04, [1])]),
+            new ChaosOptions { Latency = TimeSpan.FromSeconds(5), TimeProvider = time });
+
+        // Act
+        var receive = transport.ReceiveAsync().AsTask();
+        time.Advance(TimeSpan.FromSeconds(4));
+        var earlyCompleted = receive.IsCompleted;
+        time.Advance(TimeSpan.FromSeconds(1));
+
+        // Assert
+        earlyCompleted.Should().BeFalse();
+        (await receive)!.Value.command.Should().Be(0x04);
+    }
+
+    [Fact]
+    public async Task Disconnect_ShouldFailSendsAndEndReceives()
+    {
+        // Arrange
+        var inner = CreateInnerMock([(0x04, [1])]);
+        await using var transport = new ChaosApTransport(inner.Object, new ChaosOptions());
+
+        // Act
+        transport.Disconnect();
+        var send = async () => await transport.SendAsync(0x49, new byte[4]);
+
+        // Assert
+        await send.Should().ThrowAsync<IOException>();
+        (await transport.ReceiveAsync()).Should().BeNull();
+        transport.Disconnects.Should().Be(1);
+        inner.Verify(t => t.SendAsync(It.IsAny<byte>(), It.IsAny<ReadOnlyMemory<byte>>(), It.IsAny<CancellationToken>()), Times.Never);
+    }
+
+    [Fact]
+    public async Task ReceiveAsync_WithCertainDisconnect_ShouldDropConnection()
+    {
+        // Arrange
+        await using var transport = new ChaosApTransport(
+            CreateInner([(0x04, [1])]),
+            new ChaosOptions { DisconnectProbability = 1 });
+
+        // Act
+        var packet = await transport.ReceiveAsync();
+
+        // Assert
+        packet.Should().BeNull();
+        transport.IsDisconnected.Should().BeTrue();
+    }
+
+    private static IApTransport CreateInner(IEnumerable<(byte command, byte[] payload)> packets)
+        => CreateInnerMock(packets).Object;
+
+    private static Mock<IApTransport> CreateInnerMock(IEnumerable<(byte command, byte[] payload)> packets)
+    {
+        var queue = new Queue<(byte command, byte[] payload)>(packets);
+        var mock = MockTransportHelpers.CreateMockApTransport();
+        mock.Setup(t => t.ReceiveAsync(It.IsAny<CancellationToken>()))
+            .Returns(() => new ValueTask<(byte command, byte[] payload)?>(
+                queue.Count > 0 ? queue.Dequeue() : null));
+        mock.Setup(t => t.SendAsync(It.IsAny<byte>(), It.IsAny<ReadOnlyMemory<byte>>(), It.IsAny<CancellationToken>()))
+            .Returns(ValueTask.CompletedTask);
+        return mock;
+    }
+}
@@ -1,6 +1,7 @@
 using FluentAssertions;
 using System.Numerics;
 using Wavee.Core.Connection;
+using Wavee.Tests.Helpers;
 using Xunit;
 
 namespace Wavee.Tests.Core.Connection;
@@ -134,4 +135,45 @@ public class DiffieHellmanKeysTests
             }
         }
     }
+
+    [Fact]
+    public void Dispose_ShouldZeroPrivateKey()
+    {
+        // ============================================================
+        // WHY: The DH private exponent must not linger in memory once
+        //      the handshake is done.
+        // ============================================================
+
+        // Arrange
+        var keys = DiffieHellmanKeys.GenerateRandom();
+        var field = typeof(DiffieHellmanKeys).GetField("_privateKeyBytes",
+            System.Reflection.BindingFlags.NonPublic | System.Reflection.BindingFlags.Instance)!;
+        var privateKey = (byte[])field.GetValue(keys)!;
+
+        // Act
+        keys.Dispose();
+
+        // Assert
+        privateKey.Should().OnlyContain(b => b == 0, "private key bytes must be wiped on dispose");
+        var act = () => keys.ComputeSharedSecret(keys.PublicKey.Span);
+        act.Should().Throw<ObjectDisposedException>();
+    }
+
+    [Fact]
+    public void GenerateRandom_WithSeededRng_ShouldBeReproducible()
+    {
+        // ============================================================
+        // WHY: Handshake vectors are regenerated from a seeded RNG;
+        //      the same seed must yield the same key pair.
+        // ============================================================
+
+        // Act
+        using var first = DiffieHellmanKeys.GenerateRandom(new SeededRandomNumberGenerator(1234));
+        using var second = DiffieHellmanKeys.GenerateRandom(new SeededRandomNumberGenerator(1234));
+        using var other = DiffieHellmanKeys.GenerateRandom(new SeededRandomNumberGenerator(4321));
+
+        // Assert
+        first.PublicKey.ToArray().Should().Equal(second.PublicKey.ToArray());
+        first.PublicKey.ToArray().Should().NotEqual(other.PublicKey.ToArray());
+    }
 }
@@ -0,0 +1,74 @@
+using System.Net;
+using FluentAssertions;
+using Wavee.Core.Connection;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Connection;
+
+/// <summary>
+/// Tests for NetworkBinding address selection.
+/// </summary>
+public class NetworkBindingTests
+{
+    private static readonly IPAddress V4A = IPAddress.Parse("192.0.2.1");
+    private static readonly IPAddress V4B = IPAddress.Parse("192.0.2.2");
+    private static readonly IPAddress V6A = IPAddress.Parse("2001:db8::1");
+    private static readonly IPAddress[] Resolved = [V6A, V4A, V4B];
+
+    [Fact]
+    public void OrderCandidates_Any_ShouldKeepResolverOrder()
+    {
+        // Act
+        var result = NetworkBinding.OrderCandidates(Resolved, new NetworkConfig());
+
+        // Assert
+        result.Should().Equal(V6A, V4A, V4B);
+    }
+
+    [Fact]
+    public void OrderCandidates_PreferIPv4_ShouldPutIPv4FirstAndKeepIPv6()
+    {
+        // Arrange
+        var config = new NetworkConfig { AddressFamily = AddressFamilyPreference.PreferIPv4 };
+
+        // Act
+        var result = NetworkBinding.OrderCandidates(Resolved, config);
+
+        // Assert
+        result.Should().Equal(V4A, V4B, V6A);
+    }
+
+    [Fact]
+    public void OrderCandidates_IPv6Only_ShouldDropIPv4()
+    {
+        // Arrange
+        var config = new NetworkConfig { AddressFamily = AddressFamilyPreference.IPv6Only };
+
+        // Act
+        var result = NetworkBinding.OrderCandidates(Resolved, config);
+
+        // Assert
+        result.Should().Equal(V6A);
+    }
+
+    [Fact]
+    public void OrderCandidates_LocalAddress_ShouldOnlyKeepMatchingFamily()
+    {
+        // Arrange
+        var config = new NetworkConfig { LocalAddress = IPAddress.Parse("10.0.0.5") };
+
+        // Act
+        var result = NetworkBinding.OrderCandidates(Resolved, config);
+
+        // Assert
+        result.Should().Equal(V4A, V4B);
+    }
+
+    [Fact]
+    public void IsCustomized_Defaults_ShouldBeFalse()
+    {
+        // Act & Assert
+        NetworkBinding.IsCustomized(new NetworkConfig()).Should().BeFalse();
+    }
+}
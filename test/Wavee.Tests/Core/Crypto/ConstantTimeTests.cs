@@ -0,0 +1,96 @@
+using System.Text.RegularExpressions;
+using FluentAssertions;
+using Wavee.Core.Crypto;
+using Xunit;
+
+namespace Wavee.Tests.Core.Crypto;
+
+/// <summary>
+/// Tests for ConstantTime plus a source guard that keeps variable-time comparisons
+/// out of the crypto, connection, authentication and OAuth code.
+/// </summary>
+public class ConstantTimeTests
+{
+    private static readonly string[] GuardedFolders =
+    [
+        Path.Combine("Core", "Crypto"),
+        Path.Combine("Core", "Connection"),
+        Path.Combine("Core", "Authentication"),
+        "OAuth",
+    ];
+
+    // Plain span / string comparisons that short-circuit on the first mismatch.
+    private static readonly Regex VariableTimeComparison = new(
+        @"\.SequenceEqual\(|[!=]=\s*_expected|_expected\w*\s*[!=]=",
+        RegexOptions.Compiled);
+
+    [Fact]
+    public void BytesEqual_ShouldMatchOrdinalEquality()
+    {
+        // ============================================================
+        // WHY: The wrapper must behave exactly like SequenceEqual,
+        //      only without the early exit.
+        // ============================================================
+
+        ConstantTime.BytesEqual(new byte[] { 1, 2, 3, 4 }, new byte[] { 1, 2, 3, 4 }).Should().BeTrue();
+        ConstantTime.BytesEqual(new byte[] { 1, 2, 3, 4 }, new byte[] { 1, 2, 3, 5 }).Should().BeFalse();
+        ConstantTime.BytesEqual(new byte[] { 1, 2, 3 }, new byte[] { 1, 2, 3, 4 }).Should().BeFalse();
+    }
+
+    [Fact]
+    public void StringsEqual_WithNulls_ShouldOnlyMatchBothNull()
+    {
+        // ============================================================
+        // WHY: A missing OAuth state must never match an expected one.
+        // ============================================================
+
+        ConstantTime.StringsEqual(null, null).Should().BeTrue();
+        ConstantTime.StringsEqual(null, "state").Should().BeFalse();
+        ConstantTime.StringsEqual("state", "state").Should().BeTrue();
+        ConstantTime.StringsEqual("state", "stat3").Should().BeFalse();
+    }
+
+    [Fact]
+    public void GuardedSources_ShouldNotUseVariableTimeComparisons()
+    {
+        // ============================================================
+        // WHY: MAC and challenge checks regressed to SequenceEqual
+        //      once already; fail loudly if it happens again.
+        // ============================================================
+
+        // Arrange
+        var root = FindWaveeSourceRoot();
+        var offenders = new List<string>();
+
+        // Act
+        foreach (var folder in GuardedFolders)
+        {
+            foreach (var file in Directory.EnumerateFiles(Path.Combine(root, folder), "*.cs", SearchOption.AllDirectories))
+            {
+                var lines = File.ReadAllLines(file);
+                for (var i = 0; i < lines.Length; i++)
+                {
+                    if (VariableTimeComparison.IsMatch(lines[i]))
+                        offenders.Add($"{Path.GetRelativePath(root, file)}:{i + 1}: {lines[i].Trim()}");
+                }
+            }
+        }
+
+        // Assert
+        offenders.Should().BeEmpty("secret-dependent comparisons must go through ConstantTime");
+    }
+
+    private static string FindWaveeSourceRoot()
+    {
+        var dir = new DirectoryInfo(AppContext.BaseDirectory);
+        while (dir != null)
+        {
+            var candidate = Path.Combine(dir.FullName, "src", "Wavee");
+            if (File.Exists(Path.Combine(candidate, "Wavee.csproj")))
+                return candidate;
+            dir = dir.Parent;
+        }
+
+        throw new DirectoryNotFoundException("Could not locate src/Wavee from the test output directory");
+    }
+}
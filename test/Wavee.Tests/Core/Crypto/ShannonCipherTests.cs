@@ -357,6 +357,85 @@ public class ShannonCipherTests
         _output.WriteLine("Invalid MAC lengths correctly rejected");
     }
 
+    [Fact]
+    public void Dispose_ShouldZeroKeySchedule()
+    {
+        // Arrange
+        byte[] key = new byte[32];
+        for (int i = 0; i < 32; i++)
+            key[i] = (byte)(i + 1);
+
+        var cipher = new ShannonCipher(key);
+        cipher.NonceU32(0);
+
+        var flags = System.Reflection.BindingFlags.NonPublic | System.Reflection.BindingFlags.Instance;
+        var registers = new[] { "_R", "_CRC", "_initR" }
+            .Select(name => (uint[])typeof(ShannonCipher).GetField(name, flags)!.GetValue(cipher)!)
+            .ToArray();
+
+        // Act
+        cipher.Dispose();
+
+        // Assert
+        foreach (var register in registers)
+            Assert.All(register, word => Assert.Equal(0u, word));
+        Assert.Throws<ObjectDisposedException>(() => cipher.NonceU32(1));
+
+        _output.WriteLine("Shannon key schedule wiped on dispose");
+    }
+
+    [Theory]
+    [InlineData(100, 1)]
+    [InlineData(4096, 4096)]
+    [InlineData(4099, 37)]
+    [InlineData(65535, 1000)]
+    public void SimdPath_ShouldMatchScalarPath(int length, int callSize)
+    {
+        // Arrange - random key and data, fed in uneven slices so the buffered-byte
+        // paths hand over to the word loop mid-buffer
+        var random = new Random(length);
+        var key = new byte[32];
+        random.NextBytes(key);
+        var plaintext = new byte[length];
+        random.NextBytes(plaintext);
+
+        using var scalar = new ShannonCipher(key, allowSimd: false);
+        using var simd = new ShannonCipher(key);
+
+        for (uint nonce = 0; nonce < 3; nonce++)
+        {
+            scalar.NonceU32(nonce);
+            simd.NonceU32(nonce);
+
+            // Act
+            var scalarOut = (byte[])plaintext.Clone();
+            var simdOut = (byte[])plaintext.Clone();
+            for (var offset = 0; offset < length; offset += callSize)
+            {
+                var count = Math.Min(callSize, length - offset);
+                scalar.Encrypt(scalarOut.AsSpan(offset, count));
+                simd.Encrypt(simdOut.AsSpan(offset, count));
+            }
+
+            var scalarMac = new byte[4];
+            var simdMac = new byte[4];
+            scalar.Finish(scalarMac);
+            simd.Finish(simdMac);
+
+            // Assert
+            Assert.Equal(scalarOut, simdOut);
+            Assert.Equal(scalarMac, simdMac);
+
+            // Decrypting with the vector path restores the plaintext and MAC
+            simd.NonceU32(nonce);
+            simd.Decrypt(simdOut);
+            simd.CheckMac(scalarMac);
+            Assert.Equal(plaintext, simdOut);
+        }
+
+        _output.WriteLine($"SIMD supported: {ShannonCipher.IsSimdSupported}");
+    }
+
     #region Helper Methods
 
     private static string BytesToHex(byte[] bytes)
@@ -0,0 +1,190 @@
+using System.Buffers.Binary;
+using System.Text;
+using FluentAssertions;
+using Google.Protobuf;
+using Wavee.Core.Diagnostics;
+using Wavee.Core.Session;
+using Wavee.Protocol;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Diagnostics;
+
+/// <summary>
+/// Tests for ProtocolTraceWriter, ProtocolTrace and ProtocolTraceFormatter - validates
+/// redaction of AP, Mercury and dealer traffic, the .wavee-trace round trip and the printed timeline.
+///
+/// WHY: Users attach traces to public bug reports. Bugs here will cause:
+/// - Reusable credentials, audio keys or tokens published with a trace
+/// - Mercury continuation packets decoded as headers, producing garbage URIs
+/// - Traces from one build unreadable by another
+/// </summary>
+public class ProtocolTraceTests
+{
+    [Fact]
+    public void RecordApPacket_CredentialAndKeyPackets_ShouldKeepOnlySize()
+    {
+        // Arrange
+        var output = new StringWriter();
+        using var writer = new ProtocolTraceWriter(output);
+        var secret = Encoding.UTF8.GetBytes("reusable-credentials-blob");
+
+        // Act
+        writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.APWelcome, secret);
+        writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.AesKey, secret);
+        writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.Ping, [0, 0, 0, 1]);
+
+        // Assert
+        output.ToString().Should().NotContain(Convert.ToHexString(secret)).And.NotContain("reusable");
+        var records = ReadBack(output).Records;
+        records.Select(r => r.Command).Should().Equal("APWelcome", "AesKey", "Ping");
+        records[0].Size.Should().Be(secret.Length);
+        records[0].Payload.Should().BeNull();
+        records[2].Payload.Should().Be("00000001", "pings carry nothing sensitive");
+    }
+
+    [Fact]
+    public void RecordApPacket_Mercury_ShouldDecodeHeaderOnlyOnFirstPacket()
+    {
+        // Arrange
+        var output = new StringWriter();
+        using var writer = new ProtocolTraceWriter(output);
+        var header = new Header
+        {
+            Uri = "hm://metadata/4/track/abc?access_token=secret",
+            Method = "GET",
+            StatusCode = 200,
+            UserFields = { new UserField { Key = "Authorization", Value = ByteString.CopyFromUtf8("Bearer secret") } }
+        };
+
+        // Act - a two-packet response: header + part, then a continuation whose first part is body
+        writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.MercuryReq,
+            BuildMercuryPacket(7, final: false, header.ToByteArray(), new byte[10]));
+        writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.MercuryReq,
+            BuildMercuryPacket(7, final: true, new byte[20]));
+
+        // Assert
+        output.ToString().Should().NotContain("secret");
+        var records = ReadBack(output).Records;
+        records.Should().HaveCount(2);
+        records[0].Channel.Should().Be(ProtocolTraceChannel.Mercury);
+        records[0].Sequence.Should().Be(7UL);
+        records[0].Method.Should().Be("GET");
+        records[0].Status.Should().Be(200);
+        records[0].Uri.Should().StartWith("hm://metadata/4/track/abc?access_token=");
+        records[0].Headers.Should().ContainKey("Authorization");
+        records[0].Parts.Should().Equal(10);
+        records[1].Uri.Should().BeNull("a continuation packet has no header part");
+        records[1].Parts.Should().Equal(20);
+        records[1].Final.Should().BeTrue();
+    }
+
+    [Fact]
+    public void RecordDealerMessage_ShouldRedactTokensAndKeepStructure()
+    {
+        // Arrange
+        var output = new StringWriter();
+        using var writer = new ProtocolTraceWriter(output);
+
+        // Act
+        writer.RecordDealerMessage(ProtocolTraceDirection.Inbound, """
+            {"type":"request","key":"1/phone","message_ident":"hm://connect-state/v1/player/command",
+             "headers":{"Authorization":"Bearer secret"},"payload":{"command":{"endpoint":"pause"}}}
+            """u8);
+
+        // Assert
+        output.ToString().Should().NotContain("secret");
+        var record = ReadBack(output).Records.Single();
+        record.Command.Should().Be("request");
+        record.Uri.Should().Be("hm://connect-state/v1/player/command");
+        record.Message!.Value.GetProperty("payload").GetProperty("command").GetProperty("endpoint").GetString()
+            .Should().Be("pause");
+    }
+
+    [Fact]
+    public void Read_ShouldRoundTripHeaderAndOffsets()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider(new DateTimeOffset(2026, 10, 16, 9, 0, 0, TimeSpan.Zero));
+        var output = new StringWriter();
+        using (var writer = new ProtocolTraceWriter(output, timeProvider: time))
+        {
+            writer.RecordApPacket(ProtocolTraceDirection.Outbound, (byte)PacketType.Pong, [0, 0, 0, 0]);
+            time.Advance(TimeSpan.FromMilliseconds(1500));
+            writer.RecordApPacket(ProtocolTraceDirection.Inbound, (byte)PacketType.PongAck, []);
+        }
+
+        // Act
+        var trace = ReadBack(output);
+
+        // Assert
+        trace.Header.Format.Should().Be(ProtocolTrace.FormatName);
+        trace.Header.Version.Should().Be(ProtocolTrace.CurrentVersion);
+        trace.Header.StartedAtUtc.Should().Be(new DateTimeOffset(2026, 10, 16, 9, 0, 0, TimeSpan.Zero));
+        trace.Records.Select(r => r.OffsetMs).Should().Equal(0, 1500);
+        trace.Records.Select(r => r.Direction).Should().Equal(ProtocolTraceDirection.Outbound, ProtocolTraceDirection.Inbound);
+    }
+
+    [Theory]
+    [InlineData("")]
+    [InlineData("""{"offsetMs":0,"direction":"Inbound","message":{}}""")]
+    [InlineData("""{"format":"wavee-trace","version":99,"startedAtUtc":"2026-10-16T09:00:00Z"}""")]
+    public void Read_WhenNotAReadableTrace_ShouldThrowFormatException(string content)
+    {
+        // Act
+        var act = () => ProtocolTrace.Read(new StringReader(content));
+
+        // Assert
+        act.Should().Throw<FormatException>();
+    }
+
+    [Fact]
+    public void Format_ShouldPrintOneLinePerRecordWithDealerBodies()
+    {
+        // Arrange
+        var output = new StringWriter();
+        using (var writer = new ProtocolTraceWriter(output))
+        {
+            writer.RecordApPacket(ProtocolTraceDirection.Outbound, (byte)PacketType.MercuryReq,
+                BuildMercuryPacket(3, final: true, new Header { Uri = "hm://keymaster/token", Method = "GET" }.ToByteArray()));
+            writer.RecordDealerMessage(ProtocolTraceDirection.Outbound, """{"type":"reply","key":"1/phone","payload":{"success":true}}"""u8);
+        }
+        var trace = ReadBack(output);
+
+        // Act
+        var full = ProtocolTraceFormatter.Format(trace);
+        var summary = ProtocolTraceFormatter.Format(trace, includeMessages: false);
+
+        // Assert
+        var lines = summary.Split(Environment.NewLine, StringSplitOptions.RemoveEmptyEntries);
+        lines.Should().HaveCount(3);
+        lines[0].Should().StartWith("wavee-trace v1").And.EndWith("2 records");
+        lines[1].Should().Contain("→ Mercury").And.Contain("#3 GET hm://keymaster/token").And.Contain("final, 0 parts");
+        lines[2].Should().Contain("→ Dealer").And.Contain("reply").And.Contain("key=1/phone");
+        full.Should().Contain("\"success\": true");
+    }
+
+    private static ProtocolTrace ReadBack(StringWriter output) => ProtocolTrace.Read(new StringReader(output.ToString()));
+
+    private static byte[] BuildMercuryPacket(ulong seq, bool final, params byte[][] parts)
+    {
+        using var ms = new MemoryStream();
+        Span<byte> buffer = stackalloc byte[8];
+
+        BinaryPrimitives.WriteUInt16BigEndian(buffer, 8);
+        ms.Write(buffer[..2]);
+        BinaryPrimitives.WriteUInt64BigEndian(buffer, seq);
+        ms.Write(buffer);
+        ms.WriteByte(final ? (byte)0x01 : (byte)0x00);
+        BinaryPrimitives.WriteUInt16BigEndian(buffer, (ushort)parts.Length);
+        ms.Write(buffer[..2]);
+        foreach (var part in parts)
+        {
+            BinaryPrimitives.WriteUInt16BigEndian(buffer, (ushort)part.Length);
+            ms.Write(buffer[..2]);
+            ms.Write(part);
+        }
+
+        return ms.ToArray();
+    }
+}
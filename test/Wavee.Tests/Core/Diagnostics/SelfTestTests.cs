@@ -0,0 +1,46 @@
+using FluentAssertions;
+using Wavee.Core.Diagnostics;
+using Xunit;
+
+namespace Wavee.Tests.Core.Diagnostics;
+
+/// <summary>
+/// Tests for SelfTest - validates the embedded vectors and report semantics.
+///
+/// WHY: `wavee selftest` is what users run when something is platform-broken. Bugs here will cause:
+/// - A correct build reporting a crypto failure (a typo in an embedded vector)
+/// - One failing check hiding the results of the others
+/// </summary>
+public class SelfTestTests
+{
+    [Fact]
+    public async Task RunAsync_BuiltInChecks_ShouldAllPass()
+    {
+        // Act
+        var report = await SelfTest.RunAsync();
+
+        // Assert
+        report.Results.Select(r => r.Name).Should().Equal(SelfTest.BuiltInChecks.Select(c => c.Name));
+        report.Results.Should().OnlyContain(r => r.Passed, because: string.Join("; ", report.Results.Select(r => $"{r.Name}: {r.Detail}")));
+        report.Passed.Should().BeTrue();
+    }
+
+    [Fact]
+    public async Task RunAsync_WhenAdditionalCheckThrows_ShouldReportFailureAndKeepGoing()
+    {
+        // Arrange
+        var checks = new[]
+        {
+            new SelfTestCheck("broken", _ => throw new InvalidOperationException("no device")),
+            new SelfTestCheck("fine", _ => Task.FromResult("ok"))
+        };
+
+        // Act
+        var report = await SelfTest.RunAsync(checks);
+
+        // Assert
+        report.Passed.Should().BeFalse();
+        report.Results.Single(r => r.Name == "broken").Detail.Should().Be("no device");
+        report.Results.Single(r => r.Name == "fine").Passed.Should().BeTrue();
+    }
+}
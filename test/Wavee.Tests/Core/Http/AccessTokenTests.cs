@@ -196,4 +196,21 @@ public class AccessTokenTests
         // Assert
         act.Should().Throw<ArgumentException>();
     }
+
+    [Fact]
+    public void ShouldRefresh_WithServerCorrectedNow_ShouldUseSuppliedClock()
+    {
+        // ============================================================
+        // WHY: Devices with a wrong RTC must judge expiry on the
+        //      server-corrected clock, not the local one.
+        // ============================================================
+
+        // Arrange — token issued on a server clock that is 2h ahead of local
+        var serverNow = DateTimeOffset.UtcNow.AddHours(2);
+        var token = AccessToken.FromLogin5Response("skewed_token", 3600, issuedAt: serverNow);
+
+        // Act & Assert
+        token.ShouldRefresh(now: serverNow).Should().BeFalse("Token is fresh on the server clock");
+        token.IsExpired(now: serverNow.AddHours(1)).Should().BeTrue("Token lifetime elapsed on the server clock");
+    }
 }
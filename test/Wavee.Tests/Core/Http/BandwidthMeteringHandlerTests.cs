@@ -0,0 +1,108 @@
+using System.Net;
+using FluentAssertions;
+using Microsoft.Extensions.DependencyInjection;
+using Wavee.Core.DependencyInjection;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+/// <summary>
+/// Tests for BandwidthMeteringHandler - validates host classification and body counting.
+///
+/// WHY: Every HTTP request the session makes goes through this handler. Bugs here will cause:
+/// - Cover art or CDN audio counted as metadata
+/// - Response bodies not counted when read as a stream rather than buffered
+/// - Content-Length lost on the way through, or every request failing outright
+/// </summary>
+public class BandwidthMeteringHandlerTests
+{
+    [Theory]
+    [InlineData("https://i.scdn.co/image/ab67", TrafficCategory.Images)]
+    [InlineData("https://mosaic.scdn.co/640/ab67", TrafficCategory.Images)]
+    [InlineData("https://image-cdn-ak.spotifycdn.com/image/ab67", TrafficCategory.Images)]
+    [InlineData("https://audio4-fa.scdn.co/audio/abcd", TrafficCategory.Audio)]
+    [InlineData("https://heads-fa-tls13.spotifycdn.com/head/abcd", TrafficCategory.Audio)]
+    [InlineData("https://p.scdn.co/mp3-preview/abcd", TrafficCategory.Audio)]
+    [InlineData("https://login5.spotify.com/v3/login", TrafficCategory.Control)]
+    [InlineData("https://apresolve.spotify.com/?type=accesspoint", TrafficCategory.Control)]
+    [InlineData("https://clienttoken.spotify.com/v1/clienttoken", TrafficCategory.Control)]
+    [InlineData("https://spclient.wg.spotify.com/metadata/4/track/abcd", TrafficCategory.Metadata)]
+    [InlineData("https://api-partner.spotify.com/pathfinder/v2/query", TrafficCategory.Metadata)]
+    public void Classify_ShouldMapHostToCategory(string url, TrafficCategory expected)
+    {
+        // Act & Assert
+        BandwidthMeteringHandler.Classify(new Uri(url)).Should().Be(expected);
+    }
+
+    [Fact]
+    public async Task SendAsync_ShouldCountRequestAndBufferedResponseBodies()
+    {
+        // Arrange
+        var meter = new BandwidthMeter();
+        using var client = CreateClient(new FixedResponseHandler(new byte[300]), meter);
+
+        // Act
+        using var response = await client.PostAsync(
+            "https://spclient.wg.spotify.com/extended-metadata/v0/extended-metadata",
+            new ByteArrayContent(new byte[40]));
+        var body = await response.Content.ReadAsByteArrayAsync();
+
+        // Assert
+        body.Should().HaveCount(300);
+        response.Content.Headers.ContentLength.Should().Be(300);
+        meter.GetStatistics().Categories[TrafficCategory.Metadata].Should().Be(new TrafficCounters(40, 300));
+    }
+
+    [Fact]
+    public async Task SendAsync_StreamedResponse_ShouldCountBytesAsRead()
+    {
+        // Arrange
+        var meter = new BandwidthMeter();
+        using var client = CreateClient(new FixedResponseHandler(new byte[1000]), meter);
+
+        // Act
+        using var response = await client.GetAsync("https://i.scdn.co/image/ab67", HttpCompletionOption.ResponseHeadersRead);
+        await using var stream = await response.Content.ReadAsStreamAsync();
+        var read = await stream.ReadAsync(new byte[250]);
+
+        // Assert
+        meter.GetStatistics().Categories[TrafficCategory.Images].BytesReceived.Should().Be(read);
+    }
+
+    [Fact]
+    public async Task AddBandwidthMetering_ShouldMeterNamedClientRequests()
+    {
+        // ============================================================
+        // WHY: The session gets its client from IHttpClientFactory. The
+        //      meter must sit in that client's handler chain and share the
+        //      meter the session reports from.
+        // ============================================================
+
+        // Arrange
+        var meter = new BandwidthMeter();
+        var services = new ServiceCollection();
+        services.AddHttpClient("Wavee")
+            .ConfigurePrimaryHttpMessageHandler(() => new FixedResponseHandler(new byte[64]))
+            .AddBandwidthMetering(meter);
+        await using var provider = services.BuildServiceProvider();
+        var client = provider.GetRequiredService<IHttpClientFactory>().CreateClient("Wavee");
+
+        // Act
+        var body = await client.GetByteArrayAsync("https://spclient.wg.spotify.com/metadata/4/track/abcd");
+
+        // Assert
+        body.Should().HaveCount(64);
+        meter.GetStatistics().Categories[TrafficCategory.Metadata].BytesReceived.Should().Be(64);
+    }
+
+    private static HttpClient CreateClient(HttpMessageHandler inner, BandwidthMeter meter)
+        => new(new BandwidthMeteringHandler(meter) { InnerHandler = inner });
+
+    private sealed class FixedResponseHandler(byte[] body) : HttpMessageHandler
+    {
+        protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+            => Task.FromResult(new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent(body) });
+    }
+}
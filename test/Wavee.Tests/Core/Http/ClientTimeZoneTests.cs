@@ -0,0 +1,51 @@
+using FluentAssertions;
+using Wavee.Core.Http;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+/// <summary>
+/// Tests for ClientTimeZone - validates the configured, TZ and system fallbacks for the time
+/// zone sent with home feed requests.
+///
+/// WHY: Home content changes by time of day. Bugs here will cause:
+/// - Daemons in UTC containers getting breakfast playlists at midnight
+/// - Windows time zone ids sent where Spotify expects IANA ids
+/// </summary>
+public class ClientTimeZoneTests
+{
+    [Fact]
+    public void Resolve_ConfiguredZone_ShouldWinOverTzAndSystem()
+    {
+        // Act
+        var zone = ClientTimeZone.Resolve("Europe/Amsterdam", "Asia/Tokyo", TimeZoneInfo.Utc);
+
+        // Assert
+        zone.Should().Be("Europe/Amsterdam");
+    }
+
+    [Theory]
+    [InlineData(null, "Asia/Tokyo", "Asia/Tokyo")]
+    [InlineData(null, ":America/New_York", "America/New_York")]
+    [InlineData("Not/AZone", "Asia/Tokyo", "Asia/Tokyo")]
+    [InlineData(null, null, "UTC")]
+    public void Resolve_WithoutUsableConfiguredZone_ShouldFallBack(string? configured, string? tz, string expected)
+    {
+        // Act
+        var zone = ClientTimeZone.Resolve(configured, tz, TimeZoneInfo.Utc);
+
+        // Assert
+        zone.Should().Be(expected);
+    }
+
+    [Fact]
+    public void TryGetIanaId_WindowsId_ShouldConvertToIana()
+    {
+        // Act
+        var ok = ClientTimeZone.TryGetIanaId("W. Europe Standard Time", out var id);
+
+        // Assert
+        ok.Should().BeTrue();
+        id.Should().Be("Europe/Berlin");
+    }
+}
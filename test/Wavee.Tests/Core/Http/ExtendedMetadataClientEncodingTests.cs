@@ -1,5 +1,7 @@
 using System.Net.Http;
 using FluentAssertions;
+using Google.Protobuf;
+using Google.Protobuf.WellKnownTypes;
 using Wavee.Core.Http;
 using ZstdSharp;
 using Xunit;
@@ -39,4 +41,67 @@ public class ExtendedMetadataClientEncodingTests
 
         decoded.Should().Equal(expected);
     }
+
+    [Fact]
+    public async Task ReadResponseBytesAsync_ShouldAllowBodyExactlyAtLimit()
+    {
+        var expected = new byte[] { 0x01, 0x02, 0x03, 0x04 };
+
+        using var response = new HttpResponseMessage
+        {
+            Content = new ByteArrayContent(expected)
+        };
+
+        var decoded = await ExtendedMetadataClient.ReadResponseBytesAsync(response, maxResponseBytes: 4);
+
+        decoded.Should().Equal(expected);
+    }
+
+    [Fact]
+    public async Task ReadResponseBytesAsync_ShouldThrowTooLarge_WhenDecodedBodyExceedsLimit()
+    {
+        var compressed = new Compressor().Wrap(new byte[100_000]).ToArray();
+
+        using var response = new HttpResponseMessage
+        {
+            Content = new ByteArrayContent(compressed)
+        };
+        response.Content.Headers.ContentEncoding.Add("zstd");
+
+        var act = () => ExtendedMetadataClient.ReadResponseBytesAsync(response, maxResponseBytes: 10_000);
+
+        (await act.Should().ThrowAsync<SpClientException>())
+            .Which.Reason.Should().Be(SpClientFailureReason.TooLarge);
+    }
+
+    [Fact]
+    public async Task ParseResponseAsync_ShouldThrowTooLarge_WhenContentLengthExceedsLimit()
+    {
+        var payload = new StringValue { Value = new string('x', 1000) }.ToByteArray();
+
+        using var response = new HttpResponseMessage
+        {
+            Content = new ByteArrayContent(payload)
+        };
+
+        var act = () => ExtendedMetadataClient.ParseResponseAsync(StringValue.Parser, response, maxResponseBytes: 100);
+
+        (await act.Should().ThrowAsync<SpClientException>())
+            .Which.Reason.Should().Be(SpClientFailureReason.TooLarge);
+    }
+
+    [Fact]
+    public async Task ParseResponseAsync_ShouldParseMessage_WhenWithinLimit()
+    {
+        var payload = new StringValue { Value = "playlist" }.ToByteArray();
+
+        using var response = new HttpResponseMessage
+        {
+            Content = new ByteArrayContent(payload)
+        };
+
+        var parsed = await ExtendedMetadataClient.ParseResponseAsync(StringValue.Parser, response, maxResponseBytes: payload.Length);
+
+        parsed.Value.Should().Be("playlist");
+    }
 }
@@ -0,0 +1,129 @@
+using System.Net;
+using FluentAssertions;
+using Wavee.Core.Http;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+/// <summary>
+/// Tests for ImagePipeline - validates deduplication, the byte budget and cancellation.
+///
+/// WHY: Library views request hundreds of covers while scrolling. Bugs here will cause:
+/// - The same cover downloaded once per visible row
+/// - Unbounded image bytes held in memory
+/// - A row scrolling away cancelling the download other rows are waiting on
+/// - Session shutdown racing downloads that still read the disposed token
+/// </summary>
+public class ImagePipelineTests
+{
+    [Fact]
+    public async Task GetAsync_ConcurrentRequests_ShouldShareOneDownload()
+    {
+        // Arrange
+        var handler = new GatedHandler();
+        using var pipeline = new ImagePipeline(new HttpClient(handler));
+
+        // Act
+        var first = pipeline.GetAsync("spotify:image:ab67");
+        var second = pipeline.GetAsync("https://i.scdn.co/image/ab67");
+        handler.Release(new byte[10]);
+        var results = await Task.WhenAll(first, second);
+
+        // Assert
+        handler.Requests.Should().Be(1);
+        results[0].Should().BeSameAs(results[1]);
+    }
+
+    [Fact]
+    public async Task GetAsync_OverBudget_ShouldEvictLeastRecentlyUsed()
+    {
+        // Arrange
+        var handler = new GatedHandler();
+        handler.Release(new byte[40]);
+        using var pipeline = new ImagePipeline(new HttpClient(handler), maxBytes: 100);
+
+        // Act
+        await pipeline.GetAsync("https://img/a");
+        await pipeline.GetAsync("https://img/b");
+        pipeline.TryGetCached("https://img/a");
+        await pipeline.GetAsync("https://img/c");
+
+        // Assert
+        pipeline.CurrentBytes.Should().Be(80);
+        pipeline.TryGetCached("https://img/a").Should().NotBeNull();
+        pipeline.TryGetCached("https://img/b").Should().BeNull("b was least recently used");
+    }
+
+    [Fact]
+    public async Task GetAsync_WhenOneCallerCancels_ShouldStillCompleteForOthers()
+    {
+        // Arrange
+        var handler = new GatedHandler();
+        using var pipeline = new ImagePipeline(new HttpClient(handler));
+        using var cts = new CancellationTokenSource();
+
+        // Act
+        var cancelled = pipeline.GetAsync("https://img/a", cts.Token);
+        var waiting = pipeline.GetAsync("https://img/a");
+        cts.Cancel();
+        handler.Release(new byte[5]);
+
+        // Assert
+        await cancelled.Invoking(t => t).Should().ThrowAsync<OperationCanceledException>();
+        (await waiting).Should().HaveCount(5);
+        pipeline.TryGetCached("https://img/a").Should().NotBeNull();
+    }
+
+    [Fact]
+    public async Task GetAsync_WhenDownloadFails_ShouldReturnNullAndNotCache()
+    {
+        // Arrange
+        var handler = new GatedHandler { StatusCode = HttpStatusCode.NotFound };
+        handler.Release([]);
+        using var pipeline = new ImagePipeline(new HttpClient(handler));
+
+        // Act
+        var result = await pipeline.GetAsync("https://img/missing");
+
+        // Assert
+        result.Should().BeNull();
+        pipeline.CurrentCount.Should().Be(0);
+    }
+
+    [Fact]
+    public async Task Dispose_WithDownloadInFlight_ShouldCancelIt()
+    {
+        // Arrange
+        var handler = new GatedHandler();
+        var pipeline = new ImagePipeline(new HttpClient(handler));
+        var waiting = pipeline.GetAsync("https://img/a");
+        while (handler.Requests == 0)
+            await Task.Delay(1);
+
+        // Act
+        pipeline.Dispose();
+
+        // Assert
+        (await waiting).Should().BeNull();
+        await pipeline.Invoking(p => p.GetAsync("https://img/b")).Should().ThrowAsync<ObjectDisposedException>();
+    }
+
+    private sealed class GatedHandler : HttpMessageHandler
+    {
+        private readonly TaskCompletionSource<byte[]> _body = new(TaskCreationOptions.RunContinuationsAsynchronously);
+        private int _requests;
+
+        public HttpStatusCode StatusCode { get; init; } = HttpStatusCode.OK;
+
+        public int Requests => Volatile.Read(ref _requests);
+
+        public void Release(byte[] body) => _body.TrySetResult(body);
+
+        protected override async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+        {
+            Interlocked.Increment(ref _requests);
+            var body = await _body.Task.WaitAsync(cancellationToken);
+            return new HttpResponseMessage(StatusCode) { Content = new ByteArrayContent(body.ToArray()) };
+        }
+    }
+}
@@ -0,0 +1,116 @@
+using FluentAssertions;
+using Moq;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+/// <summary>
+/// Tests for MetadataRequestLocale - validates how the metadata locale and market overrides
+/// combine with the preferred locale and the account defaults.
+///
+/// WHY: These values pick the language of album titles and editorial content for every
+/// metadata request. Bugs here will cause:
+/// - A configured regional locale silently ignored, or overriding a language picked at runtime
+/// - Malformed Accept-Language values rejected by spclient
+/// - Catalogue lookups in the wrong market
+/// </summary>
+public class MetadataRequestLocaleTests
+{
+    [Theory]
+    [InlineData(null, null, "de", "de")]
+    [InlineData("pt-BR", null, "en", "pt-BR")]
+    [InlineData("pt-BR", "pt", "en", "pt-BR")]
+    [InlineData("pt-BR", "fr", "en", "fr")]
+    [InlineData(null, "fr", "en", "fr")]
+    public void GetLocale_ShouldApplyOverridesInOrder(string? metadataLocale, string? preferred, string? account, string expected)
+    {
+        // Arrange
+        var session = CreateSession(metadataLocale, null, preferred, account);
+
+        // Act
+        var locale = MetadataRequestLocale.GetLocale(session.Object);
+
+        // Assert
+        locale.Should().Be(expected);
+    }
+
+    [Theory]
+    [InlineData(null, null, null, "en")]
+    [InlineData(null, null, "pt_BR", "pt")]
+    [InlineData("zh_hant_tw", null, "en", "zh-Hant-TW")]
+    [InlineData("zh-TW", "ja", "en", "ja")]
+    public void GetAcceptLanguage_ShouldSendRegionOnlyForConfiguredLocale(
+        string? metadataLocale, string? preferred, string? account, string expected)
+    {
+        // Arrange
+        var session = CreateSession(metadataLocale, null, preferred, account);
+
+        // Act
+        var acceptLanguage = MetadataRequestLocale.GetAcceptLanguage(session.Object);
+
+        // Assert
+        acceptLanguage.Should().Be(expected);
+    }
+
+    [Fact]
+    public async Task GetCountryAsync_ShouldPreferOverrideOverAccountCountry()
+    {
+        // Arrange
+        var overridden = CreateSession(null, "jp", null, null);
+        var account = CreateSession(null, null, null, null);
+
+        // Act
+        var overriddenCountry = await MetadataRequestLocale.GetCountryAsync(overridden.Object, CancellationToken.None);
+        var accountCountry = await MetadataRequestLocale.GetCountryAsync(account.Object, CancellationToken.None);
+
+        // Assert
+        overriddenCountry.Should().Be("JP");
+        accountCountry.Should().Be("SE");
+        MetadataRequestLocale.GetMarket(overridden.Object).Should().Be("JP");
+        MetadataRequestLocale.GetMarket(account.Object).Should().Be("from_token");
+    }
+
+    [Theory]
+    [InlineData("en", "en")]
+    [InlineData("PT_br", "pt-BR")]
+    [InlineData("es-419", "es-419")]
+    [InlineData("sr-latn", "sr-Latn")]
+    public void TryNormalizeLocale_WhenValid_ShouldNormalize(string value, string expected)
+    {
+        // Act
+        var ok = MetadataRequestLocale.TryNormalizeLocale(value, out var locale);
+
+        // Assert
+        ok.Should().BeTrue();
+        locale.Should().Be(expected);
+    }
+
+    [Theory]
+    [InlineData("")]
+    [InlineData("e")]
+    [InlineData("english")]
+    [InlineData("pt-Brazil")]
+    [InlineData("pt-BR-x")]
+    public void TryNormalizeLocale_WhenInvalid_ShouldReturnFalse(string value)
+    {
+        // Act & Assert
+        MetadataRequestLocale.TryNormalizeLocale(value, out _).Should().BeFalse();
+    }
+
+    private static Mock<ISession> CreateSession(string? metadataLocale, string? metadataCountry, string? preferred, string? account)
+    {
+        var session = new Mock<ISession>(MockBehavior.Strict);
+        session.SetupGet(x => x.Config).Returns(new SessionConfig
+        {
+            DeviceId = "test-device-id",
+            MetadataLocale = metadataLocale,
+            MetadataCountry = metadataCountry
+        });
+        session.Setup(x => x.GetPreferredLocale()).Returns(preferred);
+        session.Setup(x => x.GetUserData()).Returns(new UserData { Username = "user", PreferredLocale = account });
+        session.Setup(x => x.GetCountryCodeAsync(It.IsAny<CancellationToken>())).ReturnsAsync("SE");
+        return session;
+    }
+}
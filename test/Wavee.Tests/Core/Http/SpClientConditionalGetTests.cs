@@ -0,0 +1,102 @@
+using System.Net;
+using System.Net.Http.Headers;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Core.Http;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+/// <summary>
+/// Tests for SpClient conditional GETs - validates If-None-Match revalidation against ConditionalResponseCache.
+///
+/// WHY: Rootlists and metadata are re-requested constantly. Bugs here will cause:
+/// - Full bodies downloaded when the server would have answered 304
+/// - Callers receiving an empty 304 body instead of the cached one
+/// </summary>
+public class SpClientConditionalGetTests
+{
+    private const string TrackUrl = "https://spclient.wg.spotify.com/metadata/4/track/abc";
+
+    [Fact]
+    public async Task GetTrackMetadataAsync_On304_ShouldReturnCachedBody()
+    {
+        // Arrange
+        var requests = new List<HttpRequestMessage>();
+        var handler = CreateHandler(requests,
+            Ok([1, 2, 3], "\"v1\""),
+            new HttpResponseMessage(HttpStatusCode.NotModified));
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession(), httpClient, "spclient.wg.spotify.com:443",
+            responseCache: new ConditionalResponseCache());
+
+        // Act
+        var first = await client.GetTrackMetadataAsync("abc");
+        var second = await client.GetTrackMetadataAsync("abc");
+
+        // Assert
+        first.Should().Equal(1, 2, 3);
+        second.Should().Equal(1, 2, 3);
+        requests[0].Headers.IfNoneMatch.Should().BeEmpty();
+        requests[1].Headers.IfNoneMatch.Should().ContainSingle().Which.Tag.Should().Be("\"v1\"");
+    }
+
+    [Fact]
+    public async Task GetTrackMetadataAsync_WithoutCache_ShouldNotSendIfNoneMatch()
+    {
+        // Arrange
+        var requests = new List<HttpRequestMessage>();
+        var handler = CreateHandler(requests, Ok([1], "\"v1\""), Ok([2], "\"v2\""));
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession(), httpClient, "spclient.wg.spotify.com:443");
+
+        // Act
+        await client.GetTrackMetadataAsync("abc");
+        var second = await client.GetTrackMetadataAsync("abc");
+
+        // Assert
+        second.Should().Equal(2);
+        requests[1].Headers.IfNoneMatch.Should().BeEmpty();
+    }
+
+    [Fact]
+    public void ConditionalResponseCache_ShouldKeyByScopeAndSkipLargeBodies()
+    {
+        // Arrange
+        var cache = new ConditionalResponseCache(maxBodyBytes: 4);
+        var etag = new EntityTagHeaderValue("\"v1\"");
+
+        // Act
+        cache.Set("alice", TrackUrl, etag, [1, 2], null);
+        cache.Set("alice", TrackUrl + "/big", etag, [1, 2, 3, 4, 5], null);
+
+        // Assert
+        cache.Get("alice", TrackUrl).Should().NotBeNull();
+        cache.Get("bob", TrackUrl).Should().BeNull("another account never sees alice's body");
+        cache.Get("alice", TrackUrl + "/big").Should().BeNull();
+    }
+
+    private static HttpResponseMessage Ok(byte[] body, string etag)
+    {
+        var response = new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent(body) };
+        response.Headers.ETag = new EntityTagHeaderValue(etag);
+        return response;
+    }
+
+    private static Mock<HttpMessageHandler> CreateHandler(List<HttpRequestMessage> requests, params HttpResponseMessage[] responses)
+    {
+        var queue = new Queue<HttpResponseMessage>(responses);
+        var handler = new Mock<HttpMessageHandler>();
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .Callback<HttpRequestMessage, CancellationToken>((request, _) => requests.Add(request))
+            .ReturnsAsync(() => queue.Dequeue());
+        return handler;
+    }
+}
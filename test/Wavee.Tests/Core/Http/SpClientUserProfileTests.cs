@@ -0,0 +1,92 @@
+using System.Net;
+using System.Text;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Core.Http;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Http;
+
+public class SpClientUserProfileTests
+{
+    [Fact]
+    public async Task GetUserPublicPlaylistsAsync_ShouldRequestPageAndDeserialize()
+    {
+        HttpRequestMessage? capturedRequest = null;
+        var handler = CreateHandler("""
+            {"public_playlists":[{"uri":"spotify:playlist:abc","name":"Road trip","followers_count":12,"owner_uri":"spotify:user:alice"}],
+             "total_public_playlists_count":51}
+            """, request => capturedRequest = request);
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession(), httpClient, "spclient.wg.spotify.com:443", null);
+
+        var page = await client.GetUserPublicPlaylistsAsync("spotify:user:alice", offset: 50, limit: 50);
+
+        capturedRequest!.RequestUri!.ToString().Should().Be(
+            "https://spclient.wg.spotify.com/user-profile-view/v3/profile/alice/playlists?offset=50&limit=50&market=from_token");
+        page.TotalPublicPlaylistsCount.Should().Be(51);
+        page.PublicPlaylists.Should().ContainSingle();
+        page.PublicPlaylists![0].Name.Should().Be("Road trip");
+        page.PublicPlaylists[0].FollowersCount.Should().Be(12);
+    }
+
+    [Fact]
+    public async Task GetUserFollowersAsync_ShouldDeserializeProfiles()
+    {
+        HttpRequestMessage? capturedRequest = null;
+        var handler = CreateHandler("""
+            {"profiles":[{"uri":"spotify:user:bob","name":"Bob","followers_count":3,"is_following":true}]}
+            """, request => capturedRequest = request);
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession(), httpClient, "spclient.wg.spotify.com:443", null);
+
+        var followers = await client.GetUserFollowersAsync("alice");
+
+        capturedRequest!.RequestUri!.AbsolutePath.Should().Be("/user-profile-view/v3/profile/alice/followers");
+        followers.Profiles.Should().ContainSingle().Which.IsFollowing.Should().BeTrue();
+    }
+
+    [Fact]
+    public async Task GetUserFollowersAsync_ShouldThrowNotFound_ForUnknownUser()
+    {
+        var handler = MockHttpHelpers.CreateMockWithSequence(new HttpResponseMessage(HttpStatusCode.NotFound));
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession(), httpClient, "spclient.wg.spotify.com:443", null);
+
+        var act = () => client.GetUserFollowersAsync("nobody");
+
+        var exception = await Assert.ThrowsAsync<SpClientException>(act);
+        exception.Reason.Should().Be(SpClientFailureReason.NotFound);
+    }
+
+    [Fact]
+    public void SpotifyUserProfile_ShouldParseFollowerCounts()
+    {
+        var profile = SpotifyUserProfile.TryParseJson(Encoding.UTF8.GetBytes("""
+            {"uri":"spotify:user:alice","name":"Alice","followers_count":120,"following_count":8}
+            """));
+
+        profile!.FollowersCount.Should().Be(120);
+        profile.FollowingCount.Should().Be(8);
+    }
+
+    private static Mock<HttpMessageHandler> CreateHandler(string json, Action<HttpRequestMessage> capture)
+    {
+        var handler = new Mock<HttpMessageHandler>(MockBehavior.Strict);
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .Callback<HttpRequestMessage, CancellationToken>((request, _) => capture(request))
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent(json, Encoding.UTF8, "application/json")
+            });
+        handler.Protected().Setup("Dispose", ItExpr.IsAny<bool>());
+        return handler;
+    }
+}
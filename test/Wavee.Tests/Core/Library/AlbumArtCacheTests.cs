@@ -0,0 +1,69 @@
+using FluentAssertions;
+using Wavee.Core.Library;
+using Xunit;
+
+namespace Wavee.Tests.Core.Library;
+
+/// <summary>
+/// Tests for AlbumArtCache - validates content-addressed writes and that
+/// cancellation is honoured before anything touches disk.
+/// </summary>
+public class AlbumArtCacheTests : IDisposable
+{
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-art-" + Guid.NewGuid().ToString("N"));
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+
+    [Fact]
+    public async Task CacheArtAsync_ShouldWriteFileOnce()
+    {
+        // Arrange
+        var cache = new AlbumArtCache(_dir);
+        var data = new byte[] { 1, 2, 3, 4 };
+
+        // Act
+        var first = await cache.CacheArtAsync(data, "image/png");
+        var second = await cache.CacheArtAsync(data, "image/png");
+
+        // Assert
+        first.Should().NotBeNull();
+        second.Should().Be(first);
+        File.ReadAllBytes(first!).Should().Equal(data);
+    }
+
+    [Fact]
+    public async Task CacheArtAsync_WithCancelledToken_ShouldThrowAndNotWrite()
+    {
+        // Arrange
+        var cache = new AlbumArtCache(_dir);
+        using var cts = new CancellationTokenSource();
+        cts.Cancel();
+
+        // Act
+        var act = () => cache.CacheArtAsync(new byte[] { 5, 6, 7 }, "image/jpeg", cts.Token);
+
+        // Assert
+        await act.Should().ThrowAsync<OperationCanceledException>();
+        Directory.GetFiles(Path.Combine(_dir, "album-art")).Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task CleanupAsync_WithCancelledToken_ShouldThrow()
+    {
+        // Arrange
+        var cache = new AlbumArtCache(_dir);
+        await cache.CacheArtAsync(new byte[] { 8, 9 }, "image/png");
+        using var cts = new CancellationTokenSource();
+        cts.Cancel();
+
+        // Act
+        var act = () => cache.CleanupAsync(TimeSpan.Zero, cts.Token);
+
+        // Assert
+        await act.Should().ThrowAsync<OperationCanceledException>();
+    }
+}
@@ -0,0 +1,205 @@
+using System.Net;
+using System.Text;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Core.Http;
+using Wavee.Core.Library.Spotify;
+using Wavee.Core.Playlists;
+using Wavee.Core.Session;
+using Wavee.Core.Storage.Abstractions;
+using Wavee.Core.Storage.Outbox;
+using Wavee.Protocol.Playlist;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Library;
+
+/// <summary>
+/// Tests for SpotifyLibraryService follow operations - validates the rootlist deltas
+/// sent for playlist follows and the saved-state events emitted for playlists and users.
+///
+/// WHY: Follow buttons flip on the Local event and only roll back on Reverted.
+/// Bugs here will cause:
+/// - Unfollow removing the wrong rootlist entry (a different playlist disappears)
+/// - Follow buttons stuck in the wrong state after a failed write
+/// - Duplicate rootlist entries when following an already-followed playlist
+/// </summary>
+public class SpotifyLibraryFollowTests
+{
+    private static readonly RootlistSnapshot Rootlist = new()
+    {
+        Revision = [1, 2, 3],
+        Items =
+        [
+            new RootlistFolderStart("f1", "Mixes"),
+            new RootlistPlaylist("spotify:playlist:inside"),
+            new RootlistFolderEnd("f1"),
+            new RootlistPlaylist("spotify:playlist:followed")
+        ]
+    };
+
+    [Fact]
+    public async Task FollowPlaylistAsync_ShouldPrependToRootlistAndEmitLocal()
+    {
+        // Arrange
+        ListChanges? sent = null;
+        var (service, cache) = CreateService(HttpStatusCode.OK, changes => sent = changes);
+        var events = new List<SavedStateChangedEvent>();
+        using var subscription = service.SavedStateChanged.Subscribe(events.Add);
+
+        // Act
+        var ok = await service.FollowPlaylistAsync("spotify:playlist:new");
+
+        // Assert
+        ok.Should().BeTrue();
+        sent!.BaseRevision.ToByteArray().Should().Equal(1, 2, 3);
+        var op = sent.Deltas.Should().ContainSingle().Subject.Ops.Should().ContainSingle().Subject;
+        op.Kind.Should().Be(Op.Types.Kind.Add);
+        op.Add.FromIndex.Should().Be(0);
+        op.Add.Items.Should().ContainSingle().Which.Uri.Should().Be("spotify:playlist:new");
+        sent.Deltas[0].Info.User.Should().Be("alice");
+        events.Should().ContainSingle().Which.Should().Be(new SavedStateChangedEvent(
+            "spotify:playlist:new", SpotifyLibraryItemType.Playlist, true, SavedStateChangeSource.Local));
+        cache.Verify(x => x.InvalidateAsync(PlaylistCacheUris.Rootlist, It.IsAny<CancellationToken>()), Times.Once);
+    }
+
+    [Fact]
+    public async Task UnfollowPlaylistAsync_ShouldRemoveAtRootlistIndex()
+    {
+        // Arrange
+        ListChanges? sent = null;
+        var (service, _) = CreateService(HttpStatusCode.OK, changes => sent = changes);
+
+        // Act
+        var ok = await service.UnfollowPlaylistAsync("spotify:playlist:followed");
+
+        // Assert
+        ok.Should().BeTrue();
+        var op = sent!.Deltas[0].Ops.Should().ContainSingle().Subject;
+        op.Kind.Should().Be(Op.Types.Kind.Rem);
+        op.Rem.FromIndex.Should().Be(3);
+        op.Rem.Length.Should().Be(1);
+    }
+
+    [Fact]
+    public async Task FollowPlaylistAsync_WhenAlreadyFollowed_ShouldNotWrite()
+    {
+        // Arrange
+        ListChanges? sent = null;
+        var (service, _) = CreateService(HttpStatusCode.OK, changes => sent = changes);
+
+        // Act
+        var ok = await service.FollowPlaylistAsync("spotify:playlist:inside");
+
+        // Assert
+        ok.Should().BeTrue();
+        sent.Should().BeNull();
+        (await service.IsPlaylistFollowedAsync("spotify:playlist:inside")).Should().BeTrue();
+        (await service.IsPlaylistFollowedAsync("spotify:playlist:other")).Should().BeFalse();
+    }
+
+    [Fact]
+    public async Task FollowPlaylistAsync_WhenRootlistWriteFails_ShouldEmitReverted()
+    {
+        // Arrange
+        var (service, _) = CreateService(HttpStatusCode.Forbidden, _ => { });
+        var events = new List<SavedStateChangedEvent>();
+        using var subscription = service.SavedStateChanged.Subscribe(events.Add);
+
+        // Act
+        var ok = await service.FollowPlaylistAsync("spotify:playlist:new");
+
+        // Assert
+        ok.Should().BeFalse();
+        events.Select(e => (e.IsSaved, e.Source)).Should().Equal(
+            (true, SavedStateChangeSource.Local),
+            (false, SavedStateChangeSource.Reverted));
+    }
+
+    [Fact]
+    public async Task FollowUserAsync_WhenRequestFails_ShouldEmitReverted()
+    {
+        // Arrange
+        var (service, _) = CreateService(HttpStatusCode.BadRequest, _ => { });
+        var events = new List<SavedStateChangedEvent>();
+        using var subscription = service.SavedStateChanged.Subscribe(events.Add);
+
+        // Act
+        var ok = await service.FollowUserAsync("spotify:user:bob");
+
+        // Assert
+        ok.Should().BeFalse();
+        events.Should().Equal(
+            new SavedStateChangedEvent("spotify:user:bob", SpotifyLibraryItemType.User, true, SavedStateChangeSource.Local),
+            new SavedStateChangedEvent("spotify:user:bob", SpotifyLibraryItemType.User, false, SavedStateChangeSource.Reverted));
+    }
+
+    [Fact]
+    public async Task IsUserFollowedAsync_ShouldReadContainsResponse()
+    {
+        // Arrange
+        HttpRequestMessage? captured = null;
+        var handler = new Mock<HttpMessageHandler>(MockBehavior.Strict);
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .Callback<HttpRequestMessage, CancellationToken>((request, _) => captured = request)
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent("[true]", Encoding.UTF8, "application/json")
+            });
+        var service = CreateService(handler.Object, Mock.Of<IPlaylistCacheService>());
+
+        // Act
+        var following = await service.IsUserFollowedAsync("spotify:user:bob");
+
+        // Assert
+        following.Should().BeTrue();
+        captured!.RequestUri!.ToString().Should().Be("https://api.spotify.com/v1/me/following/contains?type=user&ids=bob");
+    }
+
+    private static (SpotifyLibraryService Service, Mock<IPlaylistCacheService> Cache) CreateService(
+        HttpStatusCode status,
+        Action<ListChanges> capture)
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .Returns(async (HttpRequestMessage request, CancellationToken ct) =>
+            {
+                if (request.RequestUri!.AbsolutePath.EndsWith("/rootlist/changes", StringComparison.Ordinal))
+                    capture(ListChanges.Parser.ParseFrom(await request.Content!.ReadAsByteArrayAsync(ct)));
+                return new HttpResponseMessage(status) { Content = new ByteArrayContent([]) };
+            });
+
+        var cache = new Mock<IPlaylistCacheService>();
+        cache
+            .Setup(x => x.GetRootlistAsync(It.IsAny<bool>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(Rootlist);
+        cache
+            .Setup(x => x.InvalidateAsync(It.IsAny<string>(), It.IsAny<CancellationToken>()))
+            .Returns(Task.CompletedTask);
+
+        return (CreateService(handler.Object, cache.Object), cache);
+    }
+
+    private static SpotifyLibraryService CreateService(HttpMessageHandler handler, IPlaylistCacheService cache)
+    {
+        var session = new MockSession(userData: new UserData { Username = "alice" });
+        var spClient = new SpClient(session, new HttpClient(handler), "spclient.wg.spotify.com:443", null);
+        return new SpotifyLibraryService(
+            Mock.Of<IMetadataDatabase>(),
+            spClient,
+            session,
+            Mock.Of<IOutboxProcessor>(),
+            playlistCache: cache);
+    }
+}
@@ -0,0 +1,198 @@
+using System.Buffers.Binary;
+using System.Collections.Concurrent;
+using FluentAssertions;
+using Google.Protobuf;
+using Moq;
+using Wavee.Core.Mercury;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Mercury;
+
+/// <summary>
+/// Tests for MercuryManager subscriptions - validates SUB bookkeeping, event routing
+/// and resubscription after an AP reconnect.
+///
+/// WHY: The server forgets SUBs when the AP connection is replaced. Without
+/// resubscription, product-state and remote-state listeners silently go stale.
+/// </summary>
+public class MercuryManagerTests
+{
+    private const string ProductStateUri = "hm://remote/user/alice/";
+
+    private readonly ConcurrentQueue<(PacketType Type, byte[] Packet)> _sent = new();
+    private readonly Mock<ISession> _session = new();
+    private MercuryManager _mercury;
+
+    public MercuryManagerTests()
+    {
+        _session.Setup(s => s.SendAsync(It.IsAny<PacketType>(), It.IsAny<ReadOnlyMemory<byte>>(), It.IsAny<CancellationToken>()))
+            .Callback<PacketType, ReadOnlyMemory<byte>, CancellationToken>((type, payload, _) => _sent.Enqueue((type, payload.ToArray())))
+            .Returns(ValueTask.CompletedTask);
+        _mercury = new MercuryManager(_session.Object);
+    }
+
+    [Fact]
+    public async Task SubscribeAsync_ShouldSendSubAndTrackUri()
+    {
+        // Act
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        await subscribe;
+
+        // Assert
+        _mercury.Subscriptions.Should().Equal(ProductStateUri);
+    }
+
+    [Fact]
+    public async Task MercuryEvent_ShouldReachSubscriberByPrefix()
+    {
+        // Arrange
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        var events = new List<MercuryEvent>();
+        using var subscription = (await subscribe).Subscribe(events.Add);
+
+        // Act
+        Dispatch(PacketType.MercuryEvent, seq: 9000, ProductStateUri + "device", [1, 2, 3]);
+
+        // Assert
+        events.Should().ContainSingle();
+        events[0].Uri.Should().Be(ProductStateUri + "device");
+        events[0].Payload.Single().Should().Equal(1, 2, 3);
+        events[0].IsReplay.Should().BeFalse();
+    }
+
+    [Fact]
+    public async Task ResubscribeAsync_AfterReset_ShouldResendSubAndReplayRetainedState()
+    {
+        // Arrange
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        var stream = await subscribe;
+        Dispatch(PacketType.MercuryEvent, seq: 9000, ProductStateUri, [7]);
+
+        var events = new List<MercuryEvent>();
+        using var subscription = stream.Subscribe(events.Add);
+        _sent.Clear();
+
+        // Act - simulate AP reconnect
+        _mercury.Reset();
+        var resubscribe = _mercury.ResubscribeAsync();
+        RespondToNextRequest(PacketType.MercurySub);
+        var failed = await resubscribe;
+
+        // Assert
+        failed.Should().Be(0);
+        events.Should().HaveCount(2, "the late observer gets the retained event, then the replay");
+        events[1].IsReplay.Should().BeTrue();
+        events[1].Payload.Single().Should().Equal(7);
+    }
+
+    [Fact]
+    public async Task ResubscribeAsync_WhenSubReturnsState_ShouldPublishFreshEvent()
+    {
+        // Arrange
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        var events = new List<MercuryEvent>();
+        using var subscription = (await subscribe).Subscribe(events.Add);
+
+        // Act
+        var resubscribe = _mercury.ResubscribeAsync();
+        RespondToNextRequest(PacketType.MercurySub, [4, 2]);
+        await resubscribe;
+
+        // Assert
+        events.Should().ContainSingle();
+        events[0].IsReplay.Should().BeFalse();
+        events[0].Payload.Single().Should().Equal(4, 2);
+    }
+
+    [Fact]
+    public async Task UnsubscribeAsync_ShouldDropUriAndCompleteObservers()
+    {
+        // Arrange
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        var completed = false;
+        using var subscription = (await subscribe).Subscribe(_ => { }, () => completed = true);
+
+        // Act
+        var unsubscribe = _mercury.UnsubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercuryUnsub);
+        await unsubscribe;
+
+        // Assert
+        _mercury.Subscriptions.Should().BeEmpty();
+        completed.Should().BeTrue();
+    }
+
+    [Fact]
+    public async Task Response_OverMessageLimit_ShouldFailRequest()
+    {
+        // WHY: Parts are buffered until the final packet; the limit bounds that buffer
+
+        // Arrange
+        _mercury = new MercuryManager(_session.Object, limits: new ProtocolLimitsConfig { MaxMessageBytes = 256 });
+
+        // Act
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub, new byte[512]);
+        var act = () => subscribe;
+
+        // Assert
+        await act.Should().ThrowAsync<InvalidDataException>().WithMessage("*limits.maxMessageBytes*");
+        _mercury.Subscriptions.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task Event_OverPartLimit_ShouldNotBeDelivered()
+    {
+        // Arrange
+        _mercury = new MercuryManager(_session.Object, limits: new ProtocolLimitsConfig { MaxParts = 1 });
+        var subscribe = _mercury.SubscribeAsync(ProductStateUri);
+        RespondToNextRequest(PacketType.MercurySub);
+        var events = new List<MercuryEvent>();
+        using var subscription = (await subscribe).Subscribe(events.Add);
+
+        // Act - header plus one payload part is two parts
+        Dispatch(PacketType.MercuryEvent, seq: 9000, ProductStateUri, [1, 2, 3]);
+
+        // Assert
+        events.Should().BeEmpty();
+    }
+
+    private void RespondToNextRequest(PacketType expectedType, byte[]? payload = null)
+    {
+        SpinWait.SpinUntil(() => !_sent.IsEmpty, TimeSpan.FromSeconds(2)).Should().BeTrue("a request should have been sent");
+        _sent.TryDequeue(out var request).Should().BeTrue();
+        request.Type.Should().Be(expectedType);
+
+        var seq = BinaryPrimitives.ReadUInt64BigEndian(request.Packet.AsSpan(2, 8));
+        Dispatch(expectedType, seq, ProductStateUri, payload);
+    }
+
+    private void Dispatch(PacketType command, ulong seq, string uri, byte[]? payload)
+    {
+        var header = new Wavee.Protocol.Header { Uri = uri, StatusCode = 200 }.ToByteArray();
+
+        using var ms = new MemoryStream();
+        var buffer = new byte[8];
+        BinaryPrimitives.WriteUInt16BigEndian(buffer, 8);
+        ms.Write(buffer, 0, 2);
+        BinaryPrimitives.WriteUInt64BigEndian(buffer, seq);
+        ms.Write(buffer, 0, 8);
+        ms.WriteByte(0x01);
+        BinaryPrimitives.WriteUInt16BigEndian(buffer, (ushort)(payload is null ? 1 : 2));
+        ms.Write(buffer, 0, 2);
+        foreach (var part in payload is null ? new[] { header } : new[] { header, payload })
+        {
+            BinaryPrimitives.WriteUInt16BigEndian(buffer, (ushort)part.Length);
+            ms.Write(buffer, 0, 2);
+            ms.Write(part);
+        }
+
+        _mercury.DispatchPacket((byte)command, ms.ToArray());
+    }
+}
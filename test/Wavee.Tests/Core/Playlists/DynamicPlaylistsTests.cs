@@ -0,0 +1,52 @@
+using FluentAssertions;
+using Wavee.Core.Playlists;
+using Xunit;
+
+namespace Wavee.Tests.Core.Playlists;
+
+/// <summary>
+/// Tests for DynamicPlaylists - validates which playlists count as server-regenerated.
+///
+/// WHY: A regenerated playlist reloads the playing context. Bugs here will cause:
+/// - A user's own playlist edit restarting the queue mid-session
+/// - daylist / Discover Weekly queues playing the previous generation until the next play
+/// </summary>
+public class DynamicPlaylistsTests
+{
+    [Theory]
+    [InlineData("daylist", "spotify", DynamicPlaylistKind.Daylist)]
+    [InlineData("discover-weekly", "spotify", DynamicPlaylistKind.PersonalizedMix)]
+    [InlineData("Daily-Mix", "spotify", DynamicPlaylistKind.PersonalizedMix)]
+    [InlineData("blend-playlist", "alice", DynamicPlaylistKind.Blend)]
+    [InlineData("chart", "spotifycharts", DynamicPlaylistKind.Chart)]
+    [InlineData(null, "spotify", DynamicPlaylistKind.Editorial)]
+    [InlineData(null, "alice", DynamicPlaylistKind.None)]
+    [InlineData("custom", "alice", DynamicPlaylistKind.None)]
+    public void Classify_ShouldUseFormatThenOwner(string? format, string owner, DynamicPlaylistKind expected)
+    {
+        // Arrange
+        var attributes = format is null
+            ? new Dictionary<string, string>()
+            : new Dictionary<string, string> { ["format"] = format };
+
+        // Act
+        var kind = DynamicPlaylists.Classify(attributes, owner);
+
+        // Assert
+        kind.Should().Be(expected);
+    }
+
+    [Fact]
+    public void GetLastUpdated_ShouldParseAsUtc()
+    {
+        // Arrange
+        var attributes = new Dictionary<string, string> { ["last_updated"] = "2026-10-12T06:00:00" };
+
+        // Act
+        var updated = DynamicPlaylists.GetLastUpdated(attributes);
+
+        // Assert
+        updated.Should().Be(new DateTimeOffset(2026, 10, 12, 6, 0, 0, TimeSpan.Zero));
+        DynamicPlaylists.GetLastUpdated(new Dictionary<string, string> { ["last_updated"] = "soon" }).Should().BeNull();
+    }
+}
@@ -0,0 +1,65 @@
+using FluentAssertions;
+using Wavee.Core.Playlists;
+using Wavee.Protocol.Playlist;
+using Xunit;
+
+namespace Wavee.Tests.Core.Playlists;
+
+/// <summary>
+/// Tests for PlaylistEditor duplicate detection and delta construction.
+/// </summary>
+public class PlaylistEditorTests
+{
+    [Fact]
+    public void PartitionDuplicates_ShouldSkipExistingAndRepeatedUris()
+    {
+        // ============================================================
+        // WHY: "Add to playlist" must not silently double-add a track that
+        //      is already there, nor add the same URI twice from one call.
+        //      Order of the remaining URIs is preserved.
+        // ============================================================
+
+        // Arrange
+        var existing = new[]
+        {
+            new CachedPlaylistItem { Uri = "spotify:track:A" },
+            new CachedPlaylistItem { Uri = "spotify:track:B" }
+        };
+        var candidates = new[] { "spotify:track:C", "spotify:track:A", "spotify:track:D", "spotify:track:C" };
+
+        // Act
+        var (toAdd, duplicates) = PlaylistEditor.PartitionDuplicates(existing, candidates);
+
+        // Assert
+        toAdd.Should().Equal("spotify:track:C", "spotify:track:D");
+        duplicates.Should().Equal("spotify:track:A", "spotify:track:C");
+    }
+
+    [Fact]
+    public void BuildAppendChanges_ShouldEmitSingleAddLastOpAgainstBaseRevision()
+    {
+        // ============================================================
+        // WHY: The /changes endpoint rejects deltas whose base revision is
+        //      stale (409) — the revision we read from the cache must be
+        //      the one we send, and items must be appended at the end.
+        // ============================================================
+
+        // Arrange
+        var revision = new byte[] { 0, 0, 0, 5, 0xAB };
+
+        // Act
+        var changes = PlaylistEditor.BuildAppendChanges(
+            revision, ["spotify:track:X", "spotify:track:Y"], "user", nowMs: 1_700_000_000_000);
+
+        // Assert
+        changes.BaseRevision.ToByteArray().Should().Equal(revision);
+        changes.Deltas.Should().ContainSingle();
+        var op = changes.Deltas[0].Ops.Should().ContainSingle().Subject;
+        op.Kind.Should().Be(Op.Types.Kind.Add);
+        op.Add.AddLast.Should().BeTrue();
+        op.Add.Items.Select(i => i.Uri).Should().Equal("spotify:track:X", "spotify:track:Y");
+        op.Add.Items.Should().OnlyContain(i => i.Attributes.Timestamp == 1_700_000_000_000);
+        changes.Deltas[0].Info.User.Should().Be("user");
+        changes.Deltas[0].Info.Timestamp.Should().Be(1_700_000_000_000);
+    }
+}
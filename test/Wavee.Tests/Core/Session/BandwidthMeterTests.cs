@@ -0,0 +1,67 @@
+using FluentAssertions;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for BandwidthMeter - validates per-category counting, totals and AP packet attribution.
+///
+/// WHY: Users on metered connections read these numbers to decide what to turn off. Bugs here will cause:
+/// - Traffic landing in the wrong category (e.g. Mercury metadata shown as control)
+/// - Totals that don't add up to the per-category figures
+/// - Lost counts when several subsystems record at once
+/// </summary>
+public class BandwidthMeterTests
+{
+    [Fact]
+    public void GetStatistics_ShouldReportEveryCategoryAndTotal()
+    {
+        // Arrange
+        var meter = new BandwidthMeter();
+
+        // Act
+        meter.Record(TrafficCategory.Audio, 10, 1000);
+        meter.RecordReceived(TrafficCategory.Images, 500);
+        meter.RecordSent(TrafficCategory.Control, 20);
+        meter.RecordReceived(TrafficCategory.Audio, 24);
+
+        // Assert
+        var stats = meter.GetStatistics();
+        stats.Categories.Keys.Should().BeEquivalentTo(Enum.GetValues<TrafficCategory>());
+        stats.Categories[TrafficCategory.Audio].Should().Be(new TrafficCounters(10, 1024));
+        stats.Categories[TrafficCategory.Images].Should().Be(new TrafficCounters(0, 500));
+        stats.Categories[TrafficCategory.Metadata].Should().Be(default(TrafficCounters));
+        stats.Total.Should().Be(new TrafficCounters(30, 1524));
+    }
+
+    [Fact]
+    public async Task Record_Concurrently_ShouldNotLoseCounts()
+    {
+        // Arrange
+        var meter = new BandwidthMeter();
+
+        // Act
+        await Task.WhenAll(Enumerable.Range(0, 8).Select(_ => Task.Run(() =>
+        {
+            for (var i = 0; i < 10_000; i++)
+                meter.RecordReceived(TrafficCategory.Metadata, 1);
+        })));
+
+        // Assert
+        meter.GetStatistics().Categories[TrafficCategory.Metadata].BytesReceived.Should().Be(80_000);
+    }
+
+    [Theory]
+    [InlineData(PacketType.MercuryReq, TrafficCategory.Metadata)]
+    [InlineData(PacketType.MercuryEvent, TrafficCategory.Metadata)]
+    [InlineData(PacketType.AesKey, TrafficCategory.Audio)]
+    [InlineData(PacketType.StreamChunkRes, TrafficCategory.Audio)]
+    [InlineData(PacketType.Ping, TrafficCategory.Control)]
+    [InlineData(PacketType.CountryCode, TrafficCategory.Control)]
+    public void ClassifyPacket_ShouldMapToCategory(PacketType packetType, TrafficCategory expected)
+    {
+        // Act & Assert
+        Wavee.Core.Session.Session.ClassifyPacket(packetType).Should().Be(expected);
+    }
+}
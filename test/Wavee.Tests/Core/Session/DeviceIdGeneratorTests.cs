@@ -0,0 +1,63 @@
+using FluentAssertions;
+using Wavee.Core.Session;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for DeviceIdGenerator.
+/// Validates id shape and deterministic generation with an injected RNG.
+/// </summary>
+public class DeviceIdGeneratorTests
+{
+    [Fact]
+    public void Generate_ShouldReturn32LowercaseHexChars()
+    {
+        // ============================================================
+        // WHY: Persisted ids from older builds are Guid "N" format;
+        //      new ids must have the same shape.
+        // ============================================================
+
+        // Act
+        var id = DeviceIdGenerator.Generate();
+
+        // Assert
+        id.Should().MatchRegex("^[0-9a-f]{32}$");
+    }
+
+    [Fact]
+    public void Generate_WithSeededRng_ShouldBeDeterministic()
+    {
+        // ============================================================
+        // WHY: Discovery / announce tests pin the device id.
+        // ============================================================
+
+        // Act
+        var first = DeviceIdGenerator.Generate(new SeededRandomNumberGenerator(7));
+        var second = DeviceIdGenerator.Generate(new SeededRandomNumberGenerator(7));
+
+        // Assert
+        first.Should().Be(second);
+    }
+
+    [Fact]
+    public void Derive_ShouldBeStablePerZoneAndDistinctAcrossZones()
+    {
+        // ============================================================
+        // WHY: Remotes remember devices by id; a multi-zone host must
+        //      give each zone the same id on every restart.
+        // ============================================================
+
+        // Act
+        var kitchen = DeviceIdGenerator.Derive("base", "Kitchen");
+        var kitchenAgain = DeviceIdGenerator.Derive("base", " kitchen ");
+        var patio = DeviceIdGenerator.Derive("base", "Patio");
+
+        // Assert
+        kitchen.Should().MatchRegex("^[0-9a-f]{32}$");
+        kitchenAgain.Should().Be(kitchen);
+        patio.Should().NotBe(kitchen);
+        DeviceIdGenerator.Derive("other", "Kitchen").Should().NotBe(kitchen);
+    }
+}
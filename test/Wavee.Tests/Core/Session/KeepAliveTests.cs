@@ -0,0 +1,98 @@
+using FluentAssertions;
+using Wavee.Core.Session;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for KeepAlive - validates the AP Ping/Pong/PongAck state machine on a virtual clock.
+///
+/// WHY: KeepAlive decides when the AP connection is dead. Bugs here will cause:
+/// - Healthy sessions dropped after hours of uptime (timeouts drifting)
+/// - Dead sessions kept open (missed-ping detection never firing)
+/// </summary>
+public class KeepAliveTests
+{
+    [Fact]
+    public void Evaluate_OverSixHoursOfHealthyCycles_ShouldNeverDisconnect()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var keepAlive = new KeepAlive(timeProvider: time);
+        var start = time.GetUtcNow();
+
+        // Act - server pings every ~120s (60s pong delay + 60s interval)
+        var actions = new List<KeepAliveAction>();
+        while (time.GetUtcNow() - start < TimeSpan.FromHours(6))
+        {
+            time.Advance(TimeSpan.FromSeconds(10));
+            actions.Add(keepAlive.Evaluate());
+            keepAlive.OnPingReceived();
+
+            time.Advance(TimeSpan.FromSeconds(60));
+            actions.Add(keepAlive.Evaluate());
+
+            time.Advance(TimeSpan.FromSeconds(1));
+            keepAlive.OnPongAckReceived();
+
+            time.Advance(TimeSpan.FromSeconds(49));
+            actions.Add(keepAlive.Evaluate());
+        }
+
+        // Assert
+        actions.Should().NotContain(KeepAliveAction.Disconnect);
+        actions.Count(a => a == KeepAliveAction.SendPong).Should().Be(180);
+    }
+
+    [Fact]
+    public void Evaluate_WhenFirstPingIsLate_ShouldDisconnectAfter20Seconds()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var keepAlive = new KeepAlive(timeProvider: time);
+
+        // Act & Assert
+        time.Advance(TimeSpan.FromSeconds(20));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.Wait);
+
+        time.Advance(TimeSpan.FromMilliseconds(100));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.Disconnect);
+    }
+
+    [Fact]
+    public void Evaluate_WhenPingStopsAfterFirstCycle_ShouldDisconnectAfter80Seconds()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var keepAlive = new KeepAlive(timeProvider: time);
+        keepAlive.OnPingReceived();
+        time.Advance(TimeSpan.FromSeconds(60));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.SendPong);
+        keepAlive.OnPongAckReceived();
+
+        // Act & Assert
+        time.Advance(TimeSpan.FromSeconds(80));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.Wait);
+
+        time.Advance(TimeSpan.FromSeconds(1));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.Disconnect);
+    }
+
+    [Fact]
+    public void Evaluate_WhenPongAckNeverArrives_ShouldDisconnectAfter20Seconds()
+    {
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var keepAlive = new KeepAlive(timeProvider: time);
+        keepAlive.OnPingReceived();
+        time.Advance(TimeSpan.FromSeconds(60));
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.SendPong);
+
+        // Act
+        time.Advance(TimeSpan.FromSeconds(21));
+
+        // Assert
+        keepAlive.Evaluate().Should().Be(KeepAliveAction.Disconnect);
+    }
+}
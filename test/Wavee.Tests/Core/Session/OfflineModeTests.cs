@@ -0,0 +1,95 @@
+using System.Net;
+using FluentAssertions;
+using Moq;
+using Moq.Protected;
+using Wavee.Core.Http;
+using Wavee.Core.Mercury;
+using Wavee.Core.Session;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for offline mode - validates that HTTP clients stop touching the network and fail with a typed error.
+///
+/// WHY: Offline mode is a promise to the user that nothing goes out. Bugs here will cause:
+/// - Requests (and retries) sent while the user asked for no network
+/// - Callers unable to tell "offline" apart from a genuine server failure
+/// - Covers already in memory disappearing from the UI while offline
+/// </summary>
+public class OfflineModeTests
+{
+    [Fact]
+    public async Task SpClient_WhenOffline_ShouldThrowWithoutSending()
+    {
+        // Arrange
+        var handler = CreateHandler();
+        using var httpClient = new HttpClient(handler.Object);
+        var client = new SpClient(new MockSession { IsOfflineMode = true }, httpClient, "spclient.wg.spotify.com:443");
+
+        // Act
+        var act = () => client.GetTrackMetadataAsync("abc");
+
+        // Assert
+        await act.Should().ThrowAsync<OfflineModeException>();
+        handler.Protected().Verify(
+            "SendAsync",
+            Times.Never(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
+
+    [Fact]
+    public async Task MercuryManager_WhenOffline_ShouldThrowTypedError()
+    {
+        // Arrange
+        var mercury = new MercuryManager(new MockSession { IsOfflineMode = true });
+
+        // Act
+        var get = () => mercury.GetAsync("hm://identity/v1/user/test");
+        var subscribe = () => mercury.SubscribeAsync("hm://pusher/v1/connections/");
+
+        // Assert
+        await get.Should().ThrowAsync<OfflineModeException>();
+        await subscribe.Should().ThrowAsync<OfflineModeException>();
+        mercury.Subscriptions.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task ImagePipeline_WhenOffline_ShouldServeCachedAndSkipDownloads()
+    {
+        // Arrange
+        var offline = false;
+        var handler = CreateHandler();
+        using var pipeline = new ImagePipeline(new HttpClient(handler.Object), isOffline: () => offline);
+        await pipeline.GetAsync("spotify:image:cached");
+
+        // Act
+        offline = true;
+        var cached = await pipeline.GetAsync("spotify:image:cached");
+        var missing = await pipeline.GetAsync("spotify:image:missing");
+
+        // Assert
+        cached.Should().Equal(1, 2, 3);
+        missing.Should().BeNull();
+        handler.Protected().Verify(
+            "SendAsync",
+            Times.Once(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
+
+    private static Mock<HttpMessageHandler> CreateHandler()
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        handler
+            .Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage(HttpStatusCode.OK) { Content = new ByteArrayContent([1, 2, 3]) });
+        return handler;
+    }
+}
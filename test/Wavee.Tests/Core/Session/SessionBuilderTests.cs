@@ -0,0 +1,138 @@
+using System.Net;
+using FluentAssertions;
+using Wavee.Core.Configuration;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for SessionBuilder defaults and validation.
+/// </summary>
+public class SessionBuilderTests
+{
+    [Fact]
+    public void BuildConfig_OnlyDeviceId_ShouldKeepSessionConfigDefaults()
+    {
+        // Act
+        var config = new SessionBuilder().WithDeviceId("device").BuildConfig();
+
+        // Assert
+        config.Should().Be(new SessionConfig { DeviceId = "device" });
+    }
+
+    [Fact]
+    public void BuildConfig_ShouldApplyOptions()
+    {
+        // Act
+        var config = new SessionBuilder()
+            .WithDeviceId("device")
+            .WithDeviceName("Kitchen")
+            .WithDeviceType(DeviceType.Speaker)
+            .WithInitialVolumePercent(100)
+            .ConfigureNetwork(n => n with { ApConnectTimeout = TimeSpan.FromSeconds(20) })
+            .BuildConfig();
+
+        // Assert
+        config.DeviceName.Should().Be("Kitchen");
+        config.DeviceType.Should().Be(DeviceType.Speaker);
+        config.InitialVolume.Should().Be(65535);
+        config.Network.ApConnectTimeout.Should().Be(TimeSpan.FromSeconds(20));
+    }
+
+    [Fact]
+    public void BuildConfig_MissingDeviceId_ShouldThrowWithKey()
+    {
+        // Act
+        var act = () => new SessionBuilder().BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("session.deviceId");
+    }
+
+    [Fact]
+    public void BuildConfig_LocalAddressConflictingWithFamily_ShouldThrowWithKey()
+    {
+        // Arrange
+        var builder = new SessionBuilder()
+            .WithDeviceId("device")
+            .WithNetwork(new NetworkConfig
+            {
+                LocalAddress = IPAddress.Parse("10.0.0.5"),
+                AddressFamily = AddressFamilyPreference.IPv6Only
+            });
+
+        // Act
+        var act = () => builder.BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("network.localAddress");
+    }
+
+    [Fact]
+    public void BuildConfig_TlsPinningWithoutPins_ShouldThrowWithKey()
+    {
+        // Arrange
+        var builder = new SessionBuilder()
+            .WithDeviceId("device")
+            .ConfigureNetwork(n => n with { TlsPinning = TlsPinningMode.Enforce });
+
+        // Act
+        var act = () => builder.BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("network.tlsPins");
+    }
+
+    [Theory]
+    [InlineData("portuguese", null, "session.metadataLocale")]
+    [InlineData("pt-BR", "BRA", "session.metadataCountry")]
+    public void BuildConfig_InvalidMetadataLocale_ShouldThrowWithKey(string locale, string? country, string key)
+    {
+        // Arrange
+        var builder = new SessionBuilder()
+            .WithDeviceId("device")
+            .WithMetadataLocale(locale, country);
+
+        // Act
+        var act = () => builder.BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be(key);
+    }
+
+    [Fact]
+    public void BuildConfig_UnknownTimeZone_ShouldThrowWithKey()
+    {
+        // Act
+        var act = () => new SessionBuilder().WithDeviceId("device").WithTimeZone("Mars/Olympus_Mons").BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("session.timeZone");
+    }
+
+    [Fact]
+    public void BuildConfig_NegativePowerSaveIdle_ShouldThrowWithKey()
+    {
+        // Act
+        var act = () => new SessionBuilder()
+            .WithDeviceId("device")
+            .WithPowerSaveIdleTimeout(TimeSpan.FromMinutes(-1))
+            .BuildConfig();
+
+        // Assert
+        act.Should().Throw<ConfigException>().Which.Key.Should().Be("session.powerSaveIdle");
+    }
+
+    [Fact]
+    public void Build_WithoutHttpClientFactory_ShouldThrowWithKey()
+    {
+        // Act
+        var act = () => new SessionBuilder().WithDeviceId("device").Build();
+
+        // Assert
+        var ex = act.Should().Throw<ConfigException>().Which;
+        ex.Key.Should().Be("httpClientFactory");
+        ex.Source.Should().Be(nameof(SessionBuilder));
+    }
+}
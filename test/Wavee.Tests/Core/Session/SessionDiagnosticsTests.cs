@@ -0,0 +1,66 @@
+using FluentAssertions;
+using Wavee.Connect.Connection;
+using Wavee.Core.Mercury;
+using Wavee.Core.Session;
+using Wavee.Core.Storage;
+using Xunit;
+
+namespace Wavee.Tests.Core.Session;
+
+/// <summary>
+/// Tests for SessionDiagnostics serialization.
+/// </summary>
+public class SessionDiagnosticsTests
+{
+    [Fact]
+    public void ToJson_ShouldProduceCamelCaseSnapshot()
+    {
+        // ============================================================
+        // WHY: The dump is pasted into bug reports and parsed by tooling;
+        //      it must serialize without reflection (AOT) and keep
+        //      stable camelCase names and string enums.
+        // ============================================================
+
+        // Arrange
+        var diagnostics = new SessionDiagnostics(
+            CapturedAtUtc: DateTimeOffset.FromUnixTimeSeconds(1_700_000_000),
+            Connection: new ConnectionDiagnostics(
+                SessionConnectionState.Connected,
+                IsAuthenticated: true,
+                ApUrl: "ap-gew4.spotify.com:4070",
+                ConnectedAtUtc: DateTime.UnixEpoch,
+                LastApPacketUtc: DateTime.UnixEpoch,
+                EncodeNonce: 12,
+                DecodeNonce: 34,
+                ClockOffsetMs: -150,
+                ClockIsCoarse: false),
+            Dealer: new DealerDiagnostics(
+                ConnectionState.Connected,
+                "conn-id",
+                PendingRequests: 1,
+                Topics: new Dictionary<string, long> { ["hm://connect-state/v1/cluster"] = 5 }),
+            PendingMercuryRequests: [new MercuryPendingRequestInfo(7, "GET", "hm://keymaster/token", TimeSpan.FromSeconds(3))],
+            PendingAudioKeyRequests: 2,
+            Cache: new CacheStatistics(1, 10, 2048, 3, 4, 5),
+            Bandwidth: new BandwidthStatistics(
+                DateTimeOffset.FromUnixTimeSeconds(1_700_000_000),
+                new Dictionary<TrafficCategory, TrafficCounters>
+                {
+                    [TrafficCategory.Audio] = new(100, 4096),
+                    [TrafficCategory.Images] = new(0, 512)
+                }));
+
+        // Act
+        var json = diagnostics.ToJson();
+
+        // Assert
+        json.Should().Contain("\"encodeNonce\": 12");
+        json.Should().Contain("\"decodeNonce\": 34");
+        json.Should().Contain("\"state\": \"Connected\"");
+        json.Should().Contain("\"pendingAudioKeyRequests\": 2");
+        json.Should().Contain("hm://keymaster/token");
+        json.Should().Contain("\"hotCacheCount\": 1");
+        json.Should().Contain("\"Audio\": {");
+        json.Should().Contain("\"bytesReceived\": 4608");
+    }
+}
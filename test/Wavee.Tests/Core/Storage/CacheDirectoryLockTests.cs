@@ -0,0 +1,93 @@
+using FluentAssertions;
+using Microsoft.Extensions.Logging;
+using Moq;
+using Wavee.Core.Storage;
+using Xunit;
+
+namespace Wavee.Tests.Core.Storage;
+
+/// <summary>
+/// Tests for CacheDirectoryLock - validates exclusive ownership of a cache directory.
+///
+/// WHY: Two Wavee processes writing the same metadata index corrupt it. Bugs here will cause:
+/// - A second instance silently opening a cache that is already in use
+/// - An untyped IOException the host can't turn into a useful message
+/// - The wait option never picking up a lock that was released
+/// - A wait that runs out leaving no trace of who held the lock
+/// </summary>
+public class CacheDirectoryLockTests : IDisposable
+{
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-cachelock-" + Guid.NewGuid().ToString("N"));
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+
+    [Fact]
+    public void Acquire_WhenAlreadyHeld_ShouldThrowCacheLockedExceptionWithHolder()
+    {
+        // Arrange
+        using var held = CacheDirectoryLock.Acquire(_dir);
+
+        // Act
+        var act = () => CacheDirectoryLock.Acquire(_dir);
+
+        // Assert
+        var ex = act.Should().Throw<CacheLockedException>().Which;
+        ex.Directory.Should().Be(Path.GetFullPath(_dir));
+        ex.HolderProcessId.Should().Be(Environment.ProcessId);
+    }
+
+    [Fact]
+    public void Acquire_AfterDispose_ShouldSucceed()
+    {
+        // Arrange
+        CacheDirectoryLock.Acquire(_dir).Dispose();
+
+        // Act
+        using var second = CacheDirectoryLock.Acquire(_dir);
+
+        // Assert
+        second.Directory.Should().Be(Path.GetFullPath(_dir));
+    }
+
+    [Fact]
+    public async Task AcquireAsync_WithWait_ShouldSucceedOnceHolderReleases()
+    {
+        // Arrange
+        var held = CacheDirectoryLock.Acquire(_dir);
+        _ = Task.Delay(300).ContinueWith(_ => held.Dispose());
+
+        // Act
+        using var second = await CacheDirectoryLock.AcquireAsync(_dir, TimeSpan.FromSeconds(10));
+
+        // Assert
+        second.Should().NotBeNull();
+    }
+
+    [Fact]
+    public async Task AcquireAsync_WaitExpires_ShouldLogHolderAndThrow()
+    {
+        // Arrange
+        using var held = CacheDirectoryLock.Acquire(_dir);
+        var logger = new Mock<ILogger>();
+
+        // Act
+        var act = () => CacheDirectoryLock.AcquireAsync(_dir, TimeSpan.FromMilliseconds(200), logger.Object);
+
+        // Assert
+        var ex = (await act.Should().ThrowAsync<CacheLockedException>()).Which;
+        ex.HolderProcessId.Should().Be(Environment.ProcessId);
+        ex.Message.Should().Contain("gave up after waiting");
+        logger.Verify(
+            x => x.Log(
+                LogLevel.Error,
+                It.IsAny<EventId>(),
+                It.Is<It.IsAnyType>((v, _) => v.ToString()!.Contains(Environment.ProcessId.ToString())),
+                It.IsAny<Exception>(),
+                It.IsAny<Func<It.IsAnyType, Exception?, string>>()),
+            Times.Once);
+    }
+}
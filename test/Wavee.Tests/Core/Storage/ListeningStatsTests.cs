@@ -0,0 +1,103 @@
+using FluentAssertions;
+using Microsoft.Data.Sqlite;
+using Wavee.Core.Storage;
+using Wavee.Core.Storage.Abstractions;
+using Xunit;
+
+namespace Wavee.Tests.Core.Storage;
+
+/// <summary>
+/// Tests for the MetadataDatabase listening stats - validates hourly aggregation and the range queries.
+///
+/// WHY: Frontends build "your week in music" from these queries alone. Bugs here will cause:
+/// - Replays of a track in the same hour counted once, or listened time lost
+/// - Listening from outside the requested week leaking into it
+/// - Artists split across rows, or ranked by the wrong measure
+/// </summary>
+public sealed class ListeningStatsTests : IAsyncLifetime
+{
+    private static readonly DateTimeOffset Week = new(2026, 3, 2, 0, 0, 0, TimeSpan.Zero);
+
+    private readonly string _path = Path.Combine(Path.GetTempPath(), "wavee-listening-" + Guid.NewGuid().ToString("N") + ".db");
+    private MetadataDatabase _db = null!;
+
+    public ValueTask InitializeAsync()
+    {
+        _db = new MetadataDatabase(_path);
+        return ValueTask.CompletedTask;
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _db.DisposeAsync();
+        SqliteConnection.ClearAllPools();
+        File.Delete(_path);
+    }
+
+    private static ListeningEntry Listen(string track, string artist, DateTimeOffset startedAt, long ms, bool counts = true) => new()
+    {
+        TrackUri = $"spotify:track:{track}",
+        Title = track,
+        ArtistName = artist,
+        ArtistUri = $"spotify:artist:{artist}",
+        StartedAt = startedAt,
+        MsPlayed = ms,
+        CountsAsPlay = counts
+    };
+
+    [Fact]
+    public async Task RecordListeningAsync_SameTrackAndHour_ShouldAddUp()
+    {
+        // Arrange
+        await _db.RecordListeningAsync(Listen("a", "x", Week.AddMinutes(5), 200_000));
+        await _db.RecordListeningAsync(Listen("a", "x", Week.AddMinutes(50), 10_000, counts: false));
+        await _db.RecordListeningAsync(Listen("a", "x", Week.AddMinutes(61), 200_000));
+
+        // Act
+        var hours = await _db.GetListeningByHourAsync(Week, Week.AddDays(7));
+        var summary = await _db.GetListeningSummaryAsync(Week, Week.AddDays(7));
+
+        // Assert
+        hours.Should().Equal(
+            new ListeningHourStat(Week, 210_000, 1),
+            new ListeningHourStat(Week.AddHours(1), 200_000, 1));
+        summary.Should().Be(new ListeningSummary(410_000, 2, 1, 1));
+    }
+
+    [Fact]
+    public async Task GetTopListenedAsync_ShouldRankWithinRangeOnly()
+    {
+        // Arrange
+        await _db.RecordListeningAsync(Listen("a", "x", Week.AddHours(1), 100_000));
+        await _db.RecordListeningAsync(Listen("b", "x", Week.AddHours(2), 90_000));
+        await _db.RecordListeningAsync(Listen("c", "y", Week.AddHours(3), 150_000));
+        await _db.RecordListeningAsync(Listen("c", "y", Week.AddHours(4), 150_000));
+        await _db.RecordListeningAsync(Listen("d", "z", Week.AddDays(-1), 900_000));
+
+        // Act
+        var artists = await _db.GetTopListenedArtistsAsync(Week, Week.AddDays(7));
+        var tracks = await _db.GetTopListenedTracksAsync(Week, Week.AddDays(7), limit: 2);
+
+        // Assert
+        artists.Should().Equal(
+            new ListeningArtistStat("spotify:artist:y", "y", 300_000, 2),
+            new ListeningArtistStat("spotify:artist:x", "x", 190_000, 2));
+        tracks.Select(t => t.TrackUri).Should().Equal("spotify:track:c", "spotify:track:a");
+        tracks[0].PlayCount.Should().Be(2);
+    }
+
+    [Fact]
+    public async Task DeleteListeningStatsAsync_ShouldDropOlderHours()
+    {
+        // Arrange
+        await _db.RecordListeningAsync(Listen("a", "x", Week.AddDays(-10), 100_000));
+        await _db.RecordListeningAsync(Listen("b", "x", Week.AddHours(1), 100_000));
+
+        // Act
+        await _db.DeleteListeningStatsAsync(Week);
+
+        // Assert
+        (await _db.GetListeningByHourAsync(DateTimeOffset.UnixEpoch, Week.AddDays(7)))
+            .Should().ContainSingle().Which.HourStart.Should().Be(Week.AddHours(1));
+    }
+}
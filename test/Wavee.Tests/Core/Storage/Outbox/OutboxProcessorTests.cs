@@ -0,0 +1,200 @@
+using System.Reactive.Subjects;
+using FluentAssertions;
+using Moq;
+using Wavee.Core.Http;
+using Wavee.Core.Session;
+using Wavee.Core.Storage.Abstractions;
+using Wavee.Core.Storage.Outbox;
+using Xunit;
+
+namespace Wavee.Tests.Core.Storage.Outbox;
+
+/// <summary>
+/// Tests for OutboxProcessor - validates offline gating, replay on reconnect, conflict handling and status events.
+///
+/// WHY: Library and playlist edits made offline live only in this queue. Bugs here will cause:
+/// - Edits silently dropped after ten offline retries
+/// - Queued edits never sent once the network comes back
+/// - Stale playlist edits retried forever against a newer revision
+/// - One rejected edit blocking every edit queued behind it
+/// </summary>
+public class OutboxProcessorTests
+{
+    private readonly Mock<IMetadataDatabase> _db = new();
+    private readonly List<OutboxEntry> _queue = [];
+
+    public OutboxProcessorTests()
+    {
+        _db.Setup(d => d.DequeueOutboxAsync(It.IsAny<int>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(() => _queue.ToList());
+        _db.Setup(d => d.CompleteOutboxAsync(It.IsAny<long>(), It.IsAny<CancellationToken>()))
+            .Callback<long, CancellationToken>((id, _) => _queue.RemoveAll(e => e.Id == id))
+            .Returns(Task.CompletedTask);
+        _db.Setup(d => d.CountOutboxAsync(It.IsAny<CancellationToken>()))
+            .ReturnsAsync(() => _queue.Count);
+    }
+
+    [Fact]
+    public async Task RunAsync_WhileOffline_ShouldLeaveQueueAndReportStatus()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(_ => Task.CompletedTask);
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+        var state = new BehaviorSubject<SessionConnectionState>(SessionConnectionState.Disconnected);
+        processor.ReplayWhenOnline(state);
+        var statuses = new List<OutboxStatus>();
+        using var _ = processor.Status.Subscribe(statuses.Add);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        handler.Calls.Should().Be(0);
+        statuses.Should().ContainSingle().Which.Should().Be(new OutboxStatus(1, false, null));
+    }
+
+    [Fact]
+    public async Task ReplayWhenOnline_OnReconnect_ShouldDrainQueue()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(_ => Task.CompletedTask);
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+        var state = new BehaviorSubject<SessionConnectionState>(SessionConnectionState.Disconnected);
+        processor.ReplayWhenOnline(state);
+        var synced = new TaskCompletionSource<OutboxOpResult>();
+        using var _ = processor.Processed.Subscribe(r => synced.TrySetResult(r));
+
+        // Act
+        state.OnNext(SessionConnectionState.Connected);
+        var result = await synced.Task.WaitAsync(TimeSpan.FromSeconds(5));
+
+        // Assert
+        result.Outcome.Should().Be(OutboxOpOutcome.Synced);
+        _queue.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task RunAsync_OnNetworkFailure_ShouldNotBurnRetry()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(_ => throw new HttpRequestException("no route to host"));
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        _db.Verify(d => d.FailOutboxAsync(It.IsAny<long>(), It.IsAny<string?>(), It.IsAny<CancellationToken>()), Times.Never);
+        _queue.Should().ContainSingle();
+    }
+
+    [Fact]
+    public async Task RunAsync_OnRejectedRequest_ShouldDropOpAndSyncTheRest()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        _queue.Add(Entry(2));
+        var handler = new StubHandler(e => e.Id == 1
+            ? throw new SpClientException(SpClientFailureReason.RequestFailed, "400")
+            : Task.CompletedTask);
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+        var results = new List<OutboxOpResult>();
+        using var _ = processor.Processed.Subscribe(results.Add);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        results.Select(r => r.Outcome).Should().Equal(OutboxOpOutcome.Dropped, OutboxOpOutcome.Synced);
+        _queue.Should().BeEmpty();
+    }
+
+    [Fact]
+    public async Task RunAsync_OnRetriesExhaustedInTransport_ShouldNotBurnRetry()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(_ => throw new SpClientException(
+            SpClientFailureReason.RequestFailed,
+            "Failed after 3 attempts",
+            new HttpRequestException("connection reset")));
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        _db.Verify(d => d.FailOutboxAsync(It.IsAny<long>(), It.IsAny<string?>(), It.IsAny<CancellationToken>()), Times.Never);
+        _queue.Should().ContainSingle();
+    }
+
+    [Fact]
+    public async Task RunAsync_OnHttpErrorStatus_ShouldCountRetry()
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(_ => throw new HttpRequestException(
+            "Service unavailable", null, System.Net.HttpStatusCode.ServiceUnavailable));
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        _db.Verify(d => d.FailOutboxAsync(1, It.IsAny<string?>(), It.IsAny<CancellationToken>()), Times.Once);
+    }
+
+    [Theory]
+    [InlineData(OutboxConflictResolution.Discard, OutboxOpOutcome.Conflict, 0)]
+    [InlineData(OutboxConflictResolution.Resolved, OutboxOpOutcome.Synced, 0)]
+    [InlineData(OutboxConflictResolution.Retry, OutboxOpOutcome.Retrying, 1)]
+    public async Task RunAsync_OnRevisionConflict_ShouldApplyHandlerResolution(
+        OutboxConflictResolution resolution, OutboxOpOutcome expected, int remaining)
+    {
+        // Arrange
+        _queue.Add(Entry(1));
+        var handler = new StubHandler(
+            _ => throw new SpClientException(SpClientFailureReason.RevisionConflict, "409"),
+            resolution);
+        using var processor = new OutboxProcessor(_db.Object, [handler]);
+        var results = new List<OutboxOpResult>();
+        using var _ = processor.Processed.Subscribe(results.Add);
+
+        // Act
+        await processor.RunAsync();
+
+        // Assert
+        results.Should().ContainSingle().Which.Outcome.Should().Be(expected);
+        _queue.Should().HaveCount(remaining);
+    }
+
+    private static OutboxEntry Entry(long id) => new()
+    {
+        Id = id,
+        OpKind = StubHandler.Kind,
+        PrimaryUri = "spotify:playlist:p"
+    };
+
+    private sealed class StubHandler(
+        Func<OutboxEntry, Task> process,
+        OutboxConflictResolution resolution = OutboxConflictResolution.Retry) : IOutboxHandler
+    {
+        public const string Kind = "test.op";
+
+        public int Calls { get; private set; }
+
+        public string OpKind => Kind;
+
+        public Task ProcessAsync(OutboxEntry entry, CancellationToken ct)
+        {
+            Calls++;
+            return process(entry);
+        }
+
+        public Task<OutboxConflictResolution> ResolveConflictAsync(OutboxEntry entry, CancellationToken ct)
+            => Task.FromResult(resolution);
+    }
+}
@@ -0,0 +1,171 @@
+using FluentAssertions;
+using Wavee.Core.Storage;
+using Xunit;
+
+namespace Wavee.Tests.Core.Storage;
+
+/// <summary>
+/// Tests for WaveePaths - validates where config, data, caches and logs go on each platform.
+///
+/// WHY: Every persistence module takes its default location from here. Bugs here will cause:
+/// - Credentials, device id or the metadata database "lost" after an upgrade
+/// - Caches written into roaming profiles or backed-up config directories
+/// - XDG or WAVEE_HOME overrides ignored, breaking containers and portable installs
+/// </summary>
+public class WaveePathsTests : IDisposable
+{
+    private readonly string _home = Path.Combine(Path.GetTempPath(), "wavee-paths-" + Guid.NewGuid().ToString("N"));
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_home))
+            Directory.Delete(_home, recursive: true);
+    }
+
+    private WaveePaths Resolve(PathPlatform platform, Dictionary<string, string>? environment = null)
+        => WaveePaths.Resolve(
+            platform,
+            name => environment?.GetValueOrDefault(name),
+            folder => folder switch
+            {
+                Environment.SpecialFolder.ApplicationData => Path.Combine(_home, "AppData", "Roaming"),
+                Environment.SpecialFolder.LocalApplicationData => Path.Combine(_home, "AppData", "Local"),
+                _ => throw new ArgumentOutOfRangeException(nameof(folder))
+            },
+            _home);
+
+    [Fact]
+    public void Resolve_Unix_ShouldUseXdgVariablesWithHomeFallbacks()
+    {
+        // Arrange
+        var configHome = Path.Combine(_home, "xdg-config");
+
+        // Act
+        var paths = Resolve(PathPlatform.Unix, new() { ["XDG_CONFIG_HOME"] = configHome, ["XDG_CACHE_HOME"] = "relative/cache" });
+
+        // Assert
+        paths.ConfigDirectory.Should().Be(Path.Combine(configHome, "wavee"));
+        paths.DataDirectory.Should().Be(Path.Combine(_home, ".local", "share", "wavee"));
+        paths.CacheDirectory.Should().Be(Path.Combine(_home, ".cache", "wavee"), "relative XDG values are invalid");
+        paths.LogDirectory.Should().Be(Path.Combine(_home, ".local", "state", "wavee", "logs"));
+        paths.ConfigFile.Should().Be(Path.Combine(configHome, "wavee", "wavee.json"));
+        paths.MetadataDatabase.Should().Be(Path.Combine(_home, ".local", "share", "wavee", "metadata.db"));
+        paths.AudioCacheDirectory.Should().Be(Path.Combine(_home, ".cache", "wavee", "AudioCache"));
+    }
+
+    [Fact]
+    public void Resolve_MacOS_ShouldUseLibraryFolders()
+    {
+        // Act
+        var paths = Resolve(PathPlatform.MacOS);
+
+        // Assert
+        var library = Path.Combine(_home, "Library");
+        paths.DataDirectory.Should().Be(Path.Combine(library, "Application Support", "Wavee"));
+        paths.ConfigDirectory.Should().Be(paths.DataDirectory);
+        paths.CacheDirectory.Should().Be(Path.Combine(library, "Caches", "Wavee"));
+        paths.LogDirectory.Should().Be(Path.Combine(library, "Logs", "Wavee"));
+    }
+
+    [Fact]
+    public void Resolve_Android_ShouldUseAppPrivateFilesAndCacheDirectories()
+    {
+        // Arrange
+        var files = Path.Combine(_home, "data", "com.example.wavee", "files");
+        var cache = Path.Combine(_home, "data", "com.example.wavee", "cache");
+
+        // Act
+        var paths = WaveePaths.Resolve(
+            PathPlatform.Android,
+            name => name == "TMPDIR" ? cache : null,
+            folder => Path.Combine(files, ".config"),
+            files);
+
+        // Assert
+        paths.DataDirectory.Should().Be(Path.Combine(files, "wavee"));
+        paths.ConfigDirectory.Should().Be(paths.DataDirectory);
+        paths.CacheDirectory.Should().Be(Path.Combine(cache, "wavee"));
+        paths.LogDirectory.Should().Be(Path.Combine(cache, "wavee", "logs"));
+        paths.AudioCacheDirectory.Should().Be(Path.Combine(cache, "wavee", "AudioCache"));
+    }
+
+    [Fact]
+    public void Resolve_Android_WithoutTmpDir_ShouldUseSiblingCacheDirectory()
+    {
+        // Arrange
+        var files = Path.Combine(_home, "com.example.wavee", "files");
+
+        // Act
+        var paths = WaveePaths.Resolve(PathPlatform.Android, _ => null, _ => files, files);
+
+        // Assert
+        paths.CacheDirectory.Should().Be(Path.Combine(_home, "com.example.wavee", "cache", "wavee"));
+    }
+
+    [Fact]
+    public void Resolve_Windows_ShouldKeepExistingKnownFolderLayout()
+    {
+        // Act
+        var paths = Resolve(PathPlatform.Windows);
+
+        // Assert
+        var roaming = Path.Combine(_home, "AppData", "Roaming", "Wavee");
+        var local = Path.Combine(_home, "AppData", "Local", "Wavee");
+        paths.CredentialsDirectory.Should().Be(Path.Combine(roaming, "credentials"));
+        paths.DeviceIdFile.Should().Be(Path.Combine(roaming, "device_id.txt"));
+        paths.MetadataDatabase.Should().Be(Path.Combine(roaming, "metadata.db"));
+        paths.LocalArtworkDirectory.Should().Be(Path.Combine(roaming, "local-artwork"));
+        paths.AudioCacheDirectory.Should().Be(Path.Combine(local, "AudioCache"));
+        paths.NativeDepsDirectory.Should().Be(Path.Combine(local, "NativeDeps"));
+        paths.LogDirectory.Should().Be(Path.Combine(local, "Logs"));
+    }
+
+    [Fact]
+    public void Resolve_WithWaveeHome_ShouldRootEverythingThere()
+    {
+        // Arrange
+        var root = Path.Combine(_home, "portable");
+
+        // Act
+        var paths = Resolve(PathPlatform.Unix, new() { [WaveePaths.HomeEnvironmentVariable] = root, ["XDG_DATA_HOME"] = "/ignored" });
+
+        // Assert
+        paths.ConfigDirectory.Should().Be(Path.Combine(root, "config"));
+        paths.DataDirectory.Should().Be(Path.Combine(root, "data"));
+        paths.CacheDirectory.Should().Be(Path.Combine(root, "cache"));
+        paths.LogDirectory.Should().Be(Path.Combine(root, "logs"));
+    }
+
+    [Fact]
+    public void TypedPaths_WhenOnlyLegacyLocationHasData_ShouldKeepUsingIt()
+    {
+        // Arrange
+        var legacyData = Path.Combine(_home, "AppData", "Roaming", "Wavee");
+        var legacyCache = Path.Combine(_home, "AppData", "Local", "Wavee");
+        Directory.CreateDirectory(Path.Combine(legacyData, "credentials"));
+        File.WriteAllText(Path.Combine(legacyData, "metadata.db"), "");
+        Directory.CreateDirectory(Path.Combine(legacyCache, "AudioCache"));
+
+        // Act
+        var paths = Resolve(PathPlatform.Unix);
+
+        // Assert
+        paths.CredentialsDirectory.Should().Be(Path.Combine(legacyData, "credentials"));
+        paths.MetadataDatabase.Should().Be(Path.Combine(legacyData, "metadata.db"));
+        paths.LocalArtworkDirectory.Should().Be(Path.Combine(legacyData, "local-artwork"), "artwork follows the database");
+        paths.AudioCacheDirectory.Should().Be(Path.Combine(legacyCache, "AudioCache"));
+        paths.DeviceIdFile.Should().Be(Path.Combine(paths.DataDirectory, "device_id.txt"), "nothing to carry over");
+    }
+
+    [Fact]
+    public void TypedPaths_WhenBothLocationsHaveData_ShouldPreferNewLocation()
+    {
+        // Arrange
+        var paths = Resolve(PathPlatform.Unix);
+        Directory.CreateDirectory(Path.Combine(_home, "AppData", "Roaming", "Wavee", "credentials"));
+        Directory.CreateDirectory(Path.Combine(paths.DataDirectory, "credentials"));
+
+        // Act & Assert
+        paths.CredentialsDirectory.Should().Be(Path.Combine(paths.DataDirectory, "credentials"));
+    }
+}
@@ -0,0 +1,119 @@
+using FluentAssertions;
+using Moq;
+using Wavee.Core.Http;
+using Wavee.Core.Time;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Time;
+
+/// <summary>
+/// Tests for SpotifyClockService coarse server-time hints and periodic sync.
+/// Validates skew correction before the first melody sync round, and token
+/// expiry / re-sync behaviour over hours of virtual time.
+/// </summary>
+public class SpotifyClockServiceTests
+{
+    [Fact]
+    public void ObserveServerTime_WithLargeSkew_ShouldCorrectClock()
+    {
+        // ============================================================
+        // WHY: A Pi without an RTC battery can boot hours off; the
+        //      AP Ping / HTTP Date hint must correct NowMs right away.
+        // ============================================================
+
+        // Arrange
+        var clock = new SpotifyClockService(spClient: null!);
+        var serverTime = DateTimeOffset.UtcNow.AddHours(-3);
+
+        // Act
+        clock.ObserveServerTime(serverTime, TimeSpan.FromSeconds(1));
+
+        // Assert
+        clock.IsCoarse.Should().BeTrue();
+        clock.UtcNow.Should().BeCloseTo(serverTime, TimeSpan.FromSeconds(1));
+    }
+
+    [Fact]
+    public void ObserveServerTime_WithinResolution_ShouldKeepOffset()
+    {
+        // ============================================================
+        // WHY: Second-granular hints must not make the clock jitter
+        //      when the local clock is already correct.
+        // ============================================================
+
+        // Arrange
+        var clock = new SpotifyClockService(spClient: null!);
+
+        // Act
+        clock.ObserveServerTime(DateTimeOffset.UtcNow.AddMilliseconds(400), TimeSpan.FromSeconds(1));
+
+        // Assert
+        clock.OffsetMs.Should().Be(0);
+        clock.IsCoarse.Should().BeFalse();
+    }
+
+    [Fact]
+    public void UtcNow_OnVirtualClock_ShouldDriveTokenRefreshWindow()
+    {
+        // ============================================================
+        // WHY: Session.GetAccessTokenAsync refreshes against Clock.UtcNow;
+        //      a one-hour token must enter the refresh window at 55min.
+        // ============================================================
+
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var clock = new SpotifyClockService(spClient: null!, timeProvider: time);
+        var token = AccessToken.FromLogin5Response("token", expiresInSeconds: 3600, issuedAt: clock.UtcNow);
+
+        // Act & Assert
+        time.Advance(TimeSpan.FromMinutes(54));
+        token.ShouldRefresh(now: clock.UtcNow).Should().BeFalse();
+
+        time.Advance(TimeSpan.FromMinutes(1));
+        token.ShouldRefresh(now: clock.UtcNow).Should().BeTrue();
+        token.IsExpired(clock.UtcNow).Should().BeFalse();
+
+        time.Advance(TimeSpan.FromMinutes(5));
+        token.IsExpired(clock.UtcNow).Should().BeTrue();
+    }
+
+    [Fact]
+    public void Start_OnVirtualClock_ShouldResyncEveryIntervalForHours()
+    {
+        // ============================================================
+        // WHY: Drift correction depends on the periodic melody re-sync
+        //      actually firing; verify 6 hours of it without waiting.
+        // ============================================================
+
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var calls = 0;
+        var spClient = new Mock<ISpClient>();
+        spClient.Setup(c => c.GetMelodyTimeAsync(It.IsAny<CancellationToken>()))
+            .ReturnsAsync(() =>
+            {
+                Interlocked.Increment(ref calls);
+                return time.GetUtcNow().ToUnixTimeMilliseconds() + 1500;
+            });
+        using var clock = new SpotifyClockService(spClient.Object, timeProvider: time);
+
+        // Act
+        clock.Start();
+        for (var i = 0; i < 36; i++)
+        {
+            time.WaitForPendingTimers().Should().BeTrue();
+            time.Advance(TimeSpan.FromMinutes(10));
+
+            // The tick continuation runs on the pool; let each round land before the next tick
+            SpinWait.SpinUntil(() => clock.LastSyncUtc == time.GetUtcNow(), TimeSpan.FromSeconds(2))
+                .Should().BeTrue($"re-sync {i + 1} should complete");
+        }
+
+        // Assert - initial round plus one per 10-minute tick, 3 samples each
+        Volatile.Read(ref calls).Should().Be(37 * 3);
+        clock.IsSynced.Should().BeTrue();
+        clock.OffsetMs.Should().Be(1500);
+        clock.LastSyncUtc.Should().Be(time.GetUtcNow());
+    }
+}
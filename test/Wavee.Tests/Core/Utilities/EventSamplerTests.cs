@@ -0,0 +1,50 @@
+using FluentAssertions;
+using Wavee.Core.Utilities;
+using Wavee.Tests.Helpers;
+using Xunit;
+
+namespace Wavee.Tests.Core.Utilities;
+
+/// <summary>
+/// Tests for EventSampler.
+/// Validates that high-frequency logs are bounded without going silent.
+/// </summary>
+public class EventSamplerTests
+{
+    [Fact]
+    public void ShouldSample_WithinInterval_ShouldPassFirstAndCountTheRest()
+    {
+        // ============================================================
+        // WHY: Position and chunk logs fire many times per second on an
+        //      always-on daemon; only one per interval should be written,
+        //      and that one must say how many it stands for.
+        // ============================================================
+
+        // Arrange
+        var time = new VirtualTimeProvider();
+        var sampler = new EventSampler(TimeSpan.FromSeconds(10), time);
+
+        // Act
+        var first = sampler.ShouldSample(out var firstSuppressed);
+        var dropped = Enumerable.Range(0, 5).Count(_ => !sampler.ShouldSample(out _));
+        time.Advance(TimeSpan.FromSeconds(10));
+        var next = sampler.ShouldSample(out var nextSuppressed);
+
+        // Assert
+        first.Should().BeTrue();
+        firstSuppressed.Should().Be(0);
+        dropped.Should().Be(5);
+        next.Should().BeTrue();
+        nextSuppressed.Should().Be(5);
+    }
+
+    [Fact]
+    public void ShouldSample_WithZeroInterval_ShouldPassEverything()
+    {
+        // Arrange
+        var sampler = new EventSampler(TimeSpan.Zero, new VirtualTimeProvider());
+
+        // Act & Assert
+        Enumerable.Range(0, 3).Should().OnlyContain(_ => sampler.ShouldSample(out _));
+    }
+}
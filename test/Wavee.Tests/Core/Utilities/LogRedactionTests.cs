@@ -0,0 +1,65 @@
+using System.Collections.Generic;
+using FluentAssertions;
+using Wavee.Core.Utilities;
+using Xunit;
+
+namespace Wavee.Tests.Core.Utilities;
+
+/// <summary>
+/// Tests for LogRedaction.
+/// Validates that secrets never reach log output with the default configuration.
+/// </summary>
+public class LogRedactionTests
+{
+    [Fact]
+    public void Url_WithAccessToken_ShouldRedactOnlySensitiveParameters()
+    {
+        // ============================================================
+        // WHY: The dealer URL embeds the bearer token as a query
+        //      parameter; logging it verbatim leaks the session.
+        // ============================================================
+
+        // Act
+        var result = LogRedaction.Url("wss://dealer.spotify.com/?access_token=BQabc123&foo=bar");
+
+        // Assert
+        result.Should().Be("wss://dealer.spotify.com/?access_token=<redacted>&foo=bar");
+    }
+
+    [Fact]
+    public void Secret_And_Bytes_ShouldKeepShapeButNotContent()
+    {
+        // ============================================================
+        // WHY: Length is useful when debugging; content is not safe.
+        // ============================================================
+
+        // Act & Assert
+        LogRedaction.Secret("supersecret").Should().NotContain("supersecret").And.Contain("11 chars");
+        LogRedaction.Bytes(new byte[] { 0xDE, 0xAD }).Should().NotContain("DEAD").And.Contain("2 bytes");
+    }
+
+    [Fact]
+    public void Headers_WithAuthorization_ShouldRedactCredentialHeaders()
+    {
+        // ============================================================
+        // WHY: The remote state recorder persists request headers for
+        //      the Debug page; bearer / client tokens must not survive.
+        // ============================================================
+
+        // Arrange
+        var headers = new Dictionary<string, string>
+        {
+            ["Authorization"] = "Bearer BQabc123",
+            ["client-token"] = "AAtoken",
+            ["Accept"] = "application/json"
+        };
+
+        // Act
+        var result = LogRedaction.Headers(headers)!;
+
+        // Assert
+        result["Authorization"].Should().NotContain("BQabc123");
+        result["client-token"].Should().NotContain("AAtoken");
+        result["Accept"].Should().Be("application/json");
+    }
+}
@@ -0,0 +1,40 @@
+using FluentAssertions;
+using Moq;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Core.Utilities;
+
+/// <summary>
+/// Tests for LogSampling.
+/// Validates that each session samples with its own configuration.
+/// </summary>
+public class LogSamplingTests
+{
+    [Fact]
+    public async Task Create_SecondSession_ShouldNotChangeTheFirstSessionsSampling()
+    {
+        // ============================================================
+        // WHY: Zone hosts run several sessions in one process; creating
+        //      one must not silently change how another samples its logs.
+        // ============================================================
+
+        // Arrange
+        var httpClientFactory = new Mock<IHttpClientFactory>();
+        httpClientFactory.Setup(f => f.CreateClient(It.IsAny<string>())).Returns(() => new HttpClient());
+        await using var first = Wavee.Core.Session.Session.Create(new SessionConfig
+        {
+            DeviceId = "first",
+            DeviceName = "First",
+            Sampling = new SamplingConfig { PositionLogInterval = TimeSpan.FromSeconds(30) }
+        }, httpClientFactory.Object);
+
+        // Act
+        await using var second = Wavee.Core.Session.Session.Create(
+            new SessionConfig { DeviceId = "second", DeviceName = "Second" }, httpClientFactory.Object);
+
+        // Assert
+        first.LogSampling.Position.Interval.Should().Be(TimeSpan.FromSeconds(30));
+        second.LogSampling.Position.Interval.Should().Be(TimeSpan.Zero);
+    }
+}
@@ -0,0 +1,120 @@
+using System.IO;
+using Wavee.Core.Connection;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// <see cref="IApTransport"/> wrapper that adds latency, bounded reordering, throttling and
+/// disconnects on top of a real or mocked transport, for exercising the session's reconnect
+/// and timeout paths.
+/// </summary>
+/// <remarks>
+/// Received packets are pulled from the inner transport as they become available (up to
+/// <see cref="ChaosOptions.ReorderWindow"/> ahead) and handed out in order of simulated arrival,
+/// so packets can overtake each other by up to <see cref="ChaosOptions.Jitter"/>. Packets with
+/// the same command keep their order — Mercury parts and audio chunks depend on it.
+/// A disconnect behaves like a dropped socket: sends throw <see cref="IOException"/> and
+/// receives return null from then on.
+/// NOT thread-safe for concurrent receives — same contract as <see cref="ApTransport"/>.
+/// </remarks>
+internal sealed class ChaosApTransport : IApTransport
+{
+    private readonly IApTransport _inner;
+    private readonly ChaosOptions _options;
+    private readonly ChaosLink _up;
+    private readonly ChaosLink _down;
+    private readonly List<(DateTimeOffset Due, byte Command, byte[] Payload)> _arrived = new();
+    private readonly Dictionary<byte, DateTimeOffset> _lastDue = new();
+    private Task<(byte command, byte[] payload)?>? _pending;
+    private bool _innerClosed;
+    private volatile bool _disconnected;
+    private int _disconnects;
+
+    public ChaosApTransport(IApTransport inner, ChaosOptions options)
+    {
+        _inner = inner ?? throw new ArgumentNullException(nameof(inner));
+        _options = options ?? throw new ArgumentNullException(nameof(options));
+        _up = new ChaosLink(options, stream: 0);
+        _down = new ChaosLink(options, stream: 1);
+    }
+
+    /// <summary>True once the connection has been dropped.</summary>
+    public bool IsDisconnected => _disconnected;
+
+    /// <summary>Number of disconnects, forced or random.</summary>
+    public int Disconnects => Volatile.Read(ref _disconnects);
+
+    /// <summary>Drops the connection now.</summary>
+    public void Disconnect()
+    {
+        if (_disconnected)
+            return;
+        _disconnected = true;
+        Interlocked.Increment(ref _disconnects);
+    }
+
+    public async ValueTask SendAsync(byte command, ReadOnlyMemory<byte> payload, CancellationToken cancellationToken = default)
+    {
+        if (!_disconnected && _up.RollDisconnect())
+            Disconnect();
+        ThrowIfDisconnected();
+
+        await _up.WaitUntilAsync(_up.Schedule(ApCodec.GetEncodedSize(payload.Length)), cancellationToken);
+        ThrowIfDisconnected();
+        await _inner.SendAsync(command, payload, cancellationToken);
+    }
+
+    public async ValueTask<(byte command, byte[] payload)?> ReceiveAsync(CancellationToken cancellationToken = default)
+    {
+        if (!_disconnected && _down.RollDisconnect())
+            Disconnect();
+        if (_disconnected)
+            return null;
+
+        if (_arrived.Count == 0 && !_innerClosed)
+            await TakePendingAsync(cancellationToken);
+        while (!_innerClosed && _arrived.Count < _options.ReorderWindow && StartPending(cancellationToken).IsCompleted)
+            await TakePendingAsync(cancellationToken);
+
+        if (_arrived.Count == 0)
+            return null;
+
+        var next = _arrived.MinBy(p => p.Due);
+        _arrived.Remove(next);
+        await _down.WaitUntilAsync(next.Due, cancellationToken);
+
+        return _disconnected ? null : (next.Command, next.Payload);
+    }
+
+    public ValueTask DisposeAsync()
+    {
+        Disconnect();
+        return _inner.DisposeAsync();
+    }
+
+    private Task<(byte command, byte[] payload)?> StartPending(CancellationToken cancellationToken)
+        => _pending ??= _inner.ReceiveAsync(cancellationToken).AsTask();
+
+    private async Task TakePendingAsync(CancellationToken cancellationToken)
+    {
+        var packet = await StartPending(cancellationToken);
+        _pending = null;
+        if (packet is not { } p)
+        {
+            _innerClosed = true;
+            return;
+        }
+
+        var due = _down.Schedule(
+            ApCodec.GetEncodedSize(p.payload.Length),
+            _lastDue.GetValueOrDefault(p.command));
+        _lastDue[p.command] = due;
+        _arrived.Add((due, p.command, p.payload));
+    }
+
+    private void ThrowIfDisconnected()
+    {
+        if (_disconnected)
+            throw new IOException("Simulated AP disconnect");
+    }
+}
@@ -0,0 +1,97 @@
+using System.Net.WebSockets;
+using System.Text;
+using Wavee.Connect.Connection;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// <see cref="IDealerConnection"/> wrapper that delays, throttles and randomly drops a dealer
+/// connection, for exercising <c>DealerClient</c>'s heartbeat and reconnect logic.
+/// </summary>
+/// <remarks>
+/// The inner connection raises one message at a time, so messages keep their order here; use
+/// <see cref="ChaosApTransport"/> to test reordering. A disconnect closes the inner connection
+/// and raises <see cref="Closed"/> with <see cref="WebSocketCloseStatus.EndpointUnavailable"/>,
+/// like a dropped socket; <see cref="ConnectAsync"/> brings it back.
+/// </remarks>
+internal sealed class ChaosDealerConnection : IDealerConnection
+{
+    private readonly IDealerConnection _inner;
+    private readonly ChaosOptions _options;
+    private readonly ChaosLink _up;
+    private readonly ChaosLink _down;
+    private volatile bool _disconnected;
+    private int _disconnects;
+
+    public ChaosDealerConnection(IDealerConnection inner, ChaosOptions options)
+    {
+        _inner = inner ?? throw new ArgumentNullException(nameof(inner));
+        _options = options ?? throw new ArgumentNullException(nameof(options));
+        _up = new ChaosLink(options, stream: 0);
+        _down = new ChaosLink(options, stream: 1);
+
+        _inner.MessageReceived += OnInnerMessageAsync;
+        _inner.Closed += (_, status) => Closed?.Invoke(this, status);
+        _inner.Error += (_, ex) => Error?.Invoke(this, ex);
+    }
+
+    public event Func<ReadOnlyMemory<byte>, ValueTask>? MessageReceived;
+    public event EventHandler<WebSocketCloseStatus?>? Closed;
+    public event EventHandler<Exception>? Error;
+
+    public ConnectionState State => _disconnected ? ConnectionState.Disconnected : _inner.State;
+
+    /// <summary>Number of disconnects, forced or random.</summary>
+    public int Disconnects => Volatile.Read(ref _disconnects);
+
+    /// <summary>Drops the connection now.</summary>
+    public async ValueTask DisconnectAsync()
+    {
+        if (_disconnected)
+            return;
+        _disconnected = true;
+        Interlocked.Increment(ref _disconnects);
+
+        await _inner.CloseAsync();
+        Closed?.Invoke(this, WebSocketCloseStatus.EndpointUnavailable);
+    }
+
+    public async ValueTask ConnectAsync(string wsUrl, CancellationToken cancellationToken = default)
+    {
+        await Task.Delay(_options.Latency, _options.TimeProvider, cancellationToken);
+        await _inner.ConnectAsync(wsUrl, cancellationToken);
+        _disconnected = false;
+    }
+
+    public async ValueTask SendAsync(ReadOnlyMemory<byte> utf8Message, CancellationToken cancellationToken = default)
+    {
+        if (!_disconnected && _up.RollDisconnect())
+            await DisconnectAsync();
+        if (_disconnected)
+            throw new InvalidOperationException("Not connected");
+
+        await _up.WaitUntilAsync(_up.Schedule(utf8Message.Length), cancellationToken);
+        await _inner.SendAsync(utf8Message, cancellationToken);
+    }
+
+    public ValueTask SendAsync(string message, CancellationToken cancellationToken = default)
+        => SendAsync(Encoding.UTF8.GetBytes(message), cancellationToken);
+
+    public ValueTask CloseAsync(CancellationToken cancellationToken = default)
+        => _inner.CloseAsync(cancellationToken);
+
+    public ValueTask DisposeAsync() => _inner.DisposeAsync();
+
+    private async ValueTask OnInnerMessageAsync(ReadOnlyMemory<byte> message)
+    {
+        if (!_disconnected && _down.RollDisconnect())
+            await DisconnectAsync();
+        if (_disconnected)
+            return;
+
+        await _down.WaitUntilAsync(_down.Schedule(message.Length));
+        if (_disconnected || MessageReceived is not { } handler)
+            return;
+        await handler(message);
+    }
+}
@@ -0,0 +1,93 @@
+using System.IO;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// <see cref="DelegatingHandler"/> that delays requests, throttles response bodies and randomly
+/// fails mid-request or mid-body, for exercising HTTP timeouts, retries and rebuffering.
+/// </summary>
+/// <remarks>
+/// Latency is added before the response headers; the body is then released at
+/// <see cref="ChaosOptions.BytesPerSecond"/> as the caller reads it. A disconnect before the
+/// headers surfaces as <see cref="HttpRequestException"/>; one during the body as
+/// <see cref="IOException"/> from the read, like a connection reset.
+/// </remarks>
+internal sealed class ChaosHttpHandler : DelegatingHandler
+{
+    private readonly ChaosLink _up;
+    private readonly ChaosLink _down;
+
+    public ChaosHttpHandler(HttpMessageHandler inner, ChaosOptions options)
+        : base(inner)
+    {
+        ArgumentNullException.ThrowIfNull(options);
+        _up = new ChaosLink(options, stream: 0);
+        _down = new ChaosLink(options, stream: 1);
+    }
+
+    protected override async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+    {
+        if (_up.RollDisconnect())
+            throw new HttpRequestException("Simulated connection failure", new IOException("Simulated disconnect"));
+
+        await _up.WaitUntilAsync(_up.Schedule(request.Content?.Headers.ContentLength ?? 0), cancellationToken);
+        var response = await base.SendAsync(request, cancellationToken);
+
+        var body = await response.Content.ReadAsByteArrayAsync(cancellationToken);
+        await _down.WaitUntilAsync(_down.Schedule(0), cancellationToken);
+        if (_down.RollDisconnect())
+            throw new HttpRequestException("Simulated connection failure", new IOException("Simulated disconnect"));
+
+        var headers = response.Content.Headers.ToList();
+        response.Content = new StreamContent(new ThrottledStream(body, _down));
+        foreach (var header in headers)
+            response.Content.Headers.TryAddWithoutValidation(header.Key, header.Value);
+        return response;
+    }
+
+    /// <summary>
+    /// Hands out a buffered body in link-sized chunks, waiting for each chunk to "arrive".
+    /// </summary>
+    private sealed class ThrottledStream(byte[] body, ChaosLink link) : Stream
+    {
+        // Roughly one TCP segment per read keeps the throttle smooth.
+        private const int ChunkSize = 1460;
+        private int _position;
+
+        public override bool CanRead => true;
+        public override bool CanSeek => false;
+        public override bool CanWrite => false;
+        public override long Length => body.Length;
+
+        public override long Position
+        {
+            get => _position;
+            set => throw new NotSupportedException();
+        }
+
+        public override int Read(byte[] buffer, int offset, int count)
+            => ReadAsync(buffer.AsMemory(offset, count)).AsTask().GetAwaiter().GetResult();
+
+        public override async ValueTask<int> ReadAsync(Memory<byte> buffer, CancellationToken cancellationToken = default)
+        {
+            if (_position >= body.Length || buffer.IsEmpty)
+                return 0;
+            if (link.RollDisconnect())
+                throw new IOException("Simulated connection reset");
+
+            var count = Math.Min(Math.Min(buffer.Length, ChunkSize), body.Length - _position);
+            await link.WaitUntilAsync(link.Schedule(count), cancellationToken);
+            body.AsSpan(_position, count).CopyTo(buffer.Span);
+            _position += count;
+            return count;
+        }
+
+        public override Task<int> ReadAsync(byte[] buffer, int offset, int count, CancellationToken cancellationToken)
+            => ReadAsync(buffer.AsMemory(offset, count), cancellationToken).AsTask();
+
+        public override void Flush() { }
+        public override long Seek(long offset, SeekOrigin origin) => throw new NotSupportedException();
+        public override void SetLength(long value) => throw new NotSupportedException();
+        public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
+    }
+}
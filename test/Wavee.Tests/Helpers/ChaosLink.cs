@@ -0,0 +1,95 @@
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// Network conditions for the chaos transports (<see cref="ChaosApTransport"/>,
+/// <see cref="ChaosDealerConnection"/>, <see cref="ChaosHttpHandler"/>).
+/// Same seed → same delays, reorderings and disconnects, so a failing run can be replayed.
+/// </summary>
+internal sealed record ChaosOptions
+{
+    /// <summary>Seed for every random decision.</summary>
+    public int Seed { get; init; }
+
+    /// <summary>Fixed one-way delay added to every packet, message or response.</summary>
+    public TimeSpan Latency { get; init; }
+
+    /// <summary>
+    /// Extra random delay in [0, Jitter). Packets on the AP transport can overtake each other by
+    /// at most this much; packets with the same command never do.
+    /// </summary>
+    public TimeSpan Jitter { get; init; }
+
+    /// <summary>Most AP packets held back for reordering at once. Default is 8.</summary>
+    public int ReorderWindow { get; init; } = 8;
+
+    /// <summary>Link speed in each direction; null leaves it unthrottled.</summary>
+    public long? BytesPerSecond { get; init; }
+
+    /// <summary>Chance of dropping the connection on each send, receive or body read.</summary>
+    public double DisconnectProbability { get; init; }
+
+    /// <summary>Clock the delays run on; pair with <see cref="VirtualTimeProvider"/> for fast tests.</summary>
+    public TimeProvider TimeProvider { get; init; } = TimeProvider.System;
+}
+
+/// <summary>
+/// One direction of a simulated link: a serialized pipe of <see cref="ChaosOptions.BytesPerSecond"/>
+/// followed by latency and jitter. Thread-safe.
+/// </summary>
+internal sealed class ChaosLink
+{
+    private readonly object _lock = new();
+    private readonly ChaosOptions _options;
+    private readonly Random _random;
+    private DateTimeOffset _linkFreeAt = DateTimeOffset.MinValue;
+
+    public ChaosLink(ChaosOptions options, int stream)
+    {
+        _options = options;
+        _random = new Random(HashCode.Combine(options.Seed, stream));
+    }
+
+    /// <summary>
+    /// Rolls <see cref="ChaosOptions.DisconnectProbability"/>.
+    /// </summary>
+    public bool RollDisconnect()
+    {
+        if (_options.DisconnectProbability <= 0)
+            return false;
+        lock (_lock)
+            return _random.NextDouble() < _options.DisconnectProbability;
+    }
+
+    /// <summary>
+    /// Queues <paramref name="bytes"/> on the link and returns when they arrive at the far end,
+    /// never earlier than <paramref name="notBefore"/>.
+    /// </summary>
+    public DateTimeOffset Schedule(long bytes, DateTimeOffset notBefore = default)
+    {
+        lock (_lock)
+        {
+            var now = _options.TimeProvider.GetUtcNow();
+            var sent = now > _linkFreeAt ? now : _linkFreeAt;
+            if (_options.BytesPerSecond is { } rate)
+                sent += TimeSpan.FromSeconds((double)bytes / rate);
+            _linkFreeAt = sent;
+
+            var jitter = _options.Jitter > TimeSpan.Zero
+                ? TimeSpan.FromTicks((long)(_random.NextDouble() * _options.Jitter.Ticks))
+                : TimeSpan.Zero;
+            var due = sent + _options.Latency + jitter;
+            return due > notBefore ? due : notBefore;
+        }
+    }
+
+    /// <summary>
+    /// Waits on <see cref="ChaosOptions.TimeProvider"/> until <paramref name="due"/>.
+    /// </summary>
+    public Task WaitUntilAsync(DateTimeOffset due, CancellationToken cancellationToken = default)
+    {
+        var delay = due - _options.TimeProvider.GetUtcNow();
+        return delay > TimeSpan.Zero
+            ? Task.Delay(delay, _options.TimeProvider, cancellationToken)
+            : Task.CompletedTask;
+    }
+}
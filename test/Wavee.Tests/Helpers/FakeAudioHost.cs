@@ -0,0 +1,117 @@
+using System.IO.Pipes;
+using System.Threading.Channels;
+using Wavee.AudioIpc;
+using Wavee.Playback.Contracts;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// In-process stand-in for Wavee.AudioHost: the other end of an
+/// <see cref="AudioPipelineProxy"/>'s pipe. Records every command, answers each with
+/// a successful CommandResult (so request/reply calls complete) and pushes state
+/// updates on demand.
+/// </summary>
+internal sealed class FakeAudioHost : IAsyncDisposable
+{
+    private static readonly TimeSpan WaitTimeout = TimeSpan.FromSeconds(10);
+
+    private readonly IpcPipeTransport _hostTransport;
+    private readonly Channel<IpcMessage> _incoming = Channel.CreateUnbounded<IpcMessage>();
+    private readonly List<IpcMessage> _received = new();
+    private readonly CancellationTokenSource _cts = new();
+    private readonly Task _loop;
+
+    private FakeAudioHost(NamedPipeServerStream serverPipe, NamedPipeClientStream clientPipe)
+    {
+        _hostTransport = new IpcPipeTransport(clientPipe);
+        Proxy = new AudioPipelineProxy(new IpcPipeTransport(serverPipe));
+        Proxy.StartReceiving();
+        _loop = Task.Run(() => ReceiveLoopAsync(_cts.Token));
+    }
+
+    /// <summary>
+    /// Proxy connected to this host, ready to hand to a <see cref="Wavee.Audio.PlaybackOrchestrator"/>.
+    /// </summary>
+    public AudioPipelineProxy Proxy { get; }
+
+    /// <summary>
+    /// Every command received so far, in order.
+    /// </summary>
+    public IReadOnlyList<IpcMessage> Received
+    {
+        get
+        {
+            lock (_received)
+                return _received.ToList();
+        }
+    }
+
+    public static async Task<FakeAudioHost> StartAsync()
+    {
+        var name = $"wavee-test-{Guid.NewGuid():N}";
+        var server = new NamedPipeServerStream(
+            name, PipeDirection.InOut, 1, PipeTransmissionMode.Byte, PipeOptions.Asynchronous);
+        var client = new NamedPipeClientStream(".", name, PipeDirection.InOut, PipeOptions.Asynchronous);
+        await Task.WhenAll(server.WaitForConnectionAsync(), client.ConnectAsync()).WaitAsync(WaitTimeout);
+        return new FakeAudioHost(server, client);
+    }
+
+    /// <summary>
+    /// Sends a StateUpdate to the proxy, as AudioHost does on every playback change.
+    /// </summary>
+    public Task PushStateAsync(PlaybackStateSnapshot snapshot)
+        => _hostTransport.SendAsync(IpcMessageTypes.StateUpdate, IpcPayloadHelper.SerializeToUtf8(snapshot));
+
+    /// <summary>
+    /// Waits for the next command of <paramref name="type"/>, skipping others.
+    /// </summary>
+    public async Task<IpcMessage> WaitForAsync(string type)
+    {
+        using var timeout = new CancellationTokenSource(WaitTimeout);
+        while (true)
+        {
+            var message = await _incoming.Reader.ReadAsync(timeout.Token);
+            if (message.Type == type)
+                return message;
+        }
+    }
+
+    /// <summary>
+    /// Waits for the next command of <paramref name="type"/> and returns its payload.
+    /// </summary>
+    public async Task<T> WaitForAsync<T>(string type) where T : class
+        => IpcPayloadHelper.Deserialize<T>(await WaitForAsync(type))
+           ?? throw new InvalidOperationException($"'{type}' carried no payload");
+
+    private async Task ReceiveLoopAsync(CancellationToken ct)
+    {
+        try
+        {
+            while (await _hostTransport.ReceiveAsync(ct) is { } message)
+            {
+                lock (_received)
+                    _received.Add(message);
+                await _incoming.Writer.WriteAsync(message, ct);
+
+                await _hostTransport.SendAsync(
+                    IpcMessageTypes.CommandResult,
+                    IpcPayloadHelper.SerializeToUtf8(new CommandResultMessage { RequestId = message.Id, Success = true }),
+                    ct: ct);
+            }
+        }
+        catch (Exception ex) when (ex is OperationCanceledException or IOException)
+        {
+            // Disposed or the proxy closed its end.
+        }
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        // Stop reading before the transports (and the pipes they own) go away.
+        _cts.Cancel();
+        await _loop;
+        await Proxy.DisposeAsync();
+        await _hostTransport.DisposeAsync();
+        _cts.Dispose();
+    }
+}
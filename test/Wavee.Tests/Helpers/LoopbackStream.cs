@@ -0,0 +1,118 @@
+using System.IO.Pipelines;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// One end of an in-memory duplex connection, for wiring two transports back-to-back
+/// without sockets. With a split seed, every write reaches the peer in randomly sized
+/// pieces and every read returns at most one piece, so decoders see packets cut at
+/// arbitrary byte boundaries - as they would over TCP.
+/// </summary>
+internal sealed class LoopbackStream : Stream
+{
+    private readonly PipeReader _input;
+    private readonly PipeWriter _output;
+    private readonly Random? _writeSplitter;
+    private readonly Random? _readSplitter;
+    private readonly int _maxChunk;
+
+    private LoopbackStream(PipeReader input, PipeWriter output, int? splitSeed, int maxChunk)
+    {
+        _input = input;
+        _output = output;
+        _maxChunk = maxChunk;
+        if (splitSeed is { } seed)
+        {
+            _writeSplitter = new Random(seed);
+            _readSplitter = new Random(~seed);
+        }
+    }
+
+    /// <summary>
+    /// Creates two connected ends. Bytes written to one are read from the other.
+    /// </summary>
+    /// <param name="splitSeed">Seed for write/read splitting; null writes and reads whole buffers.</param>
+    /// <param name="maxChunk">Largest piece when splitting.</param>
+    public static (LoopbackStream Client, LoopbackStream Server) CreatePair(int? splitSeed = null, int maxChunk = 64)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(maxChunk);
+
+        var toServer = new Pipe();
+        var toClient = new Pipe();
+        return (
+            new LoopbackStream(toClient.Reader, toServer.Writer, splitSeed, maxChunk),
+            new LoopbackStream(toServer.Reader, toClient.Writer, splitSeed + 1, maxChunk));
+    }
+
+    public override bool CanRead => true;
+    public override bool CanSeek => false;
+    public override bool CanWrite => true;
+    public override long Length => throw new NotSupportedException();
+    public override long Position
+    {
+        get => throw new NotSupportedException();
+        set => throw new NotSupportedException();
+    }
+
+    public override async ValueTask WriteAsync(ReadOnlyMemory<byte> buffer, CancellationToken cancellationToken = default)
+    {
+        while (!buffer.IsEmpty)
+        {
+            var count = NextChunk(_writeSplitter, buffer.Length);
+            await _output.WriteAsync(buffer[..count], cancellationToken);
+            buffer = buffer[count..];
+        }
+    }
+
+    public override async ValueTask<int> ReadAsync(Memory<byte> buffer, CancellationToken cancellationToken = default)
+    {
+        if (buffer.IsEmpty)
+            return 0;
+
+        var result = await _input.ReadAsync(cancellationToken);
+        var data = result.Buffer;
+        if (data.IsEmpty && result.IsCompleted)
+        {
+            _input.AdvanceTo(data.End);
+            return 0;
+        }
+
+        var count = NextChunk(_readSplitter, (int)Math.Min(buffer.Length, data.Length));
+        data.Slice(0, count).CopyTo(buffer.Span);
+        _input.AdvanceTo(data.GetPosition(count));
+        return count;
+    }
+
+    public override Task WriteAsync(byte[] buffer, int offset, int count, CancellationToken cancellationToken)
+        => WriteAsync(buffer.AsMemory(offset, count), cancellationToken).AsTask();
+
+    public override Task<int> ReadAsync(byte[] buffer, int offset, int count, CancellationToken cancellationToken)
+        => ReadAsync(buffer.AsMemory(offset, count), cancellationToken).AsTask();
+
+    public override void Write(byte[] buffer, int offset, int count)
+        => WriteAsync(buffer.AsMemory(offset, count)).AsTask().GetAwaiter().GetResult();
+
+    public override int Read(byte[] buffer, int offset, int count)
+        => ReadAsync(buffer.AsMemory(offset, count)).AsTask().GetAwaiter().GetResult();
+
+    public override void Flush() { }
+    public override Task FlushAsync(CancellationToken cancellationToken) => Task.CompletedTask;
+    public override long Seek(long offset, SeekOrigin origin) => throw new NotSupportedException();
+    public override void SetLength(long value) => throw new NotSupportedException();
+
+    /// <summary>
+    /// Closes this end's outgoing direction; the peer reads end-of-stream once buffered bytes are drained.
+    /// </summary>
+    protected override void Dispose(bool disposing)
+    {
+        if (disposing)
+        {
+            _output.Complete();
+            _input.Complete();
+        }
+        base.Dispose(disposing);
+    }
+
+    private int NextChunk(Random? splitter, int available)
+        => splitter is null ? available : Math.Min(available, splitter.Next(1, _maxChunk + 1));
+}
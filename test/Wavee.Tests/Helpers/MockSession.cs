@@ -11,14 +11,17 @@ namespace Wavee.Tests.Helpers;
 internal class MockSession : ISession
 {
     private readonly AccessToken _accessToken;
+    private readonly UserData? _userData;
 
     /// <summary>
     /// Initializes a new instance of the <see cref="MockSession"/> class.
     /// </summary>
     /// <param name="accessToken">The access token to return.</param>
     /// <param name="config">Optional session configuration. If null, creates a default test config.</param>
-    public MockSession(AccessToken? accessToken = null, SessionConfig? config = null)
+    /// <param name="userData">Optional signed-in user data. If null, the session reports no user.</param>
+    public MockSession(AccessToken? accessToken = null, SessionConfig? config = null, UserData? userData = null)
     {
+        _userData = userData;
         _accessToken = accessToken ?? new AccessToken
         {
             Token = "test_access_token",
@@ -53,7 +56,7 @@ internal class MockSession : ISession
     /// <summary>
     /// Gets current user data.
     /// </summary>
-    public UserData? GetUserData() => null;
+    public UserData? GetUserData() => _userData;
 
     /// <summary>
     /// Checks if the session is connected.
@@ -92,6 +95,11 @@ internal class MockSession : ISession
         return Task.CompletedTask;
     }
 
+    /// <summary>
+    /// Gets or sets whether the mock reports offline mode.
+    /// </summary>
+    public bool IsOfflineMode { get; set; }
+
     public IPathfinderClient Pathfinder => throw new NotImplementedException("Mock does not support Pathfinder");
 
     public ISpClient SpClient => throw new NotImplementedException("Mock does not support SpClient");
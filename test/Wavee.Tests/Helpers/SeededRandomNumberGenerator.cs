@@ -0,0 +1,29 @@
+using System;
+using System.Security.Cryptography;
+
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// Deterministic <see cref="RandomNumberGenerator"/> for handshake / device-id tests.
+/// Same seed → same byte stream, so vectors can be regenerated byte-for-byte.
+/// NOT cryptographically secure — test use only.
+/// </summary>
+public sealed class SeededRandomNumberGenerator : RandomNumberGenerator
+{
+    private readonly Random _random;
+
+    public SeededRandomNumberGenerator(int seed)
+    {
+        _random = new Random(seed);
+    }
+
+    public override void GetBytes(byte[] data) => _random.NextBytes(data);
+
+    public override void GetBytes(Span<byte> data) => _random.NextBytes(data);
+
+    public override void GetNonZeroBytes(byte[] data)
+    {
+        for (var i = 0; i < data.Length; i++)
+            data[i] = (byte)_random.Next(1, 256);
+    }
+}
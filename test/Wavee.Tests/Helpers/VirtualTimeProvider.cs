@@ -0,0 +1,151 @@
+namespace Wavee.Tests.Helpers;
+
+/// <summary>
+/// Manually advanced <see cref="TimeProvider"/> for simulation tests.
+/// Time only moves on <see cref="Advance"/>; timers due inside the advanced window fire
+/// in due order on the calling thread, so keep-alive cycles, token expiry and reconnect
+/// backoff spanning hours run in milliseconds.
+/// NOT thread-affine — callbacks run wherever Advance is called.
+/// </summary>
+public sealed class VirtualTimeProvider : TimeProvider
+{
+    private readonly object _lock = new();
+    private readonly List<VirtualTimer> _timers = new();
+    private DateTimeOffset _now;
+
+    public VirtualTimeProvider(DateTimeOffset? start = null)
+    {
+        _now = start ?? new DateTimeOffset(2025, 1, 1, 0, 0, 0, TimeSpan.Zero);
+    }
+
+    public override DateTimeOffset GetUtcNow()
+    {
+        lock (_lock) return _now;
+    }
+
+    public override TimeZoneInfo LocalTimeZone => TimeZoneInfo.Utc;
+
+    public override long TimestampFrequency => TimeSpan.TicksPerSecond;
+
+    public override long GetTimestamp()
+    {
+        lock (_lock) return _now.UtcTicks;
+    }
+
+    /// <summary>
+    /// Number of timers currently scheduled to fire.
+    /// </summary>
+    public int PendingTimerCount
+    {
+        get
+        {
+            lock (_lock) return _timers.Count(t => t.DueAt is not null);
+        }
+    }
+
+    /// <summary>
+    /// Time until the earliest scheduled timer fires, or null when nothing is scheduled.
+    /// </summary>
+    public TimeSpan? NextTimerDueIn
+    {
+        get
+        {
+            lock (_lock)
+            {
+                var next = _timers.Where(t => t.DueAt is not null).MinBy(t => t.DueAt);
+                return next?.DueAt - _now;
+            }
+        }
+    }
+
+    public override ITimer CreateTimer(TimerCallback callback, object? state, TimeSpan dueTime, TimeSpan period)
+    {
+        var timer = new VirtualTimer(this, callback, state);
+        lock (_lock) _timers.Add(timer);
+        timer.Change(dueTime, period);
+        return timer;
+    }
+
+    /// <summary>
+    /// Moves virtual time forward, firing every timer that falls due along the way.
+    /// Timers scheduled by those callbacks also fire if they land inside the window.
+    /// </summary>
+    public void Advance(TimeSpan delta)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThan(delta, TimeSpan.Zero);
+
+        DateTimeOffset target;
+        lock (_lock) target = _now + delta;
+
+        while (true)
+        {
+            VirtualTimer? next;
+            lock (_lock)
+            {
+                next = _timers.Where(t => t.DueAt <= target).MinBy(t => t.DueAt);
+                if (next is null)
+                {
+                    _now = target;
+                    return;
+                }
+
+                _now = next.DueAt!.Value;
+                next.DueAt = next.Period > TimeSpan.Zero ? _now + next.Period : null;
+            }
+
+            next.Fire();
+        }
+    }
+
+    /// <summary>
+    /// Waits (in real time) until at least <paramref name="count"/> timers are scheduled.
+    /// Use between <see cref="Advance"/> calls when the code under test re-arms its timer
+    /// on a continuation that may not have run yet.
+    /// </summary>
+    public bool WaitForPendingTimers(int count = 1, TimeSpan? timeout = null) =>
+        SpinWait.SpinUntil(() => PendingTimerCount >= count, timeout ?? TimeSpan.FromSeconds(2));
+
+    private sealed class VirtualTimer : ITimer
+    {
+        private readonly VirtualTimeProvider _owner;
+        private readonly TimerCallback _callback;
+        private readonly object? _state;
+
+        public VirtualTimer(VirtualTimeProvider owner, TimerCallback callback, object? state)
+        {
+            _owner = owner;
+            _callback = callback;
+            _state = state;
+        }
+
+        public DateTimeOffset? DueAt { get; set; }
+
+        public TimeSpan Period { get; private set; }
+
+        public void Fire() => _callback(_state);
+
+        public bool Change(TimeSpan dueTime, TimeSpan period)
+        {
+            lock (_owner._lock)
+            {
+                if (!_owner._timers.Contains(this))
+                    return false;
+
+                DueAt = dueTime == Timeout.InfiniteTimeSpan ? null : _owner._now + dueTime;
+                Period = period;
+                return true;
+            }
+        }
+
+        public void Dispose()
+        {
+            lock (_owner._lock) _owner._timers.Remove(this);
+        }
+
+        public ValueTask DisposeAsync()
+        {
+            Dispose();
+            return ValueTask.CompletedTask;
+        }
+    }
+}
@@ -0,0 +1,97 @@
+using FluentAssertions;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Playback;
+
+/// <summary>
+/// Tests for AesCtrKeystream - validates every AES backend against the platform one and librespot.
+///
+/// WHY: The hardware paths have their own key schedule and round sequencing. Bugs here will cause:
+/// - Audio that decodes as noise only on machines with AES-NI or ARMv8 crypto
+/// - Wrong output after the first 4-block batch or at unaligned stream positions
+/// - A forced backend silently falling back instead of failing on unsupported CPUs
+/// </summary>
+public class AesCtrKeystreamTests
+{
+    private static readonly byte[] Key =
+    [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+    ];
+
+    // Bytes 0x00..0x27 encrypted under Key, from librespot's AudioDecrypt (see Core/Crypto).
+    private static readonly byte[] LibrespotCiphertext =
+    [
+        0xf5, 0x49, 0x07, 0x5f, 0x9a, 0x45, 0x52, 0x5e,
+        0x71, 0x0d, 0xad, 0xda, 0x84, 0xc9, 0x8b, 0x76,
+        0x15, 0x9f, 0xbc, 0xa5, 0x02, 0x20, 0xc1, 0x29,
+        0xe8, 0xba, 0xa8, 0x42, 0xf1, 0xc8, 0x86, 0x53,
+        0xfe, 0x74, 0x27, 0x8d, 0x47, 0x90, 0x5d, 0x5c
+    ];
+
+    public static TheoryData<AesBackend> Backends => [AesBackend.Platform, AesBackend.AesNi, AesBackend.ArmCrypto];
+
+    [Theory]
+    [MemberData(nameof(Backends))]
+    public void Apply_ShouldMatchLibrespotVector(AesBackend backend)
+    {
+        if (!AesCtrKeystream.IsSupported(backend))
+            return;
+
+        // Arrange
+        using var keystream = new AesCtrKeystream(Key, backend);
+        var buffer = (byte[])LibrespotCiphertext.Clone();
+
+        // Act
+        keystream.Apply(buffer, 0);
+
+        // Assert
+        buffer.Should().Equal(Enumerable.Range(0, buffer.Length).Select(i => (byte)i));
+    }
+
+    [Theory]
+    [MemberData(nameof(Backends))]
+    public void Apply_AtUnalignedPositions_ShouldMatchPlatformBackend(AesBackend backend)
+    {
+        if (!AesCtrKeystream.IsSupported(backend))
+            return;
+
+        // Arrange - random key, lengths crossing the 4-block batch and the 1 KiB scratch size
+        var random = new Random(42);
+        var key = new byte[16];
+        random.NextBytes(key);
+        using var reference = new AesCtrKeystream(key, AesBackend.Platform);
+        using var keystream = new AesCtrKeystream(key, backend);
+
+        foreach (var (position, length) in new[] { (0L, 64), (5L, 3), (15L, 17), (1000L, 1500), (1L << 36, 4099) })
+        {
+            var expected = new byte[length];
+            random.NextBytes(expected);
+            var actual = (byte[])expected.Clone();
+
+            // Act
+            reference.Apply(expected, position);
+            keystream.Apply(actual, position);
+
+            // Assert
+            actual.Should().Equal(expected, $"position {position}, length {length}");
+        }
+    }
+
+    [Fact]
+    public void Constructor_WithUnsupportedBackend_ShouldThrow()
+    {
+        var unsupported = new[] { AesBackend.AesNi, AesBackend.ArmCrypto }
+            .Where(b => !AesCtrKeystream.IsSupported(b))
+            .ToArray();
+        if (unsupported.Length == 0)
+            return;
+
+        // Act
+        var act = () => new AesCtrKeystream(Key, unsupported[0]);
+
+        // Assert
+        act.Should().Throw<PlatformNotSupportedException>();
+    }
+}
@@ -0,0 +1,74 @@
+using FluentAssertions;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Playback;
+
+/// <summary>
+/// Tests for AudioCacheMigrations - validates version stamping and in-place layout upgrades.
+///
+/// WHY: Users can have gigabytes of cached audio. Bugs here will cause:
+/// - A layout change throwing away the whole cache instead of converting it
+/// - An older build rewriting a cache a newer build created
+/// - Migrations re-running on every start
+/// </summary>
+public class AudioCacheMigrationsTests : IDisposable
+{
+    private const string FileId = "0123456789abcdef0123456789abcdef01234567";
+
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-cachemig-" + Guid.NewGuid().ToString("N"));
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+
+    [Fact]
+    public void Migrate_EmptyCache_ShouldStampCurrentVersion()
+    {
+        // Act
+        var result = AudioCacheMigrations.Migrate(_dir);
+
+        // Assert
+        result.Status.Should().Be(AudioCacheMigrationStatus.Created);
+        AudioCacheMigrations.GetVersion(_dir).Should().Be(AudioCacheMigrations.CurrentVersion);
+        AudioCacheMigrations.Migrate(_dir).Status.Should().Be(AudioCacheMigrationStatus.UpToDate);
+    }
+
+    [Fact]
+    public void Migrate_UnstampedSingleFileCache_ShouldConvertInPlace()
+    {
+        // Arrange
+        var data = new byte[5000];
+        Random.Shared.NextBytes(data);
+        Directory.CreateDirectory(Path.Combine(_dir, "audio"));
+        File.WriteAllBytes(Path.Combine(_dir, "audio", FileId + ".enc"), data);
+
+        // Act
+        var result = AudioCacheMigrations.Migrate(_dir);
+
+        // Assert
+        result.Should().Be(new AudioCacheMigrationResult(AudioCacheMigrationStatus.Migrated, 1, 2, 1));
+        File.Exists(Path.Combine(_dir, "audio", FileId + ".enc")).Should().BeFalse();
+        using var stream = AudioFileCache.OpenRead(_dir, FileId);
+        var read = new byte[data.Length];
+        stream.ReadExactly(read);
+        read.Should().Equal(data);
+    }
+
+    [Fact]
+    public void Migrate_CacheFromNewerBuild_ShouldLeaveItAlone()
+    {
+        // Arrange
+        Directory.CreateDirectory(Path.Combine(_dir, "audio"));
+        File.WriteAllText(Path.Combine(_dir, "audio", "format-version"), "99");
+
+        // Act
+        var result = AudioCacheMigrations.Migrate(_dir);
+
+        // Assert
+        result.Status.Should().Be(AudioCacheMigrationStatus.Downgrade);
+        AudioCacheMigrations.GetVersion(_dir).Should().Be(99);
+    }
+}
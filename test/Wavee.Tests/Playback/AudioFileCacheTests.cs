@@ -0,0 +1,191 @@
+using FluentAssertions;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Playback;
+
+/// <summary>
+/// Tests for AudioFileCache - validates the content-addressed chunk layout and read-time verification.
+///
+/// WHY: The audio cache is read by the decoder without any other integrity check. Bugs here will cause:
+/// - A flipped bit on disk played back as garbled audio instead of triggering a re-download
+/// - Half-written files becoming visible to playback after a crash
+/// - Pruning deleting chunks of a file that is still being written
+/// - Converting an old cache on the track resolution path
+/// - Caches from before the chunked layout being silently thrown away
+/// - The memory-mapped read path diverging from the buffered one
+/// </summary>
+public class AudioFileCacheTests : IDisposable
+{
+    private const string FileId = "0123456789abcdef0123456789abcdef01234567";
+
+    private readonly string _dir = Path.Combine(Path.GetTempPath(), "wavee-audiocache-" + Guid.NewGuid().ToString("N"));
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_dir))
+            Directory.Delete(_dir, recursive: true);
+    }
+
+    [Theory]
+    [InlineData(false)]
+    [InlineData(true)]
+    public void WriteThenOpenRead_ShouldRoundTripAcrossChunksAndSeeks(bool memoryMapped)
+    {
+        // Arrange
+        var data = RandomBytes(AudioFileCache.ChunkSize * 2 + 123);
+        Write(FileId, data);
+
+        // Act
+        using var stream = AudioFileCache.OpenRead(_dir, FileId, memoryMapped);
+        var all = new byte[data.Length];
+        stream.ReadExactly(all);
+        stream.Position = AudioFileCache.ChunkSize - 2;
+        var straddling = new byte[4];
+        stream.ReadExactly(straddling);
+
+        // Assert
+        stream.IsMemoryMapped.Should().Be(memoryMapped);
+        AudioFileCache.GetCachedFileSize(_dir, FileId).Should().Be(data.Length);
+        all.Should().Equal(data);
+        straddling.Should().Equal(data.AsSpan(AudioFileCache.ChunkSize - 2, 4).ToArray());
+    }
+
+    [Fact]
+    public void BeginWrite_WithoutCommit_ShouldNotBeCached()
+    {
+        // Arrange & Act
+        using (var writer = AudioFileCache.BeginWrite(_dir, FileId))
+            writer.Write(RandomBytes(AudioFileCache.ChunkSize + 1));
+
+        // Assert
+        AudioFileCache.IsCached(_dir, FileId).Should().BeFalse();
+    }
+
+    [Theory]
+    [InlineData(false)]
+    [InlineData(true)]
+    public void OpenRead_WhenChunkCorrupted_ShouldThrowAndDropFile(bool memoryMapped)
+    {
+        // Arrange
+        var data = RandomBytes(AudioFileCache.ChunkSize + 10);
+        Write(FileId, data);
+        var chunk = Directory.EnumerateFiles(Path.Combine(_dir, "audio", "chunks"), "*.chunk", SearchOption.AllDirectories)
+            .Single(p => new FileInfo(p).Length == 10);
+        var bytes = File.ReadAllBytes(chunk);
+        bytes[3] ^= 0xFF;
+        File.WriteAllBytes(chunk, bytes);
+
+        // Act
+        using var stream = AudioFileCache.OpenRead(_dir, FileId, memoryMapped);
+        stream.Position = AudioFileCache.ChunkSize;
+        var act = () => stream.ReadExactly(new byte[10]);
+
+        // Assert
+        act.Should().Throw<AudioCacheCorruptException>().Which.ChunkIndex.Should().Be(1);
+        AudioFileCache.IsCached(_dir, FileId).Should().BeFalse("the next resolution must go to the CDN");
+    }
+
+    [Fact]
+    public void OpenRead_WithLegacyFile_ShouldReportItCachedAndConvertToChunks()
+    {
+        // Arrange
+        var data = RandomBytes(1000);
+        Directory.CreateDirectory(Path.Combine(_dir, "audio"));
+        var legacyPath = Path.Combine(_dir, "audio", FileId + ".enc");
+        File.WriteAllBytes(legacyPath, data);
+
+        // Act
+        var cachedSize = AudioFileCache.GetCachedFileSize(_dir, FileId);
+        using var stream = AudioFileCache.OpenRead(_dir, FileId);
+        var read = new byte[data.Length];
+        stream.ReadExactly(read);
+
+        // Assert
+        cachedSize.Should().Be(data.Length);
+        File.Exists(legacyPath).Should().BeFalse();
+        File.Exists(AudioFileCache.GetManifestPath(_dir, FileId)).Should().BeTrue();
+        read.Should().Equal(data);
+    }
+
+    [Fact]
+    public void Prune_ShouldDropOldestAndCollectItsChunks()
+    {
+        // Arrange
+        const string otherId = "fedcba9876543210fedcba9876543210fedcba98";
+        Write(otherId, RandomBytes(100));
+        File.SetLastAccessTimeUtc(AudioFileCache.GetManifestPath(_dir, otherId), DateTime.UtcNow.AddDays(-1));
+        AgeChunks();
+        Write(FileId, RandomBytes(100));
+
+        // Act
+        var pruned = AudioFileCache.Prune(_dir, maxBytes: 150, protectedFileIdHex: FileId);
+
+        // Assert
+        pruned.Should().Equal(otherId);
+        AudioFileCache.IsCached(_dir, FileId).Should().BeTrue();
+        Directory.EnumerateFiles(Path.Combine(_dir, "audio", "chunks"), "*.chunk", SearchOption.AllDirectories)
+            .Should().ContainSingle();
+    }
+
+    [Fact]
+    public void Prune_WhileAnotherFileIsBeingWritten_ShouldKeepItsChunks()
+    {
+        // Arrange
+        const string otherId = "fedcba9876543210fedcba9876543210fedcba98";
+        Write(otherId, RandomBytes(100));
+        File.SetLastAccessTimeUtc(AudioFileCache.GetManifestPath(_dir, otherId), DateTime.UtcNow.AddDays(-1));
+        Write(FileId, RandomBytes(100));
+        using var writer = AudioFileCache.BeginWrite(_dir, "00112233445566778899aabbccddeeff00112233");
+        writer.Write(RandomBytes(AudioFileCache.ChunkSize));
+        AgeChunks();
+
+        // Act
+        AudioFileCache.Prune(_dir, maxBytes: 150, protectedFileIdHex: FileId);
+
+        // Assert
+        Directory.EnumerateFiles(Path.Combine(_dir, "audio", "chunks"), "*.chunk", SearchOption.AllDirectories)
+            .Should().HaveCount(2, "the open writer's chunk and the protected file's survive");
+    }
+
+    [Fact]
+    public void Prune_WithRecentlyWrittenUnreferencedChunk_ShouldKeepIt()
+    {
+        // Arrange - a chunk another process wrote but hasn't committed a manifest for
+        const string otherId = "fedcba9876543210fedcba9876543210fedcba98";
+        Write(otherId, RandomBytes(100));
+        File.SetLastAccessTimeUtc(AudioFileCache.GetManifestPath(_dir, otherId), DateTime.UtcNow.AddDays(-1));
+        AgeChunks();
+        using (var abandoned = AudioFileCache.BeginWrite(_dir, "00112233445566778899aabbccddeeff00112233"))
+            abandoned.Write(RandomBytes(AudioFileCache.ChunkSize));
+        Write(FileId, RandomBytes(100));
+
+        // Act
+        AudioFileCache.Prune(_dir, maxBytes: 150, protectedFileIdHex: FileId);
+
+        // Assert
+        Directory.EnumerateFiles(Path.Combine(_dir, "audio", "chunks"), "*.chunk", SearchOption.AllDirectories)
+            .Should().HaveCount(2, "only the pruned file's chunk is old enough to collect");
+    }
+
+    // Moves every chunk written so far out of the one-hour grace period for uncommitted chunks.
+    private void AgeChunks()
+    {
+        foreach (var chunk in Directory.EnumerateFiles(Path.Combine(_dir, "audio", "chunks"), "*.chunk", SearchOption.AllDirectories))
+            File.SetLastWriteTimeUtc(chunk, DateTime.UtcNow.AddDays(-1));
+    }
+
+    private void Write(string fileId, byte[] data)
+    {
+        using var writer = AudioFileCache.BeginWrite(_dir, fileId);
+        writer.Write(data);
+        writer.Commit();
+    }
+
+    private static byte[] RandomBytes(int length)
+    {
+        var bytes = new byte[length];
+        Random.Shared.NextBytes(bytes);
+        return bytes;
+    }
+}
@@ -0,0 +1,69 @@
+using FluentAssertions;
+using Wavee.Playback.Contracts;
+using Xunit;
+
+namespace Wavee.Tests.Playback;
+
+/// <summary>
+/// Tests for SlabBufferPool - validates slab sizing, reuse and the hit-rate counters.
+///
+/// WHY: The pool sits under every decoded packet and fetched chunk. Bugs here will cause:
+/// - Arrays shorter than requested (out-of-range writes in decoders)
+/// - Unbounded retention when returns outpace rents
+/// - Hit rates in the diagnostics that don't reflect real reuse
+/// </summary>
+public class SlabBufferPoolTests
+{
+    [Fact]
+    public void Rent_ShouldRoundUpToSlabSize()
+    {
+        // Arrange
+        var pool = new SlabBufferPool("test", 4096, 65536, maxRetainedPerSlab: 4);
+
+        // Act & Assert
+        pool.Rent(1).Length.Should().Be(4096);
+        pool.Rent(4096).Length.Should().Be(4096);
+        pool.Rent(4097).Length.Should().Be(8192);
+        pool.Rent(65536).Length.Should().Be(65536);
+        pool.Rent(65537).Length.Should().BeGreaterThanOrEqualTo(65537);
+    }
+
+    [Fact]
+    public void RentAfterReturn_ShouldReuseArrayAndCountHit()
+    {
+        // Arrange
+        var pool = new SlabBufferPool("test", 4096, 65536, maxRetainedPerSlab: 4);
+        var first = pool.Rent(16384);
+        pool.Return(first);
+
+        // Act
+        var second = pool.Rent(10000);
+        pool.Rent(200_000);
+
+        // Assert
+        second.Should().BeSameAs(first);
+        var stats = pool.GetStatistics();
+        stats.Name.Should().Be("test");
+        stats.Rents.Should().Be(3);
+        stats.Hits.Should().Be(1);
+        stats.Misses.Should().Be(1);
+        stats.Oversize.Should().Be(1);
+        stats.HitRate.Should().BeApproximately(1.0 / 3, 1e-9);
+        stats.RetainedBytes.Should().Be(0);
+    }
+
+    [Fact]
+    public void Return_BeyondRetentionLimit_ShouldDropArrays()
+    {
+        // Arrange
+        var pool = new SlabBufferPool("test", 4096, 65536, maxRetainedPerSlab: 2);
+        var arrays = Enumerable.Range(0, 5).Select(_ => pool.Rent(4096)).ToList();
+
+        // Act
+        arrays.ForEach(a => pool.Return(a));
+        pool.Return(new byte[5000]);
+
+        // Assert
+        pool.GetStatistics().RetainedBytes.Should().Be(2 * 4096);
+    }
+}
@@ -0,0 +1,164 @@
+#if WAVEE_WASM_PLUGINS
+using FluentAssertions;
+using Moq;
+using Wasmtime;
+using Wavee.Connect;
+using Wavee.Console;
+using Wavee.Core.Session;
+using Xunit;
+
+namespace Wavee.Tests.Plugins;
+
+/// <summary>
+/// Tests for WasmPluginHost - validates plugin loading, the sandbox limits and the host calls.
+///
+/// WHY: Plugins are untrusted code driving the player. Bugs here will cause:
+/// - Plugin commands (e.g. auto-skip) never reaching the local engine
+/// - A plugin running arbitrary daemon commands or flooding the engine
+/// - A spinning plugin stalling event delivery for every other plugin
+/// - One broken file preventing the remaining plugins from loading
+/// </summary>
+public sealed class WasmPluginHostTests : IAsyncDisposable
+{
+    private static readonly TimeSpan Timeout = TimeSpan.FromSeconds(10);
+
+    private readonly Session _session;
+    private readonly Mock<IPlaybackEngine> _engine = new();
+    private readonly WasmPluginHost _host;
+
+    public WasmPluginHostTests()
+    {
+        var httpClientFactory = new Mock<IHttpClientFactory>();
+        httpClientFactory.Setup(f => f.CreateClient(It.IsAny<string>())).Returns(() => new HttpClient());
+        _session = Session.Create(new SessionConfig { DeviceId = "device", DeviceName = "Test" }, httpClientFactory.Object);
+        _host = new WasmPluginHost(_session, new DaemonController(_session, () => _engine.Object), cache: null);
+    }
+
+    [Fact]
+    public async Task Start_PluginIssuingAllowedCommand_ShouldRunItOnTheEngine()
+    {
+        // Arrange
+        var skipped = new TaskCompletionSource();
+        _engine.Setup(e => e.SkipNextAsync(It.IsAny<CancellationToken>()))
+            .Callback(() => skipped.TrySetResult())
+            .Returns(Task.CompletedTask);
+
+        // Act
+        _host.Start([Plugin("skipper", CommandModule("next", times: 1))]);
+
+        // Assert
+        await skipped.Task.WaitAsync(Timeout);
+        _host.PluginNames.Should().Equal("skipper");
+    }
+
+    [Fact]
+    public async Task Start_PluginIssuingDisallowedCommand_ShouldUnloadItWithoutRunningIt()
+    {
+        // Act
+        _host.Start([Plugin("volume", CommandModule("volume", times: 1))]);
+
+        // Assert
+        await WaitUntilUnloadedAsync();
+        _engine.VerifyNoOtherCalls();
+    }
+
+    [Fact]
+    public async Task Start_PluginIssuingTooManyCommandsForOneEvent_ShouldUnloadItWithoutRunningThem()
+    {
+        // Act
+        _host.Start([Plugin("flood", CommandModule("pause", times: 5))]);
+
+        // Assert
+        await WaitUntilUnloadedAsync();
+        _engine.Verify(e => e.PauseAsync(It.IsAny<CancellationToken>()), Times.Never);
+    }
+
+    [Fact]
+    public async Task Start_PluginExhaustingFuel_ShouldUnloadIt()
+    {
+        // Arrange
+        const string spin = """
+            (module
+              (memory (export "memory") 1)
+              (func (export "wavee_alloc") (param i32) (result i32) (i32.const 1024))
+              (func (export "wavee_on_event") (param i32 i32)
+                (loop $spin (br $spin))))
+            """;
+
+        // Act
+        _host.Start([Plugin("spin", spin)]);
+
+        // Assert
+        await WaitUntilUnloadedAsync();
+    }
+
+    [Fact]
+    public void Start_WithBrokenModules_ShouldSkipThemAndLoadTheRest()
+    {
+        // Arrange
+        const string missingExports = """(module (memory (export "memory") 1))""";
+
+        // Act
+        _host.Start(
+        [
+            Plugin("invalid", "(module (func $oops"),
+            Plugin("incomplete", missingExports),
+            Plugin("valid", CommandModule("play", times: 0))
+        ]);
+
+        // Assert
+        _host.PluginNames.Should().Equal("valid");
+    }
+
+    [Fact]
+    public void Start_WithDirectory_ShouldSkipFilesThatAreNotWebAssembly()
+    {
+        // Arrange
+        var directory = Directory.CreateTempSubdirectory("wavee-plugins-");
+        try
+        {
+            File.WriteAllText(Path.Combine(directory.FullName, "garbage.wasm"), "not a module");
+            File.WriteAllText(Path.Combine(directory.FullName, "notes.txt"), "ignored");
+
+            // Act
+            _host.Start(directory.FullName);
+
+            // Assert
+            _host.PluginNames.Should().BeEmpty();
+        }
+        finally
+        {
+            directory.Delete(recursive: true);
+        }
+    }
+
+    private static (string, Func<Engine, Module>) Plugin(string name, string wat) =>
+        (name, engine => Module.FromText(engine, name, wat));
+
+    /// <summary>
+    /// A plugin that issues <paramref name="command"/> <paramref name="times"/> times per event.
+    /// </summary>
+    private static string CommandModule(string command, int times) => $$"""
+        (module
+          (import "wavee" "command" (func $command (param i32 i32)))
+          (memory (export "memory") 1)
+          (data (i32.const 0) "{{command}}")
+          (func (export "wavee_alloc") (param i32) (result i32) (i32.const 1024))
+          (func (export "wavee_on_event") (param i32 i32)
+            {{string.Concat(Enumerable.Repeat($"(call $command (i32.const 0) (i32.const {command.Length}))", times))}}))
+        """;
+
+    private async Task WaitUntilUnloadedAsync()
+    {
+        using var cts = new CancellationTokenSource(Timeout);
+        while (_host.PluginNames.Count > 0)
+            await Task.Delay(10, cts.Token);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await _host.DisposeAsync();
+        await _session.DisposeAsync();
+    }
+}
+#endif